@@ -0,0 +1,416 @@
+/*
+This code is part of the WhiteboxTools geospatial analysis library.
+Authors: Dr. John Lindsay
+Created: 18/10/2026
+Last Modified: 18/10/2026
+License: MIT
+*/
+
+use super::polygon_boolean::{signed_area, union_rings};
+use crate::structures::Point2D;
+use std::f64::consts::PI;
+
+/// The shape of the buffer around the end of a line.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CapStyle {
+    /// A half circle centred on the end point.
+    Round,
+    /// A straight edge through the end point.
+    Flat,
+    /// A square extending the line by the buffer distance.
+    Square,
+}
+
+/// The shape of the buffer around the outside of a corner.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum JoinStyle {
+    Round,
+    /// The offset edges are extended until they meet, or bevelled if they would meet further
+    /// than the mitre limit from the vertex.
+    Mitre,
+    /// The offset edges are joined with a straight edge.
+    Bevel,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct BufferStyle {
+    pub cap_style: CapStyle,
+    pub join_style: JoinStyle,
+    /// The greatest distance of a mitre's tip from its vertex, as a multiple of the buffer
+    /// distance.
+    pub mitre_limit: f64,
+    /// The number of segments used to approximate a quarter circle.
+    pub quadrant_segments: usize,
+}
+
+impl Default for BufferStyle {
+    fn default() -> BufferStyle {
+        BufferStyle {
+            cap_style: CapStyle::Round,
+            join_style: JoinStyle::Round,
+            mitre_limit: 5f64,
+            quadrant_segments: 8,
+        }
+    }
+}
+
+// A buffer is built as the union of simple pieces: a rectangle along each segment, a wedge on
+// the outside of each corner, and a cap at each end of a line. The union (see
+// `polygon_boolean::union_rings`) resolves all of the overlaps between the pieces, however the
+// lines or polygons fold back on themselves. Pieces are clockwise, so that they add area, or
+// counter-clockwise to remove area in an inward buffer.
+
+/// Returns the buffer of a point, as a closed clockwise ring, or nothing if `distance` is not
+/// positive. A square cap gives a square buffer and otherwise the buffer is a circle.
+pub fn buffer_point(point: Point2D, distance: f64, style: &BufferStyle) -> Vec<Vec<Point2D>> {
+    if distance.is_nan() || distance <= 0f64 {
+        return vec![];
+    }
+    let mut ring = if style.cap_style == CapStyle::Square {
+        vec![
+            Point2D::new(point.x - distance, point.y - distance),
+            Point2D::new(point.x - distance, point.y + distance),
+            Point2D::new(point.x + distance, point.y + distance),
+            Point2D::new(point.x + distance, point.y - distance),
+        ]
+    } else {
+        let n = 4 * style.quadrant_segments.max(1);
+        (0..n)
+            .map(|i| {
+                // clockwise from north
+                let angle = 2f64 * PI * i as f64 / n as f64;
+                Point2D::new(
+                    point.x + distance * angle.sin(),
+                    point.y + distance * angle.cos(),
+                )
+            })
+            .collect()
+    };
+    ring.push(ring[0]);
+    vec![ring]
+}
+
+/// Returns the buffer of a polyline as a set of closed rings, clockwise for outer boundaries
+/// and counter-clockwise for holes. A polyline whose first and last points are the same is
+/// treated as a closed loop, without caps.
+pub fn buffer_polyline(points: &[Point2D], distance: f64, style: &BufferStyle) -> Vec<Vec<Point2D>> {
+    buffer_polylines(&[points.to_vec()], distance, style)
+}
+
+/// Returns the buffer of several polylines, e.g. the parts of a multi-part feature, merged
+/// into a single set of rings.
+pub fn buffer_polylines(
+    lines: &[Vec<Point2D>],
+    distance: f64,
+    style: &BufferStyle,
+) -> Vec<Vec<Point2D>> {
+    if distance.is_nan() || distance <= 0f64 {
+        return vec![];
+    }
+    let mut pieces = vec![];
+    for line in lines {
+        let mut points = remove_repeated_points(line);
+        if points.is_empty() {
+            continue;
+        }
+        if points.len() == 1 {
+            if style.cap_style != CapStyle::Flat {
+                pieces.extend(buffer_point(points[0], distance, style));
+            }
+            continue;
+        }
+        let closed = points.len() > 3 && points[0] == points[points.len() - 1];
+        if closed {
+            points.pop();
+        }
+        let n = points.len();
+        let num_segments = if closed { n } else { n - 1 };
+        for i in 0..num_segments {
+            pieces.push(segment_rectangle(points[i], points[(i + 1) % n], distance));
+        }
+        let corners = if closed { 0..n } else { 1..n - 1 };
+        for i in corners {
+            let prev = points[(i + n - 1) % n];
+            if let Some(wedge) = join_wedge(prev, points[i], points[(i + 1) % n], distance, style) {
+                pieces.push(wedge);
+            }
+        }
+        if !closed {
+            pieces.extend(end_cap(points[1], points[0], distance, style));
+            pieces.extend(end_cap(points[n - 2], points[n - 1], distance, style));
+        }
+    }
+    union_rings(&pieces)
+}
+
+/// Returns the buffer of a polygon, given as a set of rings in the Shapefile convention
+/// (clockwise outer rings and counter-clockwise holes), as another set of rings in the same
+/// convention. A negative `distance` shrinks the polygon, and may remove it altogether.
+pub fn buffer_polygon(rings: &[Vec<Point2D>], distance: f64, style: &BufferStyle) -> Vec<Vec<Point2D>> {
+    let mut pieces: Vec<Vec<Point2D>> = rings.iter().map(|r| remove_repeated_points(r)).collect();
+    if distance == 0f64 || !distance.is_finite() {
+        return union_rings(&pieces);
+    }
+    let d = distance.abs();
+    let mut boundary = vec![];
+    for ring in &pieces {
+        let mut points = ring.clone();
+        if points.len() > 1 && points[0] == points[points.len() - 1] {
+            points.pop();
+        }
+        let n = points.len();
+        if n < 2 {
+            continue;
+        }
+        for i in 0..n {
+            boundary.push(segment_rectangle(points[i], points[(i + 1) % n], d));
+            let prev = points[(i + n - 1) % n];
+            if let Some(wedge) = join_wedge(prev, points[i], points[(i + 1) % n], d, style) {
+                boundary.push(wedge);
+            }
+        }
+    }
+    if distance < 0f64 {
+        // Remove the area within the distance of the boundary.
+        for piece in boundary.iter_mut() {
+            piece.reverse();
+        }
+    }
+    pieces.extend(boundary);
+    union_rings(&pieces)
+}
+
+fn remove_repeated_points(points: &[Point2D]) -> Vec<Point2D> {
+    let mut out: Vec<Point2D> = Vec::with_capacity(points.len());
+    for p in points {
+        if out.last() != Some(p) {
+            out.push(*p);
+        }
+    }
+    out
+}
+
+fn offset(p: Point2D, direction: Point2D, distance: f64) -> Point2D {
+    Point2D::new(p.x + direction.x * distance, p.y + direction.y * distance)
+}
+
+/// Returns the unit vector pointing to the left of the direction from `a` to `b`.
+fn left_normal(a: Point2D, b: Point2D) -> Point2D {
+    let v = b - a;
+    let length = v.magnitude();
+    Point2D::new(-v.y / length, v.x / length)
+}
+
+fn clockwise(mut ring: Vec<Point2D>) -> Vec<Point2D> {
+    if signed_area(&ring) > 0f64 {
+        ring.reverse();
+    }
+    ring
+}
+
+fn segment_rectangle(a: Point2D, b: Point2D, distance: f64) -> Vec<Point2D> {
+    let n = left_normal(a, b);
+    clockwise(vec![
+        offset(a, n, distance),
+        offset(b, n, distance),
+        offset(b, n, -distance),
+        offset(a, n, -distance),
+    ])
+}
+
+/// Adds the points of an arc around `centre`, from the direction `from` through `sweep`
+/// radians (positive counter-clockwise), excluding its first point.
+fn push_arc(
+    points: &mut Vec<Point2D>,
+    centre: Point2D,
+    from: Point2D,
+    sweep: f64,
+    distance: f64,
+    style: &BufferStyle,
+) {
+    let step = PI / 2f64 / style.quadrant_segments.max(1) as f64;
+    let num_steps = ((sweep.abs() / step).ceil() as usize).max(1);
+    let start = from.y.atan2(from.x);
+    for k in 1..=num_steps {
+        let angle = start + sweep * k as f64 / num_steps as f64;
+        points.push(Point2D::new(
+            centre.x + distance * angle.cos(),
+            centre.y + distance * angle.sin(),
+        ));
+    }
+}
+
+/// Returns the piece that fills the gap between the rectangles of two segments on the outside
+/// of the corner at `p`, or `None` if the segments are in line.
+fn join_wedge(
+    prev: Point2D,
+    p: Point2D,
+    next: Point2D,
+    distance: f64,
+    style: &BufferStyle,
+) -> Option<Vec<Point2D>> {
+    let n1 = left_normal(prev, p);
+    let n2 = left_normal(p, next);
+    let d1 = Point2D::new(n1.y, -n1.x);
+    let d2 = Point2D::new(n2.y, -n2.x);
+    let turn = d1.cross(d2);
+    let dot = d1 * d2;
+    if turn.abs() <= 1e-12 && dot > 0f64 {
+        return None;
+    }
+    // The gap is on the right of a left turn and on the left of a right turn, or a reversal.
+    let (s1, s2, direction) = if turn > 1e-12 {
+        (Point2D::new(-n1.x, -n1.y), Point2D::new(-n2.x, -n2.y), 1f64)
+    } else {
+        (n1, n2, -1f64)
+    };
+    let sweep = s1.cross(s2).atan2(s1 * s2);
+    let sweep = if sweep * direction <= 0f64 {
+        // a reversal, which sweeps a half circle ahead of the vertex
+        direction * PI
+    } else {
+        sweep
+    };
+    let mut wedge = vec![p, offset(p, s1, distance)];
+    match style.join_style {
+        JoinStyle::Round => push_arc(&mut wedge, p, s1, sweep, distance, style),
+        JoinStyle::Mitre => {
+            let cos_half = (sweep / 2f64).cos();
+            if cos_half > 1f64 / style.mitre_limit.max(1f64) {
+                let bisector = s1 + s2;
+                let bisector = Point2D::new(
+                    bisector.x / bisector.magnitude(),
+                    bisector.y / bisector.magnitude(),
+                );
+                wedge.push(offset(p, bisector, distance / cos_half));
+            }
+            wedge.push(offset(p, s2, distance));
+        }
+        JoinStyle::Bevel => wedge.push(offset(p, s2, distance)),
+    }
+    Some(clockwise(wedge))
+}
+
+/// Returns the cap at `end`, on a line arriving from `prev`.
+fn end_cap(prev: Point2D, end: Point2D, distance: f64, style: &BufferStyle) -> Option<Vec<Point2D>> {
+    let n = left_normal(prev, end);
+    let t = Point2D::new(n.y, -n.x);
+    match style.cap_style {
+        CapStyle::Flat => None,
+        CapStyle::Square => Some(clockwise(vec![
+            offset(end, n, distance),
+            offset(offset(end, n, distance), t, distance),
+            offset(offset(end, n, -distance), t, distance),
+            offset(end, n, -distance),
+        ])),
+        CapStyle::Round => {
+            let mut cap = vec![offset(end, n, distance)];
+            push_arc(&mut cap, end, n, -PI, distance, style);
+            Some(clockwise(cap))
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{
+        buffer_point, buffer_polygon, buffer_polyline, BufferStyle, CapStyle, JoinStyle,
+    };
+    use crate::algorithms::polygon_boolean::signed_area;
+    use crate::structures::Point2D;
+
+    fn area(rings: &[Vec<Point2D>]) -> f64 {
+        -rings.iter().map(|r| signed_area(r)).sum::<f64>() / 2f64
+    }
+
+    fn style(cap_style: CapStyle, join_style: JoinStyle) -> BufferStyle {
+        BufferStyle {
+            cap_style: cap_style,
+            join_style: join_style,
+            ..Default::default()
+        }
+    }
+
+    fn square() -> Vec<Point2D> {
+        // clockwise, 10 x 10
+        vec![
+            Point2D::new(0.0, 0.0),
+            Point2D::new(0.0, 10.0),
+            Point2D::new(10.0, 10.0),
+            Point2D::new(10.0, 0.0),
+            Point2D::new(0.0, 0.0),
+        ]
+    }
+
+    #[test]
+    fn test_point() {
+        let rings = buffer_point(Point2D::new(5.0, 5.0), 2.0, &BufferStyle::default());
+        assert_eq!(rings.len(), 1);
+        // A 32-sided polygon inscribed in the circle.
+        let expected = 0.5 * 32.0 * 4.0 * (2.0 * std::f64::consts::PI / 32.0).sin();
+        assert!((area(&rings) - expected).abs() < 1e-9);
+        assert!(buffer_point(Point2D::new(5.0, 5.0), -2.0, &BufferStyle::default()).is_empty());
+    }
+
+    #[test]
+    fn test_line_caps() {
+        let line = [Point2D::new(0.0, 0.0), Point2D::new(10.0, 0.0)];
+        let flat = buffer_polyline(&line, 1.0, &style(CapStyle::Flat, JoinStyle::Round));
+        assert!((area(&flat) - 20.0).abs() < 1e-9);
+        let square = buffer_polyline(&line, 1.0, &style(CapStyle::Square, JoinStyle::Round));
+        assert!((area(&square) - 24.0).abs() < 1e-9);
+        let round = buffer_polyline(&line, 1.0, &style(CapStyle::Round, JoinStyle::Round));
+        assert!(area(&round) > 23.0 && area(&round) < 20.0 + std::f64::consts::PI);
+    }
+
+    #[test]
+    fn test_line_folding_back_on_itself() {
+        // Out and back along the same path, so the buffer is that of the first segment.
+        let line = [
+            Point2D::new(0.0, 0.0),
+            Point2D::new(10.0, 0.0),
+            Point2D::new(0.0, 0.0),
+        ];
+        let rings = buffer_polyline(&line, 1.0, &style(CapStyle::Square, JoinStyle::Bevel));
+        assert_eq!(rings.len(), 1);
+        assert!((area(&rings) - 22.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_polygon_joins() {
+        let rings = [square()];
+        let mitre = buffer_polygon(&rings, 1.0, &style(CapStyle::Round, JoinStyle::Mitre));
+        assert!((area(&mitre) - 144.0).abs() < 1e-9);
+        let bevel = buffer_polygon(&rings, 1.0, &style(CapStyle::Round, JoinStyle::Bevel));
+        assert!((area(&bevel) - 142.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_inward_buffer() {
+        let rings = [square()];
+        let inward = buffer_polygon(&rings, -1.0, &BufferStyle::default());
+        assert_eq!(inward.len(), 1);
+        assert!((area(&inward) - 64.0).abs() < 1e-9);
+        assert!(buffer_polygon(&rings, -6.0, &BufferStyle::default()).is_empty());
+    }
+
+    #[test]
+    fn test_polygon_hole_shrinks() {
+        let mut hole = vec![
+            Point2D::new(3.0, 3.0),
+            Point2D::new(3.0, 7.0),
+            Point2D::new(7.0, 7.0),
+            Point2D::new(7.0, 3.0),
+            Point2D::new(3.0, 3.0),
+        ];
+        hole.reverse();
+        let rings = buffer_polygon(
+            &[square(), hole],
+            1.0,
+            &style(CapStyle::Round, JoinStyle::Mitre),
+        );
+        assert_eq!(rings.len(), 2);
+        // 12 x 12 less a 2 x 2 hole
+        assert!((area(&rings) - 140.0).abs() < 1e-9);
+    }
+}
@@ -7,6 +7,8 @@ License: MIT
 */
 
 use crate::structures::{BoundingBox, LineSegment, Point2D, Polyline};
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
 
 // pub fn lines_are_equal(line1: &[Point2D], line2: &[Point2D]) -> bool {
 //     if line1.len() == line2.len() {
@@ -92,6 +94,91 @@ pub fn simplify_rdp(points: &[Point2D], epsilon: &f64) -> Vec<Point2D> {
     }
 }
 
+/// An implementation of the Visvalingam-Whyatt line-simplification algorithm. Vertices are removed
+/// in order of the area of the triangle that each forms with its two neighbours, its effective area,
+/// until every remaining vertex has an effective area of at least `min_area`. The end points of the
+/// line are always retained.
+///
+/// Reference:
+/// Visvalingam, M., Whyatt, J.D., 1993. Line generalisation by repeated elimination of points. The
+/// Cartographic Journal 30, 46–51.
+pub fn simplify_visvalingam(points: &[Point2D], min_area: &f64) -> Vec<Point2D> {
+    let n = points.len();
+    if n < 3 {
+        return points.to_vec();
+    }
+    let triangle_area = |a: usize, b: usize, c: usize| -> f64 {
+        ((points[b] - points[a]).cross(points[c] - points[a]) / 2f64).abs()
+    };
+    let mut prev: Vec<usize> = (0..n).map(|i| i.saturating_sub(1)).collect();
+    let mut next: Vec<usize> = (0..n).map(|i| (i + 1).min(n - 1)).collect();
+    let mut areas = vec![f64::INFINITY; n];
+    let mut heap = BinaryHeap::with_capacity(n);
+    for (i, area) in areas.iter_mut().enumerate().take(n - 1).skip(1) {
+        *area = triangle_area(i - 1, i, i + 1);
+        heap.push(EffectiveArea {
+            area: *area,
+            index: i,
+        });
+    }
+    let mut removed = vec![false; n];
+    let mut max_removed_area = 0f64;
+    while let Some(EffectiveArea { area, index }) = heap.pop() {
+        if removed[index] || area != areas[index] {
+            continue; // this entry is out of date
+        }
+        if area >= *min_area {
+            break;
+        }
+        removed[index] = true;
+        max_removed_area = max_removed_area.max(area);
+        let (p, q) = (prev[index], next[index]);
+        next[p] = q;
+        prev[q] = p;
+        // A vertex's effective area is never less than that of a vertex removed before it.
+        for i in [p, q] {
+            if i > 0 && i < n - 1 {
+                areas[i] = triangle_area(prev[i], i, next[i]).max(max_removed_area);
+                heap.push(EffectiveArea {
+                    area: areas[i],
+                    index: i,
+                });
+            }
+        }
+    }
+    (0..n).filter(|i| !removed[*i]).map(|i| points[i]).collect()
+}
+
+/// A heap entry for `simplify_visvalingam`, ordered so that the smallest area is popped first.
+struct EffectiveArea {
+    area: f64,
+    index: usize,
+}
+
+impl PartialEq for EffectiveArea {
+    fn eq(&self, other: &Self) -> bool {
+        self.area == other.area && self.index == other.index
+    }
+}
+
+impl Eq for EffectiveArea {}
+
+impl PartialOrd for EffectiveArea {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for EffectiveArea {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .area
+            .partial_cmp(&self.area)
+            .unwrap_or(Ordering::Equal)
+            .then_with(|| other.index.cmp(&self.index))
+    }
+}
+
 pub fn find_line_intersections(line1: &[Point2D], line2: &[Point2D]) -> Vec<LineSegment> {
     let mut ret: Vec<LineSegment> = vec![];
     let box1 = BoundingBox::from_points(&line1);
@@ -183,7 +270,7 @@ pub fn find_split_points_at_line_intersections(line1: &mut Polyline, line2: &mut
 
 #[cfg(test)]
 mod test {
-    use super::find_line_intersections;
+    use super::{find_line_intersections, simplify_rdp, simplify_visvalingam};
     use crate::structures::{LineSegment, Point2D};
 
     #[test]
@@ -248,4 +335,52 @@ mod test {
         )];
         assert_eq!(intersections, intersections_should_be);
     }
+
+    #[test]
+    fn test_simplify_rdp() {
+        let line = vec![
+            Point2D::new(0.0, 0.0),
+            Point2D::new(1.0, 0.1),
+            Point2D::new(2.0, -0.1),
+            Point2D::new(3.0, 5.0),
+            Point2D::new(4.0, 6.0),
+            Point2D::new(5.0, 7.0),
+        ];
+        let simplified = simplify_rdp(&line, &1.0);
+        assert_eq!(
+            simplified,
+            vec![
+                Point2D::new(0.0, 0.0),
+                Point2D::new(2.0, -0.1),
+                Point2D::new(3.0, 5.0),
+                Point2D::new(5.0, 7.0),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_simplify_visvalingam() {
+        let line = vec![
+            Point2D::new(0.0, 0.0),
+            Point2D::new(1.0, 0.1),
+            Point2D::new(2.0, 0.0),
+            Point2D::new(3.0, 4.0),
+            Point2D::new(4.0, 0.0),
+        ];
+        // The vertex at (1.0, 0.1) has an effective area of 0.1; the others are much larger.
+        let simplified = simplify_visvalingam(&line, &1.0);
+        assert_eq!(
+            simplified,
+            vec![
+                Point2D::new(0.0, 0.0),
+                Point2D::new(2.0, 0.0),
+                Point2D::new(3.0, 4.0),
+                Point2D::new(4.0, 0.0),
+            ]
+        );
+        // A large enough area removes all but the end points.
+        assert_eq!(simplify_visvalingam(&line, &100.0).len(), 2);
+        // The end points of a short line are always retained.
+        assert_eq!(simplify_visvalingam(&line[0..2], &100.0).len(), 2);
+    }
 }
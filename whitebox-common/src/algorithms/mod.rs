@@ -6,6 +6,7 @@ Last Modified: 25/09/2018
 License: MIT
 */
 // private sub-module defined in other files
+mod buffer;
 mod convex_hull;
 mod delaunay_triangulation;
 mod is_clockwise_order;
@@ -15,16 +16,22 @@ mod minimum_bounding_box;
 mod poly_area;
 mod poly_ops;
 mod poly_perimeter;
+mod polygon_boolean;
+mod polygon_overlay;
 mod smallest_enclosing_circle;
+mod variogram;
 
 // exports identifiers from private sub-modules in the current module namespace
+pub use self::buffer::{
+    buffer_point, buffer_polygon, buffer_polyline, buffer_polylines, BufferStyle, CapStyle, JoinStyle,
+};
 pub use self::convex_hull::convex_hull;
 pub use self::delaunay_triangulation::{triangulate, Triangulation};
 pub use self::delaunay_triangulation::EMPTY;
 pub use self::is_clockwise_order::is_clockwise_order;
 pub use self::line_ops::{
     do_polylines_intersect, find_line_intersections, find_split_points_at_line_intersections,
-    point_line_distance, simplify_rdp,
+    point_line_distance, simplify_rdp, simplify_visvalingam,
 };
 // pub use self::lzw::{lzw_decode, lzw_encode};
 pub use self::minimum_bounding_box::{minimum_bounding_box, MinimizationCriterion};
@@ -33,4 +40,14 @@ pub use self::poly_ops::{
     interior_point, point_in_poly, poly_in_poly, poly_is_convex, poly_overlaps_poly, winding_number,
 };
 pub use self::poly_perimeter::polygon_perimeter;
+pub use self::polygon_boolean::{
+    clip_polylines, group_rings, orient_rings, polygon_boolean, polygon_boolean_with_snap,
+    union_rings, BooleanOp,
+};
+pub use self::polygon_overlay::{
+    clip_polyline_features, OverlayFeature, OverlayOp, OverlayPiece, PolygonOverlay,
+};
 pub use self::smallest_enclosing_circle::smallest_enclosing_circle;
+pub use self::variogram::{
+    empirical_variogram, fit_variogram, VariogramLag, VariogramModel, VariogramModelType,
+};
@@ -0,0 +1,1063 @@
+/*
+This code is part of the WhiteboxTools geospatial analysis library.
+Authors: Dr. John Lindsay
+Created: 18/10/2026
+Last Modified: 18/10/2026
+License: MIT
+*/
+
+use crate::structures::Point2D;
+use std::collections::HashMap;
+use std::f64::consts::PI;
+
+// The overlay follows the approach of the Martinez-Rueda algorithm. The edges of both operands
+// are split wherever they meet, with nodes that are closer together than a snap tolerance
+// merged into one, so that the edges form a planar graph. Each edge of the graph is then
+// labelled with whether the areas on either side of it lie within the subject and within the
+// clip polygons, which is found from the winding number of each operand's rings, and the
+// edges that separate the area selected by the operation from the rest are linked into the
+// rings of the result. Edges shared by both operands, rings that touch themselves or each
+// other, and holes need no special treatment because every edge is labelled the same way.
+
+/// The set operations performed by `polygon_boolean`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BooleanOp {
+    /// The area within either operand.
+    Union,
+    /// The area within both operands.
+    Intersection,
+    /// The area within the subject but not the clip polygons.
+    Difference,
+    /// The area within exactly one of the operands.
+    SymmetricDifference,
+}
+
+impl BooleanOp {
+    fn contains(self, in_subject: bool, in_clip: bool) -> bool {
+        match self {
+            BooleanOp::Union => in_subject || in_clip,
+            BooleanOp::Intersection => in_subject && in_clip,
+            BooleanOp::Difference => in_subject && !in_clip,
+            BooleanOp::SymmetricDifference => in_subject != in_clip,
+        }
+    }
+}
+
+/// Performs a set operation on two groups of polygon rings, the subject and the clip polygons.
+///
+/// Rings follow the Shapefile convention: the interior of a clockwise ring is covered and the
+/// interior of a counter-clockwise ring (a hole) is uncovered. More exactly, a point is within
+/// an operand if its rings wind around it clockwise at least once on balance, so that
+/// overlapping polygons are merged, a hole removes area only from the polygon that it belongs
+/// to, and a counter-clockwise ring on its own has no effect. Rings may be open or closed,
+/// and may intersect or touch themselves and each other. Use `orient_rings` first if the
+/// orientation of the rings is not known to be correct.
+///
+/// The rings of the result are closed, clockwise for outer boundaries and counter-clockwise
+/// for holes. Polygons that touch at a single point are returned as separate rings. Use
+/// `group_rings` to gather the holes of the result with the outer rings that they belong to.
+pub fn polygon_boolean(
+    subject: &[Vec<Point2D>],
+    clip: &[Vec<Point2D>],
+    op: BooleanOp,
+) -> Vec<Vec<Point2D>> {
+    polygon_boolean_with_snap(subject, clip, op, 0f64)
+}
+
+/// Performs a set operation on two groups of polygon rings, as `polygon_boolean` does, but
+/// merges any vertices and edges that are within `snap_distance` of each other. Use this to
+/// join polygons whose shared boundaries do not quite coincide.
+pub fn polygon_boolean_with_snap(
+    subject: &[Vec<Point2D>],
+    clip: &[Vec<Point2D>],
+    op: BooleanOp,
+    snap_distance: f64,
+) -> Vec<Vec<Point2D>> {
+    let mut segments = vec![];
+    let mut operands = vec![];
+    for (operand, rings) in [subject, clip].iter().enumerate() {
+        for ring in rings.iter() {
+            let n = open_len(ring);
+            for i in 0..n {
+                let (a, b) = (ring[i], ring[(i + 1) % n]);
+                if a != b {
+                    segments.push((a, b));
+                    operands.push(operand);
+                }
+            }
+        }
+    }
+    if segments.is_empty() {
+        return vec![];
+    }
+    let tolerance = snap_tolerance(&segments).max(snap_distance);
+    let mut graph = Graph::node(&segments, &operands, tolerance);
+    graph.classify(op);
+    graph.trace_rings()
+}
+
+/// Merges a set of polygon rings into the boundary of the area that they cover. This is the
+/// union of the rings with an empty set of clip polygons; see `polygon_boolean`.
+pub fn union_rings(rings: &[Vec<Point2D>]) -> Vec<Vec<Point2D>> {
+    polygon_boolean(rings, &[], BooleanOp::Union)
+}
+
+/// Splits polylines where they cross the boundaries of a set of polygon rings, and returns the
+/// parts that lie inside the polygons if `keep_inside` is true, or outside of them otherwise.
+/// The rings are interpreted as they are by `polygon_boolean`. Parts of lines that run along a
+/// polygon boundary are treated as being inside the polygon.
+///
+/// Each part is returned with the index of the line that it came from, in the order of the
+/// input lines. Consecutive pieces of a line that are kept are joined into a single part.
+pub fn clip_polylines(
+    lines: &[Vec<Point2D>],
+    rings: &[Vec<Point2D>],
+    keep_inside: bool,
+) -> Vec<(usize, Vec<Point2D>)> {
+    let mut edges: Vec<(Point2D, Point2D, [i32; 2])> = vec![];
+    for ring in rings {
+        let n = open_len(ring);
+        for i in 0..n {
+            let (a, b) = (ring[i], ring[(i + 1) % n]);
+            if a != b {
+                edges.push((a, b, [1, 0]));
+            }
+        }
+    }
+    let mut segments: Vec<(Point2D, Point2D)> = edges.iter().map(|e| (e.0, e.1)).collect();
+    let num_edges = segments.len();
+    let mut segment_line = vec![];
+    for (l, line) in lines.iter().enumerate() {
+        for i in 1..line.len() {
+            segments.push((line[i - 1], line[i]));
+            segment_line.push(l);
+        }
+    }
+    if segments.len() == num_edges {
+        return vec![];
+    }
+    if edges.is_empty() {
+        return if keep_inside {
+            vec![]
+        } else {
+            lines
+                .iter()
+                .enumerate()
+                .filter(|(_, line)| line.len() > 1)
+                .map(|(l, line)| (l, line.clone()))
+                .collect()
+        };
+    }
+    let tolerance = snap_tolerance(&segments);
+
+    // Split the line segments where they meet the polygon edges.
+    let mut splits: Vec<Vec<(f64, Point2D)>> = segments[num_edges..]
+        .iter()
+        .map(|(a, b)| vec![(0f64, *a), (1f64, *b)])
+        .collect();
+    let index = SegmentGrid::new(&segments);
+    for (i, j) in index.candidate_pairs(&segments) {
+        if (i < num_edges) == (j < num_edges) {
+            continue;
+        }
+        let (edge, s) = if i < num_edges { (i, j) } else { (j, i) };
+        let (a, b) = segments[s];
+        let (c, d) = segments[edge];
+        for p in segment_intersections(a, b, c, d, tolerance) {
+            splits[s - num_edges].push((parameter(a, b, p), p));
+        }
+    }
+
+    let rows = StripIndex::new(&edges, false);
+    let mut parts: Vec<(usize, Vec<Point2D>)> = vec![];
+    let mut open = false;
+    for (s, points) in splits.iter_mut().enumerate() {
+        let line = segment_line[s];
+        if s > 0 && segment_line[s - 1] != line {
+            open = false;
+        }
+        points.sort_by(|p, q| p.0.partial_cmp(&q.0).unwrap_or(std::cmp::Ordering::Equal));
+        for k in 1..points.len() {
+            let (p, q) = (points[k - 1].1, points[k].1);
+            if p.distance(&q) <= tolerance {
+                continue;
+            }
+            let mid = Point2D::midpoint(&p, &q);
+            let inside = rows.on_boundary(&edges, mid, tolerance)
+                || rows.winding(&edges, mid, usize::MAX)[0] >= 1;
+            if inside == keep_inside {
+                if open {
+                    parts.last_mut().unwrap().1.push(q);
+                } else {
+                    parts.push((line, vec![p, q]));
+                    open = true;
+                }
+            } else {
+                open = false;
+            }
+        }
+    }
+    parts
+}
+
+/// Orients the rings of a polygon by how deeply they are nested, so that rings that are within
+/// an even number of the other rings are clockwise outer boundaries and the rest are
+/// counter-clockwise holes, whatever their original orientation.
+pub fn orient_rings(rings: &mut [Vec<Point2D>]) {
+    let boxes: Vec<(Point2D, Point2D)> = rings.iter().map(|r| bounds(r)).collect();
+    let mut reverse = vec![false; rings.len()];
+    for i in 0..rings.len() {
+        if open_len(&rings[i]) < 3 {
+            continue;
+        }
+        let p = Point2D::midpoint(&rings[i][0], &rings[i][1]);
+        let mut depth = 0;
+        for j in 0..rings.len() {
+            let (min, max) = boxes[j];
+            let in_box = p.x >= min.x && p.x <= max.x && p.y >= min.y && p.y <= max.y;
+            if j != i && in_box && contains(&rings[j], p) {
+                depth += 1;
+            }
+        }
+        let clockwise = signed_area(&rings[i]) < 0f64;
+        reverse[i] = clockwise != (depth % 2 == 0);
+    }
+    for (ring, reverse) in rings.iter_mut().zip(reverse) {
+        if reverse {
+            ring.reverse();
+        }
+    }
+}
+
+/// Gathers rings, such as those returned by `polygon_boolean`, into polygons. Each polygon is
+/// a clockwise outer ring followed by the counter-clockwise holes that lie within it, and each
+/// hole is placed in the smallest outer ring that contains it. Holes that are not within any
+/// outer ring are dropped.
+pub fn group_rings(rings: Vec<Vec<Point2D>>) -> Vec<Vec<Vec<Point2D>>> {
+    let (outer, holes): (Vec<Vec<Point2D>>, Vec<Vec<Point2D>>) =
+        rings.into_iter().partition(|r| signed_area(r) < 0f64);
+    let areas: Vec<f64> = outer.iter().map(|r| -signed_area(r)).collect();
+    let boxes: Vec<(Point2D, Point2D)> = outer.iter().map(|r| bounds(r)).collect();
+    let mut polygons: Vec<Vec<Vec<Point2D>>> = outer.into_iter().map(|r| vec![r]).collect();
+    for hole in holes {
+        if hole.len() < 2 {
+            continue;
+        }
+        let p = Point2D::midpoint(&hole[0], &hole[1]);
+        let mut owner: Option<usize> = None;
+        for i in 0..polygons.len() {
+            let (min, max) = boxes[i];
+            if p.x < min.x || p.x > max.x || p.y < min.y || p.y > max.y {
+                continue;
+            }
+            if owner.map(|o| areas[i] < areas[o]).unwrap_or(true) && contains(&polygons[i][0], p)
+            {
+                owner = Some(i);
+            }
+        }
+        if let Some(i) = owner {
+            polygons[i].push(hole);
+        }
+    }
+    polygons
+}
+
+/// The number of distinct vertices in a ring that may or may not repeat its first vertex.
+fn open_len(ring: &[Point2D]) -> usize {
+    if ring.len() > 1 && ring[0] == ring[ring.len() - 1] {
+        ring.len() - 1
+    } else {
+        ring.len()
+    }
+}
+
+/// Returns the lower-left and upper-right corners of the bounding box of a set of points.
+fn bounds(points: &[Point2D]) -> (Point2D, Point2D) {
+    let mut min = Point2D::new(f64::INFINITY, f64::INFINITY);
+    let mut max = Point2D::new(f64::NEG_INFINITY, f64::NEG_INFINITY);
+    for p in points {
+        min.x = min.x.min(p.x);
+        min.y = min.y.min(p.y);
+        max.x = max.x.max(p.x);
+        max.y = max.y.max(p.y);
+    }
+    (min, max)
+}
+
+/// Tests whether a point is within a ring, open or closed, by the even-odd rule.
+fn contains(ring: &[Point2D], p: Point2D) -> bool {
+    let n = open_len(ring);
+    let mut inside = false;
+    for i in 0..n {
+        let (a, b) = (ring[i], ring[(i + 1) % n]);
+        if (a.y <= p.y) != (b.y <= p.y) {
+            let x = a.x + (p.y - a.y) * (b.x - a.x) / (b.y - a.y);
+            if x > p.x {
+                inside = !inside;
+            }
+        }
+    }
+    inside
+}
+
+/// Vertices that are closer together than the snap tolerance are merged. It is chosen to be
+/// well above the rounding error of the coordinates but well below any meaningful distance.
+fn snap_tolerance(segments: &[(Point2D, Point2D)]) -> f64 {
+    let (mut min_x, mut min_y) = (f64::INFINITY, f64::INFINITY);
+    let (mut max_x, mut max_y) = (f64::NEG_INFINITY, f64::NEG_INFINITY);
+    for (a, b) in segments {
+        for p in [a, b] {
+            min_x = min_x.min(p.x);
+            min_y = min_y.min(p.y);
+            max_x = max_x.max(p.x);
+            max_y = max_y.max(p.y);
+        }
+    }
+    let extent = (max_x - min_x).max(max_y - min_y);
+    let magnitude = min_x.abs().max(min_y.abs()).max(max_x.abs()).max(max_y.abs());
+    (extent * 1e-9).max(magnitude * 1e-12).max(f64::MIN_POSITIVE)
+}
+
+/// A planar graph formed by splitting the input segments wherever they meet.
+struct Graph {
+    nodes: Vec<Point2D>,
+    /// Each edge joins two nodes, `(from, to)`, and carries the net number of times that the
+    /// rings of each operand traverse it in that direction.
+    edges: Vec<(usize, usize, [i32; 2])>,
+    /// Whether each edge is part of the result, and if so, in which direction.
+    kept: Vec<Option<(usize, usize)>>,
+    tolerance: f64,
+}
+
+impl Graph {
+    fn node(segments: &[(Point2D, Point2D)], operands: &[usize], tolerance: f64) -> Graph {
+        // Find the points at which each segment must be split.
+        let mut splits: Vec<Vec<(f64, Point2D)>> = segments
+            .iter()
+            .map(|(a, b)| vec![(0f64, *a), (1f64, *b)])
+            .collect();
+        let index = SegmentGrid::new(segments);
+        for (i, j) in index.candidate_pairs(segments) {
+            let (a, b) = segments[i];
+            let (c, d) = segments[j];
+            for p in segment_intersections(a, b, c, d, tolerance) {
+                splits[i].push((parameter(a, b, p), p));
+                splits[j].push((parameter(c, d, p), p));
+            }
+        }
+
+        let mut nodes = NodeSet::new(tolerance);
+        let mut counts: HashMap<(usize, usize), [i32; 2]> = HashMap::new();
+        for (s, points) in splits.iter_mut().enumerate() {
+            let operand = operands[s];
+            points.sort_by(|p, q| p.0.partial_cmp(&q.0).unwrap_or(std::cmp::Ordering::Equal));
+            let mut prev = nodes.insert(points[0].1);
+            for &(_, p) in points.iter().skip(1) {
+                let node = nodes.insert(p);
+                if node != prev {
+                    if prev < node {
+                        counts.entry((prev, node)).or_insert([0, 0])[operand] += 1;
+                    } else {
+                        counts.entry((node, prev)).or_insert([0, 0])[operand] -= 1;
+                    }
+                    prev = node;
+                }
+            }
+        }
+        let mut edges: Vec<(usize, usize, [i32; 2])> = counts
+            .into_iter()
+            .filter(|(_, count)| *count != [0, 0])
+            .map(|((from, to), count)| (from, to, count))
+            .collect();
+        edges.sort();
+        let num_edges = edges.len();
+        Graph {
+            nodes: nodes.points,
+            edges: edges,
+            kept: vec![None; num_edges],
+            tolerance: tolerance,
+        }
+    }
+
+    /// Keeps the edges that separate the area selected by the operation from the rest,
+    /// directed so that the selected area is on their right.
+    fn classify(&mut self, op: BooleanOp) {
+        let segments: Vec<(Point2D, Point2D, [i32; 2])> = self
+            .edges
+            .iter()
+            .map(|&(from, to, count)| (self.nodes[from], self.nodes[to], count))
+            .collect();
+        let rows = StripIndex::new(&segments, false);
+        let columns = StripIndex::new(&segments, true);
+        for (e, &(a, b, count)) in segments.iter().enumerate() {
+            let mid = Point2D::midpoint(&a, &b);
+            // The winding numbers are found on either side of the edge by casting a ray from
+            // its midpoint, across the edge's steeper axis, and excluding the edge itself.
+            let mut left = [0i32; 2];
+            let mut right = [0i32; 2];
+            if (b.y - a.y).abs() >= (b.x - a.x).abs() {
+                let w = rows.winding(&segments, mid, e);
+                for k in 0..2 {
+                    if b.y > a.y {
+                        left[k] = w[k] - count[k];
+                        right[k] = w[k];
+                    } else {
+                        left[k] = w[k];
+                        right[k] = w[k] + count[k];
+                    }
+                }
+            } else {
+                let w = columns.winding(&segments, mid, e);
+                for k in 0..2 {
+                    if b.x > a.x {
+                        left[k] = w[k];
+                        right[k] = w[k] + count[k];
+                    } else {
+                        left[k] = w[k] - count[k];
+                        right[k] = w[k];
+                    }
+                }
+            }
+            let (from, to, _) = self.edges[e];
+            let left_in = op.contains(left[0] >= 1, left[1] >= 1);
+            let right_in = op.contains(right[0] >= 1, right[1] >= 1);
+            self.kept[e] = match (left_in, right_in) {
+                (false, true) => Some((from, to)),
+                (true, false) => Some((to, from)),
+                _ => None,
+            };
+        }
+    }
+
+    /// Links the kept edges into rings, keeping the selected area on the right of each ring.
+    fn trace_rings(&self) -> Vec<Vec<Point2D>> {
+        let mut outgoing: Vec<Vec<usize>> = vec![vec![]; self.nodes.len()];
+        for (e, kept) in self.kept.iter().enumerate() {
+            if let Some((from, _)) = kept {
+                outgoing[*from].push(e);
+            }
+        }
+        let mut used = vec![false; self.edges.len()];
+        let mut rings = vec![];
+        for start in 0..self.edges.len() {
+            if used[start] || self.kept[start].is_none() {
+                continue;
+            }
+            let mut ring = vec![];
+            let mut e = start;
+            loop {
+                used[e] = true;
+                let (from, to) = self.kept[e].unwrap();
+                ring.push(self.nodes[from]);
+                // Take the sharpest right turn, which follows the boundary of the selected area
+                // that is on the right of this edge, even where boundaries touch at a node.
+                let back = self.nodes[from] - self.nodes[to];
+                let back_angle = back.y.atan2(back.x);
+                let mut next = None;
+                let mut min_turn = f64::INFINITY;
+                for &candidate in &outgoing[to] {
+                    let (_, end) = self.kept[candidate].unwrap();
+                    let v = self.nodes[end] - self.nodes[to];
+                    let mut turn = v.y.atan2(v.x) - back_angle;
+                    while turn <= 0f64 {
+                        turn += 2f64 * PI;
+                    }
+                    while turn > 2f64 * PI {
+                        turn -= 2f64 * PI;
+                    }
+                    if turn < min_turn {
+                        min_turn = turn;
+                        next = Some(candidate);
+                    }
+                }
+                match next {
+                    Some(n) if n != start && !used[n] => e = n,
+                    _ => break,
+                }
+            }
+            let ring = remove_collinear_vertices(ring, self.tolerance);
+            if ring.len() >= 3 && signed_area(&ring).abs() > self.tolerance * self.tolerance {
+                let mut ring = ring;
+                ring.push(ring[0]);
+                rings.push(ring);
+            }
+        }
+        rings
+    }
+}
+
+/// Returns twice the signed area of a ring, which is positive for counter-clockwise rings.
+pub(crate) fn signed_area(ring: &[Point2D]) -> f64 {
+    let n = open_len(ring);
+    let mut area = 0f64;
+    for i in 0..n {
+        let (a, b) = (ring[i], ring[(i + 1) % n]);
+        area += a.x * b.y - b.x * a.y;
+    }
+    area
+}
+
+/// Removes the vertices of an open ring that lie on a straight line between their neighbours.
+fn remove_collinear_vertices(mut ring: Vec<Point2D>, tolerance: f64) -> Vec<Point2D> {
+    let mut changed = true;
+    while changed && ring.len() >= 3 {
+        changed = false;
+        let mut kept: Vec<Point2D> = Vec::with_capacity(ring.len());
+        let n = ring.len();
+        for i in 0..n {
+            let prev = if kept.is_empty() { ring[n - 1] } else { kept[kept.len() - 1] };
+            let (p, next) = (ring[i], ring[(i + 1) % n]);
+            let base = next - prev;
+            let length = base.magnitude();
+            let offset = if length > 0f64 {
+                (p - prev).cross(base).abs() / length
+            } else {
+                0f64
+            };
+            if offset <= tolerance && (p - prev) * (next - p) >= 0f64 {
+                changed = true;
+            } else {
+                kept.push(p);
+            }
+        }
+        ring = kept;
+    }
+    ring
+}
+
+/// Returns the position of `p` along the segment from `a` to `b`, from 0 to 1.
+fn parameter(a: Point2D, b: Point2D, p: Point2D) -> f64 {
+    let r = b - a;
+    ((p - a) * r / (r * r)).clamp(0f64, 1f64)
+}
+
+/// Finds the points at which two segments meet: a crossing point, or the ends of their
+/// common part if they touch or overlap.
+fn segment_intersections(
+    a: Point2D,
+    b: Point2D,
+    c: Point2D,
+    d: Point2D,
+    tolerance: f64,
+) -> Vec<Point2D> {
+    // An end point that lies on the other segment is where the two meet. Testing for these
+    // first also handles overlapping and nearly parallel segments, whose crossing point would
+    // otherwise be poorly determined.
+    let mut points = vec![];
+    for (p, (e, f)) in [(a, (c, d)), (b, (c, d)), (c, (a, b)), (d, (a, b))] {
+        if distance_to_segment(p, e, f) <= tolerance {
+            points.push(p);
+        }
+    }
+    if !points.is_empty() {
+        return points;
+    }
+    let r = b - a;
+    let s = d - c;
+    let denom = r.cross(s);
+    if denom == 0f64 {
+        return points;
+    }
+    let t = (c - a).cross(s) / denom;
+    let u = (c - a).cross(r) / denom;
+    if t > 0f64 && t < 1f64 && u > 0f64 && u < 1f64 {
+        points.push(Point2D::new(a.x + t * r.x, a.y + t * r.y));
+    }
+    points
+}
+
+/// Returns the distance from a point to a segment.
+fn distance_to_segment(p: Point2D, a: Point2D, b: Point2D) -> f64 {
+    let r = b - a;
+    let length = r * r;
+    if length == 0f64 {
+        return p.distance(&a);
+    }
+    let t = ((p - a) * r / length).clamp(0f64, 1f64);
+    p.distance(&Point2D::new(a.x + t * r.x, a.y + t * r.y))
+}
+
+/// The nodes of the graph. A point within the snap tolerance of an existing node is merged
+/// with it.
+struct NodeSet {
+    points: Vec<Point2D>,
+    cells: HashMap<(i64, i64), Vec<usize>>,
+    tolerance: f64,
+}
+
+impl NodeSet {
+    fn new(tolerance: f64) -> NodeSet {
+        NodeSet {
+            points: vec![],
+            cells: HashMap::new(),
+            tolerance: tolerance,
+        }
+    }
+
+    fn insert(&mut self, p: Point2D) -> usize {
+        let (cx, cy) = (
+            (p.x / self.tolerance).floor() as i64,
+            (p.y / self.tolerance).floor() as i64,
+        );
+        for x in cx - 1..=cx + 1 {
+            for y in cy - 1..=cy + 1 {
+                if let Some(ids) = self.cells.get(&(x, y)) {
+                    for &id in ids {
+                        if self.points[id].distance(&p) <= self.tolerance {
+                            return id;
+                        }
+                    }
+                }
+            }
+        }
+        let id = self.points.len();
+        self.points.push(p);
+        self.cells.entry((cx, cy)).or_insert(vec![]).push(id);
+        id
+    }
+}
+
+/// A uniform grid of cells, each listing the segments whose bounding boxes overlap it, used
+/// to find the pairs of segments that may intersect.
+struct SegmentGrid {
+    cells: HashMap<(i64, i64), Vec<usize>>,
+    min_x: f64,
+    min_y: f64,
+    cell_size: f64,
+}
+
+impl SegmentGrid {
+    fn new(segments: &[(Point2D, Point2D)]) -> SegmentGrid {
+        let (mut min_x, mut min_y) = (f64::INFINITY, f64::INFINITY);
+        let (mut max_x, mut max_y) = (f64::NEG_INFINITY, f64::NEG_INFINITY);
+        let mut total_length = 0f64;
+        for (a, b) in segments {
+            min_x = min_x.min(a.x.min(b.x));
+            min_y = min_y.min(a.y.min(b.y));
+            max_x = max_x.max(a.x.max(b.x));
+            max_y = max_y.max(a.y.max(b.y));
+            total_length += a.distance(b);
+        }
+        let extent = (max_x - min_x).max(max_y - min_y);
+        let cell_size = (2f64 * total_length / segments.len() as f64)
+            .max(extent / 1024f64)
+            .max(f64::MIN_POSITIVE);
+        let mut grid = SegmentGrid {
+            cells: HashMap::new(),
+            min_x: min_x,
+            min_y: min_y,
+            cell_size: cell_size,
+        };
+        for (i, (a, b)) in segments.iter().enumerate() {
+            let (x0, y0) = grid.cell(a.x.min(b.x), a.y.min(b.y));
+            let (x1, y1) = grid.cell(a.x.max(b.x), a.y.max(b.y));
+            for x in x0..=x1 {
+                for y in y0..=y1 {
+                    grid.cells.entry((x, y)).or_insert(vec![]).push(i);
+                }
+            }
+        }
+        grid
+    }
+
+    fn cell(&self, x: f64, y: f64) -> (i64, i64) {
+        (
+            ((x - self.min_x) / self.cell_size).floor() as i64,
+            ((y - self.min_y) / self.cell_size).floor() as i64,
+        )
+    }
+
+    /// Lists each pair of segments with overlapping bounding boxes once.
+    fn candidate_pairs(&self, segments: &[(Point2D, Point2D)]) -> Vec<(usize, usize)> {
+        let mut pairs = vec![];
+        for (&cell, ids) in &self.cells {
+            for (k, &i) in ids.iter().enumerate() {
+                let (a, b) = segments[i];
+                for &j in &ids[k + 1..] {
+                    let (c, d) = segments[j];
+                    let min_x = a.x.min(b.x).max(c.x.min(d.x));
+                    let min_y = a.y.min(b.y).max(c.y.min(d.y));
+                    let max_x = a.x.max(b.x).min(c.x.max(d.x));
+                    let max_y = a.y.max(b.y).min(c.y.max(d.y));
+                    let slack = self.cell_size * 1e-9;
+                    if min_x > max_x + slack || min_y > max_y + slack {
+                        continue;
+                    }
+                    // The pair is only reported from the cell holding the lower-left corner
+                    // of the overlap of their bounding boxes.
+                    let (x0, y0) = self.cell(a.x.min(b.x), a.y.min(b.y));
+                    let (x1, y1) = self.cell(c.x.min(d.x), c.y.min(d.y));
+                    let (cx, cy) = self.cell(min_x.min(max_x), min_y.min(max_y));
+                    let owner = (cx.max(x0).max(x1), cy.max(y0).max(y1));
+                    if owner == cell {
+                        pairs.push((i.min(j), i.max(j)));
+                    }
+                }
+            }
+        }
+        pairs
+    }
+}
+
+/// Edges bucketed into strips, by their y range for rays cast in the +x direction, or by their
+/// x range for rays cast in the +y direction.
+struct StripIndex {
+    strips: Vec<Vec<usize>>,
+    min: f64,
+    strip_size: f64,
+    vertical: bool,
+}
+
+impl StripIndex {
+    fn new(segments: &[(Point2D, Point2D, [i32; 2])], vertical: bool) -> StripIndex {
+        let across = |p: &Point2D| if vertical { p.x } else { p.y };
+        let (mut min, mut max) = (f64::INFINITY, f64::NEG_INFINITY);
+        for (a, b, _) in segments {
+            min = min.min(across(a).min(across(b)));
+            max = max.max(across(a).max(across(b)));
+        }
+        let num_strips = ((segments.len() as f64).sqrt().ceil() as usize).clamp(1, 4096);
+        let strip_size = ((max - min) / num_strips as f64).max(f64::MIN_POSITIVE);
+        let mut index = StripIndex {
+            strips: vec![vec![]; num_strips],
+            min: min,
+            strip_size: strip_size,
+            vertical: vertical,
+        };
+        for (i, (a, b, _)) in segments.iter().enumerate() {
+            let s0 = index.strip(across(a).min(across(b)));
+            let s1 = index.strip(across(a).max(across(b)));
+            for s in s0..=s1 {
+                index.strips[s].push(i);
+            }
+        }
+        index
+    }
+
+    fn strip(&self, v: f64) -> usize {
+        (((v - self.min) / self.strip_size).floor().max(0f64) as usize).min(self.strips.len() - 1)
+    }
+
+    /// Returns the clockwise winding numbers of each operand's edges, other than `exclude`,
+    /// around `p`.
+    fn winding(
+        &self,
+        segments: &[(Point2D, Point2D, [i32; 2])],
+        p: Point2D,
+        exclude: usize,
+    ) -> [i32; 2] {
+        let mut w = [0i32; 2];
+        let strip = if self.vertical { self.strip(p.x) } else { self.strip(p.y) };
+        for &i in &self.strips[strip] {
+            if i == exclude {
+                continue;
+            }
+            let (a, b, count) = segments[i];
+            let sign = if self.vertical {
+                // A ray cast upwards crosses a clockwise ring's upper edge moving in +x.
+                if (a.x <= p.x) == (b.x <= p.x) || a.y + (p.x - a.x) * (b.y - a.y) / (b.x - a.x) <= p.y {
+                    continue;
+                }
+                if b.x > a.x { 1 } else { -1 }
+            } else {
+                // A ray cast to the right crosses a clockwise ring's right edge moving in -y.
+                if (a.y <= p.y) == (b.y <= p.y) || a.x + (p.y - a.y) * (b.x - a.x) / (b.y - a.y) <= p.x {
+                    continue;
+                }
+                if b.y < a.y { 1 } else { -1 }
+            };
+            w[0] += sign * count[0];
+            w[1] += sign * count[1];
+        }
+        w
+    }
+
+    /// Tests whether `p` is within `tolerance` of any of the edges.
+    fn on_boundary(
+        &self,
+        segments: &[(Point2D, Point2D, [i32; 2])],
+        p: Point2D,
+        tolerance: f64,
+    ) -> bool {
+        let v = if self.vertical { p.x } else { p.y };
+        (self.strip(v - tolerance)..=self.strip(v + tolerance)).any(|s| {
+            self.strips[s]
+                .iter()
+                .any(|&i| distance_to_segment(p, segments[i].0, segments[i].1) <= tolerance)
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{
+        clip_polylines, group_rings, orient_rings, polygon_boolean, polygon_boolean_with_snap,
+        signed_area, union_rings, BooleanOp,
+    };
+    use crate::structures::Point2D;
+
+    fn square(x: f64, y: f64, size: f64) -> Vec<Point2D> {
+        // clockwise
+        vec![
+            Point2D::new(x, y),
+            Point2D::new(x, y + size),
+            Point2D::new(x + size, y + size),
+            Point2D::new(x + size, y),
+            Point2D::new(x, y),
+        ]
+    }
+
+    fn hole(x: f64, y: f64, size: f64) -> Vec<Point2D> {
+        let mut ring = square(x, y, size);
+        ring.reverse();
+        ring
+    }
+
+    fn area(rings: &[Vec<Point2D>]) -> f64 {
+        -rings.iter().map(|r| signed_area(r)).sum::<f64>() / 2f64
+    }
+
+    fn areas(subject: &[Vec<Point2D>], clip: &[Vec<Point2D>]) -> [f64; 4] {
+        [
+            BooleanOp::Union,
+            BooleanOp::Intersection,
+            BooleanOp::Difference,
+            BooleanOp::SymmetricDifference,
+        ]
+        .map(|op| area(&polygon_boolean(subject, clip, op)))
+    }
+
+    fn assert_areas(actual: [f64; 4], expected: [f64; 4]) {
+        for k in 0..4 {
+            assert!(
+                (actual[k] - expected[k]).abs() < 1e-9,
+                "{:?} != {:?}",
+                actual,
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn test_overlapping_squares() {
+        let rings = union_rings(&[square(0.0, 0.0, 2.0), square(1.0, 1.0, 2.0)]);
+        assert_eq!(rings.len(), 1);
+        assert!((area(&rings) - 7.0).abs() < 1e-9);
+        assert!(signed_area(&rings[0]) < 0.0);
+        let a = [square(0.0, 0.0, 2.0)];
+        let b = [square(1.0, 1.0, 2.0)];
+        assert_areas(areas(&a, &b), [7.0, 1.0, 3.0, 6.0]);
+    }
+
+    #[test]
+    fn test_squares_touching_at_a_corner() {
+        let rings = union_rings(&[square(0.0, 0.0, 1.0), square(1.0, 1.0, 1.0)]);
+        assert_eq!(rings.len(), 2);
+        assert!((area(&rings) - 2.0).abs() < 1e-9);
+        let a = [square(0.0, 0.0, 1.0)];
+        let b = [square(1.0, 1.0, 1.0)];
+        assert_areas(areas(&a, &b), [2.0, 0.0, 1.0, 2.0]);
+    }
+
+    #[test]
+    fn test_shared_edge_is_dissolved() {
+        let rings = union_rings(&[square(0.0, 0.0, 1.0), square(1.0, 0.0, 1.0)]);
+        assert_eq!(rings.len(), 1);
+        // The collinear vertices at the ends of the shared edge are removed.
+        assert_eq!(rings[0].len(), 5);
+        assert!((area(&rings) - 2.0).abs() < 1e-9);
+        let a = [square(0.0, 0.0, 1.0)];
+        let b = [square(1.0, 0.0, 1.0)];
+        assert_areas(areas(&a, &b), [2.0, 0.0, 1.0, 2.0]);
+        assert!(polygon_boolean(&a, &b, BooleanOp::Intersection).is_empty());
+    }
+
+    #[test]
+    fn test_partly_shared_edge() {
+        // The edges overlap along part of their length, and a vertex of each lies on an edge
+        // of the other.
+        let a = [square(0.0, 0.0, 2.0)];
+        let b = [square(2.0, 1.0, 2.0)];
+        assert_areas(areas(&a, &b), [8.0, 0.0, 4.0, 8.0]);
+        assert_eq!(polygon_boolean(&a, &b, BooleanOp::Union).len(), 1);
+    }
+
+    #[test]
+    fn test_identical_polygons() {
+        let a = [square(0.0, 0.0, 2.0)];
+        assert_areas(areas(&a, &a), [4.0, 4.0, 0.0, 0.0]);
+        assert_eq!(polygon_boolean(&a, &a, BooleanOp::Intersection)[0].len(), 5);
+        assert!(polygon_boolean(&a, &a, BooleanOp::Difference).is_empty());
+    }
+
+    #[test]
+    fn test_empty_operand() {
+        let a = [square(0.0, 0.0, 2.0)];
+        assert_areas(areas(&a, &[]), [4.0, 0.0, 4.0, 4.0]);
+        assert_areas(areas(&[], &a), [4.0, 0.0, 0.0, 4.0]);
+        assert!(polygon_boolean(&[], &[], BooleanOp::Union).is_empty());
+    }
+
+    #[test]
+    fn test_hole() {
+        let rings = union_rings(&[square(0.0, 0.0, 3.0), hole(1.0, 1.0, 1.0)]);
+        assert_eq!(rings.len(), 2);
+        assert!((area(&rings) - 8.0).abs() < 1e-9);
+        // A hole is filled by another polygon that covers it, but not on its own.
+        let rings = union_rings(&[
+            square(0.0, 0.0, 3.0),
+            hole(1.0, 1.0, 1.0),
+            square(1.0, 1.0, 1.0),
+        ]);
+        assert_eq!(rings.len(), 1);
+        assert!(union_rings(&[hole(1.0, 1.0, 1.0)]).is_empty());
+    }
+
+    #[test]
+    fn test_clip_within_a_hole() {
+        let a = [square(0.0, 0.0, 4.0), hole(1.0, 1.0, 2.0)];
+        let b = [square(1.5, 1.5, 1.0)];
+        assert_areas(areas(&a, &b), [13.0, 0.0, 12.0, 13.0]);
+        // The clip polygon overlaps the edge of the hole.
+        let b = [square(2.0, 2.0, 2.0)];
+        assert_areas(areas(&a, &b), [13.0, 3.0, 9.0, 10.0]);
+    }
+
+    #[test]
+    fn test_hole_sharing_the_outer_boundary() {
+        // A hole whose edge runs along part of the outer ring.
+        let a = [square(0.0, 0.0, 4.0), hole(0.0, 1.0, 2.0)];
+        let b = [square(0.0, 0.0, 4.0)];
+        assert_areas(areas(&a, &b), [16.0, 12.0, 0.0, 4.0]);
+    }
+
+    #[test]
+    fn test_self_touching_ring() {
+        // A single ring that touches itself at (2, 2), enclosing an inverted hole.
+        let ring = vec![
+            Point2D::new(0.0, 0.0),
+            Point2D::new(0.0, 4.0),
+            Point2D::new(4.0, 4.0),
+            Point2D::new(4.0, 0.0),
+            Point2D::new(2.0, 0.0),
+            Point2D::new(2.0, 2.0),
+            Point2D::new(1.0, 1.0),
+            Point2D::new(3.0, 1.0),
+            Point2D::new(2.0, 2.0),
+            Point2D::new(2.0, 0.0),
+            Point2D::new(0.0, 0.0),
+        ];
+        let a = [ring];
+        let rings = union_rings(&a);
+        assert!((area(&rings) - 15.0).abs() < 1e-9);
+        let b = [square(0.0, 0.0, 4.0)];
+        assert_areas(areas(&a, &b), [16.0, 15.0, 0.0, 1.0]);
+    }
+
+    #[test]
+    fn test_self_intersecting_ring() {
+        // A bow tie, with one clockwise and one counter-clockwise lobe.
+        let ring = vec![
+            Point2D::new(0.0, 0.0),
+            Point2D::new(0.0, 2.0),
+            Point2D::new(2.0, 0.0),
+            Point2D::new(2.0, 2.0),
+            Point2D::new(0.0, 0.0),
+        ];
+        let rings = union_rings(&[ring]);
+        assert_eq!(rings.len(), 1);
+        assert!((area(&rings) - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_difference_splits_polygon() {
+        let a = [square(0.0, 0.0, 3.0)];
+        let b = [vec![
+            Point2D::new(1.0, -1.0),
+            Point2D::new(1.0, 4.0),
+            Point2D::new(2.0, 4.0),
+            Point2D::new(2.0, -1.0),
+        ]];
+        let rings = polygon_boolean(&a, &b, BooleanOp::Difference);
+        assert_eq!(rings.len(), 2);
+        assert!((area(&rings) - 6.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_nearly_coincident_vertices_are_snapped() {
+        // The shared edge is out of line by far less than the snap tolerance.
+        let a = [square(500000.0, 4800000.0, 10.0)];
+        let b = [vec![
+            Point2D::new(500010.0 + 1e-9, 4800000.0),
+            Point2D::new(500010.0 - 1e-9, 4800010.0),
+            Point2D::new(500020.0, 4800010.0),
+            Point2D::new(500020.0, 4800000.0),
+        ]];
+        let rings = polygon_boolean(&a, &b, BooleanOp::Union);
+        assert_eq!(rings.len(), 1);
+        assert!((area(&rings) - 200.0).abs() < 1e-6);
+        assert!(polygon_boolean(&a, &b, BooleanOp::Intersection).is_empty());
+        // A gap that is wider than the default tolerance is closed by a larger snap distance.
+        let b = [square(500010.001, 4800000.0, 10.0)];
+        assert_eq!(polygon_boolean(&a, &b, BooleanOp::Union).len(), 2);
+        let rings = polygon_boolean_with_snap(&a, &b, BooleanOp::Union, 0.01);
+        assert_eq!(rings.len(), 1);
+    }
+
+    #[test]
+    fn test_nearly_collinear_edges() {
+        // Edges of different rings that follow the same line, such as the pieces of polygons
+        // cut by an earlier overlay, are not exactly collinear in floating point.
+        let top = Point2D::new(500136.9721173073, 4800300.411978219);
+        let p = Point2D::new(500136.7627748695, 4800299.665094502);
+        let q = Point2D::new(500134.0791861597, 4800290.090692247);
+        let bottom = Point2D::new(500134.02690296114, 4800289.904158307);
+        let offset = |v: Point2D, dx: f64| Point2D::new(v.x + dx, v.y);
+        let mut rings = vec![
+            vec![p, q, offset(q, -4.0), offset(p, -4.0)],
+            vec![q, p, offset(p, 4.0), offset(q, 4.0)],
+            vec![top, p, offset(p, -1.0), offset(top, -1.0)],
+            vec![q, bottom, offset(bottom, 4.0), offset(q, 4.0)],
+        ];
+        for ring in rings.iter_mut() {
+            if signed_area(ring) > 0.0 {
+                ring.reverse();
+            }
+        }
+        let total = area(&rings);
+        let union = union_rings(&rings);
+        assert_eq!(union.len(), 1);
+        assert!((area(&union) - total).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_clip_polylines() {
+        let rings = [square(0.0, 0.0, 2.0)];
+        let lines = vec![
+            vec![Point2D::new(-1.0, 1.0), Point2D::new(3.0, 1.0)],
+            // This line runs along the boundary and then leaves it.
+            vec![Point2D::new(0.0, 0.0), Point2D::new(0.0, 2.0), Point2D::new(-1.0, 3.0)],
+        ];
+        let inside = clip_polylines(&lines, &rings, true);
+        assert_eq!(inside.len(), 2);
+        assert_eq!(inside[0], (0, vec![Point2D::new(0.0, 1.0), Point2D::new(2.0, 1.0)]));
+        assert_eq!(inside[1], (1, vec![Point2D::new(0.0, 0.0), Point2D::new(0.0, 2.0)]));
+        let outside = clip_polylines(&lines, &rings, false);
+        assert_eq!(outside.len(), 3);
+        assert_eq!(outside[2], (1, vec![Point2D::new(0.0, 2.0), Point2D::new(-1.0, 3.0)]));
+    }
+
+    #[test]
+    fn test_orient_and_group_rings() {
+        // Counter-clockwise outer rings and a clockwise hole, as in some data sets.
+        let mut rings = vec![hole(0.0, 0.0, 4.0), square(1.0, 1.0, 2.0), hole(10.0, 0.0, 1.0)];
+        orient_rings(&mut rings);
+        assert!(signed_area(&rings[0]) < 0.0);
+        assert!(signed_area(&rings[1]) > 0.0);
+        assert!(signed_area(&rings[2]) < 0.0);
+        let polygons = group_rings(union_rings(&rings));
+        assert_eq!(polygons.len(), 2);
+        let holes: usize = polygons.iter().map(|p| p.len() - 1).sum();
+        assert_eq!(holes, 1);
+    }
+}
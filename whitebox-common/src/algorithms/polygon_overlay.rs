@@ -0,0 +1,267 @@
+/*
+This code is part of the WhiteboxTools geospatial analysis library.
+Authors: Dr. John Lindsay
+Created: 18/10/2026
+Last Modified: 18/10/2026
+License: MIT
+*/
+
+use super::polygon_boolean::{clip_polylines, polygon_boolean_with_snap, BooleanOp};
+use crate::structures::{BoundingBox, Point2D};
+
+// The overlay tools (Clip, Erase, Difference, Intersect, Union, and SymmetricalDifference)
+// combine the features of an input layer with those of an overlay layer. Each feature is
+// only compared with the features of the other layer whose bounding boxes it overlaps.
+//
+// Measure and z data are not carried through an overlay operation. Regardless of the input
+// dimension, the output of an overlay is XY only.
+
+/// A polygon feature of an overlay layer: its bounding box and rings, as returned by
+/// `ShapefileGeometry::get_polygon_rings`.
+pub type OverlayFeature = (BoundingBox, Vec<Vec<Point2D>>);
+
+/// How the features of the input and overlay layers are combined by `PolygonOverlay`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum OverlayOp {
+    /// The part of each input feature that lies within the overlay layer.
+    Clip,
+    /// The part of each input feature that lies outside of the overlay layer.
+    Erase,
+    /// The shared area of each overlapping pair of input and overlay features.
+    Intersect,
+    /// The pieces of `Intersect`, followed by the part of each feature, of either layer,
+    /// that lies outside of the other layer.
+    Union,
+    /// The part of each feature, of either layer, that lies outside of the other layer.
+    SymmetricalDifference,
+}
+
+/// A polygon produced by an overlay, with the input and overlay features that it lies within.
+/// The pieces of `OverlayOp::Clip` are within an input feature and the overlay layer as a
+/// whole, and so have no overlay feature.
+#[derive(Clone, Debug, PartialEq)]
+pub struct OverlayPiece {
+    pub rings: Vec<Vec<Point2D>>,
+    pub input: Option<usize>,
+    pub overlay: Option<usize>,
+}
+
+/// Overlays two layers of polygon features, one feature at a time. Each item holds the
+/// pieces of one feature, which is a feature of the input layer, or, for `OverlayOp::Union`
+/// and `OverlayOp::SymmetricalDifference`, a feature of the input layer followed by those of
+/// the overlay layer. Tools use the items to check for cancellation and report progress as
+/// they go. Vertices within `snap_distance` of each other are merged; see
+/// `polygon_boolean_with_snap`.
+pub struct PolygonOverlay<'a> {
+    input: &'a [OverlayFeature],
+    overlay: &'a [OverlayFeature],
+    op: OverlayOp,
+    snap_distance: f64,
+    step: usize,
+}
+
+impl<'a> PolygonOverlay<'a> {
+    pub fn new(
+        input: &'a [OverlayFeature],
+        overlay: &'a [OverlayFeature],
+        op: OverlayOp,
+        snap_distance: f64,
+    ) -> PolygonOverlay<'a> {
+        PolygonOverlay {
+            input,
+            overlay,
+            op,
+            snap_distance,
+            step: 0,
+        }
+    }
+
+    /// The number of items, i.e. of features whose pieces are found.
+    pub fn len(&self) -> usize {
+        match self.op {
+            OverlayOp::Union | OverlayOp::SymmetricalDifference => {
+                self.input.len() + self.overlay.len()
+            }
+            _ => self.input.len(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<'a> Iterator for PolygonOverlay<'a> {
+    type Item = Vec<OverlayPiece>;
+
+    fn next(&mut self) -> Option<Vec<OverlayPiece>> {
+        if self.step >= self.len() {
+            return None;
+        }
+        let n = self.step;
+        self.step += 1;
+        let from_input = n < self.input.len();
+        let (feature_num, (bb, rings), others) = if from_input {
+            (n, &self.input[n], self.overlay)
+        } else {
+            let feature_num = n - self.input.len();
+            (feature_num, &self.overlay[feature_num], self.input)
+        };
+        let piece = |rings: Vec<Vec<Point2D>>, other_num: Option<usize>| {
+            if from_input {
+                OverlayPiece {
+                    rings,
+                    input: Some(feature_num),
+                    overlay: other_num,
+                }
+            } else {
+                OverlayPiece {
+                    rings,
+                    input: other_num,
+                    overlay: Some(feature_num),
+                }
+            }
+        };
+
+        let mut pieces = vec![];
+        let mut other_rings: Vec<Vec<Point2D>> = vec![];
+        for (other_num, (other_bb, other)) in others.iter().enumerate() {
+            if !bb.overlaps(*other_bb) {
+                continue;
+            }
+            if from_input && (self.op == OverlayOp::Intersect || self.op == OverlayOp::Union) {
+                let shared = polygon_boolean_with_snap(
+                    rings,
+                    other,
+                    BooleanOp::Intersection,
+                    self.snap_distance,
+                );
+                if !shared.is_empty() {
+                    pieces.push(piece(shared, Some(other_num)));
+                }
+            }
+            if self.op != OverlayOp::Intersect {
+                other_rings.extend(other.iter().cloned());
+            }
+        }
+
+        let remainder_op = match self.op {
+            OverlayOp::Clip if !other_rings.is_empty() => Some(BooleanOp::Intersection),
+            OverlayOp::Erase | OverlayOp::Union | OverlayOp::SymmetricalDifference => {
+                Some(BooleanOp::Difference)
+            }
+            _ => None,
+        };
+        if let Some(op) = remainder_op {
+            let remainder = polygon_boolean_with_snap(rings, &other_rings, op, self.snap_distance);
+            if !remainder.is_empty() {
+                pieces.push(piece(remainder, None));
+            }
+        }
+        Some(pieces)
+    }
+}
+
+/// Clips polyline features, each a list of parts, by a set of polygon rings, as
+/// `clip_polylines` does. Returns the index of each feature that has any parts left, in order,
+/// with its remaining parts.
+pub fn clip_polyline_features(
+    features: &[Vec<Vec<Point2D>>],
+    rings: &[Vec<Point2D>],
+    keep_inside: bool,
+) -> Vec<(usize, Vec<Vec<Point2D>>)> {
+    let mut lines: Vec<Vec<Point2D>> = vec![];
+    let mut line_feature: Vec<usize> = vec![];
+    for (feature_num, parts) in features.iter().enumerate() {
+        for part in parts {
+            lines.push(part.clone());
+            line_feature.push(feature_num);
+        }
+    }
+    // The parts are in the order of the input lines, so those of each feature are together.
+    let mut clipped: Vec<(usize, Vec<Vec<Point2D>>)> = vec![];
+    for (line_num, part) in clip_polylines(&lines, rings, keep_inside) {
+        let feature_num = line_feature[line_num];
+        match clipped.last_mut() {
+            Some((last, parts)) if *last == feature_num => parts.push(part),
+            _ => clipped.push((feature_num, vec![part])),
+        }
+    }
+    clipped
+}
+
+#[cfg(test)]
+mod test {
+    use super::{clip_polyline_features, OverlayFeature, OverlayOp, PolygonOverlay};
+    use crate::structures::{BoundingBox, Point2D};
+
+    fn square(x: f64, y: f64, size: f64) -> OverlayFeature {
+        // clockwise
+        let ring = vec![
+            Point2D::new(x, y),
+            Point2D::new(x, y + size),
+            Point2D::new(x + size, y + size),
+            Point2D::new(x + size, y),
+            Point2D::new(x, y),
+        ];
+        (BoundingBox::new(x, x + size, y, y + size), vec![ring])
+    }
+
+    fn sources(op: OverlayOp) -> Vec<Vec<(Option<usize>, Option<usize>)>> {
+        let input = vec![square(0.0, 0.0, 2.0), square(10.0, 10.0, 1.0)];
+        let overlay = vec![square(1.0, 1.0, 2.0)];
+        PolygonOverlay::new(&input, &overlay, op, 0.0)
+            .map(|pieces| pieces.iter().map(|p| (p.input, p.overlay)).collect())
+            .collect()
+    }
+
+    #[test]
+    fn test_polygon_overlay() {
+        assert_eq!(
+            sources(OverlayOp::Clip),
+            vec![vec![(Some(0), None)], vec![]]
+        );
+        assert_eq!(
+            sources(OverlayOp::Erase),
+            vec![vec![(Some(0), None)], vec![(Some(1), None)]]
+        );
+        assert_eq!(
+            sources(OverlayOp::Intersect),
+            vec![vec![(Some(0), Some(0))], vec![]]
+        );
+        assert_eq!(
+            sources(OverlayOp::Union),
+            vec![
+                vec![(Some(0), Some(0)), (Some(0), None)],
+                vec![(Some(1), None)],
+                vec![(None, Some(0))]
+            ]
+        );
+        assert_eq!(
+            sources(OverlayOp::SymmetricalDifference),
+            vec![vec![(Some(0), None)], vec![(Some(1), None)], vec![(None, Some(0))]]
+        );
+    }
+
+    #[test]
+    fn test_clip_polyline_features() {
+        let (_, rings) = square(0.0, 0.0, 2.0);
+        let features = vec![
+            vec![vec![Point2D::new(-1.0, 1.0), Point2D::new(3.0, 1.0)]],
+            vec![vec![Point2D::new(5.0, 5.0), Point2D::new(6.0, 6.0)]],
+            vec![
+                vec![Point2D::new(1.0, -1.0), Point2D::new(1.0, 3.0)],
+                vec![Point2D::new(0.5, 0.5), Point2D::new(1.5, 0.5)],
+            ],
+        ];
+        let clipped = clip_polyline_features(&features, &rings, true);
+        assert_eq!(clipped.len(), 2);
+        assert_eq!(clipped[0].0, 0);
+        assert_eq!(clipped[0].1.len(), 1);
+        assert_eq!(clipped[1].0, 2);
+        assert_eq!(clipped[1].1.len(), 2);
+        let erased = clip_polyline_features(&features, &rings, false);
+        assert_eq!(erased.iter().map(|f| f.0).collect::<Vec<_>>(), vec![0, 1, 2]);
+        assert_eq!(erased[0].1.len(), 2);
+    }
+}
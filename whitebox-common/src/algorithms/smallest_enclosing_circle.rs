@@ -23,7 +23,7 @@ pub fn smallest_enclosing_circle(points: &[Point2D]) -> Circle {
 
     let mut rng = thread_rng();
     for i in (1..shuffled.len()).rev() {
-        let j = rng.gen_range(0, i + 1);
+        let j = rng.gen_range(0..i + 1);
         let temp = shuffled[i];
         shuffled[i] = shuffled[j];
         shuffled[j] = temp;
@@ -0,0 +1,346 @@
+/*
+This code is part of the WhiteboxTools geospatial analysis library.
+Authors: Dr. John Lindsay
+Created: 18/10/2026
+Last Modified: 18/10/2026
+License: MIT
+*/
+use crate::structures::Point2D;
+use std::f64;
+use std::fmt;
+
+/// A lag class of an empirical variogram.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct VariogramLag {
+    /// The mean distance between the pairs of points in the lag class.
+    pub distance: f64,
+    /// The semivariance, i.e. half of the mean squared difference, of the pairs' values.
+    pub semivariance: f64,
+    pub num_pairs: usize,
+}
+
+/// Calculates the empirical (Matheron) variogram of a set of values, measured at points, using
+/// `num_lags` lag classes of width `lag_size`. Lag classes without any pairs of points are
+/// omitted.
+pub fn empirical_variogram(
+    points: &[Point2D],
+    values: &[f64],
+    lag_size: f64,
+    num_lags: usize,
+) -> Vec<VariogramLag> {
+    let mut sum_distances = vec![0f64; num_lags];
+    let mut sum_squares = vec![0f64; num_lags];
+    let mut num_pairs = vec![0usize; num_lags];
+    let max_distance = lag_size * num_lags as f64;
+    for i in 0..points.len() {
+        for j in i + 1..points.len() {
+            let d = points[i].distance(&points[j]);
+            if d < max_distance {
+                let k = ((d / lag_size) as usize).min(num_lags - 1);
+                sum_distances[k] += d;
+                sum_squares[k] += (values[i] - values[j]) * (values[i] - values[j]);
+                num_pairs[k] += 1;
+            }
+        }
+    }
+    (0..num_lags)
+        .filter(|k| num_pairs[*k] > 0)
+        .map(|k| VariogramLag {
+            distance: sum_distances[k] / num_pairs[k] as f64,
+            semivariance: sum_squares[k] / (2f64 * num_pairs[k] as f64),
+            num_pairs: num_pairs[k],
+        })
+        .collect()
+}
+
+/// The types of variogram models.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum VariogramModelType {
+    Spherical,
+    Exponential,
+    Gaussian,
+    /// The Matérn model, with a smoothness of 0.5, 1.5, or 2.5, for which it has closed forms.
+    /// A smoothness of 0.5 gives the exponential model.
+    Matern(f64),
+    /// A pure nugget model, i.e. no spatial correlation.
+    Nugget,
+}
+
+impl VariogramModelType {
+    /// The model's semivariance at a distance of `u` ranges, for a unit partial sill and no
+    /// nugget.
+    fn shape(self, u: f64) -> f64 {
+        match self {
+            VariogramModelType::Spherical => {
+                if u < 1f64 {
+                    1.5 * u - 0.5 * u * u * u
+                } else {
+                    1f64
+                }
+            }
+            VariogramModelType::Exponential => 1f64 - (-u).exp(),
+            VariogramModelType::Gaussian => 1f64 - (-u * u).exp(),
+            VariogramModelType::Matern(smoothness) => {
+                let polynomial = if smoothness < 1f64 {
+                    1f64
+                } else if smoothness < 2f64 {
+                    1f64 + u
+                } else {
+                    1f64 + u + u * u / 3f64
+                };
+                1f64 - polynomial * (-u).exp()
+            }
+            VariogramModelType::Nugget => 0f64,
+        }
+    }
+}
+
+impl fmt::Display for VariogramModelType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            VariogramModelType::Spherical => write!(f, "spherical"),
+            VariogramModelType::Exponential => write!(f, "exponential"),
+            VariogramModelType::Gaussian => write!(f, "gaussian"),
+            VariogramModelType::Matern(smoothness) => {
+                write!(f, "matern (smoothness {})", smoothness)
+            }
+            VariogramModelType::Nugget => write!(f, "nugget"),
+        }
+    }
+}
+
+/// A variogram model, made of a nugget and a structured component with a partial sill and
+/// range. For the exponential, Gaussian, and Matérn models, the range is a scale parameter, and
+/// the semivariance only approaches the sill asymptotically.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct VariogramModel {
+    pub model_type: VariogramModelType,
+    pub nugget: f64,
+    pub partial_sill: f64,
+    pub range: f64,
+}
+
+impl VariogramModel {
+    /// The semivariance at a distance `h`, which is zero at a distance of zero.
+    pub fn semivariance(&self, h: f64) -> f64 {
+        if h <= 0f64 {
+            return 0f64;
+        }
+        if self.model_type == VariogramModelType::Nugget || self.range <= 0f64 {
+            return self.nugget + self.partial_sill;
+        }
+        self.nugget + self.partial_sill * self.model_type.shape(h / self.range)
+    }
+
+    pub fn sill(&self) -> f64 {
+        self.nugget + self.partial_sill
+    }
+
+    /// The covariance at a distance `h`, i.e. the sill less the semivariance.
+    pub fn covariance(&self, h: f64) -> f64 {
+        self.sill() - self.semivariance(h)
+    }
+}
+
+/// Fits a variogram model to an empirical variogram by weighted least squares, weighting each lag
+/// by its number of pairs divided by its squared distance. Returns the model and its weighted sum
+/// of squared residuals, or `None` if there are no lags.
+pub fn fit_variogram(
+    lags: &[VariogramLag],
+    model_type: VariogramModelType,
+) -> Option<(VariogramModel, f64)> {
+    let lags: Vec<VariogramLag> = lags.iter().filter(|l| l.distance > 0f64).copied().collect();
+    if lags.is_empty() {
+        return None;
+    }
+    let weights: Vec<f64> = lags
+        .iter()
+        .map(|l| l.num_pairs as f64 / (l.distance * l.distance))
+        .collect();
+
+    // The nugget and partial sill are linear parameters, which are solved for directly for a
+    // given range, and the range is found by a search.
+    let fit_sills = |range: f64| -> (VariogramModel, f64) {
+        let shapes: Vec<f64> = lags
+            .iter()
+            .map(|l| {
+                if model_type == VariogramModelType::Nugget {
+                    0f64
+                } else {
+                    model_type.shape(l.distance / range)
+                }
+            })
+            .collect();
+        let sse = |nugget: f64, partial_sill: f64| -> f64 {
+            (0..lags.len())
+                .map(|j| {
+                    let r = lags[j].semivariance - nugget - partial_sill * shapes[j];
+                    weights[j] * r * r
+                })
+                .sum()
+        };
+        let (mut sw, mut sf, mut sff, mut sg, mut sfg) = (0f64, 0f64, 0f64, 0f64, 0f64);
+        for j in 0..lags.len() {
+            let (w, f, g) = (weights[j], shapes[j], lags[j].semivariance);
+            sw += w;
+            sf += w * f;
+            sff += w * f * f;
+            sg += w * g;
+            sfg += w * f * g;
+        }
+        // The candidates are the unconstrained solution and the solutions with either the
+        // nugget or the partial sill fixed at zero.
+        let mut candidates = vec![(sg / sw, 0f64)];
+        if sff > 0f64 {
+            candidates.push((0f64, (sfg / sff).max(0f64)));
+            let det = sw * sff - sf * sf;
+            if det.abs() > 1e-12 * sw * sff {
+                let nugget = (sg * sff - sf * sfg) / det;
+                let partial_sill = (sw * sfg - sf * sg) / det;
+                if nugget >= 0f64 && partial_sill >= 0f64 {
+                    candidates.push((nugget, partial_sill));
+                }
+            }
+        }
+        let mut best = (
+            VariogramModel {
+                model_type,
+                nugget: 0f64,
+                partial_sill: 0f64,
+                range,
+            },
+            f64::INFINITY,
+        );
+        for (nugget, partial_sill) in candidates {
+            let value = sse(nugget, partial_sill);
+            if value < best.1 {
+                best.0.nugget = nugget;
+                best.0.partial_sill = partial_sill;
+                best.1 = value;
+            }
+        }
+        best
+    };
+
+    if model_type == VariogramModelType::Nugget {
+        let (mut model, sse) = fit_sills(1f64);
+        model.range = 0f64;
+        return Some((model, sse));
+    }
+
+    // A coarse search over log-spaced ranges, followed by a golden-section search around the best
+    // of them.
+    let max_distance = lags.iter().map(|l| l.distance).fold(0f64, f64::max);
+    let num_steps = 80;
+    let (low, high) = ((max_distance / 100f64).ln(), (max_distance * 5f64).ln());
+    let step = (high - low) / num_steps as f64;
+    let mut best = (fit_sills(low.exp()), 0usize);
+    for k in 1..=num_steps {
+        let fit = fit_sills((low + k as f64 * step).exp());
+        if fit.1 < (best.0).1 {
+            best = (fit, k);
+        }
+    }
+    let ratio = (5f64.sqrt() - 1f64) / 2f64;
+    let mut a = low + best.1.saturating_sub(1) as f64 * step;
+    let mut b = low + (best.1 + 1).min(num_steps) as f64 * step;
+    let mut c = b - ratio * (b - a);
+    let mut d = a + ratio * (b - a);
+    let mut fit_c = fit_sills(c.exp());
+    let mut fit_d = fit_sills(d.exp());
+    for _ in 0..30 {
+        if fit_c.1 < fit_d.1 {
+            b = d;
+            d = c;
+            fit_d = fit_c;
+            c = b - ratio * (b - a);
+            fit_c = fit_sills(c.exp());
+        } else {
+            a = c;
+            c = d;
+            fit_c = fit_d;
+            d = a + ratio * (b - a);
+            fit_d = fit_sills(d.exp());
+        }
+    }
+    let fit = fit_sills(((a + b) / 2f64).exp());
+    Some(if fit.1 <= (best.0).1 { fit } else { best.0 })
+}
+
+#[cfg(test)]
+mod test {
+    use super::{
+        empirical_variogram, fit_variogram, VariogramLag, VariogramModel, VariogramModelType,
+    };
+    use crate::structures::Point2D;
+
+    #[test]
+    fn test_empirical_variogram() {
+        let points = vec![
+            Point2D::new(0f64, 0f64),
+            Point2D::new(1f64, 0f64),
+            Point2D::new(3f64, 0f64),
+        ];
+        let values = vec![1f64, 2f64, 4f64];
+        let lags = empirical_variogram(&points, &values, 1.5, 2);
+        // Pairs at distances 1 and 2 fall in the first and second lags, and the pair at a
+        // distance of 3 is beyond the last lag.
+        assert_eq!(lags.len(), 2);
+        assert_eq!(lags[0].num_pairs, 1);
+        assert!((lags[0].distance - 1f64).abs() < 1e-12);
+        assert!((lags[0].semivariance - 0.5).abs() < 1e-12);
+        assert_eq!(lags[1].num_pairs, 1);
+        assert!((lags[1].semivariance - 2f64).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_variogram_model() {
+        let model = VariogramModel {
+            model_type: VariogramModelType::Spherical,
+            nugget: 1f64,
+            partial_sill: 4f64,
+            range: 10f64,
+        };
+        assert_eq!(model.semivariance(0f64), 0f64);
+        assert!((model.semivariance(5f64) - (1f64 + 4f64 * 0.6875)).abs() < 1e-12);
+        assert_eq!(model.semivariance(20f64), 5f64);
+        assert_eq!(model.covariance(0f64), 5f64);
+        assert_eq!(model.covariance(20f64), 0f64);
+
+        let matern = VariogramModel {
+            model_type: VariogramModelType::Matern(0.5),
+            ..model
+        };
+        let exponential = VariogramModel {
+            model_type: VariogramModelType::Exponential,
+            ..model
+        };
+        assert!((matern.semivariance(7f64) - exponential.semivariance(7f64)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_fit_variogram() {
+        let model = VariogramModel {
+            model_type: VariogramModelType::Spherical,
+            nugget: 0.5,
+            partial_sill: 2f64,
+            range: 30f64,
+        };
+        let lags: Vec<VariogramLag> = (1..=20)
+            .map(|k| VariogramLag {
+                distance: k as f64 * 2.5,
+                semivariance: model.semivariance(k as f64 * 2.5),
+                num_pairs: 100,
+            })
+            .collect();
+        let (fitted, sse) = fit_variogram(&lags, VariogramModelType::Spherical).unwrap();
+        assert!(sse < 1e-6);
+        assert!((fitted.nugget - 0.5).abs() < 1e-3);
+        assert!((fitted.partial_sill - 2f64).abs() < 1e-3);
+        assert!((fitted.range - 30f64).abs() < 0.1);
+
+        let (nugget, _) = fit_variogram(&lags, VariogramModelType::Nugget).unwrap();
+        assert_eq!(nugget.partial_sill, 0f64);
+        assert!(nugget.nugget > 0.5 && nugget.nugget < 2.5);
+    }
+}
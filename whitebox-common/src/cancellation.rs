@@ -0,0 +1,132 @@
+/*
+This code is part of the WhiteboxTools geospatial analysis library.
+Authors: Dr. John Lindsay
+Created: 18/10/2026
+Last Modified: 18/10/2026
+License: MIT
+*/
+
+use std::io::{Error, ErrorKind};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{Receiver, RecvTimeoutError};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// A token used to ask a running tool to stop. Clones of a token share the same state, so a
+/// token can be cancelled from another thread while a tool runs with a clone of it. A token
+/// may also carry a deadline, after which it behaves as though it had been cancelled.
+///
+/// Tools call `check` within their main loops and use `recv` to receive data from their
+/// worker threads, returning the resulting error as soon as the token is cancelled. Returning
+/// drops the receiver, so the workers stop at their next send, which then fails.
+#[derive(Clone, Debug, Default)]
+pub struct CancellationToken {
+    cancelled: Arc<AtomicBool>,
+    deadline: Option<Instant>,
+}
+
+impl CancellationToken {
+    pub fn new() -> CancellationToken {
+        CancellationToken::default()
+    }
+
+    /// Creates a token that is cancelled once `timeout` has elapsed.
+    pub fn with_timeout(timeout: Duration) -> CancellationToken {
+        CancellationToken {
+            cancelled: Arc::new(AtomicBool::new(false)),
+            deadline: Instant::now().checked_add(timeout),
+        }
+    }
+
+    /// Cancels the token and all of its clones.
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::SeqCst);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::SeqCst) || self.is_timed_out()
+    }
+
+    fn is_timed_out(&self) -> bool {
+        match self.deadline {
+            Some(d) => Instant::now() >= d,
+            None => false,
+        }
+    }
+
+    /// Returns an error if the token has been cancelled (`ErrorKind::Interrupted`) or if its
+    /// deadline has passed (`ErrorKind::TimedOut`).
+    pub fn check(&self) -> Result<(), Error> {
+        if self.cancelled.load(Ordering::SeqCst) {
+            return Err(Error::new(ErrorKind::Interrupted, "The operation was cancelled."));
+        }
+        if self.is_timed_out() {
+            return Err(Error::new(ErrorKind::TimedOut, "The time limit was exceeded."));
+        }
+        Ok(())
+    }
+
+    /// Receives a value from `rx`, waking periodically to check the token. An error is returned
+    /// if the token is cancelled while waiting, or if all of the senders have been dropped,
+    /// e.g. because a worker thread panicked.
+    pub fn recv<T>(&self, rx: &Receiver<T>) -> Result<T, Error> {
+        loop {
+            self.check()?;
+            match rx.recv_timeout(Duration::from_millis(100)) {
+                Ok(value) => return Ok(value),
+                Err(RecvTimeoutError::Timeout) => {}
+                Err(RecvTimeoutError::Disconnected) => {
+                    return Err(Error::new(
+                        ErrorKind::Other,
+                        "Error receiving data from thread.",
+                    ))
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::CancellationToken;
+    use std::io::ErrorKind;
+    use std::sync::mpsc;
+    use std::thread;
+    use std::time::Duration;
+
+    #[test]
+    fn test_cancel_is_shared_by_clones() {
+        let token = CancellationToken::new();
+        let token2 = token.clone();
+        assert!(token.check().is_ok());
+        token2.cancel();
+        assert!(token.is_cancelled());
+        assert_eq!(token.check().unwrap_err().kind(), ErrorKind::Interrupted);
+    }
+
+    #[test]
+    fn test_timeout() {
+        let token = CancellationToken::with_timeout(Duration::from_millis(0));
+        assert_eq!(token.check().unwrap_err().kind(), ErrorKind::TimedOut);
+        let token = CancellationToken::with_timeout(Duration::from_secs(3600));
+        assert!(!token.is_cancelled());
+    }
+
+    #[test]
+    fn test_recv_stops_when_cancelled() {
+        let token = CancellationToken::new();
+        let (tx, rx) = mpsc::channel::<usize>();
+        tx.send(1).unwrap();
+        assert_eq!(token.recv(&rx).unwrap(), 1);
+
+        let token2 = token.clone();
+        let handle = thread::spawn(move || {
+            thread::sleep(Duration::from_millis(50));
+            token2.cancel();
+        });
+        // The sender is still alive, so without the token this would block forever.
+        assert_eq!(token.recv(&rx).unwrap_err().kind(), ErrorKind::Interrupted);
+        handle.join().unwrap();
+        drop(tx);
+    }
+}
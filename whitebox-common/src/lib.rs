@@ -1,6 +1,10 @@
 pub mod algorithms;
+pub mod cancellation;
 pub mod configs;
 pub mod plugins;
+pub mod progress;
+pub mod provenance;
+pub mod random;
 pub mod rendering;
 pub mod spatial_ref_system;
 pub mod structures;
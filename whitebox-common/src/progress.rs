@@ -0,0 +1,359 @@
+/*
+This code is part of the WhiteboxTools geospatial analysis library.
+Authors: Dr. John Lindsay
+Created: 18/10/2026
+Last Modified: 18/10/2026
+License: MIT
+*/
+
+use crate::provenance::{self, Provenance};
+use crate::random;
+use crate::utils::{memory_namespace, set_memory_namespace};
+use num_traits::ToPrimitive;
+use serde_json::Value;
+use std::cell::RefCell;
+use std::fmt;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+// Progress and status reporting for tools. By default each function prints the same
+// plain-text line that the tools have always printed. When JSON events are enabled
+// (`--json-events`), each one instead prints a single-line JSON object with an "event"
+// field, one of:
+//
+//   start     {"event":"start","tool":"Slope"}
+//   progress  {"event":"progress","tool":"Slope","stage":"Flow directions","percent":42}
+//   message   {"event":"message","tool":"Slope","message":"Reading data..."}
+//   warning   {"event":"warning","tool":"Slope","message":"..."}
+//   output    {"event":"output","tool":"Slope","file":"/path/slope.tif"}
+//   finished  {"event":"finished","tool":"Slope","elapsed_seconds":1.25}
+//   error     {"event":"error","tool":"Slope","kind":"InvalidInput","message":"..."}
+//   step      {"event":"step","tool":"Slope","step":"slope","status":"ran","elapsed_seconds":1.25}
+//
+// The "stage" field is absent for overall progress, and the "tool" field is absent for
+// events emitted from threads that are not running a tool. A thread may also send its
+// events to a handler, rather than to stdout, with `set_event_handler`.
+//
+// The tool name and handler belong to the thread that runs the tool. Worker threads are
+// started with `spawn`, which passes them on to the new thread, along with the rest of the
+// thread's context: the provenance record that is stamped on the tool's outputs, the namespace
+// of its in-memory data sets, and its default seed.
+
+static JSON_EVENTS: AtomicBool = AtomicBool::new(false);
+
+/// A handler that receives events as JSON lines.
+pub type EventHandler = Arc<dyn Fn(&str) + Send + Sync>;
+
+/// The tool name and event handler that a thread's events are reported with.
+#[derive(Clone, Default)]
+struct EventContext {
+    tool: Option<String>,
+    handler: Option<EventHandler>,
+}
+
+/// The per-thread state that is passed on to worker threads: the event context, provenance
+/// record, memory namespace, and default seed of the thread that it was taken from.
+#[derive(Clone)]
+pub struct ThreadContext {
+    events: EventContext,
+    provenance: Option<Provenance>,
+    memory_namespace: Option<String>,
+    default_seed: Option<Option<u64>>,
+}
+
+thread_local! {
+    static CONTEXT: RefCell<EventContext> = RefCell::new(EventContext::default());
+}
+
+/// Turns JSON event output on or off for the whole process.
+pub fn set_json_events(enabled: bool) {
+    JSON_EVENTS.store(enabled, Ordering::SeqCst);
+}
+
+pub fn json_events() -> bool {
+    JSON_EVENTS.load(Ordering::SeqCst)
+}
+
+/// Sends the events emitted from the current thread to `handler`, as JSON lines, instead of
+/// printing them. This is used to capture the progress of tools that are run by the server.
+pub fn set_event_handler(handler: Option<EventHandler>) {
+    CONTEXT.with(|c| c.borrow_mut().handler = handler);
+}
+
+/// Whether events from the current thread are emitted as JSON.
+pub fn json_mode() -> bool {
+    json_events() || CONTEXT.with(|c| c.borrow().handler.is_some())
+}
+
+/// Sets the name of the tool that events emitted from the current thread belong to.
+pub fn set_current_tool(tool_name: Option<&str>) {
+    CONTEXT.with(|c| c.borrow_mut().tool = tool_name.map(|s| s.to_string()));
+}
+
+/// Returns the context of the current thread.
+pub fn context() -> ThreadContext {
+    ThreadContext {
+        events: CONTEXT.with(|c| c.borrow().clone()),
+        provenance: provenance::current(),
+        memory_namespace: memory_namespace(),
+        default_seed: random::thread_default_seed(),
+    }
+}
+
+/// Runs the current thread with a context taken from another thread.
+pub fn set_context(context: ThreadContext) {
+    CONTEXT.with(|c| *c.borrow_mut() = context.events);
+    provenance::set_current(context.provenance);
+    set_memory_namespace(context.memory_namespace.as_deref());
+    random::set_thread_default_seed(context.default_seed);
+}
+
+/// Spawns a thread that runs with the context of the current thread, so that its events are
+/// reported with the same tool name and event handler, its outputs are stamped with the same
+/// provenance record, and it sees the same in-memory data sets. Tools use this, rather than
+/// `thread::spawn`, for their worker threads.
+pub fn spawn<F, T>(f: F) -> thread::JoinHandle<T>
+where
+    F: FnOnce() -> T + Send + 'static,
+    T: Send + 'static,
+{
+    let context = context();
+    thread::spawn(move || {
+        set_context(context);
+        f()
+    })
+}
+
+/// Reports the start of a tool run. Nothing is printed in text mode.
+pub fn tool_started(tool_name: &str) {
+    if json_mode() {
+        print_event("start", tool_name, vec![]);
+    }
+}
+
+/// Reports the successful completion of a tool run. Nothing is printed in text mode.
+pub fn tool_finished(tool_name: &str, elapsed: Duration) {
+    if json_mode() {
+        print_event(
+            "finished",
+            tool_name,
+            vec![("elapsed_seconds", Value::from(elapsed.as_secs_f64()))],
+        );
+    }
+}
+
+/// Reports a failed tool run; `kind` is a short error category, e.g. `InvalidInput`.
+/// Nothing is printed in text mode, where the error is reported by the caller.
+pub fn tool_error(tool_name: &str, kind: &str, message: &str) {
+    if json_mode() {
+        print_event(
+            "error",
+            tool_name,
+            vec![("kind", Value::from(kind)), ("message", Value::from(message))],
+        );
+    }
+}
+
+/// Prints the welcome banner shown at the start of a verbose tool run.
+pub fn welcome(tool_name: &str) {
+    if json_mode() {
+        return;
+    }
+    let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28);
+    // 28 = length of the 'Powered by' by statement.
+    println!("{}", "*".repeat(welcome_len));
+    println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
+    println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
+    println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
+    println!("{}", "*".repeat(welcome_len));
+}
+
+/// Reports the overall progress of a tool, e.g. `Progress: 42%`.
+pub fn update<T: ToPrimitive>(percent: T) {
+    let percent = percent.to_i64().unwrap_or(0);
+    if json_mode() {
+        print_current_event("progress", vec![("percent", Value::from(percent))]);
+    } else {
+        println!("Progress: {}%", percent);
+    }
+}
+
+/// Reports the progress of a named stage of a tool, e.g. `Flow directions: 42%`.
+pub fn update_stage<T: ToPrimitive>(stage: &str, percent: T) {
+    let percent = percent.to_i64().unwrap_or(0);
+    if json_mode() {
+        print_current_event(
+            "progress",
+            vec![("stage", Value::from(stage)), ("percent", Value::from(percent))],
+        );
+    } else {
+        println!("{}: {}%", stage, percent);
+    }
+}
+
+/// Reports a status message or a line of tool output.
+pub fn message<T: fmt::Display>(msg: T) {
+    if json_mode() {
+        print_current_event("message", vec![("message", Value::from(msg.to_string()))]);
+    } else {
+        println!("{}", msg);
+    }
+}
+
+/// Reports a warning, printed as `Warning: ...`.
+pub fn warning<T: fmt::Display>(msg: T) {
+    if json_mode() {
+        print_current_event("warning", vec![("message", Value::from(msg.to_string()))]);
+    } else {
+        println!("Warning: {}", msg);
+    }
+}
+
+/// Reports the status of a workflow step: `running`, `cached` (skipped because its outputs are
+/// up to date), `ran`, `failed`, or `not run`. In text mode, only steps that are starting or are
+/// skipped are reported, since the workflow prints a summary of the others once it finishes.
+pub fn workflow_step(
+    step_id: &str,
+    tool_name: &str,
+    status: &str,
+    elapsed_seconds: Option<f64>,
+    error: Option<&str>,
+) {
+    if json_mode() {
+        let mut fields = vec![("step", Value::from(step_id)), ("status", Value::from(status))];
+        if let Some(elapsed_seconds) = elapsed_seconds {
+            fields.push(("elapsed_seconds", Value::from(elapsed_seconds)));
+        }
+        if let Some(error) = error {
+            fields.push(("error", Value::from(error)));
+        }
+        print_event("step", tool_name, fields);
+    } else if status == "running" {
+        println!("Running workflow step '{}' ({})", step_id, tool_name);
+    } else if status == "cached" {
+        println!("Skipping unchanged workflow step '{}' ({})", step_id, tool_name);
+    }
+}
+
+/// Reports that an output file has been written.
+pub fn output_written(file_name: &str) {
+    if json_mode() {
+        print_current_event("output", vec![("file", Value::from(file_name))]);
+    } else {
+        println!("Output file written");
+    }
+}
+
+fn print_current_event(event: &str, fields: Vec<(&str, Value)>) {
+    let tool_name = CONTEXT.with(|c| c.borrow().tool.clone()).unwrap_or_default();
+    print_event(event, &tool_name, fields);
+}
+
+fn print_event(event: &str, tool_name: &str, fields: Vec<(&str, Value)>) {
+    let line = format_event(event, tool_name, fields);
+    // The handler is called outside of the borrow, in case it reports events itself.
+    let handler = CONTEXT.with(|c| c.borrow().handler.clone());
+    match handler {
+        Some(handler) => handler(&line),
+        None => println!("{}", line),
+    }
+}
+
+/// Formats an event as a single-line JSON object, with the "event" and "tool" fields first.
+fn format_event(event: &str, tool_name: &str, fields: Vec<(&str, Value)>) -> String {
+    let mut line = format!("{{\"event\":{}", Value::from(event));
+    if !tool_name.is_empty() {
+        line.push_str(&format!(",\"tool\":{}", Value::from(tool_name)));
+    }
+    for (key, value) in fields {
+        line.push_str(&format!(",{}:{}", Value::from(key), value));
+    }
+    line.push('}');
+    line
+}
+
+#[cfg(test)]
+mod test {
+    use super::{format_event, set_current_tool, set_event_handler, spawn, update_stage, warning};
+    use crate::provenance::{self, Provenance};
+    use crate::random;
+    use crate::utils::{memory_namespace, set_memory_namespace};
+    use serde_json::Value;
+    use std::sync::{Arc, Mutex};
+
+    #[test]
+    fn test_format_event() {
+        let line = format_event(
+            "progress",
+            "Slope",
+            vec![("stage", Value::from("Loop 1 of 2")), ("percent", Value::from(42))],
+        );
+        assert_eq!(
+            line,
+            "{\"event\":\"progress\",\"tool\":\"Slope\",\"stage\":\"Loop 1 of 2\",\"percent\":42}"
+        );
+    }
+
+    #[test]
+    fn test_format_event_escapes_text() {
+        let line = format_event("message", "", vec![("message", Value::from("a \"b\"\nc"))]);
+        let v: Value = serde_json::from_str(&line).unwrap();
+        assert_eq!(v["event"], "message");
+        assert_eq!(v["message"], "a \"b\"\nc");
+        assert!(v.get("tool").is_none());
+    }
+
+    #[test]
+    fn test_event_handler() {
+        let lines = Arc::new(Mutex::new(vec![]));
+        let l = Arc::clone(&lines);
+        set_event_handler(Some(Arc::new(move |line: &str| l.lock().unwrap().push(line.to_string()))));
+        set_current_tool(Some("Slope"));
+        update_stage("Loop 1 of 2", 42);
+        set_current_tool(None);
+        set_event_handler(None);
+        let lines = lines.lock().unwrap();
+        assert_eq!(lines.len(), 1);
+        let v: Value = serde_json::from_str(&lines[0]).unwrap();
+        assert_eq!(v["tool"], "Slope");
+        assert_eq!(v["stage"], "Loop 1 of 2");
+        assert_eq!(v["percent"], 42);
+    }
+
+    #[test]
+    fn test_spawn_inherits_context() {
+        let lines = Arc::new(Mutex::new(vec![]));
+        let l = Arc::clone(&lines);
+        set_event_handler(Some(Arc::new(move |line: &str| l.lock().unwrap().push(line.to_string()))));
+        set_current_tool(Some("Slope"));
+        let record = Provenance {
+            tool: "Slope".to_string(),
+            ..Default::default()
+        };
+        provenance::set_current(Some(record.clone()));
+        set_memory_namespace(Some("job1"));
+        random::set_thread_default_seed(Some(Some(5)));
+        let (inherited, namespace, seed) = spawn(|| {
+            warning("from a worker");
+            (provenance::current(), memory_namespace(), random::default_seed())
+        })
+        .join()
+        .unwrap();
+        random::set_thread_default_seed(None);
+        set_memory_namespace(None);
+        provenance::set_current(None);
+        set_current_tool(None);
+        set_event_handler(None);
+        assert_eq!(inherited, Some(record));
+        assert_eq!(namespace, Some("job1".to_string()));
+        assert_eq!(seed, Some(5));
+        let lines = lines.lock().unwrap();
+        assert_eq!(lines.len(), 1);
+        let v: Value = serde_json::from_str(&lines[0]).unwrap();
+        assert_eq!(v["event"], "warning");
+        assert_eq!(v["tool"], "Slope");
+        assert_eq!(v["message"], "from a worker");
+    }
+}
@@ -0,0 +1,163 @@
+/*
+This code is part of the WhiteboxTools geospatial analysis library.
+Authors: Dr. John Lindsay
+Created: 18/10/2026
+Last Modified: 18/10/2026
+License: MIT
+*/
+
+use serde::{Deserialize, Serialize};
+use std::cell::RefCell;
+use std::fs;
+use std::io::{Error, ErrorKind};
+use std::path::{Path, PathBuf};
+
+/// The prefix used to identify a provenance record within a raster's metadata entries.
+pub const METADATA_PREFIX: &str = "Provenance: ";
+
+/// The extension of the sidecar file used to store the provenance of outputs whose formats
+/// cannot hold it, e.g. Shapefiles.
+pub const SIDECAR_EXTENSION: &str = "whitebox.json";
+
+/// A record of how a dataset was created: the tool that wrote it, the arguments the tool was
+/// run with, and the checksums of the tool's input files at the time.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Provenance {
+    pub tool: String,
+    pub version: String,
+    pub args: Vec<String>,
+    #[serde(default)]
+    pub working_directory: String,
+    #[serde(default)]
+    pub inputs: Vec<InputFile>,
+    /// The default random seed set with the global `--seed` option, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub seed: Option<u64>,
+    pub timestamp: String,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct InputFile {
+    pub file: String,
+    /// The value returned by `utils::hash_file_set` for the file, or `None` if the file could
+    /// not be read.
+    pub checksum: Option<String>,
+}
+
+thread_local! {
+    static CURRENT: RefCell<Option<Provenance>> = RefCell::new(None);
+}
+
+/// Sets the provenance record that is stamped on outputs written by the current thread. The
+/// tool manager sets this before running a tool and clears it afterwards; worker threads
+/// started with `progress::spawn` inherit it.
+pub fn set_current(record: Option<Provenance>) {
+    CURRENT.with(|c| *c.borrow_mut() = record);
+}
+
+/// Returns the provenance record for the tool running on the current thread, if any.
+pub fn current() -> Option<Provenance> {
+    CURRENT.with(|c| c.borrow().clone())
+}
+
+impl Provenance {
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap_or_default()
+    }
+
+    pub fn from_json(s: &str) -> Result<Provenance, Error> {
+        serde_json::from_str(s).map_err(|e| Error::new(ErrorKind::InvalidData, e.to_string()))
+    }
+
+    /// Returns the record as a single-line raster metadata entry.
+    pub fn to_metadata_entry(&self) -> String {
+        format!("{}{}", METADATA_PREFIX, self.to_json())
+    }
+
+    /// Parses a raster metadata entry, returning `None` if it is not a provenance record.
+    pub fn from_metadata_entry(entry: &str) -> Option<Provenance> {
+        let json = entry.trim().strip_prefix(METADATA_PREFIX)?;
+        Provenance::from_json(json).ok()
+    }
+
+    /// Finds the provenance record among a raster's metadata entries. If there are several,
+    /// the last one is the most recent.
+    pub fn from_metadata(metadata: &[String]) -> Option<Provenance> {
+        metadata
+            .iter()
+            .rev()
+            .find_map(|entry| Provenance::from_metadata_entry(entry))
+    }
+
+    /// Writes the record to the sidecar file of `file_name`.
+    pub fn write_sidecar(&self, file_name: &str) -> Result<(), Error> {
+        let json = serde_json::to_string_pretty(self)
+            .map_err(|e| Error::new(ErrorKind::InvalidData, e.to_string()))?;
+        fs::write(sidecar_file(file_name), json)
+    }
+
+    /// Reads the record from the sidecar file of `file_name`, returning `None` if there is none.
+    pub fn read_sidecar(file_name: &str) -> Option<Provenance> {
+        let contents = fs::read_to_string(sidecar_file(file_name)).ok()?;
+        Provenance::from_json(&contents).ok()
+    }
+}
+
+/// Returns the name of the provenance sidecar file for `file_name`, e.g. `roads.whitebox.json`
+/// for `roads.shp`.
+pub fn sidecar_file(file_name: &str) -> PathBuf {
+    Path::new(file_name).with_extension(SIDECAR_EXTENSION)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{current, set_current, sidecar_file, InputFile, Provenance};
+    use std::env;
+    use std::fs;
+
+    fn record() -> Provenance {
+        Provenance {
+            tool: "Slope".to_string(),
+            version: "2.3.0".to_string(),
+            args: vec!["--dem=dem.tif".to_string(), "--output=slope.tif".to_string()],
+            working_directory: "/data/".to_string(),
+            inputs: vec![InputFile {
+                file: "/data/dem.tif".to_string(),
+                checksum: Some("0123456789abcdef".to_string()),
+            }],
+            seed: None,
+            timestamp: "2026-10-18T12:00:00Z".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_metadata_round_trip() {
+        let p = record();
+        let entry = p.to_metadata_entry();
+        assert!(!entry.contains('\n'));
+        let metadata = vec!["Created on 2026-10-18".to_string(), entry];
+        assert_eq!(Provenance::from_metadata(&metadata), Some(p));
+        assert_eq!(Provenance::from_metadata_entry("Created on 2026-10-18"), None);
+    }
+
+    #[test]
+    fn test_sidecar_round_trip() {
+        assert!(sidecar_file("roads.shp").ends_with("roads.whitebox.json"));
+        let file = env::temp_dir().join(format!("provenance_test_{}.shp", std::process::id()));
+        let file = file.to_string_lossy().to_string();
+        let p = record();
+        p.write_sidecar(&file).unwrap();
+        assert_eq!(Provenance::read_sidecar(&file), Some(p));
+        fs::remove_file(sidecar_file(&file)).unwrap();
+        assert_eq!(Provenance::read_sidecar(&file), None);
+    }
+
+    #[test]
+    fn test_current() {
+        assert_eq!(current(), None);
+        set_current(Some(record()));
+        assert_eq!(current().unwrap().tool, "Slope");
+        set_current(None);
+        assert_eq!(current(), None);
+    }
+}
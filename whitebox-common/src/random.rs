@@ -0,0 +1,109 @@
+/*
+This code is part of the WhiteboxTools geospatial analysis library.
+Authors: Dr. John Lindsay
+Created: 18/10/2026
+Last Modified: 18/10/2026
+License: MIT
+*/
+
+//! Seeded random number generation for stochastic tools.
+//!
+//! A tool that accepts a `--seed` parameter passes it to `tool_seed`, which falls back on the
+//! default seed set with the global `--seed` option. The tool then creates one generator per
+//! thread with `new_rng`, using the thread id as the stream. With the same seed and number of
+//! threads, every run draws the same numbers; without a seed, generators are seeded from the
+//! operating system's entropy source.
+//!
+//! A thread may override the default seed with `set_thread_default_seed`, e.g. so that each job
+//! run by the server has a default seed of its own. Threads started with `progress::spawn` inherit
+//! the override.
+
+pub use rand::rngs::StdRng;
+use rand::SeedableRng;
+use std::cell::Cell;
+use std::sync::Mutex;
+
+static DEFAULT_SEED: Mutex<Option<u64>> = Mutex::new(None);
+
+thread_local! {
+    static THREAD_DEFAULT_SEED: Cell<Option<Option<u64>>> = const { Cell::new(None) };
+}
+
+/// Sets the seed used by stochastic tools that are not given a seed of their own.
+pub fn set_default_seed(seed: Option<u64>) {
+    *DEFAULT_SEED.lock().unwrap() = seed;
+}
+
+/// Overrides the default seed for the current thread. `None` removes the override, so that the
+/// process-wide default applies again.
+pub fn set_thread_default_seed(seed: Option<Option<u64>>) {
+    THREAD_DEFAULT_SEED.with(|s| s.set(seed));
+}
+
+/// Returns the current thread's override of the default seed, if it has one.
+pub fn thread_default_seed() -> Option<Option<u64>> {
+    THREAD_DEFAULT_SEED.with(|s| s.get())
+}
+
+pub fn default_seed() -> Option<u64> {
+    match thread_default_seed() {
+        Some(seed) => seed,
+        None => *DEFAULT_SEED.lock().unwrap(),
+    }
+}
+
+/// Returns the seed a tool should use: its own `--seed` argument if there is one, otherwise
+/// the default seed.
+pub fn tool_seed(seed: Option<u64>) -> Option<u64> {
+    seed.or_else(default_seed)
+}
+
+/// Creates a random number generator. Generators created with the same seed and stream produce
+/// the same sequence, while different streams (e.g. one per thread) are independent.
+pub fn new_rng(seed: Option<u64>, stream: u64) -> StdRng {
+    match seed {
+        Some(s) => StdRng::seed_from_u64(splitmix64(s ^ splitmix64(stream))),
+        None => StdRng::from_entropy(),
+    }
+}
+
+fn splitmix64(x: u64) -> u64 {
+    let mut z = x.wrapping_add(0x9e3779b97f4a7c15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+    z ^ (z >> 31)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{default_seed, new_rng, set_thread_default_seed, tool_seed};
+    use rand::Rng;
+
+    fn draw(seed: Option<u64>, stream: u64) -> Vec<u64> {
+        let mut rng = new_rng(seed, stream);
+        (0..10).map(|_| rng.gen()).collect()
+    }
+
+    #[test]
+    fn test_same_seed_and_stream() {
+        assert_eq!(draw(Some(42), 3), draw(Some(42), 3));
+        assert_ne!(draw(Some(42), 3), draw(Some(42), 4));
+        assert_ne!(draw(Some(42), 3), draw(Some(43), 3));
+    }
+
+    #[test]
+    fn test_tool_seed() {
+        // The tool's own seed takes precedence over any default.
+        assert_eq!(tool_seed(Some(7)), Some(7));
+        assert_ne!(draw(None, 0), draw(None, 0));
+    }
+
+    #[test]
+    fn test_thread_default_seed() {
+        set_thread_default_seed(Some(Some(11)));
+        assert_eq!(tool_seed(None), Some(11));
+        set_thread_default_seed(Some(None));
+        assert_eq!(default_seed(), None);
+        set_thread_default_seed(None);
+    }
+}
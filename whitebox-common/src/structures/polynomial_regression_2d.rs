@@ -126,8 +126,8 @@ mod test {
         let mut x_val: f64;
         let mut y_val: f64;
         for _ in 0..150 {
-            x_val = rng.gen_range(0.0, 10.0);
-            y_val = rng.gen_range(0.0, 10.0);
+            x_val = rng.gen_range(0.0..10.0);
+            y_val = rng.gen_range(0.0..10.0);
             x.push(x_val);
             y.push(y_val);
             x_prime.push(0.58 * x_val + 0.32 * y_val + 46.0);
@@ -0,0 +1,101 @@
+use super::is_memory_path;
+use std::fs::File;
+use std::io::prelude::*;
+use std::io::{BufReader, Error};
+use std::path::Path;
+
+/// Hashes the contents of a file together with any of its sidecar files (e.g. the .dbf, .shx, and .prj
+/// files of a Shapefile or the .tas data file of a Whitebox raster). Returns `None` if the file does not exist.
+pub fn hash_file_set(file_name: &str) -> Option<String> {
+    if is_memory_path(file_name) || !Path::new(file_name).is_file() {
+        return None;
+    }
+    let mut hasher = Fnv1a64::new();
+    hash_file(file_name, &mut hasher).ok()?;
+    let sidecars = ["dbf", "shx", "prj", "tas", "hdr", "rst", "sdat", "tfw"];
+    for ext in sidecars.iter() {
+        let p = Path::new(file_name).with_extension(ext);
+        if p.is_file() && p.to_string_lossy() != file_name {
+            hash_file(&p.to_string_lossy(), &mut hasher).ok()?;
+        }
+    }
+    Some(format!("{:016x}", hasher.finish()))
+}
+
+/// Hashes the contents of a file followed by its length, which separates it from the next file
+/// in a set. The hash does not depend on how the reads are split into chunks.
+fn hash_file(file_name: &str, hasher: &mut Fnv1a64) -> Result<(), Error> {
+    let mut reader = BufReader::new(File::open(file_name)?);
+    let mut buffer = vec![0u8; 1 << 16];
+    let mut len = 0u64;
+    loop {
+        let n = reader.read(&mut buffer)?;
+        if n == 0 {
+            break;
+        }
+        hasher.write(&buffer[..n]);
+        len += n as u64;
+    }
+    hasher.write(&len.to_le_bytes());
+    Ok(())
+}
+
+/// 64-bit FNV-1a, used rather than `DefaultHasher` because hashes that are saved to disk must be
+/// stable across builds.
+pub struct Fnv1a64 {
+    state: u64,
+}
+
+impl Fnv1a64 {
+    pub fn new() -> Fnv1a64 {
+        Fnv1a64 {
+            state: 0xcbf29ce484222325,
+        }
+    }
+
+    /// Feeds bytes to the hash. Consecutive writes hash the same as one write of the
+    /// concatenated bytes.
+    pub fn write(&mut self, bytes: &[u8]) {
+        for b in bytes {
+            self.state ^= *b as u64;
+            self.state = self.state.wrapping_mul(0x100000001b3);
+        }
+    }
+
+    /// Feeds one field of a record to the hash, prefixed with its length so that the boundaries
+    /// between fields are part of the hash, e.g. ("ab", "c") and ("a", "bc") hash differently.
+    pub fn write_field(&mut self, bytes: &[u8]) {
+        self.write(&(bytes.len() as u64).to_le_bytes());
+        self.write(bytes);
+    }
+
+    pub fn finish(&self) -> u64 {
+        self.state
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Fnv1a64;
+
+    #[test]
+    fn test_write_is_independent_of_chunking() {
+        let mut a = Fnv1a64::new();
+        a.write(b"abcdef");
+        let mut b = Fnv1a64::new();
+        b.write(b"ab");
+        b.write(b"cdef");
+        assert_eq!(a.finish(), b.finish());
+    }
+
+    #[test]
+    fn test_write_field_separates_fields() {
+        let mut a = Fnv1a64::new();
+        a.write_field(b"ab");
+        a.write_field(b"c");
+        let mut b = Fnv1a64::new();
+        b.write_field(b"a");
+        b.write_field(b"bc");
+        assert_ne!(a.finish(), b.finish());
+    }
+}
@@ -0,0 +1,173 @@
+/*
+This code is part of the WhiteboxTools geospatial analysis library.
+Authors: Dr. John Lindsay
+Created: 18/10/2026
+Last Modified: 18/10/2026
+License: MIT
+*/
+
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+
+/// The prefix identifying a data set held in a `MemoryStore` rather than on disk, e.g. `memory://dem`.
+pub const MEMORY_PREFIX: &str = "memory://";
+
+/// Returns true if `file_name` refers to an in-memory data set (i.e. it starts with `memory://`).
+pub fn is_memory_path(file_name: &str) -> bool {
+    match file_name.trim_start().get(..MEMORY_PREFIX.len()) {
+        Some(prefix) => prefix.eq_ignore_ascii_case(MEMORY_PREFIX),
+        None => false,
+    }
+}
+
+thread_local! {
+    static NAMESPACE: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Confines the `memory://` data sets that the current thread stores and reads to `namespace`,
+/// so that, e.g., jobs run concurrently by the server cannot see or replace each other's data
+/// sets. `None` returns the thread to the shared, process-wide namespace. Threads started with
+/// `progress::spawn` inherit the namespace.
+pub fn set_memory_namespace(namespace: Option<&str>) {
+    NAMESPACE.with(|n| *n.borrow_mut() = namespace.map(|s| s.to_string()));
+}
+
+pub fn memory_namespace() -> Option<String> {
+    NAMESPACE.with(|n| n.borrow().clone())
+}
+
+/// A process-wide registry of named, in-memory data sets. The raster, vector, and LiDAR
+/// crates each hold a static `MemoryStore` that their readers and writers resolve
+/// `memory://` file names through, so that the output of one tool can be used as the
+/// input to another without being written to disk.
+///
+/// Names may be given with or without the `memory://` prefix. Items are cloned on the
+/// way in and out, so a stored data set is never modified by the tools that read it. All
+/// methods act only on the items in the current thread's namespace (see `set_memory_namespace`).
+pub struct MemoryStore<T> {
+    items: Mutex<BTreeMap<String, T>>,
+}
+
+impl<T: Clone> MemoryStore<T> {
+    pub const fn new() -> MemoryStore<T> {
+        MemoryStore {
+            items: Mutex::new(BTreeMap::new()),
+        }
+    }
+
+    /// Adds an item, replacing any existing item of the same name.
+    pub fn insert(&self, name: &str, item: T) {
+        self.lock().insert(memory_key(name), item);
+    }
+
+    /// Returns a copy of the named item.
+    pub fn get(&self, name: &str) -> Option<T> {
+        self.lock().get(&memory_key(name)).cloned()
+    }
+
+    /// Removes the named item from the store, returning it.
+    pub fn remove(&self, name: &str) -> Option<T> {
+        self.lock().remove(&memory_key(name))
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.lock().contains_key(&memory_key(name))
+    }
+
+    /// Returns the names of the stored items, including the `memory://` prefix.
+    pub fn names(&self) -> Vec<String> {
+        let prefix = namespace_prefix();
+        self.lock()
+            .keys()
+            .filter_map(|k| k.strip_prefix(&prefix))
+            .filter(|k| !prefix.is_empty() || !k.starts_with(NAMESPACE_SEPARATOR))
+            .map(|k| format!("{}{}", MEMORY_PREFIX, k))
+            .collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.names().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn clear(&self) {
+        for name in self.names() {
+            self.remove(&name);
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, BTreeMap<String, T>> {
+        // A panic elsewhere cannot leave the map itself in an inconsistent state.
+        self.items.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl<T: Clone> Default for MemoryStore<T> {
+    fn default() -> MemoryStore<T> {
+        MemoryStore::new()
+    }
+}
+
+// Keys of items stored in a namespace are prefixed with "\u{0}namespace\u{0}", which cannot
+// collide with a file name.
+const NAMESPACE_SEPARATOR: char = '\u{0}';
+
+fn namespace_prefix() -> String {
+    match memory_namespace() {
+        Some(ns) => format!("{}{}{}", NAMESPACE_SEPARATOR, ns, NAMESPACE_SEPARATOR),
+        None => String::new(),
+    }
+}
+
+fn memory_key(name: &str) -> String {
+    let name = name.trim();
+    let name = if is_memory_path(name) {
+        &name[MEMORY_PREFIX.len()..]
+    } else {
+        name
+    };
+    format!("{}{}", namespace_prefix(), name)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{is_memory_path, set_memory_namespace, MemoryStore};
+
+    #[test]
+    fn test_memory_path() {
+        assert!(is_memory_path("memory://dem"));
+        assert!(is_memory_path("MEMORY://dem.tif"));
+        assert!(!is_memory_path("/data/memory/dem.tif"));
+    }
+
+    #[test]
+    fn test_memory_store() {
+        static STORE: MemoryStore<Vec<f64>> = MemoryStore::new();
+        STORE.insert("memory://dem", vec![1.0, 2.0]);
+        assert!(STORE.contains("dem"));
+        assert_eq!(STORE.get("dem"), Some(vec![1.0, 2.0]));
+        assert_eq!(STORE.names(), vec!["memory://dem".to_string()]);
+        STORE.insert("dem", vec![3.0]);
+        assert_eq!(STORE.len(), 1);
+        assert_eq!(STORE.remove("memory://dem"), Some(vec![3.0]));
+        assert!(STORE.get("dem").is_none());
+    }
+
+    #[test]
+    fn test_memory_namespace() {
+        static STORE: MemoryStore<u8> = MemoryStore::new();
+        STORE.insert("dem", 1);
+        set_memory_namespace(Some("job1"));
+        assert!(STORE.get("dem").is_none());
+        STORE.insert("dem", 2);
+        assert_eq!(STORE.names(), vec!["memory://dem".to_string()]);
+        STORE.clear();
+        set_memory_namespace(None);
+        assert_eq!(STORE.get("dem"), Some(1));
+        assert_eq!(STORE.len(), 1);
+    }
+}
@@ -1,11 +1,17 @@
 // private sub-module defined in other files
 mod byte_order_reader;
 mod byte_order_writer;
+mod file_hash;
+mod memory_store;
 
 // exports identifiers from private sub-modules in the current module namespace
 pub use self::byte_order_reader::ByteOrderReader;
 pub use self::byte_order_reader::Endianness;
 pub use self::byte_order_writer::ByteOrderWriter;
+pub use self::file_hash::{hash_file_set, Fnv1a64};
+pub use self::memory_store::{
+    is_memory_path, memory_namespace, set_memory_namespace, MemoryStore, MEMORY_PREFIX,
+};
 
 use std::time::Instant;
 
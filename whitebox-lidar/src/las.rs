@@ -2,7 +2,7 @@
 This tool is part of the WhiteboxTools geospatial analysis library.
 Authors: Dr. John Lindsay
 Created: 15/01/2017
-Last Modified: 07/12/2018
+Last Modified: 18/10/2026
 License: MIT
 */
 
@@ -14,9 +14,10 @@ use super::point_data::{ ColourData, PointData, WaveformPacket };
 use super::vlr::Vlr;
 use super::zlidar_compression::{ZlidarCompression};
 use whitebox_raster::geotiff::geokeys::GeoKeys;
+use whitebox_common::provenance::{self, Provenance};
 use whitebox_common::spatial_ref_system::esri_wkt_from_epsg;
 use whitebox_common::structures::{ BoundingBox, Point3D };
-use whitebox_common::utils::{ ByteOrderReader, Endianness };
+use whitebox_common::utils::{ is_memory_path, ByteOrderReader, Endianness, MemoryStore };
 use byteorder::{ LittleEndian, WriteBytesExt };
 use chrono::prelude::*;
 use core::slice;
@@ -50,6 +51,11 @@ use las::Writer as OtherWriter;
 use las::raw::point::ScanAngle;
 use las::raw::vlr::RecordLength;
 
+/// In-memory LiDAR point clouds, addressed by tools with `memory://name` file names. Reading
+/// such a file returns a copy of the stored `LasFile` and writing one stores a copy of the
+/// output, without any disk I/O.
+pub static MEMORY_LAS_FILES: MemoryStore<LasFile> = MemoryStore::new();
+
 #[derive(Default, Clone)]
 pub struct LasFile {
     file_name: String,
@@ -521,6 +527,20 @@ impl LasFile {
     }
 
     pub fn read(&mut self) -> Result<(), Error> {
+        if is_memory_path(&self.file_name) {
+            let stored = MEMORY_LAS_FILES.get(&self.file_name).ok_or_else(|| {
+                Error::new(
+                    ErrorKind::NotFound,
+                    format!("No in-memory LiDAR file named {}", self.file_name),
+                )
+            })?;
+            let file_name = self.file_name.clone();
+            let file_mode = self.file_mode.clone();
+            *self = stored;
+            self.file_name = file_name;
+            self.file_mode = file_mode;
+            return Ok(());
+        }
         if self.file_name.to_lowercase().ends_with(".zlidar") {
             return self.read_zlidar_data();
         }
@@ -2884,6 +2904,15 @@ impl LasFile {
             self.header.z_scale_factor = dec;
         }
 
+        if is_memory_path(&self.file_name) {
+            let mut stored = self.clone();
+            stored.file_mode = "r".to_string();
+            MEMORY_LAS_FILES.insert(&self.file_name, stored);
+            return Ok(());
+        }
+
+        self.stamp_provenance();
+
         if !self.file_name.to_lowercase().ends_with(".zip")
             && !self.file_name.to_lowercase().ends_with(".zlidar")
             && !self.file_name.to_lowercase().ends_with(".laz")
@@ -2921,6 +2950,38 @@ impl LasFile {
         Ok(())
     }
 
+    /// Returns the provenance record stored in the file's VLRs, if there is one.
+    pub fn get_provenance(&self) -> Option<Provenance> {
+        self.vlr_data
+            .iter()
+            .rev()
+            .find(|vlr| is_provenance_vlr(vlr))
+            .and_then(|vlr| Provenance::from_json(&String::from_utf8_lossy(&vlr.binary_data)).ok())
+    }
+
+    /// Replaces any provenance VLR copied over from an input file with the record of the tool
+    /// that is writing this file. Records that are too large for a VLR are left to the caller
+    /// to store in a sidecar file.
+    fn stamp_provenance(&mut self) {
+        let record = match provenance::current() {
+            Some(r) => r,
+            None => return,
+        };
+        self.vlr_data.retain(|vlr| !is_provenance_vlr(vlr));
+        let json = record.to_json().into_bytes();
+        if json.len() <= u16::MAX as usize {
+            self.vlr_data.push(Vlr {
+                reserved: 0u16,
+                user_id: PROVENANCE_VLR_USER_ID.to_string(),
+                record_id: PROVENANCE_VLR_RECORD_ID,
+                record_length_after_header: json.len() as u16,
+                description: PROVENANCE_VLR_DESCRIPTION.to_string(),
+                binary_data: json,
+            });
+        }
+        self.header.number_of_vlrs = self.vlr_data.len() as u32;
+    }
+
     fn write_data<W: Write>(&mut self, writer: &mut W) -> Result<(), Error> {
         /////////////////////////////////
         // Write the header to the file /
@@ -6947,6 +7008,15 @@ impl PointRecord10 {
     }
 }
 
+const PROVENANCE_VLR_USER_ID: &str = "WhiteboxTools";
+const PROVENANCE_VLR_RECORD_ID: u16 = 1u16;
+const PROVENANCE_VLR_DESCRIPTION: &str = "Provenance";
+
+fn is_provenance_vlr(vlr: &Vlr) -> bool {
+    vlr.user_id.trim_end_matches(char::from(0)).trim() == PROVENANCE_VLR_USER_ID
+        && vlr.record_id == PROVENANCE_VLR_RECORD_ID
+}
+
 fn fixed_length_string(s: &str, len: usize) -> String {
     let mut ret = "".to_string();
     let mut n = 0;
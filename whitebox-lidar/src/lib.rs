@@ -2,7 +2,7 @@
 This code is part of the WhiteboxTools geospatial analysis library.
 Authors: Dr. John Lindsay
 Created: June 21, 2017
-Last Modified: 12/04/2018
+Last Modified: 18/10/2026
 License: MIT
 */
 
@@ -20,6 +20,7 @@ pub use self::las::GlobalEncodingField;
 pub use self::las::GpsTimeType;
 pub use self::las::LasFile;
 pub use self::las::LidarPointRecord;
+pub use self::las::MEMORY_LAS_FILES;
 pub use self::las::PointRecord0;
 pub use self::las::PointRecord1;
 pub use self::las::PointRecord10;
@@ -8,6 +8,7 @@ License: MIT
 
 use whitebox_raster::*;
 use whitebox_vector::*;
+use whitebox_common::random::{self, StdRng};
 use whitebox_common::utils::get_formatted_elapsed_time;
 use num_cpus;
 use std::time::Instant;
@@ -312,10 +313,8 @@ fn run(args: &Vec<String>) -> Result<(), std::io::Error> {
 
     let num_files = input_files.len();
 
-    let seed: u64 = if rng_seed < 0 { // no seed specified, random seed
-        thread_rng().gen_range(u64::MIN, u64::MAX)
-    } else { rng_seed as u64 };
-    let mut rng : StdRng = StdRng::seed_from_u64(seed);
+    let seed = if rng_seed < 0 { None } else { Some(rng_seed as u64) }; // no seed specified, random seed
+    let mut rng: StdRng = random::new_rng(seed, 0);
 
     //let num_samp_input = num_samples;
     if num_samples < (25*num_files) {
@@ -725,14 +724,14 @@ fn run(args: &Vec<String>) -> Result<(), std::io::Error> {
         // draw new samples
         if rand2 < rs_prob || max <= 1 { // random sample
             // if max <= 1, there is no worst strata, do a random sample instead
-            ridx = rng.gen_range(0, samples.len());
+            ridx = rng.gen_range(0..samples.len());
             samples[ridx] = reservoir.pop().unwrap();
         } else { //swap random worst strata sample with fresh random sample
             //pick random worst sample by index
             worst_indices = (0..samples.len())
                     .filter(|s| samples[*s].k == worst_k
                             && samples[*s].q == worst_q).collect();
-            ridx = rng.gen_range(0, worst_indices.len());
+            ridx = rng.gen_range(0..worst_indices.len());
             samples[ridx] = reservoir.pop().unwrap();
         }
 
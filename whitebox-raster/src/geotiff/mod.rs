@@ -228,6 +228,118 @@ pub fn print_tags<'a>(file_name: &'a String) -> Result<(), Error> {
     Ok(())
 }
 
+/// Reads the metadata entries of a GeoTIFF from its GDAL_METADATA tag, without reading the
+/// rest of the file. Only the first IFD is searched, as in `read_geotiff`.
+pub fn read_geotiff_metadata(file_name: &str) -> Result<Vec<String>, Error> {
+    let f = File::open(file_name)?;
+    let br = BufReader::new(f);
+    let mut th = ByteOrderReader::<BufReader<File>>::new(br, Endianness::LittleEndian);
+    let bo_indicator1 = th.read_u8()?;
+    let bo_indicator2 = th.read_u8()?;
+    if bo_indicator1 == 77 && bo_indicator2 == 77 {
+        th.set_byte_order(Endianness::BigEndian);
+    } else if bo_indicator1 != 73 || bo_indicator2 != 73 {
+        return Err(Error::new(
+            ErrorKind::InvalidData,
+            "Incorrect TIFF header. Unrecognized byte-order indicator.",
+        ));
+    }
+    let is_big_tiff = match th.read_u16()? {
+        42 => false,
+        43 => true,
+        _ => {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                "Incorrect TIFF header. Unrecognized magic number.",
+            ))
+        }
+    };
+    let ifd_offset = if !is_big_tiff {
+        th.read_u32()? as usize
+    } else {
+        th.read_u16()?; // bytesize of offsets
+        th.read_u16()?;
+        th.read_u64()? as usize
+    };
+    th.seek(ifd_offset);
+    let num_directories = if !is_big_tiff {
+        th.read_u16()? as u64
+    } else {
+        th.read_u64()?
+    };
+    for _ in 0..num_directories {
+        let tag_id = th.read_u16()?;
+        let _field_type = th.read_u16()?;
+        let (num_values, value_offset, inline_size) = if !is_big_tiff {
+            (th.read_u32()? as u64, th.read_u32()? as u64, 4u64)
+        } else {
+            (th.read_u64()?, th.read_u64()?, 8u64)
+        };
+        if tag_id != TAG_GDAL_METADATA {
+            continue;
+        }
+        // The tag is ASCII, so there is one byte per value.
+        if num_values > inline_size {
+            th.seek(value_offset as usize);
+        } else {
+            let cur_pos = th.pos();
+            th.seek(cur_pos - inline_size as usize);
+        }
+        let mut data = Vec::with_capacity(num_values as usize);
+        for _ in 0..num_values {
+            data.push(th.read_u8()?);
+        }
+        return Ok(parse_gdal_metadata(&String::from_utf8_lossy(&data)));
+    }
+    Ok(vec![])
+}
+
+/// Creates the GDAL_METADATA XML document used to store a raster's metadata entries.
+fn gdal_metadata_xml(metadata: &[String]) -> String {
+    let mut xml = "<GDALMetadata>\n".to_string();
+    for (i, entry) in metadata.iter().enumerate() {
+        let escaped = entry
+            .replace("&", "&amp;")
+            .replace("<", "&lt;")
+            .replace(">", "&gt;")
+            .replace("\"", "&quot;");
+        xml.push_str(&format!(
+            "  <Item name=\"{}{}\">{}</Item>\n",
+            WBT_METADATA_ITEM,
+            i + 1,
+            escaped
+        ));
+    }
+    xml.push_str("</GDALMetadata>");
+    xml
+}
+
+/// Reads the metadata entries written by `gdal_metadata_xml` from a GDAL_METADATA XML document.
+/// Items written by other software are ignored.
+fn parse_gdal_metadata(xml: &str) -> Vec<String> {
+    let mut metadata = vec![];
+    let start_tag = format!("<Item name=\"{}", WBT_METADATA_ITEM);
+    let mut remaining = xml;
+    while let Some(start) = remaining.find(&start_tag) {
+        remaining = &remaining[start..];
+        let (content_start, content_end) = match (remaining.find('>'), remaining.find("</Item>")) {
+            (Some(a), Some(b)) if a < b => (a + 1, b),
+            _ => break,
+        };
+        metadata.push(
+            remaining[content_start..content_end]
+                .replace("&quot;", "\"")
+                .replace("&gt;", ">")
+                .replace("&lt;", "<")
+                .replace("&amp;", "&"),
+        );
+        remaining = &remaining[content_end..];
+    }
+    metadata
+}
+
+const WBT_METADATA_ITEM: &str = "WBT_METADATA_";
+
 pub fn read_geotiff<'a>(
     file_name: &'a String,
     configs: &'a mut RasterConfigs,
@@ -526,6 +638,10 @@ pub fn read_geotiff<'a>(
         _ => -32768f64,
     };
 
+    if let Some(ifd) = ifd_map.get(&TAG_GDAL_METADATA) {
+        configs.metadata = parse_gdal_metadata(&ifd.interpret_as_ascii());
+    }
+
     // GeoKeyDirectoryTag
     match ifd_map.get(&34735) {
         Some(ifd) => {
@@ -2914,6 +3030,22 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
         }
     }
 
+    // TAG_GDAL_METADATA tag (42112)
+    if r.configs.metadata.len() > 0 {
+        let mut metadata_bytes = gdal_metadata_xml(&r.configs.metadata).into_bytes();
+        if metadata_bytes.len() % 2 == 0 {
+            metadata_bytes.push(32);
+        }
+        metadata_bytes.push(0);
+        ifd_entries.push(Entry::new(
+            TAG_GDAL_METADATA,
+            DT_ASCII,
+            metadata_bytes.len() as u64,
+            larger_values_data.len() as u64,
+        ));
+        larger_values_data.write_bytes(&metadata_bytes)?;
+    }
+
     // TAG_GDAL_NODATA tag (42113)
     let nodata_str = format!("{}", r.configs.nodata);
     let mut nodata_bytes = nodata_str.into_bytes();
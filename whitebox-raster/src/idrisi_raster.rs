@@ -9,6 +9,23 @@ use std::io::Error;
 use std::io::ErrorKind;
 use std::mem;
 
+/// Reads the metadata entries from the header (.rdc) file of an Idrisi raster without reading
+/// the data file.
+pub fn read_idrisi_metadata(file_name: &str) -> Result<Vec<String>, Error> {
+    let header_file = Path::new(file_name).with_extension("rdc");
+    let f = BufReader::new(File::open(header_file)?);
+    let mut metadata = vec![];
+    for line in f.lines() {
+        let line = line?;
+        let vec = line.split(":").collect::<Vec<&str>>();
+        let key = vec[0].to_lowercase();
+        if key.contains("lineage") || key.contains("comment") {
+            metadata.push(vec[1..].join(":").trim().to_string());
+        }
+    }
+    Ok(metadata)
+}
+
 pub fn read_idrisi(
     file_name: &String,
     configs: &mut RasterConfigs,
@@ -109,7 +126,8 @@ pub fn read_idrisi(
         } else if vec[0].to_lowercase().contains("lineage")
             || vec[0].to_lowercase().contains("comment")
         {
-            configs.metadata.push(vec[1].trim().to_string());
+            // Provenance entries are written with their colons intact.
+            configs.metadata.push(vec[1..].join(":").trim().to_string());
         } else if vec[0].to_lowercase().contains("file type")
             && !vec[0].to_lowercase().contains("lineage")
         {
@@ -350,7 +368,11 @@ pub fn write_idrisi<'a>(r: &'a mut Raster) -> Result<(), Error> {
     writer.write_all("byteorder   : LITTLE_ENDIAN\n".as_bytes())?;
 
     for md in &r.configs.metadata {
-        let s = format!("comment     : {}\n", md.replace(":", ";"));
+        let s = if Provenance::from_metadata_entry(md).is_some() {
+            format!("comment     : {}\n", md)
+        } else {
+            format!("comment     : {}\n", md.replace(":", ";"))
+        };
         writer.write_all(s.as_bytes())?;
     }
 
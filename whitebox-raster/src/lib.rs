@@ -2,7 +2,7 @@
 This tool is part of the WhiteboxTools geospatial analysis library.
 Authors: Dr. John Lindsay
 Created: 02/06/2017
-Last Modified: 09/02/2019
+Last Modified: 18/10/2026
 License: MIT
 */
 
@@ -36,6 +36,7 @@ use self::surfer7_raster::*;
 use self::surfer_ascii_raster::*;
 use self::whitebox_raster::*;
 use num_traits::cast::AsPrimitive;
+use whitebox_common::provenance::{self, Provenance};
 use whitebox_common::structures::{Array2D, BoundingBox};
 use whitebox_common::utils::*;
 use std::cmp::Ordering::Equal;
@@ -53,6 +54,19 @@ use std::sync::Arc;
 use std::thread;
 // use rayon::prelude::*;
 
+/// In-memory rasters, addressed by tools with `memory://name` file names. Reading such a
+/// file returns a copy of the stored raster and writing one stores a copy of the output,
+/// without any disk I/O. A raster inserted with `MEMORY_RASTERS.insert("dem", raster)` can
+/// be used as the input `memory://dem`, and an output written to `memory://slope` can be
+/// retrieved with `MEMORY_RASTERS.remove("slope")`.
+pub static MEMORY_RASTERS: MemoryStore<Raster> = MemoryStore::new();
+
+/// The (lower-case) file extensions of the supported raster formats.
+pub const RASTER_FILE_EXTENSIONS: [&str; 15] = [
+    "tas", "dep", "tif", "tiff", "gtif", "gtiff", "bil", "flt", "rdc", "rst", "sdat", "sgrd",
+    "grd", "asc", "txt",
+];
+
 /// Raster is a common data structure that abstracts over several raster data formats,
 /// including GeoTIFFs, ArcGIS ASCII and binary rasters, Whitebox rasters, Idrisi
 /// rasters, Saga rasters, and GRASS ASCII rasters.
@@ -144,6 +158,19 @@ impl Raster {
             ..Default::default()
         };
         if r.file_mode.contains("r") {
+            if is_memory_path(file_name) {
+                return match MEMORY_RASTERS.get(file_name) {
+                    Some(mut stored) => {
+                        stored.file_name = r.file_name;
+                        stored.file_mode = r.file_mode;
+                        Ok(stored)
+                    }
+                    None => Err(Error::new(
+                        ErrorKind::NotFound,
+                        format!("No in-memory raster named {}", file_name),
+                    )),
+                };
+            }
             match get_raster_type_from_file(file_name.to_string(), fm) {
                 RasterType::ArcBinary => {
                     let _ = read_arcbinary(&r.file_name, &mut r.configs, &mut r.data)?;
@@ -201,10 +228,31 @@ impl Raster {
         // Err(Error::new(ErrorKind::Other, "Error creating raster"))
     }
 
+    /// Reads the metadata entries of a raster file without reading its data. Only the formats
+    /// that can store metadata (GeoTIFF, Whitebox, and Idrisi rasters) are supported; other
+    /// formats have no entries.
+    pub fn read_metadata(file_name: &str) -> Result<Vec<String>, Error> {
+        if is_memory_path(file_name) {
+            return match MEMORY_RASTERS.get(file_name) {
+                Some(stored) => Ok(stored.configs.metadata),
+                None => Err(Error::new(
+                    ErrorKind::NotFound,
+                    format!("No in-memory raster named {}", file_name),
+                )),
+            };
+        }
+        match get_raster_type_from_file(file_name.to_string(), "r".to_string()) {
+            RasterType::GeoTiff => read_geotiff_metadata(file_name),
+            RasterType::Whitebox => read_whitebox_metadata(file_name),
+            RasterType::IdrisiBinary => read_idrisi_metadata(file_name),
+            _ => Ok(vec![]),
+        }
+    }
+
     /// Creates a new in-memory `Raster` object with grid extent and location
     /// based on specified configurations contained within a `RasterConfigs`.
     pub fn initialize_using_config<'a>(file_name: &'a str, configs: &'a RasterConfigs) -> Raster {
-        let new_file_name = if file_name.contains(".") {
+        let new_file_name = if file_name.contains(".") || is_memory_path(file_name) {
             file_name.to_string()
         } else {
             // likely no extension provided; default to .tif
@@ -260,7 +308,7 @@ impl Raster {
     /// Creates a new in-memory `Raster` object with grid extent and location
     /// based on specified configurations contained within a `RasterConfigs`.
     pub fn initialize_using_array2d<'a, T: AsPrimitive<f64> + Copy + AddAssign + SubAssign>(file_name: &'a str, configs: &'a RasterConfigs, data: Array2D<T>) -> Raster {
-        let new_file_name = if file_name.contains(".") {
+        let new_file_name = if file_name.contains(".") || is_memory_path(file_name) {
             file_name.to_string()
         } else {
             // likely no extension provided; default to .tif
@@ -321,7 +369,7 @@ impl Raster {
     /// Creates a new in-memory `Raster` object with grid extent and location based
     /// on an existing `Raster` contained within `file_name`.
     pub fn initialize_using_file<'a>(file_name: &'a str, input: &'a Raster) -> Raster {
-        let new_file_name = if file_name.contains(".") {
+        let new_file_name = if file_name.contains(".") || is_memory_path(file_name) {
             file_name.to_string()
         } else {
             // likely no extension provided; default to .tif
@@ -377,7 +425,7 @@ impl Raster {
         configs: &'a RasterConfigs,
         array: &'a Array2D<T>,
     ) -> Raster {
-        let new_file_name = if file_name.contains(".") {
+        let new_file_name = if file_name.contains(".") || is_memory_path(file_name) {
             file_name.to_string()
         } else {
             // likely no extension provided; default to .tif
@@ -1142,6 +1190,21 @@ impl Raster {
                 "Cannot write raster that is not created in write mmode ('w').",
             ));
         }
+        if is_memory_path(&self.file_name) {
+            let mut stored = self.clone();
+            stored.file_mode = "r".to_string();
+            stored.update_min_max();
+            MEMORY_RASTERS.insert(&self.file_name, stored);
+            return Ok(());
+        }
+        // Stamp the raster with the provenance of the tool that is writing it, replacing any
+        // record that was copied over from an input raster's configs.
+        if let Some(record) = provenance::current() {
+            self.configs
+                .metadata
+                .retain(|entry| Provenance::from_metadata_entry(entry).is_none());
+            self.configs.metadata.push(record.to_metadata_entry());
+        }
         match self.raster_type {
             RasterType::ArcAscii => {
                 let _ = match write_arcascii(self) {
@@ -1357,6 +1420,10 @@ impl Default for RasterType {
 }
 
 fn get_raster_type_from_file(file_name: String, file_mode: String) -> RasterType {
+    if is_memory_path(&file_name) {
+        // in-memory rasters have no file format
+        return RasterType::Unknown;
+    }
     // get the file extension
     let extension: String = match Path::new(&file_name).extension().unwrap().to_str() {
         Some(n) => n.to_string().to_lowercase(),
@@ -10,6 +10,22 @@ use std::io::{BufReader, BufWriter, Cursor};
 use std::mem;
 use std::path::Path;
 
+/// Reads the metadata entries from the header (.dep) file of a Whitebox raster without reading
+/// the data file.
+pub fn read_whitebox_metadata(file_name: &str) -> Result<Vec<String>, Error> {
+    let header_file = Path::new(file_name).with_extension("dep");
+    let f = BufReader::new(File::open(header_file)?);
+    let mut metadata = vec![];
+    for line in f.lines() {
+        let line = line?;
+        let vec = line.split(":").collect::<Vec<&str>>();
+        if vec[0].to_lowercase().contains("metadata") {
+            metadata.push(vec[1..].join(":").trim().to_string());
+        }
+    }
+    Ok(metadata)
+}
+
 pub fn read_whitebox(
     file_name: &String,
     configs: &mut RasterConfigs,
@@ -110,7 +126,8 @@ pub fn read_whitebox(
                 configs.endian = Endianness::BigEndian;
             }
         } else if vec[0].to_lowercase().contains("metadata") {
-            configs.metadata.push(vec[1].trim().to_string());
+            // Provenance entries are written with their colons intact.
+            configs.metadata.push(vec[1..].join(":").trim().to_string());
         }
     }
 
@@ -406,7 +423,11 @@ pub fn write_whitebox<'a>(r: &'a mut Raster) -> Result<(), Error> {
     writer.write_all(s.as_bytes())?;
 
     for md in &r.configs.metadata {
-        let s = format!("Metadata Entry:\t{}\n", md.replace(":", ";"));
+        let s = if Provenance::from_metadata_entry(md).is_some() {
+            format!("Metadata Entry:\t{}\n", md)
+        } else {
+            format!("Metadata Entry:\t{}\n", md.replace(":", ";"))
+        };
         writer.write_all(s.as_bytes())?;
     }
 
@@ -0,0 +1,84 @@
+/*
+This code is part of the WhiteboxTools geospatial analysis library.
+Authors: Dr. John Lindsay
+Created: 18/10/2026
+Last Modified: 18/10/2026
+License: MIT
+*/
+
+/*!
+WhiteboxTools is an advanced geospatial data analysis platform developed at
+the University of Guelph's Geomorphometry and Hydrogeomatics Research Group (GHRG).
+
+In addition to the `whitebox_tools` command-line program, the tools can be embedded
+in other Rust programs through this library. A `ToolManager` runs any built-in tool
+by name, either with command-line style arguments (`run_tool`) or with structured
+`ToolArgs` (`run_tool_with_args`), in which case failures are reported as a `ToolError`.
+
+```no_run
+use whitebox_tools::{ToolArgs, ToolManager};
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let tm = ToolManager::new("/path/to/data/", &false)?;
+    let args = ToolArgs::new()
+        .arg("dem", "DEM.tif")
+        .arg("output", "slope.tif")
+        .arg("units", "degrees");
+    tm.run_tool_with_args("Slope", &args)?;
+    Ok(())
+}
+```
+
+Individual tools can also be retrieved with `ToolManager::get_tool` and used through
+the `WhiteboxTool` trait.
+
+A running tool can be stopped from another thread by cancelling a clone of the
+manager's `cancellation_token`, or given a time limit by replacing the token with
+`CancellationToken::with_timeout`. The tool then returns an error of kind
+`Interrupted` (or `TimedOut`), and any output files it had started writing are deleted.
+
+File names beginning with `memory://` refer to data sets held in memory rather than on
+disk (see `MEMORY_RASTERS`, `MEMORY_SHAPEFILES`, and `MEMORY_LAS_FILES`). Writing
+intermediate outputs to memory allows the output of one tool to feed the next
+without touching the filesystem.
+
+```no_run
+use whitebox_tools::whitebox_raster::{Raster, MEMORY_RASTERS};
+use whitebox_tools::{ToolArgs, ToolManager};
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let tm = ToolManager::new("/path/to/data/", &false)?;
+    MEMORY_RASTERS.insert("dem", Raster::new("/path/to/data/DEM.tif", "r")?);
+    tm.run_tool_with_args(
+        "FeaturePreservingSmoothing",
+        &ToolArgs::new().arg("dem", "memory://dem").arg("output", "memory://smoothed"),
+    )?;
+    tm.run_tool_with_args(
+        "Slope",
+        &ToolArgs::new().arg("dem", "memory://smoothed").arg("output", "memory://slope"),
+    )?;
+    let slope = MEMORY_RASTERS.remove("slope").unwrap();
+    println!("Maximum slope: {}", slope.configs.maximum);
+    MEMORY_RASTERS.clear();
+    Ok(())
+}
+```
+*/
+
+pub mod lineage;
+pub mod tools;
+pub mod server;
+pub mod workflow;
+
+pub use crate::tools::{
+    ArgValue, CancellationToken, ParsedArgs, ToolArgs, ToolError, ToolManager, WhiteboxTool,
+};
+pub use whitebox_common;
+pub use whitebox_lidar;
+pub use whitebox_raster;
+pub use whitebox_vector;
+
+use nalgebra as na;
+
+#[macro_use]
+extern crate serde_derive;
@@ -0,0 +1,125 @@
+/*
+This code is part of the WhiteboxTools geospatial analysis library.
+Authors: Dr. John Lindsay
+Created: 18/10/2026
+Last Modified: 18/10/2026
+License: MIT
+*/
+
+//! Reconstructs the processing history of a dataset from the provenance records that the
+//! `ToolManager` stamps on every output.
+//!
+//! A record names the tool that created a file, the tool's version and arguments, and the
+//! checksums of its input files at the time. Records are read from a raster's metadata
+//! (GeoTIFF, Whitebox, and Idrisi rasters), from a VLR in a LAS file, or otherwise from a
+//! `.whitebox.json` sidecar file (e.g. `roads.whitebox.json` for `roads.shp`). The history is
+//! followed back through each input file that has a record of its own, and each input is
+//! reported as unchanged, modified, or missing by comparing its current checksum with the
+//! one that was recorded.
+
+use std::collections::HashSet;
+use std::io::{Error, ErrorKind};
+use std::path::Path;
+use whitebox_common::progress;
+use whitebox_common::provenance::Provenance;
+use whitebox_common::utils::{hash_file_set, is_memory_path};
+use whitebox_lidar::LasFile;
+use whitebox_raster::Raster;
+
+/// Prints the processing history of `file_name`, which is resolved against `working_directory`.
+pub fn print_lineage(file_name: &str, working_directory: &str) -> Result<(), Error> {
+    let file_name = resolve_file_name(file_name, working_directory);
+    if is_memory_path(&file_name) {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            "The lineage of an in-memory data set cannot be reconstructed.",
+        ));
+    }
+    if !Path::new(&file_name).is_file() {
+        return Err(Error::new(
+            ErrorKind::NotFound,
+            format!("The file {} does not exist.", file_name),
+        ));
+    }
+    progress::message(&file_name);
+    if read_provenance(&file_name).is_none() {
+        progress::message("No provenance record was found; the file was not created by WhiteboxTools.");
+        return Ok(());
+    }
+    let mut visited = HashSet::new();
+    print_history(&file_name, 1, &mut visited);
+    Ok(())
+}
+
+/// Reads the provenance record of a file. A sidecar file is only left next to a file whose
+/// own record could not be stamped, so it takes precedence over any record within the file.
+pub fn read_provenance(file_name: &str) -> Option<Provenance> {
+    Provenance::read_sidecar(file_name).or_else(|| read_embedded_provenance(file_name))
+}
+
+/// Reads the provenance record stored within a file: in a raster's metadata, which is read
+/// from the header alone, or in a LAS file's VLRs.
+pub fn read_embedded_provenance(file_name: &str) -> Option<Provenance> {
+    let ext = Path::new(file_name)
+        .extension()
+        .map(|e| e.to_string_lossy().to_lowercase())
+        .unwrap_or_default();
+    match ext.as_str() {
+        "tif" | "tiff" | "dep" | "tas" | "rdc" | "rst" => match Raster::read_metadata(file_name) {
+            Ok(metadata) => Provenance::from_metadata(&metadata),
+            Err(_) => None,
+        },
+        "las" | "laz" | "zlidar" | "zip" => match LasFile::new(file_name, "rh") {
+            Ok(lf) => lf.get_provenance(),
+            Err(_) => None,
+        },
+        _ => None,
+    }
+}
+
+fn print_history(file_name: &str, depth: usize, visited: &mut HashSet<String>) {
+    let indent = "    ".repeat(depth);
+    let record = match read_provenance(file_name) {
+        Some(r) => r,
+        None => return,
+    };
+    if !visited.insert(file_name.to_string()) {
+        progress::message(format!("{}(history shown above)", indent));
+        return;
+    }
+    progress::message(format!(
+        "{}{} (WhiteboxTools v{}), {}",
+        indent, record.tool, record.version, record.timestamp
+    ));
+    progress::message(format!("{}Args: {}", indent, record.args.join(" ")));
+    if let Some(seed) = record.seed {
+        progress::message(format!("{}Seed: {}", indent, seed));
+    }
+    for input in &record.inputs {
+        let status = if is_memory_path(&input.file) {
+            "in memory"
+        } else {
+            match (&input.checksum, hash_file_set(&input.file)) {
+                (_, None) => "missing",
+                (Some(recorded), Some(current)) if *recorded == current => "unchanged",
+                (Some(_), Some(_)) => "modified",
+                (None, Some(_)) => "not checked",
+            }
+        };
+        progress::message(format!("{}Input: {} [{}]", indent, input.file, status));
+        if status != "missing" && status != "in memory" {
+            print_history(&input.file, depth + 1, visited);
+        }
+    }
+}
+
+fn resolve_file_name(file_name: &str, working_directory: &str) -> String {
+    if is_memory_path(file_name) || Path::new(file_name).is_absolute() || working_directory.is_empty() {
+        file_name.to_string()
+    } else {
+        Path::new(working_directory)
+            .join(file_name)
+            .to_string_lossy()
+            .to_string()
+    }
+}
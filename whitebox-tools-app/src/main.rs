@@ -2,7 +2,7 @@
 This code is part of the WhiteboxTools geospatial analysis library.
 Authors: Dr. John Lindsay
 Created: 21/06/2017
-Last Modified: 23/03/2023
+Last Modified: 18/10/2026
 License: MIT
 */
 
@@ -17,39 +17,32 @@ by the WhiteboxTools library:
 
 | Command           | Description                                                                                       |
 | ----------------- | ------------------------------------------------------------------------------------------------- |
+| --batch           | Runs a tool on each file matched by a pattern; --dem="*.tif" --output="{stem}_slope.tif".         |
 | --cd, --wd        | Changes the working directory; used in conjunction with --run flag.                               |
 | -h, --help        | Prints help information.                                                                          |
+| --json-events     | Prints tool progress, warnings and outputs as JSON lines, one event per line; implies -v.         |
 | -l, --license     | Prints the whitebox-tools license. Tool names may also be used, --license=\"Slope\"               |
+| --lineage         | Prints the processing history of a file created by WhiteboxTools; --lineage=\"streams.tif\".      |
 | --listtools       | Lists all available tools, with tool descriptions. Keywords may also be used, --listtools slope.  |
 | -r, --run         | Runs a tool; used in conjunction with --cd flag; -r="LidarInfo".                                  |
+| --seed            | Seeds the random number generators of stochastic tools, for reproducible outputs; --seed=42.      |
+| --serve           | Serves the tools over HTTP/JSON-RPC at the given address; --serve=127.0.0.1:8080.                 |
+| --timeout         | Stops a tool, or workflow, that runs for longer than the given number of seconds; --timeout=600.  |
 | --toolbox         | Prints the toolbox associated with a tool; --toolbox=Slope.                                       |
 | --toolhelp        | Prints the help associated with a tool; --toolhelp="LidarInfo".                                   |
 | --toolparameters  | Prints the parameters (in json form) for a specific tool; --toolparameters=\"LidarInfo\".         |
 | -v                | Verbose mode. With this flag set to false, tool outputs will not be printed. -v=true, -v=false    |
 | --viewcode        | Opens the source code of a tool in a web browser; --viewcode=\"LidarInfo\".                       |
 | --version         | Prints the version information.                                                                   |
+| --workflow        | Runs a workflow of tools described in a JSON file; --workflow=\"streams.json\".                   |
 
 */
 
-// pub mod algorithms;
-// pub mod lidar;
-// pub mod raster;
-// pub mod rendering;
-// pub mod spatial_ref_system;
-// pub mod structures;
-pub mod tools;
-// pub mod utils;
-// pub mod vector;
-
-use crate::tools::ToolManager;
-use nalgebra as na;
-// use rstar;
+use whitebox_tools::{CancellationToken, ToolManager};
 use std::env;
-use std::io::Error;
+use std::io::{Error, ErrorKind};
 use std::path;
-
-#[macro_use]
-extern crate serde_derive;
+use std::time::Duration;
 
 // extern crate late_static;
 // use late_static::LateStatic;
@@ -97,6 +90,13 @@ fn run() -> Result<(), Error> {
     let mut list_tools = false;
     let mut keywords: Vec<String> = vec![];
     let mut view_code = false;
+    let mut workflow_file = String::new();
+    let mut json_events = false;
+    let mut timeout: Option<f64> = None;
+    let mut serve_address = String::new();
+    let mut lineage_file = String::new();
+    let mut max_jobs = 1usize;
+    let mut max_queue = 100usize;
     let mut tool_args_vec: Vec<String> = vec![];
     // let mut verbose = false;
     let mut finding_working_dir = false;
@@ -315,6 +315,92 @@ fn run() -> Result<(), Error> {
                 configs.max_procs = val;
                 configs_modified = true;
             }
+        } else if arg.starts_with("-workflow") || arg.starts_with("--workflow") {
+            let mut v = arg
+                .replace("--workflow", "")
+                .replace("-workflow", "")
+                .replace("\"", "")
+                .replace("\'", "");
+            if v.starts_with("=") {
+                v = v[1..v.len()].to_string();
+            }
+            workflow_file = v;
+        } else if arg.starts_with("-lineage") || arg.starts_with("--lineage") {
+            let mut v = arg
+                .replace("--lineage", "")
+                .replace("-lineage", "")
+                .replace("\"", "")
+                .replace("\'", "");
+            if v.starts_with("=") {
+                v = v[1..v.len()].to_string();
+            }
+            lineage_file = v;
+        } else if arg.starts_with("-serve") || arg.starts_with("--serve") {
+            let mut v = arg
+                .replace("--serve", "")
+                .replace("-serve", "")
+                .replace("\"", "")
+                .replace("\'", "");
+            if v.starts_with("=") {
+                v = v[1..v.len()].to_string();
+            }
+            serve_address = if v.is_empty() { "127.0.0.1:8080".to_string() } else { v };
+        } else if arg.starts_with("-max_jobs") || arg.starts_with("--max_jobs") {
+            let mut v = arg
+                .replace("--max_jobs", "")
+                .replace("-max_jobs", "")
+                .replace("\"", "")
+                .replace("\'", "");
+            if v.starts_with("=") {
+                v = v[1..v.len()].to_string();
+            }
+            match v.trim().parse::<usize>() {
+                Ok(val) => max_jobs = val,
+                _ => {
+                    return Err(Error::new(
+                        ErrorKind::InvalidInput,
+                        format!("Invalid --max_jobs value '{}'; expected the number of jobs to run at once.", v),
+                    ))
+                }
+            }
+        } else if arg.starts_with("-max_queue") || arg.starts_with("--max_queue") {
+            let mut v = arg
+                .replace("--max_queue", "")
+                .replace("-max_queue", "")
+                .replace("\"", "")
+                .replace("\'", "");
+            if v.starts_with("=") {
+                v = v[1..v.len()].to_string();
+            }
+            match v.trim().parse::<usize>() {
+                Ok(val) => max_queue = val,
+                _ => {
+                    return Err(Error::new(
+                        ErrorKind::InvalidInput,
+                        format!("Invalid --max_queue value '{}'; expected the number of jobs that may wait in the queue.", v),
+                    ))
+                }
+            }
+        } else if arg.starts_with("-timeout") || arg.starts_with("--timeout") {
+            let mut v = arg
+                .replace("--timeout", "")
+                .replace("-timeout", "")
+                .replace("\"", "")
+                .replace("\'", "");
+            if v.starts_with("=") {
+                v = v[1..v.len()].to_string();
+            }
+            match v.trim().parse::<f64>() {
+                Ok(val) if val > 0f64 && val.is_finite() => timeout = Some(val),
+                _ => {
+                    return Err(Error::new(
+                        ErrorKind::InvalidInput,
+                        format!("Invalid --timeout value '{}'; the time limit is given in seconds.", v),
+                    ))
+                }
+            }
+        } else if arg.trim() == "-json-events" || arg.trim() == "--json-events" {
+            json_events = true;
         } else if arg.starts_with("-version") || arg.starts_with("--version") {
             version();
             return Ok(());
@@ -360,7 +446,58 @@ fn run() -> Result<(), Error> {
         whitebox_common::configs::save_configs(&configs)?;
     }
 
-    let tm = ToolManager::new(&configs.working_directory, &configs.verbose_mode)?;
+    if json_events {
+        // Events are only emitted in verbose mode, but this shouldn't change the saved setting.
+        whitebox_common::progress::set_json_events(true);
+        configs.verbose_mode = true;
+    }
+
+    // A tool that is run takes --seed as one of its own arguments. Otherwise, e.g. for a workflow
+    // or the server, it is the seed that stochastic tools without a --seed of their own fall back on.
+    let seed = if run_tool {
+        None
+    } else {
+        take_seed_flag(&mut tool_args_vec)?
+    };
+    whitebox_common::random::set_default_seed(seed);
+
+    // The time limit starts now, and covers every tool that is run, e.g. in a workflow.
+    let cancellation_token = match timeout {
+        Some(t) => CancellationToken::with_timeout(Duration::from_secs_f64(t)),
+        None => CancellationToken::new(),
+    };
+
+    if !serve_address.is_empty() {
+        // Each job may itself use up to max_procs threads, so there's no point in running more
+        // jobs at once than there are processors available.
+        let num_procs = if configs.max_procs > 0 {
+            configs.max_procs as usize
+        } else {
+            num_cpus::get()
+        };
+        return whitebox_tools::server::serve(
+            &serve_address,
+            &configs.working_directory,
+            max_jobs.max(1).min(num_procs),
+            max_queue,
+        );
+    }
+
+    if !lineage_file.is_empty() {
+        return whitebox_tools::lineage::print_lineage(&lineage_file, &configs.working_directory);
+    }
+
+    if !workflow_file.is_empty() {
+        return whitebox_tools::workflow::run_workflow(
+            &workflow_file,
+            &configs.working_directory,
+            configs.verbose_mode,
+            &cancellation_token,
+        );
+    }
+
+    let mut tm = ToolManager::new(&configs.working_directory, &configs.verbose_mode)?;
+    tm.cancellation_token = cancellation_token;
     if run_tool {
         if tool_name.is_empty() && keywords.len() > 0 {
             tool_name = keywords[0].clone();
@@ -400,6 +537,38 @@ fn run() -> Result<(), Error> {
     Ok(())
 }
 
+/// Removes the `--seed` flag, in either the `--seed=42` or the `--seed 42` form, from a list of
+/// arguments, and returns its value.
+fn take_seed_flag(args: &mut Vec<String>) -> Result<Option<u64>, Error> {
+    let mut seed = None;
+    let mut i = 0;
+    while i < args.len() {
+        let arg = args[i].replace("\"", "").replace("\'", "");
+        let flag_val = arg.to_lowercase().replace("--", "-");
+        // Matched exactly, so that tool parameters such as --seed_pts are left alone.
+        if flag_val != "-seed" && !flag_val.starts_with("-seed=") {
+            i += 1;
+            continue;
+        }
+        args.remove(i);
+        let v = match arg.find('=') {
+            Some(pos) => arg[pos + 1..].to_string(),
+            None if i < args.len() => args.remove(i).replace("\"", "").replace("\'", ""),
+            None => String::new(),
+        };
+        match v.trim().parse::<u64>() {
+            Ok(val) => seed = Some(val),
+            _ => {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    format!("Invalid --seed value '{}'; the seed must be a non-negative integer.", v),
+                ))
+            }
+        }
+    }
+    Ok(seed)
+}
+
 fn help() {
     let mut ext = "";
     if cfg!(target_os = "windows") {
@@ -411,19 +580,28 @@ fn help() {
     let s = "WhiteboxTools Help
 
 The following commands are recognized:
+--batch             Runs a tool once for each input file matched by a pattern or list, naming outputs with a template; --dem=\"*.tif\" --output=\"{stem}_slope.tif\" --batch.
 --cd, --wd          Changes the working directory; used in conjunction with --run flag.
 --compress_rasters  Sets the compress_raster option in the settings.json file; determines if newly created rasters are compressed. e.g. --compress_rasters=true
 -h, --help          Prints help information.
+--json-events       Prints tool progress, warnings and outputs as JSON lines, one event per line; implies -v.
 -l, --license       Prints the whitebox-tools license. Tool names may also be used, --license=\"Slope\"
+--lineage           Prints the processing history of a file created by WhiteboxTools; --lineage=\"streams.tif\".
 --listtools         Lists all available tools. Keywords may also be used, --listtools slope.
+--max_jobs          Sets the number of jobs that --serve runs at once (default 1), up to --max_procs. e.g. --max_jobs=2
 --max_procs         Sets the maximum number of processors used. -1 = all available processors. e.g. --max_procs=2
+--max_queue         Sets the number of jobs that --serve holds in its queue (default 100). e.g. --max_queue=20
 -r, --run           Runs a tool; used in conjunction with --wd flag; -r=\"LidarInfo\".
+--seed              Seeds the random number generators of stochastic tools, for reproducible outputs; --seed=42.
+--serve             Serves the tools over HTTP/JSON-RPC until stopped; --serve=127.0.0.1:8080.
+--timeout           Stops a tool, or workflow, that runs for longer than the given number of seconds; --timeout=600.
 --toolbox           Prints the toolbox associated with a tool; --toolbox=Slope.
 --toolhelp          Prints the help associated with a tool; --toolhelp=\"LidarInfo\".
 --toolparameters    Prints the parameters (in json form) for a specific tool; --toolparameters=\"LidarInfo\".
 -v                  Verbose mode. Without this flag, tool outputs will not be printed.
 --viewcode          Opens the source code of a tool in a web browser; --viewcode=\"LidarInfo\".
 --version           Prints the version information.
+--workflow          Runs a workflow of tools described in a JSON file; --workflow=\"streams.json\".
 
 Example Usage:
 >> .*EXE_NAME -r=lidar_info --cd=\"*path*to*data*\" -i=input.las --vlr --geokeys
@@ -0,0 +1,656 @@
+/*
+This code is part of the WhiteboxTools geospatial analysis library.
+Authors: Dr. John Lindsay
+Created: 18/10/2026
+Last Modified: 18/10/2026
+License: MIT
+*/
+
+//! Serves a resident `ToolManager` over HTTP, so that a client can run many tools without
+//! starting a new `whitebox_tools` process for each one.
+//!
+//! Requests may be made as JSON-RPC 2.0 calls, posted to `/rpc`:
+//!
+//! ```json
+//! {"jsonrpc": "2.0", "id": 1, "method": "run",
+//!  "params": {"tool": "Slope", "args": {"dem": "DEM.tif", "output": "slope.tif"}}}
+//! ```
+//!
+//! | Method            | Params                  | Result                                           |
+//! | ----------------- | ----------------------- | ------------------------------------------------ |
+//! | `list_tools`      |                         | The name, toolbox, and description of each tool. |
+//! | `tool_parameters` | `tool`                  | The tool's parameters, as in `--toolparameters`. |
+//! | `run`             | `tool`, `args`          | The new job, which is queued to run.             |
+//! | `list_jobs`       |                         | The status of each job.                          |
+//! | `job_status`      | `job`                   | The job's status, progress, outputs, and error.  |
+//! | `job_events`      | `job`, `since` (opt.)   | The job's events from index `since` onward.      |
+//! | `cancel`          | `job`                   | The job's status, once it has been cancelled.    |
+//!
+//! The same calls are available as plain HTTP requests, which return the result directly:
+//! `GET /tools`, `GET /tools/{tool}`, `POST /jobs` (with a `{"tool": ..., "args": ...}` body),
+//! `GET /jobs`, `GET /jobs/{id}`, `GET /jobs/{id}/events?since=n`, and `POST /jobs/{id}/cancel`
+//! (or `DELETE /jobs/{id}`). In addition, `GET /jobs/{id}/stream` streams the job's events, one
+//! JSON object per line, until the job has finished.
+//!
+//! Tool arguments take the form accepted by `ToolArgs::from_json`; a job whose arguments are
+//! invalid fails when it is run. Events are those of the `--json-events` mode. At most `max_jobs`
+//! jobs run at once, and each may use up to `max_procs` threads; up to `max_queue` further jobs
+//! wait in the queue. Only the most recent `MAX_FINISHED_JOBS` finished jobs, and their events,
+//! are kept. The server has no authentication and should only be bound to a local address.
+//!
+//! Each job runs with its own progress events, default seed (that of the server's `--seed`
+//! option), and namespace of `memory://` data sets, which are shared by the threads of the job's
+//! tool and discarded once the job has finished. Jobs that pass data to one another must therefore
+//! do so through files.
+
+use crate::tools::{CancellationToken, ToolArgs, ToolManager, WhiteboxTool};
+use serde_json::{json, Value};
+use std::collections::{BTreeMap, VecDeque};
+use std::io::prelude::*;
+use std::io::{BufReader, Error, ErrorKind};
+use std::net::{TcpListener, TcpStream};
+use std::sync::{Arc, Condvar, Mutex, MutexGuard};
+use std::thread;
+use std::time::Duration;
+use whitebox_common::progress;
+use whitebox_common::random;
+use whitebox_common::utils::set_memory_namespace;
+use whitebox_lidar::MEMORY_LAS_FILES;
+use whitebox_raster::MEMORY_RASTERS;
+use whitebox_vector::MEMORY_SHAPEFILES;
+
+/// The largest request body that is accepted, in bytes.
+const MAX_BODY_SIZE: usize = 1 << 20;
+
+/// The number of finished jobs that are kept; older ones are removed as further jobs finish.
+const MAX_FINISHED_JOBS: usize = 1000;
+
+#[derive(Clone, Copy, PartialEq)]
+enum JobStatus {
+    Queued,
+    Running,
+    Succeeded,
+    Failed,
+    Cancelled,
+}
+
+impl JobStatus {
+    fn as_str(&self) -> &'static str {
+        match self {
+            JobStatus::Queued => "queued",
+            JobStatus::Running => "running",
+            JobStatus::Succeeded => "succeeded",
+            JobStatus::Failed => "failed",
+            JobStatus::Cancelled => "cancelled",
+        }
+    }
+
+    fn is_finished(&self) -> bool {
+        !matches!(self, JobStatus::Queued | JobStatus::Running)
+    }
+}
+
+struct Job {
+    tool: String,
+    args: ToolArgs,
+    status: JobStatus,
+    cancel: CancellationToken,
+    events: Vec<Value>,
+    percent: Option<i64>,
+    stage: Option<String>,
+    outputs: Vec<String>,
+    error: Option<String>,
+}
+
+impl Job {
+    fn to_json(&self, id: u64) -> Value {
+        json!({
+            "job": id,
+            "tool": self.tool,
+            "status": self.status.as_str(),
+            "percent": self.percent,
+            "stage": self.stage,
+            "outputs": self.outputs,
+            "error": self.error,
+            "num_events": self.events.len(),
+        })
+    }
+
+    /// Records an event, keeping track of the job's progress and output files.
+    fn add_event(&mut self, event: Value) {
+        match event["event"].as_str() {
+            Some("progress") => {
+                self.percent = event["percent"].as_i64();
+                self.stage = event["stage"].as_str().map(|s| s.to_string());
+            }
+            Some("output") => {
+                if let Some(f) = event["file"].as_str() {
+                    self.outputs.push(f.to_string());
+                }
+            }
+            _ => {}
+        }
+        self.events.push(event);
+    }
+}
+
+#[derive(Default)]
+struct JobTable {
+    jobs: BTreeMap<u64, Job>,
+    queue: VecDeque<u64>,
+    // The finished jobs, in the order that they finished.
+    finished: VecDeque<u64>,
+    next_id: u64,
+}
+
+impl JobTable {
+    /// Records that a job has finished, removing the oldest finished jobs beyond the limit.
+    fn finish(&mut self, id: u64) {
+        self.finished.push_back(id);
+        while self.finished.len() > MAX_FINISHED_JOBS {
+            if let Some(old) = self.finished.pop_front() {
+                self.jobs.remove(&old);
+            }
+        }
+    }
+}
+
+struct Server {
+    tm: ToolManager,
+    max_queue: usize,
+    default_seed: Option<u64>,
+    jobs: Mutex<JobTable>,
+    // Notified whenever a job is queued or a job's status or events change.
+    changed: Condvar,
+}
+
+struct RpcError {
+    code: i64,
+    message: String,
+}
+
+impl RpcError {
+    fn new<T: ToString>(code: i64, message: T) -> RpcError {
+        RpcError {
+            code: code,
+            message: message.to_string(),
+        }
+    }
+
+    fn invalid_params<T: ToString>(message: T) -> RpcError {
+        RpcError::new(-32602, message)
+    }
+
+    fn http_status(&self) -> u16 {
+        match self.code {
+            -32601 | -32001 => 404,
+            -32002 => 503,
+            -32603 => 500,
+            _ => 400,
+        }
+    }
+}
+
+/// Listens on `address` (e.g. `127.0.0.1:8080`) and serves requests until the process is
+/// stopped. Tool file names are resolved against `working_directory`.
+pub fn serve(
+    address: &str,
+    working_directory: &str,
+    max_jobs: usize,
+    max_queue: usize,
+) -> Result<(), Error> {
+    let listener = TcpListener::bind(address)?;
+    let server = Arc::new(Server {
+        tm: ToolManager::new(working_directory, &true)?,
+        max_queue: max_queue,
+        default_seed: random::default_seed(),
+        jobs: Mutex::new(JobTable::default()),
+        changed: Condvar::new(),
+    });
+
+    for _ in 0..max_jobs.max(1) {
+        let server = Arc::clone(&server);
+        thread::spawn(move || run_jobs(server));
+    }
+
+    progress::message(format!(
+        "Serving WhiteboxTools on http://{} (concurrent jobs: {})",
+        listener.local_addr()?,
+        max_jobs.max(1)
+    ));
+    for stream in listener.incoming() {
+        match stream {
+            Ok(stream) => {
+                let server = Arc::clone(&server);
+                // Errors that reach this point occurred while writing the response, e.g.
+                // because the client disconnected, so they can only be logged.
+                progress::spawn(move || {
+                    if let Err(e) = handle_connection(&server, stream) {
+                        progress::warning(format!("Error handling request: {}", e));
+                    }
+                });
+            }
+            Err(e) => progress::warning(format!("Error accepting connection: {}", e)),
+        }
+    }
+    Ok(())
+}
+
+impl Server {
+    fn lock(&self) -> MutexGuard<'_, JobTable> {
+        // A panicking tool is caught by the ToolManager, so the lock can't be poisoned by one.
+        self.jobs.lock().expect("Error locking the job table.")
+    }
+
+    fn call(&self, method: &str, params: &Value) -> Result<Value, RpcError> {
+        match method {
+            "list_tools" => {
+                let mut tools = vec![];
+                for name in self.tm.get_tool_names() {
+                    if let Some(tool) = self.tm.get_tool(name) {
+                        tools.push(json!({
+                            "name": tool.get_tool_name(),
+                            "toolbox": tool.get_toolbox(),
+                            "description": tool.get_tool_description(),
+                        }));
+                    }
+                }
+                Ok(Value::Array(tools))
+            }
+            "tool_parameters" => {
+                let tool = self.get_tool_param(params)?;
+                serde_json::from_str(&tool.get_tool_parameters())
+                    .map_err(|e| RpcError::new(-32603, e))
+            }
+            "run" => self.submit(params),
+            "list_jobs" => {
+                let table = self.lock();
+                Ok(Value::Array(
+                    table.jobs.iter().map(|(id, job)| job.to_json(*id)).collect(),
+                ))
+            }
+            "job_status" => {
+                let id = get_job_param(params)?;
+                let table = self.lock();
+                let job = get_job(&table, id)?;
+                Ok(job.to_json(id))
+            }
+            "job_events" => {
+                let id = get_job_param(params)?;
+                let since = params["since"].as_u64().unwrap_or(0) as usize;
+                let table = self.lock();
+                let job = get_job(&table, id)?;
+                let events: Vec<Value> = job.events.iter().skip(since).cloned().collect();
+                Ok(json!({
+                    "job": id,
+                    "status": job.status.as_str(),
+                    "events": events,
+                    "next": since.max(job.events.len()),
+                }))
+            }
+            "cancel" => {
+                let id = get_job_param(params)?;
+                let mut table = self.lock();
+                let job = match table.jobs.get_mut(&id) {
+                    Some(job) => job,
+                    None => return Err(RpcError::new(-32001, format!("Unknown job {}.", id))),
+                };
+                job.cancel.cancel();
+                let was_queued = job.status == JobStatus::Queued;
+                if was_queued {
+                    job.status = JobStatus::Cancelled;
+                }
+                let result = job.to_json(id);
+                if was_queued {
+                    table.queue.retain(|&j| j != id);
+                    table.finish(id);
+                }
+                self.changed.notify_all();
+                Ok(result)
+            }
+            _ => Err(RpcError::new(-32601, format!("Unknown method '{}'.", method))),
+        }
+    }
+
+    fn get_tool_param(
+        &self,
+        params: &Value,
+    ) -> Result<Box<dyn WhiteboxTool>, RpcError> {
+        let name = match params["tool"].as_str() {
+            Some(name) => name,
+            None => return Err(RpcError::invalid_params("The 'tool' parameter is required.")),
+        };
+        match self.tm.get_tool(name) {
+            Some(tool) => Ok(tool),
+            None => Err(RpcError::new(-32001, format!("Unrecognized tool name {}.", name))),
+        }
+    }
+
+    /// Adds a job to the queue. The tool's arguments are checked when the job runs, since its
+    /// input files may be the outputs of jobs that are still in the queue.
+    fn submit(&self, params: &Value) -> Result<Value, RpcError> {
+        let tool = self.get_tool_param(params)?;
+        let args = match params.get("args") {
+            Some(a) => ToolArgs::from_json(a).map_err(RpcError::invalid_params)?,
+            None => ToolArgs::new(),
+        };
+
+        let mut table = self.lock();
+        if table.queue.len() >= self.max_queue {
+            return Err(RpcError::new(
+                -32002,
+                format!("The job queue is full ({} jobs are waiting).", table.queue.len()),
+            ));
+        }
+        table.next_id += 1;
+        let id = table.next_id;
+        let job = Job {
+            tool: tool.get_tool_name(),
+            args: args,
+            status: JobStatus::Queued,
+            cancel: CancellationToken::new(),
+            events: vec![],
+            percent: None,
+            stage: None,
+            outputs: vec![],
+            error: None,
+        };
+        let result = job.to_json(id);
+        table.jobs.insert(id, job);
+        table.queue.push_back(id);
+        self.changed.notify_all();
+        Ok(result)
+    }
+}
+
+fn get_job_param(params: &Value) -> Result<u64, RpcError> {
+    match params["job"].as_u64() {
+        Some(id) => Ok(id),
+        None => Err(RpcError::invalid_params("The 'job' parameter is required.")),
+    }
+}
+
+fn get_job(table: &JobTable, id: u64) -> Result<&Job, RpcError> {
+    match table.jobs.get(&id) {
+        Some(job) => Ok(job),
+        None => Err(RpcError::new(-32001, format!("Unknown job {}.", id))),
+    }
+}
+
+/// Takes jobs from the queue and runs them, one at a time, until the process is stopped.
+fn run_jobs(server: Arc<Server>) {
+    loop {
+        let (id, tool, args, cancel) = {
+            let mut table = server.lock();
+            let id = loop {
+                match table.queue.pop_front() {
+                    Some(id) => break id,
+                    None => {
+                        table = server
+                            .changed
+                            .wait(table)
+                            .expect("Error locking the job table.")
+                    }
+                }
+            };
+            let job = table.jobs.get_mut(&id).expect("Queued job is missing.");
+            job.status = JobStatus::Running;
+            (id, job.tool.clone(), job.args.clone(), job.cancel.clone())
+        };
+        server.changed.notify_all();
+
+        let mut tm = server.tm.clone();
+        tm.cancellation_token = cancel.clone();
+        // The job's event handler, memory namespace, and default seed are set on this thread,
+        // and are passed on to the tool's worker threads by `progress::spawn`.
+        let s = Arc::clone(&server);
+        progress::set_event_handler(Some(Arc::new(move |line: &str| {
+            let event = serde_json::from_str(line).unwrap_or(Value::String(line.to_string()));
+            let mut table = s.lock();
+            if let Some(job) = table.jobs.get_mut(&id) {
+                job.add_event(event);
+            }
+            s.changed.notify_all();
+        })));
+        set_memory_namespace(Some(&format!("job{}", id)));
+        random::set_thread_default_seed(Some(server.default_seed));
+        let result = tm.run_tool_with_args(&tool, &args);
+        MEMORY_RASTERS.clear();
+        MEMORY_SHAPEFILES.clear();
+        MEMORY_LAS_FILES.clear();
+        random::set_thread_default_seed(None);
+        set_memory_namespace(None);
+        progress::set_event_handler(None);
+
+        let mut table = server.lock();
+        if let Some(job) = table.jobs.get_mut(&id) {
+            match result {
+                Ok(()) => job.status = JobStatus::Succeeded,
+                Err(e) => {
+                    job.status = if cancel.is_cancelled() {
+                        JobStatus::Cancelled
+                    } else {
+                        JobStatus::Failed
+                    };
+                    job.error = Some(e.to_string());
+                }
+            }
+            table.finish(id);
+        }
+        server.changed.notify_all();
+    }
+}
+
+struct Request {
+    method: String,
+    path: String,
+    query: String,
+    body: Vec<u8>,
+}
+
+fn read_request(stream: &TcpStream) -> Result<Request, Error> {
+    let mut reader = BufReader::new(stream);
+    let mut line = String::new();
+    reader.read_line(&mut line)?;
+    let mut parts = line.split_whitespace();
+    let method = parts.next().unwrap_or("").to_string();
+    let target = parts.next().unwrap_or("").to_string();
+    if method.is_empty() || !target.starts_with('/') {
+        return Err(Error::new(ErrorKind::InvalidData, "Malformed HTTP request line."));
+    }
+
+    let mut content_length = 0usize;
+    loop {
+        line.clear();
+        if reader.read_line(&mut line)? == 0 {
+            break;
+        }
+        let header = line.trim();
+        if header.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = header.split_once(':') {
+            if name.trim().eq_ignore_ascii_case("content-length") {
+                content_length = value.trim().parse().map_err(|_| {
+                    Error::new(ErrorKind::InvalidData, "Invalid Content-Length header.")
+                })?;
+            }
+        }
+    }
+    if content_length > MAX_BODY_SIZE {
+        return Err(Error::new(ErrorKind::InvalidData, "The request body is too large."));
+    }
+    let mut body = vec![0u8; content_length];
+    reader.read_exact(&mut body)?;
+
+    let (path, query) = match target.split_once('?') {
+        Some((p, q)) => (p.to_string(), q.to_string()),
+        None => (target, String::new()),
+    };
+    Ok(Request {
+        method: method,
+        path: path,
+        query: query,
+        body: body,
+    })
+}
+
+fn handle_connection(server: &Server, mut stream: TcpStream) -> Result<(), Error> {
+    stream.set_read_timeout(Some(Duration::from_secs(30)))?;
+    let request = match read_request(&stream) {
+        Ok(r) => r,
+        Err(e) => return write_response(&mut stream, 400, &json!({ "error": e.to_string() })),
+    };
+    let body: Value = if request.body.is_empty() {
+        Value::Null
+    } else {
+        match serde_json::from_slice(&request.body) {
+            Ok(v) => v,
+            Err(e) => {
+                let message = format!("Invalid JSON: {}", e);
+                return if request.path == "/rpc" {
+                    write_response(&mut stream, 200, &rpc_error(Value::Null, -32700, &message))
+                } else {
+                    write_response(&mut stream, 400, &json!({ "error": message }))
+                };
+            }
+        }
+    };
+
+    if request.path == "/rpc" {
+        if request.method != "POST" {
+            return write_response(&mut stream, 405, &json!({ "error": "Use POST for /rpc." }));
+        }
+        let response = handle_rpc(server, &body);
+        return write_response(&mut stream, 200, &response);
+    }
+
+    let segments: Vec<&str> = request.path.split('/').filter(|s| !s.is_empty()).collect();
+    let (method, params) = match (request.method.as_str(), segments.as_slice()) {
+        ("GET", ["tools"]) => ("list_tools", Value::Null),
+        ("GET", ["tools", tool]) => ("tool_parameters", json!({ "tool": tool })),
+        ("POST", ["jobs"]) => ("run", body),
+        ("GET", ["jobs"]) => ("list_jobs", Value::Null),
+        ("GET", ["jobs", id]) => ("job_status", json!({ "job": parse_id(id) })),
+        ("GET", ["jobs", id, "events"]) => {
+            let since = request
+                .query
+                .split('&')
+                .find_map(|q| q.strip_prefix("since="))
+                .and_then(|v| v.parse::<u64>().ok())
+                .unwrap_or(0);
+            ("job_events", json!({ "job": parse_id(id), "since": since }))
+        }
+        ("GET", ["jobs", id, "stream"]) => return stream_events(server, &mut stream, parse_id(id)),
+        ("POST", ["jobs", id, "cancel"]) | ("DELETE", ["jobs", id]) => {
+            ("cancel", json!({ "job": parse_id(id) }))
+        }
+        _ => {
+            return write_response(
+                &mut stream,
+                404,
+                &json!({ "error": format!("No such endpoint: {} {}", request.method, request.path) }),
+            )
+        }
+    };
+    match server.call(method, &params) {
+        Ok(result) => {
+            let status = if method == "run" { 202 } else { 200 };
+            write_response(&mut stream, status, &result)
+        }
+        Err(e) => write_response(&mut stream, e.http_status(), &json!({ "error": e.message })),
+    }
+}
+
+fn parse_id(id: &str) -> Value {
+    match id.parse::<u64>() {
+        Ok(id) => Value::from(id),
+        Err(_) => Value::Null,
+    }
+}
+
+fn handle_rpc(server: &Server, request: &Value) -> Value {
+    let id = request.get("id").cloned().unwrap_or(Value::Null);
+    let method = match request["method"].as_str() {
+        Some(m) if request["jsonrpc"] == "2.0" => m,
+        _ => return rpc_error(id, -32600, "Invalid JSON-RPC 2.0 request."),
+    };
+    let params = request.get("params").cloned().unwrap_or(Value::Null);
+    match server.call(method, &params) {
+        Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+        Err(e) => rpc_error(id, e.code, &e.message),
+    }
+}
+
+fn rpc_error(id: Value, code: i64, message: &str) -> Value {
+    json!({ "jsonrpc": "2.0", "id": id, "error": { "code": code, "message": message } })
+}
+
+/// Writes the job's events, one per line, as they are emitted and until the job finishes.
+fn stream_events(server: &Server, stream: &mut TcpStream, id: Value) -> Result<(), Error> {
+    let id = id.as_u64().unwrap_or(0);
+    if server.lock().jobs.get(&id).is_none() {
+        return write_response(stream, 404, &json!({ "error": format!("Unknown job {}.", id) }));
+    }
+    stream.write_all(
+        b"HTTP/1.1 200 OK\r\nContent-Type: application/x-ndjson\r\nConnection: close\r\n\r\n",
+    )?;
+    let mut next = 0;
+    loop {
+        // The job may be removed once it has finished, if many other jobs finish meanwhile.
+        let (events, summary) = {
+            let mut table = server.lock();
+            while table
+                .jobs
+                .get(&id)
+                .map_or(false, |job| job.events.len() <= next && !job.status.is_finished())
+            {
+                table = server
+                    .changed
+                    .wait(table)
+                    .expect("Error locking the job table.");
+            }
+            match table.jobs.get(&id) {
+                Some(job) => (
+                    job.events[next..].to_vec(),
+                    if job.status.is_finished() {
+                        Some(job.to_json(id))
+                    } else {
+                        None
+                    },
+                ),
+                None => (vec![], Some(json!({ "job": id, "error": "The job has been removed." }))),
+            }
+        };
+        next += events.len();
+        for event in events {
+            stream.write_all(format!("{}\n", event).as_bytes())?;
+        }
+        stream.flush()?;
+        if let Some(summary) = summary {
+            return stream.write_all(format!("{}\n", summary).as_bytes());
+        }
+    }
+}
+
+fn write_response(stream: &mut TcpStream, status: u16, body: &Value) -> Result<(), Error> {
+    let reason = match status {
+        200 => "OK",
+        202 => "Accepted",
+        400 => "Bad Request",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        503 => "Service Unavailable",
+        _ => "Internal Server Error",
+    };
+    let body = body.to_string();
+    write!(
+        stream,
+        "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        reason,
+        body.len(),
+        body
+    )?;
+    stream.flush()
+}
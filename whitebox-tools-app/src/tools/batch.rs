@@ -0,0 +1,473 @@
+/*
+This code is part of the WhiteboxTools geospatial analysis library.
+Authors: Dr. John Lindsay
+Created: 18/10/2026
+Last Modified: 18/10/2026
+License: MIT
+*/
+
+//! Runs a tool once for each of many input files (`--batch`).
+//!
+//! In a batch run, the value of any `ExistingFile` parameter may be a file name pattern, in
+//! which `*` matches any sequence of characters and `?` matches any one character, e.g.
+//! `--dem="tiles/*.tif"`, or a list of files separated by semicolons or commas. Patterns are
+//! only matched against file names, not directories, and only files of the parameter's type
+//! are matched. If several parameters are expanded, they must name the same number of files,
+//! which are paired in sorted order.
+//!
+//! The values of `NewFile` parameters are templates for the output file names, in which the
+//! following placeholders are replaced using the first expanded input file of each run:
+//!
+//! | Placeholder | Replaced with                                   | e.g. for `tiles/dem_01.tif` |
+//! | ----------- | ----------------------------------------------- | --------------------------- |
+//! | `{stem}`    | the file name, without its extension            | `dem_01`                    |
+//! | `{name}`    | the file name                                   | `dem_01.tif`                |
+//! | `{ext}`     | the file's extension                            | `tif`                       |
+//! | `{dir}`     | the file's directory, with a trailing separator | `tiles/`                    |
+//! | `{index}`   | the run's position in the batch, from 1         | `1`                         |
+//!
+//! For example:
+//!
+//! ```text
+//! >>./whitebox_tools -r=Slope --wd="/data/" --dem="tiles/*.tif" --output="{stem}_slope.tif" --batch -v
+//! ```
+
+use super::parsed_args::{
+    check_file_extension, find_parameters, normalize_flag, read_parameters, resolve_file_name,
+    strip_quotes,
+};
+use super::{panic_message, ParameterType, ToolManager};
+use std::collections::HashSet;
+use std::fs;
+use std::io::{Error, ErrorKind};
+use std::panic;
+use std::path::{Path, MAIN_SEPARATOR};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::thread;
+use std::time::Instant;
+use whitebox_common::progress;
+use whitebox_common::utils::get_formatted_elapsed_time;
+
+/// One run of the tool in a batch.
+pub(super) struct BatchJob {
+    /// The name of the run's first expanded input file, used to report on the run.
+    pub label: String,
+    pub args: Vec<String>,
+}
+
+/// Removes the `--batch` flag from a tool's arguments. Returns `None` if there was no flag, or
+/// if it was `--batch=false`, and otherwise the number of runs to perform at once: the value
+/// of `--batch=N`, or 0 if no number was given.
+pub(super) fn take_batch_flag(args: &mut Vec<String>) -> Result<Option<usize>, Error> {
+    let mut max_jobs = None;
+    let mut i = 0;
+    while i < args.len() {
+        let arg = strip_quotes(&args[i]);
+        let (flag, value) = match arg.find('=') {
+            Some(pos) => (normalize_flag(&arg[..pos]), arg[pos + 1..].trim().to_string()),
+            None => (normalize_flag(&arg), String::new()),
+        };
+        if flag != "batch" {
+            i += 1;
+            continue;
+        }
+        args.remove(i);
+        max_jobs = if value.is_empty() || value.eq_ignore_ascii_case("true") {
+            Some(0)
+        } else if value.eq_ignore_ascii_case("false") {
+            None
+        } else {
+            match value.parse::<usize>() {
+                Ok(n) => Some(n),
+                Err(_) => {
+                    return Err(Error::new(
+                        ErrorKind::InvalidInput,
+                        format!(
+                            "Invalid --batch value '{}'; expected the number of runs to perform at once.",
+                            value
+                        ),
+                    ))
+                }
+            }
+        };
+    }
+    Ok(max_jobs)
+}
+
+/// Expands a batch run's arguments into the arguments of each run (see the module
+/// documentation).
+pub(super) fn expand_batch(
+    tool_parameters: &str,
+    args: &[String],
+    working_directory: &str,
+) -> Result<Vec<BatchJob>, Error> {
+    let parameters = read_parameters(tool_parameters)?;
+
+    // Pair each flag with its value, in the same way as ParsedArgs::parse. Arguments that
+    // don't match a parameter are passed on unchanged, to be reported by each run.
+    let mut flags: Vec<(String, Option<usize>, Option<String>)> = vec![];
+    let mut i = 0;
+    while i < args.len() {
+        let arg = strip_quotes(&args[i]);
+        i += 1;
+        if arg.is_empty() {
+            continue;
+        }
+        let (flag, value) = match arg.find('=') {
+            Some(pos) => (arg[..pos].to_string(), Some(arg[pos + 1..].trim().to_string())),
+            None => (arg.clone(), None),
+        };
+        let index = find_parameters(&parameters, &normalize_flag(&flag))
+            .first()
+            .cloned();
+        let value = match (value, index) {
+            (Some(v), _) => Some(v),
+            (None, Some(index)) => match parameters[index].parameter_type {
+                ParameterType::Boolean => match args.get(i).map(|a| strip_quotes(a)) {
+                    Some(v) if v.eq_ignore_ascii_case("true") || v.eq_ignore_ascii_case("false") => {
+                        i += 1;
+                        Some(v)
+                    }
+                    _ => None,
+                },
+                _ => match args.get(i) {
+                    Some(v) => {
+                        i += 1;
+                        Some(strip_quotes(v))
+                    }
+                    None => None,
+                },
+            },
+            (None, None) => None,
+        };
+        flags.push((flag, index, value));
+    }
+
+    // Expand the input file patterns and lists.
+    let mut inputs: Vec<(usize, Vec<String>)> = vec![];
+    for (n, (flag, index, value)) in flags.iter().enumerate() {
+        if let (Some(index), Some(value)) = (index, value) {
+            if let ParameterType::ExistingFile(file_type) = &parameters[*index].parameter_type {
+                let files = if value.contains('*') || value.contains('?') {
+                    let files = match_files(value, working_directory)?
+                        .into_iter()
+                        .filter(|f| check_file_extension(f, file_type, false).is_ok())
+                        .collect::<Vec<String>>();
+                    if files.is_empty() {
+                        return Err(Error::new(
+                            ErrorKind::InvalidInput,
+                            format!("No files match the pattern '{}' for '{}'.", value, flag),
+                        ));
+                    }
+                    files
+                } else if value.contains(';') || value.contains(',') {
+                    let separator = if value.contains(';') { ';' } else { ',' };
+                    value
+                        .split(separator)
+                        .map(|f| f.trim())
+                        .filter(|f| !f.is_empty())
+                        .map(|f| resolve_file_name(f, working_directory))
+                        .collect()
+                } else {
+                    continue;
+                };
+                inputs.push((n, files));
+            }
+        }
+    }
+    if inputs.is_empty() {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            "A batch run requires an input file pattern, e.g. --input=\"*.tif\", or a list of input files.",
+        ));
+    }
+    let num_jobs = inputs[0].1.len();
+    for (n, files) in &inputs[1..] {
+        if files.len() != num_jobs {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!(
+                    "The batch inputs name different numbers of files ({} for '{}' and {} for '{}').",
+                    num_jobs,
+                    flags[inputs[0].0].0,
+                    files.len(),
+                    flags[*n].0
+                ),
+            ));
+        }
+    }
+
+    // Every run must write to different output files.
+    let templates: Vec<usize> = flags
+        .iter()
+        .enumerate()
+        .filter(|(_, (_, index, value))| match (index, value) {
+            (Some(index), Some(_)) => {
+                matches!(parameters[*index].parameter_type, ParameterType::NewFile(_))
+            }
+            _ => false,
+        })
+        .map(|(n, _)| n)
+        .collect();
+    if num_jobs > 1 {
+        for &n in &templates {
+            let (flag, _, value) = &flags[n];
+            if !value.as_ref().map(|v| v.contains('{')).unwrap_or(false) {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    format!(
+                        "The output '{}' must be a file name template, e.g. {}=\"{{stem}}_out.tif\", so that each input is written to a different file.",
+                        flag, flag
+                    ),
+                ));
+            }
+        }
+    }
+
+    let mut jobs = Vec::with_capacity(num_jobs);
+    let mut output_files = HashSet::new();
+    for j in 0..num_jobs {
+        let first_input = &inputs[0].1[j];
+        let mut job_args = vec![];
+        for (n, (flag, _, value)) in flags.iter().enumerate() {
+            let value = if let Some((_, files)) = inputs.iter().find(|(m, _)| *m == n) {
+                Some(files[j].clone())
+            } else if templates.contains(&n) {
+                let file_name = fill_template(value.as_ref().unwrap(), first_input, j + 1)?;
+                if !output_files.insert(resolve_file_name(&file_name, working_directory)) {
+                    return Err(Error::new(
+                        ErrorKind::InvalidInput,
+                        format!(
+                            "The output file name template for '{}' gives the same file name, {}, for more than one input.",
+                            flag, file_name
+                        ),
+                    ));
+                }
+                Some(file_name)
+            } else {
+                value.clone()
+            };
+            job_args.push(match value {
+                Some(v) => format!("{}={}", flag, v),
+                None => flag.clone(),
+            });
+        }
+        jobs.push(BatchJob {
+            label: file_name_of(first_input),
+            args: job_args,
+        });
+    }
+    Ok(jobs)
+}
+
+/// Runs each job with `tm`, running up to `num_threads` jobs at once, and reports on each
+/// one as it finishes. Returns the labels of the jobs that failed.
+pub(super) fn run_jobs(
+    tm: &ToolManager,
+    tool_name: &str,
+    jobs: &[BatchJob],
+    num_threads: usize,
+    verbose: bool,
+) -> Vec<String> {
+    let next_job = AtomicUsize::new(0);
+    let (tx, rx) = mpsc::channel::<(usize, Result<(), Error>, String)>();
+    let mut failed = vec![];
+    thread::scope(|scope| {
+        for _ in 0..num_threads {
+            let tx = tx.clone();
+            let next_job = &next_job;
+            let context = progress::context();
+            scope.spawn(move || {
+                progress::set_context(context);
+                loop {
+                    let j = next_job.fetch_add(1, Ordering::SeqCst);
+                    if j >= jobs.len() {
+                        break;
+                    }
+                    // Once the batch has been cancelled, the remaining jobs are only reported.
+                    if let Err(e) = tm.cancellation_token.check() {
+                        tx.send((j, Err(e), String::new())).unwrap();
+                        continue;
+                    }
+                    let start = Instant::now();
+                    let result = match panic::catch_unwind(panic::AssertUnwindSafe(|| {
+                        tm.run_tool(tool_name.to_string(), jobs[j].args.clone())
+                    })) {
+                        Ok(result) => result,
+                        Err(payload) => Err(Error::new(
+                            ErrorKind::Other,
+                            format!("{} panicked: {}", tool_name, panic_message(payload.as_ref())),
+                        )),
+                    };
+                    tx.send((j, result, get_formatted_elapsed_time(start))).unwrap();
+                }
+            });
+        }
+        drop(tx);
+
+        for (completed, (j, result, elapsed)) in rx.iter().enumerate() {
+            match result {
+                Ok(()) => {
+                    if verbose {
+                        progress::message(format!(
+                            "[{}/{}] {}: done (elapsed time: {})",
+                            completed + 1,
+                            jobs.len(),
+                            jobs[j].label,
+                            elapsed
+                        ));
+                    }
+                }
+                Err(e) => {
+                    if verbose {
+                        progress::message(format!(
+                            "[{}/{}] {}: failed: {}",
+                            completed + 1,
+                            jobs.len(),
+                            jobs[j].label,
+                            e
+                        ));
+                    }
+                    failed.push(jobs[j].label.clone());
+                }
+            }
+        }
+    });
+    failed
+}
+
+/// Reports the outcome of a batch run, returning an error if any of its jobs failed.
+pub(super) fn report(
+    tool_name: &str,
+    num_jobs: usize,
+    failed: &[String],
+    start: Instant,
+    verbose: bool,
+) -> Result<(), Error> {
+    if verbose {
+        progress::message(format!(
+            "{} batch: {} of {} runs succeeded, {} failed (elapsed time: {})",
+            tool_name,
+            num_jobs - failed.len(),
+            num_jobs,
+            failed.len(),
+            get_formatted_elapsed_time(start)
+        ));
+    }
+    if failed.is_empty() {
+        Ok(())
+    } else {
+        Err(Error::new(
+            ErrorKind::Other,
+            format!(
+                "{} of {} {} runs failed: {}",
+                failed.len(),
+                num_jobs,
+                tool_name,
+                failed.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Lists the files that match a file name pattern, in sorted order.
+fn match_files(pattern: &str, working_directory: &str) -> Result<Vec<String>, Error> {
+    let pattern = resolve_file_name(pattern, working_directory);
+    let path = Path::new(&pattern);
+    let directory = match path.parent() {
+        Some(d) if d.as_os_str().is_empty() => Path::new("."),
+        Some(d) => d,
+        None => Path::new("."),
+    };
+    if directory.to_string_lossy().contains('*') || directory.to_string_lossy().contains('?') {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            format!(
+                "The pattern '{}' is not supported; only file names, not directories, may contain wildcards.",
+                pattern
+            ),
+        ));
+    }
+    let name_pattern = file_name_of(&pattern);
+    let mut files = vec![];
+    for entry in fs::read_dir(directory)? {
+        let entry = entry?;
+        let name = entry.file_name().to_string_lossy().to_string();
+        if entry.path().is_file() && matches_pattern(&name_pattern, &name) {
+            files.push(entry.path().to_string_lossy().to_string());
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+/// Matches a file name against a pattern containing `*` and `?` wildcards. File names are
+/// compared without regard to case on Windows.
+fn matches_pattern(pattern: &str, name: &str) -> bool {
+    let (pattern, name) = if cfg!(target_os = "windows") {
+        (pattern.to_lowercase(), name.to_lowercase())
+    } else {
+        (pattern.to_string(), name.to_string())
+    };
+    let p: Vec<char> = pattern.chars().collect();
+    let s: Vec<char> = name.chars().collect();
+    // Greedy matching, backtracking to the most recent '*' on a mismatch.
+    let (mut pi, mut si) = (0, 0);
+    let mut star: Option<(usize, usize)> = None;
+    while si < s.len() {
+        if pi < p.len() && (p[pi] == '?' || p[pi] == s[si]) {
+            pi += 1;
+            si += 1;
+        } else if pi < p.len() && p[pi] == '*' {
+            star = Some((pi, si));
+            pi += 1;
+        } else if let Some((star_pi, star_si)) = star {
+            pi = star_pi + 1;
+            si = star_si + 1;
+            star = Some((star_pi, star_si + 1));
+        } else {
+            return false;
+        }
+    }
+    p[pi..].iter().all(|&c| c == '*')
+}
+
+/// Fills in the placeholders of an output file name template.
+fn fill_template(template: &str, input_file: &str, index: usize) -> Result<String, Error> {
+    let path = Path::new(input_file);
+    let directory = match path.parent() {
+        Some(d) if !d.as_os_str().is_empty() => {
+            let d = d.to_string_lossy().to_string();
+            if d.ends_with(MAIN_SEPARATOR) || d.ends_with('/') {
+                d
+            } else {
+                format!("{}{}", d, MAIN_SEPARATOR)
+            }
+        }
+        _ => String::new(),
+    };
+    let file_name = template
+        .replace("{stem}", &path.file_stem().unwrap_or_default().to_string_lossy())
+        .replace("{name}", &file_name_of(input_file))
+        .replace("{ext}", &path.extension().unwrap_or_default().to_string_lossy())
+        .replace("{dir}", &directory)
+        .replace("{index}", &index.to_string());
+    if file_name.contains('{') || file_name.contains('}') {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            format!(
+                "Unrecognized placeholder in the output file name template '{}'; use {{stem}}, {{name}}, {{ext}}, {{dir}}, or {{index}}.",
+                template
+            ),
+        ));
+    }
+    Ok(file_name)
+}
+
+fn file_name_of(file_name: &str) -> String {
+    Path::new(file_name)
+        .file_name()
+        .map(|f| f.to_string_lossy().to_string())
+        .unwrap_or_else(|| file_name.to_string())
+}
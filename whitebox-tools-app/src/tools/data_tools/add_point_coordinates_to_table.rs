@@ -6,6 +6,7 @@ Last Modified: 12/10/2018
 License: MIT
 */
 
+use whitebox_common::progress;
 use crate::tools::*;
 use whitebox_vector::*;
 use std::env;
@@ -112,6 +113,7 @@ impl WhiteboxTool for AddPointCoordinatesToTable {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
 
@@ -145,13 +147,7 @@ impl WhiteboxTool for AddPointCoordinatesToTable {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -161,7 +157,7 @@ impl WhiteboxTool for AddPointCoordinatesToTable {
         }
 
         if verbose {
-            println!("Reading data...")
+            progress::message("Reading data...")
         };
 
         let input = Shapefile::read(&input_file)?;
@@ -206,23 +202,24 @@ impl WhiteboxTool for AddPointCoordinatesToTable {
             atts.push(FieldData::Real(record.points[0].y));
             output.attributes.add_record(atts, false);
 
+            cancel.check()?;
             if verbose {
                 progress =
                     (100.0_f64 * (record_num + 1) as f64 / input.num_records as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    progress::update(progress);
                     old_progress = progress;
                 }
             }
         }
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
@@ -231,7 +228,7 @@ impl WhiteboxTool for AddPointCoordinatesToTable {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!("{}", &format!("Elapsed Time: {}", elapsed_time));
+            progress::message(format!("Elapsed Time: {}", elapsed_time));
         }
 
         Ok(())
@@ -8,6 +8,7 @@ License: MIT
 
 use crate::tools::*;
 use whitebox_common::structures::Point2D;
+use whitebox_common::progress;
 use whitebox_vector::*;
 use std::env;
 use std::io::{Error, ErrorKind};
@@ -122,6 +123,7 @@ impl WhiteboxTool for CleanVector {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file: String = "".to_string();
         let mut output_file: String = "".to_string();
@@ -166,13 +168,7 @@ impl WhiteboxTool for CleanVector {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         if !input_file.contains(path::MAIN_SEPARATOR) && !input_file.contains("/") {
@@ -252,23 +248,24 @@ impl WhiteboxTool for CleanVector {
                 }
             }
 
+            cancel.check()?;
             if verbose {
                 progress =
                     (100.0_f64 * (record_num + 1) as f64 / input.num_records as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    progress::update(progress);
                     old_progress = progress;
                 }
             }
         }
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
@@ -277,7 +274,7 @@ impl WhiteboxTool for CleanVector {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!("{}", &format!("Elapsed Time: {}", elapsed_time));
+            progress::message(format!("Elapsed Time: {}", elapsed_time));
         }
 
         Ok(())
@@ -7,6 +7,7 @@ License: MIT
 */
 
 use whitebox_raster::*;
+use whitebox_common::progress;
 use crate::tools::*;
 use num_cpus;
 use std::env;
@@ -15,7 +16,6 @@ use std::io::{Error, ErrorKind};
 use std::path;
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::thread;
 
 /// This tool can be used to change the value within the grid cells of a raster file (`--input`) that contain
 /// NoData to zero. The most common reason for using this tool is to change the background region of a raster
@@ -121,6 +121,7 @@ impl WhiteboxTool for ConvertNodataToZero {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut output_file = String::new();
@@ -157,13 +158,7 @@ impl WhiteboxTool for ConvertNodataToZero {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -199,7 +194,7 @@ impl WhiteboxTool for ConvertNodataToZero {
         for tid in 0..num_procs {
             let input = input.clone();
             let tx = tx.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 for row in (0..rows).filter(|r| r % num_procs == tid) {
                     let mut data = vec![nodata; columns as usize];
                     for col in 0..columns {
@@ -209,18 +204,20 @@ impl WhiteboxTool for ConvertNodataToZero {
                             data[col as usize] = 0.0f64;
                         }
                     }
-                    tx.send((row, data)).unwrap();
+                    if tx.send((row, data)).is_err() {
+                        return;
+                    }
                 }
             });
         }
 
         for row in 0..rows {
-            let data = rx.recv().expect("Error receiving data from thread.");
+            let data = cancel.recv(&rx)?;
             output.set_row_data(data.0, data.1);
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as i32;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    progress::update(progress);
                     old_progress = progress;
                 }
             }
@@ -235,22 +232,19 @@ impl WhiteboxTool for ConvertNodataToZero {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -7,6 +7,7 @@ License: MIT
 */
 
 use whitebox_raster::*;
+use whitebox_common::progress;
 use crate::tools::*;
 use std::env;
 use std::io::{Error, ErrorKind};
@@ -117,6 +118,7 @@ impl WhiteboxTool for ConvertRasterFormat {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        _cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut output_file = String::new();
@@ -153,13 +155,7 @@ impl WhiteboxTool for ConvertRasterFormat {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -172,14 +168,14 @@ impl WhiteboxTool for ConvertRasterFormat {
         }
 
         if verbose {
-            println!("Reading data...")
+            progress::message("Reading data...")
         };
 
         let input = Raster::new(&input_file, "r")?;
         let start = Instant::now();
 
         let mut output = Raster::initialize_using_file(&output_file, &input);
-        println!("Initializing the output raster...");
+        progress::message("Initializing the output raster...");
         match output.set_data_from_raster(&input) {
             Ok(_) => (), // do nothings
             Err(err) => return Err(err),
@@ -195,22 +191,19 @@ impl WhiteboxTool for ConvertRasterFormat {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
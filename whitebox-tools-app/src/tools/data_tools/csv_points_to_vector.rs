@@ -7,6 +7,7 @@ License: MIT
 */
 
 use whitebox_common::spatial_ref_system::esri_wkt_from_epsg;
+use whitebox_common::progress;
 use crate::tools::*;
 use whitebox_vector::{AttributeField, FieldData, FieldDataType, ShapeType, Shapefile};
 use std::env;
@@ -151,6 +152,7 @@ impl WhiteboxTool for CsvPointsToVector {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut output_file = String::new();
@@ -230,13 +232,7 @@ impl WhiteboxTool for CsvPointsToVector {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -255,7 +251,7 @@ impl WhiteboxTool for CsvPointsToVector {
         let start = Instant::now();
 
         if verbose {
-            println!("Reading data...")
+            progress::message("Reading data...")
         };
 
         // read in the CSV file
@@ -437,22 +433,23 @@ impl WhiteboxTool for CsvPointsToVector {
                 .attributes
                 .add_record(data[record_num].clone(), false);
 
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * (rec_num + 1) as f64 / data.len() as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    progress::update(progress);
                     old_progress = progress;
                 }
             }
         }
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
@@ -461,7 +458,7 @@ impl WhiteboxTool for CsvPointsToVector {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!("{}", &format!("Elapsed Time: {}", elapsed_time));
+            progress::message(format!("Elapsed Time: {}", elapsed_time));
         }
 
         Ok(())
@@ -6,6 +6,7 @@ Last Modified: 18/10/2019
 License: MIT
 */
 
+use whitebox_common::progress;
 use crate::tools::*;
 use whitebox_vector::{FieldData, Shapefile};
 use std::env;
@@ -131,6 +132,7 @@ impl WhiteboxTool for ExportTableToCsv {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut output_file = String::new();
@@ -173,13 +175,7 @@ impl WhiteboxTool for ExportTableToCsv {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -195,7 +191,7 @@ impl WhiteboxTool for ExportTableToCsv {
         }
 
         if verbose {
-            println!("Reading data...")
+            progress::message("Reading data...")
         };
         let vector_data = Shapefile::read(&input_file)?;
 
@@ -251,11 +247,12 @@ impl WhiteboxTool for ExportTableToCsv {
             s.push_str("\n");
             writer.write_all(s.as_bytes())?;
 
+            cancel.check()?;
             if verbose {
                 progress =
                     (100.0_f64 * record_num as f64 / (vector_data.num_records - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Writing attributes: {}%", progress);
+                    progress::update_stage("Writing attributes", progress);
                     old_progress = progress;
                 }
             }
@@ -263,10 +260,7 @@ impl WhiteboxTool for ExportTableToCsv {
 
         if verbose {
             let elapsed_time = get_formatted_elapsed_time(start);
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -6,6 +6,7 @@ Last Modified: 22/11/2018
 License: MIT
 */
 
+use whitebox_common::progress;
 use crate::tools::*;
 use whitebox_vector::{FieldData, Shapefile};
 use std::collections::HashMap;
@@ -75,7 +76,7 @@ impl JoinTables {
 
         parameters.push(ToolParameter {
             name: "Primary Key Field".to_owned(),
-            flags: vec!["--pkey".to_owned()],
+            flags: vec!["--primary_key".to_owned(), "--pkey".to_owned()],
             description: "Primary key field.".to_owned(),
             parameter_type: ParameterType::VectorAttributeField(
                 AttributeType::Any,
@@ -99,7 +100,7 @@ impl JoinTables {
 
         parameters.push(ToolParameter {
             name: "Foreign Key Field".to_owned(),
-            flags: vec!["--fkey".to_owned()],
+            flags: vec!["--foreign_key".to_owned(), "--fkey".to_owned()],
             description: "Foreign key field.".to_owned(),
             parameter_type: ParameterType::VectorAttributeField(
                 AttributeType::Any,
@@ -183,6 +184,7 @@ impl WhiteboxTool for JoinTables {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input1_file = String::new();
         let mut primary_key = String::new();
@@ -241,13 +243,7 @@ impl WhiteboxTool for JoinTables {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -263,7 +259,7 @@ impl WhiteboxTool for JoinTables {
         }
 
         if verbose {
-            println!("Reading data...")
+            progress::message("Reading data...")
         };
         let input1 = Shapefile::read(&input1_file)?;
         let input2 = Shapefile::read(&input2_file)?;
@@ -368,23 +364,24 @@ impl WhiteboxTool for JoinTables {
 
             output.attributes.add_record(atts, false);
 
+            cancel.check()?;
             if verbose {
                 progress =
                     (100.0_f64 * (record_num + 1) as f64 / input1.num_records as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    progress::update(progress);
                     old_progress = progress;
                 }
             }
         }
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
@@ -393,7 +390,7 @@ impl WhiteboxTool for JoinTables {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!("{}", &format!("Elapsed Time: {}", elapsed_time));
+            progress::message(format!("Elapsed Time: {}", elapsed_time));
         }
 
         Ok(())
@@ -6,6 +6,7 @@ Last Modified: 09/03/2020
 License: MIT
 */
 
+use whitebox_common::progress;
 use crate::tools::*;
 use whitebox_vector::{AttributeField, FieldData, FieldDataType, Shapefile};
 use std::collections::HashMap;
@@ -81,7 +82,7 @@ impl MergeTableWithCsv {
 
         parameters.push(ToolParameter {
             name: "Primary Key Field".to_owned(),
-            flags: vec!["--pkey".to_owned()],
+            flags: vec!["--primary_key".to_owned(), "--pkey".to_owned()],
             description: "Primary key field.".to_owned(),
             parameter_type: ParameterType::VectorAttributeField(
                 AttributeType::Any,
@@ -102,7 +103,7 @@ impl MergeTableWithCsv {
 
         parameters.push(ToolParameter {
             name: "Foreign Key Field".to_owned(),
-            flags: vec!["--fkey".to_owned()],
+            flags: vec!["--foreign_key".to_owned(), "--fkey".to_owned()],
             description: "Foreign key field.".to_owned(),
             parameter_type: ParameterType::VectorAttributeField(
                 AttributeType::Any,
@@ -114,7 +115,7 @@ impl MergeTableWithCsv {
 
         parameters.push(ToolParameter {
             name: "Imported Field".to_owned(),
-            flags: vec!["--import_field".to_owned()],
+            flags: vec!["--import".to_owned(), "--import_field".to_owned()],
             description: "Imported field (all fields will be imported if not specified)."
                 .to_owned(),
             parameter_type: ParameterType::VectorAttributeField(
@@ -187,6 +188,7 @@ impl WhiteboxTool for MergeTableWithCsv {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut primary_key = String::new();
@@ -245,13 +247,7 @@ impl WhiteboxTool for MergeTableWithCsv {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -267,7 +263,7 @@ impl WhiteboxTool for MergeTableWithCsv {
         }
 
         if verbose {
-            println!("Reading data...")
+            progress::message("Reading data...")
         };
         let input = Shapefile::read(&input_file)?;
 
@@ -433,23 +429,24 @@ impl WhiteboxTool for MergeTableWithCsv {
 
             output.attributes.add_record(atts, false);
 
+            cancel.check()?;
             if verbose {
                 progress =
                     (100.0_f64 * (record_num + 1) as f64 / input.num_records as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    progress::update(progress);
                     old_progress = progress;
                 }
             }
         }
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
@@ -458,7 +455,7 @@ impl WhiteboxTool for MergeTableWithCsv {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!("{}", &format!("Elapsed Time: {}", elapsed_time));
+            progress::message(format!("Elapsed Time: {}", elapsed_time));
         }
 
         Ok(())
@@ -6,6 +6,7 @@ Last Modified: 12/10/2018
 License: MIT
 */
 
+use whitebox_common::progress;
 use crate::tools::*;
 use whitebox_vector::*;
 use std::env;
@@ -50,7 +51,7 @@ impl MergeVectors {
         let mut parameters = vec![];
         parameters.push(ToolParameter {
             name: "Input Vector Files".to_string(),
-            flags: vec!["-i".to_owned(), "--inputs".to_string()],
+            flags: vec!["-i".to_owned(), "--input".to_owned(), "--inputs".to_string()],
             description: "Input vector files.".to_string(),
             parameter_type: ParameterType::FileList(ParameterFileType::Vector(
                 VectorGeometryType::Any,
@@ -138,6 +139,7 @@ impl WhiteboxTool for MergeVectors {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_files = String::new();
         let mut output_file: String = "".to_string();
@@ -182,13 +184,7 @@ impl WhiteboxTool for MergeVectors {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         if !output_file.contains(&sep) && !output_file.contains("/") {
@@ -234,7 +230,7 @@ impl WhiteboxTool for MergeVectors {
                 in_files.push(input_file.clone());
 
                 if verbose {
-                    println!("Reading '{}'", input_file);
+                    progress::message(format!("Reading '{}'", input_file));
                 };
 
                 let input = Shapefile::read(&input_file)?;
@@ -313,11 +309,12 @@ impl WhiteboxTool for MergeVectors {
 
                 output.attributes.add_record(out_atts, false);
 
+                cancel.check()?;
                 if verbose {
                     progress =
                         (100.0_f64 * (record_num + 1) as f64 / input.num_records as f64) as usize;
                     if progress != old_progress {
-                        println!("Progress: {}%", progress);
+                        progress::update(progress);
                         old_progress = progress;
                     }
                 }
@@ -325,12 +322,12 @@ impl WhiteboxTool for MergeVectors {
         }
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
@@ -339,7 +336,7 @@ impl WhiteboxTool for MergeVectors {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!("{}", &format!("Elapsed Time: {}", elapsed_time));
+            progress::message(format!("Elapsed Time: {}", elapsed_time));
         }
 
         Ok(())
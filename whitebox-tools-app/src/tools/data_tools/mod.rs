@@ -21,6 +21,7 @@ mod reinitialize_attribute_table;
 mod remove_polygon_holes;
 mod set_nodata_value;
 mod singlepart_to_multipart;
+mod validate_polygons;
 mod vector_lines_to_raster;
 mod vector_points_to_raster;
 mod vector_polygons_to_raster;
@@ -48,6 +49,7 @@ pub use self::reinitialize_attribute_table::ReinitializeAttributeTable;
 pub use self::remove_polygon_holes::RemovePolygonHoles;
 pub use self::set_nodata_value::SetNodataValue;
 pub use self::singlepart_to_multipart::SinglePartToMultiPart;
+pub use self::validate_polygons::ValidatePolygons;
 pub use self::vector_lines_to_raster::VectorLinesToRaster;
 pub use self::vector_points_to_raster::VectorPointsToRaster;
 pub use self::vector_polygons_to_raster::VectorPolygonsToRaster;
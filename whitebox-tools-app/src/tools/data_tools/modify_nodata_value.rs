@@ -7,6 +7,7 @@ License: MIT
 */
 
 use whitebox_raster::*;
+use whitebox_common::progress;
 use crate::tools::*;
 use std::env;
 use std::f64;
@@ -118,6 +119,7 @@ impl WhiteboxTool for ModifyNoDataValue {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut new_nodata_value = -32768f64;
@@ -155,13 +157,7 @@ impl WhiteboxTool for ModifyNoDataValue {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -189,10 +185,11 @@ impl WhiteboxTool for ModifyNoDataValue {
                     input.set_value(row, col, new_nodata_value);
                 }
             }
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as i32;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    progress::update(progress);
                     old_progress = progress;
                 }
             }
@@ -201,22 +198,19 @@ impl WhiteboxTool for ModifyNoDataValue {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match input.write() {
             Ok(_) => {
                 if verbose {
-                    println!("File written")
+                    progress::message("File written")
                 }
             }
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -7,6 +7,7 @@ License: MIT
 */
 
 use whitebox_raster::*;
+use whitebox_common::progress;
 use crate::tools::*;
 use std::env;
 use std::f64;
@@ -42,7 +43,7 @@ impl NewRasterFromBase {
         let mut parameters = vec![];
         parameters.push(ToolParameter {
             name: "Input Base File".to_owned(),
-            flags: vec!["-i".to_owned(), "--base".to_owned()],
+            flags: vec!["-i".to_owned(), "--input".to_owned(), "--base".to_owned()],
             description: "Input base raster file.".to_owned(),
             parameter_type: ParameterType::ExistingFile(ParameterFileType::RasterAndVector(
                 VectorGeometryType::Any,
@@ -72,7 +73,7 @@ impl NewRasterFromBase {
 
         parameters.push(ToolParameter{
             name: "Data Type".to_owned(), 
-            flags: vec!["--data_type".to_owned()], 
+            flags: vec!["--datatype".to_owned(), "--data_type".to_owned()], 
             description: "Output raster data type; options include 'double' (64-bit), 'float' (32-bit), and 'integer' (signed 16-bit) (default is 'float').".to_owned(),
             parameter_type: ParameterType::OptionList(vec!["double".to_owned(), "float".to_owned(), "integer".to_owned()]),
             default_value: Some("float".to_owned()),
@@ -147,6 +148,7 @@ impl WhiteboxTool for NewRasterFromBase {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        _cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut base_file = String::new();
         let mut output_file = String::new();
@@ -211,13 +213,7 @@ impl WhiteboxTool for NewRasterFromBase {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -305,22 +301,19 @@ impl WhiteboxTool for NewRasterFromBase {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
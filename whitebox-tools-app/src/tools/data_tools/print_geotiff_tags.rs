@@ -7,6 +7,7 @@ License: MIT
 */
 
 use whitebox_raster::geotiff::*;
+use whitebox_common::progress;
 use crate::tools::*;
 use std::env;
 use std::io::{Error, ErrorKind};
@@ -117,6 +118,7 @@ impl WhiteboxTool for PrintGeoTiffTags {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        _cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         // let mut output_file = String::new();
@@ -153,13 +155,7 @@ impl WhiteboxTool for PrintGeoTiffTags {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
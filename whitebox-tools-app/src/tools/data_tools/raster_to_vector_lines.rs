@@ -8,6 +8,7 @@ License: MIT
 
 use whitebox_raster::*;
 use whitebox_common::structures::{Array2D, Point2D};
+use whitebox_common::progress;
 use crate::tools::*;
 use whitebox_vector::ShapefileGeometry;
 use whitebox_vector::*;
@@ -137,6 +138,7 @@ impl WhiteboxTool for RasterToVectorLines {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut output_file = String::new();
@@ -174,13 +176,7 @@ impl WhiteboxTool for RasterToVectorLines {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -196,7 +192,7 @@ impl WhiteboxTool for RasterToVectorLines {
         }
 
         if verbose {
-            println!("Reading data...")
+            progress::message("Reading data...")
         };
         let input = Raster::new(&input_file, "r")?;
 
@@ -254,17 +250,18 @@ impl WhiteboxTool for RasterToVectorLines {
                     num_cells += 1;
                 }
             }
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    progress::update(progress);
                     old_progress = progress;
                 }
             }
         }
 
         if verbose {
-            println!("Tracing raster lines...");
+            progress::message("Tracing raster lines...");
         }
         let (mut row, mut col): (isize, isize);
         let (mut row_n, mut col_n): (isize, isize);
@@ -343,10 +340,11 @@ impl WhiteboxTool for RasterToVectorLines {
                 }
             }
 
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    progress::update(progress);
                     old_progress = progress;
                 }
             }
@@ -355,7 +353,7 @@ impl WhiteboxTool for RasterToVectorLines {
         // The above procedure will not catch closed loops that are disconnected from any line end.
         // Pass over the raster looking for any untraced lines.
         if verbose {
-            println!("Searching for closed loops...");
+            progress::message("Searching for closed loops...");
         }
         let (mut row2, mut col2): (isize, isize);
         for row in 0..rows {
@@ -428,7 +426,7 @@ impl WhiteboxTool for RasterToVectorLines {
                                 / (num_cells - 1) as f64)
                                 as usize;
                             if progress != old_progress {
-                                println!("Progress: {}%", progress);
+                                progress::update(progress);
                                 old_progress = progress;
                             }
                         }
@@ -440,22 +438,19 @@ impl WhiteboxTool for RasterToVectorLines {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -7,6 +7,7 @@ License: MIT
 */
 
 use whitebox_raster::*;
+use whitebox_common::progress;
 use crate::tools::*;
 use whitebox_vector::*;
 use std::env;
@@ -128,6 +129,7 @@ impl WhiteboxTool for RasterToVectorPoints {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut output_file = String::new();
@@ -168,13 +170,7 @@ impl WhiteboxTool for RasterToVectorPoints {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -187,7 +183,7 @@ impl WhiteboxTool for RasterToVectorPoints {
         }
 
         if verbose {
-            println!("Reading data...")
+            progress::message("Reading data...")
         };
 
         let input = Raster::new(&input_file, "r")?;
@@ -229,10 +225,11 @@ impl WhiteboxTool for RasterToVectorPoints {
                     rec_num += 1i32;
                 }
             }
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    progress::update(progress);
                     old_progress = progress;
                 }
             }
@@ -241,21 +238,18 @@ impl WhiteboxTool for RasterToVectorPoints {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -7,6 +7,7 @@ License: MIT
 */
 
 use whitebox_common::algorithms::is_clockwise_order;
+use whitebox_common::progress;
 use whitebox_raster::*;
 use whitebox_common::structures::{Array2D, Point2D};
 use crate::tools::*;
@@ -133,6 +134,7 @@ impl WhiteboxTool for RasterToVectorPolygons {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut output_file = String::new();
@@ -173,13 +175,7 @@ impl WhiteboxTool for RasterToVectorPolygons {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -192,7 +188,7 @@ impl WhiteboxTool for RasterToVectorPolygons {
         }
 
         if verbose {
-            println!("Reading data...")
+            progress::message("Reading data...")
         };
 
         let input = Raster::new(&input_file, "r")?;
@@ -263,10 +259,11 @@ impl WhiteboxTool for RasterToVectorPolygons {
                 }
             }
 
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Clumping polygons: {}%", progress);
+                    progress::update_stage("Clumping polygons", progress);
                     old_progress = progress;
                 }
             }
@@ -345,10 +342,11 @@ impl WhiteboxTool for RasterToVectorPolygons {
                 }
             }
 
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Finding edges: {}%", progress);
+                    progress::update_stage("Finding edges", progress);
                     old_progress = progress;
                 }
             }
@@ -505,11 +503,12 @@ impl WhiteboxTool for RasterToVectorPolygons {
                     }
                 }
             }
+            cancel.check()?;
             if verbose {
                 progress =
                     (100.0_f64 * node as f64 / (line_segments.len() * 2 - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Tracing polygons: {}%", progress);
+                    progress::update_stage("Tracing polygons", progress);
                     old_progress = progress;
                 }
             }
@@ -662,11 +661,12 @@ impl WhiteboxTool for RasterToVectorPolygons {
                     }
                 }
             }
+            cancel.check()?;
             if verbose {
                 progress =
                     (100.0_f64 * line_segment as f64 / (line_segments.len() - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Tracing polygons: {}%", progress);
+                    progress::update_stage("Tracing polygons", progress);
                     old_progress = progress;
                 }
             }
@@ -683,10 +683,11 @@ impl WhiteboxTool for RasterToVectorPolygons {
                 false,
             );
 
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * fid as f64 / (geometries.len() - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Creating geometries: {}%", progress);
+                    progress::update_stage("Creating geometries", progress);
                     old_progress = progress;
                 }
             }
@@ -695,21 +696,18 @@ impl WhiteboxTool for RasterToVectorPolygons {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
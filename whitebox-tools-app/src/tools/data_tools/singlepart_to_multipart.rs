@@ -11,6 +11,7 @@ use whitebox_common::algorithms::{
     poly_in_poly
 };
 use whitebox_common::structures::Point2D;
+use whitebox_common::progress;
 use crate::tools::*;
 use whitebox_vector::*;
 use std::env;
@@ -153,6 +154,7 @@ impl WhiteboxTool for SinglePartToMultiPart {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file: String = "".to_string();
         let mut output_file: String = "".to_string();
@@ -206,13 +208,7 @@ impl WhiteboxTool for SinglePartToMultiPart {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         if !input_file.contains(path::MAIN_SEPARATOR) && !input_file.contains("/") {
@@ -274,12 +270,13 @@ impl WhiteboxTool for SinglePartToMultiPart {
                             z_values.push(record.z_array[0]);
                         }
 
+                        cancel.check()?;
                         if verbose {
                             progress = (100.0_f64 * (record_num + 1) as f64
                                 / input.num_records as f64)
                                 as usize;
                             if progress != old_progress {
-                                println!("Progress: {}%", progress);
+                                progress::update(progress);
                                 old_progress = progress;
                             }
                         }
@@ -303,12 +300,12 @@ impl WhiteboxTool for SinglePartToMultiPart {
                         .add_record(vec![FieldData::Int(1i32)], false);
 
                     if verbose {
-                        println!("Saving data...")
+                        progress::message("Saving data...")
                     };
                     let _ = match output.write() {
                         Ok(_) => {
                             if verbose {
-                                println!("Output file written")
+                                progress::output_written(&output.file_name)
                             }
                         }
                         Err(e) => return Err(e),
@@ -351,12 +348,13 @@ impl WhiteboxTool for SinglePartToMultiPart {
                             }
                         }
 
+                        cancel.check()?;
                         if verbose {
                             progress = (100.0_f64 * (record_num + 1) as f64
                                 / input.num_records as f64)
                                 as usize;
                             if progress != old_progress {
-                                println!("Progress: {}%", progress);
+                                progress::update(progress);
                                 old_progress = progress;
                             }
                         }
@@ -368,12 +366,12 @@ impl WhiteboxTool for SinglePartToMultiPart {
                         .add_record(vec![FieldData::Int(1i32)], false);
 
                     if verbose {
-                        println!("Saving data...")
+                        progress::message("Saving data...")
                     };
                     let _ = match output.write() {
                         Ok(_) => {
                             if verbose {
-                                println!("Output file written")
+                                progress::output_written(&output.file_name)
                             }
                         }
                         Err(e) => return Err(e),
@@ -453,12 +451,13 @@ impl WhiteboxTool for SinglePartToMultiPart {
                             }
                         }
 
+                        cancel.check()?;
                         if verbose {
                             progress = (100.0_f64 * (record_num + 1) as f64
                                 / input.num_records as f64)
                                 as usize;
                             if progress != old_progress {
-                                println!("Progress: {}%", progress);
+                                progress::update(progress);
                                 old_progress = progress;
                             }
                         }
@@ -470,12 +469,12 @@ impl WhiteboxTool for SinglePartToMultiPart {
                         .add_record(vec![FieldData::Int(1i32)], false);
 
                     if verbose {
-                        println!("Saving data...")
+                        progress::message("Saving data...")
                     };
                     let _ = match output.write() {
                         Ok(_) => {
                             if verbose {
-                                println!("Output file written")
+                                progress::output_written(&output.file_name)
                             }
                         }
                         Err(e) => return Err(e),
@@ -505,9 +504,7 @@ impl WhiteboxTool for SinglePartToMultiPart {
             // Is the field numeric?
             if !input.attributes.is_field_numeric(field_index) {
                 if input.attributes.fields[field_index].decimal_count > 0 {
-                    println!(
-                        "WARNING: The attribute field does not appear to be categorical. This may produce unexpected results."
-                    )
+                    progress::warning("The attribute field does not appear to be categorical. This may produce unexpected results.")
                 }
             }
 
@@ -615,22 +612,23 @@ impl WhiteboxTool for SinglePartToMultiPart {
                             false,
                         );
 
+                        cancel.check()?;
                         if verbose {
                             progress = (100.0_f64 * (id + 1) as f64 / max_id as f64) as usize;
                             if progress != old_progress {
-                                println!("Progress: {}%", progress);
+                                progress::update(progress);
                                 old_progress = progress;
                             }
                         }
                     }
 
                     if verbose {
-                        println!("Saving data...")
+                        progress::message("Saving data...")
                     };
                     let _ = match output.write() {
                         Ok(_) => {
                             if verbose {
-                                println!("Output file written")
+                                progress::output_written(&output.file_name)
                             }
                         }
                         Err(e) => return Err(e),
@@ -722,22 +720,23 @@ impl WhiteboxTool for SinglePartToMultiPart {
                             false,
                         );
 
+                        cancel.check()?;
                         if verbose {
                             progress = (100.0_f64 * (id + 1) as f64 / max_id as f64) as usize;
                             if progress != old_progress {
-                                println!("Progress: {}%", progress);
+                                progress::update(progress);
                                 old_progress = progress;
                             }
                         }
                     }
 
                     if verbose {
-                        println!("Saving data...")
+                        progress::message("Saving data...")
                     };
                     let _ = match output.write() {
                         Ok(_) => {
                             if verbose {
-                                println!("Output file written")
+                                progress::output_written(&output.file_name)
                             }
                         }
                         Err(e) => return Err(e),
@@ -861,22 +860,23 @@ impl WhiteboxTool for SinglePartToMultiPart {
                             false,
                         );
 
+                        cancel.check()?;
                         if verbose {
                             progress = (100.0_f64 * (id + 1) as f64 / max_id as f64) as usize;
                             if progress != old_progress {
-                                println!("Progress: {}%", progress);
+                                progress::update(progress);
                                 old_progress = progress;
                             }
                         }
                     }
 
                     if verbose {
-                        println!("Saving data...")
+                        progress::message("Saving data...")
                     };
                     let _ = match output.write() {
                         Ok(_) => {
                             if verbose {
-                                println!("Output file written")
+                                progress::output_written(&output.file_name)
                             }
                         }
                         Err(e) => return Err(e),
@@ -895,7 +895,7 @@ impl WhiteboxTool for SinglePartToMultiPart {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!("{}", &format!("Elapsed Time: {}", elapsed_time));
+            progress::message(format!("Elapsed Time: {}", elapsed_time));
         }
 
         Ok(())
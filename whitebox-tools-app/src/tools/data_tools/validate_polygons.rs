@@ -0,0 +1,955 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: Dr. John Lindsay
+Created: 18/10/2026
+Last Modified: 18/10/2026
+License: MIT
+*/
+
+use whitebox_common::algorithms::{
+    group_rings, is_clockwise_order, polygon_boolean_with_snap, BooleanOp,
+};
+use whitebox_common::structures::{BoundingBox, Point2D};
+use whitebox_common::progress;
+use crate::tools::*;
+use whitebox_vector::*;
+use num_cpus;
+use std::env;
+use std::f64;
+use std::io::{Error, ErrorKind};
+use std::path;
+use std::sync::mpsc;
+use std::sync::Arc;
+
+/// This tool checks the topology of the polygons in a vector layer (`--input`) and writes a point
+/// (`--output`) at the location of each error that it finds. The following errors are reported,
+/// with the type of error given by the `ERROR` attribute of the output points:
+///
+/// | ERROR               | Description                                                             |
+/// |:--------------------|:------------------------------------------------------------------------|
+/// | `UNCLOSED_RING`     | The last vertex of a ring is not the same as its first vertex.          |
+/// | `DEGENERATE_RING`   | A ring has fewer than three distinct vertices or encloses no area.      |
+/// | `SELF_INTERSECTION` | The edges of a polygon cross or overlap, or a ring touches itself.      |
+/// | `RING_ORIENTATION`  | An outer ring is not clockwise, or a hole is not counter-clockwise.     |
+/// | `OVERLAP`           | Two polygons share some area.                                           |
+/// | `GAP`               | An area that is enclosed by polygons is not covered by any of them.     |
+/// | `SLIVER`            | A part of a polygon is smaller than the minimum area (`--min_area`).    |
+///
+/// The `FEATURE` attribute holds the record number of the polygon with the error and, for
+/// overlaps, the `OTHER` attribute holds the record number of the second polygon. Overlaps, gaps,
+/// and slivers are located by a point within them and their area is written to the `AREA`
+/// attribute. A hole that is enclosed by a single polygon is part of that polygon and is not
+/// reported as a gap. Rings that touch each other at a single point are valid, as are holes
+/// that touch their outer ring.
+///
+/// If a file name is given for the `--repaired` parameter, the tool also repairs the polygons
+/// and writes them, with their attributes, to that file. Polygons with ring errors are rebuilt
+/// so that their areas are those enclosed by an odd number of their rings, with crossing edges
+/// split and rings correctly oriented. Areas where polygons overlap are removed from all but
+/// the first of the polygons, in the order of the attribute table. Gaps that are smaller than
+/// `--min_area` are merged into the neighbouring polygon with which they share the longest
+/// boundary, after which polygon parts that are smaller than `--min_area` are removed, along
+/// with any polygons that are left empty.
+///
+/// The `--snap` parameter sets a distance within which nearly coincident boundaries are treated
+/// as shared. Gaps and overlaps that are narrower than this distance are not reported, and when
+/// repairing, the vertices of each polygon are snapped to those of the polygons before it in the
+/// table, or to their edges, if they are within this distance of them.
+///
+/// # See Also
+/// `CleanVector`, `RepairStreamVectorTopology`, `Dissolve`
+pub struct ValidatePolygons {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl ValidatePolygons {
+    pub fn new() -> ValidatePolygons {
+        // public constructor
+        let name = "ValidatePolygons".to_string();
+        let toolbox = "Data Tools".to_string();
+        let description = "Finds topological errors in a polygon layer, such as self-intersections, overlaps, and gaps, and optionally repairs them.".to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input Vector Polygon File".to_owned(),
+            flags: vec!["-i".to_owned(), "--input".to_owned()],
+            description: "Input vector polygon file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Vector(
+                VectorGeometryType::Polygon,
+            )),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output Error Points File".to_owned(),
+            flags: vec!["-o".to_owned(), "--output".to_owned()],
+            description: "Output vector points file of topology errors.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Vector(
+                VectorGeometryType::Point,
+            )),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output Repaired Polygon File (optional)".to_owned(),
+            flags: vec!["--repaired".to_owned()],
+            description: "Optional output vector polygon file of repaired polygons.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Vector(
+                VectorGeometryType::Polygon,
+            )),
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Snap Distance".to_owned(),
+            flags: vec!["--snap".to_owned()],
+            description: "Distance within which nearly coincident boundaries are treated as shared.".to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: Some("0.0".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Minimum Area".to_owned(),
+            flags: vec!["--min_area".to_owned()],
+            description: "Area below which polygon parts and gaps are treated as slivers.".to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: Some("0.0".to_owned()),
+            optional: true,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut parent = env::current_exe().unwrap();
+        parent.pop();
+        let p = format!("{}", parent.display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(
+            ">>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=parcels.shp -o=errors.shp --repaired=parcels_fixed.shp --snap=0.01 --min_area=1.0",
+            short_exe, name
+        ).replace("*", &sep);
+
+        ValidatePolygons {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for ValidatePolygons {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        match serde_json::to_string(&self.parameters) {
+            Ok(json_str) => return format!("{{\"parameters\":{}}}", json_str),
+            Err(err) => return format!("{:?}", err),
+        }
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+        cancel: &CancellationToken,
+    ) -> Result<(), Error> {
+        let args = ParsedArgs::parse(&self.get_tool_parameters(), &args, working_directory)?;
+        self.run_with_parsed_args(args, working_directory, verbose, cancel)
+    }
+
+    fn run_with_parsed_args<'a>(
+        &self,
+        args: ParsedArgs,
+        _working_directory: &'a str,
+        verbose: bool,
+        cancel: &CancellationToken,
+    ) -> Result<(), Error> {
+        let input_file = args.get_file("input").unwrap();
+        let output_file = args.get_file("output").unwrap();
+        let repaired_file = args.get_file("repaired");
+        let snap = args.get_float("snap").unwrap_or(0f64);
+        let min_area = args.get_float("min_area").unwrap_or(0f64);
+
+        if snap < 0f64 || min_area < 0f64 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The snap distance and minimum area must not be negative.",
+            ));
+        }
+
+        let mut progress: usize;
+        let mut old_progress: usize = 1;
+
+        let start = Instant::now();
+
+        if verbose {
+            let tool_name = self.get_tool_name();
+            progress::welcome(&tool_name);
+        }
+
+        if verbose {
+            progress::message("Reading data...")
+        };
+
+        let input = Shapefile::read(&input_file)?;
+
+        // make sure the input vector file is of polygon type
+        if input.header.shape_type.base_shape_type() != ShapeType::Polygon {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The input vector data must be of polygon base shape type.",
+            ));
+        }
+
+        let num_records = input.num_records;
+        let mut errors: Vec<TopologyError> = vec![];
+        let mut features: Vec<Vec<Vec<Point2D>>> = Vec::with_capacity(num_records);
+        let mut ring_errors = vec![false; num_records];
+        for record_num in 0..num_records {
+            let record = input.get_record(record_num);
+            let mut rings = vec![];
+            for part in 0..record.num_parts as usize {
+                let mut ring = record.get_part(part).to_vec();
+                if ring.is_empty() {
+                    continue;
+                }
+                if ring[0] != ring[ring.len() - 1] {
+                    errors.push(TopologyError::new("UNCLOSED_RING", ring[0], record_num));
+                    ring_errors[record_num] = true;
+                    ring.push(ring[0]);
+                }
+                if ring.len() < 4 || signed_area(&ring) == 0f64 {
+                    errors.push(TopologyError::new("DEGENERATE_RING", ring[0], record_num));
+                    ring_errors[record_num] = true;
+                    continue;
+                }
+                rings.push(ring);
+            }
+            features.push(rings);
+        }
+
+        let boxes: Vec<BoundingBox> = features
+            .iter()
+            .map(|rings| BoundingBox::from_points(&rings.concat()))
+            .collect();
+        let mut magnitude = 0f64;
+        for (rings, bb) in features.iter().zip(&boxes) {
+            if !rings.is_empty() {
+                magnitude = magnitude
+                    .max(bb.min_x.abs())
+                    .max(bb.max_x.abs())
+                    .max(bb.min_y.abs())
+                    .max(bb.max_y.abs());
+            }
+        }
+        let tolerance = (magnitude * 1e-12).max(f64::MIN_POSITIVE).max(snap);
+        let neighbours = find_neighbours(&features, &boxes, tolerance);
+
+        // Check each polygon for errors within it and for overlaps with the polygons after it.
+        let features = Arc::new(features);
+        let neighbours = Arc::new(neighbours);
+        let mut num_procs = num_cpus::get() as isize;
+        let configs = whitebox_common::configs::get_configs()?;
+        let max_procs = configs.max_procs;
+        if max_procs > 0 && max_procs < num_procs {
+            num_procs = max_procs;
+        }
+        let (tx, rx) = mpsc::channel();
+        for tid in 0..num_procs {
+            let features = features.clone();
+            let neighbours = neighbours.clone();
+            let tx = tx.clone();
+            progress::spawn(move || {
+                for record_num in (0..num_records).filter(|r| *r as isize % num_procs == tid) {
+                    let rings = &features[record_num];
+                    let mut feature_errors = check_rings(rings, record_num, tolerance);
+                    for &other in neighbours[record_num].iter().filter(|j| **j > record_num) {
+                        for (area, point) in
+                            overlaps(rings, &features[other], snap, tolerance)
+                        {
+                            let mut error = TopologyError::new("OVERLAP", point, record_num);
+                            error.other = Some(other);
+                            error.area = Some(area);
+                            feature_errors.push(error);
+                        }
+                    }
+                    if min_area > 0f64 {
+                        for polygon in group_rings(rings.clone()) {
+                            let area = polygon_area(&polygon);
+                            if area < min_area {
+                                let mut error =
+                                    TopologyError::new("SLIVER", label_point(&polygon), record_num);
+                                error.area = Some(area);
+                                feature_errors.push(error);
+                            }
+                        }
+                    }
+                    if tx.send((record_num, feature_errors)).is_err() {
+                        return;
+                    }
+                }
+            });
+        }
+        drop(tx);
+
+        let mut feature_errors: Vec<Vec<TopologyError>> = (0..num_records).map(|_| vec![]).collect();
+        for i in 0..num_records {
+            let (record_num, record_errors) = cancel.recv(&rx)?;
+            feature_errors[record_num] = record_errors;
+            if verbose {
+                progress = (100.0_f64 * (i + 1) as f64 / num_records as f64) as usize;
+                if progress != old_progress {
+                    progress::update_stage("Checking polygons", progress);
+                    old_progress = progress;
+                }
+            }
+        }
+        for (record_num, record_errors) in feature_errors.into_iter().enumerate() {
+            for error in record_errors {
+                match error.kind {
+                    "SELF_INTERSECTION" | "RING_ORIENTATION" => ring_errors[record_num] = true,
+                    _ => {}
+                }
+                errors.push(error);
+            }
+        }
+
+        if verbose {
+            progress::message("Finding gaps...")
+        };
+        for gap in find_gaps(&features, &boxes, snap, tolerance) {
+            let feature = gap.owner().unwrap_or(0);
+            let mut error = TopologyError::new("GAP", gap.point, feature);
+            error.area = Some(gap.area);
+            errors.push(error);
+        }
+        errors.sort_by(|a, b| a.feature.cmp(&b.feature));
+        cancel.check()?;
+
+        if let Some(repaired_file) = repaired_file {
+            let mut repaired: Vec<Vec<Vec<Point2D>>> = Vec::with_capacity(num_records);
+            for record_num in 0..num_records {
+                let mut rings = features[record_num].clone();
+                let mut changed = ring_errors[record_num];
+                if snap > 0f64 {
+                    let earlier: Vec<&Vec<Vec<Point2D>>> = neighbours[record_num]
+                        .iter()
+                        .filter(|j| **j < record_num)
+                        .map(|j| &repaired[*j])
+                        .collect();
+                    changed |= snap_rings(&mut rings, &earlier, snap);
+                }
+                if changed {
+                    rings = make_valid(&rings, snap);
+                }
+                let mut overlapping = vec![];
+                for &other in neighbours[record_num].iter().filter(|j| **j < record_num) {
+                    if !overlaps(&rings, &repaired[other], snap, tolerance).is_empty() {
+                        overlapping.extend(repaired[other].iter().cloned());
+                    }
+                }
+                if !overlapping.is_empty() {
+                    rings =
+                        polygon_boolean_with_snap(&rings, &overlapping, BooleanOp::Difference, snap);
+                }
+                repaired.push(rings);
+                cancel.check()?;
+                if verbose {
+                    progress = (100.0_f64 * (record_num + 1) as f64 / num_records as f64) as usize;
+                    if progress != old_progress {
+                        progress::update_stage("Repairing polygons", progress);
+                        old_progress = progress;
+                    }
+                }
+            }
+
+            if min_area > 0f64 {
+                // Merge the small gaps into their neighbours, then remove the small parts.
+                let boxes: Vec<BoundingBox> = repaired
+                    .iter()
+                    .map(|rings| BoundingBox::from_points(&rings.concat()))
+                    .collect();
+                for gap in find_gaps(&repaired, &boxes, snap, tolerance) {
+                    if gap.area < min_area {
+                        if let Some(owner) = gap.owner() {
+                            repaired[owner] = polygon_boolean_with_snap(
+                                &repaired[owner],
+                                &gap.rings,
+                                BooleanOp::Union,
+                                snap,
+                            );
+                        }
+                    }
+                }
+                for rings in repaired.iter_mut() {
+                    *rings = group_rings(rings.clone())
+                        .into_iter()
+                        .filter(|polygon| polygon_area(polygon) >= min_area)
+                        .flatten()
+                        .collect();
+                }
+            }
+
+            let mut output = Shapefile::initialize_using_file(
+                &repaired_file,
+                &input,
+                ShapeType::Polygon,
+                true,
+            )?;
+            for (record_num, rings) in repaired.into_iter().enumerate() {
+                if rings.is_empty() {
+                    continue;
+                }
+                output.add_record(ShapefileGeometry::from_polygon_rings(rings));
+                output
+                    .attributes
+                    .add_record(input.attributes.get_record(record_num), false);
+            }
+
+            if verbose {
+                progress::message("Saving data...")
+            };
+            let _ = match output.write() {
+                Ok(_) => {
+                    if verbose {
+                        progress::output_written(&output.file_name)
+                    }
+                }
+                Err(e) => return Err(e),
+            };
+        }
+
+        if errors.is_empty() {
+            if verbose {
+                progress::message("No topology errors were found; no error points are output.");
+            }
+        } else {
+            // create output file
+            let mut output = Shapefile::new(&output_file, ShapeType::Point)?;
+            output.projection = input.projection.clone();
+            output
+                .attributes
+                .add_field(&AttributeField::new("FID", FieldDataType::Int, 7u8, 0u8));
+            output
+                .attributes
+                .add_field(&AttributeField::new("ERROR", FieldDataType::Text, 20u8, 0u8));
+            output
+                .attributes
+                .add_field(&AttributeField::new("FEATURE", FieldDataType::Int, 7u8, 0u8));
+            output
+                .attributes
+                .add_field(&AttributeField::new("OTHER", FieldDataType::Int, 7u8, 0u8));
+            output
+                .attributes
+                .add_field(&AttributeField::new("AREA", FieldDataType::Real, 18u8, 6u8));
+
+            for (i, error) in errors.iter().enumerate() {
+                output.add_point_record(error.point.x, error.point.y);
+                output.attributes.add_record(
+                    vec![
+                        FieldData::Int(i as i32 + 1),
+                        FieldData::Text(error.kind.to_string()),
+                        FieldData::Int(error.feature as i32 + 1),
+                        match error.other {
+                            Some(other) => FieldData::Int(other as i32 + 1),
+                            None => FieldData::Null,
+                        },
+                        match error.area {
+                            Some(area) => FieldData::Real(area),
+                            None => FieldData::Null,
+                        },
+                    ],
+                    false,
+                );
+            }
+
+            if verbose {
+                progress::message(format!("Number of topology errors: {}", errors.len()));
+                progress::message("Saving data...")
+            };
+            let _ = match output.write() {
+                Ok(_) => {
+                    if verbose {
+                        progress::output_written(&output.file_name)
+                    }
+                }
+                Err(e) => return Err(e),
+            };
+        }
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+
+        if verbose {
+            progress::message(format!("Elapsed Time: {}", elapsed_time));
+        }
+
+        Ok(())
+    }
+}
+
+struct TopologyError {
+    kind: &'static str,
+    point: Point2D,
+    feature: usize,
+    other: Option<usize>,
+    area: Option<f64>,
+}
+
+impl TopologyError {
+    fn new(kind: &'static str, point: Point2D, feature: usize) -> TopologyError {
+        TopologyError {
+            kind: kind,
+            point: point,
+            feature: feature,
+            other: None,
+            area: None,
+        }
+    }
+}
+
+/// An area enclosed by polygons that is not covered by any of them, with the lengths of its
+/// boundary that are shared with each of the surrounding polygons.
+struct Gap {
+    rings: Vec<Vec<Point2D>>,
+    area: f64,
+    point: Point2D,
+    shared: Vec<(usize, f64)>,
+}
+
+impl Gap {
+    /// The polygon with which the gap shares the longest boundary, or the first of them if
+    /// there is more than one.
+    fn owner(&self) -> Option<usize> {
+        let mut shared = self.shared.clone();
+        shared.sort_by_key(|(j, _)| *j);
+        shared
+            .iter()
+            .fold(None, |best: Option<(usize, f64)>, &(j, length)| match best {
+                Some((_, l)) if l >= length => best,
+                _ => Some((j, length)),
+            })
+            .map(|(j, _)| j)
+    }
+}
+
+/// Finds the pairs of polygons whose bounding boxes are within `distance` of each other.
+fn find_neighbours(
+    features: &[Vec<Vec<Point2D>>],
+    boxes: &[BoundingBox],
+    distance: f64,
+) -> Vec<Vec<usize>> {
+    let mut order: Vec<usize> = (0..features.len())
+        .filter(|i| !features[*i].is_empty())
+        .collect();
+    order.sort_by(|a, b| boxes[*a].min_x.partial_cmp(&boxes[*b].min_x).unwrap());
+    let mut neighbours = vec![vec![]; features.len()];
+    for (k, &i) in order.iter().enumerate() {
+        for &j in &order[k + 1..] {
+            if boxes[j].min_x > boxes[i].max_x + distance {
+                break;
+            }
+            if boxes[j].min_y <= boxes[i].max_y + distance
+                && boxes[i].min_y <= boxes[j].max_y + distance
+            {
+                neighbours[i].push(j);
+                neighbours[j].push(i);
+            }
+        }
+    }
+    for list in neighbours.iter_mut() {
+        list.sort();
+    }
+    neighbours
+}
+
+/// Checks the rings of a polygon for self-intersections and incorrect orientation.
+fn check_rings(rings: &[Vec<Point2D>], feature: usize, tolerance: f64) -> Vec<TopologyError> {
+    let mut errors: Vec<TopologyError> = vec![];
+
+    // Each segment is stored with its ring, its position in the ring, and its end points.
+    let mut segments: Vec<(usize, usize, Point2D, Point2D)> = vec![];
+    for (r, ring) in rings.iter().enumerate() {
+        for k in 1..ring.len() {
+            if ring[k - 1] != ring[k] {
+                segments.push((r, segments.len(), ring[k - 1], ring[k]));
+            }
+        }
+    }
+    let ring_ends: Vec<(usize, usize)> = (0..rings.len())
+        .map(|r| {
+            let first = segments.iter().position(|s| s.0 == r).unwrap_or(0);
+            let last = segments.iter().rposition(|s| s.0 == r).unwrap_or(0);
+            (first, last)
+        })
+        .collect();
+    let mut order: Vec<usize> = (0..segments.len()).collect();
+    order.sort_by(|a, b| {
+        let (_, _, p, q) = segments[*a];
+        let (_, _, s, t) = segments[*b];
+        p.x.min(q.x).partial_cmp(&s.x.min(t.x)).unwrap()
+    });
+    let mut found: Vec<Point2D> = vec![];
+    for (k, &i) in order.iter().enumerate() {
+        let (ring_a, pos_a, a, b) = segments[i];
+        for &j in &order[k + 1..] {
+            let (ring_b, pos_b, c, d) = segments[j];
+            if c.x.min(d.x) > a.x.max(b.x) + tolerance {
+                break;
+            }
+            if c.y.min(d.y) > a.y.max(b.y) + tolerance || a.y.min(b.y) > c.y.max(d.y) + tolerance
+            {
+                continue;
+            }
+            let same_ring = ring_a == ring_b;
+            let (first, last) = ring_ends[ring_a];
+            let adjacent = same_ring
+                && (pos_a.max(pos_b) - pos_a.min(pos_b) == 1
+                    || pos_a.min(pos_b) == first && pos_a.max(pos_b) == last);
+            let point = match contact(a, b, c, d, tolerance) {
+                Contact::Overlap(p) => Some(p),
+                Contact::Cross(p) if !adjacent => Some(p),
+                Contact::Touch(p) if same_ring && !adjacent => Some(p),
+                _ => None,
+            };
+            if let Some(p) = point {
+                if !found.iter().any(|q| q.distance(&p) <= tolerance) {
+                    found.push(p);
+                    errors.push(TopologyError::new("SELF_INTERSECTION", p, feature));
+                }
+            }
+        }
+    }
+
+    // Rings that are nested within an even number of the other rings are outer rings.
+    for (r, ring) in rings.iter().enumerate() {
+        let p = Point2D::midpoint(&ring[0], &ring[1]);
+        let depth = rings
+            .iter()
+            .enumerate()
+            .filter(|(s, other)| *s != r && contains(other, p))
+            .count();
+        if is_clockwise_order(ring) != (depth % 2 == 0) {
+            errors.push(TopologyError::new("RING_ORIENTATION", ring[0], feature));
+        }
+    }
+    errors
+}
+
+/// Returns the area and an interior point of each of the areas shared by two polygons.
+fn overlaps(
+    a: &[Vec<Point2D>],
+    b: &[Vec<Point2D>],
+    snap: f64,
+    tolerance: f64,
+) -> Vec<(f64, Point2D)> {
+    if a.is_empty() || b.is_empty() {
+        return vec![];
+    }
+    group_rings(polygon_boolean_with_snap(
+        a,
+        b,
+        BooleanOp::Intersection,
+        snap,
+    ))
+    .into_iter()
+    .map(|polygon| (polygon_area(&polygon), polygon))
+    .filter(|(area, polygon)| *area > tolerance * perimeter(polygon))
+    .map(|(area, polygon)| (area, label_point(&polygon)))
+    .collect()
+}
+
+/// Finds the gaps in a set of polygons. The holes in the union of the polygons are gaps unless
+/// they are bounded by a single polygon.
+fn find_gaps(
+    features: &[Vec<Vec<Point2D>>],
+    boxes: &[BoundingBox],
+    snap: f64,
+    tolerance: f64,
+) -> Vec<Gap> {
+    let all_rings: Vec<Vec<Point2D>> = features.concat();
+    let union = polygon_boolean_with_snap(&all_rings, &[], BooleanOp::Union, snap);
+    let mut gaps = vec![];
+    for hole in union.iter().filter(|ring| signed_area(ring) > 0f64) {
+        let mut bb = BoundingBox::from_points(hole);
+        bb.expand_by(tolerance);
+        let candidates: Vec<usize> = (0..features.len())
+            .filter(|j| !features[*j].is_empty() && boxes[*j].overlaps(bb))
+            .collect();
+        let mut shared: Vec<(usize, f64)> = vec![];
+        for k in 1..hole.len() {
+            let p = Point2D::midpoint(&hole[k - 1], &hole[k]);
+            let length = hole[k - 1].distance(&hole[k]);
+            for &j in &candidates {
+                let on_boundary = features[j].iter().any(|ring| {
+                    (1..ring.len())
+                        .any(|m| distance_to_segment(p, ring[m - 1], ring[m]) <= tolerance)
+                });
+                if on_boundary {
+                    match shared.iter_mut().find(|(owner, _)| *owner == j) {
+                        Some(entry) => entry.1 += length,
+                        None => shared.push((j, length)),
+                    }
+                }
+            }
+        }
+        if shared.len() == 1 {
+            continue;
+        }
+        let mut outline = hole.clone();
+        outline.reverse();
+        let islands: Vec<Vec<Point2D>> = union
+            .iter()
+            .filter(|ring| BoundingBox::from_points(ring).overlaps(bb))
+            .cloned()
+            .collect();
+        let rings = polygon_boolean_with_snap(&[outline], &islands, BooleanOp::Difference, snap);
+        let polygons = group_rings(rings.clone());
+        let area: f64 = polygons.iter().map(|polygon| polygon_area(polygon)).sum();
+        let largest = polygons.iter().max_by(|a, b| {
+            polygon_area(a).partial_cmp(&polygon_area(b)).unwrap()
+        });
+        if let Some(polygon) = largest {
+            if area > tolerance * perimeter(polygon) {
+                gaps.push(Gap {
+                    point: label_point(polygon),
+                    rings: rings,
+                    area: area,
+                    shared: shared,
+                });
+            }
+        }
+    }
+    gaps
+}
+
+/// Snaps the vertices of a polygon to the vertices, or else the edges, of other polygons that
+/// are within `snap` of them. Returns true if any of the vertices were moved.
+fn snap_rings(rings: &mut [Vec<Point2D>], others: &[&Vec<Vec<Point2D>>], snap: f64) -> bool {
+    let mut moved = false;
+    for ring in rings.iter_mut() {
+        let n = ring.len();
+        for k in 0..n - 1 {
+            let p = ring[k];
+            let mut target: Option<(f64, Point2D)> = None;
+            for other in others.iter().flat_map(|rings| rings.iter()) {
+                for q in other {
+                    let d = p.distance(q);
+                    if d <= snap && target.map(|(best, _)| d < best).unwrap_or(true) {
+                        target = Some((d, *q));
+                    }
+                }
+            }
+            if target.is_none() {
+                for other in others.iter().flat_map(|rings| rings.iter()) {
+                    for m in 1..other.len() {
+                        let q = nearest_on_segment(p, other[m - 1], other[m]);
+                        let d = p.distance(&q);
+                        if d <= snap && target.map(|(best, _)| d < best).unwrap_or(true) {
+                            target = Some((d, q));
+                        }
+                    }
+                }
+            }
+            if let Some((_, q)) = target {
+                if q != p {
+                    ring[k] = q;
+                    moved = true;
+                }
+            }
+        }
+        ring[n - 1] = ring[0];
+    }
+    moved
+}
+
+/// Rebuilds a polygon as the area that is enclosed by an odd number of its rings, with each
+/// ring covering the area that it winds around, in either direction.
+fn make_valid(rings: &[Vec<Point2D>], snap: f64) -> Vec<Vec<Point2D>> {
+    let mut result: Vec<Vec<Point2D>> = vec![];
+    for ring in rings {
+        let mut reversed = ring.clone();
+        reversed.reverse();
+        let filled = polygon_boolean_with_snap(&[ring.clone()], &[reversed], BooleanOp::Union, snap);
+        result = if result.is_empty() {
+            filled
+        } else {
+            polygon_boolean_with_snap(&result, &filled, BooleanOp::SymmetricDifference, snap)
+        };
+    }
+    result
+}
+
+enum Contact {
+    None,
+    /// The segments cross at a point that is not an end point of either.
+    Cross(Point2D),
+    /// An end point of one segment lies on the other.
+    Touch(Point2D),
+    /// The segments overlap along some length; the point is the middle of the overlap.
+    Overlap(Point2D),
+}
+
+/// Finds how the segment from `a` to `b` meets the segment from `c` to `d`.
+fn contact(a: Point2D, b: Point2D, c: Point2D, d: Point2D, tolerance: f64) -> Contact {
+    let r = b - a;
+    let s = d - c;
+    let length = r * r;
+    if distance_to_segment(c, a, b).max(distance_to_segment(d, a, b)) <= tolerance
+        || distance_to_segment(a, c, d).max(distance_to_segment(b, c, d)) <= tolerance
+    {
+        // The segments are collinear; find the overlap of their projections onto a-b.
+        let tc = (c - a) * r / length;
+        let td = (d - a) * r / length;
+        let start = tc.min(td).max(0f64);
+        let end = tc.max(td).min(1f64);
+        if (end - start) * length.sqrt() > tolerance {
+            let t = (start + end) / 2f64;
+            return Contact::Overlap(Point2D::new(a.x + t * r.x, a.y + t * r.y));
+        }
+    }
+    for (p, e, f) in [(a, c, d), (b, c, d), (c, a, b), (d, a, b)] {
+        if distance_to_segment(p, e, f) <= tolerance {
+            return Contact::Touch(p);
+        }
+    }
+    let denom = r.cross(s);
+    if denom != 0f64 {
+        let t = (c - a).cross(s) / denom;
+        let u = (c - a).cross(r) / denom;
+        if t > 0f64 && t < 1f64 && u > 0f64 && u < 1f64 {
+            return Contact::Cross(Point2D::new(a.x + t * r.x, a.y + t * r.y));
+        }
+    }
+    Contact::None
+}
+
+fn nearest_on_segment(p: Point2D, a: Point2D, b: Point2D) -> Point2D {
+    let r = b - a;
+    let length = r * r;
+    if length == 0f64 {
+        return a;
+    }
+    let t = ((p - a) * r / length).clamp(0f64, 1f64);
+    Point2D::new(a.x + t * r.x, a.y + t * r.y)
+}
+
+fn distance_to_segment(p: Point2D, a: Point2D, b: Point2D) -> f64 {
+    p.distance(&nearest_on_segment(p, a, b))
+}
+
+/// Returns the signed area of a closed ring, which is negative if the ring is clockwise.
+fn signed_area(ring: &[Point2D]) -> f64 {
+    let mut area = 0f64;
+    for k in 1..ring.len() {
+        area += (ring[k - 1] - ring[0]).cross(ring[k] - ring[0]);
+    }
+    area / 2f64
+}
+
+/// Returns the area of a polygon given as an outer ring followed by its holes.
+fn polygon_area(polygon: &[Vec<Point2D>]) -> f64 {
+    polygon
+        .iter()
+        .enumerate()
+        .map(|(k, ring)| {
+            let area = signed_area(ring).abs();
+            if k == 0 {
+                area
+            } else {
+                -area
+            }
+        })
+        .sum()
+}
+
+fn perimeter(polygon: &[Vec<Point2D>]) -> f64 {
+    polygon
+        .iter()
+        .map(|ring| (1..ring.len()).map(|k| ring[k - 1].distance(&ring[k])).sum::<f64>())
+        .sum()
+}
+
+/// Tests whether a point is within a closed ring by the even-odd rule.
+fn contains(ring: &[Point2D], p: Point2D) -> bool {
+    let mut inside = false;
+    for k in 1..ring.len() {
+        let (a, b) = (ring[k - 1], ring[k]);
+        if (a.y <= p.y) != (b.y <= p.y) {
+            let x = a.x + (p.y - a.y) * (b.x - a.x) / (b.y - a.y);
+            if x > p.x {
+                inside = !inside;
+            }
+        }
+    }
+    inside
+}
+
+/// Returns a point within a polygon, given as an outer ring followed by its holes. The point
+/// is the middle of the widest span of the polygon along a horizontal line through the middle
+/// of its vertices.
+fn label_point(polygon: &[Vec<Point2D>]) -> Point2D {
+    let outer = &polygon[0];
+    let mut ys: Vec<f64> = outer.iter().map(|p| p.y).collect();
+    ys.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    ys.dedup();
+    if ys.len() < 2 {
+        return outer[0];
+    }
+    let k = (ys.len() - 1) / 2;
+    let y = (ys[k] + ys[k + 1]) / 2f64;
+    let mut xs: Vec<f64> = vec![];
+    for ring in polygon {
+        for m in 1..ring.len() {
+            let (a, b) = (ring[m - 1], ring[m]);
+            if (a.y <= y) != (b.y <= y) {
+                xs.push(a.x + (y - a.y) * (b.x - a.x) / (b.y - a.y));
+            }
+        }
+    }
+    xs.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let mut best: Option<(f64, f64)> = None;
+    for span in xs.chunks(2).filter(|span| span.len() == 2) {
+        if best.map(|(w, _)| span[1] - span[0] > w).unwrap_or(true) {
+            best = Some((span[1] - span[0], (span[0] + span[1]) / 2f64));
+        }
+    }
+    match best {
+        Some((_, x)) => Point2D::new(x, y),
+        None => outer[0],
+    }
+}
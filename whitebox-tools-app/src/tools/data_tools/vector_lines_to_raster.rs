@@ -8,6 +8,7 @@ License: MIT
 
 use whitebox_raster::*;
 use whitebox_common::structures::BoundingBox;
+use whitebox_common::progress;
 use crate::tools::*;
 use whitebox_vector::{FieldData, ShapeType, Shapefile};
 use std::env;
@@ -175,6 +176,7 @@ impl WhiteboxTool for VectorLinesToRaster {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut field_name = String::from("FID");
@@ -245,13 +247,7 @@ impl WhiteboxTool for VectorLinesToRaster {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -267,7 +263,7 @@ impl WhiteboxTool for VectorLinesToRaster {
         }
 
         if verbose {
-            println!("Reading data...")
+            progress::message("Reading data...")
         };
         let vector_data = Shapefile::read(&input_file).expect("Error reading input Shapefile.");
 
@@ -289,7 +285,7 @@ impl WhiteboxTool for VectorLinesToRaster {
             None => {
                 // Field not found use FID
                 if verbose {
-                    println!("Warning: Attribute not found in table. FID will be used instead.");
+                    progress::warning("Attribute not found in table. FID will be used instead.");
                 }
                 field_name = "FID".to_string();
                 0
@@ -300,7 +296,7 @@ impl WhiteboxTool for VectorLinesToRaster {
         if !vector_data.attributes.is_field_numeric(field_index) {
             // Warn user of non-numeric
             if verbose {
-                println!("Warning: Non-numeric attributes cannot be rasterized. FID will be used instead.");
+                progress::warning("Non-numeric attributes cannot be rasterized. FID will be used instead.");
             }
             field_name = "FID".to_string(); // Can't use non-numeric field; use FID instead.
         }
@@ -377,11 +373,12 @@ impl WhiteboxTool for VectorLinesToRaster {
                 attribute_data[record_num] = (record_num + 1) as f64;
             }
 
+            cancel.check()?;
             if verbose {
                 progress =
                     (100.0_f64 * record_num as f64 / (vector_data.num_records - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Reading attributes: {}%", progress);
+                    progress::update_stage("Reading attributes", progress);
                     old_progress = progress;
                 }
             }
@@ -516,15 +513,11 @@ impl WhiteboxTool for VectorLinesToRaster {
                     }
                 }
             }
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * (record_num + 1) as f64 / num_records as f64) as usize;
                 if progress != old_progress {
-                    println!(
-                        "Rasterizing {} of {}: {}%",
-                        record_num + 1,
-                        num_records,
-                        progress
-                    );
+                    progress::update_stage(&format!("Rasterizing {} of {}", record_num + 1, num_records), progress);
                     old_progress = progress;
                 }
             }
@@ -539,26 +532,23 @@ impl WhiteboxTool for VectorLinesToRaster {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
 
         if !output_something && verbose {
-            println!("Warning: No polylines were output to the raster.");
+            progress::warning("No polylines were output to the raster.");
         }
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
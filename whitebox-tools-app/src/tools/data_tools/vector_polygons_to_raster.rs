@@ -7,6 +7,7 @@ License: MIT
 */
 
 use whitebox_common::algorithms::point_in_poly;
+use whitebox_common::progress;
 use whitebox_raster::*;
 use whitebox_common::structures::{Array2D, BoundingBox, Point2D};
 use crate::tools::*;
@@ -153,6 +154,7 @@ impl WhiteboxTool for VectorPolygonsToRaster {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut field_name = String::from("FID");
@@ -223,13 +225,7 @@ impl WhiteboxTool for VectorPolygonsToRaster {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -245,7 +241,7 @@ impl WhiteboxTool for VectorPolygonsToRaster {
         }
 
         if verbose {
-            println!("Reading data...")
+            progress::message("Reading data...")
         };
         let vector_data = Shapefile::read(&input_file)?;
 
@@ -265,7 +261,7 @@ impl WhiteboxTool for VectorPolygonsToRaster {
             None => {
                 // Field not found use FID
                 if verbose {
-                    println!("Warning: Attribute not found in table. FID will be used instead.");
+                    progress::warning("Attribute not found in table. FID will be used instead.");
                 }
                 field_name = "FID".to_string();
                 0
@@ -278,8 +274,8 @@ impl WhiteboxTool for VectorPolygonsToRaster {
         if !vector_data.attributes.is_field_numeric(field_index) {
             // Warn user of non-numeric
             // if verbose {
-            println!("Warning: Non-numeric attributes cannot be directly assigned to raster data. A key will be established.");
-            println!("\nKey, Value");
+            progress::warning("Non-numeric attributes cannot be directly assigned to raster data. A key will be established.");
+            progress::message("\nKey, Value");
             // }
             // field_name = "FID".to_string(); // Can't use non-numeric field; use FID instead.
             let mut id = 1f64;
@@ -293,7 +289,7 @@ impl WhiteboxTool for VectorPolygonsToRaster {
                     FieldData::Null => "null".to_string(),
                 };
                 if !freq_data.contains_key(&key) {
-                    println!("{},{}", key, id);
+                    progress::message(format!("{},{}", key, id));
                     freq_data.insert(key, id);
                     id += 1f64;
                 }
@@ -375,11 +371,12 @@ impl WhiteboxTool for VectorPolygonsToRaster {
                 attribute_data[record_num] = (record_num + 1) as f64;
             }
 
+            cancel.check()?;
             if verbose {
                 progress =
                     (100.0_f64 * record_num as f64 / (vector_data.num_records - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Reading attributes: {}%", progress);
+                    progress::update_stage("Reading attributes", progress);
                     old_progress = progress;
                 }
             }
@@ -481,17 +478,13 @@ impl WhiteboxTool for VectorPolygonsToRaster {
                                     holes.set_value(r, c, record_i32);
                                 }
                             }
+                            cancel.check()?;
                             if verbose {
                                 progress = (100.0_f64 * r as f64
                                     / (ending_row - starting_row + 1) as f64)
                                     as usize;
                                 if progress != old_progress {
-                                    println!(
-                                        "Rasterizing {} of {}: {}%",
-                                        record_num + 1,
-                                        num_records,
-                                        progress
-                                    );
+                                    progress::update_stage(&format!("Rasterizing {} of {}", record_num + 1, num_records), progress);
                                     old_progress = progress;
                                 }
                             }
@@ -570,17 +563,13 @@ impl WhiteboxTool for VectorPolygonsToRaster {
                                     }
                                 }
                             }
+                            cancel.check()?;
                             if verbose {
                                 progress = (100.0_f64 * r as f64
                                     / (ending_row - starting_row + 1) as f64)
                                     as usize;
                                 if progress != old_progress {
-                                    println!(
-                                        "Rasterizing {} of {}: {}%",
-                                        record_num + 1,
-                                        num_records,
-                                        progress
-                                    );
+                                    progress::update_stage(&format!("Rasterizing {} of {}", record_num + 1, num_records), progress);
                                     old_progress = progress;
                                 }
                             }
@@ -588,15 +577,11 @@ impl WhiteboxTool for VectorPolygonsToRaster {
                     }
                 }
             }
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * (record_num + 1) as f64 / num_records as f64) as usize;
                 if progress != old_progress {
-                    println!(
-                        "Rasterizing {} of {}: {}%",
-                        record_num + 1,
-                        num_records,
-                        progress
-                    );
+                    progress::update_stage(&format!("Rasterizing {} of {}", record_num + 1, num_records), progress);
                     old_progress = progress;
                 }
             }
@@ -611,26 +596,23 @@ impl WhiteboxTool for VectorPolygonsToRaster {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
 
         if !output_something && verbose {
-            println!("Warning: No polygons were output to the raster.");
+            progress::warning("No polygons were output to the raster.");
         }
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -0,0 +1,55 @@
+/*
+This code is part of the WhiteboxTools geospatial analysis library.
+Authors: Dr. John Lindsay
+Created: 18/10/2026
+Last Modified: 18/10/2026
+License: MIT
+*/
+
+use std::error;
+use std::fmt;
+use std::io;
+
+/// The error returned when a tool is invoked through `ToolManager::run_tool_with_args`.
+#[derive(Debug)]
+pub enum ToolError {
+    /// No built-in tool has the requested name.
+    UnrecognizedTool(String),
+    /// The structured arguments could not be converted into tool arguments.
+    InvalidArguments(String),
+    /// The tool ran but returned an error.
+    Execution { tool_name: String, source: io::Error },
+    /// The tool panicked. Many tools still report bad argument values this way.
+    Panic { tool_name: String, message: String },
+}
+
+impl fmt::Display for ToolError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ToolError::UnrecognizedTool(name) => write!(f, "Unrecognized tool name {}.", name),
+            ToolError::InvalidArguments(msg) => write!(f, "Invalid tool arguments: {}", msg),
+            ToolError::Execution { tool_name, source } => write!(f, "{} failed: {}", tool_name, source),
+            ToolError::Panic { tool_name, message } => write!(f, "{} panicked: {}", tool_name, message),
+        }
+    }
+}
+
+impl error::Error for ToolError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            ToolError::Execution { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+impl From<ToolError> for io::Error {
+    fn from(err: ToolError) -> io::Error {
+        match err {
+            ToolError::UnrecognizedTool(_) => io::Error::new(io::ErrorKind::NotFound, err.to_string()),
+            ToolError::InvalidArguments(_) => io::Error::new(io::ErrorKind::InvalidInput, err.to_string()),
+            ToolError::Execution { source, .. } => source,
+            ToolError::Panic { .. } => io::Error::new(io::ErrorKind::Other, err.to_string()),
+        }
+    }
+}
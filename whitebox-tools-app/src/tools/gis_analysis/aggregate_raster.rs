@@ -7,6 +7,7 @@ License: MIT
 */
 
 use whitebox_raster::*;
+use whitebox_common::progress;
 use crate::tools::*;
 use num_cpus;
 use std::env;
@@ -15,7 +16,6 @@ use std::io::{Error, ErrorKind};
 use std::path;
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::thread;
 
 /// This tool can be used to reduce the grid resolution of a raster by a user specified amount. For example, using
 /// an aggregation factor (`--agg_factor`) of 2 would result in a raster with half the number of rows and columns.
@@ -96,7 +96,7 @@ impl AggregateRaster {
             short_exe += ".exe";
         }
         let usage = format!(
-            ">>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=input.tif -o=output.tif --output_text",
+            ">>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=input.tif -o=output.tif",
             short_exe, name
         )
         .replace("*", &sep);
@@ -144,6 +144,7 @@ impl WhiteboxTool for AggregateRaster {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut output_file = String::new();
@@ -185,9 +186,7 @@ impl WhiteboxTool for AggregateRaster {
                     agg_factor = args[i + 1].to_string().parse::<isize>().unwrap();
                 }
                 if agg_factor < 2isize {
-                    println!(
-                        "WARNING: Aggregation factor cannot be less than 2. It has been modified."
-                    );
+                    progress::warning("Aggregation factor cannot be less than 2. It has been modified.");
                     agg_factor = 2isize;
                 }
             } else if flag_val == "-type" {
@@ -201,13 +200,7 @@ impl WhiteboxTool for AggregateRaster {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -223,7 +216,7 @@ impl WhiteboxTool for AggregateRaster {
         }
 
         if verbose {
-            println!("Reading input data...")
+            progress::message("Reading input data...")
         };
         let input = Arc::new(Raster::new(&input_file, "r")?);
 
@@ -273,7 +266,7 @@ impl WhiteboxTool for AggregateRaster {
                 for tid in 0..num_procs {
                     let input = input.clone();
                     let tx = tx.clone();
-                    thread::spawn(move || {
+                    progress::spawn(move || {
                         let mut z: f64;
                         let mut row_in: isize;
                         let mut col_in: isize;
@@ -300,18 +293,20 @@ impl WhiteboxTool for AggregateRaster {
                                     data[col as usize] = stat;
                                 }
                             }
-                            tx.send((row, data)).unwrap();
+                            if tx.send((row, data)).is_err() {
+                                return;
+                            }
                         }
                     });
                 }
 
                 for r in 0..rows_out {
-                    let (row, data) = rx.recv().expect("Error receiving data from thread.");
+                    let (row, data) = cancel.recv(&rx)?;
                     output.set_row_data(row, data);
                     if verbose {
                         progress = (100.0_f64 * r as f64 / (rows_out - 1) as f64) as usize;
                         if progress != old_progress {
-                            println!("Progress: {}%", progress);
+                            progress::update(progress);
                             old_progress = progress;
                         }
                     }
@@ -321,7 +316,7 @@ impl WhiteboxTool for AggregateRaster {
                 for tid in 0..num_procs {
                     let input = input.clone();
                     let tx = tx.clone();
-                    thread::spawn(move || {
+                    progress::spawn(move || {
                         let mut z: f64;
                         let mut row_in: isize;
                         let mut col_in: isize;
@@ -347,18 +342,20 @@ impl WhiteboxTool for AggregateRaster {
                                     data[col as usize] = stat;
                                 }
                             }
-                            tx.send((row, data)).unwrap();
+                            if tx.send((row, data)).is_err() {
+                                return;
+                            }
                         }
                     });
                 }
 
                 for r in 0..rows_out {
-                    let (row, data) = rx.recv().expect("Error receiving data from thread.");
+                    let (row, data) = cancel.recv(&rx)?;
                     output.set_row_data(row, data);
                     if verbose {
                         progress = (100.0_f64 * r as f64 / (rows_out - 1) as f64) as usize;
                         if progress != old_progress {
-                            println!("Progress: {}%", progress);
+                            progress::update(progress);
                             old_progress = progress;
                         }
                     }
@@ -368,7 +365,7 @@ impl WhiteboxTool for AggregateRaster {
                 for tid in 0..num_procs {
                     let input = input.clone();
                     let tx = tx.clone();
-                    thread::spawn(move || {
+                    progress::spawn(move || {
                         let mut z: f64;
                         let mut row_in: isize;
                         let mut col_in: isize;
@@ -396,18 +393,20 @@ impl WhiteboxTool for AggregateRaster {
                                     data[col as usize] = stat;
                                 }
                             }
-                            tx.send((row, data)).unwrap();
+                            if tx.send((row, data)).is_err() {
+                                return;
+                            }
                         }
                     });
                 }
 
                 for r in 0..rows_out {
-                    let (row, data) = rx.recv().expect("Error receiving data from thread.");
+                    let (row, data) = cancel.recv(&rx)?;
                     output.set_row_data(row, data);
                     if verbose {
                         progress = (100.0_f64 * r as f64 / (rows_out - 1) as f64) as usize;
                         if progress != old_progress {
-                            println!("Progress: {}%", progress);
+                            progress::update(progress);
                             old_progress = progress;
                         }
                     }
@@ -417,7 +416,7 @@ impl WhiteboxTool for AggregateRaster {
                 for tid in 0..num_procs {
                     let input = input.clone();
                     let tx = tx.clone();
-                    thread::spawn(move || {
+                    progress::spawn(move || {
                         let mut z: f64;
                         let mut row_in: isize;
                         let mut col_in: isize;
@@ -445,18 +444,20 @@ impl WhiteboxTool for AggregateRaster {
                                     data[col as usize] = stat;
                                 }
                             }
-                            tx.send((row, data)).unwrap();
+                            if tx.send((row, data)).is_err() {
+                                return;
+                            }
                         }
                     });
                 }
 
                 for r in 0..rows_out {
-                    let (row, data) = rx.recv().expect("Error receiving data from thread.");
+                    let (row, data) = cancel.recv(&rx)?;
                     output.set_row_data(row, data);
                     if verbose {
                         progress = (100.0_f64 * r as f64 / (rows_out - 1) as f64) as usize;
                         if progress != old_progress {
-                            println!("Progress: {}%", progress);
+                            progress::update(progress);
                             old_progress = progress;
                         }
                     }
@@ -466,7 +467,7 @@ impl WhiteboxTool for AggregateRaster {
                 for tid in 0..num_procs {
                     let input = input.clone();
                     let tx = tx.clone();
-                    thread::spawn(move || {
+                    progress::spawn(move || {
                         let mut z: f64;
                         let mut row_in: isize;
                         let mut col_in: isize;
@@ -499,18 +500,20 @@ impl WhiteboxTool for AggregateRaster {
                                     data[col as usize] = max_val - min_val;
                                 }
                             }
-                            tx.send((row, data)).unwrap();
+                            if tx.send((row, data)).is_err() {
+                                return;
+                            }
                         }
                     });
                 }
 
                 for r in 0..rows_out {
-                    let (row, data) = rx.recv().expect("Error receiving data from thread.");
+                    let (row, data) = cancel.recv(&rx)?;
                     output.set_row_data(row, data);
                     if verbose {
                         progress = (100.0_f64 * r as f64 / (rows_out - 1) as f64) as usize;
                         if progress != old_progress {
-                            println!("Progress: {}%", progress);
+                            progress::update(progress);
                             old_progress = progress;
                         }
                     }
@@ -532,22 +535,19 @@ impl WhiteboxTool for AggregateRaster {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -8,6 +8,7 @@ License: MIT
 
 use whitebox_raster::*;
 use whitebox_common::structures::Array2D;
+use whitebox_common::progress;
 use crate::tools::*;
 use std::env;
 use std::f64;
@@ -46,7 +47,7 @@ impl AverageOverlay {
         let mut parameters = vec![];
         parameters.push(ToolParameter {
             name: "Input Files".to_owned(),
-            flags: vec!["-i".to_owned(), "--inputs".to_owned()],
+            flags: vec!["-i".to_owned(), "--input".to_owned(), "--inputs".to_owned()],
             description: "Input raster files.".to_owned(),
             parameter_type: ParameterType::FileList(ParameterFileType::Raster),
             default_value: None,
@@ -120,6 +121,7 @@ impl WhiteboxTool for AverageOverlay {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_files = String::new();
         let mut output_file = String::new();
@@ -157,13 +159,7 @@ impl WhiteboxTool for AverageOverlay {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -204,7 +200,7 @@ impl WhiteboxTool for AverageOverlay {
         for value in vec {
             if !value.trim().is_empty() {
                 if verbose {
-                    println!("Reading data...")
+                    progress::message("Reading data...")
                 };
 
                 let mut input_file = value.trim().to_owned();
@@ -243,10 +239,11 @@ impl WhiteboxTool for AverageOverlay {
                             }
                         }
                     }
+                    cancel.check()?;
                     if verbose {
                         progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                         if progress != old_progress {
-                            println!("Progress (loop {} of {}): {}%", i, num_files + 1, progress);
+                            progress::update_stage(&format!("Progress (loop {} of {})", i, num_files + 1), progress);
                             old_progress = progress;
                         }
                     }
@@ -266,15 +263,11 @@ impl WhiteboxTool for AverageOverlay {
                     }
                 }
             }
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!(
-                        "Progress (loop {} of {}): {}%",
-                        num_files + 1,
-                        num_files + 1,
-                        progress
-                    );
+                    progress::update_stage(&format!("Progress (loop {} of {})", num_files + 1, num_files + 1), progress);
                     old_progress = progress;
                 }
             }
@@ -288,22 +281,19 @@ impl WhiteboxTool for AverageOverlay {
         output.add_metadata_entry(format!("Elapsed Time (including I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (including I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (including I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -7,6 +7,7 @@ License: MIT
 */
 
 use whitebox_raster::*;
+use whitebox_common::progress;
 use crate::tools::*;
 use whitebox_vector::{FieldData, ShapeType, Shapefile};
 use std::env;
@@ -149,6 +150,7 @@ impl WhiteboxTool for BlockMinimumGridding {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut field_name = String::new();
@@ -218,13 +220,7 @@ impl WhiteboxTool for BlockMinimumGridding {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -240,7 +236,7 @@ impl WhiteboxTool for BlockMinimumGridding {
         }
 
         if verbose {
-            println!("Reading data...")
+            progress::message("Reading data...")
         };
         let vector_data = Shapefile::read(&input_file)?;
 
@@ -349,12 +345,13 @@ impl WhiteboxTool for BlockMinimumGridding {
                     output.set_value(row, col, z);
                 }
 
+                cancel.check()?;
                 if verbose {
                     progress = (100.0_f64 * record_num as f64
                         / (vector_data.num_records - 1) as f64)
                         as usize;
                     if progress != old_progress {
-                        println!("Progress: {}%", progress);
+                        progress::update(progress);
                         old_progress = progress;
                     }
                 }
@@ -386,12 +383,13 @@ impl WhiteboxTool for BlockMinimumGridding {
                     }
                 }
 
+                cancel.check()?;
                 if verbose {
                     progress = (100.0_f64 * record_num as f64
                         / (vector_data.num_records - 1) as f64)
                         as usize;
                     if progress != old_progress {
-                        println!("Progress: {}%", progress);
+                        progress::update(progress);
                         old_progress = progress;
                     }
                 }
@@ -407,22 +405,19 @@ impl WhiteboxTool for BlockMinimumGridding {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
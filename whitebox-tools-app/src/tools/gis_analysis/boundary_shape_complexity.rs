@@ -8,6 +8,7 @@ License: MIT
 
 use whitebox_raster::*;
 use whitebox_common::structures::Array2D;
+use whitebox_common::progress;
 use crate::tools::*;
 use num_cpus;
 use std::env;
@@ -16,7 +17,6 @@ use std::io::{Error, ErrorKind};
 use std::path;
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::thread;
 
 /// This tools calculates a type of shape complexity index for raster objects, focused on the complexity of the
 /// boundary of polygons. The index uses the `LineThinning` tool to estimate a skeletonized network for each
@@ -81,7 +81,7 @@ impl BoundaryShapeComplexity {
             short_exe += ".exe";
         }
         let usage = format!(
-            ">>.*{} -r={} -v --wd=\"*path*to*data*\" -i=input.tif -o=output.tif --zero_back",
+            ">>.*{} -r={} -v --wd=\"*path*to*data*\" -i=input.tif -o=output.tif",
             short_exe, name
         )
         .replace("*", &sep);
@@ -129,6 +129,7 @@ impl WhiteboxTool for BoundaryShapeComplexity {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut output_file = String::new();
@@ -166,13 +167,7 @@ impl WhiteboxTool for BoundaryShapeComplexity {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -189,7 +184,7 @@ impl WhiteboxTool for BoundaryShapeComplexity {
         }
 
         if verbose {
-            println!("Reading data...");
+            progress::message("Reading data...");
         }
 
         let input = Arc::new(Raster::new(&input_file, "r")?);
@@ -214,7 +209,7 @@ impl WhiteboxTool for BoundaryShapeComplexity {
         for tid in 0..num_procs {
             let input = input.clone();
             let tx = tx.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 for row in (0..rows).filter(|r| r % num_procs == tid) {
                     let mut data: Vec<f64> = vec![nodata; columns as usize];
                     for col in 0..columns {
@@ -224,13 +219,15 @@ impl WhiteboxTool for BoundaryShapeComplexity {
                             data[col as usize] = 0.0;
                         }
                     }
-                    tx.send((row, data)).unwrap();
+                    if tx.send((row, data)).is_err() {
+                        return;
+                    }
                 }
             });
         }
 
         if verbose {
-            println!("Performing line-thinning...");
+            progress::message("Performing line-thinning...");
         }
 
         let mut output = Raster::initialize_using_file(&output_file, &input);
@@ -241,13 +238,13 @@ impl WhiteboxTool for BoundaryShapeComplexity {
         output.configs.data_type = DataType::F32;
         output.configs.palette = String::from("spectrum_black_background.pal");
         for r in 0..rows {
-            let (row, data) = rx.recv().expect("Error receiving data from thread.");
+            let (row, data) = cancel.recv(&rx)?;
             output.set_row_data(row, data);
 
             if verbose {
                 progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Initializing output: {}%", progress);
+                    progress::update_stage("Initializing output", progress);
                     old_progress = progress;
                 }
             }
@@ -318,10 +315,11 @@ impl WhiteboxTool for BoundaryShapeComplexity {
                         }
                     }
                 }
+                cancel.check()?;
                 if verbose {
                     progress = (100.0_f64 * (a + 1) as f64 / 4.0) as usize;
                     if progress != old_progress {
-                        println!("Loop Number {}: {}%", loop_num, progress);
+                        progress::update_stage(&format!("Loop Number {}", loop_num), progress);
                         old_progress = progress;
                     }
                 }
@@ -487,10 +485,11 @@ impl WhiteboxTool for BoundaryShapeComplexity {
                 //     }
                 // }
             }
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Calculating Index: {}%", progress);
+                    progress::update_stage("Calculating Index", progress);
                     old_progress = progress;
                 }
             }
@@ -527,10 +526,11 @@ impl WhiteboxTool for BoundaryShapeComplexity {
                     output[(row, col)] = 0f64;
                 }
             }
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Calculating Index: {}%", progress);
+                    progress::update_stage("Calculating Index", progress);
                     old_progress = progress;
                 }
             }
@@ -545,22 +545,19 @@ impl WhiteboxTool for BoundaryShapeComplexity {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -8,6 +8,7 @@ License: MIT
 
 use whitebox_raster::*;
 use whitebox_common::structures::Array2D;
+use whitebox_common::progress;
 use crate::tools::*;
 use std::env;
 use std::f64;
@@ -153,6 +154,7 @@ impl WhiteboxTool for BufferRaster {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut output_file = String::new();
@@ -208,13 +210,7 @@ impl WhiteboxTool for BufferRaster {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -230,7 +226,7 @@ impl WhiteboxTool for BufferRaster {
         }
 
         if verbose {
-            println!("Reading data...")
+            progress::message("Reading data...")
         };
 
         let input = Raster::new(&input_file, "r")?;
@@ -264,10 +260,11 @@ impl WhiteboxTool for BufferRaster {
                     output[(row, col)] = inf_val;
                 }
             }
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Initializing Rasters: {}%", progress);
+                    progress::update_stage("Initializing Rasters", progress);
                     old_progress = progress;
                 }
             }
@@ -306,10 +303,11 @@ impl WhiteboxTool for BufferRaster {
                     }
                 }
             }
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress (1 of 3): {}%", progress);
+                    progress::update_stage("Progress (1 of 3)", progress);
                     old_progress = progress;
                 }
             }
@@ -348,10 +346,11 @@ impl WhiteboxTool for BufferRaster {
                     }
                 }
             }
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress (2 of 3): {}%", progress);
+                    progress::update_stage("Progress (2 of 3)", progress);
                     old_progress = progress;
                 }
             }
@@ -377,10 +376,11 @@ impl WhiteboxTool for BufferRaster {
                     output[(row, col)] = nodata;
                 }
             }
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress (3 of 3): {}%", progress);
+                    progress::update_stage("Progress (3 of 3)", progress);
                     old_progress = progress;
                 }
             }
@@ -399,22 +399,19 @@ impl WhiteboxTool for BufferRaster {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -0,0 +1,459 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: Dr. John Lindsay
+Created: 18/10/2026
+Last Modified: 18/10/2026
+License: MIT
+*/
+
+use whitebox_common::structures::Point2D;
+use whitebox_common::progress;
+use crate::tools::*;
+use whitebox_vector::*;
+use std::collections::HashMap;
+use std::env;
+use std::f64;
+use std::io::{Error, ErrorKind};
+use std::path;
+
+/// This tool creates routes, i.e. polylines with measures (M values) at their vertices, for use
+/// in linear referencing. Measures identify locations along a route, such as the chainage of a
+/// road or the distance upstream along a stream, and are used by the `LocatePointsAlongRoutes`
+/// and `CreateRouteEvents` tools. The output (`--output`) is a PolyLineM vector with one feature
+/// for each route.
+///
+/// Input lines (`--input`) with the same value of the route identifier field (`--route_id`) are
+/// combined into a single route, whose parts are measured in the order in which the lines occur
+/// in the input file; each input feature is a separate route if no field is specified, in
+/// which case the routes are identified by the feature's record number in a `ROUTE_ID` field.
+/// The measures increase from `--start` at the first vertex of each route, by the length along
+/// the route multiplied by `--factor`, e.g. a factor of 0.001 gives measures in kilometres for
+/// lines in metres. The gaps between the parts of a route are not measured.
+///
+/// Alternatively, routes can be calibrated from a set of control points (`--points`), such as
+/// surveyed kilometre posts, each of which holds a measure in a numeric field (`--measure_field`).
+/// Each control point is assigned to the nearest route, if it is within `--tolerance` of the
+/// route, and the route's measures are interpolated by length between its control points and
+/// extrapolated beyond them using the rate of change between the nearest two control points.
+/// Routes with only one control point are measured by length, multiplied by `--factor`, from the
+/// control point's measure, and routes without control points are measured from `--start`.
+///
+/// The output attribute table contains the route identifier field, along with the route's
+/// `LENGTH` and the measures at its first (`FROM_M`) and last (`TO_M`) vertices.
+///
+/// # See Also
+/// `LocatePointsAlongRoutes`, `CreateRouteEvents`
+pub struct CalibrateRoutes {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl CalibrateRoutes {
+    pub fn new() -> CalibrateRoutes {
+        // public constructor
+        let name = "CalibrateRoutes".to_string();
+        let toolbox = "GIS Analysis/Linear Referencing".to_string();
+        let description =
+            "Creates measured routes from lines, using their lengths or a set of control points."
+                .to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input Vector File".to_owned(),
+            flags: vec!["-i".to_owned(), "--input".to_owned()],
+            description: "Input vector lines file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Vector(
+                VectorGeometryType::Line,
+            )),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Route Identifier Field (optional)".to_owned(),
+            flags: vec!["--route_id".to_owned()],
+            description: "Optional field identifying the route of each line; each line is a route by default."
+                .to_owned(),
+            parameter_type: ParameterType::VectorAttributeField(
+                AttributeType::Any,
+                "--input".to_string(),
+            ),
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output Vector File".to_owned(),
+            flags: vec!["-o".to_owned(), "--output".to_owned()],
+            description: "Output vector routes file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Vector(
+                VectorGeometryType::Line,
+            )),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Start Measure".to_owned(),
+            flags: vec!["--start".to_owned()],
+            description: "Measure at the start of each route.".to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: Some("0.0".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Measure Factor".to_owned(),
+            flags: vec!["--factor".to_owned()],
+            description: "Change in measure per unit of length along a route.".to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: Some("1.0".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Input Control Points File (optional)".to_owned(),
+            flags: vec!["--points".to_owned()],
+            description: "Optional input vector points file of the control points used to calibrate the routes."
+                .to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Vector(
+                VectorGeometryType::Point,
+            )),
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Measure Field (optional)".to_owned(),
+            flags: vec!["--measure_field".to_owned()],
+            description: "Numeric field holding the measure of each control point.".to_owned(),
+            parameter_type: ParameterType::VectorAttributeField(
+                AttributeType::Number,
+                "--points".to_string(),
+            ),
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Search Tolerance (optional)".to_owned(),
+            flags: vec!["--tolerance".to_owned()],
+            description: "Optional maximum distance between a control point and its route.".to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: None,
+            optional: true,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut parent = env::current_exe().unwrap();
+        parent.pop();
+        let p = format!("{}", parent.display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(
+            ">>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=roads.shp --route_id=ROAD_NUM -o=routes.shp --points=km_posts.shp --measure_field=KM --tolerance=5.0",
+            short_exe, name
+        ).replace("*", &sep);
+
+        CalibrateRoutes {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for CalibrateRoutes {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        match serde_json::to_string(&self.parameters) {
+            Ok(json_str) => return format!("{{\"parameters\":{}}}", json_str),
+            Err(err) => return format!("{:?}", err),
+        }
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+        cancel: &CancellationToken,
+    ) -> Result<(), Error> {
+        let args = ParsedArgs::parse(&self.get_tool_parameters(), &args, working_directory)?;
+        self.run_with_parsed_args(args, working_directory, verbose, cancel)
+    }
+
+    fn run_with_parsed_args<'a>(
+        &self,
+        args: ParsedArgs,
+        _working_directory: &'a str,
+        verbose: bool,
+        cancel: &CancellationToken,
+    ) -> Result<(), Error> {
+        let input_file = args.get_file("input").unwrap();
+        let route_id_field = args.get_string("route_id").filter(|s| !s.is_empty());
+        let output_file = args.get_file("output").unwrap();
+        let start_measure = args.get_float("start").unwrap_or(0f64);
+        let factor = args.get_float("factor").unwrap_or(1f64);
+        let points_file = args.get_file("points");
+        let measure_field = args.get_string("measure_field").filter(|s| !s.is_empty());
+        let tolerance = args.get_float("tolerance").unwrap_or(f64::INFINITY);
+
+        if points_file.is_some() && measure_field.is_none() {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "A measure field (--measure_field) must be specified for the control points.",
+            ));
+        }
+        if tolerance < 0f64 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The search tolerance (--tolerance) must not be negative.",
+            ));
+        }
+
+        let mut progress: usize;
+        let mut old_progress: usize = 1;
+
+        let start = Instant::now();
+
+        if verbose {
+            let tool_name = self.get_tool_name();
+            progress::welcome(&tool_name);
+        }
+
+        if verbose {
+            progress::message("Reading data...")
+        };
+
+        let input = Shapefile::read(&input_file)?;
+        if input.header.shape_type.base_shape_type() != ShapeType::PolyLine {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The input vector data must be of POLYLINE base shape type.",
+            ));
+        }
+        let id_field = match &route_id_field {
+            Some(name) => match input.attributes.get_field_num(name) {
+                Some(i) => Some(i),
+                None => {
+                    return Err(Error::new(
+                        ErrorKind::InvalidInput,
+                        format!("The field '{}' does not exist in the input attribute table.", name),
+                    ));
+                }
+            },
+            None => None,
+        };
+
+        // Gather the lines of each route, in the order in which the routes first occur.
+        let mut route_ids: Vec<FieldData> = vec![];
+        let mut route_parts: Vec<Vec<Vec<Point2D>>> = vec![];
+        let mut route_index: HashMap<String, usize> = HashMap::new();
+        let mut num_null_ids = 0;
+        for record_num in 0..input.num_records {
+            let record = input.get_record(record_num);
+            let (id, key) = match id_field {
+                Some(i) => {
+                    let id = input.attributes.get_record(record_num)[i].clone();
+                    match route_key(&id) {
+                        Some(key) => (id, key),
+                        None => {
+                            num_null_ids += 1;
+                            continue;
+                        }
+                    }
+                }
+                None => (FieldData::Int(record_num as i32 + 1), record_num.to_string()),
+            };
+            let r = *route_index.entry(key).or_insert_with(|| {
+                route_ids.push(id);
+                route_parts.push(vec![]);
+                route_parts.len() - 1
+            });
+            for part in 0..record.num_parts as usize {
+                route_parts[r].push(record.get_part(part).to_vec());
+            }
+        }
+        let mut routes: Vec<Route> = route_parts
+            .into_iter()
+            .map(|parts| Route::measured_by_length(parts, start_measure, factor))
+            .collect();
+
+        if let Some(points_file) = points_file {
+            let points = Shapefile::read(&points_file)?;
+            if points.header.shape_type.base_shape_type() != ShapeType::Point {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    "The control points vector data must be of POINT base shape type.",
+                ));
+            }
+            let measure_field = measure_field.unwrap();
+            let measure_index = match points.attributes.get_field_num(&measure_field) {
+                Some(i) => i,
+                None => {
+                    return Err(Error::new(
+                        ErrorKind::InvalidInput,
+                        format!(
+                            "The field '{}' does not exist in the control points attribute table.",
+                            measure_field
+                        ),
+                    ));
+                }
+            };
+
+            let mut controls: Vec<Vec<(f64, f64)>> = vec![vec![]; routes.len()];
+            let mut num_unmatched = 0;
+            for record_num in 0..points.num_records {
+                let measure = match points.attributes.get_record(record_num)[measure_index] {
+                    FieldData::Int(v) => v as f64,
+                    FieldData::Real(v) => v,
+                    _ => continue,
+                };
+                let p = match points.get_record(record_num).points.first() {
+                    Some(p) => *p,
+                    None => continue,
+                };
+                let mut nearest: Option<(usize, RoutePosition)> = None;
+                for (r, route) in routes.iter().enumerate() {
+                    if let Some(position) = route.locate(p) {
+                        let closer = match nearest {
+                            Some((_, best)) => position.distance < best.distance,
+                            None => true,
+                        };
+                        if closer && position.distance <= tolerance {
+                            nearest = Some((r, position));
+                        }
+                    }
+                }
+                match nearest {
+                    Some((r, position)) => controls[r].push((position.along, measure)),
+                    None => num_unmatched += 1,
+                }
+
+                if verbose {
+                    progress = (100.0_f64 * (record_num + 1) as f64 / points.num_records as f64) as usize;
+                    if progress != old_progress {
+                        progress::update_stage("Locating control points", progress);
+                        old_progress = progress;
+                    }
+                }
+            }
+            if verbose && num_unmatched > 0 {
+                progress::message(format!(
+                    "Warning: {} control points are not within the tolerance of a route.",
+                    num_unmatched
+                ));
+            }
+
+            for (route, route_controls) in routes.iter_mut().zip(&controls) {
+                route.calibrate(route_controls, factor);
+            }
+        }
+
+        let mut output = Shapefile::new(&output_file, ShapeType::PolyLineM)?;
+        output.projection = input.projection.clone();
+        match id_field {
+            Some(i) => output.attributes.add_field(input.attributes.get_field(i)),
+            None => output
+                .attributes
+                .add_field(&AttributeField::new("ROUTE_ID", FieldDataType::Int, 7u8, 0u8)),
+        }
+        output
+            .attributes
+            .add_field(&AttributeField::new("LENGTH", FieldDataType::Real, 18u8, 6u8));
+        output
+            .attributes
+            .add_field(&AttributeField::new("FROM_M", FieldDataType::Real, 18u8, 6u8));
+        output
+            .attributes
+            .add_field(&AttributeField::new("TO_M", FieldDataType::Real, 18u8, 6u8));
+
+        let num_routes = routes.len();
+        for (r, (route, id)) in routes.into_iter().zip(route_ids).enumerate() {
+            let mut sfg = ShapefileGeometry::new(ShapeType::PolyLineM);
+            for (points, measures) in route.parts.iter().zip(&route.measures) {
+                sfg.add_partm(points, measures);
+            }
+            let from_m = sfg.m_array.first().copied().unwrap_or(f64::NAN);
+            let to_m = sfg.m_array.last().copied().unwrap_or(f64::NAN);
+            output.add_record(sfg);
+            output.attributes.add_record(
+                vec![
+                    id,
+                    FieldData::Real(route.length()),
+                    FieldData::Real(from_m),
+                    FieldData::Real(to_m),
+                ],
+                false,
+            );
+
+            cancel.check()?;
+            if verbose {
+                progress = (100.0_f64 * (r + 1) as f64 / num_routes as f64) as usize;
+                if progress != old_progress {
+                    progress::update_stage("Creating routes", progress);
+                    old_progress = progress;
+                }
+            }
+        }
+
+        if verbose && num_null_ids > 0 {
+            progress::message(format!(
+                "Warning: {} lines with null route identifiers were ignored.",
+                num_null_ids
+            ));
+        }
+
+        if verbose {
+            progress::message("Saving data...")
+        };
+        let _ = match output.write() {
+            Ok(_) => {
+                if verbose {
+                    progress::output_written(&output.file_name)
+                }
+            }
+            Err(e) => return Err(e),
+        };
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+
+        if verbose {
+            progress::message(format!("Elapsed Time: {}", elapsed_time));
+        }
+
+        Ok(())
+    }
+}
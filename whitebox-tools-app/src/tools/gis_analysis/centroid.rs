@@ -9,6 +9,7 @@ NOTES: Will need to add support for vector polygons eventually.
 */
 
 use whitebox_raster::*;
+use whitebox_common::progress;
 use crate::tools::*;
 use std::env;
 use std::f64;
@@ -127,6 +128,7 @@ impl WhiteboxTool for Centroid {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut output_file = String::new();
@@ -170,13 +172,7 @@ impl WhiteboxTool for Centroid {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -192,7 +188,7 @@ impl WhiteboxTool for Centroid {
         }
 
         if verbose {
-            println!("Reading data...")
+            progress::message("Reading data...")
         };
 
         let input = Raster::new(&input_file, "r")?;
@@ -223,10 +219,11 @@ impl WhiteboxTool for Centroid {
                     total_n[a] += 1usize;
                 }
             }
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    progress::update(progress);
                     old_progress = progress;
                 }
             }
@@ -245,12 +242,12 @@ impl WhiteboxTool for Centroid {
         if text_output {
             let mut col: f64;
             let mut row: f64;
-            println!("Patch Centroid\nPatch ID\tColumn\tRow");
+            progress::message("Patch Centroid\nPatch ID\tColumn\tRow");
             for a in 0..range + 1 {
                 if total_n[a] > 0 {
                     col = total_columns[a] as f64 / total_n[a] as f64;
                     row = total_rows[a] as f64 / total_n[a] as f64;
-                    println!("{}\t{}\t{}", (a + min_val), col, row);
+                    progress::message(format!("{}\t{}\t{}", (a + min_val), col, row));
                 }
             }
         }
@@ -264,22 +261,19 @@ impl WhiteboxTool for Centroid {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
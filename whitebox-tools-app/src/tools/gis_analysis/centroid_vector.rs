@@ -6,6 +6,7 @@ Last Modified: 24/07/2020
 License: MIT
 */
 
+use whitebox_common::progress;
 use crate::tools::*;
 use whitebox_vector::*;
 use std::env;
@@ -131,6 +132,7 @@ impl WhiteboxTool for CentroidVector {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file: String = "".to_string();
         let mut output_file: String = "".to_string();
@@ -175,13 +177,7 @@ impl WhiteboxTool for CentroidVector {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         if !input_file.contains(path::MAIN_SEPARATOR) && !input_file.contains("/") {
@@ -215,11 +211,12 @@ impl WhiteboxTool for CentroidVector {
                 x_total += record.points[0].x;
                 y_total += record.points[0].y;
 
+                cancel.check()?;
                 if verbose {
                     progress =
                         (100.0_f64 * (record_num + 1) as f64 / input.num_records as f64) as usize;
                     if progress != old_progress {
-                        println!("Progress: {}%", progress);
+                        progress::update(progress);
                         old_progress = progress;
                     }
                 }
@@ -235,12 +232,12 @@ impl WhiteboxTool for CentroidVector {
                 .add_record(vec![FieldData::Int(1i32)], false);
 
             if verbose {
-                println!("Saving data...")
+                progress::message("Saving data...")
             };
             let _ = match output.write() {
                 Ok(_) => {
                     if verbose {
-                        println!("Output file written")
+                        progress::output_written(&output.file_name)
                     }
                 }
                 Err(e) => return Err(e),
@@ -277,23 +274,24 @@ impl WhiteboxTool for CentroidVector {
                 let atts = input.attributes.get_record(record_num);
                 output.attributes.add_record(atts.clone(), false);
 
+                cancel.check()?;
                 if verbose {
                     progress =
                         (100.0_f64 * (record_num + 1) as f64 / input.num_records as f64) as usize;
                     if progress != old_progress {
-                        println!("Progress: {}%", progress);
+                        progress::update(progress);
                         old_progress = progress;
                     }
                 }
             }
 
             if verbose {
-                println!("Saving data...")
+                progress::message("Saving data...")
             };
             let _ = match output.write() {
                 Ok(_) => {
                     if verbose {
-                        println!("Output file written")
+                        progress::output_written(&output.file_name)
                     }
                 }
                 Err(e) => return Err(e),
@@ -303,7 +301,7 @@ impl WhiteboxTool for CentroidVector {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!("{}", &format!("Elapsed Time: {}", elapsed_time));
+            progress::message(format!("Elapsed Time: {}", elapsed_time));
         }
 
         Ok(())
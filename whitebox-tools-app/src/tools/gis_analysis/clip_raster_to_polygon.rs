@@ -12,6 +12,7 @@ use whitebox_common::algorithms::point_in_poly;
 use whitebox_raster::*;
 use whitebox_common::structures::BoundingBox;
 use whitebox_common::structures::Point2D;
+use whitebox_common::progress;
 use crate::tools::*;
 use whitebox_vector::{ShapeType, Shapefile};
 use std::env;
@@ -59,7 +60,7 @@ impl ClipRasterToPolygon {
 
         parameters.push(ToolParameter {
             name: "Input Vector Polygon File".to_owned(),
-            flags: vec!["--polygons".to_owned()],
+            flags: vec!["--polygon".to_owned(), "--polygons".to_owned()],
             description: "Input vector polygons file.".to_owned(),
             parameter_type: ParameterType::ExistingFile(ParameterFileType::Vector(
                 VectorGeometryType::Polygon,
@@ -144,6 +145,7 @@ impl WhiteboxTool for ClipRasterToPolygon {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut polygons_file = String::new();
@@ -193,13 +195,7 @@ impl WhiteboxTool for ClipRasterToPolygon {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -218,7 +214,7 @@ impl WhiteboxTool for ClipRasterToPolygon {
         }
 
         if verbose {
-            println!("Reading data...")
+            progress::message("Reading data...")
         };
         let input = Raster::new(&input_file, "r")?;
 
@@ -302,18 +298,13 @@ impl WhiteboxTool for ClipRasterToPolygon {
                                     output.set_value(r, c, input.get_value(r, c));
                                 }
                             }
+                            cancel.check()?;
                             if verbose {
                                 progress = (100.0_f64 * (r - starting_row) as f64
                                     / (ending_row - starting_row) as f64)
                                     as usize;
                                 if progress != old_progress {
-                                    println!(
-                                        "Progress (rec {} of {} part {}): {}%",
-                                        record_num + 1,
-                                        num_records,
-                                        part_num,
-                                        progress
-                                    );
+                                    progress::update_stage(&format!("Progress (rec {} of {} part {})", record_num + 1, num_records, part_num), progress);
                                     old_progress = progress;
                                 }
                             }
@@ -366,18 +357,13 @@ impl WhiteboxTool for ClipRasterToPolygon {
                                     output.set_value(r, c, nodata);
                                 }
                             }
+                            cancel.check()?;
                             if verbose {
                                 progress = (100.0_f64 * (r - starting_row) as f64
                                     / (ending_row - starting_row) as f64)
                                     as usize;
                                 if progress != old_progress {
-                                    println!(
-                                        "Progress (rec {} of {} part {}): {}%",
-                                        record_num + 1,
-                                        num_records,
-                                        part_num,
-                                        progress
-                                    );
+                                    progress::update_stage(&format!("Progress (rec {} of {} part {})", record_num + 1, num_records, part_num), progress);
                                     old_progress = progress;
                                 }
                             }
@@ -396,22 +382,19 @@ impl WhiteboxTool for ClipRasterToPolygon {
             output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
             if verbose {
-                println!("Saving data...")
+                progress::message("Saving data...")
             };
             let _ = match output.write() {
                 Ok(_) => {
                     if verbose {
-                        println!("Output file written")
+                        progress::output_written(&output.file_name)
                     }
                 }
                 Err(e) => return Err(e),
             };
 
             if verbose {
-                println!(
-                    "{}",
-                    &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-                );
+                progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
             }
         } else {
             // we'll need to trim the raster to the extent of the polygons.
@@ -526,18 +509,13 @@ impl WhiteboxTool for ClipRasterToPolygon {
                                     output.set_value(r, c, input.get_value(row_in, col_in));
                                 }
                             }
+                            cancel.check()?;
                             if verbose {
                                 progress = (100.0_f64 * (r - starting_row) as f64
                                     / (ending_row - starting_row) as f64)
                                     as usize;
                                 if progress != old_progress {
-                                    println!(
-                                        "Progress (rec {} of {} part {}): {}%",
-                                        record_num + 1,
-                                        num_records,
-                                        part_num,
-                                        progress
-                                    );
+                                    progress::update_stage(&format!("Progress (rec {} of {} part {})", record_num + 1, num_records, part_num), progress);
                                     old_progress = progress;
                                 }
                             }
@@ -590,18 +568,13 @@ impl WhiteboxTool for ClipRasterToPolygon {
                                     output.set_value(r, c, nodata);
                                 }
                             }
+                            cancel.check()?;
                             if verbose {
                                 progress = (100.0_f64 * (r - starting_row) as f64
                                     / (ending_row - starting_row) as f64)
                                     as usize;
                                 if progress != old_progress {
-                                    println!(
-                                        "Progress (rec {} of {} part {}): {}%",
-                                        record_num + 1,
-                                        num_records,
-                                        part_num,
-                                        progress
-                                    );
+                                    progress::update_stage(&format!("Progress (rec {} of {} part {})", record_num + 1, num_records, part_num), progress);
                                     old_progress = progress;
                                 }
                             }
@@ -620,22 +593,19 @@ impl WhiteboxTool for ClipRasterToPolygon {
             output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
             if verbose {
-                println!("Saving data...")
+                progress::message("Saving data...")
             };
             let _ = match output.write() {
                 Ok(_) => {
                     if verbose {
-                        println!("Output file written")
+                        progress::output_written(&output.file_name)
                     }
                 }
                 Err(e) => return Err(e),
             };
 
             if verbose {
-                println!(
-                    "{}",
-                    &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-                );
+                progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
             }
         }
 
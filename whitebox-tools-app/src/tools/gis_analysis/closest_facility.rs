@@ -0,0 +1,433 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: Dr. John Lindsay
+Created: 18/10/2026
+Last Modified: 18/10/2026
+License: MIT
+*/
+
+use whitebox_common::structures::Point2D;
+use whitebox_common::progress;
+use crate::tools::*;
+use whitebox_vector::*;
+use num_cpus;
+use std::env;
+use std::f64;
+use std::io::{Error, ErrorKind};
+use std::path;
+use std::sync::mpsc;
+use std::sync::Arc;
+
+/// This tool finds the facilities (`--facilities`), such as hospitals, that can be reached at
+/// the least cost from each of a set of incidents (`--incidents`), such as accidents, by
+/// travelling along a line network (`--network`). The number of facilities found for each
+/// incident is set by `--num_facilities`, and facilities that cost more than `--max_cost` to
+/// reach are ignored. By default, the costs are those of travel from the incidents to the
+/// facilities; if the `--from_facility` flag is specified, they are the costs of travel from the
+/// facilities to the incidents instead, which differ where there are one-way lines.
+///
+/// The routes are output (`--output`) as polylines, in the direction of travel, with `INCIDENT`
+/// and `FACILITY` fields holding the record numbers of the incident and facility, a `RANK` field
+/// that is 1 for each incident's closest facility, 2 for the next closest, and so on, and `COST`
+/// and `LENGTH` fields. Incidents that cannot reach any facility have no routes.
+///
+/// Incidents and facilities that are not on the network are joined to it at the nearest point on
+/// the nearest line. The network's lines are connected only at their end points, and lines whose
+/// end points are within the `--snap` distance of one another are joined. The cost of travelling
+/// a line is its length, unless a numeric cost field is specified (`--cost_field`), in which case
+/// it is the field's value for the full length of the line. Lines with null or negative costs
+/// cannot be travelled, and travel on one-way lines may be restricted using a one-way field
+/// (`--oneway_field`), as described for the `ShortestPath` tool.
+///
+/// # See Also
+/// `ShortestPath`, `ServiceArea`, `OdCostMatrix`
+pub struct ClosestFacility {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl ClosestFacility {
+    pub fn new() -> ClosestFacility {
+        // public constructor
+        let name = "ClosestFacility".to_string();
+        let toolbox = "GIS Analysis/Network Analysis".to_string();
+        let description =
+            "Finds the least-cost routes between incidents and their closest facilities on a line network."
+                .to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input Network Vector File".to_owned(),
+            flags: vec!["--network".to_owned()],
+            description: "Input vector line network file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Vector(
+                VectorGeometryType::Line,
+            )),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Input Incidents Vector File".to_owned(),
+            flags: vec!["--incidents".to_owned()],
+            description: "Input vector points file of the incidents.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Vector(
+                VectorGeometryType::Point,
+            )),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Input Facilities Vector File".to_owned(),
+            flags: vec!["--facilities".to_owned()],
+            description: "Input vector points file of the facilities.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Vector(
+                VectorGeometryType::Point,
+            )),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output Vector File".to_owned(),
+            flags: vec!["-o".to_owned(), "--output".to_owned()],
+            description: "Output vector lines file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Vector(
+                VectorGeometryType::Line,
+            )),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Number of Facilities".to_owned(),
+            flags: vec!["--num_facilities".to_owned()],
+            description: "Number of closest facilities found for each incident.".to_owned(),
+            parameter_type: ParameterType::Integer,
+            default_value: Some("1".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Maximum Cost (optional)".to_owned(),
+            flags: vec!["--max_cost".to_owned()],
+            description: "Optional maximum cost of the routes.".to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Measure costs of travel from the facilities?".to_owned(),
+            flags: vec!["--from_facility".to_owned()],
+            description: "Optional flag to measure the costs of travel from the facilities to the incidents."
+                .to_owned(),
+            parameter_type: ParameterType::Boolean,
+            default_value: Some("false".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Cost Field (optional)".to_owned(),
+            flags: vec!["--cost_field".to_owned()],
+            description: "Optional numeric field holding the cost of travelling each line; line length is used by default."
+                .to_owned(),
+            parameter_type: ParameterType::VectorAttributeField(
+                AttributeType::Number,
+                "--network".to_string(),
+            ),
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "One-way Field (optional)".to_owned(),
+            flags: vec!["--oneway_field".to_owned()],
+            description: "Optional field restricting the direction of travel on each line.".to_owned(),
+            parameter_type: ParameterType::VectorAttributeField(
+                AttributeType::Any,
+                "--network".to_string(),
+            ),
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Snap Distance".to_owned(),
+            flags: vec!["--snap".to_owned()],
+            description: "Distance within which line end points are joined into a network node."
+                .to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: Some("0.0".to_owned()),
+            optional: true,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut parent = env::current_exe().unwrap();
+        parent.pop();
+        let p = format!("{}", parent.display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(
+            ">>.*{0} -r={1} -v --wd=\"*path*to*data*\" --network=roads.shp --incidents=accidents.shp --facilities=hospitals.shp -o=routes.shp --num_facilities=2 --cost_field=MINUTES",
+            short_exe, name
+        ).replace("*", &sep);
+
+        ClosestFacility {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for ClosestFacility {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        match serde_json::to_string(&self.parameters) {
+            Ok(json_str) => return format!("{{\"parameters\":{}}}", json_str),
+            Err(err) => return format!("{:?}", err),
+        }
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+        cancel: &CancellationToken,
+    ) -> Result<(), Error> {
+        let args = ParsedArgs::parse(&self.get_tool_parameters(), &args, working_directory)?;
+        self.run_with_parsed_args(args, working_directory, verbose, cancel)
+    }
+
+    fn run_with_parsed_args<'a>(
+        &self,
+        args: ParsedArgs,
+        _working_directory: &'a str,
+        verbose: bool,
+        cancel: &CancellationToken,
+    ) -> Result<(), Error> {
+        let network_file = args.get_file("network").unwrap();
+        let incidents_file = args.get_file("incidents").unwrap();
+        let facilities_file = args.get_file("facilities").unwrap();
+        let output_file = args.get_file("output").unwrap();
+        let num_closest = args.get_integer("num_facilities").unwrap_or(1);
+        let max_cost = args.get_float("max_cost").unwrap_or(f64::INFINITY);
+        let from_facility = args.get_bool("from_facility");
+        let options = NetworkOptions {
+            cost_field: args.get_string("cost_field").filter(|s| !s.is_empty()),
+            oneway_field: args.get_string("oneway_field").filter(|s| !s.is_empty()),
+            snap_distance: args.get_float("snap").unwrap_or(0f64),
+        };
+
+        if num_closest < 1 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The number of facilities (--num_facilities) must be at least 1.",
+            ));
+        }
+        let num_closest = num_closest as usize;
+        if max_cost < 0f64 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The maximum cost (--max_cost) must not be negative.",
+            ));
+        }
+
+        let mut progress: usize;
+        let mut old_progress: usize = 1;
+
+        let start = Instant::now();
+
+        if verbose {
+            let tool_name = self.get_tool_name();
+            progress::welcome(&tool_name);
+        }
+
+        if verbose {
+            progress::message("Reading data...")
+        };
+
+        let network = Shapefile::read(&network_file)?;
+        let incidents = Shapefile::read(&incidents_file)?;
+        let facilities = Shapefile::read(&facilities_file)?;
+
+        if verbose {
+            progress::message("Building network...")
+        };
+        let graph = NetworkGraph::from_shapefile(&network, &options)?;
+        let incident_locations = graph.locate_points(&incidents)?;
+        let facility_locations = graph.locate_points(&facilities)?;
+
+        let graph = Arc::new(graph);
+        let incident_locations = Arc::new(incident_locations);
+        let facility_locations = Arc::new(facility_locations);
+        let num_incidents = incidents.num_records;
+        let mut num_procs = num_cpus::get() as isize;
+        let configs = whitebox_common::configs::get_configs()?;
+        let max_procs = configs.max_procs;
+        if max_procs > 0 && max_procs < num_procs {
+            num_procs = max_procs;
+        }
+        let (tx, rx) = mpsc::channel();
+        for tid in 0..num_procs {
+            let graph = graph.clone();
+            let incident_locations = incident_locations.clone();
+            let facility_locations = facility_locations.clone();
+            let tx = tx.clone();
+            progress::spawn(move || {
+                for incident in (0..num_incidents).filter(|i| *i as isize % num_procs == tid) {
+                    let mut routes: Vec<(usize, f64, Vec<Point2D>)> = vec![];
+                    if let Some(location) = &incident_locations[incident] {
+                        // A reverse tree measures the costs of travel towards the incident.
+                        let tree = graph.shortest_path_tree(location, max_cost, from_facility);
+                        let mut costs: Vec<(usize, f64)> = facility_locations
+                            .iter()
+                            .enumerate()
+                            .filter_map(|(facility, l)| {
+                                l.as_ref().map(|l| (facility, graph.cost_to(&tree, l)))
+                            })
+                            .filter(|(_, cost)| cost.is_finite())
+                            .collect();
+                        costs.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap().then(a.0.cmp(&b.0)));
+                        for &(facility, _) in costs.iter().take(num_closest) {
+                            let l = facility_locations[facility].as_ref().unwrap();
+                            if let Some((cost, points)) = graph.path_to(&tree, l) {
+                                routes.push((facility, cost, points));
+                            }
+                        }
+                    }
+                    if tx.send((incident, routes)).is_err() {
+                        return;
+                    }
+                }
+            });
+        }
+        drop(tx);
+
+        let mut incident_routes = vec![vec![]; num_incidents];
+        for i in 0..num_incidents {
+            let (incident, routes) = cancel.recv(&rx)?;
+            incident_routes[incident] = routes;
+            if verbose {
+                progress = (100.0_f64 * (i + 1) as f64 / num_incidents as f64) as usize;
+                if progress != old_progress {
+                    progress::update_stage("Finding closest facilities", progress);
+                    old_progress = progress;
+                }
+            }
+        }
+
+        let mut output = Shapefile::new(&output_file, ShapeType::PolyLine)?;
+        output.projection = network.projection.clone();
+        output
+            .attributes
+            .add_field(&AttributeField::new("FID", FieldDataType::Int, 7u8, 0u8));
+        output
+            .attributes
+            .add_field(&AttributeField::new("INCIDENT", FieldDataType::Int, 7u8, 0u8));
+        output
+            .attributes
+            .add_field(&AttributeField::new("FACILITY", FieldDataType::Int, 7u8, 0u8));
+        output
+            .attributes
+            .add_field(&AttributeField::new("RANK", FieldDataType::Int, 4u8, 0u8));
+        output
+            .attributes
+            .add_field(&AttributeField::new("COST", FieldDataType::Real, 18u8, 6u8));
+        output
+            .attributes
+            .add_field(&AttributeField::new("LENGTH", FieldDataType::Real, 18u8, 6u8));
+
+        let mut num_unreached = 0;
+        for (incident, routes) in incident_routes.into_iter().enumerate() {
+            if routes.is_empty() {
+                num_unreached += 1;
+            }
+            for (rank, (facility, cost, points)) in routes.into_iter().enumerate() {
+                let length: f64 = (1..points.len())
+                    .map(|k| points[k - 1].distance(&points[k]))
+                    .sum();
+                // A route between two points at the same location is a single point.
+                let mut sfg = ShapefileGeometry::new(ShapeType::PolyLine);
+                if points.len() == 1 {
+                    sfg.add_part(&[points[0], points[0]]);
+                } else {
+                    sfg.add_part(&points);
+                }
+                output.add_record(sfg);
+                output.attributes.add_record(
+                    vec![
+                        FieldData::Int(output.num_records as i32),
+                        FieldData::Int(incident as i32 + 1),
+                        FieldData::Int(facility as i32 + 1),
+                        FieldData::Int(rank as i32 + 1),
+                        FieldData::Real(cost),
+                        FieldData::Real(length),
+                    ],
+                    false,
+                );
+            }
+        }
+
+        if verbose && num_unreached > 0 {
+            progress::message(format!(
+                "Warning: {} incidents could not reach a facility.",
+                num_unreached
+            ));
+        }
+
+        if verbose {
+            progress::message("Saving data...")
+        };
+        let _ = match output.write() {
+            Ok(_) => {
+                if verbose {
+                    progress::output_written(&output.file_name)
+                }
+            }
+            Err(e) => return Err(e),
+        };
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+
+        if verbose {
+            progress::message(format!("Elapsed Time: {}", elapsed_time));
+        }
+
+        Ok(())
+    }
+}
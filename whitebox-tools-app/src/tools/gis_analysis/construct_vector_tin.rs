@@ -10,6 +10,7 @@ use self::na::Vector3;
 use whitebox_common::algorithms::triangulate;
 use crate::na;
 use whitebox_common::structures::Point2D;
+use whitebox_common::progress;
 use crate::tools::*;
 use whitebox_vector::ShapefileGeometry;
 use whitebox_vector::*;
@@ -172,6 +173,7 @@ impl WhiteboxTool for ConstructVectorTIN {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file: String = "".to_string();
         let mut field_name = String::new();
@@ -245,13 +247,7 @@ impl WhiteboxTool for ConstructVectorTIN {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         if !input_file.contains(path::MAIN_SEPARATOR) && !input_file.contains("/") {
@@ -359,18 +355,19 @@ impl WhiteboxTool for ConstructVectorTIN {
                 }
             }
 
+            cancel.check()?;
             if verbose {
                 progress =
                     (100.0_f64 * (record_num + 1) as f64 / input.num_records as f64) as usize;
                 if progress != old_progress {
-                    println!("Reading points: {}%", progress);
+                    progress::update_stage("Reading points", progress);
                     old_progress = progress;
                 }
             }
         }
 
         if verbose {
-            println!("Performing triangulation...");
+            progress::message("Performing triangulation...");
         }
         // this is where the heavy-lifting is
         let result = triangulate(&points).expect("No triangulation exists.");
@@ -455,22 +452,23 @@ impl WhiteboxTool for ConstructVectorTIN {
                 rec_num += 1i32;
             }
 
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * i as f64 / (result.triangles.len() - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Creating polygons: {}%", progress);
+                    progress::update_stage("Creating polygons", progress);
                     old_progress = progress;
                 }
             }
         }
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
@@ -479,7 +477,7 @@ impl WhiteboxTool for ConstructVectorTIN {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!("{}", &format!("Elapsed Time: {}", elapsed_time));
+            progress::message(format!("Elapsed Time: {}", elapsed_time));
         }
 
         Ok(())
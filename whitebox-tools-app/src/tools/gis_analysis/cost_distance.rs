@@ -10,6 +10,7 @@ NOTES: Add anisotropy option.
 
 use whitebox_raster::*;
 use whitebox_common::structures::Array2D;
+use whitebox_common::progress;
 use crate::tools::*;
 use std::cmp::Ordering;
 use std::collections::BinaryHeap;
@@ -160,6 +161,7 @@ impl WhiteboxTool for CostDistance {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut source_file = String::new();
         let mut cost_file = String::new();
@@ -211,13 +213,7 @@ impl WhiteboxTool for CostDistance {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -239,12 +235,12 @@ impl WhiteboxTool for CostDistance {
         }
 
         if verbose {
-            println!("Reading source data...")
+            progress::message("Reading source data...")
         };
         let source = Raster::new(&source_file, "r")?;
 
         if verbose {
-            println!("Reading cost data...")
+            progress::message("Reading cost data...")
         };
         let cost = Raster::new(&cost_file, "r")?;
 
@@ -290,10 +286,11 @@ impl WhiteboxTool for CostDistance {
                     solved_cells += 1;
                 }
             }
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Initializing: {}%", progress);
+                    progress::update_stage("Initializing", progress);
                     old_progress = progress;
                 }
             }
@@ -352,7 +349,7 @@ impl WhiteboxTool for CostDistance {
                 if verbose {
                     progress = (100.0_f64 * solved_cells as f64 / (num_cells - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Progress: {}%", progress);
+                        progress::update(progress);
                         old_progress = progress;
                     }
                 }
@@ -405,10 +402,11 @@ impl WhiteboxTool for CostDistance {
                         }
                     }
                 }
+                cancel.check()?;
                 if verbose {
                     progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Loop {}: {}%", loop_num, progress);
+                        progress::update_stage(&format!("Loop {}", loop_num), progress);
                         old_progress = progress;
                     }
                 }
@@ -438,10 +436,11 @@ impl WhiteboxTool for CostDistance {
                         }
                     }
                 }
+                cancel.check()?;
                 if verbose {
                     progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Loop {}: {}%", loop_num, progress);
+                        progress::update_stage(&format!("Loop {}", loop_num), progress);
                         old_progress = progress;
                     }
                 }
@@ -471,10 +470,11 @@ impl WhiteboxTool for CostDistance {
                         }
                     }
                 }
+                cancel.check()?;
                 if verbose {
                     progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Loop {}: {}%", loop_num, progress);
+                        progress::update_stage(&format!("Loop {}", loop_num), progress);
                         old_progress = progress;
                     }
                 }
@@ -504,10 +504,11 @@ impl WhiteboxTool for CostDistance {
                         }
                     }
                 }
+                cancel.check()?;
                 if verbose {
                     progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Loop {}: {}%", loop_num, progress);
+                        progress::update_stage(&format!("Loop {}", loop_num), progress);
                         old_progress = progress;
                     }
                 }
@@ -539,10 +540,11 @@ impl WhiteboxTool for CostDistance {
                         }
                     }
                 }
+                cancel.check()?;
                 if verbose {
                     progress = (100.0_f64 * col as f64 / (columns - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Loop {}: {}%", loop_num, progress);
+                        progress::update_stage(&format!("Loop {}", loop_num), progress);
                         old_progress = progress;
                     }
                 }
@@ -572,10 +574,11 @@ impl WhiteboxTool for CostDistance {
                         }
                     }
                 }
+                cancel.check()?;
                 if verbose {
                     progress = (100.0_f64 * col as f64 / (columns - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Loop {}: {}%", loop_num, progress);
+                        progress::update_stage(&format!("Loop {}", loop_num), progress);
                         old_progress = progress;
                     }
                 }
@@ -605,10 +608,11 @@ impl WhiteboxTool for CostDistance {
                         }
                     }
                 }
+                cancel.check()?;
                 if verbose {
                     progress = (100.0_f64 * col as f64 / (columns - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Loop {}: {}%", loop_num, progress);
+                        progress::update_stage(&format!("Loop {}", loop_num), progress);
                         old_progress = progress;
                     }
                 }
@@ -638,10 +642,11 @@ impl WhiteboxTool for CostDistance {
                         }
                     }
                 }
+                cancel.check()?;
                 if verbose {
                     progress = (100.0_f64 * col as f64 / (columns - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Loop {}: {}%", loop_num, progress);
+                        progress::update_stage(&format!("Loop {}", loop_num), progress);
                         old_progress = progress;
                     }
                 }
@@ -661,12 +666,12 @@ impl WhiteboxTool for CostDistance {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
@@ -684,17 +689,14 @@ impl WhiteboxTool for CostDistance {
         let _ = match backlink.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&backlink.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
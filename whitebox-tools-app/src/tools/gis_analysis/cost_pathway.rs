@@ -7,6 +7,7 @@ License: MIT
 */
 
 use whitebox_raster::*;
+use whitebox_common::progress;
 use crate::tools::*;
 use std::env;
 use std::f64;
@@ -74,7 +75,7 @@ impl CostPathway {
 
         parameters.push(ToolParameter {
             name: "Treat zero values as background?".to_owned(),
-            flags: vec!["--zero_background".to_owned()],
+            flags: vec!["--esri_style".to_owned(), "--zero_background".to_owned()],
             description: "Flag indicating whether zero values should be treated as a background."
                 .to_owned(),
             parameter_type: ParameterType::Boolean,
@@ -140,6 +141,7 @@ impl WhiteboxTool for CostPathway {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut destination_file = String::new();
         let mut backlink_file = String::new();
@@ -192,13 +194,7 @@ impl WhiteboxTool for CostPathway {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -217,12 +213,12 @@ impl WhiteboxTool for CostPathway {
         }
 
         if verbose {
-            println!("Reading destination data...")
+            progress::message("Reading destination data...")
         };
         let destination = Raster::new(&destination_file, "r")?;
 
         if verbose {
-            println!("Reading backlink data...")
+            progress::message("Reading backlink data...")
         };
         let backlink = Raster::new(&backlink_file, "r")?;
 
@@ -290,10 +286,11 @@ impl WhiteboxTool for CostPathway {
                     output[(row, col)] = nodata;
                 }
             }
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    progress::update(progress);
                     old_progress = progress;
                 }
             }
@@ -312,22 +309,19 @@ impl WhiteboxTool for CostPathway {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -7,6 +7,7 @@ License: MIT
 */
 
 use whitebox_raster::*;
+use whitebox_common::progress;
 use crate::tools::*;
 use std::env;
 use std::f64;
@@ -124,6 +125,7 @@ impl WhiteboxTool for CountIf {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_files = String::new();
         let mut output_file = String::new();
@@ -174,13 +176,7 @@ impl WhiteboxTool for CountIf {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -225,7 +221,7 @@ impl WhiteboxTool for CountIf {
         for value in vec {
             if !value.trim().is_empty() {
                 if verbose {
-                    println!("Reading data...")
+                    progress::message("Reading data...")
                 };
 
                 let mut input_file = value.trim().to_owned();
@@ -261,10 +257,11 @@ impl WhiteboxTool for CountIf {
                             }
                         }
                     }
+                    cancel.check()?;
                     if verbose {
                         progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                         if progress != old_progress {
-                            println!("Progress (loop {} of {}): {}%", i, num_files, progress);
+                            progress::update_stage(&format!("Progress (loop {} of {})", i, num_files), progress);
                             old_progress = progress;
                         }
                     }
@@ -281,22 +278,19 @@ impl WhiteboxTool for CountIf {
         output.add_metadata_entry(format!("Elapsed Time (including I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (including I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (including I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -7,6 +7,7 @@ License: MIT
 */
 
 use whitebox_raster::*;
+use whitebox_common::progress;
 use crate::tools::*;
 use num_cpus;
 use std::env;
@@ -14,7 +15,6 @@ use std::f64;
 use std::io::{Error, ErrorKind};
 use std::path;
 use std::sync::mpsc;
-use std::thread;
 
 /// This tool can be used to create a new raster with values that are determined by the equation of a simple plane. The user
 /// must specify the name of a base raster (`--base`) from which the output raster coordinate and dimensional information
@@ -147,6 +147,7 @@ impl WhiteboxTool for CreatePlane {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut base_file = String::new();
         let mut output_file = String::new();
@@ -182,7 +183,7 @@ impl WhiteboxTool for CreatePlane {
                 } else {
                     output_file = args[i + 1].to_string();
                 }
-            } else if flag_val == "-slope" {
+            } else if flag_val == "-gradient" {
                 if keyval {
                     slope = vec[1]
                         .to_string()
@@ -223,13 +224,7 @@ impl WhiteboxTool for CreatePlane {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -295,7 +290,7 @@ impl WhiteboxTool for CreatePlane {
         let (tx, rx) = mpsc::channel();
         for tid in 0..num_procs {
             let tx = tx.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let (mut x, mut y): (f64, f64);
                 for row in (0..rows).filter(|r| r % num_procs == tid) {
                     let mut data = vec![nodata; columns as usize];
@@ -305,18 +300,20 @@ impl WhiteboxTool for CreatePlane {
                         data[col as usize] =
                             slope * aspect.sin() * x + slope * aspect.cos() * y + constant_val;
                     }
-                    tx.send((row, data)).unwrap();
+                    if tx.send((row, data)).is_err() {
+                        return;
+                    }
                 }
             });
         }
 
         for row in 0..rows {
-            let data = rx.recv().expect("Error receiving data from thread.");
+            let data = cancel.recv(&rx)?;
             output.set_row_data(data.0, data.1);
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as i32;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    progress::update(progress);
                     old_progress = progress;
                 }
             }
@@ -334,22 +331,19 @@ impl WhiteboxTool for CreatePlane {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -9,6 +9,7 @@ License: MIT
 use whitebox_lidar::*;
 use whitebox_raster::*;
 use whitebox_common::structures::{BoundingBox, Point2D};
+use whitebox_common::progress;
 use crate::tools::*;
 use whitebox_vector::ShapefileGeometry;
 use whitebox_vector::*;
@@ -166,6 +167,7 @@ impl WhiteboxTool for CreateRectangularVectorGrid {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file: String = "".to_string();
         let mut output_file: String = "".to_string();
@@ -262,13 +264,7 @@ impl WhiteboxTool for CreateRectangularVectorGrid {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         if width <= 0f64 || height <= 0f64 {
@@ -442,22 +438,23 @@ impl WhiteboxTool for CreateRectangularVectorGrid {
             }
 
             r += 1f64;
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * r / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    progress::update(progress);
                     old_progress = progress;
                 }
             }
         }
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
@@ -466,7 +463,7 @@ impl WhiteboxTool for CreateRectangularVectorGrid {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!("{}", &format!("Elapsed Time: {}", elapsed_time));
+            progress::message(format!("Elapsed Time: {}", elapsed_time));
         }
 
         Ok(())
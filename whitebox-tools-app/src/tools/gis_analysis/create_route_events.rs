@@ -0,0 +1,463 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: Dr. John Lindsay
+Created: 18/10/2026
+Last Modified: 18/10/2026
+License: MIT
+*/
+
+use whitebox_common::progress;
+use crate::tools::*;
+use whitebox_vector::*;
+use std::collections::HashMap;
+use std::env;
+use std::f64;
+use std::fs;
+use std::io::{Error, ErrorKind};
+use std::path;
+
+/// This tool creates point or line features from a table of events that are located along a
+/// set of measured routes (`--routes`) by route identifier and measure, such as the locations
+/// of accidents or the extents of pavement conditions along a road network. The routes, which
+/// may be created by the `CalibrateRoutes` tool, are identified by the `--route_id` field. The
+/// event table (`--events`) is a CSV file, with a header row, in which the `--event_route_field`
+/// column identifies the route of each event and the `--measure_field` column holds its
+/// measure. Route identifiers are matched by value, so that, for example, an event with a route
+/// identifier of `5` is placed on the route with an identifier of `5.0`.
+///
+/// If a `--to_measure_field` is specified, the events are line events, which extend from the
+/// `--measure_field` measure to the `--to_measure_field` measure, and the output (`--output`) is
+/// a measured polyline file. Otherwise, the events are point events and the output is a
+/// measured points file. A line event along a route with gaps, or one that is crossed more than
+/// once by the event's measure range, has one part for each of its sections. Events may also be
+/// displaced from their routes by the values of an `--offset_field`, which gives the distance to
+/// the right of the route, relative to the direction in which it is digitized, or to the left if
+/// negative. The output contains all of the columns of the event table. Events whose routes are
+/// not found, or whose measures are not on their routes, are not output.
+///
+/// # See Also
+/// `CalibrateRoutes`, `LocatePointsAlongRoutes`
+pub struct CreateRouteEvents {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl CreateRouteEvents {
+    pub fn new() -> CreateRouteEvents {
+        // public constructor
+        let name = "CreateRouteEvents".to_string();
+        let toolbox = "GIS Analysis/Linear Referencing".to_string();
+        let description =
+            "Creates point or line features from a table of events located by route and measure."
+                .to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input Routes File".to_owned(),
+            flags: vec!["--routes".to_owned()],
+            description: "Input vector routes file, containing measured polylines.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Vector(
+                VectorGeometryType::Line,
+            )),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Route Identifier Field".to_owned(),
+            flags: vec!["--route_id".to_owned()],
+            description: "Field identifying each route.".to_owned(),
+            parameter_type: ParameterType::VectorAttributeField(
+                AttributeType::Any,
+                "--routes".to_string(),
+            ),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Input Event Table (CSV)".to_owned(),
+            flags: vec!["--events".to_owned()],
+            description: "Input CSV file of route events.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Csv),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Event Route Identifier Column".to_owned(),
+            flags: vec!["--event_route_field".to_owned()],
+            description: "Name of the event table column identifying the route of each event."
+                .to_owned(),
+            parameter_type: ParameterType::String,
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Measure Column".to_owned(),
+            flags: vec!["--measure_field".to_owned()],
+            description: "Name of the event table column holding the measure, or from-measure, of each event."
+                .to_owned(),
+            parameter_type: ParameterType::String,
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "To-Measure Column (optional)".to_owned(),
+            flags: vec!["--to_measure_field".to_owned()],
+            description: "Optional name of the event table column holding the to-measure of each line event."
+                .to_owned(),
+            parameter_type: ParameterType::String,
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Offset Column (optional)".to_owned(),
+            flags: vec!["--offset_field".to_owned()],
+            description: "Optional name of the event table column holding the offset of each event from its route."
+                .to_owned(),
+            parameter_type: ParameterType::String,
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output Vector File".to_owned(),
+            flags: vec!["-o".to_owned(), "--output".to_owned()],
+            description: "Output vector file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Vector(
+                VectorGeometryType::Any,
+            )),
+            default_value: None,
+            optional: false,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut parent = env::current_exe().unwrap();
+        parent.pop();
+        let p = format!("{}", parent.display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(
+            ">>.*{0} -r={1} -v --wd=\"*path*to*data*\" --routes=routes.shp --route_id=ROAD_NUM --events=pavement.csv --event_route_field=ROAD --measure_field=FROM_KM --to_measure_field=TO_KM -o=pavement.shp",
+            short_exe, name
+        ).replace("*", &sep);
+
+        CreateRouteEvents {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for CreateRouteEvents {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        match serde_json::to_string(&self.parameters) {
+            Ok(json_str) => return format!("{{\"parameters\":{}}}", json_str),
+            Err(err) => return format!("{:?}", err),
+        }
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+        cancel: &CancellationToken,
+    ) -> Result<(), Error> {
+        let args = ParsedArgs::parse(&self.get_tool_parameters(), &args, working_directory)?;
+        self.run_with_parsed_args(args, working_directory, verbose, cancel)
+    }
+
+    fn run_with_parsed_args<'a>(
+        &self,
+        args: ParsedArgs,
+        _working_directory: &'a str,
+        verbose: bool,
+        cancel: &CancellationToken,
+    ) -> Result<(), Error> {
+        let routes_file = args.get_file("routes").unwrap();
+        let route_id_field = args.get_string("route_id").unwrap();
+        let events_file = args.get_file("events").unwrap();
+        let event_route_field = args.get_string("event_route_field").unwrap();
+        let measure_field = args.get_string("measure_field").unwrap();
+        let to_measure_field = args.get_string("to_measure_field").filter(|s| !s.is_empty());
+        let offset_field = args.get_string("offset_field").filter(|s| !s.is_empty());
+        let output_file = args.get_file("output").unwrap();
+
+        let mut progress: usize;
+        let mut old_progress: usize = 1;
+
+        let start = Instant::now();
+
+        if verbose {
+            let tool_name = self.get_tool_name();
+            progress::welcome(&tool_name);
+        }
+
+        if verbose {
+            progress::message("Reading data...")
+        };
+
+        let routes_shapefile = Shapefile::read(&routes_file)?;
+        let routes = read_routes(&routes_shapefile, &route_id_field)?;
+        let mut route_index: HashMap<String, Vec<usize>> = HashMap::new();
+        for (r, (id, _)) in routes.iter().enumerate() {
+            if let Some(key) = route_key(id) {
+                route_index.entry(key).or_insert_with(Vec::new).push(r);
+            }
+        }
+
+        let (headers, rows) = read_csv(&events_file)?;
+        let column = |name: &str| -> Result<usize, Error> {
+            match headers.iter().position(|h| h == name) {
+                Some(i) => Ok(i),
+                None => Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    format!("The column '{}' does not exist in the event table.", name),
+                )),
+            }
+        };
+        let route_column = column(&event_route_field)?;
+        let measure_column = column(&measure_field)?;
+        let to_measure_column = match &to_measure_field {
+            Some(name) => Some(column(name)?),
+            None => None,
+        };
+        let offset_column = match &offset_field {
+            Some(name) => Some(column(name)?),
+            None => None,
+        };
+
+        // Infer the type of each column from its non-empty values.
+        let num_columns = headers.len();
+        let mut field_types = vec![FieldDataType::Int; num_columns];
+        let mut field_lengths = vec![1u8; num_columns];
+        let mut field_precision = vec![0u8; num_columns];
+        for row in &rows {
+            for a in 0..num_columns {
+                let value = row[a].as_str();
+                if value.is_empty() {
+                    continue;
+                }
+                field_lengths[a] = field_lengths[a].max(value.len().min(254) as u8);
+                if field_types[a] == FieldDataType::Int && value.parse::<i32>().is_err() {
+                    field_types[a] = FieldDataType::Real;
+                }
+                if field_types[a] == FieldDataType::Real {
+                    if value.parse::<f64>().is_ok() {
+                        field_precision[a] = field_precision[a].max(get_precision(value));
+                    } else {
+                        field_types[a] = FieldDataType::Text;
+                    }
+                }
+            }
+        }
+        for a in 0..num_columns {
+            if field_types[a] == FieldDataType::Real {
+                field_lengths[a] = field_lengths[a].max(18u8);
+                field_precision[a] = field_precision[a].max(1u8);
+            }
+        }
+
+        let shape_type = if to_measure_column.is_some() {
+            ShapeType::PolyLineM
+        } else {
+            ShapeType::PointM
+        };
+        let mut output = Shapefile::new(&output_file, shape_type)?;
+        output.projection = routes_shapefile.projection.clone();
+        for a in 0..num_columns {
+            output.attributes.add_field(&AttributeField::new(
+                &headers[a],
+                field_types[a].clone(),
+                field_lengths[a],
+                field_precision[a],
+            ));
+        }
+
+        let number = |value: &str| -> Option<f64> { value.parse::<f64>().ok() };
+        let mut num_unlocated = 0;
+        for (row_num, row) in rows.iter().enumerate() {
+            let key = route_key(&FieldData::Text(row[route_column].clone()));
+            let candidates: &[usize] = match key.and_then(|key| route_index.get(&key)) {
+                Some(candidates) => candidates,
+                None => &[],
+            };
+            let offset = match offset_column {
+                Some(a) => number(&row[a]).unwrap_or(0f64),
+                None => 0f64,
+            };
+            let mut sfg = ShapefileGeometry::new(shape_type);
+            if let Some(measure) = number(&row[measure_column]) {
+                match to_measure_column {
+                    Some(a) => {
+                        if let Some(to_measure) = number(&row[a]) {
+                            for r in candidates {
+                                for (points, measures) in routes[*r].1.section(measure, to_measure, offset) {
+                                    sfg.add_partm(&points, &measures);
+                                }
+                            }
+                        }
+                    }
+                    None => {
+                        for r in candidates {
+                            if let Some(p) = routes[*r].1.point_at(measure, offset) {
+                                sfg.add_pointm(p, measure);
+                                break;
+                            }
+                        }
+                    }
+                }
+            }
+
+            if sfg.num_points > 0 {
+                output.add_record(sfg);
+                let atts = (0..num_columns)
+                    .map(|a| parse_value(&row[a], &field_types[a]))
+                    .collect();
+                output.attributes.add_record(atts, false);
+            } else {
+                num_unlocated += 1;
+            }
+
+            cancel.check()?;
+            if verbose {
+                progress = (100.0_f64 * (row_num + 1) as f64 / rows.len() as f64) as usize;
+                if progress != old_progress {
+                    progress::update_stage("Creating events", progress);
+                    old_progress = progress;
+                }
+            }
+        }
+
+        if verbose && num_unlocated > 0 {
+            progress::message(format!(
+                "Warning: {} events could not be located on their routes.",
+                num_unlocated
+            ));
+        }
+
+        if verbose {
+            progress::message("Saving data...")
+        };
+        let _ = match output.write() {
+            Ok(_) => {
+                if verbose {
+                    progress::output_written(&output.file_name)
+                }
+            }
+            Err(e) => return Err(e),
+        };
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+
+        if verbose {
+            progress::message(format!("Elapsed Time: {}", elapsed_time));
+        }
+
+        Ok(())
+    }
+}
+
+/// Reads the header and rows of a CSV file, which may be delimited by commas, semicolons or
+/// tabs. Values are trimmed and stripped of surrounding quotes.
+fn read_csv(file_name: &str) -> Result<(Vec<String>, Vec<Vec<String>>), Error> {
+    let contents = match fs::read_to_string(file_name) {
+        Ok(v) => v,
+        Err(_) => {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Error opening the CSV file.",
+            ));
+        }
+    };
+    let mut lines = contents.lines().filter(|line| !line.trim().is_empty());
+    let header = match lines.next() {
+        Some(line) => line,
+        None => {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The event table does not contain a header row.",
+            ));
+        }
+    };
+    let delimiter = [',', ';', '\t']
+        .iter()
+        .copied()
+        .find(|d| header.contains(*d))
+        .unwrap_or(',');
+    let split = |line: &str| -> Vec<String> {
+        line.split(delimiter)
+            .map(|v| v.trim().trim_matches('"').trim().to_string())
+            .collect()
+    };
+    let headers = split(header);
+    let mut rows = vec![];
+    for line in lines {
+        let row = split(line);
+        if row.len() != headers.len() {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Not all records in the CSV file are the same length. Cannot read the table.",
+            ));
+        }
+        rows.push(row);
+    }
+    Ok((headers, rows))
+}
+
+fn parse_value(value: &str, field_type: &FieldDataType) -> FieldData {
+    if value.is_empty() {
+        return FieldData::Null;
+    }
+    match field_type {
+        FieldDataType::Int => FieldData::Int(value.parse::<i32>().unwrap()),
+        FieldDataType::Real => FieldData::Real(value.parse::<f64>().unwrap()),
+        _ => FieldData::Text(value.to_string()),
+    }
+}
+
+fn get_precision(s: &str) -> u8 {
+    let dec_pos = match s.chars().position(|c| c == '.') {
+        Some(p) => p,
+        None => return 0u8,
+    };
+    (s.len() - dec_pos - 1) as u8
+}
@@ -9,6 +9,7 @@ License: MIT
 use crate::tools::*;
 use whitebox_common::algorithms::{minimum_bounding_box, MinimizationCriterion};
 use whitebox_common::structures::Point2D;
+use whitebox_common::progress;
 use whitebox_vector::*;
 use std::env;
 use std::f64;
@@ -131,6 +132,7 @@ impl WhiteboxTool for DeviationFromRegionalDirection {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut elongation_threshold = 0.75;
@@ -177,13 +179,7 @@ impl WhiteboxTool for DeviationFromRegionalDirection {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -196,7 +192,7 @@ impl WhiteboxTool for DeviationFromRegionalDirection {
         if elongation_threshold > 0.95 { elongation_threshold = 0.95; }
 
         if verbose {
-            println!("Reading data...")
+            progress::message("Reading data...")
         };
 
         let input = Shapefile::read(&input_file)?;
@@ -325,11 +321,12 @@ impl WhiteboxTool for DeviationFromRegionalDirection {
             // sum_sin += slope_rma.atan().sin() * weight;
             // sum_cos += slope_rma.atan().cos() * weight;
 
+            cancel.check()?;
             if verbose {
                 progress =
                     (100.0_f64 * (record_num + 1) as f64 / input.num_records as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    progress::update(progress);
                     old_progress = progress;
                 }
             }
@@ -348,7 +345,7 @@ impl WhiteboxTool for DeviationFromRegionalDirection {
         if regional_angle < 0.0 { regional_angle = 180.0 + regional_angle; }
 
         if verbose {
-            println!("Regional weighted mean polygon direction: {:.3} degrees", regional_angle);
+            progress::message(format!("Regional weighted mean polygon direction: {:.3} degrees", regional_angle));
         }
 
 
@@ -411,23 +408,24 @@ impl WhiteboxTool for DeviationFromRegionalDirection {
             atts.push(FieldData::Real(deviation_angle));
             output.attributes.add_record(atts, false);
 
+            cancel.check()?;
             if verbose {
                 progress =
                     (100.0_f64 * (record_num + 1) as f64 / input.num_records as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    progress::update(progress);
                     old_progress = progress;
                 }
             }
         }
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
@@ -436,7 +434,7 @@ impl WhiteboxTool for DeviationFromRegionalDirection {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!("{}", &format!("Elapsed Time: {}", elapsed_time));
+            progress::message(format!("Elapsed Time: {}", elapsed_time));
         }
 
         Ok(())
@@ -7,6 +7,7 @@ License: MIT
 */
 
 use whitebox_raster::*;
+use whitebox_common::progress;
 use crate::tools::*;
 use num_cpus;
 use std::env;
@@ -15,7 +16,6 @@ use std::io::{Error, ErrorKind};
 use std::path;
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::thread;
 
 /// This tool will measure the edge proportion, i.e. the proportion of grid cells in a patch that are located along the
 /// patch's boundary, for an input raster image (`--input`). Edge proportion is an indicator of polygon shape complexity
@@ -135,6 +135,7 @@ impl WhiteboxTool for EdgeProportion {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut output_file = String::new();
@@ -178,13 +179,7 @@ impl WhiteboxTool for EdgeProportion {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -200,7 +195,7 @@ impl WhiteboxTool for EdgeProportion {
         }
 
         if verbose {
-            println!("Reading input data...");
+            progress::message("Reading input data...");
         }
         let input = Arc::new(Raster::new(&input_file, "r")?);
         let rows = input.configs.rows as isize;
@@ -220,7 +215,7 @@ impl WhiteboxTool for EdgeProportion {
         for tid in 0..num_procs {
             let input = input.clone();
             let tx = tx.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let mut num_cells = vec![0usize; max_val + 1];
                 let mut num_edge_cells = vec![0usize; max_val + 1];
                 let dx = [1, 1, 1, 0, -1, -1, -1, 0];
@@ -249,14 +244,14 @@ impl WhiteboxTool for EdgeProportion {
                         }
                     }
                 }
-                tx.send((num_cells, num_edge_cells)).unwrap();
+                let _ = tx.send((num_cells, num_edge_cells));
             });
         }
 
         let mut num_cells = vec![0usize; max_val + 1];
         let mut num_edge_cells = vec![0usize; max_val + 1];
         for tid in 0..num_procs {
-            let (vec1, vec2) = rx.recv().expect("Error receiving data from thread.");
+            let (vec1, vec2) = cancel.recv(&rx)?;
             for bin in 0..max_val + 1 {
                 num_cells[bin] += vec1[bin];
                 num_edge_cells[bin] += vec2[bin];
@@ -264,7 +259,7 @@ impl WhiteboxTool for EdgeProportion {
             if verbose {
                 progress = (100.0_f64 * tid as f64 / (num_procs - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress (Loop 1 of 2): {}%", progress);
+                    progress::update_stage("Progress (Loop 1 of 2)", progress);
                     old_progress = progress;
                 }
             }
@@ -283,7 +278,7 @@ impl WhiteboxTool for EdgeProportion {
             let input = input.clone();
             let edge_props = edge_props.clone();
             let tx = tx.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let mut z: f64;
                 let mut bin: usize;
                 for row in (0..rows).filter(|r| r % num_procs == tid) {
@@ -295,7 +290,9 @@ impl WhiteboxTool for EdgeProportion {
                             data[col as usize] = edge_props[bin];
                         }
                     }
-                    tx.send((row, data)).unwrap();
+                    if tx.send((row, data)).is_err() {
+                        return;
+                    }
                 }
             });
         }
@@ -306,12 +303,12 @@ impl WhiteboxTool for EdgeProportion {
         output.configs.photometric_interp = PhotometricInterpretation::Continuous;
 
         for r in 0..rows {
-            let (row, data) = rx.recv().expect("Error receiving data from thread.");
+            let (row, data) = cancel.recv(&rx)?;
             output.set_row_data(row, data);
             if verbose {
                 progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress (Loop 2 of 2): {}%", progress);
+                    progress::update_stage("Progress (Loop 2 of 2)", progress);
                     old_progress = progress;
                 }
             }
@@ -326,31 +323,28 @@ impl WhiteboxTool for EdgeProportion {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
 
         if output_text {
-            println!("Edge Proportion\nPatch ID\tValue");
+            progress::message("Edge Proportion\nPatch ID\tValue");
             for bin in 0..max_val + 1 {
                 if edge_props[bin] > 0f64 && edge_props[bin] != nodata {
-                    println!("{}\t{}", bin, edge_props[bin]);
+                    progress::message(format!("{}\t{}", bin, edge_props[bin]));
                 }
             }
         }
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -7,6 +7,7 @@ License: MIT
 */
 
 use whitebox_common::structures::{DistanceMetric, FixedRadiusSearch2D};
+use whitebox_common::progress;
 use crate::tools::*;
 use whitebox_vector::*;
 use std::env;
@@ -140,6 +141,7 @@ impl WhiteboxTool for EliminateCoincidentPoints {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file: String = "".to_string();
         let mut output_file: String = "".to_string();
@@ -197,13 +199,7 @@ impl WhiteboxTool for EliminateCoincidentPoints {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         if tolerance <= 0f64 {
@@ -251,10 +247,11 @@ impl WhiteboxTool for EliminateCoincidentPoints {
             y = record.points[0].y;
             frs.insert(x, y, record_num);
 
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * record_num as f64 / (num_points - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Building fixed-radius search: {}%", progress);
+                    progress::update_stage("Building fixed-radius search", progress);
                     old_progress = progress;
                 }
             }
@@ -278,22 +275,23 @@ impl WhiteboxTool for EliminateCoincidentPoints {
                 let atts = input.attributes.get_record(record_num);
                 output.attributes.add_record(atts.clone(), false);
             }
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * record_num as f64 / (num_points - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    progress::update(progress);
                     old_progress = progress;
                 }
             }
         }
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
@@ -302,7 +300,7 @@ impl WhiteboxTool for EliminateCoincidentPoints {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!("{}", &format!("Elapsed Time: {}", elapsed_time));
+            progress::message(format!("Elapsed Time: {}", elapsed_time));
         }
 
         Ok(())
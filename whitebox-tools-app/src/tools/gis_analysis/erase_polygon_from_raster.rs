@@ -9,6 +9,7 @@ License: MIT
 use whitebox_common::algorithms;
 use whitebox_raster::*;
 use whitebox_common::structures::Point2D;
+use whitebox_common::progress;
 use crate::tools::*;
 use whitebox_vector::{ShapeType, Shapefile};
 use std::env;
@@ -50,7 +51,7 @@ impl ErasePolygonFromRaster {
 
         parameters.push(ToolParameter {
             name: "Input Vector Polygon File".to_owned(),
-            flags: vec!["--polygons".to_owned()],
+            flags: vec!["--polygon".to_owned(), "--polygons".to_owned()],
             description: "Input vector polygons file.".to_owned(),
             parameter_type: ParameterType::ExistingFile(ParameterFileType::Vector(
                 VectorGeometryType::Polygon,
@@ -126,6 +127,7 @@ impl WhiteboxTool for ErasePolygonFromRaster {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut polygons_file = String::new();
@@ -170,13 +172,7 @@ impl WhiteboxTool for ErasePolygonFromRaster {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -195,7 +191,7 @@ impl WhiteboxTool for ErasePolygonFromRaster {
         }
 
         if verbose {
-            println!("Reading data...")
+            progress::message("Reading data...")
         };
         let input = Raster::new(&input_file, "r")?;
 
@@ -279,17 +275,12 @@ impl WhiteboxTool for ErasePolygonFromRaster {
                                 output.set_value(r, c, nodata);
                             }
                         }
+                        cancel.check()?;
                         if verbose {
                             progress = (100.0_f64 * r as f64 / (ending_row - starting_row) as f64)
                                 as usize;
                             if progress != old_progress {
-                                println!(
-                                    "Progress (rec {} of {} part {}): {}%",
-                                    record_num + 1,
-                                    num_records,
-                                    part_num,
-                                    progress
-                                );
+                                progress::update_stage(&format!("Progress (rec {} of {} part {})", record_num + 1, num_records, part_num), progress);
                                 old_progress = progress;
                             }
                         }
@@ -342,17 +333,12 @@ impl WhiteboxTool for ErasePolygonFromRaster {
                                 output.set_value(r, c, input.get_value(r, c));
                             }
                         }
+                        cancel.check()?;
                         if verbose {
                             progress = (100.0_f64 * r as f64 / (ending_row - starting_row) as f64)
                                 as usize;
                             if progress != old_progress {
-                                println!(
-                                    "Progress (rec {} of {} part {}): {}%",
-                                    record_num + 1,
-                                    num_records,
-                                    part_num,
-                                    progress
-                                );
+                                progress::update_stage(&format!("Progress (rec {} of {} part {})", record_num + 1, num_records, part_num), progress);
                                 old_progress = progress;
                             }
                         }
@@ -371,22 +357,19 @@ impl WhiteboxTool for ErasePolygonFromRaster {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
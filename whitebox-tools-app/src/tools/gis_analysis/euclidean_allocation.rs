@@ -8,6 +8,7 @@ License: MIT
 
 use whitebox_raster::*;
 use whitebox_common::structures::Array2D;
+use whitebox_common::progress;
 use crate::tools::*;
 use std::env;
 use std::f64;
@@ -123,6 +124,7 @@ impl WhiteboxTool for EuclideanAllocation {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut output_file = String::new();
@@ -159,13 +161,7 @@ impl WhiteboxTool for EuclideanAllocation {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -181,7 +177,7 @@ impl WhiteboxTool for EuclideanAllocation {
         }
 
         if verbose {
-            println!("Reading data...")
+            progress::message("Reading data...")
         };
 
         let input = Raster::new(&input_file, "r")?;
@@ -218,10 +214,11 @@ impl WhiteboxTool for EuclideanAllocation {
                     allocation[(row, col)] = inf_val;
                 }
             }
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Initializing Rasters: {}%", progress);
+                    progress::update_stage("Initializing Rasters", progress);
                     old_progress = progress;
                 }
             }
@@ -261,10 +258,11 @@ impl WhiteboxTool for EuclideanAllocation {
                     }
                 }
             }
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress (1 of 3): {}%", progress);
+                    progress::update_stage("Progress (1 of 3)", progress);
                     old_progress = progress;
                 }
             }
@@ -304,10 +302,11 @@ impl WhiteboxTool for EuclideanAllocation {
                     }
                 }
             }
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * (rows - row) as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress (2 of 3): {}%", progress);
+                    progress::update_stage("Progress (2 of 3)", progress);
                     old_progress = progress;
                 }
             }
@@ -320,10 +319,11 @@ impl WhiteboxTool for EuclideanAllocation {
                     allocation[(row, col)] = nodata;
                 }
             }
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress (3 of 3): {}%", progress);
+                    progress::update_stage("Progress (3 of 3)", progress);
                     old_progress = progress;
                 }
             }
@@ -339,22 +339,19 @@ impl WhiteboxTool for EuclideanAllocation {
         allocation.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match allocation.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&allocation.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -8,6 +8,7 @@ License: MIT
 
 use whitebox_raster::*;
 use whitebox_common::structures::Array2D;
+use whitebox_common::progress;
 use crate::tools::*;
 use std::env;
 use std::f64;
@@ -132,6 +133,7 @@ impl WhiteboxTool for EuclideanDistance {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut output_file = String::new();
@@ -169,13 +171,7 @@ impl WhiteboxTool for EuclideanDistance {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -191,7 +187,7 @@ impl WhiteboxTool for EuclideanDistance {
         }
 
         if verbose {
-            println!("Reading data...")
+            progress::message("Reading data...")
         };
 
         let input = Raster::new(&input_file, "r")?;
@@ -227,10 +223,11 @@ impl WhiteboxTool for EuclideanDistance {
                     output.set_value(row, col, inf_val);
                 }
             }
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Initializing Rasters: {}%", progress);
+                    progress::update_stage("Initializing Rasters", progress);
                     old_progress = progress;
                 }
             }
@@ -269,10 +266,11 @@ impl WhiteboxTool for EuclideanDistance {
                     }
                 }
             }
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress (1 of 3): {}%", progress);
+                    progress::update_stage("Progress (1 of 3)", progress);
                     old_progress = progress;
                 }
             }
@@ -311,10 +309,11 @@ impl WhiteboxTool for EuclideanDistance {
                     }
                 }
             }
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * (rows - row) as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress (2 of 3): {}%", progress);
+                    progress::update_stage("Progress (2 of 3)", progress);
                     old_progress = progress;
                 }
             }
@@ -329,10 +328,11 @@ impl WhiteboxTool for EuclideanDistance {
                     output.set_value(row, col, nodata);
                 }
             }
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress (3 of 3): {}%", progress);
+                    progress::update_stage("Progress (3 of 3)", progress);
                     old_progress = progress;
                 }
             }
@@ -348,22 +348,19 @@ impl WhiteboxTool for EuclideanDistance {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -7,6 +7,7 @@ License: MIT
 */
 
 use whitebox_raster::*;
+use whitebox_common::progress;
 use crate::tools::*;
 use whitebox_vector::*;
 use std::env;
@@ -137,6 +138,7 @@ impl WhiteboxTool for ExtractRasterValuesAtPoints {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_files = String::new();
         let mut points_file = String::new();
@@ -179,13 +181,7 @@ impl WhiteboxTool for ExtractRasterValuesAtPoints {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -238,9 +234,10 @@ impl WhiteboxTool for ExtractRasterValuesAtPoints {
         let mut z: f64;
         let mut i = 1;
         for value in v {
+            cancel.check()?;
             if !value.trim().is_empty() {
                 if verbose {
-                    println!("Reading data...")
+                    progress::message("Reading data...")
                 };
 
                 let mut input_file = value.trim().to_owned();
@@ -269,34 +266,27 @@ impl WhiteboxTool for ExtractRasterValuesAtPoints {
         }
 
         if output_text {
-            println!("Point values:");
+            progress::message("Point values:");
             for record_num in 0..num_records {
-                println!(
-                    "Point {} values: {:?}",
-                    record_num + 1,
-                    raster_values[record_num]
-                );
+                progress::message(format!("Point {} values: {:?}", record_num + 1, raster_values[record_num]));
             }
         }
 
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match points.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&points.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -7,6 +7,7 @@ License: MIT
 */
 
 use whitebox_raster::*;
+use whitebox_common::progress;
 use crate::tools::*;
 use whitebox_vector::*;
 use num_cpus;
@@ -16,7 +17,6 @@ use std::io::{Error, ErrorKind};
 use std::path;
 use std::sync::mpsc;
 use std::sync::{Arc, Mutex};
-use std::thread;
 
 /// This tool locates the lowest and/or highest cells in a raster and outputs these locations to a vector
 /// points file. The user must specify the name of the input raster (`--input`) and the name of the output
@@ -140,6 +140,7 @@ impl WhiteboxTool for FindLowestOrHighestPoints {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut output_file = String::new();
@@ -196,13 +197,7 @@ impl WhiteboxTool for FindLowestOrHighestPoints {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -215,7 +210,7 @@ impl WhiteboxTool for FindLowestOrHighestPoints {
         }
 
         if verbose {
-            println!("Reading data...")
+            progress::message("Reading data...")
         };
 
         let input = Arc::new(Raster::new(&input_file, "r")?);
@@ -248,7 +243,7 @@ impl WhiteboxTool for FindLowestOrHighestPoints {
             let rows_completed = rows_completed.clone();
             let old_progress = old_progress.clone();
             let tx = tx.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let mut z: f64;
                 let mut low_z = f64::INFINITY;
                 let mut low_row = 0isize;
@@ -282,13 +277,12 @@ impl WhiteboxTool for FindLowestOrHighestPoints {
                         progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
                         let mut p = old_progress.lock().unwrap();
                         if progress != *p {
-                            println!("Progress: {}%", progress);
+                            progress::update(progress);
                             *p = progress;
                         }
                     }
                 }
-                tx.send((low_z, low_col, low_row, high_z, high_col, high_row))
-                    .unwrap();
+                let _ = tx.send((low_z, low_col, low_row, high_z, high_col, high_row));
             });
         }
 
@@ -299,7 +293,7 @@ impl WhiteboxTool for FindLowestOrHighestPoints {
         let mut high_row = 0isize;
         let mut high_col = 0isize;
         for _ in 0..num_procs {
-            let data = rx.recv().expect("Error receiving data from thread.");
+            let data = cancel.recv(&rx)?;
             if data.0 < low_z {
                 low_z = data.0;
                 low_col = data.1;
@@ -339,21 +333,18 @@ impl WhiteboxTool for FindLowestOrHighestPoints {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -7,6 +7,7 @@ License: MIT
 */
 
 use whitebox_raster::*;
+use whitebox_common::progress;
 use crate::tools::*;
 use num_cpus;
 use std::env;
@@ -15,7 +16,6 @@ use std::io::{Error, ErrorKind};
 use std::path;
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::thread;
 
 /// This tool will identify all grid cells situated along the edges of patches or class features within an
 /// input raster (`--input`). Edge cells in the output raster (`--output`) will have the patch identifier value
@@ -122,6 +122,7 @@ impl WhiteboxTool for FindPatchOrClassEdgeCells {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut output_file = String::new();
@@ -159,13 +160,7 @@ impl WhiteboxTool for FindPatchOrClassEdgeCells {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -181,7 +176,7 @@ impl WhiteboxTool for FindPatchOrClassEdgeCells {
         }
 
         if verbose {
-            println!("Reading input data...")
+            progress::message("Reading input data...")
         };
         let input = Arc::new(Raster::new(&input_file, "r")?);
         let rows = input.configs.rows as isize;
@@ -200,7 +195,7 @@ impl WhiteboxTool for FindPatchOrClassEdgeCells {
         for tid in 0..num_procs {
             let input = input.clone();
             let tx = tx.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let dx = [1, 1, 1, 0, -1, -1, -1, 0];
                 let dy = [-1, 0, 1, 1, 1, 0, -1, -1];
                 let mut z: f64;
@@ -222,7 +217,9 @@ impl WhiteboxTool for FindPatchOrClassEdgeCells {
                             data[col as usize] = zout;
                         }
                     }
-                    tx.send((row, data)).unwrap();
+                    if tx.send((row, data)).is_err() {
+                        return;
+                    }
                 }
             });
         }
@@ -230,12 +227,12 @@ impl WhiteboxTool for FindPatchOrClassEdgeCells {
         let mut output = Raster::initialize_using_file(&output_file, &input);
 
         for r in 0..rows {
-            let (row, data) = rx.recv().expect("Error receiving data from thread.");
+            let (row, data) = cancel.recv(&rx)?;
             output.set_row_data(row, data);
             if verbose {
                 progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    progress::update(progress);
                     old_progress = progress;
                 }
             }
@@ -250,22 +247,19 @@ impl WhiteboxTool for FindPatchOrClassEdgeCells {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
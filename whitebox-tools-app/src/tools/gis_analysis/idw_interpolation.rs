@@ -19,6 +19,7 @@ Most IDW tool have the option to work either based on a fixed number of neighbou
 
 use whitebox_raster::*;
 use whitebox_common::structures::{DistanceMetric, FixedRadiusSearch2D};
+use whitebox_common::progress;
 use crate::tools::*;
 use whitebox_vector::{FieldData, ShapeType, Shapefile};
 use num_cpus;
@@ -28,7 +29,6 @@ use std::io::{Error, ErrorKind};
 use std::path;
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::thread;
 
 /// This tool interpolates vector points into a raster surface using an inverse-distance weighted scheme.
 pub struct IdwInterpolation {
@@ -117,7 +117,7 @@ impl IdwInterpolation {
 
         parameters.push(ToolParameter{
             name: "Cell Size (optional)".to_owned(), 
-            flags: vec!["--cell_size".to_owned()], 
+            flags: vec!["--resolution".to_owned(), "--cell_size".to_owned()], 
             description: "Optionally specified cell size of output raster. Not used when base raster is specified.".to_owned(),
             parameter_type: ParameterType::Float,
             default_value: None,
@@ -192,6 +192,7 @@ impl WhiteboxTool for IdwInterpolation {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut field_name = String::new();
@@ -307,13 +308,7 @@ impl WhiteboxTool for IdwInterpolation {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -335,7 +330,7 @@ impl WhiteboxTool for IdwInterpolation {
         // }
 
         if verbose {
-            println!("Reading data...")
+            progress::message("Reading data...")
         };
         let vector_data = Shapefile::read(&input_file)?;
 
@@ -488,12 +483,13 @@ impl WhiteboxTool for IdwInterpolation {
                     }
                 }
 
+                cancel.check()?;
                 if verbose {
                     progress = (100.0_f64 * record_num as f64
                         / (vector_data.num_records - 1) as f64)
                         as usize;
                     if progress != old_progress {
-                        println!("Creating search structure: {}%", progress);
+                        progress::update_stage("Creating search structure", progress);
                         old_progress = progress;
                     }
                 }
@@ -525,12 +521,13 @@ impl WhiteboxTool for IdwInterpolation {
                     // p += 1;
                 }
 
+                cancel.check()?;
                 if verbose {
                     progress = (100.0_f64 * record_num as f64
                         / (vector_data.num_records - 1) as f64)
                         as usize;
                     if progress != old_progress {
-                        println!("Creating search structure: {}%", progress);
+                        progress::update_stage("Creating search structure", progress);
                         old_progress = progress;
                     }
                 }
@@ -607,7 +604,7 @@ impl WhiteboxTool for IdwInterpolation {
             // let kdtree = kdtree.clone();
             let frs = frs.clone();
             let tx = tx.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let (mut x, mut y): (f64, f64);
                 let mut zn: f64;
                 let mut dist: f64;
@@ -643,7 +640,9 @@ impl WhiteboxTool for IdwInterpolation {
                             }
                         }
                     }
-                    tx.send((row, data)).unwrap();
+                    if tx.send((row, data)).is_err() {
+                        return;
+                    }
                 }
                 // if radius > 0f64 {
                 //     for row in (0..rows).filter(|r| r % num_procs == tid) {
@@ -713,12 +712,12 @@ impl WhiteboxTool for IdwInterpolation {
         }
 
         for row in 0..rows {
-            let data = rx.recv().expect("Error receiving data from thread.");
+            let data = cancel.recv(&rx)?;
             output.set_row_data(data.0, data.1);
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    progress::update(progress);
                     old_progress = progress;
                 }
             }
@@ -733,22 +732,19 @@ impl WhiteboxTool for IdwInterpolation {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
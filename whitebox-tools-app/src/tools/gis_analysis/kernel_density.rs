@@ -0,0 +1,845 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: Dr. John Lindsay
+Created: 18/10/2026
+Last Modified: 18/10/2026
+License: MIT
+*/
+
+use whitebox_raster::*;
+use whitebox_common::structures::{DistanceMetric, FixedRadiusSearch2D};
+use whitebox_common::utils::haversine_distance;
+use whitebox_common::progress;
+use crate::tools::*;
+use whitebox_vector::{FieldData, ShapeType, Shapefile};
+use kdtree::distance::squared_euclidean;
+use kdtree::KdTree;
+use num_cpus;
+use std::env;
+use std::f64;
+use std::f64::consts::PI;
+use std::io::{Error, ErrorKind};
+use std::path;
+use std::sync::mpsc;
+use std::sync::Arc;
+
+/// This tool estimates the density of a set of vector points (`--input`), using
+/// [kernel density estimation](https://en.wikipedia.org/wiki/Kernel_density_estimation) (KDE), and outputs
+/// the density surface as a raster (`--output`). Each point contributes a kernel, a smooth bump whose
+/// width is set by the bandwidth, to the density surface, and the density at a grid cell is the sum of
+/// the kernels of the points around it. Points may optionally be weighted by a numeric attribute field
+/// (`--weight_field`), e.g. the population of each point, in which case each point's kernel is
+/// multiplied by its weight. Points with null weights are ignored.
+///
+/// Three kernel functions (`--kernel`) are available: 'quartic' (biweight), 'epanechnikov', and
+/// 'gaussian'. The quartic and Epanechnikov kernels extend to a distance of one bandwidth from each point,
+/// while the bandwidth of the Gaussian kernel is its standard deviation, and it is truncated at a distance
+/// of four bandwidths. The kernels are normalized so that each integrates to one over the plane, and so the
+/// output is a density per unit area, i.e. per square map unit, that integrates to the number of points, or
+/// the sum of their weights, over an unbounded grid. Multiply the output by the area of a grid cell to
+/// obtain the expected number of points in each cell.
+///
+/// The bandwidth (`--bandwidth`) has a much stronger influence on the density surface than the kernel.
+/// If it is not specified, the bandwidth is estimated from the points, using one of two methods
+/// (`--bandwidth_method`). Silverman's rule of thumb ('silverman') estimates the bandwidth that is optimal
+/// for normally distributed data, as `h = s n^(-1/6)`, where `n` is the number of points, or the sum of
+/// their weights, and `s` is the smaller of the standard distance of the points divided by the square root
+/// of two, and a robust estimate from the median distance of the points from their mean centre. This rule
+/// tends to over-smooth clustered data. Likelihood cross-validation ('cross-validation') instead chooses the
+/// bandwidth that maximizes the likelihood of each point under the density estimated from all of the other
+/// points, which better adapts to clustered data, but takes longer to calculate, can under-smooth data
+/// containing many coincident points, and can over-smooth data containing isolated outlying points. In
+/// both cases, the estimated bandwidth is that of a Gaussian kernel, which is rescaled to the equivalent
+/// (canonical) bandwidth of the quartic or Epanechnikov kernels, and the estimated bandwidth is reported
+/// and stored in the output's metadata.
+///
+/// If the points are in geographic coordinates, as determined from their projection (.prj) file, distances
+/// are measured along great circles, using the haversine formula, and the bandwidth is in metres and the
+/// density is per square metre. Otherwise, distances and the bandwidth are in map units.
+///
+/// The output raster's resolution and extent are either those of an optional base raster (`--base`), or are
+/// set by a grid cell size (`--cell_size`), in map units, in which case the raster covers the extent of
+/// the points, expanded by the radius of the kernel.
+///
+/// # Reference
+/// Silverman, B. W. (1986). *Density Estimation for Statistics and Data Analysis*. Chapman and Hall, London.
+///
+/// Marron, J. S., and Nolan, D. (1988). Canonical kernels for density estimation. *Statistics & Probability
+/// Letters*, 7(3), 195-199.
+///
+/// # See Also
+/// `HeatMap`, `VectorHexBinning`
+pub struct KernelDensity {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl KernelDensity {
+    pub fn new() -> KernelDensity {
+        // public constructor
+        let name = "KernelDensity".to_string();
+        let toolbox = "GIS Analysis".to_string();
+        let description =
+            "Estimates the density of vector points using kernel density estimation with bandwidth selection."
+                .to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input Vector Points File".to_owned(),
+            flags: vec!["-i".to_owned(), "--input".to_owned()],
+            description: "Input vector points file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Vector(
+                VectorGeometryType::Point,
+            )),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Weight Field (optional)".to_owned(),
+            flags: vec!["--weight_field".to_owned()],
+            description: "Optional numeric field holding the weight, e.g. the population, of each point."
+                .to_owned(),
+            parameter_type: ParameterType::VectorAttributeField(
+                AttributeType::Number,
+                "--input".to_string(),
+            ),
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output Raster File".to_owned(),
+            flags: vec!["-o".to_owned(), "--output".to_owned()],
+            description: "Output raster file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Kernel Function".to_owned(),
+            flags: vec!["--kernel".to_owned()],
+            description: "Kernel function; options are 'quartic', 'epanechnikov', and 'gaussian'."
+                .to_owned(),
+            parameter_type: ParameterType::OptionList(vec![
+                "quartic".to_owned(),
+                "epanechnikov".to_owned(),
+                "gaussian".to_owned(),
+            ]),
+            default_value: Some("quartic".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Bandwidth (optional)".to_owned(),
+            flags: vec!["--bandwidth".to_owned()],
+            description: "Optional kernel bandwidth, in map units, or metres for geographic coordinates; estimated from the points by default."
+                .to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Bandwidth Selection Method".to_owned(),
+            flags: vec!["--bandwidth_method".to_owned()],
+            description: "Method used to estimate the bandwidth when it is not specified; options are 'silverman' and 'cross-validation'."
+                .to_owned(),
+            parameter_type: ParameterType::OptionList(vec![
+                "silverman".to_owned(),
+                "cross-validation".to_owned(),
+            ]),
+            default_value: Some("silverman".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Cell Size (optional)".to_owned(),
+            flags: vec!["--cell_size".to_owned()],
+            description: "Optional output raster cell size, in map units; not used when a base raster is specified."
+                .to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Base Raster File (optional)".to_owned(),
+            flags: vec!["--base".to_owned()],
+            description: "Optional input base raster file, which sets the output raster's extent and resolution."
+                .to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: true,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut parent = env::current_exe().unwrap();
+        parent.pop();
+        let p = format!("{}", parent.display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(
+            ">>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=cases.shp -o=density.tif --kernel=quartic --bandwidth_method=cross-validation --cell_size=50.0
+>>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=towns.shp --weight_field=POP -o=density.tif --bandwidth=5000.0 --base=dem.tif",
+            short_exe, name
+        ).replace("*", &sep);
+
+        KernelDensity {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for KernelDensity {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        match serde_json::to_string(&self.parameters) {
+            Ok(json_str) => return format!("{{\"parameters\":{}}}", json_str),
+            Err(err) => return format!("{:?}", err),
+        }
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+        cancel: &CancellationToken,
+    ) -> Result<(), Error> {
+        let args = ParsedArgs::parse(&self.get_tool_parameters(), &args, working_directory)?;
+        self.run_with_parsed_args(args, working_directory, verbose, cancel)
+    }
+
+    fn run_with_parsed_args<'a>(
+        &self,
+        args: ParsedArgs,
+        _working_directory: &'a str,
+        verbose: bool,
+        cancel: &CancellationToken,
+    ) -> Result<(), Error> {
+        let input_file = args.get_file("input").unwrap();
+        let weight_field = args.get_string("weight_field").filter(|s| !s.is_empty());
+        let output_file = args.get_file("output").unwrap();
+        let kernel = match args.get_string("kernel").unwrap_or_default().as_str() {
+            "gaussian" => Kernel::Gaussian,
+            "epanechnikov" => Kernel::Epanechnikov,
+            _ => Kernel::Quartic,
+        };
+        let bandwidth = args.get_float("bandwidth");
+        let cross_validation =
+            args.get_string("bandwidth_method").unwrap_or_default() == "cross-validation";
+        let cell_size = args.get_float("cell_size").unwrap_or(0f64);
+        let base_file = args.get_file("base");
+
+        if let Some(bandwidth) = bandwidth {
+            if bandwidth <= 0f64 {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    "The bandwidth (--bandwidth) must be larger than zero.",
+                ));
+            }
+        }
+        if base_file.is_none() && cell_size <= 0f64 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Either a positive cell size (--cell_size) or a base raster (--base) must be specified.",
+            ));
+        }
+
+        let mut progress: usize;
+        let mut old_progress: usize = 1;
+
+        let start = Instant::now();
+
+        if verbose {
+            let tool_name = self.get_tool_name();
+            progress::welcome(&tool_name);
+        }
+
+        if verbose {
+            progress::message("Reading data...")
+        };
+
+        let input = Shapefile::read(&input_file)?;
+        if input.header.shape_type.base_shape_type() != ShapeType::Point
+            && input.header.shape_type.base_shape_type() != ShapeType::MultiPoint
+        {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The input vector data must be of POINT or MULTIPOINT base shape type.",
+            ));
+        }
+        let weight_index = match &weight_field {
+            Some(name) => match input.attributes.get_field_num(name) {
+                Some(i) if input.attributes.is_field_numeric(i) => Some(i),
+                Some(_) => {
+                    return Err(Error::new(
+                        ErrorKind::InvalidInput,
+                        format!("The weight field '{}' is not numeric.", name),
+                    ));
+                }
+                None => {
+                    return Err(Error::new(
+                        ErrorKind::InvalidInput,
+                        format!("The field '{}' does not exist in the input attribute table.", name),
+                    ));
+                }
+            },
+            None => None,
+        };
+
+        let mut points: Vec<WeightedPoint> = vec![];
+        let mut num_null_weights = 0;
+        for record_num in 0..input.num_records {
+            let weight = match weight_index {
+                Some(i) => match input.attributes.get_record(record_num)[i] {
+                    FieldData::Int(v) => v as f64,
+                    FieldData::Real(v) => v,
+                    _ => {
+                        num_null_weights += 1;
+                        continue;
+                    }
+                },
+                None => 1f64,
+            };
+            if weight < 0f64 {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    "The point weights must not be negative.",
+                ));
+            }
+            for p in &input.get_record(record_num).points {
+                points.push(WeightedPoint {
+                    x: p.x,
+                    y: p.y,
+                    weight: weight,
+                });
+            }
+        }
+        if verbose && num_null_weights > 0 {
+            progress::message(format!(
+                "Warning: {} points with null weights were ignored.",
+                num_null_weights
+            ));
+        }
+        let total_weight: f64 = points.iter().map(|p| p.weight).sum();
+        if points.is_empty() || total_weight <= 0f64 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The input contains no points with positive weights.",
+            ));
+        }
+
+        let points = Arc::new(points);
+        let mut num_procs = num_cpus::get() as isize;
+        let configs = whitebox_common::configs::get_configs()?;
+        let max_procs = configs.max_procs;
+        if max_procs > 0 && max_procs < num_procs {
+            num_procs = max_procs;
+        }
+
+        let metric = Metric::new(&input);
+        if verbose && metric.geographic {
+            progress::message(
+                "The points are in geographic coordinates; distances are measured in metres along great circles.",
+            );
+        }
+
+        let bandwidth = match bandwidth {
+            Some(bandwidth) => bandwidth,
+            None => {
+                let silverman = match silverman_bandwidth(&points, &metric) {
+                    Some(h) => h,
+                    None => {
+                        return Err(Error::new(
+                            ErrorKind::InvalidInput,
+                            "The bandwidth cannot be estimated because the points do not vary in location; specify a bandwidth (--bandwidth).",
+                        ));
+                    }
+                };
+                let gaussian_bandwidth = if cross_validation {
+                    if verbose {
+                        progress::message("Estimating the bandwidth by cross-validation...");
+                    }
+                    let (h, at_limit) =
+                        cross_validation_bandwidth(&points, &metric, silverman, num_procs, cancel)?;
+                    if verbose && at_limit {
+                        progress::message(
+                            "Warning: The cross-validated bandwidth is at the limit of the range searched, which may indicate coincident or outlying points.",
+                        );
+                    }
+                    h
+                } else {
+                    silverman
+                };
+                gaussian_bandwidth * kernel.gaussian_scale()
+            }
+        };
+        let radius = bandwidth * kernel.support();
+        if verbose {
+            progress::message(format!("Bandwidth: {}", bandwidth));
+        }
+
+        // Create the output raster, using the extent and resolution of a base raster, if one is
+        // specified, or else covering the points and their kernels.
+        let nodata = -32768f64;
+        let mut output = match &base_file {
+            Some(base_file) => {
+                let base = Raster::new(base_file, "r")?;
+                Raster::initialize_using_file(&output_file, &base)
+            }
+            None => {
+                let (margin_x, margin_y) = metric.margin(radius);
+                let west = input.header.x_min - margin_x;
+                let north = input.header.y_max + margin_y;
+                let rows = ((input.header.y_max + margin_y - (input.header.y_min - margin_y))
+                    / cell_size)
+                    .ceil()
+                    .max(1f64) as usize;
+                let columns = ((input.header.x_max + margin_x - west) / cell_size)
+                    .ceil()
+                    .max(1f64) as usize;
+
+                let mut configs = RasterConfigs {
+                    ..Default::default()
+                };
+                configs.rows = rows;
+                configs.columns = columns;
+                configs.north = north;
+                configs.south = north - rows as f64 * cell_size;
+                configs.east = west + columns as f64 * cell_size;
+                configs.west = west;
+                configs.resolution_x = cell_size;
+                configs.resolution_y = cell_size;
+                configs.nodata = nodata;
+                configs.data_type = DataType::F32;
+                configs.photometric_interp = PhotometricInterpretation::Continuous;
+                configs.projection = input.projection.clone();
+
+                Raster::initialize_using_config(&output_file, &configs)
+            }
+        };
+        output.configs.data_type = DataType::F32;
+        output.configs.nodata = nodata;
+        let rows = output.configs.rows as isize;
+        let columns = output.configs.columns as isize;
+        let west = output.configs.west;
+        let north = output.configs.north;
+        let res_x = output.configs.resolution_x;
+        let res_y = output.configs.resolution_y;
+
+        let index = Arc::new(build_index(&points, &metric, radius));
+        let (tx, rx) = mpsc::channel();
+        for tid in 0..num_procs {
+            let index = index.clone();
+            let points = points.clone();
+            let tx = tx.clone();
+            progress::spawn(move || {
+                let scale = 1f64 / (bandwidth * bandwidth);
+                for row in (0..rows).filter(|r| r % num_procs == tid) {
+                    let y = north - res_y / 2f64 - row as f64 * res_y;
+                    let mut data = vec![0f64; columns as usize];
+                    for col in 0..columns {
+                        let x = west + res_x / 2f64 + col as f64 * res_x;
+                        let (sx, sy) = metric.search_coordinates(x, y);
+                        let mut density = 0f64;
+                        for (j, _) in index.search(sx, sy) {
+                            let p = &points[j];
+                            let d = metric.distance(x, y, p.x, p.y);
+                            if d <= radius {
+                                density += p.weight * kernel.value(d / bandwidth);
+                            }
+                        }
+                        data[col as usize] = density * scale;
+                    }
+                    if tx.send((row, data)).is_err() {
+                        return;
+                    }
+                }
+            });
+        }
+
+        for row in 0..rows {
+            let (r, data) = cancel.recv(&rx)?;
+            output.set_row_data(r, data);
+            if verbose {
+                progress = (100.0_f64 * (row + 1) as f64 / rows as f64) as usize;
+                if progress != old_progress {
+                    progress::update_stage("Estimating density", progress);
+                    old_progress = progress;
+                }
+            }
+        }
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+        output.add_metadata_entry(format!(
+            "Created by whitebox_tools\' {} tool",
+            self.get_tool_name()
+        ));
+        output.add_metadata_entry(format!("Input file: {}", input_file));
+        output.add_metadata_entry(format!("Kernel: {}", kernel.name()));
+        output.add_metadata_entry(format!("Bandwidth: {}", bandwidth));
+        output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
+
+        if verbose {
+            progress::message("Saving data...")
+        };
+        let _ = match output.write() {
+            Ok(_) => {
+                if verbose {
+                    progress::output_written(&output.file_name)
+                }
+            }
+            Err(e) => return Err(e),
+        };
+
+        if verbose {
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
+        }
+
+        Ok(())
+    }
+}
+
+struct WeightedPoint {
+    x: f64,
+    y: f64,
+    weight: f64,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Kernel {
+    Quartic,
+    Epanechnikov,
+    Gaussian,
+}
+
+impl Kernel {
+    fn name(self) -> &'static str {
+        match self {
+            Kernel::Quartic => "quartic",
+            Kernel::Epanechnikov => "epanechnikov",
+            Kernel::Gaussian => "gaussian",
+        }
+    }
+
+    /// The value of the kernel at a distance of `u` bandwidths, for a unit bandwidth. Each
+    /// kernel integrates to one over the plane.
+    fn value(self, u: f64) -> f64 {
+        match self {
+            Kernel::Quartic => {
+                if u < 1f64 {
+                    3f64 / PI * (1f64 - u * u).powi(2)
+                } else {
+                    0f64
+                }
+            }
+            Kernel::Epanechnikov => {
+                if u < 1f64 {
+                    2f64 / PI * (1f64 - u * u)
+                } else {
+                    0f64
+                }
+            }
+            Kernel::Gaussian => (-0.5 * u * u).exp() / (2f64 * PI),
+        }
+    }
+
+    /// The distance, in bandwidths, beyond which the kernel is zero, or is truncated.
+    fn support(self) -> f64 {
+        match self {
+            Kernel::Gaussian => 4f64,
+            _ => 1f64,
+        }
+    }
+
+    /// The ratio of the kernel's bandwidth to that of a Gaussian kernel giving an equivalent
+    /// amount of smoothing, i.e. the ratio of their canonical bandwidths (Marron and Nolan, 1988).
+    fn gaussian_scale(self) -> f64 {
+        match self {
+            Kernel::Quartic => 460.8f64.powf(1f64 / 6f64),
+            Kernel::Epanechnikov => 192f64.powf(1f64 / 6f64),
+            Kernel::Gaussian => 1f64,
+        }
+    }
+}
+
+/// Measures the distances between points, either in map units or, for points in geographic
+/// coordinates, in metres along great circles.
+#[derive(Clone, Copy, Debug)]
+struct Metric {
+    geographic: bool,
+    // The scales of the planar coordinates used to search for neighbouring points. For geographic
+    // coordinates, they are in metres per degree, and the east-west scale is that at the latitude
+    // furthest from the equator, so that distances in these coordinates are never much larger than
+    // great circle distances.
+    scale_x: f64,
+    scale_y: f64,
+}
+
+impl Metric {
+    fn new(input: &Shapefile) -> Metric {
+        let projection = input.projection.to_lowercase();
+        let geographic = projection.contains("geogcs[")
+            && !projection.contains("projcs[")
+            && input.header.x_min >= -180f64
+            && input.header.x_max <= 180f64
+            && input.header.y_min >= -90f64
+            && input.header.y_max <= 90f64;
+        if geographic {
+            let metres_per_degree = 6371e3 * PI / 180f64;
+            let max_latitude = input.header.y_min.abs().max(input.header.y_max.abs());
+            Metric {
+                geographic: true,
+                scale_x: metres_per_degree * max_latitude.to_radians().cos().max(0.01),
+                scale_y: metres_per_degree,
+            }
+        } else {
+            Metric {
+                geographic: false,
+                scale_x: 1f64,
+                scale_y: 1f64,
+            }
+        }
+    }
+
+    fn distance(&self, x1: f64, y1: f64, x2: f64, y2: f64) -> f64 {
+        if self.geographic {
+            haversine_distance((y1, x1), (y2, x2))
+        } else {
+            ((x2 - x1) * (x2 - x1) + (y2 - y1) * (y2 - y1)).sqrt()
+        }
+    }
+
+    fn search_coordinates(&self, x: f64, y: f64) -> (f64, f64) {
+        (x * self.scale_x, y * self.scale_y)
+    }
+
+    /// The extent, in map units, of a distance in the x and y directions.
+    fn margin(&self, distance: f64) -> (f64, f64) {
+        (distance / self.scale_x, distance / self.scale_y)
+    }
+}
+
+/// Builds a structure for finding the points within `radius` of a location. The search radius is
+/// slightly enlarged for geographic coordinates, and so the distances of the points found must
+/// still be checked.
+fn build_index(points: &[WeightedPoint], metric: &Metric, radius: f64) -> FixedRadiusSearch2D<usize> {
+    let search_radius = if metric.geographic { radius * 1.01 } else { radius };
+    let mut index = FixedRadiusSearch2D::new(search_radius, DistanceMetric::SquaredEuclidean);
+    for (i, p) in points.iter().enumerate() {
+        let (sx, sy) = metric.search_coordinates(p.x, p.y);
+        index.insert(sx, sy, i);
+    }
+    index
+}
+
+/// Estimates the bandwidth of a Gaussian kernel using Silverman's rule of thumb, or returns `None`
+/// if the points are all coincident.
+fn silverman_bandwidth(points: &[WeightedPoint], metric: &Metric) -> Option<f64> {
+    let total_weight: f64 = points.iter().map(|p| p.weight).sum();
+    let mean_x = points.iter().map(|p| p.weight * p.x).sum::<f64>() / total_weight;
+    let mean_y = points.iter().map(|p| p.weight * p.y).sum::<f64>() / total_weight;
+    let mut distances: Vec<(f64, f64)> = points
+        .iter()
+        .map(|p| (metric.distance(mean_x, mean_y, p.x, p.y), p.weight))
+        .collect();
+
+    // For a bivariate normal distribution, the standard distance is the standard deviation times
+    // the square root of two, and the median distance is the standard deviation times the square
+    // root of 2 ln 2.
+    let standard_distance =
+        (distances.iter().map(|(d, w)| w * d * d).sum::<f64>() / total_weight).sqrt();
+    distances.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+    let mut cumulative_weight = 0f64;
+    let mut median_distance = 0f64;
+    for (d, w) in &distances {
+        cumulative_weight += w;
+        if cumulative_weight >= total_weight / 2f64 {
+            median_distance = *d;
+            break;
+        }
+    }
+    let mut sigma = standard_distance / 2f64.sqrt();
+    if median_distance > 0f64 {
+        sigma = sigma.min(median_distance / (2f64 * 2f64.ln()).sqrt());
+    }
+    if sigma > 0f64 && sigma.is_finite() {
+        Some(sigma * total_weight.powf(-1f64 / 6f64))
+    } else {
+        None
+    }
+}
+
+/// Estimates the bandwidth of a Gaussian kernel by likelihood cross-validation, searching a range
+/// of bandwidths around the Silverman estimate. Also returns whether the estimate lies at either
+/// limit of the range searched.
+fn cross_validation_bandwidth(
+    points: &Arc<Vec<WeightedPoint>>,
+    metric: &Metric,
+    silverman: f64,
+    num_procs: isize,
+    cancel: &CancellationToken,
+) -> Result<(f64, bool), Error> {
+    // The nearest neighbour of each point, which is used in place of the truncated kernels of the
+    // other points for any point that has no other points within the kernel's support.
+    let mut tree = KdTree::with_capacity(2, 64);
+    for (i, p) in points.iter().enumerate() {
+        let (sx, sy) = metric.search_coordinates(p.x, p.y);
+        tree.add([sx, sy], i).unwrap();
+    }
+    let nearest: Vec<Option<(f64, f64)>> = points
+        .iter()
+        .enumerate()
+        .map(|(i, p)| {
+            let (sx, sy) = metric.search_coordinates(p.x, p.y);
+            tree.nearest(&[sx, sy], 2, &squared_euclidean)
+                .unwrap()
+                .into_iter()
+                .find(|(_, j)| **j != i)
+                .map(|(_, j)| {
+                    let q = &points[*j];
+                    (metric.distance(p.x, p.y, q.x, q.y), q.weight)
+                })
+        })
+        .collect();
+    let nearest = Arc::new(nearest);
+    let score = |h: f64| -> Result<f64, Error> {
+        cross_validation_score(points, &nearest, metric, h, num_procs, cancel)
+    };
+
+    // A coarse search over log-spaced bandwidths, followed by a golden-section search around the
+    // best of them.
+    let num_steps = 24;
+    let (low, high) = ((silverman / 30f64).ln(), (silverman * 10f64).ln());
+    let step = (high - low) / num_steps as f64;
+    let mut best = (f64::NEG_INFINITY, 0usize);
+    for k in 0..=num_steps {
+        let value = score((low + k as f64 * step).exp())?;
+        if value > best.0 {
+            best = (value, k);
+        }
+    }
+    if best.0 == f64::NEG_INFINITY {
+        return Ok((silverman, false));
+    }
+    let at_limit = best.1 == 0 || best.1 == num_steps;
+
+    let ratio = (5f64.sqrt() - 1f64) / 2f64;
+    let mut a = low + best.1.saturating_sub(1) as f64 * step;
+    let mut b = low + (best.1 + 1).min(num_steps) as f64 * step;
+    let mut c = b - ratio * (b - a);
+    let mut d = a + ratio * (b - a);
+    let mut score_c = score(c.exp())?;
+    let mut score_d = score(d.exp())?;
+    for _ in 0..15 {
+        if score_c > score_d {
+            b = d;
+            d = c;
+            score_d = score_c;
+            c = b - ratio * (b - a);
+            score_c = score(c.exp())?;
+        } else {
+            a = c;
+            c = d;
+            score_c = score_d;
+            d = a + ratio * (b - a);
+            score_d = score(d.exp())?;
+        }
+    }
+    Ok((((a + b) / 2f64).exp(), at_limit))
+}
+
+/// The weighted log-likelihood of the points under leave-one-out Gaussian kernel density
+/// estimates with bandwidth `h`.
+fn cross_validation_score(
+    points: &Arc<Vec<WeightedPoint>>,
+    nearest: &Arc<Vec<Option<(f64, f64)>>>,
+    metric: &Metric,
+    h: f64,
+    num_procs: isize,
+    cancel: &CancellationToken,
+) -> Result<f64, Error> {
+    let radius = h * Kernel::Gaussian.support();
+    let index = Arc::new(build_index(points, metric, radius));
+    let total_weight: f64 = points.iter().map(|p| p.weight).sum();
+    let (tx, rx) = mpsc::channel();
+    for tid in 0..num_procs {
+        let points = points.clone();
+        let nearest = nearest.clone();
+        let index = index.clone();
+        let metric = *metric;
+        let tx = tx.clone();
+        progress::spawn(move || {
+            let mut score = 0f64;
+            for i in (0..points.len()).filter(|i| *i as isize % num_procs == tid) {
+                let p = &points[i];
+                if p.weight == 0f64 {
+                    continue;
+                }
+                let (sx, sy) = metric.search_coordinates(p.x, p.y);
+                let mut density = 0f64;
+                for (j, _) in index.search(sx, sy) {
+                    if j != i {
+                        let d = metric.distance(p.x, p.y, points[j].x, points[j].y);
+                        if d <= radius {
+                            density += points[j].weight * Kernel::Gaussian.value(d / h);
+                        }
+                    }
+                }
+                if density == 0f64 {
+                    if let Some((d, weight)) = nearest[i] {
+                        density = weight * Kernel::Gaussian.value(d / h);
+                    }
+                }
+                density /= h * h * (total_weight - p.weight);
+                score += if density > 0f64 && density.is_finite() {
+                    p.weight * density.ln()
+                } else {
+                    f64::NEG_INFINITY
+                };
+            }
+            let _ = tx.send(score);
+        });
+    }
+    let mut score = 0f64;
+    for _ in 0..num_procs {
+        score += cancel.recv(&rx)?;
+    }
+    Ok(score)
+}
@@ -0,0 +1,1169 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: Dr. John Lindsay
+Created: 18/10/2026
+Last Modified: 18/10/2026
+License: MIT
+*/
+
+use whitebox_raster::*;
+use whitebox_common::algorithms::{
+    empirical_variogram, fit_variogram, VariogramLag, VariogramModel, VariogramModelType,
+};
+use whitebox_common::rendering::html::*;
+use whitebox_common::rendering::LineGraph;
+use whitebox_common::structures::Point2D;
+use whitebox_common::progress;
+use whitebox_common::random;
+use crate::tools::*;
+use whitebox_vector::{FieldData, ShapeType, ShapeTypeDimension, Shapefile};
+use kdtree::distance::squared_euclidean;
+use kdtree::KdTree;
+use nalgebra::{DMatrix, DVector};
+use num_cpus;
+use rand::prelude::*;
+use std::cmp::Ordering;
+use std::env;
+use std::f64;
+use std::fs::File;
+use std::io::prelude::*;
+use std::io::BufWriter;
+use std::io::{Error, ErrorKind};
+use std::path;
+use std::sync::mpsc;
+use std::sync::Arc;
+
+/// The maximum number of points used to calculate the empirical variogram.
+const MAX_VARIOGRAM_POINTS: usize = 2000;
+
+/// This tool interpolates vector points (`--input`) into a raster surface (`--output`) using
+/// [kriging](https://en.wikipedia.org/wiki/Kriging), a geostatistical method that estimates the value at each
+/// grid cell as the weighted average of the values at the nearby points that has the minimum expected
+/// squared error, given a model of the spatial correlation of the values. The interpolated values are either
+/// those of a numeric attribute field (`--field`) or the z-coordinates of the points (`--use_z`).
+///
+/// The spatial correlation is modelled by a variogram, which describes how the variance of the difference
+/// between two values increases with the distance between them. The tool first calculates the empirical
+/// variogram, by grouping the pairs of points into a number of lags (`--num_lags`) of equal width, up to a
+/// maximum distance (`--max_distance`), which is by default one third of the diagonal of the points' extent.
+/// If there are more than 2000 points, the empirical variogram is calculated from a random sample of 2000 of
+/// them, which is the same in every run if a random seed (`--seed`) is given. A variogram model (`--model`)
+/// is then fitted to the empirical variogram, by weighted least squares, weighting each lag by its number of
+/// pairs of points divided by its squared distance.
+/// The available models are 'spherical', 'exponential', 'gaussian', 'matern', and 'nugget', a pure nugget
+/// effect that models spatially uncorrelated values. The Matérn model is fitted with a smoothness of 0.5,
+/// 1.5, and 2.5, and the best-fitting of these is used. Each model, other than the pure nugget effect,
+/// includes a nugget, a partial sill, and a range. For the exponential, Gaussian, and Matérn models, the range
+/// is a scale parameter, and the practical range, at which the semivariance reaches 95% of the sill, is
+/// about three (exponential), 1.7 (Gaussian), or four to six (Matérn) times larger. By default ('auto'),
+/// each of the models is fitted and the model with the smallest weighted residual sum of squares is used.
+/// The fitted model is reported, stored in the output's metadata, and, optionally, plotted along with the
+/// empirical variogram in an HTML report (`--report`).
+///
+/// Three kriging modes (`--mode`) are available. 'ordinary' kriging assumes that the mean of the values is
+/// constant, but unknown, within the neighbourhood of each grid cell. 'universal' kriging instead assumes
+/// that the mean varies as a 'linear' or 'quadratic' polynomial trend (`--trend`) of the coordinates, and
+/// 'external_drift' kriging assumes that the mean is a linear function of an auxiliary variable, the drift,
+/// which is read from a raster (`--drift`) that must cover the points and the output grid, e.g. an elevation
+/// model used as the drift for interpolating temperatures. In the universal and external drift modes, the
+/// variogram is calculated and fitted from the residuals of a global least-squares fit of the trend, or of
+/// the drift, to the values.
+///
+/// Each grid cell is estimated from its nearest points (`--num_points`). Coincident points are merged, and
+/// their values are averaged. Optionally, the kriging variance, i.e. the expected squared error of the
+/// estimate according to the variogram model, is output as a second raster (`--variance`). The kriging
+/// variance depends only on the configuration of the points, and not on their values, and is smallest near
+/// the points. If the kriging system of a grid cell cannot be solved, e.g. because the drift values of its
+/// neighbouring points are all equal, the grid cell is estimated by ordinary kriging instead.
+///
+/// The output raster's resolution and extent are either those of an optional base raster (`--base`), or are
+/// set by a grid cell size (`--cell_size`), in map units, in which case the raster covers the extent of
+/// the points. In the external drift mode, the output raster has the resolution and extent of the drift
+/// raster if neither of these is specified. Distances are measured in map units, so points in geographic
+/// coordinates should be projected before they are interpolated.
+///
+/// # Reference
+/// Cressie, N. (1985). Fitting variogram models by weighted least squares. *Journal of the International
+/// Association for Mathematical Geology*, 17(5), 563-586.
+///
+/// Webster, R., and Oliver, M. A. (2007). *Geostatistics for Environmental Scientists*, 2nd ed. Wiley,
+/// Chichester.
+///
+/// # See Also
+/// `IdwInterpolation`, `NaturalNeighbourInterpolation`, `RadialBasisFunctionInterpolation`,
+/// `TINGridding`
+pub struct KrigingInterpolation {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl KrigingInterpolation {
+    pub fn new() -> KrigingInterpolation {
+        // public constructor
+        let name = "KrigingInterpolation".to_string();
+        let toolbox = "GIS Analysis".to_string();
+        let description =
+            "Interpolates vector points into a raster surface by kriging, with automatic variogram fitting."
+                .to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input Vector Points File".to_owned(),
+            flags: vec!["-i".to_owned(), "--input".to_owned()],
+            description: "Input vector points file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Vector(
+                VectorGeometryType::Point,
+            )),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Field Name".to_owned(),
+            flags: vec!["--field".to_owned()],
+            description: "Input numeric field holding the values to interpolate.".to_owned(),
+            parameter_type: ParameterType::VectorAttributeField(
+                AttributeType::Number,
+                "--input".to_string(),
+            ),
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Use z-coordinate instead of field?".to_owned(),
+            flags: vec!["--use_z".to_owned()],
+            description: "Interpolate the z-coordinates of the points instead of a field?"
+                .to_owned(),
+            parameter_type: ParameterType::Boolean,
+            default_value: Some("false".to_string()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output Raster File".to_owned(),
+            flags: vec!["-o".to_owned(), "--output".to_owned()],
+            description: "Output raster file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output Kriging Variance File (optional)".to_owned(),
+            flags: vec!["--variance".to_owned()],
+            description: "Optional output kriging variance raster file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output Variogram Report File (optional)".to_owned(),
+            flags: vec!["--report".to_owned()],
+            description: "Optional output HTML file plotting the empirical and fitted variograms."
+                .to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Html),
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Kriging Mode".to_owned(),
+            flags: vec!["--mode".to_owned()],
+            description: "Kriging mode; options are 'ordinary', 'universal', and 'external_drift'."
+                .to_owned(),
+            parameter_type: ParameterType::OptionList(vec![
+                "ordinary".to_owned(),
+                "universal".to_owned(),
+                "external_drift".to_owned(),
+            ]),
+            default_value: Some("ordinary".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Trend (Universal Kriging)".to_owned(),
+            flags: vec!["--trend".to_owned()],
+            description: "Polynomial trend of the coordinates used in universal kriging; options are 'linear' and 'quadratic'."
+                .to_owned(),
+            parameter_type: ParameterType::OptionList(vec![
+                "linear".to_owned(),
+                "quadratic".to_owned(),
+            ]),
+            default_value: Some("linear".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Drift Raster File (optional)".to_owned(),
+            flags: vec!["--drift".to_owned()],
+            description: "Input drift raster file, required for kriging with external drift."
+                .to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Variogram Model".to_owned(),
+            flags: vec!["--model".to_owned()],
+            description: "Variogram model; options are 'auto', 'spherical', 'exponential', 'gaussian', 'matern', and 'nugget'."
+                .to_owned(),
+            parameter_type: ParameterType::OptionList(vec![
+                "auto".to_owned(),
+                "spherical".to_owned(),
+                "exponential".to_owned(),
+                "gaussian".to_owned(),
+                "matern".to_owned(),
+                "nugget".to_owned(),
+            ]),
+            default_value: Some("auto".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Number of Lags".to_owned(),
+            flags: vec!["--num_lags".to_owned()],
+            description: "Number of lags of the empirical variogram.".to_owned(),
+            parameter_type: ParameterType::Integer,
+            default_value: Some("15".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Maximum Lag Distance (optional)".to_owned(),
+            flags: vec!["--max_distance".to_owned()],
+            description: "Optional maximum distance of the empirical variogram; one third of the diagonal of the points' extent by default."
+                .to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Number of Neighbouring Points".to_owned(),
+            flags: vec!["--num_points".to_owned()],
+            description: "Number of nearest points used to estimate each grid cell.".to_owned(),
+            parameter_type: ParameterType::Integer,
+            default_value: Some("16".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Cell Size (optional)".to_owned(),
+            flags: vec!["--cell_size".to_owned()],
+            description: "Optional output raster cell size, in map units; not used when a base raster is specified."
+                .to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Base Raster File (optional)".to_owned(),
+            flags: vec!["--base".to_owned()],
+            description: "Optional input base raster file, which sets the output raster's extent and resolution."
+                .to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(seed_parameter());
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut parent = env::current_exe().unwrap();
+        parent.pop();
+        let p = format!("{}", parent.display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(
+            ">>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=wells.shp --field=DEPTH -o=depth.tif --variance=depth_var.tif --report=variogram.html --cell_size=25.0
+>>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=stations.shp --field=TEMP -o=temp.tif --mode=external_drift --drift=dem.tif --model=exponential",
+            short_exe, name
+        ).replace("*", &sep);
+
+        KrigingInterpolation {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for KrigingInterpolation {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        match serde_json::to_string(&self.parameters) {
+            Ok(json_str) => return format!("{{\"parameters\":{}}}", json_str),
+            Err(err) => return format!("{:?}", err),
+        }
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+        cancel: &CancellationToken,
+    ) -> Result<(), Error> {
+        let args = ParsedArgs::parse(&self.get_tool_parameters(), &args, working_directory)?;
+        self.run_with_parsed_args(args, working_directory, verbose, cancel)
+    }
+
+    fn run_with_parsed_args<'a>(
+        &self,
+        args: ParsedArgs,
+        _working_directory: &'a str,
+        verbose: bool,
+        cancel: &CancellationToken,
+    ) -> Result<(), Error> {
+        let input_file = args.get_file("input").unwrap();
+        let field_name = args.get_string("field").filter(|s| !s.is_empty());
+        let use_z = args.get_bool("use_z");
+        let output_file = args.get_file("output").unwrap();
+        let variance_file = args.get_file("variance");
+        let report_file = args.get_file("report");
+        let drift = match args.get_string("mode").unwrap_or_default().as_str() {
+            "universal" => {
+                if args.get_string("trend").unwrap_or_default() == "quadratic" {
+                    Drift::Quadratic
+                } else {
+                    Drift::Linear
+                }
+            }
+            "external_drift" => Drift::External,
+            _ => Drift::Constant,
+        };
+        let drift_file = args.get_file("drift");
+        let model_types = match args.get_string("model").unwrap_or_default().as_str() {
+            "spherical" => vec![VariogramModelType::Spherical],
+            "exponential" => vec![VariogramModelType::Exponential],
+            "gaussian" => vec![VariogramModelType::Gaussian],
+            "matern" => vec![
+                VariogramModelType::Matern(0.5),
+                VariogramModelType::Matern(1.5),
+                VariogramModelType::Matern(2.5),
+            ],
+            "nugget" => vec![VariogramModelType::Nugget],
+            // The pure nugget model comes first, so that it is preferred to an equivalent model
+            // with a partial sill of zero.
+            _ => vec![
+                VariogramModelType::Nugget,
+                VariogramModelType::Spherical,
+                VariogramModelType::Exponential,
+                VariogramModelType::Gaussian,
+                VariogramModelType::Matern(1.5),
+                VariogramModelType::Matern(2.5),
+            ],
+        };
+        let num_lags = args.get_integer("num_lags").unwrap_or(15);
+        let max_distance = args.get_float("max_distance");
+        let num_points = args.get_integer("num_points").unwrap_or(16);
+        let cell_size = args.get_float("cell_size").unwrap_or(0f64);
+        let base_file = args.get_file("base");
+        let seed = args.get_seed()?;
+
+        if !use_z && field_name.is_none() {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Either a field (--field) or the z-coordinates (--use_z) must be interpolated.",
+            ));
+        }
+        if drift == Drift::External && drift_file.is_none() {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "A drift raster (--drift) must be specified for kriging with external drift.",
+            ));
+        }
+        if num_lags < 1 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The number of lags (--num_lags) must be at least one.",
+            ));
+        }
+        if let Some(max_distance) = max_distance {
+            if max_distance <= 0f64 {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    "The maximum lag distance (--max_distance) must be larger than zero.",
+                ));
+            }
+        }
+        if num_points <= drift.num_terms() as i64 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!(
+                    "The number of neighbouring points (--num_points) must be at least {} in this kriging mode.",
+                    drift.num_terms() + 1
+                ),
+            ));
+        }
+        if base_file.is_none() && cell_size <= 0f64 && drift != Drift::External {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Either a positive cell size (--cell_size) or a base raster (--base) must be specified.",
+            ));
+        }
+        let num_lags = num_lags as usize;
+        let num_points = num_points as usize;
+
+        let mut progress: usize;
+        let mut old_progress: usize = 1;
+
+        let start = Instant::now();
+
+        if verbose {
+            let tool_name = self.get_tool_name();
+            progress::welcome(&tool_name);
+        }
+
+        if verbose {
+            progress::message("Reading data...")
+        };
+
+        let input = Shapefile::read(&input_file)?;
+        if input.header.shape_type.base_shape_type() != ShapeType::Point
+            && input.header.shape_type.base_shape_type() != ShapeType::MultiPoint
+        {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The input vector data must be of POINT or MULTIPOINT base shape type.",
+            ));
+        }
+        if use_z && input.header.shape_type.dimension() != ShapeTypeDimension::Z {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The input vector data must be of PointZ or MultiPointZ shape type to interpolate the z-coordinates.",
+            ));
+        }
+        let field_index = match &field_name {
+            Some(name) if !use_z => match input.attributes.get_field_num(name) {
+                Some(i) if input.attributes.is_field_numeric(i) => Some(i),
+                Some(_) => {
+                    return Err(Error::new(
+                        ErrorKind::InvalidInput,
+                        format!("The field '{}' is not numeric.", name),
+                    ));
+                }
+                None => {
+                    return Err(Error::new(
+                        ErrorKind::InvalidInput,
+                        format!(
+                            "The field '{}' does not exist in the input attribute table.",
+                            name
+                        ),
+                    ));
+                }
+            },
+            _ => None,
+        };
+
+        let drift_raster = match &drift_file {
+            Some(drift_file) if drift == Drift::External => Some(Raster::new(drift_file, "r")?),
+            _ => None,
+        };
+        let drift_value = |x: f64, y: f64| -> Option<f64> {
+            match &drift_raster {
+                Some(raster) => {
+                    let value =
+                        raster.get_value(raster.get_row_from_y(y), raster.get_column_from_x(x));
+                    if value != raster.configs.nodata {
+                        Some(value)
+                    } else {
+                        None
+                    }
+                }
+                None => Some(0f64),
+            }
+        };
+
+        let mut points: Vec<DataPoint> = vec![];
+        let mut num_null_values = 0;
+        let mut num_without_drift = 0;
+        for record_num in 0..input.num_records {
+            let record = input.get_record(record_num);
+            let field_value = match field_index {
+                Some(i) => match input.attributes.get_record(record_num)[i] {
+                    FieldData::Int(v) => Some(v as f64),
+                    FieldData::Real(v) => Some(v),
+                    _ => None,
+                },
+                None => None,
+            };
+            for (i, p) in record.points.iter().enumerate() {
+                let value = if use_z {
+                    record.z_array.get(i).copied()
+                } else {
+                    field_value
+                };
+                let value = match value {
+                    Some(value) => value,
+                    None => {
+                        num_null_values += 1;
+                        continue;
+                    }
+                };
+                match drift_value(p.x, p.y) {
+                    Some(d) => points.push(DataPoint {
+                        x: p.x,
+                        y: p.y,
+                        value: value,
+                        drift: d,
+                    }),
+                    None => num_without_drift += 1,
+                }
+            }
+        }
+        if verbose && num_null_values > 0 {
+            progress::message(format!(
+                "Warning: {} points with null values were ignored.",
+                num_null_values
+            ));
+        }
+        if verbose && num_without_drift > 0 {
+            progress::message(format!(
+                "Warning: {} points outside of the drift raster, or on its nodata cells, were ignored.",
+                num_without_drift
+            ));
+        }
+
+        let mut points = merge_coincident_points(points);
+        if points.len() <= drift.num_terms() {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!(
+                    "At least {} points at distinct locations are required in this kriging mode.",
+                    drift.num_terms() + 1
+                ),
+            ));
+        }
+
+        // Standardize the drift values, so that the kriging systems are well conditioned.
+        let (drift_mean, drift_scale) = if drift == Drift::External {
+            let n = points.len() as f64;
+            let mean = points.iter().map(|p| p.drift).sum::<f64>() / n;
+            let sd = (points
+                .iter()
+                .map(|p| (p.drift - mean) * (p.drift - mean))
+                .sum::<f64>()
+                / n)
+                .sqrt();
+            if sd <= 0f64 {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    "The drift raster's values do not vary at the points.",
+                ));
+            }
+            for p in points.iter_mut() {
+                p.drift = (p.drift - mean) / sd;
+            }
+            (mean, sd)
+        } else {
+            (0f64, 1f64)
+        };
+
+        ///////////////////////////////////////
+        // Calculate and fit the variogram //
+        ///////////////////////////////////////
+        if verbose {
+            progress::message("Fitting the variogram...");
+        }
+        let mut min_x = f64::INFINITY;
+        let mut max_x = f64::NEG_INFINITY;
+        let mut min_y = f64::INFINITY;
+        let mut max_y = f64::NEG_INFINITY;
+        for p in &points {
+            min_x = min_x.min(p.x);
+            max_x = max_x.max(p.x);
+            min_y = min_y.min(p.y);
+            max_y = max_y.max(p.y);
+        }
+        let diagonal =
+            ((max_x - min_x) * (max_x - min_x) + (max_y - min_y) * (max_y - min_y)).sqrt();
+        let max_distance = max_distance.unwrap_or(diagonal / 3f64);
+
+        // In the universal and external drift modes, the variogram is that of the residuals from a
+        // global fit of the trend or drift.
+        let residuals: Vec<f64> = if drift == Drift::Constant {
+            points.iter().map(|p| p.value).collect()
+        } else {
+            let centre_x = (min_x + max_x) / 2f64;
+            let centre_y = (min_y + max_y) / 2f64;
+            let scale = diagonal.max(f64::MIN_POSITIVE);
+            let terms: Vec<Vec<f64>> = points
+                .iter()
+                .map(|p| drift.terms((p.x - centre_x) / scale, (p.y - centre_y) / scale, p.drift))
+                .collect();
+            match fit_trend(&terms, &points) {
+                Some(coefficients) => points
+                    .iter()
+                    .zip(terms.iter())
+                    .map(|(p, t)| {
+                        p.value
+                            - t.iter()
+                                .zip(coefficients.iter())
+                                .map(|(a, b)| a * b)
+                                .sum::<f64>()
+                    })
+                    .collect(),
+                None => {
+                    return Err(Error::new(
+                        ErrorKind::InvalidInput,
+                        "The trend cannot be fitted to the points, e.g. because they are collinear.",
+                    ));
+                }
+            }
+        };
+
+        let mut sample: Vec<usize> = (0..points.len()).collect();
+        if points.len() > MAX_VARIOGRAM_POINTS {
+            let mut rng = random::new_rng(random::tool_seed(seed), 0);
+            sample.shuffle(&mut rng);
+            sample.truncate(MAX_VARIOGRAM_POINTS);
+        }
+        let locations: Vec<Point2D> = sample
+            .iter()
+            .map(|i| Point2D::new(points[*i].x, points[*i].y))
+            .collect();
+        let values: Vec<f64> = sample.iter().map(|i| residuals[*i]).collect();
+        let lags = empirical_variogram(
+            &locations,
+            &values,
+            max_distance / num_lags as f64,
+            num_lags,
+        );
+
+        let fits: Vec<(VariogramModel, f64)> = model_types
+            .iter()
+            .filter_map(|model_type| fit_variogram(&lags, *model_type))
+            .collect();
+        let model = match fits
+            .iter()
+            .min_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(Ordering::Equal))
+        {
+            Some((model, _)) if model.sill() > 0f64 => *model,
+            Some(_) => {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    "The variogram has a sill of zero, i.e. the values do not vary.",
+                ));
+            }
+            None => {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    "The variogram cannot be fitted because no pairs of points are within the maximum lag distance (--max_distance).",
+                ));
+            }
+        };
+        if verbose {
+            progress::message(format!(
+                "Variogram model: {} (nugget={}, partial sill={}, range={})",
+                model.model_type, model.nugget, model.partial_sill, model.range
+            ));
+        }
+
+        ////////////////////////////
+        // Interpolate the points //
+        ////////////////////////////
+        let nodata = -32768f64;
+        let mut output = match (&base_file, &drift_raster) {
+            (Some(base_file), _) => {
+                let base = Raster::new(base_file, "r")?;
+                Raster::initialize_using_file(&output_file, &base)
+            }
+            (None, Some(drift_raster)) if cell_size <= 0f64 => {
+                Raster::initialize_using_file(&output_file, drift_raster)
+            }
+            _ => {
+                let west = input.header.x_min;
+                let north = input.header.y_max;
+                let rows = ((north - input.header.y_min) / cell_size).ceil().max(1f64) as usize;
+                let columns = ((input.header.x_max - west) / cell_size).ceil().max(1f64) as usize;
+
+                let mut configs = RasterConfigs {
+                    ..Default::default()
+                };
+                configs.rows = rows;
+                configs.columns = columns;
+                configs.north = north;
+                configs.south = north - rows as f64 * cell_size;
+                configs.east = west + columns as f64 * cell_size;
+                configs.west = west;
+                configs.resolution_x = cell_size;
+                configs.resolution_y = cell_size;
+                configs.nodata = nodata;
+                configs.data_type = DataType::F32;
+                configs.photometric_interp = PhotometricInterpretation::Continuous;
+                configs.projection = input.projection.clone();
+
+                Raster::initialize_using_config(&output_file, &configs)
+            }
+        };
+        output.configs.data_type = DataType::F32;
+        output.configs.nodata = nodata;
+        output.configs.photometric_interp = PhotometricInterpretation::Continuous;
+        let rows = output.configs.rows as isize;
+        let columns = output.configs.columns as isize;
+        let west = output.configs.west;
+        let north = output.configs.north;
+        let res_x = output.configs.resolution_x;
+        let res_y = output.configs.resolution_y;
+
+        let mut tree = KdTree::with_capacity(2, 64);
+        for (i, p) in points.iter().enumerate() {
+            tree.add([p.x, p.y], i).unwrap();
+        }
+        let tree = Arc::new(tree);
+        let points = Arc::new(points);
+        let drift_raster = Arc::new(drift_raster);
+        let num_neighbours = num_points.min(points.len());
+
+        let mut num_procs = num_cpus::get() as isize;
+        let configs = whitebox_common::configs::get_configs()?;
+        let max_procs = configs.max_procs;
+        if max_procs > 0 && max_procs < num_procs {
+            num_procs = max_procs;
+        }
+        let (tx, rx) = mpsc::channel();
+        for tid in 0..num_procs {
+            let tree = tree.clone();
+            let points = points.clone();
+            let drift_raster = drift_raster.clone();
+            let tx = tx.clone();
+            progress::spawn(move || {
+                for row in (0..rows).filter(|r| r % num_procs == tid) {
+                    let y = north - res_y / 2f64 - row as f64 * res_y;
+                    let mut estimates = vec![nodata; columns as usize];
+                    let mut variances = vec![nodata; columns as usize];
+                    let mut num_fallbacks = 0;
+                    for col in 0..columns {
+                        let x = west + res_x / 2f64 + col as f64 * res_x;
+                        let drift_at_cell = match &*drift_raster {
+                            Some(raster) => {
+                                let value = raster.get_value(
+                                    raster.get_row_from_y(y),
+                                    raster.get_column_from_x(x),
+                                );
+                                if value == raster.configs.nodata {
+                                    continue;
+                                }
+                                (value - drift_mean) / drift_scale
+                            }
+                            None => 0f64,
+                        };
+                        let neighbours: Vec<&DataPoint> = tree
+                            .nearest(&[x, y], num_neighbours, &squared_euclidean)
+                            .unwrap()
+                            .into_iter()
+                            .map(|(_, i)| &points[*i])
+                            .collect();
+                        let mut result = krige(&model, drift, &neighbours, x, y, drift_at_cell);
+                        if result.is_none() && drift != Drift::Constant {
+                            num_fallbacks += 1;
+                            result = krige(&model, Drift::Constant, &neighbours, x, y, 0f64);
+                        }
+                        if let Some((estimate, variance)) = result {
+                            estimates[col as usize] = estimate;
+                            variances[col as usize] = variance;
+                        }
+                    }
+                    if tx.send((row, estimates, variances, num_fallbacks)).is_err() {
+                        return;
+                    }
+                }
+            });
+        }
+
+        let mut variance_raster = match &variance_file {
+            Some(variance_file) => Some(Raster::initialize_using_file(variance_file, &output)),
+            None => None,
+        };
+        let mut num_fallbacks = 0;
+        for row in 0..rows {
+            let (r, estimates, variances, n) = cancel.recv(&rx)?;
+            output.set_row_data(r, estimates);
+            if let Some(variance_raster) = &mut variance_raster {
+                variance_raster.set_row_data(r, variances);
+            }
+            num_fallbacks += n;
+            if verbose {
+                progress = (100.0_f64 * (row + 1) as f64 / rows as f64) as usize;
+                if progress != old_progress {
+                    progress::update_stage("Kriging", progress);
+                    old_progress = progress;
+                }
+            }
+        }
+        if verbose && num_fallbacks > 0 {
+            progress::message(format!(
+                "Warning: {} grid cells with singular kriging systems were estimated by ordinary kriging.",
+                num_fallbacks
+            ));
+        }
+
+        let mode_name = match drift {
+            Drift::Constant => "ordinary",
+            Drift::Linear => "universal (linear trend)",
+            Drift::Quadratic => "universal (quadratic trend)",
+            Drift::External => "external drift",
+        };
+        let elapsed_time = get_formatted_elapsed_time(start);
+        let mut outputs = vec![&mut output];
+        if let Some(variance_raster) = &mut variance_raster {
+            outputs.push(variance_raster);
+        }
+        for raster in outputs {
+            raster.add_metadata_entry(format!(
+                "Created by whitebox_tools\' {} tool",
+                self.get_tool_name()
+            ));
+            raster.add_metadata_entry(format!("Input file: {}", input_file));
+            raster.add_metadata_entry(format!("Kriging mode: {}", mode_name));
+            raster.add_metadata_entry(format!("Variogram model: {}", model.model_type));
+            raster.add_metadata_entry(format!("Nugget: {}", model.nugget));
+            raster.add_metadata_entry(format!("Partial sill: {}", model.partial_sill));
+            raster.add_metadata_entry(format!("Range: {}", model.range));
+            raster.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
+        }
+
+        if verbose {
+            progress::message("Saving data...")
+        };
+        let _ = match output.write() {
+            Ok(_) => {
+                if verbose {
+                    progress::output_written(&output.file_name)
+                }
+            }
+            Err(e) => return Err(e),
+        };
+        if let Some(mut variance_raster) = variance_raster {
+            let _ = match variance_raster.write() {
+                Ok(_) => {
+                    if verbose {
+                        progress::output_written(&variance_raster.file_name)
+                    }
+                }
+                Err(e) => return Err(e),
+            };
+        }
+
+        if let Some(report_file) = report_file {
+            write_report(
+                &report_file,
+                &input_file,
+                mode_name,
+                &lags,
+                &fits,
+                &model,
+                max_distance,
+            )?;
+            if verbose {
+                progress::message(format!(
+                    "Please see {} for the variogram report.",
+                    report_file
+                ));
+            }
+        }
+
+        if verbose {
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
+        }
+
+        Ok(())
+    }
+}
+
+struct DataPoint {
+    x: f64,
+    y: f64,
+    value: f64,
+    drift: f64,
+}
+
+/// The drift, i.e. the model of the mean of the values, of a kriging mode.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Drift {
+    /// A constant mean, i.e. ordinary kriging.
+    Constant,
+    Linear,
+    Quadratic,
+    /// A linear function of an external drift variable.
+    External,
+}
+
+impl Drift {
+    fn num_terms(self) -> usize {
+        match self {
+            Drift::Constant => 1,
+            Drift::Linear => 3,
+            Drift::Quadratic => 6,
+            Drift::External => 2,
+        }
+    }
+
+    /// The drift functions at a location, given its scaled coordinates and external drift value.
+    fn terms(self, x: f64, y: f64, drift: f64) -> Vec<f64> {
+        match self {
+            Drift::Constant => vec![1f64],
+            Drift::Linear => vec![1f64, x, y],
+            Drift::Quadratic => vec![1f64, x, y, x * x, x * y, y * y],
+            Drift::External => vec![1f64, drift],
+        }
+    }
+}
+
+/// Merges points at the same location, which would make the kriging systems singular, averaging
+/// their values.
+fn merge_coincident_points(mut points: Vec<DataPoint>) -> Vec<DataPoint> {
+    points.sort_by(|a, b| {
+        a.x.partial_cmp(&b.x)
+            .unwrap_or(Ordering::Equal)
+            .then(a.y.partial_cmp(&b.y).unwrap_or(Ordering::Equal))
+    });
+    let mut merged: Vec<DataPoint> = Vec::with_capacity(points.len());
+    let mut count = 0f64;
+    for p in points {
+        match merged.last_mut() {
+            Some(last) if last.x == p.x && last.y == p.y => {
+                count += 1f64;
+                last.value += (p.value - last.value) / count;
+                last.drift += (p.drift - last.drift) / count;
+            }
+            _ => {
+                merged.push(p);
+                count = 1f64;
+            }
+        }
+    }
+    merged
+}
+
+/// Fits the coefficients of the drift functions to the values by least squares.
+fn fit_trend(terms: &[Vec<f64>], points: &[DataPoint]) -> Option<Vec<f64>> {
+    let p = terms[0].len();
+    let f = DMatrix::from_fn(terms.len(), p, |i, j| terms[i][j]);
+    let z = DVector::from_iterator(points.len(), points.iter().map(|p| p.value));
+    let coefficients = (f.transpose() * &f).lu().solve(&(f.transpose() * z))?;
+    Some(coefficients.iter().copied().collect())
+}
+
+/// Solves the kriging system of a grid cell, in covariance form, returning the estimate and the
+/// kriging variance. The coordinates and drift values are taken relative to those of the grid
+/// cell, and the coordinates are scaled by the distance to the furthest neighbour, so that the
+/// system is well conditioned.
+fn krige(
+    model: &VariogramModel,
+    drift: Drift,
+    neighbours: &[&DataPoint],
+    x: f64,
+    y: f64,
+    drift_at_cell: f64,
+) -> Option<(f64, f64)> {
+    let n = neighbours.len();
+    let p = drift.num_terms();
+    let scale = neighbours
+        .iter()
+        .map(|q| ((q.x - x) * (q.x - x) + (q.y - y) * (q.y - y)).sqrt())
+        .fold(0f64, f64::max)
+        .max(f64::MIN_POSITIVE);
+    let mut a = DMatrix::zeros(n + p, n + p);
+    let mut b = DVector::zeros(n + p);
+    for i in 0..n {
+        for j in i..n {
+            let d = ((neighbours[i].x - neighbours[j].x) * (neighbours[i].x - neighbours[j].x)
+                + (neighbours[i].y - neighbours[j].y) * (neighbours[i].y - neighbours[j].y))
+                .sqrt();
+            let c = model.covariance(d);
+            a[(i, j)] = c;
+            a[(j, i)] = c;
+        }
+        let terms = drift.terms(
+            (neighbours[i].x - x) / scale,
+            (neighbours[i].y - y) / scale,
+            neighbours[i].drift - drift_at_cell,
+        );
+        for (k, t) in terms.iter().enumerate() {
+            a[(i, n + k)] = *t;
+            a[(n + k, i)] = *t;
+        }
+        let d = ((neighbours[i].x - x) * (neighbours[i].x - x)
+            + (neighbours[i].y - y) * (neighbours[i].y - y))
+            .sqrt();
+        b[i] = model.covariance(d);
+    }
+    // The drift functions are zero at the grid cell, other than the constant.
+    b[n] = 1f64;
+
+    let solution = a.lu().solve(&b)?;
+    if solution.iter().any(|v| !v.is_finite()) {
+        return None;
+    }
+    let estimate: f64 = (0..n).map(|i| solution[i] * neighbours[i].value).sum();
+    let variance = model.sill() - (0..n + p).map(|i| solution[i] * b[i]).sum::<f64>();
+    Some((estimate, variance.max(0f64)))
+}
+
+fn write_report(
+    report_file: &str,
+    input_file: &str,
+    mode_name: &str,
+    lags: &[VariogramLag],
+    fits: &[(VariogramModel, f64)],
+    model: &VariogramModel,
+    max_distance: f64,
+) -> Result<(), Error> {
+    let f = File::create(report_file)?;
+    let mut writer = BufWriter::new(f);
+
+    writer.write_all(&r#"<!DOCTYPE html PUBLIC \"-//W3C//DTD XHTML 1.0 Transitional//EN\" \"http://www.w3.org/TR/xhtml1/DTD/xhtml1-transitional.dtd\">
+    <html>
+        <head>
+            <meta content=\"text/html; charset=UTF-8\" http-equiv=\"content-type\">
+            <title>Kriging Interpolation</title>"#.as_bytes())?;
+
+    // get the style sheet
+    writer.write_all(&get_css().as_bytes())?;
+
+    writer.write_all(
+        &r#"
+        </head>
+        <body>
+            <h1>Kriging Variogram Report</h1>
+            <p>"#
+            .as_bytes(),
+    )?;
+    writer.write_all(&format!("<strong>Input</strong>: {}<br>", input_file).as_bytes())?;
+    writer.write_all(&format!("<strong>Kriging mode</strong>: {}<br>", mode_name).as_bytes())?;
+    writer.write_all(
+        &format!("<strong>Variogram model</strong>: {}<br>", model.model_type).as_bytes(),
+    )?;
+    writer.write_all(&format!("<strong>Nugget</strong>: {:.6}<br>", model.nugget).as_bytes())?;
+    writer.write_all(
+        &format!(
+            "<strong>Partial sill</strong>: {:.6}<br>",
+            model.partial_sill
+        )
+        .as_bytes(),
+    )?;
+    writer.write_all(&format!("<strong>Range</strong>: {:.6}<br>", model.range).as_bytes())?;
+    writer.write_all("</p>".as_bytes())?;
+
+    // The empirical variogram and the fitted model, sampled along the lag distances.
+    let num_samples = 50;
+    let model_x: Vec<f64> = (1..=num_samples)
+        .map(|k| k as f64 * max_distance / num_samples as f64)
+        .collect();
+    let model_y: Vec<f64> = model_x.iter().map(|h| model.semivariance(*h)).collect();
+    let graph = LineGraph {
+        parent_id: "graph".to_string(),
+        width: 700f64,
+        height: 450f64,
+        data_x: vec![lags.iter().map(|l| l.distance).collect(), model_x],
+        data_y: vec![lags.iter().map(|l| l.semivariance).collect(), model_y],
+        series_labels: vec![
+            "Empirical".to_string(),
+            format!("Model ({})", model.model_type),
+        ],
+        x_axis_label: "Distance".to_string(),
+        y_axis_label: "Semivariance".to_string(),
+        draw_points: true,
+        draw_gridlines: true,
+        draw_legend: true,
+        draw_grey_background: false,
+    };
+    writer.write_all(
+        &format!("<div id='graph' align=\"center\">{}</div>", graph.get_svg()).as_bytes(),
+    )?;
+
+    if fits.len() > 1 {
+        let s = "<p><table>
+        <caption>Fitted Variogram Models</caption>
+        <tr>
+            <th class=\"headerCell\">Model</th>
+            <th class=\"headerCell\">Nugget</th>
+            <th class=\"headerCell\">Partial Sill</th>
+            <th class=\"headerCell\">Range</th>
+            <th class=\"headerCell\">Weighted SSE</th>
+        </tr>";
+        writer.write_all(s.as_bytes())?;
+        for (fit, sse) in fits {
+            writer.write_all(
+                &format!(
+                    "<tr>
+                    <td>{}</td>
+                    <td class=\"numberCell\">{:.6}</td>
+                    <td class=\"numberCell\">{:.6}</td>
+                    <td class=\"numberCell\">{:.6}</td>
+                    <td class=\"numberCell\">{:.6}</td>
+                </tr>\n",
+                    fit.model_type, fit.nugget, fit.partial_sill, fit.range, sse
+                )
+                .as_bytes(),
+            )?;
+        }
+        writer.write_all("</table></p>".as_bytes())?;
+    }
+
+    let s = "<p><table>
+    <caption>Empirical Variogram</caption>
+    <tr>
+        <th class=\"headerCell\">Distance</th>
+        <th class=\"headerCell\">Semivariance</th>
+        <th class=\"headerCell\">Pairs</th>
+    </tr>";
+    writer.write_all(s.as_bytes())?;
+    for lag in lags {
+        writer.write_all(
+            &format!(
+                "<tr>
+                <td class=\"numberCell\">{:.6}</td>
+                <td class=\"numberCell\">{:.6}</td>
+                <td class=\"numberCell\">{}</td>
+            </tr>\n",
+                lag.distance, lag.semivariance, lag.num_pairs
+            )
+            .as_bytes(),
+        )?;
+    }
+    writer.write_all("</table></p>".as_bytes())?;
+
+    writer.write_all("</body>".as_bytes())?;
+    writer.write_all("</html>".as_bytes())?;
+
+    let _ = writer.flush();
+    Ok(())
+}
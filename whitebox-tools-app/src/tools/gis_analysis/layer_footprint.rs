@@ -9,6 +9,7 @@ License: MIT
 use whitebox_raster::*;
 use whitebox_common::algorithms::is_clockwise_order;
 use whitebox_common::structures::Point2D;
+use whitebox_common::progress;
 use crate::tools::*;
 use whitebox_vector::ShapefileGeometry;
 use whitebox_vector::*;
@@ -139,6 +140,7 @@ impl WhiteboxTool for LayerFootprint {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        _cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file: String = "".to_string();
         let mut output_file: String = "".to_string();
@@ -181,13 +183,7 @@ impl WhiteboxTool for LayerFootprint {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         if !input_file.contains(path::MAIN_SEPARATOR) && !input_file.contains("/") {
@@ -231,12 +227,12 @@ impl WhiteboxTool for LayerFootprint {
                 .add_record(vec![FieldData::Int(1i32)], false);
 
             if verbose {
-                println!("Saving data...")
+                progress::message("Saving data...")
             };
             let _ = match output.write() {
                 Ok(_) => {
                     if verbose {
-                        println!("Output file written")
+                        progress::output_written(&output.file_name)
                     }
                 }
                 Err(e) => return Err(e),
@@ -268,12 +264,12 @@ impl WhiteboxTool for LayerFootprint {
                 .add_record(vec![FieldData::Int(1i32)], false);
 
             if verbose {
-                println!("Saving data...")
+                progress::message("Saving data...")
             };
             let _ = match output.write() {
                 Ok(_) => {
                     if verbose {
-                        println!("Output file written")
+                        progress::output_written(&output.file_name)
                     }
                 }
                 Err(e) => return Err(e),
@@ -283,7 +279,7 @@ impl WhiteboxTool for LayerFootprint {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!("{}", &format!("Elapsed Time: {}", elapsed_time));
+            progress::message(format!("Elapsed Time: {}", elapsed_time));
         }
 
         Ok(())
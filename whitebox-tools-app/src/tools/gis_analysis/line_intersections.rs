@@ -7,6 +7,7 @@ License: MIT
 */
 
 use whitebox_common::algorithms::find_line_intersections;
+use whitebox_common::progress;
 use whitebox_common::structures::{BoundingBox, Point2D};
 use crate::tools::*;
 use whitebox_vector::*;
@@ -16,7 +17,6 @@ use std::io::{Error, ErrorKind};
 use std::path;
 use std::sync::mpsc;
 use std::sync::{Arc, Mutex};
-use std::thread;
 
 /// This tool identifies points where the features of two vector line/polygon layers
 /// intersect. The user must specify the names of two input vector line files and the
@@ -148,6 +148,7 @@ impl WhiteboxTool for LineIntersections {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input1_file: String = "".to_string();
         let mut input2_file: String = "".to_string();
@@ -199,13 +200,7 @@ impl WhiteboxTool for LineIntersections {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         if !input1_file.contains(path::MAIN_SEPARATOR) && !input1_file.contains("/") {
@@ -301,7 +296,7 @@ impl WhiteboxTool for LineIntersections {
             let feature_list = feature_list.clone();
             let tx1 = tx1.clone();
             let tx2 = tx2.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let mut start_point_in_part1: usize;
                 let mut start_point_in_part2: usize;
                 let mut end_point_in_part1: usize;
@@ -363,10 +358,11 @@ impl WhiteboxTool for LineIntersections {
                             }
                         }
                     }
-                    tx2.send(num_intersections).unwrap();
+                    if tx2.send(num_intersections).is_err() {
+                        return;
+                    }
                 }
-                tx1.send((intersection_points.clone(), print_warning))
-                    .unwrap();
+                let _ = tx1.send((intersection_points.clone(), print_warning));
             });
         }
 
@@ -406,7 +402,7 @@ impl WhiteboxTool for LineIntersections {
         let mut record_num2: usize;
         let mut fid = 1i32;
         for _ in 0..num_procs {
-            let (data, pw) = rx1.recv().unwrap();
+            let (data, pw) = cancel.recv(&rx1)?;
             if pw {
                 print_warning = true;
             }
@@ -437,35 +433,32 @@ impl WhiteboxTool for LineIntersections {
 
         let mut num_intersections = 0;
         for i in 0..input1.num_records {
-            let found_intersections = rx2.recv().unwrap();
+            let found_intersections = cancel.recv(&rx2)?;
             num_intersections += found_intersections;
             if verbose {
                 progress = (100.0_f64 * (i + 1) as f64 / input1.num_records as f64) as usize;
                 if progress != old_progress {
-                    println!(
-                        "Progress ({} intersections found): {}%",
-                        num_intersections, progress
-                    );
+                    progress::update_stage(&format!("Progress ({} intersections found)", num_intersections), progress);
                     old_progress = progress;
                 }
             }
         }
 
         if print_warning {
-            println!("Warning: Some of the input line features intersect at line segments rather than points.")
+            progress::warning("Some of the input line features intersect at line segments rather than points.")
         }
 
         if fid == 1 {
-            println!("Warning: No intersections were found between the input features.")
+            progress::warning("No intersections were found between the input features.")
         } else {
             // Some features were found. Save the output file.
             if verbose {
-                println!("Saving data...")
+                progress::message("Saving data...")
             };
             let _ = match output.write() {
                 Ok(_) => {
                     if verbose {
-                        println!("Output file written")
+                        progress::output_written(&output.file_name)
                     }
                 }
                 Err(e) => return Err(e),
@@ -475,7 +468,7 @@ impl WhiteboxTool for LineIntersections {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!("{}", &format!("Elapsed Time: {}", elapsed_time));
+            progress::message(format!("Elapsed Time: {}", elapsed_time));
         }
 
         Ok(())
@@ -0,0 +1,367 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: Dr. John Lindsay
+Created: 18/10/2026
+Last Modified: 18/10/2026
+License: MIT
+*/
+
+use whitebox_common::structures::{BoundingBox, Point2D};
+use whitebox_common::progress;
+use crate::tools::*;
+use whitebox_vector::*;
+use num_cpus;
+use std::env;
+use std::f64;
+use std::io::{Error, ErrorKind};
+use std::path;
+use std::sync::mpsc;
+use std::sync::Arc;
+
+/// This tool finds the locations of a set of points (`--input`), such as culverts, signs, or
+/// survey sites, along a set of measured routes (`--routes`), such as those created by the
+/// `CalibrateRoutes` tool. Each point is located on the nearest route, if it is within
+/// `--tolerance` of the route, at the nearest point on the route. The output (`--output`)
+/// contains the input points and their attributes, along with the route identifier field
+/// (`--route_id`) of the route that each point is located on, the point's `MEASURE` on the
+/// route, its `DISTANCE` from the route, and its `OFFSET` from the route, which is the distance
+/// to the right of the route, relative to the direction in which it is digitized, or the
+/// negative distance to the left. Points that are not within the tolerance of any route have
+/// null values in these fields. The output can be used to create point events with the
+/// `CreateRouteEvents` tool, e.g. after exporting it with `ExportTableToCsv`.
+///
+/// # See Also
+/// `CalibrateRoutes`, `CreateRouteEvents`
+pub struct LocatePointsAlongRoutes {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl LocatePointsAlongRoutes {
+    pub fn new() -> LocatePointsAlongRoutes {
+        // public constructor
+        let name = "LocatePointsAlongRoutes".to_string();
+        let toolbox = "GIS Analysis/Linear Referencing".to_string();
+        let description =
+            "Finds the route identifiers and measures of points located along measured routes."
+                .to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input Vector File".to_owned(),
+            flags: vec!["-i".to_owned(), "--input".to_owned()],
+            description: "Input vector points file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Vector(
+                VectorGeometryType::Point,
+            )),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Input Routes File".to_owned(),
+            flags: vec!["--routes".to_owned()],
+            description: "Input vector routes file, containing measured polylines.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Vector(
+                VectorGeometryType::Line,
+            )),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Route Identifier Field".to_owned(),
+            flags: vec!["--route_id".to_owned()],
+            description: "Field identifying each route.".to_owned(),
+            parameter_type: ParameterType::VectorAttributeField(
+                AttributeType::Any,
+                "--routes".to_string(),
+            ),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output Vector File".to_owned(),
+            flags: vec!["-o".to_owned(), "--output".to_owned()],
+            description: "Output vector points file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Vector(
+                VectorGeometryType::Point,
+            )),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Search Tolerance (optional)".to_owned(),
+            flags: vec!["--tolerance".to_owned()],
+            description: "Optional maximum distance between a point and its route.".to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: None,
+            optional: true,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut parent = env::current_exe().unwrap();
+        parent.pop();
+        let p = format!("{}", parent.display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(
+            ">>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=culverts.shp --routes=routes.shp --route_id=ROAD_NUM -o=located.shp --tolerance=10.0",
+            short_exe, name
+        ).replace("*", &sep);
+
+        LocatePointsAlongRoutes {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for LocatePointsAlongRoutes {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        match serde_json::to_string(&self.parameters) {
+            Ok(json_str) => return format!("{{\"parameters\":{}}}", json_str),
+            Err(err) => return format!("{:?}", err),
+        }
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+        cancel: &CancellationToken,
+    ) -> Result<(), Error> {
+        let args = ParsedArgs::parse(&self.get_tool_parameters(), &args, working_directory)?;
+        self.run_with_parsed_args(args, working_directory, verbose, cancel)
+    }
+
+    fn run_with_parsed_args<'a>(
+        &self,
+        args: ParsedArgs,
+        _working_directory: &'a str,
+        verbose: bool,
+        cancel: &CancellationToken,
+    ) -> Result<(), Error> {
+        let input_file = args.get_file("input").unwrap();
+        let routes_file = args.get_file("routes").unwrap();
+        let route_id_field = args.get_string("route_id").unwrap();
+        let output_file = args.get_file("output").unwrap();
+        let tolerance = args.get_float("tolerance").unwrap_or(f64::INFINITY);
+
+        if tolerance < 0f64 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The search tolerance (--tolerance) must not be negative.",
+            ));
+        }
+
+        let mut progress: usize;
+        let mut old_progress: usize = 1;
+
+        let start = Instant::now();
+
+        if verbose {
+            let tool_name = self.get_tool_name();
+            progress::welcome(&tool_name);
+        }
+
+        if verbose {
+            progress::message("Reading data...")
+        };
+
+        let input = Shapefile::read(&input_file)?;
+        if input.header.shape_type.base_shape_type() != ShapeType::Point {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The input vector data must be of POINT base shape type.",
+            ));
+        }
+        let routes_shapefile = Shapefile::read(&routes_file)?;
+        let routes = read_routes(&routes_shapefile, &route_id_field)?;
+        let boxes: Vec<BoundingBox> = routes
+            .iter()
+            .map(|(_, route)| {
+                let points: Vec<Point2D> = route.parts.iter().flatten().copied().collect();
+                BoundingBox::from_points(&points)
+            })
+            .collect();
+
+        let routes = Arc::new(routes);
+        let boxes = Arc::new(boxes);
+        let points: Arc<Vec<Option<Point2D>>> = Arc::new(
+            (0..input.num_records)
+                .map(|i| input.get_record(i).points.first().copied())
+                .collect(),
+        );
+        let num_records = input.num_records;
+        let mut num_procs = num_cpus::get() as isize;
+        let configs = whitebox_common::configs::get_configs()?;
+        let max_procs = configs.max_procs;
+        if max_procs > 0 && max_procs < num_procs {
+            num_procs = max_procs;
+        }
+        let (tx, rx) = mpsc::channel();
+        for tid in 0..num_procs {
+            let routes = routes.clone();
+            let boxes = boxes.clone();
+            let points = points.clone();
+            let tx = tx.clone();
+            progress::spawn(move || {
+                for record_num in (0..num_records).filter(|r| *r as isize % num_procs == tid) {
+                    let mut nearest: Option<(usize, RoutePosition)> = None;
+                    if let Some(p) = points[record_num] {
+                        for (r, (_, route)) in routes.iter().enumerate() {
+                            // Skip routes whose bounding boxes are further away than the
+                            // nearest route found so far.
+                            let dx = (boxes[r].min_x - p.x).max(p.x - boxes[r].max_x).max(0f64);
+                            let dy = (boxes[r].min_y - p.y).max(p.y - boxes[r].max_y).max(0f64);
+                            let box_distance = (dx * dx + dy * dy).sqrt();
+                            let limit = match nearest {
+                                Some((_, best)) => best.distance.min(tolerance),
+                                None => tolerance,
+                            };
+                            if box_distance > limit {
+                                continue;
+                            }
+                            if let Some(position) = route.locate(p) {
+                                let closer = match nearest {
+                                    Some((_, best)) => position.distance < best.distance,
+                                    None => true,
+                                };
+                                if closer && position.distance <= tolerance {
+                                    nearest = Some((r, position));
+                                }
+                            }
+                        }
+                    }
+                    if tx.send((record_num, nearest)).is_err() {
+                        return;
+                    }
+                }
+            });
+        }
+        drop(tx);
+
+        let mut located: Vec<Option<(usize, RoutePosition)>> = vec![None; num_records];
+        for i in 0..num_records {
+            let (record_num, nearest) = cancel.recv(&rx)?;
+            located[record_num] = nearest;
+            if verbose {
+                progress = (100.0_f64 * (i + 1) as f64 / num_records as f64) as usize;
+                if progress != old_progress {
+                    progress::update_stage("Locating points", progress);
+                    old_progress = progress;
+                }
+            }
+        }
+
+        let mut output =
+            Shapefile::initialize_using_file(&output_file, &input, input.header.shape_type, true)?;
+        let mut id_field = routes_shapefile
+            .attributes
+            .get_field(routes_shapefile.attributes.get_field_num(&route_id_field).unwrap())
+            .clone();
+        let base_name = id_field.name.clone();
+        let mut n = 1;
+        while output.attributes.get_field_num(&id_field.name).is_some() {
+            id_field.name = format!("{}_{}", base_name, n);
+            n += 1;
+        }
+        output.attributes.add_field(&id_field);
+        output
+            .attributes
+            .add_field(&AttributeField::new("MEASURE", FieldDataType::Real, 18u8, 6u8));
+        output
+            .attributes
+            .add_field(&AttributeField::new("DISTANCE", FieldDataType::Real, 18u8, 6u8));
+        output
+            .attributes
+            .add_field(&AttributeField::new("OFFSET", FieldDataType::Real, 18u8, 6u8));
+
+        let mut num_unlocated = 0;
+        for record_num in 0..num_records {
+            let mut atts = input.attributes.get_record(record_num);
+            match located[record_num] {
+                Some((r, position)) => {
+                    atts.push(routes[r].0.clone());
+                    atts.push(if position.measure.is_nan() {
+                        FieldData::Null
+                    } else {
+                        FieldData::Real(position.measure)
+                    });
+                    atts.push(FieldData::Real(position.distance));
+                    atts.push(FieldData::Real(position.offset));
+                }
+                None => {
+                    num_unlocated += 1;
+                    atts.extend(vec![FieldData::Null; 4]);
+                }
+            }
+            output.add_record(input.get_record(record_num).clone());
+            output.attributes.add_record(atts, false);
+        }
+
+        if verbose && num_unlocated > 0 {
+            progress::message(format!(
+                "Warning: {} points are not within the tolerance of a route.",
+                num_unlocated
+            ));
+        }
+
+        if verbose {
+            progress::message("Saving data...")
+        };
+        let _ = match output.write() {
+            Ok(_) => {
+                if verbose {
+                    progress::output_written(&output.file_name)
+                }
+            }
+            Err(e) => return Err(e),
+        };
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+
+        if verbose {
+            progress::message(format!("Elapsed Time: {}", elapsed_time));
+        }
+
+        Ok(())
+    }
+}
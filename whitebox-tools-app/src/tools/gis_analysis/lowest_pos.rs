@@ -8,6 +8,7 @@ License: MIT
 
 use whitebox_raster::*;
 use whitebox_common::structures::Array2D;
+use whitebox_common::progress;
 use crate::tools::*;
 use std::env;
 use std::f64;
@@ -120,6 +121,7 @@ impl WhiteboxTool for LowestPosition {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_files = String::new();
         let mut output_file = String::new();
@@ -156,13 +158,7 @@ impl WhiteboxTool for LowestPosition {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -201,7 +197,7 @@ impl WhiteboxTool for LowestPosition {
         for value in vec {
             if !value.trim().is_empty() {
                 if verbose {
-                    println!("Reading data...")
+                    progress::message("Reading data...")
                 };
 
                 let mut input_file = value.trim().to_owned();
@@ -236,10 +232,11 @@ impl WhiteboxTool for LowestPosition {
                             }
                         }
                     }
+                    cancel.check()?;
                     if verbose {
                         progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                         if progress != old_progress {
-                            println!("Progress (loop {} of {}): {}%", i, num_files, progress);
+                            progress::update_stage(&format!("Progress (loop {} of {})", i, num_files), progress);
                             old_progress = progress;
                         }
                     }
@@ -256,22 +253,19 @@ impl WhiteboxTool for LowestPosition {
         output.add_metadata_entry(format!("Elapsed Time (including I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (including I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (including I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -8,6 +8,7 @@ License: MIT
 extern crate kdtree;
 
 use whitebox_common::structures::{Polyline, Point2D};
+use whitebox_common::progress;
 use crate::tools::*;
 use whitebox_vector::*;
 use kdtree::distance::squared_euclidean;
@@ -142,6 +143,7 @@ impl WhiteboxTool for MergeLineSegments {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut output_file = String::new();
@@ -202,13 +204,7 @@ impl WhiteboxTool for MergeLineSegments {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         if !input_file.contains(&sep) && !input_file.contains("/") {
@@ -219,7 +215,7 @@ impl WhiteboxTool for MergeLineSegments {
         }
 
         if verbose {
-            println!("Reading data...")
+            progress::message("Reading data...")
         };
 
         let input = Shapefile::read(&input_file)?;
@@ -420,10 +416,11 @@ impl WhiteboxTool for MergeLineSegments {
                     }
                 }
             }
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * (i + 1) as f64 / polylines.len() as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress (Loop 1 of 2): {}%", progress);
+                    progress::update_stage("Progress (Loop 1 of 2)", progress);
                     old_progress = progress;
                 }
             }
@@ -490,22 +487,23 @@ impl WhiteboxTool for MergeLineSegments {
                 }
                 output.attributes.add_record(out_atts, false);
             }
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * (i + 1) as f64 / polylines.len() as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress (Loop 2 of 2): {}%", progress);
+                    progress::update_stage("Progress (Loop 2 of 2)", progress);
                     old_progress = progress;
                 }
             }
         }
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
@@ -514,7 +512,7 @@ impl WhiteboxTool for MergeLineSegments {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!("{}", &format!("Elapsed Time: {}", elapsed_time));
+            progress::message(format!("Elapsed Time: {}", elapsed_time));
         }
 
         Ok(())
@@ -8,6 +8,7 @@ License: MIT
 
 use whitebox_common::algorithms::{minimum_bounding_box, MinimizationCriterion};
 use whitebox_common::structures::Point2D;
+use whitebox_common::progress;
 use crate::tools::*;
 use whitebox_vector::ShapefileGeometry;
 use whitebox_vector::*;
@@ -74,7 +75,7 @@ impl MinimumBoundingBox {
 
         parameters.push(ToolParameter {
             name: "Find bounding rectangles around each individual feature.".to_owned(),
-            flags: vec!["--features".to_owned()],
+            flags: vec!["--feature".to_owned(), "--features".to_owned()],
             description:
                 "Find the minimum bounding rectangles around each individual vector feature"
                     .to_owned(),
@@ -151,6 +152,7 @@ impl WhiteboxTool for MinimumBoundingBox {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file: String = "".to_string();
         let mut output_file: String = "".to_string();
@@ -216,13 +218,7 @@ impl WhiteboxTool for MinimumBoundingBox {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         if !input_file.contains(path::MAIN_SEPARATOR) && !input_file.contains("/") {
@@ -264,23 +260,24 @@ impl WhiteboxTool for MinimumBoundingBox {
                 let atts = input.attributes.get_record(record_num);
                 output.attributes.add_record(atts.clone(), false);
 
+                cancel.check()?;
                 if verbose {
                     progress =
                         (100.0_f64 * (record_num + 1) as f64 / input.num_records as f64) as usize;
                     if progress != old_progress {
-                        println!("Progress: {}%", progress);
+                        progress::update(progress);
                         old_progress = progress;
                     }
                 }
             }
 
             if verbose {
-                println!("Saving data...")
+                progress::message("Saving data...")
             };
             let _ = match output.write() {
                 Ok(_) => {
                     if verbose {
-                        println!("Output file written")
+                        progress::output_written(&output.file_name)
                     }
                 }
                 Err(e) => return Err(e),
@@ -301,18 +298,19 @@ impl WhiteboxTool for MinimumBoundingBox {
                     points.push(Point2D::new(record.points[i].x, record.points[i].y));
                 }
 
+                cancel.check()?;
                 if verbose {
                     progress =
                         (100.0_f64 * (record_num + 1) as f64 / input.num_records as f64) as usize;
                     if progress != old_progress {
-                        println!("Reading points: {}%", progress);
+                        progress::update_stage("Reading points", progress);
                         old_progress = progress;
                     }
                 }
             }
 
             if verbose {
-                println!("Finding convex hull...");
+                progress::message("Finding convex hull...");
             }
             let mut mbb_points = minimum_bounding_box(&mut points, min_criterion);
             // now add a last point same as the first.
@@ -327,12 +325,12 @@ impl WhiteboxTool for MinimumBoundingBox {
                 .add_record(vec![FieldData::Int(1i32)], false);
 
             if verbose {
-                println!("Saving data...")
+                progress::message("Saving data...")
             };
             let _ = match output.write() {
                 Ok(_) => {
                     if verbose {
-                        println!("Output file written")
+                        progress::output_written(&output.file_name)
                     }
                 }
                 Err(e) => return Err(e),
@@ -342,7 +340,7 @@ impl WhiteboxTool for MinimumBoundingBox {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!("{}", &format!("Elapsed Time: {}", elapsed_time));
+            progress::message(format!("Elapsed Time: {}", elapsed_time));
         }
 
         Ok(())
@@ -8,6 +8,7 @@ License: MIT
 
 use whitebox_common::algorithms::smallest_enclosing_circle;
 use whitebox_common::structures::Point2D;
+use whitebox_common::progress;
 use crate::tools::*;
 use whitebox_vector::ShapefileGeometry;
 use whitebox_vector::*;
@@ -62,7 +63,7 @@ impl MinimumBoundingCircle {
 
         parameters.push(ToolParameter {
             name: "Find bounding circle around each individual feature.".to_owned(),
-            flags: vec!["--features".to_owned()],
+            flags: vec!["--feature".to_owned(), "--features".to_owned()],
             description: "Find the minimum bounding circle around each individual vector feature"
                 .to_owned(),
             parameter_type: ParameterType::Boolean,
@@ -139,6 +140,7 @@ impl WhiteboxTool for MinimumBoundingCircle {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file: String = "".to_string();
         let mut output_file: String = "".to_string();
@@ -188,13 +190,7 @@ impl WhiteboxTool for MinimumBoundingCircle {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         if !input_file.contains(path::MAIN_SEPARATOR) && !input_file.contains("/") {
@@ -249,23 +245,24 @@ impl WhiteboxTool for MinimumBoundingCircle {
                 let atts = input.attributes.get_record(record_num);
                 output.attributes.add_record(atts.clone(), false);
 
+                cancel.check()?;
                 if verbose {
                     progress =
                         (100.0_f64 * (record_num + 1) as f64 / input.num_records as f64) as usize;
                     if progress != old_progress {
-                        println!("Progress: {}%", progress);
+                        progress::update(progress);
                         old_progress = progress;
                     }
                 }
             }
 
             if verbose {
-                println!("Saving data...")
+                progress::message("Saving data...")
             };
             let _ = match output.write() {
                 Ok(_) => {
                     if verbose {
-                        println!("Output file written")
+                        progress::output_written(&output.file_name)
                     }
                 }
                 Err(e) => return Err(e),
@@ -286,18 +283,19 @@ impl WhiteboxTool for MinimumBoundingCircle {
                     points.push(Point2D::new(record.points[i].x, record.points[i].y));
                 }
 
+                cancel.check()?;
                 if verbose {
                     progress =
                         (100.0_f64 * (record_num + 1) as f64 / input.num_records as f64) as usize;
                     if progress != old_progress {
-                        println!("Reading points: {}%", progress);
+                        progress::update_stage("Reading points", progress);
                         old_progress = progress;
                     }
                 }
             }
 
             if verbose {
-                println!("Finding minimum bounding circle...");
+                progress::message("Finding minimum bounding circle...");
             }
             let mbc = smallest_enclosing_circle(&points);
 
@@ -325,12 +323,12 @@ impl WhiteboxTool for MinimumBoundingCircle {
                 .add_record(vec![FieldData::Int(1i32)], false);
 
             if verbose {
-                println!("Saving data...")
+                progress::message("Saving data...")
             };
             let _ = match output.write() {
                 Ok(_) => {
                     if verbose {
-                        println!("Output file written")
+                        progress::output_written(&output.file_name)
                     }
                 }
                 Err(e) => return Err(e),
@@ -340,7 +338,7 @@ impl WhiteboxTool for MinimumBoundingCircle {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!("{}", &format!("Elapsed Time: {}", elapsed_time));
+            progress::message(format!("Elapsed Time: {}", elapsed_time));
         }
 
         Ok(())
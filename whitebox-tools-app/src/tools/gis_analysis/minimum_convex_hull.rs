@@ -8,6 +8,7 @@ License: MIT
 
 use whitebox_common::algorithms::convex_hull;
 use whitebox_common::structures::Point2D;
+use whitebox_common::progress;
 use crate::tools::*;
 use whitebox_vector::ShapefileGeometry;
 use whitebox_vector::*;
@@ -64,7 +65,7 @@ impl MinimumConvexHull {
 
         parameters.push(ToolParameter {
             name: "Find hulls around each individual feature.".to_owned(),
-            flags: vec!["--features".to_owned()],
+            flags: vec!["--feature".to_owned(), "--features".to_owned()],
             description: "Find the hulls around each vector feature".to_owned(),
             parameter_type: ParameterType::Boolean,
             default_value: Some("true".to_owned()),
@@ -140,6 +141,7 @@ impl WhiteboxTool for MinimumConvexHull {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file: String = "".to_string();
         let mut output_file: String = "".to_string();
@@ -189,13 +191,7 @@ impl WhiteboxTool for MinimumConvexHull {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         if !input_file.contains(path::MAIN_SEPARATOR) && !input_file.contains("/") {
@@ -239,23 +235,24 @@ impl WhiteboxTool for MinimumConvexHull {
                 let atts = input.attributes.get_record(record_num);
                 output.attributes.add_record(atts.clone(), false);
 
+                cancel.check()?;
                 if verbose {
                     progress =
                         (100.0_f64 * (record_num + 1) as f64 / input.num_records as f64) as usize;
                     if progress != old_progress {
-                        println!("Progress: {}%", progress);
+                        progress::update(progress);
                         old_progress = progress;
                     }
                 }
             }
 
             if verbose {
-                println!("Saving data...")
+                progress::message("Saving data...")
             };
             let _ = match output.write() {
                 Ok(_) => {
                     if verbose {
-                        println!("Output file written")
+                        progress::output_written(&output.file_name)
                     }
                 }
                 Err(e) => return Err(e),
@@ -276,18 +273,19 @@ impl WhiteboxTool for MinimumConvexHull {
                     points.push(Point2D::new(record.points[i].x, record.points[i].y));
                 }
 
+                cancel.check()?;
                 if verbose {
                     progress =
                         (100.0_f64 * (record_num + 1) as f64 / input.num_records as f64) as usize;
                     if progress != old_progress {
-                        println!("Reading points: {}%", progress);
+                        progress::update_stage("Reading points", progress);
                         old_progress = progress;
                     }
                 }
             }
 
             if verbose {
-                println!("Finding convex hull...");
+                progress::message("Finding convex hull...");
             }
             let mut hull_points = convex_hull(&mut points);
             // convex_hull returns points in a counter-clockwise order but we need it to be clockwise for a shapefile poly.
@@ -304,12 +302,12 @@ impl WhiteboxTool for MinimumConvexHull {
                 .add_record(vec![FieldData::Int(1i32)], false);
 
             if verbose {
-                println!("Saving data...")
+                progress::message("Saving data...")
             };
             let _ = match output.write() {
                 Ok(_) => {
                     if verbose {
-                        println!("Output file written")
+                        progress::output_written(&output.file_name)
                     }
                 }
                 Err(e) => return Err(e),
@@ -319,7 +317,7 @@ impl WhiteboxTool for MinimumConvexHull {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!("{}", &format!("Elapsed Time: {}", elapsed_time));
+            progress::message(format!("Elapsed Time: {}", elapsed_time));
         }
 
         Ok(())
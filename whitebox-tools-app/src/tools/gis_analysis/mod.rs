@@ -5,11 +5,13 @@ mod block_maximum;
 mod block_minimum;
 mod boundary_shape_complexity;
 mod buffer_raster;
-// mod buffer_vector;
+mod buffer_vector;
+mod calibrate_routes;
 mod centroid;
 mod centroid_vector;
 mod clip;
 mod clip_raster_to_polygon;
+mod closest_facility;
 mod clump;
 mod compactness_ratio;
 mod construct_vector_tin;
@@ -20,6 +22,7 @@ mod count_if;
 mod create_hexagonal_vector_grid;
 mod create_plane;
 mod create_rectangular_vector_grid;
+mod create_route_events;
 mod deviation_from_regional_direction;
 mod difference;
 mod dissolve;
@@ -40,9 +43,12 @@ mod highest_pos;
 mod hole_proportion;
 mod idw_interpolation;
 mod intersect;
+mod kernel_density;
+mod kriging_interpolation;
 mod layer_footprint;
 mod line_intersections;
 mod linearity_index;
+mod locate_points_along_routes;
 mod lowest_pos;
 mod max_abs_overlay;
 mod max_overlay;
@@ -58,6 +64,7 @@ mod multiply_overlay;
 mod narrowness_index;
 mod natural_neighbour_interpolation;
 mod nearest_neighbour_gridding;
+mod od_cost_matrix;
 mod patch_orientation;
 mod percent_equal_to;
 mod percent_greater_than;
@@ -78,9 +85,13 @@ mod reclass;
 mod reclass_equal_interval;
 mod reclass_from_file;
 mod related_circumscribing_circle;
+mod service_area;
 mod shape_complexity_index;
 mod shape_complexity_raster;
+mod shortest_path;
+mod simplify_vector;
 mod smooth_vectors;
+mod spatial_join;
 mod split_with_lines;
 mod sum_overlay;
 mod symmetrical_difference;
@@ -99,11 +110,13 @@ pub use self::block_maximum::BlockMaximumGridding;
 pub use self::block_minimum::BlockMinimumGridding;
 pub use self::boundary_shape_complexity::BoundaryShapeComplexity;
 pub use self::buffer_raster::BufferRaster;
-// pub use self::buffer_vector::BufferVector;
+pub use self::buffer_vector::BufferVector;
+pub use self::calibrate_routes::CalibrateRoutes;
 pub use self::centroid::Centroid;
 pub use self::centroid_vector::CentroidVector;
 pub use self::clip::Clip;
 pub use self::clip_raster_to_polygon::ClipRasterToPolygon;
+pub use self::closest_facility::ClosestFacility;
 pub use self::clump::Clump;
 pub use self::compactness_ratio::CompactnessRatio;
 pub use self::construct_vector_tin::ConstructVectorTIN;
@@ -114,6 +127,7 @@ pub use self::count_if::CountIf;
 pub use self::create_hexagonal_vector_grid::CreateHexagonalVectorGrid;
 pub use self::create_plane::CreatePlane;
 pub use self::create_rectangular_vector_grid::CreateRectangularVectorGrid;
+pub use self::create_route_events::CreateRouteEvents;
 pub use self::deviation_from_regional_direction::DeviationFromRegionalDirection;
 pub use self::difference::Difference;
 pub use self::dissolve::Dissolve;
@@ -134,9 +148,12 @@ pub use self::highest_pos::HighestPosition;
 pub use self::hole_proportion::HoleProportion;
 pub use self::idw_interpolation::IdwInterpolation;
 pub use self::intersect::Intersect;
+pub use self::kernel_density::KernelDensity;
+pub use self::kriging_interpolation::KrigingInterpolation;
 pub use self::layer_footprint::LayerFootprint;
 pub use self::line_intersections::LineIntersections;
 pub use self::linearity_index::LinearityIndex;
+pub use self::locate_points_along_routes::LocatePointsAlongRoutes;
 pub use self::lowest_pos::LowestPosition;
 pub use self::max_abs_overlay::MaxAbsoluteOverlay;
 pub use self::max_overlay::MaxOverlay;
@@ -152,6 +169,7 @@ pub use self::multiply_overlay::MultiplyOverlay;
 pub use self::narrowness_index::NarrownessIndex;
 pub use self::natural_neighbour_interpolation::NaturalNeighbourInterpolation;
 pub use self::nearest_neighbour_gridding::NearestNeighbourGridding;
+pub use self::od_cost_matrix::OdCostMatrix;
 pub use self::patch_orientation::PatchOrientation;
 pub use self::percent_equal_to::PercentEqualTo;
 pub use self::percent_greater_than::PercentGreaterThan;
@@ -172,9 +190,13 @@ pub use self::reclass::Reclass;
 pub use self::reclass_equal_interval::ReclassEqualInterval;
 pub use self::reclass_from_file::ReclassFromFile;
 pub use self::related_circumscribing_circle::RelatedCircumscribingCircle;
+pub use self::service_area::ServiceArea;
 pub use self::shape_complexity_index::ShapeComplexityIndex;
 pub use self::shape_complexity_raster::ShapeComplexityIndexRaster;
+pub use self::shortest_path::ShortestPath;
+pub use self::simplify_vector::SimplifyVector;
 pub use self::smooth_vectors::SmoothVectors;
+pub use self::spatial_join::SpatialJoin;
 pub use self::split_with_lines::SplitWithLines;
 pub use self::sum_overlay::SumOverlay;
 pub use self::symmetrical_difference::SymmetricalDifference;
@@ -8,6 +8,7 @@ License: MIT
 
 use whitebox_raster::*;
 use whitebox_common::structures::Array2D;
+use whitebox_common::progress;
 use crate::tools::*;
 use std::env;
 use std::f64;
@@ -78,7 +79,7 @@ impl NarrownessIndex {
             short_exe += ".exe";
         }
         let usage = format!(
-            ">>.*{} -r={} -v --wd=\"*path*to*data*\" -i=input.tif -o=output.tif --zero_back",
+            ">>.*{} -r={} -v --wd=\"*path*to*data*\" -i=input.tif -o=output.tif",
             short_exe, name
         )
         .replace("*", &sep);
@@ -126,6 +127,7 @@ impl WhiteboxTool for NarrownessIndex {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut output_file = String::new();
@@ -163,13 +165,7 @@ impl WhiteboxTool for NarrownessIndex {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -186,7 +182,7 @@ impl WhiteboxTool for NarrownessIndex {
         }
 
         if verbose {
-            println!("Reading data...");
+            progress::message("Reading data...");
         }
 
         // let input = Arc::new(Raster::new(&input_file, "r")?);
@@ -206,7 +202,7 @@ impl WhiteboxTool for NarrownessIndex {
 
         // calculate the distance from an edge
         if verbose {
-            println!("Calculating maximum distance from edge for each patch...");
+            progress::message("Calculating maximum distance from edge for each patch...");
         }
         let mut output = Raster::initialize_using_file(&output_file, &input);
         let out_nodata = -999f64;
@@ -262,10 +258,11 @@ impl WhiteboxTool for NarrownessIndex {
                     }
                 }
             }
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Initializing Rasters: {}%", progress);
+                    progress::update_stage("Initializing Rasters", progress);
                     old_progress = progress;
                 }
             }
@@ -304,10 +301,11 @@ impl WhiteboxTool for NarrownessIndex {
                     }
                 }
             }
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress (1 of 4): {}%", progress);
+                    progress::update_stage("Progress (1 of 4)", progress);
                     old_progress = progress;
                 }
             }
@@ -346,10 +344,11 @@ impl WhiteboxTool for NarrownessIndex {
                     }
                 }
             }
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * (rows - row) as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress (2 of 4): {}%", progress);
+                    progress::update_stage("Progress (2 of 4)", progress);
                     old_progress = progress;
                 }
             }
@@ -372,10 +371,11 @@ impl WhiteboxTool for NarrownessIndex {
                     output[(row, col)] = out_nodata;
                 }
             }
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress (3 of 4): {}%", progress);
+                    progress::update_stage("Progress (3 of 4)", progress);
                     old_progress = progress;
                 }
             }
@@ -395,10 +395,11 @@ impl WhiteboxTool for NarrownessIndex {
                     output[(row, col)] = max_width[bin];
                 }
             }
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress (4 of 4): {}%", progress);
+                    progress::update_stage("Progress (4 of 4)", progress);
                     old_progress = progress;
                 }
             }
@@ -523,22 +524,19 @@ impl WhiteboxTool for NarrownessIndex {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -9,6 +9,7 @@ License: MIT
 use whitebox_common::algorithms::{point_in_poly, polygon_area, triangulate, Triangulation};
 use whitebox_raster::*;
 use whitebox_common::structures::{BoundingBox, Point2D};
+use whitebox_common::progress;
 use crate::tools::*;
 use whitebox_vector::{FieldData, ShapeType, ShapeTypeDimension, Shapefile};
 use kdtree::distance::squared_euclidean;
@@ -21,7 +22,6 @@ use std::io::{Error, ErrorKind};
 use std::path;
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::thread;
 
 /// This tool can be used to interpolate a set of input vector points (`--input`) onto a raster grid using
 /// Sibson's (1981) natural neighbour method. Similar to inverse-distance-weight interpolation (`IdwInterpolation`),
@@ -117,7 +117,7 @@ impl NaturalNeighbourInterpolation {
 
         parameters.push(ToolParameter{
             name: "Cell Size (optional)".to_owned(), 
-            flags: vec!["--cell_size".to_owned()], 
+            flags: vec!["--resolution".to_owned(), "--cell_size".to_owned()], 
             description: "Optionally specified cell size of output raster. Not used when base raster is specified.".to_owned(),
             parameter_type: ParameterType::Float,
             default_value: None,
@@ -210,6 +210,7 @@ impl WhiteboxTool for NaturalNeighbourInterpolation {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file: String = "".to_string();
         let mut field_name = String::new();
@@ -293,13 +294,7 @@ impl WhiteboxTool for NaturalNeighbourInterpolation {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         if !input_file.contains(path::MAIN_SEPARATOR) && !input_file.contains("/") {
@@ -437,18 +432,19 @@ impl WhiteboxTool for NaturalNeighbourInterpolation {
                 p += 1;
             }
 
+            cancel.check()?;
             if verbose {
                 progress =
                     (100.0_f64 * (record_num + 1) as f64 / input.num_records as f64) as usize;
                 if progress != old_progress {
-                    println!("Reading points: {}%", progress);
+                    progress::update_stage("Reading points", progress);
                     old_progress = progress;
                 }
             }
         }
 
         if verbose {
-            println!("Performing triangulation...");
+            progress::message("Performing triangulation...");
         }
         // this is where the heavy-lifting is
         let delaunay = triangulate(&points).expect("No triangulation exists.");
@@ -461,7 +457,7 @@ impl WhiteboxTool for NaturalNeighbourInterpolation {
         }
 
         if verbose {
-            println!("Creating point-halfedge mapping...");
+            progress::message("Creating point-halfedge mapping...");
         }
         const EMPTY: usize = usize::max_value();
         let mut point_edge_map = HashMap::new(); // point id to half-edge id
@@ -470,11 +466,12 @@ impl WhiteboxTool for NaturalNeighbourInterpolation {
             if !point_edge_map.contains_key(&endpoint) || delaunay.halfedges[edge] == EMPTY {
                 point_edge_map.insert(endpoint, edge);
             }
+            cancel.check()?;
             if verbose {
                 progress =
                     (100.0_f64 * edge as f64 / (delaunay.triangles.len() - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    progress::update(progress);
                     old_progress = progress;
                 }
             }
@@ -484,7 +481,7 @@ impl WhiteboxTool for NaturalNeighbourInterpolation {
         let res_y = output.configs.resolution_y;
 
         if verbose {
-            println!("Interpolating...");
+            progress::message("Interpolating...");
         }
         let points = Arc::new(points);
         let z_values = Arc::new(z_values);
@@ -507,7 +504,7 @@ impl WhiteboxTool for NaturalNeighbourInterpolation {
             let hull_vertices = hull_vertices.clone();
             let point_edge_map = point_edge_map.clone();
             let tx = tx.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let (mut px, mut py): (f64, f64);
                 let mut previous_nn = EMPTY;
                 let mut delaunay2: Triangulation;
@@ -713,18 +710,20 @@ impl WhiteboxTool for NaturalNeighbourInterpolation {
                             };
                         }
                     }
-                    tx.send((row, data)).unwrap();
+                    if tx.send((row, data)).is_err() {
+                        return;
+                    }
                 }
             });
         }
 
         for row in 0..rows {
-            let data = rx.recv().expect("Error receiving data from thread.");
+            let data = cancel.recv(&rx)?;
             output.set_row_data(data.0, data.1);
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Interpolating: {}%", progress);
+                    progress::update_stage("Interpolating", progress);
                     old_progress = progress;
                 }
             }
@@ -748,12 +747,12 @@ impl WhiteboxTool for NaturalNeighbourInterpolation {
         output.add_metadata_entry(format!("Elapsed Time (including I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
@@ -762,7 +761,7 @@ impl WhiteboxTool for NaturalNeighbourInterpolation {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!("{}", &format!("Elapsed Time: {}", elapsed_time));
+            progress::message(format!("Elapsed Time: {}", elapsed_time));
         }
 
         Ok(())
@@ -8,6 +8,7 @@ License: MIT
 
 use whitebox_raster::*;
 use whitebox_common::structures::{DistanceMetric, FixedRadiusSearch2D};
+use whitebox_common::progress;
 use crate::tools::*;
 use whitebox_vector::{FieldData, ShapeType, Shapefile};
 use num_cpus;
@@ -17,7 +18,6 @@ use std::io::{Error, ErrorKind};
 use std::path;
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::thread;
 
 /// Creates a raster grid based on a set of vector points and assigns grid values using the nearest neighbour.
 pub struct NearestNeighbourGridding {
@@ -163,6 +163,7 @@ impl WhiteboxTool for NearestNeighbourGridding {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut field_name = String::new();
@@ -245,13 +246,7 @@ impl WhiteboxTool for NearestNeighbourGridding {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -267,7 +262,7 @@ impl WhiteboxTool for NearestNeighbourGridding {
         }
 
         if verbose {
-            println!("Reading data...")
+            progress::message("Reading data...")
         };
         let vector_data = Shapefile::read(&input_file)?;
 
@@ -332,12 +327,13 @@ impl WhiteboxTool for NearestNeighbourGridding {
                     }
                 }
 
+                cancel.check()?;
                 if verbose {
                     progress = (100.0_f64 * record_num as f64
                         / (vector_data.num_records - 1) as f64)
                         as usize;
                     if progress != old_progress {
-                        println!("Creating search structure: {}%", progress);
+                        progress::update_stage("Creating search structure", progress);
                         old_progress = progress;
                     }
                 }
@@ -368,12 +364,13 @@ impl WhiteboxTool for NearestNeighbourGridding {
                     frs.insert(x, y, z);
                 }
 
+                cancel.check()?;
                 if verbose {
                     progress = (100.0_f64 * record_num as f64
                         / (vector_data.num_records - 1) as f64)
                         as usize;
                     if progress != old_progress {
-                        println!("Creating search structure: {}%", progress);
+                        progress::update_stage("Creating search structure", progress);
                         old_progress = progress;
                     }
                 }
@@ -442,7 +439,7 @@ impl WhiteboxTool for NearestNeighbourGridding {
         for tid in 0..num_procs {
             let frs = frs.clone();
             let tx = tx.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let (mut x, mut y): (f64, f64);
                 for row in (0..rows).filter(|r| r % num_procs == tid) {
                     let mut data = vec![nodata; columns as usize];
@@ -456,18 +453,20 @@ impl WhiteboxTool for NearestNeighbourGridding {
                             }
                         }
                     }
-                    tx.send((row, data)).unwrap();
+                    if tx.send((row, data)).is_err() {
+                        return;
+                    }
                 }
             });
         }
 
         for row in 0..rows {
-            let data = rx.recv().expect("Error receiving data from thread.");
+            let data = cancel.recv(&rx)?;
             output.set_row_data(data.0, data.1);
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    progress::update(progress);
                     old_progress = progress;
                 }
             }
@@ -482,22 +481,19 @@ impl WhiteboxTool for NearestNeighbourGridding {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
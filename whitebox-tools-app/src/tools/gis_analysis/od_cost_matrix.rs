@@ -0,0 +1,345 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: Dr. John Lindsay
+Created: 18/10/2026
+Last Modified: 18/10/2026
+License: MIT
+*/
+
+use whitebox_common::progress;
+use crate::tools::*;
+use whitebox_vector::*;
+use num_cpus;
+use std::env;
+use std::f64;
+use std::fs::File;
+use std::io::prelude::*;
+use std::io::{BufWriter, Error, ErrorKind};
+use std::path;
+use std::sync::mpsc;
+use std::sync::Arc;
+
+/// This tool calculates the least costs of travel between each of a set of origins (`--origins`)
+/// and each of a set of destinations (`--destinations`) along a line network (`--network`), i.e.
+/// an origin-destination (OD) cost matrix, which is commonly used in accessibility, location-
+/// allocation, and spatial interaction modelling. If no destinations file is specified, the
+/// costs between each pair of origins are calculated.
+///
+/// The matrix is output (`--output`) as a comma-separated values (CSV) file with one row for
+/// each origin-destination pair, and `ORIGIN`, `DESTINATION`, and `COST` columns holding the
+/// record numbers of the origin and destination and the cost of travel between them. Pairs that
+/// are not connected by the network, or whose cost is greater than `--max_cost`, are not output.
+///
+/// Origins and destinations that are not on the network are joined to it at the nearest point on
+/// the nearest line. The network's lines are connected only at their end points, and lines whose
+/// end points are within the `--snap` distance of one another are joined. The cost of travelling
+/// a line is its length, unless a numeric cost field is specified (`--cost_field`), in which case
+/// it is the field's value for the full length of the line. Lines with null or negative costs
+/// cannot be travelled, and travel on one-way lines may be restricted using a one-way field
+/// (`--oneway_field`), as described for the `ShortestPath` tool.
+///
+/// # See Also
+/// `ShortestPath`, `ServiceArea`, `ClosestFacility`
+pub struct OdCostMatrix {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl OdCostMatrix {
+    pub fn new() -> OdCostMatrix {
+        // public constructor
+        let name = "OdCostMatrix".to_string();
+        let toolbox = "GIS Analysis/Network Analysis".to_string();
+        let description =
+            "Calculates the least costs of travel between origins and destinations on a line network."
+                .to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input Network Vector File".to_owned(),
+            flags: vec!["--network".to_owned()],
+            description: "Input vector line network file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Vector(
+                VectorGeometryType::Line,
+            )),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Input Origins Vector File".to_owned(),
+            flags: vec!["--origins".to_owned()],
+            description: "Input vector points file of the origins.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Vector(
+                VectorGeometryType::Point,
+            )),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Input Destinations Vector File (optional)".to_owned(),
+            flags: vec!["--destinations".to_owned()],
+            description: "Optional input vector points file of the destinations; the origins are used by default."
+                .to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Vector(
+                VectorGeometryType::Point,
+            )),
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output CSV File".to_owned(),
+            flags: vec!["-o".to_owned(), "--output".to_owned()],
+            description: "Output CSV file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Csv),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Maximum Cost (optional)".to_owned(),
+            flags: vec!["--max_cost".to_owned()],
+            description: "Optional maximum cost of the output origin-destination pairs.".to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Cost Field (optional)".to_owned(),
+            flags: vec!["--cost_field".to_owned()],
+            description: "Optional numeric field holding the cost of travelling each line; line length is used by default."
+                .to_owned(),
+            parameter_type: ParameterType::VectorAttributeField(
+                AttributeType::Number,
+                "--network".to_string(),
+            ),
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "One-way Field (optional)".to_owned(),
+            flags: vec!["--oneway_field".to_owned()],
+            description: "Optional field restricting the direction of travel on each line.".to_owned(),
+            parameter_type: ParameterType::VectorAttributeField(
+                AttributeType::Any,
+                "--network".to_string(),
+            ),
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Snap Distance".to_owned(),
+            flags: vec!["--snap".to_owned()],
+            description: "Distance within which line end points are joined into a network node."
+                .to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: Some("0.0".to_owned()),
+            optional: true,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut parent = env::current_exe().unwrap();
+        parent.pop();
+        let p = format!("{}", parent.display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(
+            ">>.*{0} -r={1} -v --wd=\"*path*to*data*\" --network=roads.shp --origins=homes.shp --destinations=schools.shp -o=costs.csv --max_cost=5000.0",
+            short_exe, name
+        ).replace("*", &sep);
+
+        OdCostMatrix {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for OdCostMatrix {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        match serde_json::to_string(&self.parameters) {
+            Ok(json_str) => return format!("{{\"parameters\":{}}}", json_str),
+            Err(err) => return format!("{:?}", err),
+        }
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+        cancel: &CancellationToken,
+    ) -> Result<(), Error> {
+        let args = ParsedArgs::parse(&self.get_tool_parameters(), &args, working_directory)?;
+        self.run_with_parsed_args(args, working_directory, verbose, cancel)
+    }
+
+    fn run_with_parsed_args<'a>(
+        &self,
+        args: ParsedArgs,
+        _working_directory: &'a str,
+        verbose: bool,
+        cancel: &CancellationToken,
+    ) -> Result<(), Error> {
+        let network_file = args.get_file("network").unwrap();
+        let origins_file = args.get_file("origins").unwrap();
+        let destinations_file = args.get_file("destinations");
+        let output_file = args.get_file("output").unwrap();
+        let max_cost = args.get_float("max_cost").unwrap_or(f64::INFINITY);
+        let options = NetworkOptions {
+            cost_field: args.get_string("cost_field").filter(|s| !s.is_empty()),
+            oneway_field: args.get_string("oneway_field").filter(|s| !s.is_empty()),
+            snap_distance: args.get_float("snap").unwrap_or(0f64),
+        };
+
+        if max_cost < 0f64 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The maximum cost (--max_cost) must not be negative.",
+            ));
+        }
+
+        let mut progress: usize;
+        let mut old_progress: usize = 1;
+
+        let start = Instant::now();
+
+        if verbose {
+            let tool_name = self.get_tool_name();
+            progress::welcome(&tool_name);
+        }
+
+        if verbose {
+            progress::message("Reading data...")
+        };
+
+        let network = Shapefile::read(&network_file)?;
+        let origins = Shapefile::read(&origins_file)?;
+
+        if verbose {
+            progress::message("Building network...")
+        };
+        let graph = NetworkGraph::from_shapefile(&network, &options)?;
+        let origin_locations = graph.locate_points(&origins)?;
+        let destination_locations = match destinations_file {
+            Some(file) => graph.locate_points(&Shapefile::read(&file)?)?,
+            None => origin_locations.clone(),
+        };
+
+        let graph = Arc::new(graph);
+        let origin_locations = Arc::new(origin_locations);
+        let destination_locations = Arc::new(destination_locations);
+        let num_origins = origins.num_records;
+        let mut num_procs = num_cpus::get() as isize;
+        let configs = whitebox_common::configs::get_configs()?;
+        let max_procs = configs.max_procs;
+        if max_procs > 0 && max_procs < num_procs {
+            num_procs = max_procs;
+        }
+        let (tx, rx) = mpsc::channel();
+        for tid in 0..num_procs {
+            let graph = graph.clone();
+            let origin_locations = origin_locations.clone();
+            let destination_locations = destination_locations.clone();
+            let tx = tx.clone();
+            progress::spawn(move || {
+                for origin in (0..num_origins).filter(|o| *o as isize % num_procs == tid) {
+                    let mut costs = vec![];
+                    if let Some(location) = &origin_locations[origin] {
+                        let tree = graph.shortest_path_tree(location, max_cost, false);
+                        for (destination, l) in destination_locations.iter().enumerate() {
+                            if let Some(l) = l {
+                                let cost = graph.cost_to(&tree, l);
+                                if cost.is_finite() {
+                                    costs.push((destination, cost));
+                                }
+                            }
+                        }
+                    }
+                    if tx.send((origin, costs)).is_err() {
+                        return;
+                    }
+                }
+            });
+        }
+        drop(tx);
+
+        let mut matrix = vec![vec![]; num_origins];
+        for i in 0..num_origins {
+            let (origin, costs) = cancel.recv(&rx)?;
+            matrix[origin] = costs;
+            if verbose {
+                progress = (100.0_f64 * (i + 1) as f64 / num_origins as f64) as usize;
+                if progress != old_progress {
+                    progress::update_stage("Calculating costs", progress);
+                    old_progress = progress;
+                }
+            }
+        }
+
+        if verbose {
+            progress::message("Saving data...")
+        };
+        let f = File::create(&output_file)?;
+        let mut writer = BufWriter::new(f);
+        writer.write_all("ORIGIN,DESTINATION,COST\n".as_bytes())?;
+        for (origin, costs) in matrix.iter().enumerate() {
+            for &(destination, cost) in costs {
+                writer.write_all(
+                    format!("{},{},{}\n", origin + 1, destination + 1, cost).as_bytes(),
+                )?;
+            }
+        }
+        writer.flush()?;
+
+        if verbose {
+            progress::output_written(&output_file);
+        }
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+
+        if verbose {
+            progress::message(format!("Elapsed Time: {}", elapsed_time));
+        }
+
+        Ok(())
+    }
+}
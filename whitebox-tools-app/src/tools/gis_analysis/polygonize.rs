@@ -11,6 +11,7 @@ use whitebox_common::algorithms::{
     find_split_points_at_line_intersections, is_clockwise_order, poly_in_poly,
 };
 use whitebox_common::structures::{BoundingBox, Polyline, Point2D};
+use whitebox_common::progress;
 use crate::tools::*;
 use whitebox_vector::*;
 use kdtree::distance::squared_euclidean;
@@ -52,7 +53,7 @@ impl Polygonize {
         let mut parameters = vec![];
         parameters.push(ToolParameter {
             name: "Input Vector Lines File".to_owned(),
-            flags: vec!["-i".to_owned(), "--inputs".to_owned()],
+            flags: vec!["-i".to_owned(), "--input".to_owned(), "--inputs".to_owned()],
             description: "Input vector polyline file.".to_owned(),
             parameter_type: ParameterType::FileList(ParameterFileType::Vector(
                 VectorGeometryType::Line,
@@ -140,6 +141,7 @@ impl WhiteboxTool for Polygonize {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_files = String::new();
         let mut output_file: String = "".to_string();
@@ -184,13 +186,7 @@ impl WhiteboxTool for Polygonize {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         if !output_file.contains(&sep) && !output_file.contains("/") {
@@ -215,7 +211,7 @@ impl WhiteboxTool for Polygonize {
         let mut bb: Vec<BoundingBox> = vec![];
 
         if verbose {
-            println!("Reading data...")
+            progress::message("Reading data...")
         };
         let mut projection = String::new();
         for value in vec {
@@ -298,11 +294,12 @@ impl WhiteboxTool for Polygonize {
                 }
             }
 
+            cancel.check()?;
             if verbose {
                 progress =
                     (100.0_f64 * (record_num1 + 1) as f64 / in_polylines.len() as f64) as usize;
                 if progress != old_progress {
-                    println!("Finding line intersections: {}%", progress);
+                    progress::update_stage("Finding line intersections", progress);
                     old_progress = progress;
                 }
             }
@@ -343,7 +340,7 @@ impl WhiteboxTool for Polygonize {
         let mut p2: Point2D;
         let mut p3: Point2D;
         let mut p4: Point2D;
-        println!("Creating tree...");
+        progress::message("Creating tree...");
         for i in 0..polylines.len() {
             p1 = polylines[i].first_vertex();
             kdtree.add([p1.x, p1.y], first_node_id(i)).unwrap();
@@ -354,7 +351,7 @@ impl WhiteboxTool for Polygonize {
 
         // Find the neighbours of each endnode and check for dangling arcs
         // and self-closing arcs which form single-line polys.
-        println!("Finding node vertices...");
+        progress::message("Finding node vertices...");
         let mut is_acyclic_arc = vec![false; polylines.len()];
         let mut node_angles: Vec<Vec<f64>> = vec![vec![]; num_endnodes];
         let mut heading: f64;
@@ -475,10 +472,11 @@ impl WhiteboxTool for Polygonize {
                 }
             }
 
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * (i + 1) as f64 / polylines.len() as f64) as usize;
                 if progress != old_progress {
-                    println!("Finding acyclic arcs: {}%", progress);
+                    progress::update_stage("Finding acyclic arcs", progress);
                     old_progress = progress;
                 }
             }
@@ -794,10 +792,11 @@ impl WhiteboxTool for Polygonize {
                 }
             }
 
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * (i + 1) as f64 / polylines.len() as f64) as usize;
                 if progress != old_progress {
-                    println!("Finding polygons: {}%", progress);
+                    progress::update_stage("Finding polygons", progress);
                     old_progress = progress;
                 }
             }
@@ -1201,12 +1200,12 @@ impl WhiteboxTool for Polygonize {
         // }
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
@@ -1215,7 +1214,7 @@ impl WhiteboxTool for Polygonize {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!("{}", &format!("Elapsed Time: {}", elapsed_time));
+            progress::message(format!("Elapsed Time: {}", elapsed_time));
         }
 
         Ok(())
@@ -9,6 +9,7 @@ License: MIT
 use whitebox_common::algorithms::{convex_hull, point_in_poly};
 use whitebox_raster::*;
 use whitebox_common::structures::{Basis, Point2D, RadialBasisFunction};
+use whitebox_common::progress;
 use crate::tools::*;
 use whitebox_vector::{FieldData, ShapeType, Shapefile};
 use kdtree::distance::squared_euclidean;
@@ -21,7 +22,6 @@ use std::io::{Error, ErrorKind};
 use std::path;
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::thread;
 
 /// This tool interpolates vector points into a raster surface using a radial basis function (RBF) scheme.
 pub struct RadialBasisFunctionInterpolation {
@@ -141,7 +141,7 @@ impl RadialBasisFunctionInterpolation {
 
         parameters.push(ToolParameter{
             name: "Cell Size (optional)".to_owned(), 
-            flags: vec!["--cell_size".to_owned()], 
+            flags: vec!["--resolution".to_owned(), "--cell_size".to_owned()], 
             description: "Optionally specified cell size of output raster. Not used when base raster is specified.".to_owned(),
             parameter_type: ParameterType::Float,
             default_value: None,
@@ -215,6 +215,7 @@ impl WhiteboxTool for RadialBasisFunctionInterpolation {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut field_name = String::new();
@@ -346,13 +347,7 @@ impl WhiteboxTool for RadialBasisFunctionInterpolation {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -383,7 +378,7 @@ impl WhiteboxTool for RadialBasisFunctionInterpolation {
         radius = radius * radius; // squared distances are used
 
         if verbose {
-            println!("Reading data...")
+            progress::message("Reading data...")
         };
         let input = Shapefile::read(&input_file)?;
 
@@ -445,11 +440,12 @@ impl WhiteboxTool for RadialBasisFunctionInterpolation {
                 }
             }
 
+            cancel.check()?;
             if verbose {
                 progress =
                     (100.0_f64 * (record_num + 1) as f64 / input.num_records as f64) as usize;
                 if progress != old_progress {
-                    println!("Reading points: {}%", progress);
+                    progress::update_stage("Reading points", progress);
                     old_progress = progress;
                 }
             }
@@ -543,7 +539,7 @@ impl WhiteboxTool for RadialBasisFunctionInterpolation {
             let hull = hull.clone();
             let tree = tree.clone();
             let tx = tx.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let (mut x, mut y): (f64, f64);
                 let mut z: f64;
                 let mut point_num;
@@ -578,18 +574,20 @@ impl WhiteboxTool for RadialBasisFunctionInterpolation {
                             }
                         }
                     }
-                    tx.send((row, data)).unwrap();
+                    if tx.send((row, data)).is_err() {
+                        return;
+                    }
                 }
             });
         }
 
         for row in 0..rows {
-            let data = rx.recv().expect("Error receiving data from thread.");
+            let data = cancel.recv(&rx)?;
             output.set_row_data(data.0, data.1);
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    progress::update(progress);
                     old_progress = progress;
                 }
             }
@@ -611,22 +609,19 @@ impl WhiteboxTool for RadialBasisFunctionInterpolation {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
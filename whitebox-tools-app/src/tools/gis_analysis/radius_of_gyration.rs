@@ -7,6 +7,7 @@ License: MIT
 */
 
 use whitebox_raster::*;
+use whitebox_common::progress;
 use crate::tools::*;
 use num_cpus;
 use std::env;
@@ -15,7 +16,6 @@ use std::io::{Error, ErrorKind};
 use std::path;
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::thread;
 
 /// This can be used to calculate the radius of gyration (RoG) for the polygon
 /// features within a raster image. RoG measures how far across the landscape a polygon
@@ -133,6 +133,7 @@ impl WhiteboxTool for RadiusOfGyration {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut output_file = String::new();
@@ -175,13 +176,7 @@ impl WhiteboxTool for RadiusOfGyration {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -197,7 +192,7 @@ impl WhiteboxTool for RadiusOfGyration {
         }
 
         if verbose {
-            println!("Reading data...")
+            progress::message("Reading data...")
         };
 
         let input = Arc::new(Raster::new(&input_file, "r")?);
@@ -223,7 +218,7 @@ impl WhiteboxTool for RadiusOfGyration {
         for tid in 0..num_procs {
             let input = input.clone();
             let tx = tx.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let mut z: f64;
                 let mut a: usize;
                 for row in (0..rows).filter(|r| r % num_procs == tid) {
@@ -239,7 +234,9 @@ impl WhiteboxTool for RadiusOfGyration {
                             total_n[a] += 1usize;
                         }
                     }
-                    tx.send((total_columns, total_rows, total_n)).unwrap();
+                    if tx.send((total_columns, total_rows, total_n)).is_err() {
+                        return;
+                    }
                 }
             });
         }
@@ -249,7 +246,7 @@ impl WhiteboxTool for RadiusOfGyration {
         let mut total_n = vec![0usize; range + 1];
 
         for row in 0..rows {
-            let (tc, tr, n) = rx.recv().expect("Error receiving data from thread.");
+            let (tc, tr, n) = cancel.recv(&rx)?;
 
             for a in 0..range + 1 {
                 total_columns[a] += tc[a];
@@ -260,7 +257,7 @@ impl WhiteboxTool for RadiusOfGyration {
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress (Loop 1 of 3): {}%", progress);
+                    progress::update_stage("Progress (Loop 1 of 3)", progress);
                     old_progress = progress;
                 }
             }
@@ -283,7 +280,7 @@ impl WhiteboxTool for RadiusOfGyration {
             let centroid_x = centroid_x.clone();
             let centroid_y = centroid_y.clone();
             let tx = tx.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let mut z: f64;
                 let mut a: usize;
                 for row in (0..rows).filter(|r| r % num_procs == tid) {
@@ -298,14 +295,16 @@ impl WhiteboxTool for RadiusOfGyration {
                                     * ((row as f64 - centroid_y[a]) * resolution_y)
                         }
                     }
-                    tx.send(gyradius).unwrap();
+                    if tx.send(gyradius).is_err() {
+                        return;
+                    }
                 }
             });
         }
 
         let mut gyradius = vec![0f64; range + 1];
         for row in 0..rows {
-            let g = rx.recv().expect("Error receiving data from thread.");
+            let g = cancel.recv(&rx)?;
             for a in 0..range + 1 {
                 gyradius[a] += g[a];
             }
@@ -313,7 +312,7 @@ impl WhiteboxTool for RadiusOfGyration {
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress (Loop 2 of 3): {}%", progress);
+                    progress::update_stage("Progress (Loop 2 of 3)", progress);
                     old_progress = progress;
                 }
             }
@@ -338,20 +337,21 @@ impl WhiteboxTool for RadiusOfGyration {
                     output.set_value(row, col, z);
                 }
             }
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress (Loop 3 of 3): {}%", progress);
+                    progress::update_stage("Progress (Loop 3 of 3)", progress);
                     old_progress = progress;
                 }
             }
         }
 
         if text_output {
-            println!("Patch Radius of Gyration\nPatch ID\tValue");
+            progress::message("Patch Radius of Gyration\nPatch ID\tValue");
             for a in 0..range + 1 {
                 if total_n[a] > 0 {
-                    println!("{:.0}\t{:.4}", (a + min_val), gyradius[a]);
+                    progress::message(format!("{:.0}\t{:.4}", (a + min_val), gyradius[a]));
                 }
             }
         }
@@ -367,22 +367,19 @@ impl WhiteboxTool for RadiusOfGyration {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
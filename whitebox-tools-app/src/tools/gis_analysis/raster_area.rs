@@ -7,6 +7,7 @@ License: MIT
 */
 
 use whitebox_raster::*;
+use whitebox_common::progress;
 use crate::tools::*;
 use num_cpus;
 use std::env;
@@ -15,7 +16,6 @@ use std::io::{Error, ErrorKind};
 use std::path;
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::thread;
 
 /// This tools estimates the area of each category, polygon, or patch in an input raster. The input raster must be categorical
 /// in data scale. Rasters with floating-point cell values are not good candidates for an area analysis. The user must specify
@@ -161,6 +161,7 @@ impl WhiteboxTool for RasterArea {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut output_file = String::new();
@@ -217,13 +218,7 @@ impl WhiteboxTool for RasterArea {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -236,7 +231,7 @@ impl WhiteboxTool for RasterArea {
         }
 
         if !output_raster && !output_text {
-            println!("Warning: Niether a raster nor text outputs were selected. An area raster will be generated.");
+            progress::warning("Niether a raster nor text outputs were selected. An area raster will be generated.");
             output_file = String::from("area.tif");
             output_raster = true;
         }
@@ -248,7 +243,7 @@ impl WhiteboxTool for RasterArea {
         }
 
         if verbose {
-            println!("Reading data...")
+            progress::message("Reading data...")
         };
 
         let input = Arc::new(Raster::new(&input_file, "r")?);
@@ -275,7 +270,7 @@ impl WhiteboxTool for RasterArea {
             for tid in 0..num_procs {
                 let input = input.clone();
                 let tx = tx.clone();
-                thread::spawn(move || {
+                progress::spawn(move || {
                     let mut freq_data = vec![0usize; num_bins];
                     let mut val: f64;
                     let mut bin: usize;
@@ -289,13 +284,13 @@ impl WhiteboxTool for RasterArea {
                             }
                         }
                     }
-                    tx.send(freq_data).unwrap();
+                    let _ = tx.send(freq_data);
                 });
             }
 
             let mut freq_data = vec![0usize; num_bins];
             for tid in 0..num_procs {
-                let data = rx.recv().expect("Error receiving data from thread.");
+                let data = cancel.recv(&rx)?;
                 for a in 0..num_bins {
                     freq_data[a] += data[a];
                 }
@@ -303,7 +298,7 @@ impl WhiteboxTool for RasterArea {
                 if verbose {
                     progress = (100.0_f64 * (tid + 1) as f64 / num_procs as f64) as usize;
                     if progress != old_progress {
-                        println!("Progress: {}%", progress);
+                        progress::update(progress);
                         old_progress = progress;
                     }
                 }
@@ -326,10 +321,11 @@ impl WhiteboxTool for RasterArea {
                             output.set_value(row, col, freq_data[bin] as f64);
                         }
                     }
+                    cancel.check()?;
                     if verbose {
                         progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                         if progress != old_progress {
-                            println!("Outputting raster: {}%", progress);
+                            progress::update_stage("Outputting raster", progress);
                             old_progress = progress;
                         }
                     }
@@ -345,23 +341,23 @@ impl WhiteboxTool for RasterArea {
                     .add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
                 if verbose {
-                    println!("Saving data...")
+                    progress::message("Saving data...")
                 };
                 let _ = match output.write() {
                     Ok(_) => {
                         if verbose {
-                            println!("Output file written")
+                            progress::output_written(&output.file_name)
                         }
                     }
                     Err(e) => return Err(e),
                 };
             }
             if output_text {
-                println!("Class,Cells");
+                progress::message("Class,Cells");
                 for a in 0..num_bins {
                     if freq_data[a] > 0 {
                         val = (a as f64 + min_val).floor();
-                        println!("{},{}", val, freq_data[a]);
+                        progress::message(format!("{},{}", val, freq_data[a]));
                     }
                 }
             }
@@ -369,7 +365,7 @@ impl WhiteboxTool for RasterArea {
             // map units
             let is_geographic = input.is_in_geographic_coordinates();
             if is_geographic && verbose {
-                println!("Warning: the input file does not appear to be in a projected coordinate system. Area values will only be estimates.");
+                progress::warning("the input file does not appear to be in a projected coordinate system. Area values will only be estimates.");
             }
 
             let mut num_procs = num_cpus::get() as isize;
@@ -382,7 +378,7 @@ impl WhiteboxTool for RasterArea {
             for tid in 0..num_procs {
                 let input = input.clone();
                 let tx = tx.clone();
-                thread::spawn(move || {
+                progress::spawn(move || {
                     let mut resx = input.configs.resolution_x;
                     let mut resy = input.configs.resolution_y;
                     let mut cell_area = resx * resy;
@@ -406,7 +402,7 @@ impl WhiteboxTool for RasterArea {
                             }
                         }
                     }
-                    tx.send(area_data).unwrap();
+                    let _ = tx.send(area_data);
                 });
             }
 
@@ -415,7 +411,7 @@ impl WhiteboxTool for RasterArea {
             // cell size is not constant for the data.
             let mut area_data = vec![0f64; num_bins];
             for tid in 0..num_procs {
-                let data = rx.recv().expect("Error receiving data from thread.");
+                let data = cancel.recv(&rx)?;
                 for a in 0..num_bins {
                     area_data[a] += data[a];
                 }
@@ -423,7 +419,7 @@ impl WhiteboxTool for RasterArea {
                 if verbose {
                     progress = (100.0_f64 * (tid + 1) as f64 / num_procs as f64) as usize;
                     if progress != old_progress {
-                        println!("Progress: {}%", progress);
+                        progress::update(progress);
                         old_progress = progress;
                     }
                 }
@@ -446,10 +442,11 @@ impl WhiteboxTool for RasterArea {
                             output.set_value(row, col, area_data[bin]);
                         }
                     }
+                    cancel.check()?;
                     if verbose {
                         progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                         if progress != old_progress {
-                            println!("Outputting raster: {}%", progress);
+                            progress::update_stage("Outputting raster", progress);
                             old_progress = progress;
                         }
                     }
@@ -465,23 +462,23 @@ impl WhiteboxTool for RasterArea {
                     .add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
                 if verbose {
-                    println!("Saving data...")
+                    progress::message("Saving data...")
                 };
                 let _ = match output.write() {
                     Ok(_) => {
                         if verbose {
-                            println!("Output file written")
+                            progress::output_written(&output.file_name)
                         }
                     }
                     Err(e) => return Err(e),
                 };
             }
             if output_text {
-                println!("Class,Area");
+                progress::message("Class,Area");
                 for a in 0..num_bins {
                     if area_data[a] > 0f64 {
                         val = (a as f64 + min_val).floor();
-                        println!("{},{}", val, area_data[a]);
+                        progress::message(format!("{},{}", val, area_data[a]));
                     }
                 }
             }
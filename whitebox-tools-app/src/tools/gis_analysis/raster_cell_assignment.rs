@@ -7,6 +7,7 @@ License: MIT
 */
 
 use whitebox_raster::*;
+use whitebox_common::progress;
 use crate::tools::*;
 use num_cpus;
 use std::env;
@@ -15,7 +16,6 @@ use std::io::{Error, ErrorKind};
 use std::path;
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::thread;
 
 /// This tool can be used to create a new raster with the same coordinates and dimensions
 /// (i.e. rows and columns) as an existing base image. Grid cells in the new raster will be
@@ -126,6 +126,7 @@ impl WhiteboxTool for RasterCellAssignment {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut output_file = String::new();
@@ -171,13 +172,7 @@ impl WhiteboxTool for RasterCellAssignment {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -194,7 +189,7 @@ impl WhiteboxTool for RasterCellAssignment {
         }
 
         if verbose {
-            println!("Reading data...")
+            progress::message("Reading data...")
         };
         let input = Arc::new(Raster::new(&input_file, "r")?);
 
@@ -216,13 +211,15 @@ impl WhiteboxTool for RasterCellAssignment {
             "column" | "columns" | "col" => {
                 for tid in 0..num_procs {
                     let tx = tx.clone();
-                    thread::spawn(move || {
+                    progress::spawn(move || {
                         for row in (0..rows).filter(|r| r % num_procs == tid) {
                             let mut data: Vec<f64> = vec![nodata; columns as usize];
                             for col in 0..columns {
                                 data[col as usize] = col as f64;
                             }
-                            tx.send((row, data)).unwrap();
+                            if tx.send((row, data)).is_err() {
+                                return;
+                            }
                         }
                     });
                 }
@@ -230,13 +227,15 @@ impl WhiteboxTool for RasterCellAssignment {
             "row" | "rows" => {
                 for tid in 0..num_procs {
                     let tx = tx.clone();
-                    thread::spawn(move || {
+                    progress::spawn(move || {
                         for row in (0..rows).filter(|r| r % num_procs == tid) {
                             let mut data: Vec<f64> = vec![nodata; columns as usize];
                             for col in 0..columns {
                                 data[col as usize] = row as f64;
                             }
-                            tx.send((row, data)).unwrap();
+                            if tx.send((row, data)).is_err() {
+                                return;
+                            }
                         }
                     });
                 }
@@ -245,13 +244,15 @@ impl WhiteboxTool for RasterCellAssignment {
                 for tid in 0..num_procs {
                     let input = input.clone();
                     let tx = tx.clone();
-                    thread::spawn(move || {
+                    progress::spawn(move || {
                         for row in (0..rows).filter(|r| r % num_procs == tid) {
                             let mut data: Vec<f64> = vec![nodata; columns as usize];
                             for col in 0..columns {
                                 data[col as usize] = input.get_x_from_column(col);
                             }
-                            tx.send((row, data)).unwrap();
+                            if tx.send((row, data)).is_err() {
+                                return;
+                            }
                         }
                     });
                 }
@@ -260,13 +261,15 @@ impl WhiteboxTool for RasterCellAssignment {
                 for tid in 0..num_procs {
                     let input = input.clone();
                     let tx = tx.clone();
-                    thread::spawn(move || {
+                    progress::spawn(move || {
                         for row in (0..rows).filter(|r| r % num_procs == tid) {
                             let mut data: Vec<f64> = vec![nodata; columns as usize];
                             for col in 0..columns {
                                 data[col as usize] = input.get_y_from_row(row);
                             }
-                            tx.send((row, data)).unwrap();
+                            if tx.send((row, data)).is_err() {
+                                return;
+                            }
                         }
                     });
                 }
@@ -282,13 +285,13 @@ impl WhiteboxTool for RasterCellAssignment {
         output.configs.photometric_interp = PhotometricInterpretation::Continuous;
         output.configs.data_type = DataType::F32;
         for r in 0..rows {
-            let (row, data) = rx.recv().expect("Error receiving data from thread.");
+            let (row, data) = cancel.recv(&rx)?;
             output.set_row_data(row, data);
 
             if verbose {
                 progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    progress::update(progress);
                     old_progress = progress;
                 }
             }
@@ -303,22 +306,19 @@ impl WhiteboxTool for RasterCellAssignment {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -7,6 +7,7 @@ License: MIT
 */
 
 use whitebox_raster::*;
+use whitebox_common::progress;
 use crate::tools::*;
 use num_cpus;
 use std::env;
@@ -15,7 +16,6 @@ use std::io::{Error, ErrorKind};
 use std::path;
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::thread;
 
 /// This tool can be used to measure the length of the perimeter of polygon features in a raster layer. The user must
 /// specify the name of the input raster file (`--input`) and optionally an output raster (`--output`), which is the
@@ -161,6 +161,7 @@ impl WhiteboxTool for RasterPerimeter {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut output_file = String::new();
@@ -217,13 +218,7 @@ impl WhiteboxTool for RasterPerimeter {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -236,7 +231,7 @@ impl WhiteboxTool for RasterPerimeter {
         }
 
         if !output_raster && !output_text {
-            println!("Warning: Niether a raster nor text outputs were selected. An area raster will be generated.");
+            progress::warning("Niether a raster nor text outputs were selected. An area raster will be generated.");
             output_file = String::from("area.tif");
             output_raster = true;
         }
@@ -248,7 +243,7 @@ impl WhiteboxTool for RasterPerimeter {
         }
 
         if verbose {
-            println!("Reading data...")
+            progress::message("Reading data...")
         };
 
         let input = Arc::new(Raster::new(&input_file, "r")?);
@@ -532,7 +527,7 @@ impl WhiteboxTool for RasterPerimeter {
 
         let is_geographic = input.is_in_geographic_coordinates();
         if is_geographic && verbose {
-            println!("Warning: the input file does not appear to be in a projected coordinate system. Perimeter values will only be estimates.");
+            progress::warning("the input file does not appear to be in a projected coordinate system. Perimeter values will only be estimates.");
         }
 
         let mut num_procs = num_cpus::get() as isize;
@@ -545,7 +540,7 @@ impl WhiteboxTool for RasterPerimeter {
         for tid in 0..num_procs {
             let input = input.clone();
             let tx = tx.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let mut resx = input.configs.resolution_x;
                 let mut resy = input.configs.resolution_y;
                 let mut data = vec![0f64; num_bins];
@@ -582,13 +577,13 @@ impl WhiteboxTool for RasterPerimeter {
                         }
                     }
                 }
-                tx.send(data).unwrap();
+                let _ = tx.send(data);
             });
         }
 
         let mut data = vec![0f64; num_bins];
         for tid in 0..num_procs {
-            let data_rx = rx.recv().expect("Error receiving data from thread.");
+            let data_rx = cancel.recv(&rx)?;
             for a in 0..num_bins {
                 data[a] += data_rx[a] * avg_res;
             }
@@ -596,7 +591,7 @@ impl WhiteboxTool for RasterPerimeter {
             if verbose {
                 progress = (100.0_f64 * (tid + 1) as f64 / num_procs as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    progress::update(progress);
                     old_progress = progress;
                 }
             }
@@ -619,10 +614,11 @@ impl WhiteboxTool for RasterPerimeter {
                         output.set_value(row, col, data[bin] as f64);
                     }
                 }
+                cancel.check()?;
                 if verbose {
                     progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Outputting raster: {}%", progress);
+                        progress::update_stage("Outputting raster", progress);
                         old_progress = progress;
                     }
                 }
@@ -637,23 +633,23 @@ impl WhiteboxTool for RasterPerimeter {
             output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
             if verbose {
-                println!("Saving data...")
+                progress::message("Saving data...")
             };
             let _ = match output.write() {
                 Ok(_) => {
                     if verbose {
-                        println!("Output file written")
+                        progress::output_written(&output.file_name)
                     }
                 }
                 Err(e) => return Err(e),
             };
         }
         if output_text {
-            println!("Class,Perimeter");
+            progress::message("Class,Perimeter");
             for a in 0..num_bins {
                 if data[a] > 0f64 {
                     val = (a as f64 + min_val).floor();
-                    println!("{},{}", val, data[a]);
+                    progress::message(format!("{},{}", val, data[a]));
                 }
             }
         }
@@ -7,6 +7,7 @@ License: MIT
 */
 
 use whitebox_raster::*;
+use whitebox_common::progress;
 use crate::tools::*;
 use num_cpus;
 use std::collections::HashMap;
@@ -16,7 +17,6 @@ use std::io::{Error, ErrorKind};
 use std::path;
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::thread;
 
 /// This tool creates a new raster in which the value of each grid cell is determined by an input raster (`--input`) and a
 /// collection of user-defined classes. The user must specify the *New* value, the *From* value, and the *To Just Less Than*
@@ -153,6 +153,7 @@ impl WhiteboxTool for Reclass {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut output_file = String::new();
@@ -205,13 +206,7 @@ impl WhiteboxTool for Reclass {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -227,7 +222,7 @@ impl WhiteboxTool for Reclass {
         }
 
         if verbose {
-            println!("Reading data...")
+            progress::message("Reading data...")
         };
         let input = Arc::new(Raster::new(&input_file, "r")?);
 
@@ -289,7 +284,7 @@ impl WhiteboxTool for Reclass {
                 let input = input.clone();
                 let reclass_vals = reclass_vals.clone();
                 let tx = tx.clone();
-                thread::spawn(move || {
+                progress::spawn(move || {
                     let mut z: f64;
                     let mut prev_idx: usize = 0;
                     for row in (0..rows).filter(|r| r % num_procs == tid) {
@@ -318,19 +313,21 @@ impl WhiteboxTool for Reclass {
                                 data[col as usize] = z;
                             }
                         }
-                        tx.send((row, data)).unwrap();
+                        if tx.send((row, data)).is_err() {
+                            return;
+                        }
                     }
                 });
             }
 
             for r in 0..rows {
-                let (row, data) = rx.recv().expect("Error receiving data from thread.");
+                let (row, data) = cancel.recv(&rx)?;
                 output.set_row_data(row, data);
 
                 if verbose {
                     progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Progress: {}%", progress);
+                        progress::update(progress);
                         old_progress = progress;
                     }
                 }
@@ -357,7 +354,7 @@ impl WhiteboxTool for Reclass {
                 let input = input.clone();
                 let assign_map = assign_map.clone();
                 let tx = tx.clone();
-                thread::spawn(move || {
+                progress::spawn(move || {
                     let mut z: f64;
                     for row in (0..rows).filter(|r| r % num_procs == tid) {
                         let mut data: Vec<f64> = vec![nodata; columns as usize];
@@ -373,19 +370,21 @@ impl WhiteboxTool for Reclass {
                                 data[col as usize] = z;
                             }
                         }
-                        tx.send((row, data)).unwrap();
+                        if tx.send((row, data)).is_err() {
+                            return;
+                        }
                     }
                 });
             }
 
             for r in 0..rows {
-                let (row, data) = rx.recv().expect("Error receiving data from thread.");
+                let (row, data) = cancel.recv(&rx)?;
                 output.set_row_data(row, data);
 
                 if verbose {
                     progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Progress: {}%", progress);
+                        progress::update(progress);
                         old_progress = progress;
                     }
                 }
@@ -402,22 +401,19 @@ impl WhiteboxTool for Reclass {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -7,6 +7,7 @@ License: MIT
 */
 
 use whitebox_raster::*;
+use whitebox_common::progress;
 use crate::tools::*;
 use num_cpus;
 use std::collections::HashMap;
@@ -19,7 +20,6 @@ use std::io::{Error, ErrorKind};
 use std::path;
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::thread;
 
 /// This tool creates a new raster image in which the value of each grid cell is determined by the values in an
 /// input raster image (`--input`) and a reclass file (`--reclass_file`). The reclass file is a text file
@@ -141,6 +141,7 @@ impl WhiteboxTool for ReclassFromFile {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut output_file = String::new();
@@ -187,13 +188,7 @@ impl WhiteboxTool for ReclassFromFile {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -209,7 +204,7 @@ impl WhiteboxTool for ReclassFromFile {
         }
 
         if verbose {
-            println!("Reading data...")
+            progress::message("Reading data...")
         };
         let input = Arc::new(Raster::new(&input_file, "r")?);
 
@@ -284,7 +279,7 @@ impl WhiteboxTool for ReclassFromFile {
                 let input = input.clone();
                 let reclass_vals = reclass_vals.clone();
                 let tx = tx.clone();
-                thread::spawn(move || {
+                progress::spawn(move || {
                     let mut z: f64;
                     let mut prev_idx: usize = 0;
                     for row in (0..rows).filter(|r| r % num_procs == tid) {
@@ -313,19 +308,21 @@ impl WhiteboxTool for ReclassFromFile {
                                 data[col as usize] = z;
                             }
                         }
-                        tx.send((row, data)).unwrap();
+                        if tx.send((row, data)).is_err() {
+                            return;
+                        }
                     }
                 });
             }
 
             for r in 0..rows {
-                let (row, data) = rx.recv().expect("Error receiving data from thread.");
+                let (row, data) = cancel.recv(&rx)?;
                 output.set_row_data(row, data);
 
                 if verbose {
                     progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Progress: {}%", progress);
+                        progress::update(progress);
                         old_progress = progress;
                     }
                 }
@@ -352,7 +349,7 @@ impl WhiteboxTool for ReclassFromFile {
                 let input = input.clone();
                 let assign_map = assign_map.clone();
                 let tx = tx.clone();
-                thread::spawn(move || {
+                progress::spawn(move || {
                     let mut z: f64;
                     for row in (0..rows).filter(|r| r % num_procs == tid) {
                         let mut data: Vec<f64> = vec![nodata; columns as usize];
@@ -368,19 +365,21 @@ impl WhiteboxTool for ReclassFromFile {
                                 data[col as usize] = z;
                             }
                         }
-                        tx.send((row, data)).unwrap();
+                        if tx.send((row, data)).is_err() {
+                            return;
+                        }
                     }
                 });
             }
 
             for r in 0..rows {
-                let (row, data) = rx.recv().expect("Error receiving data from thread.");
+                let (row, data) = cancel.recv(&rx)?;
                 output.set_row_data(row, data);
 
                 if verbose {
                     progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Progress: {}%", progress);
+                        progress::update(progress);
                         old_progress = progress;
                     }
                 }
@@ -397,22 +396,19 @@ impl WhiteboxTool for ReclassFromFile {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
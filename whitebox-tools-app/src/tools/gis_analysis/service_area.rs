@@ -0,0 +1,394 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: Dr. John Lindsay
+Created: 18/10/2026
+Last Modified: 18/10/2026
+License: MIT
+*/
+
+use whitebox_common::algorithms::{buffer_polylines, BufferStyle};
+use whitebox_common::progress;
+use crate::tools::*;
+use whitebox_vector::*;
+use num_cpus;
+use std::env;
+use std::f64;
+use std::io::{Error, ErrorKind};
+use std::path;
+use std::sync::mpsc;
+use std::sync::Arc;
+
+/// This tool maps the areas that can be reached from a set of facilities (`--facilities`), such
+/// as fire stations or schools, by travelling along a line network (`--network`) within a set of
+/// costs (`--breaks`), e.g. the areas within 5, 10, and 15 minutes' drive. The cost breaks are
+/// given as a comma-separated list. The parts of the network that can be reached within each cost
+/// are buffered by a distance (`--dist`) to create the output (`--output`) service-area polygons,
+/// which have `FACILITY` and `COST` fields holding the record number of the facility and the cost
+/// break. Each polygon covers the full area that can be reached within its cost, and so contains
+/// the polygons of the same facility with smaller costs. The service areas of different
+/// facilities are output separately and may overlap. By default, the costs are those of travel
+/// from the facilities; if the `--to_facility` flag is specified, they are the costs of travel to
+/// the facilities instead, which differ where there are one-way lines.
+///
+/// A facility that is not on the network is joined to it at the nearest point on the nearest
+/// line. The network's lines are connected only at their end points, and lines whose end points
+/// are within the `--snap` distance of one another are joined. The cost of travelling a line is
+/// its length, unless a numeric cost field is specified (`--cost_field`), in which case it is the
+/// field's value for the full length of the line. Lines with null or negative costs cannot be
+/// travelled, and travel on one-way lines may be restricted using a one-way field
+/// (`--oneway_field`), as described for the `ShortestPath` tool.
+///
+/// # See Also
+/// `ShortestPath`, `ClosestFacility`, `OdCostMatrix`, `BufferVector`
+pub struct ServiceArea {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl ServiceArea {
+    pub fn new() -> ServiceArea {
+        // public constructor
+        let name = "ServiceArea".to_string();
+        let toolbox = "GIS Analysis/Network Analysis".to_string();
+        let description =
+            "Maps the areas that can be reached from facilities within a set of costs on a line network."
+                .to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input Network Vector File".to_owned(),
+            flags: vec!["--network".to_owned()],
+            description: "Input vector line network file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Vector(
+                VectorGeometryType::Line,
+            )),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Input Facilities Vector File".to_owned(),
+            flags: vec!["--facilities".to_owned()],
+            description: "Input vector points file of the facilities.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Vector(
+                VectorGeometryType::Point,
+            )),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output Vector File".to_owned(),
+            flags: vec!["-o".to_owned(), "--output".to_owned()],
+            description: "Output vector polygons file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Vector(
+                VectorGeometryType::Polygon,
+            )),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Cost Breaks".to_owned(),
+            flags: vec!["--breaks".to_owned()],
+            description: "Comma-separated list of the costs of the service areas.".to_owned(),
+            parameter_type: ParameterType::StringList,
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Buffer Distance".to_owned(),
+            flags: vec!["--dist".to_owned()],
+            description: "Distance by which the reachable lines are buffered.".to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Measure costs of travel to the facilities?".to_owned(),
+            flags: vec!["--to_facility".to_owned()],
+            description: "Optional flag to measure the costs of travel to, rather than from, the facilities."
+                .to_owned(),
+            parameter_type: ParameterType::Boolean,
+            default_value: Some("false".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Cost Field (optional)".to_owned(),
+            flags: vec!["--cost_field".to_owned()],
+            description: "Optional numeric field holding the cost of travelling each line; line length is used by default."
+                .to_owned(),
+            parameter_type: ParameterType::VectorAttributeField(
+                AttributeType::Number,
+                "--network".to_string(),
+            ),
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "One-way Field (optional)".to_owned(),
+            flags: vec!["--oneway_field".to_owned()],
+            description: "Optional field restricting the direction of travel on each line.".to_owned(),
+            parameter_type: ParameterType::VectorAttributeField(
+                AttributeType::Any,
+                "--network".to_string(),
+            ),
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Snap Distance".to_owned(),
+            flags: vec!["--snap".to_owned()],
+            description: "Distance within which line end points are joined into a network node."
+                .to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: Some("0.0".to_owned()),
+            optional: true,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut parent = env::current_exe().unwrap();
+        parent.pop();
+        let p = format!("{}", parent.display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(
+            ">>.*{0} -r={1} -v --wd=\"*path*to*data*\" --network=roads.shp --facilities=stations.shp -o=service_areas.shp --breaks=5,10,15 --dist=100.0 --cost_field=MINUTES",
+            short_exe, name
+        ).replace("*", &sep);
+
+        ServiceArea {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for ServiceArea {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        match serde_json::to_string(&self.parameters) {
+            Ok(json_str) => return format!("{{\"parameters\":{}}}", json_str),
+            Err(err) => return format!("{:?}", err),
+        }
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+        cancel: &CancellationToken,
+    ) -> Result<(), Error> {
+        let args = ParsedArgs::parse(&self.get_tool_parameters(), &args, working_directory)?;
+        self.run_with_parsed_args(args, working_directory, verbose, cancel)
+    }
+
+    fn run_with_parsed_args<'a>(
+        &self,
+        args: ParsedArgs,
+        _working_directory: &'a str,
+        verbose: bool,
+        cancel: &CancellationToken,
+    ) -> Result<(), Error> {
+        let network_file = args.get_file("network").unwrap();
+        let facilities_file = args.get_file("facilities").unwrap();
+        let output_file = args.get_file("output").unwrap();
+        let break_values = match args.get("breaks") {
+            Some(ArgValue::StringList(values)) => values.clone(),
+            _ => vec![],
+        };
+        let buffer_dist = args.get_float("dist").unwrap();
+        let to_facility = args.get_bool("to_facility");
+        let options = NetworkOptions {
+            cost_field: args.get_string("cost_field").filter(|s| !s.is_empty()),
+            oneway_field: args.get_string("oneway_field").filter(|s| !s.is_empty()),
+            snap_distance: args.get_float("snap").unwrap_or(0f64),
+        };
+
+        let mut breaks = vec![];
+        for value in &break_values {
+            match value.parse::<f64>() {
+                Ok(v) if v >= 0f64 => breaks.push(v),
+                _ => {
+                    return Err(Error::new(
+                        ErrorKind::InvalidInput,
+                        format!("The cost break '{}' is not a non-negative number.", value),
+                    ));
+                }
+            }
+        }
+        if breaks.is_empty() {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "At least one cost break (--breaks) must be specified.",
+            ));
+        }
+        breaks.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        breaks.dedup();
+        if buffer_dist <= 0f64 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The buffer distance (--dist) must be greater than zero.",
+            ));
+        }
+
+        let mut progress: usize;
+        let mut old_progress: usize = 1;
+
+        let start = Instant::now();
+
+        if verbose {
+            let tool_name = self.get_tool_name();
+            progress::welcome(&tool_name);
+        }
+
+        if verbose {
+            progress::message("Reading data...")
+        };
+
+        let network = Shapefile::read(&network_file)?;
+        let facilities = Shapefile::read(&facilities_file)?;
+
+        if verbose {
+            progress::message("Building network...")
+        };
+        let graph = NetworkGraph::from_shapefile(&network, &options)?;
+        let locations = graph.locate_points(&facilities)?;
+
+        let graph = Arc::new(graph);
+        let locations = Arc::new(locations);
+        let breaks = Arc::new(breaks);
+        let num_facilities = facilities.num_records;
+        let mut num_procs = num_cpus::get() as isize;
+        let configs = whitebox_common::configs::get_configs()?;
+        let max_procs = configs.max_procs;
+        if max_procs > 0 && max_procs < num_procs {
+            num_procs = max_procs;
+        }
+        let (tx, rx) = mpsc::channel();
+        for tid in 0..num_procs {
+            let graph = graph.clone();
+            let locations = locations.clone();
+            let breaks = breaks.clone();
+            let tx = tx.clone();
+            progress::spawn(move || {
+                let style = BufferStyle::default();
+                for facility in (0..num_facilities).filter(|f| *f as isize % num_procs == tid) {
+                    let mut areas = vec![];
+                    if let Some(location) = &locations[facility] {
+                        let max_break = breaks[breaks.len() - 1];
+                        let tree = graph.shortest_path_tree(location, max_break, to_facility);
+                        for &cost in breaks.iter() {
+                            let lines = graph.reachable_lines(&tree, cost);
+                            areas.push((cost, buffer_polylines(&lines, buffer_dist, &style)));
+                        }
+                    }
+                    if tx.send((facility, areas)).is_err() {
+                        return;
+                    }
+                }
+            });
+        }
+        drop(tx);
+
+        let mut service_areas = vec![vec![]; num_facilities];
+        for i in 0..num_facilities {
+            let (facility, areas) = cancel.recv(&rx)?;
+            service_areas[facility] = areas;
+            if verbose {
+                progress = (100.0_f64 * (i + 1) as f64 / num_facilities as f64) as usize;
+                if progress != old_progress {
+                    progress::update_stage("Finding service areas", progress);
+                    old_progress = progress;
+                }
+            }
+        }
+
+        let mut output = Shapefile::new(&output_file, ShapeType::Polygon)?;
+        output.projection = network.projection.clone();
+        output
+            .attributes
+            .add_field(&AttributeField::new("FID", FieldDataType::Int, 7u8, 0u8));
+        output
+            .attributes
+            .add_field(&AttributeField::new("FACILITY", FieldDataType::Int, 7u8, 0u8));
+        output
+            .attributes
+            .add_field(&AttributeField::new("COST", FieldDataType::Real, 18u8, 6u8));
+
+        for (facility, areas) in service_areas.into_iter().enumerate() {
+            for (cost, rings) in areas {
+                if rings.is_empty() {
+                    continue;
+                }
+                output.add_record(ShapefileGeometry::from_polygon_rings(rings));
+                output.attributes.add_record(
+                    vec![
+                        FieldData::Int(output.num_records as i32),
+                        FieldData::Int(facility as i32 + 1),
+                        FieldData::Real(cost),
+                    ],
+                    false,
+                );
+            }
+        }
+
+        if verbose {
+            progress::message("Saving data...")
+        };
+        let _ = match output.write() {
+            Ok(_) => {
+                if verbose {
+                    progress::output_written(&output.file_name)
+                }
+            }
+            Err(e) => return Err(e),
+        };
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+
+        if verbose {
+            progress::message(format!("Elapsed Time: {}", elapsed_time));
+        }
+
+        Ok(())
+    }
+}
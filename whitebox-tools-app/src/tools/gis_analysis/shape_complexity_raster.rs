@@ -7,6 +7,7 @@ License: MIT
 */
 
 use whitebox_raster::*;
+use whitebox_common::progress;
 use crate::tools::*;
 use num_cpus;
 use std::env;
@@ -15,7 +16,6 @@ use std::io::{Error, ErrorKind};
 use std::path;
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::thread;
 
 /// This tools calculates a type of shape complexity index for raster objects. The index is equal to the average
 /// number of intersections of the group of vertical and horizontal transects passing through an object. Simple
@@ -73,7 +73,7 @@ impl ShapeComplexityIndexRaster {
             short_exe += ".exe";
         }
         let usage = format!(
-            ">>.*{} -r={} -v --wd=\"*path*to*data*\" -i=input.tif -o=output.tif --zero_back",
+            ">>.*{} -r={} -v --wd=\"*path*to*data*\" -i=input.tif -o=output.tif",
             short_exe, name
         )
         .replace("*", &sep);
@@ -121,6 +121,7 @@ impl WhiteboxTool for ShapeComplexityIndexRaster {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut output_file = String::new();
@@ -158,13 +159,7 @@ impl WhiteboxTool for ShapeComplexityIndexRaster {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -181,7 +176,7 @@ impl WhiteboxTool for ShapeComplexityIndexRaster {
         }
 
         if verbose {
-            println!("Reading data...");
+            progress::message("Reading data...");
         }
 
         let input = Arc::new(Raster::new(&input_file, "r")?);
@@ -206,7 +201,7 @@ impl WhiteboxTool for ShapeComplexityIndexRaster {
         for tid in 0..num_procs {
             let input = input.clone();
             let tx = tx.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let mut freq_data = vec![0usize; num_bins];
                 let mut min_row = vec![isize::max_value(); num_bins];
                 let mut max_row = vec![isize::min_value(); num_bins];
@@ -258,8 +253,7 @@ impl WhiteboxTool for ShapeComplexityIndexRaster {
                     }
                 }
 
-                tx.send((freq_data, min_row, max_row, min_col, max_col))
-                    .unwrap();
+                let _ = tx.send((freq_data, min_row, max_row, min_col, max_col));
             });
         }
 
@@ -270,7 +264,7 @@ impl WhiteboxTool for ShapeComplexityIndexRaster {
         let mut max_col = vec![isize::min_value(); num_bins];
         for tid in 0..num_procs {
             let (data1, data2, data3, data4, data5) =
-                rx.recv().expect("Error receiving data from thread.");
+                cancel.recv(&rx)?;
             for bin in 0..num_bins {
                 freq_data[bin] += data1[bin];
                 if data2[bin] < min_row[bin] {
@@ -290,7 +284,7 @@ impl WhiteboxTool for ShapeComplexityIndexRaster {
             if verbose {
                 progress = (100.0_f64 * (tid + 1) as f64 / num_procs as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    progress::update(progress);
                     old_progress = progress;
                 }
             }
@@ -324,10 +318,11 @@ impl WhiteboxTool for ShapeComplexityIndexRaster {
                     output.set_value(row, col, 0f64);
                 }
             }
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    progress::update(progress);
                     old_progress = progress;
                 }
             }
@@ -342,22 +337,19 @@ impl WhiteboxTool for ShapeComplexityIndexRaster {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
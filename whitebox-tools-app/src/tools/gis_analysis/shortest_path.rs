@@ -0,0 +1,332 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: Dr. John Lindsay
+Created: 18/10/2026
+Last Modified: 18/10/2026
+License: MIT
+*/
+
+use whitebox_common::progress;
+use crate::tools::*;
+use whitebox_vector::*;
+use std::env;
+use std::f64;
+use std::io::Error;
+use std::path;
+
+/// This tool finds the least-cost route through a set of stops on a line network (`--network`),
+/// such as a road or trail network. The route visits the stops (`--stops`), which are points, in
+/// the order in which they occur in the stops file, and is output (`--output`) as one polyline
+/// for each leg between consecutive stops. The `FROM` and `TO` fields of each leg hold the
+/// record numbers of its stops, and the `COST` and `LENGTH` fields hold its cost and length. A
+/// stop that is not on the network is joined to it at the nearest point on the nearest line.
+///
+/// The network's lines are connected only at their end points, and lines whose end points are
+/// within the `--snap` distance of one another are joined. Lines that cross without sharing an
+/// end point, e.g. at a bridge, are not connected, and so a network may need to be split at its
+/// junctions (e.g. using `SplitWithLines`) before it is used. The cost of travelling a line is
+/// its length, unless a numeric cost field is specified (`--cost_field`), in which case it is the
+/// field's value for the full length of the line, e.g. a travel time. Lines with null or negative
+/// costs cannot be travelled. The cost of travelling part of a line is proportional to the part's
+/// length.
+///
+/// Travel on one-way lines may be restricted using a one-way field (`--oneway_field`). Lines
+/// with a value of 1, true, or `FT` (also `F`, `Y`, `YES`, `TRUE`, and `FORWARD`) may only be
+/// travelled in the direction in which they are digitized, and lines with a value of -1 or `TF`
+/// (also `T`, `REVERSE`, and `BACKWARD`) may only be travelled in the opposite direction. Lines
+/// with any other value may be travelled in either direction. Legs between stops that cannot be
+/// reached from one another are not output.
+///
+/// # See Also
+/// `ServiceArea`, `ClosestFacility`, `OdCostMatrix`, `CostPathway`
+pub struct ShortestPath {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl ShortestPath {
+    pub fn new() -> ShortestPath {
+        // public constructor
+        let name = "ShortestPath".to_string();
+        let toolbox = "GIS Analysis/Network Analysis".to_string();
+        let description =
+            "Finds the least-cost route through a sequence of stops on a line network.".to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input Network Vector File".to_owned(),
+            flags: vec!["--network".to_owned()],
+            description: "Input vector line network file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Vector(
+                VectorGeometryType::Line,
+            )),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Input Stops Vector File".to_owned(),
+            flags: vec!["--stops".to_owned()],
+            description: "Input vector points file of the stops, in the order in which they are visited."
+                .to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Vector(
+                VectorGeometryType::Point,
+            )),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output Vector File".to_owned(),
+            flags: vec!["-o".to_owned(), "--output".to_owned()],
+            description: "Output vector lines file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Vector(
+                VectorGeometryType::Line,
+            )),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Cost Field (optional)".to_owned(),
+            flags: vec!["--cost_field".to_owned()],
+            description: "Optional numeric field holding the cost of travelling each line; line length is used by default."
+                .to_owned(),
+            parameter_type: ParameterType::VectorAttributeField(
+                AttributeType::Number,
+                "--network".to_string(),
+            ),
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "One-way Field (optional)".to_owned(),
+            flags: vec!["--oneway_field".to_owned()],
+            description: "Optional field restricting the direction of travel on each line.".to_owned(),
+            parameter_type: ParameterType::VectorAttributeField(
+                AttributeType::Any,
+                "--network".to_string(),
+            ),
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Snap Distance".to_owned(),
+            flags: vec!["--snap".to_owned()],
+            description: "Distance within which line end points are joined into a network node."
+                .to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: Some("0.0".to_owned()),
+            optional: true,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut parent = env::current_exe().unwrap();
+        parent.pop();
+        let p = format!("{}", parent.display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(
+            ">>.*{0} -r={1} -v --wd=\"*path*to*data*\" --network=roads.shp --stops=stops.shp -o=route.shp --cost_field=MINUTES --oneway_field=ONEWAY",
+            short_exe, name
+        ).replace("*", &sep);
+
+        ShortestPath {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for ShortestPath {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        match serde_json::to_string(&self.parameters) {
+            Ok(json_str) => return format!("{{\"parameters\":{}}}", json_str),
+            Err(err) => return format!("{:?}", err),
+        }
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+        cancel: &CancellationToken,
+    ) -> Result<(), Error> {
+        let args = ParsedArgs::parse(&self.get_tool_parameters(), &args, working_directory)?;
+        self.run_with_parsed_args(args, working_directory, verbose, cancel)
+    }
+
+    fn run_with_parsed_args<'a>(
+        &self,
+        args: ParsedArgs,
+        _working_directory: &'a str,
+        verbose: bool,
+        cancel: &CancellationToken,
+    ) -> Result<(), Error> {
+        let network_file = args.get_file("network").unwrap();
+        let stops_file = args.get_file("stops").unwrap();
+        let output_file = args.get_file("output").unwrap();
+        let options = NetworkOptions {
+            cost_field: args.get_string("cost_field").filter(|s| !s.is_empty()),
+            oneway_field: args.get_string("oneway_field").filter(|s| !s.is_empty()),
+            snap_distance: args.get_float("snap").unwrap_or(0f64),
+        };
+
+        let mut progress: usize;
+        let mut old_progress: usize = 1;
+
+        let start = Instant::now();
+
+        if verbose {
+            let tool_name = self.get_tool_name();
+            progress::welcome(&tool_name);
+        }
+
+        if verbose {
+            progress::message("Reading data...")
+        };
+
+        let network = Shapefile::read(&network_file)?;
+        let stops = Shapefile::read(&stops_file)?;
+
+        if verbose {
+            progress::message("Building network...")
+        };
+        let graph = NetworkGraph::from_shapefile(&network, &options)?;
+        let locations = graph.locate_points(&stops)?;
+
+        let mut output = Shapefile::new(&output_file, ShapeType::PolyLine)?;
+        output.projection = network.projection.clone();
+        output
+            .attributes
+            .add_field(&AttributeField::new("FID", FieldDataType::Int, 7u8, 0u8));
+        output
+            .attributes
+            .add_field(&AttributeField::new("FROM", FieldDataType::Int, 7u8, 0u8));
+        output
+            .attributes
+            .add_field(&AttributeField::new("TO", FieldDataType::Int, 7u8, 0u8));
+        output
+            .attributes
+            .add_field(&AttributeField::new("COST", FieldDataType::Real, 18u8, 6u8));
+        output
+            .attributes
+            .add_field(&AttributeField::new("LENGTH", FieldDataType::Real, 18u8, 6u8));
+
+        let num_legs = stops.num_records.saturating_sub(1);
+        let mut total_cost = 0f64;
+        for leg in 0..num_legs {
+            let route = match (&locations[leg], &locations[leg + 1]) {
+                (Some(origin), Some(destination)) => {
+                    let tree = graph.shortest_path_tree(origin, f64::INFINITY, false);
+                    graph.path_to(&tree, destination)
+                }
+                _ => None,
+            };
+            match route {
+                Some((cost, points)) => {
+                    let length: f64 = (1..points.len())
+                        .map(|k| points[k - 1].distance(&points[k]))
+                        .sum();
+                    // A route between two stops at the same location is a single point.
+                    let mut sfg = ShapefileGeometry::new(ShapeType::PolyLine);
+                    if points.len() == 1 {
+                        sfg.add_part(&[points[0], points[0]]);
+                    } else {
+                        sfg.add_part(&points);
+                    }
+                    output.add_record(sfg);
+                    output.attributes.add_record(
+                        vec![
+                            FieldData::Int(output.num_records as i32),
+                            FieldData::Int(leg as i32 + 1),
+                            FieldData::Int(leg as i32 + 2),
+                            FieldData::Real(cost),
+                            FieldData::Real(length),
+                        ],
+                        false,
+                    );
+                    total_cost += cost;
+                }
+                None => {
+                    if verbose {
+                        progress::message(format!(
+                            "Warning: Stop {} cannot be reached from stop {}.",
+                            leg + 2,
+                            leg + 1
+                        ));
+                    }
+                }
+            }
+
+            cancel.check()?;
+            if verbose {
+                progress = (100.0_f64 * (leg + 1) as f64 / num_legs as f64) as usize;
+                if progress != old_progress {
+                    progress::update_stage("Finding routes", progress);
+                    old_progress = progress;
+                }
+            }
+        }
+
+        if verbose {
+            progress::message(format!("Total cost of the route: {}", total_cost));
+        }
+
+        if verbose {
+            progress::message("Saving data...")
+        };
+        let _ = match output.write() {
+            Ok(_) => {
+                if verbose {
+                    progress::output_written(&output.file_name)
+                }
+            }
+            Err(e) => return Err(e),
+        };
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+
+        if verbose {
+            progress::message(format!("Elapsed Time: {}", elapsed_time));
+        }
+
+        Ok(())
+    }
+}
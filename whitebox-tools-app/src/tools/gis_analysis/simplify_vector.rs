@@ -0,0 +1,688 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: Dr. John Lindsay
+Created: 18/10/2026
+Last Modified: 18/10/2026
+License: MIT
+*/
+
+use whitebox_common::algorithms::{point_line_distance, simplify_rdp, simplify_visvalingam};
+use whitebox_common::structures::Point2D;
+use whitebox_common::progress;
+use crate::tools::*;
+use whitebox_vector::*;
+use std::collections::{HashMap, HashSet};
+use std::env;
+use std::io::{Error, ErrorKind};
+use std::path;
+
+/// This tool simplifies the lines or polygons in a vector (`--input`) by removing vertices that
+/// contribute little to their shape. Two simplification methods are available (`--method`):
+///
+/// - `douglas_peucker`: the Ramer-Douglas-Peucker algorithm, which retains the vertices that are
+///   further than `--tolerance` from the simplified line. The tolerance is a distance, in the
+///   units of the input vector.
+/// - `visvalingam`: the Visvalingam-Whyatt algorithm, which repeatedly removes the vertex that
+///   forms the smallest triangle with its neighbours until every triangle has an area of at least
+///   `--tolerance`. The tolerance is an area, in the squared units of the input vector. This
+///   method tends to give smoother, more natural-looking shapes than `douglas_peucker`.
+///
+/// The end points of lines are never removed, and polygon rings always retain at least three
+/// vertices.
+///
+/// By default, each line and polygon ring is simplified independently of the others, which can
+/// open gaps and overlaps between neighbouring polygons and cause lines to cross. If the
+/// `--preserve_topology` flag is specified, the boundaries are first split into arcs wherever
+/// three or more of them meet, or where a line ends, and each arc is simplified once, so that
+/// boundaries that are shared by neighbouring polygons or lines remain coincident. Any
+/// simplified segments that would cross, touch, or overlap one another, where the original arcs
+/// did not, have vertices restored until they do not, so that the output contains no new
+/// self-intersections and no rings collapse. Boundaries are only recognized as shared where
+/// they have the same vertices, as they do in the output of `RasterToVectorPolygons`.
+///
+/// Point and multipoint vectors are not supported. The output has the base shape type of the
+/// input, i.e. any z and measure values are removed.
+///
+/// # References
+/// Douglas, D.H., Peucker, T.K., 1973. Algorithms for the reduction of the number of points
+/// required to represent a digitized line or its caricature. Cartographica 10, 112–122.
+///
+/// Visvalingam, M., Whyatt, J.D., 1993. Line generalisation by repeated elimination of points.
+/// The Cartographic Journal 30, 46–51.
+///
+/// # See Also
+/// `SmoothVectors`, `RasterToVectorPolygons`, `ValidatePolygons`
+pub struct SimplifyVector {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl SimplifyVector {
+    pub fn new() -> SimplifyVector {
+        // public constructor
+        let name = "SimplifyVector".to_string();
+        let toolbox = "GIS Analysis".to_string();
+        let description =
+            "Simplifies the lines or polygons in a vector, optionally preserving shared boundaries."
+                .to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input Vector File".to_owned(),
+            flags: vec!["-i".to_owned(), "--input".to_owned()],
+            description: "Input vector POLYLINE or POLYGON file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Vector(
+                VectorGeometryType::Any,
+            )),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output Vector File".to_owned(),
+            flags: vec!["-o".to_owned(), "--output".to_owned()],
+            description: "Output vector file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Vector(
+                VectorGeometryType::Any,
+            )),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Simplification Method".to_owned(),
+            flags: vec!["--method".to_owned()],
+            description: "Simplification method; options are 'douglas_peucker' and 'visvalingam'."
+                .to_owned(),
+            parameter_type: ParameterType::OptionList(vec![
+                "douglas_peucker".to_owned(),
+                "visvalingam".to_owned(),
+            ]),
+            default_value: Some("douglas_peucker".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Tolerance".to_owned(),
+            flags: vec!["--tolerance".to_owned()],
+            description: "Simplification tolerance; a distance for 'douglas_peucker' and an area for 'visvalingam'."
+                .to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Preserve topology?".to_owned(),
+            flags: vec!["--preserve_topology".to_owned()],
+            description: "Optional flag to keep shared boundaries coincident and prevent new intersections."
+                .to_owned(),
+            parameter_type: ParameterType::Boolean,
+            default_value: Some("false".to_owned()),
+            optional: true,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut parent = env::current_exe().unwrap();
+        parent.pop();
+        let p = format!("{}", parent.display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(
+            ">>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=landcover.shp -o=simplified.shp --method=visvalingam --tolerance=100.0 --preserve_topology",
+            short_exe, name
+        ).replace("*", &sep);
+
+        SimplifyVector {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for SimplifyVector {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        match serde_json::to_string(&self.parameters) {
+            Ok(json_str) => return format!("{{\"parameters\":{}}}", json_str),
+            Err(err) => return format!("{:?}", err),
+        }
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+        cancel: &CancellationToken,
+    ) -> Result<(), Error> {
+        let args = ParsedArgs::parse(&self.get_tool_parameters(), &args, working_directory)?;
+        self.run_with_parsed_args(args, working_directory, verbose, cancel)
+    }
+
+    fn run_with_parsed_args<'a>(
+        &self,
+        args: ParsedArgs,
+        _working_directory: &'a str,
+        verbose: bool,
+        cancel: &CancellationToken,
+    ) -> Result<(), Error> {
+        let input_file = args.get_file("input").unwrap();
+        let output_file = args.get_file("output").unwrap();
+        let method = match args.get_string("method").unwrap_or_default().as_str() {
+            "visvalingam" => Method::Visvalingam,
+            _ => Method::DouglasPeucker,
+        };
+        let tolerance = args.get_float("tolerance").unwrap();
+        let preserve_topology = args.get_bool("preserve_topology");
+
+        if tolerance < 0f64 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The tolerance must not be negative.",
+            ));
+        }
+
+        let mut progress: usize;
+        let mut old_progress: usize = 1;
+
+        let start = Instant::now();
+
+        if verbose {
+            let tool_name = self.get_tool_name();
+            progress::welcome(&tool_name);
+        }
+
+        if verbose {
+            progress::message("Reading data...")
+        };
+
+        let input = Shapefile::read(&input_file)?;
+
+        let shape_type = input.header.shape_type.base_shape_type();
+        if shape_type != ShapeType::PolyLine && shape_type != ShapeType::Polygon {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The input vector data must be of either POLYLINE or POLYGON base shape type.",
+            ));
+        }
+        let is_polygon = shape_type == ShapeType::Polygon;
+
+        // Read the parts of each feature, removing repeated vertices and closing open rings.
+        let mut features: Vec<Vec<Vec<Point2D>>> = Vec::with_capacity(input.num_records);
+        for record_num in 0..input.num_records {
+            let record = input.get_record(record_num);
+            let mut parts = vec![];
+            for part in 0..record.num_parts as usize {
+                let mut points = record.get_part(part).to_vec();
+                points.dedup();
+                if is_polygon && points.len() > 1 && points[0] != points[points.len() - 1] {
+                    points.push(points[0]);
+                }
+                parts.push(points);
+            }
+            features.push(parts);
+        }
+
+        let simplified = if preserve_topology {
+            if verbose {
+                progress::message("Finding shared boundaries...")
+            };
+            let mut topology = Topology::new(&features, is_polygon);
+            let num_arcs = topology.arcs.len();
+            for (i, arc) in topology.arcs.iter_mut().enumerate() {
+                arc.kept = simplify(&arc.points, method, tolerance);
+                if verbose {
+                    progress = (100.0_f64 * (i + 1) as f64 / num_arcs as f64) as usize;
+                    if progress != old_progress {
+                        progress::update_stage("Simplifying boundaries", progress);
+                        old_progress = progress;
+                    }
+                }
+            }
+            cancel.check()?;
+            if verbose {
+                progress::message("Resolving intersections...")
+            };
+            topology.resolve_conflicts(cancel)?;
+            topology.features()
+        } else {
+            let mut simplified = Vec::with_capacity(features.len());
+            for (record_num, parts) in features.iter().enumerate() {
+                simplified.push(
+                    parts
+                        .iter()
+                        .map(|points| {
+                            simplify(points, method, tolerance)
+                                .iter()
+                                .map(|k| points[*k])
+                                .collect()
+                        })
+                        .collect(),
+                );
+                if verbose {
+                    progress = (100.0_f64 * (record_num + 1) as f64 / features.len() as f64)
+                        as usize;
+                    if progress != old_progress {
+                        progress::update_stage("Simplifying features", progress);
+                        old_progress = progress;
+                    }
+                }
+            }
+            simplified
+        };
+
+        // create output file
+        let mut output = Shapefile::initialize_using_file(&output_file, &input, shape_type, true)?;
+        let mut num_vertices = (0usize, 0usize);
+        for (record_num, parts) in simplified.into_iter().enumerate() {
+            let mut sfg = ShapefileGeometry::new(shape_type);
+            for (part, points) in parts.into_iter().enumerate() {
+                num_vertices.0 += features[record_num][part].len();
+                num_vertices.1 += points.len();
+                if !points.is_empty() {
+                    sfg.add_part(&points);
+                }
+            }
+            output.add_record(sfg);
+            output
+                .attributes
+                .add_record(input.attributes.get_record(record_num), false);
+        }
+
+        if verbose {
+            progress::message(format!(
+                "Number of vertices: {} of {} retained",
+                num_vertices.1, num_vertices.0
+            ));
+            progress::message("Saving data...")
+        };
+        let _ = match output.write() {
+            Ok(_) => {
+                if verbose {
+                    progress::output_written(&output.file_name)
+                }
+            }
+            Err(e) => return Err(e),
+        };
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+
+        if verbose {
+            progress::message(format!("Elapsed Time: {}", elapsed_time));
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Method {
+    DouglasPeucker,
+    Visvalingam,
+}
+
+/// Simplifies a line, or a ring if its first and last points are the same, and returns the
+/// indices of the points that are retained. The end points are always retained, and a ring
+/// retains at least three distinct points.
+fn simplify(points: &[Point2D], method: Method, tolerance: f64) -> Vec<usize> {
+    let n = points.len();
+    if n < 3 {
+        return (0..n).collect();
+    }
+    let simplify_line = |start: usize, end: usize| -> Vec<usize> {
+        let line = &points[start..=end];
+        let simplified = match method {
+            Method::DouglasPeucker => simplify_rdp(line, &tolerance),
+            Method::Visvalingam => simplify_visvalingam(line, &tolerance),
+        };
+        // The simplified line is a subsequence of the original points.
+        let mut kept = Vec::with_capacity(simplified.len());
+        let mut k = 0;
+        for p in simplified {
+            while line[k] != p {
+                k += 1;
+            }
+            kept.push(start + k);
+            k += 1;
+        }
+        kept
+    };
+    if points[0] != points[n - 1] {
+        return simplify_line(0, n - 1);
+    }
+    if n <= 4 {
+        return (0..n).collect();
+    }
+    // Split the ring at the point furthest from its start, so that neither half is closed.
+    let far = (1..n - 1)
+        .max_by(|a, b| {
+            let da = points[0].distance(&points[*a]);
+            let db = points[0].distance(&points[*b]);
+            da.partial_cmp(&db).unwrap()
+        })
+        .unwrap();
+    let mut kept = simplify_line(0, far);
+    kept.pop();
+    kept.extend(simplify_line(far, n - 1));
+    if kept.len() < 4 {
+        let third = (1..n - 1)
+            .filter(|k| *k != far)
+            .max_by(|a, b| {
+                let da = point_line_distance(&points[*a], &points[0], &points[far]);
+                let db = point_line_distance(&points[*b], &points[0], &points[far]);
+                da.partial_cmp(&db).unwrap()
+            })
+            .unwrap();
+        kept.push(third);
+        kept.sort();
+    }
+    kept
+}
+
+/// A section of boundary between two nodes, with the indices of its points that are retained.
+struct Arc {
+    points: Vec<Point2D>,
+    kept: Vec<usize>,
+}
+
+/// The boundaries of a set of features, split into arcs that are shared by the features.
+struct Topology {
+    arcs: Vec<Arc>,
+    /// Each part of each feature is a sequence of arcs, each of which may be reversed.
+    features: Vec<Vec<Vec<(usize, bool)>>>,
+}
+
+impl Topology {
+    fn new(features: &[Vec<Vec<Point2D>>], is_polygon: bool) -> Topology {
+        // Number the distinct vertices and find the neighbours of each.
+        let mut ids: HashMap<(u64, u64), usize> = HashMap::new();
+        let mut vertices: Vec<Point2D> = vec![];
+        let mut parts: Vec<Vec<Vec<usize>>> = Vec::with_capacity(features.len());
+        for feature in features {
+            let mut feature_parts = vec![];
+            for points in feature {
+                let part: Vec<usize> = points
+                    .iter()
+                    .map(|p| {
+                        let key = ((p.x + 0f64).to_bits(), (p.y + 0f64).to_bits());
+                        *ids.entry(key).or_insert_with(|| {
+                            vertices.push(*p);
+                            vertices.len() - 1
+                        })
+                    })
+                    .collect();
+                feature_parts.push(part);
+            }
+            parts.push(feature_parts);
+        }
+        let mut neighbours: Vec<Vec<usize>> = vec![vec![]; vertices.len()];
+        for part in parts.iter().flatten() {
+            for k in 1..part.len() {
+                let (a, b) = (part[k - 1], part[k]);
+                if !neighbours[a].contains(&b) {
+                    neighbours[a].push(b);
+                    neighbours[b].push(a);
+                }
+            }
+        }
+
+        // Arcs end at nodes, where other than two boundary segments meet or a line ends. A ring
+        // that contains no nodes is given one at its lowest vertex, which is the same vertex for
+        // any other ring that coincides with it.
+        let mut is_node: Vec<bool> = neighbours.iter().map(|n| n.len() != 2).collect();
+        for part in parts.iter().flatten() {
+            if part.is_empty() {
+                continue;
+            }
+            if !is_polygon || part[0] != part[part.len() - 1] {
+                is_node[part[0]] = true;
+                is_node[part[part.len() - 1]] = true;
+            } else if !part.iter().any(|v| is_node[*v]) {
+                let lowest = *part
+                    .iter()
+                    .min_by(|a, b| {
+                        let (p, q) = (vertices[**a], vertices[**b]);
+                        (p.x, p.y).partial_cmp(&(q.x, q.y)).unwrap()
+                    })
+                    .unwrap();
+                is_node[lowest] = true;
+            }
+        }
+
+        // Split the parts into arcs. An arc is identified by its first segment in either
+        // direction, since the path from a node along a segment is unique.
+        let mut arcs: Vec<Arc> = vec![];
+        let mut arc_ids: HashMap<(usize, usize), (usize, bool)> = HashMap::new();
+        let mut feature_arcs = Vec::with_capacity(parts.len());
+        for feature_parts in &parts {
+            let mut arc_parts = vec![];
+            for part in feature_parts {
+                let mut part = part.clone();
+                if part.len() > 1 && part[0] == part[part.len() - 1] {
+                    // Start the ring at a node.
+                    part.pop();
+                    let first = part.iter().position(|v| is_node[*v]).unwrap_or(0);
+                    part.rotate_left(first);
+                    part.push(part[0]);
+                }
+                let mut sequence = vec![];
+                let mut start = 0;
+                for k in 1..part.len() {
+                    if is_node[part[k]] || k == part.len() - 1 {
+                        let path = &part[start..=k];
+                        let key = (path[0], path[1]);
+                        let entry = match arc_ids.get(&key) {
+                            Some(entry) => *entry,
+                            None => {
+                                let id = arcs.len();
+                                arcs.push(Arc {
+                                    points: path.iter().map(|v| vertices[*v]).collect(),
+                                    kept: vec![],
+                                });
+                                arc_ids.insert(key, (id, false));
+                                arc_ids.insert((path[path.len() - 1], path[path.len() - 2]), (id, true));
+                                (id, false)
+                            }
+                        };
+                        sequence.push(entry);
+                        start = k;
+                    }
+                }
+                arc_parts.push(sequence);
+            }
+            feature_arcs.push(arc_parts);
+        }
+        Topology {
+            arcs: arcs,
+            features: feature_arcs,
+        }
+    }
+
+    /// Restores points to the simplified arcs until none of their segments cross, touch, or
+    /// overlap, other than where their originals did.
+    fn resolve_conflicts(&mut self, cancel: &CancellationToken) -> Result<(), Error> {
+        let original: Vec<Vec<usize>> = self
+            .arcs
+            .iter()
+            .map(|arc| (0..arc.points.len()).collect())
+            .collect();
+        let allowed: HashSet<(usize, usize)> = self
+            .find_conflicts(&original)
+            .into_iter()
+            .map(|((a, _), (b, _))| (a.min(b), a.max(b)))
+            .collect();
+        loop {
+            let kept: Vec<Vec<usize>> = self.arcs.iter().map(|arc| arc.kept.clone()).collect();
+            let mut refine: Vec<(usize, usize)> = vec![];
+            for ((a, i), (b, j)) in self.find_conflicts(&kept) {
+                if !allowed.contains(&(a.min(b), a.max(b))) {
+                    refine.push((a, i));
+                    refine.push((b, j));
+                }
+            }
+            refine.sort();
+            refine.dedup();
+            let mut changed = false;
+            // Refine the segments from the end of each arc, so that earlier positions are valid.
+            for &(a, i) in refine.iter().rev() {
+                let arc = &mut self.arcs[a];
+                let (start, end) = (arc.kept[i], arc.kept[i + 1]);
+                if end - start > 1 {
+                    let furthest = (start + 1..end)
+                        .max_by(|p, q| {
+                            let dp = segment_distance(arc.points[*p], arc.points[start], arc.points[end]);
+                            let dq = segment_distance(arc.points[*q], arc.points[start], arc.points[end]);
+                            dp.partial_cmp(&dq).unwrap()
+                        })
+                        .unwrap();
+                    arc.kept.insert(i + 1, furthest);
+                    changed = true;
+                }
+            }
+            cancel.check()?;
+            if !changed {
+                return Ok(());
+            }
+        }
+    }
+
+    /// Finds the pairs of segments, given as arcs and positions in their lists of retained
+    /// points, that meet other than at a shared end point.
+    fn find_conflicts(&self, kept: &[Vec<usize>]) -> Vec<((usize, usize), (usize, usize))> {
+        let mut segments: Vec<(usize, usize, Point2D, Point2D)> = vec![];
+        for (a, arc) in self.arcs.iter().enumerate() {
+            for i in 1..kept[a].len() {
+                let (p, q) = (arc.points[kept[a][i - 1]], arc.points[kept[a][i]]);
+                segments.push((a, i - 1, p, q));
+            }
+        }
+        segments.sort_by(|s, t| {
+            s.2.x.min(s.3.x).partial_cmp(&t.2.x.min(t.3.x)).unwrap()
+        });
+        let mut conflicts = vec![];
+        for k in 0..segments.len() {
+            let (a, i, p, q) = segments[k];
+            let max_x = p.x.max(q.x);
+            for &(b, j, r, s) in &segments[k + 1..] {
+                if r.x.min(s.x) > max_x {
+                    break;
+                }
+                if r.y.min(s.y) > p.y.max(q.y) || p.y.min(q.y) > r.y.max(s.y) {
+                    continue;
+                }
+                if segments_conflict(p, q, r, s) {
+                    conflicts.push(((a, i), (b, j)));
+                }
+            }
+        }
+        conflicts
+    }
+
+    /// Returns the parts of each feature, assembled from the simplified arcs.
+    fn features(&self) -> Vec<Vec<Vec<Point2D>>> {
+        self.features
+            .iter()
+            .map(|parts| {
+                parts
+                    .iter()
+                    .map(|sequence| {
+                        let mut points: Vec<Point2D> = vec![];
+                        for &(a, reversed) in sequence {
+                            let arc = &self.arcs[a];
+                            let mut arc_points: Vec<Point2D> =
+                                arc.kept.iter().map(|k| arc.points[*k]).collect();
+                            if reversed {
+                                arc_points.reverse();
+                            }
+                            if !points.is_empty() {
+                                points.pop();
+                            }
+                            points.extend(arc_points);
+                        }
+                        points
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+/// Tests whether the segment from `p` to `q` meets the segment from `r` to `s` at any point
+/// other than an end point that they share.
+fn segments_conflict(p: Point2D, q: Point2D, r: Point2D, s: Point2D) -> bool {
+    let o1 = (q - p).cross(r - p);
+    let o2 = (q - p).cross(s - p);
+    let o3 = (s - r).cross(p - r);
+    let o4 = (s - r).cross(q - r);
+    let shared = if p == r || p == s {
+        Some(p)
+    } else if q == r || q == s {
+        Some(q)
+    } else {
+        None
+    };
+    if let Some(v) = shared {
+        if (p == r && q == s) || (p == s && q == r) {
+            return p != q;
+        }
+        // Segments that share an end point meet elsewhere only if they overlap.
+        let u = if p == v { q } else { p };
+        let w = if r == v { s } else { r };
+        return o1 == 0f64 && o2 == 0f64 && (u - v) * (w - v) > 0f64;
+    }
+    if o1 * o2 < 0f64 && o3 * o4 < 0f64 {
+        return true;
+    }
+    (o1 == 0f64 && on_segment(r, p, q))
+        || (o2 == 0f64 && on_segment(s, p, q))
+        || (o3 == 0f64 && on_segment(p, r, s))
+        || (o4 == 0f64 && on_segment(q, r, s))
+}
+
+/// Tests whether a point that is collinear with a segment lies within its extent.
+fn on_segment(p: Point2D, a: Point2D, b: Point2D) -> bool {
+    p.x >= a.x.min(b.x) && p.x <= a.x.max(b.x) && p.y >= a.y.min(b.y) && p.y <= a.y.max(b.y)
+}
+
+fn segment_distance(p: Point2D, a: Point2D, b: Point2D) -> f64 {
+    let r = b - a;
+    let length = r * r;
+    if length == 0f64 {
+        return p.distance(&a);
+    }
+    let t = ((p - a) * r / length).clamp(0f64, 1f64);
+    p.distance(&Point2D::new(a.x + t * r.x, a.y + t * r.y))
+}
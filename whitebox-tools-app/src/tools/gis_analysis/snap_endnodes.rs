@@ -11,6 +11,7 @@ use whitebox_common::algorithms::{
     find_split_points_at_line_intersections, interior_point, is_clockwise_order,
 };
 use whitebox_common::structures::{BoundingBox, Polyline};
+use whitebox_common::progress;
 use crate::tools::*;
 use whitebox_vector::*;
 use kdtree::distance::squared_euclidean;
@@ -139,6 +140,7 @@ impl WhiteboxTool for SnapEndnodes {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file: String = "".to_string();
         let mut output_file: String = "".to_string();
@@ -193,13 +195,7 @@ impl WhiteboxTool for SnapEndnodes {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         if !input_file.contains(path::MAIN_SEPARATOR) && !input_file.contains("/") {
@@ -291,7 +287,7 @@ impl WhiteboxTool for SnapEndnodes {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!("{}", &format!("Elapsed Time: {}", elapsed_time));
+            progress::message(format!("Elapsed Time: {}", elapsed_time));
         }
 
         Ok(())
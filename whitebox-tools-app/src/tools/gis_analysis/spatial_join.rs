@@ -0,0 +1,856 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: Dr. John Lindsay
+Created: 18/10/2026
+Last Modified: 18/10/2026
+License: MIT
+*/
+
+use whitebox_common::algorithms::point_in_poly;
+use whitebox_common::structures::{BoundingBox, Point2D};
+use whitebox_common::progress;
+use crate::tools::*;
+use whitebox_vector::*;
+use num_cpus;
+use std::env;
+use std::f64;
+use std::io::{Error, ErrorKind};
+use std::path;
+use std::sync::mpsc;
+use std::sync::Arc;
+
+/// This tool joins the attributes of the features in one vector (`--join`) to the features of
+/// another (`--input`) based on their spatial relationship, rather than on a common key field
+/// as `JoinTables` does. The relationship is set by the `--predicate` parameter:
+///
+/// | Predicate    | An input feature is joined to each join feature that...                  |
+/// |:-------------|:-------------------------------------------------------------------------|
+/// | `intersects` | shares at least one point with it, including along its boundary          |
+/// | `within`     | contains it entirely, i.e. the input feature is within the join feature  |
+/// | `contains`   | it contains entirely, i.e. the join feature is within the input feature |
+/// | `nearest`    | is closest to it, if it is no further away than `--max_dist`             |
+///
+/// The two vectors may contain any combination of points, lines, and polygons. Features that
+/// touch only along their boundaries are considered to intersect, and a feature that lies on
+/// the boundary of a polygon is considered to be within it. The nearest join feature is found
+/// by the shortest distance between the two geometries, which is zero if they intersect, and
+/// the distance is written to a `NEAR_DIST` field. When `--max_dist` is not specified, every
+/// input feature is joined to its nearest join feature, however distant.
+///
+/// By default, the join is one-to-one: the output contains each of the input features, with
+/// a `JOIN_COUNT` field that holds the number of join features that it is related to, and one
+/// field for each of the imported join fields. Where an input feature is related to more than
+/// one join feature, their values are combined by the statistic given by `--stat`, which may be
+/// `first` (the join feature that is first in the join vector's attribute table), `sum`, `mean`,
+/// `min`, or `max`. The `sum`, `mean`, `min`, and `max` statistics are calculated for numeric
+/// fields only; other fields are given the value of the first related join feature. If the
+/// statistic is `count`, no fields are imported and only the `JOIN_COUNT` field is added.
+///
+/// If the `--one_to_many` flag is specified, the output instead contains one copy of an input
+/// feature for each join feature that it is related to, with that feature's attributes and its
+/// record number in a `JOIN_FID` field, and the `--stat` parameter is ignored. In both cases,
+/// input features that are related to no join features are output with null join attributes.
+///
+/// The fields to import are listed with `--fields`, separated by commas; all of the join
+/// vector's fields other than `FID` are imported if no fields are listed. An imported field
+/// whose name is already used in the input attribute table is given a numbered suffix.
+///
+/// # See Also
+/// `JoinTables`, `MergeTableWithCsv`, `Intersect`, `Clip`
+pub struct SpatialJoin {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl SpatialJoin {
+    pub fn new() -> SpatialJoin {
+        // public constructor
+        let name = "SpatialJoin".to_string();
+        let toolbox = "GIS Analysis/Overlay Tools".to_string();
+        let description =
+            "Joins the attributes of one vector to the features of another based on their spatial relationship."
+                .to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input Vector File".to_owned(),
+            flags: vec!["-i".to_owned(), "--input".to_owned()],
+            description: "Input vector file, to which attributes are joined.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Vector(
+                VectorGeometryType::Any,
+            )),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Join Vector File".to_owned(),
+            flags: vec!["--join".to_owned()],
+            description: "Join vector file, from which attributes are imported.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Vector(
+                VectorGeometryType::Any,
+            )),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output Vector File".to_owned(),
+            flags: vec!["-o".to_owned(), "--output".to_owned()],
+            description: "Output vector file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Vector(
+                VectorGeometryType::Any,
+            )),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Spatial Predicate".to_owned(),
+            flags: vec!["--predicate".to_owned()],
+            description: "Spatial relationship of the joined features; options are 'intersects', 'within', 'contains', and 'nearest'."
+                .to_owned(),
+            parameter_type: ParameterType::OptionList(vec![
+                "intersects".to_owned(),
+                "within".to_owned(),
+                "contains".to_owned(),
+                "nearest".to_owned(),
+            ]),
+            default_value: Some("intersects".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Maximum Distance (optional)".to_owned(),
+            flags: vec!["--max_dist".to_owned()],
+            description: "Optional maximum distance to the nearest join feature, used with the 'nearest' predicate."
+                .to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Statistic".to_owned(),
+            flags: vec!["--stat".to_owned()],
+            description: "Statistic used to combine the attributes of multiple join features; options are 'first', 'count', 'sum', 'mean', 'min', and 'max'."
+                .to_owned(),
+            parameter_type: ParameterType::OptionList(vec![
+                "first".to_owned(),
+                "count".to_owned(),
+                "sum".to_owned(),
+                "mean".to_owned(),
+                "min".to_owned(),
+                "max".to_owned(),
+            ]),
+            default_value: Some("first".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Join Fields (optional)".to_owned(),
+            flags: vec!["--fields".to_owned()],
+            description: "Optional comma-separated list of the join fields to import; all fields are imported if none are listed."
+                .to_owned(),
+            parameter_type: ParameterType::StringList,
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output one feature for each joined feature?".to_owned(),
+            flags: vec!["--one_to_many".to_owned()],
+            description: "Optional flag to output a copy of each input feature for each join feature that it is related to."
+                .to_owned(),
+            parameter_type: ParameterType::Boolean,
+            default_value: Some("false".to_owned()),
+            optional: true,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut parent = env::current_exe().unwrap();
+        parent.pop();
+        let p = format!("{}", parent.display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(
+            ">>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=parcels.shp --join=wells.shp -o=out_file.shp --predicate=contains --stat=mean --fields=DEPTH,YIELD",
+            short_exe, name
+        ).replace("*", &sep);
+
+        SpatialJoin {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for SpatialJoin {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        match serde_json::to_string(&self.parameters) {
+            Ok(json_str) => return format!("{{\"parameters\":{}}}", json_str),
+            Err(err) => return format!("{:?}", err),
+        }
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+        cancel: &CancellationToken,
+    ) -> Result<(), Error> {
+        let args = ParsedArgs::parse(&self.get_tool_parameters(), &args, working_directory)?;
+        self.run_with_parsed_args(args, working_directory, verbose, cancel)
+    }
+
+    fn run_with_parsed_args<'a>(
+        &self,
+        args: ParsedArgs,
+        _working_directory: &'a str,
+        verbose: bool,
+        cancel: &CancellationToken,
+    ) -> Result<(), Error> {
+        let input_file = args.get_file("input").unwrap();
+        let join_file = args.get_file("join").unwrap();
+        let output_file = args.get_file("output").unwrap();
+        let predicate = match args.get_string("predicate").unwrap_or_default().as_str() {
+            "within" => Predicate::Within,
+            "contains" => Predicate::Contains,
+            "nearest" => Predicate::Nearest,
+            _ => Predicate::Intersects,
+        };
+        let max_dist = args.get_float("max_dist").unwrap_or(f64::INFINITY);
+        let stat = match args.get_string("stat").unwrap_or_default().as_str() {
+            "count" => Statistic::Count,
+            "sum" => Statistic::Sum,
+            "mean" => Statistic::Mean,
+            "min" => Statistic::Min,
+            "max" => Statistic::Max,
+            _ => Statistic::First,
+        };
+        let field_names = match args.get("fields") {
+            Some(ArgValue::StringList(names)) => names.clone(),
+            _ => vec![],
+        };
+        let one_to_many = args.get_bool("one_to_many");
+
+        if max_dist < 0f64 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The maximum distance (--max_dist) must not be negative.",
+            ));
+        }
+
+        let mut progress: usize;
+        let mut old_progress: usize = 1;
+
+        let start = Instant::now();
+
+        if verbose {
+            let tool_name = self.get_tool_name();
+            progress::welcome(&tool_name);
+        }
+
+        if verbose {
+            progress::message("Reading data...")
+        };
+
+        let input = Shapefile::read(&input_file)?;
+        let join = Shapefile::read(&join_file)?;
+
+        // Find the join fields that are imported into the output.
+        let mut join_fields: Vec<usize> = vec![];
+        if field_names.is_empty() {
+            for i in 0..join.attributes.get_num_fields() {
+                if join.attributes.get_field(i).name.to_lowercase() != "fid" {
+                    join_fields.push(i);
+                }
+            }
+        } else {
+            for name in &field_names {
+                match join.attributes.get_field_num(name) {
+                    Some(i) => join_fields.push(i),
+                    None => {
+                        return Err(Error::new(
+                            ErrorKind::InvalidInput,
+                            format!("The field '{}' does not exist in the join attribute table.", name),
+                        ));
+                    }
+                }
+            }
+        }
+        if !one_to_many && stat == Statistic::Count {
+            join_fields.clear();
+        }
+
+        let input_features: Vec<Feature> = (0..input.num_records)
+            .map(|i| Feature::new(input.get_record(i)))
+            .collect();
+        let join_features: Vec<Feature> = (0..join.num_records)
+            .map(|i| Feature::new(join.get_record(i)))
+            .collect();
+
+        // Points that are closer together than this are treated as coincident.
+        let mut magnitude = 0f64;
+        for feature in input_features.iter().chain(join_features.iter()) {
+            if feature.num_points > 0 {
+                let bb = feature.bb;
+                magnitude = magnitude
+                    .max(bb.min_x.abs())
+                    .max(bb.max_x.abs())
+                    .max(bb.min_y.abs())
+                    .max(bb.max_y.abs());
+            }
+        }
+        let tolerance = (magnitude * 1e-12).max(f64::MIN_POSITIVE);
+
+        let input_features = Arc::new(input_features);
+        let join_features = Arc::new(join_features);
+        let num_records = input.num_records;
+        let mut num_procs = num_cpus::get() as isize;
+        let configs = whitebox_common::configs::get_configs()?;
+        let max_procs = configs.max_procs;
+        if max_procs > 0 && max_procs < num_procs {
+            num_procs = max_procs;
+        }
+        let (tx, rx) = mpsc::channel();
+        for tid in 0..num_procs {
+            let input_features = input_features.clone();
+            let join_features = join_features.clone();
+            let tx = tx.clone();
+            progress::spawn(move || {
+                for record_num in (0..num_records).filter(|r| *r as isize % num_procs == tid) {
+                    let matches = find_matches(
+                        &input_features[record_num],
+                        &join_features,
+                        predicate,
+                        max_dist,
+                        tolerance,
+                    );
+                    if tx.send((record_num, matches)).is_err() {
+                        return;
+                    }
+                }
+            });
+        }
+        drop(tx);
+
+        let mut matches: Vec<Vec<(usize, f64)>> = vec![vec![]; num_records];
+        for i in 0..num_records {
+            let (record_num, record_matches) = cancel.recv(&rx)?;
+            matches[record_num] = record_matches;
+            if verbose {
+                progress = (100.0_f64 * (i + 1) as f64 / num_records as f64) as usize;
+                if progress != old_progress {
+                    progress::update_stage("Finding related features", progress);
+                    old_progress = progress;
+                }
+            }
+        }
+        drop(input_features);
+        drop(join_features);
+
+        // create output file
+        let mut output =
+            Shapefile::initialize_using_file(&output_file, &input, input.header.shape_type, true)?;
+        if one_to_many {
+            output
+                .attributes
+                .add_field(&AttributeField::new("JOIN_FID", FieldDataType::Int, 7u8, 0u8));
+        } else {
+            output
+                .attributes
+                .add_field(&AttributeField::new("JOIN_COUNT", FieldDataType::Int, 7u8, 0u8));
+        }
+        for &i in &join_fields {
+            let mut field = join.attributes.get_field(i).clone();
+            field.name = unique_field_name(&output.attributes, &field.name);
+            if !one_to_many
+                && (stat == Statistic::Sum || stat == Statistic::Mean)
+                && join.attributes.is_field_numeric(i)
+            {
+                field.field_type = FieldDataType::Real.to_char();
+                field.field_length = 18u8;
+                field.decimal_count = 6u8;
+            }
+            output.attributes.add_field(&field);
+        }
+        if predicate == Predicate::Nearest {
+            output
+                .attributes
+                .add_field(&AttributeField::new("NEAR_DIST", FieldDataType::Real, 18u8, 6u8));
+        }
+
+        for record_num in 0..num_records {
+            let record = input.get_record(record_num);
+            let atts = input.attributes.get_record(record_num);
+            let record_matches = &matches[record_num];
+            if one_to_many {
+                if record_matches.is_empty() {
+                    let mut out_atts = atts.clone();
+                    out_atts.push(FieldData::Null);
+                    out_atts.extend(join_fields.iter().map(|_| FieldData::Null));
+                    if predicate == Predicate::Nearest {
+                        out_atts.push(FieldData::Null);
+                    }
+                    output.add_record(record.clone());
+                    output.attributes.add_record(out_atts, false);
+                }
+                for &(join_num, distance) in record_matches {
+                    let join_atts = join.attributes.get_record(join_num);
+                    let mut out_atts = atts.clone();
+                    out_atts.push(FieldData::Int(join_num as i32 + 1));
+                    out_atts.extend(join_fields.iter().map(|&i| join_atts[i].clone()));
+                    if predicate == Predicate::Nearest {
+                        out_atts.push(FieldData::Real(distance));
+                    }
+                    output.add_record(record.clone());
+                    output.attributes.add_record(out_atts, false);
+                }
+            } else {
+                let mut out_atts = atts.clone();
+                out_atts.push(FieldData::Int(record_matches.len() as i32));
+                let join_atts: Vec<Vec<FieldData>> = record_matches
+                    .iter()
+                    .map(|&(join_num, _)| join.attributes.get_record(join_num))
+                    .collect();
+                for &i in &join_fields {
+                    let values: Vec<&FieldData> = join_atts.iter().map(|atts| &atts[i]).collect();
+                    let numeric = join.attributes.is_field_numeric(i);
+                    out_atts.push(combine_values(&values, stat, numeric));
+                }
+                if predicate == Predicate::Nearest {
+                    out_atts.push(match record_matches.first() {
+                        Some(&(_, distance)) => FieldData::Real(distance),
+                        None => FieldData::Null,
+                    });
+                }
+                output.add_record(record.clone());
+                output.attributes.add_record(out_atts, false);
+            }
+
+            cancel.check()?;
+            if verbose {
+                progress = (100.0_f64 * (record_num + 1) as f64 / num_records as f64) as usize;
+                if progress != old_progress {
+                    progress::update_stage("Joining attributes", progress);
+                    old_progress = progress;
+                }
+            }
+        }
+
+        if verbose {
+            progress::message("Saving data...")
+        };
+        let _ = match output.write() {
+            Ok(_) => {
+                if verbose {
+                    progress::output_written(&output.file_name)
+                }
+            }
+            Err(e) => return Err(e),
+        };
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+
+        if verbose {
+            progress::message(format!("Elapsed Time: {}", elapsed_time));
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Predicate {
+    Intersects,
+    Within,
+    Contains,
+    Nearest,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Statistic {
+    First,
+    Count,
+    Sum,
+    Mean,
+    Min,
+    Max,
+}
+
+/// The geometry of a feature, as points and lines. The rings of a polygon are its lines, and
+/// its interior is the area that is within an odd number of them.
+struct Feature {
+    bb: BoundingBox,
+    num_points: usize,
+    points: Vec<Point2D>,
+    lines: Vec<Vec<Point2D>>,
+    is_polygon: bool,
+}
+
+impl Feature {
+    fn new(record: &ShapefileGeometry) -> Feature {
+        let is_polygon = record.shape_type.base_shape_type() == ShapeType::Polygon;
+        let mut points = vec![];
+        let mut lines = vec![];
+        match record.shape_type.base_shape_type() {
+            ShapeType::Point | ShapeType::MultiPoint => points = record.points.clone(),
+            _ => {
+                for part in 0..record.num_parts as usize {
+                    let mut line = record.get_part(part).to_vec();
+                    if is_polygon && line.len() > 1 && line[0] != line[line.len() - 1] {
+                        line.push(line[0]);
+                    }
+                    if line.len() > 1 {
+                        lines.push(line);
+                    } else {
+                        points.extend(line);
+                    }
+                }
+            }
+        }
+        Feature {
+            bb: BoundingBox::from_points(&record.points),
+            num_points: record.points.len(),
+            points: points,
+            lines: lines,
+            is_polygon: is_polygon,
+        }
+    }
+
+    /// Tests whether `p` is on one of the feature's points or lines.
+    fn touches(&self, p: Point2D, tolerance: f64) -> bool {
+        self.points.iter().any(|q| q.distance(&p) <= tolerance)
+            || self.lines.iter().any(|line| {
+                (1..line.len())
+                    .any(|k| distance_to_segment(p, line[k - 1], line[k]) <= tolerance)
+            })
+    }
+
+    /// Tests whether `p` is within the interior of a polygon feature.
+    fn encloses(&self, p: Point2D) -> bool {
+        self.is_polygon
+            && self
+                .lines
+                .iter()
+                .filter(|ring| ring.len() > 3 && point_in_poly(&p, ring))
+                .count()
+                % 2
+                == 1
+    }
+
+    fn covers(&self, p: Point2D, tolerance: f64) -> bool {
+        self.touches(p, tolerance) || self.encloses(p)
+    }
+}
+
+/// Finds the join features that a feature is related to by the predicate, in the order of the
+/// join table, or the nearest join feature, along with the distance to each.
+fn find_matches(
+    feature: &Feature,
+    join_features: &[Feature],
+    predicate: Predicate,
+    max_dist: f64,
+    tolerance: f64,
+) -> Vec<(usize, f64)> {
+    if feature.num_points == 0 {
+        return vec![];
+    }
+    if predicate == Predicate::Nearest {
+        // Visit the join features in order of the distance to their bounding boxes, which is
+        // never more than the distance to the features themselves.
+        let mut candidates: Vec<(f64, usize)> = join_features
+            .iter()
+            .enumerate()
+            .filter(|(_, other)| other.num_points > 0)
+            .map(|(j, other)| (box_distance(&feature.bb, &other.bb), j))
+            .filter(|(d, _)| *d <= max_dist)
+            .collect();
+        candidates.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        let mut nearest: Option<(usize, f64)> = None;
+        for (lower_bound, j) in candidates {
+            if let Some((k, d)) = nearest {
+                if lower_bound > d {
+                    break;
+                }
+                let dist = distance(feature, &join_features[j], tolerance);
+                if dist < d || (dist == d && j < k) {
+                    nearest = Some((j, dist));
+                }
+            } else {
+                nearest = Some((j, distance(feature, &join_features[j], tolerance)));
+            }
+        }
+        return match nearest {
+            Some((j, d)) if d <= max_dist => vec![(j, d)],
+            _ => vec![],
+        };
+    }
+    let mut matches = vec![];
+    for (j, other) in join_features.iter().enumerate() {
+        if other.num_points == 0 || box_distance(&feature.bb, &other.bb) > tolerance {
+            continue;
+        }
+        let related = match predicate {
+            Predicate::Within => within(feature, other, tolerance),
+            Predicate::Contains => within(other, feature, tolerance),
+            _ => intersects(feature, other, tolerance),
+        };
+        if related {
+            matches.push((j, 0f64));
+        }
+    }
+    matches
+}
+
+/// Tests whether two features share at least one point.
+fn intersects(a: &Feature, b: &Feature, tolerance: f64) -> bool {
+    if a.points.iter().any(|p| b.covers(*p, tolerance))
+        || b.points.iter().any(|p| a.covers(*p, tolerance))
+    {
+        return true;
+    }
+    for line_a in &a.lines {
+        for line_b in &b.lines {
+            for i in 1..line_a.len() {
+                for k in 1..line_b.len() {
+                    let d = segment_distance(line_a[i - 1], line_a[i], line_b[k - 1], line_b[k]);
+                    if d <= tolerance {
+                        return true;
+                    }
+                }
+            }
+        }
+    }
+    // The lines do not meet, but one feature may lie entirely inside a polygon.
+    a.lines.iter().any(|line| b.encloses(line[0]))
+        || b.lines.iter().any(|line| a.encloses(line[0]))
+}
+
+/// Tests whether every point of feature `a` is within or on the boundary of feature `b`.
+fn within(a: &Feature, b: &Feature, tolerance: f64) -> bool {
+    if a.is_polygon && !b.is_polygon || !a.lines.is_empty() && b.lines.is_empty() {
+        return false;
+    }
+    if !a.points.iter().all(|p| b.covers(*p, tolerance)) {
+        return false;
+    }
+    for line in &a.lines {
+        for i in 1..line.len() {
+            if !segment_within(line[i - 1], line[i], b, tolerance) {
+                return false;
+            }
+        }
+    }
+    if a.is_polygon {
+        // The boundary of `b` cannot pass through the interior of `a`, as it would if `b` had a
+        // hole within `a`.
+        for ring in &b.lines {
+            for p in ring {
+                if a.encloses(*p) && !a.touches(*p, tolerance) {
+                    return false;
+                }
+            }
+        }
+    }
+    true
+}
+
+/// Tests whether the segment from `p` to `q` is within or on the boundary of a feature, by
+/// splitting it where it meets the feature's lines and testing the middle of each piece.
+fn segment_within(p: Point2D, q: Point2D, feature: &Feature, tolerance: f64) -> bool {
+    let r = q - p;
+    let length = r * r;
+    if length == 0f64 {
+        return feature.covers(p, tolerance);
+    }
+    let mut splits = vec![0f64, 1f64];
+    for line in &feature.lines {
+        for k in 1..line.len() {
+            let (c, d) = (line[k - 1], line[k]);
+            for v in [c, d] {
+                if distance_to_segment(v, p, q) <= tolerance {
+                    splits.push(((v - p) * r / length).clamp(0f64, 1f64));
+                }
+            }
+            if let Some(t) = crossing(p, q, c, d) {
+                splits.push(t);
+            }
+        }
+    }
+    splits.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    for k in 1..splits.len() {
+        if splits[k] - splits[k - 1] <= f64::EPSILON {
+            continue;
+        }
+        let t = (splits[k - 1] + splits[k]) / 2f64;
+        if !feature.covers(Point2D::new(p.x + t * r.x, p.y + t * r.y), tolerance) {
+            return false;
+        }
+    }
+    true
+}
+
+/// Returns the shortest distance between two features, which is zero if they intersect.
+fn distance(a: &Feature, b: &Feature, tolerance: f64) -> f64 {
+    if intersects(a, b, tolerance) {
+        return 0f64;
+    }
+    let mut min_dist = f64::INFINITY;
+    for p in &a.points {
+        for q in &b.points {
+            min_dist = min_dist.min(p.distance(q));
+        }
+    }
+    for (points, lines) in [(&a.points, &b.lines), (&b.points, &a.lines)] {
+        for p in points.iter() {
+            for line in lines.iter() {
+                for k in 1..line.len() {
+                    min_dist = min_dist.min(distance_to_segment(*p, line[k - 1], line[k]));
+                }
+            }
+        }
+    }
+    for line_a in &a.lines {
+        for line_b in &b.lines {
+            for i in 1..line_a.len() {
+                for k in 1..line_b.len() {
+                    let d = segment_distance(line_a[i - 1], line_a[i], line_b[k - 1], line_b[k]);
+                    min_dist = min_dist.min(d);
+                }
+            }
+        }
+    }
+    min_dist
+}
+
+/// Returns the distance between two bounding boxes, which is zero if they overlap.
+fn box_distance(a: &BoundingBox, b: &BoundingBox) -> f64 {
+    let dx = (a.min_x - b.max_x).max(b.min_x - a.max_x).max(0f64);
+    let dy = (a.min_y - b.max_y).max(b.min_y - a.max_y).max(0f64);
+    (dx * dx + dy * dy).sqrt()
+}
+
+fn distance_to_segment(p: Point2D, a: Point2D, b: Point2D) -> f64 {
+    let r = b - a;
+    let length = r * r;
+    if length == 0f64 {
+        return p.distance(&a);
+    }
+    let t = ((p - a) * r / length).clamp(0f64, 1f64);
+    p.distance(&Point2D::new(a.x + t * r.x, a.y + t * r.y))
+}
+
+/// Returns the position along the segment from `a` to `b`, from 0 to 1, at which it crosses
+/// the segment from `c` to `d`, if the two cross at a single point.
+fn crossing(a: Point2D, b: Point2D, c: Point2D, d: Point2D) -> Option<f64> {
+    let r = b - a;
+    let s = d - c;
+    let denom = r.cross(s);
+    if denom == 0f64 {
+        return None;
+    }
+    let t = (c - a).cross(s) / denom;
+    let u = (c - a).cross(r) / denom;
+    if t > 0f64 && t < 1f64 && u > 0f64 && u < 1f64 {
+        Some(t)
+    } else {
+        None
+    }
+}
+
+fn segment_distance(a: Point2D, b: Point2D, c: Point2D, d: Point2D) -> f64 {
+    if crossing(a, b, c, d).is_some() {
+        return 0f64;
+    }
+    distance_to_segment(a, c, d)
+        .min(distance_to_segment(b, c, d))
+        .min(distance_to_segment(c, a, b))
+        .min(distance_to_segment(d, a, b))
+}
+
+/// Combines the values of a field for the join features that a feature is related to.
+fn combine_values(values: &[&FieldData], stat: Statistic, numeric: bool) -> FieldData {
+    let numbers: Vec<(f64, &FieldData)> = values
+        .iter()
+        .filter_map(|v| match v {
+            FieldData::Int(i) => Some((*i as f64, *v)),
+            FieldData::Real(r) => Some((*r, *v)),
+            _ => None,
+        })
+        .collect();
+    if !numeric || stat == Statistic::First || stat == Statistic::Count {
+        return values.first().map(|v| (*v).clone()).unwrap_or(FieldData::Null);
+    }
+    if numbers.is_empty() {
+        return FieldData::Null;
+    }
+    let sum: f64 = numbers.iter().map(|(x, _)| x).sum();
+    match stat {
+        Statistic::Sum => FieldData::Real(sum),
+        Statistic::Mean => FieldData::Real(sum / numbers.len() as f64),
+        Statistic::Min => numbers
+            .iter()
+            .fold(numbers[0], |m, n| if n.0 < m.0 { *n } else { m })
+            .1
+            .clone(),
+        _ => numbers
+            .iter()
+            .fold(numbers[0], |m, n| if n.0 > m.0 { *n } else { m })
+            .1
+            .clone(),
+    }
+}
+
+/// Returns a field name that is not yet used in an attribute table, adding a numbered suffix
+/// and shortening the name to the 10 characters allowed in a DBF file if necessary.
+fn unique_field_name(attributes: &ShapefileAttributes, name: &str) -> String {
+    if attributes.get_field_num(name).is_none() {
+        return name.to_string();
+    }
+    let mut n = 1;
+    loop {
+        let suffix = format!("_{}", n);
+        let stem: String = name.chars().take(10 - suffix.len()).collect();
+        let candidate = format!("{}{}", stem, suffix);
+        if attributes.get_field_num(&candidate).is_none() {
+            return candidate;
+        }
+        n += 1;
+    }
+}
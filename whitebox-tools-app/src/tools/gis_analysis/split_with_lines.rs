@@ -11,6 +11,7 @@ use whitebox_common::algorithms::{
     find_split_points_at_line_intersections, interior_point, is_clockwise_order,
 };
 use whitebox_common::structures::{BoundingBox, Polyline, Point2D};
+use whitebox_common::progress;
 use crate::tools::*;
 use whitebox_vector::*;
 use kdtree::distance::squared_euclidean;
@@ -153,6 +154,7 @@ impl WhiteboxTool for SplitWithLines {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input1_file: String = "".to_string();
         let mut input2_file: String = "".to_string();
@@ -204,13 +206,7 @@ impl WhiteboxTool for SplitWithLines {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         if !input1_file.contains(path::MAIN_SEPARATOR) && !input1_file.contains("/") {
@@ -542,11 +538,12 @@ impl WhiteboxTool for SplitWithLines {
                     output.attributes.add_record(atts, false);
                 }
 
+                cancel.check()?;
                 if verbose {
                     progress =
                         (100.0_f64 * (record_num1 + 1) as f64 / polylines1.len() as f64) as usize;
                     if progress != old_progress {
-                        println!("Progress: {}%", progress);
+                        progress::update(progress);
                         old_progress = progress;
                     }
                 }
@@ -554,12 +551,12 @@ impl WhiteboxTool for SplitWithLines {
 
             // Some features were found. Save the output file.
             if verbose {
-                println!("Saving data...")
+                progress::message("Saving data...")
             };
             let _ = match output.write() {
                 Ok(_) => {
                     if verbose {
-                        println!("Output file written")
+                        progress::output_written(&output.file_name)
                     }
                 }
                 Err(e) => return Err(e),
@@ -660,11 +657,12 @@ impl WhiteboxTool for SplitWithLines {
                     lengths.push(split_lines[j].length());
                 }
 
+                cancel.check()?;
                 if verbose {
                     progress =
                         (100.0_f64 * (record_num1 + 1) as f64 / polylines1.len() as f64) as usize;
                     if progress != old_progress {
-                        println!("Progress: {}%", progress);
+                        progress::update(progress);
                         old_progress = progress;
                     }
                 }
@@ -700,10 +698,11 @@ impl WhiteboxTool for SplitWithLines {
                 p = polylines[i].last_vertex();
                 kdtree.add([p.x, p.y], last_node_id(i)).unwrap();
 
+                cancel.check()?;
                 if verbose {
                     progress = (100.0_f64 * (i + 1) as f64 / polylines.len() as f64) as usize;
                     if progress != old_progress {
-                        println!("Creating tree: {}%", progress);
+                        progress::update_stage("Creating tree", progress);
                         old_progress = progress;
                     }
                 }
@@ -744,10 +743,11 @@ impl WhiteboxTool for SplitWithLines {
                     }
                 }
 
+                cancel.check()?;
                 if verbose {
                     progress = (100.0_f64 * (i + 1) as f64 / polylines.len() as f64) as usize;
                     if progress != old_progress {
-                        println!("Finding node vertices: {}%", progress);
+                        progress::update_stage("Finding node vertices", progress);
                         old_progress = progress;
                     }
                 }
@@ -896,10 +896,11 @@ impl WhiteboxTool for SplitWithLines {
                     }
                 }
 
+                cancel.check()?;
                 if verbose {
                     progress = (100.0_f64 * (i + 1) as f64 / polylines.len() as f64) as usize;
                     if progress != old_progress {
-                        println!("Finding polygons: {}%", progress);
+                        progress::update_stage("Finding polygons", progress);
                         old_progress = progress;
                     }
                 }
@@ -907,12 +908,12 @@ impl WhiteboxTool for SplitWithLines {
 
             // Some features were found. Save the output file.
             if verbose {
-                println!("Saving data...")
+                progress::message("Saving data...")
             };
             let _ = match output.write() {
                 Ok(_) => {
                     if verbose {
-                        println!("Output file written")
+                        progress::output_written(&output.file_name)
                     }
                 }
                 Err(e) => return Err(e),
@@ -922,7 +923,7 @@ impl WhiteboxTool for SplitWithLines {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!("{}", &format!("Elapsed Time: {}", elapsed_time));
+            progress::message(format!("Elapsed Time: {}", elapsed_time));
         }
 
         Ok(())
@@ -11,6 +11,7 @@ use whitebox_common::algorithms::{point_in_poly, triangulate};
 use crate::na;
 use whitebox_raster::*;
 use whitebox_common::structures::Point2D;
+use whitebox_common::progress;
 use crate::tools::*;
 use whitebox_vector::*;
 use std::env;
@@ -189,6 +190,7 @@ impl WhiteboxTool for TINGridding {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file: String = "".to_string();
         let mut field_name = String::new();
@@ -282,13 +284,7 @@ impl WhiteboxTool for TINGridding {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         if !input_file.contains(path::MAIN_SEPARATOR) && !input_file.contains("/") {
@@ -445,18 +441,19 @@ impl WhiteboxTool for TINGridding {
                 }
             }
 
+            cancel.check()?;
             if verbose {
                 progress =
                     (100.0_f64 * (record_num + 1) as f64 / input.num_records as f64) as usize;
                 if progress != old_progress {
-                    println!("Reading points: {}%", progress);
+                    progress::update_stage("Reading points", progress);
                     old_progress = progress;
                 }
             }
         }
 
         if verbose {
-            println!("Performing triangulation...");
+            progress::message("Performing triangulation...");
         }
         // this is where the heavy-lifting is
         let delaunay = triangulate(&points).expect("No triangulation exists.");
@@ -536,10 +533,11 @@ impl WhiteboxTool for TINGridding {
                 }
             }
 
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * triangle as f64 / (num_triangles - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    progress::update(progress);
                     old_progress = progress;
                 }
             }
@@ -556,12 +554,12 @@ impl WhiteboxTool for TINGridding {
         output.add_metadata_entry(format!("Elapsed Time (including I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
@@ -570,7 +568,7 @@ impl WhiteboxTool for TINGridding {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!("{}", &format!("Elapsed Time: {}", elapsed_time));
+            progress::message(format!("Elapsed Time: {}", elapsed_time));
         }
 
         Ok(())
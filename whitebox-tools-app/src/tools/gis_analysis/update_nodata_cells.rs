@@ -7,6 +7,7 @@ License: MIT
 */
 
 use whitebox_raster::*;
+use whitebox_common::progress;
 use crate::tools::*;
 use std::env;
 use std::f64;
@@ -40,7 +41,7 @@ impl UpdateNodataCells {
         let mut parameters = vec![];
         parameters.push(ToolParameter {
             name: "Input File 1".to_owned(),
-            flags: vec!["--input1".to_owned()],
+            flags: vec!["--i1".to_owned(), "--input1".to_owned()],
             description: "Input raster file 1.".to_owned(),
             parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
             default_value: None,
@@ -49,7 +50,7 @@ impl UpdateNodataCells {
 
         parameters.push(ToolParameter {
             name: "Input File 2 (Update Layer)".to_owned(),
-            flags: vec!["--input2".to_owned()],
+            flags: vec!["--i2".to_owned(), "--input2".to_owned()],
             description: "Input raster file 2; update layer.".to_owned(),
             parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
             default_value: None,
@@ -127,6 +128,7 @@ impl WhiteboxTool for UpdateNodataCells {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file1 = String::new();
         let mut input_file2 = String::new();
@@ -171,13 +173,7 @@ impl WhiteboxTool for UpdateNodataCells {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -196,7 +192,7 @@ impl WhiteboxTool for UpdateNodataCells {
         }
 
         if verbose {
-            println!("Reading data...")
+            progress::message("Reading data...")
         };
 
         let input1 = Raster::new(&input_file1, "r")?;
@@ -232,10 +228,11 @@ impl WhiteboxTool for UpdateNodataCells {
                     }
                 }
             }
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    progress::update(progress);
                     old_progress = progress;
                 }
             }
@@ -251,22 +248,19 @@ impl WhiteboxTool for UpdateNodataCells {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -7,6 +7,7 @@ License: MIT
 */
 
 use whitebox_common::structures::{BoundingBox, DistanceMetric, FixedRadiusSearch2D, Point2D};
+use whitebox_common::progress;
 use crate::tools::*;
 use whitebox_vector::ShapefileGeometry;
 use whitebox_vector::*;
@@ -163,6 +164,7 @@ impl WhiteboxTool for VectorHexBinning {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file: String = "".to_string();
         let mut output_file: String = "".to_string();
@@ -234,13 +236,7 @@ impl WhiteboxTool for VectorHexBinning {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         if width <= 0f64 {
@@ -349,10 +345,11 @@ impl WhiteboxTool for VectorHexBinning {
                     hex_index = ret[0].0;
                     count[hex_index] += 1;
                 }
+                cancel.check()?;
                 if verbose {
                     progress = (100.0_f64 * i as f64 / num_points as f64) as usize;
                     if progress != old_progress {
-                        println!("Binning points: {}%", progress);
+                        progress::update_stage("Binning points", progress);
                         old_progress = progress;
                     }
                 }
@@ -392,10 +389,11 @@ impl WhiteboxTool for VectorHexBinning {
                     rec_num += 1i32;
                 }
 
+                cancel.check()?;
                 if verbose {
                     progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Progress: {}%", progress);
+                        progress::update(progress);
                         old_progress = progress;
                     }
                 }
@@ -438,10 +436,11 @@ impl WhiteboxTool for VectorHexBinning {
                     hex_index = ret[0].0;
                     count[hex_index] += 1;
                 }
+                cancel.check()?;
                 if verbose {
                     progress = (100.0_f64 * i as f64 / num_points as f64) as usize;
                     if progress != old_progress {
-                        println!("Binning points: {}%", progress);
+                        progress::update_stage("Binning points", progress);
                         old_progress = progress;
                     }
                 }
@@ -480,10 +479,11 @@ impl WhiteboxTool for VectorHexBinning {
                     rec_num += 1i32;
                 }
 
+                cancel.check()?;
                 if verbose {
                     progress = (100.0_f64 * col as f64 / (columns - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Progress: {}%", progress);
+                        progress::update(progress);
                         old_progress = progress;
                     }
                 }
@@ -491,12 +491,12 @@ impl WhiteboxTool for VectorHexBinning {
         }
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
@@ -505,7 +505,7 @@ impl WhiteboxTool for VectorHexBinning {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!("{}", &format!("Elapsed Time: {}", elapsed_time));
+            progress::message(format!("Elapsed Time: {}", elapsed_time));
         }
 
         Ok(())
@@ -8,6 +8,7 @@ License: MIT
 
 use whitebox_common::algorithms::{is_clockwise_order, triangulate};
 use whitebox_common::structures::{BoundingBox, Point2D};
+use whitebox_common::progress;
 use crate::tools::*;
 use whitebox_vector::*;
 use std::collections::HashMap;
@@ -147,6 +148,7 @@ impl WhiteboxTool for VoronoiDiagram {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file: String = "".to_string();
         let mut output_file: String = "".to_string();
@@ -191,13 +193,7 @@ impl WhiteboxTool for VoronoiDiagram {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         if !input_file.contains(path::MAIN_SEPARATOR) && !input_file.contains("/") {
@@ -215,7 +211,7 @@ impl WhiteboxTool for VoronoiDiagram {
             && input.header.shape_type.base_shape_type() != ShapeType::MultiPoint
         {
             if verbose {
-                println!("Warning: The input file is not of a POINT base shape type. Unexpected results may occur.");
+                progress::warning("The input file is not of a POINT base shape type. Unexpected results may occur.");
             }
             // return Err(Error::new(
             //     ErrorKind::InvalidInput,
@@ -244,11 +240,12 @@ impl WhiteboxTool for VoronoiDiagram {
                 record_numbers.push(record_num);
             }
 
+            cancel.check()?;
             if verbose {
                 progress =
                     (100.0_f64 * (record_num + 1) as f64 / input.num_records as f64) as usize;
                 if progress != old_progress {
-                    println!("Reading points: {}%", progress);
+                    progress::update_stage("Reading points", progress);
                     old_progress = progress;
                 }
             }
@@ -295,13 +292,13 @@ impl WhiteboxTool for VoronoiDiagram {
 
         // Do the Delaunay triangulation
         if verbose {
-            println!("Performing triangulation...");
+            progress::message("Performing triangulation...");
         }
         // this is where the heavy-lifting is
         let delaunay = triangulate(&points).expect("No triangulation exists.");
 
         if verbose {
-            println!("Creating point-halfedge mapping...");
+            progress::message("Creating point-halfedge mapping...");
         }
         let mut point_edge_map = HashMap::new(); // point id to half-edge id
         for edge in 0..delaunay.triangles.len() {
@@ -309,11 +306,12 @@ impl WhiteboxTool for VoronoiDiagram {
             if !point_edge_map.contains_key(&endpoint) || delaunay.halfedges[edge] == EMPTY {
                 point_edge_map.insert(endpoint, edge);
             }
+            cancel.check()?;
             if verbose {
                 progress =
                     (100.0_f64 * edge as f64 / (delaunay.triangles.len() - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    progress::update(progress);
                     old_progress = progress;
                 }
             }
@@ -359,22 +357,23 @@ impl WhiteboxTool for VoronoiDiagram {
                 }
             }
 
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * p as f64 / (input.num_records - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Creating Voronoi cells: {}%", progress);
+                    progress::update_stage("Creating Voronoi cells", progress);
                     old_progress = progress;
                 }
             }
         }
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
@@ -383,7 +382,7 @@ impl WhiteboxTool for VoronoiDiagram {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!("{}", &format!("Elapsed Time: {}", elapsed_time));
+            progress::message(format!("Elapsed Time: {}", elapsed_time));
         }
 
         Ok(())
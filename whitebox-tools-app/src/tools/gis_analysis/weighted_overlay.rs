@@ -7,6 +7,7 @@ License: MIT
 */
 
 use whitebox_raster::*;
+use whitebox_common::progress;
 use crate::tools::*;
 use std::env;
 use std::f64;
@@ -165,6 +166,7 @@ impl WhiteboxTool for WeightedOverlay {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_files = String::new();
         let mut weights_list = String::new();
@@ -236,13 +238,7 @@ impl WhiteboxTool for WeightedOverlay {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -342,7 +338,7 @@ impl WhiteboxTool for WeightedOverlay {
         for value in vec {
             if !value.trim().is_empty() {
                 if verbose {
-                    println!("Reading data...")
+                    progress::message("Reading data...")
                 };
 
                 let mut input_file = value.trim().to_string();
@@ -387,13 +383,11 @@ impl WhiteboxTool for WeightedOverlay {
                             }
                         }
                     }
+                    cancel.check()?;
                     if verbose {
                         progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                         if progress != old_progress {
-                            println!(
-                                "Processing Factors (loop {} of {}): {}%",
-                                i, num_files, progress
-                            );
+                            progress::update_stage(&format!("Processing Factors (loop {} of {})", i, num_files), progress);
                             old_progress = progress;
                         }
                     }
@@ -416,7 +410,7 @@ impl WhiteboxTool for WeightedOverlay {
         for value in constraint_file_names {
             if !value.trim().is_empty() {
                 if verbose {
-                    println!("Reading data...")
+                    progress::message("Reading data...")
                 };
 
                 let mut input_file = value.trim().to_string();
@@ -443,13 +437,11 @@ impl WhiteboxTool for WeightedOverlay {
                             output.set_value(row, col, out_nodata);
                         } // else it stays unaltered
                     }
+                    cancel.check()?;
                     if verbose {
                         progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                         if progress != old_progress {
-                            println!(
-                                "Processing Constraints (loop {} of {}): {}%",
-                                i, num_constraints, progress
-                            );
+                            progress::update_stage(&format!("Processing Constraints (loop {} of {})", i, num_constraints), progress);
                             old_progress = progress;
                         }
                     }
@@ -467,22 +459,19 @@ impl WhiteboxTool for WeightedOverlay {
         output.add_metadata_entry(format!("Elapsed Time (including I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (including I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (including I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -8,6 +8,7 @@ License: MIT
 
 use whitebox_raster::*;
 use whitebox_common::structures::Array2D;
+use whitebox_common::progress;
 use crate::tools::*;
 use num_cpus;
 use std::env;
@@ -16,7 +17,6 @@ use std::io::{Error, ErrorKind};
 use std::path;
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::thread;
 
 /// This tool calculates the average slope gradient (i.e. slope steepness in degrees) of the flowpaths that
 /// pass through each grid cell in an input digital elevation model (DEM). The user must specify the name of
@@ -47,7 +47,7 @@ impl AverageFlowpathSlope {
         let mut parameters = vec![];
         parameters.push(ToolParameter {
             name: "Input DEM File".to_owned(),
-            flags: vec!["-i".to_owned(), "--dem".to_owned()],
+            flags: vec!["-i".to_owned(), "--input".to_owned(), "--dem".to_owned()],
             description: "Input raster DEM file.".to_owned(),
             parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
             default_value: None,
@@ -125,6 +125,7 @@ impl WhiteboxTool for AverageFlowpathSlope {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut output_file = String::new();
@@ -162,13 +163,7 @@ impl WhiteboxTool for AverageFlowpathSlope {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -184,7 +179,7 @@ impl WhiteboxTool for AverageFlowpathSlope {
         }
 
         if verbose {
-            println!("Reading data...")
+            progress::message("Reading data...")
         };
 
         let input = Arc::new(Raster::new(&input_file, "r")?);
@@ -211,7 +206,7 @@ impl WhiteboxTool for AverageFlowpathSlope {
         for tid in 0..num_procs {
             let input = input.clone();
             let tx = tx.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let nodata = input.configs.nodata;
                 let d_x = [1, 1, 1, 0, -1, -1, -1, 0];
                 let d_y = [-1, 0, 1, 1, 1, 0, -1, -1];
@@ -262,14 +257,16 @@ impl WhiteboxTool for AverageFlowpathSlope {
                             data[col as usize] = -1i8;
                         }
                     }
-                    tx.send((row, data, interior_pit_found)).unwrap();
+                    if tx.send((row, data, interior_pit_found)).is_err() {
+                        return;
+                    }
                 }
             });
         }
 
         let mut interior_pit_found = false;
         for r in 0..rows {
-            let (row, data, pit) = rx.recv().expect("Error receiving data from thread.");
+            let (row, data, pit) = cancel.recv(&rx)?;
             flow_dir.set_row_data(row, data); //(data.0, data.1);
             if pit {
                 interior_pit_found = true;
@@ -277,7 +274,7 @@ impl WhiteboxTool for AverageFlowpathSlope {
             if verbose {
                 progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Flow directions: {}%", progress);
+                    progress::update_stage("Flow directions", progress);
                     old_progress = progress;
                 }
             }
@@ -292,7 +289,7 @@ impl WhiteboxTool for AverageFlowpathSlope {
             let input = input.clone();
             let flow_dir = flow_dir.clone();
             let tx = tx.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let d_x = [1, 1, 1, 0, -1, -1, -1, 0];
                 let d_y = [-1, 0, 1, 1, 1, 0, -1, -1];
                 let inflowing_vals: [i8; 8] = [4, 5, 6, 7, 0, 1, 2, 3];
@@ -314,7 +311,9 @@ impl WhiteboxTool for AverageFlowpathSlope {
                             data[col as usize] = -1i8;
                         }
                     }
-                    tx.send((row, data)).unwrap();
+                    if tx.send((row, data)).is_err() {
+                        return;
+                    }
                 }
             });
         }
@@ -326,7 +325,7 @@ impl WhiteboxTool for AverageFlowpathSlope {
         let mut stack = Vec::with_capacity((rows * columns) as usize);
         let mut num_solved_cells = 0;
         for r in 0..rows {
-            let (row, data) = rx.recv().expect("Error receiving data from thread.");
+            let (row, data) = cancel.recv(&rx)?;
             num_inflowing.set_row_data(row, data);
             for col in 0..columns {
                 if num_inflowing[(row, col)] == 0i8 {
@@ -342,7 +341,7 @@ impl WhiteboxTool for AverageFlowpathSlope {
             if verbose {
                 progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Num. inflowing neighbours: {}%", progress);
+                    progress::update_stage("Num. inflowing neighbours", progress);
                     old_progress = progress;
                 }
             }
@@ -401,11 +400,12 @@ impl WhiteboxTool for AverageFlowpathSlope {
                 .atan()
                 .to_degrees();
 
+            cancel.check()?;
             if verbose {
                 num_solved_cells += 1;
                 progress = (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Flowpath tracing: {}%", progress);
+                    progress::update_stage("Flowpath tracing", progress);
                     old_progress = progress;
                 }
             }
@@ -421,28 +421,25 @@ impl WhiteboxTool for AverageFlowpathSlope {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", "")
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", ""));
         }
         if interior_pit_found {
-            println!("**********************************************************************************");
-            println!("WARNING: Interior pit cells were found within the input DEM. It is likely that the 
+            progress::message("**********************************************************************************");
+            progress::warning("Interior pit cells were found within the input DEM. It is likely that the 
             DEM needs to be processed to remove topographic depressions and flats prior to
             running this tool.");
-            println!("**********************************************************************************");
+            progress::message("**********************************************************************************");
         }
 
         Ok(())
@@ -8,6 +8,7 @@ License: MIT
 
 use whitebox_raster::*;
 use whitebox_common::structures::Array2D;
+use whitebox_common::progress;
 use crate::tools::*;
 use std::cmp::Ordering;
 use std::collections::BinaryHeap;
@@ -74,7 +75,7 @@ impl BreachDepressions {
         let mut parameters = vec![];
         parameters.push(ToolParameter {
             name: "Input DEM File".to_owned(),
-            flags: vec!["-i".to_owned(), "--dem".to_owned()],
+            flags: vec!["-i".to_owned(), "--input".to_owned(), "--dem".to_owned()],
             description: "Input raster DEM file.".to_owned(),
             parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
             default_value: None,
@@ -189,6 +190,7 @@ impl WhiteboxTool for BreachDepressions {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut output_file = String::new();
@@ -273,13 +275,7 @@ impl WhiteboxTool for BreachDepressions {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -295,11 +291,11 @@ impl WhiteboxTool for BreachDepressions {
         }
 
         if verbose {
-            println!("Reading data...")
+            progress::message("Reading data...")
         };
 
         if verbose && constrained_mode {
-            println!("Breaching in constrained mode...");
+            progress::message("Breaching in constrained mode...");
         }
 
         let mut input = Raster::new(&input_file, "r")?;
@@ -351,10 +347,11 @@ impl WhiteboxTool for BreachDepressions {
                         }
                     }
                 }
+                cancel.check()?;
                 if verbose {
                     progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Filling pits: {}%", progress);
+                        progress::update_stage("Filling pits", progress);
                         old_progress = progress;
                     }
                 }
@@ -433,10 +430,11 @@ impl WhiteboxTool for BreachDepressions {
                 }
             }
 
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    progress::update(progress);
                     old_progress = progress;
                 }
             }
@@ -517,12 +515,13 @@ impl WhiteboxTool for BreachDepressions {
                     }
                 }
 
+                cancel.check()?;
                 if verbose {
                     num_solved_cells += 1;
                     progress =
                         (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Progress: {}%", progress);
+                        progress::update(progress);
                         old_progress = progress;
                     }
                 }
@@ -726,12 +725,13 @@ impl WhiteboxTool for BreachDepressions {
                     }
                 }
 
+                cancel.check()?;
                 if verbose {
                     num_solved_cells += 1;
                     progress =
                         (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Progress: {}%", progress);
+                        progress::update(progress);
                         old_progress = progress;
                     }
                 }
@@ -761,12 +761,13 @@ impl WhiteboxTool for BreachDepressions {
                             }
                         }
                     }
+                    cancel.check()?;
                     if verbose {
                         num_solved_cells += 1;
                         progress =
                             (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
                         if progress != old_progress {
-                            println!("Filling DEM: {}%", progress);
+                            progress::update_stage("Filling DEM", progress);
                             old_progress = progress;
                         }
                     }
@@ -791,21 +792,18 @@ impl WhiteboxTool for BreachDepressions {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -8,6 +8,7 @@ License: MIT
 
 use whitebox_raster::*;
 use whitebox_common::structures::Array2D;
+use whitebox_common::progress;
 use crate::tools::*;
 use std::cmp::Ordering;
 use std::cmp::Ordering::Equal;
@@ -19,7 +20,6 @@ use std::io::{Error, ErrorKind};
 use std::path;
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::thread;
 
 /// This tool can be used to perform a type of optimal depression breaching to prepare a
 /// digital elevation model (DEM) for hydrological analysis. Depression breaching is a common
@@ -111,7 +111,7 @@ impl BreachDepressionsLeastCost {
         let mut parameters = vec![];
         parameters.push(ToolParameter {
             name: "Input DEM File".to_owned(),
-            flags: vec!["-i".to_owned(), "--dem".to_owned()],
+            flags: vec!["-i".to_owned(), "--input".to_owned(), "--dem".to_owned()],
             description: "Input raster DEM file.".to_owned(),
             parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
             default_value: None,
@@ -237,6 +237,7 @@ impl WhiteboxTool for BreachDepressionsLeastCost {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut output_file = String::new();
@@ -317,13 +318,7 @@ impl WhiteboxTool for BreachDepressionsLeastCost {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -339,7 +334,7 @@ impl WhiteboxTool for BreachDepressionsLeastCost {
         }
 
         if verbose {
-            println!("Reading data...")
+            progress::message("Reading data...")
         };
 
         let input = Arc::new(Raster::new(&input_file, "r").expect("Error reading input raster"));
@@ -395,7 +390,7 @@ impl WhiteboxTool for BreachDepressionsLeastCost {
         for tid in 0..num_procs {
             let input = input.clone();
             let tx = tx.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let (mut z, mut zn, mut min_zn): (f64, f64, f64);
                 let mut flag: bool;
                 let dx = [1, 1, 1, 0, -1, -1, -1, 0];
@@ -430,7 +425,9 @@ impl WhiteboxTool for BreachDepressionsLeastCost {
                             }
                         }
                     }
-                    tx.send((row, data, pits)).unwrap();
+                    if tx.send((row, data, pits)).is_err() {
+                        return;
+                    }
                 }
             });
         }
@@ -438,14 +435,14 @@ impl WhiteboxTool for BreachDepressionsLeastCost {
         let mut undefined_flow_cells: Vec<(isize, isize, f64)> = vec![];
         let mut undefined_flow_cells2 = vec![];
         for r in 0..rows {
-            let (row, data, mut pits) = rx.recv().expect("Error receiving data from thread.");
+            let (row, data, mut pits) = cancel.recv(&rx)?;
             output.set_row_data(row, data);
             undefined_flow_cells.append(&mut pits);
 
             if verbose {
                 progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Finding pits: {}%", progress);
+                    progress::update_stage("Finding pits", progress);
                     old_progress = progress;
                 }
             }
@@ -462,7 +459,7 @@ impl WhiteboxTool for BreachDepressionsLeastCost {
         undefined_flow_cells.sort_by(|a, b| b.2.partial_cmp(&a.2).unwrap_or(Equal));
         let num_deps = undefined_flow_cells.len();
         if num_deps == 0 && verbose {
-            println!("No depressions found. Process ending...");
+            progress::message("No depressions found. Process ending...");
         }
 
         num_solved = 0;
@@ -583,25 +580,26 @@ impl WhiteboxTool for BreachDepressionsLeastCost {
                 }
             }
 
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64
                     * (1f64 - (undefined_flow_cells.len()) as f64 / (num_deps - 1) as f64))
                     as usize;
                 if progress != old_progress {
-                    println!("Breaching: {}%", progress);
+                    progress::update_stage("Breaching", progress);
                     old_progress = progress;
                 }
             }
         }
         if verbose {
-            println!("Num. solved pits: {}", num_solved);
-            println!("Num. unsolved pits: {}", num_unsolved);
+            progress::message(format!("Num. solved pits: {}", num_solved));
+            progress::message(format!("Num. unsolved pits: {}", num_unsolved));
         }
 
         // Solve any remaining pits by filling
         if fill_deps && num_unsolved > 0 {
             if verbose {
-                println!("Filling remaining depressions...");
+                progress::message("Filling remaining depressions...");
             }
             // Find pit cells. This step is parallelized.
             let output2 = Arc::new(output);
@@ -609,7 +607,7 @@ impl WhiteboxTool for BreachDepressionsLeastCost {
             for tid in 0..num_procs {
                 let output2 = output2.clone();
                 let tx = tx.clone();
-                thread::spawn(move || {
+                progress::spawn(move || {
                     let mut z: f64;
                     let mut zn: f64;
                     let mut flag: bool;
@@ -634,19 +632,19 @@ impl WhiteboxTool for BreachDepressionsLeastCost {
                             }
                         }
                     }
-                    tx.send(pits).unwrap();
+                    let _ = tx.send(pits);
                 });
             }
 
             let mut undefined_flow_cells = vec![];
             for p in 0..num_procs {
-                let mut pits = rx.recv().expect("Error receiving data from thread.");
+                let mut pits = cancel.recv(&rx)?;
                 undefined_flow_cells.append(&mut pits);
 
                 if verbose {
                     progress = (100.0_f64 * (p + 1) as f64 / num_procs as f64) as usize;
                     if progress != old_progress {
-                        println!("Finding pit cells: {}%", progress);
+                        progress::update_stage("Finding pit cells", progress);
                         old_progress = progress;
                     }
                 }
@@ -778,10 +776,11 @@ impl WhiteboxTool for BreachDepressionsLeastCost {
                     }
                 }
 
+                cancel.check()?;
                 if verbose {
                     progress = (100.0_f64 * pit_id as f64 / num_deps as f64) as usize;
                     if progress != old_progress {
-                        println!("Filling depressions: {}%", progress);
+                        progress::update_stage("Filling depressions", progress);
                         old_progress = progress;
                     }
                 }
@@ -793,8 +792,8 @@ impl WhiteboxTool for BreachDepressionsLeastCost {
             if small_num > 0f64 {
                 // fix the flats
                 if verbose {
-                    println!("Fixing flow on flats...");
-                    println!("Flats increment value: {}", small_num);
+                    progress::message("Fixing flow on flats...");
+                    progress::message(format!("Flats increment value: {}", small_num));
                 }
                 // Some of the potential outlets really will have lower cells.
                 // let mut queue = VecDeque::new();
@@ -898,11 +897,12 @@ impl WhiteboxTool for BreachDepressionsLeastCost {
                         }
                     }
 
+                    cancel.check()?;
                     if verbose {
                         progress = (100.0_f64 * (1f64 - minheap.len() as f64 / num_outlets as f64))
                             as usize;
                         if progress != old_progress {
-                            println!("Fixing flats: {}%", progress);
+                            progress::update_stage("Fixing flats", progress);
                             old_progress = progress;
                         }
                     }
@@ -925,21 +925,18 @@ impl WhiteboxTool for BreachDepressionsLeastCost {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -8,6 +8,7 @@ License: MIT
 
 use whitebox_raster::*;
 use whitebox_common::structures::{Array2D, BoundingBox};
+use whitebox_common::progress;
 use crate::tools::*;
 use whitebox_vector::{ShapeType, Shapefile};
 use std::env;
@@ -163,6 +164,7 @@ impl WhiteboxTool for BurnStreamsAtRoads {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut streams_file = String::new();
         let mut roads_file = String::new();
@@ -227,13 +229,7 @@ impl WhiteboxTool for BurnStreamsAtRoads {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -255,13 +251,13 @@ impl WhiteboxTool for BurnStreamsAtRoads {
         }
 
         if verbose {
-            println!("Reading streams and roads data...")
+            progress::message("Reading streams and roads data...")
         };
         let streams = Shapefile::read(&streams_file)?;
         let roads = Shapefile::read(&roads_file)?;
 
         if verbose {
-            println!("Reading DEM raster...")
+            progress::message("Reading DEM raster...")
         };
         let dem = Raster::new(&dem_file, "r")?;
         let rows = dem.configs.rows as isize;
@@ -406,10 +402,11 @@ impl WhiteboxTool for BurnStreamsAtRoads {
             }
 
             count += 1f64;
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * count / (streams.num_records - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Rasterizing Streams: {}%", progress);
+                    progress::update_stage("Rasterizing Streams", progress);
                     old_progress = progress;
                 }
             }
@@ -597,10 +594,11 @@ impl WhiteboxTool for BurnStreamsAtRoads {
             }
 
             count += 1f64;
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * count / (roads.num_records - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Finding road intersections: {}%", progress);
+                    progress::update_stage("Finding road intersections", progress);
                     old_progress = progress;
                 }
             }
@@ -695,21 +693,18 @@ impl WhiteboxTool for BurnStreamsAtRoads {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -8,6 +8,7 @@ License: MIT
 
 use whitebox_raster::*;
 use whitebox_common::structures::Array2D;
+use whitebox_common::progress;
 use crate::tools::*;
 use num_cpus;
 use std::env;
@@ -16,7 +17,6 @@ use std::io::{Error, ErrorKind};
 use std::path;
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::thread;
 
 /// This tool is used to generate a flow accumulation grid (i.e. catchment area) using the
 /// D8 (O'Callaghan and Mark, 1984) algorithm. This algorithm is an example of single-flow-direction
@@ -72,7 +72,7 @@ impl D8FlowAccumulation {
         let mut parameters = vec![];
         parameters.push(ToolParameter {
             name: "Input DEM or D8 Pointer File".to_owned(),
-            flags: vec!["-i".to_owned(), "--input".to_owned()],
+            flags: vec!["-i".to_owned(), "--dem".to_owned(), "--input".to_owned()],
             description: "Input raster DEM or D8 pointer file.".to_owned(),
             parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
             default_value: None,
@@ -126,7 +126,7 @@ impl D8FlowAccumulation {
 
         parameters.push(ToolParameter {
             name: "If a pointer is input, does it use the ESRI pointer scheme?".to_owned(),
-            flags: vec!["--esri_pntr".to_owned()],
+            flags: vec!["--esri_style".to_owned(), "--esri_pntr".to_owned()],
             description: "Input D8 pointer uses the ESRI style scheme.".to_owned(),
             parameter_type: ParameterType::Boolean,
             default_value: Some("false".to_owned()),
@@ -192,6 +192,7 @@ impl WhiteboxTool for D8FlowAccumulation {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut output_file = String::new();
@@ -264,13 +265,7 @@ impl WhiteboxTool for D8FlowAccumulation {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -286,7 +281,7 @@ impl WhiteboxTool for D8FlowAccumulation {
         }
 
         if verbose {
-            println!("Reading data...")
+            progress::message("Reading data...")
         };
 
         let input = Arc::new(Raster::new(&input_file, "r")?); // Memory: 8 bytes per grid cell.
@@ -315,7 +310,7 @@ impl WhiteboxTool for D8FlowAccumulation {
             for tid in 0..num_procs {
                 let input = input.clone();
                 let tx = tx.clone();
-                thread::spawn(move || {
+                progress::spawn(move || {
                     // let nodata = input.configs.nodata;
                     let dx = [1, 1, 1, 0, -1, -1, -1, 0];
                     let dy = [-1, 0, 1, 1, 1, 0, -1, -1];
@@ -364,13 +359,15 @@ impl WhiteboxTool for D8FlowAccumulation {
                                 }
                             }
                         }
-                        tx.send((row, data, interior_pit_found)).unwrap();
+                        if tx.send((row, data, interior_pit_found)).is_err() {
+                            return;
+                        }
                     }
                 });
             }
 
             for r in 0..rows {
-                let (row, data, pit) = rx.recv().expect("Error receiving data from thread.");
+                let (row, data, pit) = cancel.recv(&rx)?;
                 flow_dir.set_row_data(row, data);
                 if pit {
                     interior_pit_found = true;
@@ -378,7 +375,7 @@ impl WhiteboxTool for D8FlowAccumulation {
                 if verbose {
                     progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Flow directions: {}%", progress);
+                        progress::update_stage("Flow directions", progress);
                         old_progress = progress;
                     }
                 }
@@ -390,7 +387,7 @@ impl WhiteboxTool for D8FlowAccumulation {
             for tid in 0..num_procs {
                 let input = input.clone();
                 let tx = tx.clone();
-                thread::spawn(move || {
+                progress::spawn(move || {
                     // let nodata = input.configs.nodata;
                     let mut z: f64;
                     let mut interior_pit_found = false;
@@ -448,13 +445,15 @@ impl WhiteboxTool for D8FlowAccumulation {
                                 }
                             }
                         }
-                        tx.send((row, data, interior_pit_found)).unwrap();
+                        if tx.send((row, data, interior_pit_found)).is_err() {
+                            return;
+                        }
                     }
                 });
             }
 
             for r in 0..rows {
-                let (row, data, pit) = rx.recv().expect("Error receiving data from thread.");
+                let (row, data, pit) = cancel.recv(&rx)?;
                 flow_dir.set_row_data(row, data);
                 if pit {
                     interior_pit_found = true;
@@ -462,7 +461,7 @@ impl WhiteboxTool for D8FlowAccumulation {
                 if verbose {
                     progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Flow directions: {}%", progress);
+                        progress::update_stage("Flow directions", progress);
                         old_progress = progress;
                     }
                 }
@@ -487,7 +486,7 @@ impl WhiteboxTool for D8FlowAccumulation {
             // let input = input.clone();
             let flow_dir = flow_dir.clone();
             let tx = tx.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let dx = [1, 1, 1, 0, -1, -1, -1, 0];
                 let dy = [-1, 0, 1, 1, 1, 0, -1, -1];
                 let inflowing_vals: [i8; 8] = [4, 5, 6, 7, 0, 1, 2, 3];
@@ -511,7 +510,9 @@ impl WhiteboxTool for D8FlowAccumulation {
                             data[col as usize] = -1i8;
                         }
                     }
-                    tx.send((row, data)).unwrap();
+                    if tx.send((row, data)).is_err() {
+                        return;
+                    }
                 }
             });
         }
@@ -519,7 +520,7 @@ impl WhiteboxTool for D8FlowAccumulation {
         let mut stack = Vec::with_capacity((rows * columns) as usize);
         let mut num_solved_cells = 0;
         for r in 0..rows {
-            let (row, data) = rx.recv().expect("Error receiving data from thread.");
+            let (row, data) = cancel.recv(&rx)?;
             num_inflowing.set_row_data(row, data);
             for col in 0..columns {
                 if num_inflowing[(row, col)] == 0i8 {
@@ -532,7 +533,7 @@ impl WhiteboxTool for D8FlowAccumulation {
             if verbose {
                 progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Num. inflowing neighbours: {}%", progress);
+                    progress::update_stage("Num. inflowing neighbours", progress);
                     old_progress = progress;
                 }
             }
@@ -561,11 +562,12 @@ impl WhiteboxTool for D8FlowAccumulation {
                 }
             }
 
+            cancel.check()?;
             if verbose {
                 num_solved_cells += 1;
                 progress = (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Flow accumulation: {}%", progress);
+                    progress::update_stage("Flow accumulation", progress);
                     old_progress = progress;
                 }
             }
@@ -623,10 +625,11 @@ impl WhiteboxTool for D8FlowAccumulation {
                     }
                 }
 
+                cancel.check()?;
                 if verbose {
                     progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Correcting values: {}%", progress);
+                        progress::update_stage("Correcting values", progress);
                         old_progress = progress;
                     }
                 }
@@ -655,10 +658,11 @@ impl WhiteboxTool for D8FlowAccumulation {
                     }
                 }
 
+                cancel.check()?;
                 if verbose {
                     progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Correcting values: {}%", progress);
+                        progress::update_stage("Correcting values", progress);
                         old_progress = progress;
                     }
                 }
@@ -678,28 +682,25 @@ impl WhiteboxTool for D8FlowAccumulation {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
         if interior_pit_found {
-            println!("**********************************************************************************");
-            println!("WARNING: Interior pit cells were found within the input DEM. It is likely that the 
+            progress::message("**********************************************************************************");
+            progress::warning("Interior pit cells were found within the input DEM. It is likely that the 
             DEM needs to be processed to remove topographic depressions and flats prior to
             running this tool.");
-            println!("**********************************************************************************");
+            progress::message("**********************************************************************************");
         }
 
         Ok(())
@@ -11,6 +11,7 @@ Notes: Assumes that each of the three input rasters have the same number of rows
 
 use whitebox_raster::*;
 use whitebox_common::structures::Array2D;
+use whitebox_common::progress;
 use crate::tools::*;
 use num_cpus;
 use std::env;
@@ -19,7 +20,6 @@ use std::io::{Error, ErrorKind};
 use std::path;
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::thread;
 
 /// This tool can be used to perform a mass flux calculation using DEM-based surface flow-routing techniques.
 /// For example, it could be used to model the distribution of sediment or phosphorous within a catchment.
@@ -59,7 +59,7 @@ impl D8MassFlux {
         let mut parameters = vec![];
         parameters.push(ToolParameter {
             name: "Input DEM File".to_owned(),
-            flags: vec!["--dem".to_owned()],
+            flags: vec!["-i".to_owned(), "--dem".to_owned()],
             description: "Input raster DEM file.".to_owned(),
             parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
             default_value: None,
@@ -160,6 +160,7 @@ impl WhiteboxTool for D8MassFlux {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut loading_file = String::new();
@@ -218,13 +219,7 @@ impl WhiteboxTool for D8MassFlux {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -249,7 +244,7 @@ impl WhiteboxTool for D8MassFlux {
         }
 
         if verbose {
-            println!("Reading data...")
+            progress::message("Reading data...")
         };
 
         let input = Arc::new(Raster::new(&input_file, "r")?);
@@ -296,7 +291,7 @@ impl WhiteboxTool for D8MassFlux {
         for tid in 0..num_procs {
             let input = input.clone();
             let tx = tx.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let nodata = input.configs.nodata;
                 let dx = [1, 1, 1, 0, -1, -1, -1, 0];
                 let dy = [-1, 0, 1, 1, 1, 0, -1, -1];
@@ -347,14 +342,16 @@ impl WhiteboxTool for D8MassFlux {
                             data[col as usize] = -1i8;
                         }
                     }
-                    tx.send((row, data, interior_pit_found)).unwrap();
+                    if tx.send((row, data, interior_pit_found)).is_err() {
+                        return;
+                    }
                 }
             });
         }
 
         let mut interior_pit_found = false;
         for r in 0..rows {
-            let (row, data, pit) = rx.recv().expect("Error receiving data from thread.");
+            let (row, data, pit) = cancel.recv(&rx)?;
             flow_dir.set_row_data(row, data); //(data.0, data.1);
             if pit {
                 interior_pit_found = true;
@@ -362,7 +359,7 @@ impl WhiteboxTool for D8MassFlux {
             if verbose {
                 progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Flow directions: {}%", progress);
+                    progress::update_stage("Flow directions", progress);
                     old_progress = progress;
                 }
             }
@@ -377,7 +374,7 @@ impl WhiteboxTool for D8MassFlux {
             let input = input.clone();
             let flow_dir = flow_dir.clone();
             let tx = tx.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let dx = [1, 1, 1, 0, -1, -1, -1, 0];
                 let dy = [-1, 0, 1, 1, 1, 0, -1, -1];
                 let inflowing_vals: [i8; 8] = [4, 5, 6, 7, 0, 1, 2, 3];
@@ -399,7 +396,9 @@ impl WhiteboxTool for D8MassFlux {
                             data[col as usize] = -1i8;
                         }
                     }
-                    tx.send((row, data)).unwrap();
+                    if tx.send((row, data)).is_err() {
+                        return;
+                    }
                 }
             });
         }
@@ -407,7 +406,7 @@ impl WhiteboxTool for D8MassFlux {
         let mut stack = Vec::with_capacity((rows * columns) as usize);
         let mut num_solved_cells = 0usize;
         for r in 0..rows {
-            let (row, data) = rx.recv().expect("Error receiving data from thread.");
+            let (row, data) = cancel.recv(&rx)?;
             num_inflowing.set_row_data(row, data);
             for col in 0..columns {
                 if num_inflowing[(row, col)] == 0i8 {
@@ -420,7 +419,7 @@ impl WhiteboxTool for D8MassFlux {
             if verbose {
                 progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Num. inflowing neighbours: {}%", progress);
+                    progress::update_stage("Num. inflowing neighbours", progress);
                     old_progress = progress;
                 }
             }
@@ -450,10 +449,11 @@ impl WhiteboxTool for D8MassFlux {
                         output.set_value(row, col, nodata);
                     }
                 }
+                cancel.check()?;
                 if verbose {
                     progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Initializing output raster: {}%", progress);
+                        progress::update_stage("Initializing output raster", progress);
                         old_progress = progress;
                     }
                 }
@@ -487,11 +487,12 @@ impl WhiteboxTool for D8MassFlux {
                 }
             }
 
+            cancel.check()?;
             if verbose {
                 num_solved_cells += 1;
                 progress = (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Flow accumulation: {}%", progress);
+                    progress::update_stage("Flow accumulation", progress);
                     old_progress = progress;
                 }
             }
@@ -504,10 +505,11 @@ impl WhiteboxTool for D8MassFlux {
                 }
             }
 
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Correcting values: {}%", progress);
+                    progress::update_stage("Correcting values", progress);
                     old_progress = progress;
                 }
             }
@@ -523,28 +525,25 @@ impl WhiteboxTool for D8MassFlux {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
         if interior_pit_found {
-            println!("**********************************************************************************");
-            println!("WARNING: Interior pit cells were found within the input DEM. It is likely that the 
+            progress::message("**********************************************************************************");
+            progress::warning("Interior pit cells were found within the input DEM. It is likely that the 
             DEM needs to be processed to remove topographic depressions and flats prior to
             running this tool.");
-            println!("**********************************************************************************");
+            progress::message("**********************************************************************************");
         }
 
         Ok(())
@@ -15,8 +15,8 @@ use std::io::{Error, ErrorKind};
 use std::path;
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::thread;
 use whitebox_common::structures::Array2D;
+use whitebox_common::progress;
 
 /// This tool is used to generate a flow pointer grid using the simple D8 (O'Callaghan and Mark, 1984) algorithm. The
 /// user must specify the name (`--dem`) of a digital elevation model (DEM) that has been hydrologically
@@ -66,7 +66,7 @@ impl D8Pointer {
         let mut parameters = vec![];
         parameters.push(ToolParameter {
             name: "Input DEM File".to_owned(),
-            flags: vec!["-i".to_owned(), "--dem".to_owned()],
+            flags: vec!["-i".to_owned(), "--input".to_owned(), "--dem".to_owned()],
             description: "Input raster DEM file.".to_owned(),
             parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
             default_value: None,
@@ -84,7 +84,7 @@ impl D8Pointer {
 
         parameters.push(ToolParameter {
             name: "Should the pointer file use the ESRI pointer scheme?".to_owned(),
-            flags: vec!["--esri_pntr".to_owned()],
+            flags: vec!["--esri_style".to_owned(), "--esri_pntr".to_owned()],
             description: "D8 pointer uses the ESRI style scheme.".to_owned(),
             parameter_type: ParameterType::Boolean,
             default_value: Some("false".to_owned()),
@@ -153,6 +153,7 @@ impl WhiteboxTool for D8Pointer {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut output_file = String::new();
@@ -200,13 +201,7 @@ impl WhiteboxTool for D8Pointer {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -222,7 +217,7 @@ impl WhiteboxTool for D8Pointer {
         }
 
         if verbose {
-            println!("Reading data...")
+            progress::message("Reading data...")
         };
 
         let input = Arc::new(Raster::new(&input_file, "r")?);
@@ -248,7 +243,7 @@ impl WhiteboxTool for D8Pointer {
         for tid in 0..num_procs {
             let input = input.clone();
             let tx1 = tx.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let d_x = [1, 1, 1, 0, -1, -1, -1, 0];
                 let d_y = [-1, 0, 1, 1, 1, 0, -1, -1];
                 let grid_lengths = [
@@ -295,20 +290,22 @@ impl WhiteboxTool for D8Pointer {
                             }
                         }
                     }
-                    tx1.send((row, data)).unwrap();
+                    if tx1.send((row, data)).is_err() {
+                        return;
+                    }
                 }
             });
         }
 
         let mut output: Array2D<i16> = Array2D::new(rows, columns, out_nodata, out_nodata)?;
         for row in 0..rows {
-            let data = rx.recv().expect("Error receiving data from thread.");
+            let data = cancel.recv(&rx)?;
             output.set_row_data(data.0, data.1);
 
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    progress::update(progress);
                     old_progress = progress;
                 }
             }
@@ -337,21 +334,18 @@ impl WhiteboxTool for D8Pointer {
         output_raster.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output_raster.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output_raster.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -8,6 +8,7 @@ License: MIT
 
 use whitebox_raster::*;
 use whitebox_common::structures::Array2D;
+use whitebox_common::progress;
 use crate::tools::*;
 use std::cmp::Ordering;
 use std::cmp::Ordering::Equal;
@@ -18,7 +19,6 @@ use std::io::{Error, ErrorKind};
 use std::path;
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::thread;
 
 /// This tool measures the depth that each grid cell in an input (`--dem`) raster digital elevation model (DEM)
 /// lies within a sink feature, i.e. a closed topographic depression. A sink, or depression, is a bowl-like
@@ -55,7 +55,7 @@ impl DepthInSink {
         let mut parameters = vec![];
         parameters.push(ToolParameter {
             name: "Input DEM File".to_owned(),
-            flags: vec!["-i".to_owned(), "--dem".to_owned()],
+            flags: vec!["-i".to_owned(), "--input".to_owned(), "--dem".to_owned()],
             description: "Input raster DEM file.".to_owned(),
             parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
             default_value: None,
@@ -73,7 +73,7 @@ impl DepthInSink {
 
         parameters.push(ToolParameter {
             name: "Should a background value of zero be used?".to_owned(),
-            flags: vec!["--zero_background".to_owned()],
+            flags: vec!["--esri_style".to_owned(), "--zero_background".to_owned()],
             description: "Flag indicating whether the background value of zero should be used."
                 .to_owned(),
             parameter_type: ParameterType::Boolean,
@@ -139,6 +139,7 @@ impl WhiteboxTool for DepthInSink {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut output_file = String::new();
@@ -180,13 +181,7 @@ impl WhiteboxTool for DepthInSink {
         }
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -202,7 +197,7 @@ impl WhiteboxTool for DepthInSink {
         }
 
         if verbose {
-            println!("Reading data...")
+            progress::message("Reading data...")
         };
 
         let input = Raster::new(&input_file, "r")?;
@@ -232,7 +227,7 @@ impl WhiteboxTool for DepthInSink {
         for tid in 0..num_procs {
             let filled_dem2 = filled_dem2.clone();
             let tx = tx.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let mut z: f64;
                 let mut zn: f64;
                 let mut flag: bool;
@@ -257,19 +252,22 @@ impl WhiteboxTool for DepthInSink {
                         }
                     }
                 }
-                tx.send(pits).unwrap();
+                // Release this thread's reference before sending, so that the Arc can be unwrapped
+                // as soon as the last result is received.
+                drop(filled_dem2);
+                let _ = tx.send(pits);
             });
         }
 
         let mut undefined_flow_cells = vec![];
         for p in 0..num_procs {
-            let mut pits = rx.recv().expect("Error receiving data from thread.");
+            let mut pits = cancel.recv(&rx)?;
             undefined_flow_cells.append(&mut pits);
 
             if verbose {
                 progress = (100.0_f64 * (p + 1) as f64 / num_procs as f64) as usize;
                 if progress != old_progress {
-                    println!("Finding pit cells: {}%", progress);
+                    progress::update_stage("Finding pit cells", progress);
                     old_progress = progress;
                 }
             }
@@ -402,10 +400,11 @@ impl WhiteboxTool for DepthInSink {
                 }
             }
 
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * pit_id as f64 / num_deps as f64) as usize;
                 if progress != old_progress {
-                    println!("Finding depressions: {}%", progress);
+                    progress::update_stage("Finding depressions", progress);
                     old_progress = progress;
                 }
             }
@@ -444,11 +443,12 @@ impl WhiteboxTool for DepthInSink {
                     }
                 }
             }
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * (1.0 - possible_outlets.len() as f64 / num_outlets as f64))
                     as usize;
                 if progress != old_progress {
-                    println!("Estimating depths: {}%", progress);
+                    progress::update_stage("Estimating depths", progress);
                     old_progress = progress;
                 }
             }
@@ -463,21 +463,18 @@ impl WhiteboxTool for DepthInSink {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
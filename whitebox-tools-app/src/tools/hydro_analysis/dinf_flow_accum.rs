@@ -8,6 +8,7 @@ License: MIT
 
 use whitebox_raster::*;
 use whitebox_common::structures::Array2D;
+use whitebox_common::progress;
 use crate::tools::*;
 use num_cpus;
 use std::env;
@@ -17,7 +18,6 @@ use std::io::{Error, ErrorKind};
 use std::path;
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::thread;
 
 /// This tool is used to generate a flow accumulation grid (i.e. contributing area) using the D-infinity algorithm
 /// (Tarboton, 1997). This algorithm is an examples of a multiple-flow-direction (MFD) method because the flow entering
@@ -68,7 +68,7 @@ impl DInfFlowAccumulation {
         let mut parameters = vec![];
         parameters.push(ToolParameter {
             name: "Input DEM or D-inf Pointer File".to_owned(),
-            flags: vec!["-i".to_owned(), "--input".to_owned()],
+            flags: vec!["-i".to_owned(), "--dem".to_owned(), "--input".to_owned()],
             description: "Input raster DEM or D-infinity pointer file.".to_owned(),
             parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
             default_value: None,
@@ -195,6 +195,7 @@ impl WhiteboxTool for DInfFlowAccumulation {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut output_file = String::new();
@@ -274,13 +275,7 @@ impl WhiteboxTool for DInfFlowAccumulation {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -296,7 +291,7 @@ impl WhiteboxTool for DInfFlowAccumulation {
         }
 
         if verbose {
-            println!("Reading data...")
+            progress::message("Reading data...")
         };
 
         let input = Arc::new(Raster::new(&input_file, "r")?);
@@ -325,7 +320,7 @@ impl WhiteboxTool for DInfFlowAccumulation {
             for tid in 0..num_procs {
                 let input = input.clone();
                 let tx = tx.clone();
-                thread::spawn(move || {
+                progress::spawn(move || {
                     let nodata = input.configs.nodata;
                     let grid_res = (cell_size_x + cell_size_y) / 2.0;
                     let mut dir: f64;
@@ -435,13 +430,15 @@ impl WhiteboxTool for DInfFlowAccumulation {
                                 data[col as usize] = -1f64;
                             }
                         }
-                        tx.send((row, data, interior_pit_found)).unwrap();
+                        if tx.send((row, data, interior_pit_found)).is_err() {
+                            return;
+                        }
                     }
                 });
             }
 
             for r in 0..rows {
-                let (row, data, pit) = rx.recv().expect("Error receiving data from thread.");
+                let (row, data, pit) = cancel.recv(&rx)?;
                 flow_dir.set_row_data(row, data);
                 if pit {
                     interior_pit_found = true;
@@ -449,7 +446,7 @@ impl WhiteboxTool for DInfFlowAccumulation {
                 if verbose {
                     progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Flow directions: {}%", progress);
+                        progress::update_stage("Flow directions", progress);
                         old_progress = progress;
                     }
                 }
@@ -466,7 +463,7 @@ impl WhiteboxTool for DInfFlowAccumulation {
         for tid in 0..num_procs {
             let flow_dir = flow_dir.clone();
             let tx = tx.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let d_x = [1, 1, 1, 0, -1, -1, -1, 0];
                 let d_y = [-1, 0, 1, 1, 1, 0, -1, -1];
                 let start_fd = [180f64, 225f64, 270f64, 315f64, 0f64, 45f64, 90f64, 135f64];
@@ -497,7 +494,9 @@ impl WhiteboxTool for DInfFlowAccumulation {
                             data[col as usize] = count;
                         }
                     }
-                    tx.send((row, data)).unwrap();
+                    if tx.send((row, data)).is_err() {
+                        return;
+                    }
                 }
             });
         }
@@ -507,7 +506,7 @@ impl WhiteboxTool for DInfFlowAccumulation {
         let mut stack = Vec::with_capacity((rows * columns) as usize);
         let mut num_solved_cells = 0;
         for r in 0..rows {
-            let (row, data) = rx.recv().expect("Error receiving data from thread.");
+            let (row, data) = cancel.recv(&rx)?;
             num_inflowing.set_row_data(row, data);
             for col in 0..columns {
                 if num_inflowing[(row, col)] == 0i8 {
@@ -520,7 +519,7 @@ impl WhiteboxTool for DInfFlowAccumulation {
             if verbose {
                 progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Num. inflowing neighbours: {}%", progress);
+                    progress::update_stage("Num. inflowing neighbours", progress);
                     old_progress = progress;
                 }
             }
@@ -637,11 +636,12 @@ impl WhiteboxTool for DInfFlowAccumulation {
                 }
             }
 
+            cancel.check()?;
             if verbose {
                 num_solved_cells += 1;
                 progress = (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Flow accumulation: {}%", progress);
+                    progress::update_stage("Flow accumulation", progress);
                     old_progress = progress;
                 }
             }
@@ -666,10 +666,11 @@ impl WhiteboxTool for DInfFlowAccumulation {
                     }
                 }
 
+                cancel.check()?;
                 if verbose {
                     progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Correcting values: {}%", progress);
+                        progress::update_stage("Correcting values", progress);
                         old_progress = progress;
                     }
                 }
@@ -684,10 +685,11 @@ impl WhiteboxTool for DInfFlowAccumulation {
                     }
                 }
 
+                cancel.check()?;
                 if verbose {
                     progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Correcting values: {}%", progress);
+                        progress::update_stage("Correcting values", progress);
                         old_progress = progress;
                     }
                 }
@@ -707,28 +709,25 @@ impl WhiteboxTool for DInfFlowAccumulation {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
         if interior_pit_found {
-            println!("**********************************************************************************");
-            println!("WARNING: Interior pit cells were found within the input DEM. It is likely that the 
+            progress::message("**********************************************************************************");
+            progress::warning("Interior pit cells were found within the input DEM. It is likely that the 
             DEM needs to be processed to remove topographic depressions and flats prior to
             running this tool.");
-            println!("**********************************************************************************");
+            progress::message("**********************************************************************************");
         }
 
         Ok(())
@@ -11,6 +11,7 @@ Notes: Assumes that each of the three input rasters have the same number of rows
 
 use whitebox_raster::*;
 use whitebox_common::structures::Array2D;
+use whitebox_common::progress;
 use crate::tools::*;
 use num_cpus;
 use std::env;
@@ -20,7 +21,6 @@ use std::io::{Error, ErrorKind};
 use std::path;
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::thread;
 
 /// This tool can be used to perform a mass flux calculation using DEM-based surface flow-routing techniques. For
 /// example, it could be used to model the distribution of sediment or phosphorous within a catchment. Flow-routing
@@ -60,7 +60,7 @@ impl DInfMassFlux {
         let mut parameters = vec![];
         parameters.push(ToolParameter {
             name: "Input DEM File".to_owned(),
-            flags: vec!["--dem".to_owned()],
+            flags: vec!["-i".to_owned(), "--dem".to_owned()],
             description: "Input raster DEM file.".to_owned(),
             parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
             default_value: None,
@@ -161,6 +161,7 @@ impl WhiteboxTool for DInfMassFlux {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut loading_file = String::new();
@@ -219,13 +220,7 @@ impl WhiteboxTool for DInfMassFlux {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -250,7 +245,7 @@ impl WhiteboxTool for DInfMassFlux {
         }
 
         if verbose {
-            println!("Reading data...")
+            progress::message("Reading data...")
         };
 
         let start = Instant::now();
@@ -298,7 +293,7 @@ impl WhiteboxTool for DInfMassFlux {
         for tid in 0..num_procs {
             let input = input.clone();
             let tx = tx.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let nodata = input.configs.nodata;
                 let grid_res = (cell_size_x + cell_size_y) / 2.0;
                 let mut dir: f64;
@@ -403,14 +398,16 @@ impl WhiteboxTool for DInfMassFlux {
                             data[col as usize] = -1f64;
                         }
                     }
-                    tx.send((row, data, interior_pit_found)).unwrap();
+                    if tx.send((row, data, interior_pit_found)).is_err() {
+                        return;
+                    }
                 }
             });
         }
 
         let mut interior_pit_found = false;
         for r in 0..rows {
-            let (row, data, pit) = rx.recv().expect("Error receiving data from thread.");
+            let (row, data, pit) = cancel.recv(&rx)?;
             flow_dir.set_row_data(row, data);
             if pit {
                 interior_pit_found = true;
@@ -418,7 +415,7 @@ impl WhiteboxTool for DInfMassFlux {
             if verbose {
                 progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Flow directions: {}%", progress);
+                    progress::update_stage("Flow directions", progress);
                     old_progress = progress;
                 }
             }
@@ -431,7 +428,7 @@ impl WhiteboxTool for DInfMassFlux {
         for tid in 0..num_procs {
             let flow_dir = flow_dir.clone();
             let tx = tx.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let d_x = [1, 1, 1, 0, -1, -1, -1, 0];
                 let d_y = [-1, 0, 1, 1, 1, 0, -1, -1];
                 let start_fd = [180f64, 225f64, 270f64, 315f64, 0f64, 45f64, 90f64, 135f64];
@@ -462,7 +459,9 @@ impl WhiteboxTool for DInfMassFlux {
                             data[col as usize] = count;
                         }
                     }
-                    tx.send((row, data)).unwrap();
+                    if tx.send((row, data)).is_err() {
+                        return;
+                    }
                 }
             });
         }
@@ -470,7 +469,7 @@ impl WhiteboxTool for DInfMassFlux {
         let mut stack = Vec::with_capacity((rows * columns) as usize);
         let mut num_solved_cells = 0usize;
         for r in 0..rows {
-            let (row, data) = rx.recv().expect("Error receiving data from thread.");
+            let (row, data) = cancel.recv(&rx)?;
             num_inflowing.set_row_data(row, data);
             for col in 0..columns {
                 if num_inflowing[(row, col)] == 0i8 {
@@ -483,7 +482,7 @@ impl WhiteboxTool for DInfMassFlux {
             if verbose {
                 progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Num. inflowing neighbours: {}%", progress);
+                    progress::update_stage("Num. inflowing neighbours", progress);
                     old_progress = progress;
                 }
             }
@@ -517,10 +516,11 @@ impl WhiteboxTool for DInfMassFlux {
                         output.set_value(row, col, nodata);
                     }
                 }
+                cancel.check()?;
                 if verbose {
                     progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Initializing output raster: {}%", progress);
+                        progress::update_stage("Initializing output raster", progress);
                         old_progress = progress;
                     }
                 }
@@ -623,11 +623,12 @@ impl WhiteboxTool for DInfMassFlux {
                 }
             }
 
+            cancel.check()?;
             if verbose {
                 num_solved_cells += 1;
                 progress = (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Flow accumulation: {}%", progress);
+                    progress::update_stage("Flow accumulation", progress);
                     old_progress = progress;
                 }
             }
@@ -640,10 +641,11 @@ impl WhiteboxTool for DInfMassFlux {
                 }
             }
 
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Correcting values: {}%", progress);
+                    progress::update_stage("Correcting values", progress);
                     old_progress = progress;
                 }
             }
@@ -662,28 +664,25 @@ impl WhiteboxTool for DInfMassFlux {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
         if interior_pit_found {
-            println!("**********************************************************************************");
-            println!("WARNING: Interior pit cells were found within the input DEM. It is likely that the 
+            progress::message("**********************************************************************************");
+            progress::warning("Interior pit cells were found within the input DEM. It is likely that the 
             DEM needs to be processed to remove topographic depressions and flats prior to
             running this tool.");
-            println!("**********************************************************************************");
+            progress::message("**********************************************************************************");
         }
 
         Ok(())
@@ -7,6 +7,7 @@ License: MIT
 */
 
 use whitebox_raster::*;
+use whitebox_common::progress;
 use crate::tools::*;
 use num_cpus;
 use std::env;
@@ -16,7 +17,6 @@ use std::io::{Error, ErrorKind};
 use std::path;
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::thread;
 
 /// This tool is used to generate a flow pointer grid (i.e. flow direction) using the D-infinity
 /// (Tarboton, 1997) algorithm. Dinf is a multiple-flow-direction (MFD) method because the flow
@@ -59,7 +59,7 @@ impl DInfPointer {
         let mut parameters = vec![];
         parameters.push(ToolParameter {
             name: "Input File".to_owned(),
-            flags: vec!["-i".to_owned(), "--dem".to_owned()],
+            flags: vec!["-i".to_owned(), "--input".to_owned(), "--dem".to_owned()],
             description: "Input raster DEM file.".to_owned(),
             parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
             default_value: None,
@@ -137,6 +137,7 @@ impl WhiteboxTool for DInfPointer {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut output_file = String::new();
@@ -176,13 +177,7 @@ impl WhiteboxTool for DInfPointer {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -198,7 +193,7 @@ impl WhiteboxTool for DInfPointer {
         }
 
         if verbose {
-            println!("Reading data...")
+            progress::message("Reading data...")
         };
 
         let input = Arc::new(Raster::new(&input_file, "r")?);
@@ -221,7 +216,7 @@ impl WhiteboxTool for DInfPointer {
         for tid in 0..num_procs {
             let input = input.clone();
             let tx = tx.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let nodata = input.configs.nodata;
                 let grid_res = (cell_size_x + cell_size_y) / 2.0;
                 let mut dir: f64;
@@ -325,7 +320,9 @@ impl WhiteboxTool for DInfPointer {
                             data[col as usize] = -1f64;
                         }
                     }
-                    tx.send((row, data, interior_pit_found)).unwrap();
+                    if tx.send((row, data, interior_pit_found)).is_err() {
+                        return;
+                    }
                 }
             });
         }
@@ -333,7 +330,7 @@ impl WhiteboxTool for DInfPointer {
         let mut output = Raster::initialize_using_file(&output_file, &input);
         let mut interior_pit_found = false;
         for r in 0..rows {
-            let (row, data, pit) = rx.recv().expect("Error receiving data from thread.");
+            let (row, data, pit) = cancel.recv(&rx)?;
             output.set_row_data(row, data);
             if pit {
                 interior_pit_found = true;
@@ -341,7 +338,7 @@ impl WhiteboxTool for DInfPointer {
             if verbose {
                 progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    progress::update(progress);
                     old_progress = progress;
                 }
             }
@@ -357,28 +354,25 @@ impl WhiteboxTool for DInfPointer {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
         if interior_pit_found {
-            println!("**********************************************************************************");
-            println!("WARNING: Interior pit cells were found within the input DEM. It is likely that the 
+            progress::message("**********************************************************************************");
+            progress::warning("Interior pit cells were found within the input DEM. It is likely that the 
             DEM needs to be processed to remove topographic depressions and flats prior to
             running this tool.");
-            println!("**********************************************************************************");
+            progress::message("**********************************************************************************");
         }
 
         Ok(())
@@ -8,6 +8,7 @@ License: MIT
 
 use whitebox_raster::*;
 use whitebox_common::structures::Array2D;
+use whitebox_common::progress;
 use crate::tools::*;
 use num_cpus;
 use std::collections::VecDeque;
@@ -18,7 +19,6 @@ use std::io::{Error, ErrorKind};
 use std::path;
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::thread;
 
 /// This tool can be used to calculate the distance from each grid cell in a raster to the nearest stream cell,
 /// measured along the downslope flowpath. The user must specify the name of an input digital elevation model (`--dem`)
@@ -78,7 +78,7 @@ impl DownslopeDistanceToStream {
 
         parameters.push(ToolParameter {
             name: "Use the D-infinity flow algorithm instead of D8?".to_owned(),
-            flags: vec!["--dinf".to_owned()],
+            flags: vec!["--dinfinity".to_owned(), "--dinf".to_owned()],
             description: "Use the D-infinity flow algorithm instead of D8?".to_owned(),
             parameter_type: ParameterType::Boolean,
             default_value: Some("false".to_owned()),
@@ -143,6 +143,7 @@ impl WhiteboxTool for DownslopeDistanceToStream {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut dem_file = String::new();
         let mut streams_file = String::new();
@@ -192,13 +193,7 @@ impl WhiteboxTool for DownslopeDistanceToStream {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -217,11 +212,11 @@ impl WhiteboxTool for DownslopeDistanceToStream {
         }
 
         if verbose {
-            println!("Reading DEM data...")
+            progress::message("Reading DEM data...")
         };
         let dem = Arc::new(Raster::new(&dem_file, "r")?);
         if verbose {
-            println!("Reading streams data...")
+            progress::message("Reading streams data...")
         };
         let streams = Raster::new(&streams_file, "r")?;
 
@@ -263,7 +258,7 @@ impl WhiteboxTool for DownslopeDistanceToStream {
             for tid in 0..num_procs {
                 let dem = dem.clone();
                 let tx = tx.clone();
-                thread::spawn(move || {
+                progress::spawn(move || {
                     let dx = [1, 1, 1, 0, -1, -1, -1, 0];
                     let dy = [-1, 0, 1, 1, 1, 0, -1, -1];
                     let grid_lengths = [
@@ -311,7 +306,9 @@ impl WhiteboxTool for DownslopeDistanceToStream {
                                 }
                             }
                         }
-                        tx.send((row, data, interior_pit_found)).unwrap();
+                        if tx.send((row, data, interior_pit_found)).is_err() {
+                            return;
+                        }
                     }
                 });
             }
@@ -324,7 +321,7 @@ impl WhiteboxTool for DownslopeDistanceToStream {
             let mut stack = Vec::with_capacity((rows * columns) as usize);
             let mut num_solved_cells = 0;
             for r in 0..rows {
-                let (row, data, pit) = rx.recv().expect("Error receiving data from thread.");
+                let (row, data, pit) = cancel.recv(&rx)?;
                 flow_dir.set_row_data(row, data);
                 if pit {
                     interior_pit_found = true;
@@ -352,7 +349,7 @@ impl WhiteboxTool for DownslopeDistanceToStream {
                 if verbose {
                     progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Flow directions: {}%", progress);
+                        progress::update_stage("Flow directions", progress);
                         old_progress = progress;
                     }
                 }
@@ -397,12 +394,13 @@ impl WhiteboxTool for DownslopeDistanceToStream {
                         }
                     }
                 }
+                cancel.check()?;
                 if verbose {
                     num_solved_cells += 1;
                     progress =
                         (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Progress: {}%", progress);
+                        progress::update(progress);
                         old_progress = progress;
                     }
                 }
@@ -418,29 +416,26 @@ impl WhiteboxTool for DownslopeDistanceToStream {
             output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
             if verbose {
-                println!("Saving data...")
+                progress::message("Saving data...")
             };
             let _ = match output.write() {
                 Ok(_) => {
                     if verbose {
-                        println!("Output file written")
+                        progress::output_written(&output.file_name)
                     }
                 }
                 Err(e) => return Err(e),
             };
             if verbose {
-                println!(
-                    "{}",
-                    &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-                );
+                progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
             }
 
             if interior_pit_found {
-                println!("**********************************************************************************");
-                println!("WARNING: Interior pit cells were found within the input DEM. It is likely that the 
+                progress::message("**********************************************************************************");
+                progress::warning("Interior pit cells were found within the input DEM. It is likely that the 
                 DEM needs to be processed to remove topographic depressions and flats prior to
                 running this tool.");
-                println!("**********************************************************************************");
+                progress::message("**********************************************************************************");
             }
         } else {
             // calculate the D-inf flow directions
@@ -457,7 +452,7 @@ impl WhiteboxTool for DownslopeDistanceToStream {
             for tid in 0..num_procs {
                 let input = dem.clone();
                 let tx = tx.clone();
-                thread::spawn(move || {
+                progress::spawn(move || {
                     let nodata = input.configs.nodata;
                     let grid_res = (cell_size_x + cell_size_y) / 2.0;
                     let mut dir: f64;
@@ -567,13 +562,15 @@ impl WhiteboxTool for DownslopeDistanceToStream {
                                 data[col as usize] = -1f64;
                             }
                         }
-                        tx.send((row, data, interior_pit_found)).unwrap();
+                        if tx.send((row, data, interior_pit_found)).is_err() {
+                            return;
+                        }
                     }
                 });
             }
 
             for r in 0..rows {
-                let (row, data, pit) = rx.recv().expect("Error receiving data from thread.");
+                let (row, data, pit) = cancel.recv(&rx)?;
                 flow_dir.set_row_data(row, data);
                 if pit {
                     interior_pit_found = true;
@@ -581,7 +578,7 @@ impl WhiteboxTool for DownslopeDistanceToStream {
                 if verbose {
                     progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Flow directions: {}%", progress);
+                        progress::update_stage("Flow directions", progress);
                         old_progress = progress;
                     }
                 }
@@ -682,7 +679,7 @@ impl WhiteboxTool for DownslopeDistanceToStream {
 
                 progress = ((num_solved_cells as f64 / num_cells as f64) * 100.0) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    progress::update(progress);
                     old_progress = progress;
                 }
             }
@@ -695,7 +692,7 @@ impl WhiteboxTool for DownslopeDistanceToStream {
 
                         progress = ((num_solved_cells as f64 / num_cells as f64) * 100.0) as usize;
                         if progress != old_progress {
-                            println!("Progress: {}%", progress);
+                            progress::update(progress);
                             old_progress = progress;
                         }
                     }
@@ -712,29 +709,26 @@ impl WhiteboxTool for DownslopeDistanceToStream {
             output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
             if verbose {
-                println!("Saving data...")
+                progress::message("Saving data...")
             };
             let _ = match output.write() {
                 Ok(_) => {
                     if verbose {
-                        println!("Output file written")
+                        progress::output_written(&output.file_name)
                     }
                 }
                 Err(e) => return Err(e),
             };
             if verbose {
-                println!(
-                    "{}",
-                    &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-                );
+                progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
             }
 
             if interior_pit_found {
-                println!("**********************************************************************************");
-                println!("WARNING: Interior pit cells were found within the input DEM. It is likely that the 
+                progress::message("**********************************************************************************");
+                progress::warning("Interior pit cells were found within the input DEM. It is likely that the 
                 DEM needs to be processed to remove topographic depressions and flats prior to
                 running this tool.");
-                println!("**********************************************************************************");
+                progress::message("**********************************************************************************");
             }
         }
 
@@ -8,6 +8,7 @@ License: MIT
 
 use whitebox_raster::*;
 use whitebox_common::structures::Array2D;
+use whitebox_common::progress;
 use crate::tools::*;
 use std::env;
 use std::f64;
@@ -92,7 +93,7 @@ impl DownslopeFlowpathLength {
 
         parameters.push(ToolParameter {
             name: "Does the pointer file use the ESRI pointer scheme?".to_owned(),
-            flags: vec!["--esri_pntr".to_owned()],
+            flags: vec!["--esri_style".to_owned(), "--esri_pntr".to_owned()],
             description: "D8 pointer uses the ESRI style scheme.".to_owned(),
             parameter_type: ParameterType::Boolean,
             default_value: Some("false".to_owned()),
@@ -158,6 +159,7 @@ impl WhiteboxTool for DownslopeFlowpathLength {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut d8_file = String::new();
         let mut watersheds_file = String::new();
@@ -214,13 +216,7 @@ impl WhiteboxTool for DownslopeFlowpathLength {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -254,7 +250,7 @@ impl WhiteboxTool for DownslopeFlowpathLength {
         }
 
         if verbose {
-            println!("Reading pointer data...")
+            progress::message("Reading pointer data...")
         };
         let pntr = Raster::new(&d8_file, "r")?;
         let rows = pntr.configs.rows as isize;
@@ -265,7 +261,7 @@ impl WhiteboxTool for DownslopeFlowpathLength {
         let diag_cell_size = (cell_size_x * cell_size_x + cell_size_y * cell_size_y).sqrt();
 
         if verbose {
-            println!("Initializing watershed data...")
+            progress::message("Initializing watershed data...")
         };
         let watersheds: Array2D<f32> = match use_watersheds {
             false => Array2D::new(1, 1, 1f32, 1f32)?,
@@ -282,7 +278,7 @@ impl WhiteboxTool for DownslopeFlowpathLength {
         // let watershed_nodata = watersheds.nodata;
 
         if verbose {
-            println!("Initializing weights data...")
+            progress::message("Initializing weights data...")
         };
         let weights: Array2D<f32> = match use_weights {
             false => Array2D::new(1, 1, 1f32, 1f32)?,
@@ -412,10 +408,11 @@ impl WhiteboxTool for DownslopeFlowpathLength {
                     output.set_value(row, col, out_nodata);
                 }
             }
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    progress::update(progress);
                     old_progress = progress;
                 }
             }
@@ -439,21 +436,18 @@ impl WhiteboxTool for DownslopeFlowpathLength {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
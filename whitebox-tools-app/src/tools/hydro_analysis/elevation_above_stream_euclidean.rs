@@ -8,6 +8,7 @@ License: MIT
 
 use whitebox_raster::*;
 use whitebox_common::structures::Array2D;
+use whitebox_common::progress;
 use crate::tools::*;
 use std::env;
 use std::f64;
@@ -136,6 +137,7 @@ impl WhiteboxTool for ElevationAboveStreamEuclidean {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut dem_file = String::new();
         let mut streams_file = String::new();
@@ -180,13 +182,7 @@ impl WhiteboxTool for ElevationAboveStreamEuclidean {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -205,7 +201,7 @@ impl WhiteboxTool for ElevationAboveStreamEuclidean {
         }
 
         if verbose {
-            println!("Reading data...")
+            progress::message("Reading data...")
         };
 
         let dem = Raster::new(&dem_file, "r")?;
@@ -250,10 +246,11 @@ impl WhiteboxTool for ElevationAboveStreamEuclidean {
                     allocation.set_value(row, col, inf_val);
                 }
             }
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Initializing Rasters: {}%", progress);
+                    progress::update_stage("Initializing Rasters", progress);
                     old_progress = progress;
                 }
             }
@@ -293,10 +290,11 @@ impl WhiteboxTool for ElevationAboveStreamEuclidean {
                     }
                 }
             }
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress (1 of 3): {}%", progress);
+                    progress::update_stage("Progress (1 of 3)", progress);
                     old_progress = progress;
                 }
             }
@@ -336,10 +334,11 @@ impl WhiteboxTool for ElevationAboveStreamEuclidean {
                     }
                 }
             }
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress (2 of 3): {}%", progress);
+                    progress::update_stage("Progress (2 of 3)", progress);
                     old_progress = progress;
                 }
             }
@@ -358,10 +357,11 @@ impl WhiteboxTool for ElevationAboveStreamEuclidean {
                     );
                 }
             }
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress (3 of 3): {}%", progress);
+                    progress::update_stage("Progress (3 of 3)", progress);
                     old_progress = progress;
                 }
             }
@@ -378,22 +378,19 @@ impl WhiteboxTool for ElevationAboveStreamEuclidean {
         allocation.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match allocation.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&allocation.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -8,6 +8,7 @@ License: MIT
 
 use whitebox_raster::*;
 use whitebox_common::structures::{Array2D, BoundingBox};
+use whitebox_common::progress;
 use crate::tools::*;
 use whitebox_vector::{ShapeType, Shapefile};
 use num_cpus;
@@ -20,7 +21,6 @@ use std::io::{Error, ErrorKind};
 use std::path;
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::thread;
 
 /// Burns streams into a digital elevation model (DEM) using the FillBurn (Saunders, 1999) method which produces a hydro-enforced DEM.
 /// This tool uses the algorithm described in:
@@ -150,6 +150,7 @@ impl WhiteboxTool for FillBurn {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut dem_file = String::new();
         let mut streams_file = String::new();
@@ -194,13 +195,7 @@ impl WhiteboxTool for FillBurn {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -219,12 +214,12 @@ impl WhiteboxTool for FillBurn {
         }
 
         if verbose {
-            println!("Reading streams data...")
+            progress::message("Reading streams data...")
         };
         let streams = Shapefile::read(&streams_file)?;
 
         if verbose {
-            println!("Reading DEM data...")
+            progress::message("Reading DEM data...")
         };
         let dem = Arc::new(Raster::new(&dem_file, "r")?); // Mem usage: 8 bytes per pixel
         let rows = dem.configs.rows as isize;
@@ -354,10 +349,11 @@ impl WhiteboxTool for FillBurn {
             }
 
             count += 1f64;
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * count / (streams.num_records - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Rasterizing Streams: {}%", progress);
+                    progress::update_stage("Rasterizing Streams", progress);
                     old_progress = progress;
                 }
             }
@@ -422,10 +418,11 @@ impl WhiteboxTool for FillBurn {
                         }
                     }
                 }
+                cancel.check()?;
                 if verbose {
                     progress = (100.0_f64 * a as f64 / 7.0) as usize;
                     if progress != old_progress {
-                        println!("Line Thinning {}: {}%", loop_num, progress);
+                        progress::update_stage(&format!("Line Thinning {}", loop_num), progress);
                         old_progress = progress;
                     }
                 }
@@ -446,7 +443,7 @@ impl WhiteboxTool for FillBurn {
             let dem = dem.clone();
             let raster_streams = raster_streams.clone();
             let tx = tx.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let mut z: f64;
                 for row in (0..rows).filter(|r| r % num_procs == tid) {
                     let mut data: Vec<f64> = vec![nodata; columns as usize];
@@ -458,20 +455,22 @@ impl WhiteboxTool for FillBurn {
                             data[col as usize] = z - 10000f64;
                         }
                     }
-                    tx.send((row, data)).unwrap();
+                    if tx.send((row, data)).is_err() {
+                        return;
+                    }
                 }
             });
         }
 
         let mut output = Raster::initialize_using_file(&output_file, &dem);
         for r in 0..rows {
-            let (row, data) = rx.recv().expect("Error receiving data from thread.");
+            let (row, data) = cancel.recv(&rx)?;
             output.set_row_data(row, data);
 
             if verbose {
                 progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Initializing output: {}%", progress);
+                    progress::update_stage("Initializing output", progress);
                     old_progress = progress;
                 }
             }
@@ -544,10 +543,11 @@ impl WhiteboxTool for FillBurn {
                 }
             }
 
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    progress::update(progress);
                     old_progress = progress;
                 }
             }
@@ -587,11 +587,12 @@ impl WhiteboxTool for FillBurn {
                 }
             }
 
+            cancel.check()?;
             if verbose {
                 num_solved_cells += 1;
                 progress = (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Filling DEM: {}%", progress);
+                    progress::update_stage("Filling DEM", progress);
                     old_progress = progress;
                 }
             }
@@ -614,10 +615,11 @@ impl WhiteboxTool for FillBurn {
                     }
                 }
             }
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Updating Stream Elevations: {}%", progress);
+                    progress::update_stage("Updating Stream Elevations", progress);
                     old_progress = progress;
                 }
             }
@@ -632,10 +634,11 @@ impl WhiteboxTool for FillBurn {
                     output.set_value(row, col, z);
                 }
             }
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Updating Stream Elevations: {}%", progress);
+                    progress::update_stage("Updating Stream Elevations", progress);
                     old_progress = progress;
                 }
             }
@@ -652,21 +655,18 @@ impl WhiteboxTool for FillBurn {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
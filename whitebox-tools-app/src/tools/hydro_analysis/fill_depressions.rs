@@ -8,6 +8,7 @@ License: MIT
 
 use whitebox_raster::*;
 use whitebox_common::structures::Array2D;
+use whitebox_common::progress;
 use crate::tools::*;
 use std::cmp::Ordering;
 use std::cmp::Ordering::Equal;
@@ -19,7 +20,6 @@ use std::io::{Error, ErrorKind};
 use std::path;
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::thread;
 
 /// This tool can be used to fill all of the depressions in a digital elevation model (DEM) and to remove the
 /// flat areas. This is a common pre-processing step required by many flow-path analysis tools to ensure continuous
@@ -74,7 +74,7 @@ impl FillDepressions {
         let mut parameters = vec![];
         parameters.push(ToolParameter {
             name: "Input DEM File".to_owned(),
-            flags: vec!["-i".to_owned(), "--dem".to_owned()],
+            flags: vec!["-i".to_owned(), "--input".to_owned(), "--dem".to_owned()],
             description: "Input raster DEM file.".to_owned(),
             parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
             default_value: None,
@@ -181,6 +181,7 @@ impl WhiteboxTool for FillDepressions {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut output_file = String::new();
@@ -249,13 +250,7 @@ impl WhiteboxTool for FillDepressions {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -271,7 +266,7 @@ impl WhiteboxTool for FillDepressions {
         }
 
         if verbose {
-            println!("Reading data...")
+            progress::message("Reading data...")
         };
 
         let input = Raster::new(&input_file, "r")?;
@@ -320,7 +315,7 @@ impl WhiteboxTool for FillDepressions {
         for tid in 0..num_procs {
             let output2 = output2.clone();
             let tx = tx.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let mut z: f64;
                 let mut zn: f64;
                 let mut flag: bool;
@@ -345,19 +340,22 @@ impl WhiteboxTool for FillDepressions {
                         }
                     }
                 }
-                tx.send(pits).unwrap();
+                // Release this thread's reference before sending, so that the Arc can be unwrapped
+                // as soon as the last result is received.
+                drop(output2);
+                let _ = tx.send(pits);
             });
         }
 
         let mut undefined_flow_cells = vec![];
         for p in 0..num_procs {
-            let mut pits = rx.recv().expect("Error receiving data from thread.");
+            let mut pits = cancel.recv(&rx)?;
             undefined_flow_cells.append(&mut pits);
 
             if verbose {
                 progress = (100.0_f64 * (p + 1) as f64 / num_procs as f64) as usize;
                 if progress != old_progress {
-                    println!("Finding pit cells: {}%", progress);
+                    progress::update_stage("Finding pit cells", progress);
                     old_progress = progress;
                 }
             }
@@ -515,10 +513,11 @@ impl WhiteboxTool for FillDepressions {
                 }
             }
 
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * pit_id as f64 / num_deps as f64) as usize;
                 if progress != old_progress {
-                    println!("Filling depressions: {}%", progress);
+                    progress::update_stage("Filling depressions", progress);
                     old_progress = progress;
                 }
             }
@@ -530,8 +529,8 @@ impl WhiteboxTool for FillDepressions {
         if small_num > 0f64 && fix_flats {
             // fix the flats
             if verbose {
-                println!("Fixing flow on flats...");
-                println!("Flats increment value: {}", small_num);
+                progress::message("Fixing flow on flats...");
+                progress::message(format!("Flats increment value: {}", small_num));
             }
             // Some of the potential outlets really will have lower cells.
             // let mut queue = VecDeque::new();
@@ -650,11 +649,12 @@ impl WhiteboxTool for FillDepressions {
                     // }
                 }
 
+                cancel.check()?;
                 if verbose {
                     progress =
                         (100.0_f64 * (1f64 - minheap.len() as f64 / num_outlets as f64)) as usize;
                     if progress != old_progress {
-                        println!("Fixing flats: {}%", progress);
+                        progress::update_stage("Fixing flats", progress);
                         old_progress = progress;
                     }
                 }
@@ -734,21 +734,18 @@ impl WhiteboxTool for FillDepressions {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
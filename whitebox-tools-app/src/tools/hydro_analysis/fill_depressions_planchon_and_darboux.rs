@@ -10,6 +10,7 @@ prefer the far more efficient FillDepressions tool instead.
 */
 
 use whitebox_raster::*;
+use whitebox_common::progress;
 use crate::tools::*;
 use std::env;
 use std::f64;
@@ -53,7 +54,7 @@ impl FillDepressionsPlanchonAndDarboux {
         let mut parameters = vec![];
         parameters.push(ToolParameter {
             name: "Input DEM File".to_owned(),
-            flags: vec!["-i".to_owned(), "--dem".to_owned()],
+            flags: vec!["-i".to_owned(), "--input".to_owned(), "--dem".to_owned()],
             description: "Input raster DEM file.".to_owned(),
             parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
             default_value: None,
@@ -151,6 +152,7 @@ impl WhiteboxTool for FillDepressionsPlanchonAndDarboux {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut output_file = String::new();
@@ -206,13 +208,7 @@ impl WhiteboxTool for FillDepressionsPlanchonAndDarboux {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -228,7 +224,7 @@ impl WhiteboxTool for FillDepressionsPlanchonAndDarboux {
         }
 
         if verbose {
-            println!("Reading data...")
+            progress::message("Reading data...")
         };
 
         let start_inclusive = Instant::now();
@@ -418,10 +414,11 @@ impl WhiteboxTool for FillDepressionsPlanchonAndDarboux {
                             }
                         }
                     }
+                    cancel.check()?;
                     if verbose {
                         progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                         if progress != old_progress {
-                            println!("progress (Loop {}): {}%", loop_num, progress);
+                            progress::update_stage(&format!("progress (Loop {})", loop_num), progress);
                             old_progress = progress;
                         }
                     }
@@ -457,10 +454,11 @@ impl WhiteboxTool for FillDepressionsPlanchonAndDarboux {
                             }
                         }
                     }
+                    cancel.check()?;
                     if verbose {
                         progress = (100.0_f64 * (rows - row) as f64 / (rows - 1) as f64) as usize;
                         if progress != old_progress {
-                            println!("progress (Loop {}): {}%", loop_num, progress);
+                            progress::update_stage(&format!("progress (Loop {})", loop_num), progress);
                             old_progress = progress;
                         }
                     }
@@ -496,10 +494,11 @@ impl WhiteboxTool for FillDepressionsPlanchonAndDarboux {
                             }
                         }
                     }
+                    cancel.check()?;
                     if verbose {
                         progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                         if progress != old_progress {
-                            println!("progress (Loop {}): {}%", loop_num, progress);
+                            progress::update_stage(&format!("progress (Loop {})", loop_num), progress);
                             old_progress = progress;
                         }
                     }
@@ -536,10 +535,11 @@ impl WhiteboxTool for FillDepressionsPlanchonAndDarboux {
                             }
                         }
                     }
+                    cancel.check()?;
                     if verbose {
                         progress = (100.0_f64 * (rows - row) as f64 / (rows - 1) as f64) as usize;
                         if progress != old_progress {
-                            println!("progress (Loop {}): {}%", loop_num, progress);
+                            progress::update_stage(&format!("progress (Loop {})", loop_num), progress);
                             old_progress = progress;
                         }
                     }
@@ -550,7 +550,7 @@ impl WhiteboxTool for FillDepressionsPlanchonAndDarboux {
                 i = 0;
             }
             if verbose {
-                println!("Loop {} (Num. modified cells: {})", loop_num, num_modified);
+                progress::message(format!("Loop {} (Num. modified cells: {})", loop_num, num_modified));
             }
         }
 
@@ -569,26 +569,20 @@ impl WhiteboxTool for FillDepressionsPlanchonAndDarboux {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
             let elapsed_time = get_formatted_elapsed_time(start_inclusive);
-            println!(
-                "{}",
-                &format!("Elapsed Time (including I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (including I/O): {}", elapsed_time));
         }
 
         Ok(())
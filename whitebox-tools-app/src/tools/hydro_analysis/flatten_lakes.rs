@@ -12,6 +12,7 @@ elevation on each lake's coastline.
 */
 
 use whitebox_common::algorithms;
+use whitebox_common::progress;
 use whitebox_raster::*;
 use whitebox_common::structures::{BoundingBox, Point2D};
 use crate::tools::*;
@@ -136,6 +137,7 @@ impl WhiteboxTool for FlattenLakes {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut polygons_file = String::new();
@@ -180,13 +182,7 @@ impl WhiteboxTool for FlattenLakes {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -205,7 +201,7 @@ impl WhiteboxTool for FlattenLakes {
         }
 
         if verbose {
-            println!("Reading data...")
+            progress::message("Reading data...")
         };
         let input = Raster::new(&input_file, "r")?;
 
@@ -378,10 +374,11 @@ impl WhiteboxTool for FlattenLakes {
             }
 
             count += 1f64;
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * count / (polygons.num_records - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Finding lake elevations: {}%", progress);
+                    progress::update_stage("Finding lake elevations", progress);
                     old_progress = progress;
                 }
             }
@@ -456,17 +453,13 @@ impl WhiteboxTool for FlattenLakes {
                                     output.set_value(r, c, min_elevs[feature_num]);
                                 }
                             }
+                            cancel.check()?;
                             if verbose && num_records < 25 {
                                 progress = (100.0_f64 * (r - starting_row) as f64
                                     / (ending_row - starting_row) as f64)
                                     as usize;
                                 if progress != old_progress {
-                                    println!(
-                                        "Updating lake elevations ({} of {}): {}%",
-                                        record_num + 1,
-                                        num_records,
-                                        progress
-                                    );
+                                    progress::update_stage(&format!("Updating lake elevations ({} of {})", record_num + 1, num_records), progress);
                                     old_progress = progress;
                                 }
                             }
@@ -532,17 +525,13 @@ impl WhiteboxTool for FlattenLakes {
                                 output.set_value(r, c, input.get_value(r, c));
                             }
                         }
+                        cancel.check()?;
                         if verbose && num_records < 25 {
                             progress = (100.0_f64 * (r - starting_row) as f64
                                 / (ending_row - starting_row) as f64)
                                 as usize;
                             if progress != old_progress {
-                                println!(
-                                    "Updating lake elevations ({} of {}): {}%",
-                                    record_num + 1,
-                                    num_records,
-                                    progress
-                                );
+                                progress::update_stage(&format!("Updating lake elevations ({} of {})", record_num + 1, num_records), progress);
                                 old_progress = progress;
                             }
                         }
@@ -550,10 +539,11 @@ impl WhiteboxTool for FlattenLakes {
                 }
             }
 
+            cancel.check()?;
             if verbose && num_records > 25 {
                 progress = (100.0_f64 * (record_num as f64) / (num_records - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    progress::update(progress);
                     old_progress = progress;
                 }
             }
@@ -568,22 +558,19 @@ impl WhiteboxTool for FlattenLakes {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
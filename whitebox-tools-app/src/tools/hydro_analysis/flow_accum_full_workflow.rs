@@ -11,6 +11,7 @@ the D8 pointer raster and finally the D8 flow accumulation operation.
 
 use whitebox_raster::*;
 use whitebox_common::structures::Array2D;
+use whitebox_common::progress;
 use crate::tools::*;
 use num_cpus;
 use std::cmp::Ordering;
@@ -23,7 +24,6 @@ use std::io::{Error, ErrorKind};
 use std::path;
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::thread;
 
 /// Resolves all of the depressions in a DEM, outputting a breached DEM, an aspect-aligned non-divergent flow
 /// pointer, and a flow accumulation raster.
@@ -45,7 +45,7 @@ impl FlowAccumulationFullWorkflow {
         let mut parameters = vec![];
         parameters.push(ToolParameter {
             name: "Input DEM File".to_owned(),
-            flags: vec!["-i".to_owned(), "--dem".to_owned()],
+            flags: vec!["-i".to_owned(), "--input".to_owned(), "--dem".to_owned()],
             description: "Input raster DEM file.".to_owned(),
             parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
             default_value: None,
@@ -112,7 +112,7 @@ impl FlowAccumulationFullWorkflow {
 
         parameters.push(ToolParameter {
             name: "Does the pointer file use the ESRI pointer scheme?".to_owned(),
-            flags: vec!["--esri_pntr".to_owned()],
+            flags: vec!["--esri_style".to_owned(), "--esri_pntr".to_owned()],
             description: "D8 pointer uses the ESRI style scheme.".to_owned(),
             parameter_type: ParameterType::Boolean,
             default_value: Some("false".to_owned()),
@@ -177,6 +177,7 @@ impl WhiteboxTool for FlowAccumulationFullWorkflow {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut outdem_file = String::new();
@@ -266,13 +267,7 @@ impl WhiteboxTool for FlowAccumulationFullWorkflow {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -294,7 +289,7 @@ impl WhiteboxTool for FlowAccumulationFullWorkflow {
         }
 
         if verbose {
-            println!("Reading data...")
+            progress::message("Reading data...")
         };
 
         let input = Arc::new(Raster::new(&input_file, "r")?);
@@ -331,7 +326,7 @@ impl WhiteboxTool for FlowAccumulationFullWorkflow {
         for tid in 0..num_procs {
             let input = input.clone();
             let tx = tx.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let dx = [1, 1, 1, 0, -1, -1, -1, 0];
                 let dy = [-1, 0, 1, 1, 1, 0, -1, -1];
                 let mut n: [f64; 8] = [0.0; 8];
@@ -361,20 +356,22 @@ impl WhiteboxTool for FlowAccumulationFullWorkflow {
                             }
                         }
                     }
-                    tx.send((row, data)).unwrap();
+                    if tx.send((row, data)).is_err() {
+                        return;
+                    }
                 }
             });
         }
 
         let mut aspect: Array2D<f64> = Array2D::new(rows, columns, nodata, nodata)?;
         for row in 0..rows {
-            let data = rx.recv().expect("Error receiving data from thread.");
+            let data = cancel.recv(&rx)?;
             aspect.set_row_data(data.0, data.1);
 
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Calculating aspect: {}%", progress);
+                    progress::update_stage("Calculating aspect", progress);
                     old_progress = progress;
                 }
             }
@@ -472,10 +469,11 @@ impl WhiteboxTool for FlowAccumulationFullWorkflow {
                 }
             }
 
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("progress: {}%", progress);
+                    progress::update_stage("progress", progress);
                     old_progress = progress;
                 }
             }
@@ -576,11 +574,12 @@ impl WhiteboxTool for FlowAccumulationFullWorkflow {
                 }
             }
 
+            cancel.check()?;
             if verbose {
                 num_solved_cells += 1;
                 progress = (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    progress::update(progress);
                     old_progress = progress;
                 }
             }
@@ -599,12 +598,12 @@ impl WhiteboxTool for FlowAccumulationFullWorkflow {
         ));
 
         if verbose {
-            println!("Saving DEM data...")
+            progress::message("Saving DEM data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
@@ -619,7 +618,7 @@ impl WhiteboxTool for FlowAccumulationFullWorkflow {
             let input = input.clone();
             let flow_dir = flow_dir.clone();
             let tx = tx.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let dx = [1, 1, 1, 0, -1, -1, -1, 0];
                 let dy = [-1, 0, 1, 1, 1, 0, -1, -1];
                 let inflowing_vals: [i8; 8] = [4, 5, 6, 7, 0, 1, 2, 3];
@@ -641,7 +640,9 @@ impl WhiteboxTool for FlowAccumulationFullWorkflow {
                             data[col as usize] = -1i8;
                         }
                     }
-                    tx.send((row, data)).unwrap();
+                    if tx.send((row, data)).is_err() {
+                        return;
+                    }
                 }
             });
         }
@@ -652,7 +653,7 @@ impl WhiteboxTool for FlowAccumulationFullWorkflow {
         let mut stack = Vec::with_capacity((rows * columns) as usize);
         let mut num_solved_cells = 0;
         for r in 0..rows {
-            let (row, data) = rx.recv().expect("Error receiving data from thread.");
+            let (row, data) = cancel.recv(&rx)?;
             num_inflowing.set_row_data(row, data);
             for col in 0..columns {
                 if num_inflowing[(row, col)] == 0i8 {
@@ -665,7 +666,7 @@ impl WhiteboxTool for FlowAccumulationFullWorkflow {
             if verbose {
                 progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Num. inflowing neighbours: {}%", progress);
+                    progress::update_stage("Num. inflowing neighbours", progress);
                     old_progress = progress;
                 }
             }
@@ -690,11 +691,12 @@ impl WhiteboxTool for FlowAccumulationFullWorkflow {
                 }
             }
 
+            cancel.check()?;
             if verbose {
                 num_solved_cells += 1;
                 progress = (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Flow accumulation: {}%", progress);
+                    progress::update_stage("Flow accumulation", progress);
                     old_progress = progress;
                 }
             }
@@ -743,10 +745,11 @@ impl WhiteboxTool for FlowAccumulationFullWorkflow {
                     }
                 }
 
+                cancel.check()?;
                 if verbose {
                     progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Correcting values: {}%", progress);
+                        progress::update_stage("Correcting values", progress);
                         old_progress = progress;
                     }
                 }
@@ -769,10 +772,11 @@ impl WhiteboxTool for FlowAccumulationFullWorkflow {
                     }
                 }
 
+                cancel.check()?;
                 if verbose {
                     progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Correcting values: {}%", progress);
+                        progress::update_stage("Correcting values", progress);
                         old_progress = progress;
                     }
                 }
@@ -791,12 +795,12 @@ impl WhiteboxTool for FlowAccumulationFullWorkflow {
         pntr.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving flow pointer data...")
+            progress::message("Saving flow pointer data...")
         };
         let _ = match pntr.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&pntr.file_name)
                 }
             }
             Err(e) => return Err(e),
@@ -814,21 +818,18 @@ impl WhiteboxTool for FlowAccumulationFullWorkflow {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving accumulation data...")
+            progress::message("Saving accumulation data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -0,0 +1,1039 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: Dr. John Lindsay
+Created: 18/10/2026
+Last Modified: 18/10/2026
+License: MIT
+*/
+
+use whitebox_common::algorithms::is_clockwise_order;
+use whitebox_common::progress;
+use whitebox_common::structures::{Array2D, Point2D};
+use whitebox_raster::*;
+use whitebox_vector::*;
+use crate::tools::*;
+use std::collections::HashMap;
+use std::env;
+use std::f64;
+use std::fs::File;
+use std::io::prelude::*;
+use std::io::{BufReader, BufWriter, Error, ErrorKind};
+use std::path;
+
+/// This tool can be used to map flood inundation extents and depths from a height above nearest drainage
+/// (HAND) raster, using the approach of Zheng et al. (2018). The HAND raster (`--hand`) is the elevation of
+/// each grid cell above the nearest downslope stream cell, measured along the flowpath, and can be created
+/// using the `ElevationAboveStream` tool. Each grid cell is assigned to the stream reach, or link, that it
+/// drains to using the input D8 pointer raster (`--d8_pntr`) and the stream link identifier raster
+/// (`--links`), which can be created using the `StreamLinkIdentifier` tool. A cell in the catchment of a
+/// reach is considered inundated, for a given water stage *h*, if its HAND value is less than *h*, and the
+/// flood depth is then *h* - HAND.
+///
+/// Water stages are specified for each reach and for one or more flood scenarios in the input table
+/// (`--table`). This is a CSV file in which the first column contains stream link IDs and each of the
+/// following columns contains the values for one scenario; the first row contains the column names, which
+/// are used to name the scenario outputs, e.g.:
+///
+/// > LINK_ID,Q2,Q100
+/// > 1,1.24,3.51
+/// > 2,0.82,2.27
+///
+/// The table values can either be water stages (`--table_type=stage`), in the vertical units of the HAND
+/// raster, or discharges (`--table_type=discharge`), in cubic metres per second. For discharge tables, a
+/// synthetic rating curve (stage-discharge relation) is derived for each reach from its catchment geometry.
+/// For each stage, the flood volume within the reach catchment (*V*) and the bed area of the inundated
+/// surface (*A<sub>b</sub>*) are measured and divided by the reach length (*L*) to give the reach-averaged
+/// cross-sectional area (*A* = *V* / *L*) and wetted perimeter (*P* = *A<sub>b</sub>* / *L*). Discharge
+/// is then estimated with Manning's equation:
+///
+/// > *Q* = (1 / *n*) *A* *R*<sup>2/3</sup> *S*<sup>1/2</sup>
+///
+/// where *R* = *A* / *P* is the hydraulic radius, *S* is the channel slope of the reach, measured from the
+/// DEM (`--dem`), and *n* is Manning's roughness coefficient (`--mannings_n`). The rating curves are
+/// evaluated at stages from `--stage_step` to `--max_stage`, and the stage for a given discharge is linearly
+/// interpolated from the curve. The DEM is required for discharge tables and the synthetic rating curves
+/// can optionally be saved to a CSV file (`--rating_curves`).
+///
+/// For each scenario, the tool outputs a flood depth raster and a vector of inundation polygons, named by
+/// appending the scenario name to the output file name (`--output`), e.g. *flood_Q100.tif* and
+/// *flood_Q100.shp*. Dry cells are assigned zero depth in the depth rasters. The polygons attribute table
+/// contains the stream link ID, water stage, and discharge (discharge tables only) of each inundated area.
+///
+/// # Reference
+/// Zheng, X., Tarboton, D. G., Maidment, D. R., Liu, Y. Y., & Passalacqua, P. (2018). River channel
+/// geometry and rating curve estimation using height above the nearest drainage. JAWRA Journal of the
+/// American Water Resources Association, 54(4), 785-806.
+///
+/// # See Also
+/// `ElevationAboveStream`, `StreamLinkIdentifier`, `D8Pointer`, `DownslopeDistanceToStream`
+pub struct HandFloodInundation {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl HandFloodInundation {
+    pub fn new() -> HandFloodInundation {
+        // public constructor
+        let name = "HandFloodInundation".to_string();
+        let toolbox = "Hydrological Analysis".to_string();
+        let description =
+            "Maps flood depths and inundation extents from a HAND raster and per-reach stage or discharge scenarios."
+                .to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input HAND File".to_owned(),
+            flags: vec!["--hand".to_owned()],
+            description: "Input height above nearest drainage (HAND) raster file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Input Stream Link ID File".to_owned(),
+            flags: vec!["--links".to_owned()],
+            description: "Input stream link identifier raster file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Input D8 Pointer File".to_owned(),
+            flags: vec!["--d8_pntr".to_owned()],
+            description: "Input D8 pointer raster file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Input DEM File".to_owned(),
+            flags: vec!["--dem".to_owned()],
+            description: "Input raster DEM file; required for discharge tables and rating curve outputs."
+                .to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Input Scenario Table".to_owned(),
+            flags: vec!["--table".to_owned()],
+            description: "Input CSV table of per-reach stages or discharges, one column per scenario."
+                .to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Csv),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Table Value Type".to_owned(),
+            flags: vec!["--table_type".to_owned()],
+            description: "Type of values in the scenario table; options include 'stage' and 'discharge'."
+                .to_owned(),
+            parameter_type: ParameterType::OptionList(vec![
+                "stage".to_owned(),
+                "discharge".to_owned(),
+            ]),
+            default_value: Some("stage".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output File".to_owned(),
+            flags: vec!["-o".to_owned(), "--output".to_owned()],
+            description: "Output raster file name; scenario names are appended to it.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output Rating Curves File (optional)".to_owned(),
+            flags: vec!["--rating_curves".to_owned()],
+            description: "Optional output CSV file of the synthetic rating curves.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Csv),
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Manning's Roughness Coefficient".to_owned(),
+            flags: vec!["--mannings_n".to_owned()],
+            description: "Manning's roughness coefficient used in the rating curves.".to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: Some("0.05".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Stage Increment".to_owned(),
+            flags: vec!["--stage_step".to_owned()],
+            description: "Stage increment used to evaluate the rating curves.".to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: Some("0.1".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Maximum Stage".to_owned(),
+            flags: vec!["--max_stage".to_owned()],
+            description: "Maximum stage used to evaluate the rating curves.".to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: Some("20.0".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Does the pointer file use the ESRI pointer scheme?".to_owned(),
+            flags: vec!["--esri_style".to_owned(), "--esri_pntr".to_owned()],
+            description: "D8 pointer uses the ESRI style scheme.".to_owned(),
+            parameter_type: ParameterType::Boolean,
+            default_value: Some("false".to_owned()),
+            optional: true,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut parent = env::current_exe().unwrap();
+        parent.pop();
+        let p = format!("{}", parent.display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(">>.*{0} -r={1} -v --wd=\"*path*to*data*\" --hand=hand.tif --links=links.tif --d8_pntr=pntr.tif --dem=dem.tif --table=flows.csv --table_type=discharge -o=flood.tif --rating_curves=curves.csv --mannings_n=0.05", short_exe, name).replace("*", &sep);
+
+        HandFloodInundation {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for HandFloodInundation {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        match serde_json::to_string(&self.parameters) {
+            Ok(json_str) => return format!("{{\"parameters\":{}}}", json_str),
+            Err(err) => return format!("{:?}", err),
+        }
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+        cancel: &CancellationToken,
+    ) -> Result<(), Error> {
+        let mut hand_file = String::new();
+        let mut links_file = String::new();
+        let mut pntr_file = String::new();
+        let mut dem_file = String::new();
+        let mut table_file = String::new();
+        let mut output_file = String::new();
+        let mut curves_file = String::new();
+        let mut discharge_table = false;
+        let mut mannings_n = 0.05f64;
+        let mut stage_step = 0.1f64;
+        let mut max_stage = 20f64;
+        let mut esri_style = false;
+
+        if args.len() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Tool run with no parameters.",
+            ));
+        }
+        for i in 0..args.len() {
+            let mut arg = args[i].replace("\"", "");
+            arg = arg.replace("\'", "");
+            let cmd = arg.split("="); // in case an equals sign was used
+            let vec = cmd.collect::<Vec<&str>>();
+            let mut keyval = false;
+            if vec.len() > 1 {
+                keyval = true;
+            }
+            let flag_val = vec[0].to_lowercase().replace("--", "-");
+            if flag_val == "-hand" {
+                hand_file = if keyval {
+                    vec[1].to_string()
+                } else {
+                    args[i + 1].to_string()
+                };
+            } else if flag_val == "-links" {
+                links_file = if keyval {
+                    vec[1].to_string()
+                } else {
+                    args[i + 1].to_string()
+                };
+            } else if flag_val == "-d8_pntr" {
+                pntr_file = if keyval {
+                    vec[1].to_string()
+                } else {
+                    args[i + 1].to_string()
+                };
+            } else if flag_val == "-dem" {
+                dem_file = if keyval {
+                    vec[1].to_string()
+                } else {
+                    args[i + 1].to_string()
+                };
+            } else if flag_val == "-table" {
+                table_file = if keyval {
+                    vec[1].to_string()
+                } else {
+                    args[i + 1].to_string()
+                };
+            } else if flag_val == "-table_type" {
+                let val = if keyval {
+                    vec[1].to_lowercase()
+                } else {
+                    args[i + 1].to_lowercase()
+                };
+                discharge_table = val.contains("dis") || val.starts_with("q");
+            } else if flag_val == "-o" || flag_val == "-output" {
+                output_file = if keyval {
+                    vec[1].to_string()
+                } else {
+                    args[i + 1].to_string()
+                };
+            } else if flag_val == "-rating_curves" {
+                curves_file = if keyval {
+                    vec[1].to_string()
+                } else {
+                    args[i + 1].to_string()
+                };
+            } else if flag_val == "-mannings_n" {
+                mannings_n = if keyval {
+                    vec[1]
+                        .to_string()
+                        .parse::<f64>()
+                        .expect(&format!("Error parsing {}", flag_val))
+                } else {
+                    args[i + 1]
+                        .to_string()
+                        .parse::<f64>()
+                        .expect(&format!("Error parsing {}", flag_val))
+                };
+            } else if flag_val == "-stage_step" {
+                stage_step = if keyval {
+                    vec[1]
+                        .to_string()
+                        .parse::<f64>()
+                        .expect(&format!("Error parsing {}", flag_val))
+                } else {
+                    args[i + 1]
+                        .to_string()
+                        .parse::<f64>()
+                        .expect(&format!("Error parsing {}", flag_val))
+                };
+            } else if flag_val == "-max_stage" {
+                max_stage = if keyval {
+                    vec[1]
+                        .to_string()
+                        .parse::<f64>()
+                        .expect(&format!("Error parsing {}", flag_val))
+                } else {
+                    args[i + 1]
+                        .to_string()
+                        .parse::<f64>()
+                        .expect(&format!("Error parsing {}", flag_val))
+                };
+            } else if flag_val == "-esri_pntr" || flag_val == "-esri_style" {
+                if vec.len() == 1 || !vec[1].to_string().to_lowercase().contains("false") {
+                    esri_style = true;
+                }
+            }
+        }
+
+        if verbose {
+            let tool_name = self.get_tool_name();
+            progress::welcome(&tool_name);
+        }
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+
+        let mut progress: usize;
+        let mut old_progress: usize = 1;
+
+        if !hand_file.contains(&sep) && !hand_file.contains("/") {
+            hand_file = format!("{}{}", working_directory, hand_file);
+        }
+        if !links_file.contains(&sep) && !links_file.contains("/") {
+            links_file = format!("{}{}", working_directory, links_file);
+        }
+        if !pntr_file.contains(&sep) && !pntr_file.contains("/") {
+            pntr_file = format!("{}{}", working_directory, pntr_file);
+        }
+        if !dem_file.is_empty() && !dem_file.contains(&sep) && !dem_file.contains("/") {
+            dem_file = format!("{}{}", working_directory, dem_file);
+        }
+        if !table_file.contains(&sep) && !table_file.contains("/") {
+            table_file = format!("{}{}", working_directory, table_file);
+        }
+        if !output_file.contains(&sep) && !output_file.contains("/") {
+            output_file = format!("{}{}", working_directory, output_file);
+        }
+        if !curves_file.is_empty() && !curves_file.contains(&sep) && !curves_file.contains("/") {
+            curves_file = format!("{}{}", working_directory, curves_file);
+        }
+
+        let need_curves = discharge_table || !curves_file.is_empty();
+        if need_curves && dem_file.is_empty() {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "A DEM (--dem) is required to derive rating curves for discharge tables.",
+            ));
+        }
+        if stage_step <= 0f64 || max_stage <= stage_step {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The --stage_step parameter must be positive and less than --max_stage.",
+            ));
+        }
+        if mannings_n <= 0f64 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Manning's roughness coefficient (--mannings_n) must be positive.",
+            ));
+        }
+
+        // Read the scenario table.
+        let (scenario_names, scenario_values) = read_scenario_table(&table_file)?;
+        if scenario_names.len() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The scenario table must contain a link ID column and at least one scenario column.",
+            ));
+        }
+
+        if verbose {
+            progress::message("Reading data...")
+        };
+        let hand = Raster::new(&hand_file, "r")?;
+        let links = Raster::new(&links_file, "r")?;
+        let pntr = Raster::new(&pntr_file, "r")?;
+
+        let start = Instant::now();
+
+        let rows = hand.configs.rows as isize;
+        let columns = hand.configs.columns as isize;
+        let nodata = hand.configs.nodata;
+        let links_nodata = links.configs.nodata;
+        let pntr_nodata = pntr.configs.nodata;
+        let cell_size_x = hand.configs.resolution_x;
+        let cell_size_y = hand.configs.resolution_y;
+        let diag_cell_size = (cell_size_x * cell_size_x + cell_size_y * cell_size_y).sqrt();
+        let cell_area = cell_size_x * cell_size_y;
+
+        // make sure the input files have the same size
+        if hand.configs.rows != links.configs.rows
+            || hand.configs.columns != links.configs.columns
+            || hand.configs.rows != pntr.configs.rows
+            || hand.configs.columns != pntr.configs.columns
+        {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The input files must have the same number of rows and columns and spatial extent.",
+            ));
+        }
+
+        // Create a mapping from the pointer values to cells offsets.
+        let mut pntr_matches: [i8; 129] = [0i8; 129];
+        if !esri_style {
+            // This maps Whitebox-style D8 pointer values
+            // onto the cell offsets in dx and dy.
+            pntr_matches[1] = 0i8;
+            pntr_matches[2] = 1i8;
+            pntr_matches[4] = 2i8;
+            pntr_matches[8] = 3i8;
+            pntr_matches[16] = 4i8;
+            pntr_matches[32] = 5i8;
+            pntr_matches[64] = 6i8;
+            pntr_matches[128] = 7i8;
+        } else {
+            // This maps Esri-style D8 pointer values
+            // onto the cell offsets in dx and dy.
+            pntr_matches[1] = 1i8;
+            pntr_matches[2] = 2i8;
+            pntr_matches[4] = 3i8;
+            pntr_matches[8] = 4i8;
+            pntr_matches[16] = 5i8;
+            pntr_matches[32] = 6i8;
+            pntr_matches[64] = 7i8;
+            pntr_matches[128] = 0i8;
+        }
+        let dx = [1, 1, 1, 0, -1, -1, -1, 0];
+        let dy = [-1, 0, 1, 1, 1, 0, -1, -1];
+        let inflowing_vals = [4i8, 5i8, 6i8, 7i8, 0i8, 1i8, 2i8, 3i8];
+        let grid_lengths = [
+            diag_cell_size,
+            cell_size_x,
+            diag_cell_size,
+            cell_size_y,
+            diag_cell_size,
+            cell_size_x,
+            diag_cell_size,
+            cell_size_y,
+        ];
+
+        // Assign each grid cell to the stream link that it drains to.
+        let mut flow_dir: Array2D<i8> = Array2D::new(rows, columns, -1, -2)?;
+        let mut reach: Array2D<u32> = Array2D::new(rows, columns, 0u32, 0u32)?;
+        let mut stream: Array2D<u8> = Array2D::new(rows, columns, 0u8, 0u8)?;
+        let mut stack = vec![];
+        let mut z: f64;
+        for row in 0..rows {
+            for col in 0..columns {
+                z = pntr.get_value(row, col);
+                if z != pntr_nodata && z > 0f64 && z <= 128f64 {
+                    flow_dir.set_value(row, col, pntr_matches[z as usize]);
+                }
+                z = links.get_value(row, col);
+                if z != links_nodata && z > 0f64 {
+                    reach.set_value(row, col, z as u32);
+                    stream.set_value(row, col, 1u8);
+                    stack.push((row, col));
+                }
+            }
+            cancel.check()?;
+            if verbose {
+                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+                if progress != old_progress {
+                    progress::update_stage("Initializing", progress);
+                    old_progress = progress;
+                }
+            }
+        }
+        drop(pntr);
+        drop(links);
+
+        let (mut row_n, mut col_n): (isize, isize);
+        let mut link_id: u32;
+        while let Some(cell) = stack.pop() {
+            link_id = reach.get_value(cell.0, cell.1);
+            for n in 0..8 {
+                row_n = cell.0 + dy[n];
+                col_n = cell.1 + dx[n];
+                if flow_dir.get_value(row_n, col_n) == inflowing_vals[n]
+                    && reach.get_value(row_n, col_n) == 0u32
+                {
+                    reach.set_value(row_n, col_n, link_id);
+                    stack.push((row_n, col_n));
+                }
+            }
+        }
+
+        // Derive the synthetic rating curves, if they are needed.
+        let num_stages = (max_stage / stage_step).floor() as usize;
+        let mut curves: HashMap<u32, Vec<(f64, f64)>> = HashMap::new();
+        if need_curves {
+            if verbose {
+                progress::message("Reading DEM data...")
+            };
+            let dem = Raster::new(&dem_file, "r")?;
+            if dem.configs.rows != hand.configs.rows || dem.configs.columns != hand.configs.columns {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    "The input files must have the same number of rows and columns and spatial extent.",
+                ));
+            }
+            let dem_nodata = dem.configs.nodata;
+
+            // Reach length and elevation range, measured along the stream cells.
+            let mut reach_length: HashMap<u32, f64> = HashMap::new();
+            let mut reach_elev: HashMap<u32, (f64, f64)> = HashMap::new();
+            // The HAND value and bed area factor of each cell within a reach catchment.
+            let mut reach_cells: HashMap<u32, Vec<(f64, f64)>> = HashMap::new();
+            let (mut zn, mut fx, mut fy, mut h): (f64, f64, f64, f64);
+            let mut dir: i8;
+            for row in 0..rows {
+                for col in 0..columns {
+                    link_id = reach.get_value(row, col);
+                    h = hand.get_value(row, col);
+                    z = dem.get_value(row, col);
+                    if link_id > 0 && h != nodata && z != dem_nodata {
+                        // Bed area factor from the local slope gradient.
+                        zn = dem.get_value(row, col + 1);
+                        let ze = if zn != dem_nodata { zn } else { z };
+                        zn = dem.get_value(row, col - 1);
+                        let zw = if zn != dem_nodata { zn } else { z };
+                        zn = dem.get_value(row - 1, col);
+                        let z_north = if zn != dem_nodata { zn } else { z };
+                        zn = dem.get_value(row + 1, col);
+                        let zs = if zn != dem_nodata { zn } else { z };
+                        fx = (ze - zw) / (2f64 * cell_size_x);
+                        fy = (z_north - zs) / (2f64 * cell_size_y);
+                        reach_cells
+                            .entry(link_id)
+                            .or_insert(vec![])
+                            .push((h, (1f64 + fx * fx + fy * fy).sqrt()));
+                    }
+                }
+                cancel.check()?;
+                if verbose {
+                    progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+                    if progress != old_progress {
+                        progress::update_stage("Measuring reach geometry", progress);
+                        old_progress = progress;
+                    }
+                }
+            }
+
+            // The reach length is the flowpath length through its stream cells.
+            for row in 0..rows {
+                for col in 0..columns {
+                    link_id = reach.get_value(row, col);
+                    if link_id > 0 && stream.get_value(row, col) == 1u8 {
+                        z = dem.get_value(row, col);
+                        if z == dem_nodata {
+                            continue;
+                        }
+                        dir = flow_dir.get_value(row, col);
+                        let len = if dir >= 0 {
+                            grid_lengths[dir as usize]
+                        } else {
+                            (cell_size_x + cell_size_y) / 2f64
+                        };
+                        *reach_length.entry(link_id).or_insert(0f64) += len;
+                        let e = reach_elev.entry(link_id).or_insert((f64::INFINITY, f64::NEG_INFINITY));
+                        if z < e.0 {
+                            e.0 = z;
+                        }
+                        if z > e.1 {
+                            e.1 = z;
+                        }
+                    }
+                }
+            }
+            drop(dem);
+
+            for (id, cells) in reach_cells.iter_mut() {
+                let length = match reach_length.get(id) {
+                    Some(v) => *v,
+                    None => continue,
+                };
+                let (min_z, max_z) = reach_elev[id];
+                let slope = ((max_z - min_z) / length).max(0.0001f64);
+                cells.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+                let mut curve = Vec::with_capacity(num_stages + 1);
+                curve.push((0f64, 0f64));
+                let mut i = 0usize;
+                let mut num_flooded = 0f64;
+                let mut sum_hand = 0f64;
+                let mut sum_bed = 0f64;
+                for s in 1..=num_stages {
+                    let stage = s as f64 * stage_step;
+                    while i < cells.len() && cells[i].0 < stage {
+                        num_flooded += 1f64;
+                        sum_hand += cells[i].0;
+                        sum_bed += cells[i].1;
+                        i += 1;
+                    }
+                    let volume = (num_flooded * stage - sum_hand) * cell_area;
+                    let bed_area = sum_bed * cell_area;
+                    let xs_area = volume / length;
+                    let wetted_perimeter = bed_area / length;
+                    let discharge = if wetted_perimeter > 0f64 {
+                        let hydraulic_radius = xs_area / wetted_perimeter;
+                        xs_area * hydraulic_radius.powf(2f64 / 3f64) * slope.sqrt() / mannings_n
+                    } else {
+                        0f64
+                    };
+                    curve.push((stage, discharge));
+                }
+                curves.insert(*id, curve);
+            }
+
+            if !curves_file.is_empty() {
+                let f = File::create(&curves_file)?;
+                let mut writer = BufWriter::new(f);
+                writer.write_all("LINK_ID,STAGE,DISCHARGE\n".as_bytes())?;
+                let mut ids = curves.keys().copied().collect::<Vec<u32>>();
+                ids.sort();
+                for id in ids {
+                    for (stage, discharge) in &curves[&id] {
+                        writer.write_all(format!("{},{},{}\n", id, stage, discharge).as_bytes())?;
+                    }
+                }
+                let _ = writer.flush();
+            }
+        }
+
+        // Now create the scenario outputs.
+        let output_ext = match path::Path::new(&output_file).extension() {
+            Some(ext) => format!(".{}", ext.to_str().unwrap_or("tif")),
+            None => ".tif".to_string(),
+        };
+        let output_base = output_file.trim_end_matches(&output_ext).to_string();
+        let west = hand.configs.west;
+        let north = hand.configs.north;
+        for s in 0..scenario_names.len() {
+            if verbose {
+                progress::message(format!("Mapping scenario {}...", scenario_names[s]));
+            }
+
+            // Resolve the stage of each reach in this scenario.
+            let mut reach_stage: HashMap<u32, (f64, f64)> = HashMap::new();
+            for (id, values) in &scenario_values {
+                let value = values[s];
+                if value.is_nan() || value <= 0f64 {
+                    continue;
+                }
+                if discharge_table {
+                    match curves.get(id) {
+                        Some(curve) => {
+                            reach_stage.insert(*id, (stage_from_discharge(curve, value), value));
+                        }
+                        None => {
+                            if verbose {
+                                progress::warning(format!("No rating curve could be derived for link {}.", id));
+                            }
+                        }
+                    }
+                } else {
+                    reach_stage.insert(*id, (value, f64::NAN));
+                }
+            }
+
+            let scenario_file = format!("{}_{}{}", output_base, scenario_names[s], output_ext);
+            let mut output = Raster::initialize_using_file(&scenario_file, &hand);
+            output.configs.data_type = DataType::F32;
+            let mut flooded: Array2D<u32> = Array2D::new(rows, columns, 0u32, 0u32)?;
+            let mut h: f64;
+            for row in 0..rows {
+                for col in 0..columns {
+                    h = hand.get_value(row, col);
+                    if h != nodata {
+                        link_id = reach.get_value(row, col);
+                        output.set_value(row, col, 0f64);
+                        if let Some(stage) = reach_stage.get(&link_id) {
+                            if h < stage.0 {
+                                output.set_value(row, col, stage.0 - h);
+                                flooded.set_value(row, col, link_id);
+                            }
+                        }
+                    } else {
+                        output.set_value(row, col, nodata);
+                    }
+                }
+            }
+
+            let elapsed_time = get_formatted_elapsed_time(start);
+            output.configs.palette = "blues.plt".to_string();
+            output.add_metadata_entry(format!(
+                "Created by whitebox_tools\' {} tool",
+                self.get_tool_name()
+            ));
+            output.add_metadata_entry(format!("Input HAND file: {}", hand_file));
+            output.add_metadata_entry(format!("Input scenario table: {}", table_file));
+            output.add_metadata_entry(format!("Scenario: {}", scenario_names[s]));
+            output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
+            let _ = match output.write() {
+                Ok(_) => {
+                    if verbose {
+                        progress::output_written(&scenario_file)
+                    }
+                }
+                Err(e) => return Err(e),
+            };
+            drop(output);
+
+            // Vectorize the inundated areas.
+            let vector_file = format!("{}_{}.shp", output_base, scenario_names[s]);
+            let mut polygons = Shapefile::new(&vector_file, ShapeType::Polygon)?;
+            polygons.projection = hand.configs.coordinate_ref_system_wkt.clone();
+            polygons
+                .attributes
+                .add_field(&AttributeField::new("FID", FieldDataType::Int, 10u8, 0u8));
+            polygons
+                .attributes
+                .add_field(&AttributeField::new("LINK_ID", FieldDataType::Int, 10u8, 0u8));
+            polygons.attributes.add_field(&AttributeField::new(
+                "STAGE",
+                FieldDataType::Real,
+                12u8,
+                4u8,
+            ));
+            if discharge_table {
+                polygons.attributes.add_field(&AttributeField::new(
+                    "DISCHARGE",
+                    FieldDataType::Real,
+                    12u8,
+                    4u8,
+                ));
+            }
+            let regions = trace_regions(&flooded, west, north, cell_size_x, cell_size_y);
+            let mut fid = 1i32;
+            for (id, geometry) in regions {
+                let stage = reach_stage[&id];
+                polygons.add_record(geometry);
+                let mut rec = vec![
+                    FieldData::Int(fid),
+                    FieldData::Int(id as i32),
+                    FieldData::Real(stage.0),
+                ];
+                if discharge_table {
+                    rec.push(FieldData::Real(stage.1));
+                }
+                polygons.attributes.add_record(rec, false);
+                fid += 1;
+            }
+            let _ = match polygons.write() {
+                Ok(_) => {
+                    if verbose {
+                        progress::output_written(&vector_file)
+                    }
+                }
+                Err(e) => return Err(e),
+            };
+        }
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+        if verbose {
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
+        }
+
+        Ok(())
+    }
+}
+
+/// Reads the scenario table, returning the scenario names and the per-link scenario values.
+fn read_scenario_table(file_name: &str) -> Result<(Vec<String>, HashMap<u32, Vec<f64>>), Error> {
+    let f = match File::open(file_name) {
+        Ok(v) => v,
+        Err(_) => {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Error opening the scenario table file.",
+            ));
+        }
+    };
+    let f = BufReader::new(f);
+    let mut names = vec![];
+    let mut values: HashMap<u32, Vec<f64>> = HashMap::new();
+    let mut delimiter = ",";
+    let mut line_num = 0;
+    for line in f.lines() {
+        let line_unwrapped = line?;
+        if line_unwrapped.trim().is_empty() {
+            continue;
+        }
+        let mut line_vec = line_unwrapped.split(delimiter).collect::<Vec<&str>>();
+        if line_num == 0 {
+            if line_vec.len() == 1 {
+                delimiter = ";";
+                line_vec = line_unwrapped.split(delimiter).collect::<Vec<&str>>();
+            }
+            for i in 1..line_vec.len() {
+                // scenario names are used in output file names
+                let name = line_vec[i]
+                    .trim()
+                    .chars()
+                    .map(|c| if c.is_alphanumeric() || c == '-' { c } else { '_' })
+                    .collect::<String>();
+                names.push(name);
+            }
+        } else {
+            let id = match line_vec[0].trim().parse::<f64>() {
+                Ok(v) => v as u32,
+                Err(_) => {
+                    return Err(Error::new(
+                        ErrorKind::InvalidInput,
+                        format!("Error parsing the link ID on line {} of the scenario table.", line_num + 1),
+                    ));
+                }
+            };
+            let mut v = vec![f64::NAN; names.len()];
+            for i in 1..line_vec.len().min(names.len() + 1) {
+                v[i - 1] = line_vec[i].trim().parse::<f64>().unwrap_or(f64::NAN);
+            }
+            values.insert(id, v);
+        }
+        line_num += 1;
+    }
+    Ok((names, values))
+}
+
+/// Linearly interpolates the stage of a discharge from a rating curve.
+fn stage_from_discharge(curve: &[(f64, f64)], discharge: f64) -> f64 {
+    for i in 1..curve.len() {
+        if curve[i].1 >= discharge {
+            let (s0, q0) = curve[i - 1];
+            let (s1, q1) = curve[i];
+            if q1 > q0 {
+                return s0 + (discharge - q0) / (q1 - q0) * (s1 - s0);
+            }
+            return s1;
+        }
+    }
+    // the discharge exceeds the curve; use the maximum stage
+    curve[curve.len() - 1].0
+}
+
+/// Traces the boundaries of the 4-connected regions of equal non-zero value in a grid,
+/// returning one polygon per region along with the region value. Outer rings are
+/// clockwise and holes counter-clockwise.
+fn trace_regions(
+    grid: &Array2D<u32>,
+    west: f64,
+    north: f64,
+    res_x: f64,
+    res_y: f64,
+) -> Vec<(u32, ShapefileGeometry)> {
+    let rows = grid.rows();
+    let columns = grid.columns();
+
+    // Label the regions.
+    let mut regions: Array2D<u32> = Array2D::new(rows, columns, 0u32, 0u32).unwrap();
+    let mut region_values = vec![0u32];
+    let dx = [0, 1, 0, -1];
+    let dy = [-1, 0, 1, 0];
+    let mut stack = vec![];
+    for row in 0..rows {
+        for col in 0..columns {
+            let z = grid.get_value(row, col);
+            if z != 0 && regions.get_value(row, col) == 0 {
+                let region = region_values.len() as u32;
+                region_values.push(z);
+                regions.set_value(row, col, region);
+                stack.push((row, col));
+                while let Some(cell) = stack.pop() {
+                    for n in 0..4 {
+                        let rn = cell.0 + dy[n];
+                        let cn = cell.1 + dx[n];
+                        if grid.get_value(rn, cn) == z && regions.get_value(rn, cn) == 0 {
+                            regions.set_value(rn, cn, region);
+                            stack.push((rn, cn));
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    // Collect the directed boundary edges of each region, keyed on the grid vertex at which
+    // they start, such that the region interior lies to the right of each edge.
+    let num_regions = region_values.len();
+    let mut edges: Vec<HashMap<(isize, isize), Vec<(isize, isize)>>> =
+        vec![HashMap::new(); num_regions];
+    for row in 0..rows {
+        for col in 0..columns {
+            let r = regions.get_value(row, col);
+            if r == 0 {
+                continue;
+            }
+            let e = &mut edges[r as usize];
+            if regions.get_value(row - 1, col) != r {
+                e.entry((col, row)).or_insert(vec![]).push((col + 1, row));
+            }
+            if regions.get_value(row, col + 1) != r {
+                e.entry((col + 1, row)).or_insert(vec![]).push((col + 1, row + 1));
+            }
+            if regions.get_value(row + 1, col) != r {
+                e.entry((col + 1, row + 1)).or_insert(vec![]).push((col, row + 1));
+            }
+            if regions.get_value(row, col - 1) != r {
+                e.entry((col, row + 1)).or_insert(vec![]).push((col, row));
+            }
+        }
+    }
+
+    let mut ret = vec![];
+    for r in 1..num_regions {
+        let mut geometry = ShapefileGeometry::new(ShapeType::Polygon);
+        let mut rings = vec![];
+        let mut starts = edges[r].keys().copied().collect::<Vec<(isize, isize)>>();
+        starts.sort();
+        for start in starts {
+            while edges[r].get(&start).map_or(false, |v| !v.is_empty()) {
+                let mut ring = vec![start];
+                let mut current = start;
+                let mut heading = (0isize, 0isize);
+                loop {
+                    let out = match edges[r].get_mut(&current) {
+                        Some(v) if !v.is_empty() => v,
+                        _ => break,
+                    };
+                    // At pinch vertices, turn right to keep each ring simple.
+                    let mut k = 0;
+                    if out.len() > 1 {
+                        let right = (-heading.1, heading.0);
+                        for j in 0..out.len() {
+                            if (out[j].0 - current.0, out[j].1 - current.1) == right {
+                                k = j;
+                            }
+                        }
+                    }
+                    let next = out.swap_remove(k);
+                    heading = (next.0 - current.0, next.1 - current.1);
+                    current = next;
+                    ring.push(current);
+                    if current == start {
+                        break;
+                    }
+                }
+
+                // Convert to map coordinates, removing collinear vertices.
+                let mut points: Vec<Point2D> = vec![];
+                for i in 0..ring.len() {
+                    if i > 0 && i < ring.len() - 1 {
+                        let (a, b, c) = (ring[i - 1], ring[i], ring[i + 1]);
+                        if (b.0 - a.0) * (c.1 - b.1) == (b.1 - a.1) * (c.0 - b.0) {
+                            continue;
+                        }
+                    }
+                    points.push(Point2D::new(
+                        west + ring[i].0 as f64 * res_x,
+                        north - ring[i].1 as f64 * res_y,
+                    ));
+                }
+                if points.len() > 3 {
+                    rings.push(points);
+                }
+            }
+        }
+        // Shapefiles expect the outer ring first.
+        rings.sort_by_key(|p| !is_clockwise_order(p));
+        for points in &rings {
+            geometry.add_part(points);
+        }
+        if geometry.num_parts > 0 {
+            ret.push((region_values[r], geometry));
+        }
+    }
+    ret
+}
@@ -8,6 +8,7 @@ License: MIT
 
 use whitebox_raster::*;
 use whitebox_common::structures::Array2D;
+use whitebox_common::progress;
 use crate::tools::*;
 use std::env;
 use std::f64;
@@ -81,7 +82,7 @@ impl Hillslopes {
 
         parameters.push(ToolParameter {
             name: "Does the pointer file use the ESRI pointer scheme?".to_owned(),
-            flags: vec!["--esri_pntr".to_owned()],
+            flags: vec!["--esri_style".to_owned(), "--esri_pntr".to_owned()],
             description: "D8 pointer uses the ESRI style scheme.".to_owned(),
             parameter_type: ParameterType::Boolean,
             default_value: Some("false".to_owned()),
@@ -146,6 +147,7 @@ impl WhiteboxTool for Hillslopes {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut d8_file = String::new();
         let mut streams_file = String::new();
@@ -197,13 +199,7 @@ impl WhiteboxTool for Hillslopes {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -222,7 +218,7 @@ impl WhiteboxTool for Hillslopes {
         }
 
         if verbose {
-            println!("Reading data...")
+            progress::message("Reading data...")
         };
 
         let pntr = Raster::new(&d8_file, "r")?;
@@ -290,10 +286,11 @@ impl WhiteboxTool for Hillslopes {
                     num_solved_cells += 1;
                 }
             }
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Processing streams: {}%", progress);
+                    progress::update_stage("Processing streams", progress);
                     old_progress = progress;
                 }
             }
@@ -363,10 +360,11 @@ impl WhiteboxTool for Hillslopes {
                 }
             }
 
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Processing streams: {}%", progress);
+                    progress::update_stage("Processing streams", progress);
                     old_progress = progress;
                 }
             }
@@ -400,10 +398,11 @@ impl WhiteboxTool for Hillslopes {
                     output[(row, col)] = z;
                 }
             }
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Watershedding (Loop 1 of 2): {}%", progress);
+                    progress::update_stage("Watershedding (Loop 1 of 2)", progress);
                     old_progress = progress;
                 }
             }
@@ -460,10 +459,11 @@ impl WhiteboxTool for Hillslopes {
                     }
                 }
             }
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Watershedding (Loop 2 of 2): {}%", progress);
+                    progress::update_stage("Watershedding (Loop 2 of 2)", progress);
                     old_progress = progress;
                 }
             }
@@ -476,10 +476,11 @@ impl WhiteboxTool for Hillslopes {
                     output[(row, col)] = 0f64;
                 }
             }
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Watershedding (Loop 1 of 2): {}%", progress);
+                    progress::update_stage("Watershedding (Loop 1 of 2)", progress);
                     old_progress = progress;
                 }
             }
@@ -539,10 +540,11 @@ impl WhiteboxTool for Hillslopes {
                     }
                 }
             }
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Grouping hillslopes: {}%", progress);
+                    progress::update_stage("Grouping hillslopes", progress);
                     old_progress = progress;
                 }
             }
@@ -558,21 +560,18 @@ impl WhiteboxTool for Hillslopes {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -8,6 +8,7 @@ License: MIT
 
 use whitebox_raster::*;
 use whitebox_common::structures::Array2D;
+use whitebox_common::progress;
 use crate::tools::*;
 use std::cmp::Ordering;
 use std::collections::{BinaryHeap, VecDeque};
@@ -128,7 +129,7 @@ impl ImpoundmentSizeIndex {
         if e.contains(".exe") {
             short_exe += ".exe";
         }
-        let usage = format!(">>.*{0} -r={1} -v --wd=\"*path*to*data*\" --dem=DEM.tif -o=out.tif --out_type='max depth' --damlength=11", short_exe, name).replace("*", &sep);
+        let usage = format!(">>.*{0} -r={1} -v --wd=\"*path*to*data*\" --dem=DEM.tif --out_max=max_depth.tif --damlength=11", short_exe, name).replace("*", &sep);
 
         ImpoundmentSizeIndex {
             name: name,
@@ -180,6 +181,7 @@ impl WhiteboxTool for ImpoundmentSizeIndex {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut out_mean_file = String::new();
@@ -282,13 +284,7 @@ impl WhiteboxTool for ImpoundmentSizeIndex {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -354,7 +350,7 @@ impl WhiteboxTool for ImpoundmentSizeIndex {
         */
 
         if verbose {
-            println!("Reading data...")
+            progress::message("Reading data...")
         };
 
         // let input = Arc::new(Raster::new(&input_file, "r")?);
@@ -492,10 +488,11 @@ impl WhiteboxTool for ImpoundmentSizeIndex {
                     crest_elev.set_value(row, col, nodata);
                 }
             }
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Calculating dam heights: {}%", progress);
+                    progress::update_stage("Calculating dam heights", progress);
                     old_progress = progress;
                 }
             }
@@ -591,10 +588,11 @@ impl WhiteboxTool for ImpoundmentSizeIndex {
                 }
             }
 
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Calculating flow directions: {}%", progress);
+                    progress::update_stage("Calculating flow directions", progress);
                     old_progress = progress;
                 }
             }
@@ -644,11 +642,12 @@ impl WhiteboxTool for ImpoundmentSizeIndex {
                 stack.push((row, col));
             }
 
+            cancel.check()?;
             if verbose {
                 num_solved_cells += 1;
                 progress = (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Calculating flow directions: {}%", progress);
+                    progress::update_stage("Calculating flow directions", progress);
                     old_progress = progress;
                 }
             }
@@ -759,11 +758,12 @@ impl WhiteboxTool for ImpoundmentSizeIndex {
                 }
             }
 
+            cancel.check()?;
             if verbose {
                 num_solved_cells += 1;
                 progress = (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Calculating index: {}%", progress);
+                    progress::update_stage("Calculating index", progress);
                     old_progress = progress;
                 }
             }
@@ -802,10 +802,11 @@ impl WhiteboxTool for ImpoundmentSizeIndex {
                     //     output.set_value(row, col, nodata);
                     }
                 }
+                cancel.check()?;
                 if verbose {
                     progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Outputting dam heights: {}%", progress);
+                        progress::update_stage("Outputting dam heights", progress);
                         old_progress = progress;
                     }
                 }
@@ -823,12 +824,12 @@ impl WhiteboxTool for ImpoundmentSizeIndex {
             output_hgt.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
             if verbose {
-                println!("Saving dam height data...")
+                progress::message("Saving dam height data...")
             };
             let _ = match output_hgt.write() {
                 Ok(_) => {
                     if verbose {
-                        println!("Output file written")
+                        progress::output_written(&output_hgt.file_name)
                     }
                 }
                 Err(e) => return Err(e),
@@ -855,10 +856,11 @@ impl WhiteboxTool for ImpoundmentSizeIndex {
                 }
             }
 
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Calculating mean depths: {}%", progress);
+                    progress::update_stage("Calculating mean depths", progress);
                     old_progress = progress;
                 }
             }
@@ -876,12 +878,12 @@ impl WhiteboxTool for ImpoundmentSizeIndex {
             output.configs.data_type = DataType::F32;
             drop(out_mean);
             if verbose {
-                println!("Saving mean depth data...")
+                progress::message("Saving mean depth data...")
             };
             let _ = match output.write() {
                 Ok(_) => {
                     if verbose {
-                        println!("Output file written")
+                        progress::output_written(&output.file_name)
                     }
                 }
                 Err(e) => return Err(e),
@@ -895,12 +897,12 @@ impl WhiteboxTool for ImpoundmentSizeIndex {
             output.configs.data_type = DataType::F32;
             drop(out_max);
             if verbose {
-                println!("Saving max depth data...")
+                progress::message("Saving max depth data...")
             };
             let _ = match output.write() {
                 Ok(_) => {
                     if verbose {
-                        println!("Output file written")
+                        progress::output_written(&output.file_name)
                     }
                 }
                 Err(e) => return Err(e),
@@ -913,12 +915,12 @@ impl WhiteboxTool for ImpoundmentSizeIndex {
             output.configs.data_type = DataType::F32;
             drop(out_volume);
             if verbose {
-                println!("Saving flooded volume data...")
+                progress::message("Saving flooded volume data...")
             };
             let _ = match output.write() {
                 Ok(_) => {
                     if verbose {
-                        println!("Output file written")
+                        progress::output_written(&output.file_name)
                     }
                 }
                 Err(e) => return Err(e),
@@ -932,12 +934,12 @@ impl WhiteboxTool for ImpoundmentSizeIndex {
             output.configs.data_type = DataType::F32;
             drop(out_area);
             if verbose {
-                println!("Saving flooded area data...")
+                progress::message("Saving flooded area data...")
             };
             let _ = match output.write() {
                 Ok(_) => {
                     if verbose {
-                        println!("Output file written")
+                        progress::output_written(&output.file_name)
                     }
                 }
                 Err(e) => return Err(e),
@@ -979,10 +981,7 @@ impl WhiteboxTool for ImpoundmentSizeIndex {
         
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -7,6 +7,7 @@ License: MIT
 */
 
 use whitebox_raster::*;
+use whitebox_common::progress;
 use crate::tools::*;
 use whitebox_vector::*;
 use std::env;
@@ -145,6 +146,7 @@ impl WhiteboxTool for InsertDams {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut output_file = String::new();
@@ -205,13 +207,7 @@ impl WhiteboxTool for InsertDams {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -227,7 +223,7 @@ impl WhiteboxTool for InsertDams {
         }
 
         if verbose {
-            println!("Reading data...")
+            progress::message("Reading data...")
         };
 
         let input = Raster::new(&input_file, "r").expect("Error reading input DEM file.");
@@ -449,25 +445,24 @@ impl WhiteboxTool for InsertDams {
             } else {
                 // No dam was found that covered the point
                 if verbose {
-                    println!("Warning: No dam could be identified for Point {} due to its position in non-impoundable terrain.", record_num+1);
+                    progress::warning(format!("No dam could be identified for Point {} due to its position in non-impoundable terrain.", record_num+1));
                 }
             }
 
+            cancel.check()?;
             if verbose {
                 progress =
                     (100.0_f64 * record_num as f64 / (dam_pts.num_records - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    progress::update(progress);
                     old_progress = progress;
                 }
             }
         }
 
         if num_points_found == 0 {
-            println!(
-                "No points were dammed. If this is an unexpected result, it may be that the 
-                coordinate reference system (CRS) are not the same for the DEM and points file."
-            )
+            progress::message("No points were dammed. If this is an unexpected result, it may be that the 
+                coordinate reference system (CRS) are not the same for the DEM and points file.")
         }
 
         let elapsed_time = get_formatted_elapsed_time(start);
@@ -480,22 +475,19 @@ impl WhiteboxTool for InsertDams {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
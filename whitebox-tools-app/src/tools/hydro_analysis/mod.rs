@@ -30,6 +30,7 @@ mod flatten_lakes;
 mod flood_order;
 mod flow_accum_full_workflow;
 mod flow_length_diff;
+mod hand_flood_inundation;
 mod hillslopes;
 mod impoundment_index;
 mod insert_dams;
@@ -83,6 +84,7 @@ pub use self::flatten_lakes::FlattenLakes;
 pub use self::flood_order::FloodOrder;
 pub use self::flow_accum_full_workflow::FlowAccumulationFullWorkflow;
 pub use self::flow_length_diff::FlowLengthDiff;
+pub use self::hand_flood_inundation::HandFloodInundation;
 pub use self::hillslopes::Hillslopes;
 pub use self::impoundment_index::ImpoundmentSizeIndex;
 pub use self::insert_dams::InsertDams;
@@ -8,6 +8,7 @@ License: MIT
 
 use whitebox_raster::*;
 use whitebox_common::structures::{Array2D, BoundingBox};
+use whitebox_common::progress;
 use crate::tools::*;
 use whitebox_vector::{ShapeType, Shapefile};
 use std::env;
@@ -146,6 +147,7 @@ impl WhiteboxTool for RaiseWalls {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut breach_file = String::new();
@@ -210,13 +212,7 @@ impl WhiteboxTool for RaiseWalls {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -235,7 +231,7 @@ impl WhiteboxTool for RaiseWalls {
         }
 
         if verbose {
-            println!("Reading data...")
+            progress::message("Reading data...")
         };
         let vector_data = Shapefile::read(&input_file)?;
 
@@ -398,15 +394,11 @@ impl WhiteboxTool for RaiseWalls {
                     }
                 }
             }
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * (record_num + 1) as f64 / num_records as f64) as usize;
                 if progress != old_progress {
-                    println!(
-                        "Rasterizing {} of {}: {}%",
-                        record_num + 1,
-                        num_records,
-                        progress
-                    );
+                    progress::update_stage(&format!("Rasterizing {} of {}", record_num + 1, num_records), progress);
                     old_progress = progress;
                 }
             }
@@ -441,10 +433,11 @@ impl WhiteboxTool for RaiseWalls {
                     }
                 }
             }
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    progress::update(progress);
                     old_progress = progress;
                 }
             }
@@ -587,15 +580,11 @@ impl WhiteboxTool for RaiseWalls {
                         }
                     }
                 }
+                cancel.check()?;
                 if verbose {
                     progress = (100.0_f64 * (record_num + 1) as f64 / num_records as f64) as usize;
                     if progress != old_progress {
-                        println!(
-                            "Rasterizing {} of {}: {}%",
-                            record_num + 1,
-                            num_records,
-                            progress
-                        );
+                        progress::update_stage(&format!("Rasterizing {} of {}", record_num + 1, num_records), progress);
                         old_progress = progress;
                     }
                 }
@@ -611,26 +600,23 @@ impl WhiteboxTool for RaiseWalls {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
 
         if !output_something && verbose {
-            println!("Warning: No polygons were output to the raster. It's possible that that vector and raster inputs are in different projections.");
+            progress::warning("No polygons were output to the raster. It's possible that that vector and raster inputs are in different projections.");
         }
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -11,6 +11,7 @@ This iteration of the tool does include outlets.
 
 use whitebox_raster::*;
 use whitebox_common::structures::Array2D;
+use whitebox_common::progress;
 use crate::tools::*;
 use std::cmp::Ordering;
 use std::cmp::Ordering::Equal;
@@ -22,7 +23,6 @@ use std::io::{Error, ErrorKind};
 use std::path;
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::thread;
 
 /// This tool identifies each sink (i.e. topographic depression) in a raster digital elevation model (DEM). A
 /// sink, or depression, is a bowl-like landscape feature, which is characterized by interior drainage. Each
@@ -136,6 +136,7 @@ impl WhiteboxTool for Sink {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut output_file = String::new();
@@ -178,13 +179,7 @@ impl WhiteboxTool for Sink {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -200,7 +195,7 @@ impl WhiteboxTool for Sink {
         }
 
         if verbose {
-            println!("Reading data...")
+            progress::message("Reading data...")
         };
 
         let input = Raster::new(&input_file, "r")?;
@@ -230,7 +225,7 @@ impl WhiteboxTool for Sink {
         for tid in 0..num_procs {
             let filled_dem2 = filled_dem2.clone();
             let tx = tx.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let mut z: f64;
                 let mut zn: f64;
                 let mut flag: bool;
@@ -255,19 +250,22 @@ impl WhiteboxTool for Sink {
                         }
                     }
                 }
-                tx.send(pits).unwrap();
+                // Release this thread's reference before sending, so that the Arc can be unwrapped
+                // as soon as the last result is received.
+                drop(filled_dem2);
+                let _ = tx.send(pits);
             });
         }
 
         let mut undefined_flow_cells = vec![];
         for p in 0..num_procs {
-            let mut pits = rx.recv().expect("Error receiving data from thread.");
+            let mut pits = cancel.recv(&rx)?;
             undefined_flow_cells.append(&mut pits);
 
             if verbose {
                 progress = (100.0_f64 * (p + 1) as f64 / num_procs as f64) as usize;
                 if progress != old_progress {
-                    println!("Finding pit cells: {}%", progress);
+                    progress::update_stage("Finding pit cells", progress);
                     old_progress = progress;
                 }
             }
@@ -401,10 +399,11 @@ impl WhiteboxTool for Sink {
                 }
             }
 
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * pit_id as f64 / num_deps as f64) as usize;
                 if progress != old_progress {
-                    println!("Finding depressions: {}%", progress);
+                    progress::update_stage("Finding depressions", progress);
                     old_progress = progress;
                 }
             }
@@ -444,11 +443,12 @@ impl WhiteboxTool for Sink {
                 }
                 dep_id += 1f64;
             }
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * (1.0 - possible_outlets.len() as f64 / num_outlets as f64))
                     as usize;
                 if progress != old_progress {
-                    println!("Labelling depressions: {}%", progress);
+                    progress::update_stage("Labelling depressions", progress);
                     old_progress = progress;
                 }
             }
@@ -463,21 +463,18 @@ impl WhiteboxTool for Sink {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -8,9 +8,10 @@ License: MIT
 
 use whitebox_raster::*;
 use whitebox_common::structures::Array2D;
+use whitebox_common::progress;
+use whitebox_common::random;
 use crate::tools::*;
 use rand::prelude::*;
-use rand::rngs::SmallRng;
 use rand_distr::StandardNormal;
 use std::cmp::Ordering;
 use std::collections::{BinaryHeap, VecDeque};
@@ -21,7 +22,6 @@ use std::io::{Error, ErrorKind};
 use std::path;
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::thread;
 
 /// This tool performs a stochastic analysis of depressions within a DEM, calculating the
 /// probability of each cell belonging to a depression. This land-surface parameter
@@ -134,6 +134,8 @@ impl StochasticDepressionAnalysis {
             optional: true,
         });
 
+        parameters.push(seed_parameter());
+
         let sep: String = path::MAIN_SEPARATOR.to_string();
         let e = format!("{}", env::current_exe().unwrap().display());
         let mut parent = env::current_exe().unwrap();
@@ -199,12 +201,14 @@ impl WhiteboxTool for StochasticDepressionAnalysis {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut output_file = String::new();
         let mut rmse = 1f64;
         let mut range = 1f64;
         let mut iterations = 100;
+        let mut seed: Option<u64> = None;
 
         if args.len() == 0 {
             return Err(Error::new(
@@ -258,6 +262,9 @@ impl WhiteboxTool for StochasticDepressionAnalysis {
                         .parse::<f64>()
                         .expect(&format!("Error parsing {}", flag_val))
                 };
+            } else if flag_val == "-seed" {
+                let v = if keyval { vec[1].to_string() } else { args[i + 1].to_string() };
+                seed = Some(parse_seed(&v)?);
             } else if flag_val == "-iterations" {
                 iterations = if keyval {
                     vec[1]
@@ -275,13 +282,7 @@ impl WhiteboxTool for StochasticDepressionAnalysis {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -355,10 +356,7 @@ impl WhiteboxTool for StochasticDepressionAnalysis {
 
         if iterations > i16::max_value() as usize {
             if verbose {
-                println!(
-                    "Warning: Iterations cannot be higher than {}.",
-                    i16::max_value()
-                );
+                progress::warning(format!("Iterations cannot be higher than {}.", i16::max_value()));
             }
             iterations = i16::max_value() as usize;
         }
@@ -413,9 +411,10 @@ impl WhiteboxTool for StochasticDepressionAnalysis {
         let dx = [1, 1, 1, 0, -1, -1, -1, 0];
         let dy = [-1, 0, 1, 1, 1, 0, -1, -1];
 
+        let seed = random::tool_seed(seed);
         for iter_num in 0..iterations {
             if verbose {
-                println!("Iteration {}...", iter_num + 1);
+                progress::message(format!("Iteration {}...", iter_num + 1));
             }
 
             /////////////////////////////
@@ -425,8 +424,10 @@ impl WhiteboxTool for StochasticDepressionAnalysis {
             let (tx, rx) = mpsc::channel();
             for tid in 0..num_procs {
                 let tx = tx.clone();
-                thread::spawn(move || {
-                    let mut rng = SmallRng::from_entropy();
+                progress::spawn(move || {
+                    // Every iteration and thread draws from a different stream.
+                    let mut rng =
+                        random::new_rng(seed, (iter_num as isize * num_procs + tid) as u64);
                     let mut sn_val: f64;
                     for row in (0..rows).filter(|r| r % num_procs == tid) {
                         let mut data = vec![0i32; columns as usize];
@@ -436,7 +437,9 @@ impl WhiteboxTool for StochasticDepressionAnalysis {
                                 (sn_val * multiplier * range_in_cells * 2f64) as i32;
                         }
 
-                        tx.send((row, data)).unwrap();
+                        if tx.send((row, data)).is_err() {
+                            return;
+                        }
                     }
                 });
             }
@@ -444,7 +447,7 @@ impl WhiteboxTool for StochasticDepressionAnalysis {
             let mut error_model: Array2D<i32> =
                 Array2D::new(rows, columns, nodata_i32, nodata_i32).unwrap();
             for _ in 0..rows {
-                let (row, data) = rx.recv().expect("Error receiving data from thread.");
+                let (row, data) = cancel.recv(&rx)?;
                 error_model.set_row_data(row, data);
             }
 
@@ -504,7 +507,7 @@ impl WhiteboxTool for StochasticDepressionAnalysis {
                 for tid in 0..num_procs {
                     let tx = tx.clone();
                     let integral = integral.clone();
-                    thread::spawn(move || {
+                    progress::spawn(move || {
                         let mut z: i32;
                         let mut sum: i32;
                         let (mut x1, mut x2, mut y1, mut y2): (isize, isize, isize, isize);
@@ -548,14 +551,16 @@ impl WhiteboxTool for StochasticDepressionAnalysis {
                                 }
                             }
 
-                            tx.send((row, data, min_value, max_value)).unwrap();
+                            if tx.send((row, data, min_value, max_value)).is_err() {
+                                return;
+                            }
                         }
                     });
                 }
 
                 for _ in 0..rows {
                     let (row, data, val1, val2) =
-                        rx.recv().expect("Error receiving data from thread.");
+                        cancel.recv(&rx)?;
                     error_model.set_row_data(row, data);
                     if val1 < min_value {
                         min_value = val1;
@@ -746,7 +751,7 @@ impl WhiteboxTool for StochasticDepressionAnalysis {
             if verbose {
                 progress = (100.0_f64 * (iter_num + 1) as f64 / iterations as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    progress::update(progress);
                     old_progress = progress;
                 }
             }
@@ -778,21 +783,18 @@ impl WhiteboxTool for StochasticDepressionAnalysis {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
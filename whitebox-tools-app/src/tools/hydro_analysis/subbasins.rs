@@ -8,6 +8,7 @@ License: MIT
 
 use whitebox_raster::*;
 use whitebox_common::structures::Array2D;
+use whitebox_common::progress;
 use crate::tools::*;
 use std::env;
 use std::f64;
@@ -79,7 +80,7 @@ impl Subbasins {
 
         parameters.push(ToolParameter {
             name: "Does the pointer file use the ESRI pointer scheme?".to_owned(),
-            flags: vec!["--esri_pntr".to_owned()],
+            flags: vec!["--esri_style".to_owned(), "--esri_pntr".to_owned()],
             description: "D8 pointer uses the ESRI style scheme.".to_owned(),
             parameter_type: ParameterType::Boolean,
             default_value: Some("false".to_owned()),
@@ -144,6 +145,7 @@ impl WhiteboxTool for Subbasins {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut d8_file = String::new();
         let mut streams_file = String::new();
@@ -195,13 +197,7 @@ impl WhiteboxTool for Subbasins {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -220,7 +216,7 @@ impl WhiteboxTool for Subbasins {
         }
 
         if verbose {
-            println!("Reading data...")
+            progress::message("Reading data...")
         };
 
         let pntr = Raster::new(&d8_file, "r")?;
@@ -286,10 +282,11 @@ impl WhiteboxTool for Subbasins {
                     num_solved_cells += 1;
                 }
             }
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Processing streams: {}%", progress);
+                    progress::update_stage("Processing streams", progress);
                     old_progress = progress;
                 }
             }
@@ -359,10 +356,11 @@ impl WhiteboxTool for Subbasins {
                 }
             }
 
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Processing streams: {}%", progress);
+                    progress::update_stage("Processing streams", progress);
                     old_progress = progress;
                 }
             }
@@ -387,10 +385,11 @@ impl WhiteboxTool for Subbasins {
                     output[(row, col)] = z;
                 }
             }
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Watershedding (Loop 1 of 2): {}%", progress);
+                    progress::update_stage("Watershedding (Loop 1 of 2)", progress);
                     old_progress = progress;
                 }
             }
@@ -447,10 +446,11 @@ impl WhiteboxTool for Subbasins {
                     }
                 }
             }
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Watershedding (Loop 2 of 2): {}%", progress);
+                    progress::update_stage("Watershedding (Loop 2 of 2)", progress);
                     old_progress = progress;
                 }
             }
@@ -466,21 +466,18 @@ impl WhiteboxTool for Subbasins {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -9,6 +9,7 @@ NOTES: Add support for vector seed points.
 */
 
 use whitebox_raster::*;
+use whitebox_common::progress;
 use crate::tools::*;
 use whitebox_vector::{ShapeType, Shapefile};
 use std::env;
@@ -66,7 +67,7 @@ impl TraceDownslopeFlowpaths {
 
         parameters.push(ToolParameter {
             name: "Input D8 Pointer File".to_owned(),
-            flags: vec!["--d8_pntr".to_owned()],
+            flags: vec!["--flow_dir".to_owned(), "--d8_pntr".to_owned()],
             description: "Input D8 pointer raster file.".to_owned(),
             parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
             default_value: None,
@@ -84,7 +85,7 @@ impl TraceDownslopeFlowpaths {
 
         parameters.push(ToolParameter {
             name: "Does the pointer file use the ESRI pointer scheme?".to_owned(),
-            flags: vec!["--esri_pntr".to_owned()],
+            flags: vec!["--esri_style".to_owned(), "--esri_pntr".to_owned()],
             description: "D8 pointer uses the ESRI style scheme.".to_owned(),
             parameter_type: ParameterType::Boolean,
             default_value: Some("false".to_owned()),
@@ -159,6 +160,7 @@ impl WhiteboxTool for TraceDownslopeFlowpaths {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut seed_file = String::new();
         let mut flowdir_file = String::new();
@@ -220,13 +222,7 @@ impl WhiteboxTool for TraceDownslopeFlowpaths {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -248,7 +244,7 @@ impl WhiteboxTool for TraceDownslopeFlowpaths {
         // let seeds = Raster::new(&seed_file, "r")?;
 
         if verbose {
-            println!("Reading flow direction data...")
+            progress::message("Reading flow direction data...")
         };
         let flowdir = Raster::new(&flowdir_file, "r")?;
 
@@ -288,11 +284,12 @@ impl WhiteboxTool for TraceDownslopeFlowpaths {
                 seed_cols.push(flowdir.get_column_from_x(record.points[i].x));
             }
 
+            cancel.check()?;
             if verbose {
                 progress =
                     (100.0_f64 * record_num as f64 / (seeds.num_records - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Locating seed points: {}%", progress);
+                    progress::update_stage("Locating seed points", progress);
                     old_progress = progress;
                 }
             }
@@ -386,10 +383,11 @@ impl WhiteboxTool for TraceDownslopeFlowpaths {
                     }
                 }
             }
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * i as f64 / (seed_cols.len() - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    progress::update(progress);
                     old_progress = progress;
                 }
             }
@@ -401,10 +399,11 @@ impl WhiteboxTool for TraceDownslopeFlowpaths {
                     output.set_value(row, col, nodata);
                 }
             }
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    progress::update(progress);
                     old_progress = progress;
                 }
             }
@@ -426,21 +425,18 @@ impl WhiteboxTool for TraceDownslopeFlowpaths {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
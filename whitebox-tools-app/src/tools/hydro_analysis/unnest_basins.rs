@@ -8,6 +8,7 @@ License: MIT
 
 use whitebox_raster::*;
 use whitebox_common::structures::Array2D;
+use whitebox_common::progress;
 use crate::tools::*;
 use whitebox_vector::*;
 use std::env;
@@ -79,7 +80,7 @@ impl UnnestBasins {
 
         parameters.push(ToolParameter {
             name: "Does the pointer file use the ESRI pointer scheme?".to_owned(),
-            flags: vec!["--esri_pntr".to_owned()],
+            flags: vec!["--esri_style".to_owned(), "--esri_pntr".to_owned()],
             description: "D8 pointer uses the ESRI style scheme.".to_owned(),
             parameter_type: ParameterType::Boolean,
             default_value: Some("false".to_owned()),
@@ -144,6 +145,7 @@ impl WhiteboxTool for UnnestBasins {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut d8_file = String::new();
         let mut pourpts_file = String::new();
@@ -193,13 +195,7 @@ impl WhiteboxTool for UnnestBasins {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -220,7 +216,7 @@ impl WhiteboxTool for UnnestBasins {
         let start = Instant::now();
 
         if verbose {
-            println!("Reading data...")
+            progress::message("Reading data...")
         };
 
         let pntr = Raster::new(&d8_file, "r")?;
@@ -258,10 +254,11 @@ impl WhiteboxTool for UnnestBasins {
             outlet_rows[outlet] = row;
             outlet_columns[outlet] = col;
 
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * outlet as f64 / pourpts.num_records as f64) as usize;
                 if progress != old_progress {
-                    println!("Locating pour points: {}%", progress);
+                    progress::update_stage("Locating pour points", progress);
                     old_progress = progress;
                 }
             }
@@ -308,10 +305,11 @@ impl WhiteboxTool for UnnestBasins {
                     }
                 }
             }
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Initializing: {}%", progress);
+                    progress::update_stage("Initializing", progress);
                     old_progress = progress;
                 }
             }
@@ -355,10 +353,11 @@ impl WhiteboxTool for UnnestBasins {
                     }
                 }
             }
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * outlet as f64 / pourpts.num_records as f64) as usize;
                 if progress != old_progress {
-                    println!("Calculating outlet nesting order: {}%", progress);
+                    progress::update_stage("Calculating outlet nesting order", progress);
                     old_progress = progress;
                 }
             }
@@ -440,13 +439,11 @@ impl WhiteboxTool for UnnestBasins {
                         }
                     }
                 }
+                cancel.check()?;
                 if verbose {
                     progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!(
-                            "Progress (Loop {} of {}): {}%",
-                            order, max_nesting_order, progress
-                        );
+                        progress::update_stage(&format!("Progress (Loop {} of {})", order, max_nesting_order), progress);
                         old_progress = progress;
                     }
                 }
@@ -462,12 +459,12 @@ impl WhiteboxTool for UnnestBasins {
             output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time2));
 
             if verbose {
-                println!("Saving data for nesting order {}...", order)
+                progress::message(format!("Saving data for nesting order {}...", order))
             };
             let _ = match output.write() {
                 Ok(_) => {
                     if verbose {
-                        println!("Output file written")
+                        progress::output_written(&output.file_name)
                     }
                 }
                 Err(e) => return Err(e),
@@ -477,10 +474,7 @@ impl WhiteboxTool for UnnestBasins {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (including I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (including I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -8,6 +8,7 @@ License: MIT
 
 use whitebox_raster::*;
 use whitebox_common::structures::Array2D;
+use whitebox_common::progress;
 use crate::tools::*;
 use num_cpus;
 use std::cmp::Ordering;
@@ -19,7 +20,6 @@ use std::io::{Error, ErrorKind};
 use std::path;
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::thread;
 
 /// This tool estimates the average upslope depression storage depth using the FD8 flow algorithm.
 /// The input DEM (`--dem`) need not be hydrologically corrected; the tool will internally map depression
@@ -49,7 +49,7 @@ impl UpslopeDepressionStorage {
         let mut parameters = vec![];
         parameters.push(ToolParameter {
             name: "Input DEM File".to_owned(),
-            flags: vec!["-i".to_owned(), "--dem".to_owned()],
+            flags: vec!["-i".to_owned(), "--input".to_owned(), "--dem".to_owned()],
             description: "Input raster DEM file.".to_owned(),
             parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
             default_value: None,
@@ -127,6 +127,7 @@ impl WhiteboxTool for UpslopeDepressionStorage {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut output_file = String::new();
@@ -164,13 +165,7 @@ impl WhiteboxTool for UpslopeDepressionStorage {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -186,7 +181,7 @@ impl WhiteboxTool for UpslopeDepressionStorage {
         }
 
         if verbose {
-            println!("Reading data...")
+            progress::message("Reading data...")
         };
 
         let input = Raster::new(&input_file, "r")?;
@@ -259,10 +254,11 @@ impl WhiteboxTool for UpslopeDepressionStorage {
                     num_cells_visited += 1;
                 }
             }
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Finding edges: {}%", progress);
+                    progress::update_stage("Finding edges", progress);
                     old_progress = progress;
                 }
             }
@@ -304,11 +300,12 @@ impl WhiteboxTool for UpslopeDepressionStorage {
                     num_cells_visited += 1;
                 }
             }
+            cancel.check()?;
             if verbose {
                 progress =
                     (100.0_f64 * num_cells_visited as f64 / (rows * columns - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Filling: {}%", progress);
+                    progress::update_stage("Filling", progress);
                     old_progress = progress;
                 }
             }
@@ -439,7 +436,7 @@ impl WhiteboxTool for UpslopeDepressionStorage {
         for tid in 0..num_procs {
             let filled = filled.clone();
             let tx = tx.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let dx = [1, 1, 1, 0, -1, -1, -1, 0];
                 let dy = [-1, 0, 1, 1, 1, 0, -1, -1];
                 let mut z: f32;
@@ -460,7 +457,9 @@ impl WhiteboxTool for UpslopeDepressionStorage {
                             data[col as usize] = count;
                         }
                     }
-                    tx.send((row, data)).unwrap();
+                    if tx.send((row, data)).is_err() {
+                        return;
+                    }
                 }
             });
         }
@@ -468,7 +467,7 @@ impl WhiteboxTool for UpslopeDepressionStorage {
         let mut stack = Vec::with_capacity((rows * columns) as usize);
         num_solved = 0;
         for r in 0..rows {
-            let (row, data) = rx.recv().expect("Error receiving data from thread.");
+            let (row, data) = cancel.recv(&rx)?;
             num_inflowing.set_row_data(row, data);
             for col in 0..columns {
                 if num_inflowing.get_value(row, col) == 0i8 {
@@ -481,7 +480,7 @@ impl WhiteboxTool for UpslopeDepressionStorage {
             if verbose {
                 progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Num. inflowing neighbours: {}%", progress);
+                    progress::update_stage("Num. inflowing neighbours", progress);
                     old_progress = progress;
                 }
             }
@@ -535,11 +534,12 @@ impl WhiteboxTool for UpslopeDepressionStorage {
                 }
             }
 
+            cancel.check()?;
             if verbose {
                 num_solved += 1;
                 progress = (100.0_f64 * num_solved as f64 / (rows * columns - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Flow accumulation: {}%", progress);
+                    progress::update_stage("Flow accumulation", progress);
                     old_progress = progress;
                 }
             }
@@ -556,11 +556,12 @@ impl WhiteboxTool for UpslopeDepressionStorage {
                     );
                 }
             }
+            cancel.check()?;
             if verbose {
                 progress =
                     (100.0_f64 * num_cells_visited as f64 / (rows * columns - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Final calculation: {}%", progress);
+                    progress::update_stage("Final calculation", progress);
                     old_progress = progress;
                 }
             }
@@ -575,21 +576,18 @@ impl WhiteboxTool for UpslopeDepressionStorage {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
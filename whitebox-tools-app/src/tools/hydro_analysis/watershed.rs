@@ -8,6 +8,7 @@ License: MIT
 
 use whitebox_raster::*;
 use whitebox_common::structures::Array2D;
+use whitebox_common::progress;
 use crate::tools::*;
 use whitebox_vector::*;
 use std::env;
@@ -111,7 +112,7 @@ impl Watershed {
 
         parameters.push(ToolParameter {
             name: "Does the pointer file use the ESRI pointer scheme?".to_owned(),
-            flags: vec!["--esri_pntr".to_owned()],
+            flags: vec!["--esri_style".to_owned(), "--esri_pntr".to_owned()],
             description: "D8 pointer uses the ESRI style scheme.".to_owned(),
             parameter_type: ParameterType::Boolean,
             default_value: Some("false".to_owned()),
@@ -176,6 +177,7 @@ impl WhiteboxTool for Watershed {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut d8_file = String::new();
         let mut pourpts_file = String::new();
@@ -225,13 +227,7 @@ impl WhiteboxTool for Watershed {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -250,7 +246,7 @@ impl WhiteboxTool for Watershed {
         }
 
         if verbose {
-            println!("Reading data...")
+            progress::message("Reading data...")
         };
 
         let pntr = Raster::new(&d8_file, "r")?;
@@ -322,11 +318,12 @@ impl WhiteboxTool for Watershed {
                 let col = pntr.get_column_from_x(record.points[0].x);
                 output.set_value(row, col, (record_num + 1) as f64);
 
+                cancel.check()?;
                 if verbose {
                     progress =
                         (100.0_f64 * record_num as f64 / (pourpts.num_records - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Locating pour points: {}%", progress);
+                        progress::update_stage("Locating pour points", progress);
                         old_progress = progress;
                     }
                 }
@@ -345,10 +342,11 @@ impl WhiteboxTool for Watershed {
                         output.set_value(row, col, nodata);
                     }
                 }
+                cancel.check()?;
                 if verbose {
                     progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Initializing: {}%", progress);
+                        progress::update_stage("Initializing", progress);
                         old_progress = progress;
                     }
                 }
@@ -382,10 +380,11 @@ impl WhiteboxTool for Watershed {
                         output.set_value(row, col, z);
                     }
                 }
+                cancel.check()?;
                 if verbose {
                     progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Initializing: {}%", progress);
+                        progress::update_stage("Initializing", progress);
                         old_progress = progress;
                     }
                 }
@@ -445,10 +444,11 @@ impl WhiteboxTool for Watershed {
                     }
                 }
             }
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    progress::update(progress);
                     old_progress = progress;
                 }
             }
@@ -464,21 +464,18 @@ impl WhiteboxTool for Watershed {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
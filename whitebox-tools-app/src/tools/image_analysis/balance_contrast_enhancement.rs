@@ -7,6 +7,7 @@ License: MIT
 */
 
 use whitebox_raster::*;
+use whitebox_common::progress;
 use crate::tools::*;
 use num_cpus;
 use std::env;
@@ -15,7 +16,6 @@ use std::io::{Error, ErrorKind};
 use std::path;
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::thread;
 
 /// This tool can be used to reduce colour bias in a colour composite image based on the
 /// technique described by Liu (1991). Colour bias is a common phenomena with colour images
@@ -136,6 +136,7 @@ impl WhiteboxTool for BalanceContrastEnhancement {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut output_file = String::new();
@@ -185,13 +186,7 @@ impl WhiteboxTool for BalanceContrastEnhancement {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -207,7 +202,7 @@ impl WhiteboxTool for BalanceContrastEnhancement {
         }
 
         if verbose {
-            println!("Reading image data...")
+            progress::message("Reading image data...")
         };
         let input = Arc::new(Raster::new(&input_file, "r")?);
 
@@ -230,7 +225,7 @@ impl WhiteboxTool for BalanceContrastEnhancement {
         for tid in 0..num_procs {
             let input = input.clone();
             let tx = tx.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let mut z: f64;
                 let (mut r, mut g, mut b): (u32, u32, u32);
                 let mut num_pixels = 0f64;
@@ -284,7 +279,7 @@ impl WhiteboxTool for BalanceContrastEnhancement {
                         }
                     }
                 }
-                tx.send((
+                let _ = tx.send((
                     r_l,
                     r_h,
                     r_e,
@@ -298,8 +293,7 @@ impl WhiteboxTool for BalanceContrastEnhancement {
                     b_e,
                     b_sqr_total,
                     num_pixels,
-                ))
-                .unwrap();
+                ));
             });
         }
 
@@ -332,7 +326,7 @@ impl WhiteboxTool for BalanceContrastEnhancement {
                 tb_e,
                 tb_sqr_total,
                 tnum_pixels,
-            ) = rx.recv().expect("Error receiving data from thread.");
+            ) = cancel.recv(&rx)?;
 
             if tr_l < r_l {
                 r_l = tr_l;
@@ -366,7 +360,7 @@ impl WhiteboxTool for BalanceContrastEnhancement {
             if verbose {
                 progress = (100.0_f64 * tid as f64 / (num_procs - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress (Loop 1 of 2): {}%", progress);
+                    progress::update_stage("Progress (Loop 1 of 2)", progress);
                     old_progress = progress;
                 }
             }
@@ -399,7 +393,7 @@ impl WhiteboxTool for BalanceContrastEnhancement {
         for tid in 0..num_procs {
             let input = input.clone();
             let tx = tx.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let mut z: f64;
                 let (mut r, mut g, mut b, mut a): (u32, u32, u32, u32);
                 let (mut r_out, mut g_out, mut b_out): (u32, u32, u32);
@@ -446,7 +440,9 @@ impl WhiteboxTool for BalanceContrastEnhancement {
                                 ((a << 24) | (b_out << 16) | (g_out << 8) | r_out) as f64;
                         }
                     }
-                    tx.send((row, data)).unwrap();
+                    if tx.send((row, data)).is_err() {
+                        return;
+                    }
                 }
             });
         }
@@ -455,12 +451,12 @@ impl WhiteboxTool for BalanceContrastEnhancement {
         output.configs.photometric_interp = PhotometricInterpretation::RGB;
         output.configs.data_type = DataType::RGBA32;
         for row in 0..rows {
-            let data = rx.recv().expect("Error receiving data from thread.");
+            let data = cancel.recv(&rx)?;
             output.set_row_data(data.0, data.1);
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress (Loop 2 of 2): {}%", progress);
+                    progress::update_stage("Progress (Loop 2 of 2)", progress);
                     old_progress = progress;
                 }
             }
@@ -476,21 +472,18 @@ impl WhiteboxTool for BalanceContrastEnhancement {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
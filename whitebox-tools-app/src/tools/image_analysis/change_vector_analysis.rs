@@ -8,6 +8,7 @@ License: MIT
 
 use whitebox_raster::*;
 use whitebox_common::structures::Array2D;
+use whitebox_common::progress;
 use crate::tools::*;
 use std::env;
 use std::io::{Error, ErrorKind};
@@ -155,6 +156,7 @@ impl WhiteboxTool for ChangeVectorAnalysis {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input1_files_str = String::new();
         let mut input2_files_str = String::new();
@@ -206,13 +208,7 @@ impl WhiteboxTool for ChangeVectorAnalysis {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let start = Instant::now();
@@ -295,7 +291,7 @@ impl WhiteboxTool for ChangeVectorAnalysis {
 
         for i in 0..num_files {
             if verbose {
-                println!("Reading file {} of {}", i + 1, num_files);
+                progress::message(format!("Reading file {} of {}", i + 1, num_files));
             }
             if !input1_files[i].trim().is_empty() && !input2_files[i].trim().is_empty() {
                 let mut input1_file = input1_files[i].trim().to_owned();
@@ -342,10 +338,11 @@ impl WhiteboxTool for ChangeVectorAnalysis {
                             nodata_detected.set_value(row, col, 1i8);
                         }
                     }
+                    cancel.check()?;
                     if verbose {
                         progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                         if progress != old_progress {
-                            println!("Progress: {}%", progress);
+                            progress::update(progress);
                             old_progress = progress;
                         }
                     }
@@ -364,17 +361,18 @@ impl WhiteboxTool for ChangeVectorAnalysis {
                     out_direction.set_value(row, col, nodata);
                 }
             }
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    progress::update(progress);
                     old_progress = progress;
                 }
             }
         }
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
 
         let elapsed_time = get_formatted_elapsed_time(start);
@@ -387,7 +385,7 @@ impl WhiteboxTool for ChangeVectorAnalysis {
         let _ = match out_magnitude.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&out_magnitude.file_name)
                 }
             }
             Err(e) => return Err(e),
@@ -404,7 +402,7 @@ impl WhiteboxTool for ChangeVectorAnalysis {
         let _ = match out_direction.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&out_direction.file_name)
                 }
             }
             Err(e) => return Err(e),
@@ -429,13 +427,10 @@ impl WhiteboxTool for ChangeVectorAnalysis {
             s.push_str(&format!("{}\n", line));
         }
 
-        println!("{}", s);
+        progress::message(&s);
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -8,6 +8,7 @@ License: MIT
 
 use whitebox_raster::*;
 use whitebox_common::structures::Array2D;
+use whitebox_common::progress;
 use crate::tools::*;
 use whitebox_vector::{ShapeType, Shapefile};
 use num_cpus;
@@ -18,7 +19,6 @@ use std::io::{Error, ErrorKind};
 use std::path;
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::thread;
 
 /// This tool can be used to reduce vignetting within an image. Vignetting refers to the
 /// reduction of image brightness away from the image centre (i.e. the principal point).
@@ -167,6 +167,7 @@ impl WhiteboxTool for CorrectVignetting {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut pp_file = String::new();
@@ -250,13 +251,7 @@ impl WhiteboxTool for CorrectVignetting {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -275,7 +270,7 @@ impl WhiteboxTool for CorrectVignetting {
         }
 
         if verbose {
-            println!("Reading input data...")
+            progress::message("Reading input data...")
         };
         let input = Arc::new(Raster::new(&input_file, "r")?);
         let rows = input.configs.rows as isize;
@@ -325,7 +320,7 @@ impl WhiteboxTool for CorrectVignetting {
         for tid in 0..num_procs {
             let input = input.clone();
             let tx = tx.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let input_fn: Box<dyn Fn(isize, isize) -> f64> = if !is_rgb_image {
                     Box::new(|row: isize, col: isize| -> f64 { input.get_value(row, col) })
                 } else {
@@ -370,8 +365,9 @@ impl WhiteboxTool for CorrectVignetting {
                             }
                         }
                     }
-                    tx.send((row, data, min_in, max_in, min_out, max_out))
-                        .unwrap();
+                    if tx.send((row, data, min_in, max_in, min_out, max_out)).is_err() {
+                        return;
+                    }
                 }
             });
         }
@@ -382,7 +378,7 @@ impl WhiteboxTool for CorrectVignetting {
         let mut min_out = f64::INFINITY;
         let mut max_out = f64::NEG_INFINITY;
         for r in 0..rows {
-            let (row, data, a, b, c, d) = rx.recv().expect("Error receiving data from thread.");
+            let (row, data, a, b, c, d) = cancel.recv(&rx)?;
             if a < min_in {
                 min_in = a;
             }
@@ -399,7 +395,7 @@ impl WhiteboxTool for CorrectVignetting {
             if verbose {
                 progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress (Loop 1 of 2): {}%", progress);
+                    progress::update_stage("Progress (Loop 1 of 2)", progress);
                     old_progress = progress;
                 }
             }
@@ -413,7 +409,7 @@ impl WhiteboxTool for CorrectVignetting {
             let input = input.clone();
             let unscaled_data = unscaled_data.clone();
             let tx = tx.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let output_fn: Box<dyn Fn(isize, isize, f64) -> f64> = if !is_rgb_image {
                     // simply return the value.
                     Box::new(|_: isize, _: isize, value: f64| -> f64 { value })
@@ -439,7 +435,9 @@ impl WhiteboxTool for CorrectVignetting {
                             data[col as usize] = output_fn(row, col, z_out);
                         }
                     }
-                    tx.send((row, data)).unwrap();
+                    if tx.send((row, data)).is_err() {
+                        return;
+                    }
                 }
             });
         }
@@ -450,12 +448,12 @@ impl WhiteboxTool for CorrectVignetting {
             output.configs.data_type = DataType::RGBA32;
         }
         for r in 0..rows {
-            let (row, data) = rx.recv().expect("Error receiving data from thread.");
+            let (row, data) = cancel.recv(&rx)?;
             output.set_row_data(row, data);
             if verbose {
                 progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress (Loop 2 of 2): {}%", progress);
+                    progress::update_stage("Progress (Loop 2 of 2)", progress);
                     old_progress = progress;
                 }
             }
@@ -474,21 +472,18 @@ impl WhiteboxTool for CorrectVignetting {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -8,6 +8,7 @@ License: MIT
 
 use whitebox_raster::*;
 use whitebox_common::structures::Array2D;
+use whitebox_common::progress;
 use crate::tools::*;
 use num_cpus;
 use std::env;
@@ -16,7 +17,6 @@ use std::io::{Error, ErrorKind};
 use std::path;
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::thread;
 
 /// This tool can be used to create a colour-composite image from three bands of multi-spectral imagery.
 /// The user must specify the names of the input images to enter into the red, green, and blue channels
@@ -181,6 +181,7 @@ impl WhiteboxTool for CreateColourComposite {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input1_file = String::new();
         let mut input2_file = String::new();
@@ -251,13 +252,7 @@ impl WhiteboxTool for CreateColourComposite {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -284,15 +279,15 @@ impl WhiteboxTool for CreateColourComposite {
         }
 
         if verbose {
-            println!("Reading red band data...")
+            progress::message("Reading red band data...")
         };
         let input_r = Arc::new(Raster::new(&input1_file, "r")?);
         if verbose {
-            println!("Reading green band data...")
+            progress::message("Reading green band data...")
         };
         let input_g = Arc::new(Raster::new(&input2_file, "r")?);
         if verbose {
-            println!("Reading blue band data...")
+            progress::message("Reading blue band data...")
         };
         let input_b = Arc::new(Raster::new(&input3_file, "r")?);
 
@@ -317,7 +312,7 @@ impl WhiteboxTool for CreateColourComposite {
         let input_a = match input4_used {
             true => {
                 if verbose {
-                    println!("Reading opacity data...")
+                    progress::message("Reading opacity data...")
                 };
                 let opacity = Raster::new(&input4_file, "r")?;
                 a_min = opacity.configs.display_min;
@@ -371,7 +366,7 @@ impl WhiteboxTool for CreateColourComposite {
             let input_b = input_b.clone();
             let input_a = input_a.clone();
             let tx = tx.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let mut red_val: f64;
                 let mut green_val: f64;
                 let mut blue_val: f64;
@@ -423,7 +418,9 @@ impl WhiteboxTool for CreateColourComposite {
                             data[col as usize] = ((a << 24) | (b << 16) | (g << 8) | r) as f64;
                         }
                     }
-                    tx.send((row, data)).unwrap();
+                    if tx.send((row, data)).is_err() {
+                        return;
+                    }
                 }
             });
         }
@@ -432,12 +429,12 @@ impl WhiteboxTool for CreateColourComposite {
         output.configs.photometric_interp = PhotometricInterpretation::RGB;
         output.configs.data_type = DataType::RGBA32;
         for row in 0..rows {
-            let data = rx.recv().expect("Error receiving data from thread.");
+            let data = cancel.recv(&rx)?;
             output.set_row_data(data.0, data.1);
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    progress::update(progress);
                     old_progress = progress;
                 }
             }
@@ -502,10 +499,11 @@ impl WhiteboxTool for CreateColourComposite {
                         b_sqr_total += (b * b) as f64;
                     }
                 }
+                cancel.check()?;
                 if verbose {
                     progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Performing Enhancement (1 of 2): {}%", progress);
+                        progress::update_stage("Performing Enhancement (1 of 2)", progress);
                         old_progress = progress;
                     }
                 }
@@ -575,10 +573,11 @@ impl WhiteboxTool for CreateColourComposite {
                             ((a << 24) | (b_out << 16) | (g_out << 8) | r_out) as f64
                     }
                 }
+                cancel.check()?;
                 if verbose {
                     progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Performing Enhancement (2 of 2): {}%", progress);
+                        progress::update_stage("Performing Enhancement (2 of 2)", progress);
                         old_progress = progress;
                     }
                 }
@@ -600,21 +599,18 @@ impl WhiteboxTool for CreateColourComposite {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
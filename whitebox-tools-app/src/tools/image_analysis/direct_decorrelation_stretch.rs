@@ -8,6 +8,7 @@ License: MIT
 
 use whitebox_raster::*;
 use whitebox_common::structures::Array2D;
+use whitebox_common::progress;
 use crate::tools::*;
 use num_cpus;
 use std::env;
@@ -16,7 +17,6 @@ use std::io::{Error, ErrorKind};
 use std::path;
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::thread;
 
 /// The Direct Decorrelation Stretch (DDS) is a simple type of saturation stretch. The stretch is
 /// applied to a colour composite image and is used to improve the saturation, or colourfulness,
@@ -86,7 +86,7 @@ impl DirectDecorrelationStretch {
 
         parameters.push(ToolParameter {
             name: "Percent to clip the upper tail".to_owned(),
-            flags: vec!["--clip".to_owned()],
+            flags: vec!["--clip_percent".to_owned(), "--clip".to_owned()],
             description: "Optional percent to clip the upper tail by during the stretch."
                 .to_owned(),
             parameter_type: ParameterType::Float,
@@ -156,6 +156,7 @@ impl WhiteboxTool for DirectDecorrelationStretch {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut output_file = String::new();
@@ -225,13 +226,7 @@ impl WhiteboxTool for DirectDecorrelationStretch {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -254,7 +249,7 @@ impl WhiteboxTool for DirectDecorrelationStretch {
         }
 
         if verbose {
-            println!("Reading image data...")
+            progress::message("Reading image data...")
         };
         let input = Arc::new(Raster::new(&input_file, "r")?);
         // let input = Raster::new(&input_file, "r")?;
@@ -276,7 +271,7 @@ impl WhiteboxTool for DirectDecorrelationStretch {
         for tid in 0..num_procs {
             let input = input.clone();
             let tx = tx.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let mut z: f64;
                 let (mut red, mut green, mut blue): (u32, u32, u32);
                 let (mut r_out, mut g_out, mut b_out): (f64, f64, f64);
@@ -332,7 +327,7 @@ impl WhiteboxTool for DirectDecorrelationStretch {
                             num_cells += 1;
                         }
                     }
-                    tx.send((
+                    if tx.send((
                         row,
                         data_r,
                         histo_red,
@@ -341,8 +336,9 @@ impl WhiteboxTool for DirectDecorrelationStretch {
                         data_b,
                         histo_blue,
                         num_cells,
-                    ))
-                    .unwrap();
+                    )).is_err() {
+                        return;
+                    }
                 }
             });
         }
@@ -355,7 +351,7 @@ impl WhiteboxTool for DirectDecorrelationStretch {
         let mut histo_blue = [0usize; 256];
         let mut num_cells = 0;
         for row in 0..rows {
-            let data = rx.recv().expect("Error receiving data from thread.");
+            let data = cancel.recv(&rx)?;
             red_band.set_row_data(data.0, data.1);
             for i in 0..256 {
                 histo_red[i] += data.2[i];
@@ -375,7 +371,7 @@ impl WhiteboxTool for DirectDecorrelationStretch {
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress (Loop 1 of 2): {}%", progress);
+                    progress::update_stage("Progress (Loop 1 of 2)", progress);
                     old_progress = progress;
                 }
             }
@@ -463,7 +459,7 @@ impl WhiteboxTool for DirectDecorrelationStretch {
             let green_band = green_band.clone();
             let blue_band = blue_band.clone();
             let tx = tx.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let mut z: f64;
                 let (mut red, mut green, mut blue, mut a): (u32, u32, u32, u32);
                 for row in (0..rows).filter(|row_val| row_val % num_procs == tid) {
@@ -490,7 +486,9 @@ impl WhiteboxTool for DirectDecorrelationStretch {
                                 ((a << 24) | (blue << 16) | (green << 8) | red) as f64;
                         }
                     }
-                    tx.send((row, data)).unwrap();
+                    if tx.send((row, data)).is_err() {
+                        return;
+                    }
                 }
             });
         }
@@ -500,12 +498,12 @@ impl WhiteboxTool for DirectDecorrelationStretch {
         output.configs.photometric_interp = PhotometricInterpretation::RGB;
         output.configs.data_type = DataType::RGBA32;
         for row in 0..rows {
-            let data = rx.recv().expect("Error receiving data from thread.");
+            let data = cancel.recv(&rx)?;
             output.set_row_data(data.0, data.1);
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress (Loop 2 of 2): {}%", progress);
+                    progress::update_stage("Progress (Loop 2 of 2)", progress);
                     old_progress = progress;
                 }
             }
@@ -522,12 +520,12 @@ impl WhiteboxTool for DirectDecorrelationStretch {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
@@ -650,10 +648,7 @@ impl WhiteboxTool for DirectDecorrelationStretch {
         // }
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", "")
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", ""));
         }
 
         Ok(())
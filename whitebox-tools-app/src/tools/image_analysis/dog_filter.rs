@@ -7,6 +7,7 @@ License: MIT
 */
 
 use whitebox_raster::*;
+use whitebox_common::progress;
 use crate::tools::*;
 use num_cpus;
 use std::env;
@@ -16,7 +17,6 @@ use std::io::{Error, ErrorKind};
 use std::path;
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::thread;
 
 /// This tool can be used to perform a difference-of-Gaussians (DoG) filter on a raster image. In digital
 /// image processing, DoG is a feature enhancement algorithm that involves the subtraction of one blurred
@@ -154,6 +154,7 @@ impl WhiteboxTool for DiffOfGaussianFilter {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut output_file = String::new();
@@ -218,13 +219,7 @@ impl WhiteboxTool for DiffOfGaussianFilter {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -237,18 +232,18 @@ impl WhiteboxTool for DiffOfGaussianFilter {
         }
 
         if sigma1 < 0.25 {
-            println!("sigma1 cannot be less than 0.25. The value has been modified.");
+            progress::message("sigma1 cannot be less than 0.25. The value has been modified.");
             sigma1 = 0.25;
         } else if sigma1 > 20.0 {
-            println!("sigma1 cannot be greater than 20.0. The value has been modified.");
+            progress::message("sigma1 cannot be greater than 20.0. The value has been modified.");
             sigma1 = 20.0;
         }
 
         if sigma2 < 0.5 {
-            println!("sigma2 cannot be less than 0.5. The value has been modified.");
+            progress::message("sigma2 cannot be less than 0.5. The value has been modified.");
             sigma2 = 0.5;
         } else if sigma2 > 20.0 {
-            println!("sigma2 cannot be greater than 20. The value has been modified.");
+            progress::message("sigma2 cannot be greater than 20. The value has been modified.");
             sigma2 = 20.0;
         }
 
@@ -360,7 +355,7 @@ impl WhiteboxTool for DiffOfGaussianFilter {
         let mut old_progress: usize = 1;
 
         if verbose {
-            println!("Reading data...")
+            progress::message("Reading data...")
         };
 
         let input = Arc::new(Raster::new(&input_file, "r")?);
@@ -406,7 +401,7 @@ impl WhiteboxTool for DiffOfGaussianFilter {
             let d_y2 = d_y2.clone();
             let weights2 = weights2.clone();
             let tx1 = tx.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let input_fn: Box<dyn Fn(isize, isize) -> f64> = if !is_rgb_image {
                     Box::new(|row: isize, col: isize| -> f64 { input.get_value(row, col) })
                 } else {
@@ -458,18 +453,20 @@ impl WhiteboxTool for DiffOfGaussianFilter {
                         }
                     }
 
-                    tx1.send((row, data)).unwrap();
+                    if tx1.send((row, data)).is_err() {
+                        return;
+                    }
                 }
             });
         }
 
         for row in 0..rows {
-            let data = rx.recv().expect("Error receiving data from thread.");
+            let data = cancel.recv(&rx)?;
             output.set_row_data(data.0, data.1);
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    progress::update(progress);
                     old_progress = progress;
                 }
             }
@@ -487,22 +484,19 @@ impl WhiteboxTool for DiffOfGaussianFilter {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
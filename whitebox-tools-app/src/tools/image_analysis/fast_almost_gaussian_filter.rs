@@ -8,6 +8,7 @@ License: MIT
 
 use whitebox_raster::*;
 use whitebox_common::structures::Array2D;
+use whitebox_common::progress;
 use crate::tools::*;
 use std::env;
 use std::f64;
@@ -132,6 +133,7 @@ impl WhiteboxTool for FastAlmostGaussianFilter {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut output_file = String::new();
@@ -181,13 +183,7 @@ impl WhiteboxTool for FastAlmostGaussianFilter {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -203,7 +199,7 @@ impl WhiteboxTool for FastAlmostGaussianFilter {
         let mut old_progress: usize = 1;
 
         if sigma < 1.8 {
-            println!("Warning: Sigma values less than 1.8 cannot be achieved using this filter. Perhaps use the GaussianFilter tool instead.");
+            progress::warning("Sigma values less than 1.8 cannot be achieved using this filter. Perhaps use the GaussianFilter tool instead.");
             sigma = 1.8;
         }
 
@@ -223,7 +219,7 @@ impl WhiteboxTool for FastAlmostGaussianFilter {
             (((m * wl * wl) as f64 + ((n - m) as f64) * (wu * wu) as f64 - n as f64) / 12f64)
                 .sqrt();
         if verbose {
-            println!("Actual sigma: {:.3}", sigma_actual);
+            progress::message(format!("Actual sigma: {:.3}", sigma_actual));
         }
 
         let input = Raster::new(&input_file, "r")?;
@@ -257,7 +253,7 @@ impl WhiteboxTool for FastAlmostGaussianFilter {
 
         for iteration_num in 0..n {
             if verbose {
-                println!("Loop {} of {}", iteration_num + 1, n);
+                progress::message(format!("Loop {} of {}", iteration_num + 1, n));
             }
 
             let midpoint = if iteration_num <= m {
@@ -364,15 +360,11 @@ impl WhiteboxTool for FastAlmostGaussianFilter {
                             }
                         }
                     }
+                    cancel.check()?;
                     if verbose {
                         progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                         if progress != old_progress {
-                            println!(
-                                "Progress (Loop {} of {}): {}%",
-                                iteration_num + 1,
-                                n,
-                                progress
-                            );
+                            progress::update_stage(&format!("Progress (Loop {} of {})", iteration_num + 1, n), progress);
                             old_progress = progress;
                         }
                     }
@@ -432,15 +424,11 @@ impl WhiteboxTool for FastAlmostGaussianFilter {
                             output.set_value(row, col, val);
                         }
                     }
+                    cancel.check()?;
                     if verbose {
                         progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                         if progress != old_progress {
-                            println!(
-                                "Progress (Loop {} of {}): {}%",
-                                iteration_num + 1,
-                                n,
-                                progress
-                            );
+                            progress::update_stage(&format!("Progress (Loop {} of {})", iteration_num + 1, n), progress);
                             old_progress = progress;
                         }
                     }
@@ -458,22 +446,19 @@ impl WhiteboxTool for FastAlmostGaussianFilter {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -7,6 +7,7 @@ License: MIT
 */
 
 use whitebox_raster::*;
+use whitebox_common::progress;
 use crate::tools::*;
 use num_cpus;
 use std::env;
@@ -15,7 +16,6 @@ use std::io::{Error, ErrorKind};
 use std::path;
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::thread;
 
 /// This tool can be used to flip, or reflect, an image (`--input`) either vertically, horizontally, or both. The
 /// axis of reflection is specified using the `--direction` parameter. The input image is not reflected in place;
@@ -56,7 +56,7 @@ impl FlipImage {
 
         parameters.push(ToolParameter{
             name: "Direction".to_owned(), 
-            flags: vec!["--direction".to_owned()], 
+            flags: vec!["--dir".to_owned(), "--direction".to_owned()], 
             description: "Direction of reflection; options include 'v' (vertical), 'h' (horizontal), and 'b' (both).".to_owned(),
             parameter_type: ParameterType::OptionList(vec!["vertical".to_owned(), "horizontal".to_owned(), "both".to_owned()]),
             default_value: Some("vertical".to_owned()),
@@ -125,6 +125,7 @@ impl WhiteboxTool for FlipImage {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut output_file = String::new();
@@ -177,13 +178,7 @@ impl WhiteboxTool for FlipImage {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -218,7 +213,7 @@ impl WhiteboxTool for FlipImage {
             let input = input.clone();
             let direction = direction.clone();
             let tx = tx.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let rows_less_one = rows - 1;
                 let cols_less_one = columns - 1;
                 match &direction as &str {
@@ -228,7 +223,9 @@ impl WhiteboxTool for FlipImage {
                             for col in 0..columns {
                                 data[col as usize] = input[(rows_less_one - row, col)];
                             }
-                            tx.send((row, data)).unwrap();
+                            if tx.send((row, data)).is_err() {
+                                return;
+                            }
                         }
                     }
                     "h" => {
@@ -237,7 +234,9 @@ impl WhiteboxTool for FlipImage {
                             for col in 0..columns {
                                 data[col as usize] = input[(row, cols_less_one - col)];
                             }
-                            tx.send((row, data)).unwrap();
+                            if tx.send((row, data)).is_err() {
+                                return;
+                            }
                         }
                     }
                     _ => {
@@ -248,7 +247,9 @@ impl WhiteboxTool for FlipImage {
                                 data[col as usize] =
                                     input[(rows_less_one - row, cols_less_one - col)];
                             }
-                            tx.send((row, data)).unwrap();
+                            if tx.send((row, data)).is_err() {
+                                return;
+                            }
                         }
                     }
                 }
@@ -256,12 +257,12 @@ impl WhiteboxTool for FlipImage {
         }
 
         for row in 0..rows {
-            let data = rx.recv().expect("Error receiving data from thread.");
+            let data = cancel.recv(&rx)?;
             output.set_row_data(data.0, data.1);
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as i32;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    progress::update(progress);
                     old_progress = progress;
                 }
             }
@@ -277,21 +278,18 @@ impl WhiteboxTool for FlipImage {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -11,6 +11,7 @@ NOTES: 1. The tool should be updated to take multiple file inputs.
 */
 
 use whitebox_raster::*;
+use whitebox_common::progress;
 use crate::tools::*;
 use num_cpus;
 use std::env;
@@ -20,7 +21,6 @@ use std::io::{Error, ErrorKind};
 use std::path;
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::thread;
 
 /// This tool performs a Gaussian stretch on a raster image. The observed histogram of the input image is fitted
 /// to a Gaussian histogram, i.e. normal distribution. A histogram matching technique is used to map the values from
@@ -146,6 +146,7 @@ impl WhiteboxTool for GaussianContrastStretch {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut output_file = String::new();
@@ -196,13 +197,7 @@ impl WhiteboxTool for GaussianContrastStretch {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -218,7 +213,7 @@ impl WhiteboxTool for GaussianContrastStretch {
         }
 
         if num_tones < 16f64 {
-            println!("Warning: The output number of greytones must be at least 16. The value has been modified.");
+            progress::warning("The output number of greytones must be at least 16. The value has been modified.");
             num_tones = 16f64;
         }
 
@@ -226,7 +221,7 @@ impl WhiteboxTool for GaussianContrastStretch {
         let num_tones_less_one = num_tones - 1f64;
 
         if verbose {
-            println!("Reading input data...")
+            progress::message("Reading input data...")
         };
         let input = Arc::new(Raster::new(&input_file, "r")?);
         let rows = input.configs.rows as isize;
@@ -253,7 +248,7 @@ impl WhiteboxTool for GaussianContrastStretch {
 
         // Get the min and max values
         if verbose {
-            println!("Calculating min and max values...")
+            progress::message("Calculating min and max values...")
         };
 
         let (min_val, max_val) = if !is_rgb_image {
@@ -303,10 +298,11 @@ impl WhiteboxTool for GaussianContrastStretch {
                     histogram[bin_num] += 1f64;
                 }
             }
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Loop 1 of 2: {}%", progress);
+                    progress::update_stage("Loop 1 of 2", progress);
                     old_progress = progress;
                 }
             }
@@ -395,7 +391,7 @@ impl WhiteboxTool for GaussianContrastStretch {
             let reference_cdf = reference_cdf.clone();
             let cdf = cdf.clone();
             let tx = tx.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let input_fn: Box<dyn Fn(isize, isize) -> f64> = if !is_rgb_image {
                     Box::new(|row: isize, col: isize| -> f64 { input.get_value(row, col) })
                 } else {
@@ -462,19 +458,21 @@ impl WhiteboxTool for GaussianContrastStretch {
                             data[col as usize] = output_fn(row, col, x_val);
                         }
                     }
-                    tx.send((row, data)).unwrap();
+                    if tx.send((row, data)).is_err() {
+                        return;
+                    }
                 }
             });
         }
 
         let mut output = Raster::initialize_using_file(&output_file, &input);
         for r in 0..rows {
-            let (row, data) = rx.recv().expect("Error receiving data from thread.");
+            let (row, data) = cancel.recv(&rx)?;
             output.set_row_data(row, data);
             if verbose {
                 progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Loop 2 of 2: {}%", progress);
+                    progress::update_stage("Loop 2 of 2", progress);
                     old_progress = progress;
                 }
             }
@@ -490,22 +488,19 @@ impl WhiteboxTool for GaussianContrastStretch {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
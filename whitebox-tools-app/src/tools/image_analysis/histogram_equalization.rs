@@ -11,6 +11,7 @@ NOTES: 1. The tool should be updated to take multiple file inputs.
 */
 
 use whitebox_raster::*;
+use whitebox_common::progress;
 use crate::tools::*;
 use num_cpus;
 use std::env;
@@ -20,7 +21,6 @@ use std::io::{Error, ErrorKind};
 use std::path;
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::thread;
 
 /// This tool alters the cumulative distribution function (CDF) of a raster image to match,
 /// as closely as possible, the CDF of a uniform distribution. Histogram equalization works
@@ -154,6 +154,7 @@ impl WhiteboxTool for HistogramEqualization {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut output_file = String::new();
@@ -204,13 +205,7 @@ impl WhiteboxTool for HistogramEqualization {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -226,13 +221,13 @@ impl WhiteboxTool for HistogramEqualization {
         }
 
         if num_tones < 16f64 {
-            println!("Warning: The output number of greytones must be at least 16. The value has been modified.");
+            progress::warning("The output number of greytones must be at least 16. The value has been modified.");
             num_tones = 16f64;
         }
         let num_tones_less_one = num_tones - 1f64;
 
         if verbose {
-            println!("Reading input data...")
+            progress::message("Reading input data...")
         };
 
         let input = Arc::new(Raster::new(&input_file, "r")?);
@@ -259,7 +254,7 @@ impl WhiteboxTool for HistogramEqualization {
         let start = Instant::now();
 
         if verbose {
-            println!("Calculating clip values...")
+            progress::message("Calculating clip values...")
         };
 
         // create the histogram
@@ -288,10 +283,11 @@ impl WhiteboxTool for HistogramEqualization {
                         histo[bin] += 1f64;
                     }
                 }
+                cancel.check()?;
                 if verbose {
                     progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Calculating histogram: {}%", progress);
+                        progress::update_stage("Calculating histogram", progress);
                         old_progress = progress;
                     }
                 }
@@ -313,10 +309,11 @@ impl WhiteboxTool for HistogramEqualization {
                         histo[bin] += 1f64;
                     }
                 }
+                cancel.check()?;
                 if verbose {
                     progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Calculating histogram: {}%", progress);
+                        progress::update_stage("Calculating histogram", progress);
                         old_progress = progress;
                     }
                 }
@@ -343,7 +340,7 @@ impl WhiteboxTool for HistogramEqualization {
             let input = input.clone();
             let cdf = cdf.clone();
             let tx = tx.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let input_fn: Box<dyn Fn(isize, isize) -> usize> = if !is_rgb_image {
                     Box::new(|row: isize, col: isize| -> usize {
                         let x = input.get_value(row, col);
@@ -387,19 +384,21 @@ impl WhiteboxTool for HistogramEqualization {
                             data[col as usize] = output_fn(row, col, z_out);
                         }
                     }
-                    tx.send((row, data)).unwrap();
+                    if tx.send((row, data)).is_err() {
+                        return;
+                    }
                 }
             });
         }
 
         let mut output = Raster::initialize_using_file(&output_file, &input);
         for r in 0..rows {
-            let (row, data) = rx.recv().expect("Error receiving data from thread.");
+            let (row, data) = cancel.recv(&rx)?;
             output.set_row_data(row, data);
             if verbose {
                 progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    progress::update(progress);
                     old_progress = progress;
                 }
             }
@@ -415,21 +414,18 @@ impl WhiteboxTool for HistogramEqualization {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
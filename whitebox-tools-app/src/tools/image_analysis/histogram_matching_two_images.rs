@@ -7,6 +7,7 @@ License: MIT
 */
 
 use whitebox_raster::*;
+use whitebox_common::progress;
 use crate::tools::*;
 use num_cpus;
 use std::env;
@@ -15,7 +16,6 @@ use std::io::{Error, ErrorKind};
 use std::path;
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::thread;
 
 /// This tool alters the cumulative distribution function (CDF) of a raster image to match, as closely
 /// as possible, the CDF of a reference image. Histogram matching works by first calculating the
@@ -142,6 +142,7 @@ impl WhiteboxTool for HistogramMatchingTwoImages {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file1 = String::new();
         let mut input_file2 = String::new();
@@ -191,13 +192,7 @@ impl WhiteboxTool for HistogramMatchingTwoImages {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -216,7 +211,7 @@ impl WhiteboxTool for HistogramMatchingTwoImages {
         }
 
         if verbose {
-            println!("Reading input data...")
+            progress::message("Reading input data...")
         };
         let input1 = Arc::new(Raster::new(&input_file1, "r")?);
         // let input2 = Arc::new(Raster::new(&input_file2, "r")?);
@@ -265,10 +260,11 @@ impl WhiteboxTool for HistogramMatchingTwoImages {
                     histogram[bin_num] += 1f64;
                 }
             }
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows1 - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Loop 1 of 3: {}%", progress);
+                    progress::update_stage("Loop 1 of 3", progress);
                     old_progress = progress;
                 }
             }
@@ -306,10 +302,11 @@ impl WhiteboxTool for HistogramMatchingTwoImages {
                     histogram2[bin_num] += 1f64;
                 }
             }
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows2 - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Loop 2 of 3: {}%", progress);
+                    progress::update_stage("Loop 2 of 3", progress);
                     old_progress = progress;
                 }
             }
@@ -424,7 +421,7 @@ impl WhiteboxTool for HistogramMatchingTwoImages {
             let reference_cdf = reference_cdf.clone();
             let cdf = cdf.clone();
             let tx = tx.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let mut z: f64;
                 let mut bin_num: usize;
                 let mut j: usize;
@@ -463,19 +460,21 @@ impl WhiteboxTool for HistogramMatchingTwoImages {
                             data[col as usize] = x_val;
                         }
                     }
-                    tx.send((row, data)).unwrap();
+                    if tx.send((row, data)).is_err() {
+                        return;
+                    }
                 }
             });
         }
 
         let mut output = Raster::initialize_using_file(&output_file, &input1);
         for r in 0..rows1 {
-            let (row, data) = rx.recv().expect("Error receiving data from thread.");
+            let (row, data) = cancel.recv(&rx)?;
             output.set_row_data(row, data);
             if verbose {
                 progress = (100.0_f64 * r as f64 / (rows1 - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Loop 3 of 3: {}%", progress);
+                    progress::update_stage("Loop 3 of 3", progress);
                     old_progress = progress;
                 }
             }
@@ -491,21 +490,18 @@ impl WhiteboxTool for HistogramMatchingTwoImages {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
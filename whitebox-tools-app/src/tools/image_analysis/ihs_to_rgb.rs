@@ -7,6 +7,7 @@ License: MIT
 */
 
 use whitebox_raster::*;
+use whitebox_common::progress;
 use crate::tools::*;
 use num_cpus;
 use std::env;
@@ -16,7 +17,6 @@ use std::io::{Error, ErrorKind};
 use std::path;
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::thread;
 
 /// This tool transforms three intensity, hue, and saturation (IHS; sometimes HSI or HIS) raster images into three
 /// equivalent multispectral images corresponding with the red, green, and blue channels of an RGB composite. Intensity
@@ -66,7 +66,7 @@ impl IhsToRgb {
         let mut parameters = vec![];
         parameters.push(ToolParameter {
             name: "Input Intensity File".to_owned(),
-            flags: vec!["--intensity".to_owned()],
+            flags: vec!["-i".to_owned(), "--intensity".to_owned()],
             description: "Input intensity file.".to_owned(),
             parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
             default_value: None,
@@ -84,7 +84,7 @@ impl IhsToRgb {
 
         parameters.push(ToolParameter {
             name: "Input Saturation File".to_owned(),
-            flags: vec!["--saturation".to_owned()],
+            flags: vec!["-s".to_owned(), "--saturation".to_owned()],
             description: "Input saturation file.".to_owned(),
             parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
             default_value: None,
@@ -106,7 +106,7 @@ impl IhsToRgb {
         parameters.push(ToolParameter {
             name: "Output Green Band File (optional; only if colour-composite not specified)"
                 .to_owned(),
-            flags: vec!["--green".to_owned()],
+            flags: vec!["-g".to_owned(), "--green".to_owned()],
             description:
                 "Output green band file. Optionally specified if colour-composite not specified."
                     .to_owned(),
@@ -118,7 +118,7 @@ impl IhsToRgb {
         parameters.push(ToolParameter {
             name: "Output Blue Band File (optional; only if colour-composite not specified)"
                 .to_owned(),
-            flags: vec!["--blue".to_owned()],
+            flags: vec!["-b".to_owned(), "--blue".to_owned()],
             description:
                 "Output blue band file. Optionally specified if colour-composite not specified."
                     .to_owned(),
@@ -130,7 +130,7 @@ impl IhsToRgb {
         parameters.push(ToolParameter {
             name: "Output Colour-Composite File (optional; only if individual bands not specified)"
                 .to_owned(),
-            flags: vec!["-o".to_owned(), "--output".to_owned()],
+            flags: vec!["-o".to_owned(), "--composite".to_owned(), "--output".to_owned()],
             description:
                 "Output colour-composite file. Only used if individual bands are not specified."
                     .to_owned(),
@@ -198,6 +198,7 @@ impl WhiteboxTool for IhsToRgb {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut red_file = String::new();
         let mut green_file = String::new();
@@ -223,7 +224,7 @@ impl WhiteboxTool for IhsToRgb {
                 keyval = true;
             }
             let flag_val = vec[0].to_lowercase().replace("--", "-");
-            if flag_val == "-r" || flag_val == "-red" {
+            if flag_val == "-red" {
                 if keyval {
                     red_file = vec[1].to_string();
                 } else {
@@ -247,7 +248,7 @@ impl WhiteboxTool for IhsToRgb {
                 } else {
                     intensity_file = args[i + 1].to_string();
                 }
-            } else if flag_val == "-h" || flag_val == "-hue" {
+            } else if flag_val == "-hue" {
                 if keyval {
                     hue_file = vec[1].to_string();
                 } else {
@@ -271,13 +272,7 @@ impl WhiteboxTool for IhsToRgb {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -311,15 +306,15 @@ impl WhiteboxTool for IhsToRgb {
         }
 
         if verbose {
-            println!("Reading intensity band data...")
+            progress::message("Reading intensity band data...")
         };
         let input_i = Arc::new(Raster::new(&intensity_file, "r")?);
         if verbose {
-            println!("Reading hue band data...")
+            progress::message("Reading hue band data...")
         };
         let input_h = Arc::new(Raster::new(&hue_file, "r")?);
         if verbose {
-            println!("Reading saturation band data...")
+            progress::message("Reading saturation band data...")
         };
         let input_s = Arc::new(Raster::new(&saturation_file, "r")?);
 
@@ -362,7 +357,7 @@ impl WhiteboxTool for IhsToRgb {
                 let input_h = input_h.clone();
                 let input_s = input_s.clone();
                 let tx = tx.clone();
-                thread::spawn(move || {
+                progress::spawn(move || {
                     // let (mut r, mut g, mut b): (f64, f64, f64);
                     let (mut i, mut h, mut s): (f64, f64, f64);
                     for row in (0..rows).filter(|r| r % num_procs == tid) {
@@ -381,7 +376,9 @@ impl WhiteboxTool for IhsToRgb {
                                 blue_data[col as usize] = b as f64;
                             }
                         }
-                        tx.send((row, red_data, green_data, blue_data)).unwrap();
+                        if tx.send((row, red_data, green_data, blue_data)).is_err() {
+                            return;
+                        }
                     }
                 });
             }
@@ -399,14 +396,14 @@ impl WhiteboxTool for IhsToRgb {
             output_b.configs.data_type = DataType::F32;
 
             for row in 0..rows {
-                let data = rx.recv().expect("Error receiving data from thread.");
+                let data = cancel.recv(&rx)?;
                 output_r.set_row_data(data.0, data.1);
                 output_g.set_row_data(data.0, data.2);
                 output_b.set_row_data(data.0, data.3);
                 if verbose {
                     progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Progress: {}%", progress);
+                        progress::update(progress);
                         old_progress = progress;
                     }
                 }
@@ -425,12 +422,12 @@ impl WhiteboxTool for IhsToRgb {
             output_r.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
             if verbose {
-                println!("Saving red data...")
+                progress::message("Saving red data...")
             };
             let _ = match output_r.write() {
                 Ok(_) => {
                     if verbose {
-                        println!("Output file written")
+                        progress::output_written(&output_r.file_name)
                     }
                 }
                 Err(e) => return Err(e),
@@ -449,12 +446,12 @@ impl WhiteboxTool for IhsToRgb {
             );
 
             if verbose {
-                println!("Saving green data...")
+                progress::message("Saving green data...")
             };
             let _ = match output_g.write() {
                 Ok(_) => {
                     if verbose {
-                        println!("Output file written")
+                        progress::output_written(&output_g.file_name)
                     }
                 }
                 Err(e) => return Err(e),
@@ -473,26 +470,23 @@ impl WhiteboxTool for IhsToRgb {
             );
 
             if verbose {
-                println!("Saving blue data...")
+                progress::message("Saving blue data...")
             };
             let _ = match output_b.write() {
                 Ok(_) => {
                     if verbose {
-                        println!("Output file written")
+                        progress::output_written(&output_b.file_name)
                     }
                 }
                 Err(e) => return Err(e),
             };
 
             if verbose {
-                println!(
-                    "{}",
-                    &format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", "")
-                );
+                progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", ""));
             }
         } else {
             if verbose {
-                println!("Creating a colour composite output...");
+                progress::message("Creating a colour composite output...");
             }
             let (tx, rx) = mpsc::channel();
             for tid in 0..num_procs {
@@ -500,7 +494,7 @@ impl WhiteboxTool for IhsToRgb {
                 let input_h = input_h.clone();
                 let input_s = input_s.clone();
                 let tx = tx.clone();
-                thread::spawn(move || {
+                progress::spawn(move || {
                     let (mut i, mut h, mut s): (f64, f64, f64);
                     let mut value: f64;
                     for row in (0..rows).filter(|r| r % num_procs == tid) {
@@ -514,7 +508,9 @@ impl WhiteboxTool for IhsToRgb {
                                 data[col as usize] = value;
                             }
                         }
-                        tx.send((row, data)).unwrap();
+                        if tx.send((row, data)).is_err() {
+                            return;
+                        }
                     }
                 });
             }
@@ -524,12 +520,12 @@ impl WhiteboxTool for IhsToRgb {
             output.configs.nodata = 0f64;
             output.configs.data_type = DataType::RGBA32;
             for row in 0..rows {
-                let data = rx.recv().expect("Error receiving data from thread.");
+                let data = cancel.recv(&rx)?;
                 output.set_row_data(data.0, data.1);
                 if verbose {
                     progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Progress: {}%", progress);
+                        progress::update(progress);
                         old_progress = progress;
                     }
                 }
@@ -547,21 +543,18 @@ impl WhiteboxTool for IhsToRgb {
             output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
             if verbose {
-                println!("Saving data...")
+                progress::message("Saving data...")
             };
             let _ = match output.write() {
                 Ok(_) => {
                     if verbose {
-                        println!("Output file written")
+                        progress::output_written(&output.file_name)
                     }
                 }
                 Err(e) => return Err(e),
             };
             if verbose {
-                println!(
-                    "{}",
-                    &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-                );
+                progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
             }
         }
 
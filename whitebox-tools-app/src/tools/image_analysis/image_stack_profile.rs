@@ -9,6 +9,7 @@ License: MIT
 use whitebox_raster::*;
 use whitebox_common::rendering::html::*;
 use whitebox_common::rendering::LineGraph;
+use whitebox_common::progress;
 use crate::tools::*;
 use whitebox_vector::{ShapeType, Shapefile};
 use std::env;
@@ -52,7 +53,7 @@ impl ImageStackProfile {
         let mut parameters = vec![];
         parameters.push(ToolParameter {
             name: "Input Files".to_owned(),
-            flags: vec!["-i".to_owned(), "--inputs".to_owned()],
+            flags: vec!["-i".to_owned(), "--input".to_owned(), "--inputs".to_owned()],
             description: "Input multispectral image files.".to_owned(),
             parameter_type: ParameterType::FileList(ParameterFileType::Raster),
             default_value: None,
@@ -144,6 +145,7 @@ impl WhiteboxTool for ImageStackProfile {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_files_str = String::new();
         let mut points_file = String::new();
@@ -188,13 +190,7 @@ impl WhiteboxTool for ImageStackProfile {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -227,7 +223,7 @@ impl WhiteboxTool for ImageStackProfile {
         }
 
         if verbose {
-            println!("Reading points data...")
+            progress::message("Reading points data...")
         };
         let points = Shapefile::read(&points_file)?;
 
@@ -285,10 +281,11 @@ impl WhiteboxTool for ImageStackProfile {
                     }
                 }
             }
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * i as f64 / (num_files - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    progress::update(progress);
                     old_progress = progress;
                 }
             }
@@ -378,10 +375,7 @@ impl WhiteboxTool for ImageStackProfile {
         let _ = writer.flush();
 
         if verbose {
-            println!(
-                "\n{}",
-                &format!("Elapsed Time (including I/O): {}", elapsed_time)
-            );
+            progress::message(format!("\n{}", &format!("Elapsed Time (including I/O): {}", elapsed_time)));
         }
 
         if verbose {
@@ -409,7 +403,7 @@ impl WhiteboxTool for ImageStackProfile {
                 let _ = output.stdout;
             }
 
-            println!("Complete! Please see {} for output.", output_file);
+            progress::message(format!("Complete! Please see {} for output.", output_file));
         }
 
         Ok(())
@@ -9,6 +9,8 @@ License: MIT
 use whitebox_raster::*;
 use whitebox_common::rendering::html::*;
 use whitebox_common::rendering::LineGraph;
+use whitebox_common::progress;
+use whitebox_common::random;
 use crate::tools::*;
 use num_cpus;
 use rand::prelude::*;
@@ -22,7 +24,6 @@ use std::path;
 use std::process::Command;
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::thread;
 
 /// This tool can be used to perform a k-means clustering operation on two or more input
 /// images (`--inputs`), typically several bands of multi-spectral satellite imagery. The
@@ -136,6 +137,8 @@ impl KMeansClustering {
             optional: true,
         });
 
+        parameters.push(seed_parameter());
+
         let sep: String = path::MAIN_SEPARATOR.to_string();
         let e = format!("{}", env::current_exe().unwrap().display());
         let mut parent = env::current_exe().unwrap();
@@ -194,6 +197,7 @@ impl WhiteboxTool for KMeansClustering {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_files_str = String::new();
         let mut output_file = String::new();
@@ -203,6 +207,7 @@ impl WhiteboxTool for KMeansClustering {
         let mut percent_changed_threshold = 5f64;
         let mut initialization_mode = 1;
         let mut min_class_size = 10;
+        let mut seed: Option<u64> = None;
 
         if args.len() == 0 {
             return Err(Error::new(
@@ -284,6 +289,9 @@ impl WhiteboxTool for KMeansClustering {
                         initialization_mode = 1;
                     }
                 }
+            } else if flag_val == "-seed" {
+                let v = if keyval { vec[1].to_string() } else { args[i + 1].to_string() };
+                seed = Some(parse_seed(&v)?);
             } else if flag_val == "-min_class_size" {
                 min_class_size = if keyval {
                     vec[1]
@@ -301,13 +309,7 @@ impl WhiteboxTool for KMeansClustering {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -365,7 +367,7 @@ impl WhiteboxTool for KMeansClustering {
 
         for i in 0..num_files {
             if verbose {
-                println!("Reading file {} of {}", i + 1, num_files);
+                progress::message(format!("Reading file {} of {}", i + 1, num_files));
             }
             if !input_files[i].trim().is_empty() {
                 let mut input_file = input_files[i].trim().to_owned();
@@ -413,10 +415,10 @@ impl WhiteboxTool for KMeansClustering {
         let out_nodata = nodata[0];
         let mut output = Raster::initialize_using_file(&output_file, &input_raster[0]);
         let mut class_centres = vec![vec![0f64; num_files]; num_classes];
+        let mut rng = random::new_rng(random::tool_seed(seed), 0);
 
         if initialization_mode == 0 {
             // initialize the class centres randomly
-            let mut rng = thread_rng();
             for a in 0..num_classes {
                 let row = rng.gen_range(0..rows); // Range::new(0, rows).ind_sample(&mut rng);
                 let col = rng.gen_range(0..columns); // Range::new(0, columns).ind_sample(&mut rng);
@@ -472,7 +474,7 @@ impl WhiteboxTool for KMeansClustering {
                 let centres = centres.clone();
                 let nodata = nodata.clone();
                 let tx = tx.clone();
-                thread::spawn(move || {
+                progress::spawn(move || {
                     for row in (0..rows).filter(|r| r % num_procs == tid) {
                         let mut data = vec![-1isize; columns as usize];
                         let mut is_valid_data: bool;
@@ -519,15 +521,16 @@ impl WhiteboxTool for KMeansClustering {
                                 }
                             }
                         }
-                        tx.send((row, data, class_centre_data, class_min, class_max))
-                            .unwrap();
+                        if tx.send((row, data, class_centre_data, class_min, class_max)).is_err() {
+                            return;
+                        }
                     }
                 });
             }
 
             for r in 0..rows {
                 let (row, data, ccd, cmin, cmax) =
-                    rx.recv().expect("Error receiving data from thread.");
+                    cancel.recv(&rx)?;
                 for col in 0..columns {
                     if data[col as usize] >= 0 {
                         if !n_counted {
@@ -560,12 +563,7 @@ impl WhiteboxTool for KMeansClustering {
                 if verbose {
                     progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!(
-                            "Progress (loop {} of {}): {}%",
-                            loop_num + 1,
-                            max_iterations,
-                            progress
-                        );
+                        progress::update_stage(&format!("Progress (loop {} of {})", loop_num + 1, max_iterations), progress);
                         old_progress = progress;
                     }
                 }
@@ -632,7 +630,6 @@ impl WhiteboxTool for KMeansClustering {
                     // re-initialize the class centre randomly within the space of
                     // a class that has more than min_class_size cells
                     let mut class_min_size = vec![min_class_size * 2; num_classes];
-                    let mut rng = thread_rng();
                     // let between = Range::new(0, num_classes);
                     let mut large_class = 0;
                     let chances = num_classes * 10;
@@ -658,16 +655,13 @@ impl WhiteboxTool for KMeansClustering {
             }
 
             if verbose {
-                println!("Cluster sizes: {:?}", class_n);
+                progress::message(format!("Cluster sizes: {:?}", class_n));
             }
 
             percent_changed = 100f64 * cells_changed / n;
             ydata[0][loop_num] = percent_changed;
             if verbose {
-                println!(
-                    "Cells changed {} ({:.4} percent)",
-                    cells_changed, percent_changed
-                );
+                progress::message(format!("Cells changed {} ({:.4} percent)", cells_changed, percent_changed));
             }
             if percent_changed < percent_changed_threshold {
                 break;
@@ -695,21 +689,18 @@ impl WhiteboxTool for KMeansClustering {
         output.add_metadata_entry(format!("Elapsed Time (including I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (including I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (including I/O): {}", elapsed_time));
         }
 
         if !output_html_file.trim().is_empty() {
@@ -912,7 +903,7 @@ impl WhiteboxTool for KMeansClustering {
                     let _ = output.stdout;
                 }
 
-                println!("Complete! Please see {} for output.", output_html_file);
+                progress::message(format!("Complete! Please see {} for output.", output_html_file));
             }
         }
 
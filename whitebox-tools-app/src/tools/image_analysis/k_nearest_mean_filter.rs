@@ -7,6 +7,7 @@ License: MIT
 */
 
 use whitebox_raster::*;
+use whitebox_common::progress;
 use crate::tools::*;
 use num_cpus;
 use std::cmp::Ordering::Less;
@@ -17,7 +18,6 @@ use std::io::{Error, ErrorKind};
 use std::path;
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::thread;
 
 /// This tool performs a k-nearest mean filter on a raster image. A mean filter can be used to emphasize the
 /// longer-range variability in an image, effectively acting to smooth or blur the image. This can be useful
@@ -161,6 +161,7 @@ impl WhiteboxTool for KNearestMeanFilter {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut output_file = String::new();
@@ -257,13 +258,7 @@ impl WhiteboxTool for KNearestMeanFilter {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -285,7 +280,7 @@ impl WhiteboxTool for KNearestMeanFilter {
 
         k += 1; // Let's assume that we want k neighbours, or k + 1 including the centre cell itself.
         if k > filter_size_x * filter_size_y {
-            println!("The value of k cannot be greater than the size of the filter (i.e. filterx * filtery). The value has been changed.");
+            progress::message("The value of k cannot be greater than the size of the filter (i.e. filterx * filtery). The value has been changed.");
             k = filter_size_x * filter_size_y;
         }
 
@@ -302,7 +297,7 @@ impl WhiteboxTool for KNearestMeanFilter {
         }
 
         if verbose {
-            println!("Reading data...")
+            progress::message("Reading data...")
         };
 
         let input = Arc::new(Raster::new(&input_file, "r")?);
@@ -332,7 +327,7 @@ impl WhiteboxTool for KNearestMeanFilter {
         for tid in 0..num_procs {
             let input = input.clone();
             let tx = tx.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let input_fn: Box<dyn Fn(isize, isize) -> f64> = if !is_rgb_image {
                     Box::new(|row: isize, col: isize| -> f64 { input.get_value(row, col) })
                 } else {
@@ -407,7 +402,9 @@ impl WhiteboxTool for KNearestMeanFilter {
                         }
                     }
 
-                    tx.send((row, data)).unwrap();
+                    if tx.send((row, data)).is_err() {
+                        return;
+                    }
                 }
             });
         }
@@ -415,12 +412,12 @@ impl WhiteboxTool for KNearestMeanFilter {
         let mut output = Raster::initialize_using_file(&output_file, &input);
         output.configs.palette = input.configs.palette.clone();
         for row in 0..rows {
-            let data = rx.recv().expect("Error receiving data from thread.");
+            let data = cancel.recv(&rx)?;
             output.set_row_data(data.0, data.1);
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    progress::update(progress);
                     old_progress = progress;
                 }
             }
@@ -438,22 +435,19 @@ impl WhiteboxTool for KNearestMeanFilter {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written");
+                    progress::output_written(&output.file_name);
                 }
             }
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -7,6 +7,7 @@ License: MIT
 */
 
 use whitebox_raster::*;
+use whitebox_common::progress;
 use crate::tools::*;
 use num_cpus;
 use std::env;
@@ -15,7 +16,6 @@ use std::io::{Error, ErrorKind};
 use std::path;
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::thread;
 
 /// This tool can be used to perform one of four 3x3 line-detection filters on a raster image. These
 /// filters can be used to find one-cell-thick vertical, horizontal, or angled (135-degrees or
@@ -182,6 +182,7 @@ impl WhiteboxTool for LineDetectionFilter {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         if args.len() == 0 {
             return Err(Error::new(
@@ -256,13 +257,7 @@ impl WhiteboxTool for LineDetectionFilter {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -278,7 +273,7 @@ impl WhiteboxTool for LineDetectionFilter {
         let mut old_progress: usize = 1;
 
         if verbose {
-            println!("Reading data...")
+            progress::message("Reading data...")
         };
 
         let input = Arc::new(Raster::new(&input_file, "r")?);
@@ -302,7 +297,7 @@ impl WhiteboxTool for LineDetectionFilter {
             let input = input.clone();
             let variant = variant.clone();
             let tx1 = tx.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let mut sum: f64;
                 let mut z: f64;
                 let mut zn: f64;
@@ -335,7 +330,9 @@ impl WhiteboxTool for LineDetectionFilter {
                                 data[col as usize] = sum;
                             }
                         }
-                        tx1.send((row, data)).unwrap();
+                        if tx1.send((row, data)).is_err() {
+                            return;
+                        }
                     }
                 } else {
                     for row in (0..rows).filter(|r| r % num_procs == tid) {
@@ -354,26 +351,28 @@ impl WhiteboxTool for LineDetectionFilter {
                                 data[col as usize] = sum.abs();
                             }
                         }
-                        tx1.send((row, data)).unwrap();
+                        if tx1.send((row, data)).is_err() {
+                            return;
+                        }
                     }
                 }
             });
         }
 
         for row in 0..rows {
-            let data = rx.recv().expect("Error receiving data from thread.");
+            let data = cancel.recv(&rx)?;
             output.set_row_data(data.0, data.1);
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    progress::update(progress);
                     old_progress = progress;
                 }
             }
         }
 
         if clip_amount > 0.0 {
-            println!("Clipping output...");
+            progress::message("Clipping output...");
             output.clip_min_and_max_by_percent(clip_amount);
         }
 
@@ -390,22 +389,19 @@ impl WhiteboxTool for LineDetectionFilter {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
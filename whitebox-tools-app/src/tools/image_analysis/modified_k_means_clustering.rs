@@ -9,6 +9,8 @@ License: MIT
 use whitebox_raster::*;
 use whitebox_common::rendering::html::*;
 use whitebox_common::rendering::LineGraph;
+use whitebox_common::progress;
+use whitebox_common::random;
 use crate::tools::*;
 use num_cpus;
 use rand::prelude::*;
@@ -22,7 +24,6 @@ use std::path;
 use std::process::Command;
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::thread;
 
 /// This modified k-means algorithm is similar to that described by Mather and Koch (2011).
 /// The main difference between the traditional k-means and this technique is that the user
@@ -58,7 +59,7 @@ impl ModifiedKMeansClustering {
         let mut parameters = vec![];
         parameters.push(ToolParameter {
             name: "Input Files".to_owned(),
-            flags: vec!["-i".to_owned(), "--inputs".to_owned()],
+            flags: vec!["-i".to_owned(), "--input".to_owned(), "--inputs".to_owned()],
             description: "Input raster files.".to_owned(),
             parameter_type: ParameterType::FileList(ParameterFileType::Raster),
             default_value: None,
@@ -94,7 +95,7 @@ impl ModifiedKMeansClustering {
 
         parameters.push(ToolParameter {
             name: "Cluster Merger Distance".to_owned(),
-            flags: vec!["--merge_dist".to_owned()],
+            flags: vec!["--merger_dist".to_owned(), "--merge_dist".to_owned()],
             description: "Cluster merger distance".to_owned(),
             parameter_type: ParameterType::Float,
             default_value: None,
@@ -138,6 +139,8 @@ impl ModifiedKMeansClustering {
         //     optional: true
         // });
 
+        parameters.push(seed_parameter());
+
         let sep: String = path::MAIN_SEPARATOR.to_string();
         let e = format!("{}", env::current_exe().unwrap().display());
         let mut parent = env::current_exe().unwrap();
@@ -196,6 +199,7 @@ impl WhiteboxTool for ModifiedKMeansClustering {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_files_str = String::new();
         let mut output_file = String::new();
@@ -206,6 +210,7 @@ impl WhiteboxTool for ModifiedKMeansClustering {
         // let mut initialization_mode = 1;
         // let mut min_class_size = 10;
         let mut merger_dist = 1f64;
+        let mut seed: Option<u64> = None;
 
         if args.len() == 0 {
             return Err(Error::new(
@@ -268,6 +273,9 @@ impl WhiteboxTool for ModifiedKMeansClustering {
                         .expect(&format!("Error parsing {}", flag_val));
                 }
                 merger_dist *= merger_dist;
+            } else if flag_val == "-seed" {
+                let v = if keyval { vec[1].to_string() } else { args[i + 1].to_string() };
+                seed = Some(parse_seed(&v)?);
             } else if flag_val == "-max_iterations" {
                 if keyval {
                     max_iterations = vec[1]
@@ -315,13 +323,7 @@ impl WhiteboxTool for ModifiedKMeansClustering {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -385,7 +387,7 @@ impl WhiteboxTool for ModifiedKMeansClustering {
         let mut input_raster: Vec<Raster> = Vec::with_capacity(num_files);
 
         for i in 0..num_files {
-            println!("Reading file {} of {}", i + 1, num_files);
+            progress::message(format!("Reading file {} of {}", i + 1, num_files));
             if !input_files[i].trim().is_empty() {
                 let mut input_file = input_files[i].trim().to_owned();
                 if !input_file.contains(&sep) && !input_file.contains("/") {
@@ -433,7 +435,7 @@ impl WhiteboxTool for ModifiedKMeansClustering {
 
         // if initialization_mode == 0 {
         // initialize the class centres randomly
-        let mut rng = thread_rng();
+        let mut rng = random::new_rng(random::tool_seed(seed), 0);
         for a in 0..num_classes {
             let row = rng.gen_range(0..rows); // Range::new(0, rows).ind_sample(&mut rng);
             let col = rng.gen_range(0..columns); // Range::new(0, columns).ind_sample(&mut rng);
@@ -487,7 +489,7 @@ impl WhiteboxTool for ModifiedKMeansClustering {
                 let centres = centres.clone();
                 let nodata = nodata.clone();
                 let tx = tx.clone();
-                thread::spawn(move || {
+                progress::spawn(move || {
                     for row in (0..rows).filter(|r| r % num_procs == tid) {
                         let mut data = vec![-1isize; columns as usize];
                         let mut is_valid_data: bool;
@@ -526,13 +528,15 @@ impl WhiteboxTool for ModifiedKMeansClustering {
                                 }
                             }
                         }
-                        tx.send((row, data, class_centre_data)).unwrap();
+                        if tx.send((row, data, class_centre_data)).is_err() {
+                            return;
+                        }
                     }
                 });
             }
 
             for r in 0..rows {
-                let (row, data, ccd) = rx.recv().expect("Error receiving data from thread.");
+                let (row, data, ccd) = cancel.recv(&rx)?;
                 for col in 0..columns {
                     if data[col as usize] >= 0 {
                         if !n_counted {
@@ -559,12 +563,7 @@ impl WhiteboxTool for ModifiedKMeansClustering {
                 if verbose {
                     progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!(
-                            "Progress (loop {} of {}): {}%",
-                            loop_num + 1,
-                            max_iterations,
-                            progress
-                        );
+                        progress::update_stage(&format!("Progress (loop {} of {})", loop_num + 1, max_iterations), progress);
                         old_progress = progress;
                     }
                 }
@@ -623,14 +622,11 @@ impl WhiteboxTool for ModifiedKMeansClustering {
 
             num_classes = class_n.len();
 
-            println!("Number of clusters: {}", num_classes);
+            progress::message(format!("Number of clusters: {}", num_classes));
 
             percent_changed = 100f64 * cells_changed / n;
             ydata[0][loop_num] = percent_changed;
-            println!(
-                "Cells changed {} ({:.4} percent)",
-                cells_changed, percent_changed
-            );
+            progress::message(format!("Cells changed {} ({:.4} percent)", cells_changed, percent_changed));
             if percent_changed < percent_changed_threshold {
                 break;
             }
@@ -652,21 +648,18 @@ impl WhiteboxTool for ModifiedKMeansClustering {
         output.add_metadata_entry(format!("Elapsed Time (including I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (including I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (including I/O): {}", elapsed_time));
         }
 
         if !output_html_file.trim().is_empty() {
@@ -865,7 +858,7 @@ impl WhiteboxTool for ModifiedKMeansClustering {
                     let _ = output.stdout;
                 }
 
-                println!("Complete! Please see {} for output.", output_html_file);
+                progress::message(format!("Complete! Please see {} for output.", output_html_file));
             }
         }
 
@@ -8,6 +8,7 @@ License: MIT
 
 use whitebox_raster::*;
 use whitebox_common::structures::RectangleWithData;
+use whitebox_common::progress;
 use crate::tools::*;
 use num_cpus;
 use rstar::RTree;
@@ -18,7 +19,6 @@ use std::io::{Error, ErrorKind};
 use std::path;
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::thread;
 
 /// This tool will create an image mosaic from one or more input image files using
 /// one of three resampling methods including, nearest neighbour, bilinear interpolation,
@@ -151,6 +151,7 @@ impl WhiteboxTool for Mosaic {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_files = String::new();
         let mut output_file = String::new();
@@ -207,13 +208,7 @@ impl WhiteboxTool for Mosaic {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -283,7 +278,7 @@ impl WhiteboxTool for Mosaic {
         */
 
         let num_files = input_vec.len();
-        println!("Number of tiles: {}", num_files);
+        progress::message(format!("Number of tiles: {}", num_files));
         if num_files < 2 {
             return Err(Error::new(ErrorKind::InvalidInput,
                 "There is something incorrect about the input files. At least two inputs are required to operate this tool."));
@@ -293,7 +288,7 @@ impl WhiteboxTool for Mosaic {
 
         // read the input files
         if verbose {
-            println!("Reading data...")
+            progress::message("Reading data...")
         };
         let mut inputs: Vec<Raster> = Vec::with_capacity(num_files);
         let mut nodata_vals: Vec<f64> = Vec::with_capacity(num_files);
@@ -387,17 +382,18 @@ impl WhiteboxTool for Mosaic {
 
                     i += 1;
                 } else {
-                    println!("Warning: Error reading file {}", value);
+                    progress::warning(format!("Error reading file {}", value));
                 }
             } else {
                 return Err(Error::new(ErrorKind::InvalidInput,
                     "There is a problem with the list of input files. At least one specified input is empty."));
             }
 
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * a as f64 / (num_files - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    progress::update(progress);
                     old_progress = progress;
                 }
             }
@@ -431,10 +427,7 @@ impl WhiteboxTool for Mosaic {
         configs.palette = inputs[0].configs.palette.clone();
 
         if verbose {
-            println!(
-                "Output image size: ({} x {})",
-                configs.rows, configs.columns
-            );
+            progress::message(format!("Output image size: ({} x {})", configs.rows, configs.columns));
         }
 
         let mut output = Raster::initialize_using_config(&output_file, &configs);
@@ -469,7 +462,7 @@ impl WhiteboxTool for Mosaic {
                 let y = y.clone();
                 let tx = tx.clone();
                 let tree = tree.clone();
-                thread::spawn(move || {
+                progress::spawn(move || {
                     let mut z: f64;
                     let (mut col_src, mut row_src): (isize, isize);
                     let mut i: usize;
@@ -493,13 +486,15 @@ impl WhiteboxTool for Mosaic {
                                 }
                             }
                         }
-                        tx.send((row, data)).unwrap();
+                        if tx.send((row, data)).is_err() {
+                            return;
+                        }
                     }
                 });
             }
 
             for r in 0..rows {
-                let (row, data) = rx.recv().expect("Error receiving data from thread.");
+                let (row, data) = cancel.recv(&rx)?;
                 for col in 0..columns {
                     if data[col as usize] != nodata {
                         output.set_value(row, col, data[col as usize]);
@@ -508,7 +503,7 @@ impl WhiteboxTool for Mosaic {
                 if verbose {
                     progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Progress: {}%", progress);
+                        progress::update(progress);
                         old_progress = progress;
                     }
                 }
@@ -524,7 +519,7 @@ impl WhiteboxTool for Mosaic {
                 let y = y.clone();
                 let tx = tx.clone();
                 let tree = tree.clone();
-                thread::spawn(move || {
+                progress::spawn(move || {
                     let mut z: f64;
                     let shift_x = [-1, 0, 1, 2, -1, 0, 1, 2, -1, 0, 1, 2, -1, 0, 1, 2];
                     let shift_y = [-1, -1, -1, -1, 0, 0, 0, 0, 1, 1, 1, 1, 2, 2, 2, 2];
@@ -590,12 +585,14 @@ impl WhiteboxTool for Mosaic {
                                 }
                             }
                         }
-                        tx.send((row, data)).unwrap();
+                        if tx.send((row, data)).is_err() {
+                            return;
+                        }
                     }
                 });
             }
             for r in 0..rows {
-                let (row, data) = rx.recv().expect("Error receiving data from thread.");
+                let (row, data) = cancel.recv(&rx)?;
                 for col in 0..columns as usize {
                     if data[col] != nodata {
                         output.set_value(row, col as isize, data[col]);
@@ -604,7 +601,7 @@ impl WhiteboxTool for Mosaic {
                 if verbose {
                     progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Progress: {}%", progress);
+                        progress::update(progress);
                         old_progress = progress;
                     }
                 }
@@ -620,7 +617,7 @@ impl WhiteboxTool for Mosaic {
                 let y = y.clone();
                 let tx = tx.clone();
                 let tree = tree.clone();
-                thread::spawn(move || {
+                progress::spawn(move || {
                     let mut z: f64;
                     let shift_x = [0, 1, 0, 1];
                     let shift_y = [0, 0, 1, 1];
@@ -681,12 +678,14 @@ impl WhiteboxTool for Mosaic {
                                 }
                             }
                         }
-                        tx.send((row, data)).unwrap();
+                        if tx.send((row, data)).is_err() {
+                            return;
+                        }
                     }
                 });
             }
             for r in 0..rows {
-                let (row, data) = rx.recv().expect("Error receiving data from thread.");
+                let (row, data) = cancel.recv(&rx)?;
                 for col in 0..columns as usize {
                     if data[col] != nodata {
                         output.set_value(row, col as isize, data[col]);
@@ -695,7 +694,7 @@ impl WhiteboxTool for Mosaic {
                 if verbose {
                     progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Progress: {}%", progress);
+                        progress::update(progress);
                         old_progress = progress;
                     }
                 }
@@ -710,21 +709,18 @@ impl WhiteboxTool for Mosaic {
         output.add_metadata_entry(format!("Resampling method: {}", method));
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (including I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (including I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -8,6 +8,7 @@ License: MIT
 
 use whitebox_raster::*;
 use whitebox_common::structures::Array2D;
+use whitebox_common::progress;
 use crate::tools::*;
 use num_cpus;
 use std::env;
@@ -16,7 +17,6 @@ use std::io::{Error, ErrorKind};
 use std::path;
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::thread;
 
 /// This tool will create a mosaic from two input images. It is similar in operation to the `Mosaic` tool,
 /// however, this tool is the preferred method of mosaicing images when there is significant overlap between
@@ -168,6 +168,7 @@ impl WhiteboxTool for MosaicWithFeathering {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file1 = String::new();
         let mut input_file2 = String::new();
@@ -249,13 +250,7 @@ impl WhiteboxTool for MosaicWithFeathering {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -277,7 +272,7 @@ impl WhiteboxTool for MosaicWithFeathering {
 
         // read the input files
         if verbose {
-            println!("Reading data...")
+            progress::message("Reading data...")
         };
 
         let input1 = Arc::new(Raster::new(&input_file1, "r")?);
@@ -353,26 +348,28 @@ impl WhiteboxTool for MosaicWithFeathering {
         // create the minimum edge distance rasters
         for tid in 0..num_procs {
             let tx = tx.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 for row in (0..rows1).filter(|r| r % num_procs == tid) {
                     let mut data = vec![0u32; columns1 as usize];
                     for col in 0..columns1 {
                         data[col as usize] =
                             col.min(row.min((columns1 - col - 1).min(rows1 - row - 1))) as u32;
                     }
-                    tx.send((row, data)).unwrap();
+                    if tx.send((row, data)).is_err() {
+                        return;
+                    }
                 }
             });
         }
         let mut dist1_raster: Array2D<u32> =
             Array2D::new(rows1, columns1, u32::max_value(), u32::max_value())?;
         for row in 0..rows1 {
-            let data = rx.recv().expect("Error receiving data from thread.");
+            let data = cancel.recv(&rx)?;
             dist1_raster.set_row_data(data.0, data.1);
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Calculating distances (Loop 1 of 2): {}%", progress);
+                    progress::update_stage("Calculating distances (Loop 1 of 2)", progress);
                     old_progress = progress;
                 }
             }
@@ -380,26 +377,28 @@ impl WhiteboxTool for MosaicWithFeathering {
 
         for tid in 0..num_procs {
             let tx = tx.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 for row in (0..rows2).filter(|r| r % num_procs == tid) {
                     let mut data = vec![0u32; columns2 as usize];
                     for col in 0..columns2 {
                         data[col as usize] =
                             col.min(row.min((columns2 - col - 1).min(rows2 - row - 1))) as u32;
                     }
-                    tx.send((row, data)).unwrap();
+                    if tx.send((row, data)).is_err() {
+                        return;
+                    }
                 }
             });
         }
         let mut dist2_raster: Array2D<u32> =
             Array2D::new(rows2, columns2, u32::max_value(), u32::max_value())?;
         for row in 0..rows2 {
-            let data = rx.recv().expect("Error receiving data from thread.");
+            let data = cancel.recv(&rx)?;
             dist2_raster.set_row_data(data.0, data.1);
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Calculating distances (Loop 2 of 2): {}%", progress);
+                    progress::update_stage("Calculating distances (Loop 2 of 2)", progress);
                     old_progress = progress;
                 }
             }
@@ -430,7 +429,7 @@ impl WhiteboxTool for MosaicWithFeathering {
                 let dist1_raster = dist1_raster.clone();
                 let dist2_raster = dist2_raster.clone();
                 let tx = tx.clone();
-                thread::spawn(move || {
+                progress::spawn(move || {
                     let (mut col_src1, mut row_src1): (isize, isize);
                     let (mut col_src2, mut row_src2): (isize, isize);
                     let (mut z1, mut z2): (f64, f64);
@@ -484,18 +483,20 @@ impl WhiteboxTool for MosaicWithFeathering {
                                 data[col as usize] = z2;
                             }
                         }
-                        tx.send((row, data)).unwrap();
+                        if tx.send((row, data)).is_err() {
+                            return;
+                        }
                     }
                 });
             }
 
             for r in 0..rows {
-                let (row, data) = rx.recv().expect("Error receiving data from thread.");
+                let (row, data) = cancel.recv(&rx)?;
                 output.set_row_data(row, data);
                 if verbose {
                     progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Progress: {}%", progress);
+                        progress::update(progress);
                         old_progress = progress;
                     }
                 }
@@ -513,7 +514,7 @@ impl WhiteboxTool for MosaicWithFeathering {
                 let dist1_raster = dist1_raster.clone();
                 let dist2_raster = dist2_raster.clone();
                 let tx = tx.clone();
-                thread::spawn(move || {
+                progress::spawn(move || {
                     let shift_x = if method == "cc" {
                         vec![-1, 0, 1, 2, -1, 0, 1, 2, -1, 0, 1, 2, -1, 0, 1, 2]
                     } else {
@@ -733,17 +734,19 @@ impl WhiteboxTool for MosaicWithFeathering {
                                 }
                             }
                         }
-                        tx.send((row, data)).unwrap();
+                        if tx.send((row, data)).is_err() {
+                            return;
+                        }
                     }
                 });
             }
             for r in 0..rows {
-                let (row, data) = rx.recv().expect("Error receiving data from thread.");
+                let (row, data) = cancel.recv(&rx)?;
                 output.set_row_data(row, data);
                 if verbose {
                     progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Progress: {}%", progress);
+                        progress::update(progress);
                         old_progress = progress;
                     }
                 }
@@ -758,21 +761,18 @@ impl WhiteboxTool for MosaicWithFeathering {
         output.add_metadata_entry(format!("Resampling method: {}", method));
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (including I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (including I/O): {}", elapsed_time));
         }
 
         Ok(())
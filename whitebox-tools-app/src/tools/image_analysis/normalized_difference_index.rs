@@ -7,6 +7,7 @@ License: MIT
 */
 
 use whitebox_raster::*;
+use whitebox_common::progress;
 use crate::tools::*;
 use num_cpus;
 use std::env;
@@ -15,7 +16,6 @@ use std::io::{Error, ErrorKind};
 use std::path;
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::thread;
 
 /// This tool can be used to calculate a normalized difference index (NDI) from two bands of multispectral image data.
 /// A NDI of two band images (`image1` and `image2`) takes the general form:
@@ -144,7 +144,7 @@ impl NormalizedDifferenceIndex {
             short_exe += ".exe";
         }
         let usage = format!(">>.*{0} -r={1} -v --wd=\"*path*to*data*\" --input1=band4.tif --input2=band3.tif -o=output.tif
->>.*{0} -r={1} -v --wd=\"*path*to*data*\" --input1=band4.tif --input2=band3.tif -o=output.tif --clip=1.0 --adjustment=0.16", short_exe, name).replace("*", &sep);
+>>.*{0} -r={1} -v --wd=\"*path*to*data*\" --input1=band4.tif --input2=band3.tif -o=output.tif --clip=1.0", short_exe, name).replace("*", &sep);
 
         NormalizedDifferenceIndex {
             name: name,
@@ -196,6 +196,7 @@ impl WhiteboxTool for NormalizedDifferenceIndex {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input1_file = String::new();
         let mut input2_file = String::new();
@@ -277,13 +278,7 @@ impl WhiteboxTool for NormalizedDifferenceIndex {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -302,7 +297,7 @@ impl WhiteboxTool for NormalizedDifferenceIndex {
         }
 
         if verbose {
-            println!("Reading data...")
+            progress::message("Reading data...")
         };
 
         let nir = Arc::new(Raster::new(&input1_file, "r")?);
@@ -336,7 +331,7 @@ impl WhiteboxTool for NormalizedDifferenceIndex {
             let nir = nir.clone();
             let red = red.clone();
             let tx1 = tx.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let (mut z_nir, mut z_red): (f64, f64);
                 for row in (0..rows).filter(|r| r % num_procs == tid) {
                     let mut data = vec![nir_nodata; columns as usize];
@@ -352,18 +347,20 @@ impl WhiteboxTool for NormalizedDifferenceIndex {
                             }
                         }
                     }
-                    tx1.send((row, data)).unwrap();
+                    if tx1.send((row, data)).is_err() {
+                        return;
+                    }
                 }
             });
         }
 
         for row in 0..rows {
-            let data = rx.recv().expect("Error receiving data from thread.");
+            let data = cancel.recv(&rx)?;
             output.set_row_data(data.0, data.1);
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    progress::update(progress);
                     old_progress = progress;
                 }
             }
@@ -371,7 +368,7 @@ impl WhiteboxTool for NormalizedDifferenceIndex {
 
         if clip_amount > 0.0 {
             if verbose {
-                println!("Clipping output...");
+                progress::message("Clipping output...");
             }
             output.clip_min_and_max_by_percent(clip_amount);
         }
@@ -390,21 +387,18 @@ impl WhiteboxTool for NormalizedDifferenceIndex {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
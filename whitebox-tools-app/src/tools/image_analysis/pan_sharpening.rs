@@ -8,6 +8,7 @@ License: MIT
 
 use whitebox_raster::*;
 use whitebox_common::structures::Array2D;
+use whitebox_common::progress;
 use crate::tools::*;
 use num_cpus;
 use std::env;
@@ -16,7 +17,6 @@ use std::io::{Error, ErrorKind};
 use std::path;
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::thread;
 
 /// Panchromatic sharpening, or simply pan-sharpening, refers to a range of techniques that can be used to merge
 /// finer spatial resolution panchromatic images with coarser spatial resolution multi-spectral images. The
@@ -66,7 +66,7 @@ impl PanchromaticSharpening {
 
         parameters.push(ToolParameter{
             name: "Input Green Band File (optional; only if colour-composite not specified)".to_owned(), 
-            flags: vec!["--green".to_owned()], 
+            flags: vec!["-g".to_owned(), "--green".to_owned()], 
             description: "Input green band image file. Optionally specified if colour-composite not specified.".to_owned(),
             parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
             default_value: None,
@@ -75,7 +75,7 @@ impl PanchromaticSharpening {
 
         parameters.push(ToolParameter{
             name: "Input Blue Band File (optional; only if colour-composite not specified)".to_owned(), 
-            flags: vec!["--blue".to_owned()], 
+            flags: vec!["-b".to_owned(), "--blue".to_owned()], 
             description: "Input blue band image file. Optionally specified if colour-composite not specified.".to_owned(),
             parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
             default_value: None,
@@ -84,7 +84,7 @@ impl PanchromaticSharpening {
 
         parameters.push(ToolParameter{
             name: "Input Colour-Composite Image File (optional; only if individual bands not specified)".to_owned(), 
-            flags: vec!["--composite".to_owned()], 
+            flags: vec!["-c".to_owned(), "--composite".to_owned()], 
             description: "Input colour-composite image file. Only used if individual bands are not specified.".to_owned(),
             parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
             default_value: None,
@@ -93,7 +93,7 @@ impl PanchromaticSharpening {
 
         parameters.push(ToolParameter {
             name: "Input Panchromatic Band File".to_owned(),
-            flags: vec!["--pan".to_owned()],
+            flags: vec!["-p".to_owned(), "--pan".to_owned()],
             description: "Input panchromatic band file.".to_owned(),
             parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
             default_value: None,
@@ -184,6 +184,7 @@ impl WhiteboxTool for PanchromaticSharpening {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut red_file = String::new();
         let mut green_file = String::new();
@@ -210,7 +211,7 @@ impl WhiteboxTool for PanchromaticSharpening {
                 keyval = true;
             }
             let flag_val = vec[0].to_lowercase().replace("--", "-");
-            if flag_val == "-r" || flag_val == "-red" {
+            if flag_val == "-red" {
                 red_file = if keyval {
                     vec[1].to_string()
                 } else {
@@ -263,13 +264,7 @@ impl WhiteboxTool for PanchromaticSharpening {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -314,7 +309,7 @@ impl WhiteboxTool for PanchromaticSharpening {
 
         if use_composite {
             if verbose {
-                println!("Reading multispec image data...")
+                progress::message("Reading multispec image data...")
             };
             let input_c = Raster::new(&composite_file, "r")?;
 
@@ -330,15 +325,15 @@ impl WhiteboxTool for PanchromaticSharpening {
             input = input_c.get_data_as_array2d();
         } else {
             if verbose {
-                println!("Reading red band data...")
+                progress::message("Reading red band data...")
             };
             let input_r = Raster::new(&red_file, "r")?;
             if verbose {
-                println!("Reading green band data...")
+                progress::message("Reading green band data...")
             };
             let input_g = Raster::new(&green_file, "r")?;
             if verbose {
-                println!("Reading blue band data...")
+                progress::message("Reading blue band data...")
             };
             let input_b = Raster::new(&blue_file, "r")?;
 
@@ -420,7 +415,7 @@ impl WhiteboxTool for PanchromaticSharpening {
         let input = Arc::new(input);
 
         if verbose {
-            println!("Reading pan image data...")
+            progress::message("Reading pan image data...")
         };
         let pan = Arc::new(Raster::new(&pan_file, "r")?);
         let rows_pan = pan.configs.rows as isize;
@@ -443,7 +438,7 @@ impl WhiteboxTool for PanchromaticSharpening {
                 let pan = pan.clone();
                 let input = input.clone();
                 let tx = tx.clone();
-                thread::spawn(move || {
+                progress::spawn(move || {
                     let get_column_from_x =
                         |x: f64| -> isize { ((x - west) / resolution_x).floor() as isize };
                     let get_row_from_y =
@@ -498,18 +493,20 @@ impl WhiteboxTool for PanchromaticSharpening {
                                     ((255 << 24) | (b_out << 16) | (g_out << 8) | r_out) as f64;
                             }
                         }
-                        tx.send((row, data)).unwrap();
+                        if tx.send((row, data)).is_err() {
+                            return;
+                        }
                     }
                 });
             }
 
             for row in 0..rows_pan {
-                let data = rx.recv().expect("Error receiving data from thread.");
+                let data = cancel.recv(&rx)?;
                 output.set_row_data(data.0, data.1);
                 if verbose {
                     progress = (100.0_f64 * (row + 1) as f64 / rows_pan as f64) as usize;
                     if progress != old_progress {
-                        println!("Progress: {}%", progress);
+                        progress::update(progress);
                         old_progress = progress;
                     }
                 }
@@ -522,7 +519,7 @@ impl WhiteboxTool for PanchromaticSharpening {
             for tid in 0..num_procs {
                 let input = input.clone();
                 let tx = tx.clone();
-                thread::spawn(move || {
+                progress::spawn(move || {
                     let mut overall_max = f64::NEG_INFINITY;
                     let (mut r, mut g, mut b): (f64, f64, f64);
                     let mut z: f64;
@@ -546,20 +543,20 @@ impl WhiteboxTool for PanchromaticSharpening {
                             }
                         }
                     }
-                    tx.send(overall_max).unwrap();
+                    let _ = tx.send(overall_max);
                 });
             }
 
             let mut overall_max = f64::NEG_INFINITY;
             for tid in 0..num_procs {
-                let data = rx.recv().expect("Error receiving data from thread.");
+                let data = cancel.recv(&rx)?;
                 if data > overall_max {
                     overall_max = data;
                 }
                 if verbose {
                     progress = (100.0_f64 * tid as f64 / (num_procs - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Progress: {}%", progress);
+                        progress::update(progress);
                         old_progress = progress;
                     }
                 }
@@ -570,7 +567,7 @@ impl WhiteboxTool for PanchromaticSharpening {
                 let pan = pan.clone();
                 let input = input.clone();
                 let tx = tx.clone();
-                thread::spawn(move || {
+                progress::spawn(move || {
                     let get_column_from_x =
                         |x: f64| -> isize { ((x - west) / resolution_x).floor() as isize };
                     let get_row_from_y =
@@ -672,18 +669,20 @@ impl WhiteboxTool for PanchromaticSharpening {
                                     ((255 << 24) | (b_out << 16) | (g_out << 8) | r_out) as f64;
                             }
                         }
-                        tx.send((row, data)).unwrap();
+                        if tx.send((row, data)).is_err() {
+                            return;
+                        }
                     }
                 });
             }
 
             for row in 0..rows_pan {
-                let data = rx.recv().expect("Error receiving data from thread.");
+                let data = cancel.recv(&rx)?;
                 output.set_row_data(data.0, data.1);
                 if verbose {
                     progress = (100.0_f64 * row as f64 / (rows_pan - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Progress: {}%", progress);
+                        progress::update(progress);
                         old_progress = progress;
                     }
                 }
@@ -708,21 +707,18 @@ impl WhiteboxTool for PanchromaticSharpening {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -11,6 +11,7 @@ NOTES: 1. The tool should be updated to take multiple file inputs.
 */
 
 use whitebox_raster::*;
+use whitebox_common::progress;
 use crate::tools::*;
 use num_cpus;
 use std::env;
@@ -20,7 +21,6 @@ use std::io::{Error, ErrorKind};
 use std::path;
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::thread;
 
 /// This tool performs a percentage contrast stretch on a raster image. This operation maps each grid cell value
 /// in the input raster image (z<sub>in</sub>) onto a new scale that ranges from a lower-tail clip value (`min_val`)
@@ -89,7 +89,7 @@ impl PercentageContrastStretch {
 
         parameters.push(ToolParameter{
             name: "Tail".to_owned(), 
-            flags: vec!["--tail".to_owned()], 
+            flags: vec!["--tails".to_owned(), "--tail".to_owned()], 
             description: "Specified which tails to clip; options include 'upper', 'lower', and 'both' (default is 'both').".to_owned(),
             parameter_type: ParameterType::OptionList(vec!["upper".to_owned(), "lower".to_owned(), "both".to_owned()]),
             default_value: Some("both".to_owned()),
@@ -170,6 +170,7 @@ impl WhiteboxTool for PercentageContrastStretch {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut output_file = String::new();
@@ -247,13 +248,7 @@ impl WhiteboxTool for PercentageContrastStretch {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -269,7 +264,7 @@ impl WhiteboxTool for PercentageContrastStretch {
         }
 
         if num_tones < 16f64 {
-            println!("Warning: The output number of greytones must be at least 16. The value has been modified.");
+            progress::warning("The output number of greytones must be at least 16. The value has been modified.");
             num_tones = 16f64;
         }
 
@@ -284,7 +279,7 @@ impl WhiteboxTool for PercentageContrastStretch {
         }
 
         if verbose {
-            println!("Reading input data...")
+            progress::message("Reading input data...")
         };
         let input = Arc::new(Raster::new(&input_file, "r")?);
         let rows = input.configs.rows as isize;
@@ -310,7 +305,7 @@ impl WhiteboxTool for PercentageContrastStretch {
         let start = Instant::now();
 
         if verbose {
-            println!("Calculating clip values...")
+            progress::message("Calculating clip values...")
         };
 
         let (min_val, max_val) = if !is_rgb_image {
@@ -357,10 +352,11 @@ impl WhiteboxTool for PercentageContrastStretch {
                         histo[bin] += 1usize;
                     }
                 }
+                cancel.check()?;
                 if verbose {
                     progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Calculating clip values: {}%", progress);
+                        progress::update_stage("Calculating clip values", progress);
                         old_progress = progress;
                     }
                 }
@@ -419,7 +415,7 @@ impl WhiteboxTool for PercentageContrastStretch {
         for tid in 0..num_procs {
             let input = input.clone();
             let tx = tx.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let input_fn: Box<dyn Fn(isize, isize) -> f64> = if !is_rgb_image {
                     Box::new(|row: isize, col: isize| -> f64 { input.get_value(row, col) })
                 } else {
@@ -463,19 +459,21 @@ impl WhiteboxTool for PercentageContrastStretch {
                             data[col as usize] = output_fn(row, col, z_out);
                         }
                     }
-                    tx.send((row, data)).unwrap();
+                    if tx.send((row, data)).is_err() {
+                        return;
+                    }
                 }
             });
         }
 
         let mut output = Raster::initialize_using_file(&output_file, &input);
         for r in 0..rows {
-            let (row, data) = rx.recv().expect("Error receiving data from thread.");
+            let (row, data) = cancel.recv(&rx)?;
             output.set_row_data(row, data);
             if verbose {
                 progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    progress::update(progress);
                     old_progress = progress;
                 }
             }
@@ -493,22 +491,19 @@ impl WhiteboxTool for PercentageContrastStretch {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -10,6 +10,7 @@ and written to during the same loop. Doing so would involve using a mutex.
 */
 
 use whitebox_raster::*;
+use whitebox_common::progress;
 use crate::tools::*;
 use num_cpus;
 use std::env;
@@ -18,7 +19,6 @@ use std::io::{Error, ErrorKind};
 use std::path;
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::thread;
 
 /// This image processing tool removes small irregularities (i.e. spurs) on the boundaries of objects in a
 /// Boolean input raster image (`--input`). This operation is sometimes called *pruning*. Remove Spurs is a useful tool
@@ -143,6 +143,7 @@ impl WhiteboxTool for RemoveSpurs {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut output_file = String::new();
@@ -193,13 +194,7 @@ impl WhiteboxTool for RemoveSpurs {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -215,7 +210,7 @@ impl WhiteboxTool for RemoveSpurs {
         }
 
         if verbose {
-            println!("Reading data...")
+            progress::message("Reading data...")
         };
 
         let input = Arc::new(Raster::new(&input_file, "r")?);
@@ -235,7 +230,7 @@ impl WhiteboxTool for RemoveSpurs {
         for tid in 0..num_procs {
             let input = input.clone();
             let tx = tx.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 for row in (0..rows).filter(|r| r % num_procs == tid) {
                     let mut data: Vec<f64> = vec![nodata; columns as usize];
                     for col in 0..columns {
@@ -245,20 +240,22 @@ impl WhiteboxTool for RemoveSpurs {
                             data[col as usize] = 0.0;
                         }
                     }
-                    tx.send((row, data)).unwrap();
+                    if tx.send((row, data)).is_err() {
+                        return;
+                    }
                 }
             });
         }
 
         let mut output = Raster::initialize_using_file(&output_file, &input);
         for r in 0..rows {
-            let (row, data) = rx.recv().expect("Error receiving data from thread.");
+            let (row, data) = cancel.recv(&rx)?;
             output.set_row_data(row, data);
 
             if verbose {
                 progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Initializing output: {}%", progress);
+                    progress::update_stage("Initializing output", progress);
                     old_progress = progress;
                 }
             }
@@ -309,10 +306,11 @@ impl WhiteboxTool for RemoveSpurs {
                             }
                         }
                     }
+                    cancel.check()?;
                     if verbose {
                         progress = (100.0_f64 * a as f64 / 7.0) as usize;
                         if progress != old_progress {
-                            println!("Loop Number {}: {}%", loop_num + 1, progress);
+                            progress::update_stage(&format!("Loop Number {}", loop_num + 1), progress);
                             old_progress = progress;
                         }
                     }
@@ -342,10 +340,11 @@ impl WhiteboxTool for RemoveSpurs {
                             }
                         }
                     }
+                    cancel.check()?;
                     if verbose {
                         progress = (100.0_f64 * a as f64 / 7.0) as usize;
                         if progress != old_progress {
-                            println!("Loop Number {}: {}%", loop_num + 1, progress);
+                            progress::update_stage(&format!("Loop Number {}", loop_num + 1), progress);
                             old_progress = progress;
                         }
                     }
@@ -366,21 +365,18 @@ impl WhiteboxTool for RemoveSpurs {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
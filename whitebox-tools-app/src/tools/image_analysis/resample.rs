@@ -7,6 +7,7 @@ License: MIT
 */
 
 use whitebox_raster::*;
+use whitebox_common::progress;
 use crate::tools::*;
 use num_cpus;
 use std::env;
@@ -15,7 +16,6 @@ use std::io::{Error, ErrorKind};
 use std::path;
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::thread;
 
 /// This tool can be used to modify the grid resolution of one or more rasters. The user
 /// specifies the names of one or more input rasters (`--inputs`) and the output raster
@@ -111,7 +111,7 @@ impl Resample {
         if e.contains(".exe") {
             short_exe += ".exe";
         }
-        let usage = format!(">>.*{} -r={} -v --wd='*path*to*data*' -i='image1.tif;image2.tif;image3.tif' --destination=dest.tif --method='cc", short_exe, name).replace("*", &sep);
+        let usage = format!(">>.*{} -r={} -v --wd='*path*to*data*' -i='image1.tif;image2.tif;image3.tif' -o=output.tif --base=base.tif --method='cc", short_exe, name).replace("*", &sep);
 
         Resample {
             name: name,
@@ -156,6 +156,7 @@ impl WhiteboxTool for Resample {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_files = String::new();
         let mut output_file = String::new();
@@ -242,13 +243,7 @@ impl WhiteboxTool for Resample {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -289,7 +284,7 @@ impl WhiteboxTool for Resample {
 
         // read the input files
         if verbose {
-            println!("Reading data...")
+            progress::message("Reading data...")
         };
         let mut inputs: Vec<Raster> = Vec::with_capacity(num_files);
         let mut nodata_vals: Vec<f64> = Vec::with_capacity(num_files);
@@ -401,7 +396,7 @@ impl WhiteboxTool for Resample {
                 let x = x.clone();
                 let y = y.clone();
                 let tx = tx.clone();
-                thread::spawn(move || {
+                progress::spawn(move || {
                     let mut z: f64;
                     let (mut col_src, mut row_src): (isize, isize);
                     for row in (0..rows).filter(|r| r % num_procs == tid) {
@@ -419,12 +414,14 @@ impl WhiteboxTool for Resample {
                                 }
                             }
                         }
-                        tx.send((row, data)).unwrap();
+                        if tx.send((row, data)).is_err() {
+                            return;
+                        }
                     }
                 });
             }
             for r in 0..rows {
-                let (row, data) = rx.recv().expect("Error receiving data from thread.");
+                let (row, data) = cancel.recv(&rx)?;
                 for col in 0..columns {
                     if data[col as usize] != nodata {
                         output.set_value(row, col, data[col as usize]);
@@ -433,7 +430,7 @@ impl WhiteboxTool for Resample {
                 if verbose {
                     progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Progress: {}%", progress);
+                        progress::update(progress);
                         old_progress = progress;
                     }
                 }
@@ -448,7 +445,7 @@ impl WhiteboxTool for Resample {
                 let x = x.clone();
                 let y = y.clone();
                 let tx = tx.clone();
-                thread::spawn(move || {
+                progress::spawn(move || {
                     let mut z: f64;
                     let shift_x = [-1, 0, 1, 2, -1, 0, 1, 2, -1, 0, 1, 2, -1, 0, 1, 2];
                     let shift_y = [-1, -1, -1, -1, 0, 0, 0, 0, 1, 1, 1, 1, 2, 2, 2, 2];
@@ -504,12 +501,14 @@ impl WhiteboxTool for Resample {
                                 }
                             }
                         }
-                        tx.send((row, data)).unwrap();
+                        if tx.send((row, data)).is_err() {
+                            return;
+                        }
                     }
                 });
             }
             for r in 0..rows {
-                let (row, data) = rx.recv().expect("Error receiving data from thread.");
+                let (row, data) = cancel.recv(&rx)?;
                 for col in 0..columns as usize {
                     if data[col] != nodata {
                         output.set_value(row, col as isize, data[col]);
@@ -518,7 +517,7 @@ impl WhiteboxTool for Resample {
                 if verbose {
                     progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Progress: {}%", progress);
+                        progress::update(progress);
                         old_progress = progress;
                     }
                 }
@@ -533,7 +532,7 @@ impl WhiteboxTool for Resample {
                 let x = x.clone();
                 let y = y.clone();
                 let tx = tx.clone();
-                thread::spawn(move || {
+                progress::spawn(move || {
                     let mut z: f64;
                     let shift_x = [0, 1, 0, 1];
                     let shift_y = [0, 0, 1, 1];
@@ -587,12 +586,14 @@ impl WhiteboxTool for Resample {
                                 }
                             }
                         }
-                        tx.send((row, data)).unwrap();
+                        if tx.send((row, data)).is_err() {
+                            return;
+                        }
                     }
                 });
             }
             for r in 0..rows {
-                let (row, data) = rx.recv().expect("Error receiving data from thread.");
+                let (row, data) = cancel.recv(&rx)?;
                 for col in 0..columns as usize {
                     if data[col] != nodata {
                         output.set_value(row, col as isize, data[col]);
@@ -601,7 +602,7 @@ impl WhiteboxTool for Resample {
                 if verbose {
                     progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Progress: {}%", progress);
+                        progress::update(progress);
                         old_progress = progress;
                     }
                 }
@@ -615,21 +616,18 @@ impl WhiteboxTool for Resample {
         ));
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (including I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (including I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -7,6 +7,7 @@ License: MIT
 */
 
 use whitebox_raster::*;
+use whitebox_common::progress;
 use crate::tools::*;
 use num_cpus;
 use std::env;
@@ -16,7 +17,6 @@ use std::io::{Error, ErrorKind};
 use std::path;
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::thread;
 
 /// This tool transforms three raster images of multispectral data (red, green, and blue channels) into their equivalent
 /// intensity, hue, and saturation (IHS; sometimes HSI or HIS) images. Intensity refers to the brightness of a color, hue
@@ -78,7 +78,7 @@ impl RgbToIhs {
 
         parameters.push(ToolParameter{
             name: "Input Green Band File (optional; only if colour-composite not specified)".to_owned(), 
-            flags: vec!["--green".to_owned()], 
+            flags: vec!["-g".to_owned(), "--green".to_owned()], 
             description: "Input green band image file. Optionally specified if colour-composite not specified.".to_owned(),
             parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
             default_value: None,
@@ -87,7 +87,7 @@ impl RgbToIhs {
 
         parameters.push(ToolParameter{
             name: "Input Blue Band File (optional; only if colour-composite not specified)".to_owned(), 
-            flags: vec!["--blue".to_owned()], 
+            flags: vec!["-b".to_owned(), "--blue".to_owned()], 
             description: "Input blue band image file. Optionally specified if colour-composite not specified.".to_owned(),
             parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
             default_value: None,
@@ -105,7 +105,7 @@ impl RgbToIhs {
 
         parameters.push(ToolParameter {
             name: "Output Intensity File".to_owned(),
-            flags: vec!["--intensity".to_owned()],
+            flags: vec!["-i".to_owned(), "--intensity".to_owned()],
             description: "Output intensity raster file.".to_owned(),
             parameter_type: ParameterType::NewFile(ParameterFileType::Raster),
             default_value: None,
@@ -123,7 +123,7 @@ impl RgbToIhs {
 
         parameters.push(ToolParameter {
             name: "Output Saturation File".to_owned(),
-            flags: vec!["--saturation".to_owned()],
+            flags: vec!["-s".to_owned(), "--saturation".to_owned()],
             description: "Output saturation raster file.".to_owned(),
             parameter_type: ParameterType::NewFile(ParameterFileType::Raster),
             default_value: None,
@@ -196,6 +196,7 @@ impl WhiteboxTool for RgbToIhs {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut red_file = String::new();
         let mut green_file = String::new();
@@ -262,8 +263,7 @@ impl WhiteboxTool for RgbToIhs {
                 } else {
                     intensity_file = args[i + 1].to_string();
                 }
-            } else if vec[0].to_lowercase() == "-h"
-                || vec[0].to_lowercase() == "-hue"
+            } else if vec[0].to_lowercase() == "-hue"
                 || vec[0].to_lowercase() == "--hue"
             {
                 if keyval {
@@ -285,13 +285,7 @@ impl WhiteboxTool for RgbToIhs {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -330,15 +324,15 @@ impl WhiteboxTool for RgbToIhs {
 
         if !use_composite {
             if verbose {
-                println!("Reading red band data...")
+                progress::message("Reading red band data...")
             };
             let input_r = Arc::new(Raster::new(&red_file, "r")?);
             if verbose {
-                println!("Reading green band data...")
+                progress::message("Reading green band data...")
             };
             let input_g = Arc::new(Raster::new(&green_file, "r")?);
             if verbose {
-                println!("Reading blue band data...")
+                progress::message("Reading blue band data...")
             };
             let input_b = Arc::new(Raster::new(&blue_file, "r")?);
 
@@ -379,7 +373,7 @@ impl WhiteboxTool for RgbToIhs {
                 let input_g = input_g.clone();
                 let input_b = input_b.clone();
                 let tx = tx.clone();
-                thread::spawn(move || {
+                progress::spawn(move || {
                     // let (mut r, mut g, mut b): (u32, u32, u32);
                     let (mut red, mut green, mut blue): (f64, f64, f64);
                     // let (mut i, mut h, mut s, mut m): (f64, f64, f64, f64);
@@ -421,8 +415,9 @@ impl WhiteboxTool for RgbToIhs {
                                 intensity_data[col as usize] = i;
                             }
                         }
-                        tx.send((row, intensity_data, hue_data, saturation_data))
-                            .unwrap();
+                        if tx.send((row, intensity_data, hue_data, saturation_data)).is_err() {
+                            return;
+                        }
                     }
                 });
             }
@@ -440,14 +435,14 @@ impl WhiteboxTool for RgbToIhs {
             output_s.configs.data_type = DataType::F32;
 
             for row in 0..rows {
-                let data = rx.recv().expect("Error receiving data from thread.");
+                let data = cancel.recv(&rx)?;
                 output_i.set_row_data(data.0, data.1);
                 output_h.set_row_data(data.0, data.2);
                 output_s.set_row_data(data.0, data.3);
                 if verbose {
                     progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Progress: {}%", progress);
+                        progress::update(progress);
                         old_progress = progress;
                     }
                 }
@@ -463,12 +458,12 @@ impl WhiteboxTool for RgbToIhs {
             output_i.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
             if verbose {
-                println!("Saving intensity data...")
+                progress::message("Saving intensity data...")
             };
             let _ = match output_i.write() {
                 Ok(_) => {
                     if verbose {
-                        println!("Output file written")
+                        progress::output_written(&output_i.file_name)
                     }
                 }
                 Err(e) => return Err(e),
@@ -482,12 +477,12 @@ impl WhiteboxTool for RgbToIhs {
             output_h.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
             if verbose {
-                println!("Saving hue data...")
+                progress::message("Saving hue data...")
             };
             let _ = match output_h.write() {
                 Ok(_) => {
                     if verbose {
-                        println!("Output file written")
+                        progress::output_written(&output_h.file_name)
                     }
                 }
                 Err(e) => return Err(e),
@@ -503,26 +498,23 @@ impl WhiteboxTool for RgbToIhs {
             );
 
             if verbose {
-                println!("Saving saturation data...")
+                progress::message("Saving saturation data...")
             };
             let _ = match output_s.write() {
                 Ok(_) => {
                     if verbose {
-                        println!("Output file written")
+                        progress::output_written(&output_s.file_name)
                     }
                 }
                 Err(e) => return Err(e),
             };
 
             if verbose {
-                println!(
-                    "{}",
-                    &format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", "")
-                );
+                progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", ""));
             }
         } else {
             if verbose {
-                println!("Reading image data...")
+                progress::message("Reading image data...")
             };
             let input = Arc::new(Raster::new(&composite_file, "r")?);
             let rows = input.configs.rows as isize;
@@ -536,7 +528,7 @@ impl WhiteboxTool for RgbToIhs {
             for tid in 0..num_procs {
                 let input = input.clone();
                 let tx = tx.clone();
-                thread::spawn(move || {
+                progress::spawn(move || {
                     let mut overall_min = f64::INFINITY;
                     let mut overall_max = f64::NEG_INFINITY;
                     let (mut r, mut g, mut b): (f64, f64, f64);
@@ -572,14 +564,14 @@ impl WhiteboxTool for RgbToIhs {
                             }
                         }
                     }
-                    tx.send((overall_min, overall_max)).unwrap();
+                    let _ = tx.send((overall_min, overall_max));
                 });
             }
 
             let mut overall_min = f64::INFINITY;
             let mut overall_max = f64::NEG_INFINITY;
             for tid in 0..num_procs {
-                let data = rx.recv().expect("Error receiving data from thread.");
+                let data = cancel.recv(&rx)?;
                 if data.0 < overall_min {
                     overall_min = data.0;
                 }
@@ -589,7 +581,7 @@ impl WhiteboxTool for RgbToIhs {
                 if verbose {
                     progress = (100.0_f64 * tid as f64 / (num_procs - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Progress: {}%", progress);
+                        progress::update(progress);
                         old_progress = progress;
                     }
                 }
@@ -601,7 +593,7 @@ impl WhiteboxTool for RgbToIhs {
             for tid in 0..num_procs {
                 let input = input.clone();
                 let tx = tx.clone();
-                thread::spawn(move || {
+                progress::spawn(move || {
                     // let (mut r, mut g, mut b): (f64, f64, f64);
                     // let (mut i, mut h, mut s, mut m): (f64, f64, f64, f64);
                     // let (mut i, mut h, mut s): (f64, f64, f64);
@@ -672,8 +664,9 @@ impl WhiteboxTool for RgbToIhs {
                                 saturation_data[col as usize] = s;
                             }
                         }
-                        tx.send((row, intensity_data, hue_data, saturation_data))
-                            .unwrap();
+                        if tx.send((row, intensity_data, hue_data, saturation_data)).is_err() {
+                            return;
+                        }
                     }
                 });
             }
@@ -691,14 +684,14 @@ impl WhiteboxTool for RgbToIhs {
             output_s.configs.data_type = DataType::F32;
 
             for row in 0..rows {
-                let data = rx.recv().expect("Error receiving data from thread.");
+                let data = cancel.recv(&rx)?;
                 output_i.set_row_data(data.0, data.1);
                 output_h.set_row_data(data.0, data.2);
                 output_s.set_row_data(data.0, data.3);
                 if verbose {
                     progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Progress: {}%", progress);
+                        progress::update(progress);
                         old_progress = progress;
                     }
                 }
@@ -716,12 +709,12 @@ impl WhiteboxTool for RgbToIhs {
             );
 
             if verbose {
-                println!("Saving intensity data...")
+                progress::message("Saving intensity data...")
             };
             let _ = match output_i.write() {
                 Ok(_) => {
                     if verbose {
-                        println!("Output file written")
+                        progress::output_written(&output_i.file_name)
                     }
                 }
                 Err(e) => return Err(e),
@@ -737,12 +730,12 @@ impl WhiteboxTool for RgbToIhs {
             );
 
             if verbose {
-                println!("Saving hue data...")
+                progress::message("Saving hue data...")
             };
             let _ = match output_h.write() {
                 Ok(_) => {
                     if verbose {
-                        println!("Output file written")
+                        progress::output_written(&output_h.file_name)
                     }
                 }
                 Err(e) => return Err(e),
@@ -758,21 +751,18 @@ impl WhiteboxTool for RgbToIhs {
             );
 
             if verbose {
-                println!("Saving saturation data...")
+                progress::message("Saving saturation data...")
             };
             let _ = match output_s.write() {
                 Ok(_) => {
                     if verbose {
-                        println!("Output file written")
+                        progress::output_written(&output_s.file_name)
                     }
                 }
                 Err(e) => return Err(e),
             };
             if verbose {
-                println!(
-                    "{}",
-                    &format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", "")
-                );
+                progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", ""));
             }
         }
 
@@ -7,6 +7,7 @@ License: MIT
 */
 
 use whitebox_raster::*;
+use whitebox_common::progress;
 use crate::tools::*;
 use num_cpus;
 use std::env;
@@ -15,7 +16,6 @@ use std::io::{Error, ErrorKind};
 use std::path;
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::thread;
 
 /// This tool performs a 3 &times; 3 or 5 &times; 5 Sobel edge-detection filter on a raster image. The Sobel filter
 /// is similar to the `PrewittFilter`, in that it identifies areas of high slope in the input image through
@@ -166,6 +166,7 @@ impl WhiteboxTool for SobelFilter {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         if args.len() == 0 {
             return Err(Error::new(
@@ -231,13 +232,7 @@ impl WhiteboxTool for SobelFilter {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -253,7 +248,7 @@ impl WhiteboxTool for SobelFilter {
         let mut old_progress: usize = 1;
 
         if verbose {
-            println!("Reading data...")
+            progress::message("Reading data...")
         };
 
         let input = Arc::new(Raster::new(&input_file, "r")?);
@@ -288,7 +283,7 @@ impl WhiteboxTool for SobelFilter {
             let input = input.clone();
             let variant = variant.clone();
             let tx1 = tx.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let input_fn: Box<dyn Fn(isize, isize) -> f64> = if !is_rgb_image {
                     Box::new(|row: isize, col: isize| -> f64 { input.get_value(row, col) })
                 } else {
@@ -330,7 +325,9 @@ impl WhiteboxTool for SobelFilter {
                                 data[col as usize] = (slope_x * slope_x + slope_y * slope_y).sqrt();
                             }
                         }
-                        tx1.send((row, data)).unwrap();
+                        if tx1.send((row, data)).is_err() {
+                            return;
+                        }
                     }
                 } else {
                     // 5x5
@@ -370,19 +367,21 @@ impl WhiteboxTool for SobelFilter {
                                 data[col as usize] = (slope_x * slope_x + slope_y * slope_y).sqrt();
                             }
                         }
-                        tx1.send((row, data)).unwrap();
+                        if tx1.send((row, data)).is_err() {
+                            return;
+                        }
                     }
                 }
             });
         }
 
         for row in 0..rows {
-            let data = rx.recv().expect("Error receiving data from thread.");
+            let data = cancel.recv(&rx)?;
             output.set_row_data(data.0, data.1);
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    progress::update(progress);
                     old_progress = progress;
                 }
             }
@@ -390,7 +389,7 @@ impl WhiteboxTool for SobelFilter {
 
         if clip_amount > 0.0 {
             if verbose {
-                println!("Clipping output...");
+                progress::message("Clipping output...");
             }
             output.clip_min_and_max_by_percent(clip_amount);
         }
@@ -407,22 +406,19 @@ impl WhiteboxTool for SobelFilter {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
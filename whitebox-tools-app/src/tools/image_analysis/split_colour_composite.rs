@@ -7,6 +7,7 @@ License: MIT
 */
 
 use whitebox_raster::*;
+use whitebox_common::progress;
 use crate::tools::*;
 use num_cpus;
 use std::env;
@@ -14,7 +15,6 @@ use std::io::{Error, ErrorKind};
 use std::path;
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::thread;
 
 /// This tool can be used to split a red-green-blue (RGB) colour-composite image into three separate bands of
 /// multi-spectral imagery. The user must specify the input image (`--input`) and output red, green, blue images
@@ -145,6 +145,7 @@ impl WhiteboxTool for SplitColourComposite {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut red_file = String::new();
@@ -195,13 +196,7 @@ impl WhiteboxTool for SplitColourComposite {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -223,7 +218,7 @@ impl WhiteboxTool for SplitColourComposite {
         }
 
         if verbose {
-            println!("Reading data...")
+            progress::message("Reading data...")
         };
 
         let input = Arc::new(Raster::new(&input_file, "r")?);
@@ -245,7 +240,7 @@ impl WhiteboxTool for SplitColourComposite {
         for tid in 0..num_procs {
             let input = input.clone();
             let tx = tx.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let mut in_val: f64;
                 let mut val: u32;
                 let (mut red, mut green, mut blue): (u32, u32, u32);
@@ -265,7 +260,9 @@ impl WhiteboxTool for SplitColourComposite {
                             data_b[col as usize] = blue as f64;
                         }
                     }
-                    tx.send((row, data_r, data_g, data_b)).unwrap();
+                    if tx.send((row, data_r, data_g, data_b)).is_err() {
+                        return;
+                    }
                 }
             });
         }
@@ -286,14 +283,14 @@ impl WhiteboxTool for SplitColourComposite {
         output_b.configs.nodata = output_nodata;
 
         for row in 0..rows {
-            let data = rx.recv().expect("Error receiving data from thread.");
+            let data = cancel.recv(&rx)?;
             output_r.set_row_data(data.0, data.1);
             output_g.set_row_data(data.0, data.2);
             output_b.set_row_data(data.0, data.3);
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    progress::update(progress);
                     old_progress = progress;
                 }
             }
@@ -308,12 +305,12 @@ impl WhiteboxTool for SplitColourComposite {
         output_r.add_metadata_entry(format!("Input file: {}", input_file));
         output_r.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         if verbose {
-            println!("Saving red image...")
+            progress::message("Saving red image...")
         };
         let _ = match output_r.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output_r.file_name)
                 }
             }
             Err(e) => return Err(e),
@@ -326,12 +323,12 @@ impl WhiteboxTool for SplitColourComposite {
         output_g.add_metadata_entry(format!("Input file: {}", input_file));
         output_g.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         if verbose {
-            println!("Saving green image...")
+            progress::message("Saving green image...")
         };
         let _ = match output_g.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output_g.file_name)
                 }
             }
             Err(e) => return Err(e),
@@ -344,21 +341,18 @@ impl WhiteboxTool for SplitColourComposite {
         output_b.add_metadata_entry(format!("Input file: {}", input_file));
         output_b.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         if verbose {
-            println!("Saving blue image...")
+            progress::message("Saving blue image...")
         };
         let _ = match output_b.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output_b.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
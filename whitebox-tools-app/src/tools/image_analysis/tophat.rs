@@ -8,6 +8,7 @@ License: MIT
 
 use whitebox_raster::*;
 use whitebox_common::structures::Array2D;
+use whitebox_common::progress;
 use crate::tools::*;
 use num_cpus;
 use std::collections::VecDeque;
@@ -17,7 +18,6 @@ use std::io::{Error, ErrorKind};
 use std::path;
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::thread;
 
 /// This tool performs either a white or black [top-hat transform](https://en.wikipedia.org/wiki/Top-hat_transform)
 /// on an input image. A top-hat transform is a common digital image processing operation used for various tasks, such
@@ -165,6 +165,7 @@ impl WhiteboxTool for TophatTransform {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut output_file = String::new();
@@ -258,13 +259,7 @@ impl WhiteboxTool for TophatTransform {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -297,7 +292,7 @@ impl WhiteboxTool for TophatTransform {
         }
 
         if verbose {
-            println!("Reading data...")
+            progress::message("Reading data...")
         };
 
         let input = Arc::new(Raster::new(&input_file, "r")?);
@@ -320,7 +315,7 @@ impl WhiteboxTool for TophatTransform {
             for tid in 0..num_procs {
                 let input = input.clone();
                 let tx1 = tx.clone();
-                thread::spawn(move || {
+                progress::spawn(move || {
                     let (mut z_n, mut z): (f64, f64);
                     let mut min_val: f64;
                     let (mut start_col, mut end_col, mut start_row, mut end_row): (
@@ -378,19 +373,21 @@ impl WhiteboxTool for TophatTransform {
                                 }
                             }
                         }
-                        tx1.send((row, data)).unwrap();
+                        if tx1.send((row, data)).is_err() {
+                            return;
+                        }
                     }
                 });
             }
 
             let mut erosion: Array2D<f64> = Array2D::new(rows, columns, nodata, nodata)?;
             for row in 0..rows {
-                let data = rx.recv().expect("Error receiving data from thread.");
+                let data = cancel.recv(&rx)?;
                 erosion.set_row_data(data.0, data.1);
                 if verbose {
                     progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Progress (Loop 1 of 2): {}%", progress);
+                        progress::update_stage("Progress (Loop 1 of 2)", progress);
                         old_progress = progress;
                     }
                 }
@@ -403,7 +400,7 @@ impl WhiteboxTool for TophatTransform {
                 let input_data = input.clone();
                 let input = erosion.clone();
                 let tx1 = tx.clone();
-                thread::spawn(move || {
+                progress::spawn(move || {
                     let (mut z_n, mut z): (f64, f64);
                     let mut max_val: f64;
                     let (mut start_col, mut end_col, mut start_row, mut end_row): (
@@ -461,19 +458,21 @@ impl WhiteboxTool for TophatTransform {
                                 }
                             }
                         }
-                        tx1.send((row, data)).unwrap();
+                        if tx1.send((row, data)).is_err() {
+                            return;
+                        }
                     }
                 });
             }
 
             let mut output = Raster::initialize_using_file(&output_file, &input);
             for row in 0..rows {
-                let data = rx.recv().expect("Error receiving data from thread.");
+                let data = cancel.recv(&rx)?;
                 output.set_row_data(data.0, data.1);
                 if verbose {
                     progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Progress (Loop 2 of 2): {}%", progress);
+                        progress::update_stage("Progress (Loop 2 of 2)", progress);
                         old_progress = progress;
                     }
                 }
@@ -491,22 +490,19 @@ impl WhiteboxTool for TophatTransform {
             output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
             if verbose {
-                println!("Saving data...")
+                progress::message("Saving data...")
             };
             let _ = match output.write() {
                 Ok(_) => {
                     if verbose {
-                        println!("Output file written")
+                        progress::output_written(&output.file_name)
                     }
                 }
                 Err(e) => return Err(e),
             };
 
             if verbose {
-                println!(
-                    "{}",
-                    &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-                );
+                progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
             }
         } else {
             // black top-hat transform
@@ -515,7 +511,7 @@ impl WhiteboxTool for TophatTransform {
             for tid in 0..num_procs {
                 let input = input.clone();
                 let tx1 = tx.clone();
-                thread::spawn(move || {
+                progress::spawn(move || {
                     let (mut z_n, mut z): (f64, f64);
                     let mut max_val: f64;
                     let (mut start_col, mut end_col, mut start_row, mut end_row): (
@@ -573,19 +569,21 @@ impl WhiteboxTool for TophatTransform {
                                 }
                             }
                         }
-                        tx1.send((row, data)).unwrap();
+                        if tx1.send((row, data)).is_err() {
+                            return;
+                        }
                     }
                 });
             }
 
             let mut dilation: Array2D<f64> = Array2D::new(rows, columns, nodata, nodata)?;
             for row in 0..rows {
-                let data = rx.recv().expect("Error receiving data from thread.");
+                let data = cancel.recv(&rx)?;
                 dilation.set_row_data(data.0, data.1);
                 if verbose {
                     progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Progress (Loop 1 of 2): {}%", progress);
+                        progress::update_stage("Progress (Loop 1 of 2)", progress);
                         old_progress = progress;
                     }
                 }
@@ -598,7 +596,7 @@ impl WhiteboxTool for TophatTransform {
                 let input_data = input.clone();
                 let input = dilation.clone();
                 let tx1 = tx.clone();
-                thread::spawn(move || {
+                progress::spawn(move || {
                     let (mut z_n, mut z): (f64, f64);
                     let mut min_val: f64;
                     let (mut start_col, mut end_col, mut start_row, mut end_row): (
@@ -656,19 +654,21 @@ impl WhiteboxTool for TophatTransform {
                                 }
                             }
                         }
-                        tx1.send((row, data)).unwrap();
+                        if tx1.send((row, data)).is_err() {
+                            return;
+                        }
                     }
                 });
             }
 
             let mut output = Raster::initialize_using_file(&output_file, &input);
             for row in 0..rows {
-                let data = rx.recv().expect("Error receiving data from thread.");
+                let data = cancel.recv(&rx)?;
                 output.set_row_data(data.0, data.1);
                 if verbose {
                     progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Progress (Loop 2 of 2): {}%", progress);
+                        progress::update_stage("Progress (Loop 2 of 2)", progress);
                         old_progress = progress;
                     }
                 }
@@ -686,21 +686,18 @@ impl WhiteboxTool for TophatTransform {
             output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
             if verbose {
-                println!("Saving data...")
+                progress::message("Saving data...")
             };
             let _ = match output.write() {
                 Ok(_) => {
                     if verbose {
-                        println!("Output file written")
+                        progress::output_written(&output.file_name)
                     }
                 }
                 Err(e) => return Err(e),
             };
             if verbose {
-                println!(
-                    "{}",
-                    &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-                );
+                progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
             }
         }
 
@@ -7,6 +7,7 @@ License: MIT
 */
 
 use whitebox_raster::*;
+use whitebox_common::progress;
 use crate::tools::*;
 use num_cpus;
 use std::env;
@@ -19,7 +20,6 @@ use std::io::{Error, ErrorKind};
 use std::path;
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::thread;
 
 /// NoData values in the input image are ignored during the convolution operation.
 /// This can lead to unexpected behavior at the edges of images (since the default behavior
@@ -80,7 +80,7 @@ impl UserDefinedWeightsFilter {
 
         parameters.push(ToolParameter{
             name: "Kernel Center".to_owned(), 
-            flags: vec!["--center".to_owned()], 
+            flags: vec!["--centre".to_owned(), "--center".to_owned()], 
             description: "Kernel center cell; options include 'center', 'upper-left', 'upper-right', 'lower-left', 'lower-right'".to_owned(),
             parameter_type: ParameterType::OptionList(vec!["center".to_owned(), "upper-left".to_owned(), "upper-right".to_owned(), "lower-left".to_owned(), "lower-right".to_owned()]),
             default_value: Some("center".to_owned()),
@@ -154,6 +154,7 @@ impl WhiteboxTool for UserDefinedWeightsFilter {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut output_file = String::new();
@@ -203,13 +204,7 @@ impl WhiteboxTool for UserDefinedWeightsFilter {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -292,7 +287,7 @@ impl WhiteboxTool for UserDefinedWeightsFilter {
         let mut old_progress: usize = 1;
 
         if verbose {
-            println!("Reading data...")
+            progress::message("Reading data...")
         };
         let input = Arc::new(Raster::new(&input_file, "r")?);
 
@@ -326,7 +321,7 @@ impl WhiteboxTool for UserDefinedWeightsFilter {
             let d_y = d_y.clone();
             let weights = weights.clone();
             let tx1 = tx.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let input_fn: Box<dyn Fn(isize, isize) -> f64> = if !is_rgb_image {
                     Box::new(|row: isize, col: isize| -> f64 { input.get_value(row, col) })
                 } else {
@@ -397,19 +392,21 @@ impl WhiteboxTool for UserDefinedWeightsFilter {
                         }
                     }
 
-                    tx1.send((row, data)).unwrap();
+                    if tx1.send((row, data)).is_err() {
+                        return;
+                    }
                 }
             });
         }
 
         let mut output = Raster::initialize_using_file(&output_file, &input);
         for row in 0..rows {
-            let data = rx.recv().expect("Error receiving data from thread.");
+            let data = cancel.recv(&rx)?;
             output.set_row_data(data.0, data.1);
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    progress::update(progress);
                     old_progress = progress;
                 }
             }
@@ -425,22 +422,19 @@ impl WhiteboxTool for UserDefinedWeightsFilter {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
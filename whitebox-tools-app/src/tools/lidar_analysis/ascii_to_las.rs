@@ -17,12 +17,13 @@ use std::io::{Error, ErrorKind};
 use std::path;
 extern crate byteorder;
 use whitebox_common::spatial_ref_system::esri_wkt_from_epsg;
+use whitebox_common::progress;
 
 /// This tool can be used to convert one or more ASCII files, containing LiDAR point data, into LAS files. The user must
 /// specify the name(s) of the input ASCII file(s) (`--inputs`). Each input file will have a correspondingly named
 /// output file with a `.las` file extension. The output point data, each on a separate line, will take the format:
 ///
-/// ```
+/// ```text
 /// x,y,z,intensity,class,return,num_returns"
 /// ```
 ///
@@ -44,7 +45,7 @@ use whitebox_common::spatial_ref_system::esri_wkt_from_epsg;
 /// The `x`, `y`, and `z` patterns must always be specified. If the `rn` pattern is used, the `nr` pattern must
 /// also be specified. Examples of valid pattern string include:
 ///
-/// ```
+/// ```text
 /// 'x,y,z,i'
 /// 'x,y,z,i,rn,nr'
 /// 'x,y,z,i,c,rn,nr,sa'
@@ -75,7 +76,7 @@ impl AsciiToLas {
         let mut parameters = vec![];
         parameters.push(ToolParameter {
             name: "Input LiDAR point ASCII Files (.csv)".to_owned(),
-            flags: vec!["-i".to_owned(), "--inputs".to_owned()],
+            flags: vec!["-i".to_owned(), "--input".to_owned(), "--inputs".to_owned()],
             description: "Input LiDAR  ASCII files (.csv).".to_owned(),
             parameter_type: ParameterType::FileList(ParameterFileType::Csv),
             default_value: None,
@@ -165,6 +166,7 @@ impl WhiteboxTool for AsciiToLas {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_files: String = String::new();
         let mut pattern_string = String::new();
@@ -225,13 +227,7 @@ impl WhiteboxTool for AsciiToLas {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep = std::path::MAIN_SEPARATOR;
@@ -307,7 +303,7 @@ impl WhiteboxTool for AsciiToLas {
                     pattern_has_b = true;
                     pattern_numeric[a] = 11usize
                 }
-                _ => println!("Unrecognized pattern {}", pattern_vec[a]),
+                _ => progress::message(format!("Unrecognized pattern {}", pattern_vec[a])),
             }
         }
 
@@ -374,7 +370,7 @@ impl WhiteboxTool for AsciiToLas {
                 output.use_point_userdata = true;
 
                 if verbose {
-                    println!("Parsing file {}...", output.get_short_filename());
+                    progress::message(format!("Parsing file {}...", output.get_short_filename()));
                 }
                 let f = File::open(input_file.clone())?;
                 let f = BufReader::new(f);
@@ -475,7 +471,7 @@ impl WhiteboxTool for AsciiToLas {
                                             .parse::<u16>()
                                             .expect("Error parsing data.")
                                     }
-                                    _ => println!("unrecognized pattern"),
+                                    _ => progress::message("unrecognized pattern"),
                                 }
                             }
 
@@ -566,21 +562,22 @@ impl WhiteboxTool for AsciiToLas {
                 // output.add_vlr(vlr3);
 
                 if verbose {
-                    println!("Writing output LAS file {}...", output.get_short_filename());
+                    progress::message(format!("Writing output LAS file {}...", output.get_short_filename()));
                 }
                 let _ = match output.write() {
                     Ok(_) => {
                         if verbose {
-                            println!("Complete!")
+                            progress::message("Complete!")
                         }
                     }
-                    Err(e) => println!("error while writing: {:?}", e),
+                    Err(e) => progress::message(format!("error while writing: {:?}", e)),
                 };
             }
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * (i + 1) as f64 / num_files as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    progress::update(progress);
                     old_progress = progress;
                 }
             }
@@ -589,7 +586,7 @@ impl WhiteboxTool for AsciiToLas {
 
         if verbose {
             let elapsed_time = get_formatted_elapsed_time(start);
-            println!("{}", &format!("Elapsed Time: {}", elapsed_time));
+            progress::message(format!("Elapsed Time: {}", elapsed_time));
         }
 
         Ok(())
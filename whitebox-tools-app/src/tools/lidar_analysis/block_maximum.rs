@@ -8,6 +8,7 @@ License: MIT
 
 use whitebox_lidar::*;
 use whitebox_raster::*;
+use whitebox_common::progress;
 use crate::tools::*;
 use num_cpus;
 use std::env;
@@ -17,7 +18,6 @@ use std::io::{Error, ErrorKind};
 use std::path;
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::thread;
 
 pub struct LidarBlockMaximum {
     name: String,
@@ -62,14 +62,14 @@ impl LidarBlockMaximum {
             optional: true,
         });
 
-        // parameters.push(ToolParameter{
-        //     name: "Palette Name (Whitebox raster outputs only)".to_owned(),
-        //     flags: vec!["--palette".to_owned()],
-        //     description: "Optional palette name (for use with Whitebox raster files).".to_owned(),
-        //     parameter_type: ParameterType::String,
-        //     default_value: None,
-        //     optional: true
-        // });
+        parameters.push(ToolParameter {
+            name: "Palette Name (Whitebox raster outputs only)".to_owned(),
+            flags: vec!["--palette".to_owned()],
+            description: "Optional palette name (for use with Whitebox raster files).".to_owned(),
+            parameter_type: ParameterType::String,
+            default_value: None,
+            optional: true,
+        });
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
         let e = format!("{}", env::current_exe().unwrap().display());
@@ -85,7 +85,7 @@ impl LidarBlockMaximum {
             short_exe += ".exe";
         }
         let usage = format!(">>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=file.las -o=outfile.tif --resolution=2.0\"
-.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=file.las -o=outfile.tif --resolution=5.0 --palette=light_quant.plt", short_exe, name).replace("*", &sep);
+.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=file.las -o=outfile.tif --resolution=5.0", short_exe, name).replace("*", &sep);
 
         LidarBlockMaximum {
             name: name,
@@ -137,6 +137,7 @@ impl WhiteboxTool for LidarBlockMaximum {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file: String = "".to_string();
         let mut output_file: String = "".to_string();
@@ -281,13 +282,7 @@ impl WhiteboxTool for LidarBlockMaximum {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         for k in 0..inputs.len() {
@@ -295,12 +290,7 @@ impl WhiteboxTool for LidarBlockMaximum {
             output_file = outputs[k].replace("\"", "").clone();
 
             if verbose && inputs.len() > 1 {
-                println!(
-                    "Gridding {} of {} ({})",
-                    k + 1,
-                    inputs.len(),
-                    input_file.clone()
-                );
+                progress::message(format!("Gridding {} of {} ({})", k + 1, inputs.len(), input_file.clone()));
             }
 
             if !input_file.contains(path::MAIN_SEPARATOR) {
@@ -311,7 +301,7 @@ impl WhiteboxTool for LidarBlockMaximum {
             }
 
             if verbose && inputs.len() == 1 {
-                println!("reading input LiDAR file...");
+                progress::message("reading input LiDAR file...");
             }
             let input = match LasFile::new(&input_file, "r") {
                 Ok(lf) => lf,
@@ -321,7 +311,7 @@ impl WhiteboxTool for LidarBlockMaximum {
             let start_run = Instant::now();
 
             if verbose && inputs.len() == 1 {
-                println!("Performing analysis...");
+                progress::message("Performing analysis...");
             }
 
             let n_points = input.header.number_of_points as usize;
@@ -362,7 +352,7 @@ impl WhiteboxTool for LidarBlockMaximum {
             for tid in 0..num_procs {
                 let input = input.clone();
                 let tx = tx.clone();
-                thread::spawn(move || {
+                progress::spawn(move || {
                     let mut col: isize;
                     let mut row: isize;
                     for i in (0..n_points).filter(|point_num| point_num % num_procs == tid) {
@@ -372,7 +362,9 @@ impl WhiteboxTool for LidarBlockMaximum {
                             .floor()) as isize;
                         row = (((rows - 1) as f64 * (north - half_grid_res - p.y) / ns_range)
                             .floor()) as isize;
-                        tx.send((row, col, p.z)).unwrap();
+                        if tx.send((row, col, p.z)).is_err() {
+                            return;
+                        }
                     }
                 });
             }
@@ -383,7 +375,7 @@ impl WhiteboxTool for LidarBlockMaximum {
             let mut progress: i32;
             let mut old_progress: i32 = 1;
             for i in 0..n_points {
-                let data = rx.recv().expect("Error receiving data from thread.");
+                let data = cancel.recv(&rx)?;
                 row = data.0;
                 col = data.1;
                 z = data.2;
@@ -393,7 +385,7 @@ impl WhiteboxTool for LidarBlockMaximum {
                 if verbose {
                     progress = (100.0_f64 * i as f64 / num_points) as i32;
                     if progress != old_progress {
-                        println!("Progress: {}%", progress);
+                        progress::update(progress);
                         old_progress = progress;
                     }
                 }
@@ -411,12 +403,12 @@ impl WhiteboxTool for LidarBlockMaximum {
             ));
 
             if verbose {
-                println!("Saving data...")
+                progress::message("Saving data...")
             };
             let _ = match output.write() {
                 Ok(_) => {
                     if verbose {
-                        println!("Output file written")
+                        progress::output_written(&output.file_name)
                     }
                 }
                 Err(e) => return Err(e),
@@ -425,10 +417,7 @@ impl WhiteboxTool for LidarBlockMaximum {
 
         let elapsed_time = get_formatted_elapsed_time(start);
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (including I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (including I/O): {}", elapsed_time));
         }
 
         Ok(())
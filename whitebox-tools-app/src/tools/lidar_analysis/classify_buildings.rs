@@ -7,6 +7,7 @@ License: MIT
 */
 
 use whitebox_common::algorithms;
+use whitebox_common::progress;
 use whitebox_lidar::*;
 use whitebox_common::structures::{BoundingBox, Point2D, Point3D};
 use crate::tools::*;
@@ -16,7 +17,6 @@ use std::env;
 use std::io::{Error, ErrorKind};
 use std::path;
 use std::sync::{mpsc, Arc};
-use std::thread;
 
 /// This tool can be used to assign the building class (classification value 6) to all points within an
 /// input LiDAR point cloud (`--input`) that are contained within the polygons of an input buildings
@@ -59,7 +59,7 @@ impl ClassifyBuildingsInLidar {
 
         parameters.push(ToolParameter {
             name: "Input Building Polygon File".to_owned(),
-            flags: vec!["--buildings".to_owned()],
+            flags: vec!["--building".to_owned(), "--buildings".to_owned()],
             description: "Input vector polygons file.".to_owned(),
             parameter_type: ParameterType::ExistingFile(ParameterFileType::Vector(
                 VectorGeometryType::Polygon,
@@ -90,7 +90,7 @@ impl ClassifyBuildingsInLidar {
         if e.contains(".exe") {
             short_exe += ".exe";
         }
-        let usage = format!(">>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i='data.las' --polygons='lakes.shp' -o='output.las'", short_exe, name).replace("*", &sep);
+        let usage = format!(">>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i='data.las' -o='output.las'", short_exe, name).replace("*", &sep);
 
         ClassifyBuildingsInLidar {
             name: name,
@@ -135,6 +135,7 @@ impl WhiteboxTool for ClassifyBuildingsInLidar {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut polygons_file = String::new();
@@ -179,13 +180,7 @@ impl WhiteboxTool for ClassifyBuildingsInLidar {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -204,7 +199,7 @@ impl WhiteboxTool for ClassifyBuildingsInLidar {
         }
 
         if verbose {
-            println!("Reading data...")
+            progress::message("Reading data...")
         };
         let input = match LasFile::new(&input_file, "r") {
             Ok(lf) => lf,
@@ -245,7 +240,7 @@ impl WhiteboxTool for ClassifyBuildingsInLidar {
         }
 
         if verbose {
-            println!("Performing reclassification...")
+            progress::message("Performing reclassification...")
         };
 
         let n_points = input.header.number_of_points as usize;
@@ -263,7 +258,7 @@ impl WhiteboxTool for ClassifyBuildingsInLidar {
             let record_nums = record_nums.clone();
             let bb = bb.clone();
             let tx = tx.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 // let mut p: PointData;
                 let mut p: Point3D;
                 let mut record_num: usize;
@@ -335,7 +330,7 @@ impl WhiteboxTool for ClassifyBuildingsInLidar {
         output.header.system_id = "EXTRACTION".to_string();
         let mut num_building_points = 0;
         for i in 0..n_points {
-            let data = rx.recv().expect("Error receiving data from thread.");
+            let data = cancel.recv(&rx)?;
             if !data.0 {
                 output.add_point_record(input.get_record(data.1));
             } else {
@@ -473,41 +468,35 @@ impl WhiteboxTool for ClassifyBuildingsInLidar {
             if verbose {
                 progress = (100.0_f64 * i as f64 / num_points) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    progress::update(progress);
                     old_progress = progress;
                 }
             }
         }
 
-        println!(
-            "Number of building points classified: {}",
-            num_building_points
-        );
+        progress::message(format!("Number of building points classified: {}", num_building_points));
 
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!("Writing output LAS file...");
+            progress::message("Writing output LAS file...");
         }
         if output.header.number_of_points > 0 {
             let _ = match output.write() {
                 Ok(_) => {
                     if verbose {
-                        println!("Complete!")
+                        progress::message("Complete!")
                     }
                 }
-                Err(e) => println!("error while writing: {:?}", e),
+                Err(e) => progress::message(format!("error while writing: {:?}", e)),
             };
         } else {
             if verbose {
-                println!("Warning: the file {} does not appear to contain any points within the clip polygon. No output file has been created.", output.get_short_filename());
+                progress::warning(format!("the file {} does not appear to contain any points within the clip polygon. No output file has been created.", output.get_short_filename()));
             }
         }
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -21,6 +21,7 @@ designate overlapping points in 'classify' mode rather than class 12.
 use kd_tree::{KdPoint, KdTree};
 use whitebox_lidar::*;
 use whitebox_common::structures::Point3D;
+use whitebox_common::progress;
 use crate::tools::*;
 use std::env;
 use std::f64;
@@ -210,6 +211,7 @@ impl WhiteboxTool for ClassifyOverlapPoints {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file: String = "".to_string();
         let mut output_file: String = "".to_string();
@@ -312,22 +314,16 @@ impl WhiteboxTool for ClassifyOverlapPoints {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
-        println!("reading input LiDAR file...");
+        progress::message("reading input LiDAR file...");
         let input = match LasFile::new(&input_file, "r") {
             Ok(lf) => lf,
             Err(err) => panic!("Error reading file {}: {}", input_file, err),
         };
 
-        println!("Performing analysis...");
+        progress::message("Performing analysis...");
 
         // Make sure that the input LAS file have GPS time data?
         if input.header.point_format == 0u8 || input.header.point_format == 2u8 {
@@ -347,7 +343,7 @@ impl WhiteboxTool for ClassifyOverlapPoints {
         }
         // build the tree
         if verbose {
-            println!("Building kd-tree...");
+            progress::message("Building kd-tree...");
         }
         let kdtree: KdTree<TreeItem> = KdTree::build_by_ordered_float(points);
 
@@ -501,16 +497,17 @@ impl WhiteboxTool for ClassifyOverlapPoints {
                 //     }
                 // }
             }
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    progress::update(progress);
                     old_progress = progress;
                 }
             }
         }
 
-        println!("Num. overlapping points flagged: {num_overlap}");
+        progress::message(format!("Num. overlapping points flagged: {num_overlap}"));
 
         let mut output = LasFile::initialize_using_file(&output_file, &input);
         output.header.system_id = "EXTRACTION".to_string();
@@ -521,10 +518,11 @@ impl WhiteboxTool for ClassifyOverlapPoints {
                 if !overlapping[i] {
                     output.add_point_record(input.get_record(i));
                 }
+                cancel.check()?;
                 if verbose {
                     progress = (100.0_f64 * i as f64 / num_points) as usize;
                     if progress != old_progress {
-                        println!("Saving data: {}%", progress);
+                        progress::update_stage("Saving data", progress);
                         old_progress = progress;
                     }
                 }
@@ -665,10 +663,11 @@ impl WhiteboxTool for ClassifyOverlapPoints {
                     }
                     output.add_point_record(pr2);
                 }
+                cancel.check()?;
                 if verbose {
                     progress = (100.0_f64 * i as f64 / num_points) as usize;
                     if progress != old_progress {
-                        println!("Saving data: {}%", progress);
+                        progress::update_stage("Saving data", progress);
                         old_progress = progress;
                     }
                 }
@@ -677,21 +676,18 @@ impl WhiteboxTool for ClassifyOverlapPoints {
 
         let elapsed_time = get_formatted_elapsed_time(start);
         if verbose {
-            println!("Writing output LAS file...");
+            progress::message("Writing output LAS file...");
         }
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Complete!")
+                    progress::message("Complete!")
                 }
             }
-            Err(e) => println!("error while writing: {:?}", e),
+            Err(e) => progress::message(format!("error while writing: {:?}", e)),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -7,6 +7,7 @@ License: MIT
 */
 
 use whitebox_common::algorithms;
+use whitebox_common::progress;
 use whitebox_lidar::*;
 use whitebox_common::structures::{BoundingBox, Point2D, Point3D};
 use crate::tools::*;
@@ -16,7 +17,6 @@ use std::env;
 use std::io::{Error, ErrorKind};
 use std::path;
 use std::sync::{mpsc, Arc};
-use std::thread;
 
 /// This tool can be used to isolate, or clip, all of the LiDAR points in a LAS file (`--input`) contained within
 /// one or more vector polygon features. The user must specify the name of the input clip file (--polygons), which
@@ -56,7 +56,7 @@ impl ClipLidarToPolygon {
 
         parameters.push(ToolParameter {
             name: "Input Vector Polygon File".to_owned(),
-            flags: vec!["--polygons".to_owned()],
+            flags: vec!["--polygon".to_owned(), "--polygons".to_owned()],
             description: "Input vector polygons file.".to_owned(),
             parameter_type: ParameterType::ExistingFile(ParameterFileType::Vector(
                 VectorGeometryType::Polygon,
@@ -132,6 +132,7 @@ impl WhiteboxTool for ClipLidarToPolygon {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut polygons_file = String::new();
@@ -176,13 +177,7 @@ impl WhiteboxTool for ClipLidarToPolygon {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -201,7 +196,7 @@ impl WhiteboxTool for ClipLidarToPolygon {
         }
 
         if verbose {
-            println!("Reading data...")
+            progress::message("Reading data...")
         };
         let input = match LasFile::new(&input_file, "r") {
             Ok(lf) => lf,
@@ -242,7 +237,7 @@ impl WhiteboxTool for ClipLidarToPolygon {
         }
 
         if verbose {
-            println!("Performing clip...")
+            progress::message("Performing clip...")
         };
 
         let n_points = input.header.number_of_points as usize;
@@ -260,7 +255,7 @@ impl WhiteboxTool for ClipLidarToPolygon {
             let record_nums = record_nums.clone();
             let bb = bb.clone();
             let tx = tx.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 // let mut p: PointData;
                 let mut p: Point3D;
                 let mut record_num: usize;
@@ -331,14 +326,14 @@ impl WhiteboxTool for ClipLidarToPolygon {
         let mut output = LasFile::initialize_using_file(&output_file, &input);
         output.header.system_id = "EXTRACTION".to_string();
         for i in 0..n_points {
-            let data = rx.recv().expect("Error receiving data from thread.");
+            let data = cancel.recv(&rx)?;
             if data.0 {
                 output.add_point_record(input.get_record(data.1));
             }
             if verbose {
                 progress = (100.0_f64 * i as f64 / num_points) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    progress::update(progress);
                     old_progress = progress;
                 }
             }
@@ -419,27 +414,24 @@ impl WhiteboxTool for ClipLidarToPolygon {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!("Writing output LAS file...");
+            progress::message("Writing output LAS file...");
         }
         if output.header.number_of_points > 0 {
             let _ = match output.write() {
                 Ok(_) => {
                     if verbose {
-                        println!("Complete!")
+                        progress::message("Complete!")
                     }
                 }
-                Err(e) => println!("error while writing: {:?}", e),
+                Err(e) => progress::message(format!("error while writing: {:?}", e)),
             };
         } else {
             if verbose {
-                println!("Warning: the file {} does not appear to contain any points within the clip polygon. No output file has been created.", output.get_short_filename());
+                progress::warning(format!("the file {} does not appear to contain any points within the clip polygon. No output file has been created.", output.get_short_filename()));
             }
         }
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
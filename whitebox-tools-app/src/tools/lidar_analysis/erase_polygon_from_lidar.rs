@@ -7,6 +7,7 @@ License: MIT
 */
 
 use whitebox_common::algorithms;
+use whitebox_common::progress;
 use whitebox_lidar::*;
 use whitebox_common::structures::{BoundingBox, Point2D, Point3D};
 use crate::tools::*;
@@ -54,7 +55,7 @@ impl ErasePolygonFromLidar {
 
         parameters.push(ToolParameter {
             name: "Input Vector Polygon File".to_owned(),
-            flags: vec!["--polygons".to_owned()],
+            flags: vec!["--polygon".to_owned(), "--polygons".to_owned()],
             description: "Input vector polygons file.".to_owned(),
             parameter_type: ParameterType::ExistingFile(ParameterFileType::Vector(
                 VectorGeometryType::Polygon,
@@ -130,6 +131,7 @@ impl WhiteboxTool for ErasePolygonFromLidar {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut polygons_file = String::new();
@@ -174,13 +176,7 @@ impl WhiteboxTool for ErasePolygonFromLidar {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -199,7 +195,7 @@ impl WhiteboxTool for ErasePolygonFromLidar {
         }
 
         if verbose {
-            println!("Reading data...")
+            progress::message("Reading data...")
         };
         let input = match LasFile::new(&input_file, "r") {
             Ok(lf) => lf,
@@ -294,10 +290,11 @@ impl WhiteboxTool for ErasePolygonFromLidar {
             if !point_in_poly {
                 output.add_point_record(input.get_record(point_num));
             }
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * point_num as f64 / num_points) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    progress::update(progress);
                     old_progress = progress;
                 }
             }
@@ -306,21 +303,18 @@ impl WhiteboxTool for ErasePolygonFromLidar {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!("Writing output LAS file...");
+            progress::message("Writing output LAS file...");
         }
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Complete!")
+                    progress::message("Complete!")
                 }
             }
-            Err(e) => println!("error while writing: {:?}", e),
+            Err(e) => progress::message(format!("error while writing: {:?}", e)),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
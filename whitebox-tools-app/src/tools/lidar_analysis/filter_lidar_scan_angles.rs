@@ -10,6 +10,7 @@ NOTES: 1. This tool outputs a LAS file, compared with the original Whitebox GAT
 */
 
 use whitebox_lidar::*;
+use whitebox_common::progress;
 use crate::tools::*;
 use std::env;
 use std::f64;
@@ -125,6 +126,7 @@ impl WhiteboxTool for FilterLidarScanAngles {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file: String = "".to_string();
         let mut output_file: String = "".to_string();
@@ -171,13 +173,7 @@ impl WhiteboxTool for FilterLidarScanAngles {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep = path::MAIN_SEPARATOR;
@@ -189,7 +185,7 @@ impl WhiteboxTool for FilterLidarScanAngles {
         }
 
         if verbose {
-            println!("reading input LiDAR file...");
+            progress::message("reading input LiDAR file...");
         }
         let input = match LasFile::new(&input_file, "r") {
             Ok(lf) => lf,
@@ -199,7 +195,7 @@ impl WhiteboxTool for FilterLidarScanAngles {
         let start = Instant::now();
 
         if verbose {
-            println!("Performing analysis...");
+            progress::message("Performing analysis...");
         }
 
         let n_points = input.header.number_of_points as usize;
@@ -216,10 +212,11 @@ impl WhiteboxTool for FilterLidarScanAngles {
             if input[i].scan_angle.abs() <= threshold {
                 output.add_point_record(input.get_record(i));
             }
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * i as f64 / num_points) as i32;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    progress::update(progress);
                     old_progress = progress;
                 }
             }
@@ -228,21 +225,18 @@ impl WhiteboxTool for FilterLidarScanAngles {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!("Writing output LAS file...");
+            progress::message("Writing output LAS file...");
         }
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Complete!")
+                    progress::message("Complete!")
                 }
             }
-            Err(e) => println!("error while writing: {:?}", e),
+            Err(e) => progress::message(format!("error while writing: {:?}", e)),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
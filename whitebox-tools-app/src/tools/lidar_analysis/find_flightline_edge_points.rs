@@ -7,6 +7,7 @@ License: MIT
 */
 
 use whitebox_lidar::*;
+use whitebox_common::progress;
 use crate::tools::*;
 use std::env;
 use std::f64;
@@ -116,6 +117,7 @@ impl WhiteboxTool for FindFlightlineEdgePoints {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file: String = "".to_string();
         let mut output_file: String = "".to_string();
@@ -153,13 +155,7 @@ impl WhiteboxTool for FindFlightlineEdgePoints {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep = path::MAIN_SEPARATOR;
@@ -171,7 +167,7 @@ impl WhiteboxTool for FindFlightlineEdgePoints {
         }
 
         if verbose {
-            println!("reading input LiDAR file...");
+            progress::message("reading input LiDAR file...");
         }
         let input = match LasFile::new(&input_file, "r") {
             Ok(lf) => lf,
@@ -194,10 +190,11 @@ impl WhiteboxTool for FindFlightlineEdgePoints {
                 output.add_point_record(input.get_record(i));
                 num_output_points += 1;
             }
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * i as f64 / num_points) as i32;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    progress::update(progress);
                     old_progress = progress;
                 }
             }
@@ -205,27 +202,24 @@ impl WhiteboxTool for FindFlightlineEdgePoints {
 
         let elapsed_time = get_formatted_elapsed_time(start);
 
-        println!("");
+        progress::message("");
         if num_output_points > 0 {
             if verbose {
-                println!("Writing output LAS file...");
+                progress::message("Writing output LAS file...");
             }
             let _ = match output.write() {
                 Ok(_) => {
                     if verbose {
-                        println!("Complete!")
+                        progress::message("Complete!")
                     }
                 }
-                Err(e) => println!("error while writing: {:?}", e),
+                Err(e) => progress::message(format!("error while writing: {:?}", e)),
             };
         } else {
-            println!("No points were flagged as flightline edge features in the LAS files.");
+            progress::message("No points were flagged as flightline edge features in the LAS files.");
         }
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -8,6 +8,7 @@ License: MIT
 
 use kd_tree::{KdPoint, KdTree};
 use whitebox_common::structures::Point3D;
+use whitebox_common::progress;
 use whitebox_lidar::*;
 use whitebox_raster::*;
 use crate::tools::*;
@@ -86,6 +87,15 @@ impl FlightlineOverlap {
             optional: true,
         });
 
+        parameters.push(ToolParameter {
+            name: "Palette Name (Whitebox raster outputs only)".to_owned(),
+            flags: vec!["--palette".to_owned()],
+            description: "Optional palette name (for use with Whitebox raster files).".to_owned(),
+            parameter_type: ParameterType::String,
+            default_value: None,
+            optional: true,
+        });
+
         let sep: String = path::MAIN_SEPARATOR.to_string();
         let e = format!("{}", env::current_exe().unwrap().display());
         let mut parent = env::current_exe().unwrap();
@@ -100,7 +110,7 @@ impl FlightlineOverlap {
             short_exe += ".exe";
         }
         let usage = format!(">>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=file.las -o=outfile.tif --resolution=2.0\"
-.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=file.las -o=outfile.tif --resolution=5.0 --palette=light_quant.plt", short_exe, name).replace("*", &sep);
+.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=file.las -o=outfile.tif --resolution=5.0", short_exe, name).replace("*", &sep);
 
         FlightlineOverlap {
             name: name,
@@ -152,6 +162,7 @@ impl WhiteboxTool for FlightlineOverlap {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file: String = "".to_string();
         let mut output_file: String = "".to_string();
@@ -280,13 +291,7 @@ impl WhiteboxTool for FlightlineOverlap {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         for k in 0..inputs.len() {
@@ -294,12 +299,7 @@ impl WhiteboxTool for FlightlineOverlap {
             output_file = outputs[k].replace("\"", "").clone();
 
             if verbose && inputs.len() > 1 {
-                println!(
-                    "Gridding {} of {} ({})",
-                    k + 1,
-                    inputs.len(),
-                    input_file.clone()
-                );
+                progress::message(format!("Gridding {} of {} ({})", k + 1, inputs.len(), input_file.clone()));
             }
 
             if !input_file.contains(path::MAIN_SEPARATOR) && !input_file.contains("/") {
@@ -310,7 +310,7 @@ impl WhiteboxTool for FlightlineOverlap {
             }
 
             if verbose && inputs.len() == 1 {
-                println!("reading input LiDAR file...");
+                progress::message("reading input LiDAR file...");
             }
             let input = match LasFile::new(&input_file, "r") {
                 Ok(lf) => lf,
@@ -320,7 +320,7 @@ impl WhiteboxTool for FlightlineOverlap {
             let start_run = Instant::now();
 
             if verbose && inputs.len() == 1 {
-                println!("Performing analysis...");
+                progress::message("Performing analysis...");
             }
 
             // // Make sure that the input LAS file have GPS time data?
@@ -340,7 +340,7 @@ impl WhiteboxTool for FlightlineOverlap {
             }
             // build the tree
             if verbose {
-                println!("Building kd-tree...");
+                progress::message("Building kd-tree...");
             }
             let kdtree: KdTree<TreeItem> = KdTree::build_by_ordered_float(points);
 
@@ -409,10 +409,11 @@ impl WhiteboxTool for FlightlineOverlap {
                         }
                     }
                 }
+                cancel.check()?;
                 if verbose {
                     progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Progress: {}%", progress);
+                        progress::update(progress);
                         old_progress = progress;
                     }
                 }
@@ -430,12 +431,12 @@ impl WhiteboxTool for FlightlineOverlap {
             ));
 
             if verbose {
-                println!("Saving data...")
+                progress::message("Saving data...")
             };
             let _ = match output.write() {
                 Ok(_) => {
                     if verbose {
-                        println!("Output file written")
+                        progress::output_written(&output.file_name)
                     }
                 }
                 Err(e) => return Err(e),
@@ -444,10 +445,7 @@ impl WhiteboxTool for FlightlineOverlap {
 
         let elapsed_time = get_formatted_elapsed_time(start);
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
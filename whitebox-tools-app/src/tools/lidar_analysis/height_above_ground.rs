@@ -14,6 +14,7 @@ NOTES:
 use whitebox_lidar::*;
 use crate::tools::*;
 use whitebox_common::structures::Point3D;
+use whitebox_common::progress;
 use kdtree::distance::squared_euclidean;
 use kdtree::KdTree;
 use num_cpus;
@@ -23,7 +24,6 @@ use std::io::{Error, ErrorKind};
 use std::path;
 use std::sync::mpsc;
 use std::sync::{Arc, Mutex};
-use std::thread;
 
 /// This tool normalizes an input LiDAR point cloud (`--input`) such that point z-values in the output LAS file
 /// (`--output`) are converted from elevations to heights above the ground, specifically the height above the
@@ -137,6 +137,7 @@ impl WhiteboxTool for HeightAboveGround {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file: String = "".to_string();
         let mut output_file: String = "".to_string();
@@ -175,13 +176,7 @@ impl WhiteboxTool for HeightAboveGround {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep = path::MAIN_SEPARATOR;
@@ -216,10 +211,11 @@ impl WhiteboxTool for HeightAboveGround {
                 tree.add([p1.x, p1.y], i).unwrap();
             }
 
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * i as f64 / num_points) as i32;
                 if progress != old_progress {
-                    println!("Reading points: {}%", progress);
+                    progress::update_stage("Reading points", progress);
                     old_progress = progress;
                 }
             }
@@ -241,7 +237,7 @@ impl WhiteboxTool for HeightAboveGround {
             let tree = tree.clone();
             let input = input.clone();
             let tx = tx.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let mut pd1: PointData;
                 // let mut p2: PointData;
                 let mut p1: Point3D;
@@ -272,17 +268,17 @@ impl WhiteboxTool for HeightAboveGround {
                         *num_solved_pts += 1;
                         progress = (100.0_f64 * *num_solved_pts as f64 / num_points) as i32;
                         if progress != old_progress {
-                            println!("Progress: {}%", progress);
+                            progress::update(progress);
                         }
                     }
                 }
-                tx.send(residuals).unwrap();
+                let _ = tx.send(residuals);
             });
         }
 
         let mut output = LasFile::initialize_using_file(&output_file, &input);
         for n in 0..num_procs {
-            let residuals = rx.recv().expect("Error receiving data from thread.");
+            let residuals = cancel.recv(&rx)?;
             for (i, z) in residuals {
                 let pr = input.get_record(i);
                 let pr2: LidarPointRecord;
@@ -417,7 +413,7 @@ impl WhiteboxTool for HeightAboveGround {
             if verbose {
                 progress = (100.0_f64 * (n + 1) as f64 / num_procs as f64) as i32;
                 if progress != old_progress {
-                    println!("Creating output: {}%", progress);
+                    progress::update_stage("Creating output", progress);
                     old_progress = progress;
                 }
             }
@@ -426,22 +422,19 @@ impl WhiteboxTool for HeightAboveGround {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!("Writing output LAS file...");
+            progress::message("Writing output LAS file...");
         }
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Complete!")
+                    progress::message("Complete!")
                 }
             }
-            Err(e) => println!("error while writing: {:?}", e),
+            Err(e) => progress::message(format!("error while writing: {:?}", e)),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (including I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (including I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -7,6 +7,7 @@ License: MIT
 */
 
 use whitebox_lidar::*;
+use whitebox_common::progress;
 use crate::tools::*;
 use std;
 use std::env;
@@ -22,7 +23,7 @@ use std::path;
 /// with each column corresponding to a field in the table and each row a point value. Fields are separated by
 /// commas in the ASCII formatted file. The output point data, each on a separate line, will take the format:
 ///
-/// ```
+/// ```text
 /// X,Y,Z,INTENSITY,CLASS,RETURN,NUM_RETURN,SCAN_ANGLE
 /// ```
 ///
@@ -50,7 +51,7 @@ impl LasToAscii {
         let mut parameters = vec![];
         parameters.push(ToolParameter {
             name: "Input LiDAR Files".to_owned(),
-            flags: vec!["-i".to_owned(), "--inputs".to_owned()],
+            flags: vec!["-i".to_owned(), "--input".to_owned(), "--inputs".to_owned()],
             description: "Input LiDAR files.".to_owned(),
             parameter_type: ParameterType::FileList(ParameterFileType::Lidar),
             default_value: None,
@@ -126,6 +127,7 @@ impl WhiteboxTool for LasToAscii {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_files: String = String::new();
 
@@ -157,13 +159,7 @@ impl WhiteboxTool for LasToAscii {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep = std::path::MAIN_SEPARATOR;
@@ -311,13 +307,14 @@ impl WhiteboxTool for LasToAscii {
                     };
                     writer.write_all(s.as_bytes())?;
 
+                    cancel.check()?;
                     if verbose {
                         progress = (100.0_f64 * k as f64 / (n_points - 1) as f64) as usize;
                         if progress != old_progress {
                             if num_files > 1 {
-                                println!("Creating file: {} of {}: {}%", i, num_files, progress);
+                                progress::update_stage(&format!("Creating file: {} of {}", i, num_files), progress);
                             } else {
-                                println!("Progress: {}%", progress);
+                                progress::update(progress);
                             }
                             old_progress = progress;
                         }
@@ -330,7 +327,7 @@ impl WhiteboxTool for LasToAscii {
 
         if verbose {
             let elapsed_time = get_formatted_elapsed_time(start);
-            println!("{}", &format!("Elapsed Time: {}", elapsed_time));
+            progress::message(format!("Elapsed Time: {}", elapsed_time));
         }
 
         Ok(())
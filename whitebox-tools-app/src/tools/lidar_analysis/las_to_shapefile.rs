@@ -7,6 +7,7 @@ License: MIT
 */
 
 use whitebox_lidar::*;
+use whitebox_common::progress;
 use crate::tools::*;
 use whitebox_vector::*;
 use num_cpus;
@@ -16,7 +17,6 @@ use std::io::{Error, ErrorKind};
 use std::path;
 use std::sync::mpsc::channel;
 use std::sync::{Arc, Mutex};
-use std::thread;
 
 /// This tool converts one or more LAS files into a POINT vector. When the input parameter is
 /// not specified, the tool grids all LAS files contained within the working directory.
@@ -131,6 +131,7 @@ impl WhiteboxTool for LasToShapefile {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file: String = "".to_string();
 
@@ -162,13 +163,7 @@ impl WhiteboxTool for LasToShapefile {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let mut progress: usize;
@@ -223,7 +218,7 @@ impl WhiteboxTool for LasToShapefile {
             let inputs = inputs.clone();
             let tile_list = tile_list.clone();
             let tx = tx.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let mut tile = 0;
                 while tile < num_tiles {
                     // Get the next tile up for processing
@@ -244,16 +239,16 @@ impl WhiteboxTool for LasToShapefile {
                         .replace(".ZLIDAR", ".shp");
 
                     if verbose && num_tiles == 1 {
-                        println!("reading input LiDAR file...");
+                        progress::message("reading input LiDAR file...");
                     }
 
                     let path = path::Path::new(&input_file);
                     let filenm = path.file_stem().unwrap();
                     let short_filename = filenm.to_str().unwrap().to_string();
                     if verbose && num_tiles > 1 {
-                        println!("Processing {}", short_filename);
+                        progress::message(format!("Processing {}", short_filename));
                     } else if verbose {
-                        println!("Performing analysis...");
+                        progress::message("Performing analysis...");
                     }
                     let ret_val = match LasFile::new(&input_file, "r") {
                         Ok(mut input) => {
@@ -331,7 +326,7 @@ impl WhiteboxTool for LasToShapefile {
                                     progress =
                                         (100.0_f64 * i as f64 / (n_points - 1) as f64) as usize;
                                     if progress != old_progress {
-                                        println!("Progress: {}%", progress);
+                                        progress::update(progress);
                                         old_progress = progress;
                                     }
                                 }
@@ -354,7 +349,9 @@ impl WhiteboxTool for LasToShapefile {
                         ),
                     };
                     // send the data to the main thread to be output
-                    tx.send(ret_val).unwrap();
+                    if tx.send(ret_val).is_err() {
+                        return;
+                    }
                 }
             });
         }
@@ -363,15 +360,16 @@ impl WhiteboxTool for LasToShapefile {
             match rx.recv() {
                 Ok(data) => {
                     if !data.0 {
-                        println!("{}", data.1);
+                        progress::message(&data.1);
                     }
                 }
-                Err(val) => println!("Error: {:?}", val),
+                Err(val) => progress::message(format!("Error: {:?}", val)),
             }
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * tile as f64 / (num_tiles - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress ({} of {}): {}%", (tile + 1), num_tiles, progress);
+                    progress::update_stage(&format!("Progress ({} of {})", (tile + 1), num_tiles), progress);
                     old_progress = progress;
                 }
             }
@@ -379,7 +377,7 @@ impl WhiteboxTool for LasToShapefile {
 
         if verbose {
             let elapsed_time = get_formatted_elapsed_time(start);
-            println!("{}", &format!("Elapsed Time: {}", elapsed_time));
+            progress::message(format!("Elapsed Time: {}", elapsed_time));
         }
 
         Ok(())
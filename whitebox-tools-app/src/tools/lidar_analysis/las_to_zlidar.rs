@@ -7,12 +7,13 @@ License: MIT
 */
 
 use whitebox_lidar::*;
+use whitebox_common::progress;
 use crate::tools::*;
 use std;
 use std::io::{Error, ErrorKind};
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::{env, fs, path, thread};
+use std::{env, fs, path};
 
 /// This tool can be used to convert one or more LAS files into the
 /// [*zLidar*](https://jblindsay.github.io/zLidar_spec/intro.html) compressed
@@ -44,7 +45,7 @@ impl LasToZlidar {
         let mut parameters = vec![];
         parameters.push(ToolParameter {
             name: "Input LAS Files".to_owned(),
-            flags: vec!["-i".to_owned(), "--inputs".to_owned()],
+            flags: vec!["-i".to_owned(), "--input".to_owned(), "--inputs".to_owned()],
             description: "Input LAS files.".to_owned(),
             parameter_type: ParameterType::FileList(ParameterFileType::Lidar),
             default_value: None,
@@ -63,7 +64,7 @@ impl LasToZlidar {
 
         parameters.push(ToolParameter{
             name: "Compression Method".to_owned(), 
-            flags: vec!["--compress".to_owned()], 
+            flags: vec!["--compression".to_owned(), "--compress".to_owned()], 
             description: "Compression method, including 'brotli' and 'deflate'.".to_owned(),
             parameter_type: ParameterType::OptionList(
                 vec![
@@ -153,6 +154,7 @@ impl WhiteboxTool for LasToZlidar {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_files: String = String::new();
         let mut output_directory: String = String::new();
@@ -211,13 +213,7 @@ impl WhiteboxTool for LasToZlidar {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep = std::path::MAIN_SEPARATOR;
@@ -272,7 +268,7 @@ impl WhiteboxTool for LasToZlidar {
         for a in (0..inputs.len()).rev() {
             if !inputs[a].to_lowercase().ends_with(".las") {
                 if verbose {
-                    println!("WARNGING: Non-LAS input file detected. This file will be excluded from the analysis.");
+                    progress::message("WARNGING: Non-LAS input file detected. This file will be excluded from the analysis.");
                 }
                 inputs.remove(a);
             }
@@ -300,7 +296,7 @@ impl WhiteboxTool for LasToZlidar {
             let output_directory = output_directory.clone();
             let compression_method = compression_method.clone();
             let tx = tx.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 // let mut k = 0;
                 let mut progress: usize;
                 let mut old_progress: usize = 1;
@@ -350,7 +346,7 @@ impl WhiteboxTool for LasToZlidar {
                                             / (n_points - 1) as f64)
                                             as usize;
                                         if progress != old_progress {
-                                            println!("Creating output: {}%", progress);
+                                            progress::update_stage("Creating output", progress);
                                             old_progress = progress;
                                         }
                                     }
@@ -359,16 +355,20 @@ impl WhiteboxTool for LasToZlidar {
                                     Ok(_) => {
                                         // do nothing
                                     }
-                                    Err(e) => println!("Error while writing: {:?}", e),
+                                    Err(e) => progress::message(format!("Error while writing: {:?}", e)),
                                 };
-                                tx.send(short_filename.clone()).unwrap();
+                                if tx.send(short_filename.clone()).is_err() {
+                                    return;
+                                }
                             }
                             Err(_) => {
                                 panic!("Error reading file: {}", input_file);
                             }
                         };
                     } else {
-                        tx.send(format!("Empty file name for tile {}.", k)).unwrap();
+                        if tx.send(format!("Empty file name for tile {}.", k)).is_err() {
+                            return;
+                        }
                     }
                 }
             });
@@ -377,29 +377,19 @@ impl WhiteboxTool for LasToZlidar {
         let mut progress: usize;
         let mut old_progress: usize = 1;
         for tile in 0..num_files {
-            let file_nm = rx.recv().expect("Error receiving data from thread.");
+            let file_nm = cancel.recv(&rx)?;
             if verbose && !file_nm.contains("Empty") && num_files > 1 && tile < 99 {
-                println!(
-                    "Completed conversion of {} ({} of {})",
-                    file_nm,
-                    tile + 1,
-                    num_files
-                );
+                progress::message(format!("Completed conversion of {} ({} of {})", file_nm, tile + 1, num_files));
             } else if verbose && tile == 99 {
-                println!(
-                    "Completed conversion of {} ({} of {})",
-                    file_nm,
-                    tile + 1,
-                    num_files
-                );
-                println!("...");
+                progress::message(format!("Completed conversion of {} ({} of {})", file_nm, tile + 1, num_files));
+                progress::message("...");
             } else if file_nm.to_lowercase().contains("empty file name") {
-                println!("{}", file_nm);
+                progress::message(&file_nm);
             }
             if verbose {
                 progress = (100.0_f64 * tile as f64 / (num_files - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    progress::update(progress);
                     old_progress = progress;
                 }
             }
@@ -407,7 +397,7 @@ impl WhiteboxTool for LasToZlidar {
 
         if verbose {
             let elapsed_time = get_formatted_elapsed_time(start);
-            println!("{}", &format!("Elapsed Time: {}", elapsed_time));
+            progress::message(format!("Elapsed Time: {}", elapsed_time));
         }
 
         Ok(())
@@ -9,6 +9,7 @@ License: MIT
 extern crate kdtree;
 
 use whitebox_lidar::*;
+use whitebox_common::progress;
 use crate::tools::*;
 use kdtree::distance::squared_euclidean;
 use kdtree::KdTree;
@@ -180,6 +181,7 @@ impl WhiteboxTool for LidarClassifySubset {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_base_file: String = "".to_string();
         let mut input_subset_file: String = "".to_string();
@@ -239,13 +241,7 @@ impl WhiteboxTool for LidarClassifySubset {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep = path::MAIN_SEPARATOR;
@@ -274,7 +270,7 @@ impl WhiteboxTool for LidarClassifySubset {
         }
 
         if verbose {
-            println!("Reading input files...");
+            progress::message("Reading input files...");
         }
         let base_lidar = LasFile::new(&input_base_file, "r")?;
         let subset_lidar = LasFile::new(&input_subset_file, "r")?;
@@ -282,7 +278,7 @@ impl WhiteboxTool for LidarClassifySubset {
         let start = Instant::now();
 
         if verbose {
-            println!("Performing analysis...");
+            progress::message("Performing analysis...");
         }
 
         let num_points = (base_lidar.header.number_of_points - 1) as f64;
@@ -291,7 +287,7 @@ impl WhiteboxTool for LidarClassifySubset {
 
         let capacity_per_node = 128;
         let mut kdtree = KdTree::with_capacity(3, capacity_per_node);
-        println!("Creating tree...");
+        progress::message("Creating tree...");
         for i in 0..subset_lidar.header.number_of_points as usize {
             // let p: PointData = subset_lidar.get_point_info(i);
             let p = subset_lidar.get_transformed_coords(i);
@@ -307,7 +303,7 @@ impl WhiteboxTool for LidarClassifySubset {
         // now output the data
         let mut output = LasFile::initialize_using_file(&output_file, &base_lidar);
 
-        println!("Performing analysis...");
+        progress::message("Performing analysis...");
 
         for i in 0..base_lidar.header.number_of_points as usize {
             let pd: PointData = base_lidar.get_point_info(i);
@@ -584,10 +580,11 @@ impl WhiteboxTool for LidarClassifySubset {
                 output.add_point_record(pr2);
             }
 
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * i as f64 / num_points) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    progress::update(progress);
                     old_progress = progress;
                 }
             }
@@ -595,23 +592,20 @@ impl WhiteboxTool for LidarClassifySubset {
 
         let elapsed_time = get_formatted_elapsed_time(start);
 
-        println!("");
+        progress::message("");
         if verbose {
-            println!("Writing output LAS file...");
+            progress::message("Writing output LAS file...");
         }
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Complete!")
+                    progress::message("Complete!")
                 }
             }
-            Err(e) => println!("error while writing: {:?}", e),
+            Err(e) => progress::message(format!("error while writing: {:?}", e)),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
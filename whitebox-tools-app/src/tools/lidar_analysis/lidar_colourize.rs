@@ -8,6 +8,7 @@ License: MIT
 
 use whitebox_lidar::*;
 use whitebox_raster::*;
+use whitebox_common::progress;
 use crate::tools::*;
 use num_cpus;
 use std::env;
@@ -16,7 +17,6 @@ use std::io::{Error, ErrorKind};
 use std::path;
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::thread;
 
 /// This tool can be used to add red-green-blue (RGB) colour values to the points contained within an
 /// input LAS file (`--in_lidar`), based on the pixel values of an overlapping input colour image (`--in_image`). 
@@ -143,6 +143,7 @@ impl WhiteboxTool for LidarColourize {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_lidar_file: String = "".to_string();
         let mut input_image_file: String = "".to_string();
@@ -188,13 +189,7 @@ impl WhiteboxTool for LidarColourize {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep = path::MAIN_SEPARATOR;
@@ -209,7 +204,7 @@ impl WhiteboxTool for LidarColourize {
         }
 
         if verbose {
-            println!("Reading input files...");
+            progress::message("Reading input files...");
         }
         let in_lidar = Arc::new(LasFile::new(&input_lidar_file, "r")?);
         let in_image = Arc::new(Raster::new(&input_image_file, "r")?);
@@ -217,7 +212,7 @@ impl WhiteboxTool for LidarColourize {
         let start = Instant::now();
 
         if verbose {
-            println!("Performing analysis...");
+            progress::message("Performing analysis...");
         }
 
         let n_points = in_lidar.header.number_of_points as usize;
@@ -236,7 +231,7 @@ impl WhiteboxTool for LidarColourize {
             let in_lidar = in_lidar.clone();
             let in_image = in_image.clone();
             let tx = tx.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let (mut row, mut col): (isize, isize);
                 let mut value: f64;
                 let nodata = in_image.configs.nodata;
@@ -247,9 +242,13 @@ impl WhiteboxTool for LidarColourize {
                     col = in_image.get_column_from_x(p.x);
                     value = in_image.get_value(row, col);
                     if value != nodata {
-                        tx.send((i, value as u32)).unwrap();
+                        if tx.send((i, value as u32)).is_err() {
+                            return;
+                        }
                     } else {
-                        tx.send((i, 0u32)).unwrap();
+                        if tx.send((i, 0u32)).is_err() {
+                            return;
+                        }
                     }
                 }
             });
@@ -257,12 +256,12 @@ impl WhiteboxTool for LidarColourize {
 
         let mut colour_values: Vec<u32> = vec![0u32; n_points];
         for i in 0..n_points {
-            let data = rx.recv().expect("Error receiving data from thread.");
+            let data = cancel.recv(&rx)?;
             colour_values[data.0] = data.1;
             if verbose {
                 progress = (100.0_f64 * i as f64 / num_points) as i32;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    progress::update(progress);
                     old_progress = progress;
                 }
             }
@@ -319,10 +318,11 @@ impl WhiteboxTool for LidarColourize {
                 });
             }
 
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * i as f64 / num_points) as i32;
                 if progress != old_progress {
-                    println!("Saving data: {}%", progress);
+                    progress::update_stage("Saving data", progress);
                     old_progress = progress;
                 }
             }
@@ -330,23 +330,20 @@ impl WhiteboxTool for LidarColourize {
 
         let elapsed_time = get_formatted_elapsed_time(start);
 
-        println!("");
+        progress::message("");
         if verbose {
-            println!("Writing output LAS file...");
+            progress::message("Writing output LAS file...");
         }
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Complete!")
+                    progress::message("Complete!")
                 }
             }
-            Err(e) => println!("error while writing: {:?}", e),
+            Err(e) => progress::message(format!("error while writing: {:?}", e)),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -13,6 +13,7 @@ use whitebox_common::algorithms::triangulate;
 use whitebox_lidar::*;
 use crate::na;
 use whitebox_common::structures::Point2D;
+use whitebox_common::progress;
 use crate::tools::*;
 use whitebox_vector::ShapefileGeometry;
 use whitebox_vector::*;
@@ -20,7 +21,7 @@ use num_cpus;
 use std::io::{Error, ErrorKind};
 use std::sync::mpsc;
 use std::sync::{Arc, Mutex};
-use std::{env, f64, fs, path, thread};
+use std::{env, f64, fs, path};
 
 /// This tool creates a vector triangular irregular network (TIN) for a set of LiDAR points (`--input`)
 /// using a 2D [Delaunay triangulation](https://en.wikipedia.org/wiki/Delaunay_triangulation) algorithm.
@@ -175,6 +176,7 @@ impl WhiteboxTool for LidarConstructVectorTIN {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file: String = "".to_string();
         let mut output_file: String = "".to_string();
@@ -266,13 +268,7 @@ impl WhiteboxTool for LidarConstructVectorTIN {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let start = Instant::now();
@@ -388,7 +384,7 @@ impl WhiteboxTool for LidarConstructVectorTIN {
         }
 
         if verbose {
-            println!("Performing interpolation...");
+            progress::message("Performing interpolation...");
         }
 
         let num_tiles = inputs.len();
@@ -404,7 +400,7 @@ impl WhiteboxTool for LidarConstructVectorTIN {
             // copy over the string parameters
             let include_class_vals = include_class_vals.clone();
             let tx = tx.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let mut tile = 0;
                 while tile < num_tiles {
                     // Get the next tile up for interpolation
@@ -422,7 +418,7 @@ impl WhiteboxTool for LidarConstructVectorTIN {
                     let mut z_values = vec![];
 
                     if verbose && inputs.len() == 1 {
-                        println!("reading input LiDAR file...");
+                        progress::message("reading input LiDAR file...");
                     }
 
                     let mut progress: usize;
@@ -457,10 +453,11 @@ impl WhiteboxTool for LidarConstructVectorTIN {
                                 }
                             }
                         }
+                        cancel.check()?;
                         if verbose && inputs.len() == 1 {
                             progress = (100.0_f64 * i as f64 / num_points) as usize;
                             if progress != old_progress {
-                                println!("Reading points: {}%", progress);
+                                progress::update_stage("Reading points", progress);
                                 old_progress = progress;
                             }
                         }
@@ -504,7 +501,7 @@ impl WhiteboxTool for LidarConstructVectorTIN {
 
                     // do the triangulation
                     if num_tiles == 1 && verbose {
-                        println!("Performing triangulation...");
+                        progress::message("Performing triangulation...");
                     }
                     let result = triangulate(&points).expect("No triangulation exists.");
                     let (mut p1, mut p2, mut p3): (usize, usize, usize);
@@ -571,30 +568,33 @@ impl WhiteboxTool for LidarConstructVectorTIN {
 
                         rec_num += 1i32;
 
+                        cancel.check()?;
                         if verbose && num_tiles == 1 {
                             progress = (100.0_f64 * i as f64 / (result.triangles.len() - 1) as f64)
                                 as usize;
                             if progress != old_progress {
-                                println!("Creating polygons: {}%", progress);
+                                progress::update_stage("Creating polygons", progress);
                                 old_progress = progress;
                             }
                         }
                     }
 
                     if verbose && inputs.len() == 1 {
-                        println!("Saving data...")
+                        progress::message("Saving data...")
                     };
 
                     let _ = match output.write() {
                         Ok(_) => {
                             if verbose {
-                                println!("Output file written")
+                                progress::output_written(&output.file_name)
                             }
                         }
                         Err(e) => panic!("Error reading file {}:\n{:?}", input_file, e),
                     };
 
-                    tx.send(tile).unwrap();
+                    if tx.send(tile).is_err() {
+                        return;
+                    }
                 }
             });
         }
@@ -602,22 +602,17 @@ impl WhiteboxTool for LidarConstructVectorTIN {
         let mut progress: i32;
         let mut old_progress: i32 = -1;
         for tile in 0..inputs.len() {
-            let tile_completed = rx.recv().expect("Error receiving data from thread.");
+            let tile_completed = cancel.recv(&rx)?;
             if verbose {
-                println!(
-                    "Finished TINing {} ({} of {})",
-                    inputs[tile_completed]
+                progress::message(format!("Finished TINing {} ({} of {})", inputs[tile_completed]
                         .replace("\"", "")
                         .replace(working_directory, "")
-                        .replace(".las", ""),
-                    tile + 1,
-                    inputs.len()
-                );
+                        .replace(".las", ""), tile + 1, inputs.len()));
             }
             if verbose {
                 progress = (100.0_f64 * tile as f64 / (inputs.len() - 1) as f64) as i32;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    progress::update(progress);
                     old_progress = progress;
                 }
             }
@@ -626,10 +621,7 @@ impl WhiteboxTool for LidarConstructVectorTIN {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (including I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (including I/O): {}", elapsed_time));
         }
 
         Ok(())
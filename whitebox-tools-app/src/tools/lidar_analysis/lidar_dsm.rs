@@ -12,13 +12,14 @@ use whitebox_lidar::*;
 use crate::na;
 use whitebox_raster::*;
 use whitebox_common::spatial_ref_system::esri_wkt_from_epsg;
+use whitebox_common::progress;
 use whitebox_common::structures::{BoundingBox, DistanceMetric, FixedRadiusSearch2D, Point2D};
 use crate::tools::*;
 use num_cpus;
 use std::io::{Error, ErrorKind};
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::{env, f64, fs, path, thread};
+use std::{env, f64, fs, path};
 // use rayon::prelude::*;
 
 /// This tool creates a digital surface model (DSM) from a LiDAR point cloud. A DSM reflects the elevation of the tops
@@ -167,7 +168,7 @@ impl LidarDigitalSurfaceModel {
         if e.contains(".exe") {
             short_exe += ".exe";
         }
-        let usage = format!(">>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=file.las -o=outfile.tif --returns=last --resolution=2.0 --exclude_cls='3,4,5,6,7,18' --max_triangle_edge_length=5.0", short_exe, name).replace("*", &sep);
+        let usage = format!(">>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=file.las -o=outfile.tif --resolution=2.0 --max_triangle_edge_length=5.0", short_exe, name).replace("*", &sep);
 
         LidarDigitalSurfaceModel {
             name: name,
@@ -219,6 +220,7 @@ impl WhiteboxTool for LidarDigitalSurfaceModel {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file: String = "".to_string();
         let mut output_file: String = "".to_string();
@@ -324,13 +326,7 @@ impl WhiteboxTool for LidarDigitalSurfaceModel {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let start = Instant::now();
@@ -424,7 +420,7 @@ impl WhiteboxTool for LidarDigitalSurfaceModel {
         }
 
         if verbose {
-            println!("Performing interpolation...");
+            progress::message("Performing interpolation...");
         }
 
         let num_tiles = inputs.len();
@@ -439,7 +435,7 @@ impl WhiteboxTool for LidarDigitalSurfaceModel {
             let bounding_boxes = bounding_boxes.clone();
             let tool_name = self.get_tool_name();
             let tx2 = tx2.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 for tile in (0..num_tiles).filter(|t| t % num_procs == tid) {
                     let start_run = Instant::now();
 
@@ -461,7 +457,7 @@ impl WhiteboxTool for LidarDigitalSurfaceModel {
                     let mut z_values = vec![];
 
                     if verbose && inputs.len() == 1 {
-                        println!("Reading input LiDAR file...");
+                        progress::message("Reading input LiDAR file...");
                     }
 
                     let mut progress: i32;
@@ -496,7 +492,7 @@ impl WhiteboxTool for LidarDigitalSurfaceModel {
                                             progress =
                                                 (100.0_f64 * (i + 1) as f64 / num_points) as i32;
                                             if progress != old_progress {
-                                                println!("Reading points: {}%", progress);
+                                                progress::update_stage("Reading points", progress);
                                                 old_progress = progress;
                                             }
                                         }
@@ -508,11 +504,10 @@ impl WhiteboxTool for LidarDigitalSurfaceModel {
                     }
 
                     if points.len() < 3 {
-                        println!(
-                            "Warning: No eligible points found in {}",
-                            inputs[tile].clone()
-                        );
-                        tx2.send(tile).unwrap();
+                        progress::warning(format!("No eligible points found in {}", inputs[tile].clone()));
+                        if tx2.send(tile).is_err() {
+                            return;
+                        }
                     } else {
                         let num_points = points.len();
                         let mut remove_pt = vec![false; num_points];
@@ -541,7 +536,7 @@ impl WhiteboxTool for LidarDigitalSurfaceModel {
                             if verbose && inputs.len() == 1 {
                                 progress = (100.0_f64 * (i + 1) as f64 / num_points as f64) as i32;
                                 if progress != old_progress {
-                                    println!("Filtering points: {}%", progress);
+                                    progress::update_stage("Filtering points", progress);
                                     old_progress = progress;
                                 }
                             }
@@ -598,7 +593,7 @@ impl WhiteboxTool for LidarDigitalSurfaceModel {
 
                         // do the triangulation
                         if num_tiles == 1 && verbose {
-                            println!("Performing triangulation...");
+                            progress::message("Performing triangulation...");
                         }
                         let result = triangulate(&points2).expect("No triangulation exists.");
                         let num_triangles = result.triangles.len() / 3;
@@ -675,7 +670,7 @@ impl WhiteboxTool for LidarDigitalSurfaceModel {
                                         / (num_triangles - 1) as f64)
                                         as i32;
                                     if progress != old_progress {
-                                        println!("Progress: {}%", progress);
+                                        progress::update(progress);
                                         old_progress = progress;
                                     }
                                 }
@@ -698,12 +693,14 @@ impl WhiteboxTool for LidarDigitalSurfaceModel {
                         ));
 
                         if verbose && inputs.len() == 1 {
-                            println!("Saving data...")
+                            progress::message("Saving data...")
                         };
 
                         let _ = output.write().expect("Error writing file.");
 
-                        tx2.send(tile).unwrap();
+                        if tx2.send(tile).is_err() {
+                            return;
+                        }
                     }
                 }
             });
@@ -712,33 +709,23 @@ impl WhiteboxTool for LidarDigitalSurfaceModel {
         let mut progress: i32;
         let mut old_progress: i32 = -1;
         for tile in 0..inputs.len() {
-            let tile_completed = rx2.recv().unwrap();
+            let tile_completed = cancel.recv(&rx2)?;
             if verbose {
                 if tile <= 98 {
-                    println!(
-                        "Finished {} ({} of {})",
-                        inputs[tile_completed]
+                    progress::message(format!("Finished {} ({} of {})", inputs[tile_completed]
                             .replace("\"", "")
                             .replace(working_directory, "")
-                            .replace(".las", ""),
-                        tile + 1,
-                        inputs.len()
-                    );
+                            .replace(".las", ""), tile + 1, inputs.len()));
                 } else if tile == 99 {
-                    println!(
-                        "Finished {} ({} of {})",
-                        inputs[tile_completed]
+                    progress::message(format!("Finished {} ({} of {})", inputs[tile_completed]
                             .replace("\"", "")
                             .replace(working_directory, "")
-                            .replace(".las", ""),
-                        tile + 1,
-                        inputs.len()
-                    );
-                    println!("...");
+                            .replace(".las", ""), tile + 1, inputs.len()));
+                    progress::message("...");
                 }
                 progress = (100.0_f64 * tile as f64 / (inputs.len() - 1) as f64) as i32;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    progress::update(progress);
                     old_progress = progress;
                 }
             }
@@ -747,10 +734,7 @@ impl WhiteboxTool for LidarDigitalSurfaceModel {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (including I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (including I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -7,6 +7,7 @@ License: MIT
 */
 
 use whitebox_lidar::*;
+use whitebox_common::progress;
 use crate::tools::*;
 use std::env;
 use std::f64;
@@ -173,6 +174,7 @@ impl WhiteboxTool for LidarElevationSlice {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file: String = "".to_string();
         let mut output_file: String = "".to_string();
@@ -262,9 +264,9 @@ impl WhiteboxTool for LidarElevationSlice {
         }
 
         if verbose {
-            println!("***********************************");
-            println!("* Welcome to lidar_elevation_slice *");
-            println!("************************************");
+            progress::message("***********************************");
+            progress::message("* Welcome to lidar_elevation_slice *");
+            progress::message("************************************");
         }
 
         if in_class_value > 31 || out_class_value > 31 {
@@ -283,7 +285,7 @@ impl WhiteboxTool for LidarElevationSlice {
         }
 
         if verbose {
-            println!("reading input LiDAR file...");
+            progress::message("reading input LiDAR file...");
         }
         let input: LasFile = match LasFile::new(&input_file, "r") {
             Ok(lf) => lf,
@@ -298,7 +300,7 @@ impl WhiteboxTool for LidarElevationSlice {
         output.header.system_id = "EXTRACTION".to_string();
 
         if verbose {
-            println!("Performing analysis...");
+            progress::message("Performing analysis...");
         }
         let mut z: f64;
         let mut progress: i32;
@@ -314,10 +316,11 @@ impl WhiteboxTool for LidarElevationSlice {
                     output.add_point_record(input.get_record(i));
                     num_points_filtered += 1;
                 }
+                cancel.check()?;
                 if verbose {
                     progress = (100.0_f64 * i as f64 / num_points) as i32;
                     if progress != old_progress {
-                        println!("Progress: {}%", progress);
+                        progress::update(progress);
                         old_progress = progress;
                     }
                 }
@@ -459,10 +462,11 @@ impl WhiteboxTool for LidarElevationSlice {
                     }
                 }
                 output.add_point_record(pr2);
+                cancel.check()?;
                 if verbose {
                     progress = (100.0_f64 * i as f64 / num_points) as i32;
                     if progress != old_progress {
-                        println!("Saving data: {}%", progress);
+                        progress::update_stage("Saving data", progress);
                         old_progress = progress;
                     }
                 }
@@ -472,18 +476,18 @@ impl WhiteboxTool for LidarElevationSlice {
 
         if num_points_filtered > 0 {
             if verbose {
-                println!("Writing output LAS file...");
+                progress::message("Writing output LAS file...");
             }
             let _ = match output.write() {
                 Ok(_) => {
                     if verbose {
-                        println!("Complete!")
+                        progress::message("Complete!")
                     }
                 }
-                Err(e) => println!("error while writing: {:?}", e),
+                Err(e) => progress::message(format!("error while writing: {:?}", e)),
             };
         } else {
-            println!("No points were contained in the elevation slice.");
+            progress::message("No points were contained in the elevation slice.");
         }
 
         Ok(())
@@ -8,6 +8,7 @@ License: MIT
 
 use whitebox_lidar::*;
 use whitebox_common::structures::{DistanceMetric, FixedRadiusSearch2D, Point3D};
+use whitebox_common::progress;
 use crate::tools::*;
 use num_cpus;
 use std::env;
@@ -16,7 +17,6 @@ use std::io::{Error, ErrorKind};
 use std::path;
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::thread;
 
 /// This tool can be used to perform a slope-based classification, or filtering (i.e. removal), of
 /// non-ground points within a LiDAR point-cloud. The user must specify the name of the input and output
@@ -92,7 +92,7 @@ impl LidarGroundPointFilter {
 
         parameters.push(ToolParameter {
             name: "Minimum Number of Neighbours".to_owned(),
-            flags: vec!["--min_neighbours".to_owned()],
+            flags: vec!["--min_neighbors".to_owned(), "--min_neighbours".to_owned()],
             description: "The minimum number of neighbouring points within search areas. If fewer points than this threshold are identified during the fixed-radius search, a subsequent kNN search is performed to identify the k number of neighbours.".to_owned(),
             parameter_type: ParameterType::Integer,
             default_value: Some("0".to_owned()),
@@ -211,6 +211,7 @@ impl WhiteboxTool for LidarGroundPointFilter {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file: String = "".to_string();
         let mut output_file: String = "".to_string();
@@ -319,13 +320,7 @@ impl WhiteboxTool for LidarGroundPointFilter {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep = path::MAIN_SEPARATOR;
@@ -337,7 +332,7 @@ impl WhiteboxTool for LidarGroundPointFilter {
         }
 
         if verbose {
-            println!("reading input LiDAR file...");
+            progress::message("reading input LiDAR file...");
         }
         let input = match LasFile::new(&input_file, "r") {
             Ok(lf) => lf,
@@ -347,11 +342,11 @@ impl WhiteboxTool for LidarGroundPointFilter {
         let start = Instant::now();
 
         if verbose {
-            println!("Performing analysis...");
+            progress::message("Performing analysis...");
         }
 
         if slope_threshold > 88f64 {
-            eprintln!("Warning: the slope threshold cannot be greater than 88 degrees.");
+            progress::warning("The slope threshold cannot be greater than 88 degrees.");
             slope_threshold = 88f64;
         }
 
@@ -377,10 +372,11 @@ impl WhiteboxTool for LidarGroundPointFilter {
                     residuals[i] = p.z;
                 }
             }
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * i as f64 / num_points) as i32;
                 if progress != old_progress {
-                    println!("Binning points: {}%", progress);
+                    progress::update_stage("Binning points", progress);
                     old_progress = progress;
                 }
             }
@@ -400,7 +396,7 @@ impl WhiteboxTool for LidarGroundPointFilter {
                 let frs = frs.clone();
                 let input = input.clone();
                 let tx = tx.clone();
-                thread::spawn(move || {
+                progress::spawn(move || {
                     let mut index_n: usize;
                     let mut z_n: f64;
                     let mut min_z: f64;
@@ -419,21 +415,25 @@ impl WhiteboxTool for LidarGroundPointFilter {
                                     min_z = z_n;
                                 }
                             }
-                            tx.send((point_num, min_z)).unwrap();
+                            if tx.send((point_num, min_z)).is_err() {
+                                return;
+                            }
                         } else {
-                            tx.send((point_num, f64::MAX)).unwrap();
+                            if tx.send((point_num, f64::MAX)).is_err() {
+                                return;
+                            }
                         }
                     }
                 });
             }
 
             for i in 0..n_points {
-                let data = rx.recv().expect("Error receiving data from thread.");
+                let data = cancel.recv(&rx)?;
                 neighbourhood_min[data.0] = data.1;
                 if verbose {
                     progress = (100.0_f64 * i as f64 / num_points) as i32;
                     if progress != old_progress {
-                        println!("Erosion: {}%", progress);
+                        progress::update_stage("Erosion", progress);
                         old_progress = progress;
                     }
                 }
@@ -448,7 +448,7 @@ impl WhiteboxTool for LidarGroundPointFilter {
                 let input = input.clone();
                 let neighbourhood_min = neighbourhood_min.clone();
                 let tx = tx.clone();
-                thread::spawn(move || {
+                progress::spawn(move || {
                     let mut index_n: usize;
                     let mut z_n: f64;
                     let mut max_z: f64;
@@ -467,16 +467,20 @@ impl WhiteboxTool for LidarGroundPointFilter {
                                     max_z = z_n;
                                 }
                             }
-                            tx.send((point_num, max_z)).unwrap();
+                            if tx.send((point_num, max_z)).is_err() {
+                                return;
+                            }
                         } else {
-                            tx.send((point_num, f64::MIN)).unwrap();
+                            if tx.send((point_num, f64::MIN)).is_err() {
+                                return;
+                            }
                         }
                     }
                 });
             }
 
             for i in 0..n_points {
-                let data = rx.recv().expect("Error receiving data from thread.");
+                let data = cancel.recv(&rx)?;
                 if data.1 != f64::MIN {
                     let z = input.get_transformed_coords(data.0).z;
                     residuals[data.0] = z - data.1;
@@ -484,7 +488,7 @@ impl WhiteboxTool for LidarGroundPointFilter {
                 if verbose {
                     progress = (100.0_f64 * i as f64 / num_points) as i32;
                     if progress != old_progress {
-                        println!("Dilation: {}%", progress);
+                        progress::update_stage("Dilation", progress);
                         old_progress = progress;
                     }
                 }
@@ -501,7 +505,7 @@ impl WhiteboxTool for LidarGroundPointFilter {
             let input = input.clone();
             let residuals = residuals.clone();
             let tx = tx.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let mut index_n: usize;
                 let mut max_slope: f64;
                 let mut slope: f64;
@@ -530,24 +534,30 @@ impl WhiteboxTool for LidarGroundPointFilter {
                             }
                         }
                         if max_slope > slope_threshold {
-                            tx.send((point_num, true)).unwrap();
+                            if tx.send((point_num, true)).is_err() {
+                                return;
+                            }
                         } else {
-                            tx.send((point_num, false)).unwrap();
+                            if tx.send((point_num, false)).is_err() {
+                                return;
+                            }
                         }
                     } else {
-                        tx.send((point_num, true)).unwrap();
+                        if tx.send((point_num, true)).is_err() {
+                            return;
+                        }
                     }
                 }
             });
         }
 
         for i in 0..n_points {
-            let data = rx.recv().expect("Error receiving data from thread.");
+            let data = cancel.recv(&rx)?;
             is_off_terrain[data.0] = data.1;
             if verbose {
                 progress = (100.0_f64 * i as f64 / num_points) as i32;
                 if progress != old_progress {
-                    println!("Slope-based Filter: {}%", progress);
+                    progress::update_stage("Slope-based Filter", progress);
                     old_progress = progress;
                 }
             }
@@ -569,10 +579,11 @@ impl WhiteboxTool for LidarGroundPointFilter {
                 } else {
                     num_points_filtered += 1;
                 }
+                cancel.check()?;
                 if verbose {
                     progress = (100.0_f64 * point_num as f64 / num_points) as i32;
                     if progress != old_progress {
-                        println!("Saving data: {}%", progress);
+                        progress::update_stage("Saving data", progress);
                         old_progress = progress;
                     }
                 }
@@ -764,10 +775,11 @@ impl WhiteboxTool for LidarGroundPointFilter {
                     // Keep the classes of classified noise unaltered
                     output.add_point_record(input.get_record(point_num));
                 }
+                cancel.check()?;
                 if verbose {
                     progress = (100.0_f64 * point_num as f64 / num_points) as i32;
                     if progress != old_progress {
-                        println!("Saving data: {}%", progress);
+                        progress::update_stage("Saving data", progress);
                         old_progress = progress;
                     }
                 }
@@ -776,27 +788,24 @@ impl WhiteboxTool for LidarGroundPointFilter {
         }
 
         if num_points_filtered == 0 {
-            println!("Warning: No points were filtered from the point cloud.");
+            progress::warning("No points were filtered from the point cloud.");
         }
 
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!("Writing output LAS file...");
+            progress::message("Writing output LAS file...");
         }
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Complete!")
+                    progress::message("Complete!")
                 }
             }
-            Err(e) => println!("error while writing: {:?}", e),
+            Err(e) => progress::message(format!("error while writing: {:?}", e)),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -8,6 +8,7 @@ License: MIT
 
 use whitebox_lidar::*;
 use whitebox_common::structures::{BoundingBox, DistanceMetric, FixedRadiusSearch2D, Point2D, Point3D};
+use whitebox_common::progress;
 use crate::tools::*;
 use whitebox_vector::ShapefileGeometry;
 use whitebox_vector::*;
@@ -164,6 +165,7 @@ impl WhiteboxTool for LidarHexBinning {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file: String = "".to_string();
         let mut output_file: String = "".to_string();
@@ -235,13 +237,7 @@ impl WhiteboxTool for LidarHexBinning {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         if width <= 0f64 {
@@ -373,10 +369,11 @@ impl WhiteboxTool for LidarHexBinning {
                         max_i[hex_index] = pd.intensity as i32;
                     }
                 }
+                cancel.check()?;
                 if verbose {
                     progress = (100.0_f64 * i as f64 / num_points as f64) as usize;
                     if progress != old_progress {
-                        println!("Binning points: {}%", progress);
+                        progress::update_stage("Binning points", progress);
                         old_progress = progress;
                     }
                 }
@@ -420,10 +417,11 @@ impl WhiteboxTool for LidarHexBinning {
                     rec_num += 1i32;
                 }
 
+                cancel.check()?;
                 if verbose {
                     progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Progress: {}%", progress);
+                        progress::update(progress);
                         old_progress = progress;
                     }
                 }
@@ -483,10 +481,11 @@ impl WhiteboxTool for LidarHexBinning {
                         max_i[hex_index] = pd.intensity as i32;
                     }
                 }
+                cancel.check()?;
                 if verbose {
                     progress = (100.0_f64 * i as f64 / num_points as f64) as usize;
                     if progress != old_progress {
-                        println!("Binning points: {}%", progress);
+                        progress::update_stage("Binning points", progress);
                         old_progress = progress;
                     }
                 }
@@ -529,10 +528,11 @@ impl WhiteboxTool for LidarHexBinning {
                     rec_num += 1i32;
                 }
 
+                cancel.check()?;
                 if verbose {
                     progress = (100.0_f64 * col as f64 / (columns - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Progress: {}%", progress);
+                        progress::update(progress);
                         old_progress = progress;
                     }
                 }
@@ -540,12 +540,12 @@ impl WhiteboxTool for LidarHexBinning {
         }
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
@@ -554,7 +554,7 @@ impl WhiteboxTool for LidarHexBinning {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!("{}", &format!("Elapsed Time: {}", elapsed_time));
+            progress::message(format!("Elapsed Time: {}", elapsed_time));
         }
 
         Ok(())
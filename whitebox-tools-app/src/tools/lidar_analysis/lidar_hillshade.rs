@@ -10,6 +10,7 @@ use self::na::Vector3;
 use whitebox_lidar::*;
 use crate::na;
 use whitebox_common::structures::{DistanceMetric, FixedRadiusSearch3D};
+use whitebox_common::progress;
 use crate::tools::*;
 use num_cpus;
 use std::env;
@@ -18,7 +19,6 @@ use std::io::{Error, ErrorKind};
 use std::path;
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::thread;
 
 pub struct LidarHillshade {
     name: String,
@@ -147,6 +147,7 @@ impl WhiteboxTool for LidarHillshade {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file: String = "".to_string();
         let mut output_file: String = "".to_string();
@@ -224,13 +225,7 @@ impl WhiteboxTool for LidarHillshade {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         azimuth = (azimuth - 90f64).to_radians();
@@ -247,7 +242,7 @@ impl WhiteboxTool for LidarHillshade {
         }
 
         if verbose {
-            println!("reading input LiDAR file...");
+            progress::message("reading input LiDAR file...");
         }
         let input = match LasFile::new(&input_file, "r") {
             Ok(lf) => lf,
@@ -257,7 +252,7 @@ impl WhiteboxTool for LidarHillshade {
         let start = Instant::now();
 
         if verbose {
-            println!("Performing analysis...");
+            progress::message("Performing analysis...");
         }
 
         let n_points = input.header.number_of_points as usize;
@@ -270,10 +265,11 @@ impl WhiteboxTool for LidarHillshade {
         for i in 0..n_points {
             let p = input.get_transformed_coords(i);
             frs.insert(p.x, p.y, p.z, i);
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * i as f64 / num_points) as i32;
                 if progress != old_progress {
-                    println!("Binning points: {}%", progress);
+                    progress::update_stage("Binning points", progress);
                     old_progress = progress;
                 }
             }
@@ -289,7 +285,7 @@ impl WhiteboxTool for LidarHillshade {
             let frs = frs.clone();
             let input = input.clone();
             let tx = tx.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let mut index_n: usize;
                 for i in (0..n_points).filter(|point_num| point_num % num_procs == tid) {
                     let p = input.get_transformed_coords(i);
@@ -300,18 +296,20 @@ impl WhiteboxTool for LidarHillshade {
                         let p2 = input.get_transformed_coords(index_n);
                         data.push(Vector3::new(p2.x, p2.y, p2.z));
                     }
-                    tx.send((i, plane_from_points(&data))).unwrap();
+                    if tx.send((i, plane_from_points(&data))).is_err() {
+                        return;
+                    }
                 }
             });
         }
 
         for i in 0..n_points {
-            let data = rx.recv().expect("Error receiving data from thread.");
+            let data = cancel.recv(&rx)?;
             normal_values[data.0] = data.1;
             if verbose {
                 progress = (100.0_f64 * i as f64 / num_points) as i32;
                 if progress != old_progress {
-                    println!("Calculating point normals: {}%", progress);
+                    progress::update_stage("Calculating point normals", progress);
                     old_progress = progress;
                 }
             }
@@ -361,10 +359,11 @@ impl WhiteboxTool for LidarHillshade {
                 colour_data: rgb,
             };
             output.add_point_record(lpr);
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * i as f64 / num_points) as i32;
                 if progress != old_progress {
-                    println!("Saving data: {}%", progress);
+                    progress::update_stage("Saving data", progress);
                     old_progress = progress;
                 }
             }
@@ -372,23 +371,20 @@ impl WhiteboxTool for LidarHillshade {
 
         let elapsed_time = get_formatted_elapsed_time(start);
 
-        println!("");
+        progress::message("");
         if verbose {
-            println!("Writing output LAS file...");
+            progress::message("Writing output LAS file...");
         }
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Complete!")
+                    progress::message("Complete!")
                 }
             }
-            Err(e) => println!("error while writing: {:?}", e),
+            Err(e) => progress::message(format!("error while writing: {:?}", e)),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -11,6 +11,7 @@ use whitebox_common::rendering::html::*;
 use whitebox_common::rendering::Histogram;
 use crate::tools::*;
 use whitebox_common::structures::Point3D;
+use whitebox_common::progress;
 use std::env;
 use std::f64;
 use std::fs::File;
@@ -107,7 +108,7 @@ impl LidarHistogram {
         if e.contains(".exe") {
             short_exe += ".exe";
         }
-        let usage = format!(">>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=\"file1.tif, file2.tif, file3.tif\" -o=outfile.htm --contiguity=Bishopsl",
+        let usage = format!(">>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=\"file1.tif, file2.tif, file3.tif\" -o=outfile.htm",
                             short_exe, name).replace("*", &sep);
 
         LidarHistogram {
@@ -160,6 +161,7 @@ impl WhiteboxTool for LidarHistogram {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut output_file = String::new();
@@ -217,13 +219,7 @@ impl WhiteboxTool for LidarHistogram {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -241,7 +237,7 @@ impl WhiteboxTool for LidarHistogram {
         }
 
         if verbose {
-            println!("reading input LiDAR file...");
+            progress::message("reading input LiDAR file...");
         }
         let input = match LasFile::new(&input_file, "r") {
             Ok(lf) => lf,
@@ -259,7 +255,7 @@ impl WhiteboxTool for LidarHistogram {
             "class" => 3,
             "time" => 4,
             _ => {
-                println!("Warning: unrecognized parameter; elevation will be used");
+                progress::warning("unrecognized parameter; elevation will be used");
                 0 // elevation
             }
         };
@@ -289,10 +285,11 @@ impl WhiteboxTool for LidarHistogram {
             if z > max {
                 max = z;
             }
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * i as f64 / num_points) as i32;
                 if progress != old_progress {
-                    println!("Calculating min and max: {}%", progress);
+                    progress::update_stage("Calculating min and max", progress);
                     old_progress = progress;
                 }
             }
@@ -317,10 +314,11 @@ impl WhiteboxTool for LidarHistogram {
                 };
                 bin = ((z - min) / bin_width).floor() as isize;
                 freq_data[bin as usize] += 1;
+                cancel.check()?;
                 if verbose {
                     progress = (100.0_f64 * i as f64 / num_points) as i32;
                     if progress != old_progress {
-                        println!("Clipping the tails: {}%", progress);
+                        progress::update_stage("Clipping the tails", progress);
                         old_progress = progress;
                     }
                 }
@@ -383,10 +381,11 @@ impl WhiteboxTool for LidarHistogram {
                 if bin >= 0 && bin < num_bins as isize {
                     freq_data[bin as usize] += 1;
                 }
+                cancel.check()?;
                 if verbose {
                     progress = (100.0_f64 * i as f64 / num_points) as i32;
                     if progress != old_progress {
-                        println!("Binning the data: {}%", progress);
+                        progress::update_stage("Binning the data", progress);
                         old_progress = progress;
                     }
                 }
@@ -404,10 +403,11 @@ impl WhiteboxTool for LidarHistogram {
                 if bin >= 0 && bin < num_bins as isize {
                     freq_data[bin as usize] += 1;
                 }
+                cancel.check()?;
                 if verbose {
                     progress = (100.0_f64 * i as f64 / num_points) as i32;
                     if progress != old_progress {
-                        println!("Binning the data: {}%", progress);
+                        progress::update_stage("Binning the data", progress);
                         old_progress = progress;
                     }
                 }
@@ -477,10 +477,7 @@ impl WhiteboxTool for LidarHistogram {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!(
-                "\n{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("\n{}", &format!("Elapsed Time (excluding I/O): {}", elapsed_time)));
         }
 
         if verbose {
@@ -508,7 +505,7 @@ impl WhiteboxTool for LidarHistogram {
                 let _ = output.stdout;
             }
             if verbose {
-                println!("Complete! Please see {} for output.", output_file);
+                progress::message(format!("Complete! Please see {} for output.", output_file));
             }
         }
 
@@ -7,6 +7,7 @@ License: MIT
 */
 
 use whitebox_lidar::*;
+use whitebox_common::progress;
 use crate::tools::*;
 // use whitebox_common::structures::Point3D;
 use std;
@@ -159,6 +160,7 @@ impl WhiteboxTool for LidarInfo {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        _cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file: String = "".to_string();
         let mut output_file = String::new();
@@ -211,13 +213,7 @@ impl WhiteboxTool for LidarInfo {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep = std::path::MAIN_SEPARATOR;
@@ -550,7 +546,7 @@ impl WhiteboxTool for LidarInfo {
                 let _ = output.stdout;
             }
             if verbose {
-                println!("Complete! Please see {} for output.", output_file);
+                progress::message(format!("Complete! Please see {} for output.", output_file));
             }
         }
 
@@ -7,6 +7,7 @@ License: MIT
 */
 
 use whitebox_lidar::*;
+use whitebox_common::progress;
 use crate::tools::*;
 use std;
 use std::env;
@@ -118,6 +119,7 @@ impl WhiteboxTool for LidarJoin {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_files: String = String::new();
         let mut output_file = String::new();
@@ -156,13 +158,7 @@ impl WhiteboxTool for LidarJoin {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep = std::path::MAIN_SEPARATOR;
@@ -183,6 +179,7 @@ impl WhiteboxTool for LidarJoin {
         let num_files = vec.len();
         let mut file_format = -1i32;
         for value in vec {
+            cancel.check()?;
             if !value.trim().is_empty() {
                 let mut input_file = value.trim().to_owned();
                 if !input_file.contains(sep) && !input_file.contains("/") {
@@ -224,20 +221,20 @@ impl WhiteboxTool for LidarJoin {
             }
             i += 1;
             if verbose {
-                println!("Adding file: {} of {}", i, num_files);
+                progress::message(format!("Adding file: {} of {}", i, num_files));
             }
         }
 
         if verbose {
-            println!("Writing output LAS file...");
+            progress::message("Writing output LAS file...");
         }
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Complete!")
+                    progress::message("Complete!")
                 }
             }
-            Err(e) => println!("error while writing: {:?}", e),
+            Err(e) => progress::message(format!("error while writing: {:?}", e)),
         };
         // if verbose {
         //     println!(
@@ -9,6 +9,7 @@ License: MIT
 use whitebox_lidar::*;
 use whitebox_raster::*;
 use whitebox_common::structures::{Array2D, DistanceMetric, FixedRadiusSearch3D, Point3D};
+use whitebox_common::progress;
 use crate::tools::*;
 use std::env;
 use std::f64;
@@ -159,6 +160,7 @@ impl WhiteboxTool for LidarKappaIndex {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file1 = String::new();
         let mut input_file2 = String::new();
@@ -223,13 +225,7 @@ impl WhiteboxTool for LidarKappaIndex {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -254,7 +250,7 @@ impl WhiteboxTool for LidarKappaIndex {
         }
 
         if verbose {
-            println!("Reading data...")
+            progress::message("Reading data...")
         };
         let start = Instant::now();
 
@@ -317,10 +313,11 @@ impl WhiteboxTool for LidarKappaIndex {
             // p1 = input2.get_point_info(i);
             p1 = input2.get_transformed_coords(i);
             frs.insert(p1.x, p1.y, p1.z, i);
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * i as f64 / num_points2 as f64) as i32;
                 if progress != old_progress {
-                    println!("Binning points: {}%", progress);
+                    progress::update_stage("Binning points", progress);
                     old_progress = progress;
                 }
             }
@@ -354,10 +351,11 @@ impl WhiteboxTool for LidarKappaIndex {
                 }
             }
 
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * i as f64 / num_points1 as f64) as i32;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    progress::update(progress);
                     old_progress = progress;
                 }
             }
@@ -375,10 +373,11 @@ impl WhiteboxTool for LidarKappaIndex {
                     class_accuracy.set_value(row, col, nodata);
                 }
             }
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as i32;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    progress::update(progress);
                     old_progress = progress;
                 }
             }
@@ -702,7 +701,7 @@ impl WhiteboxTool for LidarKappaIndex {
                 let _ = output.stdout;
             }
 
-            println!("Complete!\nPlease see {} for output.", output_file);
+            progress::message(format!("Complete!\nPlease see {} for output.", output_file));
         }
 
         let elapsed_time = get_formatted_elapsed_time(start);
@@ -716,22 +715,19 @@ impl WhiteboxTool for LidarKappaIndex {
             .add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match class_accuracy.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&class_accuracy.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "\n{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("\n{}", &format!("Elapsed Time (excluding I/O): {}", elapsed_time)));
         }
 
         Ok(())
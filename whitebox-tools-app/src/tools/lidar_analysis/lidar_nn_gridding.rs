@@ -14,6 +14,7 @@ NOTES:
 use whitebox_lidar::*;
 use whitebox_raster::*;
 use whitebox_common::structures::{BoundingBox, DistanceMetric, FixedRadiusSearch2D};
+use whitebox_common::progress;
 use crate::tools::*;
 use num_cpus;
 use std::env;
@@ -23,7 +24,6 @@ use std::io::{Error, ErrorKind};
 use std::path;
 use std::sync::mpsc;
 use std::sync::{Arc, Mutex};
-use std::thread;
 
 /// This tool grids LiDAR files using nearest-neighbour (NN) scheme, that is, each grid cell in the output image will
 /// be assigned the parameter value of the point nearest the grid cell centre. This method should not be confused
@@ -186,6 +186,15 @@ impl LidarNearestNeighbourGridding {
             optional: true,
         });
 
+        parameters.push(ToolParameter {
+            name: "Palette Name (Whitebox raster outputs only)".to_owned(),
+            flags: vec!["--palette".to_owned()],
+            description: "Optional palette name (for use with Whitebox raster files).".to_owned(),
+            parameter_type: ParameterType::String,
+            default_value: None,
+            optional: true,
+        });
+
         let sep: String = path::MAIN_SEPARATOR.to_string();
         let e = format!("{}", env::current_exe().unwrap().display());
         let mut parent = env::current_exe().unwrap();
@@ -252,6 +261,7 @@ impl WhiteboxTool for LidarNearestNeighbourGridding {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file: String = "".to_string();
         let mut output_file: String = "".to_string();
@@ -384,13 +394,7 @@ impl WhiteboxTool for LidarNearestNeighbourGridding {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let start = Instant::now();
@@ -499,7 +503,7 @@ impl WhiteboxTool for LidarNearestNeighbourGridding {
         }
 
         if verbose {
-            println!("Performing interpolation...");
+            progress::message("Performing interpolation...");
         }
 
         let num_tiles = inputs.len();
@@ -527,7 +531,7 @@ impl WhiteboxTool for LidarNearestNeighbourGridding {
             let exclude_cls_str = exclude_cls_str.clone();
             let include_class_vals = include_class_vals.clone();
             let tx2 = tx2.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let mut tile = 0;
                 while tile < num_tiles {
                     // Get the next tile up for interpolation
@@ -554,7 +558,7 @@ impl WhiteboxTool for LidarNearestNeighbourGridding {
                         FixedRadiusSearch2D::new(search_radius, DistanceMetric::SquaredEuclidean);
 
                     if verbose && inputs.len() == 1 {
-                        println!("reading input LiDAR file...");
+                        progress::message("reading input LiDAR file...");
                     }
 
                     let mut progress: i32;
@@ -598,7 +602,7 @@ impl WhiteboxTool for LidarNearestNeighbourGridding {
                                         if verbose && inputs.len() == 1 {
                                             progress = (100.0_f64 * i as f64 / num_points) as i32;
                                             if progress != old_progress {
-                                                println!("Binning points: {}%", progress);
+                                                progress::update_stage("Binning points", progress);
                                                 old_progress = progress;
                                             }
                                         }
@@ -626,7 +630,7 @@ impl WhiteboxTool for LidarNearestNeighbourGridding {
                                         if verbose && inputs.len() == 1 {
                                             progress = (100.0_f64 * i as f64 / num_points) as i32;
                                             if progress != old_progress {
-                                                println!("Binning points: {}%", progress);
+                                                progress::update_stage("Binning points", progress);
                                                 old_progress = progress;
                                             }
                                         }
@@ -654,7 +658,7 @@ impl WhiteboxTool for LidarNearestNeighbourGridding {
                                         if verbose && inputs.len() == 1 {
                                             progress = (100.0_f64 * i as f64 / num_points) as i32;
                                             if progress != old_progress {
-                                                println!("Binning points: {}%", progress);
+                                                progress::update_stage("Binning points", progress);
                                                 old_progress = progress;
                                             }
                                         }
@@ -686,7 +690,7 @@ impl WhiteboxTool for LidarNearestNeighbourGridding {
                                         if verbose && inputs.len() == 1 {
                                             progress = (100.0_f64 * i as f64 / num_points) as i32;
                                             if progress != old_progress {
-                                                println!("Binning points: {}%", progress);
+                                                progress::update_stage("Binning points", progress);
                                                 old_progress = progress;
                                             }
                                         }
@@ -718,7 +722,7 @@ impl WhiteboxTool for LidarNearestNeighbourGridding {
                                         if verbose && inputs.len() == 1 {
                                             progress = (100.0_f64 * i as f64 / num_points) as i32;
                                             if progress != old_progress {
-                                                println!("Reading points: {}%", progress);
+                                                progress::update_stage("Reading points", progress);
                                                 old_progress = progress;
                                             }
                                         }
@@ -750,7 +754,7 @@ impl WhiteboxTool for LidarNearestNeighbourGridding {
                                         if verbose && inputs.len() == 1 {
                                             progress = (100.0_f64 * i as f64 / num_points) as i32;
                                             if progress != old_progress {
-                                                println!("Reading points: {}%", progress);
+                                                progress::update_stage("Reading points", progress);
                                                 old_progress = progress;
                                             }
                                         }
@@ -758,7 +762,7 @@ impl WhiteboxTool for LidarNearestNeighbourGridding {
                                 }
                                 "rgb" => {
                                     if !input.has_rgb() {
-                                        println!("Error: The input LAS file does not contain RGB colour data. The interpolation will not proceed.");
+                                        progress::message("Error: The input LAS file does not contain RGB colour data. The interpolation will not proceed.");
                                         break;
                                     }
                                     let mut clr: ColourData;
@@ -795,7 +799,7 @@ impl WhiteboxTool for LidarNearestNeighbourGridding {
                                         if verbose && inputs.len() == 1 {
                                             progress = (100.0_f64 * i as f64 / num_points) as i32;
                                             if progress != old_progress {
-                                                println!("Reading points: {}%", progress);
+                                                progress::update_stage("Reading points", progress);
                                                 old_progress = progress;
                                             }
                                         }
@@ -825,7 +829,7 @@ impl WhiteboxTool for LidarNearestNeighbourGridding {
                                         if verbose && inputs.len() == 1 {
                                             progress = (100.0_f64 * i as f64 / num_points) as i32;
                                             if progress != old_progress {
-                                                println!("Binning points: {}%", progress);
+                                                progress::update_stage("Binning points", progress);
                                                 old_progress = progress;
                                             }
                                         }
@@ -854,7 +858,7 @@ impl WhiteboxTool for LidarNearestNeighbourGridding {
                                         if verbose && inputs.len() == 1 {
                                             progress = (100.0_f64 * i as f64 / num_points) as i32;
                                             if progress != old_progress {
-                                                println!("Binning points: {}%", progress);
+                                                progress::update_stage("Binning points", progress);
                                                 old_progress = progress;
                                             }
                                         }
@@ -865,8 +869,10 @@ impl WhiteboxTool for LidarNearestNeighbourGridding {
                     }
 
                     if frs.size() == 0 {
-                        println!("No points found in {}", inputs[tile].clone());
-                        tx2.send(tile).unwrap();
+                        progress::message(format!("No points found in {}", inputs[tile].clone()));
+                        if tx2.send(tile).is_err() {
+                            return;
+                        }
                     } else {
                         let west: f64 = bounding_boxes[tile].min_x;
                         let north: f64 = bounding_boxes[tile].max_y;
@@ -932,7 +938,7 @@ impl WhiteboxTool for LidarNearestNeighbourGridding {
                                 if verbose && inputs.len() == 1 {
                                     progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as i32;
                                     if progress != old_progress {
-                                        println!("Progress: {}%", progress);
+                                        progress::update(progress);
                                         old_progress = progress;
                                     }
                                 }
@@ -950,7 +956,7 @@ impl WhiteboxTool for LidarNearestNeighbourGridding {
                             for tid in 0..num_procs {
                                 let frs = frs.clone();
                                 let tx1 = tx.clone();
-                                thread::spawn(move || {
+                                progress::spawn(move || {
                                     let (mut x, mut y): (f64, f64);
                                     let mut zn: f64;
                                     let mut dist: f64;
@@ -976,7 +982,9 @@ impl WhiteboxTool for LidarNearestNeighbourGridding {
                                                 data[col as usize] = val;
                                             }
                                         }
-                                        tx1.send((row, data)).unwrap();
+                                        if tx1.send((row, data)).is_err() {
+                                            return;
+                                        }
                                     }
                                 });
                             }
@@ -987,7 +995,7 @@ impl WhiteboxTool for LidarNearestNeighbourGridding {
                                 if verbose {
                                     progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as i32;
                                     if progress != old_progress {
-                                        println!("Progress: {}%", progress);
+                                        progress::update(progress);
                                         old_progress = progress;
                                     }
                                 }
@@ -1015,12 +1023,14 @@ impl WhiteboxTool for LidarNearestNeighbourGridding {
                         ));
 
                         if verbose && inputs.len() == 1 {
-                            println!("Saving data...")
+                            progress::message("Saving data...")
                         };
 
                         let _ = output.write().unwrap();
 
-                        tx2.send(tile).unwrap();
+                        if tx2.send(tile).is_err() {
+                            return;
+                        }
                     }
                 }
             });
@@ -1029,33 +1039,23 @@ impl WhiteboxTool for LidarNearestNeighbourGridding {
         let mut progress: i32;
         let mut old_progress: i32 = -1;
         for tile in 0..inputs.len() {
-            let tile_completed = rx2.recv().unwrap();
+            let tile_completed = cancel.recv(&rx2)?;
             if verbose {
                 if tile <= 98 {
-                    println!(
-                        "Finished {} ({} of {})",
-                        inputs[tile_completed]
+                    progress::message(format!("Finished {} ({} of {})", inputs[tile_completed]
                             .replace("\"", "")
                             .replace(working_directory, "")
-                            .replace(".las", ""),
-                        tile + 1,
-                        inputs.len()
-                    );
+                            .replace(".las", ""), tile + 1, inputs.len()));
                 } else if tile == 99 {
-                    println!(
-                        "Finished {} ({} of {})",
-                        inputs[tile_completed]
+                    progress::message(format!("Finished {} ({} of {})", inputs[tile_completed]
                             .replace("\"", "")
                             .replace(working_directory, "")
-                            .replace(".las", ""),
-                        tile + 1,
-                        inputs.len()
-                    );
-                    println!("...");
+                            .replace(".las", ""), tile + 1, inputs.len()));
+                    progress::message("...");
                 }
                 progress = (100.0_f64 * tile as f64 / (inputs.len() - 1) as f64) as i32;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    progress::update(progress);
                     old_progress = progress;
                 }
             }
@@ -1064,10 +1064,7 @@ impl WhiteboxTool for LidarNearestNeighbourGridding {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (including I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (including I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -8,6 +8,7 @@ License: MIT
 
 use whitebox_lidar::*;
 use whitebox_common::structures::{DistanceMetric, FixedRadiusSearch2D, Point3D};
+use whitebox_common::progress;
 use crate::tools::*;
 use num_cpus;
 use std::cmp::Ordering::Equal;
@@ -17,7 +18,6 @@ use std::io::{Error, ErrorKind};
 use std::path;
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::thread;
 
 /// This tool will filter out points from a LiDAR point cloud if the absolute elevation
 /// difference between a point and the averge elevation of its neighbourhood, calculated
@@ -159,6 +159,7 @@ impl WhiteboxTool for LidarRemoveOutliers {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file: String = "".to_string();
         let mut output_file: String = "".to_string();
@@ -233,13 +234,7 @@ impl WhiteboxTool for LidarRemoveOutliers {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep = path::MAIN_SEPARATOR;
@@ -251,7 +246,7 @@ impl WhiteboxTool for LidarRemoveOutliers {
         }
 
         if verbose {
-            println!("reading input LiDAR file...");
+            progress::message("reading input LiDAR file...");
         }
         let input = match LasFile::new(&input_file, "r") {
             Ok(lf) => lf,
@@ -261,7 +256,7 @@ impl WhiteboxTool for LidarRemoveOutliers {
         let start = Instant::now();
 
         if verbose {
-            println!("Performing analysis...");
+            progress::message("Performing analysis...");
         }
 
         let n_points = input.header.number_of_points as usize;
@@ -279,10 +274,11 @@ impl WhiteboxTool for LidarRemoveOutliers {
             if !pd.is_classified_noise() && !pd.withheld() {
                 frs.insert(p.x, p.y, p.z);
             }
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * i as f64 / num_points) as i32;
                 if progress != old_progress {
-                    println!("Adding points to search tree: {}%", progress);
+                    progress::update_stage("Adding points to search tree", progress);
                     old_progress = progress;
                 }
             }
@@ -297,7 +293,7 @@ impl WhiteboxTool for LidarRemoveOutliers {
                 let frs = frs.clone();
                 let input = input.clone();
                 let tx = tx.clone();
-                thread::spawn(move || {
+                progress::spawn(move || {
                     let mut avg_z: f64;
                     let mut n: f64;
                     let mut p: Point3D;
@@ -314,9 +310,13 @@ impl WhiteboxTool for LidarRemoveOutliers {
                             }
                         }
                         if n > 0f64 {
-                            tx.send((point_num, p.z - avg_z / n)).unwrap();
+                            if tx.send((point_num, p.z - avg_z / n)).is_err() {
+                                return;
+                            }
                         } else {
-                            tx.send((point_num, p.z)).unwrap();
+                            if tx.send((point_num, p.z)).is_err() {
+                                return;
+                            }
                         }
                     }
                 });
@@ -326,7 +326,7 @@ impl WhiteboxTool for LidarRemoveOutliers {
                 let frs = frs.clone();
                 let input = input.clone();
                 let tx = tx.clone();
-                thread::spawn(move || {
+                progress::spawn(move || {
                     let mut n: usize;
                     let mut median: f64;
                     let mut p: Point3D;
@@ -351,13 +351,19 @@ impl WhiteboxTool for LidarRemoveOutliers {
                                 // even num neighbours
                                 median = (z_values[n / 2 - 1] + z_values[n / 2]) / 2f64;
                             }
-                            tx.send((point_num, p.z - median)).unwrap();
+                            if tx.send((point_num, p.z - median)).is_err() {
+                                return;
+                            }
                         } else if n == 2 {
                             median = (z_values[0] + z_values[1]) / 2f64;
-                            tx.send((point_num, p.z - median)).unwrap();
+                            if tx.send((point_num, p.z - median)).is_err() {
+                                return;
+                            }
                         } else {
                             // n == 0 or n == 1 {
-                            tx.send((point_num, p.z)).unwrap();
+                            if tx.send((point_num, p.z)).is_err() {
+                                return;
+                            }
                         }
                     }
                 });
@@ -366,12 +372,12 @@ impl WhiteboxTool for LidarRemoveOutliers {
 
         let mut residuals = vec![0f64; n_points];
         for i in 0..n_points {
-            let data = rx.recv().expect("Error receiving data from thread.");
+            let data = cancel.recv(&rx)?;
             residuals[data.0] = data.1;
             if verbose {
                 progress = (100.0_f64 * i as f64 / num_points) as i32;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    progress::update(progress);
                     old_progress = progress;
                 }
             }
@@ -389,10 +395,11 @@ impl WhiteboxTool for LidarRemoveOutliers {
                 } else {
                     num_points_filtered += 1;
                 }
+                cancel.check()?;
                 if verbose {
                     progress = (100.0_f64 * i as f64 / num_points) as i32;
                     if progress != old_progress {
-                        println!("Saving data: {}%", progress);
+                        progress::update_stage("Saving data", progress);
                         old_progress = progress;
                     }
                 }
@@ -536,10 +543,11 @@ impl WhiteboxTool for LidarRemoveOutliers {
                     }
                 }
                 output.add_point_record(pr2);
+                cancel.check()?;
                 if verbose {
                     progress = (100.0_f64 * point_num as f64 / num_points) as i32;
                     if progress != old_progress {
-                        println!("Saving data: {}%", progress);
+                        progress::update_stage("Saving data", progress);
                         old_progress = progress;
                     }
                 }
@@ -548,27 +556,24 @@ impl WhiteboxTool for LidarRemoveOutliers {
         }
 
         if num_points_filtered == 0 {
-            println!("Warning: No outlier points were filtered from the point cloud.");
+            progress::warning("No outlier points were filtered from the point cloud.");
         }
 
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!("Writing output LAS file...");
+            progress::message("Writing output LAS file...");
         }
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Complete!")
+                    progress::message("Complete!")
                 }
             }
-            Err(e) => println!("error while writing: {:?}", e),
+            Err(e) => progress::message(format!("error while writing: {:?}", e)),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
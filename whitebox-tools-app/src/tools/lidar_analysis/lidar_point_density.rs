@@ -14,6 +14,7 @@ NOTES:
 use whitebox_lidar::*;
 use whitebox_raster::*;
 use whitebox_common::structures::{BoundingBox, DistanceMetric, FixedRadiusSearch2D, Point3D};
+use whitebox_common::progress;
 use crate::tools::*;
 use num_cpus;
 use std::env;
@@ -23,7 +24,6 @@ use std::io::{Error, ErrorKind};
 use std::path;
 use std::sync::mpsc;
 use std::sync::{Arc, Mutex};
-use std::thread;
 
 pub struct LidarPointDensity {
     name: String,
@@ -102,14 +102,14 @@ impl LidarPointDensity {
             optional: true
         });
 
-        // parameters.push(ToolParameter{
-        //     name: "Palette Name (Whitebox raster outputs only)".to_owned(),
-        //     flags: vec!["--palette".to_owned()],
-        //     description: "Optional palette name (for use with Whitebox raster files).".to_owned(),
-        //     parameter_type: ParameterType::String,
-        //     default_value: None,
-        //     optional: true
-        // });
+        parameters.push(ToolParameter {
+            name: "Palette Name (Whitebox raster outputs only)".to_owned(),
+            flags: vec!["--palette".to_owned()],
+            description: "Optional palette name (for use with Whitebox raster files).".to_owned(),
+            parameter_type: ParameterType::String,
+            default_value: None,
+            optional: true,
+        });
 
         parameters.push(ToolParameter {
             name: "Minimum Elevation Value (optional)".to_owned(),
@@ -143,7 +143,7 @@ impl LidarPointDensity {
             short_exe += ".exe";
         }
         let usage = format!(">>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=file.las -o=outfile.tif --resolution=2.0 --radius=5.0\"
-.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=file.las -o=outfile.tif --resolution=5.0 --radius=2.0 --exclude_cls='3,4,5,6,7,18' --palette=light_quant.plt", short_exe, name).replace("*", &sep);
+.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=file.las -o=outfile.tif --resolution=5.0 --radius=2.0 --exclude_cls='3,4,5,6,7,18'", short_exe, name).replace("*", &sep);
 
         LidarPointDensity {
             name: name,
@@ -195,6 +195,7 @@ impl WhiteboxTool for LidarPointDensity {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file: String = "".to_string();
         let mut output_file: String = "".to_string();
@@ -319,13 +320,7 @@ impl WhiteboxTool for LidarPointDensity {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let start = Instant::now();
@@ -455,7 +450,7 @@ impl WhiteboxTool for LidarPointDensity {
         }
 
         if verbose {
-            println!("Performing analysis...");
+            progress::message("Performing analysis...");
         }
 
         let num_tiles = inputs.len();
@@ -477,7 +472,7 @@ impl WhiteboxTool for LidarPointDensity {
             let exclude_cls_str = exclude_cls_str.clone();
             let include_class_vals = include_class_vals.clone();
             let tx2 = tx2.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let mut tile = 0;
                 while tile < num_tiles {
                     // Get the next tile up for interpolation
@@ -504,7 +499,7 @@ impl WhiteboxTool for LidarPointDensity {
                         FixedRadiusSearch2D::new(search_radius, DistanceMetric::SquaredEuclidean);
 
                     if verbose && inputs.len() == 1 {
-                        println!("reading input LiDAR file...");
+                        progress::message("reading input LiDAR file...");
                     }
 
                     let mut progress: i32;
@@ -547,7 +542,7 @@ impl WhiteboxTool for LidarPointDensity {
                                 if verbose && inputs.len() == 1 {
                                     progress = (100.0_f64 * i as f64 / num_points) as i32;
                                     if progress != old_progress {
-                                        println!("Binning points: {}%", progress);
+                                        progress::update_stage("Binning points", progress);
                                         old_progress = progress;
                                     }
                                 }
@@ -608,7 +603,7 @@ impl WhiteboxTool for LidarPointDensity {
                         for tid in 0..num_procs {
                             let frs = frs.clone();
                             let tx1 = tx.clone();
-                            thread::spawn(move || {
+                            progress::spawn(move || {
                                 let (mut x, mut y): (f64, f64);
                                 for row in (0..rows).filter(|r| r % num_procs == tid) {
                                     let mut data = vec![nodata; columns as usize];
@@ -618,7 +613,9 @@ impl WhiteboxTool for LidarPointDensity {
                                         let ret = frs.search(x, y);
                                         data[col as usize] = ret.len() as f64 / search_area;
                                     }
-                                    tx1.send((row, data)).unwrap();
+                                    if tx1.send((row, data)).is_err() {
+                                        return;
+                                    }
                                 }
                             });
                         }
@@ -629,7 +626,7 @@ impl WhiteboxTool for LidarPointDensity {
                             if verbose {
                                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as i32;
                                 if progress != old_progress {
-                                    println!("Progress: {}%", progress);
+                                    progress::update(progress);
                                     old_progress = progress;
                                 }
                             }
@@ -653,12 +650,14 @@ impl WhiteboxTool for LidarPointDensity {
                     ));
 
                     if verbose && inputs.len() == 1 {
-                        println!("Saving data...")
+                        progress::message("Saving data...")
                     };
 
                     let _ = output.write().unwrap();
 
-                    tx2.send(tile).unwrap();
+                    if tx2.send(tile).is_err() {
+                        return;
+                    }
                 }
             });
         }
@@ -666,22 +665,17 @@ impl WhiteboxTool for LidarPointDensity {
         let mut progress: i32;
         let mut old_progress: i32 = -1;
         for tile in 0..inputs.len() {
-            let tile_completed = rx2.recv().unwrap();
+            let tile_completed = cancel.recv(&rx2)?;
             if verbose {
-                println!(
-                    "Finished interpolating {} ({} of {})",
-                    inputs[tile_completed]
+                progress::message(format!("Finished interpolating {} ({} of {})", inputs[tile_completed]
                         .replace("\"", "")
                         .replace(working_directory, "")
-                        .replace(".las", ""),
-                    tile + 1,
-                    inputs.len()
-                );
+                        .replace(".las", ""), tile + 1, inputs.len()));
             }
             if verbose {
                 progress = (100.0_f64 * tile as f64 / (inputs.len() - 1) as f64) as i32;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    progress::update(progress);
                     old_progress = progress;
                 }
             }
@@ -690,10 +684,7 @@ impl WhiteboxTool for LidarPointDensity {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (including I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (including I/O): {}", elapsed_time));
         }
 
         Ok(())
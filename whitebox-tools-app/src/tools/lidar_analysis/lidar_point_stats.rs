@@ -9,6 +9,7 @@ License: MIT
 use whitebox_lidar::*;
 use whitebox_raster::*;
 use whitebox_common::structures::{Array2D, Point3D};
+use whitebox_common::progress;
 use crate::tools::*;
 use num_cpus;
 use std::env;
@@ -18,7 +19,6 @@ use std::io::{Error, ErrorKind};
 use std::path;
 use std::sync::mpsc;
 use std::sync::{Arc, Mutex};
-use std::thread;
 
 /// This tool creates several rasters summarizing the distribution of LiDAR points in a LAS data file.
 /// The user must specify the name of an input LAS file (`--input`) and the output raster grid
@@ -120,7 +120,7 @@ impl LidarPointStats {
 
         parameters.push(ToolParameter {
             name: "Output elevation range?".to_owned(),
-            flags: vec!["--z_range".to_owned()],
+            flags: vec!["--elev_range".to_owned(), "--z_range".to_owned()],
             description: "Flag indicating whether or not to output the elevation range raster."
                 .to_owned(),
             parameter_type: ParameterType::Boolean,
@@ -130,7 +130,7 @@ impl LidarPointStats {
 
         parameters.push(ToolParameter {
             name: "Output intensity range?".to_owned(),
-            flags: vec!["--intensity_range".to_owned()],
+            flags: vec!["--i_range".to_owned(), "--intensity_range".to_owned()],
             description: "Flag indicating whether or not to output the intensity range raster."
                 .to_owned(),
             parameter_type: ParameterType::Boolean,
@@ -140,7 +140,7 @@ impl LidarPointStats {
 
         parameters.push(ToolParameter {
             name: "Output predominant class?".to_owned(),
-            flags: vec!["--predom_class".to_owned()],
+            flags: vec!["--predominant_class".to_owned(), "--predom_class".to_owned()],
             description:
                 "Flag indicating whether or not to output the predominant classification raster."
                     .to_owned(),
@@ -218,6 +218,7 @@ impl WhiteboxTool for LidarPointStats {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file: String = "".to_string();
         let mut grid_res: f64 = 1.0;
@@ -341,13 +342,7 @@ impl WhiteboxTool for LidarPointStats {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let num_tiles = inputs.len();
@@ -361,7 +356,7 @@ impl WhiteboxTool for LidarPointStats {
             // copy over the string parameters
             let tool_name = self.get_tool_name();
             let tx2 = tx2.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let mut tile = 0;
                 while tile < num_tiles {
                     // Get the next tile up for interpolation
@@ -374,7 +369,7 @@ impl WhiteboxTool for LidarPointStats {
 
                     let input_file = inputs[tile].replace("\"", "").clone();
                     if verbose && inputs.len() == 1 {
-                        println!("reading input LiDAR file...");
+                        progress::message("reading input LiDAR file...");
                     }
                     let input = match LasFile::new(&input_file, "r") {
                         Ok(lf) => lf,
@@ -454,7 +449,7 @@ impl WhiteboxTool for LidarPointStats {
                             if verbose && inputs.len() == 1 {
                                 progress = (100.0_f64 * i as f64 / num_points_float) as i32;
                                 if progress != old_progress {
-                                    println!("Progress: {}%", progress);
+                                    progress::update(progress);
                                     old_progress = progress;
                                 }
                             }
@@ -463,7 +458,7 @@ impl WhiteboxTool for LidarPointStats {
                         let elapsed_time_run = get_formatted_elapsed_time(start_run);
 
                         if verbose && inputs.len() == 1 {
-                            println!("Saving data...")
+                            progress::message("Saving data...")
                         };
 
                         if avg_points_per_pulse {
@@ -630,7 +625,7 @@ impl WhiteboxTool for LidarPointStats {
                             if verbose && inputs.len() == 1 {
                                 progress = (100.0_f64 * i as f64 / num_points_float) as i32;
                                 if progress != old_progress {
-                                    println!("Progress: {}%", progress);
+                                    progress::update(progress);
                                     old_progress = progress;
                                 }
                             }
@@ -639,7 +634,7 @@ impl WhiteboxTool for LidarPointStats {
                         let elapsed_time_run = get_formatted_elapsed_time(start_run);
 
                         if verbose && inputs.len() == 1 {
-                            println!("Saving data...")
+                            progress::message("Saving data...")
                         };
 
                         if z_range {
@@ -721,7 +716,7 @@ impl WhiteboxTool for LidarPointStats {
                             if verbose && inputs.len() == 1 {
                                 progress = (100.0_f64 * i as f64 / num_points_float) as i32;
                                 if progress != old_progress {
-                                    println!("Progress: {}%", progress);
+                                    progress::update(progress);
                                     old_progress = progress;
                                 }
                             }
@@ -730,7 +725,7 @@ impl WhiteboxTool for LidarPointStats {
                         let elapsed_time_run = get_formatted_elapsed_time(start_run);
 
                         if verbose && inputs.len() == 1 {
-                            println!("Saving data...")
+                            progress::message("Saving data...")
                         };
 
                         out_predominant_class.add_metadata_entry(format!(
@@ -749,7 +744,9 @@ impl WhiteboxTool for LidarPointStats {
                         drop(out_predominant_class);
                     }
 
-                    tx2.send(tile).unwrap();
+                    if tx2.send(tile).is_err() {
+                        return;
+                    }
                 }
             });
         }
@@ -757,24 +754,19 @@ impl WhiteboxTool for LidarPointStats {
         let mut progress: i32;
         let mut old_progress: i32 = -1;
         for tile in 0..inputs.len() {
-            let tile_completed = rx2.recv().unwrap();
+            let tile_completed = cancel.recv(&rx2)?;
             if verbose {
-                println!(
-                    "Finished {} ({} of {})",
-                    inputs[tile_completed]
+                progress::message(format!("Finished {} ({} of {})", inputs[tile_completed]
                         .replace("\"", "")
                         .replace(working_directory, "")
                         .replace(".las", "")
                         .replace(".laz", "")
-                        .replace(".zlidar", ""),
-                    tile + 1,
-                    inputs.len()
-                );
+                        .replace(".zlidar", ""), tile + 1, inputs.len()));
             }
             if verbose {
                 progress = (100.0_f64 * tile as f64 / (inputs.len() - 1) as f64) as i32;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    progress::update(progress);
                     old_progress = progress;
                 }
             }
@@ -783,10 +775,7 @@ impl WhiteboxTool for LidarPointStats {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (including I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (including I/O): {}", elapsed_time));
         }
 
         Ok(())
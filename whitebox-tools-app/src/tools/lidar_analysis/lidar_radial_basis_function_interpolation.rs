@@ -14,6 +14,7 @@ NOTES:
 use whitebox_lidar::*;
 use whitebox_raster::*;
 use whitebox_common::structures::{Basis, BoundingBox, RadialBasisFunction};
+use whitebox_common::progress;
 use crate::tools::*;
 use kdtree::distance::squared_euclidean;
 use kdtree::KdTree;
@@ -26,7 +27,6 @@ use std::io::{Error, ErrorKind};
 use std::path;
 use std::sync::mpsc;
 use std::sync::{Arc, Mutex};
-use std::thread;
 
 pub struct LidarRbfInterpolation {
     name: String,
@@ -183,6 +183,15 @@ impl LidarRbfInterpolation {
             optional: false,
         });
 
+        parameters.push(ToolParameter {
+            name: "Palette Name (Whitebox raster outputs only)".to_owned(),
+            flags: vec!["--palette".to_owned()],
+            description: "Optional palette name (for use with Whitebox raster files).".to_owned(),
+            parameter_type: ParameterType::String,
+            default_value: None,
+            optional: true,
+        });
+
         let sep: String = path::MAIN_SEPARATOR.to_string();
         let e = format!("{}", env::current_exe().unwrap().display());
         let mut parent = env::current_exe().unwrap();
@@ -196,7 +205,7 @@ impl LidarRbfInterpolation {
         if e.contains(".exe") {
             short_exe += ".exe";
         }
-        let usage = format!(">>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=file.las -o=outfile.tif --resolution=2.0 --radius=5.0", short_exe, name).replace("*", &sep);
+        let usage = format!(">>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=file.las -o=outfile.tif --resolution=2.0", short_exe, name).replace("*", &sep);
 
         LidarRbfInterpolation {
             name: name,
@@ -248,6 +257,7 @@ impl WhiteboxTool for LidarRbfInterpolation {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file: String = "".to_string();
         let mut output_file: String = "".to_string();
@@ -480,13 +490,7 @@ impl WhiteboxTool for LidarRbfInterpolation {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let start = Instant::now();
@@ -579,7 +583,7 @@ impl WhiteboxTool for LidarRbfInterpolation {
         }
 
         if verbose {
-            println!("Performing interpolation...");
+            progress::message("Performing interpolation...");
         }
 
         let func_type = Arc::new(func_type);
@@ -604,7 +608,7 @@ impl WhiteboxTool for LidarRbfInterpolation {
             let exclude_cls_str = exclude_cls_str.clone();
             let include_class_vals = include_class_vals.clone();
             let tx2 = tx2.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let mut tile = 0;
                 while tile < num_tiles {
                     // Get the next tile up for interpolation
@@ -639,7 +643,7 @@ impl WhiteboxTool for LidarRbfInterpolation {
                     let mut z: f64;
 
                     if verbose && inputs.len() == 1 {
-                        println!("reading input LiDAR file...");
+                        progress::message("reading input LiDAR file...");
                     }
 
                     let mut progress: i32;
@@ -696,7 +700,7 @@ impl WhiteboxTool for LidarRbfInterpolation {
                                         if verbose && inputs.len() == 1 {
                                             progress = (100.0_f64 * i as f64 / num_points) as i32;
                                             if progress != old_progress {
-                                                println!("Reading points: {}%", progress);
+                                                progress::update_stage("Reading points", progress);
                                                 old_progress = progress;
                                             }
                                         }
@@ -738,7 +742,7 @@ impl WhiteboxTool for LidarRbfInterpolation {
                                         if verbose && inputs.len() == 1 {
                                             progress = (100.0_f64 * i as f64 / num_points) as i32;
                                             if progress != old_progress {
-                                                println!("Reading points: {}%", progress);
+                                                progress::update_stage("Reading points", progress);
                                                 old_progress = progress;
                                             }
                                         }
@@ -780,7 +784,7 @@ impl WhiteboxTool for LidarRbfInterpolation {
                                         if verbose && inputs.len() == 1 {
                                             progress = (100.0_f64 * i as f64 / num_points) as i32;
                                             if progress != old_progress {
-                                                println!("Reading points: {}%", progress);
+                                                progress::update_stage("Reading points", progress);
                                                 old_progress = progress;
                                             }
                                         }
@@ -822,7 +826,7 @@ impl WhiteboxTool for LidarRbfInterpolation {
                                         if verbose && inputs.len() == 1 {
                                             progress = (100.0_f64 * i as f64 / num_points) as i32;
                                             if progress != old_progress {
-                                                println!("Reading points: {}%", progress);
+                                                progress::update_stage("Reading points", progress);
                                                 old_progress = progress;
                                             }
                                         }
@@ -864,7 +868,7 @@ impl WhiteboxTool for LidarRbfInterpolation {
                                         if verbose && inputs.len() == 1 {
                                             progress = (100.0_f64 * i as f64 / num_points) as i32;
                                             if progress != old_progress {
-                                                println!("Reading points: {}%", progress);
+                                                progress::update_stage("Reading points", progress);
                                                 old_progress = progress;
                                             }
                                         }
@@ -906,7 +910,7 @@ impl WhiteboxTool for LidarRbfInterpolation {
                                         if verbose && inputs.len() == 1 {
                                             progress = (100.0_f64 * i as f64 / num_points) as i32;
                                             if progress != old_progress {
-                                                println!("Reading points: {}%", progress);
+                                                progress::update_stage("Reading points", progress);
                                                 old_progress = progress;
                                             }
                                         }
@@ -914,7 +918,7 @@ impl WhiteboxTool for LidarRbfInterpolation {
                                 }
                                 "rgb" => {
                                     if !input.has_rgb() {
-                                        println!("Error: The input LAS file does not contain RGB colour data. The interpolation will not proceed.");
+                                        progress::message("Error: The input LAS file does not contain RGB colour data. The interpolation will not proceed.");
                                         break;
                                     }
                                     // let mut clr: ColourData;
@@ -962,7 +966,7 @@ impl WhiteboxTool for LidarRbfInterpolation {
                                         if verbose && inputs.len() == 1 {
                                             progress = (100.0_f64 * i as f64 / num_points) as i32;
                                             if progress != old_progress {
-                                                println!("Reading points: {}%", progress);
+                                                progress::update_stage("Reading points", progress);
                                                 old_progress = progress;
                                             }
                                         }
@@ -1005,7 +1009,7 @@ impl WhiteboxTool for LidarRbfInterpolation {
                                         if verbose && inputs.len() == 1 {
                                             progress = (100.0_f64 * i as f64 / num_points) as i32;
                                             if progress != old_progress {
-                                                println!("Reading points: {}%", progress);
+                                                progress::update_stage("Reading points", progress);
                                                 old_progress = progress;
                                             }
                                         }
@@ -1016,8 +1020,10 @@ impl WhiteboxTool for LidarRbfInterpolation {
                     }
 
                     if points.len() == 0 {
-                        println!("Warning: No points found in {}", inputs[tile].clone());
-                        tx2.send(tile).unwrap();
+                        progress::warning(format!("No points found in {}", inputs[tile].clone()));
+                        if tx2.send(tile).is_err() {
+                            return;
+                        }
                     } else {
                         let range = max_value - min_value;
                         let range_threshold = range * 1f64; // only estimated values that are +/- 0.5 range beyond the min and max values will be output
@@ -1090,7 +1096,7 @@ impl WhiteboxTool for LidarRbfInterpolation {
                                 if verbose && inputs.len() == 1 {
                                     progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as i32;
                                     if progress != old_progress {
-                                        println!("Progress: {}%", progress);
+                                        progress::update(progress);
                                         old_progress = progress;
                                     }
                                 }
@@ -1112,7 +1118,7 @@ impl WhiteboxTool for LidarRbfInterpolation {
                                 let tx1 = tx.clone();
                                 let points = points.clone();
                                 let z_values = z_values.clone();
-                                thread::spawn(move || {
+                                progress::spawn(move || {
                                     let (mut x, mut y): (f64, f64);
                                     let mut zn: f64;
                                     let mut point_num: usize;
@@ -1144,7 +1150,9 @@ impl WhiteboxTool for LidarRbfInterpolation {
                                                 }
                                             }
                                         }
-                                        tx1.send((row, data)).unwrap();
+                                        if tx1.send((row, data)).is_err() {
+                                            return;
+                                        }
                                     }
                                 });
                             }
@@ -1155,7 +1163,7 @@ impl WhiteboxTool for LidarRbfInterpolation {
                                 if verbose {
                                     progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as i32;
                                     if progress != old_progress {
-                                        println!("Progress: {}%", progress);
+                                        progress::update(progress);
                                         old_progress = progress;
                                     }
                                 }
@@ -1190,12 +1198,14 @@ impl WhiteboxTool for LidarRbfInterpolation {
                         ));
 
                         if verbose && inputs.len() == 1 {
-                            println!("Saving data...")
+                            progress::message("Saving data...")
                         };
 
                         let _ = output.write().unwrap();
 
-                        tx2.send(tile).unwrap();
+                        if tx2.send(tile).is_err() {
+                            return;
+                        }
                     }
                 }
             });
@@ -1204,33 +1214,23 @@ impl WhiteboxTool for LidarRbfInterpolation {
         let mut progress: i32;
         let mut old_progress: i32 = -1;
         for tile in 0..inputs.len() {
-            let tile_completed = rx2.recv().unwrap();
+            let tile_completed = cancel.recv(&rx2)?;
             if verbose {
                 if tile <= 98 {
-                    println!(
-                        "Finished {} ({} of {})",
-                        inputs[tile_completed]
+                    progress::message(format!("Finished {} ({} of {})", inputs[tile_completed]
                             .replace("\"", "")
                             .replace(working_directory, "")
-                            .replace(".las", ""),
-                        tile + 1,
-                        inputs.len()
-                    );
+                            .replace(".las", ""), tile + 1, inputs.len()));
                 } else if tile == 99 {
-                    println!(
-                        "Finished {} ({} of {})",
-                        inputs[tile_completed]
+                    progress::message(format!("Finished {} ({} of {})", inputs[tile_completed]
                             .replace("\"", "")
                             .replace(working_directory, "")
-                            .replace(".las", ""),
-                        tile + 1,
-                        inputs.len()
-                    );
-                    println!("...");
+                            .replace(".las", ""), tile + 1, inputs.len()));
+                    progress::message("...");
                 }
                 progress = (100.0_f64 * tile as f64 / (inputs.len() - 1) as f64) as i32;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    progress::update(progress);
                     old_progress = progress;
                 }
             }
@@ -1239,10 +1239,7 @@ impl WhiteboxTool for LidarRbfInterpolation {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (including I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (including I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -11,6 +11,8 @@ use self::na::Vector3;
 use whitebox_lidar::*;
 use crate::na;
 use whitebox_common::structures::{DistanceMetric, FixedRadiusSearch3D, Point3D};
+use whitebox_common::progress;
+use whitebox_common::random;
 use crate::tools::*;
 use rand::seq::SliceRandom;
 // use kdtree::distance::squared_euclidean;
@@ -22,7 +24,6 @@ use std::io::{Error, ErrorKind};
 use std::path;
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::thread;
 
 /// This tool uses the [random sample consensus (RANSAC)](https://en.wikipedia.org/wiki/Random_sample_consensus)
 /// method to identify points within a LiDAR point cloud that belong to planar surfaces. RANSAC is a common
@@ -160,6 +161,8 @@ impl LidarRansacPlanes {
             optional: true,
         });
 
+        parameters.push(seed_parameter());
+
         let sep: String = path::MAIN_SEPARATOR.to_string();
         let e = format!("{}", env::current_exe().unwrap().display());
         let mut parent = env::current_exe().unwrap();
@@ -225,6 +228,7 @@ impl WhiteboxTool for LidarRansacPlanes {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file: String = "".to_string();
         let mut output_file: String = "".to_string();
@@ -236,6 +240,7 @@ impl WhiteboxTool for LidarRansacPlanes {
         let mut filter = true;
         let mut max_slope = 75f64;
         let mut last_returns = false;
+        let mut seed: Option<u64> = None;
 
         // read the arguments
         if args.len() == 0 {
@@ -278,6 +283,9 @@ impl WhiteboxTool for LidarRansacPlanes {
                         .parse::<f64>()
                         .expect(&format!("Error parsing {}", flag_val))
                 };
+            } else if flag_val == "-seed" {
+                let v = if keyval { vec[1].to_string() } else { args[i + 1].to_string() };
+                seed = Some(parse_seed(&v)?);
             } else if flag_val == "-num_iter" {
                 num_iter = if keyval {
                     vec[1]
@@ -351,13 +359,7 @@ impl WhiteboxTool for LidarRansacPlanes {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep = path::MAIN_SEPARATOR;
@@ -369,7 +371,7 @@ impl WhiteboxTool for LidarRansacPlanes {
         }
 
         if verbose {
-            println!("reading input LiDAR file...");
+            progress::message("reading input LiDAR file...");
         }
         let input = match LasFile::new(&input_file, "r") {
             Ok(lf) => lf,
@@ -379,14 +381,14 @@ impl WhiteboxTool for LidarRansacPlanes {
         if acceptable_model_size < 5 {
             acceptable_model_size = 5;
             if verbose {
-                println!("Warning: The --model_size parameter must be at least 5.");
+                progress::warning("The --model_size parameter must be at least 5.");
             }
         }
 
         if num_samples < 5 {
             num_samples = 5;
             if verbose {
-                println!("Warning: The --num_samples parameter must be at least 5.");
+                progress::warning("The --num_samples parameter must be at least 5.");
             }
         }
 
@@ -402,7 +404,7 @@ impl WhiteboxTool for LidarRansacPlanes {
         let start = Instant::now();
 
         if verbose {
-            println!("Performing analysis...");
+            progress::message("Performing analysis...");
         }
 
         let n_points = input.header.number_of_points as usize;
@@ -421,10 +423,11 @@ impl WhiteboxTool for LidarRansacPlanes {
             if (!last_returns || (last_returns && pd.is_late_return())) && !pd.is_classified_noise() {
                 frs.insert(p.x, p.y, p.z, i);
             }
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * i as f64 / num_points) as i32;
                 if progress != old_progress {
-                    println!("Adding points to search tree: {}%", progress);
+                    progress::update_stage("Adding points to search tree", progress);
                     old_progress = progress;
                 }
             }
@@ -448,20 +451,21 @@ impl WhiteboxTool for LidarRansacPlanes {
                                  // let kdtree = Arc::new(kdtree);
         let input = Arc::new(input); // wrap input in an Arc
         let num_procs = num_cpus::get();
+        let seed = random::tool_seed(seed);
         let (tx, rx) = mpsc::channel();
         for tid in 0..num_procs {
             let frs = frs.clone();
             // let kdtree = kdtree.clone();
             let input = input.clone();
             let tx = tx.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let mut n: usize;
                 // let mut p1: PointData;
                 // let mut p2: PointData;
                 let mut p1: Point3D;
                 let mut p2: Point3D;
                 let mut index: usize;
-                let mut rng = &mut rand::thread_rng();
+                let mut rng = random::new_rng(seed, tid as u64);
                 let mut model: Plane;
                 let mut better_model: Plane;
                 let mut center_point: Vector3<f64>;
@@ -544,7 +548,9 @@ impl WhiteboxTool for LidarRansacPlanes {
                             model_points.clear();
                         }
                     }
-                    tx.send(model_points).unwrap();
+                    if tx.send(model_points).is_err() {
+                        return;
+                    }
                 }
             });
         }
@@ -553,14 +559,14 @@ impl WhiteboxTool for LidarRansacPlanes {
         for i in 0..n_points {
             // let data = rx.recv().expect("Error receiving data from thread.");
             // is_a_planar_surface[data.0] = data.1;
-            let model_points = rx.recv().expect("Error receiving data from thread.");
+            let model_points = cancel.recv(&rx)?;
             for index in model_points {
                 is_a_planar_surface[index] = true;
             }
             if verbose {
                 progress = (100.0_f64 * i as f64 / num_points) as i32;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    progress::update(progress);
                     old_progress = progress;
                 }
             }
@@ -577,10 +583,11 @@ impl WhiteboxTool for LidarRansacPlanes {
                 } else {
                     num_points_filtered += 1;
                 }
+                cancel.check()?;
                 if verbose {
                     progress = (100.0_f64 * i as f64 / num_points) as i32;
                     if progress != old_progress {
-                        println!("Saving data: {}%", progress);
+                        progress::update_stage("Saving data", progress);
                         old_progress = progress;
                     }
                 }
@@ -721,10 +728,11 @@ impl WhiteboxTool for LidarRansacPlanes {
                     }
                 }
                 output.add_point_record(pr2);
+                cancel.check()?;
                 if verbose {
                     progress = (100.0_f64 * point_num as f64 / num_points) as i32;
                     if progress != old_progress {
-                        println!("Saving data: {}%", progress);
+                        progress::update_stage("Saving data", progress);
                         old_progress = progress;
                     }
                 }
@@ -735,27 +743,24 @@ impl WhiteboxTool for LidarRansacPlanes {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if num_points_filtered == 0 {
-            println!("Warning: No outlier points were filtered from the point cloud.");
+            progress::warning("No outlier points were filtered from the point cloud.");
         } else if num_points_filtered == n_points {
-            println!("Warning: All of the points have been filtered from the point cloud.")
+            progress::warning("All of the points have been filtered from the point cloud.")
         }
 
         if verbose {
-            println!("Writing output LAS file...");
+            progress::message("Writing output LAS file...");
         }
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Complete!")
+                    progress::message("Complete!")
                 }
             }
-            Err(e) => println!("error while writing: {:?}", e),
+            Err(e) => progress::message(format!("error while writing: {:?}", e)),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -8,6 +8,7 @@ License: MIT
 
 use self::na::Vector3;
 use whitebox_common::algorithms;
+use whitebox_common::progress;
 use whitebox_lidar::*;
 use crate::na;
 use whitebox_common::structures::{DistanceMetric, FixedRadiusSearch3D, Point2D, Point3D, RectangleWithData};
@@ -22,7 +23,6 @@ use std::io::{Error, ErrorKind};
 use std::ops::AddAssign;
 use std::path;
 use std::sync::{mpsc, Arc};
-use std::thread;
 use std::{env, fs};
 const EPSILON: f64 = std::f64::EPSILON;
 
@@ -57,7 +57,7 @@ impl LidarRooftopAnalysis {
 
         parameters.push(ToolParameter {
             name: "Input Building Footprint Polygon File".to_owned(),
-            flags: vec!["--buildings".to_owned()],
+            flags: vec!["--building".to_owned(), "--buildings".to_owned()],
             description: "Input vector build footprint polygons file.".to_owned(),
             parameter_type: ParameterType::ExistingFile(ParameterFileType::Vector(
                 VectorGeometryType::Polygon,
@@ -150,14 +150,14 @@ impl LidarRooftopAnalysis {
             optional: true,
         });
 
-        // parameters.push(ToolParameter{
-        //     name: "Maximum Elevation Difference Between Points".to_owned(),
-        //     flags: vec!["--maxzdiff".to_owned()],
-        //     description: "Maximum difference in elevation (z units) between neighbouring points of the same segment.".to_owned(),
-        //     parameter_type: ParameterType::Float,
-        //     default_value: Some("1.0".to_owned()),
-        //     optional: true
-        // });
+        parameters.push(ToolParameter {
+            name: "Maximum Elevation Difference Between Points".to_owned(),
+            flags: vec!["--maxzdiff".to_owned()],
+            description: "Maximum difference in elevation (z units) between neighbouring points of the same segment.".to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: Some("1.0".to_owned()),
+            optional: true,
+        });
 
         parameters.push(ToolParameter {
             name: "Azimuth (degrees)".to_owned(),
@@ -190,7 +190,7 @@ impl LidarRooftopAnalysis {
         if e.contains(".exe") {
             short_exe += ".exe";
         }
-        let usage = format!(">>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i='data.las' --polygons='buildings.shp' -o='rooftops.shp' --radius=10.0 --num_iter=10 --num_samples=5 --threshold=0.25 --max_slope=70.0", short_exe, name).replace("*", &sep);
+        let usage = format!(">>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i='data.las' -o='rooftops.shp' --radius=10.0 --num_iter=10 --num_samples=5 --threshold=0.25 --max_slope=70.0", short_exe, name).replace("*", &sep);
 
         LidarRooftopAnalysis {
             name: name,
@@ -235,6 +235,7 @@ impl WhiteboxTool for LidarRooftopAnalysis {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut polygons_file = String::new();
@@ -419,13 +420,7 @@ impl WhiteboxTool for LidarRooftopAnalysis {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -452,20 +447,20 @@ impl WhiteboxTool for LidarRooftopAnalysis {
         // }
 
         if verbose {
-            println!("Reading data...")
+            progress::message("Reading data...")
         };
 
         if acceptable_model_size < 5 {
             acceptable_model_size = 5;
             if verbose {
-                println!("Warning: The --model_size parameter must be at least 5.");
+                progress::warning("The --model_size parameter must be at least 5.");
             }
         }
 
         if num_samples < 5 {
             num_samples = 5;
             if verbose {
-                println!("Warning: The --num_samples parameter must be at least 5.");
+                progress::warning("The --num_samples parameter must be at least 5.");
             }
         }
 
@@ -564,7 +559,7 @@ impl WhiteboxTool for LidarRooftopAnalysis {
         }
 
         if verbose {
-            println!("Clipping lidar to building footprints...")
+            progress::message("Clipping lidar to building footprints...")
         };
 
         // let input = LasFile::new(&inputs[0], "r").expect("Error reading input LiDAR file");
@@ -586,7 +581,7 @@ impl WhiteboxTool for LidarRooftopAnalysis {
             match LasFile::new(&input_file, "r") {
                 Ok(mut input) => {
                     if verbose && file_num < 100 {
-                        println!("Clipping: {}", input.get_short_filename());
+                        progress::message(format!("Clipping: {}", input.get_short_filename()));
                     }
                     projection = input.get_wkt();
                     let n_points = input.header.number_of_points as usize;
@@ -597,7 +592,7 @@ impl WhiteboxTool for LidarRooftopAnalysis {
                         let building_tree = building_tree.clone();
                         let polygons = polygons.clone();
                         let tx = tx.clone();
-                        thread::spawn(move || {
+                        progress::spawn(move || {
                             let mut progress: usize;
                             let mut old_progress = 1usize;
                             let mut pd: PointData;
@@ -682,7 +677,7 @@ impl WhiteboxTool for LidarRooftopAnalysis {
                                     progress =
                                         (100.0_f64 * point_num as f64 / n_points as f64) as usize;
                                     if progress != old_progress {
-                                        println!("Progress: {}%", progress);
+                                        progress::update(progress);
                                         old_progress = progress;
                                     }
                                 }
@@ -701,7 +696,7 @@ impl WhiteboxTool for LidarRooftopAnalysis {
                     let mut building = vec![0; n_points];
                     for _ in 0..num_procs {
                         let in_building_points =
-                            rx.recv().expect("Error receiving data from thread.");
+                            cancel.recv(&rx)?;
                         for p in in_building_points {
                             point_in_building[p.0] = true;
                             building[p.0] = p.1;
@@ -722,7 +717,7 @@ impl WhiteboxTool for LidarRooftopAnalysis {
                         if num_files == 1 && verbose {
                             progress = (100.0_f64 * p as f64 / n_points as f64) as i32;
                             if progress != old_progress {
-                                println!("Progress: {}%", progress);
+                                progress::update(progress);
                                 old_progress = progress;
                             }
                         }
@@ -732,14 +727,14 @@ impl WhiteboxTool for LidarRooftopAnalysis {
                         file_num += 1;
                         progress = (100.0_f64 * file_num as f64 / num_files as f64) as i32;
                         if progress != old_progress {
-                            println!("Progress ({} of {}): {}%", file_num, num_files, progress);
+                            progress::update_stage(&format!("Progress ({} of {})", file_num, num_files), progress);
                             old_progress = progress;
                         }
                     }
                 }
                 Err(err) => {
                     if verbose {
-                        println!("Error reading file {}: {}", input_file, err);
+                        progress::message(format!("Error reading file {}: {}", input_file, err));
                     }
                 }
             };
@@ -751,7 +746,7 @@ impl WhiteboxTool for LidarRooftopAnalysis {
         ////////////
 
         if verbose {
-            println!("Building roof facet models...");
+            progress::message("Building roof facet models...");
         }
 
         let n_points = las_points_transformed.len();
@@ -773,7 +768,7 @@ impl WhiteboxTool for LidarRooftopAnalysis {
             let las_points_transformed = las_points_transformed.clone();
             let building_num = building_num.clone();
             let tx = tx.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let mut n: usize;
                 // let mut p1: PointData;
                 // let mut p2: PointData;
@@ -858,7 +853,9 @@ impl WhiteboxTool for LidarRooftopAnalysis {
                             model_points.clear();
                         }
                     }
-                    tx.send((best_model, min_rmse, model_points)).unwrap();
+                    if tx.send((best_model, min_rmse, model_points)).is_err() {
+                        return;
+                    }
                 }
             });
         }
@@ -866,7 +863,7 @@ impl WhiteboxTool for LidarRooftopAnalysis {
         let mut model_rmse = vec![f64::MAX; n_points];
         let mut planes = vec![Plane::zero(); n_points];
         for i in 0..n_points {
-            let (model, rmse, model_points) = rx.recv().expect("Error receiving data from thread.");
+            let (model, rmse, model_points) = cancel.recv(&rx)?;
             if rmse < f64::MAX {
                 for index in model_points {
                     if rmse < model_rmse[index] {
@@ -878,7 +875,7 @@ impl WhiteboxTool for LidarRooftopAnalysis {
             if verbose {
                 progress = (100.0_f64 * i as f64 / num_points) as i32;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    progress::update(progress);
                     old_progress = progress;
                 }
             }
@@ -888,7 +885,7 @@ impl WhiteboxTool for LidarRooftopAnalysis {
         // // Perform the segmentation operation //
         ////////////////////////////////////////
         if verbose {
-            println!("Segmenting the point cloud...");
+            progress::message("Segmenting the point cloud...");
         }
         // let mut p: PointData;
         // let mut pn: PointData;
@@ -935,10 +932,11 @@ impl WhiteboxTool for LidarRooftopAnalysis {
 
             while !stack.is_empty() {
                 solved_points += 1;
+                cancel.check()?;
                 if verbose {
                     progress = (100f64 * solved_points as f64 / num_points) as i32;
                     if progress != old_progress {
-                        println!("Segmenting the point cloud: {}%", progress);
+                        progress::update_stage("Segmenting the point cloud", progress);
                         old_progress = progress;
                     }
                 }
@@ -1633,7 +1631,7 @@ impl WhiteboxTool for LidarRooftopAnalysis {
             if verbose {
                 progress = (100.0_f64 * building_id as f64 / num_buildings as f64) as i32;
                 if progress != old_progress {
-                    println!("Creating roof segment polygons: {}%", progress);
+                    progress::update_stage("Creating roof segment polygons", progress);
                     old_progress = progress;
                 }
             }
@@ -1676,7 +1674,7 @@ impl WhiteboxTool for LidarRooftopAnalysis {
                 if verbose {
                     progress = (100.0_f64 * point_num as f64 / num_points) as i32;
                     if progress != old_progress {
-                        println!("Saving data: {}%", progress);
+                        progress::update_stage("Saving data", progress);
                         old_progress = progress;
                     }
                 }
@@ -1685,7 +1683,7 @@ impl WhiteboxTool for LidarRooftopAnalysis {
         */
 
         if verbose {
-            println!("Saving polygon data...");
+            progress::message("Saving polygon data...");
         }
 
         let elapsed_time = get_formatted_elapsed_time(start);
@@ -1693,7 +1691,7 @@ impl WhiteboxTool for LidarRooftopAnalysis {
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
@@ -1713,23 +1711,20 @@ impl WhiteboxTool for LidarRooftopAnalysis {
             let _ = match output_lidar.write() {
                 Ok(_) => {
                     if verbose {
-                        println!("Complete!")
+                        progress::message("Complete!")
                     }
                 }
-                Err(e) => println!("error while writing: {:?}", e),
+                Err(e) => progress::message(format!("error while writing: {:?}", e)),
             };
         } else {
             if verbose {
-                println!("Warning: the file {} does not appear to contain any points within the clip polygon. No output file has been created.", output.get_short_filename());
+                progress::warning(format!("the file {} does not appear to contain any points within the clip polygon. No output file has been created.", output.get_short_filename()));
             }
         }
         */
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -11,6 +11,7 @@ use self::na::Vector3;
 use whitebox_lidar::*;
 use crate::na;
 use whitebox_common::structures::{DistanceMetric, FixedRadiusSearch3D, Point3D};
+use whitebox_common::progress;
 use crate::tools::*;
 use rand::seq::SliceRandom;
 // use kdtree::distance::squared_euclidean;
@@ -22,7 +23,6 @@ use std::io::{Error, ErrorKind};
 use std::path;
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::thread;
 
 /// This tool can be used to segment a LiDAR point cloud based on differences in the orientation of fitted planar
 /// surfaces and point proximity. The algorithm begins by attempting to fit planar surfaces to all of the points within
@@ -263,6 +263,7 @@ impl WhiteboxTool for LidarSegmentation {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file: String = "".to_string();
         let mut output_file: String = "".to_string();
@@ -418,13 +419,7 @@ impl WhiteboxTool for LidarSegmentation {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep = path::MAIN_SEPARATOR;
@@ -436,7 +431,7 @@ impl WhiteboxTool for LidarSegmentation {
         }
 
         if verbose {
-            println!("reading input LiDAR file...");
+            progress::message("reading input LiDAR file...");
         }
         let input = match LasFile::new(&input_file, "r") {
             Ok(lf) => lf,
@@ -446,14 +441,14 @@ impl WhiteboxTool for LidarSegmentation {
         if acceptable_model_size < 5 {
             acceptable_model_size = 5;
             if verbose {
-                println!("Warning: The --model_size parameter must be at least 5.");
+                progress::warning("The --model_size parameter must be at least 5.");
             }
         }
 
         if num_samples < 5 {
             num_samples = 5;
             if verbose {
-                println!("Warning: The --num_samples parameter must be at least 5.");
+                progress::warning("The --num_samples parameter must be at least 5.");
             }
         }
 
@@ -470,7 +465,7 @@ impl WhiteboxTool for LidarSegmentation {
         let start = Instant::now();
 
         if verbose {
-            println!("Performing analysis...");
+            progress::message("Performing analysis...");
         }
 
         let n_points = input.header.number_of_points as usize;
@@ -488,10 +483,11 @@ impl WhiteboxTool for LidarSegmentation {
             if !pd.withheld() && !pd.is_classified_noise() {
                 frs.insert(p.x, p.y, p.z, i);
             }
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * i as f64 / num_points) as i32;
                 if progress != old_progress {
-                    println!("Adding points to search tree: {}%", progress);
+                    progress::update_stage("Adding points to search tree", progress);
                     old_progress = progress;
                 }
             }
@@ -507,7 +503,7 @@ impl WhiteboxTool for LidarSegmentation {
             // let kdtree = kdtree.clone();
             let input = input.clone();
             let tx = tx.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let mut n: usize;
                 // let mut p1: PointData;
                 // let mut p2: PointData;
@@ -590,10 +586,14 @@ impl WhiteboxTool for LidarSegmentation {
                                 model_points.clear();
                             }
                         }
-                        tx.send((best_model, min_rmse, model_points)).unwrap();
+                        if tx.send((best_model, min_rmse, model_points)).is_err() {
+                            return;
+                        }
                     } else {
                         let model_points: Vec<usize> = vec![];
-                        tx.send((best_model, f64::MAX, model_points)).unwrap();
+                        if tx.send((best_model, f64::MAX, model_points)).is_err() {
+                            return;
+                        }
                     }
                 }
             });
@@ -602,7 +602,7 @@ impl WhiteboxTool for LidarSegmentation {
         let mut model_rmse = vec![f64::MAX; n_points];
         let mut planes = vec![Plane::zero(); n_points];
         for i in 0..n_points {
-            let (model, rmse, model_points) = rx.recv().expect("Error receiving data from thread.");
+            let (model, rmse, model_points) = cancel.recv(&rx)?;
             if rmse < f64::MAX {
                 for index in model_points {
                     if rmse < model_rmse[index] {
@@ -614,7 +614,7 @@ impl WhiteboxTool for LidarSegmentation {
             if verbose {
                 progress = (100.0_f64 * i as f64 / num_points) as i32;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    progress::update(progress);
                     old_progress = progress;
                 }
             }
@@ -624,7 +624,7 @@ impl WhiteboxTool for LidarSegmentation {
         // Perform the segmentation operation //
         ////////////////////////////////////////
         if verbose {
-            println!("Segmenting the point cloud...");
+            progress::message("Segmenting the point cloud...");
         }
         let mut p: Point3D;
         let mut pd: PointData;
@@ -663,10 +663,11 @@ impl WhiteboxTool for LidarSegmentation {
 
             while !stack.is_empty() {
                 solved_points += 1;
+                cancel.check()?;
                 if verbose {
                     progress = (100f64 * solved_points as f64 / num_points) as i32;
                     if progress != old_progress {
-                        println!("Segmenting the point cloud: {}%", progress);
+                        progress::update_stage("Segmenting the point cloud", progress);
                         old_progress = progress;
                     }
                 }
@@ -727,7 +728,7 @@ impl WhiteboxTool for LidarSegmentation {
         /////////////////////
 
         if verbose {
-            println!("Saving data...");
+            progress::message("Saving data...");
         }
 
         let mut segment_size = vec![0usize; current_segment + 1];
@@ -785,10 +786,11 @@ impl WhiteboxTool for LidarSegmentation {
                 colour_data: rgb,
             };
             output.add_point_record(lpr);
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * point_num as f64 / num_points) as i32;
                 if progress != old_progress {
-                    println!("Saving data: {}%", progress);
+                    progress::update_stage("Saving data", progress);
                     old_progress = progress;
                 }
             }
@@ -797,21 +799,18 @@ impl WhiteboxTool for LidarSegmentation {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!("Writing output LAS file...");
+            progress::message("Writing output LAS file...");
         }
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Complete!")
+                    progress::message("Complete!")
                 }
             }
-            Err(e) => println!("error while writing: {:?}", e),
+            Err(e) => progress::message(format!("error while writing: {:?}", e)),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
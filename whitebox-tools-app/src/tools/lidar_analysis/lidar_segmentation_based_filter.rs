@@ -10,6 +10,7 @@ use self::na::Vector3;
 use whitebox_lidar::*;
 use crate::na;
 use whitebox_common::structures::{DistanceMetric, FixedRadiusSearch2D, FixedRadiusSearch3D};
+use whitebox_common::progress;
 use crate::tools::*;
 use num_cpus;
 use std::env;
@@ -19,7 +20,6 @@ use std::io::{Error, ErrorKind};
 use std::path;
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::thread;
 
 pub struct LidarSegmentationBasedFilter {
     name: String,
@@ -156,6 +156,7 @@ impl WhiteboxTool for LidarSegmentationBasedFilter {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file: String = "".to_string();
         let mut output_file: String = "".to_string();
@@ -254,13 +255,7 @@ impl WhiteboxTool for LidarSegmentationBasedFilter {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep = path::MAIN_SEPARATOR;
@@ -272,7 +267,7 @@ impl WhiteboxTool for LidarSegmentationBasedFilter {
         }
 
         if verbose {
-            println!("reading input LiDAR file...");
+            progress::message("reading input LiDAR file...");
         }
         let input = match LasFile::new(&input_file, "r") {
             Ok(lf) => lf,
@@ -284,7 +279,7 @@ impl WhiteboxTool for LidarSegmentationBasedFilter {
         let start = Instant::now();
 
         if verbose {
-            println!("Performing tophat transform...");
+            progress::message("Performing tophat transform...");
         }
 
         if max_norm_diff < 0f64 {
@@ -307,10 +302,11 @@ impl WhiteboxTool for LidarSegmentationBasedFilter {
             // let p: PointData = input.get_point_info(i);
             let p = input.get_transformed_coords(i);
             frs2d.insert(p.x, p.y, i);
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * i as f64 / num_points) as i32;
                 if progress != old_progress {
-                    println!("Binning points in 2D: {}%", progress);
+                    progress::update_stage("Binning points in 2D", progress);
                     old_progress = progress;
                 }
             }
@@ -332,7 +328,7 @@ impl WhiteboxTool for LidarSegmentationBasedFilter {
             let frs = frs.clone();
             let input = input.clone();
             let tx = tx.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let mut index_n: usize;
                 let mut z_n: f64;
                 let mut min_z: f64;
@@ -348,18 +344,20 @@ impl WhiteboxTool for LidarSegmentationBasedFilter {
                             min_z = z_n;
                         }
                     }
-                    tx.send((point_num, min_z)).unwrap();
+                    if tx.send((point_num, min_z)).is_err() {
+                        return;
+                    }
                 }
             });
         }
 
         for point_num in 0..n_points {
-            let data = rx.recv().expect("Error receiving data from thread.");
+            let data = cancel.recv(&rx)?;
             neighbourhood_min[data.0] = data.1;
             if verbose {
                 progress = (100.0_f64 * point_num as f64 / num_points) as i32;
                 if progress != old_progress {
-                    println!("Erosion: {}%", progress);
+                    progress::update_stage("Erosion", progress);
                     old_progress = progress;
                 }
             }
@@ -372,7 +370,7 @@ impl WhiteboxTool for LidarSegmentationBasedFilter {
             let input = input.clone();
             let neighbourhood_min = neighbourhood_min.clone();
             let tx = tx.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let mut index_n: usize;
                 let mut z_n: f64;
                 let mut max_z: f64;
@@ -388,19 +386,21 @@ impl WhiteboxTool for LidarSegmentationBasedFilter {
                             max_z = z_n;
                         }
                     }
-                    tx.send((point_num, max_z)).unwrap();
+                    if tx.send((point_num, max_z)).is_err() {
+                        return;
+                    }
                 }
             });
         }
 
         for point_num in 0..n_points {
-            let data = rx.recv().expect("Error receiving data from thread.");
+            let data = cancel.recv(&rx)?;
             let z = input.get_transformed_coords(data.0).z;
             residuals[data.0] = z - data.1;
             if verbose {
                 progress = (100.0_f64 * point_num as f64 / num_points) as i32;
                 if progress != old_progress {
-                    println!("Dilation: {}%", progress);
+                    progress::update_stage("Dilation", progress);
                     old_progress = progress;
                 }
             }
@@ -410,7 +410,7 @@ impl WhiteboxTool for LidarSegmentationBasedFilter {
         // Calculate the normals for each point in the dataset //
         /////////////////////////////////////////////////////////
         if verbose {
-            println!("Calculating point normals...");
+            progress::message("Calculating point normals...");
         }
         let mut frs3d: FixedRadiusSearch3D<usize> =
             FixedRadiusSearch3D::new(search_radius as f64, DistanceMetric::SquaredEuclidean);
@@ -419,10 +419,11 @@ impl WhiteboxTool for LidarSegmentationBasedFilter {
             let p = input.get_transformed_coords(point_num);
             frs3d.insert(p.x, p.y, residuals[point_num], point_num);
             // using the top-hat transformed data to calculate normals
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * point_num as f64 / num_points) as i32;
                 if progress != old_progress {
-                    println!("Binning points in 3D: {}%", progress);
+                    progress::update_stage("Binning points in 3D", progress);
                     old_progress = progress;
                 }
             }
@@ -436,7 +437,7 @@ impl WhiteboxTool for LidarSegmentationBasedFilter {
             let input = input.clone();
             let residuals = residuals.clone();
             let tx = tx.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let mut index_n: usize;
                 for point_num in (0..n_points).filter(|point_num| point_num % num_procs == tid) {
                     // let p: PointData = input.get_point_info(point_num);
@@ -449,7 +450,9 @@ impl WhiteboxTool for LidarSegmentationBasedFilter {
                         let p2 = input.get_transformed_coords(index_n);
                         data.push(Vector3::new(p2.x, p2.y, residuals[index_n]));
                     }
-                    tx.send((point_num, plane_from_points(&data))).unwrap();
+                    if tx.send((point_num, plane_from_points(&data))).is_err() {
+                        return;
+                    }
                 }
             });
         }
@@ -458,7 +461,7 @@ impl WhiteboxTool for LidarSegmentationBasedFilter {
         let mut is_ground_point = vec![false; n_points];
         let mut stack = Vec::with_capacity(n_points);
         for point_num in 0..n_points {
-            let data = rx.recv().expect("Error receiving data from thread.");
+            let data = cancel.recv(&rx)?;
             normal_vectors[data.0] = data.1;
             if residuals[data.0] == 0f64 {
                 is_ground_point[data.0] = true;
@@ -467,7 +470,7 @@ impl WhiteboxTool for LidarSegmentationBasedFilter {
             if verbose {
                 progress = (100.0_f64 * point_num as f64 / num_points) as i32;
                 if progress != old_progress {
-                    println!("Calculating point normals: {}%", progress);
+                    progress::update_stage("Calculating point normals", progress);
                     old_progress = progress;
                 }
             }
@@ -477,7 +480,7 @@ impl WhiteboxTool for LidarSegmentationBasedFilter {
         // Perform the segmentation operation //
         ////////////////////////////////////////
         if verbose {
-            println!("Segmenting the point cloud...");
+            progress::message("Segmenting the point cloud...");
         }
         let mut point_id: usize;
         let mut norm_diff: f64;
@@ -511,10 +514,11 @@ impl WhiteboxTool for LidarSegmentationBasedFilter {
                 }
             }
 
+            cancel.check()?;
             if verbose {
                 progress = (100f64 * (1f64 - stack.len() as f64 / num_points)) as i32;
                 if progress != old_progress {
-                    println!("Segmenting the point cloud: {}%", progress);
+                    progress::update_stage("Segmenting the point cloud", progress);
                     old_progress = progress;
                 }
             }
@@ -534,10 +538,11 @@ impl WhiteboxTool for LidarSegmentationBasedFilter {
                 } else {
                     num_points_filtered += 1;
                 }
+                cancel.check()?;
                 if verbose {
                     progress = (100.0_f64 * point_num as f64 / num_points) as i32;
                     if progress != old_progress {
-                        println!("Saving data: {}%", progress);
+                        progress::update_stage("Saving data", progress);
                         old_progress = progress;
                     }
                 }
@@ -678,10 +683,11 @@ impl WhiteboxTool for LidarSegmentationBasedFilter {
                     }
                 }
                 output.add_point_record(pr2);
+                cancel.check()?;
                 if verbose {
                     progress = (100.0_f64 * point_num as f64 / num_points) as i32;
                     if progress != old_progress {
-                        println!("Saving data: {}%", progress);
+                        progress::update_stage("Saving data", progress);
                         old_progress = progress;
                     }
                 }
@@ -690,28 +696,25 @@ impl WhiteboxTool for LidarSegmentationBasedFilter {
         }
 
         if num_points_filtered == 0 {
-            println!("Warning: No points were filtered from the point cloud.");
+            progress::warning("No points were filtered from the point cloud.");
         }
 
         let elapsed_time = get_formatted_elapsed_time(start);
 
-        println!("");
+        progress::message("");
         if verbose {
-            println!("Writing output LAS file...");
+            progress::message("Writing output LAS file...");
         }
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Complete!")
+                    progress::message("Complete!")
                 }
             }
-            Err(e) => println!("error while writing: {:?}", e),
+            Err(e) => progress::message(format!("error while writing: {:?}", e)),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
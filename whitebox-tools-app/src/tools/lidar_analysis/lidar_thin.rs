@@ -14,6 +14,7 @@ high variability in point density, consider using the LidarThinHighDesnity tool
 
 use whitebox_lidar::*;
 use whitebox_common::structures::{Array2D, Point3D};
+use whitebox_common::progress;
 use crate::tools::*;
 use std::env;
 use std::f64;
@@ -149,6 +150,7 @@ impl WhiteboxTool for LidarThin {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file: String = "".to_string();
         let mut output_file: String = "".to_string();
@@ -227,22 +229,16 @@ impl WhiteboxTool for LidarThin {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
-        println!("reading input LiDAR file...");
+        progress::message("reading input LiDAR file...");
         let input = match LasFile::new(&input_file, "r") {
             Ok(lf) => lf,
             Err(err) => panic!("Error reading file {}: {}", input_file, err),
         };
 
-        println!("Performing analysis...");
+        progress::message("Performing analysis...");
 
         // // Make sure that the input LAS file have GPS time data?
         // if input.header.point_format == 0u8 || input.header.point_format == 2u8 {
@@ -283,10 +279,11 @@ impl WhiteboxTool for LidarThin {
                         pt_id.set_value(row, col, i);
                         filtered[i] = false;
                     }
+                    cancel.check()?;
                     if verbose {
                         progress = (100.0_f64 * i as f64 / num_points) as usize;
                         if progress != old_progress {
-                            println!("Progress: {}%", progress);
+                            progress::update(progress);
                             old_progress = progress;
                         }
                     }
@@ -307,10 +304,11 @@ impl WhiteboxTool for LidarThin {
                         pt_id.set_value(row, col, i);
                         filtered[prev_id] = true;
                     }
+                    cancel.check()?;
                     if verbose {
                         progress = (100.0_f64 * i as f64 / num_points) as usize;
                         if progress != old_progress {
-                            println!("Progress: {}%", progress);
+                            progress::update(progress);
                             old_progress = progress;
                         }
                     }
@@ -333,10 +331,11 @@ impl WhiteboxTool for LidarThin {
                     } else {
                         filtered[i] = true;
                     }
+                    cancel.check()?;
                     if verbose {
                         progress = (100.0_f64 * i as f64 / num_points) as usize;
                         if progress != old_progress {
-                            println!("Progress: {}%", progress);
+                            progress::update(progress);
                             old_progress = progress;
                         }
                     }
@@ -359,10 +358,11 @@ impl WhiteboxTool for LidarThin {
                     } else {
                         filtered[i] = true;
                     }
+                    cancel.check()?;
                     if verbose {
                         progress = (100.0_f64 * i as f64 / num_points) as usize;
                         if progress != old_progress {
-                            println!("Progress: {}%", progress);
+                            progress::update(progress);
                             old_progress = progress;
                         }
                     }
@@ -396,10 +396,11 @@ impl WhiteboxTool for LidarThin {
                     } else {
                         filtered[i] = true;
                     }
+                    cancel.check()?;
                     if verbose {
                         progress = (100.0_f64 * i as f64 / num_points) as usize;
                         if progress != old_progress {
-                            println!("Progress: {}%", progress);
+                            progress::update(progress);
                             old_progress = progress;
                         }
                     }
@@ -427,10 +428,11 @@ impl WhiteboxTool for LidarThin {
                 if !filtered[i] {
                     output.add_point_record(input.get_record(i));
                 }
+                cancel.check()?;
                 if verbose {
                     progress = (100.0_f64 * i as f64 / num_points) as usize;
                     if progress != old_progress {
-                        println!("Saving data: {}%", progress);
+                        progress::update_stage("Saving data", progress);
                         old_progress = progress;
                     }
                 }
@@ -451,10 +453,11 @@ impl WhiteboxTool for LidarThin {
                 } else {
                     filtered_output.add_point_record(input.get_record(i));
                 }
+                cancel.check()?;
                 if verbose {
                     progress = (100.0_f64 * i as f64 / num_points) as usize;
                     if progress != old_progress {
-                        println!("Saving data: {}%", progress);
+                        progress::update_stage("Saving data", progress);
                         old_progress = progress;
                     }
                 }
@@ -463,24 +466,24 @@ impl WhiteboxTool for LidarThin {
             end = get_formatted_elapsed_time(start);
 
             let _ = match filtered_output.write() {
-                Ok(_) => println!("Filtered points LAS file saved"),
-                Err(e) => println!("error while writing: {:?}", e),
+                Ok(_) => progress::message("Filtered points LAS file saved"),
+                Err(e) => progress::message(format!("error while writing: {:?}", e)),
             };
         }
 
         if verbose {
-            println!("Writing output LAS file...");
+            progress::message("Writing output LAS file...");
         }
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Complete!")
+                    progress::message("Complete!")
                 }
             }
-            Err(e) => println!("error while writing: {:?}", e),
+            Err(e) => progress::message(format!("error while writing: {:?}", e)),
         };
         if verbose {
-            println!("{}", &format!("Elapsed Time (excluding I/O): {}", end));
+            progress::message(format!("Elapsed Time (excluding I/O): {}", end));
         }
 
         Ok(())
@@ -7,6 +7,7 @@ License: MIT
 */
 
 use whitebox_lidar::*;
+use whitebox_common::progress;
 use crate::tools::*;
 use std::collections::hash_map::Entry::{Occupied, Vacant};
 use std::collections::HashMap;
@@ -144,6 +145,7 @@ impl WhiteboxTool for LidarThinHighDensity {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file: String = "".to_string();
         let mut output_file: String = "".to_string();
@@ -213,13 +215,7 @@ impl WhiteboxTool for LidarThinHighDensity {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep = path::MAIN_SEPARATOR;
@@ -231,7 +227,7 @@ impl WhiteboxTool for LidarThinHighDensity {
         }
 
         if verbose {
-            println!("reading input LiDAR file...");
+            progress::message("reading input LiDAR file...");
         }
         let input = match LasFile::new(&input_file, "r") {
             Ok(lf) => lf,
@@ -241,7 +237,7 @@ impl WhiteboxTool for LidarThinHighDensity {
         let start = Instant::now();
 
         if verbose {
-            println!("Performing analysis...");
+            progress::message("Performing analysis...");
         }
 
         let west: f64 = input.header.min_x;
@@ -276,10 +272,11 @@ impl WhiteboxTool for LidarThinHighDensity {
                 Occupied(entry) => entry.into_mut(),
             };
             val.push(SearchEntry { z: p.z, index: i });
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * i as f64 / num_points) as i32;
                 if progress != old_progress {
-                    println!("Binning points: {}%", progress);
+                    progress::update_stage("Binning points", progress);
                     old_progress = progress;
                 }
             }
@@ -339,7 +336,7 @@ impl WhiteboxTool for LidarThinHighDensity {
                     if verbose {
                         progress = (100.0_f64 * num_solved_points as f64 / num_points) as i32;
                         if progress != old_progress {
-                            println!("Progress: {}%", progress);
+                            progress::update(progress);
                             old_progress = progress;
                         }
                     }
@@ -358,10 +355,11 @@ impl WhiteboxTool for LidarThinHighDensity {
                 if !filtered[i] {
                     output.add_point_record(input.get_record(i));
                 }
+                cancel.check()?;
                 if verbose {
                     progress = (100.0_f64 * i as f64 / num_points) as i32;
                     if progress != old_progress {
-                        println!("Saving data: {}%", progress);
+                        progress::update_stage("Saving data", progress);
                         old_progress = progress;
                     }
                 }
@@ -382,10 +380,11 @@ impl WhiteboxTool for LidarThinHighDensity {
                 } else {
                     filtered_output.add_point_record(input.get_record(i));
                 }
+                cancel.check()?;
                 if verbose {
                     progress = (100.0_f64 * i as f64 / num_points) as i32;
                     if progress != old_progress {
-                        println!("Saving data: {}%", progress);
+                        progress::update_stage("Saving data", progress);
                         old_progress = progress;
                     }
                 }
@@ -394,24 +393,24 @@ impl WhiteboxTool for LidarThinHighDensity {
             end = get_formatted_elapsed_time(start);
 
             let _ = match filtered_output.write() {
-                Ok(_) => println!("Filtered points LAS file saved"),
-                Err(e) => println!("error while writing: {:?}", e),
+                Ok(_) => progress::message("Filtered points LAS file saved"),
+                Err(e) => progress::message(format!("error while writing: {:?}", e)),
             };
         }
 
         if verbose {
-            println!("Writing output LAS file...");
+            progress::message("Writing output LAS file...");
         }
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Complete!")
+                    progress::message("Complete!")
                 }
             }
-            Err(e) => println!("error while writing: {:?}", e),
+            Err(e) => progress::message(format!("error while writing: {:?}", e)),
         };
         if verbose {
-            println!("{}", &format!("Elapsed Time (excluding I/O): {}", end));
+            progress::message(format!("Elapsed Time (excluding I/O): {}", end));
         }
 
         Ok(())
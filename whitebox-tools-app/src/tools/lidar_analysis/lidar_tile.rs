@@ -6,6 +6,7 @@ Last Modified: 05/02/2019
 License: MIT
 */
 use whitebox_lidar::*;
+use whitebox_common::progress;
 use crate::tools::*;
 use std;
 use std::env;
@@ -50,7 +51,7 @@ impl LidarTile {
 
         parameters.push(ToolParameter {
             name: "Tile Width".to_owned(),
-            flags: vec!["--width".to_owned()],
+            flags: vec!["--width_x".to_owned(), "--width".to_owned()],
             description: "Width of tiles in the X dimension; default 1000.0.".to_owned(),
             parameter_type: ParameterType::Float,
             default_value: Some("1000.0".to_owned()),
@@ -59,7 +60,7 @@ impl LidarTile {
 
         parameters.push(ToolParameter {
             name: "Tile Height".to_owned(),
-            flags: vec!["--height".to_owned()],
+            flags: vec!["--width_y".to_owned(), "--height".to_owned()],
             description: "Height of tiles in the Y dimension.".to_owned(),
             parameter_type: ParameterType::Float,
             default_value: Some("1000.0".to_owned()),
@@ -159,6 +160,7 @@ impl WhiteboxTool for LidarTile {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file: String = String::new();
         let mut width_x = 1000.0;
@@ -257,13 +259,7 @@ impl WhiteboxTool for LidarTile {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep = std::path::MAIN_SEPARATOR;
@@ -281,7 +277,7 @@ impl WhiteboxTool for LidarTile {
         }
 
         if verbose {
-            println!("Performing analysis...");
+            progress::message("Performing analysis...");
         }
 
         let input = match LasFile::new(&input_file, "r") {
@@ -324,10 +320,11 @@ impl WhiteboxTool for LidarTile {
             row = (((p.y - origin_y) / width_y) - start_y_grid).floor() as usize; // relative to the grid edge
             tile_data[i] = row * cols + col;
 
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * i as f64 / num_points) as i32;
                 if progress != old_progress {
-                    println!("Progress (Loop 1 of 3): {}%", progress);
+                    progress::update_stage("Progress (Loop 1 of 3)", progress);
                     old_progress = progress;
                 }
             }
@@ -345,10 +342,11 @@ impl WhiteboxTool for LidarTile {
             if first_point_num[tile_data[i]] == n_points_plus_one {
                 first_point_num[tile_data[i]] = i;
             }
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * i as f64 / num_points) as i32;
                 if progress != old_progress {
-                    println!("Progress (Loop 2 of 3): {}%", progress);
+                    progress::update_stage("Progress (Loop 2 of 3)", progress);
                     old_progress = progress;
                 }
             }
@@ -422,10 +420,11 @@ impl WhiteboxTool for LidarTile {
                 num_tiles_created += 1;
             }
 
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * tile_num as f64 / (num_tiles - 1) as f64) as i32;
                 if progress != old_progress {
-                    println!("Progress (Loop 3 of 3): {}%", progress);
+                    progress::update_stage("Progress (Loop 3 of 3)", progress);
                     old_progress = progress;
                 }
             }
@@ -433,7 +432,7 @@ impl WhiteboxTool for LidarTile {
 
         if num_tiles_created > 0 {
             if verbose {
-                println!("Successfully created {} tiles.", num_tiles_created);
+                progress::message(format!("Successfully created {} tiles.", num_tiles_created));
             }
         } else if num_tiles_created == 0 {
             return Err(Error::new(
@@ -9,6 +9,7 @@ License: MIT
 use whitebox_common::algorithms::convex_hull;
 use whitebox_lidar::*;
 use whitebox_common::structures::Point2D;
+use whitebox_common::progress;
 use crate::tools::*;
 use whitebox_vector::ShapefileGeometry;
 use whitebox_vector::*;
@@ -19,7 +20,6 @@ use std::io::{Error, ErrorKind};
 use std::path;
 use std::sync::mpsc::channel;
 use std::sync::{Arc, Mutex};
-use std::thread;
 
 /// This tool can be used to create a vector polygon of the bounding box or convex hull of a LiDAR point cloud (i.e. LAS file).
 /// If the user specified an input file (`--input`) and output file (`--output`), the tool will calculate the footprint,
@@ -80,7 +80,7 @@ impl LidarTileFootprint {
 
         parameters.push(ToolParameter {
             name: "Create Convex Hull Around Points".to_owned(),
-            flags: vec!["--hull".to_owned()],
+            flags: vec!["--convex_hull".to_owned(), "--hull".to_owned()],
             description: "Identify the convex hull around points.".to_owned(),
             parameter_type: ParameterType::Boolean,
             default_value: Some("false".to_string()),
@@ -156,6 +156,7 @@ impl WhiteboxTool for LidarTileFootprint {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file: String = "".to_string();
         let mut output_file: String = "".to_string();
@@ -252,13 +253,7 @@ impl WhiteboxTool for LidarTileFootprint {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let num_tiles = inputs.len();
@@ -277,7 +272,7 @@ impl WhiteboxTool for LidarTileFootprint {
             let tile_list = tile_list.clone();
             let wkt = wkt.clone();
             let tx = tx.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let mut tile = 0;
                 while tile < num_tiles {
                     // Get the next tile up for processing
@@ -291,16 +286,16 @@ impl WhiteboxTool for LidarTileFootprint {
                     let input_file = inputs[tile].replace("\"", "").clone();
 
                     if verbose && num_tiles == 1 {
-                        println!("reading input LiDAR file...");
+                        progress::message("reading input LiDAR file...");
                     }
 
                     let path = path::Path::new(&input_file);
                     let filenm = path.file_stem().unwrap();
                     let short_filename = filenm.to_str().unwrap().to_string();
                     if verbose && num_tiles > 1 && num_tiles < 500 {
-                        println!("Processing {}", short_filename);
+                        progress::message(format!("Processing {}", short_filename));
                     } else if verbose && num_tiles == 1 && num_tiles < 500 {
-                        println!("Performing analysis...");
+                        progress::message("Performing analysis...");
                     }
                     if is_convex_hull {
                         match LasFile::new(&input_file, "r") {
@@ -308,10 +303,7 @@ impl WhiteboxTool for LidarTileFootprint {
                                 let n_points = input.header.get_number_of_points() as usize;
 
                                 if n_points == 0usize {
-                                    println!(
-                                        "Warning {} does not contain any points.",
-                                        short_filename
-                                    );
+                                    progress::message(format!("Warning {} does not contain any points.", short_filename));
                                 }
 
                                 // read the points into a Vec<Point2D>
@@ -335,26 +327,28 @@ impl WhiteboxTool for LidarTileFootprint {
                                     *data = input.get_wkt();
                                 }
                                 // send the data to the main thread to be output
-                                tx.send((
+                                if tx.send((
                                     hull_points,
                                     short_filename,
                                     n_points,
                                     input.header.min_z,
                                     input.header.max_z,
                                     input.get_wkt(),
-                                ))
-                                .unwrap();
+                                )).is_err() {
+                                    return;
+                                }
                             }
                             Err(err) => {
-                                tx.send((
+                                if tx.send((
                                     vec![],
                                     format!("Error reading file {}:\n{}", input_file, err),
                                     0,
                                     0f64,
                                     0f64,
                                     "".to_string(),
-                                ))
-                                .unwrap();
+                                )).is_err() {
+                                    return;
+                                }
                             }
                         };
                     } else {
@@ -368,32 +362,31 @@ impl WhiteboxTool for LidarTileFootprint {
                                 bounding_points.push(Point2D::new(header.min_x, header.max_y));
 
                                 if header.get_number_of_points() == 0u64 {
-                                    println!(
-                                        "Warning {} does not contain any points.",
-                                        short_filename
-                                    );
+                                    progress::message(format!("Warning {} does not contain any points.", short_filename));
                                 }
 
-                                tx.send((
+                                if tx.send((
                                     bounding_points,
                                     short_filename,
                                     header.get_number_of_points() as usize,
                                     header.min_z,
                                     header.max_z,
                                     "".to_string(),
-                                ))
-                                .unwrap();
+                                )).is_err() {
+                                    return;
+                                }
                             }
                             Err(err) => {
-                                tx.send((
+                                if tx.send((
                                     vec![],
                                     format!("Error reading file {}:\n{}", input_file, err),
                                     0,
                                     0f64,
                                     0f64,
                                     "".to_string(),
-                                ))
-                                .unwrap();
+                                )).is_err() {
+                                    return;
+                                }
                             }
                         }
                     }
@@ -457,15 +450,16 @@ impl WhiteboxTool for LidarTileFootprint {
                         }
                     } else {
                         // there was an error, likely reading a LAS file.
-                        println!("{}", data.1);
+                        progress::message(&data.1);
                     }
                 }
-                Err(val) => println!("Error: {:?}", val),
+                Err(val) => progress::message(format!("Error: {:?}", val)),
             }
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * tile as f64 / (num_tiles - 1) as f64) as i32;
                 if progress != old_progress {
-                    println!("Progress ({} of {}): {}%", (tile + 1), num_tiles, progress);
+                    progress::update_stage(&format!("Progress ({} of {})", (tile + 1), num_tiles), progress);
                     old_progress = progress;
                 }
             }
@@ -486,18 +480,18 @@ impl WhiteboxTool for LidarTileFootprint {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!("{}", &format!("Elapsed Time: {}", elapsed_time));
+            progress::message(format!("Elapsed Time: {}", elapsed_time));
         }
 
         Ok(())
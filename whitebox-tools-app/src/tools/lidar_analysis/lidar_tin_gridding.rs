@@ -12,6 +12,7 @@ use whitebox_lidar::*;
 use crate::na;
 use whitebox_raster::*;
 use whitebox_common::spatial_ref_system::esri_wkt_from_epsg;
+use whitebox_common::progress;
 use whitebox_common::structures::{BoundingBox, Point2D};
 use crate::tools::*;
 use num_cpus;
@@ -19,7 +20,7 @@ use std::io::{Error, ErrorKind};
 use std::sync::mpsc;
 // use std::sync::{Arc, Mutex};
 use std::sync::Arc;
-use std::{env, f64, fs, path, thread};
+use std::{env, f64, fs, path};
 
 /// This tool creates a raster grid based on a Delaunay triangular irregular network (TIN) fitted to LiDAR points.
 /// The output grid can be based on any of the stored LiDAR point parameters (`--parameter`), including elevation
@@ -236,6 +237,7 @@ impl WhiteboxTool for LidarTINGridding {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file: String = "".to_string();
         let mut output_file: String = "".to_string();
@@ -384,13 +386,7 @@ impl WhiteboxTool for LidarTINGridding {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let start = Instant::now();
@@ -504,7 +500,7 @@ impl WhiteboxTool for LidarTINGridding {
         }
 
         if verbose {
-            println!("Performing interpolation...");
+            progress::message("Performing interpolation...");
         }
 
         let num_tiles = inputs.len();
@@ -528,7 +524,7 @@ impl WhiteboxTool for LidarTINGridding {
             let exclude_cls_str = exclude_cls_str.clone();
             let include_class_vals = include_class_vals.clone();
             let tx2 = tx2.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 for tile in (0..num_tiles).filter(|t| t % num_procs == tid) {
                     // let mut tile = 0;
                     // while tile < num_tiles {
@@ -556,7 +552,7 @@ impl WhiteboxTool for LidarTINGridding {
                     let mut z_values: Vec<f64> = vec![];
 
                     if verbose && inputs.len() == 1 {
-                        println!("Reading input LiDAR file...");
+                        progress::message("Reading input LiDAR file...");
                     }
 
                     let mut progress: i32;
@@ -611,7 +607,7 @@ impl WhiteboxTool for LidarTINGridding {
                                                     progress =
                                                         (100.0_f64 * i as f64 / num_points) as i32;
                                                     if progress != old_progress {
-                                                        println!("Reading points: {}%", progress);
+                                                        progress::update_stage("Reading points", progress);
                                                         old_progress = progress;
                                                     }
                                                 }
@@ -646,7 +642,7 @@ impl WhiteboxTool for LidarTINGridding {
                                                     progress =
                                                         (100.0_f64 * i as f64 / num_points) as i32;
                                                     if progress != old_progress {
-                                                        println!("Reading points: {}%", progress);
+                                                        progress::update_stage("Reading points", progress);
                                                         old_progress = progress;
                                                     }
                                                 }
@@ -681,7 +677,7 @@ impl WhiteboxTool for LidarTINGridding {
                                                     progress =
                                                         (100.0_f64 * i as f64 / num_points) as i32;
                                                     if progress != old_progress {
-                                                        println!("Reading points: {}%", progress);
+                                                        progress::update_stage("Reading points", progress);
                                                         old_progress = progress;
                                                     }
                                                 }
@@ -716,7 +712,7 @@ impl WhiteboxTool for LidarTINGridding {
                                                     progress =
                                                         (100.0_f64 * i as f64 / num_points) as i32;
                                                     if progress != old_progress {
-                                                        println!("Reading points: {}%", progress);
+                                                        progress::update_stage("Reading points", progress);
                                                         old_progress = progress;
                                                     }
                                                 }
@@ -752,7 +748,7 @@ impl WhiteboxTool for LidarTINGridding {
                                                     progress =
                                                         (100.0_f64 * i as f64 / num_points) as i32;
                                                     if progress != old_progress {
-                                                        println!("Reading points: {}%", progress);
+                                                        progress::update_stage("Reading points", progress);
                                                         old_progress = progress;
                                                     }
                                                 }
@@ -789,7 +785,7 @@ impl WhiteboxTool for LidarTINGridding {
                                                     progress =
                                                         (100.0_f64 * i as f64 / num_points) as i32;
                                                     if progress != old_progress {
-                                                        println!("Reading points: {}%", progress);
+                                                        progress::update_stage("Reading points", progress);
                                                         old_progress = progress;
                                                     }
                                                 }
@@ -827,7 +823,7 @@ impl WhiteboxTool for LidarTINGridding {
                                                     progress =
                                                         (100.0_f64 * i as f64 / num_points) as i32;
                                                     if progress != old_progress {
-                                                        println!("Reading points: {}%", progress);
+                                                        progress::update_stage("Reading points", progress);
                                                         old_progress = progress;
                                                     }
                                                 }
@@ -863,7 +859,7 @@ impl WhiteboxTool for LidarTINGridding {
                                                     progress =
                                                         (100.0_f64 * i as f64 / num_points) as i32;
                                                     if progress != old_progress {
-                                                        println!("Reading points: {}%", progress);
+                                                        progress::update_stage("Reading points", progress);
                                                         old_progress = progress;
                                                     }
                                                 }
@@ -877,11 +873,10 @@ impl WhiteboxTool for LidarTINGridding {
                     }
 
                     if points.len() < 3 {
-                        println!(
-                            "Warning: No eligible points found in {}",
-                            inputs[tile].clone()
-                        );
-                        tx2.send(tile).unwrap();
+                        progress::warning(format!("No eligible points found in {}", inputs[tile].clone()));
+                        if tx2.send(tile).is_err() {
+                            return;
+                        }
                     } else {
                         let west: f64 = bounding_boxes[tile].min_x;
                         let north: f64 = bounding_boxes[tile].max_y;
@@ -918,7 +913,7 @@ impl WhiteboxTool for LidarTINGridding {
 
                         // do the triangulation
                         if num_tiles == 1 && verbose {
-                            println!("Performing triangulation...");
+                            progress::message("Performing triangulation...");
                         }
                         let result = triangulate(&points).expect("No triangulation exists.");
                         let num_triangles = result.triangles.len() / 3;
@@ -1009,7 +1004,7 @@ impl WhiteboxTool for LidarTINGridding {
                                             / (num_triangles - 1) as f64)
                                             as i32;
                                         if progress != old_progress {
-                                            println!("Progress: {}%", progress);
+                                            progress::update(progress);
                                             old_progress = progress;
                                         }
                                     }
@@ -1115,7 +1110,7 @@ impl WhiteboxTool for LidarTINGridding {
                                             / (num_triangles - 1) as f64)
                                             as i32;
                                         if progress != old_progress {
-                                            println!("Progress: {}%", progress);
+                                            progress::update(progress);
                                             old_progress = progress;
                                         }
                                     }
@@ -1142,12 +1137,14 @@ impl WhiteboxTool for LidarTINGridding {
                         ));
 
                         if verbose && inputs.len() == 1 {
-                            println!("Saving data...")
+                            progress::message("Saving data...")
                         };
 
                         let _ = output.write().expect("Error writing file.");
 
-                        tx2.send(tile).unwrap();
+                        if tx2.send(tile).is_err() {
+                            return;
+                        }
                     }
                 }
             });
@@ -1156,33 +1153,23 @@ impl WhiteboxTool for LidarTINGridding {
         let mut progress: i32;
         let mut old_progress: i32 = -1;
         for tile in 0..inputs.len() {
-            let tile_completed = rx2.recv().unwrap();
+            let tile_completed = cancel.recv(&rx2)?;
             if verbose {
                 if tile <= 98 {
-                    println!(
-                        "Finished {} ({} of {})",
-                        inputs[tile_completed]
+                    progress::message(format!("Finished {} ({} of {})", inputs[tile_completed]
                             .replace("\"", "")
                             .replace(working_directory, "")
-                            .replace(".las", ""),
-                        tile + 1,
-                        inputs.len()
-                    );
+                            .replace(".las", ""), tile + 1, inputs.len()));
                 } else if tile == 99 {
-                    println!(
-                        "Finished {} ({} of {})",
-                        inputs[tile_completed]
+                    progress::message(format!("Finished {} ({} of {})", inputs[tile_completed]
                             .replace("\"", "")
                             .replace(working_directory, "")
-                            .replace(".las", ""),
-                        tile + 1,
-                        inputs.len()
-                    );
-                    println!("...");
+                            .replace(".las", ""), tile + 1, inputs.len()));
+                    progress::message("...");
                 }
                 progress = (100.0_f64 * tile as f64 / (inputs.len() - 1) as f64) as i32;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    progress::update(progress);
                     old_progress = progress;
                 }
             }
@@ -1191,10 +1178,7 @@ impl WhiteboxTool for LidarTINGridding {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (including I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (including I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -8,6 +8,7 @@ License: MIT
 
 use whitebox_lidar::*;
 use whitebox_common::structures::{DistanceMetric, FixedRadiusSearch2D};
+use whitebox_common::progress;
 use crate::tools::*;
 use num_cpus;
 use std::env;
@@ -16,7 +17,6 @@ use std::io::{Error, ErrorKind};
 use std::path;
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::thread;
 
 /// This tool performs a white [top-hat transform](https://en.wikipedia.org/wiki/Top-hat_transform) on a LiDAR point cloud (`--input`).
 /// A top-hat transform is a common digital image processing operation used for various tasks, such
@@ -150,6 +150,7 @@ impl WhiteboxTool for LidarTophatTransform {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file: String = "".to_string();
         let mut output_file: String = "".to_string();
@@ -201,13 +202,7 @@ impl WhiteboxTool for LidarTophatTransform {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep = path::MAIN_SEPARATOR;
@@ -219,7 +214,7 @@ impl WhiteboxTool for LidarTophatTransform {
         }
 
         if verbose {
-            println!("reading input LiDAR file...");
+            progress::message("reading input LiDAR file...");
         }
         let input = match LasFile::new(&input_file, "r") {
             Ok(lf) => lf,
@@ -229,7 +224,7 @@ impl WhiteboxTool for LidarTophatTransform {
         let start = Instant::now();
 
         if verbose {
-            println!("Performing analysis...");
+            progress::message("Performing analysis...");
         }
 
         let n_points = input.header.number_of_points as usize;
@@ -246,10 +241,11 @@ impl WhiteboxTool for LidarTophatTransform {
                 // low points really mess with the tophat-transform so exclude classified noise
                 frs.insert(p.x, p.y, i);
             }
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * i as f64 / num_points) as i32;
                 if progress != old_progress {
-                    println!("Binning points: {}%", progress);
+                    progress::update_stage("Binning points", progress);
                     old_progress = progress;
                 }
             }
@@ -269,7 +265,7 @@ impl WhiteboxTool for LidarTophatTransform {
             let frs = frs.clone();
             let input = input.clone();
             let tx = tx.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let mut index_n: usize;
                 let mut z_n: f64;
                 let mut min_z: f64;
@@ -290,18 +286,20 @@ impl WhiteboxTool for LidarTophatTransform {
                     } else {
                         min_z = 0f64;
                     }
-                    tx.send((i, min_z)).unwrap();
+                    if tx.send((i, min_z)).is_err() {
+                        return;
+                    }
                 }
             });
         }
 
         for i in 0..n_points {
-            let data = rx.recv().expect("Error receiving data from thread.");
+            let data = cancel.recv(&rx)?;
             neighbourhood_min[data.0] = data.1;
             if verbose {
                 progress = (100.0_f64 * i as f64 / num_points) as i32;
                 if progress != old_progress {
-                    println!("Erosion: {}%", progress);
+                    progress::update_stage("Erosion", progress);
                     old_progress = progress;
                 }
             }
@@ -316,7 +314,7 @@ impl WhiteboxTool for LidarTophatTransform {
             let input = input.clone();
             let neighbourhood_min = neighbourhood_min.clone();
             let tx = tx.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let mut index_n: usize;
                 let mut z_n: f64;
                 let mut max_z: f64;
@@ -337,20 +335,22 @@ impl WhiteboxTool for LidarTophatTransform {
                     } else {
                         max_z = 0f64;
                     }
-                    tx.send((i, max_z)).unwrap();
+                    if tx.send((i, max_z)).is_err() {
+                        return;
+                    }
                 }
             });
         }
 
         for i in 0..n_points {
-            let data = rx.recv().expect("Error receiving data from thread.");
+            let data = cancel.recv(&rx)?;
             // let z = input.get_point_info(data.0).z;
             let z = input.get_transformed_coords(data.0).z;
             residuals[data.0] = z - data.1;
             if verbose {
                 progress = (100.0_f64 * i as f64 / num_points) as i32;
                 if progress != old_progress {
-                    println!("Dilation: {}%", progress);
+                    progress::update_stage("Dilation", progress);
                     old_progress = progress;
                 }
             }
@@ -490,10 +490,11 @@ impl WhiteboxTool for LidarTophatTransform {
                 }
             }
             output.add_point_record(pr2);
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * i as f64 / num_points) as i32;
                 if progress != old_progress {
-                    println!("Saving data: {}%", progress);
+                    progress::update_stage("Saving data", progress);
                     old_progress = progress;
                 }
             }
@@ -502,21 +503,18 @@ impl WhiteboxTool for LidarTophatTransform {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!("Writing output LAS file...");
+            progress::message("Writing output LAS file...");
         }
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Complete!")
+                    progress::message("Complete!")
                 }
             }
-            Err(e) => println!("error while writing: {:?}", e),
+            Err(e) => progress::message(format!("error while writing: {:?}", e)),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -8,6 +8,7 @@ License: MIT
 
 use whitebox_lidar::*;
 use whitebox_common::structures::{DistanceMetric, FixedRadiusSearch2D};
+use whitebox_common::progress;
 use crate::tools::*;
 use num_cpus;
 use std::env;
@@ -16,7 +17,6 @@ use std::io::{Error, ErrorKind};
 use std::path;
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::thread;
 
 /// This tool removes duplicate points from a LiDAR data set. Duplicates are determined
 /// by their x, y, and optionally (`--include_z`) z coordinates.
@@ -135,6 +135,7 @@ impl WhiteboxTool for LidarRemoveDuplicates {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file: String = "".to_string();
         let mut output_file: String = "".to_string();
@@ -179,13 +180,7 @@ impl WhiteboxTool for LidarRemoveDuplicates {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep = path::MAIN_SEPARATOR;
@@ -197,7 +192,7 @@ impl WhiteboxTool for LidarRemoveDuplicates {
         }
 
         if verbose {
-            println!("reading input LiDAR file...");
+            progress::message("reading input LiDAR file...");
         }
         let input = match LasFile::new(&input_file, "r") {
             Ok(lf) => lf,
@@ -207,7 +202,7 @@ impl WhiteboxTool for LidarRemoveDuplicates {
         let start = Instant::now();
 
         if verbose {
-            println!("Performing analysis...");
+            progress::message("Performing analysis...");
         }
 
         let n_points = input.header.number_of_points as usize;
@@ -221,10 +216,11 @@ impl WhiteboxTool for LidarRemoveDuplicates {
             // let p: PointData = input.get_point_info(i);
             let p = input.get_transformed_coords(i);
             frs.insert(p.x, p.y, i);
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * i as f64 / num_points) as i32;
                 if progress != old_progress {
-                    println!("Binning points: {}%", progress);
+                    progress::update_stage("Binning points", progress);
                     old_progress = progress;
                 }
             }
@@ -238,7 +234,7 @@ impl WhiteboxTool for LidarRemoveDuplicates {
             let frs = frs.clone();
             let input = input.clone();
             let tx = tx.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let mut index_n: usize;
                 let mut dup: bool;
                 for point_num in (0..n_points).filter(|point_num| point_num % num_procs == tid) {
@@ -264,19 +260,21 @@ impl WhiteboxTool for LidarRemoveDuplicates {
                             }
                         }
                     }
-                    tx.send((point_num, dup)).unwrap();
+                    if tx.send((point_num, dup)).is_err() {
+                        return;
+                    }
                 }
             });
         }
 
         let mut is_duplicate = vec![false; n_points];
         for i in 0..n_points {
-            let data = rx.recv().expect("Error receiving data from thread.");
+            let data = cancel.recv(&rx)?;
             is_duplicate[data.0] = data.1;
             if verbose {
                 progress = (100.0_f64 * i as f64 / num_points) as i32;
                 if progress != old_progress {
-                    println!("Erosion: {}%", progress);
+                    progress::update_stage("Erosion", progress);
                     old_progress = progress;
                 }
             }
@@ -290,10 +288,11 @@ impl WhiteboxTool for LidarRemoveDuplicates {
             if !is_duplicate[i] {
                 output.add_point_record(input.get_record(i));
             }
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * i as f64 / num_points) as i32;
                 if progress != old_progress {
-                    println!("Saving data: {}%", progress);
+                    progress::update_stage("Saving data", progress);
                     old_progress = progress;
                 }
             }
@@ -302,21 +301,18 @@ impl WhiteboxTool for LidarRemoveDuplicates {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!("Writing output LAS file...");
+            progress::message("Writing output LAS file...");
         }
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Complete!")
+                    progress::message("Complete!")
                 }
             }
-            Err(e) => println!("error while writing: {:?}", e),
+            Err(e) => progress::message(format!("error while writing: {:?}", e)),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
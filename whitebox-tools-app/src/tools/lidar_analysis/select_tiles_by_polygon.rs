@@ -7,6 +7,7 @@ License: MIT
 */
 
 use whitebox_common::algorithms;
+use whitebox_common::progress;
 use whitebox_lidar::*;
 use whitebox_common::structures::{BoundingBox, Point2D};
 use crate::tools::*;
@@ -19,7 +20,6 @@ use std::io::{Error, ErrorKind};
 use std::path;
 use std::sync::mpsc;
 use std::sync::{Arc, Mutex};
-use std::thread;
 
 /// This tool copies LiDAR tiles overlapping with a polygon into an output directory. In actuality, the tool performs
 /// point-in-polygon operations, using the four corner points, the center point, and the four mid-edge points of each
@@ -68,7 +68,7 @@ impl SelectTilesByPolygon {
 
         parameters.push(ToolParameter {
             name: "Input Vector Polygon File".to_owned(),
-            flags: vec!["--polygons".to_owned()],
+            flags: vec!["--polygon".to_owned(), "--polygons".to_owned()],
             description: "Input vector polygons file.".to_owned(),
             parameter_type: ParameterType::ExistingFile(ParameterFileType::Vector(
                 VectorGeometryType::Polygon,
@@ -142,6 +142,7 @@ impl WhiteboxTool for SelectTilesByPolygon {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_directory: String = "".to_string();
         let mut output_directory: String = "".to_string();
@@ -197,13 +198,7 @@ impl WhiteboxTool for SelectTilesByPolygon {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let mut inputs = vec![];
@@ -275,7 +270,7 @@ impl WhiteboxTool for SelectTilesByPolygon {
             // copy over the string parameters
             let input_directory = input_directory.clone();
             let output_directory = output_directory.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let mut point_in_poly: bool;
                 let mut start_point_in_part: usize;
                 let mut end_point_in_part: usize;
@@ -433,11 +428,7 @@ impl WhiteboxTool for SelectTilesByPolygon {
                                     let report_copy =
                                         report_copy.lock().expect("Error unlocking mutex");
                                     if *report_copy {
-                                        println!(
-                                            "Copied \"{}\" to \"{}\"",
-                                            input_file.replace(&input_directory, "").clone(),
-                                            output_directory.clone()
-                                        )
+                                        progress::message(format!("Copied \"{}\" to \"{}\"", input_file.replace(&input_directory, "").clone(), output_directory.clone()))
                                     }
                                 }
                             }
@@ -445,7 +436,9 @@ impl WhiteboxTool for SelectTilesByPolygon {
                         }
                     }
 
-                    tx.send(point_in_poly).unwrap();
+                    if tx.send(point_in_poly).is_err() {
+                        return;
+                    }
                 }
             });
         }
@@ -454,12 +447,12 @@ impl WhiteboxTool for SelectTilesByPolygon {
         let mut old_progress: i32 = -1;
         let mut num_files_copied = 0;
         for tile in 0..num_tiles {
-            let in_poly = rx.recv().expect("Error receiving data from thread.");
+            let in_poly = cancel.recv(&rx)?;
             if in_poly {
                 num_files_copied += 1;
                 if num_files_copied == 50 {
                     if verbose {
-                        println!("...");
+                        progress::message("...");
                     }
                     let mut report_copy = report_copy.lock().expect("Error unlocking mutex");
                     *report_copy = false;
@@ -468,7 +461,7 @@ impl WhiteboxTool for SelectTilesByPolygon {
             if verbose {
                 progress = (100.0_f64 * tile as f64 / (num_tiles - 1) as f64) as i32;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    progress::update(progress);
                     old_progress = progress;
                 }
             }
@@ -477,9 +470,9 @@ impl WhiteboxTool for SelectTilesByPolygon {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!("Number of files copied: {}", num_files_copied);
+            progress::message(format!("Number of files copied: {}", num_files_copied));
 
-            println!("{}", &format!("Elapsed Time: {}", elapsed_time));
+            progress::message(format!("Elapsed Time: {}", elapsed_time));
         }
 
         Ok(())
@@ -7,12 +7,13 @@ License: MIT
 */
 
 use whitebox_lidar::*;
+use whitebox_common::progress;
 use crate::tools::*;
 use std;
 use std::io::{Error, ErrorKind};
 use std::sync::mpsc;
 use std::sync::{Arc, Mutex};
-use std::{env, fs, path, thread};
+use std::{env, fs, path};
 
 /// This tool can be used to convert one or more *zLidar* files ('*.zlidar') files into the *LAS*
 /// LiDAR data format. [zLidar](https://jblindsay.github.io/zLidar_spec/intro.html) files are a compressed
@@ -42,7 +43,7 @@ impl ZlidarToLas {
         let mut parameters = vec![];
         parameters.push(ToolParameter {
             name: "Input ZLidar Files".to_owned(),
-            flags: vec!["-i".to_owned(), "--inputs".to_owned()],
+            flags: vec!["-i".to_owned(), "--input".to_owned(), "--inputs".to_owned()],
             description: "Input ZLidar files.".to_owned(),
             parameter_type: ParameterType::FileList(ParameterFileType::Lidar),
             default_value: None,
@@ -128,6 +129,7 @@ impl WhiteboxTool for ZlidarToLas {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_files: String = String::new();
         let mut output_directory: String = String::new();
@@ -166,13 +168,7 @@ impl WhiteboxTool for ZlidarToLas {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep = std::path::MAIN_SEPARATOR;
@@ -227,7 +223,7 @@ impl WhiteboxTool for ZlidarToLas {
         for a in (0..inputs.len()).rev() {
             if !inputs[a].to_lowercase().ends_with(".zlidar") {
                 if verbose {
-                    println!("WARNGING: Non-zLidar input file detected. This file will be excluded from the analysis.");
+                    progress::message("WARNGING: Non-zLidar input file detected. This file will be excluded from the analysis.");
                 }
                 inputs.remove(a);
             }
@@ -258,7 +254,7 @@ impl WhiteboxTool for ZlidarToLas {
             let working_directory = working_directory.clone();
             let output_directory = output_directory.clone();
             let tx = tx.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let mut k = 0;
                 let mut progress: usize;
                 let mut old_progress: usize = 1;
@@ -304,7 +300,7 @@ impl WhiteboxTool for ZlidarToLas {
                                 progress =
                                     (100.0_f64 * (p + 1) as f64 / (n_points - 1) as f64) as usize;
                                 if progress != old_progress {
-                                    println!("Creating output: {}%", progress);
+                                    progress::update_stage("Creating output", progress);
                                     old_progress = progress;
                                 }
                             }
@@ -313,11 +309,15 @@ impl WhiteboxTool for ZlidarToLas {
                             Ok(_) => {
                                 // do nothing
                             }
-                            Err(e) => println!("error while writing: {:?}", e),
+                            Err(e) => progress::message(format!("error while writing: {:?}", e)),
                         };
-                        tx.send(short_filename.clone()).unwrap();
+                        if tx.send(short_filename.clone()).is_err() {
+                            return;
+                        }
                     } else {
-                        tx.send(format!("Empty file name for tile {}.", k)).unwrap();
+                        if tx.send(format!("Empty file name for tile {}.", k)).is_err() {
+                            return;
+                        }
                     }
                 }
             });
@@ -326,19 +326,19 @@ impl WhiteboxTool for ZlidarToLas {
         let mut progress: usize;
         let mut old_progress: usize = 1;
         for tile in 0..num_files {
-            let file_nm = rx.recv().expect("Error receiving data from thread.");
+            let file_nm = cancel.recv(&rx)?;
             if verbose && !file_nm.contains("Empty") && num_files > 1 && tile < 99 {
-                println!("Completed conversion of {}", file_nm);
+                progress::message(format!("Completed conversion of {}", file_nm));
             } else if verbose && tile == 99 {
-                println!("Completed conversion of {}", file_nm);
-                println!("...");
+                progress::message(format!("Completed conversion of {}", file_nm));
+                progress::message("...");
             } else if file_nm.to_lowercase().contains("empty file name") {
-                println!("{}", file_nm);
+                progress::message(&file_nm);
             }
             if verbose {
                 progress = (100.0_f64 * tile as f64 / (num_files - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    progress::update(progress);
                     old_progress = progress;
                 }
             }
@@ -346,7 +346,7 @@ impl WhiteboxTool for ZlidarToLas {
 
         if verbose {
             let elapsed_time = get_formatted_elapsed_time(start);
-            println!("{}", &format!("Elapsed Time: {}", elapsed_time));
+            progress::message(format!("Elapsed Time: {}", elapsed_time));
         }
 
         Ok(())
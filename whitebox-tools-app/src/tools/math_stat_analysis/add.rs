@@ -7,6 +7,7 @@ License: MIT
 */
 
 use whitebox_raster::*;
+use whitebox_common::progress;
 use crate::tools::*;
 use num_cpus;
 use std::env;
@@ -15,7 +16,6 @@ use std::io::{Error, ErrorKind};
 use std::path;
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::thread;
 
 /// This tool creates a new raster in which each grid cell is equal to the addition of the corresponding grid
 /// cells in two input rasters or one input raster and a constant value. If two images are input, both
@@ -45,7 +45,7 @@ impl Add {
         let mut parameters = vec![];
         parameters.push(ToolParameter {
             name: "Input File Or Constant Value".to_owned(),
-            flags: vec!["--input1".to_owned()],
+            flags: vec!["--i1".to_owned(), "--input1".to_owned()],
             description: "Input raster file or constant value.".to_owned(),
             parameter_type: ParameterType::ExistingFileOrFloat(ParameterFileType::Raster),
             default_value: None,
@@ -54,7 +54,7 @@ impl Add {
 
         parameters.push(ToolParameter {
             name: "Input File Or Constant Value".to_owned(),
-            flags: vec!["--input2".to_owned()],
+            flags: vec!["--i2".to_owned(), "--input2".to_owned()],
             description: "Input raster file or constant value.".to_owned(),
             parameter_type: ParameterType::ExistingFileOrFloat(ParameterFileType::Raster),
             default_value: None,
@@ -135,6 +135,7 @@ impl WhiteboxTool for Add {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input1 = String::new();
         let mut input2 = String::new();
@@ -178,13 +179,7 @@ impl WhiteboxTool for Add {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -228,11 +223,11 @@ impl WhiteboxTool for Add {
         if input1_is_constant && input2_is_constant {
             // return Err(Error::new(ErrorKind::InvalidInput,
             //                     "At least one of the inputs must be a raster."));
-            println!("{}", input1_constant + input2_constant);
+            progress::message(input1_constant + input2_constant);
             return Ok(());
         } else if input1_is_constant && !input2_is_constant {
             if verbose {
-                println!("Reading data...")
+                progress::message("Reading data...")
             };
             let in2 = Arc::new(Raster::new(&input2, "r")?);
 
@@ -251,7 +246,7 @@ impl WhiteboxTool for Add {
             for tid in 0..num_procs {
                 let in2 = in2.clone();
                 let tx = tx.clone();
-                thread::spawn(move || {
+                progress::spawn(move || {
                     let mut z2: f64;
                     for row in (0..rows).filter(|r| r % num_procs == tid) {
                         let mut data: Vec<f64> = vec![nodata2; columns as usize];
@@ -263,7 +258,9 @@ impl WhiteboxTool for Add {
                                 data[col as usize] = nodata2;
                             }
                         }
-                        tx.send((row, data)).unwrap();
+                        if tx.send((row, data)).is_err() {
+                            return;
+                        }
                     }
                 });
             }
@@ -277,13 +274,13 @@ impl WhiteboxTool for Add {
                 }
             }
             for r in 0..rows {
-                let (row, data) = rx.recv().expect("Error receiving data from thread.");
+                let (row, data) = cancel.recv(&rx)?;
                 output.set_row_data(row, data);
 
                 if verbose {
                     progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Progress: {}%", progress);
+                        progress::update(progress);
                         old_progress = progress;
                     }
                 }
@@ -297,25 +294,22 @@ impl WhiteboxTool for Add {
             output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
             if verbose {
-                println!("Saving data...")
+                progress::message("Saving data...")
             };
             let _ = match output.write() {
                 Ok(_) => {
                     if verbose {
-                        println!("Output file written")
+                        progress::output_written(&output.file_name)
                     }
                 }
                 Err(e) => return Err(e),
             };
             if verbose {
-                println!(
-                    "{}",
-                    &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-                );
+                progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
             }
         } else if !input1_is_constant && input2_is_constant {
             if verbose {
-                println!("Reading data...")
+                progress::message("Reading data...")
             };
             let in1 = Arc::new(Raster::new(&input1, "r")?);
 
@@ -334,7 +328,7 @@ impl WhiteboxTool for Add {
             for tid in 0..num_procs {
                 let in1 = in1.clone();
                 let tx = tx.clone();
-                thread::spawn(move || {
+                progress::spawn(move || {
                     let mut z1: f64;
                     for row in (0..rows).filter(|r| r % num_procs == tid) {
                         let mut data: Vec<f64> = vec![nodata1; columns as usize];
@@ -346,7 +340,9 @@ impl WhiteboxTool for Add {
                                 data[col as usize] = nodata1;
                             }
                         }
-                        tx.send((row, data)).unwrap();
+                        if tx.send((row, data)).is_err() {
+                            return;
+                        }
                     }
                 });
             }
@@ -360,13 +356,13 @@ impl WhiteboxTool for Add {
                 }
             }
             for r in 0..rows {
-                let (row, data) = rx.recv().expect("Error receiving data from thread.");
+                let (row, data) = cancel.recv(&rx)?;
                 output.set_row_data(row, data);
 
                 if verbose {
                     progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Progress: {}%", progress);
+                        progress::update(progress);
                         old_progress = progress;
                     }
                 }
@@ -380,26 +376,23 @@ impl WhiteboxTool for Add {
             output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
             if verbose {
-                println!("Saving data...")
+                progress::message("Saving data...")
             };
             let _ = match output.write() {
                 Ok(_) => {
                     if verbose {
-                        println!("Output file written")
+                        progress::output_written(&output.file_name)
                     }
                 }
                 Err(e) => return Err(e),
             };
             if verbose {
-                println!(
-                    "{}",
-                    &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-                );
+                progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
             }
         } else {
             // !input1_is_constant && !input2_is_constant
             if verbose {
-                println!("Reading data...")
+                progress::message("Reading data...")
             };
             let in1 = Arc::new(Raster::new(&input1, "r")?);
             let in2 = Arc::new(Raster::new(&input2, "r")?);
@@ -427,7 +420,7 @@ impl WhiteboxTool for Add {
                 let in1 = in1.clone();
                 let in2 = in2.clone();
                 let tx = tx.clone();
-                thread::spawn(move || {
+                progress::spawn(move || {
                     let mut z1: f64;
                     let mut z2: f64;
                     for row in (0..rows).filter(|r| r % num_procs == tid) {
@@ -441,20 +434,22 @@ impl WhiteboxTool for Add {
                                 data[col as usize] = nodata1;
                             }
                         }
-                        tx.send((row, data)).unwrap();
+                        if tx.send((row, data)).is_err() {
+                            return;
+                        }
                     }
                 });
             }
 
             let mut output = Raster::initialize_using_file(&output_file, &in1);
             for r in 0..rows {
-                let (row, data) = rx.recv().expect("Error receiving data from thread.");
+                let (row, data) = cancel.recv(&rx)?;
                 output.set_row_data(row, data);
 
                 if verbose {
                     progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Progress: {}%", progress);
+                        progress::update(progress);
                         old_progress = progress;
                     }
                 }
@@ -468,21 +463,18 @@ impl WhiteboxTool for Add {
             output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
             if verbose {
-                println!("Saving data...")
+                progress::message("Saving data...")
             };
             let _ = match output.write() {
                 Ok(_) => {
                     if verbose {
-                        println!("Output file written")
+                        progress::output_written(&output.file_name)
                     }
                 }
                 Err(e) => return Err(e),
             };
             if verbose {
-                println!(
-                    "{}",
-                    &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-                );
+                progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
             }
         }
 
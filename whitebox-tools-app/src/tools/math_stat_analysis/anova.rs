@@ -7,6 +7,7 @@ License: MIT
 */
 
 use whitebox_raster::*;
+use whitebox_common::progress;
 use crate::tools::*;
 use num_cpus;
 use std::env;
@@ -21,7 +22,6 @@ use std::path::Path;
 use std::process::Command;
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::thread;
 
 /// This tool performs an [Analysis of variance](https://en.wikipedia.org/wiki/Analysis_of_variance) (ANOVA)
 /// test on the distribution of values in a raster (`--input`) among a group of features (`--features`). The
@@ -135,6 +135,7 @@ impl WhiteboxTool for Anova {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut feature_file = String::new();
@@ -179,13 +180,7 @@ impl WhiteboxTool for Anova {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -207,7 +202,7 @@ impl WhiteboxTool for Anova {
         }
 
         if verbose {
-            println!("Reading data...")
+            progress::message("Reading data...")
         };
 
         let input = Arc::new(Raster::new(&input_file, "r")?);
@@ -320,7 +315,7 @@ impl WhiteboxTool for Anova {
             let input = input.clone();
             let features = features.clone();
             let tx = tx.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let mut z: f64;
                 let mut id: f64;
                 let mut id_int: i32;
@@ -346,8 +341,9 @@ impl WhiteboxTool for Anova {
                             overall_sum_sqr += z * z;
                         }
                     }
-                    tx.send((overall_n, overall_sum, overall_sum_sqr, min_id, max_id))
-                        .unwrap();
+                    if tx.send((overall_n, overall_sum, overall_sum_sqr, min_id, max_id)).is_err() {
+                        return;
+                    }
                 }
             });
         }
@@ -358,7 +354,7 @@ impl WhiteboxTool for Anova {
         let mut min_id = i32::max_value();
         let mut max_id = i32::min_value();
         for row in 0..rows {
-            let (a, b, c, d, e) = rx.recv().expect("Error receiving data from thread.");
+            let (a, b, c, d, e) = cancel.recv(&rx)?;
             overall_n += a;
             overall_sum += b;
             overall_sum_sqr += c;
@@ -372,7 +368,7 @@ impl WhiteboxTool for Anova {
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress (loop 1 of 2): {}%", progress);
+                    progress::update_stage("Progress (loop 1 of 2)", progress);
                     old_progress = progress;
                 }
             }
@@ -405,10 +401,11 @@ impl WhiteboxTool for Anova {
                     sum_sqr[vec_id] += z * z;
                 }
             }
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress (loop 2 of 2): {}%", progress);
+                    progress::update_stage("Progress (loop 2 of 2)", progress);
                     old_progress = progress;
                 }
             }
@@ -445,7 +442,7 @@ impl WhiteboxTool for Anova {
         let ms_w = ss_w / df_w as f64;
         let f = ms_b / ms_w;
 
-        println!("Calculating the p-value...");
+        progress::message("Calculating the p-value...");
         // Formula Used:
         // P value = [ 1 / Β(ndf/2,ddf/2) ] × [ (ndf × x) / (ndf × x + ddf) ]^ndf/2 × [1 - (ndf × x) / (ndf × x + ddf) ]^ddf/2 × x-1
         // Where,
@@ -456,7 +453,7 @@ impl WhiteboxTool for Anova {
         // from: https://www.easycalculation.com/statistics/f-test-p-value.php
         let p = f_call(f_spin(f, df_b, df_w));
 
-        println!("Saving output...");
+        progress::message("Saving output...");
 
         s = "<br><table align=\"center\">
         <caption>Group Summaries</caption>
@@ -656,15 +653,12 @@ impl WhiteboxTool for Anova {
                 let _ = output.stdout;
             }
 
-            println!("Complete! Please see {} for output.", output_file);
+            progress::message(format!("Complete! Please see {} for output.", output_file));
         }
 
         let elapsed_time = get_formatted_elapsed_time(start);
         if verbose {
-            println!(
-                "\n{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("\n{}", &format!("Elapsed Time (excluding I/O): {}", elapsed_time)));
         }
 
         Ok(())
@@ -7,6 +7,7 @@ License: MIT
 */
 
 use whitebox_raster::*;
+use whitebox_common::progress;
 use crate::tools::*;
 use num_cpus;
 use std::env;
@@ -15,7 +16,6 @@ use std::io::{Error, ErrorKind};
 use std::path;
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::thread;
 
 /// This tool creates a new raster (`--output`) in which each grid cell is equal to the four quadrant arctangent of
 /// an *y* (`--input_y`) and *x* (`--input_x`) inputs, measured in radians. Inputs may be either rasters or constant values.
@@ -139,6 +139,7 @@ impl WhiteboxTool for Atan2 {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input1 = String::new();
         let mut input2 = String::new();
@@ -182,13 +183,7 @@ impl WhiteboxTool for Atan2 {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -232,11 +227,11 @@ impl WhiteboxTool for Atan2 {
         if input1_is_constant && input2_is_constant {
             // return Err(Error::new(ErrorKind::InvalidInput,
             //                     "At least one of the inputs must be a raster."));
-            println!("{}", input1_constant.atan2(input2_constant));
+            progress::message(input1_constant.atan2(input2_constant));
             return Ok(());
         } else if input1_is_constant && !input2_is_constant {
             if verbose {
-                println!("Reading data...")
+                progress::message("Reading data...")
             };
             let in2 = Arc::new(Raster::new(&input2, "r")?);
 
@@ -255,7 +250,7 @@ impl WhiteboxTool for Atan2 {
             for tid in 0..num_procs {
                 let in2 = in2.clone();
                 let tx = tx.clone();
-                thread::spawn(move || {
+                progress::spawn(move || {
                     let mut z2: f64;
                     for row in (0..rows).filter(|r| r % num_procs == tid) {
                         let mut data: Vec<f64> = vec![nodata2; columns as usize];
@@ -267,20 +262,22 @@ impl WhiteboxTool for Atan2 {
                                 data[col as usize] = nodata2;
                             }
                         }
-                        tx.send((row, data)).unwrap();
+                        if tx.send((row, data)).is_err() {
+                            return;
+                        }
                     }
                 });
             }
 
             let mut output = Raster::initialize_using_file(&output_file, &in2);
             for r in 0..rows {
-                let (row, data) = rx.recv().expect("Error receiving data from thread.");
+                let (row, data) = cancel.recv(&rx)?;
                 output.set_row_data(row, data);
 
                 if verbose {
                     progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Progress: {}%", progress);
+                        progress::update(progress);
                         old_progress = progress;
                     }
                 }
@@ -294,25 +291,22 @@ impl WhiteboxTool for Atan2 {
             output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
             if verbose {
-                println!("Saving data...")
+                progress::message("Saving data...")
             };
             let _ = match output.write() {
                 Ok(_) => {
                     if verbose {
-                        println!("Output file written")
+                        progress::output_written(&output.file_name)
                     }
                 }
                 Err(e) => return Err(e),
             };
             if verbose {
-                println!(
-                    "{}",
-                    &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-                );
+                progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
             }
         } else if !input1_is_constant && input2_is_constant {
             if verbose {
-                println!("Reading data...")
+                progress::message("Reading data...")
             };
             let in1 = Arc::new(Raster::new(&input1, "r")?);
 
@@ -331,7 +325,7 @@ impl WhiteboxTool for Atan2 {
             for tid in 0..num_procs {
                 let in1 = in1.clone();
                 let tx = tx.clone();
-                thread::spawn(move || {
+                progress::spawn(move || {
                     let mut z1: f64;
                     for row in (0..rows).filter(|r| r % num_procs == tid) {
                         let mut data: Vec<f64> = vec![nodata1; columns as usize];
@@ -343,20 +337,22 @@ impl WhiteboxTool for Atan2 {
                                 data[col as usize] = nodata1;
                             }
                         }
-                        tx.send((row, data)).unwrap();
+                        if tx.send((row, data)).is_err() {
+                            return;
+                        }
                     }
                 });
             }
 
             let mut output = Raster::initialize_using_file(&output_file, &in1);
             for r in 0..rows {
-                let (row, data) = rx.recv().expect("Error receiving data from thread.");
+                let (row, data) = cancel.recv(&rx)?;
                 output.set_row_data(row, data);
 
                 if verbose {
                     progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Progress: {}%", progress);
+                        progress::update(progress);
                         old_progress = progress;
                     }
                 }
@@ -370,26 +366,23 @@ impl WhiteboxTool for Atan2 {
             output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
             if verbose {
-                println!("Saving data...")
+                progress::message("Saving data...")
             };
             let _ = match output.write() {
                 Ok(_) => {
                     if verbose {
-                        println!("Output file written")
+                        progress::output_written(&output.file_name)
                     }
                 }
                 Err(e) => return Err(e),
             };
             if verbose {
-                println!(
-                    "{}",
-                    &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-                );
+                progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
             }
         } else {
             // !input1_is_constant && !input2_is_constant
             if verbose {
-                println!("Reading data...")
+                progress::message("Reading data...")
             };
             let in1 = Arc::new(Raster::new(&input1, "r")?);
             let in2 = Arc::new(Raster::new(&input2, "r")?);
@@ -417,7 +410,7 @@ impl WhiteboxTool for Atan2 {
                 let in1 = in1.clone();
                 let in2 = in2.clone();
                 let tx = tx.clone();
-                thread::spawn(move || {
+                progress::spawn(move || {
                     let mut z1: f64;
                     let mut z2: f64;
                     for row in (0..rows).filter(|r| r % num_procs == tid) {
@@ -431,20 +424,22 @@ impl WhiteboxTool for Atan2 {
                                 data[col as usize] = nodata1;
                             }
                         }
-                        tx.send((row, data)).unwrap();
+                        if tx.send((row, data)).is_err() {
+                            return;
+                        }
                     }
                 });
             }
 
             let mut output = Raster::initialize_using_file(&output_file, &in1);
             for r in 0..rows {
-                let (row, data) = rx.recv().expect("Error receiving data from thread.");
+                let (row, data) = cancel.recv(&rx)?;
                 output.set_row_data(row, data);
 
                 if verbose {
                     progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Progress: {}%", progress);
+                        progress::update(progress);
                         old_progress = progress;
                     }
                 }
@@ -458,21 +453,18 @@ impl WhiteboxTool for Atan2 {
             output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
             if verbose {
-                println!("Saving data...")
+                progress::message("Saving data...")
             };
             let _ = match output.write() {
                 Ok(_) => {
                     if verbose {
-                        println!("Output file written")
+                        progress::output_written(&output.file_name)
                     }
                 }
                 Err(e) => return Err(e),
             };
             if verbose {
-                println!(
-                    "{}",
-                    &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-                );
+                progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
             }
         }
 
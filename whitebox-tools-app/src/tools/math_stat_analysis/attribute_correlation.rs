@@ -9,6 +9,7 @@ NOTES: Correlation is calculated for each pair of numeric attributes.
 */
 
 use whitebox_common::rendering::html::*;
+use whitebox_common::progress;
 use crate::tools::*;
 use whitebox_vector::{FieldData, Shapefile};
 use std::env;
@@ -147,6 +148,7 @@ impl WhiteboxTool for AttributeCorrelation {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut output_file = String::new();
@@ -184,13 +186,7 @@ impl WhiteboxTool for AttributeCorrelation {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -216,7 +212,7 @@ impl WhiteboxTool for AttributeCorrelation {
         }
 
         if verbose {
-            println!("Reading vector data...")
+            progress::message("Reading vector data...")
         };
         let vector_data = Shapefile::read(&input_file)?;
 
@@ -243,7 +239,7 @@ impl WhiteboxTool for AttributeCorrelation {
         let mut field_averages = vec![0f64; num_fields];
         let mut correlation_matrix = vec![vec![-99f64; num_fields]; num_fields];
         if verbose {
-            println!("Calculating attribute averages...");
+            progress::message("Calculating attribute averages...");
         }
 
         for record_num in 0..vector_data.num_records {
@@ -270,11 +266,12 @@ impl WhiteboxTool for AttributeCorrelation {
                 }
             }
 
+            cancel.check()?;
             if verbose {
                 progress =
                     (100.0_f64 * record_num as f64 / (vector_data.num_records - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    progress::update(progress);
                     old_progress = progress;
                 }
             }
@@ -287,7 +284,7 @@ impl WhiteboxTool for AttributeCorrelation {
         }
 
         if verbose {
-            println!("Calculating the correlation matrix:");
+            progress::message("Calculating the correlation matrix:");
         }
         let mut i = 0;
         let nodata = -32768f64;
@@ -334,15 +331,11 @@ impl WhiteboxTool for AttributeCorrelation {
             }
             i += 1;
 
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * a as f64 / (num_fields - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!(
-                        "Calculating the correlation matrix ({} of {}): {}%",
-                        (a + 1),
-                        num_fields,
-                        progress
-                    );
+                    progress::update_stage(&format!("Calculating the correlation matrix ({} of {})", (a + 1), num_fields), progress);
                     old_progress = progress;
                 }
             }
@@ -351,10 +344,7 @@ impl WhiteboxTool for AttributeCorrelation {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!(
-                "\n{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("\n{}", &format!("Elapsed Time (excluding I/O): {}", elapsed_time)));
         }
 
         let f = File::create(output_file.clone())?;
@@ -454,7 +444,7 @@ impl WhiteboxTool for AttributeCorrelation {
                 let _ = output.stdout;
             }
             if verbose {
-                println!("Complete! Please see {} for output.", output_file);
+                progress::message(format!("Complete! Please see {} for output.", output_file));
             }
         }
 
@@ -6,6 +6,7 @@ Last Modified: 10/01/2020
 License: MIT
 */
 
+use whitebox_common::progress;
 use crate::tools::*;
 use whitebox_vector::*;
 use kdtree::distance::squared_euclidean;
@@ -185,6 +186,7 @@ impl WhiteboxTool for AttributeCorrelationNeighbourhoodAnalysis {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut field_name1 = String::new();
@@ -271,13 +273,7 @@ impl WhiteboxTool for AttributeCorrelationNeighbourhoodAnalysis {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -290,7 +286,7 @@ impl WhiteboxTool for AttributeCorrelationNeighbourhoodAnalysis {
         }
 
         if verbose {
-            println!("Reading data...")
+            progress::message("Reading data...")
         };
 
         let mut input = Shapefile::read(&input_file)?;
@@ -349,10 +345,11 @@ impl WhiteboxTool for AttributeCorrelationNeighbourhoodAnalysis {
                 }
             }
 
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * (record_num + 1) as f64 / num_records as f64) as usize;
                 if progress != old_progress {
-                    println!("Reading points: {}%", progress);
+                    progress::update_stage("Reading points", progress);
                     old_progress = progress;
                 }
             }
@@ -687,10 +684,11 @@ impl WhiteboxTool for AttributeCorrelationNeighbourhoodAnalysis {
                         .set_value(record_num, "PVALUE", FieldData::Real(pvalue));
                 }
             }
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * (record_num + 1) as f64 / points.len() as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    progress::update(progress);
                     old_progress = progress;
                 }
             }
@@ -699,21 +697,18 @@ impl WhiteboxTool for AttributeCorrelationNeighbourhoodAnalysis {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match input.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&input.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         // } else if stat_type == "kendall" { // Perform Kendall's Tau-b correlation
@@ -8,6 +8,7 @@ License: MIT
 
 use whitebox_common::rendering::html::*;
 use whitebox_common::rendering::Histogram;
+use whitebox_common::progress;
 use crate::tools::*;
 use whitebox_vector::{FieldData, Shapefile};
 use std::env;
@@ -149,6 +150,7 @@ impl WhiteboxTool for AttributeHistogram {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut field_name = String::new();
@@ -193,13 +195,7 @@ impl WhiteboxTool for AttributeHistogram {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -217,7 +213,7 @@ impl WhiteboxTool for AttributeHistogram {
         }
 
         if verbose {
-            println!("Reading vector data...")
+            progress::message("Reading vector data...")
         };
         let vector_data = Shapefile::read(&input_file)?;
 
@@ -267,11 +263,12 @@ impl WhiteboxTool for AttributeHistogram {
                 }
             }
 
+            cancel.check()?;
             if verbose {
                 progress =
                     (100.0_f64 * record_num as f64 / (vector_data.num_records - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Finding min and max: {}%", progress);
+                    progress::update_stage("Finding min and max", progress);
                     old_progress = progress;
                 }
             }
@@ -304,11 +301,12 @@ impl WhiteboxTool for AttributeHistogram {
                 }
             }
 
+            cancel.check()?;
             if verbose {
                 progress =
                     (100.0_f64 * record_num as f64 / (vector_data.num_records - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Binning data: {}%", progress);
+                    progress::update_stage("Binning data", progress);
                     old_progress = progress;
                 }
             }
@@ -317,10 +315,7 @@ impl WhiteboxTool for AttributeHistogram {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!(
-                "\n{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("\n{}", &format!("Elapsed Time (excluding I/O): {}", elapsed_time)));
         }
 
         let f = File::create(output_file.clone())?;
@@ -394,7 +389,7 @@ impl WhiteboxTool for AttributeHistogram {
                 let _ = output.stdout;
             }
             if verbose {
-                println!("Complete! Please see {} for output.", output_file);
+                progress::message(format!("Complete! Please see {} for output.", output_file));
             }
         }
 
@@ -8,6 +8,7 @@ License: MIT
 
 use whitebox_common::rendering::html::*;
 use whitebox_common::rendering::Scattergram;
+use whitebox_common::progress;
 use crate::tools::*;
 use whitebox_vector::{FieldData, Shapefile};
 use std::env;
@@ -165,6 +166,7 @@ impl WhiteboxTool for AttributeScattergram {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut field_name_x = String::new();
@@ -221,13 +223,7 @@ impl WhiteboxTool for AttributeScattergram {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -245,7 +241,7 @@ impl WhiteboxTool for AttributeScattergram {
         }
 
         if verbose {
-            println!("Reading vector data...")
+            progress::message("Reading vector data...")
         };
         let vector_data = Shapefile::read(&input_file)?;
 
@@ -325,11 +321,12 @@ impl WhiteboxTool for AttributeScattergram {
                 series_ydata.push(y);
             }
 
+            cancel.check()?;
             if verbose {
                 progress =
                     (100.0_f64 * record_num as f64 / (vector_data.num_records - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Reading data: {}%", progress);
+                    progress::update_stage("Reading data", progress);
                     old_progress = progress;
                 }
             }
@@ -342,10 +339,7 @@ impl WhiteboxTool for AttributeScattergram {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!(
-                "\n{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("\n{}", &format!("Elapsed Time (excluding I/O): {}", elapsed_time)));
         }
 
         let f = File::create(output_file.clone())?;
@@ -432,7 +426,7 @@ impl WhiteboxTool for AttributeScattergram {
                 let _ = output.stdout;
             }
             if verbose {
-                println!("Complete! Please see {} for output.", output_file);
+                progress::message(format!("Complete! Please see {} for output.", output_file));
             }
         }
 
@@ -7,6 +7,7 @@ License: MIT
 */
 
 use whitebox_raster::*;
+use whitebox_common::progress;
 use crate::tools::*;
 use num_cpus;
 use std::env;
@@ -19,7 +20,6 @@ use std::path;
 use std::process::Command;
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::thread;
 
 /// The Crispness Index (*C*) provides a means of quantifying the crispness, or fuzziness, of a membership
 /// probability (MP) image. MP images describe the probability of each grid cell belonging to some feature
@@ -156,6 +156,7 @@ impl WhiteboxTool for CrispnessIndex {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut output_file = String::new();
@@ -192,13 +193,7 @@ impl WhiteboxTool for CrispnessIndex {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -222,7 +217,7 @@ impl WhiteboxTool for CrispnessIndex {
         }
 
         if verbose {
-            println!("Reading data...")
+            progress::message("Reading data...")
         };
 
         let input = Arc::new(Raster::new(&input_file, "r")?);
@@ -246,7 +241,7 @@ impl WhiteboxTool for CrispnessIndex {
         for tid in 0..num_procs {
             let input = input.clone();
             let tx = tx.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let mut z: f64;
                 for row in (0..rows).filter(|r| r % num_procs == tid) {
                     let mut n = 0;
@@ -262,7 +257,9 @@ impl WhiteboxTool for CrispnessIndex {
                             s += z;
                         }
                     }
-                    tx.send((n, s, warning)).unwrap();
+                    if tx.send((n, s, warning)).is_err() {
+                        return;
+                    }
                 }
             });
         }
@@ -271,7 +268,7 @@ impl WhiteboxTool for CrispnessIndex {
         let mut sum = 0.0;
         let mut warning = false;
         for row in 0..rows {
-            let (a, b, c) = rx.recv().expect("Error receiving data from thread.");
+            let (a, b, c) = cancel.recv(&rx)?;
             num_cells += a;
             sum += b;
             if c {
@@ -281,7 +278,7 @@ impl WhiteboxTool for CrispnessIndex {
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress (Loop 1 of 2): {}%", progress);
+                    progress::update_stage("Progress (Loop 1 of 2)", progress);
                     old_progress = progress;
                 }
             }
@@ -293,7 +290,7 @@ impl WhiteboxTool for CrispnessIndex {
         for tid in 0..num_procs {
             let input = input.clone();
             let tx = tx.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let mut z: f64;
                 for row in (0..rows).filter(|r| r % num_procs == tid) {
                     let mut total_dev = 0f64;
@@ -303,19 +300,21 @@ impl WhiteboxTool for CrispnessIndex {
                             total_dev += (z - mean) * (z - mean);
                         }
                     }
-                    tx.send(total_dev).unwrap();
+                    if tx.send(total_dev).is_err() {
+                        return;
+                    }
                 }
             });
         }
 
         let mut total_dev = 0f64;
         for row in 0..rows {
-            total_dev += rx.recv().expect("Error receiving data from thread.");
+            total_dev += cancel.recv(&rx)?;
 
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress (Loop 2 of 2): {}%", progress);
+                    progress::update_stage("Progress (Loop 2 of 2)", progress);
                     old_progress = progress;
                 }
             }
@@ -328,7 +327,7 @@ impl WhiteboxTool for CrispnessIndex {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if warning {
-            println!("WARNING: This tool is intended to be applied to membership probability (MP) rasters, with probability values 
+            progress::warning("This tool is intended to be applied to membership probability (MP) rasters, with probability values 
 ranging from 0-1. The input image contains values outside this range.");
         }
 
@@ -338,10 +337,7 @@ ranging from 0-1. The input image contains values outside this range.");
         // println!("SSb: {}", denominator);
         // println!("Crispness index: {}", crispness);
         if verbose {
-            println!(
-                "\n{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("\n{}", &format!("Elapsed Time (excluding I/O): {}", elapsed_time)));
         }
 
         let f = File::create(output_file.clone())?;
@@ -482,7 +478,7 @@ ranging from 0-1. The input image contains values outside this range. <em>Theref
                 let _ = output.stdout;
             }
             if verbose {
-                println!("Complete! Please see {} for output.", output_file);
+                progress::message(format!("Complete! Please see {} for output.", output_file));
             }
         }
 
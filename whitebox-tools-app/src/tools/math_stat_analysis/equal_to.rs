@@ -7,6 +7,7 @@ License: MIT
 */
 
 use whitebox_raster::*;
+use whitebox_common::progress;
 use crate::tools::*;
 use num_cpus;
 use std::env;
@@ -15,7 +16,6 @@ use std::io::{Error, ErrorKind};
 use std::path;
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::thread;
 
 /// This tool assigns grid cells for which both (`--input1` and `--input2`) input rasters (or one raster and
 /// a constant value) have equal values a new value of 1 (*True*) in the output raster (`--output`). All
@@ -42,7 +42,7 @@ impl EqualTo {
         let mut parameters = vec![];
         parameters.push(ToolParameter {
             name: "Input File Or Constant Value".to_owned(),
-            flags: vec!["--input1".to_owned()],
+            flags: vec!["--i1".to_owned(), "--input1".to_owned()],
             description: "Input raster file or constant value.".to_owned(),
             parameter_type: ParameterType::ExistingFileOrFloat(ParameterFileType::Raster),
             default_value: None,
@@ -51,7 +51,7 @@ impl EqualTo {
 
         parameters.push(ToolParameter {
             name: "Input File Or Constant Value".to_owned(),
-            flags: vec!["--input2".to_owned()],
+            flags: vec!["--i2".to_owned(), "--input2".to_owned()],
             description: "Input raster file or constant value.".to_owned(),
             parameter_type: ParameterType::ExistingFileOrFloat(ParameterFileType::Raster),
             default_value: None,
@@ -132,6 +132,7 @@ impl WhiteboxTool for EqualTo {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input1 = String::new();
         let mut input2 = String::new();
@@ -175,13 +176,7 @@ impl WhiteboxTool for EqualTo {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -225,11 +220,11 @@ impl WhiteboxTool for EqualTo {
         if input1_is_constant && input2_is_constant {
             // return Err(Error::new(ErrorKind::InvalidInput,
             //                     "At least one of the inputs must be a raster."));
-            println!("{}", equal_to(input1_constant, input2_constant));
+            progress::message(equal_to(input1_constant, input2_constant));
             return Ok(());
         } else if input1_is_constant && !input2_is_constant {
             if verbose {
-                println!("Reading data...")
+                progress::message("Reading data...")
             };
             let in2 = Arc::new(Raster::new(&input2, "r")?);
 
@@ -248,7 +243,7 @@ impl WhiteboxTool for EqualTo {
             for tid in 0..num_procs {
                 let in2 = in2.clone();
                 let tx = tx.clone();
-                thread::spawn(move || {
+                progress::spawn(move || {
                     let mut z2: f64;
                     for row in (0..rows).filter(|r| r % num_procs == tid) {
                         let mut data: Vec<f64> = vec![nodata2; columns as usize];
@@ -260,20 +255,22 @@ impl WhiteboxTool for EqualTo {
                                 data[col as usize] = nodata2;
                             }
                         }
-                        tx.send((row, data)).unwrap();
+                        if tx.send((row, data)).is_err() {
+                            return;
+                        }
                     }
                 });
             }
 
             let mut output = Raster::initialize_using_file(&output_file, &in2);
             for r in 0..rows {
-                let (row, data) = rx.recv().expect("Error receiving data from thread.");
+                let (row, data) = cancel.recv(&rx)?;
                 output.set_row_data(row, data);
 
                 if verbose {
                     progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Progress: {}%", progress);
+                        progress::update(progress);
                         old_progress = progress;
                     }
                 }
@@ -290,25 +287,22 @@ impl WhiteboxTool for EqualTo {
             output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
             if verbose {
-                println!("Saving data...")
+                progress::message("Saving data...")
             };
             let _ = match output.write() {
                 Ok(_) => {
                     if verbose {
-                        println!("Output file written")
+                        progress::output_written(&output.file_name)
                     }
                 }
                 Err(e) => return Err(e),
             };
             if verbose {
-                println!(
-                    "{}",
-                    &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-                );
+                progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
             }
         } else if !input1_is_constant && input2_is_constant {
             if verbose {
-                println!("Reading data...")
+                progress::message("Reading data...")
             };
             let in1 = Arc::new(Raster::new(&input1, "r")?);
 
@@ -327,7 +321,7 @@ impl WhiteboxTool for EqualTo {
             for tid in 0..num_procs {
                 let in1 = in1.clone();
                 let tx = tx.clone();
-                thread::spawn(move || {
+                progress::spawn(move || {
                     let mut z1: f64;
                     for row in (0..rows).filter(|r| r % num_procs == tid) {
                         let mut data: Vec<f64> = vec![nodata1; columns as usize];
@@ -339,20 +333,22 @@ impl WhiteboxTool for EqualTo {
                                 data[col as usize] = nodata1;
                             }
                         }
-                        tx.send((row, data)).unwrap();
+                        if tx.send((row, data)).is_err() {
+                            return;
+                        }
                     }
                 });
             }
 
             let mut output = Raster::initialize_using_file(&output_file, &in1);
             for r in 0..rows {
-                let (row, data) = rx.recv().expect("Error receiving data from thread.");
+                let (row, data) = cancel.recv(&rx)?;
                 output.set_row_data(row, data);
 
                 if verbose {
                     progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Progress: {}%", progress);
+                        progress::update(progress);
                         old_progress = progress;
                     }
                 }
@@ -369,26 +365,23 @@ impl WhiteboxTool for EqualTo {
             output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
             if verbose {
-                println!("Saving data...")
+                progress::message("Saving data...")
             };
             let _ = match output.write() {
                 Ok(_) => {
                     if verbose {
-                        println!("Output file written")
+                        progress::output_written(&output.file_name)
                     }
                 }
                 Err(e) => return Err(e),
             };
             if verbose {
-                println!(
-                    "{}",
-                    &format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", "")
-                );
+                progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", ""));
             }
         } else {
             // !input1_is_constant && !input2_is_constant
             if verbose {
-                println!("Reading data...")
+                progress::message("Reading data...")
             };
             let in1 = Arc::new(Raster::new(&input1, "r")?);
             let in2 = Arc::new(Raster::new(&input2, "r")?);
@@ -416,7 +409,7 @@ impl WhiteboxTool for EqualTo {
                 let in1 = in1.clone();
                 let in2 = in2.clone();
                 let tx = tx.clone();
-                thread::spawn(move || {
+                progress::spawn(move || {
                     let mut z1: f64;
                     let mut z2: f64;
                     for row in (0..rows).filter(|r| r % num_procs == tid) {
@@ -430,20 +423,22 @@ impl WhiteboxTool for EqualTo {
                                 data[col as usize] = nodata1;
                             }
                         }
-                        tx.send((row, data)).unwrap();
+                        if tx.send((row, data)).is_err() {
+                            return;
+                        }
                     }
                 });
             }
 
             let mut output = Raster::initialize_using_file(&output_file, &in1);
             for r in 0..rows {
-                let (row, data) = rx.recv().expect("Error receiving data from thread.");
+                let (row, data) = cancel.recv(&rx)?;
                 output.set_row_data(row, data);
 
                 if verbose {
                     progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Progress: {}%", progress);
+                        progress::update(progress);
                         old_progress = progress;
                     }
                 }
@@ -460,21 +455,18 @@ impl WhiteboxTool for EqualTo {
             output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
             if verbose {
-                println!("Saving data...")
+                progress::message("Saving data...")
             };
             let _ = match output.write() {
                 Ok(_) => {
                     if verbose {
-                        println!("Output file written")
+                        progress::output_written(&output.file_name)
                     }
                 }
                 Err(e) => return Err(e),
             };
             if verbose {
-                println!(
-                    "{}",
-                    &format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", "")
-                );
+                progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", ""));
             }
         }
 
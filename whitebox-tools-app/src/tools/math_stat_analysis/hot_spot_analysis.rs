@@ -0,0 +1,518 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: Dr. John Lindsay
+Created: 18/10/2026
+Last Modified: 18/10/2026
+License: MIT
+*/
+
+use whitebox_common::progress;
+use whitebox_common::random;
+use crate::tools::*;
+use whitebox_vector::*;
+use num_cpus;
+use rand::seq::index;
+use std::env;
+use std::f64;
+use std::io::{Error, ErrorKind};
+use std::path;
+use std::sync::mpsc;
+use std::sync::Arc;
+
+/// This tool identifies statistically significant hot spots and cold spots, i.e. clusters of features with high
+/// or low values, for a numeric attribute field (`--field`) of a vector point or polygon layer (`--input`),
+/// using the Getis-Ord *Gi\** statistic. The *Gi\** statistic of a feature compares the sum of the values of the
+/// feature and its neighbours with the sum that is expected if the values were arranged randomly, and is
+/// expressed as a z-score. A large positive z-score indicates that the feature and its neighbours have high
+/// values, i.e. a hot spot, and a large negative z-score indicates a cold spot. Unlike the local Moran's *I*
+/// used by the `ClusterAndOutlierAnalysis` tool, the *Gi\** statistic does not identify spatial outliers, but
+/// it detects features in hot spots whose own values are not high.
+///
+/// The significance of each feature's statistic is tested by a conditional permutation test
+/// (`--permutations`), which compares the statistic with its values when the feature's neighbours are given
+/// values drawn at random from the other features. The pseudo p-value is (*m* + 1) / (*p* + 1), where *p* is
+/// the number of permutations and *m* is the number of them giving a statistic at least as extreme as the
+/// observed statistic, in the same direction. Because a test is made for every feature, some features are
+/// expected to be significant by chance alone. The pseudo p-values can be reproduced by giving a random seed
+/// (`--seed`).
+///
+/// The output (`--output`) contains the input features and their attributes, along with the *Gi\** z-score
+/// (`GI_Z`), its pseudo p-value (`GI_P`), and a confidence bin (`GI_BIN`), which is 3 or -3 for hot or cold
+/// spots with a pseudo p-value no greater than 0.01, 2 or -2 for those with a pseudo p-value no greater than
+/// 0.05, 1 or -1 for those with a pseudo p-value no greater than 0.1, and 0 for other features. Features with
+/// null values, without neighbours, or with all of the other features as neighbours, have null values in
+/// these fields.
+///
+/// The neighbours of each feature are defined by spatial weights (`--weights`), as in the
+/// `VectorAutocorrelation` tool: 'distance_band' (`--distance`), 'k_nearest' (`--num_neighbours`), or, for
+/// polygons, 'queen' or 'rook' contiguity. Each feature is included among its own neighbours, and all of the
+/// weights are one.
+///
+/// # Reference
+/// Ord, J. K., and Getis, A. (1995). Local spatial autocorrelation statistics: distributional issues and
+/// an application. *Geographical Analysis*, 27(4), 286-306.
+///
+/// # See Also
+/// `VectorAutocorrelation`, `ClusterAndOutlierAnalysis`
+pub struct HotSpotAnalysis {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl HotSpotAnalysis {
+    pub fn new() -> HotSpotAnalysis {
+        // public constructor
+        let name = "HotSpotAnalysis".to_string();
+        let toolbox = "Math and Stats Tools".to_string();
+        let description =
+            "Identifies hot spots and cold spots of a vector attribute using the Getis-Ord Gi* statistic."
+                .to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input Vector File".to_owned(),
+            flags: vec!["-i".to_owned(), "--input".to_owned()],
+            description: "Input vector points or polygons file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Vector(
+                VectorGeometryType::Any,
+            )),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Field Name".to_owned(),
+            flags: vec!["--field".to_owned()],
+            description: "Input numeric field to analyze.".to_owned(),
+            parameter_type: ParameterType::VectorAttributeField(
+                AttributeType::Number,
+                "--input".to_string(),
+            ),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output Vector File".to_owned(),
+            flags: vec!["-o".to_owned(), "--output".to_owned()],
+            description: "Output vector file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Vector(
+                VectorGeometryType::Any,
+            )),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Spatial Weights".to_owned(),
+            flags: vec!["--weights".to_owned()],
+            description: "Method of defining the neighbours of each feature; options are 'distance_band', 'k_nearest', 'queen', and 'rook'."
+                .to_owned(),
+            parameter_type: ParameterType::OptionList(vec![
+                "distance_band".to_owned(),
+                "k_nearest".to_owned(),
+                "queen".to_owned(),
+                "rook".to_owned(),
+            ]),
+            default_value: Some("distance_band".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Distance Band (optional)".to_owned(),
+            flags: vec!["--distance".to_owned()],
+            description: "Optional neighbourhood distance for distance-band weights; by default, the smallest distance giving every feature a neighbour."
+                .to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Number of Neighbours".to_owned(),
+            flags: vec!["--num_neighbours".to_owned()],
+            description: "Number of neighbours for k-nearest weights.".to_owned(),
+            parameter_type: ParameterType::Integer,
+            default_value: Some("8".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Number of Permutations".to_owned(),
+            flags: vec!["--permutations".to_owned()],
+            description: "Number of random permutations used to calculate the pseudo p-values."
+                .to_owned(),
+            parameter_type: ParameterType::Integer,
+            default_value: Some("999".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(seed_parameter());
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut parent = env::current_exe().unwrap();
+        parent.pop();
+        let p = format!("{}", parent.display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(
+            ">>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=crimes.shp --field=COUNT -o=hot_spots.shp --weights=k_nearest --num_neighbours=12",
+            short_exe, name
+        ).replace("*", &sep);
+
+        HotSpotAnalysis {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for HotSpotAnalysis {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        match serde_json::to_string(&self.parameters) {
+            Ok(json_str) => return format!("{{\"parameters\":{}}}", json_str),
+            Err(err) => return format!("{:?}", err),
+        }
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+        cancel: &CancellationToken,
+    ) -> Result<(), Error> {
+        let args = ParsedArgs::parse(&self.get_tool_parameters(), &args, working_directory)?;
+        self.run_with_parsed_args(args, working_directory, verbose, cancel)
+    }
+
+    fn run_with_parsed_args<'a>(
+        &self,
+        args: ParsedArgs,
+        _working_directory: &'a str,
+        verbose: bool,
+        cancel: &CancellationToken,
+    ) -> Result<(), Error> {
+        let input_file = args.get_file("input").unwrap();
+        let field_name = args.get_string("field").unwrap();
+        let output_file = args.get_file("output").unwrap();
+        let weights_method = args.get_string("weights").unwrap_or_default();
+        let distance = args.get_float("distance");
+        let num_neighbours = args.get_integer("num_neighbours").unwrap_or(8);
+        let num_permutations = args.get_integer("permutations").unwrap_or(999);
+        let seed = args.get_seed()?;
+
+        if num_neighbours < 1 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The number of neighbours (--num_neighbours) must be at least one.",
+            ));
+        }
+        if num_permutations < 1 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The number of permutations (--permutations) must be at least one.",
+            ));
+        }
+        let num_permutations = num_permutations as usize;
+
+        let mut progress: usize;
+        let mut old_progress: usize = 1;
+
+        let start = Instant::now();
+
+        if verbose {
+            let tool_name = self.get_tool_name();
+            progress::welcome(&tool_name);
+        }
+
+        if verbose {
+            progress::message("Reading data...")
+        };
+
+        let input = Shapefile::read(&input_file)?;
+        let base_shape_type = input.header.shape_type.base_shape_type();
+        if base_shape_type != ShapeType::Point
+            && base_shape_type != ShapeType::MultiPoint
+            && base_shape_type != ShapeType::Polygon
+        {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The input vector data must be of POINT, MULTIPOINT, or POLYGON base shape type.",
+            ));
+        }
+        let field_index = match input.attributes.get_field_num(&field_name) {
+            Some(i) if input.attributes.is_field_numeric(i) => i,
+            Some(_) => {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    format!("The field '{}' is not numeric.", field_name),
+                ));
+            }
+            None => {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    format!(
+                        "The field '{}' does not exist in the input attribute table.",
+                        field_name
+                    ),
+                ));
+            }
+        };
+
+        // Features with null values or without geometries are excluded.
+        let mut records = vec![];
+        let mut values = vec![];
+        for record_num in 0..input.num_records {
+            let value = match input.attributes.get_record(record_num)[field_index] {
+                FieldData::Int(v) => v as f64,
+                FieldData::Real(v) => v,
+                _ => continue,
+            };
+            if feature_centroid(&input, record_num).is_some() {
+                records.push(record_num);
+                values.push(value);
+            }
+        }
+        if verbose && records.len() < input.num_records {
+            progress::warning(format!(
+                "{} features with null values or without geometries were excluded.",
+                input.num_records - records.len()
+            ));
+        }
+        let n = records.len();
+        if n < 3 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "At least three features with values are required.",
+            ));
+        }
+
+        let weights_type = match weights_method.as_str() {
+            "k_nearest" => SpatialWeightsType::KNearest(num_neighbours as usize),
+            "queen" => SpatialWeightsType::Queen,
+            "rook" => SpatialWeightsType::Rook,
+            _ => {
+                let distance = match distance {
+                    Some(d) => d,
+                    None => {
+                        let d = SpatialWeights::threshold_distance(&input, &records)?;
+                        if verbose {
+                            progress::message(format!("Distance band: {}", d));
+                        }
+                        d
+                    }
+                };
+                SpatialWeightsType::DistanceBand(distance)
+            }
+        };
+        let weights = SpatialWeights::new(&input, &records, weights_type)?;
+        let num_islands = weights.num_islands();
+        if verbose && num_islands > 0 {
+            progress::warning(format!(
+                "{} features have no neighbours.",
+                num_islands
+            ));
+        }
+        // Each feature is one of its own neighbours, so if all of the other features are too, the
+        // numerator and denominator of its Gi* statistic are both zero.
+        let num_undefined = (0..n)
+            .filter(|i| weights.neighbours(*i).len() + 1 >= n)
+            .count();
+        if verbose && num_undefined > 0 {
+            progress::warning(format!(
+                "The Gi* statistic is undefined for {} features with all of the other features as neighbours; their output values are null.",
+                num_undefined
+            ));
+        }
+
+        let mean = values.iter().sum::<f64>() / n as f64;
+        let sd = (values.iter().map(|v| (v - mean) * (v - mean)).sum::<f64>() / n as f64).sqrt();
+        if sd <= 0f64 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The field values do not vary.",
+            ));
+        }
+
+        let weights = Arc::new(weights);
+        let values = Arc::new(values);
+        let mut num_procs = num_cpus::get() as isize;
+        let configs = whitebox_common::configs::get_configs()?;
+        let max_procs = configs.max_procs;
+        if max_procs > 0 && max_procs < num_procs {
+            num_procs = max_procs;
+        }
+        let seed = random::tool_seed(seed);
+        let (tx, rx) = mpsc::channel();
+        for tid in 0..num_procs {
+            let weights = weights.clone();
+            let values = values.clone();
+            let tx = tx.clone();
+            progress::spawn(move || {
+                let mut rng = random::new_rng(seed, tid as u64);
+                let nf = n as f64;
+                for i in (0..n).filter(|i| *i as isize % num_procs == tid) {
+                    let neighbours = weights.neighbours(i);
+                    let k = neighbours.len();
+                    if k == 0 || k + 1 >= n {
+                        if tx.send((i, None)).is_err() {
+                            return;
+                        }
+                        continue;
+                    }
+                    // The feature is one of its own neighbours, and the weights are binary, so the
+                    // sum of the weights and of their squares are both the number of neighbours.
+                    let num_weights = (k + 1) as f64;
+                    let denominator =
+                        sd * ((nf * num_weights - num_weights * num_weights) / (nf - 1f64)).sqrt();
+                    let gi_star = |sum: f64| -> f64 { (sum - mean * num_weights) / denominator };
+                    let statistic =
+                        gi_star(values[i] + neighbours.iter().map(|j| values[*j]).sum::<f64>());
+
+                    // Conditional permutations, which draw the neighbours' values from all of the
+                    // other features.
+                    let mut num_larger = 0;
+                    for _ in 0..num_permutations {
+                        let permuted = gi_star(
+                            values[i]
+                                + index::sample(&mut rng, n - 1, k)
+                                    .iter()
+                                    .map(|j| values[if j < i { j } else { j + 1 }])
+                                    .sum::<f64>(),
+                        );
+                        if permuted >= statistic {
+                            num_larger += 1;
+                        }
+                    }
+                    let num_extreme = num_larger.min(num_permutations - num_larger);
+                    let p_value = (num_extreme + 1) as f64 / (num_permutations + 1) as f64;
+                    let confidence = if p_value <= 0.01 {
+                        3
+                    } else if p_value <= 0.05 {
+                        2
+                    } else if p_value <= 0.1 {
+                        1
+                    } else {
+                        0
+                    };
+                    let bin = if statistic < 0f64 { -confidence } else { confidence };
+                    if tx.send((i, Some((statistic, p_value, bin)))).is_err() {
+                        return;
+                    }
+                }
+            });
+        }
+        drop(tx);
+
+        let mut results = vec![None; n];
+        for i in 0..n {
+            let (feature, result) = cancel.recv(&rx)?;
+            results[feature] = result;
+            if verbose {
+                progress = (100.0_f64 * (i + 1) as f64 / n as f64) as usize;
+                if progress != old_progress {
+                    progress::update_stage("Calculating Gi*", progress);
+                    old_progress = progress;
+                }
+            }
+        }
+
+        let mut output =
+            Shapefile::initialize_using_file(&output_file, &input, input.header.shape_type, true)?;
+        output
+            .attributes
+            .add_field(&AttributeField::new("GI_Z", FieldDataType::Real, 18u8, 6u8));
+        output
+            .attributes
+            .add_field(&AttributeField::new("GI_P", FieldDataType::Real, 12u8, 6u8));
+        output
+            .attributes
+            .add_field(&AttributeField::new("GI_BIN", FieldDataType::Int, 2u8, 0u8));
+
+        let mut feature_num = vec![None; input.num_records];
+        for (i, record_num) in records.iter().enumerate() {
+            feature_num[*record_num] = Some(i);
+        }
+        let mut num_hot_spots = 0;
+        let mut num_cold_spots = 0;
+        for record_num in 0..input.num_records {
+            let mut atts = input.attributes.get_record(record_num);
+            match feature_num[record_num].and_then(|i| results[i]) {
+                Some((statistic, p_value, bin)) => {
+                    if bin >= 2 {
+                        num_hot_spots += 1;
+                    } else if bin <= -2 {
+                        num_cold_spots += 1;
+                    }
+                    atts.push(FieldData::Real(statistic));
+                    atts.push(FieldData::Real(p_value));
+                    atts.push(FieldData::Int(bin));
+                }
+                None => atts.extend(vec![FieldData::Null; 3]),
+            }
+            output.add_record(input.get_record(record_num).clone());
+            output.attributes.add_record(atts, false);
+        }
+
+        if verbose {
+            progress::message(format!(
+                "Features in hot spots (p <= 0.05): {}, features in cold spots (p <= 0.05): {}",
+                num_hot_spots, num_cold_spots
+            ));
+        }
+
+        if verbose {
+            progress::message("Saving data...")
+        };
+        let _ = match output.write() {
+            Ok(_) => {
+                if verbose {
+                    progress::output_written(&output.file_name)
+                }
+            }
+            Err(e) => return Err(e),
+        };
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+
+        if verbose {
+            progress::message(format!("Elapsed Time: {}", elapsed_time));
+        }
+
+        Ok(())
+    }
+}
@@ -8,6 +8,7 @@ License: MIT
 
 use self::statrs::distribution::{ContinuousCDF, Normal};
 use whitebox_raster::*;
+use whitebox_common::progress;
 use crate::tools::*;
 use num_cpus;
 use statrs;
@@ -21,7 +22,6 @@ use std::path;
 use std::process::Command;
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::thread;
 
 /// Spatial autocorrelation describes the extent to which a variable is either dispersed or clustered through space.
 /// In the case of a raster image, spatial autocorrelation refers to the similarity in the values of nearby grid
@@ -186,6 +186,7 @@ impl WhiteboxTool for ImageAutocorrelation {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_files: String = String::new();
         let mut output_file = String::new();
@@ -230,13 +231,7 @@ impl WhiteboxTool for ImageAutocorrelation {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -316,7 +311,7 @@ impl WhiteboxTool for ImageAutocorrelation {
         let mut rows: isize = 0;
         let mut columns: isize = 0;
         if verbose {
-            println!("Calculating image averages...");
+            progress::message("Calculating image averages...");
         }
         for a in 0..num_files {
             let value = &file_names[a]; //files_vec[a];
@@ -339,7 +334,7 @@ impl WhiteboxTool for ImageAutocorrelation {
             for tid in 0..num_procs {
                 let input = input.clone();
                 let tx = tx.clone();
-                thread::spawn(move || {
+                progress::spawn(move || {
                     let mut total = 0f64;
                     let mut n = 0f64;
                     let mut z: f64;
@@ -352,18 +347,18 @@ impl WhiteboxTool for ImageAutocorrelation {
                             }
                         }
                     }
-                    tx.send((total, n)).unwrap();
+                    let _ = tx.send((total, n));
                 });
             }
             for np in 0..num_procs {
-                let (total, image_n) = rx.recv().expect("Error receiving data from thread.");
+                let (total, image_n) = cancel.recv(&rx)?;
                 image_totals[a] += total;
                 n[a] += image_n;
 
                 if verbose && num_procs > 1 {
                     progress = (100.0_f64 * np as f64 / (num_procs - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Progress (Loop 1 of 2): {}%", progress);
+                        progress::update_stage("Progress (Loop 1 of 2)", progress);
                         old_progress = progress;
                     }
                 }
@@ -402,10 +397,11 @@ impl WhiteboxTool for ImageAutocorrelation {
                         s2 += wij * wij;
                     }
                 }
+                cancel.check()?;
                 if verbose {
                     progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Progress (Loop 2 of 2): {}%", progress);
+                        progress::update_stage("Progress (Loop 2 of 2)", progress);
                         old_progress = progress;
                     }
                 }
@@ -439,7 +435,7 @@ impl WhiteboxTool for ImageAutocorrelation {
             if verbose {
                 progress = (100.0_f64 * a as f64 / (num_files - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Loop {} of {}: {}%", (a + 1), files_vec.len(), progress);
+                    progress::update_stage(&format!("Loop {} of {}", (a + 1), files_vec.len()), progress);
                     old_progress = progress;
                 }
             }
@@ -448,10 +444,7 @@ impl WhiteboxTool for ImageAutocorrelation {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!(
-                "\n{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("\n{}", &format!("Elapsed Time (excluding I/O): {}", elapsed_time)));
         }
 
         let f = File::create(output_file.clone())?;
@@ -579,7 +572,7 @@ impl WhiteboxTool for ImageAutocorrelation {
                 let _ = output.stdout;
             }
             if verbose {
-                println!("Complete! Please see {} for output.", output_file);
+                progress::message(format!("Complete! Please see {} for output.", output_file));
             }
         }
 
@@ -8,6 +8,7 @@ License: MIT
 
 use whitebox_raster::*;
 use whitebox_common::rendering::html::*;
+use whitebox_common::progress;
 use crate::tools::*;
 use num_cpus;
 use std::env;
@@ -20,7 +21,6 @@ use std::path;
 use std::process::Command;
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::thread;
 
 /// This tool can be used to estimate the Pearson product-moment correlation coefficient (*r*) between two or
 /// more input images (`--inputs`). The *r*-value is a measure of the linear association in the variation of
@@ -152,6 +152,7 @@ impl WhiteboxTool for ImageCorrelation {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_files: String = String::new();
         let mut output_file = String::new();
@@ -189,13 +190,7 @@ impl WhiteboxTool for ImageCorrelation {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -253,7 +248,7 @@ impl WhiteboxTool for ImageCorrelation {
         let mut rows: isize = 0;
         let mut columns: isize = 0;
         if verbose {
-            println!("Calculating image averages...");
+            progress::message("Calculating image averages...");
         }
         for a in 0..num_files {
             let value = &file_names[a]; //files_vec[a];
@@ -276,7 +271,7 @@ impl WhiteboxTool for ImageCorrelation {
             for tid in 0..num_procs {
                 let input = input.clone();
                 let tx = tx.clone();
-                thread::spawn(move || {
+                progress::spawn(move || {
                     let mut total = 0f64;
                     let mut n = 0f64;
                     let mut z: f64;
@@ -291,11 +286,11 @@ impl WhiteboxTool for ImageCorrelation {
                             }
                         }
                     }
-                    tx.send((total, n)).unwrap();
+                    let _ = tx.send((total, n));
                 });
             }
             for _ in 0..num_procs {
-                let (total, n) = rx.recv().expect("Error receiving data from thread.");
+                let (total, n) = cancel.recv(&rx)?;
                 image_totals[a] += total;
                 image_n[a] += n;
             }
@@ -304,12 +299,7 @@ impl WhiteboxTool for ImageCorrelation {
             if verbose {
                 progress = (100.0_f64 * a as f64 / (num_files - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!(
-                        "Calculating image averages ({} of {}): {}%",
-                        (a + 1),
-                        files_vec.len(),
-                        progress
-                    );
+                    progress::update_stage(&format!("Calculating image averages ({} of {})", (a + 1), files_vec.len()), progress);
                     old_progress = progress;
                 }
             }
@@ -317,7 +307,7 @@ impl WhiteboxTool for ImageCorrelation {
         let image_averages = Arc::new(image_averages);
 
         if verbose {
-            println!("Calculating the correlation matrix:");
+            progress::message("Calculating the correlation matrix:");
         }
         let mut i = 0;
         for a in 0..num_files {
@@ -337,7 +327,7 @@ impl WhiteboxTool for ImageCorrelation {
                         let image2 = image2.clone();
                         let image_averages = image_averages.clone();
                         let tx = tx.clone();
-                        thread::spawn(move || {
+                        progress::spawn(move || {
                             let mut z1: f64;
                             let mut z2: f64;
                             let mut image1_total_deviation = 0f64;
@@ -357,12 +347,11 @@ impl WhiteboxTool for ImageCorrelation {
                                     }
                                 }
                             }
-                            tx.send((
+                            let _ = tx.send((
                                 image1_total_deviation,
                                 image2_total_deviation,
                                 total_product_deviations,
-                            ))
-                            .unwrap();
+                            ));
                         });
                     }
                     let mut image1_total_deviation = 0f64;
@@ -370,7 +359,7 @@ impl WhiteboxTool for ImageCorrelation {
                     let mut total_product_deviations = 0f64;
                     for _ in 0..num_procs {
                         let (val1, val2, val3) =
-                            rx.recv().expect("Error receiving data from thread.");
+                            cancel.recv(&rx)?;
                         image1_total_deviation += val1;
                         image2_total_deviation += val2;
                         total_product_deviations += val3;
@@ -384,12 +373,7 @@ impl WhiteboxTool for ImageCorrelation {
             if verbose {
                 progress = (100.0_f64 * a as f64 / (num_files - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!(
-                        "Calculating the correlation matrix ({} of {}): {}%",
-                        (a + 1),
-                        files_vec.len(),
-                        progress
-                    );
+                    progress::update_stage(&format!("Calculating the correlation matrix ({} of {})", (a + 1), files_vec.len()), progress);
                     old_progress = progress;
                 }
             }
@@ -398,10 +382,7 @@ impl WhiteboxTool for ImageCorrelation {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!(
-                "\n{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("\n{}", &format!("Elapsed Time (excluding I/O): {}", elapsed_time)));
         }
 
         let f = File::create(output_file.clone())?;
@@ -487,7 +468,7 @@ impl WhiteboxTool for ImageCorrelation {
                 let _ = output.stdout;
             }
             if verbose {
-                println!("Complete! Please see {} for output.", output_file);
+                progress::message(format!("Complete! Please see {} for output.", output_file));
             }
         }
 
@@ -7,6 +7,7 @@ License: MIT
 */
 
 use whitebox_raster::*;
+use whitebox_common::progress;
 use crate::tools::*;
 use statrs::distribution::{ContinuousCDF, StudentsT};
 use std::cmp::Ordering::Equal;
@@ -16,7 +17,6 @@ use std::io::{Error, ErrorKind};
 use std::path;
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::thread;
 
 /// This tool can be used to perform nieghbourhood-based (i.e. using roving search windows applied to each
 /// grid cell) correlation analysis on two input rasters (`--input1` and `--input2`). The tool outputs a
@@ -177,6 +177,7 @@ impl WhiteboxTool for ImageCorrelationNeighbourhoodAnalysis {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file1 = String::new();
         let mut input_file2 = String::new();
@@ -256,13 +257,7 @@ impl WhiteboxTool for ImageCorrelationNeighbourhoodAnalysis {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         if filter_size < 3 {
@@ -299,7 +294,7 @@ impl WhiteboxTool for ImageCorrelationNeighbourhoodAnalysis {
         }
 
         if verbose {
-            println!("Reading data...")
+            progress::message("Reading data...")
         };
 
         let image1 = Arc::new(Raster::new(&input_file1, "r")?);
@@ -325,7 +320,7 @@ impl WhiteboxTool for ImageCorrelationNeighbourhoodAnalysis {
                 let image1 = image1.clone();
                 let image2 = image2.clone();
                 let tx = tx.clone();
-                thread::spawn(move || {
+                progress::spawn(move || {
                     let mut num_cells: usize;
                     let mut sum1: f64;
                     let mut sum2: f64;
@@ -418,20 +413,22 @@ impl WhiteboxTool for ImageCorrelationNeighbourhoodAnalysis {
                                 }
                             }
                         }
-                        tx.send((row, data1, data2)).unwrap();
+                        if tx.send((row, data1, data2)).is_err() {
+                            return;
+                        }
                     }
                 });
             }
 
             for r in 0..rows {
-                let (row, data1, data2) = rx.recv().expect("Error receiving data from thread.");
+                let (row, data1, data2) = cancel.recv(&rx)?;
                 output_val.set_row_data(row, data1);
                 output_sig.set_row_data(row, data2);
 
                 if verbose {
                     progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Performing Correlation: {}%", progress);
+                        progress::update_stage("Performing Correlation", progress);
                         old_progress = progress;
                     }
                 }
@@ -443,7 +440,7 @@ impl WhiteboxTool for ImageCorrelationNeighbourhoodAnalysis {
                 let image1 = image1.clone();
                 let image2 = image2.clone();
                 let tx = tx.clone();
-                thread::spawn(move || {
+                progress::spawn(move || {
                     let mut num_cells: usize;
                     let mut num_cells_f64: f64;
                     let mut tau: f64;
@@ -610,20 +607,22 @@ impl WhiteboxTool for ImageCorrelationNeighbourhoodAnalysis {
                                 }
                             }
                         }
-                        tx.send((row, data1, data2)).unwrap();
+                        if tx.send((row, data1, data2)).is_err() {
+                            return;
+                        }
                     }
                 });
             }
 
             for r in 0..rows {
-                let (row, data1, data2) = rx.recv().expect("Error receiving data from thread.");
+                let (row, data1, data2) = cancel.recv(&rx)?;
                 output_val.set_row_data(row, data1);
                 output_sig.set_row_data(row, data2);
 
                 if verbose {
                     progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Performing Correlation: {}%", progress);
+                        progress::update_stage("Performing Correlation", progress);
                         old_progress = progress;
                     }
                 }
@@ -635,7 +634,7 @@ impl WhiteboxTool for ImageCorrelationNeighbourhoodAnalysis {
                 let image1 = image1.clone();
                 let image2 = image2.clone();
                 let tx = tx.clone();
-                thread::spawn(move || {
+                progress::spawn(move || {
                     let mut num_cells: usize;
                     let mut num_cells_f64: f64;
                     let mut rho: f64;
@@ -783,8 +782,9 @@ impl WhiteboxTool for ImageCorrelationNeighbourhoodAnalysis {
                                 }
                             }
                         }
-                        tx.send((row, data1, data2, num_ties, max_num_ties))
-                            .unwrap();
+                        if tx.send((row, data1, data2, num_ties, max_num_ties)).is_err() {
+                            return;
+                        }
                     }
                 });
             }
@@ -793,7 +793,7 @@ impl WhiteboxTool for ImageCorrelationNeighbourhoodAnalysis {
             let mut num_ties = 0;
             for r in 0..rows {
                 let (row, data1, data2, ties, max_row_ties) =
-                    rx.recv().expect("Error receiving data from thread.");
+                    cancel.recv(&rx)?;
                 output_val.set_row_data(row, data1);
                 output_sig.set_row_data(row, data2);
                 num_ties += ties;
@@ -804,14 +804,14 @@ impl WhiteboxTool for ImageCorrelationNeighbourhoodAnalysis {
                 if verbose {
                     progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Performing Correlation: {}%", progress);
+                        progress::update_stage("Performing Correlation", progress);
                         old_progress = progress;
                     }
                 }
             }
 
             if num_ties > 0 {
-                println!("Warning: There was a maximum of {} ties in a test and as a result p-values \nmay be misleading. You may want to consider using Kendall's Tau instead.", max_ties);
+                progress::warning(format!("There was a maximum of {} ties in a test and as a result p-values \nmay be misleading. You may want to consider using Kendall's Tau instead.", max_ties));
             }
         }
 
@@ -830,12 +830,12 @@ impl WhiteboxTool for ImageCorrelationNeighbourhoodAnalysis {
         );
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output_val.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output_val.file_name)
                 }
             }
             Err(e) => return Err(e),
@@ -856,17 +856,14 @@ impl WhiteboxTool for ImageCorrelationNeighbourhoodAnalysis {
         let _ = match output_sig.write() {
             Ok(_) => {
                 if verbose {
-                    println!(" ")
+                    progress::message(" ")
                 }
             }
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         // println!("Number of total cells: {}", valid);
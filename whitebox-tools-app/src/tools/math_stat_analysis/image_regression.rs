@@ -9,6 +9,7 @@ License: MIT
 use self::statrs::distribution::{ContinuousCDF, FisherSnedecor, StudentsT};
 use whitebox_raster::*;
 use whitebox_common::rendering::Scattergram;
+use whitebox_common::progress;
 use crate::tools::*;
 use num_cpus;
 use rand::prelude::*;
@@ -24,7 +25,6 @@ use std::path::Path;
 use std::process::Command;
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::thread;
 
 /// This tool performs a bivariate linear regression analysis on two input raster images. The first image
 /// (`--i1`) is considered to be the independent variable while the second image (`--i2`) is considered to
@@ -191,6 +191,7 @@ impl WhiteboxTool for ImageRegression {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file1 = String::new();
         let mut input_file2 = String::new();
@@ -267,13 +268,7 @@ impl WhiteboxTool for ImageRegression {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -318,7 +313,7 @@ impl WhiteboxTool for ImageRegression {
         let start = Instant::now();
 
         if verbose {
-            println!("Loop 1 of 2...");
+            progress::message("Loop 1 of 2...");
         }
 
         let mut num_procs = num_cpus::get() as isize;
@@ -332,7 +327,7 @@ impl WhiteboxTool for ImageRegression {
             let input1 = input1.clone();
             let input2 = input2.clone();
             let tx = tx.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let mut sum_x = 0f64;
                 let mut sum_y = 0f64;
                 let mut sum_xy = 0f64;
@@ -355,7 +350,7 @@ impl WhiteboxTool for ImageRegression {
                         }
                     }
                 }
-                tx.send((sum_x, sum_y, sum_xy, sum_xx, sum_yy, n)).unwrap();
+                let _ = tx.send((sum_x, sum_y, sum_xy, sum_xx, sum_yy, n));
             });
         }
 
@@ -366,7 +361,7 @@ impl WhiteboxTool for ImageRegression {
         let mut sum_yy = 0f64;
         let mut n = 0f64;
         for _ in 0..num_procs {
-            let (a, b, c, d, e, f) = rx.recv().expect("Error receiving data from thread.");
+            let (a, b, c, d, e, f) = cancel.recv(&rx)?;
             sum_x += a;
             sum_y += b;
             sum_xy += c;
@@ -384,14 +379,14 @@ impl WhiteboxTool for ImageRegression {
         let x_mean = sum_x / n;
 
         if verbose {
-            println!("Loop 2 of 2...");
+            progress::message("Loop 2 of 2...");
         }
         let (tx, rx) = mpsc::channel();
         for tid in 0..num_procs {
             let input1 = input1.clone();
             let input2 = input2.clone();
             let tx = tx.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let mut x: f64;
                 let mut y: f64;
                 let mut y_estimate: f64;
@@ -408,14 +403,14 @@ impl WhiteboxTool for ImageRegression {
                         }
                     }
                 }
-                tx.send((ss_error, ss_total)).unwrap();
+                let _ = tx.send((ss_error, ss_total));
             });
         }
 
         let mut ss_error = 0f64;
         let mut ss_total = 0f64;
         for _ in 0..num_procs {
-            let (a, b) = rx.recv().expect("Error receiving data from thread.");
+            let (a, b) = cancel.recv(&rx)?;
             ss_error += a;
             ss_total += b;
         }
@@ -452,7 +447,7 @@ impl WhiteboxTool for ImageRegression {
                 let input1 = input1.clone();
                 let input2 = input2.clone();
                 let tx = tx.clone();
-                thread::spawn(move || {
+                progress::spawn(move || {
                     let mut x: f64;
                     let mut y: f64;
                     let mut y_estimate: f64;
@@ -472,7 +467,9 @@ impl WhiteboxTool for ImageRegression {
                                 data[col as usize] = residual;
                             }
                         }
-                        tx.send((row, data)).unwrap();
+                        if tx.send((row, data)).is_err() {
+                            return;
+                        }
                     }
                 });
             }
@@ -480,13 +477,13 @@ impl WhiteboxTool for ImageRegression {
             let mut output = Raster::initialize_using_file(&residuals_file, &input1);
             output.configs.data_type = DataType::F32;
             for r in 0..rows {
-                let (row, data) = rx.recv().expect("Error receiving data from thread.");
+                let (row, data) = cancel.recv(&rx)?;
                 output.set_row_data(row, data);
 
                 if verbose {
                     progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Creating residuals image: {}%", progress);
+                        progress::update_stage("Creating residuals image", progress);
                         old_progress = progress;
                     }
                 }
@@ -501,12 +498,12 @@ impl WhiteboxTool for ImageRegression {
             ));
 
             if verbose {
-                println!("Saving data...")
+                progress::message("Saving data...")
             };
             let _ = match output.write() {
                 Ok(_) => {
                     if verbose {
-                        println!("Output file written")
+                        progress::output_written(&output.file_name)
                     }
                 }
                 Err(e) => return Err(e),
@@ -804,7 +801,7 @@ impl WhiteboxTool for ImageRegression {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!("\n{}", &format!("Elapsed Time: {}", elapsed_time));
+            progress::message(format!("\n{}", &format!("Elapsed Time: {}", elapsed_time)));
         }
 
         if verbose {
@@ -832,7 +829,7 @@ impl WhiteboxTool for ImageRegression {
                 let _ = output.stdout;
             }
             if verbose {
-                println!("Complete! Please see {} for output.", output_file);
+                progress::message(format!("Complete! Please see {} for output.", output_file));
             }
         }
 
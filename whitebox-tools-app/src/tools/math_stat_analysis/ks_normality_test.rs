@@ -9,6 +9,7 @@ License: MIT
 use whitebox_raster::*;
 use whitebox_common::rendering::html::*;
 use whitebox_common::rendering::Histogram;
+use whitebox_common::progress;
 use crate::tools::*;
 use rand::prelude::*;
 use std::env;
@@ -50,7 +51,7 @@ impl KsTestForNormality {
         let mut parameters = vec![];
         parameters.push(ToolParameter {
             name: "Input File".to_owned(),
-            flags: vec!["-i".to_owned(), "--input".to_owned()],
+            flags: vec!["-i".to_owned(), "--base".to_owned(), "--input".to_owned()],
             description: "Input raster file.".to_owned(),
             parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
             default_value: None,
@@ -141,6 +142,7 @@ impl WhiteboxTool for KsTestForNormality {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut output_file = String::new();
@@ -191,13 +193,7 @@ impl WhiteboxTool for KsTestForNormality {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -261,10 +257,11 @@ impl WhiteboxTool for KsTestForNormality {
                         freq_data[bin_num] += 1;
                     }
                 }
+                cancel.check()?;
                 if verbose {
                     progress = (100.0_f64 * row as f64 / rows as f64) as i32;
                     if progress != old_progress {
-                        println!("Progress: {}%", progress);
+                        progress::update(progress);
                         old_progress = progress;
                     }
                 }
@@ -279,10 +276,11 @@ impl WhiteboxTool for KsTestForNormality {
                         total_deviation += (z - mean) * (z - mean);
                     }
                 }
+                cancel.check()?;
                 if verbose {
                     progress = (100.0_f64 * row as f64 / rows as f64) as i32;
                     if progress != old_progress {
-                        println!("Progress: {}%", progress);
+                        progress::update(progress);
                         old_progress = progress;
                     }
                 }
@@ -319,7 +317,7 @@ impl WhiteboxTool for KsTestForNormality {
                     if verbose {
                         progress = (100.0_f64 * sample_num as f64 / num_samples as f64) as i32;
                         if progress != old_progress {
-                            println!("Progress: {}%", progress);
+                            progress::update(progress);
                             old_progress = progress;
                         }
                     }
@@ -335,10 +333,11 @@ impl WhiteboxTool for KsTestForNormality {
                 z = input.get_value(row, col);
                 total_deviation += (z - mean) * (z - mean);
 
+                cancel.check()?;
                 if verbose {
                     progress = (100.0_f64 * sample_num as f64 / num_samples as f64) as i32;
                     if progress != old_progress {
-                        println!("Progress: {}%", progress);
+                        progress::update(progress);
                         old_progress = progress;
                     }
                 }
@@ -489,15 +488,12 @@ impl WhiteboxTool for KsTestForNormality {
                 let _ = output.stdout;
             }
 
-            println!("Complete! Please see {} for output.", output_file);
+            progress::message(format!("Complete! Please see {} for output.", output_file));
         }
 
         let elapsed_time = get_formatted_elapsed_time(start);
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -7,6 +7,7 @@ License: MIT
 */
 
 use whitebox_common::rendering::html::*;
+use whitebox_common::progress;
 use crate::tools::*;
 use whitebox_vector::{FieldData, Shapefile};
 use std::collections::HashMap;
@@ -151,6 +152,7 @@ impl WhiteboxTool for ListUniqueValues {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut field_name = String::new();
@@ -195,13 +197,7 @@ impl WhiteboxTool for ListUniqueValues {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -219,7 +215,7 @@ impl WhiteboxTool for ListUniqueValues {
         }
 
         if verbose {
-            println!("Reading vector data...")
+            progress::message("Reading vector data...")
         };
 
         let vector_data = Shapefile::read(&input_file)?;
@@ -240,11 +236,12 @@ impl WhiteboxTool for ListUniqueValues {
             *count += 1;
             // }
 
+            cancel.check()?;
             if verbose {
                 progress =
                     (100.0_f64 * record_num as f64 / (vector_data.num_records - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Reading attribute data: {}%", progress);
+                    progress::update_stage("Reading attribute data", progress);
                     old_progress = progress;
                 }
             }
@@ -252,17 +249,14 @@ impl WhiteboxTool for ListUniqueValues {
 
         if freq_data.len() > 250 {
             if verbose {
-                println!("Warning: There are a large number of categories. A continuous attribute variable may have been input incorrectly.");
+                progress::warning("There are a large number of categories. A continuous attribute variable may have been input incorrectly.");
             }
         }
 
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!(
-                "\n{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("\n{}", &format!("Elapsed Time (excluding I/O): {}", elapsed_time)));
         }
 
         let f = File::create(output_file.clone())?;
@@ -360,7 +354,7 @@ impl WhiteboxTool for ListUniqueValues {
                 let _ = output.stdout;
             }
             if verbose {
-                println!("Complete! Please see {} for output.", output_file);
+                progress::message(format!("Complete! Please see {} for output.", output_file));
             }
         }
 
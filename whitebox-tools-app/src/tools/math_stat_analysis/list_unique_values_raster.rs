@@ -6,6 +6,7 @@ Last Modified: 09/02/2023
 License: MIT
 */
 
+use whitebox_common::progress;
 use crate::tools::*;
 use whitebox_raster::*;
 use std::collections::HashMap;
@@ -15,7 +16,6 @@ use std::io::{Error, ErrorKind};
 use std::path;
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::thread;
 use num_cpus;
 
 /// This tool can be used to list each of the unique values contained within a categorical raster (`input`). The tool 
@@ -72,7 +72,7 @@ impl ListUniqueValuesRaster {
             short_exe += ".exe";
         }
         let usage = format!(
-            ">>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=lakes.shp --field=HEIGHT -o=outfile.html",
+            ">>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=input.tif",
             short_exe, name
         )
         .replace("*", &sep);
@@ -127,6 +127,7 @@ impl WhiteboxTool for ListUniqueValuesRaster {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
 
@@ -157,13 +158,7 @@ impl WhiteboxTool for ListUniqueValuesRaster {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -176,7 +171,7 @@ impl WhiteboxTool for ListUniqueValuesRaster {
         }
 
         if verbose {
-            println!("Reading vector data...")
+            progress::message("Reading vector data...")
         };
 
         let input = Arc::new(Raster::new(&input_file, "r")?);
@@ -196,7 +191,7 @@ impl WhiteboxTool for ListUniqueValuesRaster {
         for tid in 0..num_procs {
             let input = input.clone();
             let tx1 = tx.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let mut freq_data = HashMap::new();
                 let mut z: f64;
                 for row in (0..rows).filter(|r| r % num_procs == tid) {
@@ -209,13 +204,13 @@ impl WhiteboxTool for ListUniqueValuesRaster {
                     }
                 }
 
-                tx1.send(freq_data).unwrap();
+                let _ = tx1.send(freq_data);
             });
         }
 
         let mut freq_data = HashMap::new();
         for n in 0..num_procs {
-            let data = rx.recv().expect("Error receiving data from thread.");
+            let data = cancel.recv(&rx)?;
             for (category, count) in &data {
                 let overall_count = freq_data.entry(*category).or_insert(0);
                 *overall_count += *count;
@@ -224,7 +219,7 @@ impl WhiteboxTool for ListUniqueValuesRaster {
             if verbose {
                 progress = (100.0_f64 * (n + 1) as f64 / num_procs as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    progress::update(progress);
                     old_progress = progress;
                 }
             }
@@ -237,7 +232,7 @@ impl WhiteboxTool for ListUniqueValuesRaster {
 
         if freq_data.len() > 500 {
             if verbose {
-                println!("Warning: There are a large number of categories. A continuous attribute variable may have been input incorrectly.");
+                progress::warning("There are a large number of categories. A continuous attribute variable may have been input incorrectly.");
             }
         }
 
@@ -248,14 +243,11 @@ impl WhiteboxTool for ListUniqueValuesRaster {
             ret_str.push_str(&format!("{},{}\n", freq_data_vec[i].0, freq_data_vec[i].1));
         }
 
-        println!("{ret_str}");
+        progress::message(format!("{ret_str}"));
 
         let elapsed_time = get_formatted_elapsed_time(start);
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
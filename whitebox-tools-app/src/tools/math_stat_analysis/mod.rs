@@ -15,6 +15,7 @@ mod attribute_correlation_neighbourhood_analysis;
 mod attribute_histogram;
 mod attribute_scattergram;
 mod ceil;
+mod cluster_and_outlier_analysis;
 mod cos;
 mod cosh;
 mod crispness_index;
@@ -27,6 +28,7 @@ mod exp;
 mod exp2;
 mod floor;
 mod greater_than;
+mod hot_spot_analysis;
 mod image_autocorrelation;
 mod image_correlation;
 mod image_correlation_neighbourhood_analysis;
@@ -80,6 +82,7 @@ mod trend_surface_vector_points;
 mod truncate;
 mod turning_bands;
 mod two_sample_ks_test;
+mod vector_autocorrelation;
 mod wilcoxon_signed_rank_test;
 mod xor;
 mod zonal_statistics;
@@ -102,6 +105,7 @@ pub use self::attribute_correlation_neighbourhood_analysis::AttributeCorrelation
 pub use self::attribute_histogram::AttributeHistogram;
 pub use self::attribute_scattergram::AttributeScattergram;
 pub use self::ceil::Ceil;
+pub use self::cluster_and_outlier_analysis::ClusterAndOutlierAnalysis;
 pub use self::cos::Cos;
 pub use self::cosh::Cosh;
 pub use self::crispness_index::CrispnessIndex;
@@ -114,6 +118,7 @@ pub use self::exp::Exp;
 pub use self::exp2::Exp2;
 pub use self::floor::Floor;
 pub use self::greater_than::GreaterThan;
+pub use self::hot_spot_analysis::HotSpotAnalysis;
 pub use self::image_autocorrelation::ImageAutocorrelation;
 pub use self::image_correlation::ImageCorrelation;
 pub use self::image_correlation_neighbourhood_analysis::ImageCorrelationNeighbourhoodAnalysis;
@@ -167,6 +172,7 @@ pub use self::trend_surface_vector_points::TrendSurfaceVectorPoints;
 pub use self::truncate::Truncate;
 pub use self::turning_bands::TurningBandsSimulation;
 pub use self::two_sample_ks_test::TwoSampleKsTest;
+pub use self::vector_autocorrelation::VectorAutocorrelation;
 pub use self::wilcoxon_signed_rank_test::WilcoxonSignedRankTest;
 pub use self::xor::Xor;
 pub use self::zonal_statistics::ZonalStatistics;
@@ -10,6 +10,7 @@ use crate::na::DMatrix;
 use whitebox_raster::*;
 use whitebox_common::rendering::html::*;
 use whitebox_common::rendering::LineGraph;
+use whitebox_common::progress;
 use crate::tools::*;
 use std::env;
 use std::f64;
@@ -180,6 +181,7 @@ impl WhiteboxTool for PrincipalComponentAnalysis {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_files_str = String::new();
         let mut output_html_file = String::new();
@@ -237,13 +239,7 @@ impl WhiteboxTool for PrincipalComponentAnalysis {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -295,7 +291,7 @@ impl WhiteboxTool for PrincipalComponentAnalysis {
         let mut input_raster: Vec<Raster> = Vec::with_capacity(num_files);
         let mut file_names = vec![];
         if verbose {
-            println!("Calculating image means...");
+            progress::message("Calculating image means...");
         }
         for i in 0..num_files {
             if !input_files[i].trim().is_empty() {
@@ -363,10 +359,11 @@ impl WhiteboxTool for PrincipalComponentAnalysis {
                     }
                 }
             }
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    progress::update(progress);
                     old_progress = progress;
                 }
             }
@@ -611,7 +608,7 @@ impl WhiteboxTool for PrincipalComponentAnalysis {
                 let _ = output.stdout;
             }
 
-            println!("Please see {} for output report.", output_html_file);
+            progress::message(format!("Please see {} for output report.", output_html_file));
         }
 
         // Output the component images
@@ -636,10 +633,11 @@ impl WhiteboxTool for PrincipalComponentAnalysis {
                         output.set_value(row, col, z1);
                     }
                 }
+                cancel.check()?;
                 if verbose {
                     progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Saving component image {}: {}%", (a + 1), progress);
+                        progress::update_stage(&format!("Saving component image {}", (a + 1)), progress);
                         old_progress = progress;
                     }
                 }
@@ -653,7 +651,7 @@ impl WhiteboxTool for PrincipalComponentAnalysis {
             output.add_metadata_entry(format!("Elapsed Time (including I/O): {}", elapsed_time));
 
             if verbose {
-                println!("Saving component image {}...", (a + 1))
+                progress::message(format!("Saving component image {}...", (a + 1)))
             };
             let _ = match output.write() {
                 Ok(_) => (),
@@ -664,10 +662,7 @@ impl WhiteboxTool for PrincipalComponentAnalysis {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (including I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (including I/O): {}", elapsed_time));
         }
 
         Ok(())
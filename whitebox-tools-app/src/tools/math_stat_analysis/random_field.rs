@@ -7,17 +7,17 @@ License: MIT
 */
 
 use whitebox_raster::*;
+use whitebox_common::progress;
+use whitebox_common::random;
 use crate::tools::*;
 use num_cpus;
 use rand::prelude::*;
-use rand::rngs::SmallRng;
 use rand_distr::StandardNormal;
 use std::env;
 use std::f64;
 use std::io::{Error, ErrorKind};
 use std::path;
 use std::sync::mpsc;
-use std::thread;
 
 /// This tool can be used to a raster image filled with random values drawn from a standard normal distribution.
 /// The values range from approximately -4.0 to 4.0, with a mean of 0 and a standard deviation of 1.0. The
@@ -47,7 +47,7 @@ impl RandomField {
         let mut parameters = vec![];
         parameters.push(ToolParameter {
             name: "Input Base File".to_owned(),
-            flags: vec!["-i".to_owned(), "--base".to_owned()],
+            flags: vec!["-i".to_owned(), "--input".to_owned(), "--base".to_owned()],
             description: "Input raster file.".to_owned(),
             parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
             default_value: None,
@@ -63,6 +63,8 @@ impl RandomField {
             optional: false,
         });
 
+        parameters.push(seed_parameter());
+
         let sep: String = path::MAIN_SEPARATOR.to_string();
         let e = format!("{}", env::current_exe().unwrap().display());
         let mut parent = env::current_exe().unwrap();
@@ -132,9 +134,11 @@ impl WhiteboxTool for RandomField {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut output_file = String::new();
+        let mut seed: Option<u64> = None;
 
         if args.len() == 0 {
             return Err(Error::new(
@@ -160,6 +164,9 @@ impl WhiteboxTool for RandomField {
                 } else {
                     input_file = args[i + 1].to_string();
                 }
+            } else if vec[0].to_lowercase() == "--seed" {
+                let v = if keyval { vec[1].to_string() } else { args[i + 1].to_string() };
+                seed = Some(parse_seed(&v)?);
             } else if vec[0].to_lowercase() == "-o" || vec[0].to_lowercase() == "--output" {
                 if keyval {
                     output_file = vec[1].to_string();
@@ -171,13 +178,7 @@ impl WhiteboxTool for RandomField {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -207,30 +208,31 @@ impl WhiteboxTool for RandomField {
         if max_procs > 0 && max_procs < num_procs {
             num_procs = max_procs;
         }
+        let seed = random::tool_seed(seed);
         let (tx, rx) = mpsc::channel();
         for tid in 0..num_procs {
             let tx = tx.clone();
-            thread::spawn(move || {
-                // let mut rng = rand::thread_rng();
-                // let normal = Normal::new(0.0, 1.0);
-                let mut rng = SmallRng::from_entropy();
+            progress::spawn(move || {
+                let mut rng = random::new_rng(seed, tid as u64);
                 for row in (0..rows).filter(|r| r % num_procs == tid) {
                     let mut data = vec![nodata; columns as usize];
                     for col in 0..columns {
                         data[col as usize] = rng.sample(StandardNormal); //normal.ind_sample(&mut rng);
                     }
-                    tx.send((row, data)).unwrap();
+                    if tx.send((row, data)).is_err() {
+                        return;
+                    }
                 }
             });
         }
 
         for row in 0..rows {
-            let data = rx.recv().expect("Error receiving data from thread.");
+            let data = cancel.recv(&rx)?;
             output.set_row_data(data.0, data.1);
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as i32;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    progress::update(progress);
                     old_progress = progress;
                 }
             }
@@ -246,21 +248,18 @@ impl WhiteboxTool for RandomField {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
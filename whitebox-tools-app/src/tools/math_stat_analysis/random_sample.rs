@@ -7,6 +7,8 @@ License: MIT
 */
 
 use whitebox_raster::*;
+use whitebox_common::progress;
+use whitebox_common::random;
 use crate::tools::*;
 use rand::prelude::*;
 use std::env;
@@ -42,7 +44,7 @@ impl RandomSample {
         let mut parameters = vec![];
         parameters.push(ToolParameter {
             name: "Input Base File".to_owned(),
-            flags: vec!["-i".to_owned(), "--base".to_owned()],
+            flags: vec!["-i".to_owned(), "--input".to_owned(), "--base".to_owned()],
             description: "Input raster file.".to_owned(),
             parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
             default_value: None,
@@ -67,6 +69,8 @@ impl RandomSample {
             optional: false,
         });
 
+        parameters.push(seed_parameter());
+
         let sep: String = path::MAIN_SEPARATOR.to_string();
         let e = format!("{}", env::current_exe().unwrap().display());
         let mut parent = env::current_exe().unwrap();
@@ -136,10 +140,12 @@ impl WhiteboxTool for RandomSample {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut output_file = String::new();
         let mut num_samples = 1000usize;
+        let mut seed: Option<u64> = None;
 
         if args.len() == 0 {
             return Err(Error::new(
@@ -169,6 +175,9 @@ impl WhiteboxTool for RandomSample {
                 } else {
                     args[i + 1].to_string()
                 };
+            } else if flag_val == "-seed" {
+                let v = if keyval { vec[1].to_string() } else { args[i + 1].to_string() };
+                seed = Some(parse_seed(&v)?);
             } else if flag_val == "-num_samples" {
                 num_samples = if keyval {
                     vec[1]
@@ -186,13 +195,7 @@ impl WhiteboxTool for RandomSample {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -225,12 +228,13 @@ impl WhiteboxTool for RandomSample {
         let mut output = Raster::initialize_using_file(&output_file, &input);
         output.reinitialize_values(0f64);
 
-        let mut rng = thread_rng();
+        let mut rng = random::new_rng(random::tool_seed(seed), 0);
         // let row_rng = Range::new(0, rows as isize);
         // let col_rng = Range::new(0, columns as isize);
         let mut sample_num = 0usize;
         let mut num_tries = 0usize;
         while sample_num < num_samples {
+            cancel.check()?;
             let row = rng.gen_range(0..rows as isize); //row_rng.ind_sample(&mut rng);
             let col = rng.gen_range(0..columns as isize); //col_rng.ind_sample(&mut rng);
             if output.get_value(row, col) == 0f64 && input.get_value(row, col) != nodata {
@@ -240,14 +244,14 @@ impl WhiteboxTool for RandomSample {
                 if verbose {
                     progress = (100.0_f64 * sample_num as f64 / num_samples as f64) as i32;
                     if progress != old_progress {
-                        println!("Progress: {}%", progress);
+                        progress::update(progress);
                         old_progress = progress;
                     }
                 }
             }
             num_tries += 1;
             if num_tries >= 20 * num_samples {
-                println!("Warning: num_samples may be too large for the size of grid.");
+                progress::warning("num_samples may be too large for the size of grid.");
                 break;
             }
         }
@@ -265,21 +269,18 @@ impl WhiteboxTool for RandomSample {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
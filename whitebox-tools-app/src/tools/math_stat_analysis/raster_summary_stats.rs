@@ -7,6 +7,7 @@ License: MIT
 */
 
 use whitebox_raster::*;
+use whitebox_common::progress;
 use crate::tools::*;
 use num_cpus;
 use std::env;
@@ -15,7 +16,6 @@ use std::io::{Error, ErrorKind};
 use std::path;
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::thread;
 
 /// This tool outputs distribution summary statistics for input raster images (`--input`).
 /// The distribution statistics include the raster minimum, maximum, range, total, mean,
@@ -136,6 +136,7 @@ impl WhiteboxTool for RasterSummaryStats {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
 
@@ -165,13 +166,7 @@ impl WhiteboxTool for RasterSummaryStats {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -184,7 +179,7 @@ impl WhiteboxTool for RasterSummaryStats {
         }
 
         if verbose {
-            println!("Reading data...")
+            progress::message("Reading data...")
         };
 
         let input = Arc::new(Raster::new(&input_file, "r")?);
@@ -207,7 +202,7 @@ impl WhiteboxTool for RasterSummaryStats {
         for tid in 0..num_procs {
             let input = input.clone();
             let tx = tx.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let mut z: f64;
                 for row in (0..rows).filter(|r| r % num_procs == tid) {
                     let mut n = 0;
@@ -229,7 +224,9 @@ impl WhiteboxTool for RasterSummaryStats {
                             }
                         }
                     }
-                    tx.send((n, s, sq, minz, maxz)).unwrap();
+                    if tx.send((n, s, sq, minz, maxz)).is_err() {
+                        return;
+                    }
                 }
             });
         }
@@ -240,7 +237,7 @@ impl WhiteboxTool for RasterSummaryStats {
         let mut minz = f64::INFINITY;
         let mut maxz = f64::NEG_INFINITY;
         for row in 0..rows {
-            let (a, b, c, d, e) = rx.recv().expect("Error receiving data from thread.");
+            let (a, b, c, d, e) = cancel.recv(&rx)?;
             num_cells += a;
             sum += b;
             sq_sum += c;
@@ -253,7 +250,7 @@ impl WhiteboxTool for RasterSummaryStats {
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    progress::update(progress);
                     old_progress = progress;
                 }
             }
@@ -265,23 +262,17 @@ impl WhiteboxTool for RasterSummaryStats {
 
         let elapsed_time = get_formatted_elapsed_time(start);
 
-        println!("\nNumber of non-nodata grid cells: {}", num_cells);
-        println!(
-            "Number of nodata grid cells: {}",
-            input.num_cells() - num_cells
-        );
-        println!("Image minimum: {}", minz);
-        println!("Image maximum: {}", maxz);
-        println!("Image range: {}", maxz - minz);
-        println!("Image total: {}", sum);
-        println!("Image average: {}", mean);
-        println!("Image variance: {}", variance);
-        println!("Image standard deviation: {}", std_dev);
+        progress::message(format!("\nNumber of non-nodata grid cells: {}", num_cells));
+        progress::message(format!("Number of nodata grid cells: {}", input.num_cells() - num_cells));
+        progress::message(format!("Image minimum: {}", minz));
+        progress::message(format!("Image maximum: {}", maxz));
+        progress::message(format!("Image range: {}", maxz - minz));
+        progress::message(format!("Image total: {}", sum));
+        progress::message(format!("Image average: {}", mean));
+        progress::message(format!("Image variance: {}", variance));
+        progress::message(format!("Image standard deviation: {}", std_dev));
         if verbose {
-            println!(
-                "\n{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("\n{}", &format!("Elapsed Time (excluding I/O): {}", elapsed_time)));
         }
 
         Ok(())
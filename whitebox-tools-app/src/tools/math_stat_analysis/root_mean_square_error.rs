@@ -7,6 +7,7 @@ License: MIT
 */
 
 use whitebox_raster::*;
+use whitebox_common::progress;
 use crate::tools::*;
 use num_cpus;
 use std::cmp::Ordering::Equal;
@@ -16,7 +17,6 @@ use std::io::{Error, ErrorKind};
 use std::path;
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::thread;
 
 /// This tool calculates the root-mean-square-error (RMSE) or root-mean-square-difference (RMSD) from two
 /// input rasters. If the two input rasters possess the same number of rows and columns, the RMSE is
@@ -132,6 +132,7 @@ impl WhiteboxTool for RootMeanSquareError {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut base_file = String::new();
@@ -168,13 +169,7 @@ impl WhiteboxTool for RootMeanSquareError {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -190,7 +185,7 @@ impl WhiteboxTool for RootMeanSquareError {
         }
 
         if verbose {
-            println!("Reading data...")
+            progress::message("Reading data...")
         };
 
         let input = Arc::new(Raster::new(&input_file, "r")?);
@@ -221,7 +216,7 @@ impl WhiteboxTool for RootMeanSquareError {
                 let input = input.clone();
                 let base_raster = base_raster.clone();
                 let tx = tx.clone();
-                thread::spawn(move || {
+                progress::spawn(move || {
                     let mut z1: f64;
                     let mut z2: f64;
                     let mut z_diff: f64;
@@ -241,7 +236,9 @@ impl WhiteboxTool for RootMeanSquareError {
                                 data.push(z_diff.abs() as f32);
                             }
                         }
-                        tx.send((n, s, sq, data)).unwrap();
+                        if tx.send((n, s, sq, data)).is_err() {
+                            return;
+                        }
                     }
                 });
             }
@@ -251,7 +248,7 @@ impl WhiteboxTool for RootMeanSquareError {
             let mut sq_sum = 0.0;
             let mut abs_residuals: Vec<f32> = Vec::with_capacity((rows * columns) as usize);
             for row in 0..rows {
-                let (a, b, c, d) = rx.recv().expect("Error receiving data from thread.");
+                let (a, b, c, d) = cancel.recv(&rx)?;
                 num_cells += a;
                 sum += b;
                 sq_sum += c;
@@ -263,7 +260,7 @@ impl WhiteboxTool for RootMeanSquareError {
                 if verbose {
                     progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Progress: {}%", progress);
+                        progress::update(progress);
                         old_progress = progress;
                     }
                 }
@@ -273,21 +270,18 @@ impl WhiteboxTool for RootMeanSquareError {
             let mean_vertical_error = sum / num_cells as f64;
             let ninety_percent_cell = (0.9 * num_cells as f64) as usize;
             if verbose {
-                println!("Sorting the residuals...");
+                progress::message("Sorting the residuals...");
             }
             abs_residuals.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Equal));
             let le90 = abs_residuals[ninety_percent_cell];
 
-            println!("\nVertical Accuracy Analysis:\n");
-            println!("Comparison File: {}", input_file);
-            println!("Base File: {}", base_file);
-            println!("Mean vertical error: {:.5}", mean_vertical_error);
-            println!("RMSE: {:.5}", rmse);
-            println!(
-                "Accuracy at 95-percent confidence limit: {:.5}",
-                rmse * 1.96f64
-            );
-            println!("LE90: {:.5}", le90);
+            progress::message("\nVertical Accuracy Analysis:\n");
+            progress::message(format!("Comparison File: {}", input_file));
+            progress::message(format!("Base File: {}", base_file));
+            progress::message(format!("Mean vertical error: {:.5}", mean_vertical_error));
+            progress::message(format!("RMSE: {:.5}", rmse));
+            progress::message(format!("Accuracy at 95-percent confidence limit: {:.5}", rmse * 1.96f64));
+            progress::message(format!("LE90: {:.5}", le90));
         } else {
             /* The two grids are not of the same resolution. Bilinear resampling will have to be
             carried out to estimate z-values. Base image = source; input image = destination */
@@ -302,7 +296,7 @@ impl WhiteboxTool for RootMeanSquareError {
                 let input = input.clone();
                 let base_raster = base_raster.clone();
                 let tx = tx.clone();
-                thread::spawn(move || {
+                progress::spawn(move || {
                     let mut y: f64;
                     let mut x: f64;
                     let mut z1: f64;
@@ -395,7 +389,9 @@ impl WhiteboxTool for RootMeanSquareError {
                                 }
                             }
                         }
-                        tx.send((n, s, sq, data)).unwrap();
+                        if tx.send((n, s, sq, data)).is_err() {
+                            return;
+                        }
                     }
                 });
             }
@@ -405,7 +401,7 @@ impl WhiteboxTool for RootMeanSquareError {
             let mut sq_sum = 0.0;
             let mut abs_residuals: Vec<f32> = Vec::with_capacity((rows * columns) as usize);
             for row in 0..rows {
-                let (a, b, c, d) = rx.recv().expect("Error receiving data from thread.");
+                let (a, b, c, d) = cancel.recv(&rx)?;
                 num_cells += a;
                 sum += b;
                 sq_sum += c;
@@ -417,7 +413,7 @@ impl WhiteboxTool for RootMeanSquareError {
                 if verbose {
                     progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Progress: {}%", progress);
+                        progress::update(progress);
                         old_progress = progress;
                     }
                 }
@@ -427,29 +423,23 @@ impl WhiteboxTool for RootMeanSquareError {
             let mean_vertical_error = sum / num_cells as f64;
             let ninety_percent_cell = (0.9 * num_cells as f64) as usize;
             if verbose {
-                println!("Sorting the residuals...");
+                progress::message("Sorting the residuals...");
             }
             abs_residuals.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Equal));
             let le90 = abs_residuals[ninety_percent_cell];
 
-            println!("\nVertical Accuracy Analysis:\n");
-            println!("Comparison File: {}", input_file);
-            println!("Base File: {}", base_file);
-            println!("Mean vertical error: {:.5}", mean_vertical_error);
-            println!("RMSE: {:.5}", rmse);
-            println!(
-                "Accuracy at 95-percent confidence limit: {:.5}",
-                rmse * 1.96f64
-            );
-            println!("LE90: {:.5}", le90);
+            progress::message("\nVertical Accuracy Analysis:\n");
+            progress::message(format!("Comparison File: {}", input_file));
+            progress::message(format!("Base File: {}", base_file));
+            progress::message(format!("Mean vertical error: {:.5}", mean_vertical_error));
+            progress::message(format!("RMSE: {:.5}", rmse));
+            progress::message(format!("Accuracy at 95-percent confidence limit: {:.5}", rmse * 1.96f64));
+            progress::message(format!("LE90: {:.5}", le90));
         }
 
         let elapsed_time = get_formatted_elapsed_time(start);
         if verbose {
-            println!(
-                "\n{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("\n{}", &format!("Elapsed Time (excluding I/O): {}", elapsed_time)));
         }
 
         Ok(())
@@ -9,6 +9,7 @@ License: MIT
 use crate::na::{DMatrix, DVector};
 use whitebox_raster::*;
 use whitebox_common::rendering::html::*;
+use whitebox_common::progress;
 use crate::tools::*;
 use whitebox_vector::{FieldData, ShapeType, Shapefile};
 use std::env;
@@ -161,6 +162,7 @@ impl WhiteboxTool for TrendSurfaceVectorPoints {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut field_name = String::new();
@@ -231,13 +233,7 @@ impl WhiteboxTool for TrendSurfaceVectorPoints {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -260,7 +256,7 @@ impl WhiteboxTool for TrendSurfaceVectorPoints {
         }
 
         if verbose {
-            println!("Reading data...")
+            progress::message("Reading data...")
         };
 
         let vector_data = Shapefile::read(&input_file)?;
@@ -356,11 +352,12 @@ impl WhiteboxTool for TrendSurfaceVectorPoints {
             y.push(y_val);
             z.push(z_val);
 
+            cancel.check()?;
             if verbose {
                 progress =
                     (100.0_f64 * record_num as f64 / (vector_data.num_records - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Reading attributes: {}%", progress);
+                    progress::update_stage("Reading attributes", progress);
                     old_progress = progress;
                 }
             }
@@ -558,7 +555,7 @@ impl WhiteboxTool for TrendSurfaceVectorPoints {
                 let _ = output.stdout;
             }
 
-            println!("Please see {} for output report.", output_html_file);
+            progress::message(format!("Please see {} for output report.", output_html_file));
         }
 
         // create the output trend-surface raster
@@ -579,10 +576,11 @@ impl WhiteboxTool for TrendSurfaceVectorPoints {
                 }
                 output.set_value(row, col, z_val);
             }
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    progress::update(progress);
                     old_progress = progress;
                 }
             }
@@ -599,22 +597,19 @@ impl WhiteboxTool for TrendSurfaceVectorPoints {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (including I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (including I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -7,11 +7,11 @@ License: MIT
 */
 
 use whitebox_raster::*;
+use whitebox_common::progress;
+use whitebox_common::random;
 use crate::tools::*;
 use num_cpus;
 use rand::prelude::*;
-// use rand::{Rng, SeedableRng};
-use rand::thread_rng;
 use rand_distr::StandardNormal;
 use std::env;
 use std::f64;
@@ -19,7 +19,6 @@ use std::io::{Error, ErrorKind};
 use std::path;
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::thread;
 
 /// This tool can be used to create a random field using the turning bands algorithm. The user must specify
 /// the name of a base raster image (`--base`) from which the output raster will derive its geographical
@@ -65,7 +64,7 @@ impl TurningBandsSimulation {
         let mut parameters = vec![];
         parameters.push(ToolParameter {
             name: "Input Base File".to_owned(),
-            flags: vec!["-i".to_owned(), "--base".to_owned()],
+            flags: vec!["-i".to_owned(), "--input".to_owned(), "--base".to_owned()],
             description: "Input base raster file.".to_owned(),
             parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
             default_value: None,
@@ -101,6 +100,8 @@ impl TurningBandsSimulation {
             optional: true,
         });
 
+        parameters.push(seed_parameter());
+
         let sep: String = path::MAIN_SEPARATOR.to_string();
         let e = format!("{}", env::current_exe().unwrap().display());
         let mut parent = env::current_exe().unwrap();
@@ -166,11 +167,13 @@ impl WhiteboxTool for TurningBandsSimulation {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut output_file = String::new();
         let mut range = 1f64;
         let mut iterations = 1000;
+        let mut seed: Option<u64> = None;
 
         if args.len() == 0 {
             return Err(Error::new(
@@ -200,6 +203,9 @@ impl WhiteboxTool for TurningBandsSimulation {
                 } else {
                     output_file = args[i + 1].to_string();
                 }
+            } else if flag_val == "-seed" {
+                let v = if keyval { vec[1].to_string() } else { args[i + 1].to_string() };
+                seed = Some(parse_seed(&v)?);
             } else if flag_val == "-range" {
                 if keyval {
                     range = vec[1]
@@ -231,13 +237,7 @@ impl WhiteboxTool for TurningBandsSimulation {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -273,8 +273,9 @@ impl WhiteboxTool for TurningBandsSimulation {
         let mut output = Raster::initialize_using_file(&output_file, &input);
         output.reinitialize_values(0.0);
 
-        let mut rng = thread_rng();
-        let mut rng2 = thread_rng();
+        let seed = random::tool_seed(seed);
+        let mut rng = random::new_rng(seed, 0);
+        let mut rng2 = random::new_rng(seed, 1);
         // let normal = Normal::new(0.0, 1.0);
         // let between = Range::new(0, 4);
         // let between_rows = Range::new(0f64, rows as f64);
@@ -325,67 +326,13 @@ impl WhiteboxTool for TurningBandsSimulation {
                 edge2 = rng.gen_range(0..4); //between.ind_sample(&mut rng);
             }
 
-            match edge1 {
-                0 => {
-                    pnt1x = 0f64;
-                    pnt1y = rng2.gen_range(0..rows as isize) as f64; //between_rows.ind_sample(&mut rng);
-                }
-                1 => {
-                    pnt1x = rng2.gen_range(0..columns as isize) as f64; //between_cols.ind_sample(&mut rng);
-                    pnt1y = 0f64;
-                }
-                2 => {
-                    pnt1x = (columns - 1) as f64;
-                    pnt1y = rng2.gen_range(0..rows as isize) as f64; //between_rows.ind_sample(&mut rng);
-                }
-                _ => {
-                    // 3
-                    pnt1x = rng2.gen_range(0..columns as isize) as f64; //between_cols.ind_sample(&mut rng);
-                    pnt1y = (rows - 1) as f64;
-                }
-            }
-
-            match edge2 {
-                0 => {
-                    pnt2x = 0f64;
-                    pnt2y = rng2.gen_range(0..rows as isize) as f64; //between_rows.ind_sample(&mut rng);
-                }
-                1 => {
-                    pnt2x = rng2.gen_range(0..columns as isize) as f64; //between_cols.ind_sample(&mut rng);
-                    pnt2y = 0f64;
-                }
-                2 => {
-                    pnt2x = (columns - 1) as f64;
-                    pnt2y = rng2.gen_range(0..rows as isize) as f64; //between_rows.ind_sample(&mut rng);
-                }
-                _ => {
-                    // 3
-                    pnt2x = rng2.gen_range(0..columns as isize) as f64; //between_cols.ind_sample(&mut rng);
-                    pnt2y = (rows - 1) as f64;
-                }
-            }
-
-            if pnt1x == pnt2x || pnt1y == pnt2y {
-                while pnt1x == pnt2x || pnt1y == pnt2y {
-                    match edge2 {
-                        0 => {
-                            pnt2x = 0f64;
-                            pnt2y = rng2.gen_range(0..rows as isize) as f64; //between_rows.ind_sample(&mut rng);
-                        }
-                        1 => {
-                            pnt2x = rng2.gen_range(0..columns as isize) as f64; //between_cols.ind_sample(&mut rng);
-                            pnt2y = 0f64;
-                        }
-                        2 => {
-                            pnt2x = (columns - 1) as f64;
-                            pnt2y = rng2.gen_range(0..rows as isize) as f64; //between_rows.ind_sample(&mut rng);
-                        }
-                        _ => {
-                            // 3
-                            pnt2x = rng2.gen_range(0..columns as isize) as f64; //between_cols.ind_sample(&mut rng);
-                            pnt2y = (rows - 1) as f64;
-                        }
-                    }
+            // Redraw both points until they differ in both x and y. Redrawing only the second
+            // point could loop forever when the first lies on a corner shared with edge2.
+            loop {
+                (pnt1x, pnt1y) = point_on_edge(edge1, rows, columns, &mut rng2);
+                (pnt2x, pnt2y) = point_on_edge(edge2, rows, columns, &mut rng2);
+                if pnt1x != pnt2x && pnt1y != pnt2y {
+                    break;
                 }
             }
 
@@ -453,7 +400,7 @@ impl WhiteboxTool for TurningBandsSimulation {
             for tid in 0..num_procs {
                 let y = y.clone();
                 let tx = tx.clone();
-                thread::spawn(move || {
+                progress::spawn(move || {
                     for row in (0..rows).filter(|r| r % num_procs == tid) {
                         let mut perpendicular_line_intercept: f64;
                         let (mut intersecting_point_x, mut intersecting_point_y): (f64, f64);
@@ -478,20 +425,22 @@ impl WhiteboxTool for TurningBandsSimulation {
                             }
                             data[col as usize] = y[p as usize];
                         }
-                        tx.send((row, data)).unwrap();
+                        if tx.send((row, data)).is_err() {
+                            return;
+                        }
                     }
                 });
             }
 
             for _ in 0..rows {
-                let (row, data) = rx.recv().expect("Error receiving data from thread.");
+                let (row, data) = cancel.recv(&rx)?;
                 output.increment_row_data(row, data);
             }
 
             if verbose {
                 progress = (100.0_f64 * i as f64 / (iterations - 1) as f64) as i32;
                 if progress != old_progress {
-                    println!("Progress (Loop 1 of 2): {}%", progress);
+                    progress::update_stage("Progress (Loop 1 of 2)", progress);
                     old_progress = progress;
                 }
             }
@@ -504,10 +453,11 @@ impl WhiteboxTool for TurningBandsSimulation {
                 output[(row, col)] = output[(row, col)] / iterations_rooted;
             }
 
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as i32;
                 if progress != old_progress {
-                    println!("Progress (Loop 2 of 2): {}%", progress);
+                    progress::update_stage("Progress (Loop 2 of 2)", progress);
                     old_progress = progress;
                 }
             }
@@ -527,23 +477,30 @@ impl WhiteboxTool for TurningBandsSimulation {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
     }
 }
+
+/// Returns a random point on one of the grid's edges: 0 = left, 1 = top, 2 = right, and 3 = bottom.
+fn point_on_edge<R: Rng>(edge: usize, rows: isize, columns: isize, rng: &mut R) -> (f64, f64) {
+    match edge {
+        0 => (0f64, rng.gen_range(0..rows) as f64),
+        1 => (rng.gen_range(0..columns) as f64, 0f64),
+        2 => ((columns - 1) as f64, rng.gen_range(0..rows) as f64),
+        _ => (rng.gen_range(0..columns) as f64, (rows - 1) as f64),
+    }
+}
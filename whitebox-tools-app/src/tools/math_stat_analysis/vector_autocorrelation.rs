@@ -0,0 +1,672 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: Dr. John Lindsay
+Created: 18/10/2026
+Last Modified: 18/10/2026
+License: MIT
+*/
+
+use self::statrs::distribution::{ContinuousCDF, Normal};
+use whitebox_common::rendering::html::*;
+use whitebox_common::progress;
+use whitebox_common::random;
+use crate::tools::*;
+use whitebox_vector::*;
+use num_cpus;
+use rand::prelude::*;
+use statrs;
+use std::env;
+use std::f64;
+use std::fs::File;
+use std::io::prelude::*;
+use std::io::BufWriter;
+use std::io::{Error, ErrorKind};
+use std::path;
+use std::process::Command;
+use std::sync::mpsc;
+use std::sync::Arc;
+
+/// This tool measures the global spatial autocorrelation of a numeric attribute field (`--field`) of a
+/// vector point or polygon layer (`--input`), i.e. the extent to which the values of neighbouring features
+/// are similar (clustered) or dissimilar (dispersed), using two statistics. Moran's *I* is near zero
+/// (its expected value is -1/(n-1)) for randomly arranged values, positive for clustered values, and negative
+/// for dispersed values. Geary's *C* is near one for randomly arranged values, less than one for clustered
+/// values, and greater than one for dispersed values; it is more sensitive than Moran's *I* to differences
+/// between the values of neighbouring features, rather than to their covariance.
+///
+/// The neighbours of each feature are defined by spatial weights (`--weights`). With 'distance_band' weights,
+/// the neighbours of a feature are the features whose centroids are within a distance (`--distance`) of its
+/// centroid. By default, the distance is the smallest distance within which every feature has at least one
+/// neighbour. With 'k_nearest' weights, the neighbours are the features (`--num_neighbours`) with the nearest
+/// centroids. For polygon layers, 'queen' contiguity weights define the neighbours of a polygon as the polygons
+/// that share at least one vertex with it, and 'rook' contiguity weights as the polygons that share at least
+/// one edge with it. Polygons are contiguous only if their shared boundaries have the same vertices, as in a
+/// topologically clean polygon layer. The weights of the neighbours of each feature are optionally row
+/// standardized (`--row_standardize`), i.e. divided by the feature's number of neighbours, so that features
+/// with many neighbours do not dominate the statistics. Features with null values are excluded, and features
+/// without neighbours (islands) do not contribute to the statistics.
+///
+/// The significance of each statistic is tested against the null hypothesis of no spatial autocorrelation,
+/// both analytically, with z-scores and two-tailed p-values under the normality and randomization
+/// assumptions, and by a permutation test (`--permutations`), which compares the statistic with its values
+/// for random rearrangements of the values among the features. The pseudo p-value of the permutation test is
+/// (*m* + 1) / (*p* + 1), where *p* is the number of permutations and *m* is the number of them giving a
+/// statistic at least as extreme as the observed statistic, in the same direction, and can be reproduced by
+/// giving a random seed (`--seed`). The tool outputs an HTML report (`--output`).
+///
+/// Distances are measured in map units, so point layers in geographic coordinates should be projected before
+/// using distance-band or k-nearest weights.
+///
+/// # Reference
+/// Cliff, A. D., and Ord, J. K. (1981). *Spatial Processes: Models and Applications*. Pion, London.
+///
+/// # See Also
+/// `ImageAutocorrelation`, `ClusterAndOutlierAnalysis`, `HotSpotAnalysis`
+pub struct VectorAutocorrelation {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl VectorAutocorrelation {
+    pub fn new() -> VectorAutocorrelation {
+        // public constructor
+        let name = "VectorAutocorrelation".to_string();
+        let toolbox = "Math and Stats Tools".to_string();
+        let description =
+            "Measures the global spatial autocorrelation of a vector attribute using Moran's I and Geary's C."
+                .to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input Vector File".to_owned(),
+            flags: vec!["-i".to_owned(), "--input".to_owned()],
+            description: "Input vector points or polygons file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Vector(
+                VectorGeometryType::Any,
+            )),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Field Name".to_owned(),
+            flags: vec!["--field".to_owned()],
+            description: "Input numeric field to analyze.".to_owned(),
+            parameter_type: ParameterType::VectorAttributeField(
+                AttributeType::Number,
+                "--input".to_string(),
+            ),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output HTML File".to_owned(),
+            flags: vec!["-o".to_owned(), "--output".to_owned()],
+            description: "Output HTML report file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Html),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Spatial Weights".to_owned(),
+            flags: vec!["--weights".to_owned()],
+            description: "Method of defining the neighbours of each feature; options are 'distance_band', 'k_nearest', 'queen', and 'rook'."
+                .to_owned(),
+            parameter_type: ParameterType::OptionList(vec![
+                "distance_band".to_owned(),
+                "k_nearest".to_owned(),
+                "queen".to_owned(),
+                "rook".to_owned(),
+            ]),
+            default_value: Some("distance_band".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Distance Band (optional)".to_owned(),
+            flags: vec!["--distance".to_owned()],
+            description: "Optional neighbourhood distance for distance-band weights; by default, the smallest distance giving every feature a neighbour."
+                .to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Number of Neighbours".to_owned(),
+            flags: vec!["--num_neighbours".to_owned()],
+            description: "Number of neighbours for k-nearest weights.".to_owned(),
+            parameter_type: ParameterType::Integer,
+            default_value: Some("8".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Row Standardize Weights?".to_owned(),
+            flags: vec!["--row_standardize".to_owned()],
+            description: "Divide the weights of each feature's neighbours by its number of neighbours?"
+                .to_owned(),
+            parameter_type: ParameterType::Boolean,
+            default_value: Some("true".to_string()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Number of Permutations".to_owned(),
+            flags: vec!["--permutations".to_owned()],
+            description: "Number of random permutations used to calculate the pseudo p-values."
+                .to_owned(),
+            parameter_type: ParameterType::Integer,
+            default_value: Some("999".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(seed_parameter());
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut parent = env::current_exe().unwrap();
+        parent.pop();
+        let p = format!("{}", parent.display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(
+            ">>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=counties.shp --field=INCOME -o=report.html --weights=queen
+>>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=wells.shp --field=NITRATE -o=report.html --weights=k_nearest --num_neighbours=6 --permutations=9999",
+            short_exe, name
+        ).replace("*", &sep);
+
+        VectorAutocorrelation {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for VectorAutocorrelation {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        match serde_json::to_string(&self.parameters) {
+            Ok(json_str) => return format!("{{\"parameters\":{}}}", json_str),
+            Err(err) => return format!("{:?}", err),
+        }
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+        cancel: &CancellationToken,
+    ) -> Result<(), Error> {
+        let args = ParsedArgs::parse(&self.get_tool_parameters(), &args, working_directory)?;
+        self.run_with_parsed_args(args, working_directory, verbose, cancel)
+    }
+
+    fn run_with_parsed_args<'a>(
+        &self,
+        args: ParsedArgs,
+        _working_directory: &'a str,
+        verbose: bool,
+        cancel: &CancellationToken,
+    ) -> Result<(), Error> {
+        let input_file = args.get_file("input").unwrap();
+        let field_name = args.get_string("field").unwrap();
+        let output_file = args.get_file("output").unwrap();
+        let weights_method = args.get_string("weights").unwrap_or_default();
+        let distance = args.get_float("distance");
+        let num_neighbours = args.get_integer("num_neighbours").unwrap_or(8);
+        let row_standardize = args.get_bool("row_standardize");
+        let num_permutations = args.get_integer("permutations").unwrap_or(999);
+        let seed = args.get_seed()?;
+
+        if num_neighbours < 1 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The number of neighbours (--num_neighbours) must be at least one.",
+            ));
+        }
+        if num_permutations < 1 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The number of permutations (--permutations) must be at least one.",
+            ));
+        }
+        let num_permutations = num_permutations as usize;
+
+        let start = Instant::now();
+
+        if verbose {
+            let tool_name = self.get_tool_name();
+            progress::welcome(&tool_name);
+        }
+
+        if verbose {
+            progress::message("Reading data...")
+        };
+
+        let input = Shapefile::read(&input_file)?;
+        let base_shape_type = input.header.shape_type.base_shape_type();
+        if base_shape_type != ShapeType::Point
+            && base_shape_type != ShapeType::MultiPoint
+            && base_shape_type != ShapeType::Polygon
+        {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The input vector data must be of POINT, MULTIPOINT, or POLYGON base shape type.",
+            ));
+        }
+        let field_index = match input.attributes.get_field_num(&field_name) {
+            Some(i) if input.attributes.is_field_numeric(i) => i,
+            Some(_) => {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    format!("The field '{}' is not numeric.", field_name),
+                ));
+            }
+            None => {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    format!(
+                        "The field '{}' does not exist in the input attribute table.",
+                        field_name
+                    ),
+                ));
+            }
+        };
+
+        // Features with null values or without geometries are excluded.
+        let mut records = vec![];
+        let mut values = vec![];
+        for record_num in 0..input.num_records {
+            let value = match input.attributes.get_record(record_num)[field_index] {
+                FieldData::Int(v) => v as f64,
+                FieldData::Real(v) => v,
+                _ => continue,
+            };
+            if feature_centroid(&input, record_num).is_some() {
+                records.push(record_num);
+                values.push(value);
+            }
+        }
+        if verbose && records.len() < input.num_records {
+            progress::warning(format!(
+                "{} features with null values or without geometries were excluded.",
+                input.num_records - records.len()
+            ));
+        }
+        let n = records.len();
+        if n < 4 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "At least four features with values are required.",
+            ));
+        }
+
+        let weights_type = match weights_method.as_str() {
+            "k_nearest" => SpatialWeightsType::KNearest(num_neighbours as usize),
+            "queen" => SpatialWeightsType::Queen,
+            "rook" => SpatialWeightsType::Rook,
+            _ => {
+                let distance = match distance {
+                    Some(d) => d,
+                    None => {
+                        let d = SpatialWeights::threshold_distance(&input, &records)?;
+                        if verbose {
+                            progress::message(format!("Distance band: {}", d));
+                        }
+                        d
+                    }
+                };
+                SpatialWeightsType::DistanceBand(distance)
+            }
+        };
+        let weights = SpatialWeights::new(&input, &records, weights_type)?;
+        let num_islands = weights.num_islands();
+        if num_islands == n {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "None of the features have neighbours.",
+            ));
+        }
+        if verbose && num_islands > 0 {
+            progress::warning(format!(
+                "{} features have no neighbours.",
+                num_islands
+            ));
+        }
+
+        // The weight of each of the neighbours of each feature.
+        let row_weights: Vec<f64> = (0..n)
+            .map(|i| {
+                let k = weights.neighbours(i).len();
+                if row_standardize && k > 0 {
+                    1f64 / k as f64
+                } else {
+                    1f64
+                }
+            })
+            .collect();
+
+        let nf = n as f64;
+        let mean = values.iter().sum::<f64>() / nf;
+        let deviations: Vec<f64> = values.iter().map(|v| v - mean).collect();
+        let sum_squares: f64 = deviations.iter().map(|z| z * z).sum();
+        if sum_squares <= 0f64 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The field values do not vary.",
+            ));
+        }
+        let b2 = nf * deviations.iter().map(|z| z.powi(4)).sum::<f64>() / (sum_squares * sum_squares);
+
+        // The sums of the weights used in the moments of the statistics.
+        let mut s0 = 0f64;
+        let mut s1 = 0f64;
+        let mut column_sums = vec![0f64; n];
+        for i in 0..n {
+            for j in weights.neighbours(i) {
+                let w_ij = row_weights[i];
+                s0 += w_ij;
+                column_sums[*j] += w_ij;
+                // A pair of features that are not mutual neighbours is only visited once.
+                if weights.neighbours(*j).binary_search(&i).is_ok() {
+                    let w_ji = row_weights[*j];
+                    s1 += (w_ij + w_ji) * (w_ij + w_ji) / 2f64;
+                } else {
+                    s1 += w_ij * w_ij;
+                }
+            }
+        }
+        let s2: f64 = (0..n)
+            .map(|i| {
+                let row_sum = row_weights[i] * weights.neighbours(i).len() as f64;
+                (row_sum + column_sums[i]) * (row_sum + column_sums[i])
+            })
+            .sum();
+
+        let (morans_i, gearys_c) = statistics(&weights, &row_weights, &values, mean, s0);
+
+        // Moran's I
+        let expected_i = -1f64 / (nf - 1f64);
+        let var_i_normality = (nf * nf * s1 - nf * s2 + 3f64 * s0 * s0)
+            / ((nf * nf - 1f64) * s0 * s0)
+            - expected_i * expected_i;
+        let var_i_randomization = (nf * ((nf * nf - 3f64 * nf + 3f64) * s1 - nf * s2 + 3f64 * s0 * s0)
+            - b2 * ((nf * nf - nf) * s1 - 2f64 * nf * s2 + 6f64 * s0 * s0))
+            / ((nf - 1f64) * (nf - 2f64) * (nf - 3f64) * s0 * s0)
+            - expected_i * expected_i;
+
+        // Geary's C
+        let expected_c = 1f64;
+        let var_c_normality = ((2f64 * s1 + s2) * (nf - 1f64) - 4f64 * s0 * s0)
+            / (2f64 * (nf + 1f64) * s0 * s0);
+        let var_c_randomization = ((nf - 1f64) * s1 * (nf * nf - 3f64 * nf + 3f64 - (nf - 1f64) * b2)
+            - 0.25 * (nf - 1f64) * s2 * (nf * nf + 3f64 * nf - 6f64 - (nf * nf - nf + 2f64) * b2)
+            + s0 * s0 * (nf * nf - 3f64 - (nf - 1f64) * (nf - 1f64) * b2))
+            / (nf * (nf - 2f64) * (nf - 3f64) * s0 * s0);
+
+        // Permutation tests
+        let weights = Arc::new(weights);
+        let row_weights = Arc::new(row_weights);
+        let values = Arc::new(values);
+        let mut num_procs = num_cpus::get() as isize;
+        let configs = whitebox_common::configs::get_configs()?;
+        let max_procs = configs.max_procs;
+        if max_procs > 0 && max_procs < num_procs {
+            num_procs = max_procs;
+        }
+        let seed = random::tool_seed(seed);
+        let (tx, rx) = mpsc::channel();
+        for tid in 0..num_procs {
+            let weights = weights.clone();
+            let row_weights = row_weights.clone();
+            let values = values.clone();
+            let tx = tx.clone();
+            progress::spawn(move || {
+                let mut rng = random::new_rng(seed, tid as u64);
+                let mut permuted: Vec<f64> = values.to_vec();
+                for _ in (0..num_permutations).filter(|p| *p as isize % num_procs == tid) {
+                    permuted.shuffle(&mut rng);
+                    if tx.send(statistics(&weights, &row_weights, &permuted, mean, s0)).is_err() {
+                        return;
+                    }
+                }
+            });
+        }
+        drop(tx);
+        let mut num_larger_i = 0;
+        let mut num_larger_c = 0;
+        let mut old_progress: usize = 1;
+        for p in 0..num_permutations {
+            let (i, c) = cancel.recv(&rx)?;
+            if i >= morans_i {
+                num_larger_i += 1;
+            }
+            if c >= gearys_c {
+                num_larger_c += 1;
+            }
+            if verbose {
+                let progress = (100.0_f64 * (p + 1) as f64 / num_permutations as f64) as usize;
+                if progress != old_progress {
+                    progress::update_stage("Permutations", progress);
+                    old_progress = progress;
+                }
+            }
+        }
+        let pseudo_p_value = |num_larger: usize| -> f64 {
+            let num_extreme = num_larger.min(num_permutations - num_larger);
+            (num_extreme + 1) as f64 / (num_permutations + 1) as f64
+        };
+
+        let distribution = Normal::new(0.0, 1.0).unwrap();
+        let z_test = |statistic: f64, expected: f64, variance: f64| -> (f64, f64) {
+            let z = (statistic - expected) / variance.sqrt();
+            (z, 2f64 * (1f64 - distribution.cdf(z.abs())))
+        };
+        let rows: Vec<(&str, f64, f64, f64, f64, f64)> = vec![
+            (
+                "Moran's I",
+                morans_i,
+                expected_i,
+                var_i_normality,
+                var_i_randomization,
+                pseudo_p_value(num_larger_i),
+            ),
+            (
+                "Geary's C",
+                gearys_c,
+                expected_c,
+                var_c_normality,
+                var_c_randomization,
+                pseudo_p_value(num_larger_c),
+            ),
+        ];
+
+        if verbose {
+            progress::message(format!(
+                "Moran's I: {:.6}, Geary's C: {:.6}",
+                morans_i, gearys_c
+            ));
+        }
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+
+        let f = File::create(output_file.clone())?;
+        let mut writer = BufWriter::new(f);
+
+        writer.write_all(&r#"<!DOCTYPE html PUBLIC \"-//W3C//DTD XHTML 1.0 Transitional//EN\" \"http://www.w3.org/TR/xhtml1/DTD/xhtml1-transitional.dtd\">
+        <html>
+            <head>
+                <meta content=\"text/html; charset=UTF-8\" http-equiv=\"content-type\">
+                <title>Spatial Autocorrelation</title>"#.as_bytes())?;
+
+        // get the style sheet
+        writer.write_all(&get_css().as_bytes())?;
+
+        writer.write_all(
+            &r#"
+            </head>
+            <body>
+                <h1>Spatial Autocorrelation Report</h1>
+                <p>"#
+                .as_bytes(),
+        )?;
+        writer.write_all(&format!("<strong>Input</strong>: {}<br>", input_file).as_bytes())?;
+        writer.write_all(&format!("<strong>Field</strong>: {}<br>", field_name).as_bytes())?;
+        let weights_name = match weights_type {
+            SpatialWeightsType::DistanceBand(d) => format!("distance band ({})", d),
+            SpatialWeightsType::KNearest(k) => format!("{} nearest neighbours", k),
+            SpatialWeightsType::Queen => "queen contiguity".to_string(),
+            SpatialWeightsType::Rook => "rook contiguity".to_string(),
+        };
+        writer.write_all(
+            &format!(
+                "<strong>Spatial weights</strong>: {}{}<br>",
+                weights_name,
+                if row_standardize {
+                    ", row standardized"
+                } else {
+                    ""
+                }
+            )
+            .as_bytes(),
+        )?;
+        writer.write_all(&format!("<strong>Number of features</strong>: {}<br>", n).as_bytes())?;
+        writer.write_all(
+            &format!("<strong>Features without neighbours</strong>: {}<br>", num_islands)
+                .as_bytes(),
+        )?;
+        writer.write_all(&format!("<strong>Mean</strong>: {:.4}<br>", mean).as_bytes())?;
+        writer.write_all(
+            &format!("<strong>Permutations</strong>: {}<br>", num_permutations).as_bytes(),
+        )?;
+        writer.write_all("</p>".as_bytes())?;
+
+        for (name, statistic, expected, var_normality, var_randomization, p_value) in rows {
+            let (z_n, p_n) = z_test(statistic, expected, var_normality);
+            let (z_r, p_r) = z_test(statistic, expected, var_randomization);
+            writer.write_all("<div><table align=\"center\">".as_bytes())?;
+            writer.write_all(&format!("<caption>{} Results</caption>", name).as_bytes())?;
+            let table_rows = vec![
+                (name.to_string(), statistic),
+                ("Expected value".to_string(), expected),
+                ("Variance (normality assumption)".to_string(), var_normality),
+                ("z test stat (normality assumption)".to_string(), z_n),
+                ("p-value (normality assumption)".to_string(), p_n),
+                ("Variance (randomization assumption)".to_string(), var_randomization),
+                ("z test stat (randomization assumption)".to_string(), z_r),
+                ("p-value (randomization assumption)".to_string(), p_r),
+                ("Pseudo p-value (permutations)".to_string(), p_value),
+            ];
+            for (label, value) in table_rows {
+                writer.write_all(
+                    &format!(
+                        "<tr><td>{}</td> <td class=\"numberCell\">{:.*}</td></tr>",
+                        label, 4, value
+                    )
+                    .as_bytes(),
+                )?;
+            }
+            writer.write_all("</table></div>".as_bytes())?;
+        }
+
+        writer.write_all("</body>".as_bytes())?;
+        writer.write_all("</html>".as_bytes())?;
+
+        let _ = writer.flush();
+
+        if verbose {
+            if cfg!(target_os = "macos") || cfg!(target_os = "ios") {
+                let output = Command::new("open")
+                    .arg(output_file.clone())
+                    .output()
+                    .expect("failed to execute process");
+
+                let _ = output.stdout;
+            } else if cfg!(target_os = "windows") {
+                // let output = Command::new("cmd /c start")
+                let output = Command::new("explorer.exe")
+                    .arg(output_file.clone())
+                    .output()
+                    .expect("failed to execute process");
+
+                let _ = output.stdout;
+            } else if cfg!(target_os = "linux") {
+                let output = Command::new("xdg-open")
+                    .arg(output_file.clone())
+                    .output()
+                    .expect("failed to execute process");
+
+                let _ = output.stdout;
+            }
+
+            progress::message(format!("Complete! Please see {} for output.", output_file));
+        }
+
+        if verbose {
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
+        }
+
+        Ok(())
+    }
+}
+
+/// Calculates Moran's I and Geary's C for a set of values.
+fn statistics(
+    weights: &SpatialWeights,
+    row_weights: &[f64],
+    values: &[f64],
+    mean: f64,
+    s0: f64,
+) -> (f64, f64) {
+    let n = values.len() as f64;
+    let mut cross_products = 0f64;
+    let mut squared_differences = 0f64;
+    let mut sum_squares = 0f64;
+    for i in 0..values.len() {
+        let z_i = values[i] - mean;
+        sum_squares += z_i * z_i;
+        for j in weights.neighbours(i) {
+            cross_products += row_weights[i] * z_i * (values[*j] - mean);
+            squared_differences += row_weights[i] * (values[i] - values[*j]) * (values[i] - values[*j]);
+        }
+    }
+    (
+        n * cross_products / (s0 * sum_squares),
+        (n - 1f64) * squared_differences / (2f64 * s0 * sum_squares),
+    )
+}
@@ -10,6 +10,7 @@ use self::statrs::distribution::{ContinuousCDF, Normal};
 use whitebox_raster::*;
 use whitebox_common::rendering::html::*;
 use whitebox_common::rendering::LineGraph;
+use whitebox_common::progress;
 use crate::tools::*;
 use rand::prelude::*;
 use statrs;
@@ -24,7 +25,6 @@ use std::path;
 use std::process::Command;
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::thread;
 
 /// This tool will perform a Wilcoxon signed-rank test to evaluate whether a significant
 /// statistical difference exists between the two rasters. The Wilcoxon signed-rank test is often used as a
@@ -163,6 +163,7 @@ impl WhiteboxTool for WilcoxonSignedRankTest {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file1 = String::new();
         let mut input_file2 = String::new();
@@ -220,13 +221,7 @@ impl WhiteboxTool for WilcoxonSignedRankTest {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -291,7 +286,7 @@ impl WhiteboxTool for WilcoxonSignedRankTest {
                 let input1 = input1.clone();
                 let input2 = input2.clone();
                 let tx = tx.clone();
-                thread::spawn(move || {
+                progress::spawn(move || {
                     let mut z1: f64;
                     let mut z2: f64;
                     let mut diff: f64;
@@ -311,7 +306,9 @@ impl WhiteboxTool for WilcoxonSignedRankTest {
                                 diffs.push(diff);
                             }
                         }
-                        tx.send((n, s, sq, diffs)).unwrap();
+                        if tx.send((n, s, sq, diffs)).is_err() {
+                            return;
+                        }
                     }
                 });
             }
@@ -319,7 +316,7 @@ impl WhiteboxTool for WilcoxonSignedRankTest {
             let mut sum = 0.0;
             let mut sq_sum = 0.0;
             for row in 0..rows {
-                let (a, b, c, d) = rx.recv().expect("Error receiving data from thread.");
+                let (a, b, c, d) = cancel.recv(&rx)?;
                 n += a;
                 sum += b;
                 sq_sum += c;
@@ -329,7 +326,7 @@ impl WhiteboxTool for WilcoxonSignedRankTest {
                 if verbose {
                     progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as i32;
                     if progress != old_progress {
-                        println!("Progress: {}%", progress);
+                        progress::update(progress);
                         old_progress = progress;
                     }
                 }
@@ -365,7 +362,7 @@ impl WhiteboxTool for WilcoxonSignedRankTest {
                     if verbose {
                         progress = (100.0_f64 * sample_num as f64 / num_samples as f64) as i32;
                         if progress != old_progress {
-                            println!("Progress: {}%", progress);
+                            progress::update(progress);
                             old_progress = progress;
                         }
                     }
@@ -598,15 +595,12 @@ impl WhiteboxTool for WilcoxonSignedRankTest {
                 let _ = output.stdout;
             }
 
-            println!("Complete! Please see {} for output.", output_file);
+            progress::message(format!("Complete! Please see {} for output.", output_file));
         }
 
         let elapsed_time = get_formatted_elapsed_time(start);
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
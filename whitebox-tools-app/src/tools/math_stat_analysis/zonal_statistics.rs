@@ -11,6 +11,7 @@ only represent one statistic, given by the --stat flag.
 */
 
 use whitebox_raster::*;
+use whitebox_common::progress;
 use crate::tools::*;
 use num_cpus;
 use std::cmp::Ordering::Equal;
@@ -25,7 +26,6 @@ use std::path;
 use std::process::Command;
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::thread;
 
 /// This tool can be used to extract common descriptive statistics associated with the distribution
 /// of some underlying data raster based on feature units defined by a feature definition raster.
@@ -194,6 +194,7 @@ impl WhiteboxTool for ZonalStatistics {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut features_file = String::new();
@@ -259,13 +260,7 @@ impl WhiteboxTool for ZonalStatistics {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -297,7 +292,7 @@ impl WhiteboxTool for ZonalStatistics {
         }
 
         if verbose {
-            println!("Reading data...")
+            progress::message("Reading data...")
         };
         let input = Arc::new(Raster::new(&input_file, "r")?);
         let features = Arc::new(Raster::new(&features_file, "r")?);
@@ -326,7 +321,7 @@ impl WhiteboxTool for ZonalStatistics {
         for tid in 0..num_procs {
             let features = features.clone();
             let tx = tx.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let mut features_val: f64;
                 for row in (0..rows).filter(|r| r % num_procs == tid) {
                     let mut min_id = isize::max_value();
@@ -344,7 +339,9 @@ impl WhiteboxTool for ZonalStatistics {
                             }
                         }
                     }
-                    tx.send((min_id, max_id)).unwrap();
+                    if tx.send((min_id, max_id)).is_err() {
+                        return;
+                    }
                 }
             });
         }
@@ -352,7 +349,7 @@ impl WhiteboxTool for ZonalStatistics {
         let mut min_id = isize::max_value();
         let mut max_id = isize::min_value();
         for row in 0..rows {
-            let (min, max) = rx.recv().expect("Error receiving data from thread.");
+            let (min, max) = cancel.recv(&rx)?;
             if min < min_id {
                 min_id = min;
             }
@@ -363,7 +360,7 @@ impl WhiteboxTool for ZonalStatistics {
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress (Loop 1 of 3): {}%", progress);
+                    progress::update_stage("Progress (Loop 1 of 3)", progress);
                     old_progress = progress;
                 }
             }
@@ -406,10 +403,11 @@ impl WhiteboxTool for ZonalStatistics {
                     }
                 }
             }
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress (Loop 2 of 3): {}%", progress);
+                    progress::update_stage("Progress (Loop 2 of 3)", progress);
                     old_progress = progress;
                 }
             }
@@ -432,17 +430,18 @@ impl WhiteboxTool for ZonalStatistics {
                         (val - features_average[id]) * (val - features_average[id]);
                 }
             }
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress (Loop 3 of 3): {}%", progress);
+                    progress::update_stage("Progress (Loop 3 of 3)", progress);
                     old_progress = progress;
                 }
             }
         }
 
         if verbose {
-            println!("Calculating medians...");
+            progress::message("Calculating medians...");
         }
         for id in 0..num_features {
             if features_n[id] > 1f64 {
@@ -493,10 +492,11 @@ impl WhiteboxTool for ZonalStatistics {
                         output.set_value(row, col, out_stat[id]);
                     }
                 }
+                cancel.check()?;
                 if verbose {
                     progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Output: {}%", progress);
+                        progress::update_stage("Output", progress);
                         old_progress = progress;
                     }
                 }
@@ -513,12 +513,12 @@ impl WhiteboxTool for ZonalStatistics {
             output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
             if verbose {
-                println!("Saving data...")
+                progress::message("Saving data...")
             };
             let _ = match output.write() {
                 Ok(_) => {
                     if verbose {
-                        println!("Output file written")
+                        progress::output_written(&output.file_name)
                     }
                 }
                 Err(e) => return Err(e),
@@ -679,15 +679,12 @@ impl WhiteboxTool for ZonalStatistics {
                     let _ = output.stdout;
                 }
 
-                println!("Complete! Please see {} for output.", output_html_file);
+                progress::message(format!("Complete! Please see {} for output.", output_html_file));
             }
         }
         if verbose {
             let elapsed_time = get_formatted_elapsed_time(start);
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
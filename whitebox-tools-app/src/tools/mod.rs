@@ -6,23 +6,43 @@ pub mod lidar_analysis;
 pub mod math_stat_analysis;
 pub mod stream_network_analysis;
 pub mod terrain_analysis;
+mod batch;
+mod error;
+mod parsed_args;
+mod tool_args;
 
+pub use self::error::ToolError;
+pub use self::parsed_args::{ArgValue, ParsedArgs};
+pub use self::tool_args::ToolArgs;
+pub use whitebox_common::cancellation::CancellationToken;
 use whitebox_common::utils::get_formatted_elapsed_time;
+use crate::lineage;
+use whitebox_common::progress;
+use whitebox_common::provenance::{self, InputFile, Provenance};
+use whitebox_common::random;
+use whitebox_common::utils::hash_file_set;
+use whitebox_common::utils::is_memory_path;
+use chrono::prelude::*;
 use serde_json;
 use std::io::{Error, ErrorKind};
-use std::time::Instant;
+use std::time::{Instant, SystemTime};
 use std::path;
 use std::fs;
 use std::collections::HashMap;
 use std::process::Command;
 use std::env;
+use std::panic;
+use std::any::Any;
 // use std::io;
 // use std::path::PathBuf;
 
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub struct ToolManager {
     pub working_dir: String,
     pub verbose: bool,
+    /// The token passed to each tool that this manager runs. Cancelling it, or a clone of it,
+    /// stops the running tool; a cancelled token stays cancelled for later runs.
+    pub cancellation_token: CancellationToken,
     tool_names: Vec<String>,
 }
 
@@ -55,6 +75,7 @@ impl ToolManager {
         tool_names.push("RemovePolygonHoles".to_string());
         tool_names.push("SetNodataValue".to_string());
         tool_names.push("SinglePartToMultiPart".to_string());
+        tool_names.push("ValidatePolygons".to_string());
         tool_names.push("VectorLinesToRaster".to_string());
         tool_names.push("VectorPointsToRaster".to_string());
         tool_names.push("VectorPolygonsToRaster".to_string());
@@ -66,11 +87,13 @@ impl ToolManager {
         tool_names.push("BlockMinimumGridding".to_string());
         tool_names.push("BoundaryShapeComplexity".to_string());
         tool_names.push("BufferRaster".to_string());
-        // tool_names.push("BufferVector".to_string());
+        tool_names.push("BufferVector".to_string());
+        tool_names.push("CalibrateRoutes".to_string());
         tool_names.push("Centroid".to_string());
         tool_names.push("CentroidVector".to_string());
         tool_names.push("Clip".to_string());
         tool_names.push("ClipRasterToPolygon".to_string());
+        tool_names.push("ClosestFacility".to_string());
         tool_names.push("Clump".to_string());
         tool_names.push("CompactnessRatio".to_string());
         tool_names.push("ConstructVectorTIN".to_string());
@@ -81,6 +104,7 @@ impl ToolManager {
         tool_names.push("CreateHexagonalVectorGrid".to_string());
         tool_names.push("CreatePlane".to_string());
         tool_names.push("CreateRectangularVectorGrid".to_string());
+        tool_names.push("CreateRouteEvents".to_string());
         tool_names.push("DeviationFromRegionalDirection".to_string());
         tool_names.push("Difference".to_string());
         tool_names.push("Dissolve".to_string());
@@ -101,9 +125,12 @@ impl ToolManager {
         tool_names.push("HoleProportion".to_string());
         tool_names.push("IdwInterpolation".to_string());
         tool_names.push("Intersect".to_string());
+        tool_names.push("KernelDensity".to_string());
+        tool_names.push("KrigingInterpolation".to_string());
         tool_names.push("LayerFootprint".to_string());
         tool_names.push("LinearityIndex".to_string());
         tool_names.push("LineIntersections".to_string());
+        tool_names.push("LocatePointsAlongRoutes".to_string());
         tool_names.push("LowestPosition".to_string());
         tool_names.push("MaxAbsoluteOverlay".to_string());
         tool_names.push("MaxOverlay".to_string());
@@ -119,6 +146,7 @@ impl ToolManager {
         tool_names.push("NaturalNeighbourInterpolation".to_string());
         tool_names.push("NearestNeighbourGridding".to_string());
         tool_names.push("MinOverlay".to_string());
+        tool_names.push("OdCostMatrix".to_string());
         tool_names.push("PatchOrientation".to_string());
         tool_names.push("PercentEqualTo".to_string());
         tool_names.push("PercentGreaterThan".to_string());
@@ -139,9 +167,13 @@ impl ToolManager {
         tool_names.push("ReclassEqualInterval".to_string());
         tool_names.push("ReclassFromFile".to_string());
         tool_names.push("RelatedCircumscribingCircle".to_string());
+        tool_names.push("ServiceArea".to_string());
         tool_names.push("ShapeComplexityIndex".to_string());
         tool_names.push("ShapeComplexityIndexRaster".to_string());
+        tool_names.push("ShortestPath".to_string());
+        tool_names.push("SimplifyVector".to_string());
         tool_names.push("SmoothVectors".to_string());
+        tool_names.push("SpatialJoin".to_string());
         tool_names.push("SplitWithLines".to_string());
         tool_names.push("SumOverlay".to_string());
         tool_names.push("SymmetricalDifference".to_string());
@@ -185,6 +217,7 @@ impl ToolManager {
         tool_names.push("FloodOrder".to_string());
         tool_names.push("FlowAccumulationFullWorkflow".to_string());
         tool_names.push("FlowLengthDiff".to_string());
+        tool_names.push("HandFloodInundation".to_string());
         tool_names.push("Hillslopes".to_string());
         tool_names.push("ImpoundmentSizeIndex".to_string());
         tool_names.push("InsertDams".to_string());
@@ -344,6 +377,7 @@ impl ToolManager {
         tool_names.push("AttributeHistogram".to_string());
         tool_names.push("AttributeScattergram".to_string());
         tool_names.push("Ceil".to_string());
+        tool_names.push("ClusterAndOutlierAnalysis".to_string());
         tool_names.push("Cos".to_string());
         tool_names.push("Cosh".to_string());
         tool_names.push("CrispnessIndex".to_string());
@@ -357,6 +391,7 @@ impl ToolManager {
         tool_names.push("ZonalStatistics".to_string());
         tool_names.push("Floor".to_string());
         tool_names.push("GreaterThan".to_string());
+        tool_names.push("HotSpotAnalysis".to_string());
         tool_names.push("ImageAutocorrelation".to_string());
         tool_names.push("ImageCorrelation".to_string());
         tool_names.push("ImageCorrelationNeighbourhoodAnalysis".to_string());
@@ -410,6 +445,7 @@ impl ToolManager {
         tool_names.push("Truncate".to_string());
         tool_names.push("TurningBandsSimulation".to_string());
         tool_names.push("TwoSampleKsTest".to_string());
+        tool_names.push("VectorAutocorrelation".to_string());
         tool_names.push("WilcoxonSignedRankTest".to_string());
         tool_names.push("Xor".to_string());
         tool_names.push("ZScores".to_string());
@@ -444,6 +480,7 @@ impl ToolManager {
         tool_names.push("CircularVarianceOfAspect".to_string());
         tool_names.push("ContoursFromPoints".to_string());
         tool_names.push("ContoursFromRaster".to_string());
+        tool_names.push("DemOfDifference".to_string());
         tool_names.push("DevFromMeanElev".to_string());
         tool_names.push("DiffFromMeanElev".to_string());
         tool_names.push("DirectionalRelief".to_string());
@@ -463,6 +500,7 @@ impl ToolManager {
         tool_names.push("Hillshade".to_string());
         tool_names.push("HorizonAngle".to_string());
         tool_names.push("HypsometricAnalysis".to_string());
+        tool_names.push("IwahashiPikeClass".to_string());
         tool_names.push("HypsometricallyTintedHillshade".to_string());
         tool_names.push("MapOffTerrainObjects".to_string());
         tool_names.push("MaxAnisotropyDev".to_string());
@@ -486,6 +524,7 @@ impl ToolManager {
         tool_names.push("MultiscaleTopographicPositionImage".to_string());
         tool_names.push("NumDownslopeNeighbours".to_string());
         tool_names.push("NumUpslopeNeighbours".to_string());
+        tool_names.push("Openness".to_string());
         tool_names.push("PennockLandformClass".to_string());
         tool_names.push("PercentElevRange".to_string());
         tool_names.push("PlanCurvature".to_string());
@@ -499,13 +538,16 @@ impl ToolManager {
         tool_names.push("TimeInDaylight".to_string());
         tool_names.push("TopographicHachures".to_string());
         tool_names.push("SedimentTransportIndex".to_string());
+        tool_names.push("SkyViewFactor".to_string());
         tool_names.push("Slope".to_string());
         tool_names.push("SlopeVsElevationPlot".to_string());
+        tool_names.push("SolarRadiation".to_string());
         tool_names.push("SphericalStdDevOfNormals".to_string());
         tool_names.push("StandardDeviationOfSlope".to_string());
         tool_names.push("SurfaceAreaRatio".to_string());
         tool_names.push("TangentialCurvature".to_string());
         tool_names.push("TotalCurvature".to_string());
+        tool_names.push("TpiLandformClass".to_string());
         tool_names.push("Viewshed".to_string());
         tool_names.push("VisibilityIndex".to_string());
         tool_names.push("WetnessIndex".to_string());
@@ -515,12 +557,19 @@ impl ToolManager {
         let tm = ToolManager {
             working_dir: working_directory.to_string(),
             verbose: *verbose_mode,
+            cancellation_token: CancellationToken::new(),
             tool_names: tool_names,
         };
         Ok(tm)
     }
 
-    fn get_tool(&self, tool_name: &str) -> Option<Box<dyn WhiteboxTool + 'static>> {
+    /// Returns the names of the built-in tools.
+    pub fn get_tool_names(&self) -> &[String] {
+        &self.tool_names
+    }
+
+    /// Returns the built-in tool with the given name, ignoring case and underscores.
+    pub fn get_tool(&self, tool_name: &str) -> Option<Box<dyn WhiteboxTool + 'static>> {
         match tool_name.to_lowercase().replace("_", "").as_ref() {
             // data_tools
             "addpointcoordinatestotable" => {
@@ -549,6 +598,7 @@ impl ToolManager {
             "removepolygonholes" => Some(Box::new(data_tools::RemovePolygonHoles::new())),
             "setnodatavalue" => Some(Box::new(data_tools::SetNodataValue::new())),
             "singleparttomultipart" => Some(Box::new(data_tools::SinglePartToMultiPart::new())),
+            "validatepolygons" => Some(Box::new(data_tools::ValidatePolygons::new())),
             "vectorlinestoraster" => Some(Box::new(data_tools::VectorLinesToRaster::new())),
             "vectorpointstoraster" => Some(Box::new(data_tools::VectorPointsToRaster::new())),
             "vectorpolygonstoraster" => Some(Box::new(data_tools::VectorPolygonsToRaster::new())),
@@ -562,11 +612,13 @@ impl ToolManager {
                 Some(Box::new(gis_analysis::BoundaryShapeComplexity::new()))
             }
             "bufferraster" => Some(Box::new(gis_analysis::BufferRaster::new())),
-            // "buffervector" => Some(Box::new(gis_analysis::BufferVector::new())),
+            "buffervector" => Some(Box::new(gis_analysis::BufferVector::new())),
+            "calibrateroutes" => Some(Box::new(gis_analysis::CalibrateRoutes::new())),
             "centroid" => Some(Box::new(gis_analysis::Centroid::new())),
             "centroidvector" => Some(Box::new(gis_analysis::CentroidVector::new())),
             "clip" => Some(Box::new(gis_analysis::Clip::new())),
             "cliprastertopolygon" => Some(Box::new(gis_analysis::ClipRasterToPolygon::new())),
+            "closestfacility" => Some(Box::new(gis_analysis::ClosestFacility::new())),
             "clump" => Some(Box::new(gis_analysis::Clump::new())),
             "compactnessratio" => Some(Box::new(gis_analysis::CompactnessRatio::new())),
             "constructvectortin" => Some(Box::new(gis_analysis::ConstructVectorTIN::new())),
@@ -581,6 +633,7 @@ impl ToolManager {
             "createrectangularvectorgrid" => {
                 Some(Box::new(gis_analysis::CreateRectangularVectorGrid::new()))
             }
+            "createrouteevents" => Some(Box::new(gis_analysis::CreateRouteEvents::new())),
             "deviationfromregionaldirection" => Some(Box::new(gis_analysis::DeviationFromRegionalDirection::new())), 
             "difference" => Some(Box::new(gis_analysis::Difference::new())),
             "dissolve" => Some(Box::new(gis_analysis::Dissolve::new())),
@@ -611,9 +664,14 @@ impl ToolManager {
             "holeproportion" => Some(Box::new(gis_analysis::HoleProportion::new())),
             "idwinterpolation" => Some(Box::new(gis_analysis::IdwInterpolation::new())),
             "intersect" => Some(Box::new(gis_analysis::Intersect::new())),
+            "kerneldensity" => Some(Box::new(gis_analysis::KernelDensity::new())),
+            "kriginginterpolation" => Some(Box::new(gis_analysis::KrigingInterpolation::new())),
             "layerfootprint" => Some(Box::new(gis_analysis::LayerFootprint::new())),
             "lineintersections" => Some(Box::new(gis_analysis::LineIntersections::new())),
             "linearityindex" => Some(Box::new(gis_analysis::LinearityIndex::new())),
+            "locatepointsalongroutes" => {
+                Some(Box::new(gis_analysis::LocatePointsAlongRoutes::new()))
+            }
             "lowestposition" => Some(Box::new(gis_analysis::LowestPosition::new())),
             "maxabsoluteoverlay" => Some(Box::new(gis_analysis::MaxAbsoluteOverlay::new())),
             "maxoverlay" => Some(Box::new(gis_analysis::MaxOverlay::new())),
@@ -635,6 +693,7 @@ impl ToolManager {
                 Some(Box::new(gis_analysis::NearestNeighbourGridding::new()))
             }
             "narrownessindex" => Some(Box::new(gis_analysis::NarrownessIndex::new())),
+            "odcostmatrix" => Some(Box::new(gis_analysis::OdCostMatrix::new())),
             "patchorientation" => Some(Box::new(gis_analysis::PatchOrientation::new())),
             "percentequalto" => Some(Box::new(gis_analysis::PercentEqualTo::new())),
             "percentgreaterthan" => Some(Box::new(gis_analysis::PercentGreaterThan::new())),
@@ -659,11 +718,15 @@ impl ToolManager {
             "relatedcircumscribingcircle" => {
                 Some(Box::new(gis_analysis::RelatedCircumscribingCircle::new()))
             }
+            "servicearea" => Some(Box::new(gis_analysis::ServiceArea::new())),
             "shapecomplexityindex" => Some(Box::new(gis_analysis::ShapeComplexityIndex::new())),
             "shapecomplexityindexraster" => {
                 Some(Box::new(gis_analysis::ShapeComplexityIndexRaster::new()))
             }
+            "shortestpath" => Some(Box::new(gis_analysis::ShortestPath::new())),
+            "simplifyvector" => Some(Box::new(gis_analysis::SimplifyVector::new())),
             "smoothvectors" => Some(Box::new(gis_analysis::SmoothVectors::new())),
+            "spatialjoin" => Some(Box::new(gis_analysis::SpatialJoin::new())),
             "splitwithlines" => Some(Box::new(gis_analysis::SplitWithLines::new())),
             "sumoverlay" => Some(Box::new(gis_analysis::SumOverlay::new())),
             "symmetricaldifference" => Some(Box::new(gis_analysis::SymmetricalDifference::new())),
@@ -723,6 +786,9 @@ impl ToolManager {
                 Some(Box::new(hydro_analysis::FlowAccumulationFullWorkflow::new()))
             }
             "flowlengthdiff" => Some(Box::new(hydro_analysis::FlowLengthDiff::new())),
+            "handfloodinundation" => {
+                Some(Box::new(hydro_analysis::HandFloodInundation::new()))
+            }
             "hillslopes" => Some(Box::new(hydro_analysis::Hillslopes::new())),
             "impoundmentsizeindex" => Some(Box::new(hydro_analysis::ImpoundmentSizeIndex::new())),
             "insertdams" => Some(Box::new(hydro_analysis::InsertDams::new())),
@@ -948,6 +1014,9 @@ impl ToolManager {
                 Some(Box::new(math_stat_analysis::AttributeScattergram::new()))
             }
             "ceil" => Some(Box::new(math_stat_analysis::Ceil::new())),
+            "clusterandoutlieranalysis" => {
+                Some(Box::new(math_stat_analysis::ClusterAndOutlierAnalysis::new()))
+            }
             "cos" => Some(Box::new(math_stat_analysis::Cos::new())),
             "cosh" => Some(Box::new(math_stat_analysis::Cosh::new())),
             "crispnessindex" => Some(Box::new(math_stat_analysis::CrispnessIndex::new())),
@@ -963,6 +1032,7 @@ impl ToolManager {
             "zonalstatistics" => Some(Box::new(math_stat_analysis::ZonalStatistics::new())),
             "floor" => Some(Box::new(math_stat_analysis::Floor::new())),
             "greaterthan" => Some(Box::new(math_stat_analysis::GreaterThan::new())),
+            "hotspotanalysis" => Some(Box::new(math_stat_analysis::HotSpotAnalysis::new())),
             "imageautocorrelation" => {
                 Some(Box::new(math_stat_analysis::ImageAutocorrelation::new()))
             }
@@ -1026,6 +1096,9 @@ impl ToolManager {
                 Some(Box::new(math_stat_analysis::TurningBandsSimulation::new()))
             }
             "twosamplekstest" => Some(Box::new(math_stat_analysis::TwoSampleKsTest::new())),
+            "vectorautocorrelation" => {
+                Some(Box::new(math_stat_analysis::VectorAutocorrelation::new()))
+            }
             "wilcoxonsignedranktest" => {
                 Some(Box::new(math_stat_analysis::WilcoxonSignedRankTest::new()))
             }
@@ -1090,6 +1163,7 @@ impl ToolManager {
             }
             "contoursfrompoints" => Some(Box::new(terrain_analysis::ContoursFromPoints::new())),
             "contoursfromraster" => Some(Box::new(terrain_analysis::ContoursFromRaster::new())),
+            "demofdifference" => Some(Box::new(terrain_analysis::DemOfDifference::new())),
             "devfrommeanelev" => Some(Box::new(terrain_analysis::DevFromMeanElev::new())),
             "difffrommeanelev" => Some(Box::new(terrain_analysis::DiffFromMeanElev::new())),
             "directionalrelief" => Some(Box::new(terrain_analysis::DirectionalRelief::new())),
@@ -1113,6 +1187,7 @@ impl ToolManager {
             "hillshade" => Some(Box::new(terrain_analysis::Hillshade::new())),
             "horizonangle" => Some(Box::new(terrain_analysis::HorizonAngle::new())),
             "hypsometricanalysis" => Some(Box::new(terrain_analysis::HypsometricAnalysis::new())),
+            "iwahashipikeclass" => Some(Box::new(terrain_analysis::IwahashiPikeClass::new())),
             "hypsometricallytintedhillshade" => Some(Box::new(
                 terrain_analysis::HypsometricallyTintedHillshade::new(),
             )),
@@ -1162,6 +1237,7 @@ impl ToolManager {
                 Some(Box::new(terrain_analysis::NumDownslopeNeighbours::new()))
             }
             "numupslopeneighbours" => Some(Box::new(terrain_analysis::NumUpslopeNeighbours::new())),
+            "openness" => Some(Box::new(terrain_analysis::Openness::new())),
             "pennocklandformclass" => Some(Box::new(terrain_analysis::PennockLandformClass::new())),
             "percentelevrange" => Some(Box::new(terrain_analysis::PercentElevRange::new())),
             "plancurvature" => Some(Box::new(terrain_analysis::PlanCurvature::new())),
@@ -1181,8 +1257,10 @@ impl ToolManager {
             "sedimenttransportindex" => {
                 Some(Box::new(terrain_analysis::SedimentTransportIndex::new()))
             }
+            "skyviewfactor" => Some(Box::new(terrain_analysis::SkyViewFactor::new())),
             "slope" => Some(Box::new(terrain_analysis::Slope::new())),
             "slopevselevationplot" => Some(Box::new(terrain_analysis::SlopeVsElevationPlot::new())),
+            "solarradiation" => Some(Box::new(terrain_analysis::SolarRadiation::new())),
             "sphericalstddevofnormals" => {
                 Some(Box::new(terrain_analysis::SphericalStdDevOfNormals::new()))
             }
@@ -1193,6 +1271,7 @@ impl ToolManager {
             "tangentialcurvature" => Some(Box::new(terrain_analysis::TangentialCurvature::new())),
             "topographichachures" => Some(Box::new(terrain_analysis::TopographicHachures::new())),
             "totalcurvature" => Some(Box::new(terrain_analysis::TotalCurvature::new())),
+            "tpilandformclass" => Some(Box::new(terrain_analysis::TpiLandformClass::new())),
             "viewshed" => Some(Box::new(terrain_analysis::Viewshed::new())),
             "visibilityindex" => Some(Box::new(terrain_analysis::VisibilityIndex::new())),
             "wetnessindex" => Some(Box::new(terrain_analysis::WetnessIndex::new())),
@@ -1233,9 +1312,124 @@ impl ToolManager {
         Ok(plugins)
     }
 
-    pub fn run_tool(&self, tool_name: String, args: Vec<String>) -> Result<(), Error> {
+    /// Runs a built-in tool with structured arguments. Unlike `run_tool`, plugin tools are not
+    /// searched and a panic within the tool is returned as `ToolError::Panic`.
+    pub fn run_tool_with_args(&self, tool_name: &str, args: &ToolArgs) -> Result<(), ToolError> {
+        let tool = match self.get_tool(tool_name) {
+            Some(tool) => tool,
+            None => return Err(ToolError::UnrecognizedTool(tool_name.to_string())),
+        };
+        let tool_name = tool.get_tool_name();
+        progress::set_current_tool(Some(&tool_name));
+        progress::tool_started(&tool_name);
+        let start = Instant::now();
+        let result = match ParsedArgs::parse(
+            &tool.get_tool_parameters(),
+            &args.to_args_vec(),
+            &self.working_dir,
+        ) {
+            Ok(args) => match panic::catch_unwind(panic::AssertUnwindSafe(|| {
+                self.run_parsed_args(tool.as_ref(), args)
+            })) {
+                Ok(Ok(())) => Ok(()),
+                Ok(Err(e)) => Err(ToolError::Execution {
+                    tool_name: tool_name.clone(),
+                    source: e,
+                }),
+                Err(payload) => Err(ToolError::Panic {
+                    tool_name: tool_name.clone(),
+                    message: panic_message(payload.as_ref()),
+                }),
+            },
+            Err(e) => Err(ToolError::InvalidArguments(e.to_string())),
+        };
+        match &result {
+            Ok(()) => progress::tool_finished(&tool_name, start.elapsed()),
+            Err(e) => {
+                let kind = match e {
+                    ToolError::Execution { source, .. } => format!("{:?}", source.kind()),
+                    ToolError::Panic { .. } => "Panic".to_string(),
+                    _ => "InvalidInput".to_string(),
+                };
+                progress::tool_error(&tool_name, &kind, &e.to_string());
+            }
+        }
+        progress::set_current_tool(None);
+        result
+    }
+
+    /// Runs a tool with validated arguments. Each of the tool's outputs is stamped with a
+    /// provenance record (see `write_provenance`). If the tool is cancelled, or runs out of time,
+    /// any output files that it had started to write are deleted.
+    fn run_parsed_args(&self, tool: &dyn WhiteboxTool, args: ParsedArgs) -> Result<(), Error> {
+        let output_files = args.output_files().to_vec();
+        let record = Provenance {
+            tool: tool.get_tool_name(),
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            args: args.raw_args().to_vec(),
+            working_directory: self.working_dir.clone(),
+            inputs: args
+                .input_files()
+                .iter()
+                .map(|f| InputFile {
+                    file: f.clone(),
+                    checksum: hash_file_set(f),
+                })
+                .collect(),
+            seed: random::tool_seed(args.get_seed().unwrap_or(None)),
+            timestamp: Utc::now().to_rfc3339_opts(SecondsFormat::Secs, true),
+        };
+        let start_time = SystemTime::now();
+        provenance::set_current(Some(record.clone()));
+        let result = tool.run_with_parsed_args(
+            args,
+            &self.working_dir,
+            self.verbose,
+            &self.cancellation_token,
+        );
+        provenance::set_current(None);
+        match &result {
+            Ok(()) => write_provenance(&record, &output_files),
+            Err(e) => {
+                if e.kind() == ErrorKind::Interrupted || e.kind() == ErrorKind::TimedOut {
+                    remove_partial_outputs(&output_files, start_time);
+                }
+            }
+        }
+        result
+    }
+
+    /// Runs a tool, either a built-in tool or a plugin. With the `--batch` flag, a built-in
+    /// tool is run once for each of many input files (see `batch`).
+    pub fn run_tool(&self, tool_name: String, mut args: Vec<String>) -> Result<(), Error> {
+        if let Some(max_jobs) = batch::take_batch_flag(&mut args)? {
+            return self.run_batch(&tool_name, &args, max_jobs);
+        }
         match self.get_tool(tool_name.as_ref()) {
-            Some(tool) => return tool.run(args, &self.working_dir, self.verbose),
+            Some(tool) => {
+                let tool_name = tool.get_tool_name();
+                progress::set_current_tool(Some(&tool_name));
+                progress::tool_started(&tool_name);
+                let start = Instant::now();
+                let result = match ParsedArgs::parse(&tool.get_tool_parameters(), &args, &self.working_dir) {
+                    Ok(args) => self.run_parsed_args(tool.as_ref(), args),
+                    Err(e) => Err(Error::new(
+                        ErrorKind::InvalidInput,
+                        format!(
+                            "Invalid arguments for {}: {}\nRun with --toolhelp={} for a description of the tool's parameters.",
+                            tool_name,
+                            e,
+                            tool_name
+                        ),
+                    )),
+                };
+                match &result {
+                    Ok(()) => progress::tool_finished(&tool_name, start.elapsed()),
+                    Err(e) => progress::tool_error(&tool_name, &format!("{:?}", e.kind()), &e.to_string()),
+                }
+                progress::set_current_tool(None);
+                return result;
+            }
             None => {
                 // Check the 'plugins' folder to see if the tool is in the Enterprise plugins.
                 // if yes, then run it.
@@ -1254,6 +1448,16 @@ impl ToolManager {
                             args2.push(args[a].clone());
                         }
                     }
+                    // Pass the global --seed on to plugins that take a seed of their own.
+                    if let Some(seed) = random::default_seed() {
+                        let takes_seed = plugin_data["parameters"]
+                            .as_array()
+                            .map(|p| p.iter().any(|p| p["flags"].to_string().contains("\"--seed\"")))
+                            .unwrap_or(false);
+                        if takes_seed && !args2.iter().any(|a| a.trim_start_matches('-').starts_with("seed")) {
+                            args2.push(format!("--seed={}", seed));
+                        }
+                    }
                     let exe = format!("{}{}{}{}", 
                         plugin_data["plugin_directory"]
                         .as_str()
@@ -1320,7 +1524,6 @@ impl ToolManager {
                         "lowpointsonheadwaterdivides",
                         "mindistclassification",
                         "modifylidar",
-                        "openness",
                         "parallelepipedclassification",
                         "phicoefficient",
                         "piecewisecontraststretch",
@@ -1368,6 +1571,45 @@ impl ToolManager {
         }
     }
 
+    /// Runs a built-in tool once for each of the input files named by a batch run's arguments,
+    /// with up to `max_jobs` runs at once, or as many as there are processors if `max_jobs` is 0.
+    fn run_batch(&self, tool_name: &str, args: &[String], max_jobs: usize) -> Result<(), Error> {
+        let tool = match self.get_tool(tool_name) {
+            Some(tool) => tool,
+            None => {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    format!("Unrecognized tool name {}; plugin tools can't be run in batches.", tool_name),
+                ))
+            }
+        };
+        let tool_name = tool.get_tool_name();
+        let jobs = batch::expand_batch(&tool.get_tool_parameters(), args, &self.working_dir)?;
+        let configs = whitebox_common::configs::get_configs()?;
+        let num_procs = if configs.max_procs > 0 {
+            configs.max_procs as usize
+        } else {
+            num_cpus::get()
+        };
+        let max_jobs = if max_jobs == 0 { num_procs } else { max_jobs.min(num_procs) };
+        let num_threads = max_jobs.min(jobs.len()).max(1);
+        if self.verbose {
+            progress::message(format!(
+                "Running {} on {} inputs, {} at a time...",
+                tool_name,
+                jobs.len(),
+                num_threads
+            ));
+        }
+        // The progress output of tools that run side by side would be interleaved, so only
+        // the outcome of each run is reported.
+        let mut tm = self.clone();
+        tm.verbose = self.verbose && num_threads == 1;
+        let start = Instant::now();
+        let failed = batch::run_jobs(&tm, &tool_name, &jobs, num_threads, self.verbose);
+        batch::report(&tool_name, jobs.len(), &failed, start, self.verbose)
+    }
+
     pub fn tool_help(&self, tool_name: String) -> Result<(), Error> {
         if !tool_name.is_empty() {
             match self.get_tool(tool_name.as_ref()) {
@@ -1667,12 +1909,109 @@ pub trait WhiteboxTool {
     fn get_example_usage(&self) -> String;
     fn get_toolbox(&self) -> String;
     fn get_source_file(&self) -> String;
+    /// Runs the tool. Long-running tools check `cancel` within their main loops and return
+    /// its error once it has been cancelled or has timed out.
     fn run<'a>(
         &self,
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error>;
+
+    /// Runs the tool with arguments that the `ToolManager` has already validated against
+    /// `get_tool_parameters`. Tools that override this method receive the typed values; by
+    /// default, the original command-line arguments are passed on to `run`.
+    fn run_with_parsed_args<'a>(
+        &self,
+        args: ParsedArgs,
+        working_directory: &'a str,
+        verbose: bool,
+        cancel: &CancellationToken,
+    ) -> Result<(), Error> {
+        self.run(args.into_raw_args(), working_directory, verbose, cancel)
+    }
+}
+
+/// Writes a provenance sidecar file for each output that does not hold the record itself.
+/// Rasters in the GeoTIFF, Whitebox, and Idrisi formats store it in their metadata and LAS files
+/// store it in a VLR, both of which are written by the tool as it saves its output. Each output
+/// is checked after the tool returns, so that one that was not stamped, e.g. because it was
+/// written by a thread that did not inherit the record, still gets a sidecar.
+fn write_provenance(record: &Provenance, output_files: &[String]) {
+    for output_file in output_files {
+        if is_memory_path(output_file) || !path::Path::new(output_file).is_file() {
+            continue;
+        }
+        if lineage::read_embedded_provenance(output_file).as_ref() == Some(record) {
+            // Remove any sidecar left by an earlier run so that it can't contradict the record.
+            let _ = fs::remove_file(provenance::sidecar_file(output_file));
+        } else if let Err(e) = record.write_sidecar(output_file) {
+            progress::warning(&format!(
+                "Unable to write the provenance of {}: {}",
+                output_file, e
+            ));
+        }
+    }
+}
+
+/// Deletes each output file, along with the files that its format writes beside it (e.g. the
+/// .shx and .dbf files of a shapefile) and its provenance sidecar, that has been modified since
+/// `start_time`. Files that a tool did not get as far as writing are left alone, as are
+/// in-memory outputs and any other files that happen to share an output's name.
+fn remove_partial_outputs(output_files: &[String], start_time: SystemTime) {
+    for output_file in output_files {
+        if is_memory_path(output_file) {
+            continue;
+        }
+        for file in output_file_set(output_file) {
+            let modified = fs::metadata(&file).and_then(|m| m.modified());
+            if let Ok(modified) = modified {
+                if modified >= start_time {
+                    let _ = fs::remove_file(&file);
+                }
+            }
+        }
+    }
+}
+
+/// Returns the files that an output is written to: the output itself, the companion files of
+/// its format, and its provenance sidecar.
+fn output_file_set(output_file: &str) -> Vec<path::PathBuf> {
+    let output_path = path::Path::new(output_file);
+    let extension = output_path
+        .extension()
+        .map(|e| e.to_string_lossy().to_lowercase())
+        .unwrap_or_default();
+    let companions: &[&str] = match extension.as_str() {
+        "shp" => &["shx", "dbf", "prj"],
+        "dep" | "tas" => &["dep", "tas", "wstat"],
+        "rdc" | "rst" => &["rdc", "rst"],
+        "sdat" | "sgrd" => &["sdat", "sgrd", "prj"],
+        "bil" => &["hdr", "prj"],
+        "flt" => &["hdr"],
+        _ => &[],
+    };
+    let mut files = vec![output_path.to_path_buf()];
+    for ext in companions {
+        let file = output_path.with_extension(ext);
+        if !files.contains(&file) {
+            files.push(file);
+        }
+    }
+    files.push(provenance::sidecar_file(output_file));
+    files
+}
+
+/// Returns the message of a panic caught with `panic::catch_unwind`.
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        s.to_string()
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        s.clone()
+    } else {
+        "unknown panic".to_string()
+    }
 }
 
 fn get_help<'a>(wt: Box<dyn WhiteboxTool + 'a>) -> String {
@@ -1751,6 +2090,29 @@ impl ToolParameter {
     }
 }
 
+/// Returns the optional `--seed` parameter of a stochastic tool (see `whitebox_common::random`).
+fn seed_parameter() -> ToolParameter {
+    ToolParameter {
+        name: "Random Seed".to_owned(),
+        flags: vec!["--seed".to_owned()],
+        description: "Optional seed for the random number generator, for reproducible outputs."
+            .to_owned(),
+        parameter_type: ParameterType::Integer,
+        default_value: None,
+        optional: true,
+    }
+}
+
+/// Parses the value of a tool's `--seed` argument.
+fn parse_seed(value: &str) -> Result<u64, Error> {
+    value.trim().parse::<u64>().map_err(|_| {
+        Error::new(
+            ErrorKind::InvalidInput,
+            format!("Invalid --seed value '{}'; the seed must be a non-negative integer.", value),
+        )
+    })
+}
+
 #[derive(Serialize, Deserialize, Debug)]
 enum ParameterType {
     Boolean,
@@ -1800,3 +2162,54 @@ enum AttributeType {
     Boolean,
     Date,
 }
+
+#[cfg(test)]
+mod test {
+    use super::{output_file_set, remove_partial_outputs};
+    use std::env;
+    use std::fs;
+    use std::time::{Duration, SystemTime};
+
+    #[test]
+    fn test_output_file_set() {
+        let names = |output_file: &str| -> Vec<String> {
+            output_file_set(output_file)
+                .iter()
+                .map(|f| f.to_string_lossy().to_string())
+                .collect()
+        };
+        assert_eq!(
+            names("out.shp"),
+            vec!["out.shp", "out.shx", "out.dbf", "out.prj", "out.whitebox.json"]
+        );
+        assert_eq!(
+            names("out.tas"),
+            vec!["out.tas", "out.dep", "out.wstat", "out.whitebox.json"]
+        );
+        assert_eq!(names("out.tif"), vec!["out.tif", "out.whitebox.json"]);
+    }
+
+    #[test]
+    fn test_remove_partial_outputs() {
+        let dir =
+            env::temp_dir().join(format!("remove_partial_outputs_test_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let start_time = SystemTime::now() - Duration::from_secs(1);
+        let file = |name: &str| dir.join(name).to_string_lossy().to_string();
+        // The files of the cancelled run's output, and unrelated files that share its name and
+        // were written while it ran.
+        let written = ["dem.shp", "dem.shx", "dem.dbf", "dem.prj", "dem.whitebox.json"];
+        let unrelated = ["dem.csv", "dem.aux.xml", "dem.tif", "dem"];
+        for name in written.iter().chain(unrelated.iter()) {
+            fs::write(file(name), b"data").unwrap();
+        }
+        remove_partial_outputs(&[file("dem.shp")], start_time);
+        for name in written.iter() {
+            assert!(fs::metadata(file(name)).is_err(), "{} was not removed", name);
+        }
+        for name in unrelated.iter() {
+            assert!(fs::metadata(file(name)).is_ok(), "{} was removed", name);
+        }
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
@@ -0,0 +1,721 @@
+/*
+This code is part of the WhiteboxTools geospatial analysis library.
+Authors: Dr. John Lindsay
+Created: 18/10/2026
+Last Modified: 18/10/2026
+License: MIT
+*/
+
+use super::{ParameterFileType, ParameterType, ToolParameter, VectorGeometryType};
+use serde_json::Value;
+use std::fs::File;
+use std::io::{Error, ErrorKind, Read};
+use std::path;
+use whitebox_common::utils::is_memory_path;
+use whitebox_lidar::MEMORY_LAS_FILES;
+use whitebox_raster::{MEMORY_RASTERS, RASTER_FILE_EXTENSIONS};
+use whitebox_vector::{ShapeType, MEMORY_SHAPEFILES};
+
+const LIDAR_FILE_EXTENSIONS: [&str; 4] = ["las", "laz", "zlidar", "zip"];
+
+/// An argument value, typed according to the parameter that it was supplied for.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ArgValue {
+    Boolean(bool),
+    Integer(i64),
+    Float(f64),
+    String(String),
+    StringList(Vec<String>),
+    /// A file name, resolved against the working directory.
+    File(String),
+    FileList(Vec<String>),
+}
+
+/// Tool arguments that have been validated against the tool's parameters.
+///
+/// `ParsedArgs::parse` reads the parameter list returned by `WhiteboxTool::get_tool_parameters`
+/// and checks the command-line arguments against it: unrecognized flags, missing required
+/// parameters, values that do not match the `ParameterType`, `OptionList` values that are not
+/// among the options, and input files that do not exist or are of the wrong type are all
+/// reported as `ErrorKind::InvalidInput` errors. Parameters that were not supplied take their
+/// `default_value`.
+///
+/// A flag such as `--filter` that is shorthand for an `x` and `y` pair of parameters
+/// (`--filterx` and `--filtery`) sets both of them.
+///
+/// Values are looked up by any of the parameter's flags, with or without leading dashes:
+///
+/// ```no_run
+/// # use whitebox_tools::ParsedArgs;
+/// # fn f(args: &ParsedArgs) {
+/// let input_file = args.get_file("dem").unwrap();
+/// let z_factor = args.get_float("zfactor").unwrap_or(1.0);
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct ParsedArgs {
+    values: Vec<(Vec<String>, ArgValue)>,
+    input_files: Vec<String>,
+    output_files: Vec<String>,
+    raw_args: Vec<String>,
+}
+
+impl ParsedArgs {
+    pub fn parse(
+        tool_parameters: &str,
+        args: &[String],
+        working_directory: &str,
+    ) -> Result<ParsedArgs, Error> {
+        let parameters = read_parameters(tool_parameters)?;
+
+        // Match each argument to a parameter. Values are given either as `--flag=value`
+        // or as `--flag value`; a boolean flag on its own is true.
+        let mut supplied: Vec<Option<String>> = vec![None; parameters.len()];
+        let mut i = 0;
+        while i < args.len() {
+            let arg = strip_quotes(&args[i]);
+            i += 1;
+            if arg.is_empty() {
+                continue;
+            }
+            if !arg.starts_with('-') {
+                return Err(invalid_input(format!("unexpected argument '{}'", arg)));
+            }
+            let (flag, value) = match arg.find('=') {
+                Some(pos) => (arg[..pos].to_string(), Some(arg[pos + 1..].to_string())),
+                None => (arg.clone(), None),
+            };
+            let indices = find_parameters(&parameters, &normalize_flag(&flag));
+            if indices.is_empty() {
+                return Err(invalid_input(format!("unrecognized argument '{}'", flag)));
+            }
+            let value = match value {
+                Some(v) => v,
+                None => match parameters[indices[0]].parameter_type {
+                    ParameterType::Boolean => match args.get(i).map(|a| strip_quotes(a)) {
+                        Some(v)
+                            if v.eq_ignore_ascii_case("true")
+                                || v.eq_ignore_ascii_case("false") =>
+                        {
+                            i += 1;
+                            v
+                        }
+                        _ => "true".to_string(),
+                    },
+                    _ => match args.get(i) {
+                        Some(v) => {
+                            i += 1;
+                            strip_quotes(v)
+                        }
+                        None => {
+                            return Err(invalid_input(format!("no value was given for '{}'", flag)))
+                        }
+                    },
+                },
+            };
+            for index in indices {
+                supplied[index] = Some(value.trim().to_string());
+            }
+        }
+
+        let mut values = vec![];
+        let mut input_files = vec![];
+        let mut output_files = vec![];
+        for (parameter, value) in parameters.iter().zip(supplied) {
+            let value = match value {
+                Some(v) => {
+                    if v.is_empty() && !matches!(parameter.parameter_type, ParameterType::Boolean) {
+                        None
+                    } else {
+                        Some(parse_value(parameter, &v, working_directory, true)?)
+                    }
+                }
+                None => match &parameter.default_value {
+                    Some(d) if !d.trim().is_empty() => {
+                        Some(parse_value(parameter, d.trim(), working_directory, false)?)
+                    }
+                    _ => None,
+                },
+            };
+            match (&parameter.parameter_type, &value) {
+                (ParameterType::NewFile(_), Some(ArgValue::File(f))) => output_files.push(f.clone()),
+                (_, Some(ArgValue::File(f))) => input_files.push(f.clone()),
+                (_, Some(ArgValue::FileList(files))) => input_files.extend(files.iter().cloned()),
+                _ => {}
+            }
+            match value {
+                Some(v) => values.push((
+                    parameter.flags.iter().map(|f| normalize_flag(f)).collect(),
+                    v,
+                )),
+                None => {
+                    if !parameter.optional
+                        && !matches!(parameter.parameter_type, ParameterType::Boolean)
+                    {
+                        return Err(invalid_input(format!(
+                            "the required parameter '{}' ({}) was not specified",
+                            long_flag(parameter),
+                            parameter.name
+                        )));
+                    }
+                }
+            }
+        }
+
+        Ok(ParsedArgs {
+            values: values,
+            input_files: input_files,
+            output_files: output_files,
+            raw_args: args.to_vec(),
+        })
+    }
+
+    /// Returns the value of the parameter with the given flag, if it was supplied or has a default.
+    pub fn get(&self, flag: &str) -> Option<&ArgValue> {
+        let key = normalize_flag(flag);
+        self.values
+            .iter()
+            .find(|(flags, _)| flags.contains(&key))
+            .map(|(_, v)| v)
+    }
+
+    pub fn contains(&self, flag: &str) -> bool {
+        self.get(flag).is_some()
+    }
+
+    /// Returns the value of a boolean parameter; a parameter that was not set is false.
+    pub fn get_bool(&self, flag: &str) -> bool {
+        match self.get(flag) {
+            Some(ArgValue::Boolean(b)) => *b,
+            _ => false,
+        }
+    }
+
+    pub fn get_integer(&self, flag: &str) -> Option<i64> {
+        match self.get(flag) {
+            Some(ArgValue::Integer(v)) => Some(*v),
+            _ => None,
+        }
+    }
+
+    /// Returns the value of a stochastic tool's `--seed` parameter, which must not be negative.
+    pub fn get_seed(&self) -> Result<Option<u64>, Error> {
+        match self.get_integer("seed") {
+            Some(s) if s < 0 => Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The random seed (--seed) must not be negative.",
+            )),
+            seed => Ok(seed.map(|s| s as u64)),
+        }
+    }
+
+    /// Returns the value of a numeric parameter, either `Float` or `Integer`.
+    pub fn get_float(&self, flag: &str) -> Option<f64> {
+        match self.get(flag) {
+            Some(ArgValue::Float(v)) => Some(*v),
+            Some(ArgValue::Integer(v)) => Some(*v as f64),
+            _ => None,
+        }
+    }
+
+    /// Returns the value of a string or option list parameter. For an option list, this is
+    /// the option as it is spelled in the parameter's list of options.
+    pub fn get_string(&self, flag: &str) -> Option<String> {
+        match self.get(flag) {
+            Some(ArgValue::String(s)) | Some(ArgValue::File(s)) => Some(s.clone()),
+            _ => None,
+        }
+    }
+
+    /// Returns the value of a file parameter, resolved against the working directory.
+    pub fn get_file(&self, flag: &str) -> Option<String> {
+        match self.get(flag) {
+            Some(ArgValue::File(s)) => Some(s.clone()),
+            _ => None,
+        }
+    }
+
+    /// Returns the files of a file list parameter, or an empty list if it was not set.
+    pub fn get_file_list(&self, flag: &str) -> Vec<String> {
+        match self.get(flag) {
+            Some(ArgValue::FileList(files)) => files.clone(),
+            Some(ArgValue::File(file)) => vec![file.clone()],
+            _ => vec![],
+        }
+    }
+
+    /// The files named by the tool's input (`ExistingFile`, `ExistingFileOrFloat`, and `FileList`)
+    /// parameters, resolved against the working directory.
+    pub fn input_files(&self) -> &[String] {
+        &self.input_files
+    }
+
+    /// The files named by the tool's output (`NewFile`) parameters, resolved against the
+    /// working directory.
+    pub fn output_files(&self) -> &[String] {
+        &self.output_files
+    }
+
+    /// The command-line arguments that were parsed.
+    pub fn raw_args(&self) -> &[String] {
+        &self.raw_args
+    }
+
+    pub fn into_raw_args(self) -> Vec<String> {
+        self.raw_args
+    }
+}
+
+pub(super) fn read_parameters(tool_parameters: &str) -> Result<Vec<ToolParameter>, Error> {
+    let v: Value = serde_json::from_str(tool_parameters)?;
+    match v.get("parameters") {
+        Some(p) => Ok(serde_json::from_value(p.clone())?),
+        None => Err(Error::new(
+            ErrorKind::InvalidData,
+            "The tool parameters do not contain a 'parameters' list.",
+        )),
+    }
+}
+
+/// Returns the parameters that a flag refers to. A flag that is not a parameter's own, e.g.
+/// `filter`, sets both of a pair of parameters named for it with an `x` and `y` suffix.
+pub(super) fn find_parameters(parameters: &[ToolParameter], key: &str) -> Vec<usize> {
+    let find = |key: &str| {
+        parameters
+            .iter()
+            .position(|p| p.flags.iter().any(|f| normalize_flag(f) == key))
+    };
+    if let Some(index) = find(key) {
+        return vec![index];
+    }
+    match (find(&format!("{}x", key)), find(&format!("{}y", key))) {
+        (Some(x), Some(y)) => vec![x, y],
+        _ => vec![],
+    }
+}
+
+fn parse_value(
+    parameter: &ToolParameter,
+    value: &str,
+    working_directory: &str,
+    check_files: bool,
+) -> Result<ArgValue, Error> {
+    let result = match &parameter.parameter_type {
+        ParameterType::Boolean => {
+            if value.is_empty() || value.eq_ignore_ascii_case("true") {
+                Ok(ArgValue::Boolean(true))
+            } else if value.eq_ignore_ascii_case("false") {
+                Ok(ArgValue::Boolean(false))
+            } else {
+                Err("expected true or false".to_string())
+            }
+        }
+        ParameterType::Integer => parse_integer(value).map(ArgValue::Integer),
+        ParameterType::Float => parse_float(value).map(ArgValue::Float),
+        ParameterType::String
+        | ParameterType::StringOrNumber
+        | ParameterType::VectorAttributeField(_, _)
+        | ParameterType::Directory => Ok(ArgValue::String(value.to_string())),
+        ParameterType::StringList => Ok(ArgValue::StringList(
+            value
+                .split(',')
+                .map(|s| s.trim().to_string())
+                .filter(|s| !s.is_empty())
+                .collect(),
+        )),
+        ParameterType::OptionList(options) => {
+            match options.iter().find(|o| o.eq_ignore_ascii_case(value)) {
+                Some(o) => Ok(ArgValue::String(o.clone())),
+                None => Err(format!("expected one of: {}", options.join(", "))),
+            }
+        }
+        ParameterType::ExistingFile(file_type) => {
+            let file_name = resolve_file_name(value, working_directory);
+            if check_files {
+                check_existing_file(&file_name, file_type).map(|_| ArgValue::File(file_name))
+            } else {
+                Ok(ArgValue::File(file_name))
+            }
+        }
+        ParameterType::ExistingFileOrFloat(file_type) => match parse_float(value) {
+            Ok(v) => Ok(ArgValue::Float(v)),
+            Err(_) => {
+                let file_name = resolve_file_name(value, working_directory);
+                if check_files {
+                    check_existing_file(&file_name, file_type).map(|_| ArgValue::File(file_name))
+                } else {
+                    Ok(ArgValue::File(file_name))
+                }
+            }
+        },
+        ParameterType::NewFile(file_type) => {
+            let file_name = resolve_file_name(value, working_directory);
+            check_new_file(&file_name, file_type).map(|_| ArgValue::File(file_name))
+        }
+        ParameterType::FileList(file_type) => {
+            // Lists are separated by semicolons, or by commas if there are no semicolons.
+            let separator = if value.contains(';') { ';' } else { ',' };
+            let mut files = vec![];
+            let mut result = Ok(());
+            for f in value
+                .split(separator)
+                .map(|f| f.trim())
+                .filter(|f| !f.is_empty())
+            {
+                let file_name = resolve_file_name(f, working_directory);
+                if check_files && result.is_ok() {
+                    result = check_existing_file(&file_name, file_type)
+                        .map_err(|msg| format!("{} ({})", msg, file_name));
+                }
+                files.push(file_name);
+            }
+            result.map(|_| ArgValue::FileList(files))
+        }
+    };
+    result.map_err(|msg| {
+        invalid_input(format!(
+            "invalid value '{}' for '{}': {}",
+            value,
+            long_flag(parameter),
+            msg
+        ))
+    })
+}
+
+fn parse_integer(value: &str) -> Result<i64, String> {
+    if let Ok(v) = value.parse::<i64>() {
+        return Ok(v);
+    }
+    // Accept integral floating-point values, e.g. '11.0'.
+    match value.parse::<f64>() {
+        Ok(v) if v.is_finite() && v.fract() == 0.0 && v.abs() < 9.0e15 => Ok(v as i64),
+        _ => Err("expected an integer".to_string()),
+    }
+}
+
+fn parse_float(value: &str) -> Result<f64, String> {
+    value
+        .parse::<f64>()
+        .map_err(|_| "expected a number".to_string())
+}
+
+/// File names without a directory are taken to be in the working directory, as they are in
+/// the tools themselves.
+pub(super) fn resolve_file_name(file_name: &str, working_directory: &str) -> String {
+    let sep = path::MAIN_SEPARATOR.to_string();
+    if is_memory_path(file_name) || file_name.contains(&sep) || file_name.contains('/') {
+        file_name.to_string()
+    } else {
+        format!("{}{}", working_directory, file_name)
+    }
+}
+
+fn check_existing_file(file_name: &str, file_type: &ParameterFileType) -> Result<(), String> {
+    if is_memory_path(file_name) {
+        let found = match file_type {
+            ParameterFileType::Raster => MEMORY_RASTERS.contains(file_name),
+            ParameterFileType::Lidar => MEMORY_LAS_FILES.contains(file_name),
+            ParameterFileType::Vector(_) => MEMORY_SHAPEFILES.contains(file_name),
+            ParameterFileType::RasterAndVector(_) => {
+                MEMORY_RASTERS.contains(file_name) || MEMORY_SHAPEFILES.contains(file_name)
+            }
+            _ => {
+                MEMORY_RASTERS.contains(file_name)
+                    || MEMORY_SHAPEFILES.contains(file_name)
+                    || MEMORY_LAS_FILES.contains(file_name)
+            }
+        };
+        return if found {
+            Ok(())
+        } else {
+            Err("there is no in-memory data set with this name".to_string())
+        };
+    }
+    if !path::Path::new(file_name).is_file() {
+        return Err("the file does not exist".to_string());
+    }
+    check_file_extension(file_name, file_type, false)?;
+    match file_type {
+        ParameterFileType::Vector(geometry_type)
+        | ParameterFileType::RasterAndVector(geometry_type) => {
+            if get_extension(file_name) == "shp" {
+                check_geometry_type(file_name, geometry_type)?;
+            }
+            Ok(())
+        }
+        _ => Ok(()),
+    }
+}
+
+fn check_new_file(file_name: &str, file_type: &ParameterFileType) -> Result<(), String> {
+    if is_memory_path(file_name) {
+        return Ok(());
+    }
+    // Outputs without an extension are given the default extension of the data type.
+    check_file_extension(file_name, file_type, true)
+}
+
+pub(super) fn check_file_extension(
+    file_name: &str,
+    file_type: &ParameterFileType,
+    allow_missing: bool,
+) -> Result<(), String> {
+    let extension = get_extension(file_name);
+    if extension.is_empty() && allow_missing {
+        return Ok(());
+    }
+    let is_raster = RASTER_FILE_EXTENSIONS.contains(&extension.as_str());
+    let (valid, expected) = match file_type {
+        ParameterFileType::Raster => (is_raster, "a raster"),
+        ParameterFileType::Lidar => (
+            LIDAR_FILE_EXTENSIONS.contains(&extension.as_str()),
+            "a LiDAR (las, laz, zlidar, or zip)",
+        ),
+        ParameterFileType::Vector(_) => (extension == "shp", "a vector (shp)"),
+        ParameterFileType::RasterAndVector(_) => {
+            (is_raster || extension == "shp", "a raster or vector (shp)")
+        }
+        ParameterFileType::Csv => (extension == "csv" || extension == "txt", "a CSV"),
+        ParameterFileType::Html => (extension == "html" || extension == "htm", "an HTML"),
+        _ => (true, ""),
+    };
+    if valid {
+        Ok(())
+    } else {
+        Err(format!("expected {} file", expected))
+    }
+}
+
+/// Checks the shape type in a shapefile's header against the required geometry type.
+fn check_geometry_type(file_name: &str, geometry_type: &VectorGeometryType) -> Result<(), String> {
+    let mut header = [0u8; 36];
+    File::open(file_name)
+        .and_then(|mut f| f.read_exact(&mut header))
+        .map_err(|e| format!("the file could not be read ({})", e))?;
+    let shape_type = ShapeType::from_int(i32::from_le_bytes([
+        header[32], header[33], header[34], header[35],
+    ]))
+    .base_shape_type();
+    let (valid, expected) = match geometry_type {
+        VectorGeometryType::Any => (true, ""),
+        VectorGeometryType::Point => (
+            shape_type == ShapeType::Point || shape_type == ShapeType::MultiPoint,
+            "point",
+        ),
+        VectorGeometryType::Line => (shape_type == ShapeType::PolyLine, "line"),
+        VectorGeometryType::Polygon => (shape_type == ShapeType::Polygon, "polygon"),
+        VectorGeometryType::LineOrPolygon => (
+            shape_type == ShapeType::PolyLine || shape_type == ShapeType::Polygon,
+            "line or polygon",
+        ),
+    };
+    // An empty (null) shapefile is accepted for any geometry type.
+    if valid || shape_type == ShapeType::Null {
+        Ok(())
+    } else {
+        Err(format!(
+            "expected a {} vector, but the file contains {} geometries",
+            expected, shape_type
+        ))
+    }
+}
+
+fn get_extension(file_name: &str) -> String {
+    match path::Path::new(file_name).extension() {
+        Some(ext) => ext.to_string_lossy().to_lowercase(),
+        None => String::new(),
+    }
+}
+
+pub(super) fn normalize_flag(flag: &str) -> String {
+    flag.trim().trim_start_matches('-').to_lowercase()
+}
+
+fn long_flag(parameter: &ToolParameter) -> String {
+    match parameter.flags.last() {
+        Some(f) => f.clone(),
+        None => parameter.name.clone(),
+    }
+}
+
+pub(super) fn strip_quotes(arg: &str) -> String {
+    arg.replace("\"", "").replace("\'", "").trim().to_string()
+}
+
+fn invalid_input(msg: String) -> Error {
+    Error::new(ErrorKind::InvalidInput, msg)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{find_parameters, normalize_flag, read_parameters, ArgValue, ParsedArgs};
+    use crate::tools::{
+        ParameterFileType, ParameterType, ToolManager, ToolParameter, VectorGeometryType,
+    };
+    use std::env;
+    use std::fs;
+    use std::path::Path;
+
+    fn parameter(flags: &[&str], parameter_type: ParameterType, optional: bool) -> ToolParameter {
+        ToolParameter {
+            name: flags[0].to_string(),
+            flags: flags.iter().map(|f| f.to_string()).collect(),
+            description: String::new(),
+            parameter_type: parameter_type,
+            default_value: None,
+            optional: optional,
+        }
+    }
+
+    fn parameters_json(parameters: &[ToolParameter]) -> String {
+        let list: Vec<String> = parameters.iter().map(|p| p.to_string()).collect();
+        format!("{{\"parameters\": [{}]}}", list.join(","))
+    }
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|a| a.to_string()).collect()
+    }
+
+    fn temp_file(name: &str, contents: &[u8]) -> String {
+        let file = env::temp_dir().join(format!("parsed_args_test_{}_{}", std::process::id(), name));
+        fs::write(&file, contents).unwrap();
+        file.to_string_lossy().to_string()
+    }
+
+    #[test]
+    fn test_unknown_flags() {
+        let parameters = parameters_json(&[
+            parameter(&["-i", "--input"], ParameterType::String, false),
+            parameter(&["--zfactor"], ParameterType::Float, true),
+        ]);
+        let parsed = ParsedArgs::parse(&parameters, &args(&["-i=a", "--zfactor", "2"]), "").unwrap();
+        assert_eq!(parsed.get_string("input"), Some("a".to_string()));
+        assert_eq!(parsed.get_float("--zfactor"), Some(2.0));
+
+        let err = ParsedArgs::parse(&parameters, &args(&["-i=a", "--slope=2"]), "").unwrap_err();
+        assert!(err.to_string().contains("unrecognized argument '--slope'"));
+        assert!(ParsedArgs::parse(&parameters, &args(&["--zfactor=2"]), "").is_err());
+        assert!(ParsedArgs::parse(&parameters, &args(&["-i=a", "--zfactor=x"]), "").is_err());
+    }
+
+    #[test]
+    fn test_filter_expansion() {
+        let parameters = parameters_json(&[
+            parameter(&["--filterx"], ParameterType::Integer, true),
+            parameter(&["--filtery"], ParameterType::Integer, true),
+        ]);
+        let parsed = ParsedArgs::parse(&parameters, &args(&["--filter=7"]), "").unwrap();
+        assert_eq!(parsed.get("filterx"), Some(&ArgValue::Integer(7)));
+        assert_eq!(parsed.get("filtery"), Some(&ArgValue::Integer(7)));
+        let parsed = ParsedArgs::parse(&parameters, &args(&["--filter=7", "--filtery=3"]), "").unwrap();
+        assert_eq!(parsed.get_integer("filtery"), Some(3));
+        assert!(ParsedArgs::parse(&parameters, &args(&["--filt=7"]), "").is_err());
+    }
+
+    #[test]
+    fn test_files() {
+        let parameters = parameters_json(&[
+            parameter(&["--dem"], ParameterType::ExistingFile(ParameterFileType::Raster), false),
+            parameter(&["-o", "--output"], ParameterType::NewFile(ParameterFileType::Raster), false),
+        ]);
+        let dem = temp_file("dem.tif", b"");
+        let wd = format!("{}/", Path::new(&dem).parent().unwrap().to_string_lossy());
+        let dem_name = Path::new(&dem).file_name().unwrap().to_string_lossy().to_string();
+        let parsed = ParsedArgs::parse(
+            &parameters,
+            &args(&[&format!("--dem={}", dem_name), "-o=slope"]),
+            &wd,
+        )
+        .unwrap();
+        assert_eq!(parsed.input_files(), &[dem.clone()]);
+        assert_eq!(parsed.output_files(), &[format!("{}slope", wd)]);
+
+        let err = ParsedArgs::parse(&parameters, &args(&["--dem=missing.tif", "-o=slope.tif"]), &wd)
+            .unwrap_err();
+        assert!(err.to_string().contains("the file does not exist"));
+        let err = ParsedArgs::parse(
+            &parameters,
+            &args(&[&format!("--dem={}", dem_name), "-o=slope.shp"]),
+            &wd,
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("expected a raster file"));
+        let csv = temp_file("dem.csv", b"");
+        assert!(ParsedArgs::parse(&parameters, &args(&[&format!("--dem={}", csv), "-o=s.tif"]), &wd).is_err());
+        fs::remove_file(dem).unwrap();
+        fs::remove_file(csv).unwrap();
+    }
+
+    #[test]
+    fn test_geometry_type() {
+        let parameters = |geometry_type| {
+            parameters_json(&[parameter(
+                &["-i", "--input"],
+                ParameterType::ExistingFile(ParameterFileType::Vector(geometry_type)),
+                false,
+            )])
+        };
+        // A shapefile header of a point layer, i.e. shape type 1.
+        let mut header = vec![0u8; 100];
+        header[32] = 1;
+        let points = temp_file("points.shp", &header);
+        let input = args(&[&format!("--input={}", points)]);
+        assert!(ParsedArgs::parse(&parameters(VectorGeometryType::Point), &input, "").is_ok());
+        assert!(ParsedArgs::parse(&parameters(VectorGeometryType::Any), &input, "").is_ok());
+        let err = ParsedArgs::parse(&parameters(VectorGeometryType::Polygon), &input, "").unwrap_err();
+        assert!(err.to_string().contains("expected a polygon vector"));
+        fs::remove_file(points).unwrap();
+    }
+
+    /// Tools that still parse their own arguments in `run` must only read flags that they declare,
+    /// since `ParsedArgs::parse` rejects any others. Flags are matched in `run` by comparing
+    /// `flag_val` or `vec[0].to_lowercase()` with a flag, or by looking for part of a flag in
+    /// `flag_val`, and a flag matched by part must be part of a declared flag.
+    #[test]
+    fn test_run_flags_are_declared() {
+        let tm = ToolManager::new("", &false).unwrap();
+        let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+        let patterns = [
+            ("flag_val == \"", "=="),
+            ("to_lowercase() == \"", "=="),
+            ("flag_val.contains(\"", "contains"),
+            ("flag_val.starts_with(\"", "starts_with"),
+        ];
+        let mut undeclared = vec![];
+        for name in &tm.tool_names {
+            let tool = tm.get_tool(name).unwrap();
+            let source_file = tool.get_source_file();
+            let path = match root.parent().map(|p| p.join(&source_file)) {
+                Some(p) if p.is_file() => p,
+                _ => root.join(&source_file),
+            };
+            let source = fs::read_to_string(&path).unwrap();
+            let parameters = read_parameters(&tool.get_tool_parameters()).unwrap();
+            let flags: Vec<String> = parameters
+                .iter()
+                .flat_map(|p| p.flags.iter().map(|f| normalize_flag(f)))
+                .collect();
+            for line in source.lines().filter(|l| !l.trim_start().starts_with("//")) {
+                for (pattern, comparison) in patterns.iter() {
+                    for part in line.split(pattern).skip(1) {
+                        let literal = part.split('"').next().unwrap_or("");
+                        let flag = normalize_flag(literal);
+                        let declared = match *comparison {
+                            // Flags always start with a dash, so other values never match.
+                            "==" => {
+                                !literal.starts_with('-')
+                                    || !find_parameters(&parameters, &flag).is_empty()
+                            }
+                            "contains" => flags.iter().any(|f| f.contains(&flag)),
+                            _ => flags.iter().any(|f| f.starts_with(&flag)),
+                        };
+                        if !declared {
+                            undeclared.push(format!("{} ({} \"{}\")", name, comparison, literal));
+                        }
+                    }
+                }
+            }
+        }
+        assert!(undeclared.is_empty(), "undeclared flags: {}", undeclared.join(", "));
+    }
+}
@@ -7,6 +7,7 @@ License: MIT
 */
 
 use whitebox_raster::*;
+use whitebox_common::progress;
 use crate::tools::*;
 use num_cpus;
 use std::cmp::Ordering::Equal;
@@ -16,7 +17,6 @@ use std::io::{Error, ErrorKind};
 use std::path;
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::thread;
 
 /// This tool can be used to extract channel networks from an input digital elevation models (`--dem`) using
 /// one of three techniques that are based on local topography alone.
@@ -199,6 +199,7 @@ impl WhiteboxTool for ExtractValleys {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut output_file = String::new();
@@ -269,7 +270,7 @@ impl WhiteboxTool for ExtractValleys {
 
                 //the filter dimensions must be odd numbers such that there is a middle pixel
                 if (filter_size as f64 / 2f64).floor() == filter_size as f64 / 2f64 {
-                    println!("WARNING: Filter dimensions must be odd numbers. The specified filter dimension has been modified.");
+                    progress::warning("Filter dimensions must be odd numbers. The specified filter dimension has been modified.");
                     filter_size += 1;
                 }
             }
@@ -277,13 +278,7 @@ impl WhiteboxTool for ExtractValleys {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -322,7 +317,7 @@ impl WhiteboxTool for ExtractValleys {
                 for tid in 0..num_procs {
                     let input = input.clone();
                     let tx = tx.clone();
-                    thread::spawn(move || {
+                    progress::spawn(move || {
                         let num_cells_in_filter = filter_size * filter_size;
                         let mut dx = vec![0isize; num_cells_in_filter];
                         let mut dy = vec![0isize; num_cells_in_filter];
@@ -382,18 +377,20 @@ impl WhiteboxTool for ExtractValleys {
                                     }
                                 }
                             }
-                            tx.send((row, data)).unwrap();
+                            if tx.send((row, data)).is_err() {
+                                return;
+                            }
                         }
                     });
                 }
 
                 for row in 0..rows {
-                    let data = rx.recv().expect("Error receiving data from thread.");
+                    let data = cancel.recv(&rx)?;
                     output.set_row_data(data.0, data.1);
                     if verbose {
                         progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as i32;
                         if progress != old_progress {
-                            println!("Progress: {}%", progress);
+                            progress::update(progress);
                             old_progress = progress;
                         }
                     }
@@ -412,7 +409,7 @@ impl WhiteboxTool for ExtractValleys {
                 for tid in 0..num_procs {
                     let input = input.clone();
                     let tx = tx.clone();
-                    thread::spawn(move || {
+                    progress::spawn(move || {
                         let (mut z, mut zn1, mut zn2): (f64, f64, f64);
                         let dx = [0, 0, -1, 1];
                         let dy = [-1, 1, 0, 0];
@@ -436,18 +433,20 @@ impl WhiteboxTool for ExtractValleys {
                                     }
                                 }
                             }
-                            tx.send((row, data)).unwrap();
+                            if tx.send((row, data)).is_err() {
+                                return;
+                            }
                         }
                     });
                 }
 
                 for row in 0..rows {
-                    let data = rx.recv().expect("Error receiving data from thread.");
+                    let data = cancel.recv(&rx)?;
                     output.set_row_data(data.0, data.1);
                     if verbose {
                         progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as i32;
                         if progress != old_progress {
-                            println!("Progress: {}%", progress);
+                            progress::update(progress);
                             old_progress = progress;
                         }
                     }
@@ -484,10 +483,11 @@ impl WhiteboxTool for ExtractValleys {
                             output[(row, col)] = nodata;
                         }
                     }
+                    cancel.check()?;
                     if verbose {
                         progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as i32;
                         if progress != old_progress {
-                            println!("Progress: {}%", progress);
+                            progress::update(progress);
                             old_progress = progress;
                         }
                     }
@@ -496,7 +496,7 @@ impl WhiteboxTool for ExtractValleys {
         }
 
         if line_thin {
-            println!("Line thinning operation...");
+            progress::message("Line thinning operation...");
             let mut did_something = true;
             let mut loop_num = 0;
             let dx = [1, 1, 1, 0, -1, -1, -1, 0];
@@ -553,10 +553,11 @@ impl WhiteboxTool for ExtractValleys {
                             }
                         }
                     }
+                    cancel.check()?;
                     if verbose {
                         progress = (100.0_f64 * a as f64 / 7.0) as i32;
                         if progress != old_progress {
-                            println!("Loop Number {}: {}%", loop_num, progress);
+                            progress::update_stage(&format!("Loop Number {}", loop_num), progress);
                             old_progress = progress;
                         }
                     }
@@ -578,21 +579,18 @@ impl WhiteboxTool for ExtractValleys {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -10,6 +10,7 @@ use whitebox_raster::*;
 use whitebox_common::rendering::html::*;
 use whitebox_common::rendering::LineGraph;
 use whitebox_common::structures::Array2D;
+use whitebox_common::progress;
 use crate::tools::*;
 use num_cpus;
 use std::env;
@@ -22,7 +23,6 @@ use std::path;
 use std::process::Command;
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::thread;
 
 /// This tool can be used to create a [longitudinal profile](http://www.fao.org/docrep/003/X6841E/X6841E02.HTM) plot.
 /// A longitudinal stream profile is a plot of elevation against downstream distance. Most long profiles use distance
@@ -102,7 +102,7 @@ impl LongProfile {
 
         parameters.push(ToolParameter {
             name: "Does the pointer file use the ESRI pointer scheme?".to_owned(),
-            flags: vec!["--esri_pntr".to_owned()],
+            flags: vec!["--esri_style".to_owned(), "--esri_pntr".to_owned()],
             description: "D8 pointer uses the ESRI style scheme.".to_owned(),
             parameter_type: ParameterType::Boolean,
             default_value: Some("false".to_owned()),
@@ -174,6 +174,7 @@ impl WhiteboxTool for LongProfile {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut d8_file = String::new();
         let mut streams_file = String::new();
@@ -230,13 +231,7 @@ impl WhiteboxTool for LongProfile {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -258,15 +253,15 @@ impl WhiteboxTool for LongProfile {
         }
 
         if verbose {
-            println!("Reading pointer data...")
+            progress::message("Reading pointer data...")
         };
         let pntr = Raster::new(&d8_file, "r")?;
         if verbose {
-            println!("Reading streams data...")
+            progress::message("Reading streams data...")
         };
         let streams = Raster::new(&streams_file, "r")?;
         if verbose {
-            println!("Reading DEM data...")
+            progress::message("Reading DEM data...")
         };
         let dem = Raster::new(&dem_file, "r")?;
 
@@ -299,7 +294,7 @@ impl WhiteboxTool for LongProfile {
 
         // find all the stream head cells and add them to a list
         if verbose {
-            println!("Finding channel heads...");
+            progress::message("Finding channel heads...");
         }
         let streams = Arc::new(streams);
         let pntr = Arc::new(pntr);
@@ -314,7 +309,7 @@ impl WhiteboxTool for LongProfile {
             let streams = streams.clone();
             let pntr = pntr.clone();
             let tx1 = tx.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let d_x = [1, 1, 1, 0, -1, -1, -1, 0];
                 let d_y = [-1, 0, 1, 1, 1, 0, -1, -1];
                 let inflowing_vals = if !esri_style {
@@ -345,14 +340,16 @@ impl WhiteboxTool for LongProfile {
                             }
                         }
                     }
-                    tx1.send((row, heads)).unwrap();
+                    if tx1.send((row, heads)).is_err() {
+                        return;
+                    }
                 }
             });
         }
 
         let mut heads = vec![];
         for row in 0..rows {
-            let (r, data) = rx.recv().expect("Error receiving data from thread.");
+            let (r, data) = cancel.recv(&rx)?;
             for col in data {
                 heads.push((r, col));
             }
@@ -360,14 +357,14 @@ impl WhiteboxTool for LongProfile {
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Finding channel heads: {}%", progress);
+                    progress::update_stage("Finding channel heads", progress);
                     old_progress = progress;
                 }
             }
         }
 
         if verbose {
-            println!("Traversing streams...");
+            progress::message("Traversing streams...");
         }
         // Now traverse each flowpath starting from each stream head and
         // retrieve the elevation and distance from outlet data.
@@ -460,10 +457,11 @@ impl WhiteboxTool for LongProfile {
             traverse_num += 1;
             stream_lengths[h] = dist;
             // update progress here
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * h as f64 / (num_heads - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Loop 1 of 2: {}%", progress);
+                    progress::update_stage("Loop 1 of 2", progress);
                     old_progress = progress;
                 }
             }
@@ -518,10 +516,11 @@ impl WhiteboxTool for LongProfile {
                 traverse_num += 1;
             }
             // update progress here
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * h as f64 / (num_heads - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Loop 2 of 2: {}%", progress);
+                    progress::update_stage("Loop 2 of 2", progress);
                     old_progress = progress;
                 }
             }
@@ -589,10 +588,7 @@ impl WhiteboxTool for LongProfile {
         let _ = writer.flush();
 
         if verbose {
-            println!(
-                "\n{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("\n{}", &format!("Elapsed Time (excluding I/O): {}", elapsed_time)));
         }
 
         if verbose {
@@ -620,7 +616,7 @@ impl WhiteboxTool for LongProfile {
                 let _ = output.stdout;
             }
 
-            println!("Complete! Please see {} for output.", output_file);
+            progress::message(format!("Complete! Please see {} for output.", output_file));
         }
 
         Ok(())
@@ -10,6 +10,7 @@ use whitebox_raster::*;
 use whitebox_common::rendering::html::*;
 use whitebox_common::rendering::LineGraph;
 use whitebox_common::structures::Array2D;
+use whitebox_common::progress;
 use crate::tools::*;
 use whitebox_vector::{ShapeType, Shapefile};
 use std::env;
@@ -92,7 +93,7 @@ impl LongProfileFromPoints {
 
         parameters.push(ToolParameter {
             name: "Does the pointer file use the ESRI pointer scheme?".to_owned(),
-            flags: vec!["--esri_pntr".to_owned()],
+            flags: vec!["--esri_style".to_owned(), "--esri_pntr".to_owned()],
             description: "D8 pointer uses the ESRI style scheme.".to_owned(),
             parameter_type: ParameterType::Boolean,
             default_value: Some("false".to_owned()),
@@ -164,6 +165,7 @@ impl WhiteboxTool for LongProfileFromPoints {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut d8_file = String::new();
         let mut points_file = String::new();
@@ -220,13 +222,7 @@ impl WhiteboxTool for LongProfileFromPoints {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -248,17 +244,17 @@ impl WhiteboxTool for LongProfileFromPoints {
         }
 
         if verbose {
-            println!("Reading pointer data...")
+            progress::message("Reading pointer data...")
         };
         let pntr = Raster::new(&d8_file, "r")?;
 
         if verbose {
-            println!("Reading points data...")
+            progress::message("Reading points data...")
         };
         let points = Shapefile::read(&points_file)?;
 
         if verbose {
-            println!("Reading DEM data...")
+            progress::message("Reading DEM data...")
         };
         let dem = Raster::new(&dem_file, "r")?;
 
@@ -294,17 +290,18 @@ impl WhiteboxTool for LongProfileFromPoints {
             let col = dem.get_column_from_x(record.points[0].x);
             heads.push((row, col));
 
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Finding channel heads: {}%", progress);
+                    progress::update_stage("Finding channel heads", progress);
                     old_progress = progress;
                 }
             }
         }
 
         if verbose {
-            println!("Traversing flowpaths...");
+            progress::message("Traversing flowpaths...");
         }
         // Now traverse each flowpath starting from each stream head and
         // retrieve the elevation and distance from outlet data.
@@ -392,10 +389,11 @@ impl WhiteboxTool for LongProfileFromPoints {
             traverse_num += 1;
             stream_lengths[h] = dist;
             // update progress here
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * h as f64 / (num_heads - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Loop 1 of 2: {}%", progress);
+                    progress::update_stage("Loop 1 of 2", progress);
                     old_progress = progress;
                 }
             }
@@ -445,10 +443,11 @@ impl WhiteboxTool for LongProfileFromPoints {
                 traverse_num += 1;
             }
             // update progress here
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * h as f64 / (num_heads - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Loop 2 of 2: {}%", progress);
+                    progress::update_stage("Loop 2 of 2", progress);
                     old_progress = progress;
                 }
             }
@@ -509,10 +508,7 @@ impl WhiteboxTool for LongProfileFromPoints {
         let _ = writer.flush();
 
         if verbose {
-            println!(
-                "\n{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("\n{}", &format!("Elapsed Time (excluding I/O): {}", elapsed_time)));
         }
 
         if verbose {
@@ -540,7 +536,7 @@ impl WhiteboxTool for LongProfileFromPoints {
                 let _ = output.stdout;
             }
 
-            println!("Complete! Please see {} for output.", output_file);
+            progress::message(format!("Complete! Please see {} for output.", output_file));
         }
 
         Ok(())
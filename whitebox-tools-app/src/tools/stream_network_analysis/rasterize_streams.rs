@@ -8,6 +8,7 @@ License: MIT
 
 use whitebox_raster::*;
 use whitebox_common::structures::{Array2D, BoundingBox};
+use whitebox_common::progress;
 use crate::tools::*;
 use whitebox_vector::{ShapeType, Shapefile};
 use std::env;
@@ -153,6 +154,7 @@ impl WhiteboxTool for RasterizeStreams {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut streams_file = String::new();
         let mut base_file = String::new();
@@ -208,13 +210,7 @@ impl WhiteboxTool for RasterizeStreams {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -233,12 +229,12 @@ impl WhiteboxTool for RasterizeStreams {
         }
 
         if verbose {
-            println!("Reading streams data...")
+            progress::message("Reading streams data...")
         };
         let streams = Shapefile::read(&streams_file)?;
 
         if verbose {
-            println!("Reading base raster data...")
+            progress::message("Reading base raster data...")
         };
         let base = Raster::new(&base_file, "r")?;
         let rows = base.configs.rows as isize;
@@ -392,10 +388,11 @@ impl WhiteboxTool for RasterizeStreams {
             }
 
             count += 1f64;
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * count / (streams.num_records - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Rasterizing Streams: {}%", progress);
+                    progress::update_stage("Rasterizing Streams", progress);
                     old_progress = progress;
                 }
             }
@@ -408,7 +405,7 @@ impl WhiteboxTool for RasterizeStreams {
         let dx4 = [1isize, 0isize, -1isize, 0isize];
         let dy4 = [0isize, 1isize, 0isize, -1isize];
         let (mut row_n, mut col_n): (isize, isize);
-        println!("Counting stream cell adjacencies...");
+        progress::message("Counting stream cell adjacencies...");
         for row in 0..rows {
             for col in 0..columns {
                 id = output.get_value(row, col);
@@ -432,17 +429,18 @@ impl WhiteboxTool for RasterizeStreams {
                     }
                 }
             }
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    progress::update(progress);
                     old_progress = progress;
                 }
             }
         }
 
         if !feature_id {
-            println!("Updating output...");
+            progress::message("Updating output...");
             for row in 0..rows {
                 for col in 0..columns {
                     z = output.get_value(row, col);
@@ -451,10 +449,11 @@ impl WhiteboxTool for RasterizeStreams {
                         output.set_value(row, col, 1f64);
                     }
                 }
+                cancel.check()?;
                 if verbose {
                     progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Progress: {}%", progress);
+                        progress::update(progress);
                         old_progress = progress;
                     }
                 }
@@ -462,9 +461,9 @@ impl WhiteboxTool for RasterizeStreams {
         }
 
         if verbose {
-            println!("Number of stream cells: {}", num_stream_cells);
-            println!("Number of stream collisions: {}", num_link_collisions);
-            println!("Number of stream adjacencies: {}", num_adjacencies);
+            progress::message(format!("Number of stream cells: {}", num_stream_cells));
+            progress::message(format!("Number of stream collisions: {}", num_link_collisions));
+            progress::message(format!("Number of stream adjacencies: {}", num_adjacencies));
         }
 
         let elapsed_time = get_formatted_elapsed_time(start);
@@ -479,21 +478,18 @@ impl WhiteboxTool for RasterizeStreams {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -8,6 +8,7 @@ License: MIT
 
 use whitebox_raster::*;
 use whitebox_common::structures::Array2D;
+use whitebox_common::progress;
 use crate::tools::*;
 use num_cpus;
 use std::env;
@@ -16,7 +17,6 @@ use std::io::{Error, ErrorKind};
 use std::path;
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::thread;
 
 /// This tool characterizes the spatial distribution of the average normal vector angular deviation, a measure of
 /// surface roughness. Working in the field of 3D printing, Ko et al. (2016) defined a measure of surface roughness
@@ -57,7 +57,7 @@ impl AverageNormalVectorAngularDeviation {
         let mut parameters = vec![];
         parameters.push(ToolParameter {
             name: "Input DEM File".to_owned(),
-            flags: vec!["-i".to_owned(), "--dem".to_owned()],
+            flags: vec!["-i".to_owned(), "--input".to_owned(), "--dem".to_owned()],
             description: "Input raster DEM file.".to_owned(),
             parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
             default_value: None,
@@ -66,7 +66,7 @@ impl AverageNormalVectorAngularDeviation {
 
         parameters.push(ToolParameter {
             name: "Output Raster File".to_owned(),
-            flags: vec!["--output".to_owned()],
+            flags: vec!["-o".to_owned(), "--output".to_owned()],
             description: "Output raster file.".to_owned(),
             parameter_type: ParameterType::NewFile(ParameterFileType::Raster),
             default_value: None,
@@ -95,7 +95,7 @@ impl AverageNormalVectorAngularDeviation {
         if e.contains(".exe") {
             short_exe += ".exe";
         }
-        let usage = format!(">>.*{} -r={} -v --wd=\"*path*to*data*\" --dem=DEM.tif --out_mag=roughness_mag.tif --out_scale=roughness_scale.tif --min_scale=1 --max_scale=1000 --step=5", short_exe, name).replace("*", &sep);
+        let usage = format!(">>.*{} -r={} -v --wd=\"*path*to*data*\" --dem=DEM.tif -o=output.tif --filter=11", short_exe, name).replace("*", &sep);
 
         AverageNormalVectorAngularDeviation {
             name: name,
@@ -147,6 +147,7 @@ impl WhiteboxTool for AverageNormalVectorAngularDeviation {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut output_file = String::new();
@@ -196,13 +197,7 @@ impl WhiteboxTool for AverageNormalVectorAngularDeviation {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -235,7 +230,7 @@ impl WhiteboxTool for AverageNormalVectorAngularDeviation {
         }
 
         if verbose {
-            println!("Reading data...")
+            progress::message("Reading data...")
         };
 
         let input = Arc::new(Raster::new(&input_file, "r")?);
@@ -251,7 +246,7 @@ impl WhiteboxTool for AverageNormalVectorAngularDeviation {
         // Smooth the DEM
         let mut smoothed_dem = input.get_data_as_array2d();
         if verbose {
-            println!("Smoothing the input DEM...");
+            progress::message("Smoothing the input DEM...");
         }
         let mut sigma = (midpoint as f64 + 0.5) / 3f64;
         if sigma < 1.0 {
@@ -322,7 +317,7 @@ impl WhiteboxTool for AverageNormalVectorAngularDeviation {
                 let d_y = d_y.clone();
                 let weights = weights.clone();
                 let tx1 = tx.clone();
-                thread::spawn(move || {
+                progress::spawn(move || {
                     let (mut sum, mut z_final): (f64, f64);
                     let mut z: f64;
                     let mut zn: f64;
@@ -349,13 +344,15 @@ impl WhiteboxTool for AverageNormalVectorAngularDeviation {
                             }
                         }
 
-                        tx1.send((row, data)).unwrap();
+                        if tx1.send((row, data)).is_err() {
+                            return;
+                        }
                     }
                 });
             }
 
             for _ in 0..rows {
-                let data = rx.recv().expect("Error receiving data from thread.");
+                let data = cancel.recv(&rx)?;
                 smoothed_dem.set_row_data(data.0, data.1);
             }
         } else {
@@ -387,7 +384,7 @@ impl WhiteboxTool for AverageNormalVectorAngularDeviation {
 
             for iteration_num in 0..n {
                 if verbose {
-                    println!("Loop {} of {}", iteration_num + 1, n);
+                    progress::message(format!("Loop {} of {}", iteration_num + 1, n));
                 }
 
                 let midpoint = if iteration_num <= m {
@@ -508,7 +505,7 @@ impl WhiteboxTool for AverageNormalVectorAngularDeviation {
             let input = input.clone();
             let smoothed_dem = smoothed_dem.clone();
             let tx = tx.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let dx = [1, 1, 1, 0, -1, -1, -1, 0];
                 let dy = [-1, 0, 1, 1, 1, 0, -1, -1];
                 let mut n1: [f64; 8] = [0.0; 8];
@@ -554,7 +551,9 @@ impl WhiteboxTool for AverageNormalVectorAngularDeviation {
                             .to_degrees();
                         }
                     }
-                    tx.send((row, data)).unwrap();
+                    if tx.send((row, data)).is_err() {
+                        return;
+                    }
                 }
             });
         }
@@ -563,13 +562,13 @@ impl WhiteboxTool for AverageNormalVectorAngularDeviation {
         // let mut output = Raster::initialize_using_config(&output_file, &configs);
         // output.configs.data_type = DataType::F32;
         for row in 0..rows {
-            let data = rx.recv().expect("Error receiving data from thread.");
+            let data = cancel.recv(&rx)?;
             angular_diff.set_row_data(data.0, data.1);
             // output.set_row_data(data.0, angular_diff.get_row_data(data.0));
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Calculating angular deviation data: {}%", progress);
+                    progress::update_stage("Calculating angular deviation data", progress);
                     old_progress = progress;
                 }
             }
@@ -606,10 +605,11 @@ impl WhiteboxTool for AverageNormalVectorAngularDeviation {
                     }
                 }
             }
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Creating integral images: {}%", progress);
+                    progress::update_stage("Creating integral images", progress);
                     old_progress = progress;
                 }
             }
@@ -623,7 +623,7 @@ impl WhiteboxTool for AverageNormalVectorAngularDeviation {
             let smoothed_dem = smoothed_dem.clone();
             let i_n = i_n.clone();
             let tx2 = tx2.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let (mut x1, mut x2, mut y1, mut y2): (isize, isize, isize, isize);
                 let mut n: f64;
                 let mut sum: f64;
@@ -680,10 +680,7 @@ impl WhiteboxTool for AverageNormalVectorAngularDeviation {
                     match tx2.send((row, data)) {
                         Ok(_) => {}
                         Err(_) => {
-                            println!(
-                                "Error sending data from thread {} processing row {}.",
-                                tid, row
-                            );
+                            progress::message(format!("Error sending data from thread {} processing row {}.", tid, row));
                         }
                     }
                 }
@@ -710,10 +707,11 @@ impl WhiteboxTool for AverageNormalVectorAngularDeviation {
                 }
             }
 
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Performing analysis: {}%", progress);
+                    progress::update_stage("Performing analysis", progress);
                     old_progress = progress;
                 }
             }
@@ -730,22 +728,19 @@ impl WhiteboxTool for AverageNormalVectorAngularDeviation {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
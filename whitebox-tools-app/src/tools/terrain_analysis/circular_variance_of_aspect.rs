@@ -8,6 +8,7 @@ License: MIT
 
 use whitebox_raster::*;
 use whitebox_common::structures::Array2D;
+use whitebox_common::progress;
 use crate::tools::*;
 use num_cpus;
 use std::env;
@@ -16,7 +17,6 @@ use std::io::{Error, ErrorKind};
 use std::path;
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::thread;
 
 /// This tool can be used to calculate the circular variance (i.e. one minus the mean resultant length) of aspect
 /// for an input digital elevation model (DEM). This is a measure of how variable slope aspect is within a local
@@ -64,7 +64,7 @@ impl CircularVarianceOfAspect {
         let mut parameters = vec![];
         parameters.push(ToolParameter {
             name: "Input DEM File".to_owned(),
-            flags: vec!["-i".to_owned(), "--dem".to_owned()],
+            flags: vec!["-i".to_owned(), "--input".to_owned(), "--dem".to_owned()],
             description: "Input raster DEM file.".to_owned(),
             parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
             default_value: None,
@@ -73,7 +73,7 @@ impl CircularVarianceOfAspect {
 
         parameters.push(ToolParameter {
             name: "Output Raster File".to_owned(),
-            flags: vec!["--output".to_owned()],
+            flags: vec!["-o".to_owned(), "--output".to_owned()],
             description: "Output raster file.".to_owned(),
             parameter_type: ParameterType::NewFile(ParameterFileType::Raster),
             default_value: None,
@@ -102,7 +102,7 @@ impl CircularVarianceOfAspect {
         if e.contains(".exe") {
             short_exe += ".exe";
         }
-        let usage = format!(">>.*{} -r={} -v --wd=\"*path*to*data*\" --dem=DEM.tif --out_mag=roughness_mag.tif --out_scale=roughness_scale.tif --min_scale=1 --max_scale=1000 --step=5", short_exe, name).replace("*", &sep);
+        let usage = format!(">>.*{} -r={} -v --wd=\"*path*to*data*\" --dem=DEM.tif -o=output.tif --filter=11", short_exe, name).replace("*", &sep);
 
         CircularVarianceOfAspect {
             name: name,
@@ -154,6 +154,7 @@ impl WhiteboxTool for CircularVarianceOfAspect {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut output_file = String::new();
@@ -203,13 +204,7 @@ impl WhiteboxTool for CircularVarianceOfAspect {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -242,7 +237,7 @@ impl WhiteboxTool for CircularVarianceOfAspect {
         }
 
         if verbose {
-            println!("Reading data...")
+            progress::message("Reading data...")
         };
 
         let input = Arc::new(Raster::new(&input_file, "r")?);
@@ -257,7 +252,7 @@ impl WhiteboxTool for CircularVarianceOfAspect {
         // Smooth the DEM
         let mut smoothed_dem = input.get_data_as_array2d();
         if verbose {
-            println!("Smoothing the input DEM...");
+            progress::message("Smoothing the input DEM...");
         }
         let sigma = (midpoint as f64 + 0.5) / 3f64;
         if sigma < 1.8 && filter_size > 3 {
@@ -324,7 +319,7 @@ impl WhiteboxTool for CircularVarianceOfAspect {
                 let d_y = d_y.clone();
                 let weights = weights.clone();
                 let tx1 = tx.clone();
-                thread::spawn(move || {
+                progress::spawn(move || {
                     let (mut sum, mut z_final): (f64, f64);
                     let mut z: f64;
                     let mut zn: f64;
@@ -349,13 +344,15 @@ impl WhiteboxTool for CircularVarianceOfAspect {
                             }
                         }
 
-                        tx1.send((row, data)).unwrap();
+                        if tx1.send((row, data)).is_err() {
+                            return;
+                        }
                     }
                 });
             }
 
             for _ in 0..rows {
-                let data = rx.recv().expect("Error receiving data from thread.");
+                let data = cancel.recv(&rx)?;
                 smoothed_dem.set_row_data(data.0, data.1);
             }
         } else if filter_size > 3 {
@@ -387,7 +384,7 @@ impl WhiteboxTool for CircularVarianceOfAspect {
 
             for iteration_num in 0..n {
                 if verbose {
-                    println!("Loop {} of {}", iteration_num + 1, n);
+                    progress::message(format!("Loop {} of {}", iteration_num + 1, n));
                 }
 
                 let midpoint = if iteration_num <= m {
@@ -508,7 +505,7 @@ impl WhiteboxTool for CircularVarianceOfAspect {
         for tid in 0..num_procs {
             let smoothed_dem = smoothed_dem.clone();
             let tx = tx.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let dx = [1, 1, 1, 0, -1, -1, -1, 0];
                 let dy = [-1, 0, 1, 1, 1, 0, -1, -1];
                 let mut n: [f64; 8] = [0.0; 8];
@@ -541,7 +538,9 @@ impl WhiteboxTool for CircularVarianceOfAspect {
                             }
                         }
                     }
-                    tx.send((row, xdata, ydata)).unwrap();
+                    if tx.send((row, xdata, ydata)).is_err() {
+                        return;
+                    }
                 }
             });
         }
@@ -549,13 +548,13 @@ impl WhiteboxTool for CircularVarianceOfAspect {
         let mut xc: Array2D<f64> = Array2D::new(rows, columns, 0f64, -1f64)?;
         let mut yc: Array2D<f64> = Array2D::new(rows, columns, 0f64, -1f64)?;
         for row in 0..rows {
-            let data = rx.recv().expect("Error receiving data from thread.");
+            let data = cancel.recv(&rx)?;
             xc.set_row_data(data.0, data.1);
             yc.set_row_data(data.0, data.2);
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Calculating aspect data: {}%", progress);
+                    progress::update_stage("Calculating aspect data", progress);
                     old_progress = progress;
                 }
             }
@@ -600,10 +599,11 @@ impl WhiteboxTool for CircularVarianceOfAspect {
                     }
                 }
             }
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Creating integral images: {}%", progress);
+                    progress::update_stage("Creating integral images", progress);
                     old_progress = progress;
                 }
             }
@@ -619,7 +619,7 @@ impl WhiteboxTool for CircularVarianceOfAspect {
             let smoothed_dem = smoothed_dem.clone();
             let i_n = i_n.clone();
             let tx2 = tx2.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let (mut x1, mut x2, mut y1, mut y2): (isize, isize, isize, isize);
                 let mut n: f64;
                 let (mut sumx, mut sumy): (f64, f64);
@@ -682,10 +682,7 @@ impl WhiteboxTool for CircularVarianceOfAspect {
                     match tx2.send((row, data)) {
                         Ok(_) => {}
                         Err(_) => {
-                            println!(
-                                "Error sending data from thread {} processing row {}.",
-                                tid, row
-                            );
+                            progress::message(format!("Error sending data from thread {} processing row {}.", tid, row));
                         }
                     }
                 }
@@ -709,10 +706,11 @@ impl WhiteboxTool for CircularVarianceOfAspect {
                 }
             }
 
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Performing analysis: {}%", progress);
+                    progress::update_stage("Performing analysis", progress);
                     old_progress = progress;
                 }
             }
@@ -729,22 +727,19 @@ impl WhiteboxTool for CircularVarianceOfAspect {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -8,6 +8,7 @@ License: MIT
 
 use whitebox_common::algorithms::triangulate;
 use whitebox_common::structures::Point2D;
+use whitebox_common::progress;
 use crate::tools::*;
 use whitebox_vector::ShapefileGeometry;
 use whitebox_vector::*;
@@ -194,6 +195,7 @@ impl WhiteboxTool for ContoursFromPoints {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file: String = "".to_string();
         let mut field_name = String::new();
@@ -316,13 +318,7 @@ impl WhiteboxTool for ContoursFromPoints {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         if !input_file.contains(path::MAIN_SEPARATOR) && !input_file.contains("/") {
@@ -414,11 +410,12 @@ impl WhiteboxTool for ContoursFromPoints {
                 }
             }
 
+            cancel.check()?;
             if verbose {
                 progress =
                     (100.0_f64 * (record_num + 1) as f64 / input.num_records as f64) as usize;
                 if progress != old_progress {
-                    println!("Reading points: {}%", progress);
+                    progress::update_stage("Reading points", progress);
                     old_progress = progress;
                 }
             }
@@ -433,7 +430,7 @@ impl WhiteboxTool for ContoursFromPoints {
             ));
         }
         if verbose {
-            println!("Performing triangulation...");
+            progress::message("Performing triangulation...");
         }
         // this is where the heavy-lifting is
         let result = triangulate(&points).expect("No triangulation exists.");
@@ -541,7 +538,7 @@ impl WhiteboxTool for ContoursFromPoints {
                     }
 
                     if num_intersections != 2 && verbose {
-                        println!("Warning: An error occurred during the contouring operation.");
+                        progress::warning("An error occurred during the contouring operation.");
                     }
 
                     if intersect2 && intersect3 {
@@ -565,10 +562,11 @@ impl WhiteboxTool for ContoursFromPoints {
                 }
             }
 
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * i as f64 / (result.triangles.len() - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress (Loop 1 of 4): {}%", progress);
+                    progress::update_stage("Progress (Loop 1 of 4)", progress);
                     old_progress = progress;
                 }
             }
@@ -612,10 +610,11 @@ impl WhiteboxTool for ContoursFromPoints {
                 }
             }
                 
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * i as f64 / (num_points - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress (Loop 2 of 4): {}%", progress);
+                    progress::update_stage("Progress (Loop 2 of 4)", progress);
                     old_progress = progress;
                 }
             }
@@ -800,10 +799,11 @@ impl WhiteboxTool for ContoursFromPoints {
                     }
                 }
             }
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * i as f64 / (num_points - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress (Loop 3 of 4): {}%", progress);
+                    progress::update_stage("Progress (Loop 3 of 4)", progress);
                     old_progress = progress;
                 }
             }
@@ -951,22 +951,23 @@ impl WhiteboxTool for ContoursFromPoints {
                     fid += 1;
                 }
             }
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * i as f64 / (num_points - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress (Loop 4 of 4): {}%", progress);
+                    progress::update_stage("Progress (Loop 4 of 4)", progress);
                     old_progress = progress;
                 }
             }
         }
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
@@ -975,7 +976,7 @@ impl WhiteboxTool for ContoursFromPoints {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!("{}", &format!("Elapsed Time: {}", elapsed_time));
+            progress::message(format!("Elapsed Time: {}", elapsed_time));
         }
 
         Ok(())
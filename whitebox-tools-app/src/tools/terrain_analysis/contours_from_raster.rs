@@ -8,6 +8,7 @@ License: MIT
 
 use whitebox_raster::Raster;
 use whitebox_common::structures::{Array2D, Point2D};
+use whitebox_common::progress;
 use crate::tools::*;
 use whitebox_vector::*;
 use std::env;
@@ -20,7 +21,6 @@ use kdtree::KdTree;
 use num_cpus;
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::thread;
 
 /// This tool can be used to create a vector contour coverage from an input raster surface model (`--input`), such as a digital
 /// elevation model (DEM). The user must specify the contour interval (`--interval`) and optionally, the base contour value (`--base`).
@@ -51,7 +51,7 @@ impl ContoursFromRaster {
         let mut parameters = vec![];
         parameters.push(ToolParameter {
             name: "Input Raster Surface File".to_owned(),
-            flags: vec!["-i".to_owned(), "--input".to_owned()],
+            flags: vec!["-i".to_owned(), "--dem".to_owned(), "--input".to_owned()],
             description: "Input surface raster file.".to_owned(),
             parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
             default_value: None,
@@ -176,6 +176,7 @@ impl WhiteboxTool for ContoursFromRaster {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut output_file = String::new();
@@ -285,13 +286,7 @@ impl WhiteboxTool for ContoursFromRaster {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -304,7 +299,7 @@ impl WhiteboxTool for ContoursFromRaster {
         }
 
         if verbose {
-            println!("Reading data...")
+            progress::message("Reading data...")
         };
 
         let input = Arc::new(Raster::new(&input_file, "r").expect("Error reading input raster."));
@@ -356,7 +351,7 @@ impl WhiteboxTool for ContoursFromRaster {
         for tid in 0..num_procs {
             let input = input.clone();
             let tx = tx.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let mut z: f64;
                 for row in (0..rows).filter(|r| r % num_procs == tid) {
                     let mut data: Vec<f64> = vec![nodata; columns as usize];
@@ -366,20 +361,22 @@ impl WhiteboxTool for ContoursFromRaster {
                             data[col as usize] = ((z - base_contour) / contour_interval).floor();
                         }
                     }
-                    tx.send((row, data)).expect("Error sending data to thread.");
+                    if tx.send((row, data)).is_err() {
+                        return;
+                    }
                 }
             });
         }
 
         let mut reclassed: Array2D<f64> = Array2D::new(rows, columns, nodata, nodata)?;
         for r in 0..rows {
-            let (row, data) = rx.recv().expect("Error receiving data from thread.");
+            let (row, data) = cancel.recv(&rx)?;
             reclassed.set_row_data(row, data);
 
             if verbose {
                 progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Reclassifying surface: {}%", progress);
+                    progress::update_stage("Reclassifying surface", progress);
                     old_progress = progress;
                 }
             }
@@ -462,10 +459,11 @@ impl WhiteboxTool for ContoursFromRaster {
                 }
             }
 
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Finding edges: {}%", progress);
+                    progress::update_stage("Finding edges", progress);
                     old_progress = progress;
                 }
             }
@@ -703,11 +701,12 @@ impl WhiteboxTool for ContoursFromRaster {
                     }
                 }
             }
+            cancel.check()?;
             if verbose {
                 progress =
                     (100.0_f64 * line_segment as f64 / (line_segments.len() - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Tracing contours (Loop 1 of 2): {}%", progress);
+                    progress::update_stage("Tracing contours (Loop 1 of 2)", progress);
                     old_progress = progress;
                 }
             }
@@ -907,11 +906,12 @@ impl WhiteboxTool for ContoursFromRaster {
                     }
                 }
             }
+            cancel.check()?;
             if verbose {
                 progress =
                     (100.0_f64 * line_segment as f64 / (line_segments.len() - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Tracing contours (Loop 2 of 2): {}%", progress);
+                    progress::update_stage("Tracing contours (Loop 2 of 2)", progress);
                     old_progress = progress;
                 }
             }
@@ -922,21 +922,18 @@ impl WhiteboxTool for ContoursFromRaster {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
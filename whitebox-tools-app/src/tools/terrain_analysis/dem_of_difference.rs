@@ -0,0 +1,839 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: Dr. John Lindsay
+Created: 18/10/2026
+Last Modified: 18/10/2026
+License: MIT
+*/
+
+use whitebox_raster::*;
+use whitebox_common::structures::Array2D;
+use whitebox_common::rendering::html::*;
+use whitebox_common::rendering::Histogram;
+use whitebox_common::progress;
+use crate::tools::*;
+use statrs::distribution::{ContinuousCDF, Normal};
+use std::env;
+use std::f64;
+use std::fs::File;
+use std::io::prelude::*;
+use std::io::BufWriter;
+use std::io::{Error, ErrorKind};
+use std::path;
+use std::process::Command;
+
+/// This tool creates a DEM of difference (DoD) from two digital elevation models of the same area,
+/// surveyed at different times, while accounting for the uncertainty in each survey (Wheaton et al., 2010).
+/// The user must specify the earlier (`--dem1`) and later (`--dem2`) DEMs, which must share the same grid,
+/// and the elevation uncertainty (standard deviation of elevation error) of each survey (`--error1` and
+/// `--error2`). Uncertainties may be specified either as per-cell rasters, e.g. derived from point density,
+/// roughness, or survey precision, or as global constant values. The elevation change is calculated as
+/// *DoD* = *DEM2* - *DEM1*, such that negative values indicate erosion and positive values deposition.
+///
+/// The survey errors are propagated into the DoD as:
+///
+/// > &sigma;<sub>DoD</sub> = (&sigma;<sub>1</sub><sup>2</sup> + &sigma;<sub>2</sub><sup>2</sup>)<sup>0.5</sup>
+///
+/// and the probability that the change in a cell is real, rather than the result of survey error, is
+/// estimated from the *t*-score, |*DoD*| / &sigma;<sub>DoD</sub>, using the normal distribution. A cell is
+/// considered to have detectably changed if this probability is at least equal to the confidence level
+/// (`--confidence`; default 0.95) and its absolute elevation change is at least equal to the minimum
+/// level of detection (`--min_lod`). Cells with undetectable change are assigned zero in the output DoD
+/// raster (`--output`).
+///
+/// When the `--spatial_coherence` flag is specified, the probability of change is updated using Bayes'
+/// theorem and the spatial coherence of erosion and deposition, based on the observation that real
+/// change tends to occur in spatially contiguous patches while error-driven change is more spatially
+/// random. The proportion of cells within a square neighbourhood (`--filter`; default 5) that share the
+/// sign of the change in the centre cell is transformed into a conditional probability that increases
+/// linearly from 0.05, where 20% or fewer of neighbours agree, through 0.5, where half agree (as expected
+/// of random error), to 0.95, where 80% or more agree. The posterior probability of change may optionally
+/// be output (`--output_prob`).
+///
+/// The tool also outputs an HTML report (`--output_html`) of the areas and volumes of erosion and
+/// deposition, both before and after thresholding, and the net volume of change. Volume uncertainties are
+/// reported at the specified confidence level assuming spatially independent errors, along with the more
+/// conservative error bound that assumes fully correlated errors (i.e. the sum of &sigma;<sub>DoD</sub> times
+/// the cell area over the changed cells).
+///
+/// # Reference
+/// Wheaton, J. M., Brasington, J., Darby, S. E., & Sear, D. A. (2010). Accounting for uncertainty in DEMs
+/// from repeat topographic surveys: improved sediment budgets. Earth Surface Processes and Landforms,
+/// 35(2), 136-156.
+///
+/// Lane, S. N., Westaway, R. M., & Murray Hicks, D. (2003). Estimation of erosion and deposition volumes in
+/// a large, gravel-bed, braided river using synoptic remote sensing. Earth Surface Processes and Landforms,
+/// 28(3), 249-271.
+///
+/// # See Also
+/// `Subtract`, `RasterHistogram`
+pub struct DemOfDifference {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl DemOfDifference {
+    pub fn new() -> DemOfDifference {
+        // public constructor
+        let name = "DemOfDifference".to_string();
+        let toolbox = "Geomorphometric Analysis".to_string();
+        let description =
+            "Calculates a thresholded DEM of difference from two DEMs and their survey uncertainties."
+                .to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input Earlier DEM File".to_owned(),
+            flags: vec!["--dem1".to_owned()],
+            description: "Input raster DEM file of the earlier survey.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Input Later DEM File".to_owned(),
+            flags: vec!["--dem2".to_owned()],
+            description: "Input raster DEM file of the later survey.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Earlier DEM Uncertainty (File or Constant)".to_owned(),
+            flags: vec!["--error1".to_owned()],
+            description: "Elevation uncertainty (standard deviation) of the earlier DEM, as a raster or constant value.".to_owned(),
+            parameter_type: ParameterType::ExistingFileOrFloat(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Later DEM Uncertainty (File or Constant)".to_owned(),
+            flags: vec!["--error2".to_owned()],
+            description: "Elevation uncertainty (standard deviation) of the later DEM, as a raster or constant value.".to_owned(),
+            parameter_type: ParameterType::ExistingFileOrFloat(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output File".to_owned(),
+            flags: vec!["-o".to_owned(), "--output".to_owned()],
+            description: "Output thresholded DEM of difference raster file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output HTML Report File".to_owned(),
+            flags: vec!["--output_html".to_owned()],
+            description: "Output HTML report file of erosion and deposition volumes.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Html),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output Probability File (optional)".to_owned(),
+            flags: vec!["--output_prob".to_owned()],
+            description: "Optional output raster file of the probability of change.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Minimum Level of Detection".to_owned(),
+            flags: vec!["--min_lod".to_owned()],
+            description: "Minimum level of detection, i.e. the smallest absolute elevation change considered real.".to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: Some("0.0".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Confidence Level".to_owned(),
+            flags: vec!["--confidence".to_owned()],
+            description: "Confidence level (0.5-0.999) used to threshold the change and report volume uncertainties.".to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: Some("0.95".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Use Bayesian spatial coherence?".to_owned(),
+            flags: vec!["--spatial_coherence".to_owned()],
+            description: "Update the probability of change using the spatial coherence of erosion and deposition.".to_owned(),
+            parameter_type: ParameterType::Boolean,
+            default_value: Some("false".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Spatial Coherence Filter Size".to_owned(),
+            flags: vec!["--filter".to_owned()],
+            description: "Size of the neighbourhood used to measure spatial coherence, in grid cells.".to_owned(),
+            parameter_type: ParameterType::Integer,
+            default_value: Some("5".to_owned()),
+            optional: true,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut parent = env::current_exe().unwrap();
+        parent.pop();
+        let p = format!("{}", parent.display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(">>.*{0} -r={1} -v --wd=\"*path*to*data*\" --dem1=dem2015.tif --dem2=dem2020.tif --error1=0.08 --error2=error2020.tif -o=dod.tif --output_html=dod_report.html --min_lod=0.1 --confidence=0.95 --spatial_coherence", short_exe, name).replace("*", &sep);
+
+        DemOfDifference {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for DemOfDifference {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        match serde_json::to_string(&self.parameters) {
+            Ok(json_str) => return format!("{{\"parameters\":{}}}", json_str),
+            Err(err) => return format!("{:?}", err),
+        }
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+        cancel: &CancellationToken,
+    ) -> Result<(), Error> {
+        let mut dem1_file = String::new();
+        let mut dem2_file = String::new();
+        let mut error1 = String::new();
+        let mut error2 = String::new();
+        let mut output_file = String::new();
+        let mut html_file = String::new();
+        let mut prob_file = String::new();
+        let mut min_lod = 0f64;
+        let mut confidence = 0.95f64;
+        let mut spatial_coherence = false;
+        let mut filter_size = 5isize;
+
+        if args.len() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Tool run with no parameters.",
+            ));
+        }
+        for i in 0..args.len() {
+            let mut arg = args[i].replace("\"", "");
+            arg = arg.replace("\'", "");
+            let cmd = arg.split("="); // in case an equals sign was used
+            let vec = cmd.collect::<Vec<&str>>();
+            let mut keyval = false;
+            if vec.len() > 1 {
+                keyval = true;
+            }
+            let flag_val = vec[0].to_lowercase().replace("--", "-");
+            if flag_val == "-dem1" {
+                dem1_file = if keyval {
+                    vec[1].to_string()
+                } else {
+                    args[i + 1].to_string()
+                };
+            } else if flag_val == "-dem2" {
+                dem2_file = if keyval {
+                    vec[1].to_string()
+                } else {
+                    args[i + 1].to_string()
+                };
+            } else if flag_val == "-error1" {
+                error1 = if keyval {
+                    vec[1].to_string()
+                } else {
+                    args[i + 1].to_string()
+                };
+            } else if flag_val == "-error2" {
+                error2 = if keyval {
+                    vec[1].to_string()
+                } else {
+                    args[i + 1].to_string()
+                };
+            } else if flag_val == "-o" || flag_val == "-output" {
+                output_file = if keyval {
+                    vec[1].to_string()
+                } else {
+                    args[i + 1].to_string()
+                };
+            } else if flag_val == "-output_html" {
+                html_file = if keyval {
+                    vec[1].to_string()
+                } else {
+                    args[i + 1].to_string()
+                };
+            } else if flag_val == "-output_prob" {
+                prob_file = if keyval {
+                    vec[1].to_string()
+                } else {
+                    args[i + 1].to_string()
+                };
+            } else if flag_val == "-min_lod" {
+                min_lod = if keyval {
+                    vec[1]
+                        .to_string()
+                        .parse::<f64>()
+                        .expect(&format!("Error parsing {}", flag_val))
+                } else {
+                    args[i + 1]
+                        .to_string()
+                        .parse::<f64>()
+                        .expect(&format!("Error parsing {}", flag_val))
+                };
+            } else if flag_val == "-confidence" {
+                confidence = if keyval {
+                    vec[1]
+                        .to_string()
+                        .parse::<f64>()
+                        .expect(&format!("Error parsing {}", flag_val))
+                } else {
+                    args[i + 1]
+                        .to_string()
+                        .parse::<f64>()
+                        .expect(&format!("Error parsing {}", flag_val))
+                };
+            } else if flag_val == "-spatial_coherence" {
+                if vec.len() == 1 || !vec[1].to_string().to_lowercase().contains("false") {
+                    spatial_coherence = true;
+                }
+            } else if flag_val == "-filter" {
+                filter_size = if keyval {
+                    vec[1]
+                        .to_string()
+                        .parse::<f32>()
+                        .expect(&format!("Error parsing {}", flag_val)) as isize
+                } else {
+                    args[i + 1]
+                        .to_string()
+                        .parse::<f32>()
+                        .expect(&format!("Error parsing {}", flag_val)) as isize
+                };
+            }
+        }
+
+        if verbose {
+            let tool_name = self.get_tool_name();
+            progress::welcome(&tool_name);
+        }
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+
+        let mut progress: usize;
+        let mut old_progress: usize = 1;
+
+        if !dem1_file.contains(&sep) && !dem1_file.contains("/") {
+            dem1_file = format!("{}{}", working_directory, dem1_file);
+        }
+        if !dem2_file.contains(&sep) && !dem2_file.contains("/") {
+            dem2_file = format!("{}{}", working_directory, dem2_file);
+        }
+        if !output_file.contains(&sep) && !output_file.contains("/") {
+            output_file = format!("{}{}", working_directory, output_file);
+        }
+        if !html_file.contains(&sep) && !html_file.contains("/") {
+            html_file = format!("{}{}", working_directory, html_file);
+        }
+        if !prob_file.is_empty() && !prob_file.contains(&sep) && !prob_file.contains("/") {
+            prob_file = format!("{}{}", working_directory, prob_file);
+        }
+
+        if confidence < 0.5 || confidence >= 1.0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The confidence level (--confidence) must be between 0.5 and 1.0.",
+            ));
+        }
+        if min_lod < 0f64 {
+            min_lod = 0f64;
+        }
+        if filter_size < 3 {
+            filter_size = 3;
+        }
+        if filter_size % 2 == 0 {
+            filter_size += 1;
+        }
+        let midpoint = filter_size / 2;
+
+        // Are either of the uncertainties constants?
+        let mut error1_constant = 0f64;
+        let error1_is_constant = match error1.parse::<f64>() {
+            Ok(val) => {
+                error1_constant = val;
+                true
+            }
+            Err(_) => false,
+        };
+        if !error1_is_constant && !error1.contains(&sep) && !error1.contains("/") {
+            error1 = format!("{}{}", working_directory, error1);
+        }
+        let mut error2_constant = 0f64;
+        let error2_is_constant = match error2.parse::<f64>() {
+            Ok(val) => {
+                error2_constant = val;
+                true
+            }
+            Err(_) => false,
+        };
+        if !error2_is_constant && !error2.contains(&sep) && !error2.contains("/") {
+            error2 = format!("{}{}", working_directory, error2);
+        }
+
+        if verbose {
+            progress::message("Reading data...")
+        };
+        let dem1 = Raster::new(&dem1_file, "r")?;
+        let dem2 = Raster::new(&dem2_file, "r")?;
+        if dem1.configs.rows != dem2.configs.rows || dem1.configs.columns != dem2.configs.columns {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The input files must have the same number of rows and columns and spatial extent.",
+            ));
+        }
+        let err1 = if !error1_is_constant {
+            let r = Raster::new(&error1, "r")?;
+            if r.configs.rows != dem1.configs.rows || r.configs.columns != dem1.configs.columns {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    "The input files must have the same number of rows and columns and spatial extent.",
+                ));
+            }
+            Some(r)
+        } else {
+            None
+        };
+        let err2 = if !error2_is_constant {
+            let r = Raster::new(&error2, "r")?;
+            if r.configs.rows != dem1.configs.rows || r.configs.columns != dem1.configs.columns {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    "The input files must have the same number of rows and columns and spatial extent.",
+                ));
+            }
+            Some(r)
+        } else {
+            None
+        };
+
+        let start = Instant::now();
+
+        let rows = dem1.configs.rows as isize;
+        let columns = dem1.configs.columns as isize;
+        let nodata1 = dem1.configs.nodata;
+        let nodata2 = dem2.configs.nodata;
+        let nodata = nodata1;
+        let cell_area = dem1.configs.resolution_x * dem1.configs.resolution_y;
+
+        // Calculate the raw elevation change and the propagated error.
+        let mut dod = Raster::initialize_using_file(&output_file, &dem1);
+        dod.configs.data_type = DataType::F32;
+        let mut sigma: Array2D<f64> = Array2D::new(rows, columns, nodata, nodata)?;
+        let (mut z1, mut z2, mut e1, mut e2): (f64, f64, f64, f64);
+        for row in 0..rows {
+            for col in 0..columns {
+                z1 = dem1.get_value(row, col);
+                z2 = dem2.get_value(row, col);
+                e1 = match &err1 {
+                    Some(r) => {
+                        let v = r.get_value(row, col);
+                        if v != r.configs.nodata { v } else { f64::NAN }
+                    }
+                    None => error1_constant,
+                };
+                e2 = match &err2 {
+                    Some(r) => {
+                        let v = r.get_value(row, col);
+                        if v != r.configs.nodata { v } else { f64::NAN }
+                    }
+                    None => error2_constant,
+                };
+                if z1 != nodata1 && z2 != nodata2 && !e1.is_nan() && !e2.is_nan() {
+                    dod.set_value(row, col, z2 - z1);
+                    sigma.set_value(row, col, (e1 * e1 + e2 * e2).sqrt());
+                } else {
+                    dod.set_value(row, col, nodata);
+                }
+            }
+            cancel.check()?;
+            if verbose {
+                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+                if progress != old_progress {
+                    progress::update_stage("Calculating elevation change", progress);
+                    old_progress = progress;
+                }
+            }
+        }
+        drop(dem1);
+        drop(dem2);
+        drop(err1);
+        drop(err2);
+
+        // Probability that each change is real, and the thresholded DoD.
+        let distribution = Normal::new(0.0, 1.0).unwrap();
+        let z_crit = distribution.inverse_cdf(0.5 + confidence / 2f64);
+        let mut prob: Array2D<f64> = Array2D::new(rows, columns, nodata, nodata)?;
+        let mut output: Array2D<f64> = Array2D::new(rows, columns, nodata, nodata)?;
+        let num_neighbours = (filter_size * filter_size - 1) as f64;
+        let (mut z, mut zn, mut s): (f64, f64, f64);
+        let mut p: f64;
+        let mut stats = VolumeStats::default();
+        let mut changes = vec![];
+        for row in 0..rows {
+            for col in 0..columns {
+                z = dod.get_value(row, col);
+                if z == nodata {
+                    continue;
+                }
+                s = sigma.get_value(row, col);
+                p = if s > 0f64 {
+                    2f64 * distribution.cdf(z.abs() / s) - 1f64
+                } else if z != 0f64 {
+                    1f64
+                } else {
+                    0f64
+                };
+                if spatial_coherence && z != 0f64 {
+                    let mut num_same = 0f64;
+                    for y in -midpoint..=midpoint {
+                        for x in -midpoint..=midpoint {
+                            if x == 0 && y == 0 {
+                                continue;
+                            }
+                            zn = dod.get_value(row + y, col + x);
+                            if zn != nodata && zn * z > 0f64 {
+                                num_same += 1f64;
+                            }
+                        }
+                    }
+                    let ratio = num_same / num_neighbours;
+                    let p_spatial = 0.5 + 0.45 * ((ratio - 0.5) / 0.3).max(-1f64).min(1f64);
+                    let denom = p * p_spatial + (1f64 - p) * (1f64 - p_spatial);
+                    if denom > 0f64 {
+                        p = p * p_spatial / denom;
+                    }
+                }
+                prob.set_value(row, col, p);
+
+                stats.add_raw(z, cell_area);
+                if z.abs() >= min_lod && p >= confidence && z != 0f64 {
+                    output.set_value(row, col, z);
+                    stats.add_detected(z, s, cell_area);
+                    changes.push(z);
+                } else {
+                    output.set_value(row, col, 0f64);
+                }
+            }
+            cancel.check()?;
+            if verbose {
+                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+                if progress != old_progress {
+                    progress::update_stage("Thresholding", progress);
+                    old_progress = progress;
+                }
+            }
+        }
+        drop(sigma);
+
+        for row in 0..rows {
+            dod.set_row_data(row, output.get_row_data(row));
+        }
+        drop(output);
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+        dod.configs.palette = "blue_white_red.plt".to_string();
+        dod.add_metadata_entry(format!(
+            "Created by whitebox_tools\' {} tool",
+            self.get_tool_name()
+        ));
+        dod.add_metadata_entry(format!("Earlier DEM: {}", dem1_file));
+        dod.add_metadata_entry(format!("Later DEM: {}", dem2_file));
+        dod.add_metadata_entry(format!("Earlier DEM uncertainty: {}", error1));
+        dod.add_metadata_entry(format!("Later DEM uncertainty: {}", error2));
+        dod.add_metadata_entry(format!("Minimum level of detection: {}", min_lod));
+        dod.add_metadata_entry(format!("Confidence level: {}", confidence));
+        dod.add_metadata_entry(format!("Spatial coherence: {}", spatial_coherence));
+        dod.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
+
+        if verbose {
+            progress::message("Saving data...")
+        };
+        let _ = match dod.write() {
+            Ok(_) => {
+                if verbose {
+                    progress::output_written(&dod.file_name)
+                }
+            }
+            Err(e) => return Err(e),
+        };
+
+        if !prob_file.is_empty() {
+            let mut output = Raster::initialize_using_file(&prob_file, &dod);
+            output.configs.data_type = DataType::F32;
+            output.configs.palette = "spectrum.plt".to_string();
+            for row in 0..rows {
+                output.set_row_data(row, prob.get_row_data(row));
+            }
+            output.add_metadata_entry(format!(
+                "Created by whitebox_tools\' {} tool",
+                self.get_tool_name()
+            ));
+            let _ = match output.write() {
+                Ok(_) => {
+                    if verbose {
+                        progress::message("Probability file written")
+                    }
+                }
+                Err(e) => return Err(e),
+            };
+        }
+        drop(dod);
+
+        // Now write the report.
+        let f = File::create(html_file.clone())?;
+        let mut writer = BufWriter::new(f);
+
+        writer.write_all(&r#"<!DOCTYPE html PUBLIC \"-//W3C//DTD XHTML 1.0 Transitional//EN\" \"http://www.w3.org/TR/xhtml1/DTD/xhtml1-transitional.dtd\">
+        <head>
+            <meta content=\"text/html; charset=UTF-8\" http-equiv=\"content-type\">
+            <title>DEM of Difference</title>"#.as_bytes())?;
+
+        // get the style sheet
+        writer.write_all(&get_css().as_bytes())?;
+
+        writer.write_all(
+            &r#"</head>
+        <body>
+            <h1>DEM of Difference Report</h1>"#
+                .as_bytes(),
+        )?;
+
+        writer.write_all(&format!("<p><strong>Earlier DEM</strong>: {}<br>", dem1_file).as_bytes())?;
+        writer.write_all(&format!("<strong>Later DEM</strong>: {}<br>", dem2_file).as_bytes())?;
+        writer.write_all(&format!("<strong>Earlier DEM uncertainty</strong>: {}<br>", error1).as_bytes())?;
+        writer.write_all(&format!("<strong>Later DEM uncertainty</strong>: {}<br>", error2).as_bytes())?;
+        writer.write_all(&format!("<strong>Minimum level of detection</strong>: {}<br>", min_lod).as_bytes())?;
+        writer.write_all(&format!("<strong>Confidence level</strong>: {}<br>", confidence).as_bytes())?;
+        writer.write_all(&format!("<strong>Spatial coherence</strong>: {}</p>", spatial_coherence).as_bytes())?;
+
+        let pct = (confidence * 100f64).round();
+        writer.write_all("<div><table align=\"center\">".as_bytes())?;
+        writer.write_all(&format!("<caption>Areas and Volumes of Change</caption>").as_bytes())?;
+        writer.write_all(
+            &format!(
+                "<tr><th></th><th>Raw</th><th>Thresholded</th><th>&plusmn; {}% CI (independent errors)</th><th>&plusmn; Correlated errors</th></tr>",
+                pct
+            )
+            .as_bytes(),
+        )?;
+        writer.write_all(
+            &format!(
+                "<tr><td>Area of erosion</td><td class=\"numberCell\">{:.3}</td><td class=\"numberCell\">{:.3}</td><td></td><td></td></tr>",
+                stats.raw_erosion_area, stats.erosion_area
+            )
+            .as_bytes(),
+        )?;
+        writer.write_all(
+            &format!(
+                "<tr><td>Area of deposition</td><td class=\"numberCell\">{:.3}</td><td class=\"numberCell\">{:.3}</td><td></td><td></td></tr>",
+                stats.raw_deposition_area, stats.deposition_area
+            )
+            .as_bytes(),
+        )?;
+        writer.write_all(
+            &format!(
+                "<tr><td>Volume of erosion</td><td class=\"numberCell\">{:.3}</td><td class=\"numberCell\">{:.3}</td><td class=\"numberCell\">{:.3}</td><td class=\"numberCell\">{:.3}</td></tr>",
+                stats.raw_erosion_volume,
+                stats.erosion_volume,
+                z_crit * stats.erosion_var.sqrt(),
+                stats.erosion_err
+            )
+            .as_bytes(),
+        )?;
+        writer.write_all(
+            &format!(
+                "<tr><td>Volume of deposition</td><td class=\"numberCell\">{:.3}</td><td class=\"numberCell\">{:.3}</td><td class=\"numberCell\">{:.3}</td><td class=\"numberCell\">{:.3}</td></tr>",
+                stats.raw_deposition_volume,
+                stats.deposition_volume,
+                z_crit * stats.deposition_var.sqrt(),
+                stats.deposition_err
+            )
+            .as_bytes(),
+        )?;
+        writer.write_all(
+            &format!(
+                "<tr><td>Net volume change</td><td class=\"numberCell\">{:.3}</td><td class=\"numberCell\">{:.3}</td><td class=\"numberCell\">{:.3}</td><td class=\"numberCell\">{:.3}</td></tr>",
+                stats.raw_deposition_volume - stats.raw_erosion_volume,
+                stats.deposition_volume - stats.erosion_volume,
+                z_crit * (stats.erosion_var + stats.deposition_var).sqrt(),
+                stats.erosion_err + stats.deposition_err
+            )
+            .as_bytes(),
+        )?;
+        writer.write_all("</table></div>".as_bytes())?;
+
+        if stats.raw_erosion_volume + stats.raw_deposition_volume > 0f64 {
+            writer.write_all(
+                &format!(
+                    "<p>Percent of the raw volume of change that is detectable: {:.2}%</p>",
+                    100f64 * (stats.erosion_volume + stats.deposition_volume)
+                        / (stats.raw_erosion_volume + stats.raw_deposition_volume)
+                )
+                .as_bytes(),
+            )?;
+        }
+
+        if changes.len() > 1 {
+            let mut min = f64::INFINITY;
+            let mut max = f64::NEG_INFINITY;
+            for v in &changes {
+                if *v < min {
+                    min = *v;
+                }
+                if *v > max {
+                    max = *v;
+                }
+            }
+            let range = max - min + 0.00001f64;
+            let num_bins = (changes.len() as f64).log2().ceil() as usize + 1;
+            let bin_width = range / num_bins as f64;
+            let mut freq_data = vec![0usize; num_bins];
+            for v in &changes {
+                let bin = (((*v - min) / bin_width).floor() as usize).min(num_bins - 1);
+                freq_data[bin] += 1;
+            }
+            let histo = Histogram {
+                parent_id: "histo".to_owned(),
+                width: 700f64,
+                height: 500f64,
+                freq_data: freq_data,
+                min_bin_val: min,
+                bin_width: bin_width,
+                x_axis_label: "Detectable Elevation Change".to_owned(),
+                cumulative: false,
+            };
+            writer.write_all(
+                &format!("<div id='histo' align=\"center\">{}</div>", histo.get_svg()).as_bytes(),
+            )?;
+        }
+
+        writer.write_all("</body>".as_bytes())?;
+        let _ = writer.flush();
+
+        if verbose {
+            if cfg!(target_os = "macos") || cfg!(target_os = "ios") {
+                let output = Command::new("open")
+                    .arg(html_file.clone())
+                    .output()
+                    .expect("failed to execute process");
+
+                let _ = output.stdout;
+            } else if cfg!(target_os = "windows") {
+                let output = Command::new("explorer.exe")
+                    .arg(html_file.clone())
+                    .output()
+                    .expect("failed to execute process");
+
+                let _ = output.stdout;
+            } else if cfg!(target_os = "linux") {
+                let output = Command::new("xdg-open")
+                    .arg(html_file.clone())
+                    .output()
+                    .expect("failed to execute process");
+
+                let _ = output.stdout;
+            }
+            progress::message(format!("Complete! Please see {} for output.", html_file));
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
+        }
+
+        Ok(())
+    }
+}
+
+/// Accumulates the areas and volumes of erosion and deposition.
+#[derive(Default)]
+struct VolumeStats {
+    raw_erosion_area: f64,
+    raw_deposition_area: f64,
+    raw_erosion_volume: f64,
+    raw_deposition_volume: f64,
+    erosion_area: f64,
+    deposition_area: f64,
+    erosion_volume: f64,
+    deposition_volume: f64,
+    erosion_var: f64,
+    deposition_var: f64,
+    erosion_err: f64,
+    deposition_err: f64,
+}
+
+impl VolumeStats {
+    fn add_raw(&mut self, dz: f64, cell_area: f64) {
+        if dz < 0f64 {
+            self.raw_erosion_area += cell_area;
+            self.raw_erosion_volume -= dz * cell_area;
+        } else if dz > 0f64 {
+            self.raw_deposition_area += cell_area;
+            self.raw_deposition_volume += dz * cell_area;
+        }
+    }
+
+    fn add_detected(&mut self, dz: f64, sigma: f64, cell_area: f64) {
+        if dz < 0f64 {
+            self.erosion_area += cell_area;
+            self.erosion_volume -= dz * cell_area;
+            self.erosion_var += (sigma * cell_area) * (sigma * cell_area);
+            self.erosion_err += sigma * cell_area;
+        } else if dz > 0f64 {
+            self.deposition_area += cell_area;
+            self.deposition_volume += dz * cell_area;
+            self.deposition_var += (sigma * cell_area) * (sigma * cell_area);
+            self.deposition_err += sigma * cell_area;
+        }
+    }
+}
@@ -13,6 +13,7 @@ Testing showed that it was not substantially better than the FeaturePreservingSm
 
 use whitebox_raster::Raster;
 use whitebox_common::structures::Array2D;
+use whitebox_common::progress;
 use crate::tools::*;
 use num_cpus;
 use std::env;
@@ -24,7 +25,6 @@ use std::ops::SubAssign;
 use std::path;
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::thread;
 
 /// This tool implements a modified form of the algorithm described by
 /// Sun, Rosin, Martin, and Langbein (2007) '*Fast and effective feature-preserving
@@ -54,7 +54,7 @@ impl DrainagePreservingSmoothing {
         let mut parameters = vec![];
         parameters.push(ToolParameter {
             name: "Input DEM File".to_owned(),
-            flags: vec!["-i".to_owned(), "--dem".to_owned()],
+            flags: vec!["-i".to_owned(), "--input".to_owned(), "--dem".to_owned()],
             description: "Input raster DEM file.".to_owned(),
             parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
             default_value: None,
@@ -207,6 +207,7 @@ impl WhiteboxTool for DrainagePreservingSmoothing {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut output_file = String::new();
@@ -295,13 +296,7 @@ impl WhiteboxTool for DrainagePreservingSmoothing {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         if filter_size < 3 {
@@ -340,7 +335,7 @@ impl WhiteboxTool for DrainagePreservingSmoothing {
         }
 
         if verbose {
-            println!("Reading data...")
+            progress::message("Reading data...")
         }
 
         let input = Arc::new(Raster::new(&input_file, "r")?);
@@ -353,7 +348,7 @@ impl WhiteboxTool for DrainagePreservingSmoothing {
             if mid_lat <= 90.0 && mid_lat >= -90.0 {
                 mid_lat = mid_lat.to_radians();
                 z_factor = 1.0 / (111320.0 * mid_lat.cos());
-                println!("It appears that the DEM is in geographic coordinates. The z-factor has been updated: {}.", z_factor);
+                progress::message(format!("It appears that the DEM is in geographic coordinates. The z-factor has been updated: {}.", z_factor));
             }
         } else if z_factor < 0.0 {
             z_factor = 1.0;
@@ -387,7 +382,7 @@ impl WhiteboxTool for DrainagePreservingSmoothing {
         for tid in 0..num_procs {
             let input = input.clone();
             let tx = tx.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let mut z: f64;
                 let mut val: i64;
                 for row in (0..rows).filter(|r| r % num_procs == tid) {
@@ -399,18 +394,20 @@ impl WhiteboxTool for DrainagePreservingSmoothing {
                             data[col as usize] = val;
                         }
                     }
-                    tx.send((row, data)).unwrap();
+                    if tx.send((row, data)).is_err() {
+                        return;
+                    }
                 }
             });
         }
 
         for row in 0..rows {
-            let data = rx.recv().expect("Error receiving data from thread.");
+            let data = cancel.recv(&rx)?;
             binned_data.set_row_data(data.0, data.1);
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Binning elevations: {}%", progress);
+                    progress::update_stage("Binning elevations", progress);
                     old_progress = progress;
                 }
             }
@@ -421,7 +418,7 @@ impl WhiteboxTool for DrainagePreservingSmoothing {
         for tid in 0..num_procs {
             let binned_data = bd.clone();
             let tx = tx.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let (mut bin_val, mut bin_val_n): (i64, i64);
                 let (mut start_col, mut end_col, mut start_row, mut end_row): (
                     isize,
@@ -533,19 +530,21 @@ impl WhiteboxTool for DrainagePreservingSmoothing {
                             old_median = bin_nodata;
                         }
                     }
-                    tx.send((row, data)).unwrap();
+                    if tx.send((row, data)).is_err() {
+                        return;
+                    }
                 }
             });
         }
 
         let mut dfm_data: Array2D<f64> = Array2D::new(rows, columns, nodata, nodata)?;
         for row in 0..rows {
-            let data = rx.recv().expect("Error receiving data from thread.");
+            let data = cancel.recv(&rx)?;
             dfm_data.set_row_data(data.0, data.1);
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Calculating topographic position: {}%", progress);
+                    progress::update_stage("Calculating topographic position", progress);
                     old_progress = progress;
                 }
             }
@@ -565,7 +564,7 @@ impl WhiteboxTool for DrainagePreservingSmoothing {
             let input = input.clone();
             // let exclusions = exclusions.clone();
             let tx = tx.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let dx = [1, 1, 1, 0, -1, -1, -1, 0];
                 let dy = [-1, 0, 1, 1, 1, 0, -1, -1];
                 let eight_grid_res = input.configs.resolution_x as f32 * 8f32;
@@ -608,7 +607,9 @@ impl WhiteboxTool for DrainagePreservingSmoothing {
                             };
                         }
                     }
-                    tx.send((row, data)).unwrap();
+                    if tx.send((row, data)).is_err() {
+                        return;
+                    }
                 }
             });
         }
@@ -620,13 +621,13 @@ impl WhiteboxTool for DrainagePreservingSmoothing {
         };
         let mut nv: Array2D<Normal> = Array2D::new(rows, columns, zero_vector, zero_vector)?;
         for row in 0..rows {
-            let data = rx.recv().expect("Error receiving data from thread.");
+            let data = cancel.recv(&rx)?;
             nv.set_row_data(data.0, data.1);
 
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Calculating normal vectors: {}%", progress);
+                    progress::update_stage("Calculating normal vectors", progress);
                     old_progress = progress;
                 }
             }
@@ -634,13 +635,10 @@ impl WhiteboxTool for DrainagePreservingSmoothing {
 
         let t1 = Instant::now();
         if verbose {
-            println!(
-                "{}",
-                format!(
+            progress::message(format!(
                     "Calculating normal vectors: {}",
                     get_formatted_elapsed_time(start)
-                )
-            );
+                ));
         }
 
         //////////////////////////////////////////////////////////
@@ -654,7 +652,7 @@ impl WhiteboxTool for DrainagePreservingSmoothing {
             let nv = nv.clone();
             let dfm_data = dfm_data.clone();
             let tx = tx.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let num_pixels_in_filter = filter_size * filter_size;
                 let mut dx = vec![0isize; num_pixels_in_filter];
                 let mut dy = vec![0isize; num_pixels_in_filter];
@@ -727,33 +725,32 @@ impl WhiteboxTool for DrainagePreservingSmoothing {
                             data[col as usize] = Normal { a: a, b: b, c: c };
                         }
                     }
-                    tx.send((row, data)).unwrap();
+                    if tx.send((row, data)).is_err() {
+                        return;
+                    }
                 }
             });
         }
 
         let mut nv_smooth: Array2D<Normal> = Array2D::new(rows, columns, zero_vector, zero_vector)?;
         for row in 0..rows {
-            let data = rx.recv().expect("Error receiving data from thread.");
+            let data = cancel.recv(&rx)?;
             nv_smooth.set_row_data(data.0, data.1);
 
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Smoothing normal vectors: {}%", progress);
+                    progress::update_stage("Smoothing normal vectors", progress);
                     old_progress = progress;
                 }
             }
         }
 
         if verbose {
-            println!(
-                "{}",
-                format!(
+            progress::message(format!(
                     "Smoothing normal vectors: {}",
                     get_formatted_elapsed_time(t1)
-                )
-            );
+                ));
         }
 
         ///////////////////////////////////////////////////////////////////////////
@@ -776,11 +773,11 @@ impl WhiteboxTool for DrainagePreservingSmoothing {
         let mut output = Raster::initialize_using_file(&output_file, &input);
         output.set_data_from_raster(&input)?;
         if verbose {
-            println!("Updating elevations...");
+            progress::message("Updating elevations...");
         }
         for loop_num in 0..num_iter {
             if verbose {
-                println!("Iteration {} of {}...", loop_num + 1, num_iter);
+                progress::message(format!("Iteration {} of {}...", loop_num + 1, num_iter));
             }
 
             for row in 0..rows {
@@ -833,15 +830,11 @@ impl WhiteboxTool for DrainagePreservingSmoothing {
                         }
                     }
                 }
+                cancel.check()?;
                 if verbose {
                     progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!(
-                            "Updating DEM elevations (Loop {} of {}): {}%",
-                            loop_num + 1,
-                            num_iter,
-                            progress
-                        );
+                        progress::update_stage(&format!("Updating DEM elevations (Loop {} of {})", loop_num + 1, num_iter), progress);
                         old_progress = progress;
                     }
                 }
@@ -867,21 +860,18 @@ impl WhiteboxTool for DrainagePreservingSmoothing {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
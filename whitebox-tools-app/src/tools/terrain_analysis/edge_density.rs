@@ -8,6 +8,7 @@ License: MIT
 
 use whitebox_raster::*;
 use whitebox_common::structures::Array2D;
+use whitebox_common::progress;
 use crate::tools::*;
 use num_cpus;
 use std::env;
@@ -18,7 +19,6 @@ use std::ops::SubAssign;
 use std::path;
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::thread;
 
 /// This tool calculates the density of edges, or breaks-in-slope within an input digital elevation model (DEM).
 /// A break-in-slope occurs between two neighbouring grid cells if the angular difference between their normal
@@ -54,7 +54,7 @@ impl EdgeDensity {
         let mut parameters = vec![];
         parameters.push(ToolParameter {
             name: "Input DEM File".to_owned(),
-            flags: vec!["-i".to_owned(), "--dem".to_owned()],
+            flags: vec!["-i".to_owned(), "--input".to_owned(), "--dem".to_owned()],
             description: "Input raster DEM file.".to_owned(),
             parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
             default_value: None,
@@ -113,7 +113,7 @@ impl EdgeDensity {
             short_exe += ".exe";
         }
         let usage = format!(
-            ">>.*{} -r={} -v --wd=\"*path*to*data*\" --dem=DEM.tif -o=output.tif --filter=15 --norm_diff=20.0 --num_iter=4",
+            ">>.*{} -r={} -v --wd=\"*path*to*data*\" --dem=DEM.tif -o=output.tif --filter=15 --norm_diff=20.0",
             short_exe, name
         ).replace("*", &sep);
 
@@ -167,6 +167,7 @@ impl WhiteboxTool for EdgeDensity {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut output_file = String::new();
@@ -243,13 +244,7 @@ impl WhiteboxTool for EdgeDensity {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         if filter_size < 3 {
@@ -281,7 +276,7 @@ impl WhiteboxTool for EdgeDensity {
         }
 
         if verbose {
-            println!("Reading data...")
+            progress::message("Reading data...")
         };
 
         let input = Arc::new(Raster::new(&input_file, "r")?);
@@ -294,7 +289,7 @@ impl WhiteboxTool for EdgeDensity {
             if mid_lat <= 90.0 && mid_lat >= -90.0 {
                 mid_lat = mid_lat.to_radians();
                 z_factor = 1.0 / (111320.0 * mid_lat.cos());
-                println!("It appears that the DEM is in geographic coordinates. The z-factor has been updated: {}.", z_factor);
+                progress::message(format!("It appears that the DEM is in geographic coordinates. The z-factor has been updated: {}.", z_factor));
             }
         } else if z_factor < 0.0 {
             z_factor = 1.0;
@@ -317,7 +312,7 @@ impl WhiteboxTool for EdgeDensity {
         for tid in 0..num_procs {
             let input = input.clone();
             let tx = tx.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let dx = [1, 1, 1, 0, -1, -1, -1, 0];
                 let dy = [-1, 0, 1, 1, 1, 0, -1, -1];
                 let eight_grid_res = input.configs.resolution_x as f32 * 8f32;
@@ -360,7 +355,9 @@ impl WhiteboxTool for EdgeDensity {
                             };
                         }
                     }
-                    tx.send((row, data)).unwrap();
+                    if tx.send((row, data)).is_err() {
+                        return;
+                    }
                 }
             });
         }
@@ -372,13 +369,13 @@ impl WhiteboxTool for EdgeDensity {
         };
         let mut nv: Array2D<Normal> = Array2D::new(rows, columns, zero_vector, zero_vector)?;
         for row in 0..rows {
-            let data = rx.recv().expect("Error receiving data from thread.");
+            let data = cancel.recv(&rx)?;
             nv.set_row_data(data.0, data.1);
 
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Calculating normal vectors: {}%", progress);
+                    progress::update_stage("Calculating normal vectors", progress);
                     old_progress = progress;
                 }
             }
@@ -393,7 +390,7 @@ impl WhiteboxTool for EdgeDensity {
             let nv = nv.clone();
             let input = input.clone();
             let tx = tx.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let dx = [1, 1, 1, 0, -1, -1, -1, 0];
                 let dy = [-1, 0, 1, 1, 1, 0, -1, -1];
                 let mut z: f64;
@@ -424,20 +421,22 @@ impl WhiteboxTool for EdgeDensity {
                             }
                         }
                     }
-                    tx.send((row, data)).unwrap();
+                    if tx.send((row, data)).is_err() {
+                        return;
+                    }
                 }
             });
         }
 
         let mut edges: Array2D<f64> = Array2D::new(rows, columns, 0f64, nodata)?;
         for row in 0..rows {
-            let data = rx.recv().expect("Error receiving data from thread.");
+            let data = cancel.recv(&rx)?;
             edges.set_row_data(data.0, data.1);
 
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Finding edges: {}%", progress);
+                    progress::update_stage("Finding edges", progress);
                     old_progress = progress;
                 }
             }
@@ -472,10 +471,11 @@ impl WhiteboxTool for EdgeDensity {
                     }
                 }
             }
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Creating integral images: {}%", progress);
+                    progress::update_stage("Creating integral images", progress);
                     old_progress = progress;
                 }
             }
@@ -489,7 +489,7 @@ impl WhiteboxTool for EdgeDensity {
             let i_n = i_n.clone();
             let input = input.clone();
             let tx2 = tx2.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let (mut x1, mut x2, mut y1, mut y2): (isize, isize, isize, isize);
                 let mut n: f64;
                 let mut sum: f64;
@@ -534,10 +534,7 @@ impl WhiteboxTool for EdgeDensity {
                     match tx2.send((row, data)) {
                         Ok(_) => {}
                         Err(_) => {
-                            println!(
-                                "Error sending data from thread {} processing row {}.",
-                                tid, row
-                            );
+                            progress::message(format!("Error sending data from thread {} processing row {}.", tid, row));
                         }
                     }
                 }
@@ -561,10 +558,11 @@ impl WhiteboxTool for EdgeDensity {
                 }
             }
 
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Performing analysis: {}%", progress);
+                    progress::update_stage("Performing analysis", progress);
                     old_progress = progress;
                 }
             }
@@ -583,21 +581,18 @@ impl WhiteboxTool for EdgeDensity {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -7,6 +7,7 @@ License: MIT
 */
 
 use whitebox_raster::Raster;
+use whitebox_common::progress;
 use crate::tools::*;
 use num_cpus;
 use std::env;
@@ -15,7 +16,6 @@ use std::io::{Error, ErrorKind};
 use std::path;
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::thread;
 
 /// This tool can be used to express the elevation of a grid cell in a digital elevation model (DEM) as a percentage
 /// of the relief between the watershed minimum and maximum values. As such, it provides a basic
@@ -42,7 +42,7 @@ impl ElevRelativeToWatershedMinMax {
         let mut parameters = vec![];
         parameters.push(ToolParameter {
             name: "Input DEM File".to_owned(),
-            flags: vec!["-i".to_owned(), "--dem".to_owned()],
+            flags: vec!["-i".to_owned(), "--input".to_owned(), "--dem".to_owned()],
             description: "Input raster DEM file.".to_owned(),
             parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
             default_value: None,
@@ -132,6 +132,7 @@ impl WhiteboxTool for ElevRelativeToWatershedMinMax {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut watersheds_file = String::new();
@@ -180,13 +181,7 @@ impl WhiteboxTool for ElevRelativeToWatershedMinMax {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -205,7 +200,7 @@ impl WhiteboxTool for ElevRelativeToWatershedMinMax {
         }
 
         if verbose {
-            println!("Reading data...")
+            progress::message("Reading data...")
         };
 
         let input = Arc::new(Raster::new(&input_file, "r")?);
@@ -246,7 +241,7 @@ impl WhiteboxTool for ElevRelativeToWatershedMinMax {
             let input = input.clone();
             let watersheds = watersheds.clone();
             let tx = tx.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let mut watershed_min_vals = vec![f64::INFINITY; range_watersheds as usize + 1];
                 let mut watershed_max_vals = vec![f64::NEG_INFINITY; range_watersheds as usize + 1];
                 let mut z: f64;
@@ -266,14 +261,14 @@ impl WhiteboxTool for ElevRelativeToWatershedMinMax {
                         }
                     }
                 }
-                tx.send((watershed_min_vals, watershed_max_vals)).unwrap();
+                let _ = tx.send((watershed_min_vals, watershed_max_vals));
             });
         }
 
         let mut watershed_min_vals = vec![f64::INFINITY; range_watersheds as usize + 1];
         let mut watershed_max_vals = vec![f64::NEG_INFINITY; range_watersheds as usize + 1];
         for tid in 0..num_procs {
-            let (mins, maxs) = rx.recv().expect("Error receiving data from thread.");
+            let (mins, maxs) = cancel.recv(&rx)?;
             for i in 0..mins.len() {
                 //(range_watersheds as usize+1) {
                 if mins[i] != f64::INFINITY && mins[i] < watershed_min_vals[i] {
@@ -286,7 +281,7 @@ impl WhiteboxTool for ElevRelativeToWatershedMinMax {
             if verbose {
                 progress = (100.0_f64 * tid as f64 / (num_procs - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    progress::update(progress);
                     old_progress = progress;
                 }
             }
@@ -299,7 +294,7 @@ impl WhiteboxTool for ElevRelativeToWatershedMinMax {
             let watershed_min_vals = watershed_min_vals.clone();
             let watershed_max_vals = watershed_max_vals.clone();
             let tx = tx.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let mut z: f64;
                 let mut watershed: f64;
                 for row in (0..rows).filter(|r| r % num_procs == tid) {
@@ -315,18 +310,20 @@ impl WhiteboxTool for ElevRelativeToWatershedMinMax {
                                 * 100f64;
                         }
                     }
-                    tx.send((row, data)).unwrap();
+                    if tx.send((row, data)).is_err() {
+                        return;
+                    }
                 }
             });
         }
 
         for r in 0..rows {
-            let (row, data) = rx.recv().expect("Error receiving data from thread.");
+            let (row, data) = cancel.recv(&rx)?;
             output.set_row_data(row, data);
             if verbose {
                 progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    progress::update(progress);
                     old_progress = progress;
                 }
             }
@@ -342,21 +339,18 @@ impl WhiteboxTool for ElevRelativeToWatershedMinMax {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -8,6 +8,7 @@ License: MIT
 
 use whitebox_raster::Raster;
 use whitebox_common::structures::{Array2D, BoundingBox, DistanceMetric, FixedRadiusSearch2D};
+use whitebox_common::progress;
 use crate::tools::*;
 use whitebox_vector::{ShapeType, Shapefile};
 use std::cmp::Ordering;
@@ -61,7 +62,7 @@ impl EmbankmentMapping {
         let mut parameters = vec![];
         parameters.push(ToolParameter {
             name: "Input DEM File".to_owned(),
-            flags: vec!["-i".to_owned(), "--dem".to_owned()],
+            flags: vec!["-i".to_owned(), "--input".to_owned(), "--dem".to_owned()],
             description: "Input raster DEM file.".to_owned(),
             parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
             default_value: None,
@@ -239,6 +240,7 @@ impl WhiteboxTool for EmbankmentMapping {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut roads_file = String::new();
@@ -381,13 +383,7 @@ impl WhiteboxTool for EmbankmentMapping {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -437,7 +433,7 @@ impl WhiteboxTool for EmbankmentMapping {
         }
 
         if verbose {
-            println!("Reading data...")
+            progress::message("Reading data...")
         };
 
         let dem = Raster::new(&input_file, "r").expect("Error reading input DEM.");
@@ -450,7 +446,7 @@ impl WhiteboxTool for EmbankmentMapping {
         ////////////////////////////////////////////////
 
         if verbose {
-            println!("Rasterizing transportation vector...");
+            progress::message("Rasterizing transportation vector...");
         }
 
         // make sure the input vector file is of polyline type
@@ -596,15 +592,11 @@ impl WhiteboxTool for EmbankmentMapping {
                     }
                 }
             }
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * (record_num + 1) as f64 / num_records as f64) as usize;
                 if progress != old_progress {
-                    println!(
-                        "Rasterizing roads {} of {}: {}%",
-                        record_num + 1,
-                        num_records,
-                        progress
-                    );
+                    progress::update_stage(&format!("Rasterizing roads {} of {}", record_num + 1, num_records), progress);
                     old_progress = progress;
                 }
             }
@@ -612,17 +604,14 @@ impl WhiteboxTool for EmbankmentMapping {
 
         let elapsed_time = get_formatted_elapsed_time(start);
         if verbose {
-            println!(
-                "Time for transportation vector rasterization: {}",
-                elapsed_time
-            );
+            progress::message(format!("Time for transportation vector rasterization: {}", elapsed_time));
         }
         let start2 = Instant::now();
 
         // Now perform the analysis. //
 
         if verbose {
-            println!("Mapping embankment cells...");
+            progress::message("Mapping embankment cells...");
         }
 
         let nodata = dem.configs.nodata;
@@ -722,7 +711,7 @@ impl WhiteboxTool for EmbankmentMapping {
             }
             progress = (100.0 * row as f64 / (rows - 1) as f64) as usize;
             if progress != old_progress && verbose {
-                println!("Progress: {}%", progress);
+                progress::update(progress);
                 old_progress = progress;
             }
         }
@@ -764,11 +753,12 @@ impl WhiteboxTool for EmbankmentMapping {
                 }
             }
 
+            cancel.check()?;
             if verbose {
                 num_mapped_cells += 1f64;
                 progress = (100.0 * num_mapped_cells / (num_cells as f64)) as usize;
                 if progress != old_progress {
-                    println!("Percent of total cells parameterized: {}%", progress);
+                    progress::update_stage("Percent of total cells parameterized", progress);
                     old_progress = progress;
                 }
             }
@@ -842,14 +832,14 @@ impl WhiteboxTool for EmbankmentMapping {
             num_mapped_cells += 1f64;
             progress = (100.0 * num_mapped_cells / total_cells_in_buffer) as usize;
             if progress != old_progress && verbose {
-                println!("Progress: {}%", progress);
+                progress::update(progress);
                 old_progress = progress;
             }
         }
 
         if verbose {
             let elapsed_time = get_formatted_elapsed_time(start2);
-            println!("Time for embankment mapping: {}", elapsed_time);
+            progress::message(format!("Time for embankment mapping: {}", elapsed_time));
         }
         let start3 = Instant::now();
 
@@ -857,7 +847,7 @@ impl WhiteboxTool for EmbankmentMapping {
 
         if remove_embankments {
             if verbose {
-                println!("Creating embankment-less DEM...");
+                progress::message("Creating embankment-less DEM...");
             }
             // Not let's interpolate a surface beneath the road embankments.
             let mut frs: FixedRadiusSearch2D<f64> =
@@ -884,10 +874,11 @@ impl WhiteboxTool for EmbankmentMapping {
                         }
                     }
                 }
+                cancel.check()?;
                 if verbose {
                     progress = (100.0_f32 * row as f32 / (rows - 1) as f32) as usize;
                     if progress != old_progress {
-                        println!("Finding edge cells: {}%", progress);
+                        progress::update_stage("Finding edge cells", progress);
                         old_progress = progress;
                     }
                 }
@@ -930,10 +921,11 @@ impl WhiteboxTool for EmbankmentMapping {
                         out_dem.set_value(row, col, dem.get_value(row, col));
                     }
                 }
+                cancel.check()?;
                 if verbose {
                     progress = (100.0_f32 * row as f32 / (rows - 1) as f32) as usize;
                     if progress != old_progress {
-                        println!("Interpolating data holes: {}%", progress);
+                        progress::update_stage("Interpolating data holes", progress);
                         old_progress = progress;
                     }
                 }
@@ -941,7 +933,7 @@ impl WhiteboxTool for EmbankmentMapping {
 
             if verbose {
                 let elapsed_time = get_formatted_elapsed_time(start3);
-                println!("Time to create embankment-less DEM: {}", elapsed_time);
+                progress::message(format!("Time to create embankment-less DEM: {}", elapsed_time));
             }
 
             total_time = get_formatted_elapsed_time(start);
@@ -953,12 +945,12 @@ impl WhiteboxTool for EmbankmentMapping {
             out_dem.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", total_time));
 
             if verbose {
-                println!("Saving data...")
+                progress::message("Saving data...")
             };
             let _ = match out_dem.write() {
                 Ok(_) => {
                     if verbose {
-                        println!("Output file written")
+                        progress::output_written(&out_dem.file_name)
                     }
                 }
                 Err(e) => return Err(e),
@@ -973,21 +965,18 @@ impl WhiteboxTool for EmbankmentMapping {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", total_time));
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", total_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", total_time));
         }
 
         Ok(())
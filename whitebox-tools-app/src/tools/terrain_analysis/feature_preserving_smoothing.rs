@@ -8,6 +8,7 @@ License: MIT
 
 use whitebox_raster::*;
 use whitebox_common::structures::Array2D;
+use whitebox_common::progress;
 use crate::tools::*;
 use num_cpus;
 use std::env;
@@ -17,7 +18,6 @@ use std::ops::SubAssign;
 use std::path;
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::thread;
 use std::{f32, f64};
 
 /// This tool implements a highly modified form of the DEM de-noising algorithm described
@@ -87,7 +87,7 @@ impl FeaturePreservingSmoothing {
         let mut parameters = vec![];
         parameters.push(ToolParameter {
             name: "Input DEM File".to_owned(),
-            flags: vec!["-i".to_owned(), "--dem".to_owned()],
+            flags: vec!["-i".to_owned(), "--input".to_owned(), "--dem".to_owned()],
             description: "Input raster DEM file.".to_owned(),
             parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
             default_value: None,
@@ -218,6 +218,7 @@ impl WhiteboxTool for FeaturePreservingSmoothing {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut output_file = String::new();
@@ -320,13 +321,7 @@ impl WhiteboxTool for FeaturePreservingSmoothing {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         if filter_size < 3 {
@@ -353,7 +348,7 @@ impl WhiteboxTool for FeaturePreservingSmoothing {
         }
 
         if verbose {
-            println!("Reading data...")
+            progress::message("Reading data...")
         }
 
         let input_dem = Raster::new(&input_file, "r")?;
@@ -366,7 +361,7 @@ impl WhiteboxTool for FeaturePreservingSmoothing {
             if mid_lat <= 90.0 && mid_lat >= -90.0 {
                 mid_lat = mid_lat.to_radians();
                 z_factor = (1.0 / (111320.0 * mid_lat.cos())) as f32;
-                println!("It appears that the DEM is in geographic coordinates. The z-factor has been updated to {}.", z_factor);
+                progress::message(format!("It appears that the DEM is in geographic coordinates. The z-factor has been updated to {}.", z_factor));
             }
         } else if z_factor < 0.0 {
             z_factor = 1.0;
@@ -406,7 +401,7 @@ impl WhiteboxTool for FeaturePreservingSmoothing {
         for tid in 0..num_procs {
             let input = input.clone();
             let tx = tx.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let dx = [1, 1, 1, 0, -1, -1, -1, 0];
                 let dy = [-1, 0, 1, 1, 1, 0, -1, -1];
                 let mut z: f32;
@@ -443,7 +438,9 @@ impl WhiteboxTool for FeaturePreservingSmoothing {
                             data[col as usize] = Normal { a: a, b: b };
                         }
                     }
-                    tx.send((row, data)).unwrap();
+                    if tx.send((row, data)).is_err() {
+                        return;
+                    }
                 }
             });
         }
@@ -451,13 +448,13 @@ impl WhiteboxTool for FeaturePreservingSmoothing {
         let zero_vector = Normal { a: 0f32, b: 0f32 };
         let mut nv: Array2D<Normal> = Array2D::new(rows, columns, zero_vector, zero_vector)?;
         for row in 0..rows {
-            let data = rx.recv().expect("Error receiving data from thread.");
+            let data = cancel.recv(&rx)?;
             nv.set_row_data(data.0, data.1);
 
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Calculating normal vectors: {}%", progress);
+                    progress::update_stage("Calculating normal vectors", progress);
                     old_progress = progress;
                 }
             }
@@ -465,13 +462,10 @@ impl WhiteboxTool for FeaturePreservingSmoothing {
 
         let t1 = Instant::now();
         if verbose {
-            println!(
-                "{}",
-                format!(
+            progress::message(format!(
                     "Calculating normal vectors: {}",
                     get_formatted_elapsed_time(start)
-                )
-            );
+                ));
         }
 
         //////////////////////////////////////////////////////////
@@ -483,7 +477,7 @@ impl WhiteboxTool for FeaturePreservingSmoothing {
             let input = input.clone();
             let nv = nv.clone();
             let tx = tx.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let num_pixels_in_filter = filter_size * filter_size;
                 let mut dx = vec![0isize; num_pixels_in_filter];
                 let mut dy = vec![0isize; num_pixels_in_filter];
@@ -534,33 +528,32 @@ impl WhiteboxTool for FeaturePreservingSmoothing {
                             data[col as usize] = Normal { a: a, b: b };
                         }
                     }
-                    tx.send((row, data)).unwrap();
+                    if tx.send((row, data)).is_err() {
+                        return;
+                    }
                 }
             });
         }
 
         let mut nv_smooth: Array2D<Normal> = Array2D::new(rows, columns, zero_vector, zero_vector)?;
         for row in 0..rows {
-            let data = rx.recv().expect("Error receiving data from thread.");
+            let data = cancel.recv(&rx)?;
             nv_smooth.set_row_data(data.0, data.1);
 
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Smoothing normal vectors: {}%", progress);
+                    progress::update_stage("Smoothing normal vectors", progress);
                     old_progress = progress;
                 }
             }
         }
 
         if verbose {
-            println!(
-                "{}",
-                format!(
+            progress::message(format!(
                     "Smoothing normal vectors: {}",
                     get_formatted_elapsed_time(t1)
-                )
-            );
+                ));
         }
 
         drop(nv);
@@ -590,11 +583,11 @@ impl WhiteboxTool for FeaturePreservingSmoothing {
         // let mut output = Arc::try_unwrap(input).unwrap_err().clone();
 
         if verbose {
-            println!("Updating elevations...");
+            progress::message("Updating elevations...");
         }
         for loop_num in 0..num_iter {
             if verbose {
-                println!("Iteration {} of {}...", loop_num + 1, num_iter);
+                progress::message(format!("Iteration {} of {}...", loop_num + 1, num_iter));
             }
 
             for row in 0..rows {
@@ -633,15 +626,11 @@ impl WhiteboxTool for FeaturePreservingSmoothing {
                         }
                     }
                 }
+                cancel.check()?;
                 if verbose {
                     progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!(
-                            "Updating DEM elevations (Loop {} of {}): {}%",
-                            loop_num + 1,
-                            num_iter,
-                            progress
-                        );
+                        progress::update_stage(&format!("Updating DEM elevations (Loop {} of {})", loop_num + 1, num_iter), progress);
                         old_progress = progress;
                     }
                 }
@@ -672,21 +661,18 @@ impl WhiteboxTool for FeaturePreservingSmoothing {
         output_raster.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         }
         let _ = match output_raster.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output_raster.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
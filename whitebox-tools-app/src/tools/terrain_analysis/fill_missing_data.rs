@@ -8,6 +8,7 @@ License: MIT
 
 use whitebox_raster::Raster;
 use whitebox_common::structures::{Array2D, DistanceMetric, FixedRadiusSearch2D};
+use whitebox_common::progress;
 use crate::tools::*;
 use num_cpus;
 use std::env;
@@ -16,7 +17,6 @@ use std::io::{Error, ErrorKind};
 use std::path;
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::thread;
 
 /// This tool can be used to fill in small gaps in a raster or digital elevation model (DEM). The gaps,
 /// or holes, must have recognized NoData values. If gaps do not currently have this characteristic, use
@@ -169,6 +169,7 @@ impl WhiteboxTool for FillMissingData {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut output_file = String::new();
@@ -236,13 +237,7 @@ impl WhiteboxTool for FillMissingData {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -265,7 +260,7 @@ impl WhiteboxTool for FillMissingData {
         }
 
         if verbose {
-            println!("Reading data...")
+            progress::message("Reading data...")
         };
 
         let input = Raster::new(&input_file, "r")?;
@@ -283,7 +278,7 @@ impl WhiteboxTool for FillMissingData {
 
         if exclude_edge_nodata {
             if verbose {
-                println!("Finding edge-connected NoData values...");
+                progress::message("Finding edge-connected NoData values...");
             }
             // Scan the raster edges, placing NoData values in a stack
             let mut stack = vec![]; // Vec::with_capacity(((rows + columns) * 5) as usize);
@@ -322,12 +317,13 @@ impl WhiteboxTool for FillMissingData {
                         edge_connected_nodata.set_value(cell.0 + dy[n], cell.1 + dx[n], 1);
                     }
                 }
+                cancel.check()?;
                 if verbose {
                     num_cells_popped += 1;
                     progress = (100.0_f64 * num_cells_popped as f64 / (rows * columns - 1) as f64)
                         as usize;
                     if progress != old_progress {
-                        println!("Finding edge-connected NoData cells: {}%", progress);
+                        progress::update_stage("Finding edge-connected NoData cells", progress);
                         old_progress = progress;
                     }
                 }
@@ -336,7 +332,7 @@ impl WhiteboxTool for FillMissingData {
 
         // Interpolate the data holes. Start by locating all the edge cells.
         if verbose {
-            println!("Interpolating data holes...")
+            progress::message("Interpolating data holes...")
         };
         let mut frs: FixedRadiusSearch2D<f64> =
             FixedRadiusSearch2D::new(filter_size as f64, DistanceMetric::Euclidean);
@@ -358,10 +354,11 @@ impl WhiteboxTool for FillMissingData {
                     }
                 }
             }
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Interpolating data holes: {}%", progress);
+                    progress::update_stage("Interpolating data holes", progress);
                     old_progress = progress;
                 }
             }
@@ -382,7 +379,7 @@ impl WhiteboxTool for FillMissingData {
             let frs = frs.clone();
             let tx1 = tx.clone();
             let edge_connected_nodata = edge_connected_nodata.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let nodata = input.configs.nodata;
                 let columns = input.configs.columns as isize;
                 let mut z: f64;
@@ -420,7 +417,9 @@ impl WhiteboxTool for FillMissingData {
                                     data[col as usize] = input[(row, col)];
                                 }
                             }
-                            tx1.send((row, data)).unwrap();
+                            if tx1.send((row, data)).is_err() {
+                                return;
+                            }
                         }
                     }
                     _ => {
@@ -454,7 +453,9 @@ impl WhiteboxTool for FillMissingData {
                                     data[col as usize] = input[(row, col)];
                                 }
                             }
-                            tx1.send((row, data)).unwrap();
+                            if tx1.send((row, data)).is_err() {
+                                return;
+                            }
                         }
                     }
                 }
@@ -492,12 +493,12 @@ impl WhiteboxTool for FillMissingData {
         }
 
         for row in 0..rows {
-            let data = rx.recv().expect("Error receiving data from thread.");
+            let data = cancel.recv(&rx)?;
             output.set_row_data(data.0, data.1);
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Performing analysis: {}%", progress);
+                    progress::update_stage("Performing analysis", progress);
                     old_progress = progress;
                 }
             }
@@ -514,21 +515,18 @@ impl WhiteboxTool for FillMissingData {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
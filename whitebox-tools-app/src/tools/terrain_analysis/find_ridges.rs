@@ -7,6 +7,7 @@ License: MIT
 */
 
 use whitebox_raster::Raster;
+use whitebox_common::progress;
 use crate::tools::*;
 use num_cpus;
 use std::env;
@@ -15,7 +16,6 @@ use std::io::{Error, ErrorKind};
 use std::path;
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::thread;
 
 /// This tool can be used to identify ridge cells in a digital elevation model (DEM). Ridge cells are those that
 /// have lower neighbours either to the north and south or the east and west. Line thinning can optionally be used
@@ -132,6 +132,7 @@ impl WhiteboxTool for FindRidges {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut output_file = String::new();
@@ -178,13 +179,7 @@ impl WhiteboxTool for FindRidges {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -221,7 +216,7 @@ impl WhiteboxTool for FindRidges {
         for tid in 0..num_procs {
             let input = input.clone();
             let tx = tx.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let dx = vec![0, 0, -1, 1];
                 let dy = vec![-1, 1, 0, 0];
                 let mut z: f64;
@@ -245,18 +240,20 @@ impl WhiteboxTool for FindRidges {
                             }
                         }
                     }
-                    tx.send((row, data)).unwrap();
+                    if tx.send((row, data)).is_err() {
+                        return;
+                    }
                 }
             });
         }
 
         for row in 0..rows {
-            let data = rx.recv().expect("Error receiving data from thread.");
+            let data = cancel.recv(&rx)?;
             output.set_row_data(data.0, data.1);
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as i32;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    progress::update(progress);
                     old_progress = progress;
                 }
             }
@@ -264,7 +261,7 @@ impl WhiteboxTool for FindRidges {
 
         if line_thin {
             if verbose {
-                println!("Line thinning operation...");
+                progress::message("Line thinning operation...");
             }
             let mut did_something = true;
             let mut loop_num = 0;
@@ -322,10 +319,11 @@ impl WhiteboxTool for FindRidges {
                             }
                         }
                     }
+                    cancel.check()?;
                     if verbose {
                         progress = (100.0_f64 * a as f64 / 7.0) as i32;
                         if progress != old_progress {
-                            println!("Loop Number {}: {}%", loop_num, progress);
+                            progress::update_stage(&format!("Loop Number {}", loop_num), progress);
                             old_progress = progress;
                         }
                     }
@@ -343,21 +341,18 @@ impl WhiteboxTool for FindRidges {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
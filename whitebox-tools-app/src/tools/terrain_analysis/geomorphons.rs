@@ -8,6 +8,7 @@ License: MIT
 */
 
 use whitebox_raster::*;
+use whitebox_common::progress;
 use crate::tools::*;
 use num_cpus;
 use std::env;
@@ -15,7 +16,6 @@ use std::path;
 use std::f64;
 use std::sync::Arc;
 use std::sync::mpsc;
-use std::thread;
 use std::io::{Error, ErrorKind};
 use nalgebra::{Vector3, Matrix3};
 //use std::collections::HashSet;
@@ -84,7 +84,7 @@ impl Geomorphons {
         let mut parameters = vec![];
         parameters.push(ToolParameter{
             name: "Input DEM file.".to_owned(),
-            flags: vec!["-i".to_owned(), "--dem".to_owned()],
+            flags: vec!["-i".to_owned(), "--input".to_owned(), "--dem".to_owned()],
             description: "Input raster DEM file.".to_owned(),
             parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
             default_value: None,
@@ -163,7 +163,7 @@ impl Geomorphons {
         if e.contains(".exe") {
             short_exe += ".exe";
         }
-        let usage = format!(">>.*{} -r={} -v --wd=\"*path*to*data*\" --dem=DEM.tif -o=output.tif --search=50 --threshold=0.0 --tdist=0.0 --forms", short_exe, name).replace("*", &sep);
+        let usage = format!(">>.*{} -r={} -v --wd=\"*path*to*data*\" --dem=DEM.tif -o=output.tif --search=50 --threshold=0.0 --forms", short_exe, name).replace("*", &sep);
 
         Geomorphons {
             name: name,
@@ -210,7 +210,7 @@ impl WhiteboxTool for Geomorphons {
         self.toolbox.clone()
     }
 
-    fn run<'a>(&self, args: Vec<String>, working_directory: &'a str, verbose: bool) -> Result<(), Error> {
+    fn run<'a>(&self, args: Vec<String>, working_directory: &'a str, verbose: bool, cancel: &CancellationToken) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut output_file = String::new();
         let mut search_radius_cells: usize = 1;
@@ -289,13 +289,7 @@ impl WhiteboxTool for Geomorphons {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28);
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -310,7 +304,7 @@ impl WhiteboxTool for Geomorphons {
             output_file = format!("{}{}", working_directory, output_file);
         }
 
-        if verbose { println!("Reading data...") };
+        if verbose { progress::message("Reading data...") };
 
         let input = Arc::new(Raster::new(&input_file, "r")?);
         let mut output = Raster::initialize_using_file(&output_file, &input);
@@ -338,7 +332,7 @@ impl WhiteboxTool for Geomorphons {
 
         // generate global ternary codes
         if verbose {
-            println!("Generating global ternary codes...");
+            progress::message("Generating global ternary codes...");
         }
         let max_codes: usize = 6561; // = 3^8 for 8-bit ternary
         let mut num_procs = num_cpus::get() as isize;
@@ -350,7 +344,7 @@ impl WhiteboxTool for Geomorphons {
         let (tx, rx) = mpsc::channel();
         for tid in 0..num_procs {
             let tx = tx.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let (mut power, mut k): (i32, i32);
                 let (mut value, mut data): (i32, i32);
                 let (mut code, mut rev_code): (i32, i32);
@@ -401,14 +395,16 @@ impl WhiteboxTool for Geomorphons {
                     if code < rev_code {
                         data = code;
                     } else { data = rev_code; }
-                    tx.send((val, data)).unwrap();
+                    if tx.send((val, data)).is_err() {
+                        return;
+                    }
                 }
             });
         };
 
         let mut gtc = [u16::MAX; 6561];
         for _ in 0..max_codes {
-            let out = rx.recv().expect("Error receiving data from thread.");
+            let out = cancel.recv(&rx)?;
             gtc[out.0 as usize] = out.1 as u16;
         }
         //let mut gtchash = HashSet::new();
@@ -419,12 +415,12 @@ impl WhiteboxTool for Geomorphons {
 
         // transform input to residuals
         if use_residuals {
-            if verbose { println!("Calculating residuals..."); }
+            if verbose { progress::message("Calculating residuals..."); }
             let (tx, rx) = mpsc::channel();
             for tid in 0..num_procs {
                 let input = input.clone();
                 let tx = tx.clone();
-                thread::spawn(move || {
+                progress::spawn(move || {
                     let mut sum_y = 0f64;
                     let mut sum_xr_y = 0f64;
                     let mut sum_xc_y = 0f64;
@@ -454,8 +450,8 @@ impl WhiteboxTool for Geomorphons {
                             }
                         }
                     }
-                    tx.send((sum_y, sum_xr_y, sum_xc_y, sum_xr, sum_xc,
-                                sum_xr_xr, sum_xc_xc, sum_xr_xc, n)).unwrap();
+                    let _ = tx.send((sum_y, sum_xr_y, sum_xc_y, sum_xr, sum_xc,
+                                sum_xr_xr, sum_xc_xc, sum_xr_xc, n));
                 });
             }
 
@@ -507,7 +503,7 @@ impl WhiteboxTool for Geomorphons {
             for tid in 0..num_procs {
                 let input = input.clone();
                 let tx = tx.clone();
-                thread::spawn(move || {
+                progress::spawn(move || {
                     let mut z: f64;
                     let (mut r, mut c): (f64, f64);
                     for row in (0..rows).filter(|r| r % num_procs == tid) {
@@ -521,12 +517,14 @@ impl WhiteboxTool for Geomorphons {
                                     (b0 + (b1r * r) + (b1c * c)); // estimate
                             }
                         }
-                        tx.send((row, data)).unwrap();
+                        if tx.send((row, data)).is_err() {
+                            return;
+                        }
                     }
                 });
             }
             for _ in 0..rows {
-                let data = rx.recv().expect("Error receiving data from thread.");
+                let data = cancel.recv(&rx)?;
                 output.set_row_data(data.0, data.1);
             }
         }
@@ -538,7 +536,7 @@ impl WhiteboxTool for Geomorphons {
         };
 
         // main loop
-        if verbose { println!("Computing geomorphons..."); }
+        if verbose { progress::message("Computing geomorphons..."); }
 
         let classes: [[u8; 9]; 9] = [ /* 0  1  2  3  4  5  6  7  8 */   // 1  = Flat
                                 /* 0*/  [1, 1, 1, 8, 8, 9, 9, 9,10],    // 2  = Peak // Summit
@@ -566,7 +564,7 @@ impl WhiteboxTool for Geomorphons {
             let input = input.clone();
             let gtc = gtc.clone();
             let tx = tx.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let (mut z, mut z2, mut angle, mut distance): (f64, f64, f64, f64);
                 let (mut r, mut c, mut d): (isize, isize, isize);
                 let (mut x1, mut x2, mut xdif): (f64, f64, f64);
@@ -682,18 +680,20 @@ impl WhiteboxTool for Geomorphons {
                             }
                         }
                     }
-                    tx.send((row, data)).unwrap();
+                    if tx.send((row, data)).is_err() {
+                        return;
+                    }
                 }
             });
         }
 
         for row in 0..rows {
-            let data = rx.recv().expect("Error receiving data from thread.");
+            let data = cancel.recv(&rx)?;
             output.set_row_data(data.0, data.1);
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    progress::update(progress);
                     old_progress = progress;
                 }
             }
@@ -718,19 +718,18 @@ impl WhiteboxTool for Geomorphons {
                                       .replace("PT", ""));
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!("{}",
-                 &format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", ""));
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", ""));
         }
 
         Ok(())
@@ -9,6 +9,8 @@ NOTES: The tool should have the option to output a distance raster as well.
 */
 
 use whitebox_raster::Raster;
+use whitebox_common::structures::Array2D;
+use whitebox_common::progress;
 use crate::tools::*;
 use num_cpus;
 use std::env;
@@ -17,7 +19,6 @@ use std::io::{Error, ErrorKind};
 use std::path;
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::thread;
 
 /// This tool calculates the horizon angle (*Sx*), i.e. the maximum slope along a specified azimuth (0-360 degrees) for
 /// each grid cell in an input digital elevation model (DEM). Horizon angle is sometime referred to as the maximum upwind
@@ -69,7 +70,7 @@ impl HorizonAngle {
         let mut parameters = vec![];
         parameters.push(ToolParameter {
             name: "Input DEM File".to_owned(),
-            flags: vec!["-i".to_owned(), "--dem".to_owned()],
+            flags: vec!["-i".to_owned(), "--input".to_owned(), "--dem".to_owned()],
             description: "Input raster DEM file.".to_owned(),
             parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
             default_value: None,
@@ -169,6 +170,7 @@ impl WhiteboxTool for HorizonAngle {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut output_file = String::new();
@@ -233,13 +235,7 @@ impl WhiteboxTool for HorizonAngle {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -255,7 +251,7 @@ impl WhiteboxTool for HorizonAngle {
         }
 
         if verbose {
-            println!("Reading data...")
+            progress::message("Reading data...")
         };
         let inputf64 = Arc::new(Raster::new(&input_file, "r")?);
         let configs = inputf64.configs.clone();
@@ -286,12 +282,6 @@ impl WhiteboxTool for HorizonAngle {
 
         let start = Instant::now();
 
-        let line_slope: f32 = if azimuth < 180f32 {
-            (90f32 - azimuth).to_radians().tan()
-        } else {
-            (270f32 - azimuth).to_radians().tan()
-        };
-
         let rows = configs.rows as isize;
         let columns = configs.columns as isize;
         let nodata = configs.nodata;
@@ -310,161 +300,34 @@ impl WhiteboxTool for HorizonAngle {
         for tid in 0..num_procs {
             let input = input.clone();
             let tx = tx.clone();
-            thread::spawn(move || {
-                // The ray-tracing operation can be viewed as a linear maximum filter. The first step
-                // is to create the filter offsets and calculate the offset distances.
-
-                let x_step: isize;
-                let y_step: isize;
-                if azimuth > 0f32 && azimuth <= 90f32 {
-                    x_step = 1;
-                    y_step = 1;
-                } else if azimuth <= 180f32 {
-                    x_step = 1;
-                    y_step = -1;
-                } else if azimuth <= 270f32 {
-                    x_step = -1;
-                    y_step = -1;
-                } else {
-                    x_step = -1;
-                    y_step = 1;
-                }
-
-                let mut flag: bool;
-                let (mut delta_x, mut delta_y): (f32, f32);
-                let (mut x, mut y): (f32, f32);
-                let (mut x1, mut y1): (isize, isize);
-                let (mut x2, mut y2): (isize, isize);
-                let (mut z1, mut z2): (f32, f32);
-                let mut dist: f32;
-                let mut weight: f32;
-                let mut offsets = vec![];
-
-                // Find all of the horizontal grid intersections.
-                if line_slope != 0f32 {
-                    // Otherwise, there are no horizontal intersections.
-                    y = 0f32;
-                    flag = true;
-                    while flag {
-                        y += y_step as f32;
-                        x = y / line_slope;
-
-                        // calculate the distance
-                        delta_x = x * cell_size_x;
-                        delta_y = -y * cell_size_y;
-                        dist = delta_x.hypot(delta_y);
-                        if dist <= max_dist {
-                            x1 = x.floor() as isize;
-                            x2 = x1 + 1;
-                            y1 = -y as isize;
-                            weight = x - x1 as f32;
-                            offsets.push((x1, y1, x2, y1, weight, dist));
-                        } else {
-                            flag = false;
-                        }
-                    }
-                }
-
-                // Find all of the vertical grid intersections.
-                x = 0f32;
-                flag = true;
-                while flag {
-                    x += x_step as f32;
-                    y = -(line_slope * x); // * -1f32;
-
-                    // calculate the distance
-                    delta_x = x * cell_size_x;
-                    delta_y = y * cell_size_y;
-
-                    dist = delta_x.hypot(delta_y);
-                    if dist <= max_dist {
-                        y1 = y.floor() as isize;
-                        y2 = y1 + 1; // - y_step;
-                        x1 = x as isize;
-                        weight = y - y1 as f32;
-                        offsets.push((x1, y1, x1, y2, weight, dist));
-                    } else {
-                        flag = false;
-                    }
-                }
-
-                // Sort by distance.
-                offsets.sort_by(|a, b| a.5.partial_cmp(&b.5).unwrap());
-
-                let num_offsets = offsets.len();
-                let mut z: f32;
-                let mut slope: f32;
-                let early_stopping_slope = 80f32.to_radians().tan();
+            progress::spawn(move || {
+                let offsets = horizon_offsets(azimuth, max_dist, cell_size_x, cell_size_y);
                 let mut current_elev: f32;
-                let mut current_max_slope: f32;
-                let mut current_max_elev: f32;
-                let a_small_value = -9999999f32;
                 for row in (0..rows).filter(|r| r % num_procs == tid) {
                     let mut data: Vec<f64> = vec![nodata; columns as usize];
                     for col in 0..columns {
                         current_elev = input.get_value(row, col);
                         if current_elev != nodata_f32 {
-                            // Run down the offsets of the ray
-                            current_max_slope = a_small_value;
-                            current_max_elev = a_small_value;
-                            for i in 0..num_offsets {
-                                // Where are we on the grid?
-                                x1 = col + offsets[i].0;
-                                y1 = row + offsets[i].1;
-                                x2 = col + offsets[i].2;
-                                y2 = row + offsets[i].3;
-
-                                // What is the elevation?
-                                z1 = input.get_value(y1, x1);
-                                z2 = input.get_value(y2, x2);
-
-                                if z1 == nodata_f32 && z2 == nodata_f32 {
-                                    break; // We're likely off the grid.
-                                } else if z1 == nodata_f32 {
-                                    z1 = z2;
-                                } else if z2 == nodata_f32 {
-                                    z2 = z2;
-                                }
-
-                                z = z1 + offsets[i].4 * (z2 - z1);
-
-                                // All previous cells are nearer, and so if this isn't a higher
-                                // cell than the current highest, it can't be the horizon cell.
-                                if z > current_max_elev {
-                                    current_max_elev = z;
-
-                                    // Calculate the slope
-                                    slope = (z - current_elev) / offsets[i].5;
-                                    if slope > current_max_slope {
-                                        current_max_slope = slope;
-                                        if slope > early_stopping_slope {
-                                            break; // we're unlikely to find a farther horizon cell.
-                                        }
-                                    }
-                                }
-                            }
-
-                            if current_max_slope == a_small_value {
-                                data[col as usize] = 0f64; // It's a zero-length scan. We didn't encounter any valid cells.
-                            } else {
-                                data[col as usize] = current_max_slope.atan().to_degrees() as f64;
-                            }
+                            data[col as usize] =
+                                horizon_angle_at(&input, row, col, &offsets, nodata_f32) as f64;
                         }
                     }
-                    tx.send((row, data)).unwrap();
+                    if tx.send((row, data)).is_err() {
+                        return;
+                    }
                 }
             });
         }
 
         let mut output = Raster::initialize_from_array2d(&output_file, &configs, &input);
         for r in 0..rows {
-            let (row, data) = rx.recv().expect("Error receiving data from thread.");
+            let (row, data) = cancel.recv(&rx)?;
             output.set_row_data(row, data);
 
             if verbose {
                 progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    progress::update(progress);
                     old_progress = progress;
                 }
             }
@@ -482,23 +345,177 @@ impl WhiteboxTool for HorizonAngle {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
     }
 }
+
+/// Calculates the offsets, distances, and interpolation weights of the grid intersections along a ray
+/// traced in the direction of `azimuth` (degrees), out to `max_dist`. The ray-tracing operation can be
+/// viewed as a linear maximum filter and these are the filter offsets, sorted by distance.
+pub(crate) fn horizon_offsets(
+    azimuth: f32,
+    max_dist: f32,
+    cell_size_x: f32,
+    cell_size_y: f32,
+) -> Vec<(isize, isize, isize, isize, f32, f32)> {
+    let line_slope: f32 = if azimuth < 180f32 {
+        (90f32 - azimuth).to_radians().tan()
+    } else {
+        (270f32 - azimuth).to_radians().tan()
+    };
+
+    let x_step: isize;
+    let y_step: isize;
+    if azimuth >= 0f32 && azimuth <= 90f32 {
+        x_step = 1;
+        y_step = 1;
+    } else if azimuth <= 180f32 {
+        x_step = 1;
+        y_step = -1;
+    } else if azimuth <= 270f32 {
+        x_step = -1;
+        y_step = -1;
+    } else {
+        x_step = -1;
+        y_step = 1;
+    }
+
+    let mut flag: bool;
+    let (mut delta_x, mut delta_y): (f32, f32);
+    let (mut x, mut y): (f32, f32);
+    let (mut x1, mut y1): (isize, isize);
+    let (mut x2, mut y2): (isize, isize);
+    let mut dist: f32;
+    let mut weight: f32;
+    let mut offsets = vec![];
+
+    // Find all of the horizontal grid intersections.
+    if line_slope != 0f32 {
+        // Otherwise, there are no horizontal intersections.
+        y = 0f32;
+        flag = true;
+        while flag {
+            y += y_step as f32;
+            x = y / line_slope;
+
+            // calculate the distance
+            delta_x = x * cell_size_x;
+            delta_y = -y * cell_size_y;
+            dist = delta_x.hypot(delta_y);
+            if dist <= max_dist {
+                x1 = x.floor() as isize;
+                x2 = x1 + 1;
+                y1 = -y as isize;
+                weight = x - x1 as f32;
+                offsets.push((x1, y1, x2, y1, weight, dist));
+            } else {
+                flag = false;
+            }
+        }
+    }
+
+    // Find all of the vertical grid intersections.
+    x = 0f32;
+    flag = true;
+    while flag {
+        x += x_step as f32;
+        y = -(line_slope * x); // * -1f32;
+
+        // calculate the distance
+        delta_x = x * cell_size_x;
+        delta_y = y * cell_size_y;
+
+        dist = delta_x.hypot(delta_y);
+        if dist <= max_dist {
+            y1 = y.floor() as isize;
+            y2 = y1 + 1; // - y_step;
+            x1 = x as isize;
+            weight = y - y1 as f32;
+            offsets.push((x1, y1, x1, y2, weight, dist));
+        } else {
+            flag = false;
+        }
+    }
+
+    // Sort by distance.
+    offsets.sort_by(|a, b| a.5.partial_cmp(&b.5).unwrap());
+
+    offsets
+}
+
+/// Calculates the horizon angle, in degrees, of the grid cell at (`row`, `col`) by running down the
+/// ray offsets created by `horizon_offsets`. The cell must not contain nodata.
+pub(crate) fn horizon_angle_at(
+    input: &Array2D<f32>,
+    row: isize,
+    col: isize,
+    offsets: &[(isize, isize, isize, isize, f32, f32)],
+    nodata: f32,
+) -> f32 {
+    let (mut x1, mut y1): (isize, isize);
+    let (mut x2, mut y2): (isize, isize);
+    let (mut z1, mut z2): (f32, f32);
+    let mut z: f32;
+    let mut slope: f32;
+    let early_stopping_slope = 80f32.to_radians().tan();
+    let a_small_value = -9999999f32;
+    let current_elev = input.get_value(row, col);
+    let mut current_max_slope = a_small_value;
+    let mut current_max_elev = a_small_value;
+    for i in 0..offsets.len() {
+        // Where are we on the grid?
+        x1 = col + offsets[i].0;
+        y1 = row + offsets[i].1;
+        x2 = col + offsets[i].2;
+        y2 = row + offsets[i].3;
+
+        // What is the elevation?
+        z1 = input.get_value(y1, x1);
+        z2 = input.get_value(y2, x2);
+
+        if z1 == nodata && z2 == nodata {
+            break; // We're likely off the grid.
+        } else if z1 == nodata {
+            z1 = z2;
+        } else if z2 == nodata {
+            z2 = z1;
+        }
+
+        z = z1 + offsets[i].4 * (z2 - z1);
+
+        // All previous cells are nearer, and so if this isn't a higher
+        // cell than the current highest, it can't be the horizon cell.
+        if z > current_max_elev {
+            current_max_elev = z;
+
+            // Calculate the slope
+            slope = (z - current_elev) / offsets[i].5;
+            if slope > current_max_slope {
+                current_max_slope = slope;
+                if slope > early_stopping_slope {
+                    break; // we're unlikely to find a farther horizon cell.
+                }
+            }
+        }
+    }
+
+    if current_max_slope == a_small_value {
+        0f32 // It's a zero-length scan. We didn't encounter any valid cells.
+    } else {
+        current_max_slope.atan().to_degrees()
+    }
+}
@@ -9,6 +9,7 @@ License: MIT
 use whitebox_raster::Raster;
 use whitebox_common::rendering::html::*;
 use whitebox_common::rendering::LineGraph;
+use whitebox_common::progress;
 use crate::tools::*;
 use std::env;
 use std::f64;
@@ -138,6 +139,7 @@ impl WhiteboxTool for HypsometricAnalysis {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_files_str = String::new();
         let mut watershed_files_str = "".to_string();
@@ -182,13 +184,7 @@ impl WhiteboxTool for HypsometricAnalysis {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -280,10 +276,11 @@ impl WhiteboxTool for HypsometricAnalysis {
                             total += val;
                         }
                     }
+                    cancel.check()?;
                     if verbose {
                         progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                         if progress != old_progress {
-                            println!("Binning the data: {}%", progress);
+                            progress::update_stage("Binning the data", progress);
                             old_progress = progress;
                         }
                     }
@@ -394,10 +391,11 @@ impl WhiteboxTool for HypsometricAnalysis {
                             }
                         }
                     }
+                    cancel.check()?;
                     if verbose {
                         progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                         if progress != old_progress {
-                            println!("Processing watershed data: {}%", progress);
+                            progress::update_stage("Processing watershed data", progress);
                             old_progress = progress;
                         }
                     }
@@ -445,10 +443,11 @@ impl WhiteboxTool for HypsometricAnalysis {
                             }
                         }
                     }
+                    cancel.check()?;
                     if verbose {
                         progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                         if progress != old_progress {
-                            println!("Binning the data: {}%", progress);
+                            progress::update_stage("Binning the data", progress);
                             old_progress = progress;
                         }
                     }
@@ -511,10 +510,7 @@ impl WhiteboxTool for HypsometricAnalysis {
         let _ = writer.flush();
 
         if verbose {
-            println!(
-                "\n{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("\n{}", &format!("Elapsed Time (excluding I/O): {}", elapsed_time)));
         }
 
         if verbose {
@@ -542,7 +538,7 @@ impl WhiteboxTool for HypsometricAnalysis {
                 let _ = output.stdout;
             }
 
-            println!("Complete! Please see {} for output.", output_file);
+            progress::message(format!("Complete! Please see {} for output.", output_file));
         }
 
         Ok(())
@@ -8,6 +8,7 @@ License: MIT
 
 use whitebox_raster::*;
 use whitebox_common::structures::Array2D;
+use whitebox_common::progress;
 use crate::tools::*;
 use num_cpus;
 use rand::prelude::*;
@@ -20,7 +21,6 @@ use std::io::{Error, ErrorKind};
 use std::path;
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::thread;
 
 /// This tool creates a hypsometrically tinted shaded relief (Swiss hillshading) image from an input digital elevation model (DEM).
 /// The tool combines a colourized version of the DEM with varying illumination provided by a hillshade image, to
@@ -69,7 +69,7 @@ impl HypsometricallyTintedHillshade {
         let mut parameters = vec![];
         parameters.push(ToolParameter {
             name: "Input DEM File".to_owned(),
-            flags: vec!["-i".to_owned(), "--dem".to_owned()],
+            flags: vec!["-i".to_owned(), "--input".to_owned(), "--dem".to_owned()],
             description: "Input raster DEM file.".to_owned(),
             parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
             default_value: None,
@@ -175,7 +175,7 @@ impl HypsometricallyTintedHillshade {
 
         parameters.push(ToolParameter {
             name: "Full 360-degree hillshade mode?".to_owned(),
-            flags: vec!["--full_mode".to_owned()],
+            flags: vec!["--fullmode".to_owned(), "--full_mode".to_owned()],
             description:
                 "Optional flag indicating whether to use full 360-degrees of illumination sources."
                     .to_owned(),
@@ -249,6 +249,7 @@ impl WhiteboxTool for HypsometricallyTintedHillshade {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut output_file = String::new();
@@ -400,13 +401,7 @@ impl WhiteboxTool for HypsometricallyTintedHillshade {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -424,7 +419,7 @@ impl WhiteboxTool for HypsometricallyTintedHillshade {
         let relief_alpha = 1f32 - hs_alpha;
 
         if verbose {
-            println!("Reading data...")
+            progress::message("Reading data...")
         };
 
         let input = Arc::new(Raster::new(&input_file, "r")?);
@@ -461,7 +456,7 @@ impl WhiteboxTool for HypsometricallyTintedHillshade {
         for tid in 0..num_procs {
             let input = input.clone();
             let tx1 = tx.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let nodata = input.configs.nodata;
                 let out_nodata = -32768i16;
                 let columns = input.configs.columns as isize;
@@ -545,7 +540,9 @@ impl WhiteboxTool for HypsometricallyTintedHillshade {
                             data[col as usize] = z.round() as i16;
                         }
                     }
-                    tx1.send((row, data)).unwrap();
+                    if tx1.send((row, data)).is_err() {
+                        return;
+                    }
                 }
             });
         }
@@ -559,7 +556,7 @@ impl WhiteboxTool for HypsometricallyTintedHillshade {
         let mut elev: f64;
         let mut bin: usize;
         for row in 0..rows {
-            let data = rx.recv().expect("Error receiving data from thread.");
+            let data = cancel.recv(&rx)?;
             for col in 0..data.1.len() {
                 if data.1[col] != -32768 {
                     bin = data.1[col] as usize;
@@ -575,7 +572,7 @@ impl WhiteboxTool for HypsometricallyTintedHillshade {
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Performing analysis: {}% (Loop 1 of 2)", progress);
+                    progress::message(format!("Performing analysis: {}% (Loop 1 of 2)", progress));
                     old_progress = progress;
                 }
             }
@@ -644,7 +641,7 @@ impl WhiteboxTool for HypsometricallyTintedHillshade {
             let hs = hs.clone();
             let palette = palette.clone();
             let tx1 = tx.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let mut elev_palette = if palette.contains("atlas") {
                     vec![
                         (72f32, 135f32, 55f32),
@@ -1272,19 +1269,21 @@ impl WhiteboxTool for HypsometricallyTintedHillshade {
                             data[col as usize] = out_nodata;
                         }
                     }
-                    tx1.send((row, data)).unwrap();
+                    if tx1.send((row, data)).is_err() {
+                        return;
+                    }
                 }
             });
         }
 
         for row in 0..rows {
-            let data = rx.recv().expect("Error receiving data from thread.");
+            let data = cancel.recv(&rx)?;
             output.set_row_data(data.0, data.1);
 
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Performing analysis: {}%  (Loop 2 of 2)", progress);
+                    progress::message(format!("Performing analysis: {}%  (Loop 2 of 2)", progress));
                     old_progress = progress;
                 }
             }
@@ -1302,21 +1301,18 @@ impl WhiteboxTool for HypsometricallyTintedHillshade {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -0,0 +1,568 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: Dr. John Lindsay
+Created: 18/10/2026
+Last Modified: 18/10/2026
+License: MIT
+*/
+
+use whitebox_raster::*;
+use whitebox_common::structures::Array2D;
+use whitebox_common::progress;
+use crate::tools::*;
+use num_cpus;
+use std::env;
+use std::f64;
+use std::io::{Error, ErrorKind};
+use std::path;
+use std::sync::mpsc;
+use std::sync::Arc;
+
+/// This tool performs the unsupervised, nested-means terrain classification of Iwahashi and Pike (2007)
+/// on an input digital elevation model (DEM; `--dem`). The method characterizes topography using three
+/// measures:
+///
+/// 1. **Slope gradient**, in degrees, calculated using the 3 x 3 method of Horn (1981).
+/// 2. **Surface convexity**, the percentage of grid cells within a circular neighbourhood of radius
+///    `--radius` grid cells that have a positive Laplacian (i.e. 4*z* minus the sum of the four cardinal
+///    neighbours) greater than `--conv_threshold`.
+/// 3. **Surface texture**, the percentage of grid cells within the same neighbourhood that are pits or
+///    peaks, i.e. that differ from the median of their 3 x 3 neighbourhood by more than `--text_threshold`.
+///
+/// Rather than using fixed thresholds, each measure is divided at its mean value, calculated for
+/// progressively more restricted subsets of the landscape. Grid cells are first divided into steep and
+/// gentle groups using the overall mean slope and each group is then divided into high and low convexity
+/// using the group's mean convexity, and into fine and coarse texture using the group's mean texture. This
+/// yields 8 classes (`--num_classes=8`). For 12 classes, the gentle group is further divided at its own mean
+/// slope before the convexity and texture subdivision and for 16 classes, the gentlest group is divided once
+/// more. Classes are numbered from the steepest slope group to the gentlest. Within each slope group, the
+/// four classes are numbered in the order: high convexity and fine texture; high convexity and coarse texture;
+/// low convexity and fine texture; and low convexity and coarse texture. The output raster (`--output`)
+/// is categorical and the classification key is written to the raster's metadata.
+///
+/// Because the class breaks are the means of the data, the classification adapts to the DEM's relief and
+/// resolution and classes are relative to the input area rather than absolute.
+///
+/// # Reference
+/// Iwahashi, J., & Pike, R. J. (2007). Automated classifications of topography from DEMs by an unsupervised
+/// nested-means algorithm and a three-part geometric signature. Geomorphology, 86(3-4), 409-440.
+///
+/// # See Also
+/// `TpiLandformClass`, `PennockLandformClass`, `Geomorphons`, `Slope`
+pub struct IwahashiPikeClass {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl IwahashiPikeClass {
+    pub fn new() -> IwahashiPikeClass {
+        // public constructor
+        let name = "IwahashiPikeClass".to_string();
+        let toolbox = "Geomorphometric Analysis".to_string();
+        let description =
+            "Classifies terrain using the nested-means slope, convexity, and texture method of Iwahashi and Pike (2007)."
+                .to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input DEM File".to_owned(),
+            flags: vec!["-i".to_owned(), "--input".to_owned(), "--dem".to_owned()],
+            description: "Input raster DEM file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output File".to_owned(),
+            flags: vec!["-o".to_owned(), "--output".to_owned()],
+            description: "Output raster file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Number of Classes".to_owned(),
+            flags: vec!["--num_classes".to_owned()],
+            description: "Number of terrain classes (8, 12, or 16).".to_owned(),
+            parameter_type: ParameterType::OptionList(vec![
+                "8".to_owned(),
+                "12".to_owned(),
+                "16".to_owned(),
+            ]),
+            default_value: Some("8".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Neighbourhood Radius (cells)".to_owned(),
+            flags: vec!["--radius".to_owned()],
+            description: "Radius of the circular neighbourhood used to measure convexity and texture, in grid cells.".to_owned(),
+            parameter_type: ParameterType::Integer,
+            default_value: Some("10".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Convexity Threshold".to_owned(),
+            flags: vec!["--conv_threshold".to_owned()],
+            description: "Laplacian threshold above which a grid cell is considered convex.".to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: Some("0.0".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Texture Threshold".to_owned(),
+            flags: vec!["--text_threshold".to_owned()],
+            description: "Elevation difference from the 3 x 3 median above which a grid cell is considered a pit or peak.".to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: Some("1.0".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Z Conversion Factor".to_owned(),
+            flags: vec!["--zfactor".to_owned()],
+            description: "Optional multiplier for when the vertical and horizontal units are not the same.".to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: None,
+            optional: true,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut parent = env::current_exe().unwrap();
+        parent.pop();
+        let p = format!("{}", parent.display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(">>.*{0} -r={1} -v --wd=\"*path*to*data*\" --dem=DEM.tif -o=classes.tif --num_classes=12 --radius=10", short_exe, name).replace("*", &sep);
+
+        IwahashiPikeClass {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for IwahashiPikeClass {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        match serde_json::to_string(&self.parameters) {
+            Ok(json_str) => return format!("{{\"parameters\":{}}}", json_str),
+            Err(err) => return format!("{:?}", err),
+        }
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+        cancel: &CancellationToken,
+    ) -> Result<(), Error> {
+        let args = ParsedArgs::parse(&self.get_tool_parameters(), &args, working_directory)?;
+        self.run_with_parsed_args(args, working_directory, verbose, cancel)
+    }
+
+    fn run_with_parsed_args<'a>(
+        &self,
+        args: ParsedArgs,
+        _working_directory: &'a str,
+        verbose: bool,
+
+        cancel: &CancellationToken,
+    ) -> Result<(), Error> {
+        let input_file = args.get_file("dem").unwrap();
+        let output_file = args.get_file("output").unwrap();
+        let num_classes = match args.get_string("num_classes") {
+            Some(s) => s.parse::<usize>().unwrap_or(8),
+            None => 8,
+        };
+        let mut radius = args.get_integer("radius").unwrap_or(10) as isize;
+        let conv_threshold = args.get_float("conv_threshold").unwrap_or(0.0);
+        let text_threshold = args.get_float("text_threshold").unwrap_or(1.0);
+        let mut z_factor = args.get_float("zfactor").unwrap_or(-1.0);
+
+        if verbose {
+            let tool_name = self.get_tool_name();
+            progress::welcome(&tool_name);
+        }
+
+        let mut progress: usize;
+        let mut old_progress: usize = 1;
+
+        if num_classes != 8 && num_classes != 12 && num_classes != 16 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The number of classes (--num_classes) must be 8, 12, or 16.",
+            ));
+        }
+        if radius < 1 {
+            radius = 1;
+        }
+
+        if verbose {
+            progress::message("Reading data...")
+        };
+
+        let input = Arc::new(Raster::new(&input_file, "r")?);
+
+        let start = Instant::now();
+
+        let rows = input.configs.rows as isize;
+        let columns = input.configs.columns as isize;
+        let nodata = input.configs.nodata;
+        let eight_grid_res = input.configs.resolution_x * 8.0;
+
+        if input.is_in_geographic_coordinates() && z_factor < 0.0 {
+            // calculate a new z-conversion factor
+            let mid_lat = ((input.configs.north + input.configs.south) / 2.0).to_radians();
+            z_factor = 1.0 / (111320.0 * mid_lat.cos());
+        } else if z_factor < 0.0 {
+            z_factor = 1.0;
+        }
+
+        let mut num_procs = num_cpus::get() as isize;
+        let configs = whitebox_common::configs::get_configs()?;
+        let max_procs = configs.max_procs;
+        if max_procs > 0 && max_procs < num_procs {
+            num_procs = max_procs;
+        }
+
+        // Calculate slope and flag the convex and pit/peak cells.
+        let (tx, rx) = mpsc::channel();
+        for tid in 0..num_procs {
+            let input = input.clone();
+            let tx = tx.clone();
+            progress::spawn(move || {
+                let dx = [1, 1, 1, 0, -1, -1, -1, 0];
+                let dy = [-1, 0, 1, 1, 1, 0, -1, -1];
+                let mut n: [f64; 8] = [0.0; 8];
+                let mut window: Vec<f64> = Vec::with_capacity(9);
+                let mut z: f64;
+                let (mut fx, mut fy, mut laplacian, mut median): (f64, f64, f64, f64);
+                for row in (0..rows).filter(|r| r % num_procs == tid) {
+                    let mut data = vec![(nodata, 0u8, 0u8); columns as usize];
+                    for col in 0..columns {
+                        z = input.get_value(row, col);
+                        if z != nodata {
+                            window.clear();
+                            window.push(z);
+                            for c in 0..8 {
+                                n[c] = input.get_value(row + dy[c], col + dx[c]);
+                                if n[c] != nodata {
+                                    window.push(n[c]);
+                                } else {
+                                    n[c] = z;
+                                }
+                            }
+                            fy = (n[6] - n[4] + 2.0 * (n[7] - n[3]) + n[0] - n[2]) * z_factor
+                                / eight_grid_res;
+                            fx = (n[2] - n[4] + 2.0 * (n[1] - n[5]) + n[0] - n[6]) * z_factor
+                                / eight_grid_res;
+                            laplacian = 4.0 * z - n[1] - n[3] - n[5] - n[7];
+                            window.sort_by(|a, b| a.partial_cmp(b).unwrap());
+                            median = if window.len() % 2 == 1 {
+                                window[window.len() / 2]
+                            } else {
+                                (window[window.len() / 2 - 1] + window[window.len() / 2]) / 2.0
+                            };
+                            data[col as usize] = (
+                                (fx * fx + fy * fy).sqrt().atan().to_degrees(),
+                                if laplacian > conv_threshold { 1u8 } else { 0u8 },
+                                if (z - median).abs() > text_threshold { 1u8 } else { 0u8 },
+                            );
+                        }
+                    }
+                    if tx.send((row, data)).is_err() {
+                        return;
+                    }
+                }
+            });
+        }
+
+        // Each row stores running (column-wise) sums of the valid, convex, and pit/peak cells so
+        // that counts within the circular neighbourhood can be found with one lookup per row.
+        let mut slope: Array2D<f64> = Array2D::new(rows, columns, nodata, nodata)?;
+        let mut row_n: Array2D<u32> = Array2D::new(rows, columns + 1, 0, 0)?;
+        let mut row_conv: Array2D<u32> = Array2D::new(rows, columns + 1, 0, 0)?;
+        let mut row_text: Array2D<u32> = Array2D::new(rows, columns + 1, 0, 0)?;
+        for r in 0..rows {
+            let (row, data) = cancel.recv(&rx)?;
+            let (mut sum_n, mut sum_conv, mut sum_text) = (0u32, 0u32, 0u32);
+            for col in 0..columns {
+                let (s, c, t) = data[col as usize];
+                if s != nodata {
+                    slope.set_value(row, col, s);
+                    sum_n += 1;
+                    sum_conv += c as u32;
+                    sum_text += t as u32;
+                }
+                row_n.set_value(row, col + 1, sum_n);
+                row_conv.set_value(row, col + 1, sum_conv);
+                row_text.set_value(row, col + 1, sum_text);
+            }
+            if verbose {
+                progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
+                if progress != old_progress {
+                    progress::update_stage("Calculating slope, convexity, and texture", progress);
+                    old_progress = progress;
+                }
+            }
+        }
+
+        let slope = Arc::new(slope);
+        let row_n = Arc::new(row_n);
+        let row_conv = Arc::new(row_conv);
+        let row_text = Arc::new(row_text);
+
+        // Convert the flags into the percentage of convex and pit/peak cells in the neighbourhood.
+        let half_widths: Vec<isize> = (-radius..=radius)
+            .map(|y| (((radius * radius - y * y) as f64).sqrt()).floor() as isize)
+            .collect();
+        let half_widths = Arc::new(half_widths);
+        let (tx, rx) = mpsc::channel();
+        for tid in 0..num_procs {
+            let slope = slope.clone();
+            let row_n = row_n.clone();
+            let row_conv = row_conv.clone();
+            let row_text = row_text.clone();
+            let half_widths = half_widths.clone();
+            let tx = tx.clone();
+            progress::spawn(move || {
+                for row in (0..rows).filter(|r| r % num_procs == tid) {
+                    let mut data = vec![(nodata, nodata); columns as usize];
+                    for col in 0..columns {
+                        if slope.get_value(row, col) != nodata {
+                            let (mut n, mut conv, mut text) = (0u32, 0u32, 0u32);
+                            for y in -radius..=radius {
+                                let r = row + y;
+                                if r < 0 || r >= rows {
+                                    continue;
+                                }
+                                let w = half_widths[(y + radius) as usize];
+                                let x1 = (col - w).max(0);
+                                let x2 = (col + w + 1).min(columns);
+                                n += row_n.get_value(r, x2) - row_n.get_value(r, x1);
+                                conv += row_conv.get_value(r, x2) - row_conv.get_value(r, x1);
+                                text += row_text.get_value(r, x2) - row_text.get_value(r, x1);
+                            }
+                            data[col as usize] = (
+                                100f64 * conv as f64 / n as f64,
+                                100f64 * text as f64 / n as f64,
+                            );
+                        }
+                    }
+                    if tx.send((row, data)).is_err() {
+                        return;
+                    }
+                }
+            });
+        }
+
+        let mut convexity: Array2D<f64> = Array2D::new(rows, columns, nodata, nodata)?;
+        let mut texture: Array2D<f64> = Array2D::new(rows, columns, nodata, nodata)?;
+        for r in 0..rows {
+            let (row, data) = cancel.recv(&rx)?;
+            for col in 0..columns {
+                convexity.set_value(row, col, data[col as usize].0);
+                texture.set_value(row, col, data[col as usize].1);
+            }
+            if verbose {
+                progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
+                if progress != old_progress {
+                    progress::update_stage("Calculating neighbourhood statistics", progress);
+                    old_progress = progress;
+                }
+            }
+        }
+
+        // Divide the slopes into nested-means groups. Group 0 is the steepest. Each subsequent
+        // split is applied to the gentlest group, using the mean slope of that group.
+        let num_groups = num_classes / 4;
+        let mut group: Array2D<i8> = Array2D::new(rows, columns, -1, -1)?;
+        for row in 0..rows {
+            for col in 0..columns {
+                if slope.get_value(row, col) != nodata {
+                    group.set_value(row, col, 0);
+                }
+            }
+        }
+        for g in 0..(num_groups - 1) as i8 {
+            let (mut sum, mut n) = (0f64, 0f64);
+            for row in 0..rows {
+                for col in 0..columns {
+                    if group.get_value(row, col) == g {
+                        sum += slope.get_value(row, col);
+                        n += 1f64;
+                    }
+                }
+            }
+            if n == 0f64 {
+                break;
+            }
+            let mean = sum / n;
+            for row in 0..rows {
+                for col in 0..columns {
+                    if group.get_value(row, col) == g && slope.get_value(row, col) <= mean {
+                        group.set_value(row, col, g + 1);
+                    }
+                }
+            }
+        }
+
+        // Find the mean convexity and texture within each slope group.
+        let mut conv_mean = vec![0f64; num_groups];
+        let mut text_mean = vec![0f64; num_groups];
+        let mut group_n = vec![0f64; num_groups];
+        for row in 0..rows {
+            for col in 0..columns {
+                let g = group.get_value(row, col);
+                if g >= 0 {
+                    conv_mean[g as usize] += convexity.get_value(row, col);
+                    text_mean[g as usize] += texture.get_value(row, col);
+                    group_n[g as usize] += 1f64;
+                }
+            }
+        }
+        for g in 0..num_groups {
+            if group_n[g] > 0f64 {
+                conv_mean[g] /= group_n[g];
+                text_mean[g] /= group_n[g];
+            }
+        }
+
+        let mut output = Raster::initialize_using_file(&output_file, &input);
+        output.configs.nodata = -128f64;
+        output.configs.data_type = DataType::I8;
+        output.configs.photometric_interp = PhotometricInterpretation::Categorical;
+        output.configs.palette = "qual.plt".to_string();
+        for row in 0..rows {
+            let mut data = vec![-128f64; columns as usize];
+            for col in 0..columns {
+                let g = group.get_value(row, col);
+                if g >= 0 {
+                    let g = g as usize;
+                    let mut class = 4 * g + 1;
+                    if convexity.get_value(row, col) <= conv_mean[g] {
+                        class += 2;
+                    }
+                    if texture.get_value(row, col) <= text_mean[g] {
+                        class += 1;
+                    }
+                    data[col as usize] = class as f64;
+                }
+            }
+            output.set_row_data(row, data);
+            cancel.check()?;
+            if verbose {
+                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+                if progress != old_progress {
+                    progress::update_stage("Classifying", progress);
+                    old_progress = progress;
+                }
+            }
+        }
+
+        let slope_names = match num_groups {
+            2 => vec!["Steep", "Gentle"],
+            3 => vec!["Steep", "Moderate", "Gentle"],
+            _ => vec!["Steep", "Moderate", "Gentle", "Very gentle"],
+        };
+        let mut class_key = vec![];
+        for g in 0..num_groups {
+            for (c, desc) in [
+                "high convexity, fine texture",
+                "high convexity, coarse texture",
+                "low convexity, fine texture",
+                "low convexity, coarse texture",
+            ]
+            .iter()
+            .enumerate()
+            {
+                class_key.push(format!(
+                    "{:<7}{}, {}",
+                    4 * g + c + 1,
+                    slope_names[g],
+                    desc
+                ));
+            }
+        }
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+        output.add_metadata_entry(format!(
+            "Created by whitebox_tools\' {} tool",
+            self.get_tool_name()
+        ));
+        output.add_metadata_entry(format!("Input file: {}", input_file));
+        output.add_metadata_entry(format!("Number of classes: {}", num_classes));
+        output.add_metadata_entry(format!("Neighbourhood radius: {}", radius));
+        output.add_metadata_entry(format!("Convexity threshold: {}", conv_threshold));
+        output.add_metadata_entry(format!("Texture threshold: {}", text_threshold));
+        output.add_metadata_entry(format!("Z-factor: {}", z_factor));
+        output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
+        output.add_metadata_entry(format!("CLASSIFICATION KEY"));
+        output.add_metadata_entry(format!("Value  Class"));
+        for entry in &class_key {
+            output.add_metadata_entry(entry.clone());
+        }
+
+        if verbose {
+            progress::message("Saving data...")
+        };
+        let _ = match output.write() {
+            Ok(_) => {
+                if verbose {
+                    progress::output_written(&output.file_name)
+                }
+            }
+            Err(e) => return Err(e),
+        };
+
+        if verbose {
+            progress::message("CLASSIFICATION KEY");
+            progress::message("Value  Class");
+            for entry in &class_key {
+                progress::message(&entry);
+            }
+
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
+        }
+
+        Ok(())
+    }
+}
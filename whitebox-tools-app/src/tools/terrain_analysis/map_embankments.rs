@@ -8,6 +8,7 @@ License: MIT
 
 use whitebox_raster::*;
 use whitebox_common::structures::{Array2D, Point2D};
+use whitebox_common::progress;
 use crate::tools::*;
 use whitebox_vector::*;
 use std::env;
@@ -20,7 +21,6 @@ use kdtree::KdTree;
 use num_cpus;
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::thread;
 
 /// This tool can be used to map road and rail embankments in a digital elevation model (DEM).
 pub struct MapEmbankments {
@@ -88,6 +88,15 @@ impl MapEmbankments {
             optional: false,
         });
 
+        parameters.push(ToolParameter {
+            name: "Base Contour".to_owned(),
+            flags: vec!["--base".to_owned()],
+            description: "Base contour height.".to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: Some("0.0".to_owned()),
+            optional: true,
+        });
+
         parameters.push(ToolParameter {
             name: "Smoothing Filter Size".to_owned(),
             flags: vec!["--smooth".to_owned()],
@@ -98,6 +107,16 @@ impl MapEmbankments {
             optional: true,
         });
 
+        parameters.push(ToolParameter {
+            name: "Tolerance".to_owned(),
+            flags: vec!["--tolerance".to_owned()],
+            description: "Tolerance factor, in degrees (0-45); determines generalization level."
+                .to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: Some("10.0".to_owned()),
+            optional: true,
+        });
+
         let sep: String = path::MAIN_SEPARATOR.to_string();
         let e = format!("{}", env::current_exe().unwrap().display());
         let mut parent = env::current_exe().unwrap();
@@ -167,6 +186,7 @@ impl WhiteboxTool for MapEmbankments {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut roads_file = String::new();
@@ -189,7 +209,7 @@ impl WhiteboxTool for MapEmbankments {
                 keyval = true;
             }
             let flag_val = vec[0].to_lowercase().replace("--", "-");
-            if flag_val == "-i" || flag_val == "-input" || flag_val == "dem" {
+            if flag_val == "-i" || flag_val == "-input" || flag_val == "-dem" {
                 input_file = if keyval {
                     vec[1].to_string()
                 } else {
@@ -270,13 +290,7 @@ impl WhiteboxTool for MapEmbankments {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -289,7 +303,7 @@ impl WhiteboxTool for MapEmbankments {
         }
 
         if verbose {
-            println!("Reading data...")
+            progress::message("Reading data...")
         };
 
         let input = Arc::new(Raster::new(&input_file, "r").expect("Error reading input raster."));
@@ -341,7 +355,7 @@ impl WhiteboxTool for MapEmbankments {
         for tid in 0..num_procs {
             let input = input.clone();
             let tx = tx.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let mut z: f64;
                 for row in (0..rows).filter(|r| r % num_procs == tid) {
                     let mut data: Vec<f64> = vec![nodata; columns as usize];
@@ -351,20 +365,22 @@ impl WhiteboxTool for MapEmbankments {
                             data[col as usize] = ((z - base_contour) / contour_interval).floor();
                         }
                     }
-                    tx.send((row, data)).expect("Error sending data to thread.");
+                    if tx.send((row, data)).is_err() {
+                        return;
+                    }
                 }
             });
         }
 
         let mut reclassed: Array2D<f64> = Array2D::new(rows, columns, nodata, nodata)?;
         for r in 0..rows {
-            let (row, data) = rx.recv().expect("Error receiving data from thread.");
+            let (row, data) = cancel.recv(&rx)?;
             reclassed.set_row_data(row, data);
 
             if verbose {
                 progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Reclassifying surface: {}%", progress);
+                    progress::update_stage("Reclassifying surface", progress);
                     old_progress = progress;
                 }
             }
@@ -447,10 +463,11 @@ impl WhiteboxTool for MapEmbankments {
                 }
             }
 
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Finding edges: {}%", progress);
+                    progress::update_stage("Finding edges", progress);
                     old_progress = progress;
                 }
             }
@@ -688,11 +705,12 @@ impl WhiteboxTool for MapEmbankments {
                     }
                 }
             }
+            cancel.check()?;
             if verbose {
                 progress =
                     (100.0_f64 * line_segment as f64 / (line_segments.len() - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Tracing contours (Loop 1 of 2): {}%", progress);
+                    progress::update_stage("Tracing contours (Loop 1 of 2)", progress);
                     old_progress = progress;
                 }
             }
@@ -892,11 +910,12 @@ impl WhiteboxTool for MapEmbankments {
                     }
                 }
             }
+            cancel.check()?;
             if verbose {
                 progress =
                     (100.0_f64 * line_segment as f64 / (line_segments.len() - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Tracing contours (Loop 2 of 2): {}%", progress);
+                    progress::update_stage("Tracing contours (Loop 2 of 2)", progress);
                     old_progress = progress;
                 }
             }
@@ -907,21 +926,18 @@ impl WhiteboxTool for MapEmbankments {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
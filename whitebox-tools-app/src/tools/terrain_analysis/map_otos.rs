@@ -8,6 +8,7 @@ License: MIT
 
 use whitebox_raster::*;
 // use crate::structures::Array2D;
+use whitebox_common::progress;
 use crate::tools::*;
 use std::env;
 use std::io::{Error, ErrorKind};
@@ -44,7 +45,7 @@ impl MapOffTerrainObjects {
         let mut parameters = vec![];
         parameters.push(ToolParameter {
             name: "Input DEM File".to_owned(),
-            flags: vec!["-i".to_owned(), "--dem".to_owned()],
+            flags: vec!["-i".to_owned(), "--input".to_owned(), "--dem".to_owned()],
             description: "Input raster DEM file.".to_owned(),
             parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
             default_value: None,
@@ -92,7 +93,7 @@ impl MapOffTerrainObjects {
             short_exe += ".exe";
         }
         let usage = format!(
-            ">>.*{} -r={} -v --wd=\"*path*to*data*\" --dem=DEM.tif -o=output.tif --max_diff=1.0",
+            ">>.*{} -r={} -v --wd=\"*path*to*data*\" --dem=DEM.tif -o=output.tif",
             short_exe, name
         )
         .replace("*", &sep);
@@ -147,6 +148,7 @@ impl WhiteboxTool for MapOffTerrainObjects {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut output_file = String::new();
@@ -210,13 +212,7 @@ impl WhiteboxTool for MapOffTerrainObjects {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -240,7 +236,7 @@ impl WhiteboxTool for MapOffTerrainObjects {
         max_slope = max_slope.to_radians().tan();
 
         if verbose {
-            println!("Reading data...")
+            progress::message("Reading data...")
         }
 
         let input_dem = Raster::new(&input_file, "r")?;
@@ -263,7 +259,7 @@ impl WhiteboxTool for MapOffTerrainObjects {
         /////////////////////////////////////////////
 
         if verbose {
-            println!("Performing the region-growing operation...");
+            progress::message("Performing the region-growing operation...");
         }
 
         let mut output = Raster::initialize_using_config(&output_file, &configs);
@@ -310,10 +306,11 @@ impl WhiteboxTool for MapOffTerrainObjects {
                             }
 
                             num_cells_popped += 1f64;
+                            cancel.check()?;
                             if verbose {
                                 progress = (100.0_f64 * num_cells_popped / num_cells) as usize;
                                 if progress != old_progress {
-                                    println!("Region growing: {}%", progress);
+                                    progress::update_stage("Region growing", progress);
                                     old_progress = progress;
                                 }
                             }
@@ -356,21 +353,18 @@ impl WhiteboxTool for MapOffTerrainObjects {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         }
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
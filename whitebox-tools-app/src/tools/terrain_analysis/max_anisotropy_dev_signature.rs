@@ -10,6 +10,7 @@ use whitebox_raster::Raster;
 use whitebox_common::rendering::html::*;
 use whitebox_common::rendering::LineGraph;
 use whitebox_common::structures::Array2D;
+use whitebox_common::progress;
 use crate::tools::*;
 use whitebox_vector::{ShapeType, Shapefile};
 use std::env;
@@ -39,7 +40,7 @@ impl MaxAnisotropyDevSignature {
         let mut parameters = vec![];
         parameters.push(ToolParameter {
             name: "Input DEM File".to_owned(),
-            flags: vec!["-i".to_owned(), "--dem".to_owned()],
+            flags: vec!["-i".to_owned(), "--input".to_owned(), "--dem".to_owned()],
             description: "Input raster DEM file.".to_owned(),
             parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
             default_value: None,
@@ -158,6 +159,7 @@ impl WhiteboxTool for MaxAnisotropyDevSignature {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut points_file = String::new();
@@ -239,13 +241,7 @@ impl WhiteboxTool for MaxAnisotropyDevSignature {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -264,7 +260,7 @@ impl WhiteboxTool for MaxAnisotropyDevSignature {
         }
 
         if verbose {
-            println!("Reading DEM data...")
+            progress::message("Reading DEM data...")
         };
         let input = Raster::new(&input_file, "r")?;
         let start = Instant::now();
@@ -273,7 +269,7 @@ impl WhiteboxTool for MaxAnisotropyDevSignature {
         let nodata = input.configs.nodata;
 
         if verbose {
-            println!("Reading points data...")
+            progress::message("Reading points data...")
         };
         let points = Shapefile::read(&points_file)?;
 
@@ -301,10 +297,11 @@ impl WhiteboxTool for MaxAnisotropyDevSignature {
                 series_names.push(format!("Site {}", record_num + 1));
             }
 
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Finding site row/column values: {}%", progress);
+                    progress::update_stage("Finding site row/column values", progress);
                     old_progress = progress;
                 }
             }
@@ -347,10 +344,11 @@ impl WhiteboxTool for MaxAnisotropyDevSignature {
                     i_n[(row, col)] = sum_n;
                 }
             }
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Creating integral images: {}%", progress);
+                    progress::update_stage("Creating integral images", progress);
                     old_progress = progress;
                 }
             }
@@ -360,7 +358,7 @@ impl WhiteboxTool for MaxAnisotropyDevSignature {
         for midpoint in (min_scale..max_scale).filter(|s| (s - min_scale) % step == 0) {
             // .step_by(step) { once step_by is stabilized
             if verbose {
-                println!("Filter Size {} / {}", midpoint, max_scale);
+                progress::message(format!("Filter Size {} / {}", midpoint, max_scale));
             }
             middle_pane_radius = (midpoint * 2 + 1) / 6;
 
@@ -580,10 +578,7 @@ impl WhiteboxTool for MaxAnisotropyDevSignature {
 
         let elapsed_time = get_formatted_elapsed_time(start);
         if verbose {
-            println!(
-                "\n{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("\n{}", &format!("Elapsed Time (excluding I/O): {}", elapsed_time)));
         }
 
         let f = File::create(output_file.clone())?;
@@ -663,7 +658,7 @@ impl WhiteboxTool for MaxAnisotropyDevSignature {
                 let _ = output.stdout;
             }
 
-            println!("Complete! Please see {} for output.", output_file);
+            progress::message(format!("Complete! Please see {} for output.", output_file));
         }
 
         Ok(())
@@ -8,6 +8,7 @@ License: MIT
 
 use whitebox_raster::*;
 use whitebox_common::structures::Array2D;
+use whitebox_common::progress;
 use crate::tools::*;
 use num_cpus;
 use std::env;
@@ -16,7 +17,6 @@ use std::io::{Error, ErrorKind};
 use std::path;
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::thread;
 
 /// Calculates the maximum difference from mean elevation over a range of spatial scales.
 pub struct MaxDifferenceFromMean {
@@ -39,7 +39,7 @@ impl MaxDifferenceFromMean {
         let mut parameters = vec![];
         parameters.push(ToolParameter {
             name: "Input DEM File".to_owned(),
-            flags: vec!["-i".to_owned(), "--dem".to_owned()],
+            flags: vec!["-i".to_owned(), "--input".to_owned(), "--dem".to_owned()],
             description: "Input raster DEM file.".to_owned(),
             parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
             default_value: None,
@@ -156,6 +156,7 @@ impl WhiteboxTool for MaxDifferenceFromMean {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut output_mag_file = String::new();
@@ -229,13 +230,7 @@ impl WhiteboxTool for MaxDifferenceFromMean {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -254,7 +249,7 @@ impl WhiteboxTool for MaxDifferenceFromMean {
         }
 
         if verbose {
-            println!("Reading data...")
+            progress::message("Reading data...")
         };
         let input = Arc::new(Raster::new(&input_file, "r")?);
         let start = Instant::now();
@@ -293,10 +288,11 @@ impl WhiteboxTool for MaxDifferenceFromMean {
                     integral_n[(row, col)] = sum_n;
                 }
             }
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Creating integral images: {}%", progress);
+                    progress::update_stage("Creating integral images", progress);
                     old_progress = progress;
                 }
             }
@@ -328,7 +324,7 @@ impl WhiteboxTool for MaxDifferenceFromMean {
                 let i = i.clone();
                 let i_n = i_n.clone();
                 let tx1 = tx.clone();
-                thread::spawn(move || {
+                progress::spawn(move || {
                     let (mut x1, mut x2, mut y1, mut y2): (isize, isize, isize, isize);
                     let mut n: i32;
                     let (mut mean, mut sum): (f64, f64);
@@ -367,14 +363,16 @@ impl WhiteboxTool for MaxDifferenceFromMean {
                             }
                         }
 
-                        tx1.send((row, data)).unwrap();
+                        if tx1.send((row, data)).is_err() {
+                            return;
+                        }
                     }
                 });
             }
 
             let (mut z1, mut z2): (f64, f64);
             for r in 0..rows {
-                let (row, data) = rx.recv().expect("Error receiving data from thread.");
+                let (row, data) = cancel.recv(&rx)?;
                 for col in 0..columns {
                     z2 = data[col as usize];
                     if z2 != nodata {
@@ -393,10 +391,7 @@ impl WhiteboxTool for MaxDifferenceFromMean {
                 if verbose {
                     progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!(
-                            "Progress (Loop {} of {}): {}%",
-                            loop_num, num_loops, progress
-                        );
+                        progress::update_stage(&format!("Progress (Loop {} of {})", loop_num, num_loops), progress);
                         old_progress = progress;
                     }
                 }
@@ -416,12 +411,12 @@ impl WhiteboxTool for MaxDifferenceFromMean {
         output_mag.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving magnitude data...")
+            progress::message("Saving magnitude data...")
         };
         let _ = match output_mag.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output_mag.file_name)
                 }
             }
             Err(e) => return Err(e),
@@ -439,22 +434,19 @@ impl WhiteboxTool for MaxDifferenceFromMean {
         output_scale.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving scale data...")
+            progress::message("Saving scale data...")
         };
         let _ = match output_scale.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output_scale.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", "")
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", ""));
         }
 
         Ok(())
@@ -10,6 +10,7 @@ use whitebox_raster::Raster;
 use whitebox_common::rendering::html::*;
 use whitebox_common::rendering::LineGraph;
 use whitebox_common::structures::Array2D;
+use whitebox_common::progress;
 use crate::tools::*;
 use whitebox_vector::{ShapeType, Shapefile};
 use std::env;
@@ -39,7 +40,7 @@ impl MaxElevDevSignature {
         let mut parameters = vec![];
         parameters.push(ToolParameter {
             name: "Input DEM File".to_owned(),
-            flags: vec!["-i".to_owned(), "--dem".to_owned()],
+            flags: vec!["-i".to_owned(), "--input".to_owned(), "--dem".to_owned()],
             description: "Input raster DEM file.".to_owned(),
             parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
             default_value: None,
@@ -158,6 +159,7 @@ impl WhiteboxTool for MaxElevDevSignature {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut points_file = String::new();
@@ -239,13 +241,7 @@ impl WhiteboxTool for MaxElevDevSignature {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -264,7 +260,7 @@ impl WhiteboxTool for MaxElevDevSignature {
         }
 
         if verbose {
-            println!("Reading data...")
+            progress::message("Reading data...")
         };
         let input = Raster::new(&input_file, "r")?;
         let start = Instant::now();
@@ -274,7 +270,7 @@ impl WhiteboxTool for MaxElevDevSignature {
         let nodata = input.configs.nodata;
 
         if verbose {
-            println!("Reading points data...")
+            progress::message("Reading points data...")
         };
         let points = Shapefile::read(&points_file)?;
 
@@ -302,10 +298,11 @@ impl WhiteboxTool for MaxElevDevSignature {
                 series_names.push(format!("Site {}", record_num + 1));
             }
 
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Finding site row/column values: {}%", progress);
+                    progress::update_stage("Finding site row/column values", progress);
                     old_progress = progress;
                 }
             }
@@ -348,10 +345,11 @@ impl WhiteboxTool for MaxElevDevSignature {
                     i_n[(row, col)] = sum_n;
                 }
             }
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Creating integral images: {}%", progress);
+                    progress::update_stage("Creating integral images", progress);
                     old_progress = progress;
                 }
             }
@@ -410,10 +408,7 @@ impl WhiteboxTool for MaxElevDevSignature {
 
         let elapsed_time = get_formatted_elapsed_time(start);
         if verbose {
-            println!(
-                "\n{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("\n{}", &format!("Elapsed Time (excluding I/O): {}", elapsed_time)));
         }
 
         let f = File::create(output_file.clone())?;
@@ -471,10 +466,7 @@ impl WhiteboxTool for MaxElevDevSignature {
         let _ = writer.flush();
 
         if verbose {
-            println!(
-                "\n{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("\n{}", &format!("Elapsed Time (excluding I/O): {}", elapsed_time)));
         }
 
         if verbose {
@@ -501,7 +493,7 @@ impl WhiteboxTool for MaxElevDevSignature {
                 let _ = output.stdout;
             }
             if verbose {
-                println!("Complete! Please see {} for output.", output_file);
+                progress::message(format!("Complete! Please see {} for output.", output_file));
             }
         }
 
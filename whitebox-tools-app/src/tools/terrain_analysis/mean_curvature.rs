@@ -7,6 +7,7 @@ License: MIT
 */
 
 use whitebox_raster::*;
+use whitebox_common::progress;
 use crate::tools::*;
 use num_cpus;
 use std::env;
@@ -15,7 +16,6 @@ use std::io::{Error, ErrorKind};
 use std::path;
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::thread;
 use whitebox_common::utils::{
     get_formatted_elapsed_time, 
     haversine_distance,
@@ -78,7 +78,7 @@ impl MeanCurvature {
         let mut parameters = vec![];
         parameters.push(ToolParameter {
             name: "Input DEM File".to_owned(),
-            flags: vec!["-i".to_owned(), "--dem".to_owned()],
+            flags: vec!["-i".to_owned(), "--input".to_owned(), "--dem".to_owned()],
             description: "Input raster DEM file.".to_owned(),
             parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
             default_value: None,
@@ -184,6 +184,7 @@ impl WhiteboxTool for MeanCurvature {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let tool_name = self.get_tool_name();
 
@@ -246,13 +247,7 @@ impl WhiteboxTool for MeanCurvature {
         }
 
         if verbose {
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let mut progress: usize;
@@ -297,7 +292,7 @@ impl WhiteboxTool for MeanCurvature {
             for tid in 0..num_procs {
                 let input = input.clone();
                 let tx = tx.clone();
-                thread::spawn(move || {
+                progress::spawn(move || {
                     let mut z12: f64;
                     let mut p: f64;
                     let mut q: f64;
@@ -364,7 +359,9 @@ impl WhiteboxTool for MeanCurvature {
                             }
                         }
 
-                        tx.send((row, data)).unwrap();
+                        if tx.send((row, data)).is_err() {
+                            return;
+                        }
                     }
                 });
             }
@@ -396,7 +393,7 @@ impl WhiteboxTool for MeanCurvature {
             for tid in 0..num_procs {
                 let input = input.clone();
                 let tx = tx.clone();
-                thread::spawn(move || {
+                progress::spawn(move || {
                     let mut z4: f64;
                     let mut p: f64;
                     let mut q: f64;
@@ -531,7 +528,9 @@ impl WhiteboxTool for MeanCurvature {
                             }
                         }
 
-                        tx.send((row, data)).unwrap();
+                        if tx.send((row, data)).is_err() {
+                            return;
+                        }
                     }
                 });
             }
@@ -540,12 +539,12 @@ impl WhiteboxTool for MeanCurvature {
         let mut output = Raster::initialize_using_file(&output_file, &input);
         output.configs.data_type = DataType::F32;
         for row in 0..rows {
-            let (r, data) = rx.recv().expect("Error receiving data from thread.");
+            let (r, data) = cancel.recv(&rx)?;
             output.set_row_data(r, data);
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    progress::update(progress);
                     old_progress = progress;
                 }
             }
@@ -556,7 +555,7 @@ impl WhiteboxTool for MeanCurvature {
         // Output the image //
         //////////////////////
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         
         let elapsed_time = get_formatted_elapsed_time(start);
@@ -571,17 +570,14 @@ impl WhiteboxTool for MeanCurvature {
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -4,6 +4,7 @@ mod average_normal_vector_angular_deviation;
 mod circular_variance_of_aspect;
 mod contours_from_points;
 mod contours_from_raster;
+mod dem_of_difference;
 mod dev_from_mean_elev;
 mod diff_from_mean_elev;
 mod directional_relief;
@@ -25,6 +26,7 @@ mod geomorphons;
 mod hillshade;
 mod horizon_angle;
 mod hypsometric_analysis;
+mod iwahashi_pike_class;
 mod map_otos;
 mod max_anisotropy_dev;
 mod max_anisotropy_dev_signature;
@@ -47,6 +49,7 @@ mod multiscale_std_dev_normals_signature;
 mod multiscale_topographic_position_image;
 mod num_downslope_neighbours;
 mod num_upslope_neighbours;
+mod openness;
 mod pennock_landform_class;
 mod percent_elev_range;
 mod plan_curvature;
@@ -58,8 +61,10 @@ mod relative_topographic_position;
 mod remove_off_terrain_objects;
 mod ruggedness_index;
 mod sediment_transport_index;
+mod sky_view_factor;
 mod slope;
 mod slope_vs_elev_plot;
+mod solar_radiation;
 mod spherical_std_dev_of_normals;
 mod standard_deviation_of_slope;
 mod surface_area_ratio;
@@ -67,6 +72,7 @@ mod tan_curvature;
 mod time_in_daylight;
 mod topographic_hachures;
 mod total_curvature;
+mod tpi_landform_class;
 mod viewshed;
 mod visibility_index;
 mod wetness_index;
@@ -77,6 +83,7 @@ pub use self::average_normal_vector_angular_deviation::AverageNormalVectorAngula
 pub use self::circular_variance_of_aspect::CircularVarianceOfAspect;
 pub use self::contours_from_points::ContoursFromPoints;
 pub use self::contours_from_raster::ContoursFromRaster;
+pub use self::dem_of_difference::DemOfDifference;
 pub use self::dev_from_mean_elev::DevFromMeanElev;
 pub use self::diff_from_mean_elev::DiffFromMeanElev;
 pub use self::directional_relief::DirectionalRelief;
@@ -98,6 +105,7 @@ pub use self::geomorphons::Geomorphons;
 pub use self::hillshade::Hillshade;
 pub use self::horizon_angle::HorizonAngle;
 pub use self::hypsometric_analysis::HypsometricAnalysis;
+pub use self::iwahashi_pike_class::IwahashiPikeClass;
 pub use self::map_otos::MapOffTerrainObjects;
 pub use self::max_anisotropy_dev::MaxAnisotropyDev;
 pub use self::max_anisotropy_dev_signature::MaxAnisotropyDevSignature;
@@ -120,6 +128,7 @@ pub use self::multiscale_std_dev_normals_signature::MultiscaleStdDevNormalsSigna
 pub use self::multiscale_topographic_position_image::MultiscaleTopographicPositionImage;
 pub use self::num_downslope_neighbours::NumDownslopeNeighbours;
 pub use self::num_upslope_neighbours::NumUpslopeNeighbours;
+pub use self::openness::Openness;
 pub use self::pennock_landform_class::PennockLandformClass;
 pub use self::percent_elev_range::PercentElevRange;
 pub use self::plan_curvature::PlanCurvature;
@@ -131,8 +140,10 @@ pub use self::relative_topographic_position::RelativeTopographicPosition;
 pub use self::remove_off_terrain_objects::RemoveOffTerrainObjects;
 pub use self::ruggedness_index::RuggednessIndex;
 pub use self::sediment_transport_index::SedimentTransportIndex;
+pub use self::sky_view_factor::SkyViewFactor;
 pub use self::slope::Slope;
 pub use self::slope_vs_elev_plot::SlopeVsElevationPlot;
+pub use self::solar_radiation::SolarRadiation;
 pub use self::spherical_std_dev_of_normals::SphericalStdDevOfNormals;
 pub use self::standard_deviation_of_slope::StandardDeviationOfSlope;
 pub use self::surface_area_ratio::SurfaceAreaRatio;
@@ -140,6 +151,7 @@ pub use self::tan_curvature::TangentialCurvature;
 pub use self::time_in_daylight::TimeInDaylight;
 pub use self::topographic_hachures::TopographicHachures;
 pub use self::total_curvature::TotalCurvature;
+pub use self::tpi_landform_class::TpiLandformClass;
 pub use self::viewshed::Viewshed;
 pub use self::visibility_index::VisibilityIndex;
 pub use self::wetness_index::WetnessIndex;
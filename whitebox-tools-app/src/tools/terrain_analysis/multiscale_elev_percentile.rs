@@ -8,6 +8,7 @@ License: MIT
 
 use whitebox_raster::*;
 use whitebox_common::structures::Array2D;
+use whitebox_common::progress;
 use crate::tools::*;
 use num_cpus;
 use std::env;
@@ -17,7 +18,6 @@ use std::io::{Error, ErrorKind};
 use std::path;
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::thread;
 
 /// This tool calculates the most elevation percentile (EP) across a range of spatial scales.
 /// EP is a measure of local topographic position (LTP) and expresses the vertical
@@ -91,7 +91,7 @@ impl MultiscaleElevationPercentile {
         let mut parameters = vec![];
         parameters.push(ToolParameter {
             name: "Input DEM File".to_owned(),
-            flags: vec!["-i".to_owned(), "--dem".to_owned()],
+            flags: vec!["-i".to_owned(), "--input".to_owned(), "--dem".to_owned()],
             description: "Input raster DEM file.".to_owned(),
             parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
             default_value: None,
@@ -226,6 +226,7 @@ impl WhiteboxTool for MultiscaleElevationPercentile {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut output_mag_file = String::new();
@@ -318,34 +319,28 @@ impl WhiteboxTool for MultiscaleElevationPercentile {
         }
 
         if step < 1 {
-            eprintln!("Warning: Step value must be at least 1.0. Value set to 1.0.");
+            progress::warning("Step value must be at least 1.0. Value set to 1.0.");
             step = 1;
         }
 
         if step_nonlinearity < 1.0 {
-            eprintln!("Warning: Step nonlinearity value must be great than 1.0. Value set to 1.0.");
+            progress::warning("Step nonlinearity value must be great than 1.0. Value set to 1.0.");
             step_nonlinearity = 1.0;
         }
 
         if step_nonlinearity > 4.0 {
-            eprintln!("Warning: Step nonlinearity is set too high. Value reset to 4.0.");
+            progress::warning("Step nonlinearity is set too high. Value reset to 4.0.");
             step_nonlinearity = 4.0;
         }
 
         if num_steps < 1 {
-            eprintln!("Warning: Number of steps must be at least 1.");
+            progress::warning("Number of steps must be at least 1.");
             num_steps = 1;
         }
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -364,7 +359,7 @@ impl WhiteboxTool for MultiscaleElevationPercentile {
         }
 
         if verbose {
-            println!("Reading data...")
+            progress::message("Reading data...")
         };
         let input = Raster::new(&input_file, "r")?; // Memory requirements: 2.0X, assuming data is stored as f32s
         let start = Instant::now();
@@ -392,7 +387,7 @@ impl WhiteboxTool for MultiscaleElevationPercentile {
         for tid in 0..num_procs {
             let input = input.clone();
             let tx1 = tx.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let mut z: f64;
                 let mut val: i64;
                 for row in (0..rows).filter(|r| r % num_procs == tid) {
@@ -404,18 +399,20 @@ impl WhiteboxTool for MultiscaleElevationPercentile {
                             data[col as usize] = val;
                         }
                     }
-                    tx1.send((row, data)).unwrap();
+                    if tx1.send((row, data)).is_err() {
+                        return;
+                    }
                 }
             });
         }
 
         for row in 0..rows {
-            let data = rx.recv().expect("Error receiving data from thread.");
+            let data = cancel.recv(&rx)?;
             binned_data.set_row_data(data.0, data.1);
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Binning data: {}%", progress);
+                    progress::update_stage("Binning data", progress);
                     old_progress = progress;
                 }
             }
@@ -424,7 +421,7 @@ impl WhiteboxTool for MultiscaleElevationPercentile {
         drop(input); // Memory requirements: 2.0X
 
         if verbose {
-            println!("Initializing grids...");
+            progress::message("Initializing grids...");
         }
         let mut output_mag = Raster::initialize_using_config(&output_mag_file, &configs); // Memory requirements: 4.0X
         let mut output_scale = Raster::initialize_using_config(&output_scale_file, &configs); // Memory requirements: 6.0X
@@ -448,24 +445,18 @@ impl WhiteboxTool for MultiscaleElevationPercentile {
 
             if filter_size > columns.max(rows) { 
                 if verbose {
-                    println!("{}", &format!("Warning: The number of steps resulted in filter sizes that \nexceeded the raster extent. As a result, the simulation was cut \nshort after {} steps.", s - min_scale));
+                    progress::message(format!("Warning: The number of steps resulted in filter sizes that \nexceeded the raster extent. As a result, the simulation was cut \nshort after {} steps.", s - min_scale));
                 }
                 break;
             }
 
-            println!(
-                "Loop {} / {} ({}x{})",
-                s - min_scale + 1,
-                num_steps,
-                filter_size,
-                filter_size
-            );
+            progress::message(format!("Loop {} / {} ({}x{})", s - min_scale + 1, num_steps, filter_size, filter_size));
 
             let (tx, rx) = mpsc::channel();
             for tid in 0..num_procs {
                 let binned_data = bd.clone();
                 let tx1 = tx.clone();
-                thread::spawn(move || {
+                progress::spawn(move || {
                     let (mut bin_val, mut bin_val_n, mut old_bin_val): (i64, i64, i64);
                     let (mut start_col, mut end_col, mut start_row, mut end_row): (
                         isize,
@@ -555,7 +546,9 @@ impl WhiteboxTool for MultiscaleElevationPercentile {
 
                             old_bin_val = bin_val;
                         }
-                        tx1.send((row, data)).unwrap();
+                        if tx1.send((row, data)).is_err() {
+                            return;
+                        }
                     }
                 });
             }
@@ -563,7 +556,7 @@ impl WhiteboxTool for MultiscaleElevationPercentile {
             let mut z1: f64;
             let mut z2: f64;
             for r in 0..rows {
-                let (row, data) = rx.recv().expect("Error receiving data from thread.");
+                let (row, data) = cancel.recv(&rx)?;
                 for col in 0..columns {
                     if data[col as usize] != nodata {
                         z1 = output_mag.get_value(row, col);
@@ -583,7 +576,7 @@ impl WhiteboxTool for MultiscaleElevationPercentile {
                 if verbose {
                     progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Performing analysis: {}%", progress);
+                        progress::update_stage("Performing analysis", progress);
                         old_progress = progress;
                     }
                 }
@@ -592,7 +585,7 @@ impl WhiteboxTool for MultiscaleElevationPercentile {
             if verbose {
                 progress = (s as f32 / num_steps as f32 * 100f32) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    progress::update(progress);
                     old_progress = progress;
                 }
             }
@@ -614,12 +607,12 @@ impl WhiteboxTool for MultiscaleElevationPercentile {
         output_mag.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving magnitude data...")
+            progress::message("Saving magnitude data...")
         };
         let _ = match output_mag.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output_mag.file_name)
                 }
             }
             Err(e) => return Err(e),
@@ -640,22 +633,19 @@ impl WhiteboxTool for MultiscaleElevationPercentile {
         output_scale.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving scale data...")
+            progress::message("Saving scale data...")
         };
         let _ = match output_scale.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output_scale.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", "")
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", ""));
         }
 
         Ok(())
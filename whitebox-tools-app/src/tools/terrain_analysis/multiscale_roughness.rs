@@ -8,6 +8,7 @@ License: MIT
 
 use whitebox_raster::*;
 use whitebox_common::structures::Array2D;
+use whitebox_common::progress;
 use crate::tools::*;
 use num_cpus;
 use std::env;
@@ -18,7 +19,6 @@ use std::ops::SubAssign;
 use std::path;
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::thread;
 
 pub struct MultiscaleRoughness {
     name: String,
@@ -39,7 +39,7 @@ impl MultiscaleRoughness {
         let mut parameters = vec![];
         parameters.push(ToolParameter {
             name: "Input DEM File".to_owned(),
-            flags: vec!["-i".to_owned(), "--dem".to_owned()],
+            flags: vec!["-i".to_owned(), "--input".to_owned(), "--dem".to_owned()],
             description: "Input raster DEM file.".to_owned(),
             parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
             default_value: None,
@@ -156,6 +156,7 @@ impl WhiteboxTool for MultiscaleRoughness {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut output_mag_file = String::new();
@@ -237,13 +238,7 @@ impl WhiteboxTool for MultiscaleRoughness {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -262,7 +257,7 @@ impl WhiteboxTool for MultiscaleRoughness {
         }
 
         if verbose {
-            println!("Reading data...")
+            progress::message("Reading data...")
         };
         let input = Arc::new(Raster::new(&input_file, "r")?);
         let start = Instant::now();
@@ -294,7 +289,7 @@ impl WhiteboxTool for MultiscaleRoughness {
         for tid in 0..num_procs {
             let input = input.clone();
             let tx = tx.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let dx = [1, 1, 1, 0, -1, -1, -1, 0];
                 let dy = [-1, 0, 1, 1, 1, 0, -1, -1];
                 let eight_grid_res = input.configs.resolution_x * 8f64;
@@ -338,7 +333,9 @@ impl WhiteboxTool for MultiscaleRoughness {
                             };
                         }
                     }
-                    tx.send((row, data)).unwrap();
+                    if tx.send((row, data)).is_err() {
+                        return;
+                    }
                 }
             });
         }
@@ -350,13 +347,13 @@ impl WhiteboxTool for MultiscaleRoughness {
         };
         let mut nv: Array2D<Normal> = Array2D::new(rows, columns, zero_vector, zero_vector)?;
         for row in 0..rows {
-            let data = rx.recv().expect("Error receiving data from thread.");
+            let data = cancel.recv(&rx)?;
             nv.set_row_data(data.0, data.1);
 
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Calculating normal vectors: {}%", progress);
+                    progress::update_stage("Calculating normal vectors", progress);
                     old_progress = progress;
                 }
             }
@@ -394,10 +391,11 @@ impl WhiteboxTool for MultiscaleRoughness {
                     integral_n[(row, col)] = sum_n;
                 }
             }
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Creating integral images: {}%", progress);
+                    progress::update_stage("Creating integral images", progress);
                     old_progress = progress;
                 }
             }
@@ -428,19 +426,13 @@ impl WhiteboxTool for MultiscaleRoughness {
 
             if midpoint*2+1 > columns.max(rows) { 
                 if verbose {
-                    println!("{}", &format!("Warning: The number of steps resulted in filter sizes that \nexceeded the raster extent. As a result, the simulation was cut \nshort after {} steps.", midpoint - min_scale));
+                    progress::message(format!("Warning: The number of steps resulted in filter sizes that \nexceeded the raster extent. As a result, the simulation was cut \nshort after {} steps.", midpoint - min_scale));
                 }
                 break;
             }
 
             if verbose {
-                println!(
-                    "Loop {} / {} ({}x{})",
-                    midpoint - min_scale,
-                    max_scale - min_scale,
-                    midpoint*2+1,
-                    midpoint*2+1
-                );
+                progress::message(format!("Loop {} / {} ({}x{})", midpoint - min_scale, max_scale - min_scale, midpoint*2+1, midpoint*2+1));
             }
 
 
@@ -453,7 +445,7 @@ impl WhiteboxTool for MultiscaleRoughness {
                 let i = i.clone();
                 let i_n = i_n.clone();
                 let tx1 = tx.clone();
-                thread::spawn(move || {
+                progress::spawn(move || {
                     let (mut x1, mut x2, mut y1, mut y2): (isize, isize, isize, isize);
                     let mut n: i32;
                     let mut sum: f64;
@@ -489,14 +481,16 @@ impl WhiteboxTool for MultiscaleRoughness {
                             }
                         }
 
-                        tx1.send((row, data)).unwrap();
+                        if tx1.send((row, data)).is_err() {
+                            return;
+                        }
                     }
                 });
             }
 
             let mut smoothed: Array2D<f64> = Array2D::new(rows, columns, 0f64, nodata)?;
             for _ in 0..rows {
-                let (row, data) = rx.recv().expect("Error receiving data from thread.");
+                let (row, data) = cancel.recv(&rx)?;
                 smoothed.set_row_data(row, data);
             }
 
@@ -564,7 +558,7 @@ impl WhiteboxTool for MultiscaleRoughness {
                 let i_n = i_n.clone();
                 let i_diff_nv = i_diff_nv.clone();
                 let tx1 = tx.clone();
-                thread::spawn(move || {
+                progress::spawn(move || {
                     let (mut x1, mut x2, mut y1, mut y2): (isize, isize, isize, isize);
                     let mut n: i32;
                     let mut sum: f64;
@@ -602,13 +596,15 @@ impl WhiteboxTool for MultiscaleRoughness {
                             }
                         }
 
-                        tx1.send((row, data)).unwrap();
+                        if tx1.send((row, data)).is_err() {
+                            return;
+                        }
                     }
                 });
             }
 
             for _ in 0..rows {
-                let (row, data) = rx.recv().expect("Error receiving data from thread.");
+                let (row, data) = cancel.recv(&rx)?;
                 for col in 0..columns {
                     if data[col as usize] > output_mag.get_value(row, col) {
                         output_mag.set_value(row, col, data[col as usize]);
@@ -631,12 +627,12 @@ impl WhiteboxTool for MultiscaleRoughness {
         output_mag.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving magnitude data...")
+            progress::message("Saving magnitude data...")
         };
         let _ = match output_mag.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output_mag.file_name)
                 }
             }
             Err(e) => return Err(e),
@@ -654,22 +650,19 @@ impl WhiteboxTool for MultiscaleRoughness {
         output_scale.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving scale data...")
+            progress::message("Saving scale data...")
         };
         let _ = match output_scale.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output_scale.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", "")
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", ""));
         }
 
         Ok(())
@@ -10,6 +10,7 @@ use whitebox_raster::Raster;
 use whitebox_common::rendering::html::*;
 use whitebox_common::rendering::LineGraph;
 use whitebox_common::structures::Array2D;
+use whitebox_common::progress;
 use crate::tools::*;
 use whitebox_vector::{ShapeType, Shapefile};
 use num_cpus;
@@ -25,7 +26,6 @@ use std::path;
 use std::process::Command;
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::thread;
 
 pub struct MultiscaleRoughnessSignature {
     name: String,
@@ -47,7 +47,7 @@ impl MultiscaleRoughnessSignature {
         let mut parameters = vec![];
         parameters.push(ToolParameter {
             name: "Input DEM File".to_owned(),
-            flags: vec!["-i".to_owned(), "--dem".to_owned()],
+            flags: vec!["-i".to_owned(), "--input".to_owned(), "--dem".to_owned()],
             description: "Input raster DEM file.".to_owned(),
             parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
             default_value: None,
@@ -166,6 +166,7 @@ impl WhiteboxTool for MultiscaleRoughnessSignature {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut points_file = String::new();
@@ -247,13 +248,7 @@ impl WhiteboxTool for MultiscaleRoughnessSignature {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -272,7 +267,7 @@ impl WhiteboxTool for MultiscaleRoughnessSignature {
         }
 
         if verbose {
-            println!("Reading DEM data...")
+            progress::message("Reading DEM data...")
         };
         let input = Arc::new(Raster::new(&input_file, "r")?);
         let start = Instant::now();
@@ -291,7 +286,7 @@ impl WhiteboxTool for MultiscaleRoughnessSignature {
         }
 
         if verbose {
-            println!("Reading points data...")
+            progress::message("Reading points data...")
         };
         let points = Shapefile::read(&points_file)?;
 
@@ -319,11 +314,12 @@ impl WhiteboxTool for MultiscaleRoughnessSignature {
                 series_names.push(format!("Site {}", record_num + 1));
             }
 
+            cancel.check()?;
             if verbose {
                 progress =
                     (100.0_f64 * record_num as f64 / (points.num_records - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Finding site row/column values: {}%", progress);
+                    progress::update_stage("Finding site row/column values", progress);
                     old_progress = progress;
                 }
             }
@@ -342,7 +338,7 @@ impl WhiteboxTool for MultiscaleRoughnessSignature {
         for tid in 0..num_procs {
             let input = input.clone();
             let tx = tx.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let dx = [1, 1, 1, 0, -1, -1, -1, 0];
                 let dy = [-1, 0, 1, 1, 1, 0, -1, -1];
                 let eight_grid_res = input.configs.resolution_x * 8f64;
@@ -386,7 +382,9 @@ impl WhiteboxTool for MultiscaleRoughnessSignature {
                             };
                         }
                     }
-                    tx.send((row, data)).unwrap();
+                    if tx.send((row, data)).is_err() {
+                        return;
+                    }
                 }
             });
         }
@@ -398,13 +396,13 @@ impl WhiteboxTool for MultiscaleRoughnessSignature {
         };
         let mut nv: Array2D<Normal> = Array2D::new(rows, columns, zero_vector, zero_vector)?;
         for row in 0..rows {
-            let data = rx.recv().expect("Error receiving data from thread.");
+            let data = cancel.recv(&rx)?;
             nv.set_row_data(data.0, data.1);
 
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Calculating normal vectors: {}%", progress);
+                    progress::update_stage("Calculating normal vectors", progress);
                     old_progress = progress;
                 }
             }
@@ -442,10 +440,11 @@ impl WhiteboxTool for MultiscaleRoughnessSignature {
                     integral_n[(row, col)] = sum_n;
                 }
             }
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Creating integral images: {}%", progress);
+                    progress::update_stage("Creating integral images", progress);
                     old_progress = progress;
                 }
             }
@@ -467,7 +466,7 @@ impl WhiteboxTool for MultiscaleRoughnessSignature {
             // .step_by(step) { once step_by is stabilized
             // loop_num += 1;
 
-            println!("Filter Size {} / {}", midpoint, max_scale);
+            progress::message(format!("Filter Size {} / {}", midpoint, max_scale));
 
             ////////////////////////////////////////////////////////////////////////////
             // Use the integral image to smooth the DEM at a scale of the filter size //
@@ -478,7 +477,7 @@ impl WhiteboxTool for MultiscaleRoughnessSignature {
                 let i = i.clone();
                 let i_n = i_n.clone();
                 let tx1 = tx.clone();
-                thread::spawn(move || {
+                progress::spawn(move || {
                     let (mut x1, mut x2, mut y1, mut y2): (isize, isize, isize, isize);
                     let mut n: i32;
                     let mut sum: f64;
@@ -514,14 +513,16 @@ impl WhiteboxTool for MultiscaleRoughnessSignature {
                             }
                         }
 
-                        tx1.send((row, data)).unwrap();
+                        if tx1.send((row, data)).is_err() {
+                            return;
+                        }
                     }
                 });
             }
 
             let mut smoothed: Array2D<f64> = Array2D::new(rows, columns, 0f64, nodata)?;
             for _ in 0..rows {
-                let (row, data) = rx.recv().expect("Error receiving data from thread.");
+                let (row, data) = cancel.recv(&rx)?;
                 smoothed.set_row_data(row, data);
             }
 
@@ -624,10 +625,7 @@ impl WhiteboxTool for MultiscaleRoughnessSignature {
 
         let elapsed_time = get_formatted_elapsed_time(start);
         if verbose {
-            println!(
-                "\n{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("\n{}", &format!("Elapsed Time (excluding I/O): {}", elapsed_time)));
         }
 
         let f = File::create(output_file.clone())?;
@@ -707,7 +705,7 @@ impl WhiteboxTool for MultiscaleRoughnessSignature {
                 let _ = output.stdout;
             }
 
-            println!("Complete! Please see {} for output.", output_file);
+            progress::message(format!("Complete! Please see {} for output.", output_file));
         }
 
         Ok(())
@@ -10,6 +10,7 @@ use whitebox_raster::*;
 use whitebox_common::rendering::html::*;
 use whitebox_common::rendering::LineGraph;
 use whitebox_common::structures::Array2D;
+use whitebox_common::progress;
 use crate::tools::*;
 use num_cpus;
 use std::env;
@@ -22,7 +23,6 @@ use std::path::Path;
 use std::process::Command;
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::thread;
 
 /// This tool can be used to map the spatial pattern of maximum spherical standard deviation
 /// (σ<sub>*s max*</sub>; `--out_mag`), as well as the scale at which maximum spherical standard deviation occurs
@@ -77,7 +77,7 @@ impl MultiscaleStdDevNormals {
         let mut parameters = vec![];
         parameters.push(ToolParameter {
             name: "Input DEM File".to_owned(),
-            flags: vec!["-i".to_owned(), "--dem".to_owned()],
+            flags: vec!["-i".to_owned(), "--input".to_owned(), "--dem".to_owned()],
             description: "Input raster DEM file.".to_owned(),
             parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
             default_value: None,
@@ -203,6 +203,7 @@ impl WhiteboxTool for MultiscaleStdDevNormals {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut output_mag_file = String::new();
@@ -292,34 +293,28 @@ impl WhiteboxTool for MultiscaleStdDevNormals {
         // }
 
         if step < 1 {
-            eprintln!("Warning: Step value must be at least 1.0. Value set to 1.0.");
+            progress::warning("Step value must be at least 1.0. Value set to 1.0.");
             step = 1;
         }
 
         if step_nonlinearity < 1.0 {
-            eprintln!("Warning: Step nonlinearity value must be great than 1.0. Value set to 1.0.");
+            progress::warning("Step nonlinearity value must be great than 1.0. Value set to 1.0.");
             step_nonlinearity = 1.0;
         }
 
         if step_nonlinearity > 4.0 {
-            eprintln!("Warning: Step nonlinearity is set too high. Value reset to 4.0.");
+            progress::warning("Step nonlinearity is set too high. Value reset to 4.0.");
             step_nonlinearity = 4.0;
         }
 
         if num_steps < 1 {
-            eprintln!("Warning: Number of steps must be at least 1.");
+            progress::warning("Number of steps must be at least 1.");
             num_steps = 1;
         }
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -338,7 +333,7 @@ impl WhiteboxTool for MultiscaleStdDevNormals {
         }
 
         if verbose {
-            println!("Reading data...")
+            progress::message("Reading data...")
         };
         let input_raster = Raster::new(&input_file, "r")?; // Memory requirements: 2.0X, assuming data is stored as f32s
         let start = Instant::now();
@@ -372,7 +367,7 @@ impl WhiteboxTool for MultiscaleStdDevNormals {
         }
 
         if verbose {
-            println!("Initializing grids...");
+            progress::message("Initializing grids...");
         }
         let mut output_mag = Array2D::<f32>::new(rows, columns, -1f32, nodata)?; // Memory requirements: 2.0X
         let mut output_scale = Array2D::<i16>::new(rows, columns, -32768i16, -32768i16)?; // Memory requirements: 2.5X
@@ -440,18 +435,12 @@ impl WhiteboxTool for MultiscaleStdDevNormals {
             
             let filter_size = midpoint * 2 + 1;
             if filter_size > rows || filter_size > columns {
-                println!("Warning: Operation exited early as a tested scale exceeded the raster extent.");
+                progress::warning("Operation exited early as a tested scale exceeded the raster extent.");
                 break;
             }
 
             if verbose {
-                println!(
-                    "Loop {} / {} ({}x{})",
-                    s - min_scale + 1,
-                    num_steps,
-                    filter_size,
-                    filter_size
-                );
+                progress::message(format!("Loop {} / {} ({}x{})", s - min_scale + 1, num_steps, filter_size, filter_size));
             }
             
             ////////////////////////////////////////
@@ -519,7 +508,7 @@ impl WhiteboxTool for MultiscaleStdDevNormals {
                     let d_y = d_y.clone();
                     let weights = weights.clone();
                     let tx1 = tx.clone();
-                    thread::spawn(move || {
+                    progress::spawn(move || {
                         let (mut sum, mut z_final): (f32, f32);
                         let mut z: f32;
                         let mut zn: f32;
@@ -544,13 +533,15 @@ impl WhiteboxTool for MultiscaleStdDevNormals {
                                 }
                             }
 
-                            tx1.send((row, data)).unwrap();
+                            if tx1.send((row, data)).is_err() {
+                                return;
+                            }
                         }
                     });
                 }
 
                 for _ in 0..rows {
-                    let data = rx.recv().expect("Error receiving data from thread.");
+                    let data = cancel.recv(&rx)?;
                     smoothed_dem.set_row_data(data.0, data.1);
                 }
             } else if filter_size > 3 {
@@ -682,7 +673,7 @@ impl WhiteboxTool for MultiscaleStdDevNormals {
             for tid in 0..num_procs {
                 let smoothed_dem = smoothed_dem.clone();
                 let tx = tx.clone();
-                thread::spawn(move || {
+                progress::spawn(move || {
                     let dx = [1, 1, 1, 0, -1, -1, -1, 0];
                     let dy = [-1, 0, 1, 1, 1, 0, -1, -1];
                     let mut n: [f32; 8] = [0.0; 8];
@@ -722,7 +713,9 @@ impl WhiteboxTool for MultiscaleStdDevNormals {
                                 }
                             }
                         }
-                        tx.send((row, xdata, ydata, zdata)).unwrap();
+                        if tx.send((row, xdata, ydata, zdata)).is_err() {
+                            return;
+                        }
                     }
                 });
             }
@@ -732,7 +725,7 @@ impl WhiteboxTool for MultiscaleStdDevNormals {
             let mut yc: Array2D<f64> = Array2D::new(rows, columns, 0f64, -1f64)?; // Memory requirements: 8.5X
             let mut zc: Array2D<f64> = Array2D::new(rows, columns, 0f64, -1f64)?; // Memory requirements: 10.5X
             for _ in 0..rows {
-                let data = rx.recv().expect("Error receiving data from thread.");
+                let data = cancel.recv(&rx)?;
                 xc.set_row_data(data.0, data.1);
                 yc.set_row_data(data.0, data.2);
                 zc.set_row_data(data.0, data.3);
@@ -780,7 +773,7 @@ impl WhiteboxTool for MultiscaleStdDevNormals {
                 let input = input.clone();
                 let i_n = i_n.clone();
                 let tx2 = tx2.clone();
-                thread::spawn(move || {
+                progress::spawn(move || {
                     let (mut x1, mut x2, mut y1, mut y2): (isize, isize, isize, isize);
                     let mut n: f32;
                     let (mut sumx, mut sumy, mut sumz): (f64, f64, f64);
@@ -849,10 +842,7 @@ impl WhiteboxTool for MultiscaleStdDevNormals {
                         match tx2.send((row, data)) {
                             Ok(_) => {}
                             Err(_) => {
-                                println!(
-                                    "Error sending data from thread {} processing row {}.",
-                                    tid, row
-                                );
+                                progress::message(format!("Error sending data from thread {} processing row {}.", tid, row));
                             }
                         }
                     }
@@ -896,7 +886,7 @@ impl WhiteboxTool for MultiscaleStdDevNormals {
             if verbose {
                 progress = (s as f32 / num_steps as f32 * 100f32) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    progress::update(progress);
                     old_progress = progress;
                 }
             }
@@ -929,12 +919,12 @@ impl WhiteboxTool for MultiscaleStdDevNormals {
             .add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving magnitude data...")
+            progress::message("Saving magnitude data...")
         };
         let _ = match output_mag_raster.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output_mag_raster.file_name)
                 }
             }
             Err(e) => return Err(e),
@@ -963,22 +953,19 @@ impl WhiteboxTool for MultiscaleStdDevNormals {
             .add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving scale data...")
+            progress::message("Saving scale data...")
         };
         let _ = match output_scale_raster.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output_scale_raster.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", "")
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", ""));
         }
 
         // Memory requirements: 0.0X (when output_scale_raster is freed automatically at end of scope)
@@ -1060,10 +1047,7 @@ impl WhiteboxTool for MultiscaleStdDevNormals {
                 let _ = output.stdout;
             }
 
-            println!(
-                "Complete! Please see {} for signature output.",
-                signature_file
-            );
+            progress::message(format!("Complete! Please see {} for signature output.", signature_file));
         }
 
         Ok(())
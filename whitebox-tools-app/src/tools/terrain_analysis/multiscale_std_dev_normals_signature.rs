@@ -10,6 +10,7 @@ use whitebox_raster::Raster;
 use whitebox_common::rendering::html::*;
 use whitebox_common::rendering::LineGraph;
 use whitebox_common::structures::Array2D;
+use whitebox_common::progress;
 use crate::tools::*;
 use whitebox_vector::{ShapeType, Shapefile};
 use num_cpus;
@@ -23,7 +24,6 @@ use std::path;
 use std::process::Command;
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::thread;
 
 pub struct MultiscaleStdDevNormalsSignature {
     name: String,
@@ -45,7 +45,7 @@ impl MultiscaleStdDevNormalsSignature {
         let mut parameters = vec![];
         parameters.push(ToolParameter {
             name: "Input DEM File".to_owned(),
-            flags: vec!["-i".to_owned(), "--dem".to_owned()],
+            flags: vec!["-i".to_owned(), "--input".to_owned(), "--dem".to_owned()],
             description: "Input raster DEM file.".to_owned(),
             parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
             default_value: None,
@@ -173,6 +173,7 @@ impl WhiteboxTool for MultiscaleStdDevNormalsSignature {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut points_file = String::new();
@@ -252,34 +253,28 @@ impl WhiteboxTool for MultiscaleStdDevNormalsSignature {
         }
 
         if step < 1 {
-            eprintln!("Warning: Step value must be at least 1.0. Value set to 1.0.");
+            progress::warning("Step value must be at least 1.0. Value set to 1.0.");
             step = 1;
         }
 
         if step_nonlinearity < 1.0 {
-            eprintln!("Warning: Step nonlinearity value must be great than 1.0. Value set to 1.0.");
+            progress::warning("Step nonlinearity value must be great than 1.0. Value set to 1.0.");
             step_nonlinearity = 1.0;
         }
 
         if step_nonlinearity > 4.0 {
-            eprintln!("Warning: Step nonlinearity is set too high. Value reset to 4.0.");
+            progress::warning("Step nonlinearity is set too high. Value reset to 4.0.");
             step_nonlinearity = 4.0;
         }
 
         if num_steps < 1 {
-            eprintln!("Warning: Number of steps must be at least 1.");
+            progress::warning("Number of steps must be at least 1.");
             num_steps = 1;
         }
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -298,7 +293,7 @@ impl WhiteboxTool for MultiscaleStdDevNormalsSignature {
         }
 
         if verbose {
-            println!("Reading DEM data...")
+            progress::message("Reading DEM data...")
         };
         let input_raster = Raster::new(&input_file, "r")?; // Memory requirements: 2.0X, assuming data is stored as f32s
         let start = Instant::now();
@@ -324,7 +319,7 @@ impl WhiteboxTool for MultiscaleStdDevNormalsSignature {
         }
 
         if verbose {
-            println!("Reading points data...")
+            progress::message("Reading points data...")
         };
         let points = Shapefile::read(&points_file)?;
 
@@ -353,17 +348,18 @@ impl WhiteboxTool for MultiscaleStdDevNormalsSignature {
                 series_names.push(format!("Site {}", record_num + 1));
             }
 
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Finding site row/column values: {}%", progress);
+                    progress::update_stage("Finding site row/column values", progress);
                     old_progress = progress;
                 }
             }
         }
 
         if verbose {
-            println!("Initializing grids...");
+            progress::message("Initializing grids...");
         }
 
         // calculate the 'n' integral image
@@ -417,7 +413,7 @@ impl WhiteboxTool for MultiscaleStdDevNormalsSignature {
         for s in min_scale..(min_scale + num_steps) {
             let midpoint = min_scale
                 + ((((s - min_scale) * step) as f32).powf(step_nonlinearity)).floor() as isize;
-            println!("Loop {} / {}", s - min_scale + 1, num_steps);
+            progress::message(format!("Loop {} / {}", s - min_scale + 1, num_steps));
 
             let filter_size = midpoint * 2 + 1;
 
@@ -486,7 +482,7 @@ impl WhiteboxTool for MultiscaleStdDevNormalsSignature {
                     let d_y = d_y.clone();
                     let weights = weights.clone();
                     let tx1 = tx.clone();
-                    thread::spawn(move || {
+                    progress::spawn(move || {
                         let (mut sum, mut z_final): (f32, f32);
                         let mut z: f32;
                         let mut zn: f32;
@@ -511,13 +507,15 @@ impl WhiteboxTool for MultiscaleStdDevNormalsSignature {
                                 }
                             }
 
-                            tx1.send((row, data)).unwrap();
+                            if tx1.send((row, data)).is_err() {
+                                return;
+                            }
                         }
                     });
                 }
 
                 for _ in 0..rows {
-                    let data = rx.recv().expect("Error receiving data from thread.");
+                    let data = cancel.recv(&rx)?;
                     smoothed_dem.set_row_data(data.0, data.1);
                 }
             } else if filter_size > 3 {
@@ -649,7 +647,7 @@ impl WhiteboxTool for MultiscaleStdDevNormalsSignature {
             for tid in 0..num_procs {
                 let smoothed_dem = smoothed_dem.clone();
                 let tx = tx.clone();
-                thread::spawn(move || {
+                progress::spawn(move || {
                     let dx = [1, 1, 1, 0, -1, -1, -1, 0];
                     let dy = [-1, 0, 1, 1, 1, 0, -1, -1];
                     let mut n: [f32; 8] = [0.0; 8];
@@ -689,7 +687,9 @@ impl WhiteboxTool for MultiscaleStdDevNormalsSignature {
                                 }
                             }
                         }
-                        tx.send((row, xdata, ydata, zdata)).unwrap();
+                        if tx.send((row, xdata, ydata, zdata)).is_err() {
+                            return;
+                        }
                     }
                 });
             }
@@ -699,7 +699,7 @@ impl WhiteboxTool for MultiscaleStdDevNormalsSignature {
             let mut yc: Array2D<f64> = Array2D::new(rows, columns, 0f64, -1f64)?; // Memory requirements: 8.5X
             let mut zc: Array2D<f64> = Array2D::new(rows, columns, 0f64, -1f64)?; // Memory requirements: 10.5X
             for _ in 0..rows {
-                let data = rx.recv().expect("Error receiving data from thread.");
+                let data = cancel.recv(&rx)?;
                 xc.set_row_data(data.0, data.1);
                 yc.set_row_data(data.0, data.2);
                 zc.set_row_data(data.0, data.3);
@@ -796,7 +796,7 @@ impl WhiteboxTool for MultiscaleStdDevNormalsSignature {
             if verbose {
                 progress = (s as f32 / num_steps as f32 * 100f32) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    progress::update(progress);
                     old_progress = progress;
                 }
             }
@@ -804,10 +804,7 @@ impl WhiteboxTool for MultiscaleStdDevNormalsSignature {
 
         let elapsed_time = get_formatted_elapsed_time(start);
         if verbose {
-            println!(
-                "\n{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("\n{}", &format!("Elapsed Time (excluding I/O): {}", elapsed_time)));
         }
 
         let f = File::create(output_file.clone())?;
@@ -882,7 +879,7 @@ impl WhiteboxTool for MultiscaleStdDevNormalsSignature {
                 let _ = output.stdout;
             }
 
-            println!("Complete! Please see {} for output.", output_file);
+            progress::message(format!("Complete! Please see {} for output.", output_file));
         }
 
         Ok(())
@@ -10,13 +10,13 @@ use whitebox_raster::*;
 use crate::tools::*;
 use num_cpus;
 use whitebox_common::structures::Array2D;
+use whitebox_common::progress;
 use std::env;
 use std::f64;
 use std::io::{Error, ErrorKind};
 use std::path;
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::thread;
 
 /// This tool creates a multiscale topographic position (MTP) image ([see here for an
 /// example](https://www.uoguelph.ca/~hydrogeo/pubs/UpdatedPosterMapSm.png)) from three DEV<sub>max</sub> rasters of differing
@@ -182,6 +182,7 @@ impl WhiteboxTool for MultiscaleTopographicPositionImage {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input1_file = String::new();
         let mut input2_file = String::new();
@@ -254,13 +255,7 @@ impl WhiteboxTool for MultiscaleTopographicPositionImage {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -285,7 +280,7 @@ impl WhiteboxTool for MultiscaleTopographicPositionImage {
         }
 
         if verbose {
-            println!("Reading broad-scale DEV data...")
+            progress::message("Reading broad-scale DEV data...")
         };
         let input_r = Arc::new(Raster::new(&input1_file, "r")?);
 
@@ -293,16 +288,16 @@ impl WhiteboxTool for MultiscaleTopographicPositionImage {
         let columns = input_r.configs.columns as isize;
         
         if verbose {
-            println!("Reading meso-scale DEV data...")
+            progress::message("Reading meso-scale DEV data...")
         };
         let input_g = Arc::new(Raster::new(&input2_file, "r")?);
         if verbose {
-            println!("Reading local-scale DEV data...")
+            progress::message("Reading local-scale DEV data...")
         };
         let input_b = Arc::new(Raster::new(&input3_file, "r")?);
 
         if hs_specified && verbose {
-            println!("Preparing hillshade data...")
+            progress::message("Preparing hillshade data...")
         };
         let (hs, hs_min, hs_range) = if hs_specified {
             let hs = Raster::new(&hs_file, "r")?;
@@ -368,7 +363,7 @@ impl WhiteboxTool for MultiscaleTopographicPositionImage {
             let input_b = input_b.clone();
             let hs = hs.clone();
             let tx = tx.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let mut red_val: f64;
                 let mut green_val: f64;
                 let mut blue_val: f64;
@@ -424,7 +419,9 @@ impl WhiteboxTool for MultiscaleTopographicPositionImage {
                                 ((255 << 24) | (blue << 16) | (green << 8) | red) as f64;
                         }
                     }
-                    tx.send((row, data)).unwrap();
+                    if tx.send((row, data)).is_err() {
+                        return;
+                    }
                 }
             });
         }
@@ -433,12 +430,12 @@ impl WhiteboxTool for MultiscaleTopographicPositionImage {
         output.configs.photometric_interp = PhotometricInterpretation::RGB;
         output.configs.data_type = DataType::RGBA32;
         for row in 0..rows {
-            let data = rx.recv().expect("Error receiving data from thread.");
+            let data = cancel.recv(&rx)?;
             output.set_row_data(data.0, data.1);
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    progress::update(progress);
                     old_progress = progress;
                 }
             }
@@ -455,21 +452,18 @@ impl WhiteboxTool for MultiscaleTopographicPositionImage {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -0,0 +1,402 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: Dr. John Lindsay
+Created: 18/10/2026
+Last Modified: 18/10/2026
+License: MIT
+*/
+
+use whitebox_raster::*;
+use whitebox_common::structures::Array2D;
+use whitebox_common::progress;
+use super::horizon_angle::{horizon_angle_at, horizon_offsets};
+use crate::tools::*;
+use num_cpus;
+use std::env;
+use std::f64;
+use std::io::{Error, ErrorKind};
+use std::path;
+use std::sync::mpsc;
+use std::sync::Arc;
+
+/// This tool calculates the topographic openness index from an input digital elevation model (DEM; `--input`).
+/// Openness is an angular measure of the relation between surface relief and horizontal distance (Yokoyama et al.,
+/// 2002). Positive openness (`--pos_output`) expresses the openness of a location to the sky, and is the average,
+/// over a number of azimuth directions (`--num_dirs`; default 8), of the zenith angle of the horizon within a
+/// search distance (`--dist`), measured in grid cells. Negative openness (`--neg_output`) expresses the openness
+/// of a location below the surface, and is the average nadir angle of the lowest point, i.e. the deepest depression,
+/// within the search distance in each direction. Both measures are expressed in degrees and are typically near
+/// 90 degrees on flat terrain. Convex landforms, such as ridges and peaks, have high positive openness and low
+/// negative openness, while concave landforms, such as valleys and pits, have low positive openness and high
+/// negative openness.
+///
+/// Openness is less sensitive to the illumination direction than hillshading, which makes it useful for
+/// visualizing subtle landforms in high-resolution LiDAR DEMs, e.g. archaeological features. The horizon angles
+/// are calculated using the same ray-tracing method as the `HorizonAngle` tool and the tool is multi-threaded,
+/// with each ray interpolating elevations where it intersects the DEM grid. Larger search distances and numbers
+/// of directions will result in longer run-times.
+///
+/// # Reference
+/// Yokoyama, R., Shirasawa, M., & Pike, R. J. (2002). Visualizing topography by openness: a new application of
+/// image processing to digital elevation models. Photogrammetric Engineering and Remote Sensing, 68(3), 257-266.
+///
+/// # See Also
+/// `SkyViewFactor`, `HorizonAngle`, `Hillshade`
+pub struct Openness {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl Openness {
+    pub fn new() -> Openness {
+        // public constructor
+        let name = "Openness".to_string();
+        let toolbox = "Geomorphometric Analysis".to_string();
+        let description =
+            "This tool calculates the topographic openness index from an input DEM.".to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input DEM File".to_owned(),
+            flags: vec!["-i".to_owned(), "--dem".to_owned(), "--input".to_owned()],
+            description: "Name of the input raster DEM file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Positive Openness Output File".to_owned(),
+            flags: vec!["--pos_output".to_owned()],
+            description: "Name of the positive openness output raster file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Negative Openness Output File".to_owned(),
+            flags: vec!["--neg_output".to_owned()],
+            description: "Name of the negative openness output raster file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Search Distance (grid cells)".to_owned(),
+            flags: vec!["--dist".to_owned()],
+            description: "Search distance, in grid cells.".to_owned(),
+            parameter_type: ParameterType::Integer,
+            default_value: Some("20".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Number of Directions".to_owned(),
+            flags: vec!["--num_dirs".to_owned()],
+            description: "Number of azimuth directions used to calculate openness.".to_owned(),
+            parameter_type: ParameterType::Integer,
+            default_value: Some("8".to_owned()),
+            optional: true,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut parent = env::current_exe().unwrap();
+        parent.pop();
+        let p = format!("{}", parent.display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(">>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=dem.tif --pos_output=pos_open.tif --neg_output=neg_open.tif --dist=50 --num_dirs=16", short_exe, name).replace("*", &sep);
+
+        Openness {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for Openness {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        match serde_json::to_string(&self.parameters) {
+            Ok(json_str) => return format!("{{\"parameters\":{}}}", json_str),
+            Err(err) => return format!("{:?}", err),
+        }
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+        cancel: &CancellationToken,
+    ) -> Result<(), Error> {
+        let mut input_file = String::new();
+        let mut pos_output_file = String::new();
+        let mut neg_output_file = String::new();
+        let mut dist = 20isize;
+        let mut num_dirs = 8usize;
+
+        if args.len() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Tool run with no parameters.",
+            ));
+        }
+        for i in 0..args.len() {
+            let mut arg = args[i].replace("\"", "");
+            arg = arg.replace("\'", "");
+            let cmd = arg.split("="); // in case an equals sign was used
+            let vec = cmd.collect::<Vec<&str>>();
+            let mut keyval = false;
+            if vec.len() > 1 {
+                keyval = true;
+            }
+            let flag_val = vec[0].to_lowercase().replace("--", "-");
+            if flag_val == "-i" || flag_val == "-input" || flag_val == "-dem" {
+                input_file = if keyval {
+                    vec[1].to_string()
+                } else {
+                    args[i + 1].to_string()
+                };
+            } else if flag_val == "-pos_output" {
+                pos_output_file = if keyval {
+                    vec[1].to_string()
+                } else {
+                    args[i + 1].to_string()
+                };
+            } else if flag_val == "-neg_output" {
+                neg_output_file = if keyval {
+                    vec[1].to_string()
+                } else {
+                    args[i + 1].to_string()
+                };
+            } else if flag_val == "-dist" {
+                dist = if keyval {
+                    vec[1]
+                        .to_string()
+                        .parse::<f32>()
+                        .expect(&format!("Error parsing {}", flag_val)) as isize
+                } else {
+                    args[i + 1]
+                        .to_string()
+                        .parse::<f32>()
+                        .expect(&format!("Error parsing {}", flag_val)) as isize
+                };
+            } else if flag_val == "-num_dirs" {
+                num_dirs = if keyval {
+                    vec[1]
+                        .to_string()
+                        .parse::<f32>()
+                        .expect(&format!("Error parsing {}", flag_val)) as usize
+                } else {
+                    args[i + 1]
+                        .to_string()
+                        .parse::<f32>()
+                        .expect(&format!("Error parsing {}", flag_val)) as usize
+                };
+            }
+        }
+
+        if verbose {
+            let tool_name = self.get_tool_name();
+            progress::welcome(&tool_name);
+        }
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+
+        let mut progress: usize;
+        let mut old_progress: usize = 1;
+
+        if !input_file.contains(&sep) && !input_file.contains("/") {
+            input_file = format!("{}{}", working_directory, input_file);
+        }
+        if !pos_output_file.contains(&sep) && !pos_output_file.contains("/") {
+            pos_output_file = format!("{}{}", working_directory, pos_output_file);
+        }
+        if !neg_output_file.contains(&sep) && !neg_output_file.contains("/") {
+            neg_output_file = format!("{}{}", working_directory, neg_output_file);
+        }
+
+        if dist < 1 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The search distance (--dist) must be at least one grid cell.",
+            ));
+        }
+        if num_dirs < 2 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The number of directions (--num_dirs) must be at least two.",
+            ));
+        }
+
+        if verbose {
+            progress::message("Reading data...")
+        };
+        let inputf64 = Raster::new(&input_file, "r")?;
+        let configs = inputf64.configs.clone();
+        let mut cell_size_x = configs.resolution_x as f32;
+        let mut cell_size_y = configs.resolution_y as f32;
+        if inputf64.is_in_geographic_coordinates() {
+            let mid_lat = ((configs.north + configs.south) / 2.0).to_radians() as f32;
+            cell_size_x = cell_size_x * (111320.0 * mid_lat.cos());
+            cell_size_y = cell_size_y * 111320.0;
+        }
+        let max_dist = dist as f32 * cell_size_x.max(cell_size_y);
+
+        let rows = configs.rows as isize;
+        let columns = configs.columns as isize;
+        let nodata = configs.nodata;
+        let nodata_f32 = nodata as f32;
+
+        let start = Instant::now();
+
+        // Negative openness is the positive openness of the inverted surface.
+        let input = Arc::new(inputf64.get_data_as_f32_array2d());
+        let mut inverted: Array2D<f32> = Array2D::new(rows, columns, nodata_f32, nodata_f32)?;
+        let mut z: f32;
+        for row in 0..rows {
+            for col in 0..columns {
+                z = input.get_value(row, col);
+                if z != nodata_f32 {
+                    inverted.set_value(row, col, -z);
+                }
+            }
+        }
+        let inverted = Arc::new(inverted);
+
+        let mut num_procs = num_cpus::get() as isize;
+        let configurations = whitebox_common::configs::get_configs()?;
+        let max_procs = configurations.max_procs;
+        if max_procs > 0 && max_procs < num_procs {
+            num_procs = max_procs;
+        }
+        let (tx, rx) = mpsc::channel();
+        for tid in 0..num_procs {
+            let input = input.clone();
+            let inverted = inverted.clone();
+            let tx = tx.clone();
+            progress::spawn(move || {
+                let offsets: Vec<Vec<(isize, isize, isize, isize, f32, f32)>> = (0..num_dirs)
+                    .map(|d| {
+                        let azimuth = d as f32 * 360f32 / num_dirs as f32;
+                        horizon_offsets(azimuth, max_dist, cell_size_x, cell_size_y)
+                    })
+                    .collect();
+                let (mut pos, mut neg): (f64, f64);
+                for row in (0..rows).filter(|r| r % num_procs == tid) {
+                    let mut pos_data = vec![nodata; columns as usize];
+                    let mut neg_data = vec![nodata; columns as usize];
+                    for col in 0..columns {
+                        if input.get_value(row, col) != nodata_f32 {
+                            pos = 0f64;
+                            neg = 0f64;
+                            for d in 0..num_dirs {
+                                pos += 90f64
+                                    - horizon_angle_at(&input, row, col, &offsets[d], nodata_f32)
+                                        as f64;
+                                neg += 90f64
+                                    - horizon_angle_at(&inverted, row, col, &offsets[d], nodata_f32)
+                                        as f64;
+                            }
+                            pos_data[col as usize] = pos / num_dirs as f64;
+                            neg_data[col as usize] = neg / num_dirs as f64;
+                        }
+                    }
+                    if tx.send((row, pos_data, neg_data)).is_err() {
+                        return;
+                    }
+                }
+            });
+        }
+
+        let mut pos_output = Raster::initialize_using_file(&pos_output_file, &inputf64);
+        pos_output.configs.data_type = DataType::F32;
+        let mut neg_output = Raster::initialize_using_file(&neg_output_file, &inputf64);
+        neg_output.configs.data_type = DataType::F32;
+        for r in 0..rows {
+            let (row, pos_data, neg_data) = cancel.recv(&rx)?;
+            pos_output.set_row_data(row, pos_data);
+            neg_output.set_row_data(row, neg_data);
+            if verbose {
+                progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
+                if progress != old_progress {
+                    progress::update(progress);
+                    old_progress = progress;
+                }
+            }
+        }
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+        for output in [&mut pos_output, &mut neg_output] {
+            output.configs.palette = "grey.plt".to_string();
+            output.add_metadata_entry(format!(
+                "Created by whitebox_tools\' {} tool",
+                self.get_tool_name()
+            ));
+            output.add_metadata_entry(format!("Input file: {}", input_file));
+            output.add_metadata_entry(format!("Search distance: {}", dist));
+            output.add_metadata_entry(format!("Number of directions: {}", num_dirs));
+            output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
+        }
+
+        if verbose {
+            progress::message("Saving data...")
+        };
+        let _ = match pos_output.write() {
+            Ok(_) => {
+                if verbose {
+                    progress::message("Positive openness file written")
+                }
+            }
+            Err(e) => return Err(e),
+        };
+        let _ = match neg_output.write() {
+            Ok(_) => {
+                if verbose {
+                    progress::message("Negative openness file written")
+                }
+            }
+            Err(e) => return Err(e),
+        };
+        if verbose {
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
+        }
+
+        Ok(())
+    }
+}
@@ -9,6 +9,7 @@ Notes: Some degree of DEM smoothing is likely required to get reasonable results
 */
 
 use whitebox_raster::*;
+use whitebox_common::progress;
 use crate::tools::*;
 use num_cpus;
 use std::env;
@@ -17,7 +18,6 @@ use std::io::{Error, ErrorKind};
 use std::path;
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::thread;
 
 /// Tool can be used to perform a simple landform classification based on measures of slope gradient
 /// and curvature derived from a user-specified digital elevation model (DEM). The classification
@@ -85,7 +85,7 @@ impl PennockLandformClass {
         let mut parameters = vec![];
         parameters.push(ToolParameter {
             name: "Input DEM File".to_owned(),
-            flags: vec!["-i".to_owned(), "--dem".to_owned()],
+            flags: vec!["-i".to_owned(), "--input".to_owned(), "--dem".to_owned()],
             description: "Input raster DEM file.".to_owned(),
             parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
             default_value: None,
@@ -204,6 +204,7 @@ impl WhiteboxTool for PennockLandformClass {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut output_file = String::new();
@@ -293,13 +294,7 @@ impl WhiteboxTool for PennockLandformClass {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -315,7 +310,7 @@ impl WhiteboxTool for PennockLandformClass {
         }
 
         if verbose {
-            println!("Reading data...")
+            progress::message("Reading data...")
         };
 
         let input = Arc::new(Raster::new(&input_file, "r")?);
@@ -357,7 +352,7 @@ impl WhiteboxTool for PennockLandformClass {
         for tid in 0..num_procs {
             let input = input.clone();
             let tx = tx.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let dx = [1, 1, 1, 0, -1, -1, -1, 0];
                 let dy = [-1, 0, 1, 1, 1, 0, -1, -1];
                 let mut n: [f64; 8] = [0.0; 8];
@@ -461,18 +456,20 @@ impl WhiteboxTool for PennockLandformClass {
                             }
                         }
                     }
-                    tx.send((row, data)).unwrap();
+                    if tx.send((row, data)).is_err() {
+                        return;
+                    }
                 }
             });
         }
 
         for r in 0..rows {
-            let (row, data) = rx.recv().expect("Error receiving data from thread.");
+            let (row, data) = cancel.recv(&rx)?;
             output.set_row_data(row, data);
             if verbose {
                 progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    progress::update(progress);
                     old_progress = progress;
                 }
             }
@@ -499,32 +496,29 @@ impl WhiteboxTool for PennockLandformClass {
         output.add_metadata_entry(format!("6      Divergent Backslope"));
         output.add_metadata_entry(format!("7      Level"));
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!("CLASSIFICATION KEY");
-            println!("Value  Class");
-            println!("1      Convergent Footslope");
-            println!("2      Divergent Footslope");
-            println!("3      Convergent Shoulder");
-            println!("4      Divergent Shoulder");
-            println!("5      Convergent Backslope");
-            println!("6      Divergent Backslope");
-            println!("7      Level");
-
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message("CLASSIFICATION KEY");
+            progress::message("Value  Class");
+            progress::message("1      Convergent Footslope");
+            progress::message("2      Divergent Footslope");
+            progress::message("3      Convergent Shoulder");
+            progress::message("4      Divergent Shoulder");
+            progress::message("5      Convergent Backslope");
+            progress::message("6      Divergent Backslope");
+            progress::message("7      Level");
+
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
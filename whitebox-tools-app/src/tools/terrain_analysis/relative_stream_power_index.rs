@@ -7,6 +7,7 @@ License: MIT
 */
 
 use whitebox_raster::*;
+use whitebox_common::progress;
 use crate::tools::*;
 use num_cpus;
 use std::env;
@@ -15,7 +16,6 @@ use std::io::{Error, ErrorKind};
 use std::path;
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::thread;
 
 /// This tool can be used to calculate the relative stream power (*RSP*) index. This index is directly related
 /// to the stream power if the assumption can be made that discharge is directly proportional to upslope
@@ -155,6 +155,7 @@ impl WhiteboxTool for StreamPowerIndex {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut sca_file = String::new();
         let mut slope_file = String::new();
@@ -212,13 +213,7 @@ impl WhiteboxTool for StreamPowerIndex {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -237,7 +232,7 @@ impl WhiteboxTool for StreamPowerIndex {
         }
 
         if verbose {
-            println!("Reading data...")
+            progress::message("Reading data...")
         };
         let sca = Arc::new(Raster::new(&sca_file, "r")?);
         let slope = Arc::new(Raster::new(&slope_file, "r")?);
@@ -268,7 +263,7 @@ impl WhiteboxTool for StreamPowerIndex {
             let sca = sca.clone();
             let slope = slope.clone();
             let tx = tx.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let mut sca_val: f64;
                 let mut slope_val: f64;
                 for row in (0..rows).filter(|r| r % num_procs == tid) {
@@ -281,20 +276,22 @@ impl WhiteboxTool for StreamPowerIndex {
                                 sca_val.powf(sca_exponent) * slope_val.to_radians().tan();
                         }
                     }
-                    tx.send((row, data)).unwrap();
+                    if tx.send((row, data)).is_err() {
+                        return;
+                    }
                 }
             });
         }
 
         let mut output = Raster::initialize_using_file(&output_file, &sca);
         for r in 0..rows {
-            let (row, data) = rx.recv().expect("Error receiving data from thread.");
+            let (row, data) = cancel.recv(&rx)?;
             output.set_row_data(row, data);
 
             if verbose {
                 progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    progress::update(progress);
                     old_progress = progress;
                 }
             }
@@ -315,26 +312,23 @@ impl WhiteboxTool for StreamPowerIndex {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
 
         if sca.configs.maximum < 100.0 {
-            println!("WARNING: The input SCA data layer contained only low values. It is likely that it has been
+            progress::warning("The input SCA data layer contained only low values. It is likely that it has been
             log-transformed. This tool requires non-transformed SCA as an input.")
         }
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
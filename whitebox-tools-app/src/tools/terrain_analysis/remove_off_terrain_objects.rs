@@ -10,6 +10,7 @@ Note: This algorithm could be parallelized
 
 use whitebox_raster::Raster;
 use whitebox_common::structures::{Array2D, DistanceMetric, FixedRadiusSearch2D};
+use whitebox_common::progress;
 use crate::tools::*;
 use num_cpus;
 use std::collections::VecDeque;
@@ -19,7 +20,6 @@ use std::io::{Error, ErrorKind};
 use std::path;
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::thread;
 
 /// This tool can be used to create a bare-earth DEM from a fine-resolution digital surface model. The
 /// tool is typically applied to LiDAR DEMs which frequently contain numerous off-terrain objects (OTOs) such
@@ -158,6 +158,7 @@ impl WhiteboxTool for RemoveOffTerrainObjects {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut output_file = String::new();
@@ -222,13 +223,7 @@ impl WhiteboxTool for RemoveOffTerrainObjects {
         }
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -253,7 +248,7 @@ impl WhiteboxTool for RemoveOffTerrainObjects {
         }
 
         if verbose {
-            println!("Reading data...")
+            progress::message("Reading data...")
         };
         let input_f64 = Raster::new(&input_file, "r")?; // 2X
         let input = Arc::new(input_f64.get_data_as_f32_array2d()); // 3X
@@ -282,7 +277,7 @@ impl WhiteboxTool for RemoveOffTerrainObjects {
 
         // Perform the white tophat transform
         if verbose {
-            println!("Performing tophat transform...")
+            progress::message("Performing tophat transform...")
         };
 
         let mut num_procs = num_cpus::get() as isize;
@@ -295,7 +290,7 @@ impl WhiteboxTool for RemoveOffTerrainObjects {
         for tid in 0..num_procs {
             let input = input.clone();
             let tx = tx.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let mut z: f32;
                 let mut z_n: f32;
                 let mut min_val: f32;
@@ -343,7 +338,9 @@ impl WhiteboxTool for RemoveOffTerrainObjects {
                             erosion[col as usize] = min_val;
                         }
                     }
-                    tx.send((row, erosion)).unwrap();
+                    if tx.send((row, erosion)).is_err() {
+                        return;
+                    }
                 }
             });
         }
@@ -351,13 +348,13 @@ impl WhiteboxTool for RemoveOffTerrainObjects {
         let mut erosion: Array2D<f32> =
             Array2D::new(rows, columns, 0f32, nodata).expect("Error creating Array2D."); // 2X
         for r in 0..rows {
-            let data = rx.recv().expect("Error receiving data from thread.");
+            let data = cancel.recv(&rx)?;
             erosion.set_row_data(data.0, data.1);
 
             if verbose {
                 progress = (100.0_f32 * r as f32 / (rows - 1) as f32) as usize;
                 if progress != old_progress {
-                    println!("Performing erosion: {}%", progress);
+                    progress::update_stage("Performing erosion", progress);
                     old_progress = progress;
                 }
             }
@@ -369,7 +366,7 @@ impl WhiteboxTool for RemoveOffTerrainObjects {
             let input = input.clone();
             let erosion = erosion.clone();
             let tx = tx.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let mut z: f32;
                 let mut z_n: f32;
                 let mut max_val: f32;
@@ -421,7 +418,9 @@ impl WhiteboxTool for RemoveOffTerrainObjects {
                             }
                         }
                     }
-                    tx.send((row, tophat, opening)).unwrap();
+                    if tx.send((row, tophat, opening)).is_err() {
+                        return;
+                    }
                 }
             });
         }
@@ -431,14 +430,14 @@ impl WhiteboxTool for RemoveOffTerrainObjects {
         let mut tophat: Array2D<f32> =
             Array2D::new(rows, columns, 0f32, nodata).expect("Error creating Array2D."); // 4X
         for r in 0..rows {
-            let data = rx.recv().expect("Error receiving data from thread.");
+            let data = cancel.recv(&rx)?;
             tophat.set_row_data(data.0, data.1);
             opening.set_row_data(data.0, data.2);
 
             if verbose {
                 progress = (100.0_f32 * r as f32 / (rows - 1) as f32) as usize;
                 if progress != old_progress {
-                    println!("Performing dilation: {}%", progress);
+                    progress::update_stage("Performing dilation", progress);
                     old_progress = progress;
                 }
             }
@@ -447,12 +446,12 @@ impl WhiteboxTool for RemoveOffTerrainObjects {
         // drop(erosion); // 3X
         // drop(input); // 2X
 
-        drop(Arc::try_unwrap(erosion).expect("Error unwrapping Arc."));
-        drop(Arc::try_unwrap(input).expect("Error unwrapping Arc."));
+        drop(erosion);
+        drop(input);
 
         // Back-fill the shallow hills using region growing
         if verbose {
-            println!("Backfilling hills...")
+            progress::message("Backfilling hills...")
         };
         let initial_value = f32::NEG_INFINITY;
         let mut out: Array2D<f32> =
@@ -475,10 +474,11 @@ impl WhiteboxTool for RemoveOffTerrainObjects {
                     out.set_value(row, col, nodata);
                 }
             }
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f32 * row as f32 / (rows - 1) as f32) as usize;
                 if progress != old_progress {
-                    println!("Finding seed cells: {}%", progress);
+                    progress::update_stage("Finding seed cells", progress);
                     old_progress = progress;
                 }
             }
@@ -509,7 +509,7 @@ impl WhiteboxTool for RemoveOffTerrainObjects {
 
         // Interpolate the data holes. Start by locating all the edge cells.
         if verbose {
-            println!("Interpolating data holes...")
+            progress::message("Interpolating data holes...")
         };
         let mut frs: FixedRadiusSearch2D<f32> = FixedRadiusSearch2D::new(
             filter_size as f64 / 1.5f64,
@@ -535,10 +535,11 @@ impl WhiteboxTool for RemoveOffTerrainObjects {
                     }
                 }
             }
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f32 * row as f32 / (rows - 1) as f32) as usize;
                 if progress != old_progress {
-                    println!("Finding OTO edge cells: {}%", progress);
+                    progress::update_stage("Finding OTO edge cells", progress);
                     old_progress = progress;
                 }
             }
@@ -577,10 +578,11 @@ impl WhiteboxTool for RemoveOffTerrainObjects {
                     );
                 }
             }
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f32 * row as f32 / (rows - 1) as f32) as usize;
                 if progress != old_progress {
-                    println!("Interpolating data holes: {}%", progress);
+                    progress::update_stage("Interpolating data holes", progress);
                     old_progress = progress;
                 }
             }
@@ -601,10 +603,11 @@ impl WhiteboxTool for RemoveOffTerrainObjects {
                     output.set_value(row, col, nodata as f64);
                 }
             }
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f32 * row as f32 / (rows - 1) as f32) as usize;
                 if progress != old_progress {
-                    println!("Outputting data: {}%", progress);
+                    progress::update_stage("Outputting data", progress);
                     old_progress = progress;
                 }
             }
@@ -619,21 +622,18 @@ impl WhiteboxTool for RemoveOffTerrainObjects {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
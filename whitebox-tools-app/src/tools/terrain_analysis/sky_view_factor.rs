@@ -0,0 +1,356 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: Dr. John Lindsay
+Created: 18/10/2026
+Last Modified: 18/10/2026
+License: MIT
+*/
+
+use whitebox_raster::*;
+use super::horizon_angle::{horizon_angle_at, horizon_offsets};
+use whitebox_common::progress;
+use crate::tools::*;
+use num_cpus;
+use std::env;
+use std::f64;
+use std::io::{Error, ErrorKind};
+use std::path;
+use std::sync::mpsc;
+use std::sync::Arc;
+
+/// This tool calculates the sky-view factor (SVF) from an input digital elevation model (DEM; `--dem`). The
+/// sky-view factor is the proportion of the visible sky hemisphere above a location that is not obstructed by
+/// the surrounding relief (Zakšek et al., 2011). It is calculated as:
+///
+/// > SVF = 1 - &Sigma;<sub>i=1..n</sub> sin(&gamma;<sub>i</sub>) / *n*
+///
+/// where &gamma;<sub>i</sub> is the horizon angle, i.e. the elevation angle of the horizon, in each of *n*
+/// azimuth directions (`--num_dirs`; default 16), and negative horizon angles are treated as zero. The horizon
+/// is searched out to a maximum distance (`--dist`), measured in grid cells. SVF values range from 0 to 1,
+/// with a value of 1 indicating that the entire hemisphere is visible, as on a flat plain or a peak, and
+/// lower values in incised valleys, depressions, and the bases of steep slopes.
+///
+/// Sky-view factor is a direction-independent relief visualization technique that is well suited to the
+/// detection of small-scale features, such as archaeological remains, in high-resolution LiDAR DEMs. It is
+/// also a measure of the diffuse illumination of a surface. The horizon angles are calculated using the
+/// same ray-tracing method as the `HorizonAngle` tool and the tool is multi-threaded. Larger search distances
+/// and numbers of directions will result in longer run-times.
+///
+/// # Reference
+/// Zakšek, K., Oštir, K., & Kokalj, Ž. (2011). Sky-view factor as a relief visualization technique. Remote
+/// Sensing, 3(2), 398-415.
+///
+/// # See Also
+/// `Openness`, `HorizonAngle`, `Hillshade`
+pub struct SkyViewFactor {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl SkyViewFactor {
+    pub fn new() -> SkyViewFactor {
+        // public constructor
+        let name = "SkyViewFactor".to_string();
+        let toolbox = "Geomorphometric Analysis".to_string();
+        let description =
+            "Calculates the proportion of the sky hemisphere that is not obstructed by relief."
+                .to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input DEM File".to_owned(),
+            flags: vec!["-i".to_owned(), "--input".to_owned(), "--dem".to_owned()],
+            description: "Input raster DEM file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output File".to_owned(),
+            flags: vec!["-o".to_owned(), "--output".to_owned()],
+            description: "Output raster file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Search Distance (grid cells)".to_owned(),
+            flags: vec!["--dist".to_owned()],
+            description: "Search distance, in grid cells.".to_owned(),
+            parameter_type: ParameterType::Integer,
+            default_value: Some("10".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Number of Directions".to_owned(),
+            flags: vec!["--num_dirs".to_owned()],
+            description: "Number of azimuth directions used to calculate the sky-view factor.".to_owned(),
+            parameter_type: ParameterType::Integer,
+            default_value: Some("16".to_owned()),
+            optional: true,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut parent = env::current_exe().unwrap();
+        parent.pop();
+        let p = format!("{}", parent.display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(">>.*{0} -r={1} -v --wd=\"*path*to*data*\" --dem=dem.tif -o=svf.tif --dist=10 --num_dirs=16", short_exe, name).replace("*", &sep);
+
+        SkyViewFactor {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for SkyViewFactor {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        match serde_json::to_string(&self.parameters) {
+            Ok(json_str) => return format!("{{\"parameters\":{}}}", json_str),
+            Err(err) => return format!("{:?}", err),
+        }
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+        cancel: &CancellationToken,
+    ) -> Result<(), Error> {
+        let mut input_file = String::new();
+        let mut output_file = String::new();
+        let mut dist = 10isize;
+        let mut num_dirs = 16usize;
+
+        if args.len() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Tool run with no parameters.",
+            ));
+        }
+        for i in 0..args.len() {
+            let mut arg = args[i].replace("\"", "");
+            arg = arg.replace("\'", "");
+            let cmd = arg.split("="); // in case an equals sign was used
+            let vec = cmd.collect::<Vec<&str>>();
+            let mut keyval = false;
+            if vec.len() > 1 {
+                keyval = true;
+            }
+            let flag_val = vec[0].to_lowercase().replace("--", "-");
+            if flag_val == "-i" || flag_val == "-input" || flag_val == "-dem" {
+                input_file = if keyval {
+                    vec[1].to_string()
+                } else {
+                    args[i + 1].to_string()
+                };
+            } else if flag_val == "-o" || flag_val == "-output" {
+                output_file = if keyval {
+                    vec[1].to_string()
+                } else {
+                    args[i + 1].to_string()
+                };
+            } else if flag_val == "-dist" {
+                dist = if keyval {
+                    vec[1]
+                        .to_string()
+                        .parse::<f32>()
+                        .expect(&format!("Error parsing {}", flag_val)) as isize
+                } else {
+                    args[i + 1]
+                        .to_string()
+                        .parse::<f32>()
+                        .expect(&format!("Error parsing {}", flag_val)) as isize
+                };
+            } else if flag_val == "-num_dirs" {
+                num_dirs = if keyval {
+                    vec[1]
+                        .to_string()
+                        .parse::<f32>()
+                        .expect(&format!("Error parsing {}", flag_val)) as usize
+                } else {
+                    args[i + 1]
+                        .to_string()
+                        .parse::<f32>()
+                        .expect(&format!("Error parsing {}", flag_val)) as usize
+                };
+            }
+        }
+
+        if verbose {
+            let tool_name = self.get_tool_name();
+            progress::welcome(&tool_name);
+        }
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+
+        let mut progress: usize;
+        let mut old_progress: usize = 1;
+
+        if !input_file.contains(&sep) && !input_file.contains("/") {
+            input_file = format!("{}{}", working_directory, input_file);
+        }
+        if !output_file.contains(&sep) && !output_file.contains("/") {
+            output_file = format!("{}{}", working_directory, output_file);
+        }
+
+        if dist < 1 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The search distance (--dist) must be at least one grid cell.",
+            ));
+        }
+        if num_dirs < 2 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The number of directions (--num_dirs) must be at least two.",
+            ));
+        }
+
+        if verbose {
+            progress::message("Reading data...")
+        };
+        let inputf64 = Raster::new(&input_file, "r")?;
+        let configs = inputf64.configs.clone();
+        let mut cell_size_x = configs.resolution_x as f32;
+        let mut cell_size_y = configs.resolution_y as f32;
+        if inputf64.is_in_geographic_coordinates() {
+            let mid_lat = ((configs.north + configs.south) / 2.0).to_radians() as f32;
+            cell_size_x = cell_size_x * (111320.0 * mid_lat.cos());
+            cell_size_y = cell_size_y * 111320.0;
+        }
+        let max_dist = dist as f32 * cell_size_x.max(cell_size_y);
+
+        let rows = configs.rows as isize;
+        let columns = configs.columns as isize;
+        let nodata = configs.nodata;
+        let nodata_f32 = nodata as f32;
+
+        let input = Arc::new(inputf64.get_data_as_f32_array2d());
+
+        let start = Instant::now();
+
+        let mut num_procs = num_cpus::get() as isize;
+        let configurations = whitebox_common::configs::get_configs()?;
+        let max_procs = configurations.max_procs;
+        if max_procs > 0 && max_procs < num_procs {
+            num_procs = max_procs;
+        }
+        let (tx, rx) = mpsc::channel();
+        for tid in 0..num_procs {
+            let input = input.clone();
+            let tx = tx.clone();
+            progress::spawn(move || {
+                let offsets: Vec<Vec<(isize, isize, isize, isize, f32, f32)>> = (0..num_dirs)
+                    .map(|d| {
+                        let azimuth = d as f32 * 360f32 / num_dirs as f32;
+                        horizon_offsets(azimuth, max_dist, cell_size_x, cell_size_y)
+                    })
+                    .collect();
+                let mut horizon: f64;
+                let mut sum: f64;
+                for row in (0..rows).filter(|r| r % num_procs == tid) {
+                    let mut data = vec![nodata; columns as usize];
+                    for col in 0..columns {
+                        if input.get_value(row, col) != nodata_f32 {
+                            sum = 0f64;
+                            for d in 0..num_dirs {
+                                horizon =
+                                    horizon_angle_at(&input, row, col, &offsets[d], nodata_f32)
+                                        as f64;
+                                if horizon > 0f64 {
+                                    sum += horizon.to_radians().sin();
+                                }
+                            }
+                            data[col as usize] = 1f64 - sum / num_dirs as f64;
+                        }
+                    }
+                    if tx.send((row, data)).is_err() {
+                        return;
+                    }
+                }
+            });
+        }
+
+        let mut output = Raster::initialize_using_file(&output_file, &inputf64);
+        output.configs.data_type = DataType::F32;
+        for r in 0..rows {
+            let (row, data) = cancel.recv(&rx)?;
+            output.set_row_data(row, data);
+            if verbose {
+                progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
+                if progress != old_progress {
+                    progress::update(progress);
+                    old_progress = progress;
+                }
+            }
+        }
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+        output.configs.palette = "grey.plt".to_string();
+        output.add_metadata_entry(format!(
+            "Created by whitebox_tools\' {} tool",
+            self.get_tool_name()
+        ));
+        output.add_metadata_entry(format!("Input file: {}", input_file));
+        output.add_metadata_entry(format!("Search distance: {}", dist));
+        output.add_metadata_entry(format!("Number of directions: {}", num_dirs));
+        output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
+
+        if verbose {
+            progress::message("Saving data...")
+        };
+        let _ = match output.write() {
+            Ok(_) => {
+                if verbose {
+                    progress::output_written(&output.file_name)
+                }
+            }
+            Err(e) => return Err(e),
+        };
+        if verbose {
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
+        }
+
+        Ok(())
+    }
+}
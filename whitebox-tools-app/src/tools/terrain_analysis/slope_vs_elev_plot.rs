@@ -10,6 +10,7 @@ use whitebox_raster::Raster;
 use whitebox_common::rendering::html::*;
 use whitebox_common::rendering::LineGraph;
 use whitebox_common::structures::Array2D;
+use whitebox_common::progress;
 use crate::tools::*;
 use num_cpus;
 use std::env;
@@ -22,7 +23,6 @@ use std::path;
 use std::process::Command;
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::thread;
 
 /// This tool can be used to create a slope versus average elevation plot for one or more digital elevation models (DEMs).
 /// Similar to a hypsometric analysis (`HypsometricAnalysis`), the slope-elevation relation can reveal the basic
@@ -150,6 +150,7 @@ impl WhiteboxTool for SlopeVsElevationPlot {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_files_str = String::new();
         let mut watershed_files_str = "".to_string();
@@ -194,13 +195,7 @@ impl WhiteboxTool for SlopeVsElevationPlot {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -263,7 +258,7 @@ impl WhiteboxTool for SlopeVsElevationPlot {
                 }
 
                 if verbose {
-                    println!("Reading data...");
+                    progress::message("Reading data...");
                 }
                 let input = Arc::new(Raster::new(&input_file, "r")?);
 
@@ -302,7 +297,7 @@ impl WhiteboxTool for SlopeVsElevationPlot {
                 for tid in 0..num_procs {
                     let input = input.clone();
                     let tx1 = tx.clone();
-                    thread::spawn(move || {
+                    progress::spawn(move || {
                         let nodata = input.configs.nodata;
                         let columns = input.configs.columns as isize;
                         let d_x = [1, 1, 1, 0, -1, -1, -1, 0];
@@ -332,20 +327,22 @@ impl WhiteboxTool for SlopeVsElevationPlot {
                                         (fx * fx + fy * fy).sqrt().atan().to_degrees();
                                 }
                             }
-                            tx1.send((row, data)).unwrap();
+                            if tx1.send((row, data)).is_err() {
+                                return;
+                            }
                         }
                     });
                 }
 
                 let mut slope: Array2D<f64> = Array2D::new(rows, columns, nodata, nodata)?;
                 for row in 0..rows {
-                    let data = rx.recv().expect("Error receiving data from thread.");
+                    let data = cancel.recv(&rx)?;
                     slope.set_row_data(data.0, data.1);
 
                     if verbose {
                         progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                         if progress != old_progress {
-                            println!("Slope analysis: {}%", progress);
+                            progress::update_stage("Slope analysis", progress);
                             old_progress = progress;
                         }
                     }
@@ -373,10 +370,11 @@ impl WhiteboxTool for SlopeVsElevationPlot {
                             slope_data[bin] += slope.get_value(row, col);
                         }
                     }
+                    cancel.check()?;
                     if verbose {
                         progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                         if progress != old_progress {
-                            println!("Binning the data: {}%", progress);
+                            progress::update_stage("Binning the data", progress);
                             old_progress = progress;
                         }
                     }
@@ -416,7 +414,7 @@ impl WhiteboxTool for SlopeVsElevationPlot {
                     input_file = format!("{}{}", working_directory, input_file);
                 }
                 if verbose {
-                    println!("Reading data...");
+                    progress::message("Reading data...");
                 }
                 let input = Arc::new(Raster::new(&input_file, "r")?);
                 if num_files == 1 {
@@ -454,7 +452,7 @@ impl WhiteboxTool for SlopeVsElevationPlot {
                 for tid in 0..num_procs {
                     let input = input.clone();
                     let tx1 = tx.clone();
-                    thread::spawn(move || {
+                    progress::spawn(move || {
                         let nodata = input.configs.nodata;
                         let columns = input.configs.columns as isize;
                         let d_x = [1, 1, 1, 0, -1, -1, -1, 0];
@@ -484,20 +482,22 @@ impl WhiteboxTool for SlopeVsElevationPlot {
                                         (fx * fx + fy * fy).sqrt().atan().to_degrees();
                                 }
                             }
-                            tx1.send((row, data)).unwrap();
+                            if tx1.send((row, data)).is_err() {
+                                return;
+                            }
                         }
                     });
                 }
 
                 let mut slope: Array2D<f64> = Array2D::new(rows, columns, nodata, nodata)?;
                 for row in 0..rows {
-                    let data = rx.recv().expect("Error receiving data from thread.");
+                    let data = cancel.recv(&rx)?;
                     slope.set_row_data(data.0, data.1);
 
                     if verbose {
                         progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                         if progress != old_progress {
-                            println!("Slope analysis: {}%", progress);
+                            progress::update_stage("Slope analysis", progress);
                             old_progress = progress;
                         }
                     }
@@ -519,7 +519,7 @@ impl WhiteboxTool for SlopeVsElevationPlot {
                 let watershed_min = watershed.configs.minimum;
                 let watershed_max = watershed.configs.maximum;
                 let num_watersheds = (watershed_max - watershed_min) as usize + 1;
-                println!("Num. Watersheds {}", num_watersheds);
+                progress::message(format!("Num. Watersheds {}", num_watersheds));
 
                 // get the number of watersheds, and the min and max elev for each watershed
                 let mut min_elevs = vec![f64::INFINITY; num_watersheds];
@@ -543,10 +543,11 @@ impl WhiteboxTool for SlopeVsElevationPlot {
                             }
                         }
                     }
+                    cancel.check()?;
                     if verbose {
                         progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                         if progress != old_progress {
-                            println!("Processing watershed data: {}%", progress);
+                            progress::update_stage("Processing watershed data", progress);
                             old_progress = progress;
                         }
                     }
@@ -596,10 +597,11 @@ impl WhiteboxTool for SlopeVsElevationPlot {
                             }
                         }
                     }
+                    cancel.check()?;
                     if verbose {
                         progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                         if progress != old_progress {
-                            println!("Binning the data: {}%", progress);
+                            progress::update_stage("Binning the data", progress);
                             old_progress = progress;
                         }
                     }
@@ -658,10 +660,7 @@ impl WhiteboxTool for SlopeVsElevationPlot {
         let _ = writer.flush();
 
         if verbose {
-            println!(
-                "\n{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("\n{}", &format!("Elapsed Time (excluding I/O): {}", elapsed_time)));
         }
 
         if verbose {
@@ -689,7 +688,7 @@ impl WhiteboxTool for SlopeVsElevationPlot {
                 let _ = output.stdout;
             }
 
-            println!("Complete! Please see {} for output.", output_file);
+            progress::message(format!("Complete! Please see {} for output.", output_file));
         }
 
         Ok(())
@@ -0,0 +1,860 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: Dr. John Lindsay
+Created: 18/10/2026
+Last Modified: 18/10/2026
+License: MIT
+*/
+
+use whitebox_raster::*;
+use whitebox_common::structures::Array2D;
+use whitebox_common::progress;
+use super::horizon_angle::{horizon_angle_at, horizon_offsets};
+use crate::tools::*;
+use num_cpus;
+use std::env;
+use std::f64;
+use std::f64::consts::PI;
+use std::io::{Error, ErrorKind};
+use std::path;
+use std::sync::mpsc;
+use std::sync::Arc;
+
+/// This tool estimates the incoming shortwave (solar) radiation, in Wh/m<sup>2</sup>, received by each grid
+/// cell in an input digital elevation model (`--dem`) over a user-defined range of days (`--start_day`,
+/// `--end_day`). The total (global) radiation is the sum of the direct (beam), diffuse, and reflected
+/// components, each of which may also be output separately (`--output_direct`, `--output_diffuse`,
+/// `--output_reflected`).
+///
+/// The position of the sun is calculated for each sampled day, at intervals of `--day_interval` days, and
+/// at time steps of `--time_step` hours between sunrise and sunset, in local solar time. The latitude of
+/// the site is taken from the centre of the DEM, using the raster's coordinate reference system. Rasters in
+/// geographic coordinates and projected rasters in a Transverse Mercator projection (e.g. UTM) are
+/// supported; for other projections, the user must specify the latitude (`--lat`), which also overrides
+/// the latitude calculated from the DEM. The latitude is assumed to be constant throughout the DEM.
+///
+/// The direct radiation received by a cell is calculated from the extraterrestrial radiation, the
+/// atmospheric transmissivity (`--transmissivity`; default 0.5), which is the proportion of radiation
+/// transmitted through the atmosphere along the shortest (zenith) path, the relative optical air mass,
+/// which is corrected for elevation, and the angle of incidence between the sun's rays and the terrain
+/// surface, as determined by the slope and aspect of the cell. Terrain shading is modelled using horizon
+/// angles, calculated in the same manner as the `HorizonAngle` tool, for a set of azimuth sectors
+/// (`--az_fraction`; default 15 degrees) out to a maximum search distance (`--max_dist`). The sun is
+/// deemed to be obscured when its altitude is lower than the cell's horizon angle in the direction of the
+/// sun. Diffuse radiation is modelled using a uniform (isotropic) sky, in which the diffuse proportion
+/// (`--diffuse_prop`; default 0.3) is the proportion of global radiation that is diffuse, reduced by the
+/// sky-view factor of each cell, as estimated from the horizon angles (Dozier and Marks, 1990). Reflected
+/// radiation is estimated from the unobstructed global radiation, the surface albedo (`--albedo`; default
+/// 0.2), and the terrain configuration factor.
+///
+/// Horizon angle calculation is computationally intensive, and larger values of `--max_dist` and smaller
+/// values of `--az_fraction` will result in significantly longer run-times.
+///
+/// # Reference
+/// Dozier, J., & Marks, D. (1990). Rapid calculation of terrain parameters for radiation modeling from
+/// digital elevation data. IEEE Transactions on Geoscience and Remote Sensing, 28(5), 963-969.
+///
+/// Fu, P., & Rich, P. M. (2002). A geometric solar radiation model with applications in agriculture and
+/// forestry. Computers and Electronics in Agriculture, 37(1-3), 25-35.
+///
+/// Kasten, F., & Young, A. T. (1989). Revised optical air mass tables and approximation formula. Applied
+/// Optics, 28(22), 4735-4738.
+///
+/// # See Also
+/// `HorizonAngle`, `TimeInDaylight`, `Hillshade`
+pub struct SolarRadiation {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl SolarRadiation {
+    pub fn new() -> SolarRadiation {
+        // public constructor
+        let name = "SolarRadiation".to_string();
+        let toolbox = "Geomorphometric Analysis".to_string();
+        let description =
+            "Estimates the incoming direct, diffuse, and reflected shortwave radiation received by a DEM."
+                .to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input DEM File".to_owned(),
+            flags: vec!["-i".to_owned(), "--input".to_owned(), "--dem".to_owned()],
+            description: "Input raster DEM file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output Global Radiation File".to_owned(),
+            flags: vec!["-o".to_owned(), "--output".to_owned()],
+            description: "Output raster file of total (global) radiation, in Wh/m2.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output Direct Radiation File (optional)".to_owned(),
+            flags: vec!["--output_direct".to_owned()],
+            description: "Optional output raster file of direct radiation, in Wh/m2.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output Diffuse Radiation File (optional)".to_owned(),
+            flags: vec!["--output_diffuse".to_owned()],
+            description: "Optional output raster file of diffuse radiation, in Wh/m2.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output Reflected Radiation File (optional)".to_owned(),
+            flags: vec!["--output_reflected".to_owned()],
+            description: "Optional output raster file of reflected radiation, in Wh/m2.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Latitude (optional)".to_owned(),
+            flags: vec!["--latitude".to_owned(), "--lat".to_owned()],
+            description: "Site latitude, in degrees. If unspecified, it is calculated from the DEM's coordinate reference system.".to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Start Day Of The Year (1-365)".to_owned(),
+            flags: vec!["--start_day".to_owned()],
+            description: "Start day of the year (1-365).".to_owned(),
+            parameter_type: ParameterType::Integer,
+            default_value: Some("1".to_string()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "End Day Of The Year (1-365)".to_owned(),
+            flags: vec!["--end_day".to_owned()],
+            description: "End day of the year (1-365).".to_owned(),
+            parameter_type: ParameterType::Integer,
+            default_value: Some("365".to_string()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Day Interval".to_owned(),
+            flags: vec!["--day_interval".to_owned()],
+            description: "Interval, in days, between sampled days.".to_owned(),
+            parameter_type: ParameterType::Integer,
+            default_value: Some("7".to_string()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Time Step (hours)".to_owned(),
+            flags: vec!["--time_step".to_owned()],
+            description: "Time step, in hours, between sampled sun positions.".to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: Some("0.5".to_string()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Azimuth Fraction".to_owned(),
+            flags: vec!["--az_fraction".to_owned()],
+            description: "Width of the azimuth sectors used to calculate horizon angles, in degrees.".to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: Some("15.0".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Maximum Search Distance".to_owned(),
+            flags: vec!["--max_dist".to_owned()],
+            description: "Optional maximum search distance for horizon angles. Minimum value is 5 x cell size.".to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Atmospheric Transmissivity".to_owned(),
+            flags: vec!["--transmissivity".to_owned()],
+            description: "Proportion of radiation transmitted through the atmosphere along the zenith path (0-1).".to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: Some("0.5".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Diffuse Proportion".to_owned(),
+            flags: vec!["--diffuse_prop".to_owned()],
+            description: "Proportion of global radiation that is diffuse (0-1).".to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: Some("0.3".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Surface Albedo".to_owned(),
+            flags: vec!["--albedo".to_owned()],
+            description: "Surface albedo used to calculate reflected radiation (0-1).".to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: Some("0.2".to_owned()),
+            optional: true,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut parent = env::current_exe().unwrap();
+        parent.pop();
+        let p = format!("{}", parent.display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(">>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=dem.tif -o=global.tif --output_direct=direct.tif --start_day=152 --end_day=243 --day_interval=7 --time_step=0.5 --max_dist=2000.0 --transmissivity=0.6", short_exe, name).replace("*", &sep);
+
+        SolarRadiation {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for SolarRadiation {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        match serde_json::to_string(&self.parameters) {
+            Ok(json_str) => return format!("{{\"parameters\":{}}}", json_str),
+            Err(err) => return format!("{:?}", err),
+        }
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+        cancel: &CancellationToken,
+    ) -> Result<(), Error> {
+        let mut input_file = String::new();
+        let mut output_file = String::new();
+        let mut direct_file = String::new();
+        let mut diffuse_file = String::new();
+        let mut reflected_file = String::new();
+        let mut latitude = f64::NAN;
+        let mut start_day = 1usize;
+        let mut end_day = 365usize;
+        let mut day_interval = 7usize;
+        let mut time_step = 0.5f64;
+        let mut az_fraction = 15f32;
+        let mut max_dist = f32::INFINITY;
+        let mut transmissivity = 0.5f64;
+        let mut diffuse_prop = 0.3f64;
+        let mut albedo = 0.2f64;
+
+        if args.len() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Tool run with no parameters.",
+            ));
+        }
+        for i in 0..args.len() {
+            let mut arg = args[i].replace("\"", "");
+            arg = arg.replace("\'", "");
+            let cmd = arg.split("="); // in case an equals sign was used
+            let vec = cmd.collect::<Vec<&str>>();
+            let mut keyval = false;
+            if vec.len() > 1 {
+                keyval = true;
+            }
+            let flag_val = vec[0].to_lowercase().replace("--", "-");
+            let val = if keyval {
+                vec[1].to_string()
+            } else if i + 1 < args.len() {
+                args[i + 1].to_string()
+            } else {
+                String::new()
+            };
+            if flag_val == "-i" || flag_val == "-input" || flag_val == "-dem" {
+                input_file = val;
+            } else if flag_val == "-o" || flag_val == "-output" {
+                output_file = val;
+            } else if flag_val == "-output_direct" {
+                direct_file = val;
+            } else if flag_val == "-output_diffuse" {
+                diffuse_file = val;
+            } else if flag_val == "-output_reflected" {
+                reflected_file = val;
+            } else if flag_val == "-lat" || flag_val == "-latitude" {
+                latitude = val
+                    .parse::<f64>()
+                    .expect(&format!("Error parsing {}", flag_val));
+            } else if flag_val == "-start_day" {
+                start_day = val
+                    .parse::<f32>()
+                    .expect(&format!("Error parsing {}", flag_val)) as usize;
+            } else if flag_val == "-end_day" {
+                end_day = val
+                    .parse::<f32>()
+                    .expect(&format!("Error parsing {}", flag_val)) as usize;
+            } else if flag_val == "-day_interval" {
+                day_interval = val
+                    .parse::<f32>()
+                    .expect(&format!("Error parsing {}", flag_val)) as usize;
+            } else if flag_val == "-time_step" {
+                time_step = val
+                    .parse::<f64>()
+                    .expect(&format!("Error parsing {}", flag_val));
+            } else if flag_val == "-az_fraction" {
+                az_fraction = val
+                    .parse::<f32>()
+                    .expect(&format!("Error parsing {}", flag_val));
+            } else if flag_val == "-max_dist" {
+                max_dist = val
+                    .parse::<f32>()
+                    .expect(&format!("Error parsing {}", flag_val));
+            } else if flag_val == "-transmissivity" {
+                transmissivity = val
+                    .parse::<f64>()
+                    .expect(&format!("Error parsing {}", flag_val));
+            } else if flag_val == "-diffuse_prop" {
+                diffuse_prop = val
+                    .parse::<f64>()
+                    .expect(&format!("Error parsing {}", flag_val));
+            } else if flag_val == "-albedo" {
+                albedo = val
+                    .parse::<f64>()
+                    .expect(&format!("Error parsing {}", flag_val));
+            }
+        }
+
+        if verbose {
+            let tool_name = self.get_tool_name();
+            progress::welcome(&tool_name);
+        }
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+
+        let mut progress: usize;
+        let mut old_progress: usize = 1;
+
+        if !input_file.contains(&sep) && !input_file.contains("/") {
+            input_file = format!("{}{}", working_directory, input_file);
+        }
+        if !output_file.contains(&sep) && !output_file.contains("/") {
+            output_file = format!("{}{}", working_directory, output_file);
+        }
+        if !direct_file.is_empty() && !direct_file.contains(&sep) && !direct_file.contains("/") {
+            direct_file = format!("{}{}", working_directory, direct_file);
+        }
+        if !diffuse_file.is_empty() && !diffuse_file.contains(&sep) && !diffuse_file.contains("/") {
+            diffuse_file = format!("{}{}", working_directory, diffuse_file);
+        }
+        if !reflected_file.is_empty()
+            && !reflected_file.contains(&sep)
+            && !reflected_file.contains("/")
+        {
+            reflected_file = format!("{}{}", working_directory, reflected_file);
+        }
+
+        if start_day < 1 || start_day > 365 || end_day < 1 || end_day > 365 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The start and end days must be between 1 and 365.",
+            ));
+        }
+        if end_day < start_day {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The start day must occur before the end day.",
+            ));
+        }
+        if day_interval < 1 {
+            day_interval = 1;
+        }
+        if time_step <= 0f64 || time_step > 12f64 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The time step (--time_step) must be larger than zero and no more than 12 hours.",
+            ));
+        }
+        if az_fraction <= 0f32 || az_fraction >= 360f32 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The az_fraction parameter must be between 0 and 360.",
+            ));
+        }
+        if transmissivity <= 0f64 || transmissivity > 1f64 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The transmissivity must be between 0 and 1.",
+            ));
+        }
+        if diffuse_prop < 0f64 || diffuse_prop >= 1f64 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The diffuse proportion must be between 0 and 1.",
+            ));
+        }
+        if albedo < 0f64 || albedo > 1f64 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The albedo must be between 0 and 1.",
+            ));
+        }
+
+        if verbose {
+            progress::message("Reading data...")
+        };
+        let inputf64 = Raster::new(&input_file, "r")?;
+        let configs = inputf64.configs.clone();
+
+        if latitude.is_nan() {
+            latitude = match centre_latitude(&inputf64) {
+                Some(lat) => lat,
+                None => {
+                    return Err(Error::new(
+                        ErrorKind::InvalidInput,
+                        "The latitude could not be determined from the DEM's coordinate reference system. Please specify the latitude (--lat).",
+                    ));
+                }
+            };
+        }
+        if latitude > 90.0 || latitude < -90.0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The latitude must be between -90 and 90 degrees.",
+            ));
+        }
+
+        let mut cell_size_x = configs.resolution_x;
+        let mut cell_size_y = configs.resolution_y;
+        if inputf64.is_in_geographic_coordinates() {
+            let mid_lat = ((configs.north + configs.south) / 2.0).to_radians();
+            cell_size_x = cell_size_x * (111320.0 * mid_lat.cos());
+            cell_size_y = cell_size_y * 111320.0;
+        }
+
+        // The longest that max_dist ever needs to be is the raster diagonal length.
+        let diag_length = ((configs.rows as f64 * cell_size_y).powi(2)
+            + (configs.columns as f64 * cell_size_x).powi(2))
+        .sqrt() as f32;
+        if max_dist > diag_length {
+            max_dist = diag_length;
+        }
+        if max_dist <= 5f32 * cell_size_x as f32 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The maximum search distance parameter (--max_dist) must be larger than 5 x cell size.",
+            ));
+        }
+
+        let input = Arc::new(inputf64.get_data_as_f32_array2d());
+
+        let start = Instant::now();
+
+        let rows = configs.rows as isize;
+        let columns = configs.columns as isize;
+        let nodata = configs.nodata;
+        let nodata_f32 = nodata as f32;
+
+        let mut num_procs = num_cpus::get() as isize;
+        let configurations = whitebox_common::configs::get_configs()?;
+        let max_procs = configurations.max_procs;
+        if max_procs > 0 && max_procs < num_procs {
+            num_procs = max_procs;
+        }
+
+        // Calculate the horizon angle for each azimuth sector.
+        let num_sectors = (360f32 / az_fraction).ceil() as usize;
+        let mut horizons: Vec<Array2D<f32>> = Vec::with_capacity(num_sectors);
+        for sector in 0..num_sectors {
+            let azimuth = sector as f32 * 360f32 / num_sectors as f32;
+            let (tx, rx) = mpsc::channel();
+            for tid in 0..num_procs {
+                let input = input.clone();
+                let tx = tx.clone();
+                progress::spawn(move || {
+                    let offsets =
+                        horizon_offsets(azimuth, max_dist, cell_size_x as f32, cell_size_y as f32);
+                    for row in (0..rows).filter(|r| r % num_procs == tid) {
+                        let mut data = vec![nodata_f32; columns as usize];
+                        for col in 0..columns {
+                            if input.get_value(row, col) != nodata_f32 {
+                                data[col as usize] =
+                                    horizon_angle_at(&input, row, col, &offsets, nodata_f32);
+                            }
+                        }
+                        if tx.send((row, data)).is_err() {
+                            return;
+                        }
+                    }
+                });
+            }
+
+            let mut horizon: Array2D<f32> = Array2D::new(rows, columns, nodata_f32, nodata_f32)?;
+            for _ in 0..rows {
+                let (row, data) = cancel.recv(&rx)?;
+                horizon.set_row_data(row, data);
+            }
+            horizons.push(horizon);
+
+            if verbose {
+                progress = (100.0_f64 * (sector + 1) as f64 / num_sectors as f64) as usize;
+                if progress != old_progress {
+                    progress::update_stage("Calculating horizon angles", progress);
+                    old_progress = progress;
+                }
+            }
+        }
+        let horizons = Arc::new(horizons);
+
+        // Calculate the sun positions. Each entry contains the sun's altitude (degrees), azimuth (radians),
+        // zenith angle (radians), extraterrestrial radiation (W/m2), and duration (hours).
+        let phi = latitude.to_radians();
+        let mut sun_positions = vec![];
+        let mut day = start_day;
+        while day <= end_day {
+            let num_days = day_interval.min(end_day - day + 1) as f64;
+            let day_angle = 2f64 * PI * day as f64 / 365f64;
+            let s0 = 1367f64 * (1f64 + 0.033 * day_angle.cos());
+            let declination = (23.45f64.to_radians()) * (2f64 * PI * (284f64 + day as f64) / 365f64).sin();
+            let mut t = time_step / 2f64;
+            while t < 24f64 {
+                let hour_angle = (15f64 * (t - 12f64)).to_radians();
+                let sin_alt = phi.sin() * declination.sin()
+                    + phi.cos() * declination.cos() * hour_angle.cos();
+                if sin_alt > 0f64 {
+                    let altitude = sin_alt.asin();
+                    let azimuth = hour_angle
+                        .sin()
+                        .atan2(hour_angle.cos() * phi.sin() - declination.tan() * phi.cos())
+                        + PI;
+                    sun_positions.push((
+                        altitude.to_degrees(),
+                        azimuth,
+                        PI / 2f64 - altitude,
+                        s0,
+                        time_step.min(24f64 - (t - time_step / 2f64)) * num_days,
+                    ));
+                }
+                t += time_step;
+            }
+            day += day_interval;
+        }
+        let sun_positions = Arc::new(sun_positions);
+
+        let (tx, rx) = mpsc::channel();
+        for tid in 0..num_procs {
+            let input = input.clone();
+            let horizons = horizons.clone();
+            let sun_positions = sun_positions.clone();
+            let tx = tx.clone();
+            progress::spawn(move || {
+                let sector_width = 2f64 * PI / num_sectors as f64;
+                let dx = [1, 1, 1, 0, -1, -1, -1, 0];
+                let dy = [-1, 0, 1, 1, 1, 0, -1, -1];
+                let eight_grid_res_x = cell_size_x * 8f64;
+                let eight_grid_res_y = cell_size_y * 8f64;
+                let mut n: [f64; 8] = [0.0; 8];
+                let mut z: f64;
+                let mut zn: f32;
+                let (mut fx, mut fy): (f64, f64);
+                let (mut slope, mut aspect): (f64, f64);
+                let (mut h, mut hz): (f64, f64);
+                let mut sky_view: f64;
+                let mut terrain_config: f64;
+                let mut pressure_factor: f64;
+                let mut air_mass: f64;
+                let mut beam_normal: f64;
+                let mut cos_incidence: f64;
+                let mut sector: usize;
+                let (mut direct, mut diffuse, mut reflected): (f64, f64, f64);
+                for row in (0..rows).filter(|r| r % num_procs == tid) {
+                    let mut data = vec![(nodata, nodata, nodata); columns as usize];
+                    for col in 0..columns {
+                        if input.get_value(row, col) == nodata_f32 {
+                            continue;
+                        }
+                        z = input.get_value(row, col) as f64;
+
+                        // Slope and aspect, using Horn's method.
+                        for c in 0..8 {
+                            zn = input.get_value(row + dy[c], col + dx[c]);
+                            n[c] = if zn != nodata_f32 { zn as f64 } else { z };
+                        }
+                        fx = (n[2] - n[4] + 2f64 * (n[1] - n[5]) + n[0] - n[6]) / eight_grid_res_x;
+                        fy = (n[6] - n[4] + 2f64 * (n[7] - n[3]) + n[0] - n[2]) / eight_grid_res_y;
+                        slope = (fx * fx + fy * fy).sqrt().atan();
+                        aspect = if fx != 0f64 || fy != 0f64 {
+                            // the direction of steepest descent, clockwise from north
+                            (-fx).atan2(-fy).rem_euclid(2f64 * PI)
+                        } else {
+                            0f64
+                        };
+
+                        // Sky-view factor, after Dozier and Marks (1990).
+                        sky_view = 0f64;
+                        for s in 0..num_sectors {
+                            h = (horizons[s].get_value(row, col) as f64).to_radians();
+                            hz = (PI / 2f64 - h).min(PI).max(0f64); // horizon zenith angle
+                            sky_view += slope.cos() * hz.sin() * hz.sin()
+                                + slope.sin()
+                                    * (s as f64 * sector_width - aspect).cos()
+                                    * (hz - hz.sin() * hz.cos());
+                        }
+                        sky_view = (sky_view / num_sectors as f64).max(0f64).min(1f64);
+                        terrain_config = ((1f64 + slope.cos()) / 2f64 - sky_view).max(0f64);
+
+                        pressure_factor = (-0.000118 * z - 1.638e-9 * z * z).exp();
+                        direct = 0f64;
+                        diffuse = 0f64;
+                        reflected = 0f64;
+                        for p in sun_positions.iter() {
+                            // relative optical air mass (Kasten and Young, 1989)
+                            air_mass = pressure_factor
+                                / (p.2.cos()
+                                    + 0.50572 * (96.07995 - p.2.to_degrees()).powf(-1.6364));
+                            beam_normal = p.3 * transmissivity.powf(air_mass);
+                            let beam_horizontal = beam_normal * p.2.cos();
+                            let diffuse_horizontal =
+                                beam_horizontal * diffuse_prop / (1f64 - diffuse_prop);
+
+                            sector = ((p.1 / sector_width).round() as usize) % num_sectors;
+                            if p.0 > horizons[sector].get_value(row, col) as f64 {
+                                cos_incidence = p.2.cos() * slope.cos()
+                                    + p.2.sin() * slope.sin() * (p.1 - aspect).cos();
+                                if cos_incidence > 0f64 {
+                                    direct += beam_normal * cos_incidence * p.4;
+                                }
+                            }
+                            diffuse += diffuse_horizontal * sky_view * p.4;
+                            reflected += albedo
+                                * (beam_horizontal + diffuse_horizontal)
+                                * terrain_config
+                                * p.4;
+                        }
+                        data[col as usize] = (direct, diffuse, reflected);
+                    }
+                    if tx.send((row, data)).is_err() {
+                        return;
+                    }
+                }
+            });
+        }
+
+        let mut output = Raster::initialize_using_file(&output_file, &inputf64);
+        output.configs.data_type = DataType::F32;
+        output.configs.palette = "spectrum.plt".to_string();
+        let mut direct: Array2D<f64> = Array2D::new(rows, columns, nodata, nodata)?;
+        let mut diffuse: Array2D<f64> = Array2D::new(rows, columns, nodata, nodata)?;
+        let mut reflected: Array2D<f64> = Array2D::new(rows, columns, nodata, nodata)?;
+        for r in 0..rows {
+            let (row, data) = cancel.recv(&rx)?;
+            for col in 0..columns {
+                let (b, d, rf) = data[col as usize];
+                if b != nodata {
+                    output.set_value(row, col, b + d + rf);
+                    direct.set_value(row, col, b);
+                    diffuse.set_value(row, col, d);
+                    reflected.set_value(row, col, rf);
+                } else {
+                    output.set_value(row, col, nodata);
+                }
+            }
+            if verbose {
+                progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
+                if progress != old_progress {
+                    progress::update_stage("Calculating radiation", progress);
+                    old_progress = progress;
+                }
+            }
+        }
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+        let metadata = vec![
+            format!("Created by whitebox_tools\' {} tool", self.get_tool_name()),
+            format!("Input file: {}", input_file),
+            format!("Latitude: {}", latitude),
+            format!("Days: {}-{} (interval {})", start_day, end_day, day_interval),
+            format!("Time step: {} hours", time_step),
+            format!("Azimuth fraction: {}", az_fraction),
+            format!("Max dist: {}", max_dist),
+            format!("Transmissivity: {}", transmissivity),
+            format!("Diffuse proportion: {}", diffuse_prop),
+            format!("Albedo: {}", albedo),
+            format!("Elapsed Time (excluding I/O): {}", elapsed_time),
+        ];
+        for entry in &metadata {
+            output.add_metadata_entry(entry.clone());
+        }
+
+        if verbose {
+            progress::message("Saving data...")
+        };
+        let _ = match output.write() {
+            Ok(_) => {
+                if verbose {
+                    progress::output_written(&output.file_name)
+                }
+            }
+            Err(e) => return Err(e),
+        };
+
+        for (file_name, component) in [
+            (&direct_file, &direct),
+            (&diffuse_file, &diffuse),
+            (&reflected_file, &reflected),
+        ] {
+            if file_name.is_empty() {
+                continue;
+            }
+            let mut output = Raster::initialize_using_file(file_name, &inputf64);
+            output.configs.data_type = DataType::F32;
+            output.configs.palette = "spectrum.plt".to_string();
+            for row in 0..rows {
+                output.set_row_data(row, component.get_row_data(row));
+            }
+            for entry in &metadata {
+                output.add_metadata_entry(entry.clone());
+            }
+            let _ = match output.write() {
+                Ok(_) => {
+                    if verbose {
+                        progress::output_written(&file_name)
+                    }
+                }
+                Err(e) => return Err(e),
+            };
+        }
+
+        if verbose {
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns the latitude, in degrees, of the centre of a raster, provided that it is either in
+/// geographic coordinates or in a Transverse Mercator projection (e.g. UTM).
+fn centre_latitude(raster: &Raster) -> Option<f64> {
+    let configs = &raster.configs;
+    let x = (configs.west + configs.east) / 2f64;
+    let y = (configs.north + configs.south) / 2f64;
+    if raster.is_in_geographic_coordinates() {
+        return Some(y);
+    }
+
+    let wkt = configs.coordinate_ref_system_wkt.to_lowercase();
+    if !wkt.contains("transverse_mercator") && !wkt.contains("transverse mercator") {
+        return None;
+    }
+    let wkt_value = |key: &str| -> Option<f64> {
+        let start = wkt.find(&format!("\"{}\"", key))? + key.len() + 3;
+        let end = start + wkt[start..].find(']')?;
+        wkt[start..end].trim().parse::<f64>().ok()
+    };
+    let false_easting = wkt_value("false_easting").unwrap_or(0f64);
+    let false_northing = wkt_value("false_northing").unwrap_or(0f64);
+    let k0 = wkt_value("scale_factor").unwrap_or(1f64);
+    let lat0 = wkt_value("latitude_of_origin").unwrap_or(0f64).to_radians();
+
+    // Semi-major axis and inverse flattening from the spheroid, defaulting to WGS84.
+    let (mut a, mut inv_f) = (6378137f64, 298.257223563f64);
+    if let Some(i) = wkt.find("spheroid[") {
+        let parts: Vec<&str> = wkt[i..].split(|c| c == ',' || c == ']').collect();
+        if parts.len() > 2 {
+            if let (Ok(v1), Ok(v2)) = (parts[1].trim().parse::<f64>(), parts[2].trim().parse::<f64>()) {
+                a = v1;
+                inv_f = v2;
+            }
+        }
+    }
+    // The linear unit is the last unit in the WKT.
+    let mut unit = 1f64;
+    if let Some(i) = wkt.rfind("unit[") {
+        let parts: Vec<&str> = wkt[i..].split(|c| c == ',' || c == ']').collect();
+        if parts.len() > 1 {
+            unit = parts[1].trim().parse::<f64>().unwrap_or(1f64);
+        }
+    }
+
+    // Inverse Transverse Mercator, after Snyder (1987).
+    let f = if inv_f > 0f64 { 1f64 / inv_f } else { 0f64 };
+    let e2 = f * (2f64 - f);
+    let e4 = e2 * e2;
+    let e6 = e4 * e2;
+    let ep2 = e2 / (1f64 - e2);
+    let meridian_arc = |lat: f64| -> f64 {
+        a * ((1f64 - e2 / 4f64 - 3f64 * e4 / 64f64 - 5f64 * e6 / 256f64) * lat
+            - (3f64 * e2 / 8f64 + 3f64 * e4 / 32f64 + 45f64 * e6 / 1024f64) * (2f64 * lat).sin()
+            + (15f64 * e4 / 256f64 + 45f64 * e6 / 1024f64) * (4f64 * lat).sin()
+            - (35f64 * e6 / 3072f64) * (6f64 * lat).sin())
+    };
+    let m = meridian_arc(lat0) + (y * unit - false_northing * unit) / k0;
+    let mu = m / (a * (1f64 - e2 / 4f64 - 3f64 * e4 / 64f64 - 5f64 * e6 / 256f64));
+    let e1 = (1f64 - (1f64 - e2).sqrt()) / (1f64 + (1f64 - e2).sqrt());
+    let phi1 = mu
+        + (3f64 * e1 / 2f64 - 27f64 * e1.powi(3) / 32f64) * (2f64 * mu).sin()
+        + (21f64 * e1 * e1 / 16f64 - 55f64 * e1.powi(4) / 32f64) * (4f64 * mu).sin()
+        + (151f64 * e1.powi(3) / 96f64) * (6f64 * mu).sin()
+        + (1097f64 * e1.powi(4) / 512f64) * (8f64 * mu).sin();
+    let c1 = ep2 * phi1.cos().powi(2);
+    let t1 = phi1.tan().powi(2);
+    let n1 = a / (1f64 - e2 * phi1.sin().powi(2)).sqrt();
+    let r1 = a * (1f64 - e2) / (1f64 - e2 * phi1.sin().powi(2)).powf(1.5);
+    let d = (x * unit - false_easting * unit) / (n1 * k0);
+    let lat = phi1
+        - (n1 * phi1.tan() / r1)
+            * (d * d / 2f64
+                - (5f64 + 3f64 * t1 + 10f64 * c1 - 4f64 * c1 * c1 - 9f64 * ep2) * d.powi(4) / 24f64
+                + (61f64 + 90f64 * t1 + 298f64 * c1 + 45f64 * t1 * t1 - 252f64 * ep2 - 3f64 * c1 * c1)
+                    * d.powi(6)
+                    / 720f64);
+    let lat = lat.to_degrees();
+    if lat.is_finite() && lat >= -90f64 && lat <= 90f64 {
+        Some(lat)
+    } else {
+        None
+    }
+}
@@ -8,6 +8,7 @@ License: MIT
 
 use whitebox_raster::*;
 use whitebox_common::structures::Array2D;
+use whitebox_common::progress;
 use crate::tools::*;
 use num_cpus;
 use std::env;
@@ -16,7 +17,6 @@ use std::io::{Error, ErrorKind};
 use std::path;
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::thread;
 
 /// This tool can be used to calculate the spherical standard deviation of the distribution of surface normals
 /// for an input digital elevation model (DEM; `--dem`). This is a measure of the angular dispersion of the surface
@@ -77,7 +77,7 @@ impl SphericalStdDevOfNormals {
         let mut parameters = vec![];
         parameters.push(ToolParameter {
             name: "Input DEM File".to_owned(),
-            flags: vec!["-i".to_owned(), "--dem".to_owned()],
+            flags: vec!["-i".to_owned(), "--input".to_owned(), "--dem".to_owned()],
             description: "Input raster DEM file.".to_owned(),
             parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
             default_value: None,
@@ -86,7 +86,7 @@ impl SphericalStdDevOfNormals {
 
         parameters.push(ToolParameter {
             name: "Output Raster File".to_owned(),
-            flags: vec!["--output".to_owned()],
+            flags: vec!["-o".to_owned(), "--output".to_owned()],
             description: "Output raster file.".to_owned(),
             parameter_type: ParameterType::NewFile(ParameterFileType::Raster),
             default_value: None,
@@ -167,6 +167,7 @@ impl WhiteboxTool for SphericalStdDevOfNormals {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut output_file = String::new();
@@ -216,13 +217,7 @@ impl WhiteboxTool for SphericalStdDevOfNormals {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -255,7 +250,7 @@ impl WhiteboxTool for SphericalStdDevOfNormals {
         }
 
         if verbose {
-            println!("Reading data...")
+            progress::message("Reading data...")
         };
 
         let input = Arc::new(Raster::new(&input_file, "r")?);
@@ -271,7 +266,7 @@ impl WhiteboxTool for SphericalStdDevOfNormals {
         // Smooth the DEM
         let mut smoothed_dem = input.get_data_as_array2d();
         if verbose {
-            println!("Smoothing the input DEM...");
+            progress::message("Smoothing the input DEM...");
         }
         let sigma = (midpoint as f64 + 0.5) / 3f64;
         if sigma < 1.8 && filter_size > 3 {
@@ -338,7 +333,7 @@ impl WhiteboxTool for SphericalStdDevOfNormals {
                 let d_y = d_y.clone();
                 let weights = weights.clone();
                 let tx1 = tx.clone();
-                thread::spawn(move || {
+                progress::spawn(move || {
                     let (mut sum, mut z_final): (f64, f64);
                     let mut z: f64;
                     let mut zn: f64;
@@ -363,13 +358,15 @@ impl WhiteboxTool for SphericalStdDevOfNormals {
                             }
                         }
 
-                        tx1.send((row, data)).unwrap();
+                        if tx1.send((row, data)).is_err() {
+                            return;
+                        }
                     }
                 });
             }
 
             for _ in 0..rows {
-                let data = rx.recv().expect("Error receiving data from thread.");
+                let data = cancel.recv(&rx)?;
                 smoothed_dem.set_row_data(data.0, data.1);
             }
         } else if filter_size > 3 {
@@ -401,7 +398,7 @@ impl WhiteboxTool for SphericalStdDevOfNormals {
 
             for iteration_num in 0..n {
                 if verbose {
-                    println!("Loop {} of {}", iteration_num + 1, n);
+                    progress::message(format!("Loop {} of {}", iteration_num + 1, n));
                 }
 
                 let midpoint = if iteration_num <= m {
@@ -522,7 +519,7 @@ impl WhiteboxTool for SphericalStdDevOfNormals {
         for tid in 0..num_procs {
             let smoothed_dem = smoothed_dem.clone();
             let tx = tx.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let dx = [1, 1, 1, 0, -1, -1, -1, 0];
                 let dy = [-1, 0, 1, 1, 1, 0, -1, -1];
                 let mut n: [f64; 8] = [0.0; 8];
@@ -562,7 +559,9 @@ impl WhiteboxTool for SphericalStdDevOfNormals {
                             }
                         }
                     }
-                    tx.send((row, xdata, ydata, zdata)).unwrap();
+                    if tx.send((row, xdata, ydata, zdata)).is_err() {
+                        return;
+                    }
                 }
             });
         }
@@ -571,14 +570,14 @@ impl WhiteboxTool for SphericalStdDevOfNormals {
         let mut yc: Array2D<f64> = Array2D::new(rows, columns, 0f64, -1f64)?;
         let mut zc: Array2D<f64> = Array2D::new(rows, columns, 0f64, -1f64)?;
         for row in 0..rows {
-            let data = rx.recv().expect("Error receiving data from thread.");
+            let data = cancel.recv(&rx)?;
             xc.set_row_data(data.0, data.1);
             yc.set_row_data(data.0, data.2);
             zc.set_row_data(data.0, data.3);
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Calculating normals: {}%", progress);
+                    progress::update_stage("Calculating normals", progress);
                     old_progress = progress;
                 }
             }
@@ -627,10 +626,11 @@ impl WhiteboxTool for SphericalStdDevOfNormals {
                     }
                 }
             }
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Creating integral images: {}%", progress);
+                    progress::update_stage("Creating integral images", progress);
                     old_progress = progress;
                 }
             }
@@ -648,7 +648,7 @@ impl WhiteboxTool for SphericalStdDevOfNormals {
             let smoothed_dem = smoothed_dem.clone();
             let i_n = i_n.clone();
             let tx2 = tx2.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let (mut x1, mut x2, mut y1, mut y2): (isize, isize, isize, isize);
                 let mut n: f64;
                 let (mut sumx, mut sumy, mut sumz): (f64, f64, f64);
@@ -715,10 +715,7 @@ impl WhiteboxTool for SphericalStdDevOfNormals {
                     match tx2.send((row, data)) {
                         Ok(_) => {}
                         Err(_) => {
-                            println!(
-                                "Error sending data from thread {} processing row {}.",
-                                tid, row
-                            );
+                            progress::message(format!("Error sending data from thread {} processing row {}.", tid, row));
                         }
                     }
                 }
@@ -742,10 +739,11 @@ impl WhiteboxTool for SphericalStdDevOfNormals {
                 }
             }
 
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Performing analysis: {}%", progress);
+                    progress::update_stage("Performing analysis", progress);
                     old_progress = progress;
                 }
             }
@@ -762,22 +760,19 @@ impl WhiteboxTool for SphericalStdDevOfNormals {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
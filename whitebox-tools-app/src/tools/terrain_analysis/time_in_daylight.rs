@@ -8,6 +8,7 @@ License: MIT
 
 use whitebox_raster::Raster;
 use whitebox_common::structures::Array2D;
+use whitebox_common::progress;
 use crate::tools::*;
 use time::{Date, macros::time, OffsetDateTime, Time, UtcOffset};
 use num_cpus;
@@ -19,7 +20,6 @@ use std::io::{Error, ErrorKind};
 use std::path;
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::thread;
 
 /// This tool calculates the proportion of time a location is within daylight. That is, it calculates the
 /// proportion of time, during a user-defined time frame, that a grid cell in an input digital elevation
@@ -76,7 +76,7 @@ impl TimeInDaylight {
         let mut parameters = vec![];
         parameters.push(ToolParameter {
             name: "Input DEM File".to_owned(),
-            flags: vec!["-i".to_owned(), "--dem".to_owned()],
+            flags: vec!["-i".to_owned(), "--input".to_owned(), "--dem".to_owned()],
             description: "Input raster DEM file.".to_owned(),
             parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
             default_value: None,
@@ -113,7 +113,7 @@ impl TimeInDaylight {
 
         parameters.push(ToolParameter {
             name: "Centre Point Latitude".to_owned(),
-            flags: vec!["--lat".to_owned()],
+            flags: vec!["--latitude".to_owned(), "--lat".to_owned()],
             description: "Centre point latitude.".to_owned(),
             parameter_type: ParameterType::Float,
             default_value: None,
@@ -122,7 +122,7 @@ impl TimeInDaylight {
 
         parameters.push(ToolParameter {
             name: "Centre Point Longitude".to_owned(),
-            flags: vec!["--long".to_owned()],
+            flags: vec!["--longitude".to_owned(), "--long".to_owned()],
             description: "Centre point longitude.".to_owned(),
             parameter_type: ParameterType::Float,
             default_value: None,
@@ -131,7 +131,7 @@ impl TimeInDaylight {
 
         parameters.push(ToolParameter {
             name: "UTC Offset (e.g. -04:00, +06:00)".to_owned(),
-            flags: vec!["--utc_offset".to_owned()],
+            flags: vec!["--utc".to_owned(), "--utc_offset".to_owned()],
             description: "UTC time offset, in hours (e.g. -04:00, +06:00).".to_owned(),
             parameter_type: ParameterType::String,
             default_value: Some("00:00".to_string()),
@@ -239,6 +239,7 @@ impl WhiteboxTool for TimeInDaylight {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut output_file = String::new();
@@ -460,13 +461,7 @@ impl WhiteboxTool for TimeInDaylight {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -516,7 +511,7 @@ impl WhiteboxTool for TimeInDaylight {
 
         // Calculate the almanac
         if verbose {
-            println!("Calculating the local almanac...");
+            progress::message("Calculating the local almanac...");
         }
         let seconds_interval = 10;
         let almanac = generate_almanac(
@@ -528,7 +523,7 @@ impl WhiteboxTool for TimeInDaylight {
         );
 
         if verbose {
-            println!("Reading data...")
+            progress::message("Reading data...")
         };
         let inputf64 = Arc::new(Raster::new(&input_file, "r")?);
         let configs = inputf64.configs.clone();
@@ -629,7 +624,7 @@ impl WhiteboxTool for TimeInDaylight {
                 for tid in 0..num_procs {
                     let input = input.clone();
                     let tx = tx.clone();
-                    thread::spawn(move || {
+                    progress::spawn(move || {
                         // The ray-tracing operation can be viewed as a linear maximum filter. The first step
                         // is to create the filter offsets and calculate the offset distances.
 
@@ -770,18 +765,20 @@ impl WhiteboxTool for TimeInDaylight {
                                     }
                                 }
                             }
-                            tx.send((row, data)).unwrap();
+                            if tx.send((row, data)).is_err() {
+                                return;
+                            }
                         }
                     });
                 }
 
                 for row in 0..rows {
-                    let (r, data) = rx.recv().expect("Error receiving data from thread.");
+                    let (r, data) = cancel.recv(&rx)?;
                     horizon_angle.set_row_data(r, data);
                     if verbose {
                         progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                         if progress != old_progress {
-                            println!("Progress (Az={}): {}%", azimuth, progress);
+                            progress::update_stage(&format!("Progress (Az={})", azimuth), progress);
                             old_progress = progress;
                         }
                     }
@@ -831,10 +828,11 @@ impl WhiteboxTool for TimeInDaylight {
 
                     output.increment_row_data(row, new_vals);
 
+                    cancel.check()?;
                     if verbose {
                         progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                         if progress != old_progress {
-                            println!("Progress (Az={}): {}%", azimuth, progress);
+                            progress::update_stage(&format!("Progress (Az={})", azimuth), progress);
                             old_progress = progress;
                         }
                     }
@@ -855,10 +853,11 @@ impl WhiteboxTool for TimeInDaylight {
                         output.set_value(row, col, 1f64 - (z / total_daylight));
                     }
                 }
+                cancel.check()?;
                 if verbose {
                     progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Progress: {}%", progress);
+                        progress::update(progress);
                         old_progress = progress;
                     }
                 }
@@ -877,25 +876,22 @@ impl WhiteboxTool for TimeInDaylight {
             output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
             if verbose {
-                println!("Saving data...")
+                progress::message("Saving data...")
             };
             let _ = match output.write() {
                 Ok(_) => {
                     if verbose {
-                        println!("Output file written")
+                        progress::output_written(&output.file_name)
                     }
                 }
                 Err(e) => return Err(e),
             };
 
             if verbose {
-                println!(
-                    "{}",
-                    &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-                );
+                progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
             }
         } else {
-            println!("No daylight was detected. Either broaden the time-span parameters or decrease the azimuth fraction.");
+            progress::message("No daylight was detected. Either broaden the time-span parameters or decrease the azimuth fraction.");
         }
 
         Ok(())
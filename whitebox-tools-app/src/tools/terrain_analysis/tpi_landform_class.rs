@@ -0,0 +1,518 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: Dr. John Lindsay
+Created: 18/10/2026
+Last Modified: 18/10/2026
+License: MIT
+*/
+
+use whitebox_raster::*;
+use whitebox_common::structures::Array2D;
+use whitebox_common::progress;
+use crate::tools::*;
+use num_cpus;
+use std::env;
+use std::f64;
+use std::io::{Error, ErrorKind};
+use std::path;
+use std::sync::mpsc;
+use std::sync::Arc;
+
+/// This tool performs the topographic position index (TPI) based landform classification of Weiss (2001)
+/// on an input digital elevation model (DEM; `--dem`). TPI is the difference between the elevation of a
+/// grid cell and the mean elevation of its neighbourhood (see `DiffFromMeanElev`). TPI is calculated for
+/// both a small (`--small_filter`) and large (`--large_filter`) square neighbourhood, with sizes specified
+/// in grid cells, and each is standardized by subtracting its mean and dividing by its standard deviation
+/// over the whole DEM. The standardized small- and large-neighbourhood TPI values are then combined with
+/// slope gradient to classify the landscape into the following ten landform classes:
+///
+/// | Code | Landform Class                          | Small TPI  | Large TPI  | Slope       |
+/// |:-----|:----------------------------------------|:-----------|:-----------|:------------|
+/// | 1    | Canyons, deeply incised streams         | &le; -t    | &le; -t    |             |
+/// | 2    | Midslope drainages, shallow valleys     | &le; -t    | -t..t      |             |
+/// | 3    | Upland drainages, headwaters            | &le; -t    | &ge; t     |             |
+/// | 4    | U-shaped valleys                        | -t..t      | &le; -t    |             |
+/// | 5    | Plains                                  | -t..t      | -t..t      | &le; slope  |
+/// | 6    | Open slopes                             | -t..t      | -t..t      | > slope     |
+/// | 7    | Upper slopes, mesas                     | -t..t      | &ge; t     |             |
+/// | 8    | Local ridges, hills in valleys          | &ge; t     | &le; -t    |             |
+/// | 9    | Midslope ridges, small hills in plains  | &ge; t     | -t..t      |             |
+/// | 10   | Mountain tops, high ridges              | &ge; t     | &ge; t     |             |
+///
+/// where *t* is the standardized TPI threshold (`--threshold`; default 1.0 standard deviations) and
+/// *slope* is the slope gradient threshold (`--slope`; default 5.0 degrees) that distinguishes plains
+/// from open slopes. The output raster (`--output`) is categorical and the classification key is written
+/// to the raster's metadata.
+///
+/// The scales of the two neighbourhoods strongly affect the classification, and should be chosen to
+/// reflect the sizes of the landforms of interest. The small neighbourhood should be comparable in size to
+/// minor landforms, such as gullies and small hills, and the large neighbourhood to major valleys and ridges.
+///
+/// # Reference
+/// Weiss, A. (2001). Topographic position and landforms analysis. Poster presentation, ESRI User Conference,
+/// San Diego, CA.
+///
+/// De Reu, J., Bourgeois, J., Bats, M., Zwertvaegher, A., Gelorini, V., De Smedt, P., ... & Crombé, P. (2013).
+/// Application of the topographic position index to heterogeneous landscapes. Geomorphology, 186, 39-49.
+///
+/// # See Also
+/// `DiffFromMeanElev`, `DevFromMeanElev`, `IwahashiPikeClass`, `PennockLandformClass`, `Geomorphons`
+pub struct TpiLandformClass {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl TpiLandformClass {
+    pub fn new() -> TpiLandformClass {
+        // public constructor
+        let name = "TpiLandformClass".to_string();
+        let toolbox = "Geomorphometric Analysis".to_string();
+        let description =
+            "Classifies landforms using small- and large-neighbourhood topographic position index (TPI) and slope."
+                .to_string();
+
+        let mut parameters = vec![];
+        parameters.push(ToolParameter {
+            name: "Input DEM File".to_owned(),
+            flags: vec!["-i".to_owned(), "--input".to_owned(), "--dem".to_owned()],
+            description: "Input raster DEM file.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output File".to_owned(),
+            flags: vec!["-o".to_owned(), "--output".to_owned()],
+            description: "Output raster file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: false,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Small Neighbourhood Size (cells)".to_owned(),
+            flags: vec!["--small_filter".to_owned()],
+            description: "Size of the small TPI neighbourhood, in grid cells.".to_owned(),
+            parameter_type: ParameterType::Integer,
+            default_value: Some("11".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Large Neighbourhood Size (cells)".to_owned(),
+            flags: vec!["--large_filter".to_owned()],
+            description: "Size of the large TPI neighbourhood, in grid cells.".to_owned(),
+            parameter_type: ParameterType::Integer,
+            default_value: Some("51".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Standardized TPI Threshold".to_owned(),
+            flags: vec!["--threshold".to_owned()],
+            description: "Standardized TPI threshold, in standard deviations.".to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: Some("1.0".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Slope Threshold (degrees)".to_owned(),
+            flags: vec!["--slope".to_owned()],
+            description: "Slope threshold, in degrees, separating plains from open slopes.".to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: Some("5.0".to_owned()),
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Z Conversion Factor".to_owned(),
+            flags: vec!["--zfactor".to_owned()],
+            description: "Optional multiplier for when the vertical and horizontal units are not the same.".to_owned(),
+            parameter_type: ParameterType::Float,
+            default_value: None,
+            optional: true,
+        });
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut parent = env::current_exe().unwrap();
+        parent.pop();
+        let p = format!("{}", parent.display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(">>.*{0} -r={1} -v --wd=\"*path*to*data*\" --dem=DEM.tif -o=landforms.tif --small_filter=11 --large_filter=51 --threshold=1.0 --slope=5.0", short_exe, name).replace("*", &sep);
+
+        TpiLandformClass {
+            name: name,
+            description: description,
+            toolbox: toolbox,
+            parameters: parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl WhiteboxTool for TpiLandformClass {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        match serde_json::to_string(&self.parameters) {
+            Ok(json_str) => return format!("{{\"parameters\":{}}}", json_str),
+            Err(err) => return format!("{:?}", err),
+        }
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run<'a>(
+        &self,
+        args: Vec<String>,
+        working_directory: &'a str,
+        verbose: bool,
+        cancel: &CancellationToken,
+    ) -> Result<(), Error> {
+        let args = ParsedArgs::parse(&self.get_tool_parameters(), &args, working_directory)?;
+        self.run_with_parsed_args(args, working_directory, verbose, cancel)
+    }
+
+    fn run_with_parsed_args<'a>(
+        &self,
+        args: ParsedArgs,
+        _working_directory: &'a str,
+        verbose: bool,
+
+        cancel: &CancellationToken,
+    ) -> Result<(), Error> {
+        let input_file = args.get_file("dem").unwrap();
+        let output_file = args.get_file("output").unwrap();
+        let mut small_filter = args.get_integer("small_filter").unwrap_or(11) as isize;
+        let mut large_filter = args.get_integer("large_filter").unwrap_or(51) as isize;
+        let threshold = args.get_float("threshold").unwrap_or(1.0);
+        let slope_threshold = args.get_float("slope").unwrap_or(5.0);
+        let mut z_factor = args.get_float("zfactor").unwrap_or(-1.0);
+
+        if verbose {
+            let tool_name = self.get_tool_name();
+            progress::welcome(&tool_name);
+        }
+
+        let mut progress: usize;
+        let mut old_progress: usize = 1;
+
+        // The filter dimensions must be odd numbers such that there is a middle pixel
+        if small_filter < 3 {
+            small_filter = 3;
+        }
+        if small_filter % 2 == 0 {
+            small_filter += 1;
+        }
+        if large_filter % 2 == 0 {
+            large_filter += 1;
+        }
+        if large_filter <= small_filter {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The large neighbourhood (--large_filter) must be larger than the small neighbourhood (--small_filter).",
+            ));
+        }
+        if threshold <= 0f64 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The standardized TPI threshold (--threshold) must be larger than zero.",
+            ));
+        }
+
+        if verbose {
+            progress::message("Reading data...")
+        };
+
+        let input = Arc::new(Raster::new(&input_file, "r")?);
+
+        let start = Instant::now();
+
+        let rows = input.configs.rows as isize;
+        let columns = input.configs.columns as isize;
+        let nodata = input.configs.nodata;
+        let eight_grid_res = input.configs.resolution_x * 8.0;
+
+        if input.is_in_geographic_coordinates() && z_factor < 0.0 {
+            // calculate a new z-conversion factor
+            let mid_lat = ((input.configs.north + input.configs.south) / 2.0).to_radians();
+            z_factor = 1.0 / (111320.0 * mid_lat.cos());
+        } else if z_factor < 0.0 {
+            z_factor = 1.0;
+        }
+
+        // Create the integral images, padded with an extra leading row and column of zeros. The
+        // elevations are offset by the minimum to reduce floating-point error in the sums.
+        let mut min_val = f64::INFINITY;
+        let mut z: f64;
+        for row in 0..rows {
+            for col in 0..columns {
+                z = input.get_value(row, col);
+                if z != nodata && z < min_val {
+                    min_val = z;
+                }
+            }
+        }
+        let mut integral: Array2D<f64> = Array2D::new(rows + 1, columns + 1, 0f64, 0f64)?;
+        let mut integral_n: Array2D<u32> = Array2D::new(rows + 1, columns + 1, 0, 0)?;
+        for row in 0..rows {
+            let mut sum = 0f64;
+            let mut sum_n = 0u32;
+            for col in 0..columns {
+                z = input.get_value(row, col);
+                if z != nodata {
+                    sum += z - min_val;
+                    sum_n += 1;
+                }
+                integral.set_value(row + 1, col + 1, sum + integral.get_value(row, col + 1));
+                integral_n.set_value(row + 1, col + 1, sum_n + integral_n.get_value(row, col + 1));
+            }
+        }
+        let integral = Arc::new(integral);
+        let integral_n = Arc::new(integral_n);
+
+        let mut num_procs = num_cpus::get() as isize;
+        let configs = whitebox_common::configs::get_configs()?;
+        let max_procs = configs.max_procs;
+        if max_procs > 0 && max_procs < num_procs {
+            num_procs = max_procs;
+        }
+
+        // Calculate the small- and large-neighbourhood TPI and the slope.
+        let (tx, rx) = mpsc::channel();
+        for tid in 0..num_procs {
+            let input = input.clone();
+            let integral = integral.clone();
+            let integral_n = integral_n.clone();
+            let tx = tx.clone();
+            progress::spawn(move || {
+                let tpi = |row: isize, col: isize, z: f64, midpoint: isize| -> f64 {
+                    let y1 = (row - midpoint).max(0);
+                    let y2 = (row + midpoint + 1).min(rows);
+                    let x1 = (col - midpoint).max(0);
+                    let x2 = (col + midpoint + 1).min(columns);
+                    let n = integral_n.get_value(y2, x2) + integral_n.get_value(y1, x1)
+                        - integral_n.get_value(y1, x2)
+                        - integral_n.get_value(y2, x1);
+                    let sum = integral.get_value(y2, x2) + integral.get_value(y1, x1)
+                        - integral.get_value(y1, x2)
+                        - integral.get_value(y2, x1);
+                    z - min_val - sum / n as f64
+                };
+                let dx = [1, 1, 1, 0, -1, -1, -1, 0];
+                let dy = [-1, 0, 1, 1, 1, 0, -1, -1];
+                let mut n: [f64; 8] = [0.0; 8];
+                let mut z: f64;
+                let (mut fx, mut fy): (f64, f64);
+                for row in (0..rows).filter(|r| r % num_procs == tid) {
+                    let mut data = vec![(nodata, nodata, nodata); columns as usize];
+                    for col in 0..columns {
+                        z = input.get_value(row, col);
+                        if z != nodata {
+                            for c in 0..8 {
+                                n[c] = input.get_value(row + dy[c], col + dx[c]);
+                                if n[c] != nodata {
+                                    n[c] = n[c] * z_factor;
+                                } else {
+                                    n[c] = z * z_factor;
+                                }
+                            }
+                            fy = (n[6] - n[4] + 2.0 * (n[7] - n[3]) + n[0] - n[2]) / eight_grid_res;
+                            fx = (n[2] - n[4] + 2.0 * (n[1] - n[5]) + n[0] - n[6]) / eight_grid_res;
+                            data[col as usize] = (
+                                tpi(row, col, z, small_filter / 2),
+                                tpi(row, col, z, large_filter / 2),
+                                (fx * fx + fy * fy).sqrt().atan().to_degrees(),
+                            );
+                        }
+                    }
+                    if tx.send((row, data)).is_err() {
+                        return;
+                    }
+                }
+            });
+        }
+
+        let mut small_tpi: Array2D<f64> = Array2D::new(rows, columns, nodata, nodata)?;
+        let mut large_tpi: Array2D<f64> = Array2D::new(rows, columns, nodata, nodata)?;
+        let mut slope: Array2D<f64> = Array2D::new(rows, columns, nodata, nodata)?;
+        let (mut n, mut small_sum, mut small_sqr, mut large_sum, mut large_sqr) =
+            (0f64, 0f64, 0f64, 0f64, 0f64);
+        for r in 0..rows {
+            let (row, data) = cancel.recv(&rx)?;
+            for col in 0..columns {
+                let (s, l, g) = data[col as usize];
+                if s != nodata {
+                    small_tpi.set_value(row, col, s);
+                    large_tpi.set_value(row, col, l);
+                    slope.set_value(row, col, g);
+                    n += 1f64;
+                    small_sum += s;
+                    small_sqr += s * s;
+                    large_sum += l;
+                    large_sqr += l * l;
+                }
+            }
+            if verbose {
+                progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
+                if progress != old_progress {
+                    progress::update_stage("Calculating TPI", progress);
+                    old_progress = progress;
+                }
+            }
+        }
+
+        if n == 0f64 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The input DEM does not contain any valid grid cells.",
+            ));
+        }
+        let small_mean = small_sum / n;
+        let large_mean = large_sum / n;
+        let small_sd = (small_sqr / n - small_mean * small_mean).max(0f64).sqrt();
+        let large_sd = (large_sqr / n - large_mean * large_mean).max(0f64).sqrt();
+
+        // Classify the landforms.
+        let mut output = Raster::initialize_using_file(&output_file, &input);
+        output.configs.nodata = -128f64;
+        output.configs.data_type = DataType::I8;
+        output.configs.photometric_interp = PhotometricInterpretation::Categorical;
+        output.configs.palette = "qual.plt".to_string();
+        let (mut sn, mut ln): (f64, f64);
+        for row in 0..rows {
+            let mut data = vec![-128f64; columns as usize];
+            for col in 0..columns {
+                sn = small_tpi.get_value(row, col);
+                if sn == nodata {
+                    continue;
+                }
+                ln = large_tpi.get_value(row, col);
+                sn = if small_sd > 0f64 { (sn - small_mean) / small_sd } else { 0f64 };
+                ln = if large_sd > 0f64 { (ln - large_mean) / large_sd } else { 0f64 };
+                data[col as usize] = if sn <= -threshold {
+                    if ln <= -threshold {
+                        1f64 // Canyons, deeply incised streams
+                    } else if ln < threshold {
+                        2f64 // Midslope drainages, shallow valleys
+                    } else {
+                        3f64 // Upland drainages, headwaters
+                    }
+                } else if sn < threshold {
+                    if ln <= -threshold {
+                        4f64 // U-shaped valleys
+                    } else if ln < threshold {
+                        if slope.get_value(row, col) <= slope_threshold {
+                            5f64 // Plains
+                        } else {
+                            6f64 // Open slopes
+                        }
+                    } else {
+                        7f64 // Upper slopes, mesas
+                    }
+                } else {
+                    if ln <= -threshold {
+                        8f64 // Local ridges, hills in valleys
+                    } else if ln < threshold {
+                        9f64 // Midslope ridges, small hills in plains
+                    } else {
+                        10f64 // Mountain tops, high ridges
+                    }
+                };
+            }
+            output.set_row_data(row, data);
+            cancel.check()?;
+            if verbose {
+                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+                if progress != old_progress {
+                    progress::update_stage("Classifying", progress);
+                    old_progress = progress;
+                }
+            }
+        }
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+        output.add_metadata_entry(format!(
+            "Created by whitebox_tools\' {} tool",
+            self.get_tool_name()
+        ));
+        output.add_metadata_entry(format!("Input file: {}", input_file));
+        output.add_metadata_entry(format!("Small neighbourhood size: {}", small_filter));
+        output.add_metadata_entry(format!("Large neighbourhood size: {}", large_filter));
+        output.add_metadata_entry(format!("Standardized TPI threshold: {}", threshold));
+        output.add_metadata_entry(format!("Slope threshold: {}", slope_threshold));
+        output.add_metadata_entry(format!("Z-factor: {}", z_factor));
+        output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
+        output.add_metadata_entry(format!("CLASSIFICATION KEY"));
+        output.add_metadata_entry(format!("Value  Class"));
+        for entry in CLASS_KEY.iter() {
+            output.add_metadata_entry(entry.to_string());
+        }
+
+        if verbose {
+            progress::message("Saving data...")
+        };
+        let _ = match output.write() {
+            Ok(_) => {
+                if verbose {
+                    progress::output_written(&output.file_name)
+                }
+            }
+            Err(e) => return Err(e),
+        };
+
+        if verbose {
+            progress::message("CLASSIFICATION KEY");
+            progress::message("Value  Class");
+            for entry in CLASS_KEY.iter() {
+                progress::message(&entry);
+            }
+
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
+        }
+
+        Ok(())
+    }
+}
+
+const CLASS_KEY: [&str; 10] = [
+    "1      Canyons, deeply incised streams",
+    "2      Midslope drainages, shallow valleys",
+    "3      Upland drainages, headwaters",
+    "4      U-shaped valleys",
+    "5      Plains",
+    "6      Open slopes",
+    "7      Upper slopes, mesas",
+    "8      Local ridges, hills in valleys",
+    "9      Midslope ridges, small hills in plains",
+    "10     Mountain tops, high ridges",
+];
@@ -8,6 +8,7 @@ License: MIT
 
 use whitebox_raster::Raster;
 use whitebox_common::structures::Array2D;
+use whitebox_common::progress;
 use crate::tools::*;
 use whitebox_vector::*;
 use num_cpus;
@@ -17,7 +18,6 @@ use std::io::{Error, ErrorKind};
 use std::path;
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::thread;
 
 /// This tool can be used to calculate the viewshed (i.e. the visible area) from a
 /// location (i.e. viewing station) or group of locations based on the topography defined
@@ -61,7 +61,7 @@ impl Viewshed {
         let mut parameters = vec![];
         parameters.push(ToolParameter {
             name: "Input DEM File".to_owned(),
-            flags: vec!["--dem".to_owned()],
+            flags: vec!["-i".to_owned(), "--input".to_owned(), "--dem".to_owned()],
             description: "Input raster DEM file.".to_owned(),
             parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
             default_value: None,
@@ -70,7 +70,7 @@ impl Viewshed {
 
         parameters.push(ToolParameter {
             name: "Viewing Station Vector File".to_owned(),
-            flags: vec!["--stations".to_owned()],
+            flags: vec!["--station".to_owned(), "--stations".to_owned()],
             description: "Input viewing station vector file.".to_owned(),
             parameter_type: ParameterType::ExistingFile(ParameterFileType::Vector(
                 VectorGeometryType::Point,
@@ -162,6 +162,7 @@ impl WhiteboxTool for Viewshed {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut stations_file = String::new();
@@ -219,13 +220,7 @@ impl WhiteboxTool for Viewshed {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let mut num_procs = num_cpus::get() as isize;
@@ -251,14 +246,14 @@ impl WhiteboxTool for Viewshed {
         }
 
         if verbose {
-            println!("Reading data...")
+            progress::message("Reading data...")
         };
         let dem = Arc::new(Raster::new(&input_file, "r")?);
 
         let start = Instant::now();
 
         if height < 0f64 {
-            println!("Warning: Input station height cannot be less than zero.");
+            progress::warning("Input station height cannot be less than zero.");
             height = 0f64;
         }
 
@@ -307,11 +302,12 @@ impl WhiteboxTool for Viewshed {
             station_y.push(record.points[0].y);
             station_x.push(record.points[0].x);
 
+            cancel.check()?;
             if verbose {
                 progress =
                     (100.0_f64 * record_num as f64 / (stations.num_records - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Locating view stations: {}%", progress);
+                    progress::update_stage("Locating view stations", progress);
                     old_progress = progress;
                 }
             }
@@ -325,7 +321,7 @@ impl WhiteboxTool for Viewshed {
         let num_stn = station_x.len();
         while !station_x.is_empty() {
             stn_num += 1;
-            println!("Station {} of {}", stn_num, num_stn);
+            progress::message(format!("Station {} of {}", stn_num, num_stn));
 
             stn_x = station_x.pop().expect("Error during pop operation.");
             stn_col = dem.get_column_from_x(stn_x);
@@ -345,7 +341,7 @@ impl WhiteboxTool for Viewshed {
             for tid in 0..num_procs {
                 let dem = dem.clone();
                 let tx = tx.clone();
-                thread::spawn(move || {
+                progress::spawn(move || {
                     let (mut x, mut y): (f64, f64);
                     let mut z: f64;
                     let mut dz: f64;
@@ -367,22 +363,21 @@ impl WhiteboxTool for Viewshed {
                                 }
                             }
                         }
-                        tx.send((row, data)).unwrap();
+                        if tx.send((row, data)).is_err() {
+                            return;
+                        }
                     }
                 });
             }
 
             for r in 0..rows {
-                let (row, data) = rx.recv().expect("Error receiving data from thread.");
+                let (row, data) = cancel.recv(&rx)?;
                 view_angle.set_row_data(row, data);
 
                 if verbose {
                     progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!(
-                            "Calculating view angle (Station {} of {}): {}%",
-                            stn_num, num_stn, progress
-                        );
+                        progress::update_stage(&format!("Calculating view angle (Station {} of {})", stn_num, num_stn), progress);
                         old_progress = progress;
                     }
                 }
@@ -678,13 +673,11 @@ impl WhiteboxTool for Viewshed {
                     }
                 }
 
+                cancel.check()?;
                 if verbose {
                     progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!(
-                            "Creating output: (Station {} of {}): {}%",
-                            stn_num, num_stn, progress
-                        );
+                        progress::update_stage(&format!("Creating output: (Station {} of {})", stn_num, num_stn), progress);
                         old_progress = progress;
                     }
                 }
@@ -700,21 +693,18 @@ impl WhiteboxTool for Viewshed {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -10,6 +10,7 @@ use whitebox_raster::Raster;
 use whitebox_common::structures::Array2D;
 use crate::tools::*;
 use whitebox_common::utils::get_formatted_elapsed_time;
+use whitebox_common::progress;
 use num_cpus;
 use std::env;
 use std::f64;
@@ -17,7 +18,6 @@ use std::io::{Error, ErrorKind};
 use std::path;
 use std::sync::mpsc;
 use std::sync::{Arc, Mutex};
-use std::thread;
 
 /// This tool can be used to calculate a measure of landscape visibility based on the
 /// topography of an input digital elevation model (DEM). The user must specify the name of
@@ -61,7 +61,7 @@ impl VisibilityIndex {
         let mut parameters = vec![];
         parameters.push(ToolParameter {
             name: "Input DEM File".to_owned(),
-            flags: vec!["--dem".to_owned()],
+            flags: vec!["-i".to_owned(), "--input".to_owned(), "--dem".to_owned()],
             description: "Input raster DEM file.".to_owned(),
             parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
             default_value: None,
@@ -161,6 +161,7 @@ impl WhiteboxTool for VisibilityIndex {
         args: Vec<String>,
         working_directory: &'a str,
         verbose: bool,
+        cancel: &CancellationToken,
     ) -> Result<(), Error> {
         let mut input_file = String::new();
         let mut output_file = String::new();
@@ -224,13 +225,7 @@ impl WhiteboxTool for VisibilityIndex {
 
         if verbose {
             let tool_name = self.get_tool_name();
-            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
-            // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            progress::welcome(&tool_name);
         }
 
         let mut num_procs = num_cpus::get() as isize;
@@ -253,14 +248,14 @@ impl WhiteboxTool for VisibilityIndex {
         }
 
         if verbose {
-            println!("Reading data...")
+            progress::message("Reading data...")
         };
         let dem = Arc::new(Raster::new(&input_file, "r")?);
 
         let start = Instant::now();
 
         if height < 0f32 {
-            println!("Warning: Input station height cannot be less than zero.");
+            progress::warning("Input station height cannot be less than zero.");
             height = 0f32;
         }
 
@@ -276,7 +271,7 @@ impl WhiteboxTool for VisibilityIndex {
         let nodata = dem.configs.nodata;
 
         if verbose {
-            println!("Performing analysis. Please be patient...");
+            progress::message("Performing analysis. Please be patient...");
         }
 
         let num_cells_tested =
@@ -288,7 +283,7 @@ impl WhiteboxTool for VisibilityIndex {
             let dem = dem.clone();
             let num_cells_completed = num_cells_completed.clone();
             let tx = tx.clone();
-            thread::spawn(move || {
+            progress::spawn(move || {
                 let mut return_data: Array2D<usize> =
                     Array2D::new(rows, columns, 0usize, 0usize).unwrap();
                 let mut view_angle: Array2D<f32> =
@@ -624,7 +619,7 @@ impl WhiteboxTool for VisibilityIndex {
                                 let progress = (100.0_f64 * *num_cells_completed as f64
                                     / (num_cells_tested - 1f64))
                                     as usize;
-                                println!("Progress (Loop 1 of 2): {}%", progress);
+                                progress::update_stage("Progress (Loop 1 of 2)", progress);
                             }
                         }
                     }
@@ -634,16 +629,16 @@ impl WhiteboxTool for VisibilityIndex {
                 if verbose {
                     let progress = (100.0_f64 * *num_cells_completed as f64
                         / (num_cells_tested - 1f64)) as usize;
-                    println!("Progress (Loop 1 of 2): {}%", progress);
+                    progress::update_stage("Progress (Loop 1 of 2)", progress);
                 }
-                tx.send(return_data).unwrap();
+                let _ = tx.send(return_data);
             });
         }
 
         let mut output = Raster::initialize_using_file(&output_file, &dem);
         let mut z: f64;
         for _p in 0..num_procs {
-            let data = rx.recv().expect("Error receiving data from thread.");
+            let data = cancel.recv(&rx)?;
             for row in 0..rows {
                 for col in 0..columns {
                     if dem.get_value(row, col) != nodata {
@@ -661,10 +656,11 @@ impl WhiteboxTool for VisibilityIndex {
                     output.set_value(row, col, z / num_cells_tested)
                 }
             }
+            cancel.check()?;
             if verbose {
                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress (Loop 2 of 2): {}%", progress);
+                    progress::update_stage("Progress (Loop 2 of 2)", progress);
                     old_progress = progress;
                 }
             }
@@ -679,21 +675,18 @@ impl WhiteboxTool for VisibilityIndex {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            progress::message("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    progress::output_written(&output.file_name)
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            progress::message(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
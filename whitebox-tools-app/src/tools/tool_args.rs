@@ -0,0 +1,121 @@
+/*
+This code is part of the WhiteboxTools geospatial analysis library.
+Authors: Dr. John Lindsay
+Created: 18/10/2026
+Last Modified: 18/10/2026
+License: MIT
+*/
+
+use super::ToolError;
+use serde_json::Value;
+
+/// Structured arguments for a tool, keyed by parameter flag.
+///
+/// Keys are given without leading dashes (e.g. `input`, `filterx`, or `i`) and are converted into the
+/// `--key=value` form that tools parse from the command line. A boolean parameter is set with `flag`
+/// and a file list parameter with `files`.
+///
+/// ```
+/// use whitebox_tools::ToolArgs;
+///
+/// let args = ToolArgs::new()
+///     .arg("input", "DEM.tif")
+///     .arg("output", "slope.tif")
+///     .arg("zfactor", 1.5)
+///     .flag("verbose_output");
+/// assert_eq!(args.to_args_vec()[2], "--zfactor=1.5");
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct ToolArgs {
+    args: Vec<(String, Option<String>)>,
+}
+
+impl ToolArgs {
+    pub fn new() -> ToolArgs {
+        ToolArgs { args: vec![] }
+    }
+
+    /// Sets a parameter value, replacing any earlier value for the same key.
+    pub fn arg<K: AsRef<str>, V: ToString>(mut self, key: K, value: V) -> ToolArgs {
+        self.set(key, Some(value.to_string()));
+        self
+    }
+
+    /// Sets a boolean parameter.
+    pub fn flag<K: AsRef<str>>(mut self, key: K) -> ToolArgs {
+        self.set(key, None);
+        self
+    }
+
+    /// Sets a file list parameter; the files are joined with semicolons.
+    pub fn files<K: AsRef<str>, V: AsRef<str>>(self, key: K, files: &[V]) -> ToolArgs {
+        let list = files.iter().map(|f| f.as_ref()).collect::<Vec<&str>>().join(";");
+        self.arg(key, list)
+    }
+
+    /// Creates arguments from a JSON object, e.g. `{"input": "DEM.tif", "filter": 11, "vlr": true}`.
+    /// A `true` value sets a flag, `false` and `null` values are omitted, and arrays become
+    /// semicolon-separated lists.
+    pub fn from_json(value: &Value) -> Result<ToolArgs, ToolError> {
+        let obj = value.as_object().ok_or_else(|| {
+            ToolError::InvalidArguments("tool arguments must be a JSON object.".to_string())
+        })?;
+        let mut args = ToolArgs::new();
+        for (key, v) in obj {
+            match v {
+                Value::Null | Value::Bool(false) => {}
+                Value::Bool(true) => args.set(key, None),
+                Value::Array(a) => {
+                    let mut list = vec![];
+                    for item in a {
+                        list.push(json_scalar(key, item)?);
+                    }
+                    args.set(key, Some(list.join(";")));
+                }
+                _ => args.set(key, Some(json_scalar(key, v)?)),
+            }
+        }
+        Ok(args)
+    }
+
+    /// Returns true if the key has been set.
+    pub fn contains<K: AsRef<str>>(&self, key: K) -> bool {
+        let key = normalize_key(key.as_ref());
+        self.args.iter().any(|(k, _)| *k == key)
+    }
+
+    /// Converts the arguments into the command-line form passed to `WhiteboxTool::run`.
+    pub fn to_args_vec(&self) -> Vec<String> {
+        self.args
+            .iter()
+            .map(|(k, v)| match v {
+                Some(v) => format!("--{}={}", k, v),
+                None => format!("--{}", k),
+            })
+            .collect()
+    }
+
+    fn set<K: AsRef<str>>(&mut self, key: K, value: Option<String>) {
+        let key = normalize_key(key.as_ref());
+        match self.args.iter_mut().find(|(k, _)| *k == key) {
+            Some(entry) => entry.1 = value,
+            None => self.args.push((key, value)),
+        }
+    }
+}
+
+fn normalize_key(key: &str) -> String {
+    key.trim().trim_start_matches('-').to_string()
+}
+
+fn json_scalar(key: &str, value: &Value) -> Result<String, ToolError> {
+    match value {
+        Value::String(s) => Ok(s.clone()),
+        Value::Number(n) => Ok(n.to_string()),
+        Value::Bool(b) => Ok(b.to_string()),
+        _ => Err(ToolError::InvalidArguments(format!(
+            "unsupported value for argument '{}': {}",
+            key, value
+        ))),
+    }
+}
@@ -0,0 +1,750 @@
+/*
+This code is part of the WhiteboxTools geospatial analysis library.
+Authors: Dr. John Lindsay
+Created: 18/10/2026
+Last Modified: 18/10/2026
+License: MIT
+*/
+
+//! Runs a workflow, i.e. a dependency graph of tool invocations described in a JSON file.
+//!
+//! ```json
+//! {
+//!   "working_directory": "/path/to/data/",
+//!   "max_parallel": 2,
+//!   "steps": [
+//!     { "id": "breach", "tool": "BreachDepressionsLeastCost",
+//!       "args": { "dem": "DEM.tif", "output": "breached.tif", "dist": 100 } },
+//!     { "id": "pointer", "tool": "D8Pointer",
+//!       "args": { "dem": "$breach", "output": "d8.tif" } },
+//!     { "id": "accum", "tool": "D8FlowAccumulation",
+//!       "args": { "input": "$breach.output", "output": "accum.tif", "out_type": "cells" } },
+//!     { "id": "streams", "tool": "ExtractStreams",
+//!       "args": { "flow_accum": "$accum", "output": "streams.tif", "threshold": 1000 } }
+//!   ]
+//! }
+//! ```
+//!
+//! Each step names a tool and its arguments, in the form accepted by `ToolArgs::from_json`. An argument
+//! value of `$id` is replaced by the single output file of step `id`, and `$id.flag` by the value of that
+//! step's `flag` argument; either creates a dependency on step `id`. Additional ordering constraints can be
+//! given with `"depends_on": ["id", ...]`. Outputs are the arguments of a tool's new-file parameters and
+//! may be `memory://` data sets, in which case intermediate results are never written to disk.
+//!
+//! Steps run as soon as their dependencies have completed, with up to `max_parallel` (default: the number
+//! of processors) independent steps running at once. When a step fails, the steps that depend on it are not
+//! run but independent branches continue.
+//!
+//! Unless `"cache": false` is specified, a cache file (by default, the workflow file name with a
+//! `.cache.json` extension; see `cache_file`) records a content hash of each step's tool, arguments,
+//! input files, and upstream steps, together with hashes of the output files it produced. On later runs,
+//! a step is skipped if this key is unchanged and its output files are still present and unmodified.
+//! Steps that write to `memory://` are always run.
+//!
+//! Once the cancellation token passed to `run_workflow` is cancelled, or its time limit is reached, the
+//! running steps are stopped and no further steps are started.
+//!
+//! The progress of the steps and a summary of their outcomes are reported through `progress`, so that
+//! with `--json-events` they are reported as `step` events.
+
+use crate::tools::{CancellationToken, ToolArgs, ToolManager};
+use serde_json::{Map, Value};
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::fs::File;
+use std::io::{Error, ErrorKind};
+use std::path;
+use std::path::Path;
+use std::sync::mpsc;
+use std::thread;
+use std::time::Instant;
+use whitebox_common::progress;
+use whitebox_common::utils::{get_formatted_elapsed_time, hash_file_set, is_memory_path, Fnv1a64};
+
+#[derive(Deserialize)]
+struct Workflow {
+    working_directory: Option<String>,
+    max_parallel: Option<usize>,
+    cache: Option<bool>,
+    cache_file: Option<String>,
+    steps: Vec<WorkflowStep>,
+}
+
+#[derive(Deserialize)]
+struct WorkflowStep {
+    id: String,
+    tool: String,
+    #[serde(default)]
+    args: Map<String, Value>,
+    #[serde(default)]
+    depends_on: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Default)]
+struct WorkflowCache {
+    steps: BTreeMap<String, CachedStep>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+struct CachedStep {
+    key: String,
+    outputs: BTreeMap<String, String>,
+}
+
+/// The file-related parameters of a tool, keyed by each of their flags (without dashes).
+#[derive(Default)]
+struct ToolFiles {
+    inputs: Vec<Vec<String>>,
+    outputs: Vec<Vec<String>>,
+}
+
+#[derive(Clone, PartialEq)]
+enum StepStatus {
+    Pending,
+    Running,
+    Ran,
+    Cached,
+    Failed(String),
+    NotRun,
+}
+
+/// Runs the workflow contained in `workflow_file`, printing a summary of the steps at the end.
+/// An error is returned if the workflow is invalid or if any of its steps fail.
+pub fn run_workflow(
+    workflow_file: &str,
+    working_directory: &str,
+    verbose: bool,
+    cancel: &CancellationToken,
+) -> Result<(), Error> {
+    let start = Instant::now();
+    let workflow_file = resolve_path(working_directory, workflow_file);
+    let contents = fs::read_to_string(&workflow_file)?;
+    let workflow: Workflow = serde_json::from_str(&contents).map_err(|e| {
+        Error::new(
+            ErrorKind::InvalidInput,
+            format!("Error parsing workflow file {}: {}", workflow_file, e),
+        )
+    })?;
+
+    let mut working_directory = working_directory.to_string();
+    if let Some(wd) = &workflow.working_directory {
+        working_directory = wd.clone();
+        let sep = path::MAIN_SEPARATOR.to_string();
+        if !working_directory.ends_with(&sep) && !working_directory.ends_with("/") {
+            working_directory.push_str(&sep);
+        }
+    }
+    let mut tm = ToolManager::new(&working_directory, &verbose)?;
+    tm.cancellation_token = cancel.clone();
+
+    let steps = &workflow.steps;
+    let num_steps = steps.len();
+    if num_steps == 0 {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            "The workflow does not contain any steps.",
+        ));
+    }
+
+    // Validate the steps and gather the file parameters of each tool.
+    let mut step_index = HashMap::new();
+    let mut tool_files = vec![];
+    for (i, step) in steps.iter().enumerate() {
+        if step.id.is_empty() || step.id.contains('.') {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!("Invalid workflow step id '{}'.", step.id),
+            ));
+        }
+        if step_index.insert(step.id.clone(), i).is_some() {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!("Duplicate workflow step id '{}'.", step.id),
+            ));
+        }
+        match tm.get_tool(&step.tool) {
+            Some(tool) => tool_files.push(get_tool_files(&tool.get_tool_parameters())),
+            None => {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    format!("Unrecognized tool name {} in workflow step '{}'.", step.tool, step.id),
+                ))
+            }
+        }
+    }
+
+    // Build the dependency graph.
+    let mut dependencies: Vec<Vec<usize>> = vec![vec![]; num_steps];
+    for (i, step) in steps.iter().enumerate() {
+        let mut ids = step.depends_on.clone();
+        for value in step.args.values() {
+            collect_references(value, &mut ids);
+        }
+        for id in ids {
+            match step_index.get(&id) {
+                Some(&j) if j == i => {
+                    return Err(Error::new(
+                        ErrorKind::InvalidInput,
+                        format!("Workflow step '{}' refers to itself.", step.id),
+                    ))
+                }
+                Some(&j) => {
+                    if !dependencies[i].contains(&j) {
+                        dependencies[i].push(j);
+                    }
+                }
+                None => {
+                    return Err(Error::new(
+                        ErrorKind::InvalidInput,
+                        format!("Workflow step '{}' refers to an unknown step '{}'.", step.id, id),
+                    ))
+                }
+            }
+        }
+    }
+    let mut dependents: Vec<Vec<usize>> = vec![vec![]; num_steps];
+    for i in 0..num_steps {
+        for &j in &dependencies[i] {
+            dependents[j].push(i);
+        }
+    }
+    check_for_cycles(steps, &dependencies)?;
+
+    let use_cache = workflow.cache.unwrap_or(true);
+    let cache_file = match &workflow.cache_file {
+        Some(f) => resolve_path(&working_directory, f),
+        None => Path::new(&workflow_file)
+            .with_extension("cache.json")
+            .to_string_lossy()
+            .to_string(),
+    };
+    let mut cache: WorkflowCache = if use_cache && Path::new(&cache_file).exists() {
+        serde_json::from_str(&fs::read_to_string(&cache_file)?).unwrap_or_default()
+    } else {
+        WorkflowCache::default()
+    };
+
+    let max_parallel = workflow
+        .max_parallel
+        .unwrap_or(num_cpus::get())
+        .max(1);
+
+    let mut status = vec![StepStatus::Pending; num_steps];
+    let mut elapsed = vec![String::new(); num_steps];
+    let mut elapsed_seconds = vec![None; num_steps];
+    let mut resolved: Vec<Map<String, Value>> = vec![Map::new(); num_steps];
+    let mut keys = vec![String::new(); num_steps];
+    let mut remaining_deps: Vec<usize> = dependencies.iter().map(|d| d.len()).collect();
+    let mut ready: Vec<usize> = (0..num_steps).filter(|&i| remaining_deps[i] == 0).collect();
+    let mut num_running = 0;
+    let mut num_finished = 0;
+
+    thread::scope(|scope| {
+        let (tx, rx) = mpsc::channel();
+        while num_finished < num_steps {
+            // Start, or skip, as many of the ready steps as allowed.
+            while num_running < max_parallel && !ready.is_empty() && !cancel.is_cancelled() {
+                let i = ready.remove(0);
+                let step = &steps[i];
+                let args = match resolve_args(i, steps, &step_index, &resolved, &tool_files) {
+                    Ok(args) => args,
+                    Err(e) => {
+                        status[i] = StepStatus::Failed(e.to_string());
+                        num_finished += 1;
+                        num_finished += block_dependents(i, &dependents, &mut status);
+                        continue;
+                    }
+                };
+                let outputs = get_output_paths(&args, &tool_files[i], &working_directory);
+                let mut deps_keys: Vec<&str> = dependencies[i].iter().map(|&j| keys[j].as_str()).collect();
+                deps_keys.sort();
+                keys[i] = step_key(&step.tool, &args, &tool_files[i], &working_directory, &deps_keys);
+                resolved[i] = args;
+
+                let cached = use_cache
+                    && !outputs.iter().any(|o| is_memory_path(o))
+                    && match cache.steps.get(&step.id) {
+                        Some(c) => c.key == keys[i] && outputs_unchanged(&c.outputs),
+                        None => false,
+                    };
+                if cached {
+                    if verbose {
+                        progress::workflow_step(&step.id, &step.tool, "cached", None, None);
+                    }
+                    status[i] = StepStatus::Cached;
+                    num_finished += 1;
+                    release_dependents(i, &dependents, &mut remaining_deps, &status, &mut ready);
+                    continue;
+                }
+
+                if verbose {
+                    progress::workflow_step(&step.id, &step.tool, "running", None, None);
+                }
+                status[i] = StepStatus::Running;
+                num_running += 1;
+                let tx = tx.clone();
+                let tm = &tm;
+                let tool_args = ToolArgs::from_json(&Value::Object(resolved[i].clone()));
+                let context = progress::context();
+                scope.spawn(move || {
+                    progress::set_context(context);
+                    let step_start = Instant::now();
+                    let result = match tool_args {
+                        Ok(a) => tm.run_tool_with_args(&step.tool, &a).map_err(|e| e.to_string()),
+                        Err(e) => Err(e.to_string()),
+                    };
+                    tx.send((i, result, step_start)).unwrap();
+                });
+            }
+
+            if num_running == 0 {
+                // Nothing can make progress; the remaining steps are blocked.
+                break;
+            }
+
+            let (i, result, step_start) = rx.recv().expect("Error receiving data from thread.");
+            num_running -= 1;
+            num_finished += 1;
+            elapsed[i] = get_formatted_elapsed_time(step_start);
+            elapsed_seconds[i] = Some(step_start.elapsed().as_secs_f64());
+            match result {
+                Ok(()) => {
+                    status[i] = StepStatus::Ran;
+                    let outputs = get_output_paths(&resolved[i], &tool_files[i], &working_directory);
+                    if use_cache && !outputs.iter().any(|o| is_memory_path(o)) {
+                        let mut hashes = BTreeMap::new();
+                        for o in outputs {
+                            if let Some(h) = hash_file_set(&o) {
+                                hashes.insert(o, h);
+                            }
+                        }
+                        cache.steps.insert(
+                            steps[i].id.clone(),
+                            CachedStep {
+                                key: keys[i].clone(),
+                                outputs: hashes,
+                            },
+                        );
+                    }
+                    release_dependents(i, &dependents, &mut remaining_deps, &status, &mut ready);
+                }
+                Err(e) => {
+                    status[i] = StepStatus::Failed(e);
+                    cache.steps.remove(&steps[i].id);
+                    num_finished += block_dependents(i, &dependents, &mut status);
+                }
+            }
+        }
+    });
+
+    if use_cache {
+        let ids: Vec<&String> = steps.iter().map(|s| &s.id).collect();
+        cache.steps.retain(|id, _| ids.contains(&id));
+        let f = File::create(&cache_file)?;
+        serde_json::to_writer_pretty(f, &cache)?;
+    }
+
+    // Report the outcome of each step, in a summary table in text mode.
+    let json = progress::json_mode();
+    let id_width = steps.iter().map(|s| s.id.len()).max().unwrap_or(0).max(4);
+    let tool_width = steps.iter().map(|s| s.tool.len()).max().unwrap_or(0).max(4);
+    if !json {
+        progress::message("\nWorkflow summary:");
+        progress::message(format!("{:id_width$}  {:tool_width$}  {:8}  {}", "Step", "Tool", "Status", "Time", id_width = id_width, tool_width = tool_width));
+    }
+    let (mut num_ran, mut num_cached, mut num_failed, mut num_not_run) = (0, 0, 0, 0);
+    let mut failures = vec![];
+    for (i, step) in steps.iter().enumerate() {
+        let mut error = None;
+        let s = match &status[i] {
+            StepStatus::Ran => {
+                num_ran += 1;
+                "ran"
+            }
+            StepStatus::Cached => {
+                num_cached += 1;
+                "cached"
+            }
+            StepStatus::Failed(e) => {
+                num_failed += 1;
+                failures.push(format!("{}: {}", step.id, e));
+                error = Some(e.as_str());
+                "failed"
+            }
+            _ => {
+                num_not_run += 1;
+                "not run"
+            }
+        };
+        if json {
+            progress::workflow_step(&step.id, &step.tool, s, elapsed_seconds[i], error);
+        } else {
+            progress::message(format!("{:id_width$}  {:tool_width$}  {:8}  {}", step.id, step.tool, s, elapsed[i], id_width = id_width, tool_width = tool_width));
+        }
+    }
+    progress::message(format!(
+        "{} ran, {} cached, {} failed, {} not run",
+        num_ran, num_cached, num_failed, num_not_run
+    ));
+    if !json {
+        for f in &failures {
+            progress::message(format!("Failed step {}", f));
+        }
+    }
+    progress::message(format!("Elapsed Time: {}", get_formatted_elapsed_time(start)));
+
+    cancel.check()?;
+    if num_failed > 0 || num_not_run > 0 {
+        return Err(Error::new(
+            ErrorKind::Other,
+            format!("{} workflow step(s) failed and {} were not run.", num_failed, num_not_run),
+        ));
+    }
+    Ok(())
+}
+
+fn resolve_path(working_directory: &str, file_name: &str) -> String {
+    let sep = path::MAIN_SEPARATOR.to_string();
+    if !file_name.contains(&sep) && !file_name.contains("/") {
+        format!("{}{}", working_directory, file_name)
+    } else {
+        file_name.to_string()
+    }
+}
+
+fn normalize_flag(flag: &str) -> String {
+    flag.trim().trim_start_matches('-').to_lowercase()
+}
+
+fn get_tool_files(parameters: &str) -> ToolFiles {
+    let mut files = ToolFiles::default();
+    let v: Value = match serde_json::from_str(parameters) {
+        Ok(v) => v,
+        Err(_) => return files,
+    };
+    if let Some(params) = v["parameters"].as_array() {
+        for p in params {
+            let flags: Vec<String> = p["flags"]
+                .as_array()
+                .map(|a| a.iter().filter_map(|f| f.as_str()).map(normalize_flag).collect())
+                .unwrap_or_default();
+            let pt = &p["parameter_type"];
+            if pt.get("NewFile").is_some() {
+                files.outputs.push(flags);
+            } else if pt.get("ExistingFile").is_some()
+                || pt.get("ExistingFileOrFloat").is_some()
+                || pt.get("FileList").is_some()
+            {
+                files.inputs.push(flags);
+            }
+        }
+    }
+    files
+}
+
+/// Finds the `$id` and `$id.flag` references in an argument value.
+fn collect_references(value: &Value, ids: &mut Vec<String>) {
+    match value {
+        Value::String(s) => {
+            if let Some(r) = s.strip_prefix('$') {
+                ids.push(r.split('.').next().unwrap_or("").to_string());
+            }
+        }
+        Value::Array(a) => {
+            for v in a {
+                collect_references(v, ids);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn resolve_args(
+    i: usize,
+    steps: &[WorkflowStep],
+    step_index: &HashMap<String, usize>,
+    resolved: &[Map<String, Value>],
+    tool_files: &[ToolFiles],
+) -> Result<Map<String, Value>, Error> {
+    let resolve = |s: &str| -> Result<Value, Error> {
+        let r = match s.strip_prefix('$') {
+            Some(r) => r,
+            None => return Ok(Value::String(s.to_string())),
+        };
+        let mut parts = r.splitn(2, '.');
+        let id = parts.next().unwrap_or("");
+        let j = step_index[id];
+        let upstream = &resolved[j];
+        match parts.next() {
+            Some(flag) => {
+                let flag = normalize_flag(flag);
+                // Accept any of the aliases of the parameter, e.g. 'o' for 'output'.
+                let aliases = tool_files[j]
+                    .outputs
+                    .iter()
+                    .chain(tool_files[j].inputs.iter())
+                    .find(|f| f.contains(&flag))
+                    .cloned()
+                    .unwrap_or_else(|| vec![flag.clone()]);
+                upstream
+                    .iter()
+                    .find(|(k, _)| aliases.contains(&normalize_flag(k)))
+                    .map(|(_, v)| v.clone())
+                    .ok_or_else(|| {
+                        Error::new(
+                            ErrorKind::InvalidInput,
+                            format!("Workflow step '{}' has no argument '{}'.", id, flag),
+                        )
+                    })
+            }
+            None => {
+                let outputs: Vec<&Value> = upstream
+                    .iter()
+                    .filter(|(k, _)| {
+                        let k = normalize_flag(k);
+                        tool_files[j].outputs.iter().any(|f| f.contains(&k))
+                    })
+                    .map(|(_, v)| v)
+                    .collect();
+                if outputs.len() == 1 {
+                    Ok(outputs[0].clone())
+                } else {
+                    Err(Error::new(
+                        ErrorKind::InvalidInput,
+                        format!("Workflow step '{}' does not have a single output; use ${}.<flag>.", id, id),
+                    ))
+                }
+            }
+        }
+    };
+
+    let mut args = Map::new();
+    for (k, v) in &steps[i].args {
+        let value = match v {
+            Value::String(s) => resolve(s)?,
+            Value::Array(a) => {
+                let mut list = vec![];
+                for item in a {
+                    list.push(match item {
+                        Value::String(s) => resolve(s)?,
+                        _ => item.clone(),
+                    });
+                }
+                Value::Array(list)
+            }
+            _ => v.clone(),
+        };
+        args.insert(k.clone(), value);
+    }
+    Ok(args)
+}
+
+fn check_for_cycles(steps: &[WorkflowStep], dependencies: &[Vec<usize>]) -> Result<(), Error> {
+    // Kahn's algorithm; any steps left unvisited lie on a cycle.
+    let n = steps.len();
+    let mut remaining: Vec<usize> = dependencies.iter().map(|d| d.len()).collect();
+    let mut stack: Vec<usize> = (0..n).filter(|&i| remaining[i] == 0).collect();
+    let mut visited = 0;
+    while let Some(j) = stack.pop() {
+        visited += 1;
+        for i in 0..n {
+            if dependencies[i].contains(&j) {
+                remaining[i] -= 1;
+                if remaining[i] == 0 {
+                    stack.push(i);
+                }
+            }
+        }
+    }
+    if visited < n {
+        let ids: Vec<&str> = (0..n)
+            .filter(|&i| remaining[i] > 0)
+            .map(|i| steps[i].id.as_str())
+            .collect();
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            format!("The workflow contains a dependency cycle; the following steps cannot be run: {}", ids.join(", ")),
+        ));
+    }
+    Ok(())
+}
+
+fn release_dependents(
+    i: usize,
+    dependents: &[Vec<usize>],
+    remaining_deps: &mut [usize],
+    status: &[StepStatus],
+    ready: &mut Vec<usize>,
+) {
+    for &d in &dependents[i] {
+        remaining_deps[d] -= 1;
+        if remaining_deps[d] == 0 && status[d] == StepStatus::Pending {
+            ready.push(d);
+        }
+    }
+}
+
+/// Marks all of the steps downstream of a failed step as not run, returning their number.
+fn block_dependents(i: usize, dependents: &[Vec<usize>], status: &mut [StepStatus]) -> usize {
+    let mut count = 0;
+    let mut stack = vec![i];
+    while let Some(j) = stack.pop() {
+        for &d in &dependents[j] {
+            if status[d] == StepStatus::Pending {
+                status[d] = StepStatus::NotRun;
+                count += 1;
+                stack.push(d);
+            }
+        }
+    }
+    count
+}
+
+fn arg_values(args: &Map<String, Value>, flags: &[Vec<String>]) -> Vec<String> {
+    let mut values = vec![];
+    for (k, v) in args {
+        let k = normalize_flag(k);
+        if flags.iter().any(|f| f.contains(&k)) {
+            match v {
+                Value::String(s) => values.extend(s.split(';').map(|s| s.trim().to_string())),
+                Value::Array(a) => values.extend(a.iter().filter_map(|s| s.as_str()).map(|s| s.to_string())),
+                _ => {}
+            }
+        }
+    }
+    values
+}
+
+fn get_output_paths(args: &Map<String, Value>, files: &ToolFiles, working_directory: &str) -> Vec<String> {
+    arg_values(args, &files.outputs)
+        .iter()
+        .filter(|s| !s.is_empty())
+        .map(|s| resolve_path(working_directory, s))
+        .collect()
+}
+
+/// The cache key of a step, which changes whenever the tool, its arguments, the contents of its
+/// input files, or any of its upstream steps change.
+fn step_key(
+    tool: &str,
+    args: &Map<String, Value>,
+    files: &ToolFiles,
+    working_directory: &str,
+    upstream_keys: &[&str],
+) -> String {
+    let mut hasher = Fnv1a64::new();
+    hasher.write_field(env!("CARGO_PKG_VERSION").as_bytes());
+    hasher.write_field(tool.to_lowercase().as_bytes());
+    let mut tool_args: Vec<String> = match ToolArgs::from_json(&Value::Object(args.clone())) {
+        Ok(a) => a.to_args_vec(),
+        Err(_) => vec![],
+    };
+    tool_args.sort();
+    for a in &tool_args {
+        hasher.write_field(a.as_bytes());
+    }
+    for input in arg_values(args, &files.inputs) {
+        let input = resolve_path(working_directory, &input);
+        hasher.write_field(input.as_bytes());
+        if let Some(h) = hash_file_set(&input) {
+            hasher.write_field(h.as_bytes());
+        }
+    }
+    for k in upstream_keys {
+        hasher.write_field(k.as_bytes());
+    }
+    format!("{:016x}", hasher.finish())
+}
+
+fn outputs_unchanged(outputs: &BTreeMap<String, String>) -> bool {
+    outputs
+        .iter()
+        .all(|(file, hash)| hash_file_set(file).as_ref() == Some(hash))
+}
+
+#[cfg(test)]
+mod test {
+    use super::{check_for_cycles, collect_references, resolve_args, step_key, ToolFiles, WorkflowStep};
+    use serde_json::{json, Map, Value};
+    use std::collections::HashMap;
+    use std::env;
+    use std::fs;
+
+    fn step(id: &str, args: Value) -> WorkflowStep {
+        serde_json::from_value(json!({ "id": id, "tool": "Slope", "args": args })).unwrap()
+    }
+
+    fn files() -> ToolFiles {
+        ToolFiles {
+            inputs: vec![vec!["i".to_string(), "dem".to_string()]],
+            outputs: vec![vec!["o".to_string(), "output".to_string()]],
+        }
+    }
+
+    fn args(value: Value) -> Map<String, Value> {
+        value.as_object().unwrap().clone()
+    }
+
+    #[test]
+    fn test_check_for_cycles() {
+        let steps = vec![step("a", json!({})), step("b", json!({})), step("c", json!({}))];
+        assert!(check_for_cycles(&steps, &[vec![], vec![0], vec![0, 1]]).is_ok());
+        let err = check_for_cycles(&steps, &[vec![2], vec![0], vec![1]]).unwrap_err();
+        assert!(err.to_string().contains("a, b, c"));
+        // Only the steps on, or downstream of, the cycle are reported.
+        let err = check_for_cycles(&steps, &[vec![], vec![2], vec![1]]).unwrap_err();
+        assert!(err.to_string().ends_with("b, c"));
+    }
+
+    #[test]
+    fn test_resolve_args() {
+        let steps = vec![
+            step("fill", json!({ "dem": "dem.tif", "output": "filled.tif" })),
+            step(
+                "slope",
+                json!({
+                    "dem": "$fill",
+                    "output": "$fill.dem",
+                    "inputs": ["$fill.o", "other.tif"],
+                    "zfactor": 2.0
+                }),
+            ),
+            step("bad", json!({ "dem": "$fill.missing" })),
+        ];
+        let mut ids = vec![];
+        for value in steps[1].args.values() {
+            collect_references(value, &mut ids);
+        }
+        assert_eq!(ids, vec!["fill", "fill", "fill"]);
+
+        let step_index: HashMap<String, usize> =
+            steps.iter().enumerate().map(|(i, s)| (s.id.clone(), i)).collect();
+        let tool_files = vec![files(), files(), files()];
+        let mut resolved = vec![Map::new(); 3];
+        resolved[0] = resolve_args(0, &steps, &step_index, &resolved, &tool_files).unwrap();
+        let args = resolve_args(1, &steps, &step_index, &resolved, &tool_files).unwrap();
+        assert_eq!(args["dem"], json!("filled.tif"));
+        assert_eq!(args["output"], json!("dem.tif"));
+        assert_eq!(args["inputs"], json!(["filled.tif", "other.tif"]));
+        assert_eq!(args["zfactor"], json!(2.0));
+        assert!(resolve_args(2, &steps, &step_index, &resolved, &tool_files).is_err());
+    }
+
+    #[test]
+    fn test_step_key() {
+        let dir = env::temp_dir().to_string_lossy().to_string() + "/";
+        let input = format!("workflow_test_{}.tif", std::process::id());
+        fs::write(format!("{}{}", dir, input), b"original").unwrap();
+        let step_args = args(json!({ "dem": input, "output": "slope.tif" }));
+        let key = step_key("Slope", &step_args, &files(), &dir, &["upstream"]);
+        assert_eq!(key, step_key("Slope", &step_args, &files(), &dir, &["upstream"]));
+
+        // Changes to the arguments, the upstream steps, or the contents of the inputs change the key.
+        let other_args = args(json!({ "dem": input, "output": "slope2.tif" }));
+        assert_ne!(key, step_key("Slope", &other_args, &files(), &dir, &["upstream"]));
+        assert_ne!(key, step_key("Slope", &step_args, &files(), &dir, &["changed"]));
+        fs::write(format!("{}{}", dir, input), b"modified").unwrap();
+        assert_ne!(key, step_key("Slope", &step_args, &files(), &dir, &["upstream"]));
+        fs::remove_file(format!("{}{}", dir, input)).unwrap();
+    }
+}
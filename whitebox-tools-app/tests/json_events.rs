@@ -0,0 +1,72 @@
+/*
+This code is part of the WhiteboxTools geospatial analysis library.
+Authors: Dr. John Lindsay
+Created: 18/10/2026
+Last Modified: 18/10/2026
+License: MIT
+*/
+
+use serde_json::Value;
+use std::env;
+use std::fs;
+use std::process::Command;
+
+/// With `--json-events`, everything that a workflow prints to stdout, including the progress of
+/// its steps and its summary, is a JSON event.
+#[test]
+fn test_workflow_json_events() {
+    let dir = env::temp_dir().join(format!("json_events_test_{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let mut dem = String::from(
+        "ncols 5\nnrows 5\nxllcorner 0.0\nyllcorner 0.0\ncellsize 1.0\nNODATA_value -9999\n",
+    );
+    for row in 0..5 {
+        let values: Vec<String> = (0..5).map(|col| format!("{}", row * 5 + col)).collect();
+        dem.push_str(&format!("{}\n", values.join(" ")));
+    }
+    fs::write(dir.join("dem.asc"), dem).unwrap();
+    let workflow = format!(
+        r#"{{
+            "working_directory": {},
+            "cache": false,
+            "steps": [
+                {{ "id": "slope", "tool": "Slope", "args": {{ "dem": "dem.asc", "output": "slope.tif" }} }},
+                {{ "id": "missing", "tool": "Slope", "args": {{ "dem": "missing.asc", "output": "s.tif" }} }},
+                {{ "id": "aspect", "tool": "Aspect", "args": {{ "dem": "$missing", "output": "a.tif" }} }}
+            ]
+        }}"#,
+        Value::from(format!("{}/", dir.to_string_lossy()))
+    );
+    let workflow_file = dir.join("workflow.json");
+    fs::write(&workflow_file, workflow).unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_whitebox_tools"))
+        .arg(format!("--workflow={}", workflow_file.to_string_lossy()))
+        .arg("--json-events")
+        .output()
+        .unwrap();
+    let _ = fs::remove_dir_all(&dir);
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let mut steps = vec![];
+    for line in stdout.lines().filter(|l| !l.trim().is_empty()) {
+        let event: Value = match serde_json::from_str(line) {
+            Ok(v) => v,
+            Err(_) => panic!("not a JSON event: {}", line),
+        };
+        if event["event"] == "step" && event["status"] != "running" {
+            steps.push((
+                event["step"].as_str().unwrap().to_string(),
+                event["status"].as_str().unwrap().to_string(),
+            ));
+        }
+    }
+    assert_eq!(
+        steps,
+        vec![
+            ("slope".to_string(), "ran".to_string()),
+            ("missing".to_string(), "failed".to_string()),
+            ("aspect".to_string(), "not run".to_string()),
+        ]
+    );
+}
@@ -2,20 +2,27 @@
 This code is part of the WhiteboxTools geospatial analysis library.
 Authors: Dr. John Lindsay
 Created: June 21, 2017
-Last Modified: 12/04/2018
+Last Modified: 18/10/2026
 License: MIT
 */
 
 // private sub-module defined in other files
+pub mod network;
+pub mod routes;
 pub mod shapefile;
+pub mod spatial_weights;
 
 // exports identifiers from private sub-modules in the current module namespace
 // pub use self::shapefile::attributes::{
 //     AttributeField, AttributeHeader, DateData, FieldData, FieldDataType, Intersector,
 //     ShapefileAttributes,
 // };
+pub use crate::network::{NetworkEdge, NetworkGraph, NetworkLocation, NetworkOptions, ShortestPathTree};
+pub use crate::routes::{read_routes, route_key, Route, RoutePosition};
 pub use crate::shapefile::attributes::*;
 pub use crate::shapefile::geometry::*;
 pub use crate::shapefile::geometry::ShapeType;
 pub use crate::shapefile::Shapefile;
+pub use crate::shapefile::MEMORY_SHAPEFILES;
+pub use crate::spatial_weights::{feature_centroid, SpatialWeights, SpatialWeightsType};
 // pub use whitebox_common::structures::Point2D;
@@ -0,0 +1,721 @@
+/*
+This code is part of the WhiteboxTools geospatial analysis library.
+Authors: Dr. John Lindsay
+Created: 18/10/2026
+Last Modified: 18/10/2026
+License: MIT
+*/
+
+use crate::{FieldData, ShapeType, Shapefile};
+use rstar::primitives::{GeomWithData, Line};
+use rstar::RTree;
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap};
+use std::f64;
+use std::io::{Error, ErrorKind};
+use whitebox_common::structures::{DistanceMetric, FixedRadiusSearch2D, Point2D};
+
+// A network is a graph whose edges are the features of a polyline layer, each of which joins the
+// node at its first point to the node at its last point. Lines are connected only at their end
+// points, so a network must be split at its junctions, e.g. with the SplitWithLines tool. Each
+// edge has a cost of travel in each direction, which is infinite if travel in that direction is
+// not allowed. Locations on the network can be anywhere along an edge, and the cost of travelling
+// part of an edge is proportional to the length travelled.
+
+/// The options used to build a `NetworkGraph` from a polyline Shapefile.
+#[derive(Clone, Debug, Default)]
+pub struct NetworkOptions {
+    /// The numeric field holding the cost of travelling the full length of each line. The cost
+    /// is the length of the line if no field is given. Lines with null or negative costs are
+    /// impassable.
+    pub cost_field: Option<String>,
+    /// The field specifying the direction in which each line may be travelled. A value of 1,
+    /// true, or one of the strings `FT`, `F`, `Y`, `YES`, `TRUE`, or `FORWARD` restricts travel to
+    /// the direction in which the line is digitized, and -1 or one of `TF`, `T`, `REVERSE`, or
+    /// `BACKWARD` restricts travel to the opposite direction; lines with other values may be
+    /// travelled in either direction.
+    pub oneway_field: Option<String>,
+    /// The distance within which line end points are joined into a single node.
+    pub snap_distance: f64,
+}
+
+/// A line of the network, from node `from` to node `to`.
+#[derive(Clone, Debug)]
+pub struct NetworkEdge {
+    /// The record number of the line in the network Shapefile.
+    pub line: usize,
+    pub from: usize,
+    pub to: usize,
+    pub points: Vec<Point2D>,
+    pub length: f64,
+    /// The cost of travelling from `from` to `to`, which is infinite if it is not allowed.
+    pub forward_cost: f64,
+    /// The cost of travelling from `to` to `from`, which is infinite if it is not allowed.
+    pub backward_cost: f64,
+}
+
+/// A location on a network, found by `NetworkGraph::locate`.
+#[derive(Clone, Copy, Debug)]
+pub struct NetworkLocation {
+    pub edge: usize,
+    /// The position along the edge, as a proportion of its length from its first point.
+    pub position: f64,
+    /// The location.
+    pub point: Point2D,
+    /// The distance from the located point to the network.
+    pub distance: f64,
+}
+
+/// The least costs of travel between the nodes of a network and a location, in the direction
+/// given by `reverse`, found by `NetworkGraph::shortest_path_tree`.
+pub struct ShortestPathTree {
+    pub origin: NetworkLocation,
+    /// If false, the costs are from the origin to the nodes; if true, they are from the nodes
+    /// to the origin.
+    pub reverse: bool,
+    /// The least cost of each node, which is infinite if it cannot be reached.
+    pub cost: Vec<f64>,
+    /// The edge leading from each node towards the origin, or `None` for nodes that are reached
+    /// directly along the origin's edge.
+    predecessor: Vec<Option<usize>>,
+    max_cost: f64,
+}
+
+/// A segment of an edge, with the edge number and the index of its first point.
+type Segment = GeomWithData<Line<[f64; 2]>, (usize, usize)>;
+
+/// A graph of the lines in a polyline Shapefile, connected at their end points.
+pub struct NetworkGraph {
+    pub nodes: Vec<Point2D>,
+    pub edges: Vec<NetworkEdge>,
+    incident: Vec<Vec<usize>>,
+    index: RTree<Segment>,
+}
+
+impl NetworkGraph {
+    /// Builds a network from the lines of a polyline Shapefile. Each part of a multi-part line
+    /// becomes a separate edge, with a share of the line's cost in proportion to its length.
+    pub fn from_shapefile(network: &Shapefile, options: &NetworkOptions) -> Result<NetworkGraph, Error> {
+        if network.header.shape_type.base_shape_type() != ShapeType::PolyLine {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The input network vector data must be of POLYLINE base shape type.",
+            ));
+        }
+        let find_field = |name: &Option<String>| -> Result<Option<usize>, Error> {
+            match name {
+                Some(name) => match network.attributes.get_field_num(name) {
+                    Some(i) => Ok(Some(i)),
+                    None => Err(Error::new(
+                        ErrorKind::InvalidInput,
+                        format!("The field '{}' does not exist in the network attribute table.", name),
+                    )),
+                },
+                None => Ok(None),
+            }
+        };
+        let cost_field = find_field(&options.cost_field)?;
+        let oneway_field = find_field(&options.oneway_field)?;
+        if let Some(i) = cost_field {
+            if !network.attributes.is_field_numeric(i) {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    "The network cost field must be numeric.",
+                ));
+            }
+        }
+
+        let mut lines = vec![];
+        let mut costs = vec![];
+        for record_num in 0..network.num_records {
+            let record = network.get_record(record_num);
+            let parts: Vec<Vec<Point2D>> = (0..record.num_parts as usize)
+                .map(|part| record.get_part(part).to_vec())
+                .collect();
+            let lengths: Vec<f64> = parts.iter().map(|p| line_length(p)).collect();
+            let total_length: f64 = lengths.iter().sum();
+            let cost = match cost_field {
+                Some(i) => match network.attributes.get_record(record_num)[i] {
+                    FieldData::Int(v) if v >= 0 => v as f64,
+                    FieldData::Real(v) if v >= 0f64 => v,
+                    _ => f64::INFINITY,
+                },
+                None => total_length,
+            };
+            let (forward, backward) = match oneway_field {
+                Some(i) => oneway(&network.attributes.get_record(record_num)[i]),
+                None => (true, true),
+            };
+            for (points, length) in parts.into_iter().zip(lengths) {
+                let part_cost = if cost_field.is_none() {
+                    length
+                } else if total_length > 0f64 && cost.is_finite() {
+                    cost * length / total_length
+                } else {
+                    cost
+                };
+                let forward_cost = if forward { part_cost } else { f64::INFINITY };
+                let backward_cost = if backward { part_cost } else { f64::INFINITY };
+                lines.push((record_num, points));
+                costs.push((forward_cost, backward_cost));
+            }
+        }
+        Ok(NetworkGraph::new(lines, costs, options.snap_distance))
+    }
+
+    /// Builds a network from a set of lines, each given with the record number that it came
+    /// from, and the costs of travelling each line forwards and backwards.
+    pub fn new(
+        lines: Vec<(usize, Vec<Point2D>)>,
+        costs: Vec<(f64, f64)>,
+        snap_distance: f64,
+    ) -> NetworkGraph {
+        let mut nodes: Vec<Point2D> = vec![];
+        let mut exact: HashMap<(u64, u64), usize> = HashMap::new();
+        let mut nearby: Option<FixedRadiusSearch2D<usize>> = if snap_distance > 0f64 {
+            Some(FixedRadiusSearch2D::new(snap_distance, DistanceMetric::Euclidean))
+        } else {
+            None
+        };
+        let mut find_node = |p: Point2D, nodes: &mut Vec<Point2D>| -> usize {
+            if let Some(search) = nearby.as_mut() {
+                let found = search
+                    .search(p.x, p.y)
+                    .into_iter()
+                    .filter(|(_, d)| *d <= snap_distance)
+                    .min_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(Ordering::Equal));
+                if let Some((node, _)) = found {
+                    return node;
+                }
+                nodes.push(p);
+                search.insert(p.x, p.y, nodes.len() - 1);
+                nodes.len() - 1
+            } else {
+                let key = ((p.x + 0f64).to_bits(), (p.y + 0f64).to_bits());
+                *exact.entry(key).or_insert_with(|| {
+                    nodes.push(p);
+                    nodes.len() - 1
+                })
+            }
+        };
+
+        let mut edges = vec![];
+        for ((line, mut points), (forward_cost, backward_cost)) in lines.into_iter().zip(costs) {
+            points.dedup();
+            if points.is_empty() {
+                continue;
+            }
+            let from = find_node(points[0], &mut nodes);
+            let to = find_node(points[points.len() - 1], &mut nodes);
+            // Join the line to its nodes, which may have been snapped.
+            let n = points.len();
+            points[0] = nodes[from];
+            if n > 1 {
+                points[n - 1] = nodes[to];
+            }
+            edges.push(NetworkEdge {
+                line: line,
+                from: from,
+                to: to,
+                length: line_length(&points),
+                points: points,
+                forward_cost: forward_cost,
+                backward_cost: backward_cost,
+            });
+        }
+
+        let mut incident = vec![vec![]; nodes.len()];
+        let mut segments = vec![];
+        for (e, edge) in edges.iter().enumerate() {
+            incident[edge.from].push(e);
+            if edge.to != edge.from {
+                incident[edge.to].push(e);
+            }
+            for k in 1..edge.points.len() {
+                let (a, b) = (edge.points[k - 1], edge.points[k]);
+                segments.push(GeomWithData::new(Line::new([a.x, a.y], [b.x, b.y]), (e, k - 1)));
+            }
+        }
+
+        NetworkGraph {
+            nodes: nodes,
+            edges: edges,
+            incident: incident,
+            index: RTree::bulk_load(segments),
+        }
+    }
+
+    /// Finds the nearest location on the network to a point, or `None` if the network has no
+    /// lines of non-zero length.
+    pub fn locate(&self, p: Point2D) -> Option<NetworkLocation> {
+        let nearest = self.index.nearest_neighbor(&[p.x, p.y])?;
+        let (e, k) = nearest.data;
+        let edge = &self.edges[e];
+        let q = nearest.geom().nearest_point(&[p.x, p.y]);
+        let q = Point2D::new(q[0], q[1]);
+        let along = line_length(&edge.points[..=k]) + edge.points[k].distance(&q);
+        Some(NetworkLocation {
+            edge: e,
+            position: if edge.length > 0f64 { (along / edge.length).min(1f64) } else { 0f64 },
+            point: q,
+            distance: p.distance(&q),
+        })
+    }
+
+    /// Finds the nearest locations on the network to the features of a point Shapefile, using
+    /// the first point of each feature. The location of a null feature is `None`.
+    pub fn locate_points(&self, points: &Shapefile) -> Result<Vec<Option<NetworkLocation>>, Error> {
+        if points.header.shape_type.base_shape_type() != ShapeType::Point
+            && points.header.shape_type.base_shape_type() != ShapeType::MultiPoint
+        {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!(
+                    "The vector data '{}' must be of POINT base shape type.",
+                    points.file_name
+                ),
+            ));
+        }
+        Ok((0..points.num_records)
+            .map(|record_num| match points.get_record(record_num).points.first() {
+                Some(p) => self.locate(*p),
+                None => None,
+            })
+            .collect())
+    }
+
+    /// Finds the least costs of travel from a location to each node of the network, or from
+    /// each node to the location if `reverse` is true. Nodes that cost more than `max_cost` to
+    /// reach are treated as unreachable.
+    pub fn shortest_path_tree(
+        &self,
+        origin: &NetworkLocation,
+        max_cost: f64,
+        reverse: bool,
+    ) -> ShortestPathTree {
+        let mut cost = vec![f64::INFINITY; self.nodes.len()];
+        let mut predecessor: Vec<Option<usize>> = vec![None; self.nodes.len()];
+        let mut settled = vec![false; self.nodes.len()];
+        let mut queue = BinaryHeap::new();
+
+        // The origin's edge leads to both of its nodes.
+        let edge = &self.edges[origin.edge];
+        let f = origin.position;
+        let (to_end, to_start) = if reverse {
+            (edge.backward_cost * (1f64 - f), edge.forward_cost * f)
+        } else {
+            (edge.forward_cost * (1f64 - f), edge.backward_cost * f)
+        };
+        for (node, c) in [(edge.to, partial_cost(to_end, 1f64 - f)), (edge.from, partial_cost(to_start, f))] {
+            if c <= max_cost && c < cost[node] {
+                cost[node] = c;
+                queue.push(QueueItem { cost: c, node: node });
+            }
+        }
+
+        while let Some(QueueItem { cost: c, node }) = queue.pop() {
+            if settled[node] || c > cost[node] {
+                continue;
+            }
+            settled[node] = true;
+            for &e in &self.incident[node] {
+                let edge = &self.edges[e];
+                // In reverse, the edge is travelled towards this node rather than away from it.
+                let (other, edge_cost) = if edge.from == node {
+                    (edge.to, if reverse { edge.backward_cost } else { edge.forward_cost })
+                } else {
+                    (edge.from, if reverse { edge.forward_cost } else { edge.backward_cost })
+                };
+                let new_cost = c + edge_cost;
+                if new_cost <= max_cost && new_cost < cost[other] {
+                    cost[other] = new_cost;
+                    predecessor[other] = Some(e);
+                    queue.push(QueueItem {
+                        cost: new_cost,
+                        node: other,
+                    });
+                }
+            }
+        }
+
+        ShortestPathTree {
+            origin: *origin,
+            reverse: reverse,
+            cost: cost,
+            predecessor: predecessor,
+            max_cost: max_cost,
+        }
+    }
+
+    /// Returns the least cost of travel between the origin of a shortest path tree and another
+    /// location, in the direction of the tree, which is infinite if it cannot be reached within
+    /// the maximum cost used to build the tree.
+    pub fn cost_to(&self, tree: &ShortestPathTree, destination: &NetworkLocation) -> f64 {
+        let cost = self.route(tree, destination).0;
+        if cost <= tree.max_cost {
+            cost
+        } else {
+            f64::INFINITY
+        }
+    }
+
+    /// Returns the least cost of travel between the origin of a shortest path tree and another
+    /// location, and the route taken, in the direction of travel, or `None` if it cannot be
+    /// reached.
+    pub fn path_to(
+        &self,
+        tree: &ShortestPathTree,
+        destination: &NetworkLocation,
+    ) -> Option<(f64, Vec<Point2D>)> {
+        let (cost, via) = self.route(tree, destination);
+        if !cost.is_finite() || cost > tree.max_cost {
+            return None;
+        }
+        let d = destination;
+        let o = &tree.origin;
+        // Trace the route from the destination back to the origin.
+        let mut points = match via {
+            None => self.section(o.edge, d.position, o.position),
+            Some(node) => {
+                let edge = &self.edges[d.edge];
+                let node_position = if node == edge.from { 0f64 } else { 1f64 };
+                let mut points = self.section(d.edge, d.position, node_position);
+                let mut node = node;
+                while let Some(e) = tree.predecessor[node] {
+                    let edge = &self.edges[e];
+                    let (next, section) = if edge.from == node {
+                        (edge.to, self.section(e, 0f64, 1f64))
+                    } else {
+                        (edge.from, self.section(e, 1f64, 0f64))
+                    };
+                    points.pop();
+                    points.extend(section);
+                    node = next;
+                }
+                let edge = &self.edges[o.edge];
+                let node_position = if node == edge.from { 0f64 } else { 1f64 };
+                points.pop();
+                points.extend(self.section(o.edge, node_position, o.position));
+                points
+            }
+        };
+        if !tree.reverse {
+            points.reverse();
+        }
+        points.dedup();
+        Some((cost, points))
+    }
+
+    /// Returns the parts of the network that can be reached within a given cost of the origin
+    /// of a shortest path tree, in the direction of the tree, as a set of lines. The cost is
+    /// limited to the maximum cost used to build the tree.
+    pub fn reachable_lines(&self, tree: &ShortestPathTree, max_cost: f64) -> Vec<Vec<Point2D>> {
+        let max_cost = max_cost.min(tree.max_cost);
+        let mut lines = vec![];
+        for (e, edge) in self.edges.iter().enumerate() {
+            // The costs of travelling the edge away from its first and last nodes, or towards
+            // them in reverse.
+            let (start_cost, end_cost) = if tree.reverse {
+                (edge.backward_cost, edge.forward_cost)
+            } else {
+                (edge.forward_cost, edge.backward_cost)
+            };
+            // The proportion of the edge that can be reached from each of its nodes.
+            let reach = |node_cost: f64, edge_cost: f64| -> f64 {
+                if !node_cost.is_finite() || node_cost > max_cost || !edge_cost.is_finite() {
+                    0f64
+                } else if edge_cost == 0f64 {
+                    1f64
+                } else {
+                    ((max_cost - node_cost) / edge_cost).min(1f64)
+                }
+            };
+            let mut intervals = vec![];
+            let from_start = reach(tree.cost[edge.from], start_cost);
+            if from_start > 0f64 {
+                intervals.push((0f64, from_start));
+            }
+            let from_end = reach(tree.cost[edge.to], end_cost);
+            if from_end > 0f64 {
+                intervals.push((1f64 - from_end, 1f64));
+            }
+            if e == tree.origin.edge {
+                let f = tree.origin.position;
+                let (ahead, behind) = (reach(0f64, start_cost), reach(0f64, end_cost));
+                intervals.push(((f - behind).max(0f64), (f + ahead).min(1f64)));
+            }
+            intervals.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+            let mut merged: Vec<(f64, f64)> = vec![];
+            for (start, end) in intervals {
+                match merged.last_mut() {
+                    Some(last) if start <= last.1 => last.1 = last.1.max(end),
+                    _ => merged.push((start, end)),
+                }
+            }
+            for (start, end) in merged {
+                lines.push(self.section(e, start, end));
+            }
+        }
+        lines
+    }
+
+    /// Finds the least cost between the origin of a tree and a location, and the node through
+    /// which the route reaches the location's edge, or `None` if it follows the origin's edge
+    /// directly.
+    fn route(&self, tree: &ShortestPathTree, destination: &NetworkLocation) -> (f64, Option<usize>) {
+        let edge = &self.edges[destination.edge];
+        let g = destination.position;
+        // The costs of travel along the destination's edge to and from its nodes.
+        let (from_start, from_end) = if tree.reverse {
+            (
+                partial_cost(edge.backward_cost * g, g),
+                partial_cost(edge.forward_cost * (1f64 - g), 1f64 - g),
+            )
+        } else {
+            (
+                partial_cost(edge.forward_cost * g, g),
+                partial_cost(edge.backward_cost * (1f64 - g), 1f64 - g),
+            )
+        };
+        let mut best = (f64::INFINITY, None);
+        for (node, c) in [(edge.from, from_start), (edge.to, from_end)] {
+            let total = tree.cost[node] + c;
+            if total < best.0 {
+                best = (total, Some(node));
+            }
+        }
+        if destination.edge == tree.origin.edge {
+            let f = tree.origin.position;
+            // Travel along the edge from the origin towards the destination, or the reverse.
+            let ahead = if tree.reverse { g <= f } else { g >= f };
+            let cost = if ahead {
+                edge.forward_cost * (g - f).abs()
+            } else {
+                edge.backward_cost * (g - f).abs()
+            };
+            let cost = partial_cost(cost, (g - f).abs());
+            if cost <= best.0 {
+                best = (cost, None);
+            }
+        }
+        best
+    }
+
+    /// Returns the points of an edge between two positions along it, in the order from `start`
+    /// to `end`.
+    fn section(&self, e: usize, start: f64, end: f64) -> Vec<Point2D> {
+        let edge = &self.edges[e];
+        let (lo, hi) = (start.min(end), start.max(end));
+        let mut points = vec![];
+        let mut along = 0f64;
+        for k in 1..edge.points.len() {
+            let (a, b) = (edge.points[k - 1], edge.points[k]);
+            let length = a.distance(&b);
+            let t0 = along / edge.length.max(f64::MIN_POSITIVE);
+            along += length;
+            let t1 = along / edge.length.max(f64::MIN_POSITIVE);
+            let interpolate = |t: f64| -> Point2D {
+                if t1 > t0 {
+                    let s = ((t - t0) / (t1 - t0)).clamp(0f64, 1f64);
+                    Point2D::new(a.x + s * (b.x - a.x), a.y + s * (b.y - a.y))
+                } else {
+                    a
+                }
+            };
+            if points.is_empty() && lo <= t1 {
+                points.push(interpolate(lo));
+            }
+            if !points.is_empty() {
+                if hi <= t1 {
+                    points.push(interpolate(hi));
+                    break;
+                }
+                points.push(b);
+            }
+        }
+        if points.is_empty() {
+            points.push(edge.points[edge.points.len() - 1]);
+        }
+        if start > end {
+            points.reverse();
+        }
+        points.dedup();
+        points
+    }
+}
+
+/// Returns the cost of travelling part of an edge, which is zero if no distance is travelled,
+/// even along an edge that cannot be travelled.
+fn partial_cost(cost: f64, proportion: f64) -> f64 {
+    if proportion <= 0f64 {
+        0f64
+    } else {
+        cost
+    }
+}
+
+/// Interprets a one-way field value as whether travel is allowed forwards and backwards.
+fn oneway(value: &FieldData) -> (bool, bool) {
+    match value {
+        FieldData::Int(1) => (true, false),
+        FieldData::Int(-1) => (false, true),
+        FieldData::Real(v) if *v == 1f64 => (true, false),
+        FieldData::Real(v) if *v == -1f64 => (false, true),
+        FieldData::Bool(true) => (true, false),
+        FieldData::Text(s) => match s.trim().to_uppercase().as_str() {
+            "FT" | "F" | "Y" | "YES" | "TRUE" | "FORWARD" | "1" => (true, false),
+            "TF" | "T" | "REVERSE" | "BACKWARD" | "-1" => (false, true),
+            _ => (true, true),
+        },
+        _ => (true, true),
+    }
+}
+
+fn line_length(points: &[Point2D]) -> f64 {
+    (1..points.len())
+        .map(|k| points[k - 1].distance(&points[k]))
+        .sum()
+}
+
+/// A node waiting to be settled, ordered so that the lowest cost is popped first.
+struct QueueItem {
+    cost: f64,
+    node: usize,
+}
+
+impl PartialEq for QueueItem {
+    fn eq(&self, other: &Self) -> bool {
+        self.cost == other.cost && self.node == other.node
+    }
+}
+
+impl Eq for QueueItem {}
+
+impl PartialOrd for QueueItem {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for QueueItem {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .cost
+            .partial_cmp(&self.cost)
+            .unwrap_or(Ordering::Equal)
+            .then_with(|| other.node.cmp(&self.node))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::NetworkGraph;
+    use whitebox_common::structures::Point2D;
+
+    fn p(x: f64, y: f64) -> Point2D {
+        Point2D::new(x, y)
+    }
+
+    // A 10 by 10 square, A(0, 0), B(10, 0), C(10, 10), D(0, 10), with a diagonal from A to C
+    // that costs more to travel than going around by B.
+    fn square(snap_distance: f64, ab_cost: (f64, f64)) -> NetworkGraph {
+        let lines = vec![
+            (0, vec![p(0.0, 0.0), p(10.0, 0.0)]),
+            (1, vec![p(10.0, 0.0), p(10.0, 10.0)]),
+            (2, vec![p(10.0, 10.0), p(0.0, 10.0)]),
+            (3, vec![p(0.0, 10.0), p(0.0, 0.0)]),
+            (4, vec![p(0.0, 0.0), p(10.0, 10.0)]),
+        ];
+        let costs = vec![ab_cost, (10.0, 10.0), (10.0, 10.0), (10.0, 10.0), (30.0, 30.0)];
+        NetworkGraph::new(lines, costs, snap_distance)
+    }
+
+    fn total_length(lines: &[Vec<Point2D>]) -> f64 {
+        lines.iter().map(|l| super::line_length(l)).sum()
+    }
+
+    #[test]
+    fn test_build_graph() {
+        let graph = square(0.0, (10.0, 10.0));
+        assert_eq!(graph.nodes.len(), 4);
+        assert_eq!(graph.edges.len(), 5);
+        let ends: Vec<(usize, usize)> = graph.edges.iter().map(|e| (e.from, e.to)).collect();
+        assert_eq!(ends, vec![(0, 1), (1, 2), (2, 3), (3, 0), (0, 2)]);
+        assert_eq!(graph.incident[0], vec![0, 3, 4]);
+        assert!((graph.edges[4].length - 200f64.sqrt()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_snapping() {
+        let lines = vec![
+            (0, vec![p(0.0, 0.0), p(10.0, 0.0)]),
+            (1, vec![p(10.05, 0.02), p(20.0, 0.0)]),
+        ];
+        let costs = vec![(10.0, 10.0), (10.0, 10.0)];
+        let graph = NetworkGraph::new(lines.clone(), costs.clone(), 0.0);
+        assert_eq!(graph.nodes.len(), 4);
+
+        let graph = NetworkGraph::new(lines, costs, 0.1);
+        assert_eq!(graph.nodes.len(), 3);
+        assert_eq!(graph.edges[1].from, graph.edges[0].to);
+        // The line is joined to the node that it was snapped to.
+        assert_eq!(graph.edges[1].points[0], p(10.0, 0.0));
+    }
+
+    #[test]
+    fn test_shortest_path() {
+        let graph = square(0.0, (10.0, 10.0));
+        let origin = graph.locate(p(5.0, -1.0)).unwrap();
+        assert_eq!(origin.edge, 0);
+        assert!((origin.position - 0.5).abs() < 1e-9);
+        assert!((origin.distance - 1.0).abs() < 1e-9);
+        let tree = graph.shortest_path_tree(&origin, f64::INFINITY, false);
+        assert_eq!(tree.cost, vec![5.0, 5.0, 15.0, 15.0]);
+
+        let destination = graph.locate(p(-1.0, 5.0)).unwrap();
+        assert_eq!(destination.edge, 3);
+        let (cost, points) = graph.path_to(&tree, &destination).unwrap();
+        assert!((cost - 10.0).abs() < 1e-9);
+        assert_eq!(points, vec![p(5.0, 0.0), p(0.0, 0.0), p(0.0, 5.0)]);
+
+        // A destination on the origin's own edge is reached directly.
+        let nearby = graph.locate(p(8.0, 1.0)).unwrap();
+        assert!((graph.cost_to(&tree, &nearby) - 3.0).abs() < 1e-9);
+
+        // If A to B can only be travelled towards B, the route goes the other way around.
+        let graph = square(0.0, (10.0, f64::INFINITY));
+        let tree = graph.shortest_path_tree(&origin, f64::INFINITY, false);
+        let (cost, points) = graph.path_to(&tree, &destination).unwrap();
+        assert!((cost - 30.0).abs() < 1e-9);
+        assert_eq!(points[1], p(10.0, 0.0));
+        // In reverse, travel is towards the origin, so A to B is allowed.
+        let tree = graph.shortest_path_tree(&origin, f64::INFINITY, true);
+        assert!((graph.cost_to(&tree, &destination) - 10.0).abs() < 1e-9);
+
+        // Nodes that cost more than the maximum to reach are unreachable.
+        let tree = graph.shortest_path_tree(&origin, 8.0, false);
+        assert!(graph.path_to(&tree, &destination).is_none());
+        assert!(graph.cost_to(&tree, &destination).is_infinite());
+    }
+
+    #[test]
+    fn test_service_area() {
+        let graph = square(0.0, (10.0, 10.0));
+        let origin = graph.locate(p(5.0, 0.0)).unwrap();
+        let tree = graph.shortest_path_tree(&origin, f64::INFINITY, false);
+        // Within 6, all of A-B is reached, along with 1 unit up each side of the square and
+        // 1/30 of the diagonal.
+        let lines = graph.reachable_lines(&tree, 6.0);
+        assert_eq!(lines.len(), 4);
+        let expected = 12.0 + 200f64.sqrt() / 30.0;
+        assert!((total_length(&lines) - expected).abs() < 1e-9);
+
+        // Within 3, only the middle of A-B is reached.
+        let lines = graph.reachable_lines(&tree, 3.0);
+        assert_eq!(lines, vec![vec![p(2.0, 0.0), p(8.0, 0.0)]]);
+
+        // Everything is reached with an unlimited cost.
+        let lines = graph.reachable_lines(&tree, f64::INFINITY);
+        assert!((total_length(&lines) - (40.0 + 200f64.sqrt())).abs() < 1e-9);
+    }
+}
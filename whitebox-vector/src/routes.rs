@@ -0,0 +1,479 @@
+/*
+This code is part of the WhiteboxTools geospatial analysis library.
+Authors: Dr. John Lindsay
+Created: 18/10/2026
+Last Modified: 18/10/2026
+License: MIT
+*/
+
+use crate::{FieldData, ShapeType, Shapefile, ShapefileGeometry};
+use std::f64;
+use std::io::{Error, ErrorKind};
+use whitebox_common::structures::Point2D;
+
+// A route is a measured polyline, i.e. one whose vertices hold M values, which are used to
+// identify locations along it, e.g. by a distance from a reference point such as a kilometre
+// post. Measures are interpolated linearly between vertices, and need not increase along the
+// route. Measures smaller than -1e38 are treated as missing, following the Shapefile
+// specification, and the segments that they belong to cannot be located by measure.
+
+/// A measured polyline, made of one or more parts.
+#[derive(Clone, Debug, Default)]
+pub struct Route {
+    pub parts: Vec<Vec<Point2D>>,
+    pub measures: Vec<Vec<f64>>,
+}
+
+/// A location on a route, found by `Route::locate`.
+#[derive(Clone, Copy, Debug)]
+pub struct RoutePosition {
+    /// The location.
+    pub point: Point2D,
+    /// The measure of the location, which is NaN where the route's measures are missing.
+    pub measure: f64,
+    /// The length of the route from its start to the location, across all of its parts.
+    pub along: f64,
+    /// The distance from the located point to the route.
+    pub distance: f64,
+    /// The distance of the located point to the right of the route, or to the left if
+    /// negative, relative to the direction in which the route is digitized.
+    pub offset: f64,
+}
+
+impl Route {
+    /// Creates a route from a measured polyline, or returns `None` if it has no measures.
+    pub fn from_geometry(sfg: &ShapefileGeometry) -> Option<Route> {
+        if !sfg.has_m_data() || sfg.m_array.len() != sfg.points.len() {
+            return None;
+        }
+        let mut route = Route::default();
+        for part in 0..sfg.num_parts as usize {
+            let start = sfg.parts[part] as usize;
+            let end = if part < sfg.num_parts as usize - 1 {
+                sfg.parts[part + 1] as usize
+            } else {
+                sfg.num_points as usize
+            };
+            route.parts.push(sfg.points[start..end].to_vec());
+            route.measures.push(
+                sfg.m_array[start..end]
+                    .iter()
+                    .map(|&m| if m < -1e38 { f64::NAN } else { m })
+                    .collect(),
+            );
+        }
+        Some(route)
+    }
+
+    /// Creates a route from a set of lines, measured by the length along the lines, across all
+    /// of the parts in order, multiplied by `factor` and added to `start`.
+    pub fn measured_by_length(parts: Vec<Vec<Point2D>>, start: f64, factor: f64) -> Route {
+        let mut along = 0f64;
+        let mut measures = vec![];
+        for part in &parts {
+            let mut part_measures = vec![];
+            for k in 0..part.len() {
+                if k > 0 {
+                    along += part[k - 1].distance(&part[k]);
+                }
+                part_measures.push(start + along * factor);
+            }
+            measures.push(part_measures);
+        }
+        Route {
+            parts: parts,
+            measures: measures,
+        }
+    }
+
+    /// Returns the length of the route.
+    pub fn length(&self) -> f64 {
+        self.parts
+            .iter()
+            .map(|part| (1..part.len()).map(|k| part[k - 1].distance(&part[k])).sum::<f64>())
+            .sum()
+    }
+
+    /// Recalculates the measures of the route from a set of control points, each given by its
+    /// length along the route and its measure. The measures are interpolated by length between
+    /// the control points, and extrapolated beyond the first and last control points using the
+    /// rate of change between the two control points nearest to each end, or `factor` if there
+    /// is only one control point. The measures are unchanged if there are no control points.
+    pub fn calibrate(&mut self, controls: &[(f64, f64)], factor: f64) {
+        let mut controls: Vec<(f64, f64)> = controls.to_vec();
+        controls.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+        // Control points at the same position cannot be interpolated between.
+        controls.dedup_by(|a, b| a.0 == b.0);
+        if controls.is_empty() {
+            return;
+        }
+        let n = controls.len();
+        let rate = |i: usize, j: usize| -> f64 { (controls[j].1 - controls[i].1) / (controls[j].0 - controls[i].0) };
+        let (first_rate, last_rate) = if n > 1 {
+            (rate(0, 1), rate(n - 2, n - 1))
+        } else {
+            (factor, factor)
+        };
+        let mut along = 0f64;
+        let mut c = 0;
+        for (part, measures) in self.parts.iter().zip(self.measures.iter_mut()) {
+            for k in 0..part.len() {
+                if k > 0 {
+                    along += part[k - 1].distance(&part[k]);
+                }
+                while c + 1 < n && controls[c + 1].0 <= along {
+                    c += 1;
+                }
+                measures[k] = if along <= controls[0].0 {
+                    controls[0].1 + (along - controls[0].0) * first_rate
+                } else if c + 1 >= n {
+                    controls[n - 1].1 + (along - controls[n - 1].0) * last_rate
+                } else {
+                    controls[c].1 + (along - controls[c].0) * rate(c, c + 1)
+                };
+            }
+        }
+    }
+
+    /// Finds the nearest location on the route to a point, or `None` if the route has no
+    /// segments.
+    pub fn locate(&self, p: Point2D) -> Option<RoutePosition> {
+        let mut best: Option<RoutePosition> = None;
+        let mut along = 0f64;
+        for (part, measures) in self.parts.iter().zip(&self.measures) {
+            for k in 1..part.len() {
+                let (a, b) = (part[k - 1], part[k]);
+                let length = a.distance(&b);
+                let t = if length > 0f64 {
+                    (((p.x - a.x) * (b.x - a.x) + (p.y - a.y) * (b.y - a.y)) / (length * length))
+                        .clamp(0f64, 1f64)
+                } else {
+                    0f64
+                };
+                let q = Point2D::new(a.x + t * (b.x - a.x), a.y + t * (b.y - a.y));
+                let distance = p.distance(&q);
+                let closer = match best {
+                    Some(best) => distance < best.distance,
+                    None => true,
+                };
+                if closer {
+                    // The cross product is negative for points to the right of the segment.
+                    let cross = (b.x - a.x) * (p.y - a.y) - (b.y - a.y) * (p.x - a.x);
+                    best = Some(RoutePosition {
+                        point: q,
+                        measure: measures[k - 1] + t * (measures[k] - measures[k - 1]),
+                        along: along + t * length,
+                        distance: distance,
+                        offset: if cross > 0f64 { -distance } else { distance },
+                    });
+                }
+                along += length;
+            }
+        }
+        best
+    }
+
+    /// Returns the location of a measure on the route, displaced by `offset` to the right of
+    /// the route, or to the left if negative, or `None` if the measure is not on the route.
+    /// Where the route passes through a measure more than once, the first location is returned.
+    pub fn point_at(&self, measure: f64, offset: f64) -> Option<Point2D> {
+        for (part, measures) in self.parts.iter().zip(&self.measures) {
+            if part.len() == 1 && measures[0] == measure {
+                return Some(part[0]);
+            }
+            for k in 1..part.len() {
+                let (m0, m1) = (measures[k - 1], measures[k]);
+                if measure < m0.min(m1) || measure > m0.max(m1) {
+                    continue;
+                }
+                let t = if m1 != m0 { (measure - m0) / (m1 - m0) } else { 0f64 };
+                let (a, b) = (part[k - 1], part[k]);
+                let q = Point2D::new(a.x + t * (b.x - a.x), a.y + t * (b.y - a.y));
+                return Some(match normal(a, b) {
+                    Some(n) => Point2D::new(q.x + offset * n.x, q.y + offset * n.y),
+                    None => q,
+                });
+            }
+        }
+        None
+    }
+
+    /// Returns the parts of the route between two measures, with their measures, displaced by
+    /// `offset` to the right of the route, or to the left if negative. The sections follow the
+    /// direction of the route, whichever of the two measures is larger.
+    pub fn section(&self, from: f64, to: f64, offset: f64) -> Vec<(Vec<Point2D>, Vec<f64>)> {
+        let (lo, hi) = (from.min(to), from.max(to));
+        let mut sections = vec![];
+        for (part, measures) in self.parts.iter().zip(&self.measures) {
+            let mut points: Vec<Point2D> = vec![];
+            let mut section_measures: Vec<f64> = vec![];
+            for k in 1..part.len() {
+                let (a, b) = (part[k - 1], part[k]);
+                let (m0, m1) = (measures[k - 1], measures[k]);
+                let overlaps = m0.max(m1) >= lo && m0.min(m1) <= hi;
+                if !overlaps {
+                    sections.push((points, section_measures));
+                    points = vec![];
+                    section_measures = vec![];
+                    continue;
+                }
+                // The proportions of the segment at which it enters and leaves the range.
+                let (t0, t1) = if m1 != m0 {
+                    let ta = ((lo - m0) / (m1 - m0)).clamp(0f64, 1f64);
+                    let tb = ((hi - m0) / (m1 - m0)).clamp(0f64, 1f64);
+                    (ta.min(tb), ta.max(tb))
+                } else {
+                    (0f64, 1f64)
+                };
+                if t0 > 0f64 {
+                    sections.push((points, section_measures));
+                    points = vec![];
+                    section_measures = vec![];
+                }
+                for t in [t0, t1] {
+                    if points.is_empty() || t > t0 {
+                        points.push(Point2D::new(a.x + t * (b.x - a.x), a.y + t * (b.y - a.y)));
+                        section_measures.push(m0 + t * (m1 - m0));
+                    }
+                }
+                if t1 < 1f64 {
+                    sections.push((points, section_measures));
+                    points = vec![];
+                    section_measures = vec![];
+                }
+            }
+            sections.push((points, section_measures));
+        }
+        // Sections that only touch the range at a point are removed.
+        sections.retain(|(points, _)| points.len() > 1);
+        if offset != 0f64 {
+            for (points, _) in sections.iter_mut() {
+                *points = offset_line(points, offset);
+            }
+        }
+        sections
+    }
+}
+
+/// Reads the routes of a measured polyline Shapefile, along with the value of each route's
+/// identifier field. Features with null identifiers are ignored.
+pub fn read_routes(routes: &Shapefile, route_id_field: &str) -> Result<Vec<(FieldData, Route)>, Error> {
+    if routes.header.shape_type.base_shape_type() != ShapeType::PolyLine {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            "The routes vector data must be of POLYLINE base shape type.",
+        ));
+    }
+    let id_field = match routes.attributes.get_field_num(route_id_field) {
+        Some(i) => i,
+        None => {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!(
+                    "The field '{}' does not exist in the routes attribute table.",
+                    route_id_field
+                ),
+            ));
+        }
+    };
+    let mut ret = vec![];
+    for record_num in 0..routes.num_records {
+        let record = routes.get_record(record_num);
+        if record.shape_type == ShapeType::Null {
+            continue;
+        }
+        let route = match Route::from_geometry(record) {
+            Some(route) => route,
+            None => {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    "The routes vector data must contain measures (M values), e.g. as created by the CalibrateRoutes tool.",
+                ));
+            }
+        };
+        let id = routes.attributes.get_record(record_num)[id_field].clone();
+        if id != FieldData::Null {
+            ret.push((id, route));
+        }
+    }
+    Ok(ret)
+}
+
+/// Returns a key identifying a route by the value of its route identifier field, which matches
+/// numbers regardless of whether they are stored as integers or reals, or `None` for a null
+/// value.
+pub fn route_key(value: &FieldData) -> Option<String> {
+    match value {
+        FieldData::Int(v) => Some(v.to_string()),
+        FieldData::Real(v) => Some(if v.fract() == 0f64 && v.abs() < 1e15 {
+            format!("{}", *v as i64)
+        } else {
+            v.to_string()
+        }),
+        FieldData::Text(v) => {
+            let v = v.trim();
+            match v.parse::<f64>() {
+                Ok(n) => route_key(&FieldData::Real(n)),
+                Err(_) => Some(v.to_string()),
+            }
+        }
+        FieldData::Date(v) => Some(v.to_string()),
+        FieldData::Bool(v) => Some(v.to_string()),
+        FieldData::Null => None,
+    }
+}
+
+/// Returns the unit vector to the right of a segment, or `None` if it has no length.
+fn normal(a: Point2D, b: Point2D) -> Option<Point2D> {
+    let length = a.distance(&b);
+    if length > 0f64 {
+        Some(Point2D::new((b.y - a.y) / length, -(b.x - a.x) / length))
+    } else {
+        None
+    }
+}
+
+/// Displaces a line by a distance to its right, or to its left if negative, moving each
+/// vertex along the bisector of its two segments.
+fn offset_line(points: &[Point2D], offset: f64) -> Vec<Point2D> {
+    let n = points.len();
+    let normals: Vec<Option<Point2D>> = (1..n).map(|k| normal(points[k - 1], points[k])).collect();
+    (0..n)
+        .map(|k| {
+            let before = if k > 0 { normals[k - 1] } else { None };
+            let after = if k < n - 1 { normals[k] } else { None };
+            let (nx, ny) = match (before, after) {
+                (Some(u), Some(v)) => {
+                    let (x, y) = (u.x + v.x, u.y + v.y);
+                    let length_sqr = x * x + y * y;
+                    // Scale the bisector so that both segments are displaced by the offset,
+                    // unless they double back on one another.
+                    if length_sqr > 1e-12 {
+                        (2f64 * x / length_sqr, 2f64 * y / length_sqr)
+                    } else {
+                        (u.x, u.y)
+                    }
+                }
+                (Some(u), None) | (None, Some(u)) => (u.x, u.y),
+                (None, None) => (0f64, 0f64),
+            };
+            Point2D::new(points[k].x + offset * nx, points[k].y + offset * ny)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::{route_key, Route};
+    use crate::{FieldData, ShapeType, ShapefileGeometry};
+    use whitebox_common::structures::Point2D;
+
+    fn p(x: f64, y: f64) -> Point2D {
+        Point2D::new(x, y)
+    }
+
+    // Runs east from (0, 0) to (10, 0), then north to (10, 10), measured by length.
+    fn corner() -> Route {
+        Route::measured_by_length(vec![vec![p(0.0, 0.0), p(10.0, 0.0), p(10.0, 10.0)]], 0.0, 1.0)
+    }
+
+    #[test]
+    fn test_from_geometry() {
+        let mut sfg = ShapefileGeometry::new(ShapeType::PolyLineM);
+        sfg.add_partm(&[p(0.0, 0.0), p(10.0, 0.0)], &[0.0, 10.0]);
+        sfg.add_partm(&[p(20.0, 0.0), p(30.0, 0.0)], &[-1e39, 30.0]);
+        let route = Route::from_geometry(&sfg).unwrap();
+        assert_eq!(route.parts.len(), 2);
+        assert_eq!(route.measures[0], vec![0.0, 10.0]);
+        // Measures below -1e38 are missing.
+        assert!(route.measures[1][0].is_nan());
+
+        let mut sfg = ShapefileGeometry::new(ShapeType::PolyLine);
+        sfg.add_part(&[p(0.0, 0.0), p(10.0, 0.0)]);
+        assert!(Route::from_geometry(&sfg).is_none());
+    }
+
+    #[test]
+    fn test_measured_by_length() {
+        let route = Route::measured_by_length(
+            vec![vec![p(0.0, 0.0), p(10.0, 0.0)], vec![p(10.0, 0.0), p(10.0, 5.0)]],
+            100.0,
+            2.0,
+        );
+        assert_eq!(route.measures, vec![vec![100.0, 120.0], vec![120.0, 130.0]]);
+        assert_eq!(route.length(), 15.0);
+    }
+
+    #[test]
+    fn test_calibrate() {
+        let line = vec![vec![p(0.0, 0.0), p(10.0, 0.0), p(20.0, 0.0), p(30.0, 0.0)]];
+        let mut route = Route::measured_by_length(line.clone(), 0.0, 1.0);
+        // The control points are sorted, and extrapolated beyond with the nearest rate.
+        route.calibrate(&[(20.0, 150.0), (10.0, 100.0)], 1.0);
+        assert_eq!(route.measures[0], vec![50.0, 100.0, 150.0, 200.0]);
+
+        // A single control point is extrapolated with the factor.
+        let mut route = Route::measured_by_length(line.clone(), 0.0, 1.0);
+        route.calibrate(&[(10.0, 100.0)], 2.0);
+        assert_eq!(route.measures[0], vec![80.0, 100.0, 120.0, 140.0]);
+
+        // Three control points are interpolated between piecewise.
+        let mut route = Route::measured_by_length(line.clone(), 0.0, 1.0);
+        route.calibrate(&[(0.0, 0.0), (10.0, 5.0), (30.0, 45.0)], 1.0);
+        assert_eq!(route.measures[0], vec![0.0, 5.0, 25.0, 45.0]);
+
+        let mut route = Route::measured_by_length(line, 0.0, 1.0);
+        route.calibrate(&[], 1.0);
+        assert_eq!(route.measures[0], vec![0.0, 10.0, 20.0, 30.0]);
+    }
+
+    #[test]
+    fn test_locate() {
+        let route = corner();
+        let pos = route.locate(p(5.0, 2.0)).unwrap();
+        assert_eq!(pos.point, p(5.0, 0.0));
+        assert_eq!(pos.measure, 5.0);
+        assert_eq!(pos.along, 5.0);
+        assert_eq!(pos.distance, 2.0);
+        // The point is to the left of the eastward segment.
+        assert_eq!(pos.offset, -2.0);
+
+        let pos = route.locate(p(12.0, 5.0)).unwrap();
+        assert_eq!(pos.point, p(10.0, 5.0));
+        assert_eq!(pos.measure, 15.0);
+        assert_eq!(pos.offset, 2.0);
+
+        assert!(Route::default().locate(p(0.0, 0.0)).is_none());
+    }
+
+    #[test]
+    fn test_point_at() {
+        let route = corner();
+        assert_eq!(route.point_at(15.0, 0.0), Some(p(10.0, 5.0)));
+        // Right of the northward segment is east.
+        assert_eq!(route.point_at(15.0, 1.0), Some(p(11.0, 5.0)));
+        assert_eq!(route.point_at(5.0, 1.0), Some(p(5.0, -1.0)));
+        assert_eq!(route.point_at(25.0, 0.0), None);
+    }
+
+    #[test]
+    fn test_section() {
+        let route = corner();
+        let sections = route.section(15.0, 5.0, 0.0);
+        assert_eq!(sections.len(), 1);
+        assert_eq!(sections[0].0, vec![p(5.0, 0.0), p(10.0, 0.0), p(10.0, 5.0)]);
+        assert_eq!(sections[0].1, vec![5.0, 10.0, 15.0]);
+
+        // A section that only touches the route at a point is removed.
+        assert!(route.section(20.0, 30.0, 0.0).is_empty());
+    }
+
+    #[test]
+    fn test_route_key() {
+        assert_eq!(route_key(&FieldData::Int(3)), Some("3".to_string()));
+        assert_eq!(route_key(&FieldData::Real(3.0)), Some("3".to_string()));
+        assert_eq!(route_key(&FieldData::Text(" 3 ".to_string())), Some("3".to_string()));
+        assert_eq!(route_key(&FieldData::Text("A1".to_string())), Some("A1".to_string()));
+        assert_eq!(route_key(&FieldData::Null), None);
+    }
+}
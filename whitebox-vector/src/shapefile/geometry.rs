@@ -2,10 +2,10 @@
 This file is part of the WhiteboxTools geospatial analysis library.
 Authors: Dr. John Lindsay
 Created: 10/04/2018
-Last Modified: 30/09/2018
+Last Modified: 18/10/2026
 License: MIT
 */
-use whitebox_common::algorithms::point_in_poly;
+use whitebox_common::algorithms::{group_rings, orient_rings, point_in_poly};
 use whitebox_common::structures::{BoundingBox, Point2D};
 use std::f64;
 use std::fmt;
@@ -136,6 +136,19 @@ impl ShapefileGeometry {
         self.num_points += points.len() as i32;
     }
 
+    /// Creates a polygon ShapefileGeometry from a set of rings, such as those returned by
+    /// `whitebox_common::algorithms::polygon_boolean`, with each hole following the outer
+    /// ring that contains it.
+    pub fn from_polygon_rings(rings: Vec<Vec<Point2D>>) -> ShapefileGeometry {
+        let mut sfg = ShapefileGeometry::new(ShapeType::Polygon);
+        for polygon in group_rings(rings) {
+            for ring in &polygon {
+                sfg.add_part(ring);
+            }
+        }
+        sfg
+    }
+
     /// Adds a part of Point2Ds and measures to the ShapefileGeometry.
     pub fn add_partm(&mut self, points: &[Point2D], measures: &[f64]) {
         if points.len() != measures.len() {
@@ -284,6 +297,29 @@ impl ShapefileGeometry {
         self.z_array.len() > 0
     }
 
+    /// Returns the points of a part of the geometry.
+    pub fn get_part(&self, part_num: usize) -> &[Point2D] {
+        let start = self.parts[part_num] as usize;
+        let end = if part_num + 1 < self.num_parts as usize {
+            self.parts[part_num + 1] as usize
+        } else {
+            self.num_points as usize
+        };
+        &self.points[start..end]
+    }
+
+    /// Returns the rings of a polygon geometry, oriented by how deeply they are nested so
+    /// that outer rings are clockwise and holes are counter-clockwise, whatever their order
+    /// in the file. This is the form expected by the polygon overlay functions of
+    /// `whitebox_common::algorithms`.
+    pub fn get_polygon_rings(&self) -> Vec<Vec<Point2D>> {
+        let mut rings: Vec<Vec<Point2D>> = (0..self.num_parts as usize)
+            .map(|part| self.get_part(part).to_vec())
+            .collect();
+        orient_rings(&mut rings);
+        rings
+    }
+
     /// Checks whether a point is within the hull of the geometry. If
     /// the geometry is not of POLYGON base ShapeType, the function
     /// will return false.
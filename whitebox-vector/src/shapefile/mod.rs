@@ -2,7 +2,7 @@
 This code is part of the WhiteboxTools geospatial analysis library.
 Authors: Dr. John Lindsay
 Created: 21/06/2017
-Last Modified: 17/10/2018
+Last Modified: 18/10/2026
 License: MIT
 
 Notes: The logic behind working with the ESRI Shapefile format.
@@ -13,8 +13,9 @@ pub mod geometry;
 
 use self::attributes::*;
 use self::geometry::*;
+use whitebox_common::algorithms::OverlayFeature;
 use whitebox_common::structures::Point2D;
-use whitebox_common::utils::{ByteOrderReader, Endianness};
+use whitebox_common::utils::{is_memory_path, ByteOrderReader, Endianness, MemoryStore};
 use byteorder::{BigEndian, LittleEndian, WriteBytesExt};
 use chrono::prelude::*;
 use std::f64;
@@ -79,6 +80,11 @@ m_max: {}",
     }
 }
 
+/// In-memory vectors, addressed by tools with `memory://name` file names. Reading such a
+/// file returns a copy of the stored `Shapefile` and writing one stores a copy of the
+/// output, without any disk I/O.
+pub static MEMORY_SHAPEFILES: MemoryStore<Shapefile> = MemoryStore::new();
+
 /// `Shapefile` is an in-memory ESRI Shapefile.
 ///
 /// Examples:
@@ -110,6 +116,19 @@ pub struct Shapefile {
 
 impl Shapefile {
     pub fn read<'a>(file_name: &'a str) -> Result<Shapefile, Error> {
+        if is_memory_path(file_name) {
+            return match MEMORY_SHAPEFILES.get(file_name) {
+                Some(mut sf) => {
+                    sf.file_name = file_name.to_string();
+                    sf.file_mode = "r".to_string();
+                    Ok(sf)
+                }
+                None => Err(Error::new(
+                    ErrorKind::NotFound,
+                    format!("No in-memory vector named {}", file_name),
+                )),
+            };
+        }
         let mut sf = Shapefile {
             file_name: file_name.to_string(),
             file_mode: "r".to_string(),
@@ -120,7 +139,7 @@ impl Shapefile {
     }
 
     pub fn new<'a>(file_name: &'a str, file_type: ShapeType) -> Result<Shapefile, Error> {
-        let new_file_name = if file_name.contains(".") {
+        let new_file_name = if file_name.contains(".") || is_memory_path(file_name) {
             file_name.to_string()
         } else {
             // likely no extension provided; default to .shp
@@ -141,7 +160,7 @@ impl Shapefile {
         shape_type: ShapeType,
         copy_fields: bool,
     ) -> Result<Shapefile, Error> {
-        let new_file_name = if file_name.contains(".") {
+        let new_file_name = if file_name.contains(".") || is_memory_path(file_name) {
             file_name.to_string()
         } else {
             // likely no extension provided; default to .shp
@@ -194,6 +213,28 @@ impl Shapefile {
         &self.records[index]
     }
 
+    /// Returns the bounding box and rings of each record, in the form used by
+    /// `whitebox_common::algorithms::PolygonOverlay`.
+    pub fn get_polygon_features(&self) -> Vec<OverlayFeature> {
+        self.records
+            .iter()
+            .map(|record| (record.get_bounding_box(), record.get_polygon_rings()))
+            .collect()
+    }
+
+    /// Returns the parts of each record, in the form used by
+    /// `whitebox_common::algorithms::clip_polyline_features`.
+    pub fn get_line_features(&self) -> Vec<Vec<Vec<Point2D>>> {
+        self.records
+            .iter()
+            .map(|record| {
+                (0..record.num_parts as usize)
+                    .map(|part| record.get_part(part).to_vec())
+                    .collect()
+            })
+            .collect()
+    }
+
     /// Adds a new ShapefileGeometry.
     pub fn add_record(&mut self, geometry: ShapefileGeometry) {
         if self.file_mode == "r" {
@@ -727,6 +768,14 @@ impl Shapefile {
             ));
         }
 
+        if is_memory_path(&self.file_name) {
+            self.calculate_extent();
+            let mut stored = self.clone();
+            stored.file_mode = "r".to_string();
+            MEMORY_SHAPEFILES.insert(&self.file_name, stored);
+            return Ok(());
+        }
+
         /////////////////////////////////////////
         // Write the geometry data (.shp file) //
         /////////////////////////////////////////
@@ -0,0 +1,370 @@
+/*
+This code is part of the WhiteboxTools geospatial analysis library.
+Authors: Dr. John Lindsay
+Created: 18/10/2026
+Last Modified: 18/10/2026
+License: MIT
+*/
+
+use crate::{ShapeType, Shapefile};
+use rstar::primitives::GeomWithData;
+use rstar::RTree;
+use std::collections::HashMap;
+use std::f64;
+use std::io::{Error, ErrorKind};
+use whitebox_common::structures::Point2D;
+
+// Spatial weights describe which features of a layer are neighbours of each other, for measuring
+// spatial autocorrelation. Neighbours are defined either by the distances between the centroids of
+// the features, or, for polygons, by contiguity. Polygons are contiguous only if their boundaries
+// share vertices, which is the case for a topologically clean polygon layer, e.g. one created by
+// the Polygonize tool, but not for polygons that merely touch along their edges.
+
+/// The methods of defining the neighbours of the features in `SpatialWeights`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SpatialWeightsType {
+    /// Features whose centroids are within a distance of each other.
+    DistanceBand(f64),
+    /// The given number of features with the nearest centroids.
+    KNearest(usize),
+    /// Polygons that share at least one vertex.
+    Queen,
+    /// Polygons that share at least one edge, i.e. a pair of consecutive vertices.
+    Rook,
+}
+
+/// The neighbours of a set of features of a point or polygon Shapefile. Features are identified by
+/// their index in the list of records from which the weights are built. The weights are binary, and
+/// apart from k-nearest neighbours, symmetric.
+pub struct SpatialWeights {
+    neighbours: Vec<Vec<usize>>,
+}
+
+impl SpatialWeights {
+    /// Builds the spatial weights of a set of records of a point or polygon Shapefile, each of
+    /// which must have a centroid.
+    pub fn new(
+        shapefile: &Shapefile,
+        records: &[usize],
+        weights_type: SpatialWeightsType,
+    ) -> Result<SpatialWeights, Error> {
+        let neighbours = match weights_type {
+            SpatialWeightsType::DistanceBand(distance) => {
+                let centroids = centroids(shapefile, records)?;
+                let tree = build_index(&centroids);
+                centroids
+                    .iter()
+                    .enumerate()
+                    .map(|(i, p)| {
+                        let mut list: Vec<usize> = tree
+                            .locate_within_distance([p.x, p.y], distance * distance)
+                            .map(|g| g.data)
+                            .filter(|j| *j != i)
+                            .collect();
+                        list.sort_unstable();
+                        list
+                    })
+                    .collect()
+            }
+            SpatialWeightsType::KNearest(k) => {
+                let centroids = centroids(shapefile, records)?;
+                let tree = build_index(&centroids);
+                centroids
+                    .iter()
+                    .enumerate()
+                    .map(|(i, p)| {
+                        let mut list: Vec<usize> = tree
+                            .nearest_neighbor_iter(&[p.x, p.y])
+                            .map(|g| g.data)
+                            .filter(|j| *j != i)
+                            .take(k)
+                            .collect();
+                        list.sort_unstable();
+                        list
+                    })
+                    .collect()
+            }
+            SpatialWeightsType::Queen | SpatialWeightsType::Rook => {
+                if shapefile.header.shape_type.base_shape_type() != ShapeType::Polygon {
+                    return Err(Error::new(
+                        ErrorKind::InvalidInput,
+                        "Contiguity-based spatial weights require a polygon layer.",
+                    ));
+                }
+                contiguity(shapefile, records, weights_type == SpatialWeightsType::Rook)
+            }
+        };
+        Ok(SpatialWeights { neighbours })
+    }
+
+    /// Finds the smallest distance band within which every one of a set of records has at least
+    /// one neighbour, i.e. the largest distance from a feature's centroid to its nearest neighbour.
+    pub fn threshold_distance(shapefile: &Shapefile, records: &[usize]) -> Result<f64, Error> {
+        let centroids = centroids(shapefile, records)?;
+        let tree = build_index(&centroids);
+        let mut threshold = 0f64;
+        for (i, p) in centroids.iter().enumerate() {
+            if let Some(q) = tree.nearest_neighbor_iter(&[p.x, p.y]).find(|g| g.data != i) {
+                threshold = threshold.max(p.distance(&centroids[q.data]));
+            }
+        }
+        Ok(threshold)
+    }
+
+    /// The number of features.
+    pub fn len(&self) -> usize {
+        self.neighbours.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.neighbours.is_empty()
+    }
+
+    /// The neighbours of a feature.
+    pub fn neighbours(&self, i: usize) -> &[usize] {
+        &self.neighbours[i]
+    }
+
+    /// The number of features that have no neighbours.
+    pub fn num_islands(&self) -> usize {
+        self.neighbours.iter().filter(|n| n.is_empty()).count()
+    }
+}
+
+/// The centroid of a feature of a point or polygon Shapefile, i.e. the mean of the points of a
+/// point or multipoint feature, or the centroid of the area of a polygon. Returns `None` for a
+/// feature without any points.
+pub fn feature_centroid(shapefile: &Shapefile, record_num: usize) -> Option<Point2D> {
+    let record = shapefile.get_record(record_num);
+    if record.points.is_empty() {
+        return None;
+    }
+    let n = record.points.len() as f64;
+    let mean = Point2D::new(
+        record.points.iter().map(|p| p.x).sum::<f64>() / n,
+        record.points.iter().map(|p| p.y).sum::<f64>() / n,
+    );
+    if shapefile.header.shape_type.base_shape_type() != ShapeType::Polygon {
+        return Some(mean);
+    }
+    // The area-weighted centroid of the rings, with holes having negative areas. Coordinates are
+    // taken relative to the mean point to limit rounding errors.
+    let (mut area, mut cx, mut cy) = (0f64, 0f64, 0f64);
+    for part in 0..record.num_parts as usize {
+        let start = record.parts[part] as usize;
+        let end = if part < record.num_parts as usize - 1 {
+            record.parts[part + 1] as usize
+        } else {
+            record.num_points as usize
+        };
+        let (mut ring_area, mut ring_x, mut ring_y) = (0f64, 0f64, 0f64);
+        for k in start..end {
+            let a = record.points[k] - mean;
+            let b = record.points[if k + 1 < end { k + 1 } else { start }] - mean;
+            let cross = a.x * b.y - b.x * a.y;
+            ring_area += cross / 2f64;
+            ring_x += (a.x + b.x) * cross / 6f64;
+            ring_y += (a.y + b.y) * cross / 6f64;
+        }
+        // Make the ring's area positive for an outer ring and negative for a hole, whatever
+        // its orientation.
+        let sign = if (ring_area < 0f64) != record.is_hole(part as i32) {
+            -1f64
+        } else {
+            1f64
+        };
+        area += sign * ring_area;
+        cx += sign * ring_x;
+        cy += sign * ring_y;
+    }
+    if area.abs() > 0f64 {
+        Some(Point2D::new(mean.x + cx / area, mean.y + cy / area))
+    } else {
+        Some(mean)
+    }
+}
+
+fn centroids(shapefile: &Shapefile, records: &[usize]) -> Result<Vec<Point2D>, Error> {
+    records
+        .iter()
+        .map(|r| {
+            feature_centroid(shapefile, *r).ok_or_else(|| {
+                Error::new(
+                    ErrorKind::InvalidInput,
+                    format!("Feature {} has no geometry.", r + 1),
+                )
+            })
+        })
+        .collect()
+}
+
+fn build_index(centroids: &[Point2D]) -> RTree<GeomWithData<[f64; 2], usize>> {
+    RTree::bulk_load(
+        centroids
+            .iter()
+            .enumerate()
+            .map(|(i, p)| GeomWithData::new([p.x, p.y], i))
+            .collect(),
+    )
+}
+
+/// A vertex, keyed by its exact coordinates.
+type VertexKey = (u64, u64);
+
+/// Finds the polygons sharing vertices, or, for rook contiguity, edges.
+fn contiguity(shapefile: &Shapefile, records: &[usize], rook: bool) -> Vec<Vec<usize>> {
+    let key = |p: &Point2D| -> VertexKey { (p.x.to_bits(), p.y.to_bits()) };
+    let mut shared: HashMap<(VertexKey, VertexKey), Vec<usize>> = HashMap::new();
+    for (i, record_num) in records.iter().enumerate() {
+        let record = shapefile.get_record(*record_num);
+        for part in 0..record.num_parts as usize {
+            let start = record.parts[part] as usize;
+            let end = if part < record.num_parts as usize - 1 {
+                record.parts[part + 1] as usize
+            } else {
+                record.num_points as usize
+            };
+            for k in start..end {
+                let a = key(&record.points[k]);
+                // Vertices are keyed as degenerate edges.
+                let element = if rook {
+                    let b = key(&record.points[if k + 1 < end { k + 1 } else { start }]);
+                    if a == b {
+                        continue;
+                    }
+                    (a.min(b), a.max(b))
+                } else {
+                    (a, a)
+                };
+                let features = shared.entry(element).or_default();
+                if features.last() != Some(&i) {
+                    features.push(i);
+                }
+            }
+        }
+    }
+    let mut neighbours = vec![vec![]; records.len()];
+    for features in shared.values() {
+        for a in features {
+            for b in features {
+                if a != b {
+                    neighbours[*a].push(*b);
+                }
+            }
+        }
+    }
+    for list in neighbours.iter_mut() {
+        list.sort_unstable();
+        list.dedup();
+    }
+    neighbours
+}
+
+#[cfg(test)]
+mod test {
+    use super::{feature_centroid, SpatialWeights, SpatialWeightsType};
+    use crate::{ShapeType, Shapefile, ShapefileGeometry};
+    use whitebox_common::structures::Point2D;
+
+    // Points along the x axis at 0, 1, 3, and 10.
+    fn points() -> Shapefile {
+        let mut shapefile = Shapefile::new("points.shp", ShapeType::Point).unwrap();
+        for x in [0.0, 1.0, 3.0, 10.0] {
+            shapefile.add_point_record(x, 0.0);
+        }
+        shapefile
+    }
+
+    // A 2 by 2 grid of unit squares, A, B, C, and D, in that order, and a separate square E.
+    fn squares() -> Shapefile {
+        let mut shapefile = Shapefile::new("squares.shp", ShapeType::Polygon).unwrap();
+        for (x, y) in [(0.0, 0.0), (1.0, 0.0), (0.0, 1.0), (1.0, 1.0), (5.0, 5.0)] {
+            let mut sfg = ShapefileGeometry::new(ShapeType::Polygon);
+            sfg.add_part(&[
+                Point2D::new(x, y),
+                Point2D::new(x, y + 1.0),
+                Point2D::new(x + 1.0, y + 1.0),
+                Point2D::new(x + 1.0, y),
+                Point2D::new(x, y),
+            ]);
+            shapefile.add_record(sfg);
+        }
+        shapefile
+    }
+
+    fn neighbour_lists(weights: &SpatialWeights) -> Vec<Vec<usize>> {
+        (0..weights.len()).map(|i| weights.neighbours(i).to_vec()).collect()
+    }
+
+    #[test]
+    fn test_distance_band() {
+        let shapefile = points();
+        let records = [0, 1, 2, 3];
+        let weights =
+            SpatialWeights::new(&shapefile, &records, SpatialWeightsType::DistanceBand(1.5)).unwrap();
+        assert_eq!(neighbour_lists(&weights), vec![vec![1], vec![0], vec![], vec![]]);
+        assert_eq!(weights.num_islands(), 2);
+
+        let weights =
+            SpatialWeights::new(&shapefile, &records, SpatialWeightsType::DistanceBand(2.0)).unwrap();
+        assert_eq!(neighbour_lists(&weights), vec![vec![1], vec![0, 2], vec![1], vec![]]);
+        assert_eq!(weights.num_islands(), 1);
+
+        // The threshold distance leaves no islands.
+        let threshold = SpatialWeights::threshold_distance(&shapefile, &records).unwrap();
+        assert_eq!(threshold, 7.0);
+        let weights = SpatialWeights::new(
+            &shapefile,
+            &records,
+            SpatialWeightsType::DistanceBand(threshold),
+        )
+        .unwrap();
+        assert_eq!(weights.num_islands(), 0);
+
+        // Features are identified by their index in the list of records.
+        let weights =
+            SpatialWeights::new(&shapefile, &[1, 3], SpatialWeightsType::DistanceBand(9.0)).unwrap();
+        assert_eq!(neighbour_lists(&weights), vec![vec![1], vec![0]]);
+    }
+
+    #[test]
+    fn test_k_nearest() {
+        let shapefile = points();
+        let weights =
+            SpatialWeights::new(&shapefile, &[0, 1, 2, 3], SpatialWeightsType::KNearest(2)).unwrap();
+        assert_eq!(
+            neighbour_lists(&weights),
+            vec![vec![1, 2], vec![0, 2], vec![0, 1], vec![1, 2]]
+        );
+        assert_eq!(weights.num_islands(), 0);
+    }
+
+    #[test]
+    fn test_contiguity() {
+        let shapefile = squares();
+        let records = [0, 1, 2, 3, 4];
+        let queen = SpatialWeights::new(&shapefile, &records, SpatialWeightsType::Queen).unwrap();
+        assert_eq!(
+            neighbour_lists(&queen),
+            vec![vec![1, 2, 3], vec![0, 2, 3], vec![0, 1, 3], vec![0, 1, 2], vec![]]
+        );
+        assert_eq!(queen.num_islands(), 1);
+
+        // Diagonal squares only share a corner, so they are not rook neighbours.
+        let rook = SpatialWeights::new(&shapefile, &records, SpatialWeightsType::Rook).unwrap();
+        assert_eq!(
+            neighbour_lists(&rook),
+            vec![vec![1, 2], vec![0, 3], vec![0, 3], vec![1, 2], vec![]]
+        );
+
+        assert!(SpatialWeights::new(&points(), &[0, 1], SpatialWeightsType::Queen).is_err());
+    }
+
+    #[test]
+    fn test_feature_centroid() {
+        let shapefile = squares();
+        assert_eq!(feature_centroid(&shapefile, 3), Some(Point2D::new(1.5, 1.5)));
+        let shapefile = points();
+        assert_eq!(feature_centroid(&shapefile, 2), Some(Point2D::new(3.0, 0.0)));
+    }
+}
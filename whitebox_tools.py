@@ -346,6 +346,51 @@ class WhiteboxTools(object):
             callback(str(err))
             return 1
 
+    def run_workflow(self, workflow_file, callback=None):
+        '''
+        Runs a workflow of tools described in a JSON file.
+        Returns 0 if completes without error.
+        Returns 1 if error encountered (details are sent to callback).
+        Returns 2 if process is cancelled by user.
+        '''
+        try:
+            if callback is None:
+                callback = self.default_callback
+
+            os.chdir(self.exe_path)
+            args2 = []
+            args2.append("." + path.sep + self.exe_name)
+            args2.append("--workflow=\"{}\"".format(workflow_file))
+
+            if self.work_dir.strip() != "":
+                args2.append("--wd=\"{}\"".format(self.work_dir))
+
+            if self.verbose:
+                args2.append("-v")
+            else:
+                args2.append("-v=false")
+
+            proc = Popen(args2, shell=False, stdout=PIPE,
+                        stderr=STDOUT, bufsize=1, universal_newlines=True)
+
+            while proc is not None:
+                line = proc.stdout.readline()
+                sys.stdout.flush()
+                if line != '':
+                    if not self.cancel_op:
+                        callback(line.strip())
+                    else:
+                        self.cancel_op = False
+                        proc.terminate()
+                        return 2
+                else:
+                    break
+
+            return 0
+        except (OSError, ValueError, CalledProcessError) as err:
+            callback(str(err))
+            return 1
+
     def help(self):
         ''' 
         Retrieves the help description for WhiteboxTools.
@@ -1100,6 +1145,26 @@ Okay, that's it for now.
         args.append("--output='{}'".format(output))
         return self.run_tool('single_part_to_multi_part', args, callback) # returns 1 if error
 
+    def validate_polygons(self, i, output, repaired=None, snap=0.0, min_area=0.0, callback=None):
+        """Finds topological errors in a polygon layer, such as self-intersections, overlaps, and gaps, and optionally repairs them.
+
+        Keyword arguments:
+
+        i -- Input vector polygon file. 
+        output -- Output vector points file of topology errors. 
+        repaired -- Optional output vector polygon file of repaired polygons. 
+        snap -- Distance within which nearly coincident boundaries are treated as shared. 
+        min_area -- Area below which polygon parts and gaps are treated as slivers. 
+        callback -- Custom function for handling tool text outputs.
+        """
+        args = []
+        args.append("--input='{}'".format(i))
+        args.append("--output='{}'".format(output))
+        if repaired is not None: args.append("--repaired='{}'".format(repaired))
+        args.append("--snap={}".format(snap))
+        args.append("--min_area={}".format(min_area))
+        return self.run_tool('validate_polygons', args, callback) # returns 1 if error
+
     def vector_lines_to_raster(self, i, output, field="FID", nodata=True, cell_size=None, base=None, callback=None):
         """Converts a vector containing polylines into a raster.
 
@@ -1234,6 +1299,34 @@ Okay, that's it for now.
         if base is not None: args.append("--base='{}'".format(base))
         return self.run_tool('block_minimum_gridding', args, callback) # returns 1 if error
 
+    def buffer_vector(self, i, output, dist=10.0, dist_field=None, cap_style="round", join_style="round", mitre_limit=5.0, segments=8, dissolve=False, callback=None):
+        """Creates the polygons within a specified distance of the features in a vector of points, lines, or polygons.
+
+        Keyword arguments:
+
+        i -- Input vector file. 
+        output -- Output vector polygon file. 
+        dist -- Buffer distance; negative distances shrink polygons. 
+        dist_field -- Optional numeric field containing the buffer distance of each feature. 
+        cap_style -- Shape of the buffer at the ends of lines; options are 'round', 'flat', and 'square'. 
+        join_style -- Shape of the buffer around corners; options are 'round', 'mitre', and 'bevel'. 
+        mitre_limit -- Greatest distance of a mitre join's tip from its vertex, as a multiple of the buffer distance. 
+        segments -- Number of segments used to approximate a quarter circle in round caps and joins. 
+        dissolve -- Optional flag to request the output polygons be dissolved. 
+        callback -- Custom function for handling tool text outputs.
+        """
+        args = []
+        args.append("--input='{}'".format(i))
+        args.append("--output='{}'".format(output))
+        args.append("--dist={}".format(dist))
+        if dist_field is not None: args.append("--dist_field='{}'".format(dist_field))
+        args.append("--cap_style={}".format(cap_style))
+        args.append("--join_style={}".format(join_style))
+        args.append("--mitre_limit={}".format(mitre_limit))
+        args.append("--segments={}".format(segments))
+        if dissolve: args.append("--dissolve")
+        return self.run_tool('buffer_vector', args, callback) # returns 1 if error
+
     def centroid(self, i, output, text_output=False, callback=None):
         """Calculates the centroid, or average location, of raster polygon objects.
 
@@ -1530,6 +1623,74 @@ Okay, that's it for now.
         if base is not None: args.append("--base='{}'".format(base))
         return self.run_tool('idw_interpolation', args, callback) # returns 1 if error
 
+    def kernel_density(self, i, output, weight_field=None, kernel="quartic", bandwidth=None, bandwidth_method="silverman", cell_size=None, base=None, callback=None):
+        """Estimates the density of vector points using kernel density estimation with bandwidth selection.
+
+        Keyword arguments:
+
+        i -- Input vector points file. 
+        weight_field -- Optional numeric field holding the weight, e.g. the population, of each point. 
+        output -- Output raster file. 
+        kernel -- Kernel function; options are 'quartic', 'epanechnikov', and 'gaussian'. 
+        bandwidth -- Optional kernel bandwidth, in map units, or metres for geographic coordinates; estimated from the points by default. 
+        bandwidth_method -- Method used to estimate the bandwidth when it is not specified; options are 'silverman' and 'cross-validation'. 
+        cell_size -- Optional output raster cell size, in map units; not used when a base raster is specified. 
+        base -- Optional input base raster file, which sets the output raster's extent and resolution. 
+        callback -- Custom function for handling tool text outputs.
+        """
+        args = []
+        args.append("--input='{}'".format(i))
+        if weight_field is not None: args.append("--weight_field='{}'".format(weight_field))
+        args.append("--output='{}'".format(output))
+        args.append("--kernel={}".format(kernel))
+        if bandwidth is not None: args.append("--bandwidth='{}'".format(bandwidth))
+        args.append("--bandwidth_method={}".format(bandwidth_method))
+        if cell_size is not None: args.append("--cell_size='{}'".format(cell_size))
+        if base is not None: args.append("--base='{}'".format(base))
+        return self.run_tool('kernel_density', args, callback) # returns 1 if error
+
+    def kriging_interpolation(self, i, output, field=None, use_z=False, variance=None, report=None, mode="ordinary", trend="linear", drift=None, model="auto", num_lags=15, max_distance=None, num_points=16, cell_size=None, base=None, seed=None, callback=None):
+        """Interpolates vector points into a raster surface by kriging, with automatic variogram fitting.
+
+        Keyword arguments:
+
+        i -- Input vector points file. 
+        field -- Input numeric field holding the values to interpolate. 
+        use_z -- Interpolate the z-coordinates of the points instead of a field?. 
+        output -- Output raster file. 
+        variance -- Optional output kriging variance raster file. 
+        report -- Optional output HTML file plotting the empirical and fitted variograms. 
+        mode -- Kriging mode; options are 'ordinary', 'universal', and 'external_drift'. 
+        trend -- Polynomial trend of the coordinates used in universal kriging; options are 'linear' and 'quadratic'. 
+        drift -- Input drift raster file, required for kriging with external drift. 
+        model -- Variogram model; options are 'auto', 'spherical', 'exponential', 'gaussian', 'matern', and 'nugget'. 
+        num_lags -- Number of lags of the empirical variogram. 
+        max_distance -- Optional maximum distance of the empirical variogram; one third of the diagonal of the points' extent by default. 
+        num_points -- Number of nearest points used to estimate each grid cell. 
+        cell_size -- Optional output raster cell size, in map units; not used when a base raster is specified. 
+        base -- Optional input base raster file, which sets the output raster's extent and resolution. 
+        seed -- Optional seed for the random number generator, for reproducible outputs. 
+        callback -- Custom function for handling tool text outputs.
+        """
+        args = []
+        args.append("--input='{}'".format(i))
+        if field is not None: args.append("--field='{}'".format(field))
+        if use_z: args.append("--use_z")
+        args.append("--output='{}'".format(output))
+        if variance is not None: args.append("--variance='{}'".format(variance))
+        if report is not None: args.append("--report='{}'".format(report))
+        args.append("--mode={}".format(mode))
+        args.append("--trend={}".format(trend))
+        if drift is not None: args.append("--drift='{}'".format(drift))
+        args.append("--model={}".format(model))
+        args.append("--num_lags={}".format(num_lags))
+        if max_distance is not None: args.append("--max_distance='{}'".format(max_distance))
+        args.append("--num_points={}".format(num_points))
+        if cell_size is not None: args.append("--cell_size='{}'".format(cell_size))
+        if base is not None: args.append("--base='{}'".format(base))
+        if seed is not None: args.append("--seed='{}'".format(seed))
+        return self.run_tool('kriging_interpolation', args, callback) # returns 1 if error
+
     def layer_footprint(self, i, output, callback=None):
         """Creates a vector polygon footprint of the area covered by a raster grid or vector layer.
 
@@ -1866,6 +2027,26 @@ Okay, that's it for now.
         args.append("--output='{}'".format(output))
         return self.run_tool('reclass_from_file', args, callback) # returns 1 if error
 
+    def simplify_vector(self, i, output, tolerance, method="douglas_peucker", preserve_topology=False, callback=None):
+        """Simplifies the lines or polygons in a vector, optionally preserving shared boundaries.
+
+        Keyword arguments:
+
+        i -- Input vector POLYLINE or POLYGON file. 
+        output -- Output vector file. 
+        method -- Simplification method; options are 'douglas_peucker' and 'visvalingam'. 
+        tolerance -- Simplification tolerance; a distance for 'douglas_peucker' and an area for 'visvalingam'. 
+        preserve_topology -- Optional flag to keep shared boundaries coincident and prevent new intersections. 
+        callback -- Custom function for handling tool text outputs.
+        """
+        args = []
+        args.append("--input='{}'".format(i))
+        args.append("--output='{}'".format(output))
+        args.append("--method={}".format(method))
+        args.append("--tolerance='{}'".format(tolerance))
+        if preserve_topology: args.append("--preserve_topology")
+        return self.run_tool('simplify_vector', args, callback) # returns 1 if error
+
     def smooth_vectors(self, i, output, filter=3, callback=None):
         """Smooths a vector coverage of either a POLYLINE or POLYGON base ShapeType.
 
@@ -2072,6 +2253,192 @@ Okay, that's it for now.
         args.append("--output='{}'".format(output))
         return self.run_tool('euclidean_distance', args, callback) # returns 1 if error
 
+    ###################################
+    # GIS Analysis/Linear Referencing #
+    ###################################
+
+    def calibrate_routes(self, i, output, route_id=None, start=0.0, factor=1.0, points=None, measure_field=None, tolerance=None, callback=None):
+        """Creates measured routes from lines, with measures assigned by length or from control points.
+
+        Keyword arguments:
+
+        i -- Input vector lines file. 
+        route_id -- Optional field identifying the route of each line; each line is a route by default. 
+        output -- Output vector routes file. 
+        start -- Measure at the start of each route. 
+        factor -- Change in measure per unit of length along a route. 
+        points -- Optional input vector points file of the control points used to calibrate the routes. 
+        measure_field -- Numeric field holding the measure of each control point. 
+        tolerance -- Optional maximum distance between a control point and its route. 
+        callback -- Custom function for handling tool text outputs.
+        """
+        args = []
+        args.append("--input='{}'".format(i))
+        if route_id is not None: args.append("--route_id='{}'".format(route_id))
+        args.append("--output='{}'".format(output))
+        args.append("--start={}".format(start))
+        args.append("--factor={}".format(factor))
+        if points is not None: args.append("--points='{}'".format(points))
+        if measure_field is not None: args.append("--measure_field='{}'".format(measure_field))
+        if tolerance is not None: args.append("--tolerance='{}'".format(tolerance))
+        return self.run_tool('calibrate_routes', args, callback) # returns 1 if error
+
+    def create_route_events(self, routes, route_id, events, event_route_field, measure_field, output, to_measure_field=None, offset_field=None, callback=None):
+        """Creates point or line features from a table of events located by route and measure.
+
+        Keyword arguments:
+
+        routes -- Input vector routes file, containing measured polylines. 
+        route_id -- Field identifying each route. 
+        events -- Input CSV file of route events. 
+        event_route_field -- Name of the event table column identifying the route of each event. 
+        measure_field -- Name of the event table column holding the measure, or from-measure, of each event. 
+        to_measure_field -- Optional name of the event table column holding the to-measure of each line event. 
+        offset_field -- Optional name of the event table column holding the offset of each event from its route. 
+        output -- Output vector file. 
+        callback -- Custom function for handling tool text outputs.
+        """
+        args = []
+        args.append("--routes='{}'".format(routes))
+        args.append("--route_id='{}'".format(route_id))
+        args.append("--events='{}'".format(events))
+        args.append("--event_route_field='{}'".format(event_route_field))
+        args.append("--measure_field='{}'".format(measure_field))
+        if to_measure_field is not None: args.append("--to_measure_field='{}'".format(to_measure_field))
+        if offset_field is not None: args.append("--offset_field='{}'".format(offset_field))
+        args.append("--output='{}'".format(output))
+        return self.run_tool('create_route_events', args, callback) # returns 1 if error
+
+    def locate_points_along_routes(self, i, routes, route_id, output, tolerance=None, callback=None):
+        """Finds the route identifiers and measures of points located along measured routes.
+
+        Keyword arguments:
+
+        i -- Input vector points file. 
+        routes -- Input vector routes file, containing measured polylines. 
+        route_id -- Field identifying each route. 
+        output -- Output vector points file. 
+        tolerance -- Optional maximum distance between a point and its route. 
+        callback -- Custom function for handling tool text outputs.
+        """
+        args = []
+        args.append("--input='{}'".format(i))
+        args.append("--routes='{}'".format(routes))
+        args.append("--route_id='{}'".format(route_id))
+        args.append("--output='{}'".format(output))
+        if tolerance is not None: args.append("--tolerance='{}'".format(tolerance))
+        return self.run_tool('locate_points_along_routes', args, callback) # returns 1 if error
+
+    #################################
+    # GIS Analysis/Network Analysis #
+    #################################
+
+    def closest_facility(self, network, incidents, facilities, output, num_facilities=1, max_cost=None, from_facility=False, cost_field=None, oneway_field=None, snap=0.0, callback=None):
+        """Finds the least-cost routes between incidents and their closest facilities on a line network.
+
+        Keyword arguments:
+
+        network -- Input vector line network file. 
+        incidents -- Input vector points file of the incidents. 
+        facilities -- Input vector points file of the facilities. 
+        output -- Output vector lines file. 
+        num_facilities -- Number of closest facilities found for each incident. 
+        max_cost -- Optional maximum cost of the routes. 
+        from_facility -- Optional flag to measure the costs of travel from the facilities to the incidents. 
+        cost_field -- Optional numeric field holding the cost of travelling each line; line length is used by default. 
+        oneway_field -- Optional field restricting the direction of travel on each line. 
+        snap -- Distance within which line end points are joined into a network node. 
+        callback -- Custom function for handling tool text outputs.
+        """
+        args = []
+        args.append("--network='{}'".format(network))
+        args.append("--incidents='{}'".format(incidents))
+        args.append("--facilities='{}'".format(facilities))
+        args.append("--output='{}'".format(output))
+        args.append("--num_facilities={}".format(num_facilities))
+        if max_cost is not None: args.append("--max_cost='{}'".format(max_cost))
+        if from_facility: args.append("--from_facility")
+        if cost_field is not None: args.append("--cost_field='{}'".format(cost_field))
+        if oneway_field is not None: args.append("--oneway_field='{}'".format(oneway_field))
+        args.append("--snap={}".format(snap))
+        return self.run_tool('closest_facility', args, callback) # returns 1 if error
+
+    def od_cost_matrix(self, network, origins, output, destinations=None, max_cost=None, cost_field=None, oneway_field=None, snap=0.0, callback=None):
+        """Calculates the least costs of travel between origins and destinations on a line network.
+
+        Keyword arguments:
+
+        network -- Input vector line network file. 
+        origins -- Input vector points file of the origins. 
+        destinations -- Optional input vector points file of the destinations; the origins are used by default. 
+        output -- Output CSV file. 
+        max_cost -- Optional maximum cost of the output origin-destination pairs. 
+        cost_field -- Optional numeric field holding the cost of travelling each line; line length is used by default. 
+        oneway_field -- Optional field restricting the direction of travel on each line. 
+        snap -- Distance within which line end points are joined into a network node. 
+        callback -- Custom function for handling tool text outputs.
+        """
+        args = []
+        args.append("--network='{}'".format(network))
+        args.append("--origins='{}'".format(origins))
+        if destinations is not None: args.append("--destinations='{}'".format(destinations))
+        args.append("--output='{}'".format(output))
+        if max_cost is not None: args.append("--max_cost='{}'".format(max_cost))
+        if cost_field is not None: args.append("--cost_field='{}'".format(cost_field))
+        if oneway_field is not None: args.append("--oneway_field='{}'".format(oneway_field))
+        args.append("--snap={}".format(snap))
+        return self.run_tool('od_cost_matrix', args, callback) # returns 1 if error
+
+    def service_area(self, network, facilities, output, breaks, dist, to_facility=False, cost_field=None, oneway_field=None, snap=0.0, callback=None):
+        """Maps the areas that can be reached from facilities within a set of costs on a line network.
+
+        Keyword arguments:
+
+        network -- Input vector line network file. 
+        facilities -- Input vector points file of the facilities. 
+        output -- Output vector polygons file. 
+        breaks -- Comma-separated list of the costs of the service areas. 
+        dist -- Distance by which the reachable lines are buffered. 
+        to_facility -- Optional flag to measure the costs of travel to, rather than from, the facilities. 
+        cost_field -- Optional numeric field holding the cost of travelling each line; line length is used by default. 
+        oneway_field -- Optional field restricting the direction of travel on each line. 
+        snap -- Distance within which line end points are joined into a network node. 
+        callback -- Custom function for handling tool text outputs.
+        """
+        args = []
+        args.append("--network='{}'".format(network))
+        args.append("--facilities='{}'".format(facilities))
+        args.append("--output='{}'".format(output))
+        args.append("--breaks='{}'".format(breaks))
+        args.append("--dist='{}'".format(dist))
+        if to_facility: args.append("--to_facility")
+        if cost_field is not None: args.append("--cost_field='{}'".format(cost_field))
+        if oneway_field is not None: args.append("--oneway_field='{}'".format(oneway_field))
+        args.append("--snap={}".format(snap))
+        return self.run_tool('service_area', args, callback) # returns 1 if error
+
+    def shortest_path(self, network, stops, output, cost_field=None, oneway_field=None, snap=0.0, callback=None):
+        """Finds the least-cost route through a sequence of stops on a line network.
+
+        Keyword arguments:
+
+        network -- Input vector line network file. 
+        stops -- Input vector points file of the stops, in the order in which they are visited. 
+        output -- Output vector lines file. 
+        cost_field -- Optional numeric field holding the cost of travelling each line; line length is used by default. 
+        oneway_field -- Optional field restricting the direction of travel on each line. 
+        snap -- Distance within which line end points are joined into a network node. 
+        callback -- Custom function for handling tool text outputs.
+        """
+        args = []
+        args.append("--network='{}'".format(network))
+        args.append("--stops='{}'".format(stops))
+        args.append("--output='{}'".format(output))
+        if cost_field is not None: args.append("--cost_field='{}'".format(cost_field))
+        if oneway_field is not None: args.append("--oneway_field='{}'".format(oneway_field))
+        args.append("--snap={}".format(snap))
+        return self.run_tool('shortest_path', args, callback) # returns 1 if error
+
     ##############################
     # GIS Analysis/Overlay Tools #
     ##############################
@@ -2414,6 +2781,32 @@ Okay, that's it for now.
         args.append("--output='{}'".format(output))
         return self.run_tool('polygonize', args, callback) # returns 1 if error
 
+    def spatial_join(self, i, join, output, predicate="intersects", max_dist=None, stat="first", fields=None, one_to_many=False, callback=None):
+        """Joins the attributes of one vector to the features of another based on their spatial relationship.
+
+        Keyword arguments:
+
+        i -- Input vector file, to which attributes are joined. 
+        join -- Join vector file, from which attributes are imported. 
+        output -- Output vector file. 
+        predicate -- Spatial relationship of the joined features; options are 'intersects', 'within', 'contains', and 'nearest'. 
+        max_dist -- Optional maximum distance to the nearest join feature, used with the 'nearest' predicate. 
+        stat -- Statistic used to combine the attributes of multiple join features; options are 'first', 'count', 'sum', 'mean', 'min', and 'max'. 
+        fields -- Optional comma-separated list of the join fields to import; all fields are imported if none are listed. 
+        one_to_many -- Optional flag to output a copy of each input feature for each join feature that it is related to. 
+        callback -- Custom function for handling tool text outputs.
+        """
+        args = []
+        args.append("--input='{}'".format(i))
+        args.append("--join='{}'".format(join))
+        args.append("--output='{}'".format(output))
+        args.append("--predicate={}".format(predicate))
+        if max_dist is not None: args.append("--max_dist='{}'".format(max_dist))
+        args.append("--stat={}".format(stat))
+        if fields is not None: args.append("--fields='{}'".format(fields))
+        if one_to_many: args.append("--one_to_many")
+        return self.run_tool('spatial_join', args, callback) # returns 1 if error
+
     def split_with_lines(self, i, split, output, callback=None):
         """Splits the lines or polygons in one layer using the lines in another layer.
 
@@ -2896,6 +3289,38 @@ Okay, that's it for now.
         args.append("--zfactor={}".format(zfactor))
         return self.run_tool('curvedness', args, callback) # returns 1 if error
 
+    def dem_of_difference(self, dem1, dem2, error1, error2, output, output_html, output_prob=None, min_lod=0.0, confidence=0.95, spatial_coherence=False, filter=5, callback=None):
+        """Calculates a thresholded DEM of difference from two DEMs and their survey uncertainties.
+
+        Keyword arguments:
+
+        dem1 -- Input raster DEM file of the earlier survey. 
+        dem2 -- Input raster DEM file of the later survey. 
+        error1 -- Elevation uncertainty (standard deviation) of the earlier DEM, as a raster or constant value. 
+        error2 -- Elevation uncertainty (standard deviation) of the later DEM, as a raster or constant value. 
+        output -- Output thresholded DEM of difference raster file. 
+        output_html -- Output HTML report file of erosion and deposition volumes. 
+        output_prob -- Optional output raster file of the probability of change. 
+        min_lod -- Minimum level of detection, i.e. the smallest absolute elevation change considered real. 
+        confidence -- Confidence level (0.5-0.999) used to threshold the change and report volume uncertainties. 
+        spatial_coherence -- Update the probability of change using the spatial coherence of erosion and deposition. 
+        filter -- Size of the neighbourhood used to measure spatial coherence, in grid cells. 
+        callback -- Custom function for handling tool text outputs.
+        """
+        args = []
+        args.append("--dem1='{}'".format(dem1))
+        args.append("--dem2='{}'".format(dem2))
+        args.append("--error1='{}'".format(error1))
+        args.append("--error2='{}'".format(error2))
+        args.append("--output='{}'".format(output))
+        args.append("--output_html='{}'".format(output_html))
+        if output_prob is not None: args.append("--output_prob='{}'".format(output_prob))
+        args.append("--min_lod={}".format(min_lod))
+        args.append("--confidence={}".format(confidence))
+        if spatial_coherence: args.append("--spatial_coherence")
+        args.append("--filter={}".format(filter))
+        return self.run_tool('dem_of_difference', args, callback) # returns 1 if error
+
     def dem_void_filling(self, dem, fill, output, mean_plane_dist=20, edge_treatment="use DEM", weight_value=2.0, callback=None):
         """This tool can be used to fill the void areas of a DEM using another fill DEM data set.
 
@@ -3416,6 +3841,30 @@ Okay, that's it for now.
         if full_mode: args.append("--full_mode")
         return self.run_tool('hypsometrically_tinted_hillshade', args, callback) # returns 1 if error
 
+    def iwahashi_pike_class(self, dem, output, num_classes=8, radius=10, conv_threshold=0.0, text_threshold=1.0, zfactor=None, callback=None):
+        """Classifies terrain using the nested-means slope, convexity, and texture method of Iwahashi and Pike (2007).
+
+        Keyword arguments:
+
+        dem -- Input raster DEM file. 
+        output -- Output raster file. 
+        num_classes -- Number of terrain classes (8, 12, or 16). 
+        radius -- Radius of the circular neighbourhood used to measure convexity and texture, in grid cells. 
+        conv_threshold -- Laplacian threshold above which a grid cell is considered convex. 
+        text_threshold -- Elevation difference from the 3 x 3 median above which a grid cell is considered a pit or peak. 
+        zfactor -- Optional multiplier for when the vertical and horizontal units are not the same. 
+        callback -- Custom function for handling tool text outputs.
+        """
+        args = []
+        args.append("--dem='{}'".format(dem))
+        args.append("--output='{}'".format(output))
+        args.append("--num_classes={}".format(num_classes))
+        args.append("--radius={}".format(radius))
+        args.append("--conv_threshold={}".format(conv_threshold))
+        args.append("--text_threshold={}".format(text_threshold))
+        if zfactor is not None: args.append("--zfactor='{}'".format(zfactor))
+        return self.run_tool('iwahashi_pike_class', args, callback) # returns 1 if error
+
     def local_hypsometric_analysis(self, i, out_mag, out_scale, min_scale=4, step=1, num_steps=10, step_nonlinearity=1.0, callback=None):
         """This tool calculates a local, neighbourhood-based hypsometric integral raster.
 
@@ -3914,7 +4363,7 @@ Okay, that's it for now.
         args.append("--output='{}'".format(output))
         return self.run_tool('num_upslope_neighbours', args, callback) # returns 1 if error
 
-    def openness(self, i, pos_output, neg_output, dist=20, callback=None):
+    def openness(self, i, pos_output, neg_output, dist=20, num_dirs=8, callback=None):
         """This tool calculates the topographic openness index from an input DEM.
 
         Keyword arguments:
@@ -3923,6 +4372,7 @@ Okay, that's it for now.
         pos_output -- Name of the positive openness output raster file. 
         neg_output -- Name of the negative openness output raster file. 
         dist -- Search distance, in grid cells. 
+        num_dirs -- Number of azimuth directions used to calculate openness. 
         callback -- Custom function for handling tool text outputs.
         """
         args = []
@@ -3930,6 +4380,7 @@ Okay, that's it for now.
         args.append("--pos_output='{}'".format(pos_output))
         args.append("--neg_output='{}'".format(neg_output))
         args.append("--dist={}".format(dist))
+        args.append("--num_dirs={}".format(num_dirs))
         return self.run_tool('openness', args, callback) # returns 1 if error
 
     def pennock_landform_class(self, dem, output, slope=3.0, prof=0.1, plan=0.0, zfactor=None, callback=None):
@@ -4218,6 +4669,24 @@ Okay, that's it for now.
         args.append("--zfactor={}".format(zfactor))
         return self.run_tool('shape_index', args, callback) # returns 1 if error
 
+    def sky_view_factor(self, dem, output, dist=10, num_dirs=16, callback=None):
+        """Calculates the proportion of the sky hemisphere that is not obstructed by relief.
+
+        Keyword arguments:
+
+        dem -- Input raster DEM file. 
+        output -- Output raster file. 
+        dist -- Search distance, in grid cells. 
+        num_dirs -- Number of azimuth directions used to calculate the sky-view factor. 
+        callback -- Custom function for handling tool text outputs.
+        """
+        args = []
+        args.append("--dem='{}'".format(dem))
+        args.append("--output='{}'".format(output))
+        args.append("--dist={}".format(dist))
+        args.append("--num_dirs={}".format(num_dirs))
+        return self.run_tool('sky_view_factor', args, callback) # returns 1 if error
+
     def slope(self, dem, output, zfactor=None, units="degrees", callback=None):
         """Calculates a slope raster from an input DEM.
 
@@ -4292,6 +4761,46 @@ Okay, that's it for now.
         args.append("--scale_threshold={}".format(scale_threshold))
         return self.run_tool('smooth_vegetation_residual', args, callback) # returns 1 if error
 
+    def solar_radiation(self, dem, output, output_direct=None, output_diffuse=None, output_reflected=None, lat=None, start_day=1, end_day=365, day_interval=7, time_step=0.5, az_fraction=15.0, max_dist=None, transmissivity=0.5, diffuse_prop=0.3, albedo=0.2, callback=None):
+        """Estimates the incoming direct, diffuse, and reflected shortwave radiation received by a DEM.
+
+        Keyword arguments:
+
+        dem -- Input raster DEM file. 
+        output -- Output raster file of total (global) radiation, in Wh/m2. 
+        output_direct -- Optional output raster file of direct radiation, in Wh/m2. 
+        output_diffuse -- Optional output raster file of diffuse radiation, in Wh/m2. 
+        output_reflected -- Optional output raster file of reflected radiation, in Wh/m2. 
+        lat -- Site latitude, in degrees. If unspecified, it is calculated from the DEM's coordinate reference system. 
+        start_day -- Start day of the year (1-365). 
+        end_day -- End day of the year (1-365). 
+        day_interval -- Interval, in days, between sampled days. 
+        time_step -- Time step, in hours, between sampled sun positions. 
+        az_fraction -- Width of the azimuth sectors used to calculate horizon angles, in degrees. 
+        max_dist -- Optional maximum search distance for horizon angles. Minimum value is 5 x cell size. 
+        transmissivity -- Proportion of radiation transmitted through the atmosphere along the zenith path (0-1). 
+        diffuse_prop -- Proportion of global radiation that is diffuse (0-1). 
+        albedo -- Surface albedo used to calculate reflected radiation (0-1). 
+        callback -- Custom function for handling tool text outputs.
+        """
+        args = []
+        args.append("--dem='{}'".format(dem))
+        args.append("--output='{}'".format(output))
+        if output_direct is not None: args.append("--output_direct='{}'".format(output_direct))
+        if output_diffuse is not None: args.append("--output_diffuse='{}'".format(output_diffuse))
+        if output_reflected is not None: args.append("--output_reflected='{}'".format(output_reflected))
+        if lat is not None: args.append("--lat='{}'".format(lat))
+        args.append("--start_day={}".format(start_day))
+        args.append("--end_day={}".format(end_day))
+        args.append("--day_interval={}".format(day_interval))
+        args.append("--time_step={}".format(time_step))
+        args.append("--az_fraction={}".format(az_fraction))
+        if max_dist is not None: args.append("--max_dist='{}'".format(max_dist))
+        args.append("--transmissivity={}".format(transmissivity))
+        args.append("--diffuse_prop={}".format(diffuse_prop))
+        args.append("--albedo={}".format(albedo))
+        return self.run_tool('solar_radiation', args, callback) # returns 1 if error
+
     def spherical_std_dev_of_normals(self, dem, output, filter=11, callback=None):
         """Calculates the spherical standard deviation of surface normals for a DEM.
 
@@ -4492,6 +5001,30 @@ Okay, that's it for now.
         if zfactor is not None: args.append("--zfactor='{}'".format(zfactor))
         return self.run_tool('total_curvature', args, callback) # returns 1 if error
 
+    def tpi_landform_class(self, dem, output, small_filter=11, large_filter=51, threshold=1.0, slope=5.0, zfactor=None, callback=None):
+        """Classifies landforms using small- and large-neighbourhood topographic position index (TPI) and slope.
+
+        Keyword arguments:
+
+        dem -- Input raster DEM file. 
+        output -- Output raster file. 
+        small_filter -- Size of the small TPI neighbourhood, in grid cells. 
+        large_filter -- Size of the large TPI neighbourhood, in grid cells. 
+        threshold -- Standardized TPI threshold, in standard deviations. 
+        slope -- Slope threshold, in degrees, separating plains from open slopes. 
+        zfactor -- Optional multiplier for when the vertical and horizontal units are not the same. 
+        callback -- Custom function for handling tool text outputs.
+        """
+        args = []
+        args.append("--dem='{}'".format(dem))
+        args.append("--output='{}'".format(output))
+        args.append("--small_filter={}".format(small_filter))
+        args.append("--large_filter={}".format(large_filter))
+        args.append("--threshold={}".format(threshold))
+        args.append("--slope={}".format(slope))
+        if zfactor is not None: args.append("--zfactor='{}'".format(zfactor))
+        return self.run_tool('tpi_landform_class', args, callback) # returns 1 if error
+
     def unsphericity(self, dem, output, log=False, zfactor=1.0, callback=None):
         """This tool calculates the unsphericity curvature from an input DEM.
 
@@ -5174,6 +5707,40 @@ Okay, that's it for now.
         if esri_pntr: args.append("--esri_pntr")
         return self.run_tool('flow_length_diff', args, callback) # returns 1 if error
 
+    def hand_flood_inundation(self, hand, links, d8_pntr, table, output, dem=None, table_type="stage", rating_curves=None, mannings_n=0.05, stage_step=0.1, max_stage=20.0, esri_pntr=False, callback=None):
+        """Maps flood depths and inundation extents from a HAND raster and per-reach stage or discharge scenarios.
+
+        Keyword arguments:
+
+        hand -- Input height above nearest drainage (HAND) raster file. 
+        links -- Input stream link identifier raster file. 
+        d8_pntr -- Input D8 pointer raster file. 
+        dem -- Input raster DEM file; required for discharge tables and rating curve outputs. 
+        table -- Input CSV table of per-reach stages or discharges, one column per scenario. 
+        table_type -- Type of values in the scenario table; options include 'stage' and 'discharge'. 
+        output -- Output raster file name; scenario names are appended to it. 
+        rating_curves -- Optional output CSV file of the synthetic rating curves. 
+        mannings_n -- Manning's roughness coefficient used in the rating curves. 
+        stage_step -- Stage increment used to evaluate the rating curves. 
+        max_stage -- Maximum stage used to evaluate the rating curves. 
+        esri_pntr -- D8 pointer uses the ESRI style scheme. 
+        callback -- Custom function for handling tool text outputs.
+        """
+        args = []
+        args.append("--hand='{}'".format(hand))
+        args.append("--links='{}'".format(links))
+        args.append("--d8_pntr='{}'".format(d8_pntr))
+        if dem is not None: args.append("--dem='{}'".format(dem))
+        args.append("--table='{}'".format(table))
+        args.append("--table_type={}".format(table_type))
+        args.append("--output='{}'".format(output))
+        if rating_curves is not None: args.append("--rating_curves='{}'".format(rating_curves))
+        args.append("--mannings_n={}".format(mannings_n))
+        args.append("--stage_step={}".format(stage_step))
+        args.append("--max_stage={}".format(max_stage))
+        if esri_pntr: args.append("--esri_pntr")
+        return self.run_tool('hand_flood_inundation', args, callback) # returns 1 if error
+
     def hillslopes(self, d8_pntr, streams, output, esri_pntr=False, callback=None):
         """Identifies the individual hillslopes draining to each link in a stream network.
 
@@ -7302,7 +7869,7 @@ Okay, that's it for now.
         args.append("--output='{}'".format(output))
         return self.run_tool('find_flightline_edge_points', args, callback) # returns 1 if error
 
-    def flightline_overlap(self, i=None, output=None, resolution=1.0, callback=None):
+    def flightline_overlap(self, i=None, output=None, resolution=1.0, palette=None, callback=None):
         """Reads a LiDAR (LAS) point file and outputs a raster containing the number of overlapping flight-lines in each grid cell.
 
         Keyword arguments:
@@ -7310,12 +7877,14 @@ Okay, that's it for now.
         i -- Input LiDAR file. 
         output -- Output file. 
         resolution -- Output raster's grid resolution. 
+        palette -- Optional palette name (for use with Whitebox raster files). 
         callback -- Custom function for handling tool text outputs.
         """
         args = []
         if i is not None: args.append("--input='{}'".format(i))
         if output is not None: args.append("--output='{}'".format(output))
         args.append("--resolution={}".format(resolution))
+        if palette is not None: args.append("--palette='{}'".format(palette))
         return self.run_tool('flightline_overlap', args, callback) # returns 1 if error
 
     def height_above_ground(self, i=None, output=None, callback=None):
@@ -7438,7 +8007,7 @@ Okay, that's it for now.
         if output is not None: args.append("--output='{}'".format(output))
         return self.run_tool('laz_to_las', args, callback) # returns 1 if error
 
-    def lidar_block_maximum(self, i=None, output=None, resolution=1.0, callback=None):
+    def lidar_block_maximum(self, i=None, output=None, resolution=1.0, palette=None, callback=None):
         """Creates a block-maximum raster from an input LAS file. When the input/output parameters are not specified, the tool grids all LAS files contained within the working directory.
 
         Keyword arguments:
@@ -7446,15 +8015,17 @@ Okay, that's it for now.
         i -- Input LiDAR file. 
         output -- Output file. 
         resolution -- Output raster's grid resolution. 
+        palette -- Optional palette name (for use with Whitebox raster files). 
         callback -- Custom function for handling tool text outputs.
         """
         args = []
         if i is not None: args.append("--input='{}'".format(i))
         if output is not None: args.append("--output='{}'".format(output))
         args.append("--resolution={}".format(resolution))
+        if palette is not None: args.append("--palette='{}'".format(palette))
         return self.run_tool('lidar_block_maximum', args, callback) # returns 1 if error
 
-    def lidar_block_minimum(self, i=None, output=None, resolution=1.0, callback=None):
+    def lidar_block_minimum(self, i=None, output=None, resolution=1.0, palette=None, callback=None):
         """Creates a block-minimum raster from an input LAS file. When the input/output parameters are not specified, the tool grids all LAS files contained within the working directory.
 
         Keyword arguments:
@@ -7462,12 +8033,14 @@ Okay, that's it for now.
         i -- Input LiDAR file. 
         output -- Output file. 
         resolution -- Output raster's grid resolution. 
+        palette -- Optional palette name (for use with Whitebox raster files). 
         callback -- Custom function for handling tool text outputs.
         """
         args = []
         if i is not None: args.append("--input='{}'".format(i))
         if output is not None: args.append("--output='{}'".format(output))
         args.append("--resolution={}".format(resolution))
+        if palette is not None: args.append("--palette='{}'".format(palette))
         return self.run_tool('lidar_block_minimum', args, callback) # returns 1 if error
 
     def lidar_classify_subset(self, base, subset, output, subset_class, nonsubset_class=None, callback=None):
@@ -7686,7 +8259,7 @@ Okay, that's it for now.
         args.append("--clip={}".format(clip))
         return self.run_tool('lidar_histogram', args, callback) # returns 1 if error
 
-    def lidar_idw_interpolation(self, i=None, output=None, parameter="elevation", returns="all", resolution=1.0, weight=1.0, radius=2.5, exclude_cls=None, minz=None, maxz=None, callback=None):
+    def lidar_idw_interpolation(self, i=None, output=None, parameter="elevation", returns="all", resolution=1.0, weight=1.0, radius=2.5, exclude_cls=None, minz=None, maxz=None, palette=None, callback=None):
         """Interpolates LAS files using an inverse-distance weighted (IDW) scheme. When the input/output parameters are not specified, the tool interpolates all LAS files contained within the working directory.
 
         Keyword arguments:
@@ -7701,6 +8274,7 @@ Okay, that's it for now.
         exclude_cls -- Optional exclude classes from interpolation; Valid class values range from 0 to 18, based on LAS specifications. Example, --exclude_cls='3,4,5,6,7,18'. 
         minz -- Optional minimum elevation for inclusion in interpolation. 
         maxz -- Optional maximum elevation for inclusion in interpolation. 
+        palette -- Optional palette name (for use with Whitebox raster files). 
         callback -- Custom function for handling tool text outputs.
         """
         args = []
@@ -7714,6 +8288,7 @@ Okay, that's it for now.
         if exclude_cls is not None: args.append("--exclude_cls='{}'".format(exclude_cls))
         if minz is not None: args.append("--minz='{}'".format(minz))
         if maxz is not None: args.append("--maxz='{}'".format(maxz))
+        if palette is not None: args.append("--palette='{}'".format(palette))
         return self.run_tool('lidar_idw_interpolation', args, callback) # returns 1 if error
 
     def lidar_info(self, i, output, density=True, vlr=True, geokeys=True, callback=None):
@@ -7770,7 +8345,7 @@ Okay, that's it for now.
         args.append("--resolution={}".format(resolution))
         return self.run_tool('lidar_kappa_index', args, callback) # returns 1 if error
 
-    def lidar_nearest_neighbour_gridding(self, i=None, output=None, parameter="elevation", returns="all", resolution=1.0, radius=2.5, exclude_cls=None, minz=None, maxz=None, callback=None):
+    def lidar_nearest_neighbour_gridding(self, i=None, output=None, parameter="elevation", returns="all", resolution=1.0, radius=2.5, exclude_cls=None, minz=None, maxz=None, palette=None, callback=None):
         """Grids LiDAR files using nearest-neighbour scheme. When the input/output parameters are not specified, the tool grids all LAS files contained within the working directory.
 
         Keyword arguments:
@@ -7784,6 +8359,7 @@ Okay, that's it for now.
         exclude_cls -- Optional exclude classes from interpolation; Valid class values range from 0 to 18, based on LAS specifications. Example, --exclude_cls='3,4,5,6,7,18'. 
         minz -- Optional minimum elevation for inclusion in interpolation. 
         maxz -- Optional maximum elevation for inclusion in interpolation. 
+        palette -- Optional palette name (for use with Whitebox raster files). 
         callback -- Custom function for handling tool text outputs.
         """
         args = []
@@ -7796,9 +8372,10 @@ Okay, that's it for now.
         if exclude_cls is not None: args.append("--exclude_cls='{}'".format(exclude_cls))
         if minz is not None: args.append("--minz='{}'".format(minz))
         if maxz is not None: args.append("--maxz='{}'".format(maxz))
+        if palette is not None: args.append("--palette='{}'".format(palette))
         return self.run_tool('lidar_nearest_neighbour_gridding', args, callback) # returns 1 if error
 
-    def lidar_point_density(self, i=None, output=None, returns="all", resolution=1.0, radius=2.5, exclude_cls=None, minz=None, maxz=None, callback=None):
+    def lidar_point_density(self, i=None, output=None, returns="all", resolution=1.0, radius=2.5, exclude_cls=None, palette=None, minz=None, maxz=None, callback=None):
         """Calculates the spatial pattern of point density for a LiDAR data set. When the input/output parameters are not specified, the tool grids all LAS files contained within the working directory.
 
         Keyword arguments:
@@ -7809,6 +8386,7 @@ Okay, that's it for now.
         resolution -- Output raster's grid resolution. 
         radius -- Search radius. 
         exclude_cls -- Optional exclude classes from interpolation; Valid class values range from 0 to 18, based on LAS specifications. Example, --exclude_cls='3,4,5,6,7,18'. 
+        palette -- Optional palette name (for use with Whitebox raster files). 
         minz -- Optional minimum elevation for inclusion in interpolation. 
         maxz -- Optional maximum elevation for inclusion in interpolation. 
         callback -- Custom function for handling tool text outputs.
@@ -7820,6 +8398,7 @@ Okay, that's it for now.
         args.append("--resolution={}".format(resolution))
         args.append("--radius={}".format(radius))
         if exclude_cls is not None: args.append("--exclude_cls='{}'".format(exclude_cls))
+        if palette is not None: args.append("--palette='{}'".format(palette))
         if minz is not None: args.append("--minz='{}'".format(minz))
         if maxz is not None: args.append("--maxz='{}'".format(maxz))
         return self.run_tool('lidar_point_density', args, callback) # returns 1 if error
@@ -7966,7 +8545,7 @@ Okay, that's it for now.
         if classify: args.append("--classify")
         return self.run_tool('lidar_remove_outliers', args, callback) # returns 1 if error
 
-    def lidar_rooftop_analysis(self, buildings, output, i=None, radius=2.0, num_iter=50, num_samples=10, threshold=0.15, model_size=15, max_slope=65.0, norm_diff=10.0, azimuth=180.0, altitude=30.0, callback=None):
+    def lidar_rooftop_analysis(self, buildings, output, i=None, radius=2.0, num_iter=50, num_samples=10, threshold=0.15, model_size=15, max_slope=65.0, norm_diff=10.0, maxzdiff=1.0, azimuth=180.0, altitude=30.0, callback=None):
         """Identifies roof segments in a LiDAR point cloud.
 
         Keyword arguments:
@@ -7981,6 +8560,7 @@ Okay, that's it for now.
         model_size -- Acceptable model size, in points. 
         max_slope -- Maximum planar slope, in degrees. 
         norm_diff -- Maximum difference in normal vectors, in degrees. 
+        maxzdiff -- Maximum difference in elevation (z units) between neighbouring points of the same segment. 
         azimuth -- Illumination source azimuth, in degrees. 
         altitude -- Illumination source altitude in degrees. 
         callback -- Custom function for handling tool text outputs.
@@ -7996,6 +8576,7 @@ Okay, that's it for now.
         args.append("--model_size={}".format(model_size))
         args.append("--max_slope={}".format(max_slope))
         args.append("--norm_diff={}".format(norm_diff))
+        args.append("--maxzdiff={}".format(maxzdiff))
         args.append("--azimuth={}".format(azimuth))
         args.append("--altitude={}".format(altitude))
         return self.run_tool('lidar_rooftop_analysis', args, callback) # returns 1 if error
@@ -8914,6 +9495,36 @@ Okay, that's it for now.
         args.append("--output='{}'".format(output))
         return self.run_tool('ceil', args, callback) # returns 1 if error
 
+    def cluster_and_outlier_analysis(self, i, field, output, weights="distance_band", distance=None, num_neighbours=8, row_standardize=True, permutations=999, significance=0.05, seed=None, callback=None):
+        """Identifies clusters and spatial outliers of a vector attribute using the local Moran's I (LISA).
+
+        Keyword arguments:
+
+        i -- Input vector points or polygons file. 
+        field -- Input numeric field to analyze. 
+        output -- Output vector file. 
+        weights -- Method of defining the neighbours of each feature; options are 'distance_band', 'k_nearest', 'queen', and 'rook'. 
+        distance -- Optional neighbourhood distance for distance-band weights; by default, the smallest distance giving every feature a neighbour. 
+        num_neighbours -- Number of neighbours for k-nearest weights. 
+        row_standardize -- Divide the weights of each feature's neighbours by its number of neighbours?. 
+        permutations -- Number of random permutations used to calculate the pseudo p-values. 
+        significance -- Significance level of the cluster and outlier types. 
+        seed -- Optional seed for the random number generator, for reproducible outputs. 
+        callback -- Custom function for handling tool text outputs.
+        """
+        args = []
+        args.append("--input='{}'".format(i))
+        args.append("--field='{}'".format(field))
+        args.append("--output='{}'".format(output))
+        args.append("--weights={}".format(weights))
+        if distance is not None: args.append("--distance='{}'".format(distance))
+        args.append("--num_neighbours={}".format(num_neighbours))
+        if row_standardize: args.append("--row_standardize")
+        args.append("--permutations={}".format(permutations))
+        args.append("--significance={}".format(significance))
+        if seed is not None: args.append("--seed='{}'".format(seed))
+        return self.run_tool('cluster_and_outlier_analysis', args, callback) # returns 1 if error
+
     def conditional_evaluation(self, i, output, statement="", true=None, false=None, callback=None):
         """Performs a conditional evaluation (if-then-else) operation on a raster.
 
@@ -9144,6 +9755,32 @@ Okay, that's it for now.
         if incl_equals: args.append("--incl_equals")
         return self.run_tool('greater_than', args, callback) # returns 1 if error
 
+    def hot_spot_analysis(self, i, field, output, weights="distance_band", distance=None, num_neighbours=8, permutations=999, seed=None, callback=None):
+        """Identifies hot spots and cold spots of a vector attribute using the Getis-Ord Gi* statistic.
+
+        Keyword arguments:
+
+        i -- Input vector points or polygons file. 
+        field -- Input numeric field to analyze. 
+        output -- Output vector file. 
+        weights -- Method of defining the neighbours of each feature; options are 'distance_band', 'k_nearest', 'queen', and 'rook'. 
+        distance -- Optional neighbourhood distance for distance-band weights; by default, the smallest distance giving every feature a neighbour. 
+        num_neighbours -- Number of neighbours for k-nearest weights. 
+        permutations -- Number of random permutations used to calculate the pseudo p-values. 
+        seed -- Optional seed for the random number generator, for reproducible outputs. 
+        callback -- Custom function for handling tool text outputs.
+        """
+        args = []
+        args.append("--input='{}'".format(i))
+        args.append("--field='{}'".format(field))
+        args.append("--output='{}'".format(output))
+        args.append("--weights={}".format(weights))
+        if distance is not None: args.append("--distance='{}'".format(distance))
+        args.append("--num_neighbours={}".format(num_neighbours))
+        args.append("--permutations={}".format(permutations))
+        if seed is not None: args.append("--seed='{}'".format(seed))
+        return self.run_tool('hot_spot_analysis', args, callback) # returns 1 if error
+
     def image_autocorrelation(self, inputs, output, contiguity="Rook", callback=None):
         """Performs Moran's I analysis on two or more input images.
 
@@ -9982,6 +10619,34 @@ Okay, that's it for now.
         if num_samples is not None: args.append("--num_samples='{}'".format(num_samples))
         return self.run_tool('two_sample_ks_test', args, callback) # returns 1 if error
 
+    def vector_autocorrelation(self, i, field, output, weights="distance_band", distance=None, num_neighbours=8, row_standardize=True, permutations=999, seed=None, callback=None):
+        """Measures the global spatial autocorrelation of a vector attribute using Moran's I and Geary's C.
+
+        Keyword arguments:
+
+        i -- Input vector points or polygons file. 
+        field -- Input numeric field to analyze. 
+        output -- Output HTML report file. 
+        weights -- Method of defining the neighbours of each feature; options are 'distance_band', 'k_nearest', 'queen', and 'rook'. 
+        distance -- Optional neighbourhood distance for distance-band weights; by default, the smallest distance giving every feature a neighbour. 
+        num_neighbours -- Number of neighbours for k-nearest weights. 
+        row_standardize -- Divide the weights of each feature's neighbours by its number of neighbours?. 
+        permutations -- Number of random permutations used to calculate the pseudo p-values. 
+        seed -- Optional seed for the random number generator, for reproducible outputs. 
+        callback -- Custom function for handling tool text outputs.
+        """
+        args = []
+        args.append("--input='{}'".format(i))
+        args.append("--field='{}'".format(field))
+        args.append("--output='{}'".format(output))
+        args.append("--weights={}".format(weights))
+        if distance is not None: args.append("--distance='{}'".format(distance))
+        args.append("--num_neighbours={}".format(num_neighbours))
+        if row_standardize: args.append("--row_standardize")
+        args.append("--permutations={}".format(permutations))
+        if seed is not None: args.append("--seed='{}'".format(seed))
+        return self.run_tool('vector_autocorrelation', args, callback) # returns 1 if error
+
     def wilcoxon_signed_rank_test(self, input1, input2, output, num_samples=None, callback=None):
         """Performs a 2-sample K-S test for significant differences on two input rasters.
 